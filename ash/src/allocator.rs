@@ -1,120 +1,464 @@
-#![allow(dead_code)]
-use vk;
+//! Host allocation callbacks backed by a Rust [`GlobalAlloc`], with an [`AllocationListener`]
+//! that sees every allocation the driver makes on the host.
+//!
+//! ```no_run
+//! use ash::allocator::{AllocationCounter, HostAllocator};
+//! use ash::version::DeviceV1_0;
+//! use ash::vk;
+//! # unsafe fn create(device: &ash::Device, create_info: &vk::BufferCreateInfo) -> ash::prelude::VkResult<()> {
+//! let allocator = HostAllocator::with_listener(AllocationCounter::default());
+//! let callbacks = allocator.callbacks();
+//! let buffer = device.create_buffer(create_info, Some(&*callbacks))?;
+//! println!("{} bytes", allocator.listener().total_live_bytes());
+//! device.destroy_buffer(buffer, Some(&*callbacks));
+//! # Ok(()) }
+//! ```
+use crate::vk;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::marker::PhantomData;
+use std::mem::{self, align_of, size_of};
+use std::ops::Deref;
 use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
-pub trait VkAllocation {
-    unsafe extern "system" fn allocation(
-        *mut (),
-        usize,
-        usize,
-        vk::SystemAllocationScope,
-    ) -> *mut ();
-    unsafe extern "system" fn reallocation(
-        *mut c_void,
-        *mut c_void,
-        usize,
-        usize,
-        vk::SystemAllocationScope,
-    ) -> *mut c_void;
-    unsafe extern "system" fn free(*mut c_void, *mut c_void);
-    unsafe extern "system" fn internal_allocation(
-        *mut c_void,
-        usize,
-        vk::InternalAllocationType,
-        vk::SystemAllocationScope,
-    );
-    unsafe extern "system" fn internal_free(
-        *mut c_void,
-        usize,
-        vk::InternalAllocationType,
-        vk::SystemAllocationScope,
-    );
-    fn create_allocation_callback() -> Option<vk::AllocationCallbacks> {
-        let alloc = vk::AllocationCallbacks {
-            p_user_data: ptr::null_mut(),
-            pfn_allocation: Self::allocation,
-            pfn_reallocation: Self::reallocation,
-            pfn_free: Self::free,
-            pfn_internal_allocation: Self::internal_allocation,
-            pfn_internal_free: Self::internal_free,
-        };
-        Some(alloc)
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Observes the host allocations of a [`HostAllocator`]. The methods are called from any thread
+/// that uses the callbacks, after the allocation succeeded or before the memory is freed.
+pub trait AllocationListener: Send + Sync {
+    fn allocated(&self, _size: usize, _alignment: usize, _scope: vk::SystemAllocationScope) {}
+
+    /// `scope` is the scope of the latest allocation or reallocation of the memory.
+    fn freed(&self, _size: usize, _alignment: usize, _scope: vk::SystemAllocationScope) {}
+
+    fn reallocated(
+        &self,
+        _old_size: usize,
+        _new_size: usize,
+        _alignment: usize,
+        _scope: vk::SystemAllocationScope,
+    ) {
+    }
+
+    /// Notifies of memory the driver allocated itself, such as executable memory.
+    fn internal_allocated(
+        &self,
+        _size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
+    ) {
+    }
+
+    fn internal_freed(
+        &self,
+        _size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
+    ) {
     }
 }
 
-pub struct DefaultAllocatorCallback;
-pub struct TestAlloc;
+impl AllocationListener for () {}
 
-impl VkAllocation for TestAlloc {
-    unsafe extern "system" fn allocation(
-        _: *mut (),
-        _: usize,
-        _: usize,
-        _: vk::SystemAllocationScope,
-    ) -> *mut () {
-        ptr::null_mut()
+/// An [`AllocationListener`] counting the live bytes of every scope.
+#[derive(Debug, Default)]
+pub struct AllocationCounter {
+    live_bytes: [AtomicUsize; 5],
+    live_allocations: AtomicUsize,
+    internal_bytes: AtomicUsize,
+}
+
+impl AllocationCounter {
+    fn scope_bytes(&self, scope: vk::SystemAllocationScope) -> Option<&AtomicUsize> {
+        self.live_bytes.get(scope.as_raw() as usize)
     }
 
-    unsafe extern "system" fn reallocation(
-        _: *mut c_void,
-        _: *mut c_void,
-        _: usize,
-        _: usize,
-        _: vk::SystemAllocationScope,
-    ) -> *mut c_void {
-        ptr::null_mut()
+    pub fn live_bytes(&self, scope: vk::SystemAllocationScope) -> usize {
+        self.scope_bytes(scope)
+            .map_or(0, |bytes| bytes.load(Ordering::Relaxed))
     }
-    unsafe extern "system" fn free(_: *mut c_void, _: *mut c_void) {}
-    unsafe extern "system" fn internal_allocation(
-        _: *mut c_void,
-        _: usize,
-        _: vk::InternalAllocationType,
-        _: vk::SystemAllocationScope,
+
+    pub fn total_live_bytes(&self) -> usize {
+        self.live_bytes
+            .iter()
+            .map(|bytes| bytes.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    pub fn live_allocations(&self) -> usize {
+        self.live_allocations.load(Ordering::Relaxed)
+    }
+
+    /// The bytes of [`AllocationListener::internal_allocated`] that weren't freed.
+    pub fn internal_bytes(&self) -> usize {
+        self.internal_bytes.load(Ordering::Relaxed)
+    }
+}
+
+impl AllocationListener for AllocationCounter {
+    fn allocated(&self, size: usize, _alignment: usize, scope: vk::SystemAllocationScope) {
+        if let Some(bytes) = self.scope_bytes(scope) {
+            bytes.fetch_add(size, Ordering::Relaxed);
+        }
+        self.live_allocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn freed(&self, size: usize, _alignment: usize, scope: vk::SystemAllocationScope) {
+        if let Some(bytes) = self.scope_bytes(scope) {
+            bytes.fetch_sub(size, Ordering::Relaxed);
+        }
+        self.live_allocations.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn reallocated(
+        &self,
+        old_size: usize,
+        new_size: usize,
+        _alignment: usize,
+        scope: vk::SystemAllocationScope,
     ) {
+        if let Some(bytes) = self.scope_bytes(scope) {
+            bytes.fetch_add(new_size, Ordering::Relaxed);
+            bytes.fetch_sub(old_size, Ordering::Relaxed);
+        }
     }
-    unsafe extern "system" fn internal_free(
-        _: *mut c_void,
-        _: usize,
-        _: vk::InternalAllocationType,
-        _: vk::SystemAllocationScope,
+
+    fn internal_allocated(
+        &self,
+        size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
+    ) {
+        self.internal_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    fn internal_freed(
+        &self,
+        size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _scope: vk::SystemAllocationScope,
     ) {
+        self.internal_bytes.fetch_sub(size, Ordering::Relaxed);
     }
 }
-impl VkAllocation for DefaultAllocatorCallback {
+
+/// Stored in front of every allocation, as `pfnFree` only gets the pointer.
+#[derive(Clone, Copy)]
+struct Header {
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+}
+
+impl Header {
+    /// The offset of the memory from the start of the allocation, which keeps the memory at
+    /// `alignment` with the header right in front of it.
+    fn offset(alignment: usize) -> usize {
+        let align = alignment.max(align_of::<Header>());
+        (size_of::<Header>() + align - 1) & !(align - 1)
+    }
+
+    fn layout(&self) -> Option<Layout> {
+        let size = Self::offset(self.alignment).checked_add(self.size)?;
+        Layout::from_size_align(size, self.alignment.max(align_of::<Header>())).ok()
+    }
+
+    unsafe fn read(memory: *mut c_void) -> (*mut u8, Header) {
+        let header = ptr::read((memory as *mut Header).offset(-1));
+        let base = (memory as *mut u8).sub(Self::offset(header.alignment));
+        (base, header)
+    }
+
+    unsafe fn write(self, base: *mut u8) -> *mut c_void {
+        let memory = base.add(Self::offset(self.alignment));
+        ptr::write((memory as *mut Header).offset(-1), self);
+        memory as *mut c_void
+    }
+}
+
+/// Allocates the host memory of Vulkan objects with `A`, and reports the allocations to `L`.
+/// The callbacks of [`HostAllocator::callbacks`] point to the allocator, so it has to outlive
+/// every object created with them.
+#[derive(Debug, Default)]
+pub struct HostAllocator<A = System, L = ()> {
+    allocator: A,
+    listener: L,
+}
+
+impl HostAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<L> HostAllocator<System, L> {
+    pub fn with_listener(listener: L) -> Self {
+        HostAllocator {
+            allocator: System,
+            listener,
+        }
+    }
+}
+
+impl<A, L> HostAllocator<A, L> {
+    pub fn with_allocator(allocator: A, listener: L) -> Self {
+        HostAllocator {
+            allocator,
+            listener,
+        }
+    }
+
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+}
+
+impl<A: GlobalAlloc + Send + Sync, L: AllocationListener> HostAllocator<A, L> {
+    /// The allocation callbacks to pass to commands. Objects have to be destroyed with the
+    /// callbacks they were created with.
+    pub fn callbacks(&self) -> HostAllocationCallbacks<'_> {
+        // The C callbacks return void, which `c_void` can't be constructed as
+        type Notification = unsafe extern "system" fn(
+            *mut c_void,
+            usize,
+            vk::InternalAllocationType,
+            vk::SystemAllocationScope,
+        );
+        unsafe {
+            HostAllocationCallbacks {
+                callbacks: vk::AllocationCallbacks {
+                    p_user_data: self as *const Self as *mut c_void,
+                    pfn_allocation: Some(Self::allocation),
+                    pfn_reallocation: Some(Self::reallocation),
+                    pfn_free: Some(mem::transmute::<
+                        unsafe extern "system" fn(*mut c_void, *mut c_void),
+                        _,
+                    >(Self::free)),
+                    pfn_internal_allocation: Some(mem::transmute::<Notification, _>(
+                        Self::internal_allocation,
+                    )),
+                    pfn_internal_free: Some(mem::transmute::<Notification, _>(Self::internal_free)),
+                },
+                marker: PhantomData,
+            }
+        }
+    }
+
+    unsafe fn allocate(
+        &self,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        let header = Header {
+            size,
+            alignment,
+            scope,
+        };
+        if !alignment.is_power_of_two() {
+            return ptr::null_mut();
+        }
+        let layout = match header.layout() {
+            Some(layout) => layout,
+            None => return ptr::null_mut(),
+        };
+        let base = self.allocator.alloc(layout);
+        if base.is_null() {
+            return ptr::null_mut();
+        }
+        self.listener.allocated(size, alignment, scope);
+        header.write(base)
+    }
+
+    unsafe fn deallocate(&self, memory: *mut c_void) {
+        let (base, header) = Header::read(memory);
+        self.listener
+            .freed(header.size, header.alignment, header.scope);
+        self.allocator.dealloc(base, header.layout().unwrap());
+    }
+
+    unsafe fn reallocate(
+        &self,
+        original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        if original.is_null() {
+            return self.allocate(size, alignment, scope);
+        }
+        if size == 0 {
+            self.deallocate(original);
+            return ptr::null_mut();
+        }
+        let (base, old) = Header::read(original);
+        if old.alignment != alignment {
+            // Vulkan requires the original alignment, but moving the memory is still correct
+            let memory = self.allocate(size, alignment, scope);
+            if !memory.is_null() {
+                ptr::copy_nonoverlapping(
+                    original as *const u8,
+                    memory as *mut u8,
+                    old.size.min(size),
+                );
+                self.deallocate(original);
+            }
+            return memory;
+        }
+        let header = Header {
+            size,
+            alignment,
+            scope,
+        };
+        let new_size = match header.layout() {
+            Some(layout) => layout.size(),
+            None => return ptr::null_mut(),
+        };
+        let base = self
+            .allocator
+            .realloc(base, old.layout().unwrap(), new_size);
+        if base.is_null() {
+            return ptr::null_mut();
+        }
+        if old.scope == scope {
+            self.listener.reallocated(old.size, size, alignment, scope);
+        } else {
+            self.listener.freed(old.size, alignment, old.scope);
+            self.listener.allocated(size, alignment, scope);
+        }
+        header.write(base)
+    }
+
     unsafe extern "system" fn allocation(
-        _: *mut (),
-        _: usize,
-        _: usize,
-        _: vk::SystemAllocationScope,
-    ) -> *mut () {
-        ptr::null_mut()
+        user_data: *mut c_void,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        let this = &*(user_data as *const Self);
+        // Unwinding into the driver is undefined behavior
+        panic::catch_unwind(AssertUnwindSafe(|| this.allocate(size, alignment, scope)))
+            .unwrap_or(ptr::null_mut())
     }
 
     unsafe extern "system" fn reallocation(
-        _: *mut c_void,
-        _: *mut c_void,
-        _: usize,
-        _: usize,
-        _: vk::SystemAllocationScope,
+        user_data: *mut c_void,
+        original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
     ) -> *mut c_void {
-        ptr::null_mut()
+        let this = &*(user_data as *const Self);
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            this.reallocate(original, size, alignment, scope)
+        }))
+        .unwrap_or(ptr::null_mut())
+    }
+
+    unsafe extern "system" fn free(user_data: *mut c_void, memory: *mut c_void) {
+        if !memory.is_null() {
+            let this = &*(user_data as *const Self);
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| this.deallocate(memory)));
+        }
     }
-    unsafe extern "system" fn free(_: *mut c_void, _: *mut c_void) {}
+
     unsafe extern "system" fn internal_allocation(
-        _: *mut c_void,
-        _: usize,
-        _: vk::InternalAllocationType,
-        _: vk::SystemAllocationScope,
+        user_data: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        scope: vk::SystemAllocationScope,
     ) {
+        let this = &*(user_data as *const Self);
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            this.listener
+                .internal_allocated(size, allocation_type, scope)
+        }));
     }
+
     unsafe extern "system" fn internal_free(
-        _: *mut c_void,
-        _: usize,
-        _: vk::InternalAllocationType,
-        _: vk::SystemAllocationScope,
+        user_data: *mut c_void,
+        size: usize,
+        allocation_type: vk::InternalAllocationType,
+        scope: vk::SystemAllocationScope,
     ) {
+        let this = &*(user_data as *const Self);
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            this.listener.internal_freed(size, allocation_type, scope)
+        }));
+    }
+}
+
+/// The [`vk::AllocationCallbacks`] of a [`HostAllocator`], which can't outlive it.
+#[derive(Clone, Copy, Debug)]
+pub struct HostAllocationCallbacks<'a> {
+    callbacks: vk::AllocationCallbacks,
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Deref for HostAllocationCallbacks<'a> {
+    type Target = vk::AllocationCallbacks;
+    fn deref(&self) -> &Self::Target {
+        &self.callbacks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocations_are_aligned_and_counted() {
+        let allocator = HostAllocator::with_listener(AllocationCounter::default());
+        let callbacks = allocator.callbacks();
+        let counter = allocator.listener();
+        let scope = vk::SystemAllocationScope::OBJECT;
+        unsafe {
+            let memory = callbacks.pfn_allocation.unwrap()(callbacks.p_user_data, 24, 64, scope);
+            assert!(!memory.is_null());
+            assert_eq!(memory as usize % 64, 0);
+            ptr::write_bytes(memory as *mut u8, 7, 24);
+            assert_eq!(counter.live_bytes(scope), 24);
+            assert_eq!(counter.live_allocations(), 1);
+
+            let memory =
+                callbacks.pfn_reallocation.unwrap()(callbacks.p_user_data, memory, 4096, 64, scope);
+            assert_eq!(memory as usize % 64, 0);
+            assert_eq!(*(memory as *const [u8; 24]), [7; 24]);
+            assert_eq!(counter.live_bytes(scope), 4096);
+
+            let memory = callbacks.pfn_reallocation.unwrap()(
+                callbacks.p_user_data,
+                memory,
+                8,
+                64,
+                vk::SystemAllocationScope::DEVICE,
+            );
+            assert_eq!(counter.live_bytes(scope), 0);
+            assert_eq!(counter.live_bytes(vk::SystemAllocationScope::DEVICE), 8);
+
+            callbacks.pfn_free.unwrap()(callbacks.p_user_data, memory);
+            callbacks.pfn_free.unwrap()(callbacks.p_user_data, ptr::null_mut());
+        }
+        assert_eq!(counter.total_live_bytes(), 0);
+        assert_eq!(counter.live_allocations(), 0);
     }
-    fn create_allocation_callback() -> Option<vk::AllocationCallbacks> {
-        None
+
+    #[test]
+    fn invalid_alignment_fails() {
+        let allocator = HostAllocator::new();
+        let callbacks = allocator.callbacks();
+        let memory = unsafe {
+            callbacks.pfn_allocation.unwrap()(
+                callbacks.p_user_data,
+                16,
+                3,
+                vk::SystemAllocationScope::COMMAND,
+            )
+        };
+        assert!(memory.is_null());
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,12 @@
 #![allow(clippy::trivially_copy_pass_by_ref)]
 use crate::prelude::*;
+use crate::util::read_spv_bytes;
 use crate::vk;
 use crate::RawPtr;
+use std::error::Error;
+use std::ffi::CStr;
+use std::fmt;
+use std::io;
 use std::mem;
 use std::os::raw::c_void;
 use std::ptr;
@@ -493,16 +498,12 @@ pub trait DeviceV1_0 {
     ) {
         self.fp_v1_0().cmd_wait_events(
             command_buffer,
-            events.len() as _,
-            events.as_ptr(),
+            events,
             src_stage_mask,
             dst_stage_mask,
-            memory_barriers.len() as _,
-            memory_barriers.as_ptr(),
-            buffer_memory_barriers.len() as _,
-            buffer_memory_barriers.as_ptr(),
-            image_memory_barriers.len() as _,
-            image_memory_barriers.as_ptr(),
+            memory_barriers,
+            buffer_memory_barriers,
+            image_memory_barriers,
         );
     }
 
@@ -758,8 +759,7 @@ pub trait DeviceV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            regions.len() as _,
-            regions.as_ptr(),
+            regions,
             filter,
         );
     }
@@ -780,8 +780,7 @@ pub trait DeviceV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            regions.len() as u32,
-            regions.as_ptr(),
+            regions,
         );
     }
 
@@ -823,13 +822,8 @@ pub trait DeviceV1_0 {
         dst_buffer: vk::Buffer,
         regions: &[vk::BufferCopy],
     ) {
-        self.fp_v1_0().cmd_copy_buffer(
-            command_buffer,
-            src_buffer,
-            dst_buffer,
-            regions.len() as u32,
-            regions.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_copy_buffer(command_buffer, src_buffer, dst_buffer, regions);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyImageToBuffer.html>"]
@@ -846,8 +840,7 @@ pub trait DeviceV1_0 {
             src_image,
             src_image_layout,
             dst_buffer,
-            regions.len() as u32,
-            regions.as_ptr(),
+            regions,
         );
     }
 
@@ -865,8 +858,7 @@ pub trait DeviceV1_0 {
             src_buffer,
             dst_image,
             dst_image_layout,
-            regions.len() as u32,
-            regions.as_ptr(),
+            regions,
         );
     }
 
@@ -886,8 +878,7 @@ pub trait DeviceV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            regions.len() as u32,
-            regions.as_ptr(),
+            regions,
         );
     }
 
@@ -1012,8 +1003,7 @@ pub trait DeviceV1_0 {
             image,
             image_layout,
             clear_color_value,
-            ranges.len() as u32,
-            ranges.as_ptr(),
+            ranges,
         );
     }
 
@@ -1031,8 +1021,7 @@ pub trait DeviceV1_0 {
             image,
             image_layout,
             clear_depth_stencil_value,
-            ranges.len() as u32,
-            ranges.as_ptr(),
+            ranges,
         );
     }
 
@@ -1043,13 +1032,8 @@ pub trait DeviceV1_0 {
         attachments: &[vk::ClearAttachment],
         rects: &[vk::ClearRect],
     ) {
-        self.fp_v1_0().cmd_clear_attachments(
-            command_buffer,
-            attachments.len() as u32,
-            attachments.as_ptr(),
-            rects.len() as u32,
-            rects.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_clear_attachments(command_buffer, attachments, rects);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexed.html>"]
@@ -1096,11 +1080,8 @@ pub trait DeviceV1_0 {
         primary_command_buffer: vk::CommandBuffer,
         secondary_command_buffers: &[vk::CommandBuffer],
     ) {
-        self.fp_v1_0().cmd_execute_commands(
-            primary_command_buffer,
-            secondary_command_buffers.len() as u32,
-            secondary_command_buffers.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_execute_commands(primary_command_buffer, secondary_command_buffers);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindDescriptorSets.html>"]
@@ -1118,10 +1099,8 @@ pub trait DeviceV1_0 {
             pipeline_bind_point,
             layout,
             first_set,
-            descriptor_sets.len() as u32,
-            descriptor_sets.as_ptr(),
-            dynamic_offsets.len() as u32,
-            dynamic_offsets.as_ptr(),
+            descriptor_sets,
+            dynamic_offsets,
         );
     }
 
@@ -1206,12 +1185,8 @@ pub trait DeviceV1_0 {
         first_scissor: u32,
         scissors: &[vk::Rect2D],
     ) {
-        self.fp_v1_0().cmd_set_scissor(
-            command_buffer,
-            first_scissor,
-            scissors.len() as u32,
-            scissors.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_set_scissor(command_buffer, first_scissor, scissors);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetLineWidth.html>"]
@@ -1228,14 +1203,8 @@ pub trait DeviceV1_0 {
         buffers: &[vk::Buffer],
         offsets: &[vk::DeviceSize],
     ) {
-        debug_assert_eq!(buffers.len(), offsets.len());
-        self.fp_v1_0().cmd_bind_vertex_buffers(
-            command_buffer,
-            first_binding,
-            buffers.len() as u32,
-            buffers.as_ptr(),
-            offsets.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_bind_vertex_buffers(command_buffer, first_binding, buffers, offsets);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass.html>"]
@@ -1304,12 +1273,8 @@ pub trait DeviceV1_0 {
         first_viewport: u32,
         viewports: &[vk::Viewport],
     ) {
-        self.fp_v1_0().cmd_set_viewport(
-            command_buffer,
-            first_viewport,
-            viewports.len() as u32,
-            viewports.as_ptr(),
-        );
+        self.fp_v1_0()
+            .cmd_set_viewport(command_buffer, first_viewport, viewports);
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthBias.html>"]
@@ -1478,8 +1443,21 @@ pub trait DeviceV1_0 {
         create_infos: &[vk::GraphicsPipelineCreateInfo],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        self.create_graphics_pipelines_batch(pipeline_cache, create_infos, allocation_callbacks)
+            .into_result()
+    }
+
+    /// Like [`DeviceV1_0::create_graphics_pipelines`], but returns the pipelines and the result
+    /// together, to keep the pipelines that were created when others failed.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateGraphicsPipelines.html>"]
+    unsafe fn create_graphics_pipelines_batch(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::GraphicsPipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> PipelineBatch {
         let mut pipelines = Vec::with_capacity(create_infos.len());
-        let err_code = self.fp_v1_0().create_graphics_pipelines(
+        let result = self.fp_v1_0().create_graphics_pipelines(
             self.handle(),
             pipeline_cache,
             create_infos.len() as u32,
@@ -1488,10 +1466,7 @@ pub trait DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
         pipelines.set_len(create_infos.len());
-        match err_code {
-            vk::Result::SUCCESS => Ok(pipelines),
-            _ => Err((pipelines, err_code)),
-        }
+        PipelineBatch { pipelines, result }
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateComputePipelines.html>"]
@@ -1501,8 +1476,21 @@ pub trait DeviceV1_0 {
         create_infos: &[vk::ComputePipelineCreateInfo],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
     ) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        self.create_compute_pipelines_batch(pipeline_cache, create_infos, allocation_callbacks)
+            .into_result()
+    }
+
+    /// Like [`DeviceV1_0::create_compute_pipelines`], but returns the pipelines and the result
+    /// together, to keep the pipelines that were created when others failed.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateComputePipelines.html>"]
+    unsafe fn create_compute_pipelines_batch(
+        &self,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::ComputePipelineCreateInfo],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> PipelineBatch {
         let mut pipelines = Vec::with_capacity(create_infos.len());
-        let err_code = self.fp_v1_0().create_compute_pipelines(
+        let result = self.fp_v1_0().create_compute_pipelines(
             self.handle(),
             pipeline_cache,
             create_infos.len() as u32,
@@ -1511,10 +1499,7 @@ pub trait DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
         pipelines.set_len(create_infos.len());
-        match err_code {
-            vk::Result::SUCCESS => Ok(pipelines),
-            _ => Err((pipelines, err_code)),
-        }
+        PipelineBatch { pipelines, result }
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateBuffer.html>"]
@@ -1670,12 +1655,9 @@ pub trait DeviceV1_0 {
             src_stage_mask,
             dst_stage_mask,
             dependency_flags,
-            memory_barriers.len() as u32,
-            memory_barriers.as_ptr(),
-            buffer_memory_barriers.len() as u32,
-            buffer_memory_barriers.as_ptr(),
-            image_memory_barriers.len() as u32,
-            image_memory_barriers.as_ptr(),
+            memory_barriers,
+            buffer_memory_barriers,
+            image_memory_barriers,
         );
     }
 
@@ -1888,18 +1870,18 @@ pub trait DeviceV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetImageMemoryRequirements.html>"]
     unsafe fn get_image_memory_requirements(&self, image: vk::Image) -> vk::MemoryRequirements {
-        let mut mem_req = mem::zeroed();
+        let mut mem_req = mem::MaybeUninit::<vk::MemoryRequirements>::zeroed();
         self.fp_v1_0()
-            .get_image_memory_requirements(self.handle(), image, &mut mem_req);
-        mem_req
+            .get_image_memory_requirements(self.handle(), image, mem_req.as_mut_ptr());
+        mem_req.assume_init()
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetBufferMemoryRequirements.html>"]
     unsafe fn get_buffer_memory_requirements(&self, buffer: vk::Buffer) -> vk::MemoryRequirements {
-        let mut mem_req = mem::zeroed();
+        let mut mem_req = mem::MaybeUninit::<vk::MemoryRequirements>::zeroed();
         self.fp_v1_0()
-            .get_buffer_memory_requirements(self.handle(), buffer, &mut mem_req);
-        mem_req
+            .get_buffer_memory_requirements(self.handle(), buffer, mem_req.as_mut_ptr());
+        mem_req.assume_init()
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkAllocateMemory.html>"]
@@ -1936,6 +1918,43 @@ pub trait DeviceV1_0 {
             .result_with_success(shader)
     }
 
+    /// Creates a shader module from SPIR-V bytes, for example the output of `include_bytes!`,
+    /// which don't need to be aligned. Bytes that aren't SPIR-V are reported as
+    /// [`ShaderModuleError::InvalidSpirv`] instead of reaching the driver.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateShaderModule.html>"]
+    unsafe fn create_shader_module_from_bytes(
+        &self,
+        code: &[u8],
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::ShaderModule, ShaderModuleError> {
+        let words = spirv_words(code)?;
+        let create_info = vk::ShaderModuleCreateInfo::builder().code(&words);
+        self.create_shader_module(&create_info, allocation_callbacks)
+            .map_err(ShaderModuleError::VkError)
+    }
+
+    /// Like [`DeviceV1_0::create_shader_module_from_bytes`], but also checks that the module
+    /// has an `OpEntryPoint` named `entry_point`.
+    #[cfg(feature = "spirv-entry-points")]
+    unsafe fn create_shader_module_with_entry_point(
+        &self,
+        code: &[u8],
+        entry_point: &str,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> Result<vk::ShaderModule, ShaderModuleError> {
+        let words = spirv_words(code)?;
+        if !crate::util::spirv_entry_points(&words)
+            .map_err(ShaderModuleError::InvalidSpirv)?
+            .iter()
+            .any(|name| name == entry_point)
+        {
+            return Err(ShaderModuleError::MissingEntryPoint(entry_point.to_owned()));
+        }
+        let create_info = vk::ShaderModuleCreateInfo::builder().code(&words);
+        self.create_shader_module(&create_info, allocation_callbacks)
+            .map_err(ShaderModuleError::VkError)
+    }
+
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateFence.html>"]
     unsafe fn create_fence(
         &self,
@@ -1989,15 +2008,52 @@ pub struct Device {
 
 impl Device {
     pub unsafe fn load(instance_fn: &vk::InstanceFnV1_0, device: vk::Device) -> Self {
-        let device_fn_1_0 = vk::DeviceFnV1_0::load(|name| {
-            mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
-        });
-        let device_fn_1_1 = vk::DeviceFnV1_1::load(|name| {
+        Self::load_with(device, |name| {
             mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
-        });
-        let device_fn_1_2 = vk::DeviceFnV1_2::load(|name| {
-            mem::transmute(instance_fn.get_device_proc_addr(device, name.as_ptr()))
-        });
+        })
+    }
+
+    /// Loads the commands of `device` with `load`, which returns the address of a command or null
+    /// like `vkGetDeviceProcAddr`. This allows interposing some of the commands, to trace them for
+    /// instance.
+    pub unsafe fn load_with<F>(device: vk::Device, mut load: F) -> Self
+    where
+        F: FnMut(&CStr) -> *const c_void,
+    {
+        Device {
+            handle: device,
+            device_fn_1_0: vk::DeviceFnV1_0::load(&mut load),
+            device_fn_1_1: vk::DeviceFnV1_1::load(&mut load),
+            device_fn_1_2: vk::DeviceFnV1_2::load(&mut load),
+        }
+    }
+
+    /// Creates a `Device` from command tables filled in by hand, to test code that calls into a
+    /// `Device` without a driver for instance. The tables of `load` with a function that returns
+    /// null have commands that panic when called, and are a starting point for mocks.
+    ///
+    /// ```
+    /// # use ash::{version::DeviceV1_0, vk};
+    /// extern "system" fn device_wait_idle(_: vk::Device) -> vk::Result {
+    ///     vk::Result::ERROR_DEVICE_LOST
+    /// }
+    ///
+    /// let mut fn_1_0 = vk::DeviceFnV1_0::load(|_| std::ptr::null());
+    /// fn_1_0.device_wait_idle = device_wait_idle;
+    /// let device = ash::Device::from_fns(
+    ///     vk::Device::null(),
+    ///     fn_1_0,
+    ///     vk::DeviceFnV1_1::load(|_| std::ptr::null()),
+    ///     vk::DeviceFnV1_2::load(|_| std::ptr::null()),
+    /// );
+    /// assert_eq!(unsafe { device.device_wait_idle() }, Err(vk::Result::ERROR_DEVICE_LOST));
+    /// ```
+    pub fn from_fns(
+        device: vk::Device,
+        device_fn_1_0: vk::DeviceFnV1_0,
+        device_fn_1_1: vk::DeviceFnV1_1,
+        device_fn_1_2: vk::DeviceFnV1_2,
+    ) -> Self {
         Device {
             handle: device,
             device_fn_1_0,
@@ -2034,3 +2090,75 @@ impl Device {
         self.handle
     }
 }
+
+/// Decodes `code` with [`read_spv_bytes`] and checks that it is long enough for the header.
+fn spirv_words(code: &[u8]) -> Result<Vec<u32>, ShaderModuleError> {
+    let words = read_spv_bytes(code).map_err(ShaderModuleError::InvalidSpirv)?;
+    if words.len() < 5 {
+        return Err(ShaderModuleError::InvalidSpirv(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "input shorter than the SPIR-V header",
+        )));
+    }
+    Ok(words)
+}
+
+/// Errors of [`DeviceV1_0::create_shader_module_from_bytes`].
+#[derive(Debug)]
+pub enum ShaderModuleError {
+    /// The code isn't SPIR-V, or is truncated.
+    InvalidSpirv(io::Error),
+    /// The module has no entry point with this name.
+    MissingEntryPoint(String),
+    VkError(vk::Result),
+}
+
+impl fmt::Display for ShaderModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShaderModuleError::InvalidSpirv(e) => write!(f, "invalid SPIR-V: {}", e),
+            ShaderModuleError::MissingEntryPoint(name) => {
+                write!(f, "shader module has no entry point named {:?}", name)
+            }
+            ShaderModuleError::VkError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ShaderModuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ShaderModuleError::InvalidSpirv(e) => Some(e),
+            ShaderModuleError::MissingEntryPoint(_) => None,
+            ShaderModuleError::VkError(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_with_resolver() {
+        extern "system" fn device_wait_idle(_device: vk::Device) -> vk::Result {
+            vk::Result::ERROR_DEVICE_LOST
+        }
+
+        let mut requested = Vec::new();
+        let device = unsafe {
+            Device::load_with(vk::Device::null(), |name| {
+                requested.push(name.to_owned());
+                match name.to_bytes() {
+                    b"vkDeviceWaitIdle" => device_wait_idle as *const c_void,
+                    _ => ptr::null(),
+                }
+            })
+        };
+        assert!(requested.iter().any(|name| name.to_bytes() == b"vkCmdDraw"));
+        assert_eq!(
+            unsafe { device.device_wait_idle() },
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+    }
+}
@@ -34,7 +34,14 @@ impl fmt::Display for InstanceError {
     }
 }
 
-impl Error for InstanceError {}
+impl Error for InstanceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InstanceError::LoadError(_) => None,
+            InstanceError::VkError(e) => Some(e),
+        }
+    }
+}
 
 #[allow(non_camel_case_types)]
 pub trait EntryV1_0 {
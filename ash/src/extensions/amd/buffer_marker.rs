@@ -0,0 +1,71 @@
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::slice;
+
+#[derive(Clone)]
+pub struct BufferMarker {
+    handle: vk::Device,
+    buffer_marker_fn: vk::AmdBufferMarkerFn,
+}
+
+impl BufferMarker {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let buffer_marker_fn = vk::AmdBufferMarkerFn::load(|name| unsafe {
+            mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            buffer_marker_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::AmdBufferMarkerFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
+    pub unsafe fn cmd_write_buffer_marker(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        pipeline_stage: vk::PipelineStageFlags,
+        dst_buffer: vk::Buffer,
+        dst_offset: vk::DeviceSize,
+        marker: u32,
+    ) {
+        self.buffer_marker_fn.cmd_write_buffer_marker_amd(
+            command_buffer,
+            pipeline_stage,
+            dst_buffer,
+            dst_offset,
+            marker,
+        );
+    }
+
+    /// Reads `count` markers at `offset` of `memory`, such as after a
+    /// [`vk::Result::ERROR_DEVICE_LOST`], to find the last commands that the device reached.
+    ///
+    /// `memory` must be host visible and host coherent, and must not be mapped already.
+    pub unsafe fn read_markers<D: DeviceV1_0>(
+        device: &D,
+        memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+        count: usize,
+    ) -> VkResult<Vec<u32>> {
+        let size = (count * mem::size_of::<u32>()) as vk::DeviceSize;
+        let ptr = device.map_memory(memory, offset, size, vk::MemoryMapFlags::empty())?;
+        let markers = slice::from_raw_parts(ptr as *const u32, count).to_vec();
+        device.unmap_memory(memory);
+        Ok(markers)
+    }
+
+    pub fn fp(&self) -> &vk::AmdBufferMarkerFn {
+        &self.buffer_marker_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
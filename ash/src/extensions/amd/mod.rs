@@ -0,0 +1,3 @@
+pub use self::buffer_marker::BufferMarker;
+
+mod buffer_marker;
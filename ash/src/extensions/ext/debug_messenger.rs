@@ -0,0 +1,248 @@
+use super::DebugUtils;
+use crate::prelude::*;
+use crate::vk;
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::{fmt, slice};
+
+type Callback = dyn Fn(&DebugMessage) + Send + Sync;
+
+/// A debug utils messenger that calls a Rust closure, see [`DebugMessenger::new`].
+///
+/// The messenger is destroyed when dropped, which has to happen before the instance is
+/// destroyed.
+///
+/// ```no_run
+/// # use ash::{extensions::ext::{DebugMessenger, DebugUtils}, vk};
+/// # unsafe fn create(debug_utils: &DebugUtils) -> ash::prelude::VkResult<()> {
+/// let messenger = DebugMessenger::new(
+///     debug_utils,
+///     vk::DebugUtilsMessageSeverityFlagsEXT::WARNING | vk::DebugUtilsMessageSeverityFlagsEXT::ERROR,
+///     vk::DebugUtilsMessageTypeFlagsEXT::all(),
+///     |message| eprintln!("{:?}", message),
+/// )?;
+/// # Ok(()) }
+/// ```
+pub struct DebugMessenger {
+    debug_utils: DebugUtils,
+    messenger: vk::DebugUtilsMessengerEXT,
+    callback: *mut Box<Callback>,
+}
+
+unsafe impl Send for DebugMessenger {}
+unsafe impl Sync for DebugMessenger {}
+
+impl DebugMessenger {
+    /// Creates a messenger that calls `callback` for the messages of `severity` and `types`.
+    /// `callback` can be called from any thread that uses the instance, panics are caught and
+    /// leave the message unhandled.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>"]
+    pub unsafe fn new<F>(
+        debug_utils: &DebugUtils,
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        callback: F,
+    ) -> VkResult<Self>
+    where
+        F: Fn(&DebugMessage) + Send + Sync + 'static,
+    {
+        let callback: Box<Callback> = Box::new(callback);
+        let callback = Box::into_raw(Box::new(callback));
+        let create_info = vk::DebugUtilsMessengerCreateInfoEXT::builder()
+            .message_severity(severity)
+            .message_type(types)
+            .pfn_user_callback(Some(debug_messenger_callback))
+            .user_data(callback as *mut c_void);
+        match debug_utils.create_debug_utils_messenger(&create_info, None) {
+            Ok(messenger) => Ok(DebugMessenger {
+                debug_utils: debug_utils.clone(),
+                messenger,
+                callback,
+            }),
+            Err(err) => {
+                drop(Box::from_raw(callback));
+                Err(err)
+            }
+        }
+    }
+
+    pub fn handle(&self) -> vk::DebugUtilsMessengerEXT {
+        self.messenger
+    }
+}
+
+impl Drop for DebugMessenger {
+    fn drop(&mut self) {
+        unsafe {
+            self.debug_utils
+                .destroy_debug_utils_messenger(self.messenger, None);
+            drop(Box::from_raw(self.callback));
+        }
+    }
+}
+
+unsafe extern "system" fn debug_messenger_callback(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    p_user_data: *mut c_void,
+) -> vk::Bool32 {
+    if let (Some(data), Some(callback)) = (
+        p_callback_data.as_ref(),
+        (p_user_data as *const Box<Callback>).as_ref(),
+    ) {
+        let message = DebugMessage {
+            severity: message_severity,
+            types: message_types,
+            data,
+        };
+        // Unwinding into the driver is undefined behavior
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(&message)));
+    }
+    // Only layers may abort the command
+    vk::FALSE
+}
+
+/// Converts a nullable C string of a message to a string, replacing invalid UTF-8.
+unsafe fn message_str<'a>(ptr: *const c_char) -> Option<Cow<'a, str>> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy())
+    }
+}
+
+unsafe fn message_slice<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+    if ptr.is_null() || count == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, count as usize)
+    }
+}
+
+/// A message passed to the callback of a [`DebugMessenger`], borrowed for the duration of the
+/// call.
+pub struct DebugMessage<'a> {
+    pub severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    pub types: vk::DebugUtilsMessageTypeFlagsEXT,
+    data: &'a vk::DebugUtilsMessengerCallbackDataEXT,
+}
+
+impl<'a> DebugMessage<'a> {
+    pub fn message(&self) -> Cow<'a, str> {
+        unsafe { message_str(self.data.p_message) }.unwrap_or(Cow::Borrowed(""))
+    }
+
+    /// The name of the message, such as the VUID of validation errors.
+    pub fn id_name(&self) -> Option<Cow<'a, str>> {
+        unsafe { message_str(self.data.p_message_id_name) }
+    }
+
+    pub fn id_number(&self) -> i32 {
+        self.data.message_id_number
+    }
+
+    /// The objects that the message is about.
+    pub fn objects(&self) -> impl Iterator<Item = DebugObject<'a>> + 'a {
+        unsafe { message_slice(self.data.p_objects, self.data.object_count) }
+            .iter()
+            .map(|object| DebugObject {
+                object_type: object.object_type,
+                handle: object.object_handle,
+                name: unsafe { message_str(object.p_object_name) },
+            })
+    }
+
+    /// The names of the queue label regions that were open.
+    pub fn queue_labels(&self) -> impl Iterator<Item = Cow<'a, str>> + 'a {
+        unsafe { message_slice(self.data.p_queue_labels, self.data.queue_label_count) }
+            .iter()
+            .filter_map(|label| unsafe { message_str(label.p_label_name) })
+    }
+
+    /// The names of the command buffer label regions that were open.
+    pub fn cmd_buf_labels(&self) -> impl Iterator<Item = Cow<'a, str>> + 'a {
+        unsafe { message_slice(self.data.p_cmd_buf_labels, self.data.cmd_buf_label_count) }
+            .iter()
+            .filter_map(|label| unsafe { message_str(label.p_label_name) })
+    }
+
+    pub fn raw(&self) -> &'a vk::DebugUtilsMessengerCallbackDataEXT {
+        self.data
+    }
+}
+
+impl<'a> fmt::Debug for DebugMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DebugMessage")
+            .field("severity", &self.severity)
+            .field("types", &self.types)
+            .field("id_name", &self.id_name())
+            .field("message", &self.message())
+            .field("objects", &self.objects().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// An object of a [`DebugMessage`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugObject<'a> {
+    pub object_type: vk::ObjectType,
+    pub handle: u64,
+    /// The name given with `debug_utils_set_object_name`.
+    pub name: Option<Cow<'a, str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn callback_converts_message() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let callback: Box<Callback> = Box::new(move |message: &DebugMessage| {
+            let objects: Vec<_> = message.objects().collect();
+            sink.lock().unwrap().push((
+                message.id_name().map(|name| name.into_owned()),
+                message.message().into_owned(),
+                objects[0].name.as_ref().map(|name| name.to_string()),
+            ));
+            panic!("not unwound into the driver");
+        });
+        let objects = [vk::DebugUtilsObjectNameInfoEXT {
+            object_type: vk::ObjectType::BUFFER,
+            object_handle: 1,
+            p_object_name: b"vertices\0".as_ptr() as *const c_char,
+            ..Default::default()
+        }];
+        let data = vk::DebugUtilsMessengerCallbackDataEXT {
+            p_message_id_name: b"VUID-vkCmdDraw-None-02699\0".as_ptr() as *const c_char,
+            p_message: b"descriptor set not bound\0".as_ptr() as *const c_char,
+            object_count: 1,
+            p_objects: objects.as_ptr(),
+            ..Default::default()
+        };
+        let handled = unsafe {
+            debug_messenger_callback(
+                vk::DebugUtilsMessageSeverityFlagsEXT::ERROR,
+                vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION,
+                &data,
+                &callback as *const Box<Callback> as *mut c_void,
+            )
+        };
+        assert_eq!(handled, vk::FALSE);
+        assert_eq!(
+            *received.lock().unwrap(),
+            [(
+                Some("VUID-vkCmdDraw-None-02699".to_owned()),
+                "descriptor set not bound".to_owned(),
+                Some("vertices".to_owned())
+            )]
+        );
+        assert!(unsafe { message_str(std::ptr::null()) }.is_none());
+    }
+}
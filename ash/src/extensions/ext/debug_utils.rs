@@ -145,6 +145,48 @@ impl DebugUtils {
         );
     }
 
+    /// Opens a label region in `command_buffer` that is closed when the returned guard is
+    /// dropped. The guard must be dropped while `command_buffer` is still recording.
+    pub unsafe fn cmd_debug_label_scope(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        name: &CStr,
+        color: [f32; 4],
+    ) -> CmdDebugLabel<'_> {
+        let label = vk::DebugUtilsLabelEXT::builder()
+            .label_name(name)
+            .color(color);
+        self.cmd_begin_debug_utils_label(command_buffer, &label);
+        CmdDebugLabel {
+            debug_utils: self,
+            command_buffer,
+        }
+    }
+
+    /// Opens a label region in `queue` that is closed when the returned guard is dropped.
+    pub unsafe fn queue_debug_label_scope(
+        &self,
+        queue: vk::Queue,
+        name: &CStr,
+        color: [f32; 4],
+    ) -> QueueDebugLabel<'_> {
+        let label = vk::DebugUtilsLabelEXT::builder()
+            .label_name(name)
+            .color(color);
+        self.queue_begin_debug_utils_label(queue, &label);
+        QueueDebugLabel {
+            debug_utils: self,
+            queue,
+        }
+    }
+
+    /// Converts a `0xRRGGBB` color to the normalized RGBA color of `DebugUtilsLabelEXT`, with an
+    /// alpha of 1.0.
+    pub fn label_color(rgb: u32) -> [f32; 4] {
+        let channel = |shift: u32| ((rgb >> shift) & 0xff) as f32 / 255.0;
+        [channel(16), channel(8), channel(0), 1.0]
+    }
+
     pub fn fp(&self) -> &vk::ExtDebugUtilsFn {
         &self.debug_utils_fn
     }
@@ -153,3 +195,46 @@ impl DebugUtils {
         self.handle
     }
 }
+
+/// Ends the label region opened by [`DebugUtils::cmd_debug_label_scope`] when dropped.
+#[must_use = "the label region ends when the guard is dropped"]
+pub struct CmdDebugLabel<'a> {
+    debug_utils: &'a DebugUtils,
+    command_buffer: vk::CommandBuffer,
+}
+
+impl<'a> CmdDebugLabel<'a> {
+    pub fn command_buffer(&self) -> vk::CommandBuffer {
+        self.command_buffer
+    }
+}
+
+impl<'a> Drop for CmdDebugLabel<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.debug_utils
+                .cmd_end_debug_utils_label(self.command_buffer);
+        }
+    }
+}
+
+/// Ends the label region opened by [`DebugUtils::queue_debug_label_scope`] when dropped.
+#[must_use = "the label region ends when the guard is dropped"]
+pub struct QueueDebugLabel<'a> {
+    debug_utils: &'a DebugUtils,
+    queue: vk::Queue,
+}
+
+impl<'a> QueueDebugLabel<'a> {
+    pub fn queue(&self) -> vk::Queue {
+        self.queue
+    }
+}
+
+impl<'a> Drop for QueueDebugLabel<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.debug_utils.queue_end_debug_utils_label(self.queue);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+use crate::prelude::*;
+use crate::version::{EntryV1_0, InstanceV1_0};
+use crate::vk;
+use crate::RawPtr;
+use std::ffi::CStr;
+use std::mem;
+
+#[derive(Clone)]
+pub struct DirectFBSurface {
+    handle: vk::Instance,
+    directfb_surface_fn: vk::ExtDirectfbSurfaceFn,
+}
+
+impl DirectFBSurface {
+    pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> DirectFBSurface {
+        let surface_fn = vk::ExtDirectfbSurfaceFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        DirectFBSurface {
+            handle: instance.handle(),
+            directfb_surface_fn: surface_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::ExtDirectfbSurfaceFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateDirectFBSurfaceEXT.html>"]
+    pub unsafe fn create_directfb_surface(
+        &self,
+        create_info: &vk::DirectFBSurfaceCreateInfoEXT,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::SurfaceKHR> {
+        let mut surface = mem::zeroed();
+        self.directfb_surface_fn
+            .create_direct_fb_surface_ext(
+                self.handle,
+                create_info,
+                allocation_callbacks.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceDirectFBPresentationSupportEXT.html>"]
+    pub unsafe fn get_physical_device_directfb_presentation_support(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        queue_family_index: u32,
+        dfb: &mut vk::IDirectFB,
+    ) -> bool {
+        let b = self
+            .directfb_surface_fn
+            .get_physical_device_direct_fb_presentation_support_ext(
+                physical_device,
+                queue_family_index,
+                dfb,
+            );
+
+        b > 0
+    }
+
+    pub fn fp(&self) -> &vk::ExtDirectfbSurfaceFn {
+        &self.directfb_surface_fn
+    }
+
+    pub fn instance(&self) -> vk::Instance {
+        self.handle
+    }
+}
@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, InstanceV1_0, InstanceV1_1};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+
+#[derive(Clone)]
+pub struct ImageDrmFormatModifier {
+    handle: vk::Device,
+    image_drm_format_modifier_fn: vk::ExtImageDrmFormatModifierFn,
+}
+
+impl ImageDrmFormatModifier {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let image_drm_format_modifier_fn = vk::ExtImageDrmFormatModifierFn::load(|name| unsafe {
+            mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            image_drm_format_modifier_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::ExtImageDrmFormatModifierFn::name()
+    }
+
+    /// Returns the modifiers that `format` supports, with their plane counts and tiling features,
+    /// from a [`vk::DrmFormatModifierPropertiesListEXT`] chained to
+    /// `vkGetPhysicalDeviceFormatProperties2`.
+    pub unsafe fn get_drm_format_modifier_properties<I: InstanceV1_1>(
+        instance: &I,
+        pdevice: vk::PhysicalDevice,
+        format: vk::Format,
+    ) -> Vec<vk::DrmFormatModifierPropertiesEXT> {
+        let mut list = vk::DrmFormatModifierPropertiesListEXT::default();
+        {
+            let mut props = vk::FormatProperties2::builder().push_next(&mut list);
+            instance.get_physical_device_format_properties2(pdevice, format, &mut props);
+        }
+        let mut modifiers = Vec::with_capacity(list.drm_format_modifier_count as usize);
+        list.p_drm_format_modifier_properties = modifiers.as_mut_ptr();
+        {
+            let mut props = vk::FormatProperties2::builder().push_next(&mut list);
+            instance.get_physical_device_format_properties2(pdevice, format, &mut props);
+        }
+        modifiers.set_len(list.drm_format_modifier_count as usize);
+        modifiers
+    }
+
+    /// Returns the aspect of the memory plane `plane` of an image with a DRM format modifier,
+    /// which has at most 4 memory planes.
+    pub fn memory_plane_aspect(plane: u32) -> vk::ImageAspectFlags {
+        match plane {
+            0 => vk::ImageAspectFlags::MEMORY_PLANE_0_EXT,
+            1 => vk::ImageAspectFlags::MEMORY_PLANE_1_EXT,
+            2 => vk::ImageAspectFlags::MEMORY_PLANE_2_EXT,
+            3 => vk::ImageAspectFlags::MEMORY_PLANE_3_EXT,
+            _ => panic!("Memory plane {} is out of range", plane),
+        }
+    }
+
+    /// Returns the layouts of the first `plane_count` memory planes of `image`, as needed to
+    /// export it, or to import it elsewhere with an
+    /// [`vk::ImageDrmFormatModifierExplicitCreateInfoEXT`].
+    pub unsafe fn get_memory_plane_layouts<D: DeviceV1_0>(
+        device: &D,
+        image: vk::Image,
+        plane_count: u32,
+    ) -> Vec<vk::SubresourceLayout> {
+        (0..plane_count)
+            .map(|plane| {
+                let subresource = vk::ImageSubresource {
+                    aspect_mask: Self::memory_plane_aspect(plane),
+                    mip_level: 0,
+                    array_layer: 0,
+                };
+                device.get_image_subresource_layout(image, subresource)
+            })
+            .collect()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetImageDrmFormatModifierPropertiesEXT.html>"]
+    pub unsafe fn get_image_drm_format_modifier_properties(
+        &self,
+        image: vk::Image,
+    ) -> VkResult<vk::ImageDrmFormatModifierPropertiesEXT> {
+        let mut properties = vk::ImageDrmFormatModifierPropertiesEXT::default();
+        self.image_drm_format_modifier_fn
+            .get_image_drm_format_modifier_properties_ext(self.handle, image, &mut properties)
+            .result_with_success(properties)
+    }
+
+    pub fn fp(&self) -> &vk::ExtImageDrmFormatModifierFn {
+        &self.image_drm_format_modifier_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
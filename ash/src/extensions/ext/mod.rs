@@ -1,15 +1,25 @@
 #[allow(deprecated)]
 pub use self::debug_marker::DebugMarker;
+pub use self::debug_messenger::{DebugMessage, DebugMessenger, DebugObject};
 #[allow(deprecated)]
 pub use self::debug_report::DebugReport;
-pub use self::debug_utils::DebugUtils;
+pub use self::debug_utils::{CmdDebugLabel, DebugUtils, QueueDebugLabel};
+pub use self::directfb_surface::DirectFBSurface;
+pub use self::headless_surface::HeadlessSurface;
+pub use self::image_drm_format_modifier::ImageDrmFormatModifier;
 pub use self::metal_surface::MetalSurface;
 pub use self::tooling_info::ToolingInfo;
+pub use self::validation_features::ValidationFeatures;
 
 #[deprecated(note = "Please use the [DebugUtils](struct.DebugUtils.html) extension instead.")]
 mod debug_marker;
+mod debug_messenger;
 #[deprecated(note = "Please use the [DebugUtils](struct.DebugUtils.html) extension instead.")]
 mod debug_report;
 mod debug_utils;
+mod directfb_surface;
+mod headless_surface;
+mod image_drm_format_modifier;
 mod metal_surface;
 mod tooling_info;
+mod validation_features;
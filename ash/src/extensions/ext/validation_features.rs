@@ -0,0 +1,99 @@
+use crate::vk;
+use std::ffi::CStr;
+
+/// Collects the validation features to enable or disable in the validation layer.
+/// `VK_EXT_validation_features` has no commands, the features are chained into
+/// `InstanceCreateInfo` with [`ValidationFeatures::create_info`].
+///
+/// ```no_run
+/// # use ash::{extensions::ext::ValidationFeatures, vk};
+/// let validation = ValidationFeatures::new()
+///     .best_practices()
+///     .synchronization_validation();
+/// let mut features = validation.create_info();
+/// let create_info = vk::InstanceCreateInfo::builder().push_next(&mut features);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationFeatures {
+    enabled: Vec<vk::ValidationFeatureEnableEXT>,
+    disabled: Vec<vk::ValidationFeatureDisableEXT>,
+}
+
+impl ValidationFeatures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::ExtValidationFeaturesFn::name()
+    }
+
+    pub fn enable(mut self, feature: vk::ValidationFeatureEnableEXT) -> Self {
+        if !self.enabled.contains(&feature) {
+            self.enabled.push(feature);
+        }
+        self
+    }
+
+    pub fn disable(mut self, feature: vk::ValidationFeatureDisableEXT) -> Self {
+        if !self.disabled.contains(&feature) {
+            self.disabled.push(feature);
+        }
+        self
+    }
+
+    /// GPU-assisted validation, which instruments shaders to find out of bounds accesses. The
+    /// validation layer doesn't support it together with [`ValidationFeatures::debug_printf`].
+    pub fn gpu_assisted(self) -> Self {
+        self.enable(vk::ValidationFeatureEnableEXT::GPU_ASSISTED)
+    }
+
+    pub fn best_practices(self) -> Self {
+        self.enable(vk::ValidationFeatureEnableEXT::BEST_PRACTICES)
+    }
+
+    /// Forwards `debugPrintfEXT` of shaders to the debug messenger.
+    pub fn debug_printf(self) -> Self {
+        self.enable(vk::ValidationFeatureEnableEXT::DEBUG_PRINTF)
+    }
+
+    pub fn synchronization_validation(self) -> Self {
+        self.enable(vk::ValidationFeatureEnableEXT::SYNCHRONIZATION_VALIDATION)
+    }
+
+    pub fn enabled(&self) -> &[vk::ValidationFeatureEnableEXT] {
+        &self.enabled
+    }
+
+    pub fn disabled(&self) -> &[vk::ValidationFeatureDisableEXT] {
+        &self.disabled
+    }
+
+    /// The struct to chain into `InstanceCreateInfo`.
+    pub fn create_info(&self) -> vk::ValidationFeaturesEXTBuilder<'_> {
+        vk::ValidationFeaturesEXT::builder()
+            .enabled_validation_features(&self.enabled)
+            .disabled_validation_features(&self.disabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deduplicated_features() {
+        let validation = ValidationFeatures::new()
+            .gpu_assisted()
+            .best_practices()
+            .gpu_assisted()
+            .disable(vk::ValidationFeatureDisableEXT::SHADERS);
+        let features = validation.create_info();
+        assert_eq!(features.enabled_validation_feature_count, 2);
+        assert_eq!(
+            unsafe { *features.p_enabled_validation_features.add(1) },
+            vk::ValidationFeatureEnableEXT::BEST_PRACTICES
+        );
+        assert_eq!(features.disabled_validation_feature_count, 1);
+    }
+}
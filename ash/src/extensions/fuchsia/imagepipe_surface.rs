@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+use crate::prelude::*;
+use crate::version::{EntryV1_0, InstanceV1_0};
+use crate::vk;
+use crate::RawPtr;
+use std::ffi::CStr;
+use std::mem;
+
+#[derive(Clone)]
+pub struct ImagePipeSurface {
+    handle: vk::Instance,
+    imagepipe_surface_fn: vk::FuchsiaImagepipeSurfaceFn,
+}
+
+impl ImagePipeSurface {
+    pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> ImagePipeSurface {
+        let surface_fn = vk::FuchsiaImagepipeSurfaceFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        ImagePipeSurface {
+            handle: instance.handle(),
+            imagepipe_surface_fn: surface_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::FuchsiaImagepipeSurfaceFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateImagePipeSurfaceFUCHSIA.html>"]
+    pub unsafe fn create_image_pipe_surface(
+        &self,
+        create_info: &vk::ImagePipeSurfaceCreateInfoFUCHSIA,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::SurfaceKHR> {
+        let mut surface = mem::zeroed();
+        self.imagepipe_surface_fn
+            .create_image_pipe_surface_fuchsia(
+                self.handle,
+                create_info,
+                allocation_callbacks.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+    }
+
+    pub fn fp(&self) -> &vk::FuchsiaImagepipeSurfaceFn {
+        &self.imagepipe_surface_fn
+    }
+
+    pub fn instance(&self) -> vk::Instance {
+        self.handle
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::imagepipe_surface::ImagePipeSurface;
+
+mod imagepipe_surface;
@@ -0,0 +1,111 @@
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+#[derive(Clone)]
+pub struct DisplayTiming {
+    handle: vk::Device,
+    display_timing_fn: vk::GoogleDisplayTimingFn,
+}
+
+impl DisplayTiming {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let display_timing_fn = vk::GoogleDisplayTimingFn::load(|name| unsafe {
+            mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            display_timing_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::GoogleDisplayTimingFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetRefreshCycleDurationGOOGLE.html>"]
+    pub unsafe fn get_refresh_cycle_duration(
+        &self,
+        swapchain: vk::SwapchainKHR,
+    ) -> VkResult<vk::RefreshCycleDurationGOOGLE> {
+        let mut properties = vk::RefreshCycleDurationGOOGLE::default();
+        self.display_timing_fn
+            .get_refresh_cycle_duration_google(self.handle, swapchain, &mut properties)
+            .result_with_success(properties)
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html>"]
+    pub unsafe fn get_past_presentation_timing(
+        &self,
+        swapchain: vk::SwapchainKHR,
+    ) -> VkResult<Vec<vk::PastPresentationTimingGOOGLE>> {
+        let mut count = 0;
+        self.display_timing_fn
+            .get_past_presentation_timing_google(
+                self.handle,
+                swapchain,
+                &mut count,
+                ptr::null_mut(),
+            )
+            .result()?;
+
+        let mut v = Vec::with_capacity(count as usize);
+        let err_code = self.display_timing_fn.get_past_presentation_timing_google(
+            self.handle,
+            swapchain,
+            &mut count,
+            v.as_mut_ptr(),
+        );
+        v.set_len(count as usize);
+        err_code.result_with_success(v)
+    }
+
+    /// Returns the `desired_present_time` of a [`vk::PresentTimeGOOGLE`] that displays an image
+    /// at the first refresh cycle that starts at or after `earliest`, counting cycles of
+    /// `refresh_duration` from the `actual_present_time` of `past`.
+    ///
+    /// The time is half a cycle before the start of the cycle, so that jitter in the reported
+    /// times doesn't push the image to the next cycle.
+    pub fn target_present_time(
+        refresh_duration: u64,
+        past: &vk::PastPresentationTimingGOOGLE,
+        earliest: u64,
+    ) -> u64 {
+        if refresh_duration == 0 {
+            return earliest;
+        }
+        let reference = past.actual_present_time;
+        let cycles = earliest
+            .saturating_sub(reference)
+            .div_ceil(refresh_duration);
+        (reference + cycles * refresh_duration).saturating_sub(refresh_duration / 2)
+    }
+
+    pub fn fp(&self) -> &vk::GoogleDisplayTimingFn {
+        &self.display_timing_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_present_time() {
+        let past = vk::PastPresentationTimingGOOGLE {
+            actual_present_time: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(DisplayTiming::target_present_time(100, &past, 1_250), 1_250);
+        assert_eq!(DisplayTiming::target_present_time(100, &past, 1_300), 1_250);
+        assert_eq!(DisplayTiming::target_present_time(100, &past, 500), 950);
+        assert_eq!(DisplayTiming::target_present_time(0, &past, 1_234), 1_234);
+    }
+}
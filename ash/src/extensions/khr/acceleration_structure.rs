@@ -258,8 +258,7 @@ impl AccelerationStructure {
         self.acceleration_structure_fn
             .cmd_write_acceleration_structures_properties_khr(
                 command_buffer,
-                structures.len() as _,
-                structures.as_ptr(),
+                structures,
                 query_type,
                 query_pool,
                 first_query,
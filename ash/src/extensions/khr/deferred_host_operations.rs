@@ -5,6 +5,8 @@ use crate::vk;
 use crate::RawPtr;
 use std::ffi::CStr;
 use std::mem;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
 
 #[derive(Clone)]
 pub struct DeferredHostOperations {
@@ -81,6 +83,74 @@ impl DeferredHostOperations {
             .result()
     }
 
+    /// Runs `operation` to completion on the calling thread and on up to `max_threads - 1`
+    /// threads that `spawn` starts, for instance on a thread pool, and returns its result.
+    ///
+    /// No more threads are started than the operation can use. The calling thread waits for the
+    /// threads that joined the operation to return, and the ones that start after it completed
+    /// don't touch it, so it can be destroyed right away. The errors of the other threads
+    /// surface in the result of the operation.
+    pub unsafe fn join_deferred_operation<S>(
+        &self,
+        operation: vk::DeferredOperationKHR,
+        max_threads: usize,
+        mut spawn: S,
+    ) -> VkResult<()>
+    where
+        S: FnMut(Box<dyn FnOnce() + Send>),
+    {
+        // Whether the operation completed, and how many other threads are joining it
+        let state = Arc::new((Mutex::new((false, 0usize)), Condvar::new()));
+        let concurrency = self.get_deferred_operation_max_concurrency(operation) as usize;
+        for _ in 1..concurrency.min(max_threads) {
+            let operations = self.clone();
+            let state = Arc::clone(&state);
+            spawn(Box::new(move || {
+                let (lock, joined) = &*state;
+                {
+                    let mut state = lock.lock().unwrap();
+                    if state.0 {
+                        return;
+                    }
+                    state.1 += 1;
+                }
+                let _ = unsafe { operations.join_until_done(operation) };
+                lock.lock().unwrap().1 -= 1;
+                joined.notify_all();
+            }));
+        }
+        let result = self.join_until_done(operation).and_then(|()| loop {
+            match self
+                .deferred_host_operations_fn
+                .get_deferred_operation_result_khr(self.handle, operation)
+            {
+                vk::Result::NOT_READY => thread::yield_now(),
+                result => break result.result(),
+            }
+        });
+        let (lock, joined) = &*state;
+        let mut state = lock.lock().unwrap();
+        state.0 = true;
+        while state.1 > 0 {
+            state = joined.wait(state).unwrap();
+        }
+        result
+    }
+
+    /// Joins `operation` until there is no work left for this thread.
+    unsafe fn join_until_done(&self, operation: vk::DeferredOperationKHR) -> VkResult<()> {
+        loop {
+            match self
+                .deferred_host_operations_fn
+                .deferred_operation_join_khr(self.handle, operation)
+            {
+                vk::Result::SUCCESS | vk::Result::THREAD_DONE_KHR => return Ok(()),
+                vk::Result::THREAD_IDLE_KHR => thread::yield_now(),
+                err => return Err(err),
+            }
+        }
+    }
+
     pub fn name() -> &'static CStr {
         vk::KhrDeferredHostOperationsFn::name()
     }
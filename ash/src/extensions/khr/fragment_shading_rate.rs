@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use crate::version::{EntryV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+#[derive(Clone)]
+pub struct FragmentShadingRate {
+    handle: vk::Instance,
+    fragment_shading_rate_fn: vk::KhrFragmentShadingRateFn,
+}
+
+impl FragmentShadingRate {
+    /// Loads the commands through the instance, as the rate enumeration is a physical device
+    /// command, which can't be loaded through a device.
+    pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> Self {
+        let fragment_shading_rate_fn = vk::KhrFragmentShadingRateFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: instance.handle(),
+            fragment_shading_rate_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::KhrFragmentShadingRateFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html>"]
+    pub unsafe fn get_physical_device_fragment_shading_rates(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Vec<vk::PhysicalDeviceFragmentShadingRateKHR>> {
+        let mut count = 0;
+        self.fragment_shading_rate_fn
+            .get_physical_device_fragment_shading_rates_khr(
+                physical_device,
+                &mut count,
+                ptr::null_mut(),
+            )
+            .result()?;
+
+        let mut v = vec![vk::PhysicalDeviceFragmentShadingRateKHR::default(); count as usize];
+        let err_code = self
+            .fragment_shading_rate_fn
+            .get_physical_device_fragment_shading_rates_khr(
+                physical_device,
+                &mut count,
+                v.as_mut_ptr(),
+            );
+        v.truncate(count as usize);
+        err_code.result_with_success(v)
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetFragmentShadingRateKHR.html>"]
+    pub unsafe fn cmd_set_fragment_shading_rate(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        fragment_size: &vk::Extent2D,
+        combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2],
+    ) {
+        self.fragment_shading_rate_fn
+            .cmd_set_fragment_shading_rate_khr(command_buffer, fragment_size, combiner_ops);
+    }
+
+    pub fn fp(&self) -> &vk::KhrFragmentShadingRateFn {
+        &self.fragment_shading_rate_fn
+    }
+
+    pub fn instance(&self) -> vk::Instance {
+        self.handle
+    }
+}
@@ -4,7 +4,13 @@ pub use self::deferred_host_operations::DeferredHostOperations;
 pub use self::display::Display;
 pub use self::display_swapchain::DisplaySwapchain;
 pub use self::draw_indirect_count::DrawIndirectCount;
+pub use self::external_fence_fd::ExternalFenceFd;
+pub use self::external_fence_win32::ExternalFenceWin32;
 pub use self::external_memory_fd::ExternalMemoryFd;
+pub use self::external_semaphore_fd::ExternalSemaphoreFd;
+pub use self::external_semaphore_win32::ExternalSemaphoreWin32;
+pub use self::fragment_shading_rate::FragmentShadingRate;
+pub use self::performance_query::{PerformanceCounterValue, PerformanceQuery};
 pub use self::pipeline_executable_properties::PipelineExecutableProperties;
 pub use self::push_descriptor::PushDescriptor;
 pub use self::ray_query::RayQuery;
@@ -23,7 +29,13 @@ mod deferred_host_operations;
 mod display;
 mod display_swapchain;
 mod draw_indirect_count;
+mod external_fence_fd;
+mod external_fence_win32;
 mod external_memory_fd;
+mod external_semaphore_fd;
+mod external_semaphore_win32;
+mod fragment_shading_rate;
+mod performance_query;
 mod pipeline_executable_properties;
 mod push_descriptor;
 mod ray_query;
@@ -0,0 +1,175 @@
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, EntryV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+/// The value of a performance counter, decoded from a [`vk::PerformanceCounterResultKHR`] with
+/// the storage type of the counter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PerformanceCounterValue {
+    Int32(i32),
+    Int64(i64),
+    Uint32(u32),
+    Uint64(u64),
+    Float32(f32),
+    Float64(f64),
+}
+
+impl PerformanceCounterValue {
+    /// Decodes `result` of a counter with `storage`, `None` for an unknown storage type.
+    pub fn new(
+        result: &vk::PerformanceCounterResultKHR,
+        storage: vk::PerformanceCounterStorageKHR,
+    ) -> Option<Self> {
+        // Every bit pattern is a valid value of every field
+        unsafe {
+            Some(match storage {
+                vk::PerformanceCounterStorageKHR::INT32 => Self::Int32(result.int32),
+                vk::PerformanceCounterStorageKHR::INT64 => Self::Int64(result.int64),
+                vk::PerformanceCounterStorageKHR::UINT32 => Self::Uint32(result.uint32),
+                vk::PerformanceCounterStorageKHR::UINT64 => Self::Uint64(result.uint64),
+                vk::PerformanceCounterStorageKHR::FLOAT32 => Self::Float32(result.float32),
+                vk::PerformanceCounterStorageKHR::FLOAT64 => Self::Float64(result.float64),
+                _ => return None,
+            })
+        }
+    }
+
+    /// Returns the value as an `f64`, which loses precision for 64-bit integers above 2^53.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Int32(value) => value.into(),
+            Self::Int64(value) => value as f64,
+            Self::Uint32(value) => value.into(),
+            Self::Uint64(value) => value as f64,
+            Self::Float32(value) => value.into(),
+            Self::Float64(value) => value,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PerformanceQuery {
+    handle: vk::Device,
+    performance_query_fn: vk::KhrPerformanceQueryFn,
+}
+
+impl PerformanceQuery {
+    /// Loads the commands through the instance, as the counter enumeration is a physical device
+    /// command, which can't be loaded through a device.
+    pub fn new<E: EntryV1_0, I: InstanceV1_0, D: DeviceV1_0>(
+        entry: &E,
+        instance: &I,
+        device: &D,
+    ) -> Self {
+        let performance_query_fn = vk::KhrPerformanceQueryFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            performance_query_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::KhrPerformanceQueryFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html>"]
+    pub unsafe fn enumerate_physical_device_queue_family_performance_query_counters(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        queue_family_index: u32,
+    ) -> VkResult<(
+        Vec<vk::PerformanceCounterKHR>,
+        Vec<vk::PerformanceCounterDescriptionKHR>,
+    )> {
+        let mut count = 0;
+        self.performance_query_fn
+            .enumerate_physical_device_queue_family_performance_query_counters_khr(
+                physical_device,
+                queue_family_index,
+                &mut count,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+            .result()?;
+
+        let mut counters = vec![vk::PerformanceCounterKHR::default(); count as usize];
+        let mut descriptions =
+            vec![vk::PerformanceCounterDescriptionKHR::default(); count as usize];
+        let err_code = self
+            .performance_query_fn
+            .enumerate_physical_device_queue_family_performance_query_counters_khr(
+                physical_device,
+                queue_family_index,
+                &mut count,
+                counters.as_mut_ptr(),
+                descriptions.as_mut_ptr(),
+            );
+        counters.truncate(count as usize);
+        descriptions.truncate(count as usize);
+        err_code.result_with_success((counters, descriptions))
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR.html>"]
+    pub unsafe fn get_physical_device_queue_family_performance_query_passes(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        performance_query_create_info: &vk::QueryPoolPerformanceCreateInfoKHR,
+    ) -> u32 {
+        let mut num_passes = 0;
+        self.performance_query_fn
+            .get_physical_device_queue_family_performance_query_passes_khr(
+                physical_device,
+                performance_query_create_info,
+                &mut num_passes,
+            );
+        num_passes
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkAcquireProfilingLockKHR.html>"]
+    pub unsafe fn acquire_profiling_lock(
+        &self,
+        info: &vk::AcquireProfilingLockInfoKHR,
+    ) -> VkResult<()> {
+        self.performance_query_fn
+            .acquire_profiling_lock_khr(self.handle, info)
+            .result()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkReleaseProfilingLockKHR.html>"]
+    pub unsafe fn release_profiling_lock(&self) {
+        self.performance_query_fn
+            .release_profiling_lock_khr(self.handle);
+    }
+
+    pub fn fp(&self) -> &vk::KhrPerformanceQueryFn {
+        &self.performance_query_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_values() {
+        let result = vk::PerformanceCounterResultKHR { float64: 2.5 };
+        let value =
+            PerformanceCounterValue::new(&result, vk::PerformanceCounterStorageKHR::FLOAT64);
+        assert_eq!(value, Some(PerformanceCounterValue::Float64(2.5)));
+        let result = vk::PerformanceCounterResultKHR { uint64: u64::MAX };
+        let value = PerformanceCounterValue::new(&result, vk::PerformanceCounterStorageKHR::INT32);
+        assert_eq!(value, Some(PerformanceCounterValue::Int32(-1)));
+        assert_eq!(value.unwrap().as_f64(), -1.0);
+        let storage = vk::PerformanceCounterStorageKHR::from_raw(42);
+        assert_eq!(PerformanceCounterValue::new(&result, storage), None);
+    }
+}
@@ -41,8 +41,7 @@ impl PushDescriptor {
             pipeline_bind_point,
             layout,
             set,
-            descriptor_writes.len() as u32,
-            descriptor_writes.as_ptr(),
+            descriptor_writes,
         );
     }
 
@@ -1,8 +1,9 @@
 #![allow(dead_code)]
-use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::version::{DeviceV1_0, InstanceV1_0, InstanceV1_1};
 use crate::vk;
 use std::ffi::CStr;
 use std::mem;
+use std::os::raw::c_void;
 
 #[derive(Clone)]
 pub struct RayQuery {
@@ -21,6 +22,20 @@ impl RayQuery {
         }
     }
 
+    pub unsafe fn get_features<I: InstanceV1_1>(
+        instance: &I,
+        pdevice: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceRayQueryFeaturesKHR {
+        let mut features_rq = vk::PhysicalDeviceRayQueryFeaturesKHR::default();
+        // `PhysicalDeviceFeatures2` extends `DeviceCreateInfo`, so its builder has no `push_next`
+        let mut features = vk::PhysicalDeviceFeatures2 {
+            p_next: &mut features_rq as *mut _ as *mut c_void,
+            ..Default::default()
+        };
+        instance.get_physical_device_features2(pdevice, &mut features);
+        features_rq
+    }
+
     pub fn name() -> &'static CStr {
         vk::KhrRayQueryFn::name()
     }
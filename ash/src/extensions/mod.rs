@@ -1,5 +1,9 @@
+pub mod amd;
 pub mod experimental;
 pub mod ext;
+pub mod fuchsia;
+pub mod google;
 pub mod khr;
 pub mod mvk;
+pub mod nn;
 pub mod nv;
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use crate::version::{EntryV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+#[derive(Clone)]
+pub struct CooperativeMatrix {
+    handle: vk::Instance,
+    cooperative_matrix_fn: vk::NvCooperativeMatrixFn,
+}
+
+impl CooperativeMatrix {
+    pub fn new<E: EntryV1_0, I: InstanceV1_0>(entry: &E, instance: &I) -> Self {
+        let cooperative_matrix_fn = vk::NvCooperativeMatrixFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: instance.handle(),
+            cooperative_matrix_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::NvCooperativeMatrixFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html>"]
+    pub unsafe fn get_physical_device_cooperative_matrix_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Vec<vk::CooperativeMatrixPropertiesNV>> {
+        let mut count = 0;
+        self.cooperative_matrix_fn
+            .get_physical_device_cooperative_matrix_properties_nv(
+                physical_device,
+                &mut count,
+                ptr::null_mut(),
+            )
+            .result()?;
+
+        let mut v = vec![vk::CooperativeMatrixPropertiesNV::default(); count as usize];
+        let err_code = self
+            .cooperative_matrix_fn
+            .get_physical_device_cooperative_matrix_properties_nv(
+                physical_device,
+                &mut count,
+                v.as_mut_ptr(),
+            );
+        v.truncate(count as usize);
+        err_code.result_with_success(v)
+    }
+
+    pub fn fp(&self) -> &vk::NvCooperativeMatrixFn {
+        &self.cooperative_matrix_fn
+    }
+
+    pub fn instance(&self) -> vk::Instance {
+        self.handle
+    }
+}
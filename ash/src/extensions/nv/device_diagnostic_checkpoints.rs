@@ -0,0 +1,68 @@
+use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::fmt::Write;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+
+#[derive(Clone)]
+pub struct DeviceDiagnosticCheckpoints {
+    device_diagnostic_checkpoints_fn: vk::NvDeviceDiagnosticCheckpointsFn,
+}
+
+impl DeviceDiagnosticCheckpoints {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let device_diagnostic_checkpoints_fn =
+            vk::NvDeviceDiagnosticCheckpointsFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+            });
+        Self {
+            device_diagnostic_checkpoints_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::NvDeviceDiagnosticCheckpointsFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetCheckpointNV.html>"]
+    pub unsafe fn cmd_set_checkpoint(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        checkpoint_marker: *const c_void,
+    ) {
+        self.device_diagnostic_checkpoints_fn
+            .cmd_set_checkpoint_nv(command_buffer, checkpoint_marker);
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetQueueCheckpointDataNV.html>"]
+    pub unsafe fn get_queue_checkpoint_data(&self, queue: vk::Queue) -> Vec<vk::CheckpointDataNV> {
+        let mut count = 0;
+        self.device_diagnostic_checkpoints_fn
+            .get_queue_checkpoint_data_nv(queue, &mut count, ptr::null_mut());
+        let mut v = vec![vk::CheckpointDataNV::default(); count as usize];
+        self.device_diagnostic_checkpoints_fn
+            .get_queue_checkpoint_data_nv(queue, &mut count, v.as_mut_ptr());
+        v.truncate(count as usize);
+        v
+    }
+
+    /// Returns the last checkpoints that `queue` reached, one per line with the pipeline stages
+    /// and the marker, for logging after a [`vk::Result::ERROR_DEVICE_LOST`].
+    pub unsafe fn dump_queue_checkpoints(&self, queue: vk::Queue) -> String {
+        let mut dump = String::new();
+        for checkpoint in self.get_queue_checkpoint_data(queue) {
+            let _ = writeln!(
+                dump,
+                "{:?}: {:p}",
+                checkpoint.stage, checkpoint.p_checkpoint_marker
+            );
+        }
+        dump
+    }
+
+    pub fn fp(&self) -> &vk::NvDeviceDiagnosticCheckpointsFn {
+        &self.device_diagnostic_checkpoints_fn
+    }
+}
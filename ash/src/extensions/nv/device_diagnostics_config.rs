@@ -0,0 +1,36 @@
+use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+
+/// `VK_NV_device_diagnostics_config` has no commands, it is configured by chaining a
+/// [`vk::DeviceDiagnosticsConfigCreateInfoNV`] to the [`vk::DeviceCreateInfo`].
+#[derive(Clone)]
+pub struct DeviceDiagnosticsConfig {
+    handle: vk::Device,
+    device_diagnostics_config_fn: vk::NvDeviceDiagnosticsConfigFn,
+}
+
+impl DeviceDiagnosticsConfig {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let device_diagnostics_config_fn = vk::NvDeviceDiagnosticsConfigFn::load(|name| unsafe {
+            mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            device_diagnostics_config_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::NvDeviceDiagnosticsConfigFn::name()
+    }
+
+    pub fn fp(&self) -> &vk::NvDeviceDiagnosticsConfigFn {
+        &self.device_diagnostics_config_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
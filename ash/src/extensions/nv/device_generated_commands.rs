@@ -0,0 +1,119 @@
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, InstanceV1_0};
+use crate::vk;
+use crate::RawPtr;
+use std::ffi::CStr;
+use std::mem;
+
+#[derive(Clone)]
+pub struct DeviceGeneratedCommands {
+    handle: vk::Device,
+    device_generated_commands_fn: vk::NvDeviceGeneratedCommandsFn,
+}
+
+impl DeviceGeneratedCommands {
+    pub fn new<I: InstanceV1_0, D: DeviceV1_0>(instance: &I, device: &D) -> Self {
+        let device_generated_commands_fn = vk::NvDeviceGeneratedCommandsFn::load(|name| unsafe {
+            mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: device.handle(),
+            device_generated_commands_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::NvDeviceGeneratedCommandsFn::name()
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
+    pub unsafe fn get_generated_commands_memory_requirements(
+        &self,
+        info: &vk::GeneratedCommandsMemoryRequirementsInfoNV,
+        out: &mut vk::MemoryRequirements2,
+    ) {
+        self.device_generated_commands_fn
+            .get_generated_commands_memory_requirements_nv(self.handle, info, out);
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
+    pub unsafe fn cmd_preprocess_generated_commands(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        generated_commands_info: &vk::GeneratedCommandsInfoNV,
+    ) {
+        self.device_generated_commands_fn
+            .cmd_preprocess_generated_commands_nv(command_buffer, generated_commands_info);
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
+    pub unsafe fn cmd_execute_generated_commands(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        is_preprocessed: bool,
+        generated_commands_info: &vk::GeneratedCommandsInfoNV,
+    ) {
+        self.device_generated_commands_fn
+            .cmd_execute_generated_commands_nv(
+                command_buffer,
+                is_preprocessed as u32,
+                generated_commands_info,
+            );
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
+    pub unsafe fn cmd_bind_pipeline_shader_group(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        pipeline: vk::Pipeline,
+        group_index: u32,
+    ) {
+        self.device_generated_commands_fn
+            .cmd_bind_pipeline_shader_group_nv(
+                command_buffer,
+                pipeline_bind_point,
+                pipeline,
+                group_index,
+            );
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateIndirectCommandsLayoutNV.html>"]
+    pub unsafe fn create_indirect_commands_layout(
+        &self,
+        create_info: &vk::IndirectCommandsLayoutCreateInfoNV,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::IndirectCommandsLayoutNV> {
+        let mut indirect_commands_layout = mem::zeroed();
+        self.device_generated_commands_fn
+            .create_indirect_commands_layout_nv(
+                self.handle,
+                create_info,
+                allocation_callbacks.as_raw_ptr(),
+                &mut indirect_commands_layout,
+            )
+            .result_with_success(indirect_commands_layout)
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyIndirectCommandsLayoutNV.html>"]
+    pub unsafe fn destroy_indirect_commands_layout(
+        &self,
+        indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.device_generated_commands_fn
+            .destroy_indirect_commands_layout_nv(
+                self.handle,
+                indirect_commands_layout,
+                allocation_callbacks.as_raw_ptr(),
+            );
+    }
+
+    pub fn fp(&self) -> &vk::NvDeviceGeneratedCommandsFn {
+        &self.device_generated_commands_fn
+    }
+
+    pub fn device(&self) -> vk::Device {
+        self.handle
+    }
+}
@@ -1,5 +1,13 @@
+pub use self::cooperative_matrix::CooperativeMatrix;
+pub use self::device_diagnostic_checkpoints::DeviceDiagnosticCheckpoints;
+pub use self::device_diagnostics_config::DeviceDiagnosticsConfig;
+pub use self::device_generated_commands::DeviceGeneratedCommands;
 pub use self::mesh_shader::MeshShader;
 pub use self::ray_tracing::RayTracing;
 
+mod cooperative_matrix;
+mod device_diagnostic_checkpoints;
+mod device_diagnostics_config;
+mod device_generated_commands;
 mod mesh_shader;
 mod ray_tracing;
@@ -239,8 +239,7 @@ impl RayTracing {
         self.ray_tracing_fn
             .cmd_write_acceleration_structures_properties_nv(
                 command_buffer,
-                structures.len() as u32,
-                structures.as_ptr(),
+                structures,
                 query_type,
                 query_pool,
                 first_query,
@@ -3,8 +3,9 @@ use crate::device::Device;
 use crate::prelude::*;
 use crate::vk;
 use crate::RawPtr;
+use std::ffi::CStr;
 use std::mem;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
 use std::ptr;
 
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkInstance.html>"]
@@ -17,16 +18,33 @@ pub struct Instance {
 }
 impl Instance {
     pub unsafe fn load(static_fn: &vk::StaticFn, instance: vk::Instance) -> Self {
-        let instance_fn_1_0 = vk::InstanceFnV1_0::load(|name| {
+        Self::load_with(instance, |name| {
             mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
-        });
-        let instance_fn_1_1 = vk::InstanceFnV1_1::load(|name| {
-            mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
-        });
-        let instance_fn_1_2 = vk::InstanceFnV1_2::load(|name| {
-            mem::transmute(static_fn.get_instance_proc_addr(instance, name.as_ptr()))
-        });
+        })
+    }
+
+    /// Loads the commands of `instance` with `load`, which returns the address of a command or
+    /// null like `vkGetInstanceProcAddr`. This allows resolving commands with another
+    /// `vkGetInstanceProcAddr`, such as the one of an OpenXR runtime, or interposing some of them.
+    pub unsafe fn load_with<F>(instance: vk::Instance, mut load: F) -> Self
+    where
+        F: FnMut(&CStr) -> *const c_void,
+    {
+        Instance {
+            handle: instance,
+            instance_fn_1_0: vk::InstanceFnV1_0::load(&mut load),
+            instance_fn_1_1: vk::InstanceFnV1_1::load(&mut load),
+            instance_fn_1_2: vk::InstanceFnV1_2::load(&mut load),
+        }
+    }
 
+    /// Creates an `Instance` from command tables filled in by hand, see [`Device::from_fns`].
+    pub fn from_fns(
+        instance: vk::Instance,
+        instance_fn_1_0: vk::InstanceFnV1_0,
+        instance_fn_1_1: vk::InstanceFnV1_1,
+        instance_fn_1_2: vk::InstanceFnV1_2,
+    ) -> Self {
         Instance {
             handle: instance,
             instance_fn_1_0,
@@ -313,13 +331,13 @@ pub trait InstanceV1_0 {
         physical_device: vk::PhysicalDevice,
         format: vk::Format,
     ) -> vk::FormatProperties {
-        let mut format_prop = mem::zeroed();
+        let mut format_prop = mem::MaybeUninit::<vk::FormatProperties>::zeroed();
         self.fp_v1_0().get_physical_device_format_properties(
             physical_device,
             format,
-            &mut format_prop,
+            format_prop.as_mut_ptr(),
         );
-        format_prop
+        format_prop.assume_init()
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties.html>"]
@@ -351,10 +369,10 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceMemoryProperties {
-        let mut memory_prop = mem::zeroed();
+        let mut memory_prop = mem::MaybeUninit::<vk::PhysicalDeviceMemoryProperties>::zeroed();
         self.fp_v1_0()
-            .get_physical_device_memory_properties(physical_device, &mut memory_prop);
-        memory_prop
+            .get_physical_device_memory_properties(physical_device, memory_prop.as_mut_ptr());
+        memory_prop.assume_init()
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceProperties.html>"]
@@ -362,10 +380,10 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: vk::PhysicalDevice,
     ) -> vk::PhysicalDeviceProperties {
-        let mut prop = mem::zeroed();
+        let mut prop = mem::MaybeUninit::<vk::PhysicalDeviceProperties>::zeroed();
         self.fp_v1_0()
-            .get_physical_device_properties(physical_device, &mut prop);
-        prop
+            .get_physical_device_properties(physical_device, prop.as_mut_ptr());
+        prop.assume_init()
     }
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties.html>"]
@@ -22,18 +22,29 @@
 //! ```
 //!
 
-pub use crate::device::Device;
+pub use crate::device::{Device, ShaderModuleError};
 pub use crate::entry::{EntryCustom, InstanceError};
 #[cfg(feature = "libloading")]
 pub use crate::entry_libloading::{Entry, LoadingError};
 pub use crate::instance::Instance;
 
+pub mod allocator;
 mod device;
 mod entry;
 #[cfg(feature = "libloading")]
 mod entry_libloading;
 mod instance;
+#[cfg(feature = "null-driver")]
+pub mod null_driver;
+#[cfg(feature = "owned")]
+pub mod owned;
 pub mod prelude;
+#[cfg(feature = "recording")]
+pub mod recording;
+#[cfg(feature = "swapchain-helper")]
+pub mod swapchain_helper;
+#[cfg(feature = "trace")]
+pub mod trace;
 pub mod util;
 pub mod version;
 #[macro_use]
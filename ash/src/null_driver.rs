@@ -0,0 +1,936 @@
+//! A driver that does no work, for testing code that uses Vulkan on machines without a GPU.
+//!
+//! Objects are dummy handles, device memory is host memory so that mapping it works, and the
+//! physical device queries return the canned data of [`physical_device_properties`],
+//! [`memory_properties`] and [`queue_family_properties`]. Submissions complete immediately and
+//! fences are always signaled. Commands that the driver doesn't implement panic when called, like
+//! commands that a real driver doesn't provide.
+//!
+//! ```
+//! use ash::version::{EntryV1_0, InstanceV1_0};
+//! use ash::vk;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let entry = ash::null_driver::entry();
+//! let instance = unsafe { entry.create_instance(&vk::InstanceCreateInfo::default(), None)? };
+//! let physical_devices = unsafe { instance.enumerate_physical_devices()? };
+//! assert_eq!(physical_devices.len(), 1);
+//! # Ok(()) }
+//! ```
+use crate::entry::EntryCustom;
+use crate::vk::{self, Handle};
+use std::alloc::{self, Layout};
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The alignment of mapped memory, `PhysicalDeviceLimits::min_memory_map_alignment`.
+const MAP_ALIGNMENT: usize = 64;
+
+/// An entry whose commands are the ones of the null driver.
+pub fn entry() -> EntryCustom<()> {
+    EntryCustom::new_custom((), |_, name| proc_addr(name))
+}
+
+/// The address of the command `name` of the null driver, or null if it isn't implemented. This
+/// is the `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr` of the driver, e.g. for
+/// `Instance::load_with`.
+pub fn proc_addr(name: &CStr) -> *const c_void {
+    let name = name.to_bytes();
+    match name {
+        b"vkGetInstanceProcAddr" => get_instance_proc_addr as *const c_void,
+        b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+        b"vkEnumerateInstanceVersion" => enumerate_instance_version as *const c_void,
+        b"vkEnumerateInstanceExtensionProperties" => {
+            enumerate_instance_extension_properties as *const c_void
+        }
+        b"vkEnumerateInstanceLayerProperties" => {
+            enumerate_instance_layer_properties as *const c_void
+        }
+        b"vkCreateInstance" => create_instance as *const c_void,
+        b"vkDestroyInstance" => destroy_instance as *const c_void,
+        b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const c_void,
+        b"vkGetPhysicalDeviceProperties" => get_physical_device_properties as *const c_void,
+        b"vkGetPhysicalDeviceProperties2" => get_physical_device_properties2 as *const c_void,
+        b"vkGetPhysicalDeviceFeatures" => get_physical_device_features as *const c_void,
+        b"vkGetPhysicalDeviceFeatures2" => get_physical_device_features2 as *const c_void,
+        b"vkGetPhysicalDeviceMemoryProperties" => {
+            get_physical_device_memory_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => {
+            get_physical_device_queue_family_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceFormatProperties" => {
+            get_physical_device_format_properties as *const c_void
+        }
+        b"vkEnumerateDeviceExtensionProperties" => {
+            enumerate_device_extension_properties as *const c_void
+        }
+        b"vkCreateDevice" => create_device as *const c_void,
+        b"vkDestroyDevice" => destroy_device as *const c_void,
+        b"vkGetDeviceQueue" => get_device_queue as *const c_void,
+        b"vkQueueSubmit" => queue_submit as *const c_void,
+        b"vkQueueWaitIdle" => queue_wait_idle as *const c_void,
+        b"vkDeviceWaitIdle" => device_wait_idle as *const c_void,
+        b"vkAllocateMemory" => allocate_memory as *const c_void,
+        b"vkFreeMemory" => free_memory as *const c_void,
+        b"vkMapMemory" => map_memory as *const c_void,
+        b"vkUnmapMemory" => unmap_memory as *const c_void,
+        b"vkFlushMappedMemoryRanges" | b"vkInvalidateMappedMemoryRanges" => {
+            mapped_memory_ranges as *const c_void
+        }
+        b"vkCreateBuffer" => create_buffer as *const c_void,
+        b"vkDestroyBuffer" => destroy_buffer as *const c_void,
+        b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements as *const c_void,
+        b"vkBindBufferMemory" => bind_buffer_memory as *const c_void,
+        b"vkCreateImage" => create_image as *const c_void,
+        b"vkDestroyImage" => destroy_image as *const c_void,
+        b"vkGetImageMemoryRequirements" => get_image_memory_requirements as *const c_void,
+        b"vkBindImageMemory" => bind_image_memory as *const c_void,
+        b"vkCreateGraphicsPipelines" => create_graphics_pipelines as *const c_void,
+        b"vkCreateComputePipelines" => create_compute_pipelines as *const c_void,
+        b"vkGetPipelineCacheData" => get_pipeline_cache_data as *const c_void,
+        b"vkAllocateCommandBuffers" => allocate_command_buffers as *const c_void,
+        b"vkFreeCommandBuffers" => free_command_buffers as *const c_void,
+        b"vkResetCommandPool" => reset_command_pool as *const c_void,
+        b"vkAllocateDescriptorSets" => allocate_descriptor_sets as *const c_void,
+        b"vkFreeDescriptorSets" => free_descriptor_sets as *const c_void,
+        b"vkResetDescriptorPool" => reset_descriptor_pool as *const c_void,
+        b"vkUpdateDescriptorSets" => update_descriptor_sets as *const c_void,
+        b"vkWaitForFences" => wait_for_fences as *const c_void,
+        b"vkResetFences" => reset_fences as *const c_void,
+        b"vkGetFenceStatus" => get_fence_status as *const c_void,
+        b"vkBeginCommandBuffer" => begin_command_buffer as *const c_void,
+        b"vkEndCommandBuffer" => end_command_buffer as *const c_void,
+        b"vkResetCommandBuffer" => reset_command_buffer as *const c_void,
+        _ => object_proc_addr(name),
+    }
+}
+
+/// The properties of the only physical device.
+pub fn physical_device_properties() -> vk::PhysicalDeviceProperties {
+    let mut properties = vk::PhysicalDeviceProperties {
+        api_version: vk::make_version(1, 2, 0),
+        driver_version: vk::make_version(0, 1, 0),
+        device_type: vk::PhysicalDeviceType::CPU,
+        limits: vk::PhysicalDeviceLimits {
+            max_image_dimension1_d: 16384,
+            max_image_dimension2_d: 16384,
+            max_image_dimension3_d: 2048,
+            max_image_dimension_cube: 16384,
+            max_image_array_layers: 2048,
+            max_uniform_buffer_range: 65536,
+            max_storage_buffer_range: 1 << 27,
+            max_push_constants_size: 128,
+            max_memory_allocation_count: 4096,
+            max_sampler_allocation_count: 4000,
+            buffer_image_granularity: 1,
+            max_bound_descriptor_sets: 8,
+            max_vertex_input_attributes: 16,
+            max_vertex_input_bindings: 16,
+            max_color_attachments: 8,
+            max_framebuffer_width: 16384,
+            max_framebuffer_height: 16384,
+            max_framebuffer_layers: 2048,
+            max_viewports: 1,
+            max_viewport_dimensions: [16384; 2],
+            max_compute_work_group_count: [65535; 3],
+            max_compute_work_group_invocations: 1024,
+            max_compute_work_group_size: [1024, 1024, 64],
+            min_memory_map_alignment: MAP_ALIGNMENT,
+            min_uniform_buffer_offset_alignment: 256,
+            min_storage_buffer_offset_alignment: 256,
+            min_texel_buffer_offset_alignment: 256,
+            non_coherent_atom_size: 64,
+            timestamp_period: 1.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let name = b"ash null driver";
+    for (dst, &src) in properties.device_name.iter_mut().zip(name) {
+        *dst = src as c_char;
+    }
+    properties
+}
+
+/// A `DEVICE_LOCAL` memory type and a `HOST_VISIBLE` and `HOST_COHERENT` one, each with its own
+/// heap.
+pub fn memory_properties() -> vk::PhysicalDeviceMemoryProperties {
+    let mut properties = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 2,
+        memory_heap_count: 2,
+        ..Default::default()
+    };
+    properties.memory_types[0] = vk::MemoryType {
+        property_flags: vk::MemoryPropertyFlags::DEVICE_LOCAL,
+        heap_index: 0,
+    };
+    properties.memory_types[1] = vk::MemoryType {
+        property_flags: vk::MemoryPropertyFlags::HOST_VISIBLE
+            | vk::MemoryPropertyFlags::HOST_COHERENT,
+        heap_index: 1,
+    };
+    properties.memory_heaps[0] = vk::MemoryHeap {
+        size: 1 << 30,
+        flags: vk::MemoryHeapFlags::DEVICE_LOCAL,
+    };
+    properties.memory_heaps[1] = vk::MemoryHeap {
+        size: 1 << 30,
+        flags: vk::MemoryHeapFlags::empty(),
+    };
+    properties
+}
+
+/// A single queue family that supports graphics, compute and transfers.
+pub fn queue_family_properties() -> vk::QueueFamilyProperties {
+    vk::QueueFamilyProperties {
+        queue_flags: vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER,
+        queue_count: 1,
+        timestamp_valid_bits: 64,
+        min_image_transfer_granularity: vk::Extent3D {
+            width: 1,
+            height: 1,
+            depth: 1,
+        },
+    }
+}
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+fn new_handle<T: Handle>() -> T {
+    T::from_raw(NEXT_HANDLE.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Writes `items` the way of `vkEnumerate*` commands, the count if `p_items` is null.
+unsafe fn enumerate<T: Copy>(items: &[T], p_count: *mut u32, p_items: *mut T) -> vk::Result {
+    if p_items.is_null() {
+        *p_count = items.len() as u32;
+        return vk::Result::SUCCESS;
+    }
+    let count = (*p_count as usize).min(items.len());
+    ptr::copy_nonoverlapping(items.as_ptr(), p_items, count);
+    *p_count = count as u32;
+    if count < items.len() {
+        vk::Result::INCOMPLETE
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+/// The host memory behind a `DeviceMemory`, whose handle is a pointer to it.
+struct HostMemory {
+    ptr: *mut u8,
+    layout: Layout,
+}
+
+impl Drop for HostMemory {
+    fn drop(&mut self) {
+        unsafe { alloc::dealloc(self.ptr, self.layout) };
+    }
+}
+
+/// The requirements of a buffer or image, whose handle is a pointer to them.
+unsafe fn requirements_handle<T: Handle>(requirements: vk::MemoryRequirements) -> T {
+    T::from_raw(Box::into_raw(Box::new(requirements)) as u64)
+}
+
+unsafe fn free_requirements_handle<T: Handle>(handle: T) {
+    let raw = handle.as_raw();
+    if raw != 0 {
+        drop(Box::from_raw(raw as *mut vk::MemoryRequirements));
+    }
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    _: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    mem::transmute(proc_addr(CStr::from_ptr(p_name)))
+}
+
+unsafe extern "system" fn get_device_proc_addr(
+    _: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    mem::transmute(proc_addr(CStr::from_ptr(p_name)))
+}
+
+unsafe extern "system" fn enumerate_instance_version(p_api_version: *mut u32) -> vk::Result {
+    *p_api_version = vk::make_version(1, 2, 0);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    _: *const c_char,
+    p_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    enumerate(&[], p_count, p_properties)
+}
+
+unsafe extern "system" fn enumerate_instance_layer_properties(
+    p_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    enumerate(&[], p_count, p_properties)
+}
+
+unsafe extern "system" fn create_instance(
+    _: *const vk::InstanceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    *p_instance = new_handle();
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_instance(_: vk::Instance, _: *const vk::AllocationCallbacks) {}
+
+unsafe extern "system" fn enumerate_physical_devices(
+    instance: vk::Instance,
+    p_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    // One physical device per instance, with the handle of the instance
+    let physical_device = vk::PhysicalDevice::from_raw(instance.as_raw());
+    enumerate(&[physical_device], p_count, p_physical_devices)
+}
+
+unsafe extern "system" fn get_physical_device_properties(
+    _: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    *p_properties = physical_device_properties();
+}
+
+unsafe extern "system" fn get_physical_device_properties2(
+    _: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties2,
+) {
+    (*p_properties).properties = physical_device_properties();
+}
+
+unsafe extern "system" fn get_physical_device_features(
+    _: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures,
+) {
+    *p_features = vk::PhysicalDeviceFeatures::default();
+}
+
+unsafe extern "system" fn get_physical_device_features2(
+    _: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures2,
+) {
+    (*p_features).features = vk::PhysicalDeviceFeatures::default();
+}
+
+unsafe extern "system" fn get_physical_device_memory_properties(
+    _: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    *p_properties = memory_properties();
+}
+
+unsafe extern "system" fn get_physical_device_queue_family_properties(
+    _: vk::PhysicalDevice,
+    p_count: *mut u32,
+    p_properties: *mut vk::QueueFamilyProperties,
+) {
+    // The command has no result, a `p_count` smaller than the number of families is how it
+    // reports `INCOMPLETE`
+    let _ = enumerate(&[queue_family_properties()], p_count, p_properties);
+}
+
+unsafe extern "system" fn get_physical_device_format_properties(
+    _: vk::PhysicalDevice,
+    _: vk::Format,
+    p_properties: *mut vk::FormatProperties,
+) {
+    let image_features = vk::FormatFeatureFlags::SAMPLED_IMAGE
+        | vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR
+        | vk::FormatFeatureFlags::STORAGE_IMAGE
+        | vk::FormatFeatureFlags::COLOR_ATTACHMENT
+        | vk::FormatFeatureFlags::COLOR_ATTACHMENT_BLEND
+        | vk::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT
+        | vk::FormatFeatureFlags::BLIT_SRC
+        | vk::FormatFeatureFlags::BLIT_DST
+        | vk::FormatFeatureFlags::TRANSFER_SRC
+        | vk::FormatFeatureFlags::TRANSFER_DST;
+    *p_properties = vk::FormatProperties {
+        linear_tiling_features: image_features,
+        optimal_tiling_features: image_features,
+        buffer_features: vk::FormatFeatureFlags::VERTEX_BUFFER
+            | vk::FormatFeatureFlags::UNIFORM_TEXEL_BUFFER
+            | vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER,
+    };
+}
+
+unsafe extern "system" fn enumerate_device_extension_properties(
+    _: vk::PhysicalDevice,
+    _: *const c_char,
+    p_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    enumerate(&[], p_count, p_properties)
+}
+
+unsafe extern "system" fn create_device(
+    _: vk::PhysicalDevice,
+    _: *const vk::DeviceCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    *p_device = new_handle();
+    vk::Result::SUCCESS
+}
+
+extern "system" fn destroy_device(_: vk::Device, _: *const vk::AllocationCallbacks) {}
+
+unsafe extern "system" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    // The same queue every time, and out of the way of `new_handle`
+    *p_queue = vk::Queue::from_raw(
+        device.as_raw() << 32 | u64::from(queue_family_index) << 16 | u64::from(queue_index),
+    );
+}
+
+extern "system" fn queue_submit(
+    _: vk::Queue,
+    _: u32,
+    _: *const vk::SubmitInfo,
+    _: vk::Fence,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn queue_wait_idle(_: vk::Queue) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn device_wait_idle(_: vk::Device) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn allocate_memory(
+    _: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let allocate_info = &*p_allocate_info;
+    if allocate_info.memory_type_index >= memory_properties().memory_type_count {
+        return vk::Result::ERROR_OUT_OF_DEVICE_MEMORY;
+    }
+    let layout =
+        match Layout::from_size_align(allocate_info.allocation_size.max(1) as usize, MAP_ALIGNMENT)
+        {
+            Ok(layout) => layout,
+            Err(_) => return vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        };
+    let ptr = alloc::alloc_zeroed(layout);
+    if ptr.is_null() {
+        return vk::Result::ERROR_OUT_OF_HOST_MEMORY;
+    }
+    let memory = Box::new(HostMemory { ptr, layout });
+    *p_memory = vk::DeviceMemory::from_raw(Box::into_raw(memory) as u64);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn free_memory(
+    _: vk::Device,
+    memory: vk::DeviceMemory,
+    _: *const vk::AllocationCallbacks,
+) {
+    if memory != vk::DeviceMemory::null() {
+        drop(Box::from_raw(memory.as_raw() as *mut HostMemory));
+    }
+}
+
+unsafe extern "system" fn map_memory(
+    _: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    _: vk::DeviceSize,
+    _: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let memory = &*(memory.as_raw() as *const HostMemory);
+    *pp_data = memory.ptr.add(offset as usize) as *mut c_void;
+    vk::Result::SUCCESS
+}
+
+extern "system" fn unmap_memory(_: vk::Device, _: vk::DeviceMemory) {}
+
+extern "system" fn mapped_memory_ranges(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::MappedMemoryRange,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_buffer(
+    _: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    let alignment = 256;
+    *p_buffer = requirements_handle(vk::MemoryRequirements {
+        size: (*p_create_info).size.div_ceil(alignment) * alignment,
+        alignment,
+        memory_type_bits: 0b11,
+    });
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_buffer(
+    _: vk::Device,
+    buffer: vk::Buffer,
+    _: *const vk::AllocationCallbacks,
+) {
+    free_requirements_handle(buffer);
+}
+
+unsafe extern "system" fn get_buffer_memory_requirements(
+    _: vk::Device,
+    buffer: vk::Buffer,
+    p_requirements: *mut vk::MemoryRequirements,
+) {
+    *p_requirements = *(buffer.as_raw() as *const vk::MemoryRequirements);
+}
+
+extern "system" fn bind_buffer_memory(
+    _: vk::Device,
+    _: vk::Buffer,
+    _: vk::DeviceMemory,
+    _: vk::DeviceSize,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_image(
+    _: vk::Device,
+    p_create_info: *const vk::ImageCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    let create_info = &*p_create_info;
+    let extent = create_info.extent;
+    // Enough for the largest texels, and twice that for the mip chain
+    let texels = u64::from(extent.width)
+        * u64::from(extent.height)
+        * u64::from(extent.depth)
+        * u64::from(create_info.array_layers);
+    let mip_factor = if create_info.mip_levels > 1 { 2 } else { 1 };
+    *p_image = requirements_handle(vk::MemoryRequirements {
+        size: texels * 16 * mip_factor,
+        alignment: 4096,
+        // Only linear images can be host visible
+        memory_type_bits: if create_info.tiling == vk::ImageTiling::LINEAR {
+            0b11
+        } else {
+            0b01
+        },
+    });
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn destroy_image(
+    _: vk::Device,
+    image: vk::Image,
+    _: *const vk::AllocationCallbacks,
+) {
+    free_requirements_handle(image);
+}
+
+unsafe extern "system" fn get_image_memory_requirements(
+    _: vk::Device,
+    image: vk::Image,
+    p_requirements: *mut vk::MemoryRequirements,
+) {
+    *p_requirements = *(image.as_raw() as *const vk::MemoryRequirements);
+}
+
+extern "system" fn bind_image_memory(
+    _: vk::Device,
+    _: vk::Image,
+    _: vk::DeviceMemory,
+    _: vk::DeviceSize,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe fn new_handles<T: Handle>(count: u32, p_handles: *mut T) {
+    for handle in slice::from_raw_parts_mut(p_handles, count as usize) {
+        *handle = new_handle();
+    }
+}
+
+unsafe extern "system" fn create_graphics_pipelines(
+    _: vk::Device,
+    _: vk::PipelineCache,
+    count: u32,
+    _: *const vk::GraphicsPipelineCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    new_handles(count, p_pipelines);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_compute_pipelines(
+    _: vk::Device,
+    _: vk::PipelineCache,
+    count: u32,
+    _: *const vk::ComputePipelineCreateInfo,
+    _: *const vk::AllocationCallbacks,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    new_handles(count, p_pipelines);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_pipeline_cache_data(
+    _: vk::Device,
+    _: vk::PipelineCache,
+    p_data_size: *mut usize,
+    _: *mut c_void,
+) -> vk::Result {
+    *p_data_size = 0;
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn allocate_command_buffers(
+    _: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    new_handles((*p_allocate_info).command_buffer_count, p_command_buffers);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn free_command_buffers(
+    _: vk::Device,
+    _: vk::CommandPool,
+    _: u32,
+    _: *const vk::CommandBuffer,
+) {
+}
+
+extern "system" fn reset_command_pool(
+    _: vk::Device,
+    _: vk::CommandPool,
+    _: vk::CommandPoolResetFlags,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn allocate_descriptor_sets(
+    _: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    new_handles((*p_allocate_info).descriptor_set_count, p_descriptor_sets);
+    vk::Result::SUCCESS
+}
+
+extern "system" fn free_descriptor_sets(
+    _: vk::Device,
+    _: vk::DescriptorPool,
+    _: u32,
+    _: *const vk::DescriptorSet,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn reset_descriptor_pool(
+    _: vk::Device,
+    _: vk::DescriptorPool,
+    _: vk::DescriptorPoolResetFlags,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn update_descriptor_sets(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::WriteDescriptorSet,
+    _: u32,
+    _: *const vk::CopyDescriptorSet,
+) {
+}
+
+extern "system" fn wait_for_fences(
+    _: vk::Device,
+    _: u32,
+    _: *const vk::Fence,
+    _: vk::Bool32,
+    _: u64,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn reset_fences(_: vk::Device, _: u32, _: *const vk::Fence) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn get_fence_status(_: vk::Device, _: vk::Fence) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn begin_command_buffer(
+    _: vk::CommandBuffer,
+    _: *const vk::CommandBufferBeginInfo,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn end_command_buffer(_: vk::CommandBuffer) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+extern "system" fn reset_command_buffer(
+    _: vk::CommandBuffer,
+    _: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+/// Defines the commands that create objects without any state, and the ones that destroy them,
+/// and `object_proc_addr` for them. Unknown names fall back to `command_proc_addr`.
+macro_rules! stateless_objects {
+    ($($create_name:literal => $create:ident($create_info:ident),
+       $destroy_name:literal => $destroy:ident($handle:ident);)*) => {
+        $(
+            unsafe extern "system" fn $create(
+                _: vk::Device,
+                _: *const vk::$create_info,
+                _: *const vk::AllocationCallbacks,
+                p_handle: *mut vk::$handle,
+            ) -> vk::Result {
+                *p_handle = new_handle();
+                vk::Result::SUCCESS
+            }
+
+            extern "system" fn $destroy(
+                _: vk::Device,
+                _: vk::$handle,
+                _: *const vk::AllocationCallbacks,
+            ) {
+            }
+        )*
+
+        fn object_proc_addr(name: &[u8]) -> *const c_void {
+            match name {
+                $(
+                    $create_name => $create as *const c_void,
+                    $destroy_name => $destroy as *const c_void,
+                )*
+                _ => command_proc_addr(name),
+            }
+        }
+    };
+}
+
+stateless_objects! {
+    b"vkCreateBufferView" => create_buffer_view(BufferViewCreateInfo),
+    b"vkDestroyBufferView" => destroy_buffer_view(BufferView);
+    b"vkCreateCommandPool" => create_command_pool(CommandPoolCreateInfo),
+    b"vkDestroyCommandPool" => destroy_command_pool(CommandPool);
+    b"vkCreateDescriptorPool" => create_descriptor_pool(DescriptorPoolCreateInfo),
+    b"vkDestroyDescriptorPool" => destroy_descriptor_pool(DescriptorPool);
+    b"vkCreateDescriptorSetLayout" => create_descriptor_set_layout(DescriptorSetLayoutCreateInfo),
+    b"vkDestroyDescriptorSetLayout" => destroy_descriptor_set_layout(DescriptorSetLayout);
+    b"vkCreateEvent" => create_event(EventCreateInfo),
+    b"vkDestroyEvent" => destroy_event(Event);
+    b"vkCreateFence" => create_fence(FenceCreateInfo),
+    b"vkDestroyFence" => destroy_fence(Fence);
+    b"vkCreateFramebuffer" => create_framebuffer(FramebufferCreateInfo),
+    b"vkDestroyFramebuffer" => destroy_framebuffer(Framebuffer);
+    b"vkCreateImageView" => create_image_view(ImageViewCreateInfo),
+    b"vkDestroyImageView" => destroy_image_view(ImageView);
+    b"vkCreatePipelineCache" => create_pipeline_cache(PipelineCacheCreateInfo),
+    b"vkDestroyPipelineCache" => destroy_pipeline_cache(PipelineCache);
+    b"vkCreatePipelineLayout" => create_pipeline_layout(PipelineLayoutCreateInfo),
+    b"vkDestroyPipelineLayout" => destroy_pipeline_layout(PipelineLayout);
+    b"vkCreateQueryPool" => create_query_pool(QueryPoolCreateInfo),
+    b"vkDestroyQueryPool" => destroy_query_pool(QueryPool);
+    b"vkCreateRenderPass" => create_render_pass(RenderPassCreateInfo),
+    b"vkDestroyRenderPass" => destroy_render_pass(RenderPass);
+    b"vkCreateSampler" => create_sampler(SamplerCreateInfo),
+    b"vkDestroySampler" => destroy_sampler(Sampler);
+    b"vkCreateSemaphore" => create_semaphore(SemaphoreCreateInfo),
+    b"vkDestroySemaphore" => destroy_semaphore(Semaphore);
+    b"vkCreateShaderModule" => create_shader_module(ShaderModuleCreateInfo),
+    b"vkDestroyShaderModule" => destroy_shader_module(ShaderModule);
+}
+
+// `vkDestroyPipeline` has no create command of the same shape
+extern "system" fn destroy_pipeline(
+    _: vk::Device,
+    _: vk::Pipeline,
+    _: *const vk::AllocationCallbacks,
+) {
+}
+
+/// Defines commands that do nothing, and `command_proc_addr` for them.
+macro_rules! no_op_commands {
+    ($($name:literal => $command:ident($($param:ty),*);)*) => {
+        $(
+            extern "system" fn $command($(_: $param),*) {}
+        )*
+
+        fn command_proc_addr(name: &[u8]) -> *const c_void {
+            match name {
+                b"vkDestroyPipeline" => destroy_pipeline as *const c_void,
+                $($name => $command as *const c_void,)*
+                _ => ptr::null(),
+            }
+        }
+    };
+}
+
+no_op_commands! {
+    b"vkCmdBeginRenderPass" => cmd_begin_render_pass(
+        vk::CommandBuffer,
+        *const vk::RenderPassBeginInfo,
+        vk::SubpassContents
+    );
+    b"vkCmdNextSubpass" => cmd_next_subpass(vk::CommandBuffer, vk::SubpassContents);
+    b"vkCmdEndRenderPass" => cmd_end_render_pass(vk::CommandBuffer);
+    b"vkCmdBindPipeline" => cmd_bind_pipeline(
+        vk::CommandBuffer,
+        vk::PipelineBindPoint,
+        vk::Pipeline
+    );
+    b"vkCmdBindDescriptorSets" => cmd_bind_descriptor_sets(
+        vk::CommandBuffer,
+        vk::PipelineBindPoint,
+        vk::PipelineLayout,
+        u32,
+        u32,
+        *const vk::DescriptorSet,
+        u32,
+        *const u32
+    );
+    b"vkCmdBindVertexBuffers" => cmd_bind_vertex_buffers(
+        vk::CommandBuffer,
+        u32,
+        u32,
+        *const vk::Buffer,
+        *const vk::DeviceSize
+    );
+    b"vkCmdBindIndexBuffer" => cmd_bind_index_buffer(
+        vk::CommandBuffer,
+        vk::Buffer,
+        vk::DeviceSize,
+        vk::IndexType
+    );
+    b"vkCmdPushConstants" => cmd_push_constants(
+        vk::CommandBuffer,
+        vk::PipelineLayout,
+        vk::ShaderStageFlags,
+        u32,
+        u32,
+        *const c_void
+    );
+    b"vkCmdSetViewport" => cmd_set_viewport(vk::CommandBuffer, u32, u32, *const vk::Viewport);
+    b"vkCmdSetScissor" => cmd_set_scissor(vk::CommandBuffer, u32, u32, *const vk::Rect2D);
+    b"vkCmdDraw" => cmd_draw(vk::CommandBuffer, u32, u32, u32, u32);
+    b"vkCmdDrawIndexed" => cmd_draw_indexed(vk::CommandBuffer, u32, u32, u32, i32, u32);
+    b"vkCmdDispatch" => cmd_dispatch(vk::CommandBuffer, u32, u32, u32);
+    b"vkCmdCopyBuffer" => cmd_copy_buffer(
+        vk::CommandBuffer,
+        vk::Buffer,
+        vk::Buffer,
+        u32,
+        *const vk::BufferCopy
+    );
+    b"vkCmdCopyBufferToImage" => cmd_copy_buffer_to_image(
+        vk::CommandBuffer,
+        vk::Buffer,
+        vk::Image,
+        vk::ImageLayout,
+        u32,
+        *const vk::BufferImageCopy
+    );
+    b"vkCmdPipelineBarrier" => cmd_pipeline_barrier(
+        vk::CommandBuffer,
+        vk::PipelineStageFlags,
+        vk::PipelineStageFlags,
+        vk::DependencyFlags,
+        u32,
+        *const vk::MemoryBarrier,
+        u32,
+        *const vk::BufferMemoryBarrier,
+        u32,
+        *const vk::ImageMemoryBarrier
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{find_host_visible_memory_type_index, MappedMemory};
+    use crate::version::{DeviceV1_0, EntryV1_0, InstanceV1_0};
+
+    #[test]
+    fn create_and_map_buffer() {
+        let entry = entry();
+        unsafe {
+            let instance = entry
+                .create_instance(&vk::InstanceCreateInfo::default(), None)
+                .unwrap();
+            let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+            let properties = instance.get_physical_device_properties(physical_device);
+            assert_eq!(
+                CStr::from_ptr(properties.device_name.as_ptr()).to_bytes(),
+                b"ash null driver"
+            );
+            let queue_priorities = [1.0];
+            let queue_info = vk::DeviceQueueCreateInfo::builder()
+                .queue_family_index(0)
+                .queue_priorities(&queue_priorities);
+            let queue_infos = [queue_info.build()];
+            let device_info = vk::DeviceCreateInfo::builder().queue_create_infos(&queue_infos);
+            let device = instance
+                .create_device(physical_device, &device_info, None)
+                .unwrap();
+            assert_eq!(device.get_device_queue(0, 0), device.get_device_queue(0, 0));
+
+            let buffer_info = vk::BufferCreateInfo::builder()
+                .size(100)
+                .usage(vk::BufferUsageFlags::TRANSFER_SRC);
+            let buffer = device.create_buffer(&buffer_info, None).unwrap();
+            let requirements = device.get_buffer_memory_requirements(buffer);
+            assert_eq!(requirements.size, 256);
+            let memory_type_index = find_host_visible_memory_type_index(
+                &instance.get_physical_device_memory_properties(physical_device),
+                requirements.memory_type_bits,
+            )
+            .unwrap();
+            let allocate_info = vk::MemoryAllocateInfo::builder()
+                .allocation_size(requirements.size)
+                .memory_type_index(memory_type_index);
+            let memory = device.allocate_memory(&allocate_info, None).unwrap();
+            device.bind_buffer_memory(buffer, memory, 0).unwrap();
+            {
+                let mut mapping = MappedMemory::map(&device, memory, 0, 256, 64).unwrap();
+                mapping.write(&[1u32, 2, 3]);
+                assert_eq!(&mapping.as_bytes()[4..8], &2u32.to_ne_bytes());
+            }
+            device.destroy_buffer(buffer, None);
+            device.free_memory(memory, None);
+            device.destroy_device(None);
+            instance.destroy_instance(None);
+        }
+    }
+}
@@ -0,0 +1,856 @@
+#![doc = r" Handles that carry a reference to their [`Device`] and destroy themselves when dropped,"]
+#![doc = r" for scope-based cleanup. The wrappers only cover the handles that a core version of"]
+#![doc = r" Vulkan destroys on their own, and don't support allocation callbacks."]
+use crate::version::{DeviceV1_0, DeviceV1_1};
+use crate::vk;
+use crate::Device;
+use std::fmt;
+use std::mem;
+#[doc = "A [`vk::Buffer`] that is destroyed with `destroy_buffer` when dropped."]
+pub struct OwnedBuffer<'a> {
+    device: &'a Device,
+    handle: vk::Buffer,
+}
+impl<'a> OwnedBuffer<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Buffer) -> Self {
+        OwnedBuffer { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Buffer {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Buffer {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedBuffer<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_buffer(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedBuffer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedBuffer))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::BufferView`] that is destroyed with `destroy_buffer_view` when dropped."]
+pub struct OwnedBufferView<'a> {
+    device: &'a Device,
+    handle: vk::BufferView,
+}
+impl<'a> OwnedBufferView<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::BufferView) -> Self {
+        OwnedBufferView { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::BufferView {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::BufferView {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedBufferView<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_buffer_view(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedBufferView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedBufferView))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::CommandPool`] that is destroyed with `destroy_command_pool` when dropped."]
+pub struct OwnedCommandPool<'a> {
+    device: &'a Device,
+    handle: vk::CommandPool,
+}
+impl<'a> OwnedCommandPool<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::CommandPool) -> Self {
+        OwnedCommandPool { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::CommandPool {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::CommandPool {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedCommandPool<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_command_pool(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedCommandPool<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedCommandPool))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::DescriptorPool`] that is destroyed with `destroy_descriptor_pool` when dropped."]
+pub struct OwnedDescriptorPool<'a> {
+    device: &'a Device,
+    handle: vk::DescriptorPool,
+}
+impl<'a> OwnedDescriptorPool<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::DescriptorPool) -> Self {
+        OwnedDescriptorPool { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::DescriptorPool {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::DescriptorPool {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedDescriptorPool<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_descriptor_pool(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedDescriptorPool<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedDescriptorPool))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::DescriptorSetLayout`] that is destroyed with `destroy_descriptor_set_layout` when dropped."]
+pub struct OwnedDescriptorSetLayout<'a> {
+    device: &'a Device,
+    handle: vk::DescriptorSetLayout,
+}
+impl<'a> OwnedDescriptorSetLayout<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::DescriptorSetLayout) -> Self {
+        OwnedDescriptorSetLayout { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::DescriptorSetLayout {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::DescriptorSetLayout {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedDescriptorSetLayout<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_descriptor_set_layout(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedDescriptorSetLayout<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedDescriptorSetLayout))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::DescriptorUpdateTemplate`] that is destroyed with `destroy_descriptor_update_template` when dropped."]
+pub struct OwnedDescriptorUpdateTemplate<'a> {
+    device: &'a Device,
+    handle: vk::DescriptorUpdateTemplate,
+}
+impl<'a> OwnedDescriptorUpdateTemplate<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::DescriptorUpdateTemplate) -> Self {
+        OwnedDescriptorUpdateTemplate { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::DescriptorUpdateTemplate {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::DescriptorUpdateTemplate {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedDescriptorUpdateTemplate<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.device
+                .destroy_descriptor_update_template(self.handle, None)
+        };
+    }
+}
+impl<'a> fmt::Debug for OwnedDescriptorUpdateTemplate<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedDescriptorUpdateTemplate))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::DeviceMemory`] that is destroyed with `free_memory` when dropped."]
+pub struct OwnedDeviceMemory<'a> {
+    device: &'a Device,
+    handle: vk::DeviceMemory,
+}
+impl<'a> OwnedDeviceMemory<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::DeviceMemory) -> Self {
+        OwnedDeviceMemory { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::DeviceMemory {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::DeviceMemory {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedDeviceMemory<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.free_memory(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedDeviceMemory<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedDeviceMemory))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Event`] that is destroyed with `destroy_event` when dropped."]
+pub struct OwnedEvent<'a> {
+    device: &'a Device,
+    handle: vk::Event,
+}
+impl<'a> OwnedEvent<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Event) -> Self {
+        OwnedEvent { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Event {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Event {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedEvent<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_event(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedEvent<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedEvent))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Fence`] that is destroyed with `destroy_fence` when dropped."]
+pub struct OwnedFence<'a> {
+    device: &'a Device,
+    handle: vk::Fence,
+}
+impl<'a> OwnedFence<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Fence) -> Self {
+        OwnedFence { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Fence {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Fence {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedFence<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_fence(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedFence<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedFence))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Framebuffer`] that is destroyed with `destroy_framebuffer` when dropped."]
+pub struct OwnedFramebuffer<'a> {
+    device: &'a Device,
+    handle: vk::Framebuffer,
+}
+impl<'a> OwnedFramebuffer<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Framebuffer) -> Self {
+        OwnedFramebuffer { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Framebuffer {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Framebuffer {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedFramebuffer<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_framebuffer(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedFramebuffer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedFramebuffer))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Image`] that is destroyed with `destroy_image` when dropped."]
+pub struct OwnedImage<'a> {
+    device: &'a Device,
+    handle: vk::Image,
+}
+impl<'a> OwnedImage<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Image) -> Self {
+        OwnedImage { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Image {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Image {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedImage<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_image(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedImage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedImage))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::ImageView`] that is destroyed with `destroy_image_view` when dropped."]
+pub struct OwnedImageView<'a> {
+    device: &'a Device,
+    handle: vk::ImageView,
+}
+impl<'a> OwnedImageView<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::ImageView) -> Self {
+        OwnedImageView { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::ImageView {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::ImageView {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedImageView<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_image_view(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedImageView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedImageView))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Pipeline`] that is destroyed with `destroy_pipeline` when dropped."]
+pub struct OwnedPipeline<'a> {
+    device: &'a Device,
+    handle: vk::Pipeline,
+}
+impl<'a> OwnedPipeline<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Pipeline) -> Self {
+        OwnedPipeline { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Pipeline {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Pipeline {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedPipeline<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_pipeline(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedPipeline<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedPipeline))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::PipelineCache`] that is destroyed with `destroy_pipeline_cache` when dropped."]
+pub struct OwnedPipelineCache<'a> {
+    device: &'a Device,
+    handle: vk::PipelineCache,
+}
+impl<'a> OwnedPipelineCache<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::PipelineCache) -> Self {
+        OwnedPipelineCache { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::PipelineCache {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::PipelineCache {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedPipelineCache<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_pipeline_cache(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedPipelineCache<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedPipelineCache))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::PipelineLayout`] that is destroyed with `destroy_pipeline_layout` when dropped."]
+pub struct OwnedPipelineLayout<'a> {
+    device: &'a Device,
+    handle: vk::PipelineLayout,
+}
+impl<'a> OwnedPipelineLayout<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::PipelineLayout) -> Self {
+        OwnedPipelineLayout { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::PipelineLayout {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::PipelineLayout {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedPipelineLayout<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_pipeline_layout(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedPipelineLayout<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedPipelineLayout))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::QueryPool`] that is destroyed with `destroy_query_pool` when dropped."]
+pub struct OwnedQueryPool<'a> {
+    device: &'a Device,
+    handle: vk::QueryPool,
+}
+impl<'a> OwnedQueryPool<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::QueryPool) -> Self {
+        OwnedQueryPool { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::QueryPool {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::QueryPool {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedQueryPool<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_query_pool(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedQueryPool<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedQueryPool))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::RenderPass`] that is destroyed with `destroy_render_pass` when dropped."]
+pub struct OwnedRenderPass<'a> {
+    device: &'a Device,
+    handle: vk::RenderPass,
+}
+impl<'a> OwnedRenderPass<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::RenderPass) -> Self {
+        OwnedRenderPass { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::RenderPass {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::RenderPass {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedRenderPass<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_render_pass(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedRenderPass<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedRenderPass))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Sampler`] that is destroyed with `destroy_sampler` when dropped."]
+pub struct OwnedSampler<'a> {
+    device: &'a Device,
+    handle: vk::Sampler,
+}
+impl<'a> OwnedSampler<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Sampler) -> Self {
+        OwnedSampler { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Sampler {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Sampler {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedSampler<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_sampler(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedSampler<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedSampler))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::SamplerYcbcrConversion`] that is destroyed with `destroy_sampler_ycbcr_conversion` when dropped."]
+pub struct OwnedSamplerYcbcrConversion<'a> {
+    device: &'a Device,
+    handle: vk::SamplerYcbcrConversion,
+}
+impl<'a> OwnedSamplerYcbcrConversion<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::SamplerYcbcrConversion) -> Self {
+        OwnedSamplerYcbcrConversion { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::SamplerYcbcrConversion {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::SamplerYcbcrConversion {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedSamplerYcbcrConversion<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.device
+                .destroy_sampler_ycbcr_conversion(self.handle, None)
+        };
+    }
+}
+impl<'a> fmt::Debug for OwnedSamplerYcbcrConversion<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedSamplerYcbcrConversion))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::Semaphore`] that is destroyed with `destroy_semaphore` when dropped."]
+pub struct OwnedSemaphore<'a> {
+    device: &'a Device,
+    handle: vk::Semaphore,
+}
+impl<'a> OwnedSemaphore<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::Semaphore) -> Self {
+        OwnedSemaphore { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::Semaphore {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::Semaphore {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedSemaphore<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_semaphore(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedSemaphore<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedSemaphore))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[doc = "A [`vk::ShaderModule`] that is destroyed with `destroy_shader_module` when dropped."]
+pub struct OwnedShaderModule<'a> {
+    device: &'a Device,
+    handle: vk::ShaderModule,
+}
+impl<'a> OwnedShaderModule<'a> {
+    #[doc = r" Takes ownership of `handle`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `handle` must be created from `device` without allocation callbacks, and must"]
+    #[doc = r" not be destroyed elsewhere."]
+    pub unsafe fn new(device: &'a Device, handle: vk::ShaderModule) -> Self {
+        OwnedShaderModule { device, handle }
+    }
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+    pub fn handle(&self) -> vk::ShaderModule {
+        self.handle
+    }
+    #[doc = r" Gives up ownership of the handle without destroying it."]
+    pub fn into_raw(self) -> vk::ShaderModule {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+impl<'a> Drop for OwnedShaderModule<'a> {
+    fn drop(&mut self) {
+        unsafe { self.device.destroy_shader_module(self.handle, None) };
+    }
+}
+impl<'a> fmt::Debug for OwnedShaderModule<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple(stringify!(OwnedShaderModule))
+            .field(&self.handle)
+            .finish()
+    }
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn destroy_on_drop() {
+    use std::os::raw::c_void;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use vk::Handle;
+
+    static DESTROYED: AtomicU64 = AtomicU64::new(0);
+    extern "system" fn destroy_buffer(
+        _: vk::Device,
+        buffer: vk::Buffer,
+        _: *const vk::AllocationCallbacks,
+    ) {
+        DESTROYED.store(buffer.as_raw(), Ordering::SeqCst);
+    }
+
+    let device = unsafe {
+        Device::load_with(vk::Device::null(), |name| match name.to_bytes() {
+            b"vkDestroyBuffer" => destroy_buffer as *const c_void,
+            _ => std::ptr::null(),
+        })
+    };
+    let kept = unsafe { OwnedBuffer::new(&device, vk::Buffer::from_raw(1)) };
+    assert_eq!(kept.into_raw(), vk::Buffer::from_raw(1));
+    let buffer = unsafe { OwnedBuffer::new(&device, vk::Buffer::from_raw(2)) };
+    assert_eq!(buffer.handle(), vk::Buffer::from_raw(2));
+    assert_eq!(DESTROYED.load(Ordering::SeqCst), 0);
+    drop(buffer);
+    assert_eq!(DESTROYED.load(Ordering::SeqCst), 2);
+}
@@ -1,4 +1,7 @@
 use crate::vk;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
 pub type VkResult<T> = Result<T, vk::Result>;
 
 impl From<vk::Result> for VkResult<()> {
@@ -19,3 +22,180 @@ impl vk::Result {
         }
     }
 }
+
+/// A `vk::Result` together with a description of what failed, see [`ResultExt`].
+///
+/// This implements `std::error::Error + Send + Sync` with the `vk::Result` as its source, so it
+/// converts into `anyhow::Error` or `Box<dyn Error>` with `?` and can be wrapped by `thiserror`
+/// error enums with `#[from]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContextError {
+    pub context: Cow<'static, str>,
+    pub result: vk::Result,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.result)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.result)
+    }
+}
+
+/// Adds context to the errors of `VkResult`.
+///
+/// ```
+/// use ash::prelude::{ResultExt, VkResult};
+/// use ash::vk;
+///
+/// let result: VkResult<()> = Err(vk::Result::ERROR_OUT_OF_DATE_KHR);
+/// let err = result.context_vk("creating swapchain").unwrap_err();
+/// assert_eq!(err.result, vk::Result::ERROR_OUT_OF_DATE_KHR);
+/// assert!(err.to_string().starts_with("creating swapchain: "));
+/// ```
+pub trait ResultExt<T> {
+    fn context_vk(self, context: &'static str) -> Result<T, ContextError>;
+
+    /// Like [`ResultExt::context_vk`], but only builds the context if there is an error.
+    fn with_context_vk<F: FnOnce() -> String>(self, f: F) -> Result<T, ContextError>;
+}
+
+impl<T> ResultExt<T> for VkResult<T> {
+    fn context_vk(self, context: &'static str) -> Result<T, ContextError> {
+        self.map_err(|result| ContextError {
+            context: Cow::Borrowed(context),
+            result,
+        })
+    }
+
+    fn with_context_vk<F: FnOnce() -> String>(self, f: F) -> Result<T, ContextError> {
+        self.map_err(|result| ContextError {
+            context: Cow::Owned(f()),
+            result,
+        })
+    }
+}
+
+/// Describes the error of a `VkResult` with the name and description of its code from the
+/// registry, which need the `debug-strings` feature.
+///
+/// ```
+/// use ash::prelude::{VkResult, VkResultExt};
+/// use ash::vk;
+///
+/// let result: VkResult<()> = Err(vk::Result::ERROR_SURFACE_LOST_KHR);
+/// if cfg!(feature = "debug-strings") {
+///     assert_eq!(result.describe(), Some("A surface is no longer available"));
+/// } else {
+///     assert_eq!(result.describe(), None);
+/// }
+/// ```
+pub trait VkResultExt<T> {
+    /// The description of the error code, `None` on success.
+    fn describe(&self) -> Option<&'static str>;
+
+    /// Returns the value, or panics with a message like `vkCreateSwapchainKHR failed:
+    /// VK_ERROR_SURFACE_LOST_KHR (A surface is no longer available)`.
+    fn expect_success(self, context: &str) -> T;
+}
+
+impl<T> VkResultExt<T> for VkResult<T> {
+    fn describe(&self) -> Option<&'static str> {
+        self.as_ref().err().and_then(|result| result.describe())
+    }
+
+    fn expect_success(self, context: &str) -> T {
+        match self {
+            Ok(value) => value,
+            Err(result) => {
+                let name = result
+                    .name()
+                    .map_or_else(|| format!("{:?}", result), str::to_owned);
+                match result.describe() {
+                    Some(description) => {
+                        panic!("{} failed: {} ({})", context, name, description)
+                    }
+                    None => panic!("{} failed: {}", context, name),
+                }
+            }
+        }
+    }
+}
+
+/// The pipelines of a batched `vkCreate*Pipelines` call, together with its result.
+///
+/// The command can fail for some create infos while the others succeed, in which case the
+/// failed pipelines are `vk::Pipeline::null()` and the others must still be used or destroyed.
+///
+/// ```
+/// use ash::prelude::PipelineBatch;
+/// use ash::vk::{self, Handle};
+///
+/// let batch = PipelineBatch {
+///     pipelines: vec![vk::Pipeline::from_raw(1), vk::Pipeline::null()],
+///     result: vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+/// };
+/// assert_eq!(batch.created().collect::<Vec<_>>(), [(0, vk::Pipeline::from_raw(1))]);
+/// assert_eq!(batch.failed().collect::<Vec<_>>(), [1]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PipelineBatch {
+    /// One pipeline per create info, in the same order.
+    pub pipelines: Vec<vk::Pipeline>,
+    pub result: vk::Result,
+}
+
+impl PipelineBatch {
+    /// The indices and handles of the pipelines that were created.
+    pub fn created(&self) -> impl Iterator<Item = (usize, vk::Pipeline)> + '_ {
+        self.pipelines
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, pipeline)| pipeline != vk::Pipeline::null())
+    }
+
+    /// The indices of the create infos whose pipeline wasn't created.
+    pub fn failed(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pipelines
+            .iter()
+            .enumerate()
+            .filter(|&(_, &pipeline)| pipeline == vk::Pipeline::null())
+            .map(|(index, _)| index)
+    }
+
+    /// `Ok` with all the pipelines on `SUCCESS`, or `Err` with the pipelines that were created
+    /// and the result otherwise.
+    pub fn into_result(self) -> Result<Vec<vk::Pipeline>, (Vec<vk::Pipeline>, vk::Result)> {
+        match self.result {
+            vk::Result::SUCCESS => Ok(self.pipelines),
+            _ => Err((self.pipelines, self.result)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "debug-strings"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(
+        expected = "vkCreateSwapchainKHR failed: VK_ERROR_SURFACE_LOST_KHR (A surface is no longer available)"
+    )]
+    fn expect_success() {
+        assert_eq!(
+            vk::Result::ERROR_OUT_OF_POOL_MEMORY.name(),
+            Some("VK_ERROR_OUT_OF_POOL_MEMORY")
+        );
+        assert_eq!(vk::Result::from_raw(-1_000_999_000).describe(), None);
+        let ok: VkResult<u32> = Ok(3);
+        assert_eq!(ok.describe(), None);
+        assert_eq!(ok.expect_success("vkAcquireNextImageKHR"), 3);
+        let lost: VkResult<u32> = Err(vk::Result::ERROR_SURFACE_LOST_KHR);
+        lost.expect_success("vkCreateSwapchainKHR");
+    }
+}
@@ -0,0 +1,179 @@
+//! A thin layer over recording command buffers, which ends what it begins: the command buffer,
+//! render passes and debug label regions are scoped to closures.
+//!
+//! ```no_run
+//! use ash::recording::CommandRecorder;
+//! use ash::version::DeviceV1_0;
+//! use ash::vk;
+//! # unsafe fn record(
+//! #     device: &ash::Device,
+//! #     command_buffer: vk::CommandBuffer,
+//! #     render_pass_begin_info: &vk::RenderPassBeginInfo,
+//! # ) -> ash::prelude::VkResult<()> {
+//! let begin_info = vk::CommandBufferBeginInfo::builder()
+//!     .flags(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT);
+//! CommandRecorder::new(device, command_buffer).record(&begin_info, |recorder| {
+//!     recorder.render_pass(
+//!         render_pass_begin_info,
+//!         vk::SubpassContents::INLINE,
+//!         |recorder| {
+//!             device.cmd_draw(recorder.command_buffer(), 3, 1, 0, 0);
+//!         },
+//!     );
+//! })
+//! # }
+//! ```
+use crate::extensions::ext::DebugUtils;
+use crate::prelude::*;
+use crate::version::DeviceV1_0;
+use crate::vk;
+use crate::Device;
+use std::ffi::CStr;
+
+/// Records into a command buffer with scoped render passes and debug labels. The commands
+/// themselves are recorded through [`CommandRecorder::device`] with
+/// [`CommandRecorder::command_buffer`].
+#[derive(Clone, Copy)]
+pub struct CommandRecorder<'a> {
+    device: &'a Device,
+    debug_utils: Option<&'a DebugUtils>,
+    command_buffer: vk::CommandBuffer,
+}
+
+impl<'a> CommandRecorder<'a> {
+    pub fn new(device: &'a Device, command_buffer: vk::CommandBuffer) -> Self {
+        CommandRecorder {
+            device,
+            debug_utils: None,
+            command_buffer,
+        }
+    }
+
+    /// Opens the regions of [`CommandRecorder::label`] with `debug_utils`. Without it the
+    /// labels are skipped, so they can be left in release builds without `VK_EXT_debug_utils`.
+    pub fn with_debug_utils(mut self, debug_utils: &'a DebugUtils) -> Self {
+        self.debug_utils = Some(debug_utils);
+        self
+    }
+
+    pub fn device(&self) -> &'a Device {
+        self.device
+    }
+
+    pub fn command_buffer(&self) -> vk::CommandBuffer {
+        self.command_buffer
+    }
+
+    /// Begins the command buffer, records `f` and ends the command buffer. `f` isn't called if
+    /// the command buffer can't be begun.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkBeginCommandBuffer.html>"]
+    pub unsafe fn record<R, F: FnOnce(&mut Self) -> R>(
+        &mut self,
+        begin_info: &vk::CommandBufferBeginInfo,
+        f: F,
+    ) -> VkResult<R> {
+        self.device
+            .begin_command_buffer(self.command_buffer, begin_info)?;
+        let value = f(self);
+        self.device.end_command_buffer(self.command_buffer)?;
+        Ok(value)
+    }
+
+    /// Begins a render pass, records `f` and ends the render pass.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginRenderPass.html>"]
+    pub unsafe fn render_pass<R, F: FnOnce(&mut Self) -> R>(
+        &mut self,
+        begin_info: &vk::RenderPassBeginInfo,
+        contents: vk::SubpassContents,
+        f: F,
+    ) -> R {
+        self.device
+            .cmd_begin_render_pass(self.command_buffer, begin_info, contents);
+        let value = f(self);
+        self.device.cmd_end_render_pass(self.command_buffer);
+        value
+    }
+
+    /// Moves to the next subpass of the render pass opened by [`CommandRecorder::render_pass`].
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdNextSubpass.html>"]
+    pub unsafe fn next_subpass(&mut self, contents: vk::SubpassContents) {
+        self.device.cmd_next_subpass(self.command_buffer, contents);
+    }
+
+    /// Records `f` in a debug label region named `name`, see
+    /// [`DebugUtils::cmd_debug_label_scope`]. Only records `f` without
+    /// [`CommandRecorder::with_debug_utils`].
+    pub unsafe fn label<R, F: FnOnce(&mut Self) -> R>(
+        &mut self,
+        name: &CStr,
+        color: [f32; 4],
+        f: F,
+    ) -> R {
+        let _label = self
+            .debug_utils
+            .map(|debug_utils| debug_utils.cmd_debug_label_scope(self.command_buffer, name, color));
+        f(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_scopes() {
+        use std::os::raw::c_void;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Every recorded command appends its digit
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn call(digit: usize) {
+            CALLS.store(CALLS.load(Ordering::SeqCst) * 10 + digit, Ordering::SeqCst);
+        }
+        extern "system" fn begin_command_buffer(
+            _: vk::CommandBuffer,
+            _: *const vk::CommandBufferBeginInfo,
+        ) -> vk::Result {
+            call(1);
+            vk::Result::SUCCESS
+        }
+        extern "system" fn cmd_begin_render_pass(
+            _: vk::CommandBuffer,
+            _: *const vk::RenderPassBeginInfo,
+            _: vk::SubpassContents,
+        ) {
+            call(2);
+        }
+        extern "system" fn cmd_end_render_pass(_: vk::CommandBuffer) {
+            call(3);
+        }
+        extern "system" fn end_command_buffer(_: vk::CommandBuffer) -> vk::Result {
+            call(4);
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY
+        }
+
+        let device = unsafe {
+            Device::load_with(vk::Device::null(), |name| match name.to_bytes() {
+                b"vkBeginCommandBuffer" => begin_command_buffer as *const c_void,
+                b"vkCmdBeginRenderPass" => cmd_begin_render_pass as *const c_void,
+                b"vkCmdEndRenderPass" => cmd_end_render_pass as *const c_void,
+                b"vkEndCommandBuffer" => end_command_buffer as *const c_void,
+                _ => std::ptr::null(),
+            })
+        };
+        let mut recorder = CommandRecorder::new(&device, vk::CommandBuffer::null());
+        let result = unsafe {
+            recorder.record(&vk::CommandBufferBeginInfo::default(), |recorder| {
+                recorder.label(Default::default(), [0.0; 4], |recorder| {
+                    recorder.render_pass(
+                        &vk::RenderPassBeginInfo::default(),
+                        vk::SubpassContents::INLINE,
+                        |_| 5,
+                    )
+                })
+            })
+        };
+        assert_eq!(result, Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1234);
+    }
+}
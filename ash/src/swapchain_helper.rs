@@ -0,0 +1,492 @@
+//! A swapchain that picks its format, present mode and extent from the surface, owns the views
+//! of its images and the synchronization of the frames in flight, and recreates itself when it
+//! is out of date or suboptimal.
+//!
+//! ```no_run
+//! use ash::swapchain_helper::SwapchainHelper;
+//! use ash::version::DeviceV1_0;
+//! use ash::vk;
+//! # unsafe fn frame(
+//! #     helper: &mut SwapchainHelper,
+//! #     device: &ash::Device,
+//! #     queue: vk::Queue,
+//! #     command_buffer: vk::CommandBuffer,
+//! #     window_extent: vk::Extent2D,
+//! # ) -> ash::prelude::VkResult<()> {
+//! let frame = match helper.acquire(window_extent)? {
+//!     Some(frame) => frame,
+//!     // The swapchain was recreated, try again with the next window event
+//!     None => return Ok(()),
+//! };
+//! // Record `command_buffer` for `frame.image_view`...
+//! let wait_semaphores = [frame.image_available];
+//! let wait_stages = [vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT];
+//! let command_buffers = [command_buffer];
+//! let signal_semaphores = [frame.render_finished];
+//! let submit_info = vk::SubmitInfo::builder()
+//!     .wait_semaphores(&wait_semaphores)
+//!     .wait_dst_stage_mask(&wait_stages)
+//!     .command_buffers(&command_buffers)
+//!     .signal_semaphores(&signal_semaphores);
+//! device.queue_submit(queue, &[submit_info.build()], frame.in_flight)?;
+//! helper.present(queue, &frame, window_extent)
+//! # }
+//! ```
+use crate::extensions::khr::{Surface, Swapchain};
+use crate::prelude::*;
+use crate::version::DeviceV1_0;
+use crate::vk;
+use crate::Device;
+
+/// Returns the first of `preferred` that the surface supports, or the first supported format.
+/// A surface that only reports `UNDEFINED` supports any format, in which case the first
+/// preferred format is picked.
+pub fn choose_surface_format(
+    available: &[vk::SurfaceFormatKHR],
+    preferred: &[vk::SurfaceFormatKHR],
+) -> Option<vk::SurfaceFormatKHR> {
+    match available {
+        [format] if format.format == vk::Format::UNDEFINED => preferred.first().copied(),
+        _ => preferred
+            .iter()
+            .find(|format| available.contains(format))
+            .or_else(|| available.first())
+            .copied(),
+    }
+}
+
+/// Returns the first of `preferred` that the surface supports, or `FIFO`, which every surface
+/// supports.
+pub fn choose_present_mode(
+    available: &[vk::PresentModeKHR],
+    preferred: &[vk::PresentModeKHR],
+) -> vk::PresentModeKHR {
+    preferred
+        .iter()
+        .copied()
+        .find(|mode| available.contains(mode))
+        .unwrap_or(vk::PresentModeKHR::FIFO)
+}
+
+/// Returns the extent of the surface, or `window_extent` clamped to the supported extents when
+/// the surface leaves it to the swapchain.
+pub fn choose_extent(
+    capabilities: &vk::SurfaceCapabilitiesKHR,
+    window_extent: vk::Extent2D,
+) -> vk::Extent2D {
+    if capabilities.current_extent.width != u32::MAX {
+        return capabilities.current_extent;
+    }
+    let (min, max) = (capabilities.min_image_extent, capabilities.max_image_extent);
+    vk::Extent2D {
+        width: window_extent.width.max(min.width).min(max.width),
+        height: window_extent.height.max(min.height).min(max.height),
+    }
+}
+
+/// Returns `desired` clamped to the supported image counts, where a `max_image_count` of 0
+/// means that there is no maximum.
+pub fn choose_image_count(capabilities: &vk::SurfaceCapabilitiesKHR, desired: u32) -> u32 {
+    let count = desired.max(capabilities.min_image_count);
+    match capabilities.max_image_count {
+        0 => count,
+        max => count.min(max),
+    }
+}
+
+/// How [`SwapchainHelper`] creates the swapchain.
+#[derive(Clone, Debug)]
+pub struct SwapchainConfig {
+    /// Tried in order, see [`choose_surface_format`].
+    pub preferred_formats: Vec<vk::SurfaceFormatKHR>,
+    /// Tried in order, see [`choose_present_mode`].
+    pub preferred_present_modes: Vec<vk::PresentModeKHR>,
+    pub image_usage: vk::ImageUsageFlags,
+    /// Clamped to the supported image counts, see [`choose_image_count`].
+    pub image_count: u32,
+    /// The number of frames that are recorded while earlier ones are still rendered.
+    pub frames_in_flight: usize,
+}
+
+impl Default for SwapchainConfig {
+    fn default() -> Self {
+        SwapchainConfig {
+            preferred_formats: vec![
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::B8G8R8A8_SRGB,
+                    color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+                },
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::R8G8B8A8_SRGB,
+                    color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+                },
+            ],
+            preferred_present_modes: vec![vk::PresentModeKHR::MAILBOX],
+            image_usage: vk::ImageUsageFlags::COLOR_ATTACHMENT,
+            image_count: 3,
+            frames_in_flight: 2,
+        }
+    }
+}
+
+/// An image acquired by [`SwapchainHelper::acquire`], to be rendered and handed to
+/// [`SwapchainHelper::present`].
+#[derive(Clone, Copy, Debug)]
+pub struct AcquiredImage {
+    pub image_index: u32,
+    pub image: vk::Image,
+    pub image_view: vk::ImageView,
+    /// Signaled when the image can be rendered to, the submission must wait for it.
+    pub image_available: vk::Semaphore,
+    /// Must be signaled by the submission, presenting waits for it.
+    pub render_finished: vk::Semaphore,
+    /// Must be signaled by the submission, the frame is reused once it is.
+    pub in_flight: vk::Fence,
+    /// The swapchain no longer matches the surface exactly, it is recreated after presenting.
+    pub suboptimal: bool,
+}
+
+#[derive(Clone, Copy)]
+struct FrameSync {
+    image_available: vk::Semaphore,
+    in_flight: vk::Fence,
+}
+
+/// Manages a swapchain of [`Swapchain`], see the [module docs](self).
+///
+/// Nothing is destroyed on drop, [`SwapchainHelper::destroy`] must be called before the device
+/// and the surface are destroyed.
+pub struct SwapchainHelper {
+    device: Device,
+    surface_loader: Surface,
+    swapchain_loader: Swapchain,
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    config: SwapchainConfig,
+    swapchain: vk::SwapchainKHR,
+    format: vk::SurfaceFormatKHR,
+    present_mode: vk::PresentModeKHR,
+    extent: vk::Extent2D,
+    images: Vec<vk::Image>,
+    image_views: Vec<vk::ImageView>,
+    render_finished: Vec<vk::Semaphore>,
+    frames: Vec<FrameSync>,
+    frame: usize,
+}
+
+impl SwapchainHelper {
+    /// Creates the swapchain for `surface`, whose size is `window_extent` if the surface doesn't
+    /// report one.
+    pub unsafe fn new(
+        device: &Device,
+        surface_loader: &Surface,
+        swapchain_loader: &Swapchain,
+        physical_device: vk::PhysicalDevice,
+        surface: vk::SurfaceKHR,
+        window_extent: vk::Extent2D,
+        config: SwapchainConfig,
+    ) -> VkResult<Self> {
+        let mut helper = SwapchainHelper {
+            device: device.clone(),
+            surface_loader: surface_loader.clone(),
+            swapchain_loader: swapchain_loader.clone(),
+            physical_device,
+            surface,
+            config,
+            swapchain: vk::SwapchainKHR::null(),
+            format: vk::SurfaceFormatKHR::default(),
+            present_mode: vk::PresentModeKHR::FIFO,
+            extent: window_extent,
+            images: Vec::new(),
+            image_views: Vec::new(),
+            render_finished: Vec::new(),
+            frames: Vec::new(),
+            frame: 0,
+        };
+        for _ in 0..helper.config.frames_in_flight.max(1) {
+            let frame = FrameSync {
+                image_available: helper
+                    .device
+                    .create_semaphore(&vk::SemaphoreCreateInfo::default(), None)?,
+                in_flight: helper.device.create_fence(
+                    &vk::FenceCreateInfo::builder().flags(vk::FenceCreateFlags::SIGNALED),
+                    None,
+                )?,
+            };
+            helper.frames.push(frame);
+        }
+        helper.create_swapchain(window_extent)?;
+        Ok(helper)
+    }
+
+    unsafe fn create_swapchain(&mut self, window_extent: vk::Extent2D) -> VkResult<()> {
+        let capabilities = self
+            .surface_loader
+            .get_physical_device_surface_capabilities(self.physical_device, self.surface)?;
+        let formats = self
+            .surface_loader
+            .get_physical_device_surface_formats(self.physical_device, self.surface)?;
+        let present_modes = self
+            .surface_loader
+            .get_physical_device_surface_present_modes(self.physical_device, self.surface)?;
+        self.format = choose_surface_format(&formats, &self.config.preferred_formats)
+            .ok_or(vk::Result::ERROR_FORMAT_NOT_SUPPORTED)?;
+        self.present_mode =
+            choose_present_mode(&present_modes, &self.config.preferred_present_modes);
+        self.extent = choose_extent(&capabilities, window_extent);
+        let composite_alpha = [
+            vk::CompositeAlphaFlagsKHR::OPAQUE,
+            vk::CompositeAlphaFlagsKHR::PRE_MULTIPLIED,
+            vk::CompositeAlphaFlagsKHR::POST_MULTIPLIED,
+            vk::CompositeAlphaFlagsKHR::INHERIT,
+        ]
+        .iter()
+        .copied()
+        .find(|&alpha| capabilities.supported_composite_alpha.contains(alpha))
+        .unwrap_or(vk::CompositeAlphaFlagsKHR::OPAQUE);
+
+        let old_swapchain = self.swapchain;
+        let create_info = vk::SwapchainCreateInfoKHR::builder()
+            .surface(self.surface)
+            .min_image_count(choose_image_count(&capabilities, self.config.image_count))
+            .image_format(self.format.format)
+            .image_color_space(self.format.color_space)
+            .image_extent(self.extent)
+            .image_array_layers(1)
+            .image_usage(self.config.image_usage)
+            .image_sharing_mode(vk::SharingMode::EXCLUSIVE)
+            .pre_transform(capabilities.current_transform)
+            .composite_alpha(composite_alpha)
+            .present_mode(self.present_mode)
+            .clipped(true)
+            .old_swapchain(old_swapchain);
+        self.swapchain = self.swapchain_loader.create_swapchain(&create_info, None)?;
+        if old_swapchain != vk::SwapchainKHR::null() {
+            self.swapchain_loader.destroy_swapchain(old_swapchain, None);
+        }
+
+        self.images = self.swapchain_loader.get_swapchain_images(self.swapchain)?;
+        for &image in &self.images {
+            let create_info = vk::ImageViewCreateInfo::builder()
+                .image(image)
+                .view_type(vk::ImageViewType::TYPE_2D)
+                .format(self.format.format)
+                .subresource_range(vk::ImageSubresourceRange {
+                    aspect_mask: vk::ImageAspectFlags::COLOR,
+                    base_mip_level: 0,
+                    level_count: 1,
+                    base_array_layer: 0,
+                    layer_count: 1,
+                });
+            self.image_views
+                .push(self.device.create_image_view(&create_info, None)?);
+            self.render_finished.push(
+                self.device
+                    .create_semaphore(&vk::SemaphoreCreateInfo::default(), None)?,
+            );
+        }
+        Ok(())
+    }
+
+    unsafe fn destroy_image_resources(&mut self) {
+        for image_view in self.image_views.drain(..) {
+            self.device.destroy_image_view(image_view, None);
+        }
+        for semaphore in self.render_finished.drain(..) {
+            self.device.destroy_semaphore(semaphore, None);
+        }
+        self.images.clear();
+    }
+
+    /// Waits for the device to be idle and recreates the swapchain, e.g. after the window was
+    /// resized. [`SwapchainHelper::acquire`] and [`SwapchainHelper::present`] already do this
+    /// when the swapchain is out of date or suboptimal.
+    pub unsafe fn recreate(&mut self, window_extent: vk::Extent2D) -> VkResult<()> {
+        self.device.device_wait_idle()?;
+        self.destroy_image_resources();
+        self.create_swapchain(window_extent)
+    }
+
+    /// Waits until the next frame is no longer in flight and acquires an image for it. Returns
+    /// `None` if the swapchain was out of date and had to be recreated, in which case no image
+    /// was acquired.
+    pub unsafe fn acquire(
+        &mut self,
+        window_extent: vk::Extent2D,
+    ) -> VkResult<Option<AcquiredImage>> {
+        let frame = self.frames[self.frame];
+        self.device
+            .wait_for_fences(&[frame.in_flight], true, u64::MAX)?;
+        let (image_index, suboptimal) = match self.swapchain_loader.acquire_next_image(
+            self.swapchain,
+            u64::MAX,
+            frame.image_available,
+            vk::Fence::null(),
+        ) {
+            Ok(acquired) => acquired,
+            Err(vk::Result::ERROR_OUT_OF_DATE_KHR) => {
+                self.recreate(window_extent)?;
+                return Ok(None);
+            }
+            Err(err) => return Err(err),
+        };
+        // Only reset once the frame is certainly submitted, or the next wait never returns
+        self.device.reset_fences(&[frame.in_flight])?;
+        let index = image_index as usize;
+        Ok(Some(AcquiredImage {
+            image_index,
+            image: self.images[index],
+            image_view: self.image_views[index],
+            image_available: frame.image_available,
+            render_finished: self.render_finished[index],
+            in_flight: frame.in_flight,
+            suboptimal,
+        }))
+    }
+
+    /// Presents `image` once its `render_finished` is signaled and moves on to the next frame.
+    /// Recreates the swapchain if it is out of date or suboptimal.
+    pub unsafe fn present(
+        &mut self,
+        queue: vk::Queue,
+        image: &AcquiredImage,
+        window_extent: vk::Extent2D,
+    ) -> VkResult<()> {
+        self.frame = (self.frame + 1) % self.frames.len();
+        let swapchains = [self.swapchain];
+        let wait_semaphores = [image.render_finished];
+        let image_indices = [image.image_index];
+        let present_info = vk::PresentInfoKHR::builder()
+            .wait_semaphores(&wait_semaphores)
+            .swapchains(&swapchains)
+            .image_indices(&image_indices);
+        match self.swapchain_loader.queue_present(queue, &present_info) {
+            Ok(false) if !image.suboptimal => Ok(()),
+            Ok(_) | Err(vk::Result::ERROR_OUT_OF_DATE_KHR) => self.recreate(window_extent),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn swapchain(&self) -> vk::SwapchainKHR {
+        self.swapchain
+    }
+
+    pub fn format(&self) -> vk::SurfaceFormatKHR {
+        self.format
+    }
+
+    pub fn present_mode(&self) -> vk::PresentModeKHR {
+        self.present_mode
+    }
+
+    pub fn extent(&self) -> vk::Extent2D {
+        self.extent
+    }
+
+    pub fn images(&self) -> &[vk::Image] {
+        &self.images
+    }
+
+    pub fn image_views(&self) -> &[vk::ImageView] {
+        &self.image_views
+    }
+
+    /// Waits for the device to be idle and destroys the swapchain, the image views and the
+    /// synchronization primitives. The surface isn't destroyed.
+    pub unsafe fn destroy(&mut self) -> VkResult<()> {
+        self.device.device_wait_idle()?;
+        self.destroy_image_resources();
+        for frame in self.frames.drain(..) {
+            self.device.destroy_semaphore(frame.image_available, None);
+            self.device.destroy_fence(frame.in_flight, None);
+        }
+        self.swapchain_loader
+            .destroy_swapchain(self.swapchain, None);
+        self.swapchain = vk::SwapchainKHR::null();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capabilities(current: u32) -> vk::SurfaceCapabilitiesKHR {
+        vk::SurfaceCapabilitiesKHR {
+            min_image_count: 2,
+            max_image_count: 3,
+            current_extent: vk::Extent2D {
+                width: current,
+                height: current,
+            },
+            min_image_extent: vk::Extent2D {
+                width: 16,
+                height: 16,
+            },
+            max_image_extent: vk::Extent2D {
+                width: 1024,
+                height: 1024,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn surface_format_choice() {
+        let format = |format| vk::SurfaceFormatKHR {
+            format,
+            color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        };
+        let preferred = [format(vk::Format::B8G8R8A8_SRGB)];
+        let available = [
+            format(vk::Format::B8G8R8A8_UNORM),
+            format(vk::Format::B8G8R8A8_SRGB),
+        ];
+        assert_eq!(
+            choose_surface_format(&available, &preferred),
+            Some(available[1])
+        );
+        assert_eq!(
+            choose_surface_format(&available[..1], &preferred),
+            Some(available[0])
+        );
+        assert_eq!(
+            choose_surface_format(&[format(vk::Format::UNDEFINED)], &preferred),
+            Some(preferred[0])
+        );
+        assert_eq!(choose_surface_format(&[], &preferred), None);
+    }
+
+    #[test]
+    fn present_mode_choice() {
+        let available = [vk::PresentModeKHR::FIFO, vk::PresentModeKHR::IMMEDIATE];
+        let preferred = [vk::PresentModeKHR::MAILBOX, vk::PresentModeKHR::IMMEDIATE];
+        assert_eq!(
+            choose_present_mode(&available, &preferred),
+            vk::PresentModeKHR::IMMEDIATE
+        );
+        assert_eq!(
+            choose_present_mode(&available[..1], &preferred),
+            vk::PresentModeKHR::FIFO
+        );
+    }
+
+    #[test]
+    fn extent_and_image_count_choice() {
+        let window = vk::Extent2D {
+            width: 2000,
+            height: 8,
+        };
+        assert_eq!(choose_extent(&capabilities(512), window).width, 512);
+        let extent = choose_extent(&capabilities(u32::MAX), window);
+        assert_eq!((extent.width, extent.height), (1024, 16));
+
+        assert_eq!(choose_image_count(&capabilities(512), 1), 2);
+        assert_eq!(choose_image_count(&capabilities(512), 4), 3);
+        let unbounded = vk::SurfaceCapabilitiesKHR {
+            max_image_count: 0,
+            ..capabilities(512)
+        };
+        assert_eq!(choose_image_count(&unbounded, 4), 4);
+    }
+}
@@ -0,0 +1,17016 @@
+#![allow(clippy::too_many_arguments)]
+use super::lifetimes::{check_handles, track_created, track_destroyed};
+use super::{handle_arg, record, CStrArg, Pointee, Pointees, SyncGuard, TraceCall};
+use crate::vk::platform::*;
+use crate::vk::*;
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+#[doc = r" The slot that the real command of `name` is stored in, and the function that calls it."]
+pub(super) fn tracer(name: &[u8]) -> Option<(&'static AtomicPtr<c_void>, *const c_void)> {
+    Some(match name {
+        b"vkAcquireFullScreenExclusiveModeEXT" => (
+            &ACQUIRE_FULL_SCREEN_EXCLUSIVE_MODE_EXT,
+            acquire_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkAcquireImageANDROID" => (
+            &ACQUIRE_IMAGE_ANDROID,
+            acquire_image_android as *const c_void,
+        ),
+        b"vkAcquireNextImage2KHR" => (
+            &ACQUIRE_NEXT_IMAGE2_KHR,
+            acquire_next_image2_khr as *const c_void,
+        ),
+        b"vkAcquireNextImageKHR" => (
+            &ACQUIRE_NEXT_IMAGE_KHR,
+            acquire_next_image_khr as *const c_void,
+        ),
+        b"vkAcquirePerformanceConfigurationINTEL" => (
+            &ACQUIRE_PERFORMANCE_CONFIGURATION_INTEL,
+            acquire_performance_configuration_intel as *const c_void,
+        ),
+        b"vkAcquireProfilingLockKHR" => (
+            &ACQUIRE_PROFILING_LOCK_KHR,
+            acquire_profiling_lock_khr as *const c_void,
+        ),
+        b"vkAcquireXlibDisplayEXT" => (
+            &ACQUIRE_XLIB_DISPLAY_EXT,
+            acquire_xlib_display_ext as *const c_void,
+        ),
+        b"vkAllocateCommandBuffers" => (
+            &ALLOCATE_COMMAND_BUFFERS,
+            allocate_command_buffers as *const c_void,
+        ),
+        b"vkAllocateDescriptorSets" => (
+            &ALLOCATE_DESCRIPTOR_SETS,
+            allocate_descriptor_sets as *const c_void,
+        ),
+        b"vkAllocateMemory" => (&ALLOCATE_MEMORY, allocate_memory as *const c_void),
+        b"vkBeginCommandBuffer" => (&BEGIN_COMMAND_BUFFER, begin_command_buffer as *const c_void),
+        b"vkBindAccelerationStructureMemoryNV" => (
+            &BIND_ACCELERATION_STRUCTURE_MEMORY_NV,
+            bind_acceleration_structure_memory_nv as *const c_void,
+        ),
+        b"vkBindBufferMemory" => (&BIND_BUFFER_MEMORY, bind_buffer_memory as *const c_void),
+        b"vkBindBufferMemory2" => (&BIND_BUFFER_MEMORY2, bind_buffer_memory2 as *const c_void),
+        b"vkBindBufferMemory2KHR" => (
+            &BIND_BUFFER_MEMORY2_KHR,
+            bind_buffer_memory2_khr as *const c_void,
+        ),
+        b"vkBindImageMemory" => (&BIND_IMAGE_MEMORY, bind_image_memory as *const c_void),
+        b"vkBindImageMemory2" => (&BIND_IMAGE_MEMORY2, bind_image_memory2 as *const c_void),
+        b"vkBindImageMemory2KHR" => (
+            &BIND_IMAGE_MEMORY2_KHR,
+            bind_image_memory2_khr as *const c_void,
+        ),
+        b"vkBuildAccelerationStructuresKHR" => (
+            &BUILD_ACCELERATION_STRUCTURES_KHR,
+            build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkCmdBeginConditionalRenderingEXT" => (
+            &CMD_BEGIN_CONDITIONAL_RENDERING_EXT,
+            cmd_begin_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdBeginDebugUtilsLabelEXT" => (
+            &CMD_BEGIN_DEBUG_UTILS_LABEL_EXT,
+            cmd_begin_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdBeginQuery" => (&CMD_BEGIN_QUERY, cmd_begin_query as *const c_void),
+        b"vkCmdBeginQueryIndexedEXT" => (
+            &CMD_BEGIN_QUERY_INDEXED_EXT,
+            cmd_begin_query_indexed_ext as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass" => (
+            &CMD_BEGIN_RENDER_PASS,
+            cmd_begin_render_pass as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass2" => (
+            &CMD_BEGIN_RENDER_PASS2,
+            cmd_begin_render_pass2 as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass2KHR" => (
+            &CMD_BEGIN_RENDER_PASS2_KHR,
+            cmd_begin_render_pass2_khr as *const c_void,
+        ),
+        b"vkCmdBeginTransformFeedbackEXT" => (
+            &CMD_BEGIN_TRANSFORM_FEEDBACK_EXT,
+            cmd_begin_transform_feedback_ext as *const c_void,
+        ),
+        b"vkCmdBindDescriptorSets" => (
+            &CMD_BIND_DESCRIPTOR_SETS,
+            cmd_bind_descriptor_sets as *const c_void,
+        ),
+        b"vkCmdBindIndexBuffer" => (
+            &CMD_BIND_INDEX_BUFFER,
+            cmd_bind_index_buffer as *const c_void,
+        ),
+        b"vkCmdBindPipeline" => (&CMD_BIND_PIPELINE, cmd_bind_pipeline as *const c_void),
+        b"vkCmdBindPipelineShaderGroupNV" => (
+            &CMD_BIND_PIPELINE_SHADER_GROUP_NV,
+            cmd_bind_pipeline_shader_group_nv as *const c_void,
+        ),
+        b"vkCmdBindShadingRateImageNV" => (
+            &CMD_BIND_SHADING_RATE_IMAGE_NV,
+            cmd_bind_shading_rate_image_nv as *const c_void,
+        ),
+        b"vkCmdBindTransformFeedbackBuffersEXT" => (
+            &CMD_BIND_TRANSFORM_FEEDBACK_BUFFERS_EXT,
+            cmd_bind_transform_feedback_buffers_ext as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers" => (
+            &CMD_BIND_VERTEX_BUFFERS,
+            cmd_bind_vertex_buffers as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers2EXT" => (
+            &CMD_BIND_VERTEX_BUFFERS2_EXT,
+            cmd_bind_vertex_buffers2_ext as *const c_void,
+        ),
+        b"vkCmdBlitImage" => (&CMD_BLIT_IMAGE, cmd_blit_image as *const c_void),
+        b"vkCmdBlitImage2KHR" => (&CMD_BLIT_IMAGE2_KHR, cmd_blit_image2_khr as *const c_void),
+        b"vkCmdBuildAccelerationStructureNV" => (
+            &CMD_BUILD_ACCELERATION_STRUCTURE_NV,
+            cmd_build_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresIndirectKHR" => (
+            &CMD_BUILD_ACCELERATION_STRUCTURES_INDIRECT_KHR,
+            cmd_build_acceleration_structures_indirect_khr as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresKHR" => (
+            &CMD_BUILD_ACCELERATION_STRUCTURES_KHR,
+            cmd_build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkCmdClearAttachments" => (
+            &CMD_CLEAR_ATTACHMENTS,
+            cmd_clear_attachments as *const c_void,
+        ),
+        b"vkCmdClearColorImage" => (
+            &CMD_CLEAR_COLOR_IMAGE,
+            cmd_clear_color_image as *const c_void,
+        ),
+        b"vkCmdClearDepthStencilImage" => (
+            &CMD_CLEAR_DEPTH_STENCIL_IMAGE,
+            cmd_clear_depth_stencil_image as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureKHR" => (
+            &CMD_COPY_ACCELERATION_STRUCTURE_KHR,
+            cmd_copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureNV" => (
+            &CMD_COPY_ACCELERATION_STRUCTURE_NV,
+            cmd_copy_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureToMemoryKHR" => (
+            &CMD_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR,
+            cmd_copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCmdCopyBuffer" => (&CMD_COPY_BUFFER, cmd_copy_buffer as *const c_void),
+        b"vkCmdCopyBuffer2KHR" => (&CMD_COPY_BUFFER2_KHR, cmd_copy_buffer2_khr as *const c_void),
+        b"vkCmdCopyBufferToImage" => (
+            &CMD_COPY_BUFFER_TO_IMAGE,
+            cmd_copy_buffer_to_image as *const c_void,
+        ),
+        b"vkCmdCopyBufferToImage2KHR" => (
+            &CMD_COPY_BUFFER_TO_IMAGE2_KHR,
+            cmd_copy_buffer_to_image2_khr as *const c_void,
+        ),
+        b"vkCmdCopyImage" => (&CMD_COPY_IMAGE, cmd_copy_image as *const c_void),
+        b"vkCmdCopyImage2KHR" => (&CMD_COPY_IMAGE2_KHR, cmd_copy_image2_khr as *const c_void),
+        b"vkCmdCopyImageToBuffer" => (
+            &CMD_COPY_IMAGE_TO_BUFFER,
+            cmd_copy_image_to_buffer as *const c_void,
+        ),
+        b"vkCmdCopyImageToBuffer2KHR" => (
+            &CMD_COPY_IMAGE_TO_BUFFER2_KHR,
+            cmd_copy_image_to_buffer2_khr as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToAccelerationStructureKHR" => (
+            &CMD_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR,
+            cmd_copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyQueryPoolResults" => (
+            &CMD_COPY_QUERY_POOL_RESULTS,
+            cmd_copy_query_pool_results as *const c_void,
+        ),
+        b"vkCmdDebugMarkerBeginEXT" => (
+            &CMD_DEBUG_MARKER_BEGIN_EXT,
+            cmd_debug_marker_begin_ext as *const c_void,
+        ),
+        b"vkCmdDebugMarkerEndEXT" => (
+            &CMD_DEBUG_MARKER_END_EXT,
+            cmd_debug_marker_end_ext as *const c_void,
+        ),
+        b"vkCmdDebugMarkerInsertEXT" => (
+            &CMD_DEBUG_MARKER_INSERT_EXT,
+            cmd_debug_marker_insert_ext as *const c_void,
+        ),
+        b"vkCmdDispatch" => (&CMD_DISPATCH, cmd_dispatch as *const c_void),
+        b"vkCmdDispatchBase" => (&CMD_DISPATCH_BASE, cmd_dispatch_base as *const c_void),
+        b"vkCmdDispatchBaseKHR" => (
+            &CMD_DISPATCH_BASE_KHR,
+            cmd_dispatch_base_khr as *const c_void,
+        ),
+        b"vkCmdDispatchIndirect" => (
+            &CMD_DISPATCH_INDIRECT,
+            cmd_dispatch_indirect as *const c_void,
+        ),
+        b"vkCmdDraw" => (&CMD_DRAW, cmd_draw as *const c_void),
+        b"vkCmdDrawIndexed" => (&CMD_DRAW_INDEXED, cmd_draw_indexed as *const c_void),
+        b"vkCmdDrawIndexedIndirect" => (
+            &CMD_DRAW_INDEXED_INDIRECT,
+            cmd_draw_indexed_indirect as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCount" => (
+            &CMD_DRAW_INDEXED_INDIRECT_COUNT,
+            cmd_draw_indexed_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCountAMD" => (
+            &CMD_DRAW_INDEXED_INDIRECT_COUNT_AMD,
+            cmd_draw_indexed_indirect_count_amd as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCountKHR" => (
+            &CMD_DRAW_INDEXED_INDIRECT_COUNT_KHR,
+            cmd_draw_indexed_indirect_count_khr as *const c_void,
+        ),
+        b"vkCmdDrawIndirect" => (&CMD_DRAW_INDIRECT, cmd_draw_indirect as *const c_void),
+        b"vkCmdDrawIndirectByteCountEXT" => (
+            &CMD_DRAW_INDIRECT_BYTE_COUNT_EXT,
+            cmd_draw_indirect_byte_count_ext as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCount" => (
+            &CMD_DRAW_INDIRECT_COUNT,
+            cmd_draw_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCountAMD" => (
+            &CMD_DRAW_INDIRECT_COUNT_AMD,
+            cmd_draw_indirect_count_amd as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCountKHR" => (
+            &CMD_DRAW_INDIRECT_COUNT_KHR,
+            cmd_draw_indirect_count_khr as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectCountNV" => (
+            &CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_NV,
+            cmd_draw_mesh_tasks_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectNV" => (
+            &CMD_DRAW_MESH_TASKS_INDIRECT_NV,
+            cmd_draw_mesh_tasks_indirect_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksNV" => (
+            &CMD_DRAW_MESH_TASKS_NV,
+            cmd_draw_mesh_tasks_nv as *const c_void,
+        ),
+        b"vkCmdEndConditionalRenderingEXT" => (
+            &CMD_END_CONDITIONAL_RENDERING_EXT,
+            cmd_end_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdEndDebugUtilsLabelEXT" => (
+            &CMD_END_DEBUG_UTILS_LABEL_EXT,
+            cmd_end_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdEndQuery" => (&CMD_END_QUERY, cmd_end_query as *const c_void),
+        b"vkCmdEndQueryIndexedEXT" => (
+            &CMD_END_QUERY_INDEXED_EXT,
+            cmd_end_query_indexed_ext as *const c_void,
+        ),
+        b"vkCmdEndRenderPass" => (&CMD_END_RENDER_PASS, cmd_end_render_pass as *const c_void),
+        b"vkCmdEndRenderPass2" => (&CMD_END_RENDER_PASS2, cmd_end_render_pass2 as *const c_void),
+        b"vkCmdEndRenderPass2KHR" => (
+            &CMD_END_RENDER_PASS2_KHR,
+            cmd_end_render_pass2_khr as *const c_void,
+        ),
+        b"vkCmdEndTransformFeedbackEXT" => (
+            &CMD_END_TRANSFORM_FEEDBACK_EXT,
+            cmd_end_transform_feedback_ext as *const c_void,
+        ),
+        b"vkCmdExecuteCommands" => (&CMD_EXECUTE_COMMANDS, cmd_execute_commands as *const c_void),
+        b"vkCmdExecuteGeneratedCommandsNV" => (
+            &CMD_EXECUTE_GENERATED_COMMANDS_NV,
+            cmd_execute_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdFillBuffer" => (&CMD_FILL_BUFFER, cmd_fill_buffer as *const c_void),
+        b"vkCmdInsertDebugUtilsLabelEXT" => (
+            &CMD_INSERT_DEBUG_UTILS_LABEL_EXT,
+            cmd_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdNextSubpass" => (&CMD_NEXT_SUBPASS, cmd_next_subpass as *const c_void),
+        b"vkCmdNextSubpass2" => (&CMD_NEXT_SUBPASS2, cmd_next_subpass2 as *const c_void),
+        b"vkCmdNextSubpass2KHR" => (
+            &CMD_NEXT_SUBPASS2_KHR,
+            cmd_next_subpass2_khr as *const c_void,
+        ),
+        b"vkCmdPipelineBarrier" => (&CMD_PIPELINE_BARRIER, cmd_pipeline_barrier as *const c_void),
+        b"vkCmdPreprocessGeneratedCommandsNV" => (
+            &CMD_PREPROCESS_GENERATED_COMMANDS_NV,
+            cmd_preprocess_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdPushConstants" => (&CMD_PUSH_CONSTANTS, cmd_push_constants as *const c_void),
+        b"vkCmdPushDescriptorSetKHR" => (
+            &CMD_PUSH_DESCRIPTOR_SET_KHR,
+            cmd_push_descriptor_set_khr as *const c_void,
+        ),
+        b"vkCmdPushDescriptorSetWithTemplateKHR" => (
+            &CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_KHR,
+            cmd_push_descriptor_set_with_template_khr as *const c_void,
+        ),
+        b"vkCmdResetEvent" => (&CMD_RESET_EVENT, cmd_reset_event as *const c_void),
+        b"vkCmdResetQueryPool" => (&CMD_RESET_QUERY_POOL, cmd_reset_query_pool as *const c_void),
+        b"vkCmdResolveImage" => (&CMD_RESOLVE_IMAGE, cmd_resolve_image as *const c_void),
+        b"vkCmdResolveImage2KHR" => (
+            &CMD_RESOLVE_IMAGE2_KHR,
+            cmd_resolve_image2_khr as *const c_void,
+        ),
+        b"vkCmdSetBlendConstants" => (
+            &CMD_SET_BLEND_CONSTANTS,
+            cmd_set_blend_constants as *const c_void,
+        ),
+        b"vkCmdSetCheckpointNV" => (
+            &CMD_SET_CHECKPOINT_NV,
+            cmd_set_checkpoint_nv as *const c_void,
+        ),
+        b"vkCmdSetCoarseSampleOrderNV" => (
+            &CMD_SET_COARSE_SAMPLE_ORDER_NV,
+            cmd_set_coarse_sample_order_nv as *const c_void,
+        ),
+        b"vkCmdSetCullModeEXT" => (
+            &CMD_SET_CULL_MODE_EXT,
+            cmd_set_cull_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthBias" => (&CMD_SET_DEPTH_BIAS, cmd_set_depth_bias as *const c_void),
+        b"vkCmdSetDepthBounds" => (&CMD_SET_DEPTH_BOUNDS, cmd_set_depth_bounds as *const c_void),
+        b"vkCmdSetDepthBoundsTestEnableEXT" => (
+            &CMD_SET_DEPTH_BOUNDS_TEST_ENABLE_EXT,
+            cmd_set_depth_bounds_test_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthCompareOpEXT" => (
+            &CMD_SET_DEPTH_COMPARE_OP_EXT,
+            cmd_set_depth_compare_op_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthTestEnableEXT" => (
+            &CMD_SET_DEPTH_TEST_ENABLE_EXT,
+            cmd_set_depth_test_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthWriteEnableEXT" => (
+            &CMD_SET_DEPTH_WRITE_ENABLE_EXT,
+            cmd_set_depth_write_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDeviceMask" => (&CMD_SET_DEVICE_MASK, cmd_set_device_mask as *const c_void),
+        b"vkCmdSetDeviceMaskKHR" => (
+            &CMD_SET_DEVICE_MASK_KHR,
+            cmd_set_device_mask_khr as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleEXT" => (
+            &CMD_SET_DISCARD_RECTANGLE_EXT,
+            cmd_set_discard_rectangle_ext as *const c_void,
+        ),
+        b"vkCmdSetEvent" => (&CMD_SET_EVENT, cmd_set_event as *const c_void),
+        b"vkCmdSetExclusiveScissorNV" => (
+            &CMD_SET_EXCLUSIVE_SCISSOR_NV,
+            cmd_set_exclusive_scissor_nv as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateEnumNV" => (
+            &CMD_SET_FRAGMENT_SHADING_RATE_ENUM_NV,
+            cmd_set_fragment_shading_rate_enum_nv as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateKHR" => (
+            &CMD_SET_FRAGMENT_SHADING_RATE_KHR,
+            cmd_set_fragment_shading_rate_khr as *const c_void,
+        ),
+        b"vkCmdSetFrontFaceEXT" => (
+            &CMD_SET_FRONT_FACE_EXT,
+            cmd_set_front_face_ext as *const c_void,
+        ),
+        b"vkCmdSetLineStippleEXT" => (
+            &CMD_SET_LINE_STIPPLE_EXT,
+            cmd_set_line_stipple_ext as *const c_void,
+        ),
+        b"vkCmdSetLineWidth" => (&CMD_SET_LINE_WIDTH, cmd_set_line_width as *const c_void),
+        b"vkCmdSetPerformanceMarkerINTEL" => (
+            &CMD_SET_PERFORMANCE_MARKER_INTEL,
+            cmd_set_performance_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceOverrideINTEL" => (
+            &CMD_SET_PERFORMANCE_OVERRIDE_INTEL,
+            cmd_set_performance_override_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceStreamMarkerINTEL" => (
+            &CMD_SET_PERFORMANCE_STREAM_MARKER_INTEL,
+            cmd_set_performance_stream_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPrimitiveTopologyEXT" => (
+            &CMD_SET_PRIMITIVE_TOPOLOGY_EXT,
+            cmd_set_primitive_topology_ext as *const c_void,
+        ),
+        b"vkCmdSetRayTracingPipelineStackSizeKHR" => (
+            &CMD_SET_RAY_TRACING_PIPELINE_STACK_SIZE_KHR,
+            cmd_set_ray_tracing_pipeline_stack_size_khr as *const c_void,
+        ),
+        b"vkCmdSetSampleLocationsEXT" => (
+            &CMD_SET_SAMPLE_LOCATIONS_EXT,
+            cmd_set_sample_locations_ext as *const c_void,
+        ),
+        b"vkCmdSetScissor" => (&CMD_SET_SCISSOR, cmd_set_scissor as *const c_void),
+        b"vkCmdSetScissorWithCountEXT" => (
+            &CMD_SET_SCISSOR_WITH_COUNT_EXT,
+            cmd_set_scissor_with_count_ext as *const c_void,
+        ),
+        b"vkCmdSetStencilCompareMask" => (
+            &CMD_SET_STENCIL_COMPARE_MASK,
+            cmd_set_stencil_compare_mask as *const c_void,
+        ),
+        b"vkCmdSetStencilOpEXT" => (
+            &CMD_SET_STENCIL_OP_EXT,
+            cmd_set_stencil_op_ext as *const c_void,
+        ),
+        b"vkCmdSetStencilReference" => (
+            &CMD_SET_STENCIL_REFERENCE,
+            cmd_set_stencil_reference as *const c_void,
+        ),
+        b"vkCmdSetStencilTestEnableEXT" => (
+            &CMD_SET_STENCIL_TEST_ENABLE_EXT,
+            cmd_set_stencil_test_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetStencilWriteMask" => (
+            &CMD_SET_STENCIL_WRITE_MASK,
+            cmd_set_stencil_write_mask as *const c_void,
+        ),
+        b"vkCmdSetViewport" => (&CMD_SET_VIEWPORT, cmd_set_viewport as *const c_void),
+        b"vkCmdSetViewportShadingRatePaletteNV" => (
+            &CMD_SET_VIEWPORT_SHADING_RATE_PALETTE_NV,
+            cmd_set_viewport_shading_rate_palette_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWScalingNV" => (
+            &CMD_SET_VIEWPORT_W_SCALING_NV,
+            cmd_set_viewport_w_scaling_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWithCountEXT" => (
+            &CMD_SET_VIEWPORT_WITH_COUNT_EXT,
+            cmd_set_viewport_with_count_ext as *const c_void,
+        ),
+        b"vkCmdTraceRaysIndirectKHR" => (
+            &CMD_TRACE_RAYS_INDIRECT_KHR,
+            cmd_trace_rays_indirect_khr as *const c_void,
+        ),
+        b"vkCmdTraceRaysKHR" => (&CMD_TRACE_RAYS_KHR, cmd_trace_rays_khr as *const c_void),
+        b"vkCmdTraceRaysNV" => (&CMD_TRACE_RAYS_NV, cmd_trace_rays_nv as *const c_void),
+        b"vkCmdUpdateBuffer" => (&CMD_UPDATE_BUFFER, cmd_update_buffer as *const c_void),
+        b"vkCmdWaitEvents" => (&CMD_WAIT_EVENTS, cmd_wait_events as *const c_void),
+        b"vkCmdWriteAccelerationStructuresPropertiesKHR" => (
+            &CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR,
+            cmd_write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkCmdWriteAccelerationStructuresPropertiesNV" => (
+            &CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_NV,
+            cmd_write_acceleration_structures_properties_nv as *const c_void,
+        ),
+        b"vkCmdWriteBufferMarkerAMD" => (
+            &CMD_WRITE_BUFFER_MARKER_AMD,
+            cmd_write_buffer_marker_amd as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp" => (&CMD_WRITE_TIMESTAMP, cmd_write_timestamp as *const c_void),
+        b"vkCompileDeferredNV" => (&COMPILE_DEFERRED_NV, compile_deferred_nv as *const c_void),
+        b"vkCopyAccelerationStructureKHR" => (
+            &COPY_ACCELERATION_STRUCTURE_KHR,
+            copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCopyAccelerationStructureToMemoryKHR" => (
+            &COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR,
+            copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToAccelerationStructureKHR" => (
+            &COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR,
+            copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCreateAccelerationStructureKHR" => (
+            &CREATE_ACCELERATION_STRUCTURE_KHR,
+            create_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCreateAccelerationStructureNV" => (
+            &CREATE_ACCELERATION_STRUCTURE_NV,
+            create_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCreateAndroidSurfaceKHR" => (
+            &CREATE_ANDROID_SURFACE_KHR,
+            create_android_surface_khr as *const c_void,
+        ),
+        b"vkCreateBuffer" => (&CREATE_BUFFER, create_buffer as *const c_void),
+        b"vkCreateBufferView" => (&CREATE_BUFFER_VIEW, create_buffer_view as *const c_void),
+        b"vkCreateCommandPool" => (&CREATE_COMMAND_POOL, create_command_pool as *const c_void),
+        b"vkCreateComputePipelines" => (
+            &CREATE_COMPUTE_PIPELINES,
+            create_compute_pipelines as *const c_void,
+        ),
+        b"vkCreateDebugReportCallbackEXT" => (
+            &CREATE_DEBUG_REPORT_CALLBACK_EXT,
+            create_debug_report_callback_ext as *const c_void,
+        ),
+        b"vkCreateDebugUtilsMessengerEXT" => (
+            &CREATE_DEBUG_UTILS_MESSENGER_EXT,
+            create_debug_utils_messenger_ext as *const c_void,
+        ),
+        b"vkCreateDeferredOperationKHR" => (
+            &CREATE_DEFERRED_OPERATION_KHR,
+            create_deferred_operation_khr as *const c_void,
+        ),
+        b"vkCreateDescriptorPool" => (
+            &CREATE_DESCRIPTOR_POOL,
+            create_descriptor_pool as *const c_void,
+        ),
+        b"vkCreateDescriptorSetLayout" => (
+            &CREATE_DESCRIPTOR_SET_LAYOUT,
+            create_descriptor_set_layout as *const c_void,
+        ),
+        b"vkCreateDescriptorUpdateTemplate" => (
+            &CREATE_DESCRIPTOR_UPDATE_TEMPLATE,
+            create_descriptor_update_template as *const c_void,
+        ),
+        b"vkCreateDescriptorUpdateTemplateKHR" => (
+            &CREATE_DESCRIPTOR_UPDATE_TEMPLATE_KHR,
+            create_descriptor_update_template_khr as *const c_void,
+        ),
+        b"vkCreateDevice" => (&CREATE_DEVICE, create_device as *const c_void),
+        b"vkCreateDirectFBSurfaceEXT" => (
+            &CREATE_DIRECT_FB_SURFACE_EXT,
+            create_direct_fb_surface_ext as *const c_void,
+        ),
+        b"vkCreateDisplayModeKHR" => (
+            &CREATE_DISPLAY_MODE_KHR,
+            create_display_mode_khr as *const c_void,
+        ),
+        b"vkCreateDisplayPlaneSurfaceKHR" => (
+            &CREATE_DISPLAY_PLANE_SURFACE_KHR,
+            create_display_plane_surface_khr as *const c_void,
+        ),
+        b"vkCreateEvent" => (&CREATE_EVENT, create_event as *const c_void),
+        b"vkCreateFence" => (&CREATE_FENCE, create_fence as *const c_void),
+        b"vkCreateFramebuffer" => (&CREATE_FRAMEBUFFER, create_framebuffer as *const c_void),
+        b"vkCreateGraphicsPipelines" => (
+            &CREATE_GRAPHICS_PIPELINES,
+            create_graphics_pipelines as *const c_void,
+        ),
+        b"vkCreateHeadlessSurfaceEXT" => (
+            &CREATE_HEADLESS_SURFACE_EXT,
+            create_headless_surface_ext as *const c_void,
+        ),
+        b"vkCreateIOSSurfaceMVK" => (
+            &CREATE_IOS_SURFACE_MVK,
+            create_ios_surface_mvk as *const c_void,
+        ),
+        b"vkCreateImage" => (&CREATE_IMAGE, create_image as *const c_void),
+        b"vkCreateImagePipeSurfaceFUCHSIA" => (
+            &CREATE_IMAGE_PIPE_SURFACE_FUCHSIA,
+            create_image_pipe_surface_fuchsia as *const c_void,
+        ),
+        b"vkCreateImageView" => (&CREATE_IMAGE_VIEW, create_image_view as *const c_void),
+        b"vkCreateIndirectCommandsLayoutNV" => (
+            &CREATE_INDIRECT_COMMANDS_LAYOUT_NV,
+            create_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkCreateInstance" => (&CREATE_INSTANCE, create_instance as *const c_void),
+        b"vkCreateMacOSSurfaceMVK" => (
+            &CREATE_MAC_OS_SURFACE_MVK,
+            create_mac_os_surface_mvk as *const c_void,
+        ),
+        b"vkCreateMetalSurfaceEXT" => (
+            &CREATE_METAL_SURFACE_EXT,
+            create_metal_surface_ext as *const c_void,
+        ),
+        b"vkCreatePipelineCache" => (
+            &CREATE_PIPELINE_CACHE,
+            create_pipeline_cache as *const c_void,
+        ),
+        b"vkCreatePipelineLayout" => (
+            &CREATE_PIPELINE_LAYOUT,
+            create_pipeline_layout as *const c_void,
+        ),
+        b"vkCreatePrivateDataSlotEXT" => (
+            &CREATE_PRIVATE_DATA_SLOT_EXT,
+            create_private_data_slot_ext as *const c_void,
+        ),
+        b"vkCreateQueryPool" => (&CREATE_QUERY_POOL, create_query_pool as *const c_void),
+        b"vkCreateRayTracingPipelinesKHR" => (
+            &CREATE_RAY_TRACING_PIPELINES_KHR,
+            create_ray_tracing_pipelines_khr as *const c_void,
+        ),
+        b"vkCreateRayTracingPipelinesNV" => (
+            &CREATE_RAY_TRACING_PIPELINES_NV,
+            create_ray_tracing_pipelines_nv as *const c_void,
+        ),
+        b"vkCreateRenderPass" => (&CREATE_RENDER_PASS, create_render_pass as *const c_void),
+        b"vkCreateRenderPass2" => (&CREATE_RENDER_PASS2, create_render_pass2 as *const c_void),
+        b"vkCreateRenderPass2KHR" => (
+            &CREATE_RENDER_PASS2_KHR,
+            create_render_pass2_khr as *const c_void,
+        ),
+        b"vkCreateSampler" => (&CREATE_SAMPLER, create_sampler as *const c_void),
+        b"vkCreateSamplerYcbcrConversion" => (
+            &CREATE_SAMPLER_YCBCR_CONVERSION,
+            create_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkCreateSamplerYcbcrConversionKHR" => (
+            &CREATE_SAMPLER_YCBCR_CONVERSION_KHR,
+            create_sampler_ycbcr_conversion_khr as *const c_void,
+        ),
+        b"vkCreateSemaphore" => (&CREATE_SEMAPHORE, create_semaphore as *const c_void),
+        b"vkCreateShaderModule" => (&CREATE_SHADER_MODULE, create_shader_module as *const c_void),
+        b"vkCreateSharedSwapchainsKHR" => (
+            &CREATE_SHARED_SWAPCHAINS_KHR,
+            create_shared_swapchains_khr as *const c_void,
+        ),
+        b"vkCreateStreamDescriptorSurfaceGGP" => (
+            &CREATE_STREAM_DESCRIPTOR_SURFACE_GGP,
+            create_stream_descriptor_surface_ggp as *const c_void,
+        ),
+        b"vkCreateSwapchainKHR" => (&CREATE_SWAPCHAIN_KHR, create_swapchain_khr as *const c_void),
+        b"vkCreateValidationCacheEXT" => (
+            &CREATE_VALIDATION_CACHE_EXT,
+            create_validation_cache_ext as *const c_void,
+        ),
+        b"vkCreateViSurfaceNN" => (&CREATE_VI_SURFACE_NN, create_vi_surface_nn as *const c_void),
+        b"vkCreateWaylandSurfaceKHR" => (
+            &CREATE_WAYLAND_SURFACE_KHR,
+            create_wayland_surface_khr as *const c_void,
+        ),
+        b"vkCreateWin32SurfaceKHR" => (
+            &CREATE_WIN32_SURFACE_KHR,
+            create_win32_surface_khr as *const c_void,
+        ),
+        b"vkCreateXcbSurfaceKHR" => (
+            &CREATE_XCB_SURFACE_KHR,
+            create_xcb_surface_khr as *const c_void,
+        ),
+        b"vkCreateXlibSurfaceKHR" => (
+            &CREATE_XLIB_SURFACE_KHR,
+            create_xlib_surface_khr as *const c_void,
+        ),
+        b"vkDebugMarkerSetObjectNameEXT" => (
+            &DEBUG_MARKER_SET_OBJECT_NAME_EXT,
+            debug_marker_set_object_name_ext as *const c_void,
+        ),
+        b"vkDebugMarkerSetObjectTagEXT" => (
+            &DEBUG_MARKER_SET_OBJECT_TAG_EXT,
+            debug_marker_set_object_tag_ext as *const c_void,
+        ),
+        b"vkDebugReportMessageEXT" => (
+            &DEBUG_REPORT_MESSAGE_EXT,
+            debug_report_message_ext as *const c_void,
+        ),
+        b"vkDeferredOperationJoinKHR" => (
+            &DEFERRED_OPERATION_JOIN_KHR,
+            deferred_operation_join_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureKHR" => (
+            &DESTROY_ACCELERATION_STRUCTURE_KHR,
+            destroy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureNV" => (
+            &DESTROY_ACCELERATION_STRUCTURE_NV,
+            destroy_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkDestroyBuffer" => (&DESTROY_BUFFER, destroy_buffer as *const c_void),
+        b"vkDestroyBufferView" => (&DESTROY_BUFFER_VIEW, destroy_buffer_view as *const c_void),
+        b"vkDestroyCommandPool" => (&DESTROY_COMMAND_POOL, destroy_command_pool as *const c_void),
+        b"vkDestroyDebugReportCallbackEXT" => (
+            &DESTROY_DEBUG_REPORT_CALLBACK_EXT,
+            destroy_debug_report_callback_ext as *const c_void,
+        ),
+        b"vkDestroyDebugUtilsMessengerEXT" => (
+            &DESTROY_DEBUG_UTILS_MESSENGER_EXT,
+            destroy_debug_utils_messenger_ext as *const c_void,
+        ),
+        b"vkDestroyDeferredOperationKHR" => (
+            &DESTROY_DEFERRED_OPERATION_KHR,
+            destroy_deferred_operation_khr as *const c_void,
+        ),
+        b"vkDestroyDescriptorPool" => (
+            &DESTROY_DESCRIPTOR_POOL,
+            destroy_descriptor_pool as *const c_void,
+        ),
+        b"vkDestroyDescriptorSetLayout" => (
+            &DESTROY_DESCRIPTOR_SET_LAYOUT,
+            destroy_descriptor_set_layout as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplate" => (
+            &DESTROY_DESCRIPTOR_UPDATE_TEMPLATE,
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplateKHR" => (
+            &DESTROY_DESCRIPTOR_UPDATE_TEMPLATE_KHR,
+            destroy_descriptor_update_template_khr as *const c_void,
+        ),
+        b"vkDestroyDevice" => (&DESTROY_DEVICE, destroy_device as *const c_void),
+        b"vkDestroyEvent" => (&DESTROY_EVENT, destroy_event as *const c_void),
+        b"vkDestroyFence" => (&DESTROY_FENCE, destroy_fence as *const c_void),
+        b"vkDestroyFramebuffer" => (&DESTROY_FRAMEBUFFER, destroy_framebuffer as *const c_void),
+        b"vkDestroyImage" => (&DESTROY_IMAGE, destroy_image as *const c_void),
+        b"vkDestroyImageView" => (&DESTROY_IMAGE_VIEW, destroy_image_view as *const c_void),
+        b"vkDestroyIndirectCommandsLayoutNV" => (
+            &DESTROY_INDIRECT_COMMANDS_LAYOUT_NV,
+            destroy_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkDestroyInstance" => (&DESTROY_INSTANCE, destroy_instance as *const c_void),
+        b"vkDestroyPipeline" => (&DESTROY_PIPELINE, destroy_pipeline as *const c_void),
+        b"vkDestroyPipelineCache" => (
+            &DESTROY_PIPELINE_CACHE,
+            destroy_pipeline_cache as *const c_void,
+        ),
+        b"vkDestroyPipelineLayout" => (
+            &DESTROY_PIPELINE_LAYOUT,
+            destroy_pipeline_layout as *const c_void,
+        ),
+        b"vkDestroyPrivateDataSlotEXT" => (
+            &DESTROY_PRIVATE_DATA_SLOT_EXT,
+            destroy_private_data_slot_ext as *const c_void,
+        ),
+        b"vkDestroyQueryPool" => (&DESTROY_QUERY_POOL, destroy_query_pool as *const c_void),
+        b"vkDestroyRenderPass" => (&DESTROY_RENDER_PASS, destroy_render_pass as *const c_void),
+        b"vkDestroySampler" => (&DESTROY_SAMPLER, destroy_sampler as *const c_void),
+        b"vkDestroySamplerYcbcrConversion" => (
+            &DESTROY_SAMPLER_YCBCR_CONVERSION,
+            destroy_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkDestroySamplerYcbcrConversionKHR" => (
+            &DESTROY_SAMPLER_YCBCR_CONVERSION_KHR,
+            destroy_sampler_ycbcr_conversion_khr as *const c_void,
+        ),
+        b"vkDestroySemaphore" => (&DESTROY_SEMAPHORE, destroy_semaphore as *const c_void),
+        b"vkDestroyShaderModule" => (
+            &DESTROY_SHADER_MODULE,
+            destroy_shader_module as *const c_void,
+        ),
+        b"vkDestroySurfaceKHR" => (&DESTROY_SURFACE_KHR, destroy_surface_khr as *const c_void),
+        b"vkDestroySwapchainKHR" => (
+            &DESTROY_SWAPCHAIN_KHR,
+            destroy_swapchain_khr as *const c_void,
+        ),
+        b"vkDestroyValidationCacheEXT" => (
+            &DESTROY_VALIDATION_CACHE_EXT,
+            destroy_validation_cache_ext as *const c_void,
+        ),
+        b"vkDeviceWaitIdle" => (&DEVICE_WAIT_IDLE, device_wait_idle as *const c_void),
+        b"vkDisplayPowerControlEXT" => (
+            &DISPLAY_POWER_CONTROL_EXT,
+            display_power_control_ext as *const c_void,
+        ),
+        b"vkEndCommandBuffer" => (&END_COMMAND_BUFFER, end_command_buffer as *const c_void),
+        b"vkEnumerateDeviceExtensionProperties" => (
+            &ENUMERATE_DEVICE_EXTENSION_PROPERTIES,
+            enumerate_device_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateDeviceLayerProperties" => (
+            &ENUMERATE_DEVICE_LAYER_PROPERTIES,
+            enumerate_device_layer_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceExtensionProperties" => (
+            &ENUMERATE_INSTANCE_EXTENSION_PROPERTIES,
+            enumerate_instance_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceLayerProperties" => (
+            &ENUMERATE_INSTANCE_LAYER_PROPERTIES,
+            enumerate_instance_layer_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceVersion" => (
+            &ENUMERATE_INSTANCE_VERSION,
+            enumerate_instance_version as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceGroups" => (
+            &ENUMERATE_PHYSICAL_DEVICE_GROUPS,
+            enumerate_physical_device_groups as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceGroupsKHR" => (
+            &ENUMERATE_PHYSICAL_DEVICE_GROUPS_KHR,
+            enumerate_physical_device_groups_khr as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR" => (
+            &ENUMERATE_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_COUNTERS_KHR,
+            enumerate_physical_device_queue_family_performance_query_counters_khr as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDevices" => (
+            &ENUMERATE_PHYSICAL_DEVICES,
+            enumerate_physical_devices as *const c_void,
+        ),
+        b"vkFlushMappedMemoryRanges" => (
+            &FLUSH_MAPPED_MEMORY_RANGES,
+            flush_mapped_memory_ranges as *const c_void,
+        ),
+        b"vkFreeCommandBuffers" => (&FREE_COMMAND_BUFFERS, free_command_buffers as *const c_void),
+        b"vkFreeDescriptorSets" => (&FREE_DESCRIPTOR_SETS, free_descriptor_sets as *const c_void),
+        b"vkFreeMemory" => (&FREE_MEMORY, free_memory as *const c_void),
+        b"vkGetAccelerationStructureBuildSizesKHR" => (
+            &GET_ACCELERATION_STRUCTURE_BUILD_SIZES_KHR,
+            get_acceleration_structure_build_sizes_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureDeviceAddressKHR" => (
+            &GET_ACCELERATION_STRUCTURE_DEVICE_ADDRESS_KHR,
+            get_acceleration_structure_device_address_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureHandleNV" => (
+            &GET_ACCELERATION_STRUCTURE_HANDLE_NV,
+            get_acceleration_structure_handle_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureMemoryRequirementsNV" => (
+            &GET_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_NV,
+            get_acceleration_structure_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetAndroidHardwareBufferPropertiesANDROID" => (
+            &GET_ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+            get_android_hardware_buffer_properties_android as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddress" => (
+            &GET_BUFFER_DEVICE_ADDRESS,
+            get_buffer_device_address as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddressEXT" => (
+            &GET_BUFFER_DEVICE_ADDRESS_EXT,
+            get_buffer_device_address_ext as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddressKHR" => (
+            &GET_BUFFER_DEVICE_ADDRESS_KHR,
+            get_buffer_device_address_khr as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements" => (
+            &GET_BUFFER_MEMORY_REQUIREMENTS,
+            get_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements2" => (
+            &GET_BUFFER_MEMORY_REQUIREMENTS2,
+            get_buffer_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements2KHR" => (
+            &GET_BUFFER_MEMORY_REQUIREMENTS2_KHR,
+            get_buffer_memory_requirements2_khr as *const c_void,
+        ),
+        b"vkGetBufferOpaqueCaptureAddress" => (
+            &GET_BUFFER_OPAQUE_CAPTURE_ADDRESS,
+            get_buffer_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetBufferOpaqueCaptureAddressKHR" => (
+            &GET_BUFFER_OPAQUE_CAPTURE_ADDRESS_KHR,
+            get_buffer_opaque_capture_address_khr as *const c_void,
+        ),
+        b"vkGetCalibratedTimestampsEXT" => (
+            &GET_CALIBRATED_TIMESTAMPS_EXT,
+            get_calibrated_timestamps_ext as *const c_void,
+        ),
+        b"vkGetDeferredOperationMaxConcurrencyKHR" => (
+            &GET_DEFERRED_OPERATION_MAX_CONCURRENCY_KHR,
+            get_deferred_operation_max_concurrency_khr as *const c_void,
+        ),
+        b"vkGetDeferredOperationResultKHR" => (
+            &GET_DEFERRED_OPERATION_RESULT_KHR,
+            get_deferred_operation_result_khr as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupport" => (
+            &GET_DESCRIPTOR_SET_LAYOUT_SUPPORT,
+            get_descriptor_set_layout_support as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupportKHR" => (
+            &GET_DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR,
+            get_descriptor_set_layout_support_khr as *const c_void,
+        ),
+        b"vkGetDeviceAccelerationStructureCompatibilityKHR" => (
+            &GET_DEVICE_ACCELERATION_STRUCTURE_COMPATIBILITY_KHR,
+            get_device_acceleration_structure_compatibility_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeatures" => (
+            &GET_DEVICE_GROUP_PEER_MEMORY_FEATURES,
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeaturesKHR" => (
+            &GET_DEVICE_GROUP_PEER_MEMORY_FEATURES_KHR,
+            get_device_group_peer_memory_features_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupPresentCapabilitiesKHR" => (
+            &GET_DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+            get_device_group_present_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModes2EXT" => (
+            &GET_DEVICE_GROUP_SURFACE_PRESENT_MODES2_EXT,
+            get_device_group_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModesKHR" => (
+            &GET_DEVICE_GROUP_SURFACE_PRESENT_MODES_KHR,
+            get_device_group_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetDeviceMemoryCommitment" => (
+            &GET_DEVICE_MEMORY_COMMITMENT,
+            get_device_memory_commitment as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddress" => (
+            &GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS,
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => (
+            &GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_KHR,
+            get_device_memory_opaque_capture_address_khr as *const c_void,
+        ),
+        b"vkGetDeviceQueue" => (&GET_DEVICE_QUEUE, get_device_queue as *const c_void),
+        b"vkGetDeviceQueue2" => (&GET_DEVICE_QUEUE2, get_device_queue2 as *const c_void),
+        b"vkGetDisplayModeProperties2KHR" => (
+            &GET_DISPLAY_MODE_PROPERTIES2_KHR,
+            get_display_mode_properties2_khr as *const c_void,
+        ),
+        b"vkGetDisplayModePropertiesKHR" => (
+            &GET_DISPLAY_MODE_PROPERTIES_KHR,
+            get_display_mode_properties_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneCapabilities2KHR" => (
+            &GET_DISPLAY_PLANE_CAPABILITIES2_KHR,
+            get_display_plane_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneCapabilitiesKHR" => (
+            &GET_DISPLAY_PLANE_CAPABILITIES_KHR,
+            get_display_plane_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneSupportedDisplaysKHR" => (
+            &GET_DISPLAY_PLANE_SUPPORTED_DISPLAYS_KHR,
+            get_display_plane_supported_displays_khr as *const c_void,
+        ),
+        b"vkGetEventStatus" => (&GET_EVENT_STATUS, get_event_status as *const c_void),
+        b"vkGetFenceFdKHR" => (&GET_FENCE_FD_KHR, get_fence_fd_khr as *const c_void),
+        b"vkGetFenceStatus" => (&GET_FENCE_STATUS, get_fence_status as *const c_void),
+        b"vkGetFenceWin32HandleKHR" => (
+            &GET_FENCE_WIN32_HANDLE_KHR,
+            get_fence_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetGeneratedCommandsMemoryRequirementsNV" => (
+            &GET_GENERATED_COMMANDS_MEMORY_REQUIREMENTS_NV,
+            get_generated_commands_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetImageDrmFormatModifierPropertiesEXT" => (
+            &GET_IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+            get_image_drm_format_modifier_properties_ext as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements" => (
+            &GET_IMAGE_MEMORY_REQUIREMENTS,
+            get_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements2" => (
+            &GET_IMAGE_MEMORY_REQUIREMENTS2,
+            get_image_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements2KHR" => (
+            &GET_IMAGE_MEMORY_REQUIREMENTS2_KHR,
+            get_image_memory_requirements2_khr as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements" => (
+            &GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS,
+            get_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2" => (
+            &GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2,
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2KHR" => (
+            &GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2_KHR,
+            get_image_sparse_memory_requirements2_khr as *const c_void,
+        ),
+        b"vkGetImageSubresourceLayout" => (
+            &GET_IMAGE_SUBRESOURCE_LAYOUT,
+            get_image_subresource_layout as *const c_void,
+        ),
+        b"vkGetImageViewAddressNVX" => (
+            &GET_IMAGE_VIEW_ADDRESS_NVX,
+            get_image_view_address_nvx as *const c_void,
+        ),
+        b"vkGetImageViewHandleNVX" => (
+            &GET_IMAGE_VIEW_HANDLE_NVX,
+            get_image_view_handle_nvx as *const c_void,
+        ),
+        b"vkGetMemoryAndroidHardwareBufferANDROID" => (
+            &GET_MEMORY_ANDROID_HARDWARE_BUFFER_ANDROID,
+            get_memory_android_hardware_buffer_android as *const c_void,
+        ),
+        b"vkGetMemoryFdKHR" => (&GET_MEMORY_FD_KHR, get_memory_fd_khr as *const c_void),
+        b"vkGetMemoryFdPropertiesKHR" => (
+            &GET_MEMORY_FD_PROPERTIES_KHR,
+            get_memory_fd_properties_khr as *const c_void,
+        ),
+        b"vkGetMemoryHostPointerPropertiesEXT" => (
+            &GET_MEMORY_HOST_POINTER_PROPERTIES_EXT,
+            get_memory_host_pointer_properties_ext as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandleKHR" => (
+            &GET_MEMORY_WIN32_HANDLE_KHR,
+            get_memory_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandleNV" => (
+            &GET_MEMORY_WIN32_HANDLE_NV,
+            get_memory_win32_handle_nv as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandlePropertiesKHR" => (
+            &GET_MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+            get_memory_win32_handle_properties_khr as *const c_void,
+        ),
+        b"vkGetPastPresentationTimingGOOGLE" => (
+            &GET_PAST_PRESENTATION_TIMING_GOOGLE,
+            get_past_presentation_timing_google as *const c_void,
+        ),
+        b"vkGetPerformanceParameterINTEL" => (
+            &GET_PERFORMANCE_PARAMETER_INTEL,
+            get_performance_parameter_intel as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => (
+            &GET_PHYSICAL_DEVICE_CALIBRATEABLE_TIME_DOMAINS_EXT,
+            get_physical_device_calibrateable_time_domains_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV" => (
+            &GET_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+            get_physical_device_cooperative_matrix_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT" => (
+            &GET_PHYSICAL_DEVICE_DIRECT_FB_PRESENTATION_SUPPORT_EXT,
+            get_physical_device_direct_fb_presentation_support_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES2_KHR,
+            get_physical_device_display_plane_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => (
+            &GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES_KHR,
+            get_physical_device_display_plane_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES2_KHR,
+            get_physical_device_display_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPropertiesKHR" => (
+            &GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES_KHR,
+            get_physical_device_display_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferProperties" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES,
+            get_physical_device_external_buffer_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferPropertiesKHR" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES_KHR,
+            get_physical_device_external_buffer_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFenceProperties" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES,
+            get_physical_device_external_fence_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFencePropertiesKHR" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES_KHR,
+            get_physical_device_external_fence_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_PROPERTIES_NV,
+            get_physical_device_external_image_format_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphoreProperties" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES,
+            get_physical_device_external_semaphore_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphorePropertiesKHR" => (
+            &GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES_KHR,
+            get_physical_device_external_semaphore_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures" => (
+            &GET_PHYSICAL_DEVICE_FEATURES,
+            get_physical_device_features as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures2" => (
+            &GET_PHYSICAL_DEVICE_FEATURES2,
+            get_physical_device_features2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures2KHR" => (
+            &GET_PHYSICAL_DEVICE_FEATURES2_KHR,
+            get_physical_device_features2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties" => (
+            &GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES,
+            get_physical_device_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2" => (
+            &GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2,
+            get_physical_device_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2_KHR,
+            get_physical_device_format_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFragmentShadingRatesKHR" => (
+            &GET_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATES_KHR,
+            get_physical_device_fragment_shading_rates_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties" => (
+            &GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES,
+            get_physical_device_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2" => (
+            &GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2,
+            get_physical_device_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2_KHR,
+            get_physical_device_image_format_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties" => (
+            &GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES,
+            get_physical_device_memory_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2" => (
+            &GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2,
+            get_physical_device_memory_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2_KHR,
+            get_physical_device_memory_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMultisamplePropertiesEXT" => (
+            &GET_PHYSICAL_DEVICE_MULTISAMPLE_PROPERTIES_EXT,
+            get_physical_device_multisample_properties_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDevicePresentRectanglesKHR" => (
+            &GET_PHYSICAL_DEVICE_PRESENT_RECTANGLES_KHR,
+            get_physical_device_present_rectangles_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties" => (
+            &GET_PHYSICAL_DEVICE_PROPERTIES,
+            get_physical_device_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties2" => (
+            &GET_PHYSICAL_DEVICE_PROPERTIES2,
+            get_physical_device_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_PROPERTIES2_KHR,
+            get_physical_device_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR" => (
+            &GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_PASSES_KHR,
+            get_physical_device_queue_family_performance_query_passes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => (
+            &GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES,
+            get_physical_device_queue_family_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2" => (
+            &GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2,
+            get_physical_device_queue_family_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2_KHR,
+            get_physical_device_queue_family_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties" => (
+            &GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES,
+            get_physical_device_sparse_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2" => (
+            &GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2,
+            get_physical_device_sparse_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2KHR" => (
+            &GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2_KHR,
+            get_physical_device_sparse_image_format_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV" => (
+            &GET_PHYSICAL_DEVICE_SUPPORTED_FRAMEBUFFER_MIXED_SAMPLES_COMBINATIONS_NV,
+            get_physical_device_supported_framebuffer_mixed_samples_combinations_nv
+                as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2EXT" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_EXT,
+            get_physical_device_surface_capabilities2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2KHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_KHR,
+            get_physical_device_surface_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES_KHR,
+            get_physical_device_surface_capabilities_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormats2KHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_FORMATS2_KHR,
+            get_physical_device_surface_formats2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormatsKHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_FORMATS_KHR,
+            get_physical_device_surface_formats_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModes2EXT" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES2_EXT,
+            get_physical_device_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModesKHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES_KHR,
+            get_physical_device_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceSupportKHR" => (
+            &GET_PHYSICAL_DEVICE_SURFACE_SUPPORT_KHR,
+            get_physical_device_surface_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceToolPropertiesEXT" => (
+            &GET_PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT,
+            get_physical_device_tool_properties_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWaylandPresentationSupportKHR" => (
+            &GET_PHYSICAL_DEVICE_WAYLAND_PRESENTATION_SUPPORT_KHR,
+            get_physical_device_wayland_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWin32PresentationSupportKHR" => (
+            &GET_PHYSICAL_DEVICE_WIN32_PRESENTATION_SUPPORT_KHR,
+            get_physical_device_win32_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => (
+            &GET_PHYSICAL_DEVICE_XCB_PRESENTATION_SUPPORT_KHR,
+            get_physical_device_xcb_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXlibPresentationSupportKHR" => (
+            &GET_PHYSICAL_DEVICE_XLIB_PRESENTATION_SUPPORT_KHR,
+            get_physical_device_xlib_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPipelineCacheData" => (
+            &GET_PIPELINE_CACHE_DATA,
+            get_pipeline_cache_data as *const c_void,
+        ),
+        b"vkGetPipelineExecutableInternalRepresentationsKHR" => (
+            &GET_PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATIONS_KHR,
+            get_pipeline_executable_internal_representations_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutablePropertiesKHR" => (
+            &GET_PIPELINE_EXECUTABLE_PROPERTIES_KHR,
+            get_pipeline_executable_properties_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutableStatisticsKHR" => (
+            &GET_PIPELINE_EXECUTABLE_STATISTICS_KHR,
+            get_pipeline_executable_statistics_khr as *const c_void,
+        ),
+        b"vkGetPrivateDataEXT" => (&GET_PRIVATE_DATA_EXT, get_private_data_ext as *const c_void),
+        b"vkGetQueryPoolResults" => (
+            &GET_QUERY_POOL_RESULTS,
+            get_query_pool_results as *const c_void,
+        ),
+        b"vkGetQueueCheckpointDataNV" => (
+            &GET_QUEUE_CHECKPOINT_DATA_NV,
+            get_queue_checkpoint_data_nv as *const c_void,
+        ),
+        b"vkGetRandROutputDisplayEXT" => (
+            &GET_RAND_R_OUTPUT_DISPLAY_EXT,
+            get_rand_r_output_display_ext as *const c_void,
+        ),
+        b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => (
+            &GET_RAY_TRACING_CAPTURE_REPLAY_SHADER_GROUP_HANDLES_KHR,
+            get_ray_tracing_capture_replay_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesKHR" => (
+            &GET_RAY_TRACING_SHADER_GROUP_HANDLES_KHR,
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesNV" => (
+            &GET_RAY_TRACING_SHADER_GROUP_HANDLES_NV,
+            get_ray_tracing_shader_group_handles_nv as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupStackSizeKHR" => (
+            &GET_RAY_TRACING_SHADER_GROUP_STACK_SIZE_KHR,
+            get_ray_tracing_shader_group_stack_size_khr as *const c_void,
+        ),
+        b"vkGetRefreshCycleDurationGOOGLE" => (
+            &GET_REFRESH_CYCLE_DURATION_GOOGLE,
+            get_refresh_cycle_duration_google as *const c_void,
+        ),
+        b"vkGetRenderAreaGranularity" => (
+            &GET_RENDER_AREA_GRANULARITY,
+            get_render_area_granularity as *const c_void,
+        ),
+        b"vkGetSemaphoreCounterValue" => (
+            &GET_SEMAPHORE_COUNTER_VALUE,
+            get_semaphore_counter_value as *const c_void,
+        ),
+        b"vkGetSemaphoreCounterValueKHR" => (
+            &GET_SEMAPHORE_COUNTER_VALUE_KHR,
+            get_semaphore_counter_value_khr as *const c_void,
+        ),
+        b"vkGetSemaphoreFdKHR" => (&GET_SEMAPHORE_FD_KHR, get_semaphore_fd_khr as *const c_void),
+        b"vkGetSemaphoreWin32HandleKHR" => (
+            &GET_SEMAPHORE_WIN32_HANDLE_KHR,
+            get_semaphore_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetShaderInfoAMD" => (&GET_SHADER_INFO_AMD, get_shader_info_amd as *const c_void),
+        b"vkGetSwapchainCounterEXT" => (
+            &GET_SWAPCHAIN_COUNTER_EXT,
+            get_swapchain_counter_ext as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsage2ANDROID" => (
+            &GET_SWAPCHAIN_GRALLOC_USAGE2_ANDROID,
+            get_swapchain_gralloc_usage2_android as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsageANDROID" => (
+            &GET_SWAPCHAIN_GRALLOC_USAGE_ANDROID,
+            get_swapchain_gralloc_usage_android as *const c_void,
+        ),
+        b"vkGetSwapchainImagesKHR" => (
+            &GET_SWAPCHAIN_IMAGES_KHR,
+            get_swapchain_images_khr as *const c_void,
+        ),
+        b"vkGetSwapchainStatusKHR" => (
+            &GET_SWAPCHAIN_STATUS_KHR,
+            get_swapchain_status_khr as *const c_void,
+        ),
+        b"vkGetValidationCacheDataEXT" => (
+            &GET_VALIDATION_CACHE_DATA_EXT,
+            get_validation_cache_data_ext as *const c_void,
+        ),
+        b"vkImportFenceFdKHR" => (&IMPORT_FENCE_FD_KHR, import_fence_fd_khr as *const c_void),
+        b"vkImportFenceWin32HandleKHR" => (
+            &IMPORT_FENCE_WIN32_HANDLE_KHR,
+            import_fence_win32_handle_khr as *const c_void,
+        ),
+        b"vkImportSemaphoreFdKHR" => (
+            &IMPORT_SEMAPHORE_FD_KHR,
+            import_semaphore_fd_khr as *const c_void,
+        ),
+        b"vkImportSemaphoreWin32HandleKHR" => (
+            &IMPORT_SEMAPHORE_WIN32_HANDLE_KHR,
+            import_semaphore_win32_handle_khr as *const c_void,
+        ),
+        b"vkInitializePerformanceApiINTEL" => (
+            &INITIALIZE_PERFORMANCE_API_INTEL,
+            initialize_performance_api_intel as *const c_void,
+        ),
+        b"vkInvalidateMappedMemoryRanges" => (
+            &INVALIDATE_MAPPED_MEMORY_RANGES,
+            invalidate_mapped_memory_ranges as *const c_void,
+        ),
+        b"vkMapMemory" => (&MAP_MEMORY, map_memory as *const c_void),
+        b"vkMergePipelineCaches" => (
+            &MERGE_PIPELINE_CACHES,
+            merge_pipeline_caches as *const c_void,
+        ),
+        b"vkMergeValidationCachesEXT" => (
+            &MERGE_VALIDATION_CACHES_EXT,
+            merge_validation_caches_ext as *const c_void,
+        ),
+        b"vkQueueBeginDebugUtilsLabelEXT" => (
+            &QUEUE_BEGIN_DEBUG_UTILS_LABEL_EXT,
+            queue_begin_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueueBindSparse" => (&QUEUE_BIND_SPARSE, queue_bind_sparse as *const c_void),
+        b"vkQueueEndDebugUtilsLabelEXT" => (
+            &QUEUE_END_DEBUG_UTILS_LABEL_EXT,
+            queue_end_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueueInsertDebugUtilsLabelEXT" => (
+            &QUEUE_INSERT_DEBUG_UTILS_LABEL_EXT,
+            queue_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueuePresentKHR" => (&QUEUE_PRESENT_KHR, queue_present_khr as *const c_void),
+        b"vkQueueSetPerformanceConfigurationINTEL" => (
+            &QUEUE_SET_PERFORMANCE_CONFIGURATION_INTEL,
+            queue_set_performance_configuration_intel as *const c_void,
+        ),
+        b"vkQueueSignalReleaseImageANDROID" => (
+            &QUEUE_SIGNAL_RELEASE_IMAGE_ANDROID,
+            queue_signal_release_image_android as *const c_void,
+        ),
+        b"vkQueueSubmit" => (&QUEUE_SUBMIT, queue_submit as *const c_void),
+        b"vkQueueWaitIdle" => (&QUEUE_WAIT_IDLE, queue_wait_idle as *const c_void),
+        b"vkRegisterDeviceEventEXT" => (
+            &REGISTER_DEVICE_EVENT_EXT,
+            register_device_event_ext as *const c_void,
+        ),
+        b"vkRegisterDisplayEventEXT" => (
+            &REGISTER_DISPLAY_EVENT_EXT,
+            register_display_event_ext as *const c_void,
+        ),
+        b"vkReleaseDisplayEXT" => (&RELEASE_DISPLAY_EXT, release_display_ext as *const c_void),
+        b"vkReleaseFullScreenExclusiveModeEXT" => (
+            &RELEASE_FULL_SCREEN_EXCLUSIVE_MODE_EXT,
+            release_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkReleasePerformanceConfigurationINTEL" => (
+            &RELEASE_PERFORMANCE_CONFIGURATION_INTEL,
+            release_performance_configuration_intel as *const c_void,
+        ),
+        b"vkReleaseProfilingLockKHR" => (
+            &RELEASE_PROFILING_LOCK_KHR,
+            release_profiling_lock_khr as *const c_void,
+        ),
+        b"vkResetCommandBuffer" => (&RESET_COMMAND_BUFFER, reset_command_buffer as *const c_void),
+        b"vkResetCommandPool" => (&RESET_COMMAND_POOL, reset_command_pool as *const c_void),
+        b"vkResetDescriptorPool" => (
+            &RESET_DESCRIPTOR_POOL,
+            reset_descriptor_pool as *const c_void,
+        ),
+        b"vkResetEvent" => (&RESET_EVENT, reset_event as *const c_void),
+        b"vkResetFences" => (&RESET_FENCES, reset_fences as *const c_void),
+        b"vkResetQueryPool" => (&RESET_QUERY_POOL, reset_query_pool as *const c_void),
+        b"vkResetQueryPoolEXT" => (&RESET_QUERY_POOL_EXT, reset_query_pool_ext as *const c_void),
+        b"vkSetDebugUtilsObjectNameEXT" => (
+            &SET_DEBUG_UTILS_OBJECT_NAME_EXT,
+            set_debug_utils_object_name_ext as *const c_void,
+        ),
+        b"vkSetDebugUtilsObjectTagEXT" => (
+            &SET_DEBUG_UTILS_OBJECT_TAG_EXT,
+            set_debug_utils_object_tag_ext as *const c_void,
+        ),
+        b"vkSetEvent" => (&SET_EVENT, set_event as *const c_void),
+        b"vkSetHdrMetadataEXT" => (&SET_HDR_METADATA_EXT, set_hdr_metadata_ext as *const c_void),
+        b"vkSetLocalDimmingAMD" => (
+            &SET_LOCAL_DIMMING_AMD,
+            set_local_dimming_amd as *const c_void,
+        ),
+        b"vkSetPrivateDataEXT" => (&SET_PRIVATE_DATA_EXT, set_private_data_ext as *const c_void),
+        b"vkSignalSemaphore" => (&SIGNAL_SEMAPHORE, signal_semaphore as *const c_void),
+        b"vkSignalSemaphoreKHR" => (&SIGNAL_SEMAPHORE_KHR, signal_semaphore_khr as *const c_void),
+        b"vkSubmitDebugUtilsMessageEXT" => (
+            &SUBMIT_DEBUG_UTILS_MESSAGE_EXT,
+            submit_debug_utils_message_ext as *const c_void,
+        ),
+        b"vkTrimCommandPool" => (&TRIM_COMMAND_POOL, trim_command_pool as *const c_void),
+        b"vkTrimCommandPoolKHR" => (
+            &TRIM_COMMAND_POOL_KHR,
+            trim_command_pool_khr as *const c_void,
+        ),
+        b"vkUninitializePerformanceApiINTEL" => (
+            &UNINITIALIZE_PERFORMANCE_API_INTEL,
+            uninitialize_performance_api_intel as *const c_void,
+        ),
+        b"vkUnmapMemory" => (&UNMAP_MEMORY, unmap_memory as *const c_void),
+        b"vkUpdateDescriptorSetWithTemplate" => (
+            &UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE,
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSetWithTemplateKHR" => (
+            &UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE_KHR,
+            update_descriptor_set_with_template_khr as *const c_void,
+        ),
+        b"vkUpdateDescriptorSets" => (
+            &UPDATE_DESCRIPTOR_SETS,
+            update_descriptor_sets as *const c_void,
+        ),
+        b"vkWaitForFences" => (&WAIT_FOR_FENCES, wait_for_fences as *const c_void),
+        b"vkWaitSemaphores" => (&WAIT_SEMAPHORES, wait_semaphores as *const c_void),
+        b"vkWaitSemaphoresKHR" => (&WAIT_SEMAPHORES_KHR, wait_semaphores_khr as *const c_void),
+        b"vkWriteAccelerationStructuresPropertiesKHR" => (
+            &WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR,
+            write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        _ => return None,
+    })
+}
+static ACQUIRE_FULL_SCREEN_EXCLUSIVE_MODE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_full_screen_exclusive_mode_ext(
+    device: Device,
+    swapchain: SwapchainKHR,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR) -> Result =
+        unsafe { mem::transmute(ACQUIRE_FULL_SCREEN_EXCLUSIVE_MODE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkAcquireFullScreenExclusiveModeEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain);
+    record(&TraceCall {
+        command: "vkAcquireFullScreenExclusiveModeEXT",
+        args: &[("device", &device), ("swapchain", &swapchain)],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_IMAGE_ANDROID: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_image_android(
+    device: Device,
+    image: Image,
+    native_fence_fd: c_int,
+    semaphore: Semaphore,
+    fence: Fence,
+) -> Result {
+    let real: extern "system" fn(Device, Image, c_int, Semaphore, Fence) -> Result =
+        unsafe { mem::transmute(ACQUIRE_IMAGE_ANDROID.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkAcquireImageANDROID",
+        &[
+            handle_arg("device", device),
+            handle_arg("image", image),
+            handle_arg("semaphore", semaphore),
+            handle_arg("fence", fence),
+        ],
+    );
+    let result = real(device, image, native_fence_fd, semaphore, fence);
+    record(&TraceCall {
+        command: "vkAcquireImageANDROID",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("nativeFenceFd", &native_fence_fd),
+            ("semaphore", &semaphore),
+            ("fence", &fence),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_NEXT_IMAGE2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_next_image2_khr(
+    device: Device,
+    p_acquire_info: *const AcquireNextImageInfoKHR,
+    p_image_index: *mut u32,
+) -> Result {
+    let real: extern "system" fn(Device, *const AcquireNextImageInfoKHR, *mut u32) -> Result =
+        unsafe { mem::transmute(ACQUIRE_NEXT_IMAGE2_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkAcquireNextImage2KHR", &[handle_arg("device", device)]);
+    let result = real(device, p_acquire_info, p_image_index);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkAcquireNextImage2KHR",
+        args: &[
+            ("device", &device),
+            ("pAcquireInfo", &Pointee(p_acquire_info, true)),
+            ("pImageIndex", &Pointee(p_image_index, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_NEXT_IMAGE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_next_image_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    timeout: u64,
+    semaphore: Semaphore,
+    fence: Fence,
+    p_image_index: *mut u32,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR, u64, Semaphore, Fence, *mut u32) -> Result =
+        unsafe { mem::transmute(ACQUIRE_NEXT_IMAGE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkAcquireNextImageKHR",
+        &[
+            handle_arg("swapchain", swapchain),
+            handle_arg("semaphore", semaphore),
+            handle_arg("fence", fence),
+        ],
+    );
+    check_handles(
+        "vkAcquireNextImageKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+            handle_arg("semaphore", semaphore),
+            handle_arg("fence", fence),
+        ],
+    );
+    let result = real(device, swapchain, timeout, semaphore, fence, p_image_index);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkAcquireNextImageKHR",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            ("timeout", &timeout),
+            ("semaphore", &semaphore),
+            ("fence", &fence),
+            ("pImageIndex", &Pointee(p_image_index, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_PERFORMANCE_CONFIGURATION_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_performance_configuration_intel(
+    device: Device,
+    p_acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
+    p_configuration: *mut PerformanceConfigurationINTEL,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PerformanceConfigurationAcquireInfoINTEL,
+        *mut PerformanceConfigurationINTEL,
+    ) -> Result =
+        unsafe { mem::transmute(ACQUIRE_PERFORMANCE_CONFIGURATION_INTEL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkAcquirePerformanceConfigurationINTEL",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_acquire_info, p_configuration);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkAcquirePerformanceConfigurationINTEL",
+                false,
+                Some(handle_arg("device", device)),
+                p_configuration,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkAcquirePerformanceConfigurationINTEL",
+        args: &[
+            ("device", &device),
+            ("pAcquireInfo", &Pointee(p_acquire_info, true)),
+            ("pConfiguration", &Pointee(p_configuration, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_PROFILING_LOCK_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_profiling_lock_khr(
+    device: Device,
+    p_info: *const AcquireProfilingLockInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *const AcquireProfilingLockInfoKHR) -> Result =
+        unsafe { mem::transmute(ACQUIRE_PROFILING_LOCK_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkAcquireProfilingLockKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkAcquireProfilingLockKHR",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static ACQUIRE_XLIB_DISPLAY_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn acquire_xlib_display_ext(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    display: DisplayKHR,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, *mut Display, DisplayKHR) -> Result =
+        unsafe { mem::transmute(ACQUIRE_XLIB_DISPLAY_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkAcquireXlibDisplayEXT",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("display", display),
+        ],
+    );
+    let result = real(physical_device, dpy, display);
+    record(&TraceCall {
+        command: "vkAcquireXlibDisplayEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("dpy", &dpy),
+            ("display", &display),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ALLOCATE_COMMAND_BUFFERS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn allocate_command_buffers(
+    device: Device,
+    p_allocate_info: *const CommandBufferAllocateInfo,
+    p_command_buffers: *mut CommandBuffer,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const CommandBufferAllocateInfo,
+        *mut CommandBuffer,
+    ) -> Result = unsafe { mem::transmute(ALLOCATE_COMMAND_BUFFERS.load(Ordering::Relaxed)) };
+    check_handles("vkAllocateCommandBuffers", &[handle_arg("device", device)]);
+    let result = real(device, p_allocate_info, p_command_buffers);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkAllocateCommandBuffers",
+                true,
+                Some(handle_arg(
+                    "pAllocateInfo->commandPool",
+                    (*p_allocate_info).command_pool,
+                )),
+                p_command_buffers,
+                (*p_allocate_info).command_buffer_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkAllocateCommandBuffers",
+        args: &[
+            ("device", &device),
+            ("pAllocateInfo", &Pointee(p_allocate_info, true)),
+            ("pCommandBuffers", &p_command_buffers),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ALLOCATE_DESCRIPTOR_SETS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn allocate_descriptor_sets(
+    device: Device,
+    p_allocate_info: *const DescriptorSetAllocateInfo,
+    p_descriptor_sets: *mut DescriptorSet,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorSetAllocateInfo,
+        *mut DescriptorSet,
+    ) -> Result = unsafe { mem::transmute(ALLOCATE_DESCRIPTOR_SETS.load(Ordering::Relaxed)) };
+    check_handles("vkAllocateDescriptorSets", &[handle_arg("device", device)]);
+    let result = real(device, p_allocate_info, p_descriptor_sets);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkAllocateDescriptorSets",
+                true,
+                Some(handle_arg(
+                    "pAllocateInfo->descriptorPool",
+                    (*p_allocate_info).descriptor_pool,
+                )),
+                p_descriptor_sets,
+                (*p_allocate_info).descriptor_set_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkAllocateDescriptorSets",
+        args: &[
+            ("device", &device),
+            ("pAllocateInfo", &Pointee(p_allocate_info, true)),
+            ("pDescriptorSets", &p_descriptor_sets),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ALLOCATE_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn allocate_memory(
+    device: Device,
+    p_allocate_info: *const MemoryAllocateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_memory: *mut DeviceMemory,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const MemoryAllocateInfo,
+        *const AllocationCallbacks,
+        *mut DeviceMemory,
+    ) -> Result = unsafe { mem::transmute(ALLOCATE_MEMORY.load(Ordering::Relaxed)) };
+    check_handles("vkAllocateMemory", &[handle_arg("device", device)]);
+    let result = real(device, p_allocate_info, p_allocator, p_memory);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkAllocateMemory",
+                true,
+                Some(handle_arg("device", device)),
+                p_memory,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkAllocateMemory",
+        args: &[
+            ("device", &device),
+            ("pAllocateInfo", &Pointee(p_allocate_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pMemory", &Pointee(p_memory, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BEGIN_COMMAND_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn begin_command_buffer(
+    command_buffer: CommandBuffer,
+    p_begin_info: *const CommandBufferBeginInfo,
+) -> Result {
+    let real: extern "system" fn(CommandBuffer, *const CommandBufferBeginInfo) -> Result =
+        unsafe { mem::transmute(BEGIN_COMMAND_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkBeginCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkBeginCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_begin_info);
+    record(&TraceCall {
+        command: "vkBeginCommandBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pBeginInfo", &Pointee(p_begin_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_ACCELERATION_STRUCTURE_MEMORY_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_acceleration_structure_memory_nv(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const BindAccelerationStructureMemoryInfoNV,
+    ) -> Result =
+        unsafe { mem::transmute(BIND_ACCELERATION_STRUCTURE_MEMORY_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkBindAccelerationStructureMemoryNV",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, bind_info_count, p_bind_infos);
+    record(&TraceCall {
+        command: "vkBindAccelerationStructureMemoryNV",
+        args: &[
+            ("device", &device),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfos",
+                &Pointees(p_bind_infos, bind_info_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_BUFFER_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_buffer_memory(
+    device: Device,
+    buffer: Buffer,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    let real: extern "system" fn(Device, Buffer, DeviceMemory, DeviceSize) -> Result =
+        unsafe { mem::transmute(BIND_BUFFER_MEMORY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkBindBufferMemory", &[handle_arg("buffer", buffer)]);
+    check_handles(
+        "vkBindBufferMemory",
+        &[
+            handle_arg("device", device),
+            handle_arg("buffer", buffer),
+            handle_arg("memory", memory),
+        ],
+    );
+    let result = real(device, buffer, memory, memory_offset);
+    record(&TraceCall {
+        command: "vkBindBufferMemory",
+        args: &[
+            ("device", &device),
+            ("buffer", &buffer),
+            ("memory", &memory),
+            ("memoryOffset", &memory_offset),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_BUFFER_MEMORY2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_buffer_memory2(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const BindBufferMemoryInfo) -> Result =
+        unsafe { mem::transmute(BIND_BUFFER_MEMORY2.load(Ordering::Relaxed)) };
+    check_handles("vkBindBufferMemory2", &[handle_arg("device", device)]);
+    let result = real(device, bind_info_count, p_bind_infos);
+    record(&TraceCall {
+        command: "vkBindBufferMemory2",
+        args: &[
+            ("device", &device),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfos",
+                &Pointees(p_bind_infos, bind_info_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_BUFFER_MEMORY2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_buffer_memory2_khr(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const BindBufferMemoryInfo) -> Result =
+        unsafe { mem::transmute(BIND_BUFFER_MEMORY2_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkBindBufferMemory2KHR", &[handle_arg("device", device)]);
+    let result = real(device, bind_info_count, p_bind_infos);
+    record(&TraceCall {
+        command: "vkBindBufferMemory2KHR",
+        args: &[
+            ("device", &device),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfos",
+                &Pointees(p_bind_infos, bind_info_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_IMAGE_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_image_memory(
+    device: Device,
+    image: Image,
+    memory: DeviceMemory,
+    memory_offset: DeviceSize,
+) -> Result {
+    let real: extern "system" fn(Device, Image, DeviceMemory, DeviceSize) -> Result =
+        unsafe { mem::transmute(BIND_IMAGE_MEMORY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkBindImageMemory", &[handle_arg("image", image)]);
+    check_handles(
+        "vkBindImageMemory",
+        &[
+            handle_arg("device", device),
+            handle_arg("image", image),
+            handle_arg("memory", memory),
+        ],
+    );
+    let result = real(device, image, memory, memory_offset);
+    record(&TraceCall {
+        command: "vkBindImageMemory",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("memory", &memory),
+            ("memoryOffset", &memory_offset),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_IMAGE_MEMORY2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_image_memory2(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const BindImageMemoryInfo) -> Result =
+        unsafe { mem::transmute(BIND_IMAGE_MEMORY2.load(Ordering::Relaxed)) };
+    check_handles("vkBindImageMemory2", &[handle_arg("device", device)]);
+    let result = real(device, bind_info_count, p_bind_infos);
+    record(&TraceCall {
+        command: "vkBindImageMemory2",
+        args: &[
+            ("device", &device),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfos",
+                &Pointees(p_bind_infos, bind_info_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BIND_IMAGE_MEMORY2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn bind_image_memory2_khr(
+    device: Device,
+    bind_info_count: u32,
+    p_bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const BindImageMemoryInfo) -> Result =
+        unsafe { mem::transmute(BIND_IMAGE_MEMORY2_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkBindImageMemory2KHR", &[handle_arg("device", device)]);
+    let result = real(device, bind_info_count, p_bind_infos);
+    record(&TraceCall {
+        command: "vkBindImageMemory2KHR",
+        args: &[
+            ("device", &device),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfos",
+                &Pointees(p_bind_infos, bind_info_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static BUILD_ACCELERATION_STRUCTURES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn build_acceleration_structures_khr(
+    device: Device,
+    deferred_operation: DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        u32,
+        *const AccelerationStructureBuildGeometryInfoKHR,
+        *const *const AccelerationStructureBuildRangeInfoKHR,
+    ) -> Result =
+        unsafe { mem::transmute(BUILD_ACCELERATION_STRUCTURES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkBuildAccelerationStructuresKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("deferredOperation", deferred_operation),
+        ],
+    );
+    let result = real(
+        device,
+        deferred_operation,
+        info_count,
+        p_infos,
+        pp_build_range_infos,
+    );
+    record(&TraceCall {
+        command: "vkBuildAccelerationStructuresKHR",
+        args: &[
+            ("device", &device),
+            ("deferredOperation", &deferred_operation),
+            ("infoCount", &info_count),
+            ("pInfos", &Pointees(p_infos, info_count as usize, true)),
+            ("ppBuildRangeInfos", &pp_build_range_infos),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CMD_BEGIN_CONDITIONAL_RENDERING_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: CommandBuffer,
+    p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const ConditionalRenderingBeginInfoEXT) -> c_void =
+        unsafe { mem::transmute(CMD_BEGIN_CONDITIONAL_RENDERING_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginConditionalRenderingEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginConditionalRenderingEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_conditional_rendering_begin);
+    record(&TraceCall {
+        command: "vkCmdBeginConditionalRenderingEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pConditionalRenderingBegin",
+                &Pointee(p_conditional_rendering_begin, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const DebugUtilsLabelEXT) -> c_void =
+        unsafe { mem::transmute(CMD_BEGIN_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_label_info);
+    record(&TraceCall {
+        command: "vkCmdBeginDebugUtilsLabelEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pLabelInfo", &Pointee(p_label_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_QUERY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_query(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, QueryPool, u32, QueryControlFlags) -> c_void =
+        unsafe { mem::transmute(CMD_BEGIN_QUERY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginQuery",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginQuery",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, query_pool, query, flags);
+    record(&TraceCall {
+        command: "vkCmdBeginQuery",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("query", &query),
+            ("flags", &flags),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_QUERY_INDEXED_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    flags: QueryControlFlags,
+    index: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, QueryPool, u32, QueryControlFlags, u32) -> c_void =
+        unsafe { mem::transmute(CMD_BEGIN_QUERY_INDEXED_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginQueryIndexedEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginQueryIndexedEXT",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, query_pool, query, flags, index);
+    record(&TraceCall {
+        command: "vkCmdBeginQueryIndexedEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("query", &query),
+            ("flags", &flags),
+            ("index", &index),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_RENDER_PASS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_render_pass(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    contents: SubpassContents,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const RenderPassBeginInfo,
+        SubpassContents,
+    ) -> c_void = unsafe { mem::transmute(CMD_BEGIN_RENDER_PASS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginRenderPass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginRenderPass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_render_pass_begin, contents);
+    record(&TraceCall {
+        command: "vkCmdBeginRenderPass",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pRenderPassBegin", &Pointee(p_render_pass_begin, true)),
+            ("contents", &contents),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_RENDER_PASS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_render_pass2(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    p_subpass_begin_info: *const SubpassBeginInfo,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const RenderPassBeginInfo,
+        *const SubpassBeginInfo,
+    ) -> c_void = unsafe { mem::transmute(CMD_BEGIN_RENDER_PASS2.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginRenderPass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginRenderPass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    record(&TraceCall {
+        command: "vkCmdBeginRenderPass2",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pRenderPassBegin", &Pointee(p_render_pass_begin, true)),
+            ("pSubpassBeginInfo", &Pointee(p_subpass_begin_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_RENDER_PASS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_render_pass2_khr(
+    command_buffer: CommandBuffer,
+    p_render_pass_begin: *const RenderPassBeginInfo,
+    p_subpass_begin_info: *const SubpassBeginInfo,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const RenderPassBeginInfo,
+        *const SubpassBeginInfo,
+    ) -> c_void = unsafe { mem::transmute(CMD_BEGIN_RENDER_PASS2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginRenderPass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginRenderPass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    record(&TraceCall {
+        command: "vkCmdBeginRenderPass2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pRenderPassBegin", &Pointee(p_render_pass_begin, true)),
+            ("pSubpassBeginInfo", &Pointee(p_subpass_begin_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BEGIN_TRANSFORM_FEEDBACK_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        u32,
+        *const Buffer,
+        *const DeviceSize,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_BEGIN_TRANSFORM_FEEDBACK_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBeginTransformFeedbackEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBeginTransformFeedbackEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    record(&TraceCall {
+        command: "vkCmdBeginTransformFeedbackEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstCounterBuffer", &first_counter_buffer),
+            ("counterBufferCount", &counter_buffer_count),
+            (
+                "pCounterBuffers",
+                &Pointees(p_counter_buffers, counter_buffer_count as usize, true),
+            ),
+            (
+                "pCounterBufferOffsets",
+                &Pointees(
+                    p_counter_buffer_offsets,
+                    counter_buffer_count as usize,
+                    true,
+                ),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_DESCRIPTOR_SETS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        PipelineBindPoint,
+        PipelineLayout,
+        u32,
+        u32,
+        *const DescriptorSet,
+        u32,
+        *const u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_BIND_DESCRIPTOR_SETS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindDescriptorSets",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindDescriptorSets",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("layout", layout),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    );
+    record(&TraceCall {
+        command: "vkCmdBindDescriptorSets",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineBindPoint", &pipeline_bind_point),
+            ("layout", &layout),
+            ("firstSet", &first_set),
+            ("descriptorSetCount", &descriptor_set_count),
+            (
+                "pDescriptorSets",
+                &Pointees(p_descriptor_sets, descriptor_set_count as usize, true),
+            ),
+            ("dynamicOffsetCount", &dynamic_offset_count),
+            (
+                "pDynamicOffsets",
+                &Pointees(p_dynamic_offsets, dynamic_offset_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_INDEX_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_index_buffer(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    index_type: IndexType,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize, IndexType) -> c_void =
+        unsafe { mem::transmute(CMD_BIND_INDEX_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindIndexBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindIndexBuffer",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+        ],
+    );
+    let result = real(command_buffer, buffer, offset, index_type);
+    record(&TraceCall {
+        command: "vkCmdBindIndexBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("indexType", &index_type),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_PIPELINE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_pipeline(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    pipeline: Pipeline,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, PipelineBindPoint, Pipeline) -> c_void =
+        unsafe { mem::transmute(CMD_BIND_PIPELINE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindPipeline",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindPipeline",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(command_buffer, pipeline_bind_point, pipeline);
+    record(&TraceCall {
+        command: "vkCmdBindPipeline",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineBindPoint", &pipeline_bind_point),
+            ("pipeline", &pipeline),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_PIPELINE_SHADER_GROUP_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_pipeline_shader_group_nv(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    pipeline: Pipeline,
+    group_index: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, PipelineBindPoint, Pipeline, u32) -> c_void =
+        unsafe { mem::transmute(CMD_BIND_PIPELINE_SHADER_GROUP_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindPipelineShaderGroupNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindPipelineShaderGroupNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(command_buffer, pipeline_bind_point, pipeline, group_index);
+    record(&TraceCall {
+        command: "vkCmdBindPipelineShaderGroupNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineBindPoint", &pipeline_bind_point),
+            ("pipeline", &pipeline),
+            ("groupIndex", &group_index),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_SHADING_RATE_IMAGE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: CommandBuffer,
+    image_view: ImageView,
+    image_layout: ImageLayout,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, ImageView, ImageLayout) -> c_void =
+        unsafe { mem::transmute(CMD_BIND_SHADING_RATE_IMAGE_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindShadingRateImageNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindShadingRateImageNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("imageView", image_view),
+        ],
+    );
+    let result = real(command_buffer, image_view, image_layout);
+    record(&TraceCall {
+        command: "vkCmdBindShadingRateImageNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("imageView", &image_view),
+            ("imageLayout", &image_layout),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_TRANSFORM_FEEDBACK_BUFFERS_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+    p_sizes: *const DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        u32,
+        *const Buffer,
+        *const DeviceSize,
+        *const DeviceSize,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_BIND_TRANSFORM_FEEDBACK_BUFFERS_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    );
+    record(&TraceCall {
+        command: "vkCmdBindTransformFeedbackBuffersEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstBinding", &first_binding),
+            ("bindingCount", &binding_count),
+            (
+                "pBuffers",
+                &Pointees(p_buffers, binding_count as usize, true),
+            ),
+            (
+                "pOffsets",
+                &Pointees(p_offsets, binding_count as usize, true),
+            ),
+            ("pSizes", &Pointees(p_sizes, binding_count as usize, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_VERTEX_BUFFERS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        u32,
+        *const Buffer,
+        *const DeviceSize,
+    ) -> c_void = unsafe { mem::transmute(CMD_BIND_VERTEX_BUFFERS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindVertexBuffers",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindVertexBuffers",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    );
+    record(&TraceCall {
+        command: "vkCmdBindVertexBuffers",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstBinding", &first_binding),
+            ("bindingCount", &binding_count),
+            (
+                "pBuffers",
+                &Pointees(p_buffers, binding_count as usize, true),
+            ),
+            (
+                "pOffsets",
+                &Pointees(p_offsets, binding_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BIND_VERTEX_BUFFERS2_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_bind_vertex_buffers2_ext(
+    command_buffer: CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const Buffer,
+    p_offsets: *const DeviceSize,
+    p_sizes: *const DeviceSize,
+    p_strides: *const DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        u32,
+        *const Buffer,
+        *const DeviceSize,
+        *const DeviceSize,
+        *const DeviceSize,
+    ) -> c_void = unsafe { mem::transmute(CMD_BIND_VERTEX_BUFFERS2_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBindVertexBuffers2EXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBindVertexBuffers2EXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+        p_strides,
+    );
+    record(&TraceCall {
+        command: "vkCmdBindVertexBuffers2EXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstBinding", &first_binding),
+            ("bindingCount", &binding_count),
+            (
+                "pBuffers",
+                &Pointees(p_buffers, binding_count as usize, true),
+            ),
+            (
+                "pOffsets",
+                &Pointees(p_offsets, binding_count as usize, true),
+            ),
+            ("pSizes", &Pointees(p_sizes, binding_count as usize, true)),
+            (
+                "pStrides",
+                &Pointees(p_strides, binding_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BLIT_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_blit_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageBlit,
+    filter: Filter,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        Image,
+        ImageLayout,
+        u32,
+        *const ImageBlit,
+        Filter,
+    ) -> c_void = unsafe { mem::transmute(CMD_BLIT_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBlitImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBlitImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcImage", src_image),
+            handle_arg("dstImage", dst_image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    );
+    record(&TraceCall {
+        command: "vkCmdBlitImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcImage", &src_image),
+            ("srcImageLayout", &src_image_layout),
+            ("dstImage", &dst_image),
+            ("dstImageLayout", &dst_image_layout),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+            ("filter", &filter),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BLIT_IMAGE2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_blit_image2_khr(
+    command_buffer: CommandBuffer,
+    p_blit_image_info: *const BlitImageInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const BlitImageInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_BLIT_IMAGE2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBlitImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBlitImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_blit_image_info);
+    record(&TraceCall {
+        command: "vkCmdBlitImage2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pBlitImageInfo", &Pointee(p_blit_image_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BUILD_ACCELERATION_STRUCTURE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: CommandBuffer,
+    p_info: *const AccelerationStructureInfoNV,
+    instance_data: Buffer,
+    instance_offset: DeviceSize,
+    update: Bool32,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    scratch: Buffer,
+    scratch_offset: DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const AccelerationStructureInfoNV,
+        Buffer,
+        DeviceSize,
+        Bool32,
+        AccelerationStructureNV,
+        AccelerationStructureNV,
+        Buffer,
+        DeviceSize,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_BUILD_ACCELERATION_STRUCTURE_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBuildAccelerationStructureNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBuildAccelerationStructureNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("instanceData", instance_data),
+            handle_arg("dst", dst),
+            handle_arg("src", src),
+            handle_arg("scratch", scratch),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    );
+    record(&TraceCall {
+        command: "vkCmdBuildAccelerationStructureNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pInfo", &Pointee(p_info, true)),
+            ("instanceData", &instance_data),
+            ("instanceOffset", &instance_offset),
+            ("update", &update),
+            ("dst", &dst),
+            ("src", &src),
+            ("scratch", &scratch),
+            ("scratchOffset", &scratch_offset),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BUILD_ACCELERATION_STRUCTURES_INDIRECT_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_build_acceleration_structures_indirect_khr(
+    command_buffer: CommandBuffer,
+    info_count: u32,
+    p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    p_indirect_device_addresses: *const DeviceAddress,
+    p_indirect_strides: *const u32,
+    pp_max_primitive_counts: *const *const u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const AccelerationStructureBuildGeometryInfoKHR,
+        *const DeviceAddress,
+        *const u32,
+        *const *const u32,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_BUILD_ACCELERATION_STRUCTURES_INDIRECT_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        info_count,
+        p_infos,
+        p_indirect_device_addresses,
+        p_indirect_strides,
+        pp_max_primitive_counts,
+    );
+    record(&TraceCall {
+        command: "vkCmdBuildAccelerationStructuresIndirectKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("infoCount", &info_count),
+            ("pInfos", &Pointees(p_infos, info_count as usize, true)),
+            (
+                "pIndirectDeviceAddresses",
+                &Pointees(p_indirect_device_addresses, info_count as usize, true),
+            ),
+            (
+                "pIndirectStrides",
+                &Pointees(p_indirect_strides, info_count as usize, true),
+            ),
+            ("ppMaxPrimitiveCounts", &pp_max_primitive_counts),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_BUILD_ACCELERATION_STRUCTURES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_build_acceleration_structures_khr(
+    command_buffer: CommandBuffer,
+    info_count: u32,
+    p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const AccelerationStructureBuildGeometryInfoKHR,
+        *const *const AccelerationStructureBuildRangeInfoKHR,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_BUILD_ACCELERATION_STRUCTURES_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdBuildAccelerationStructuresKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdBuildAccelerationStructuresKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, info_count, p_infos, pp_build_range_infos);
+    record(&TraceCall {
+        command: "vkCmdBuildAccelerationStructuresKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("infoCount", &info_count),
+            ("pInfos", &Pointees(p_infos, info_count as usize, true)),
+            ("ppBuildRangeInfos", &pp_build_range_infos),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_CLEAR_ATTACHMENTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_clear_attachments(
+    command_buffer: CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const ClearAttachment,
+    rect_count: u32,
+    p_rects: *const ClearRect,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const ClearAttachment,
+        u32,
+        *const ClearRect,
+    ) -> c_void = unsafe { mem::transmute(CMD_CLEAR_ATTACHMENTS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdClearAttachments",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdClearAttachments",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    );
+    record(&TraceCall {
+        command: "vkCmdClearAttachments",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("attachmentCount", &attachment_count),
+            (
+                "pAttachments",
+                &Pointees(p_attachments, attachment_count as usize, true),
+            ),
+            ("rectCount", &rect_count),
+            ("pRects", &Pointees(p_rects, rect_count as usize, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_CLEAR_COLOR_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_clear_color_image(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_color: *const ClearColorValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        *const ClearColorValue,
+        u32,
+        *const ImageSubresourceRange,
+    ) -> c_void = unsafe { mem::transmute(CMD_CLEAR_COLOR_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdClearColorImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdClearColorImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("image", image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    );
+    record(&TraceCall {
+        command: "vkCmdClearColorImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("image", &image),
+            ("imageLayout", &image_layout),
+            ("pColor", &p_color),
+            ("rangeCount", &range_count),
+            ("pRanges", &Pointees(p_ranges, range_count as usize, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_CLEAR_DEPTH_STENCIL_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: CommandBuffer,
+    image: Image,
+    image_layout: ImageLayout,
+    p_depth_stencil: *const ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const ImageSubresourceRange,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        *const ClearDepthStencilValue,
+        u32,
+        *const ImageSubresourceRange,
+    ) -> c_void = unsafe { mem::transmute(CMD_CLEAR_DEPTH_STENCIL_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdClearDepthStencilImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdClearDepthStencilImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("image", image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    );
+    record(&TraceCall {
+        command: "vkCmdClearDepthStencilImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("image", &image),
+            ("imageLayout", &image_layout),
+            ("pDepthStencil", &Pointee(p_depth_stencil, true)),
+            ("rangeCount", &range_count),
+            ("pRanges", &Pointees(p_ranges, range_count as usize, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_acceleration_structure_khr(
+    command_buffer: CommandBuffer,
+    p_info: *const CopyAccelerationStructureInfoKHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const CopyAccelerationStructureInfoKHR) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyAccelerationStructureKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyAccelerationStructureKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_info);
+    record(&TraceCall {
+        command: "vkCmdCopyAccelerationStructureKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_ACCELERATION_STRUCTURE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: CommandBuffer,
+    dst: AccelerationStructureNV,
+    src: AccelerationStructureNV,
+    mode: CopyAccelerationStructureModeKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        AccelerationStructureNV,
+        AccelerationStructureNV,
+        CopyAccelerationStructureModeKHR,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_ACCELERATION_STRUCTURE_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyAccelerationStructureNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyAccelerationStructureNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("dst", dst),
+            handle_arg("src", src),
+        ],
+    );
+    let result = real(command_buffer, dst, src, mode);
+    record(&TraceCall {
+        command: "vkCmdCopyAccelerationStructureNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("dst", &dst),
+            ("src", &src),
+            ("mode", &mode),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_acceleration_structure_to_memory_khr(
+    command_buffer: CommandBuffer,
+    p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const CopyAccelerationStructureToMemoryInfoKHR,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_info);
+    record(&TraceCall {
+        command: "vkCmdCopyAccelerationStructureToMemoryKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_buffer(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferCopy,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, Buffer, u32, *const BufferCopy) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyBuffer",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcBuffer", src_buffer),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    record(&TraceCall {
+        command: "vkCmdCopyBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcBuffer", &src_buffer),
+            ("dstBuffer", &dst_buffer),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_BUFFER2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_buffer2_khr(
+    command_buffer: CommandBuffer,
+    p_copy_buffer_info: *const CopyBufferInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const CopyBufferInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_BUFFER2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyBuffer2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyBuffer2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_copy_buffer_info);
+    record(&TraceCall {
+        command: "vkCmdCopyBuffer2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pCopyBufferInfo", &Pointee(p_copy_buffer_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_BUFFER_TO_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: CommandBuffer,
+    src_buffer: Buffer,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        Image,
+        ImageLayout,
+        u32,
+        *const BufferImageCopy,
+    ) -> c_void = unsafe { mem::transmute(CMD_COPY_BUFFER_TO_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyBufferToImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyBufferToImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcBuffer", src_buffer),
+            handle_arg("dstImage", dst_image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    record(&TraceCall {
+        command: "vkCmdCopyBufferToImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcBuffer", &src_buffer),
+            ("dstImage", &dst_image),
+            ("dstImageLayout", &dst_image_layout),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_BUFFER_TO_IMAGE2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_buffer_to_image2_khr(
+    command_buffer: CommandBuffer,
+    p_copy_buffer_to_image_info: *const CopyBufferToImageInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const CopyBufferToImageInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_BUFFER_TO_IMAGE2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyBufferToImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyBufferToImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_copy_buffer_to_image_info);
+    record(&TraceCall {
+        command: "vkCmdCopyBufferToImage2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pCopyBufferToImageInfo",
+                &Pointee(p_copy_buffer_to_image_info, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageCopy,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        Image,
+        ImageLayout,
+        u32,
+        *const ImageCopy,
+    ) -> c_void = unsafe { mem::transmute(CMD_COPY_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcImage", src_image),
+            handle_arg("dstImage", dst_image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    record(&TraceCall {
+        command: "vkCmdCopyImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcImage", &src_image),
+            ("srcImageLayout", &src_image_layout),
+            ("dstImage", &dst_image),
+            ("dstImageLayout", &dst_image_layout),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_IMAGE2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_image2_khr(
+    command_buffer: CommandBuffer,
+    p_copy_image_info: *const CopyImageInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const CopyImageInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_IMAGE2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_copy_image_info);
+    record(&TraceCall {
+        command: "vkCmdCopyImage2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pCopyImageInfo", &Pointee(p_copy_image_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_IMAGE_TO_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_buffer: Buffer,
+    region_count: u32,
+    p_regions: *const BufferImageCopy,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        Buffer,
+        u32,
+        *const BufferImageCopy,
+    ) -> c_void = unsafe { mem::transmute(CMD_COPY_IMAGE_TO_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyImageToBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyImageToBuffer",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcImage", src_image),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    record(&TraceCall {
+        command: "vkCmdCopyImageToBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcImage", &src_image),
+            ("srcImageLayout", &src_image_layout),
+            ("dstBuffer", &dst_buffer),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_IMAGE_TO_BUFFER2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_image_to_buffer2_khr(
+    command_buffer: CommandBuffer,
+    p_copy_image_to_buffer_info: *const CopyImageToBufferInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const CopyImageToBufferInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_COPY_IMAGE_TO_BUFFER2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyImageToBuffer2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyImageToBuffer2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_copy_image_to_buffer_info);
+    record(&TraceCall {
+        command: "vkCmdCopyImageToBuffer2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pCopyImageToBufferInfo",
+                &Pointee(p_copy_image_to_buffer_info, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_memory_to_acceleration_structure_khr(
+    command_buffer: CommandBuffer,
+    p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const CopyMemoryToAccelerationStructureInfoKHR,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_info);
+    record(&TraceCall {
+        command: "vkCmdCopyMemoryToAccelerationStructureKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_COPY_QUERY_POOL_RESULTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        QueryPool,
+        u32,
+        u32,
+        Buffer,
+        DeviceSize,
+        DeviceSize,
+        QueryResultFlags,
+    ) -> c_void = unsafe { mem::transmute(CMD_COPY_QUERY_POOL_RESULTS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdCopyQueryPoolResults",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdCopyQueryPoolResults",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    );
+    record(&TraceCall {
+        command: "vkCmdCopyQueryPoolResults",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+            ("queryCount", &query_count),
+            ("dstBuffer", &dst_buffer),
+            ("dstOffset", &dst_offset),
+            ("stride", &stride),
+            ("flags", &flags),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DEBUG_MARKER_BEGIN_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const DebugMarkerMarkerInfoEXT) -> c_void =
+        unsafe { mem::transmute(CMD_DEBUG_MARKER_BEGIN_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDebugMarkerBeginEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDebugMarkerBeginEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_marker_info);
+    record(&TraceCall {
+        command: "vkCmdDebugMarkerBeginEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pMarkerInfo", &Pointee(p_marker_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DEBUG_MARKER_END_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_debug_marker_end_ext(command_buffer: CommandBuffer) -> c_void {
+    let real: extern "system" fn(CommandBuffer) -> c_void =
+        unsafe { mem::transmute(CMD_DEBUG_MARKER_END_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDebugMarkerEndEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDebugMarkerEndEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer);
+    record(&TraceCall {
+        command: "vkCmdDebugMarkerEndEXT",
+        args: &[("commandBuffer", &command_buffer)],
+        result: None,
+    });
+    result
+}
+static CMD_DEBUG_MARKER_INSERT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const DebugMarkerMarkerInfoEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const DebugMarkerMarkerInfoEXT) -> c_void =
+        unsafe { mem::transmute(CMD_DEBUG_MARKER_INSERT_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDebugMarkerInsertEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDebugMarkerInsertEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_marker_info);
+    record(&TraceCall {
+        command: "vkCmdDebugMarkerInsertEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pMarkerInfo", &Pointee(p_marker_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DISPATCH: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_dispatch(
+    command_buffer: CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DISPATCH.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDispatch",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDispatch",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, group_count_x, group_count_y, group_count_z);
+    record(&TraceCall {
+        command: "vkCmdDispatch",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("groupCountX", &group_count_x),
+            ("groupCountY", &group_count_y),
+            ("groupCountZ", &group_count_z),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DISPATCH_BASE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_dispatch_base(
+    command_buffer: CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, u32, u32, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DISPATCH_BASE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDispatchBase",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDispatchBase",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    record(&TraceCall {
+        command: "vkCmdDispatchBase",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("baseGroupX", &base_group_x),
+            ("baseGroupY", &base_group_y),
+            ("baseGroupZ", &base_group_z),
+            ("groupCountX", &group_count_x),
+            ("groupCountY", &group_count_y),
+            ("groupCountZ", &group_count_z),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DISPATCH_BASE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_dispatch_base_khr(
+    command_buffer: CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, u32, u32, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DISPATCH_BASE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDispatchBaseKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDispatchBaseKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    record(&TraceCall {
+        command: "vkCmdDispatchBaseKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("baseGroupX", &base_group_x),
+            ("baseGroupY", &base_group_y),
+            ("baseGroupZ", &base_group_z),
+            ("groupCountX", &group_count_x),
+            ("groupCountY", &group_count_y),
+            ("groupCountZ", &group_count_z),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DISPATCH_INDIRECT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_dispatch_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize) -> c_void =
+        unsafe { mem::transmute(CMD_DISPATCH_INDIRECT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDispatchIndirect",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDispatchIndirect",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+        ],
+    );
+    let result = real(command_buffer, buffer, offset);
+    record(&TraceCall {
+        command: "vkCmdDispatchIndirect",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw(
+    command_buffer: CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkCmdDraw", &[handle_arg("commandBuffer", command_buffer)]);
+    check_handles("vkCmdDraw", &[handle_arg("commandBuffer", command_buffer)]);
+    let result = real(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    );
+    record(&TraceCall {
+        command: "vkCmdDraw",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("vertexCount", &vertex_count),
+            ("instanceCount", &instance_count),
+            ("firstVertex", &first_vertex),
+            ("firstInstance", &first_instance),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDEXED: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indexed(
+    command_buffer: CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, u32, i32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDEXED.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndexed",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndexed",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndexed",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("indexCount", &index_count),
+            ("instanceCount", &instance_count),
+            ("firstIndex", &first_index),
+            ("vertexOffset", &vertex_offset),
+            ("firstInstance", &first_instance),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDEXED_INDIRECT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDEXED_INDIRECT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndexedIndirect",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndexedIndirect",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+        ],
+    );
+    let result = real(command_buffer, buffer, offset, draw_count, stride);
+    record(&TraceCall {
+        command: "vkCmdDrawIndexedIndirect",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("drawCount", &draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDEXED_INDIRECT_COUNT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indexed_indirect_count(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDEXED_INDIRECT_COUNT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndexedIndirectCount",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndexedIndirectCount",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndexedIndirectCount",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDEXED_INDIRECT_COUNT_AMD: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indexed_indirect_count_amd(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDEXED_INDIRECT_COUNT_AMD.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndexedIndirectCountAMD",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndexedIndirectCountAMD",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndexedIndirectCountAMD",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDEXED_INDIRECT_COUNT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indexed_indirect_count_khr(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDEXED_INDIRECT_COUNT_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndexedIndirectCountKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndexedIndirectCountKHR",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndexedIndirectCountKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDIRECT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indirect(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDIRECT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndirect",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndirect",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+        ],
+    );
+    let result = real(command_buffer, buffer, offset, draw_count, stride);
+    record(&TraceCall {
+        command: "vkCmdDrawIndirect",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("drawCount", &draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDIRECT_BYTE_COUNT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: Buffer,
+    counter_buffer_offset: DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, Buffer, DeviceSize, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_INDIRECT_BYTE_COUNT_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndirectByteCountEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndirectByteCountEXT",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("counterBuffer", counter_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndirectByteCountEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("instanceCount", &instance_count),
+            ("firstInstance", &first_instance),
+            ("counterBuffer", &counter_buffer),
+            ("counterBufferOffset", &counter_buffer_offset),
+            ("counterOffset", &counter_offset),
+            ("vertexStride", &vertex_stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDIRECT_COUNT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indirect_count(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_DRAW_INDIRECT_COUNT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndirectCount",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndirectCount",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndirectCount",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDIRECT_COUNT_AMD: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indirect_count_amd(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_DRAW_INDIRECT_COUNT_AMD.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndirectCountAMD",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndirectCountAMD",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndirectCountAMD",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_INDIRECT_COUNT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_indirect_count_khr(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_DRAW_INDIRECT_COUNT_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawIndirectCountKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawIndirectCountKHR",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawIndirectCountKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    count_buffer: Buffer,
+    count_buffer_offset: DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        u32,
+        u32,
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_MESH_TASKS_INDIRECT_COUNT_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+            handle_arg("countBuffer", count_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkCmdDrawMeshTasksIndirectCountNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("countBuffer", &count_buffer),
+            ("countBufferOffset", &count_buffer_offset),
+            ("maxDrawCount", &max_draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_MESH_TASKS_INDIRECT_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: CommandBuffer,
+    buffer: Buffer,
+    offset: DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_MESH_TASKS_INDIRECT_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawMeshTasksIndirectNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawMeshTasksIndirectNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("buffer", buffer),
+        ],
+    );
+    let result = real(command_buffer, buffer, offset, draw_count, stride);
+    record(&TraceCall {
+        command: "vkCmdDrawMeshTasksIndirectNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("buffer", &buffer),
+            ("offset", &offset),
+            ("drawCount", &draw_count),
+            ("stride", &stride),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_DRAW_MESH_TASKS_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_DRAW_MESH_TASKS_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdDrawMeshTasksNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdDrawMeshTasksNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, task_count, first_task);
+    record(&TraceCall {
+        command: "vkCmdDrawMeshTasksNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("taskCount", &task_count),
+            ("firstTask", &first_task),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_END_CONDITIONAL_RENDERING_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: CommandBuffer) -> c_void {
+    let real: extern "system" fn(CommandBuffer) -> c_void =
+        unsafe { mem::transmute(CMD_END_CONDITIONAL_RENDERING_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndConditionalRenderingEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndConditionalRenderingEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer);
+    record(&TraceCall {
+        command: "vkCmdEndConditionalRenderingEXT",
+        args: &[("commandBuffer", &command_buffer)],
+        result: None,
+    });
+    result
+}
+static CMD_END_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: CommandBuffer) -> c_void {
+    let real: extern "system" fn(CommandBuffer) -> c_void =
+        unsafe { mem::transmute(CMD_END_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer);
+    record(&TraceCall {
+        command: "vkCmdEndDebugUtilsLabelEXT",
+        args: &[("commandBuffer", &command_buffer)],
+        result: None,
+    });
+    result
+}
+static CMD_END_QUERY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_query(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, QueryPool, u32) -> c_void =
+        unsafe { mem::transmute(CMD_END_QUERY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndQuery",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndQuery",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, query_pool, query);
+    record(&TraceCall {
+        command: "vkCmdEndQuery",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("query", &query),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_END_QUERY_INDEXED_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    query: u32,
+    index: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, QueryPool, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_END_QUERY_INDEXED_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndQueryIndexedEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndQueryIndexedEXT",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, query_pool, query, index);
+    record(&TraceCall {
+        command: "vkCmdEndQueryIndexedEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("query", &query),
+            ("index", &index),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_END_RENDER_PASS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_render_pass(command_buffer: CommandBuffer) -> c_void {
+    let real: extern "system" fn(CommandBuffer) -> c_void =
+        unsafe { mem::transmute(CMD_END_RENDER_PASS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndRenderPass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndRenderPass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer);
+    record(&TraceCall {
+        command: "vkCmdEndRenderPass",
+        args: &[("commandBuffer", &command_buffer)],
+        result: None,
+    });
+    result
+}
+static CMD_END_RENDER_PASS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_render_pass2(
+    command_buffer: CommandBuffer,
+    p_subpass_end_info: *const SubpassEndInfo,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const SubpassEndInfo) -> c_void =
+        unsafe { mem::transmute(CMD_END_RENDER_PASS2.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndRenderPass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndRenderPass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_subpass_end_info);
+    record(&TraceCall {
+        command: "vkCmdEndRenderPass2",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pSubpassEndInfo", &Pointee(p_subpass_end_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_END_RENDER_PASS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_render_pass2_khr(
+    command_buffer: CommandBuffer,
+    p_subpass_end_info: *const SubpassEndInfo,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const SubpassEndInfo) -> c_void =
+        unsafe { mem::transmute(CMD_END_RENDER_PASS2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndRenderPass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndRenderPass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_subpass_end_info);
+    record(&TraceCall {
+        command: "vkCmdEndRenderPass2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pSubpassEndInfo", &Pointee(p_subpass_end_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_END_TRANSFORM_FEEDBACK_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const Buffer,
+    p_counter_buffer_offsets: *const DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        u32,
+        *const Buffer,
+        *const DeviceSize,
+    ) -> c_void = unsafe { mem::transmute(CMD_END_TRANSFORM_FEEDBACK_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdEndTransformFeedbackEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdEndTransformFeedbackEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    record(&TraceCall {
+        command: "vkCmdEndTransformFeedbackEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstCounterBuffer", &first_counter_buffer),
+            ("counterBufferCount", &counter_buffer_count),
+            (
+                "pCounterBuffers",
+                &Pointees(p_counter_buffers, counter_buffer_count as usize, true),
+            ),
+            (
+                "pCounterBufferOffsets",
+                &Pointees(
+                    p_counter_buffer_offsets,
+                    counter_buffer_count as usize,
+                    true,
+                ),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_EXECUTE_COMMANDS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_execute_commands(
+    command_buffer: CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, *const CommandBuffer) -> c_void =
+        unsafe { mem::transmute(CMD_EXECUTE_COMMANDS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdExecuteCommands",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdExecuteCommands",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, command_buffer_count, p_command_buffers);
+    record(&TraceCall {
+        command: "vkCmdExecuteCommands",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("commandBufferCount", &command_buffer_count),
+            (
+                "pCommandBuffers",
+                &Pointees(p_command_buffers, command_buffer_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_EXECUTE_GENERATED_COMMANDS_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_execute_generated_commands_nv(
+    command_buffer: CommandBuffer,
+    is_preprocessed: Bool32,
+    p_generated_commands_info: *const GeneratedCommandsInfoNV,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Bool32, *const GeneratedCommandsInfoNV) -> c_void =
+        unsafe { mem::transmute(CMD_EXECUTE_GENERATED_COMMANDS_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdExecuteGeneratedCommandsNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdExecuteGeneratedCommandsNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, is_preprocessed, p_generated_commands_info);
+    record(&TraceCall {
+        command: "vkCmdExecuteGeneratedCommandsNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("isPreprocessed", &is_preprocessed),
+            (
+                "pGeneratedCommandsInfo",
+                &Pointee(p_generated_commands_info, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_FILL_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_fill_buffer(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    size: DeviceSize,
+    data: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Buffer, DeviceSize, DeviceSize, u32) -> c_void =
+        unsafe { mem::transmute(CMD_FILL_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdFillBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdFillBuffer",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(command_buffer, dst_buffer, dst_offset, size, data);
+    record(&TraceCall {
+        command: "vkCmdFillBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("dstBuffer", &dst_buffer),
+            ("dstOffset", &dst_offset),
+            ("size", &size),
+            ("data", &data),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_INSERT_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: CommandBuffer,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const DebugUtilsLabelEXT) -> c_void =
+        unsafe { mem::transmute(CMD_INSERT_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdInsertDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdInsertDebugUtilsLabelEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_label_info);
+    record(&TraceCall {
+        command: "vkCmdInsertDebugUtilsLabelEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pLabelInfo", &Pointee(p_label_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_NEXT_SUBPASS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_next_subpass(
+    command_buffer: CommandBuffer,
+    contents: SubpassContents,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, SubpassContents) -> c_void =
+        unsafe { mem::transmute(CMD_NEXT_SUBPASS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdNextSubpass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdNextSubpass",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, contents);
+    record(&TraceCall {
+        command: "vkCmdNextSubpass",
+        args: &[("commandBuffer", &command_buffer), ("contents", &contents)],
+        result: None,
+    });
+    result
+}
+static CMD_NEXT_SUBPASS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_next_subpass2(
+    command_buffer: CommandBuffer,
+    p_subpass_begin_info: *const SubpassBeginInfo,
+    p_subpass_end_info: *const SubpassEndInfo,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const SubpassBeginInfo,
+        *const SubpassEndInfo,
+    ) -> c_void = unsafe { mem::transmute(CMD_NEXT_SUBPASS2.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdNextSubpass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdNextSubpass2",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    record(&TraceCall {
+        command: "vkCmdNextSubpass2",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pSubpassBeginInfo", &Pointee(p_subpass_begin_info, true)),
+            ("pSubpassEndInfo", &Pointee(p_subpass_end_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_NEXT_SUBPASS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_next_subpass2_khr(
+    command_buffer: CommandBuffer,
+    p_subpass_begin_info: *const SubpassBeginInfo,
+    p_subpass_end_info: *const SubpassEndInfo,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const SubpassBeginInfo,
+        *const SubpassEndInfo,
+    ) -> c_void = unsafe { mem::transmute(CMD_NEXT_SUBPASS2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdNextSubpass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdNextSubpass2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    record(&TraceCall {
+        command: "vkCmdNextSubpass2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pSubpassBeginInfo", &Pointee(p_subpass_begin_info, true)),
+            ("pSubpassEndInfo", &Pointee(p_subpass_end_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_PIPELINE_BARRIER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_pipeline_barrier(
+    command_buffer: CommandBuffer,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    dependency_flags: DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        PipelineStageFlags,
+        PipelineStageFlags,
+        DependencyFlags,
+        u32,
+        *const MemoryBarrier,
+        u32,
+        *const BufferMemoryBarrier,
+        u32,
+        *const ImageMemoryBarrier,
+    ) -> c_void = unsafe { mem::transmute(CMD_PIPELINE_BARRIER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdPipelineBarrier",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdPipelineBarrier",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    record(&TraceCall {
+        command: "vkCmdPipelineBarrier",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcStageMask", &src_stage_mask),
+            ("dstStageMask", &dst_stage_mask),
+            ("dependencyFlags", &dependency_flags),
+            ("memoryBarrierCount", &memory_barrier_count),
+            (
+                "pMemoryBarriers",
+                &Pointees(p_memory_barriers, memory_barrier_count as usize, true),
+            ),
+            ("bufferMemoryBarrierCount", &buffer_memory_barrier_count),
+            (
+                "pBufferMemoryBarriers",
+                &Pointees(
+                    p_buffer_memory_barriers,
+                    buffer_memory_barrier_count as usize,
+                    true,
+                ),
+            ),
+            ("imageMemoryBarrierCount", &image_memory_barrier_count),
+            (
+                "pImageMemoryBarriers",
+                &Pointees(
+                    p_image_memory_barriers,
+                    image_memory_barrier_count as usize,
+                    true,
+                ),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_PREPROCESS_GENERATED_COMMANDS_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_preprocess_generated_commands_nv(
+    command_buffer: CommandBuffer,
+    p_generated_commands_info: *const GeneratedCommandsInfoNV,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const GeneratedCommandsInfoNV) -> c_void =
+        unsafe { mem::transmute(CMD_PREPROCESS_GENERATED_COMMANDS_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdPreprocessGeneratedCommandsNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdPreprocessGeneratedCommandsNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_generated_commands_info);
+    record(&TraceCall {
+        command: "vkCmdPreprocessGeneratedCommandsNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pGeneratedCommandsInfo",
+                &Pointee(p_generated_commands_info, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_PUSH_CONSTANTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_push_constants(
+    command_buffer: CommandBuffer,
+    layout: PipelineLayout,
+    stage_flags: ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        PipelineLayout,
+        ShaderStageFlags,
+        u32,
+        u32,
+        *const c_void,
+    ) -> c_void = unsafe { mem::transmute(CMD_PUSH_CONSTANTS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdPushConstants",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdPushConstants",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("layout", layout),
+        ],
+    );
+    let result = real(command_buffer, layout, stage_flags, offset, size, p_values);
+    record(&TraceCall {
+        command: "vkCmdPushConstants",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("layout", &layout),
+            ("stageFlags", &stage_flags),
+            ("offset", &offset),
+            ("size", &size),
+            ("pValues", &p_values),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_PUSH_DESCRIPTOR_SET_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: CommandBuffer,
+    pipeline_bind_point: PipelineBindPoint,
+    layout: PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        PipelineBindPoint,
+        PipelineLayout,
+        u32,
+        u32,
+        *const WriteDescriptorSet,
+    ) -> c_void = unsafe { mem::transmute(CMD_PUSH_DESCRIPTOR_SET_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdPushDescriptorSetKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdPushDescriptorSetKHR",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("layout", layout),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    );
+    record(&TraceCall {
+        command: "vkCmdPushDescriptorSetKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineBindPoint", &pipeline_bind_point),
+            ("layout", &layout),
+            ("set", &set),
+            ("descriptorWriteCount", &descriptor_write_count),
+            (
+                "pDescriptorWrites",
+                &Pointees(p_descriptor_writes, descriptor_write_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: CommandBuffer,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    layout: PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        DescriptorUpdateTemplate,
+        PipelineLayout,
+        u32,
+        *const c_void,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("descriptorUpdateTemplate", descriptor_update_template),
+            handle_arg("layout", layout),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        descriptor_update_template,
+        layout,
+        set,
+        p_data,
+    );
+    record(&TraceCall {
+        command: "vkCmdPushDescriptorSetWithTemplateKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("descriptorUpdateTemplate", &descriptor_update_template),
+            ("layout", &layout),
+            ("set", &set),
+            ("pData", &p_data),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_RESET_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_reset_event(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Event, PipelineStageFlags) -> c_void =
+        unsafe { mem::transmute(CMD_RESET_EVENT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdResetEvent",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdResetEvent",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("event", event),
+        ],
+    );
+    let result = real(command_buffer, event, stage_mask);
+    record(&TraceCall {
+        command: "vkCmdResetEvent",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("event", &event),
+            ("stageMask", &stage_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_RESET_QUERY_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_reset_query_pool(
+    command_buffer: CommandBuffer,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, QueryPool, u32, u32) -> c_void =
+        unsafe { mem::transmute(CMD_RESET_QUERY_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdResetQueryPool",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdResetQueryPool",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, query_pool, first_query, query_count);
+    record(&TraceCall {
+        command: "vkCmdResetQueryPool",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+            ("queryCount", &query_count),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_RESOLVE_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_resolve_image(
+    command_buffer: CommandBuffer,
+    src_image: Image,
+    src_image_layout: ImageLayout,
+    dst_image: Image,
+    dst_image_layout: ImageLayout,
+    region_count: u32,
+    p_regions: *const ImageResolve,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Image,
+        ImageLayout,
+        Image,
+        ImageLayout,
+        u32,
+        *const ImageResolve,
+    ) -> c_void = unsafe { mem::transmute(CMD_RESOLVE_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdResolveImage",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdResolveImage",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("srcImage", src_image),
+            handle_arg("dstImage", dst_image),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    record(&TraceCall {
+        command: "vkCmdResolveImage",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("srcImage", &src_image),
+            ("srcImageLayout", &src_image_layout),
+            ("dstImage", &dst_image),
+            ("dstImageLayout", &dst_image_layout),
+            ("regionCount", &region_count),
+            (
+                "pRegions",
+                &Pointees(p_regions, region_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_RESOLVE_IMAGE2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_resolve_image2_khr(
+    command_buffer: CommandBuffer,
+    p_resolve_image_info: *const ResolveImageInfo2KHR,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const ResolveImageInfo2KHR) -> c_void =
+        unsafe { mem::transmute(CMD_RESOLVE_IMAGE2_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdResolveImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdResolveImage2KHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_resolve_image_info);
+    record(&TraceCall {
+        command: "vkCmdResolveImage2KHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pResolveImageInfo", &Pointee(p_resolve_image_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_BLEND_CONSTANTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_blend_constants(
+    command_buffer: CommandBuffer,
+    blend_constants: *const [f32; 4],
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const [f32; 4]) -> c_void =
+        unsafe { mem::transmute(CMD_SET_BLEND_CONSTANTS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetBlendConstants",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetBlendConstants",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, blend_constants);
+    record(&TraceCall {
+        command: "vkCmdSetBlendConstants",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("blendConstants", &blend_constants),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_CHECKPOINT_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const c_void) -> c_void =
+        unsafe { mem::transmute(CMD_SET_CHECKPOINT_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetCheckpointNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetCheckpointNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_checkpoint_marker);
+    record(&TraceCall {
+        command: "vkCmdSetCheckpointNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pCheckpointMarker", &p_checkpoint_marker),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_COARSE_SAMPLE_ORDER_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: CommandBuffer,
+    sample_order_type: CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        CoarseSampleOrderTypeNV,
+        u32,
+        *const CoarseSampleOrderCustomNV,
+    ) -> c_void = unsafe { mem::transmute(CMD_SET_COARSE_SAMPLE_ORDER_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetCoarseSampleOrderNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetCoarseSampleOrderNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetCoarseSampleOrderNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("sampleOrderType", &sample_order_type),
+            ("customSampleOrderCount", &custom_sample_order_count),
+            (
+                "pCustomSampleOrders",
+                &Pointees(
+                    p_custom_sample_orders,
+                    custom_sample_order_count as usize,
+                    true,
+                ),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_CULL_MODE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_cull_mode_ext(
+    command_buffer: CommandBuffer,
+    cull_mode: CullModeFlags,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, CullModeFlags) -> c_void =
+        unsafe { mem::transmute(CMD_SET_CULL_MODE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetCullModeEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetCullModeEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, cull_mode);
+    record(&TraceCall {
+        command: "vkCmdSetCullModeEXT",
+        args: &[("commandBuffer", &command_buffer), ("cullMode", &cull_mode)],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_BIAS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_bias(
+    command_buffer: CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, f32, f32, f32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_BIAS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthBias",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthBias",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetDepthBias",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("depthBiasConstantFactor", &depth_bias_constant_factor),
+            ("depthBiasClamp", &depth_bias_clamp),
+            ("depthBiasSlopeFactor", &depth_bias_slope_factor),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_BOUNDS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_bounds(
+    command_buffer: CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, f32, f32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_BOUNDS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthBounds",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthBounds",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, min_depth_bounds, max_depth_bounds);
+    record(&TraceCall {
+        command: "vkCmdSetDepthBounds",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("minDepthBounds", &min_depth_bounds),
+            ("maxDepthBounds", &max_depth_bounds),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_BOUNDS_TEST_ENABLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_bounds_test_enable_ext(
+    command_buffer: CommandBuffer,
+    depth_bounds_test_enable: Bool32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Bool32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_BOUNDS_TEST_ENABLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, depth_bounds_test_enable);
+    record(&TraceCall {
+        command: "vkCmdSetDepthBoundsTestEnableEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("depthBoundsTestEnable", &depth_bounds_test_enable),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_COMPARE_OP_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_compare_op_ext(
+    command_buffer: CommandBuffer,
+    depth_compare_op: CompareOp,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, CompareOp) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_COMPARE_OP_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthCompareOpEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthCompareOpEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, depth_compare_op);
+    record(&TraceCall {
+        command: "vkCmdSetDepthCompareOpEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("depthCompareOp", &depth_compare_op),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_TEST_ENABLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_test_enable_ext(
+    command_buffer: CommandBuffer,
+    depth_test_enable: Bool32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Bool32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_TEST_ENABLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, depth_test_enable);
+    record(&TraceCall {
+        command: "vkCmdSetDepthTestEnableEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("depthTestEnable", &depth_test_enable),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEPTH_WRITE_ENABLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_depth_write_enable_ext(
+    command_buffer: CommandBuffer,
+    depth_write_enable: Bool32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Bool32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEPTH_WRITE_ENABLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDepthWriteEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDepthWriteEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, depth_write_enable);
+    record(&TraceCall {
+        command: "vkCmdSetDepthWriteEnableEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("depthWriteEnable", &depth_write_enable),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEVICE_MASK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_device_mask(command_buffer: CommandBuffer, device_mask: u32) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEVICE_MASK.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDeviceMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDeviceMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, device_mask);
+    record(&TraceCall {
+        command: "vkCmdSetDeviceMask",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("deviceMask", &device_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DEVICE_MASK_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_device_mask_khr(
+    command_buffer: CommandBuffer,
+    device_mask: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DEVICE_MASK_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDeviceMaskKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDeviceMaskKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, device_mask);
+    record(&TraceCall {
+        command: "vkCmdSetDeviceMaskKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("deviceMask", &device_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_DISCARD_RECTANGLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const Rect2D,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const Rect2D) -> c_void =
+        unsafe { mem::transmute(CMD_SET_DISCARD_RECTANGLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetDiscardRectangleEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetDiscardRectangleEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetDiscardRectangleEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstDiscardRectangle", &first_discard_rectangle),
+            ("discardRectangleCount", &discard_rectangle_count),
+            (
+                "pDiscardRectangles",
+                &Pointees(p_discard_rectangles, discard_rectangle_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_event(
+    command_buffer: CommandBuffer,
+    event: Event,
+    stage_mask: PipelineStageFlags,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Event, PipelineStageFlags) -> c_void =
+        unsafe { mem::transmute(CMD_SET_EVENT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetEvent",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetEvent",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("event", event),
+        ],
+    );
+    let result = real(command_buffer, event, stage_mask);
+    record(&TraceCall {
+        command: "vkCmdSetEvent",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("event", &event),
+            ("stageMask", &stage_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_EXCLUSIVE_SCISSOR_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const Rect2D,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const Rect2D) -> c_void =
+        unsafe { mem::transmute(CMD_SET_EXCLUSIVE_SCISSOR_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetExclusiveScissorNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetExclusiveScissorNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetExclusiveScissorNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstExclusiveScissor", &first_exclusive_scissor),
+            ("exclusiveScissorCount", &exclusive_scissor_count),
+            (
+                "pExclusiveScissors",
+                &Pointees(p_exclusive_scissors, exclusive_scissor_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_FRAGMENT_SHADING_RATE_ENUM_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_fragment_shading_rate_enum_nv(
+    command_buffer: CommandBuffer,
+    shading_rate: FragmentShadingRateNV,
+    combiner_ops: *const [FragmentShadingRateCombinerOpKHR; 2],
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        FragmentShadingRateNV,
+        *const [FragmentShadingRateCombinerOpKHR; 2],
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_SET_FRAGMENT_SHADING_RATE_ENUM_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetFragmentShadingRateEnumNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetFragmentShadingRateEnumNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, shading_rate, combiner_ops);
+    record(&TraceCall {
+        command: "vkCmdSetFragmentShadingRateEnumNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("shadingRate", &shading_rate),
+            ("combinerOps", &combiner_ops),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_FRAGMENT_SHADING_RATE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_fragment_shading_rate_khr(
+    command_buffer: CommandBuffer,
+    p_fragment_size: *const Extent2D,
+    combiner_ops: *const [FragmentShadingRateCombinerOpKHR; 2],
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const Extent2D,
+        *const [FragmentShadingRateCombinerOpKHR; 2],
+    ) -> c_void =
+        unsafe { mem::transmute(CMD_SET_FRAGMENT_SHADING_RATE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetFragmentShadingRateKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetFragmentShadingRateKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_fragment_size, combiner_ops);
+    record(&TraceCall {
+        command: "vkCmdSetFragmentShadingRateKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pFragmentSize", &Pointee(p_fragment_size, true)),
+            ("combinerOps", &combiner_ops),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_FRONT_FACE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_front_face_ext(
+    command_buffer: CommandBuffer,
+    front_face: FrontFace,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, FrontFace) -> c_void =
+        unsafe { mem::transmute(CMD_SET_FRONT_FACE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetFrontFaceEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetFrontFaceEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, front_face);
+    record(&TraceCall {
+        command: "vkCmdSetFrontFaceEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("frontFace", &front_face),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_LINE_STIPPLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_line_stipple_ext(
+    command_buffer: CommandBuffer,
+    line_stipple_factor: u32,
+    line_stipple_pattern: u16,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u16) -> c_void =
+        unsafe { mem::transmute(CMD_SET_LINE_STIPPLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetLineStippleEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetLineStippleEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, line_stipple_factor, line_stipple_pattern);
+    record(&TraceCall {
+        command: "vkCmdSetLineStippleEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("lineStippleFactor", &line_stipple_factor),
+            ("lineStipplePattern", &line_stipple_pattern),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_LINE_WIDTH: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_line_width(command_buffer: CommandBuffer, line_width: f32) -> c_void {
+    let real: extern "system" fn(CommandBuffer, f32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_LINE_WIDTH.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetLineWidth",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetLineWidth",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, line_width);
+    record(&TraceCall {
+        command: "vkCmdSetLineWidth",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("lineWidth", &line_width),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_PERFORMANCE_MARKER_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_performance_marker_intel(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const PerformanceMarkerInfoINTEL,
+) -> Result {
+    let real: extern "system" fn(CommandBuffer, *const PerformanceMarkerInfoINTEL) -> Result =
+        unsafe { mem::transmute(CMD_SET_PERFORMANCE_MARKER_INTEL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetPerformanceMarkerINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetPerformanceMarkerINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_marker_info);
+    record(&TraceCall {
+        command: "vkCmdSetPerformanceMarkerINTEL",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pMarkerInfo", &Pointee(p_marker_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CMD_SET_PERFORMANCE_OVERRIDE_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_performance_override_intel(
+    command_buffer: CommandBuffer,
+    p_override_info: *const PerformanceOverrideInfoINTEL,
+) -> Result {
+    let real: extern "system" fn(CommandBuffer, *const PerformanceOverrideInfoINTEL) -> Result =
+        unsafe { mem::transmute(CMD_SET_PERFORMANCE_OVERRIDE_INTEL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetPerformanceOverrideINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetPerformanceOverrideINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_override_info);
+    record(&TraceCall {
+        command: "vkCmdSetPerformanceOverrideINTEL",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pOverrideInfo", &Pointee(p_override_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CMD_SET_PERFORMANCE_STREAM_MARKER_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_performance_stream_marker_intel(
+    command_buffer: CommandBuffer,
+    p_marker_info: *const PerformanceStreamMarkerInfoINTEL,
+) -> Result {
+    let real: extern "system" fn(CommandBuffer, *const PerformanceStreamMarkerInfoINTEL) -> Result =
+        unsafe { mem::transmute(CMD_SET_PERFORMANCE_STREAM_MARKER_INTEL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_marker_info);
+    record(&TraceCall {
+        command: "vkCmdSetPerformanceStreamMarkerINTEL",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pMarkerInfo", &Pointee(p_marker_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CMD_SET_PRIMITIVE_TOPOLOGY_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_primitive_topology_ext(
+    command_buffer: CommandBuffer,
+    primitive_topology: PrimitiveTopology,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, PrimitiveTopology) -> c_void =
+        unsafe { mem::transmute(CMD_SET_PRIMITIVE_TOPOLOGY_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetPrimitiveTopologyEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetPrimitiveTopologyEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, primitive_topology);
+    record(&TraceCall {
+        command: "vkCmdSetPrimitiveTopologyEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("primitiveTopology", &primitive_topology),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_RAY_TRACING_PIPELINE_STACK_SIZE_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_ray_tracing_pipeline_stack_size_khr(
+    command_buffer: CommandBuffer,
+    pipeline_stack_size: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32) -> c_void = unsafe {
+        mem::transmute(CMD_SET_RAY_TRACING_PIPELINE_STACK_SIZE_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, pipeline_stack_size);
+    record(&TraceCall {
+        command: "vkCmdSetRayTracingPipelineStackSizeKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineStackSize", &pipeline_stack_size),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_SAMPLE_LOCATIONS_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: CommandBuffer,
+    p_sample_locations_info: *const SampleLocationsInfoEXT,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, *const SampleLocationsInfoEXT) -> c_void =
+        unsafe { mem::transmute(CMD_SET_SAMPLE_LOCATIONS_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetSampleLocationsEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetSampleLocationsEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, p_sample_locations_info);
+    record(&TraceCall {
+        command: "vkCmdSetSampleLocationsEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pSampleLocationsInfo",
+                &Pointee(p_sample_locations_info, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_SCISSOR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_scissor(
+    command_buffer: CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const Rect2D,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const Rect2D) -> c_void =
+        unsafe { mem::transmute(CMD_SET_SCISSOR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetScissor",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetScissor",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, first_scissor, scissor_count, p_scissors);
+    record(&TraceCall {
+        command: "vkCmdSetScissor",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstScissor", &first_scissor),
+            ("scissorCount", &scissor_count),
+            (
+                "pScissors",
+                &Pointees(p_scissors, scissor_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_SCISSOR_WITH_COUNT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_scissor_with_count_ext(
+    command_buffer: CommandBuffer,
+    scissor_count: u32,
+    p_scissors: *const Rect2D,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, *const Rect2D) -> c_void =
+        unsafe { mem::transmute(CMD_SET_SCISSOR_WITH_COUNT_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetScissorWithCountEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetScissorWithCountEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, scissor_count, p_scissors);
+    record(&TraceCall {
+        command: "vkCmdSetScissorWithCountEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("scissorCount", &scissor_count),
+            (
+                "pScissors",
+                &Pointees(p_scissors, scissor_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_STENCIL_COMPARE_MASK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    compare_mask: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, StencilFaceFlags, u32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_STENCIL_COMPARE_MASK.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetStencilCompareMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetStencilCompareMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, face_mask, compare_mask);
+    record(&TraceCall {
+        command: "vkCmdSetStencilCompareMask",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("faceMask", &face_mask),
+            ("compareMask", &compare_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_STENCIL_OP_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_stencil_op_ext(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    fail_op: StencilOp,
+    pass_op: StencilOp,
+    depth_fail_op: StencilOp,
+    compare_op: CompareOp,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        StencilFaceFlags,
+        StencilOp,
+        StencilOp,
+        StencilOp,
+        CompareOp,
+    ) -> c_void = unsafe { mem::transmute(CMD_SET_STENCIL_OP_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetStencilOpEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetStencilOpEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        face_mask,
+        fail_op,
+        pass_op,
+        depth_fail_op,
+        compare_op,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetStencilOpEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("faceMask", &face_mask),
+            ("failOp", &fail_op),
+            ("passOp", &pass_op),
+            ("depthFailOp", &depth_fail_op),
+            ("compareOp", &compare_op),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_STENCIL_REFERENCE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_stencil_reference(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    reference: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, StencilFaceFlags, u32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_STENCIL_REFERENCE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetStencilReference",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetStencilReference",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, face_mask, reference);
+    record(&TraceCall {
+        command: "vkCmdSetStencilReference",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("faceMask", &face_mask),
+            ("reference", &reference),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_STENCIL_TEST_ENABLE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_stencil_test_enable_ext(
+    command_buffer: CommandBuffer,
+    stencil_test_enable: Bool32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, Bool32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_STENCIL_TEST_ENABLE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetStencilTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetStencilTestEnableEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, stencil_test_enable);
+    record(&TraceCall {
+        command: "vkCmdSetStencilTestEnableEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("stencilTestEnable", &stencil_test_enable),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_STENCIL_WRITE_MASK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: CommandBuffer,
+    face_mask: StencilFaceFlags,
+    write_mask: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, StencilFaceFlags, u32) -> c_void =
+        unsafe { mem::transmute(CMD_SET_STENCIL_WRITE_MASK.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetStencilWriteMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetStencilWriteMask",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, face_mask, write_mask);
+    record(&TraceCall {
+        command: "vkCmdSetStencilWriteMask",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("faceMask", &face_mask),
+            ("writeMask", &write_mask),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_VIEWPORT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_viewport(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const Viewport,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const Viewport) -> c_void =
+        unsafe { mem::transmute(CMD_SET_VIEWPORT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetViewport",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetViewport",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, first_viewport, viewport_count, p_viewports);
+    record(&TraceCall {
+        command: "vkCmdSetViewport",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstViewport", &first_viewport),
+            ("viewportCount", &viewport_count),
+            (
+                "pViewports",
+                &Pointees(p_viewports, viewport_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_VIEWPORT_SHADING_RATE_PALETTE_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const ShadingRatePaletteNV,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const ShadingRatePaletteNV) -> c_void =
+        unsafe { mem::transmute(CMD_SET_VIEWPORT_SHADING_RATE_PALETTE_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetViewportShadingRatePaletteNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetViewportShadingRatePaletteNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetViewportShadingRatePaletteNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstViewport", &first_viewport),
+            ("viewportCount", &viewport_count),
+            (
+                "pShadingRatePalettes",
+                &Pointees(p_shading_rate_palettes, viewport_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_VIEWPORT_W_SCALING_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const ViewportWScalingNV,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, u32, *const ViewportWScalingNV) -> c_void =
+        unsafe { mem::transmute(CMD_SET_VIEWPORT_W_SCALING_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetViewportWScalingNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetViewportWScalingNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    );
+    record(&TraceCall {
+        command: "vkCmdSetViewportWScalingNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("firstViewport", &first_viewport),
+            ("viewportCount", &viewport_count),
+            (
+                "pViewportWScalings",
+                &Pointees(p_viewport_w_scalings, viewport_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_SET_VIEWPORT_WITH_COUNT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_set_viewport_with_count_ext(
+    command_buffer: CommandBuffer,
+    viewport_count: u32,
+    p_viewports: *const Viewport,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, u32, *const Viewport) -> c_void =
+        unsafe { mem::transmute(CMD_SET_VIEWPORT_WITH_COUNT_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdSetViewportWithCountEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdSetViewportWithCountEXT",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, viewport_count, p_viewports);
+    record(&TraceCall {
+        command: "vkCmdSetViewportWithCountEXT",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("viewportCount", &viewport_count),
+            (
+                "pViewports",
+                &Pointees(p_viewports, viewport_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_TRACE_RAYS_INDIRECT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_trace_rays_indirect_khr(
+    command_buffer: CommandBuffer,
+    p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    indirect_device_address: DeviceAddress,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        DeviceAddress,
+    ) -> c_void = unsafe { mem::transmute(CMD_TRACE_RAYS_INDIRECT_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdTraceRaysIndirectKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdTraceRaysIndirectKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        indirect_device_address,
+    );
+    record(&TraceCall {
+        command: "vkCmdTraceRaysIndirectKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pRaygenShaderBindingTable",
+                &Pointee(p_raygen_shader_binding_table, true),
+            ),
+            (
+                "pMissShaderBindingTable",
+                &Pointee(p_miss_shader_binding_table, true),
+            ),
+            (
+                "pHitShaderBindingTable",
+                &Pointee(p_hit_shader_binding_table, true),
+            ),
+            (
+                "pCallableShaderBindingTable",
+                &Pointee(p_callable_shader_binding_table, true),
+            ),
+            ("indirectDeviceAddress", &indirect_device_address),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_TRACE_RAYS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_trace_rays_khr(
+    command_buffer: CommandBuffer,
+    p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        *const StridedDeviceAddressRegionKHR,
+        u32,
+        u32,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_TRACE_RAYS_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdTraceRaysKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdTraceRaysKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        width,
+        height,
+        depth,
+    );
+    record(&TraceCall {
+        command: "vkCmdTraceRaysKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "pRaygenShaderBindingTable",
+                &Pointee(p_raygen_shader_binding_table, true),
+            ),
+            (
+                "pMissShaderBindingTable",
+                &Pointee(p_miss_shader_binding_table, true),
+            ),
+            (
+                "pHitShaderBindingTable",
+                &Pointee(p_hit_shader_binding_table, true),
+            ),
+            (
+                "pCallableShaderBindingTable",
+                &Pointee(p_callable_shader_binding_table, true),
+            ),
+            ("width", &width),
+            ("height", &height),
+            ("depth", &depth),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_TRACE_RAYS_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_trace_rays_nv(
+    command_buffer: CommandBuffer,
+    raygen_shader_binding_table_buffer: Buffer,
+    raygen_shader_binding_offset: DeviceSize,
+    miss_shader_binding_table_buffer: Buffer,
+    miss_shader_binding_offset: DeviceSize,
+    miss_shader_binding_stride: DeviceSize,
+    hit_shader_binding_table_buffer: Buffer,
+    hit_shader_binding_offset: DeviceSize,
+    hit_shader_binding_stride: DeviceSize,
+    callable_shader_binding_table_buffer: Buffer,
+    callable_shader_binding_offset: DeviceSize,
+    callable_shader_binding_stride: DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        DeviceSize,
+        Buffer,
+        DeviceSize,
+        DeviceSize,
+        u32,
+        u32,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_TRACE_RAYS_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdTraceRaysNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdTraceRaysNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg(
+                "raygenShaderBindingTableBuffer",
+                raygen_shader_binding_table_buffer,
+            ),
+            handle_arg(
+                "missShaderBindingTableBuffer",
+                miss_shader_binding_table_buffer,
+            ),
+            handle_arg(
+                "hitShaderBindingTableBuffer",
+                hit_shader_binding_table_buffer,
+            ),
+            handle_arg(
+                "callableShaderBindingTableBuffer",
+                callable_shader_binding_table_buffer,
+            ),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    );
+    record(&TraceCall {
+        command: "vkCmdTraceRaysNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            (
+                "raygenShaderBindingTableBuffer",
+                &raygen_shader_binding_table_buffer,
+            ),
+            ("raygenShaderBindingOffset", &raygen_shader_binding_offset),
+            (
+                "missShaderBindingTableBuffer",
+                &miss_shader_binding_table_buffer,
+            ),
+            ("missShaderBindingOffset", &miss_shader_binding_offset),
+            ("missShaderBindingStride", &miss_shader_binding_stride),
+            (
+                "hitShaderBindingTableBuffer",
+                &hit_shader_binding_table_buffer,
+            ),
+            ("hitShaderBindingOffset", &hit_shader_binding_offset),
+            ("hitShaderBindingStride", &hit_shader_binding_stride),
+            (
+                "callableShaderBindingTableBuffer",
+                &callable_shader_binding_table_buffer,
+            ),
+            (
+                "callableShaderBindingOffset",
+                &callable_shader_binding_offset,
+            ),
+            (
+                "callableShaderBindingStride",
+                &callable_shader_binding_stride,
+            ),
+            ("width", &width),
+            ("height", &height),
+            ("depth", &depth),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_UPDATE_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_update_buffer(
+    command_buffer: CommandBuffer,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    data_size: DeviceSize,
+    p_data: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        Buffer,
+        DeviceSize,
+        DeviceSize,
+        *const c_void,
+    ) -> c_void = unsafe { mem::transmute(CMD_UPDATE_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdUpdateBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdUpdateBuffer",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    record(&TraceCall {
+        command: "vkCmdUpdateBuffer",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("dstBuffer", &dst_buffer),
+            ("dstOffset", &dst_offset),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_WAIT_EVENTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_wait_events(
+    command_buffer: CommandBuffer,
+    event_count: u32,
+    p_events: *const Event,
+    src_stage_mask: PipelineStageFlags,
+    dst_stage_mask: PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const MemoryBarrier,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const BufferMemoryBarrier,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const ImageMemoryBarrier,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const Event,
+        PipelineStageFlags,
+        PipelineStageFlags,
+        u32,
+        *const MemoryBarrier,
+        u32,
+        *const BufferMemoryBarrier,
+        u32,
+        *const ImageMemoryBarrier,
+    ) -> c_void = unsafe { mem::transmute(CMD_WAIT_EVENTS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdWaitEvents",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdWaitEvents",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    record(&TraceCall {
+        command: "vkCmdWaitEvents",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("eventCount", &event_count),
+            ("pEvents", &Pointees(p_events, event_count as usize, true)),
+            ("srcStageMask", &src_stage_mask),
+            ("dstStageMask", &dst_stage_mask),
+            ("memoryBarrierCount", &memory_barrier_count),
+            (
+                "pMemoryBarriers",
+                &Pointees(p_memory_barriers, memory_barrier_count as usize, true),
+            ),
+            ("bufferMemoryBarrierCount", &buffer_memory_barrier_count),
+            (
+                "pBufferMemoryBarriers",
+                &Pointees(
+                    p_buffer_memory_barriers,
+                    buffer_memory_barrier_count as usize,
+                    true,
+                ),
+            ),
+            ("imageMemoryBarrierCount", &image_memory_barrier_count),
+            (
+                "pImageMemoryBarriers",
+                &Pointees(
+                    p_image_memory_barriers,
+                    image_memory_barrier_count as usize,
+                    true,
+                ),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_write_acceleration_structures_properties_khr(
+    command_buffer: CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const AccelerationStructureKHR,
+    query_type: QueryType,
+    query_pool: QueryPool,
+    first_query: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const AccelerationStructureKHR,
+        QueryType,
+        QueryPool,
+        u32,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    record(&TraceCall {
+        command: "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("accelerationStructureCount", &acceleration_structure_count),
+            (
+                "pAccelerationStructures",
+                &Pointees(
+                    p_acceleration_structures,
+                    acceleration_structure_count as usize,
+                    true,
+                ),
+            ),
+            ("queryType", &query_type),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const AccelerationStructureNV,
+    query_type: QueryType,
+    query_pool: QueryPool,
+    first_query: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        u32,
+        *const AccelerationStructureNV,
+        QueryType,
+        QueryPool,
+        u32,
+    ) -> c_void = unsafe {
+        mem::transmute(CMD_WRITE_ACCELERATION_STRUCTURES_PROPERTIES_NV.load(Ordering::Relaxed))
+    };
+    let _sync = SyncGuard::new(
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    record(&TraceCall {
+        command: "vkCmdWriteAccelerationStructuresPropertiesNV",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("accelerationStructureCount", &acceleration_structure_count),
+            (
+                "pAccelerationStructures",
+                &Pointees(
+                    p_acceleration_structures,
+                    acceleration_structure_count as usize,
+                    true,
+                ),
+            ),
+            ("queryType", &query_type),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_WRITE_BUFFER_MARKER_AMD: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    dst_buffer: Buffer,
+    dst_offset: DeviceSize,
+    marker: u32,
+) -> c_void {
+    let real: extern "system" fn(
+        CommandBuffer,
+        PipelineStageFlags,
+        Buffer,
+        DeviceSize,
+        u32,
+    ) -> c_void = unsafe { mem::transmute(CMD_WRITE_BUFFER_MARKER_AMD.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdWriteBufferMarkerAMD",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdWriteBufferMarkerAMD",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("dstBuffer", dst_buffer),
+        ],
+    );
+    let result = real(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    );
+    record(&TraceCall {
+        command: "vkCmdWriteBufferMarkerAMD",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineStage", &pipeline_stage),
+            ("dstBuffer", &dst_buffer),
+            ("dstOffset", &dst_offset),
+            ("marker", &marker),
+        ],
+        result: None,
+    });
+    result
+}
+static CMD_WRITE_TIMESTAMP: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn cmd_write_timestamp(
+    command_buffer: CommandBuffer,
+    pipeline_stage: PipelineStageFlags,
+    query_pool: QueryPool,
+    query: u32,
+) -> c_void {
+    let real: extern "system" fn(CommandBuffer, PipelineStageFlags, QueryPool, u32) -> c_void =
+        unsafe { mem::transmute(CMD_WRITE_TIMESTAMP.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkCmdWriteTimestamp",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkCmdWriteTimestamp",
+        &[
+            handle_arg("commandBuffer", command_buffer),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(command_buffer, pipeline_stage, query_pool, query);
+    record(&TraceCall {
+        command: "vkCmdWriteTimestamp",
+        args: &[
+            ("commandBuffer", &command_buffer),
+            ("pipelineStage", &pipeline_stage),
+            ("queryPool", &query_pool),
+            ("query", &query),
+        ],
+        result: None,
+    });
+    result
+}
+static COMPILE_DEFERRED_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn compile_deferred_nv(device: Device, pipeline: Pipeline, shader: u32) -> Result {
+    let real: extern "system" fn(Device, Pipeline, u32) -> Result =
+        unsafe { mem::transmute(COMPILE_DEFERRED_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCompileDeferredNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(device, pipeline, shader);
+    record(&TraceCall {
+        command: "vkCompileDeferredNV",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("shader", &shader),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static COPY_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn copy_acceleration_structure_khr(
+    device: Device,
+    deferred_operation: DeferredOperationKHR,
+    p_info: *const CopyAccelerationStructureInfoKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        *const CopyAccelerationStructureInfoKHR,
+    ) -> Result =
+        unsafe { mem::transmute(COPY_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCopyAccelerationStructureKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("deferredOperation", deferred_operation),
+        ],
+    );
+    let result = real(device, deferred_operation, p_info);
+    record(&TraceCall {
+        command: "vkCopyAccelerationStructureKHR",
+        args: &[
+            ("device", &device),
+            ("deferredOperation", &deferred_operation),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn copy_acceleration_structure_to_memory_khr(
+    device: Device,
+    deferred_operation: DeferredOperationKHR,
+    p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        *const CopyAccelerationStructureToMemoryInfoKHR,
+    ) -> Result = unsafe {
+        mem::transmute(COPY_ACCELERATION_STRUCTURE_TO_MEMORY_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkCopyAccelerationStructureToMemoryKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("deferredOperation", deferred_operation),
+        ],
+    );
+    let result = real(device, deferred_operation, p_info);
+    record(&TraceCall {
+        command: "vkCopyAccelerationStructureToMemoryKHR",
+        args: &[
+            ("device", &device),
+            ("deferredOperation", &deferred_operation),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn copy_memory_to_acceleration_structure_khr(
+    device: Device,
+    deferred_operation: DeferredOperationKHR,
+    p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        *const CopyMemoryToAccelerationStructureInfoKHR,
+    ) -> Result = unsafe {
+        mem::transmute(COPY_MEMORY_TO_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkCopyMemoryToAccelerationStructureKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("deferredOperation", deferred_operation),
+        ],
+    );
+    let result = real(device, deferred_operation, p_info);
+    record(&TraceCall {
+        command: "vkCopyMemoryToAccelerationStructureKHR",
+        args: &[
+            ("device", &device),
+            ("deferredOperation", &deferred_operation),
+            ("pInfo", &Pointee(p_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_acceleration_structure_khr(
+    device: Device,
+    p_create_info: *const AccelerationStructureCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_acceleration_structure: *mut AccelerationStructureKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const AccelerationStructureCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut AccelerationStructureKHR,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateAccelerationStructureKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_acceleration_structure);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateAccelerationStructureKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_acceleration_structure,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateAccelerationStructureKHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            (
+                "pAccelerationStructure",
+                &Pointee(p_acceleration_structure, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_ACCELERATION_STRUCTURE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_acceleration_structure_nv(
+    device: Device,
+    p_create_info: *const AccelerationStructureCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_acceleration_structure: *mut AccelerationStructureNV,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const AccelerationStructureCreateInfoNV,
+        *const AllocationCallbacks,
+        *mut AccelerationStructureNV,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_ACCELERATION_STRUCTURE_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateAccelerationStructureNV",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_acceleration_structure);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateAccelerationStructureNV",
+                true,
+                Some(handle_arg("device", device)),
+                p_acceleration_structure,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateAccelerationStructureNV",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            (
+                "pAccelerationStructure",
+                &Pointee(p_acceleration_structure, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_ANDROID_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_android_surface_khr(
+    instance: Instance,
+    p_create_info: *const AndroidSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const AndroidSurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_ANDROID_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateAndroidSurfaceKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateAndroidSurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateAndroidSurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_buffer(
+    device: Device,
+    p_create_info: *const BufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_buffer: *mut Buffer,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const BufferCreateInfo,
+        *const AllocationCallbacks,
+        *mut Buffer,
+    ) -> Result = unsafe { mem::transmute(CREATE_BUFFER.load(Ordering::Relaxed)) };
+    check_handles("vkCreateBuffer", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_buffer);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateBuffer",
+                true,
+                Some(handle_arg("device", device)),
+                p_buffer,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateBuffer",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pBuffer", &Pointee(p_buffer, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_BUFFER_VIEW: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_buffer_view(
+    device: Device,
+    p_create_info: *const BufferViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut BufferView,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const BufferViewCreateInfo,
+        *const AllocationCallbacks,
+        *mut BufferView,
+    ) -> Result = unsafe { mem::transmute(CREATE_BUFFER_VIEW.load(Ordering::Relaxed)) };
+    check_handles("vkCreateBufferView", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_view);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateBufferView",
+                true,
+                Some(handle_arg("device", device)),
+                p_view,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateBufferView",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pView", &Pointee(p_view, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_COMMAND_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_command_pool(
+    device: Device,
+    p_create_info: *const CommandPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_command_pool: *mut CommandPool,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const CommandPoolCreateInfo,
+        *const AllocationCallbacks,
+        *mut CommandPool,
+    ) -> Result = unsafe { mem::transmute(CREATE_COMMAND_POOL.load(Ordering::Relaxed)) };
+    check_handles("vkCreateCommandPool", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_command_pool);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateCommandPool",
+                true,
+                Some(handle_arg("device", device)),
+                p_command_pool,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateCommandPool",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pCommandPool", &Pointee(p_command_pool, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_COMPUTE_PIPELINES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_compute_pipelines(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const ComputePipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        PipelineCache,
+        u32,
+        *const ComputePipelineCreateInfo,
+        *const AllocationCallbacks,
+        *mut Pipeline,
+    ) -> Result = unsafe { mem::transmute(CREATE_COMPUTE_PIPELINES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateComputePipelines",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateComputePipelines",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipelines,
+                create_info_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateComputePipelines",
+        args: &[
+            ("device", &device),
+            ("pipelineCache", &pipeline_cache),
+            ("createInfoCount", &create_info_count),
+            (
+                "pCreateInfos",
+                &Pointees(p_create_infos, create_info_count as usize, true),
+            ),
+            (
+                "pAllocator",
+                &Pointees(p_allocator, create_info_count as usize, true),
+            ),
+            (
+                "pPipelines",
+                &Pointees(p_pipelines, create_info_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DEBUG_REPORT_CALLBACK_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_debug_report_callback_ext(
+    instance: Instance,
+    p_create_info: *const DebugReportCallbackCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_callback: *mut DebugReportCallbackEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const DebugReportCallbackCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut DebugReportCallbackEXT,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_DEBUG_REPORT_CALLBACK_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDebugReportCallbackEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_callback);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDebugReportCallbackEXT",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_callback,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDebugReportCallbackEXT",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pCallback", &Pointee(p_callback, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DEBUG_UTILS_MESSENGER_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_debug_utils_messenger_ext(
+    instance: Instance,
+    p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const DebugUtilsMessengerCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut DebugUtilsMessengerEXT,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_DEBUG_UTILS_MESSENGER_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDebugUtilsMessengerEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_messenger);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDebugUtilsMessengerEXT",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_messenger,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDebugUtilsMessengerEXT",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pMessenger", &Pointee(p_messenger, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DEFERRED_OPERATION_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_deferred_operation_khr(
+    device: Device,
+    p_allocator: *const AllocationCallbacks,
+    p_deferred_operation: *mut DeferredOperationKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const AllocationCallbacks,
+        *mut DeferredOperationKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_DEFERRED_OPERATION_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDeferredOperationKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_allocator, p_deferred_operation);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDeferredOperationKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_deferred_operation,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDeferredOperationKHR",
+        args: &[
+            ("device", &device),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pDeferredOperation", &Pointee(p_deferred_operation, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DESCRIPTOR_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_descriptor_pool(
+    device: Device,
+    p_create_info: *const DescriptorPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_pool: *mut DescriptorPool,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorPoolCreateInfo,
+        *const AllocationCallbacks,
+        *mut DescriptorPool,
+    ) -> Result = unsafe { mem::transmute(CREATE_DESCRIPTOR_POOL.load(Ordering::Relaxed)) };
+    check_handles("vkCreateDescriptorPool", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_descriptor_pool);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDescriptorPool",
+                true,
+                Some(handle_arg("device", device)),
+                p_descriptor_pool,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDescriptorPool",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pDescriptorPool", &Pointee(p_descriptor_pool, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DESCRIPTOR_SET_LAYOUT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_descriptor_set_layout(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_set_layout: *mut DescriptorSetLayout,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorSetLayoutCreateInfo,
+        *const AllocationCallbacks,
+        *mut DescriptorSetLayout,
+    ) -> Result = unsafe { mem::transmute(CREATE_DESCRIPTOR_SET_LAYOUT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDescriptorSetLayout",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_set_layout);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDescriptorSetLayout",
+                true,
+                Some(handle_arg("device", device)),
+                p_set_layout,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDescriptorSetLayout",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSetLayout", &Pointee(p_set_layout, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DESCRIPTOR_UPDATE_TEMPLATE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_descriptor_update_template(
+    device: Device,
+    p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorUpdateTemplateCreateInfo,
+        *const AllocationCallbacks,
+        *mut DescriptorUpdateTemplate,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_DESCRIPTOR_UPDATE_TEMPLATE.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDescriptorUpdateTemplate",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDescriptorUpdateTemplate",
+                true,
+                Some(handle_arg("device", device)),
+                p_descriptor_update_template,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDescriptorUpdateTemplate",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            (
+                "pDescriptorUpdateTemplate",
+                &Pointee(p_descriptor_update_template, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DESCRIPTOR_UPDATE_TEMPLATE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_descriptor_update_template_khr(
+    device: Device,
+    p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorUpdateTemplateCreateInfo,
+        *const AllocationCallbacks,
+        *mut DescriptorUpdateTemplate,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_DESCRIPTOR_UPDATE_TEMPLATE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDescriptorUpdateTemplateKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDescriptorUpdateTemplateKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_descriptor_update_template,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDescriptorUpdateTemplateKHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            (
+                "pDescriptorUpdateTemplate",
+                &Pointee(p_descriptor_update_template, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DEVICE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_device(
+    physical_device: PhysicalDevice,
+    p_create_info: *const DeviceCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_device: *mut Device,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const DeviceCreateInfo,
+        *const AllocationCallbacks,
+        *mut Device,
+    ) -> Result = unsafe { mem::transmute(CREATE_DEVICE.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDevice",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_create_info, p_allocator, p_device);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDevice",
+                true,
+                Some(handle_arg("physicalDevice", physical_device)),
+                p_device,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDevice",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pDevice", &Pointee(p_device, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DIRECT_FB_SURFACE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_direct_fb_surface_ext(
+    instance: Instance,
+    p_create_info: *const DirectFBSurfaceCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const DirectFBSurfaceCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_DIRECT_FB_SURFACE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDirectFBSurfaceEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDirectFBSurfaceEXT",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDirectFBSurfaceEXT",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DISPLAY_MODE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_display_mode_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_create_info: *const DisplayModeCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_mode: *mut DisplayModeKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        DisplayKHR,
+        *const DisplayModeCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut DisplayModeKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_DISPLAY_MODE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDisplayModeKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("display", display),
+        ],
+    );
+    let result = real(physical_device, display, p_create_info, p_allocator, p_mode);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDisplayModeKHR",
+                false,
+                Some(handle_arg("display", display)),
+                p_mode,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDisplayModeKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("display", &display),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pMode", &Pointee(p_mode, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_DISPLAY_PLANE_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_display_plane_surface_khr(
+    instance: Instance,
+    p_create_info: *const DisplaySurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const DisplaySurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_DISPLAY_PLANE_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateDisplayPlaneSurfaceKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateDisplayPlaneSurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateDisplayPlaneSurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_event(
+    device: Device,
+    p_create_info: *const EventCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_event: *mut Event,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const EventCreateInfo,
+        *const AllocationCallbacks,
+        *mut Event,
+    ) -> Result = unsafe { mem::transmute(CREATE_EVENT.load(Ordering::Relaxed)) };
+    check_handles("vkCreateEvent", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_event);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateEvent",
+                true,
+                Some(handle_arg("device", device)),
+                p_event,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateEvent",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pEvent", &Pointee(p_event, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_FENCE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_fence(
+    device: Device,
+    p_create_info: *const FenceCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const FenceCreateInfo,
+        *const AllocationCallbacks,
+        *mut Fence,
+    ) -> Result = unsafe { mem::transmute(CREATE_FENCE.load(Ordering::Relaxed)) };
+    check_handles("vkCreateFence", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_fence);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateFence",
+                true,
+                Some(handle_arg("device", device)),
+                p_fence,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateFence",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pFence", &Pointee(p_fence, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_FRAMEBUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_framebuffer(
+    device: Device,
+    p_create_info: *const FramebufferCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_framebuffer: *mut Framebuffer,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const FramebufferCreateInfo,
+        *const AllocationCallbacks,
+        *mut Framebuffer,
+    ) -> Result = unsafe { mem::transmute(CREATE_FRAMEBUFFER.load(Ordering::Relaxed)) };
+    check_handles("vkCreateFramebuffer", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_framebuffer);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateFramebuffer",
+                true,
+                Some(handle_arg("device", device)),
+                p_framebuffer,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateFramebuffer",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pFramebuffer", &Pointee(p_framebuffer, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_GRAPHICS_PIPELINES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_graphics_pipelines(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const GraphicsPipelineCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        PipelineCache,
+        u32,
+        *const GraphicsPipelineCreateInfo,
+        *const AllocationCallbacks,
+        *mut Pipeline,
+    ) -> Result = unsafe { mem::transmute(CREATE_GRAPHICS_PIPELINES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateGraphicsPipelines",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateGraphicsPipelines",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipelines,
+                create_info_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateGraphicsPipelines",
+        args: &[
+            ("device", &device),
+            ("pipelineCache", &pipeline_cache),
+            ("createInfoCount", &create_info_count),
+            (
+                "pCreateInfos",
+                &Pointees(p_create_infos, create_info_count as usize, true),
+            ),
+            (
+                "pAllocator",
+                &Pointees(p_allocator, create_info_count as usize, true),
+            ),
+            (
+                "pPipelines",
+                &Pointees(p_pipelines, create_info_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_HEADLESS_SURFACE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_headless_surface_ext(
+    instance: Instance,
+    p_create_info: *const HeadlessSurfaceCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const HeadlessSurfaceCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_HEADLESS_SURFACE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateHeadlessSurfaceEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateHeadlessSurfaceEXT",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateHeadlessSurfaceEXT",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_IOS_SURFACE_MVK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_ios_surface_mvk(
+    instance: Instance,
+    p_create_info: *const IOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const IOSSurfaceCreateInfoMVK,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_IOS_SURFACE_MVK.load(Ordering::Relaxed)) };
+    check_handles("vkCreateIOSSurfaceMVK", &[handle_arg("instance", instance)]);
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateIOSSurfaceMVK",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateIOSSurfaceMVK",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_image(
+    device: Device,
+    p_create_info: *const ImageCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_image: *mut Image,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const ImageCreateInfo,
+        *const AllocationCallbacks,
+        *mut Image,
+    ) -> Result = unsafe { mem::transmute(CREATE_IMAGE.load(Ordering::Relaxed)) };
+    check_handles("vkCreateImage", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_image);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateImage",
+                true,
+                Some(handle_arg("device", device)),
+                p_image,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateImage",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pImage", &Pointee(p_image, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_IMAGE_PIPE_SURFACE_FUCHSIA: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_image_pipe_surface_fuchsia(
+    instance: Instance,
+    p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const ImagePipeSurfaceCreateInfoFUCHSIA,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_IMAGE_PIPE_SURFACE_FUCHSIA.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateImagePipeSurfaceFUCHSIA",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateImagePipeSurfaceFUCHSIA",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateImagePipeSurfaceFUCHSIA",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_IMAGE_VIEW: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_image_view(
+    device: Device,
+    p_create_info: *const ImageViewCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_view: *mut ImageView,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const ImageViewCreateInfo,
+        *const AllocationCallbacks,
+        *mut ImageView,
+    ) -> Result = unsafe { mem::transmute(CREATE_IMAGE_VIEW.load(Ordering::Relaxed)) };
+    check_handles("vkCreateImageView", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_view);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateImageView",
+                true,
+                Some(handle_arg("device", device)),
+                p_view,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateImageView",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pView", &Pointee(p_view, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_INDIRECT_COMMANDS_LAYOUT_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_indirect_commands_layout_nv(
+    device: Device,
+    p_create_info: *const IndirectCommandsLayoutCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_indirect_commands_layout: *mut IndirectCommandsLayoutNV,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const IndirectCommandsLayoutCreateInfoNV,
+        *const AllocationCallbacks,
+        *mut IndirectCommandsLayoutNV,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_INDIRECT_COMMANDS_LAYOUT_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateIndirectCommandsLayoutNV",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateIndirectCommandsLayoutNV",
+                true,
+                Some(handle_arg("device", device)),
+                p_indirect_commands_layout,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateIndirectCommandsLayoutNV",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            (
+                "pIndirectCommandsLayout",
+                &Pointee(p_indirect_commands_layout, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_INSTANCE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_instance(
+    p_create_info: *const InstanceCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_instance: *mut Instance,
+) -> Result {
+    let real: extern "system" fn(
+        *const InstanceCreateInfo,
+        *const AllocationCallbacks,
+        *mut Instance,
+    ) -> Result = unsafe { mem::transmute(CREATE_INSTANCE.load(Ordering::Relaxed)) };
+    let result = real(p_create_info, p_allocator, p_instance);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created("vkCreateInstance", true, None, p_instance, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateInstance",
+        args: &[
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pInstance", &Pointee(p_instance, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_MAC_OS_SURFACE_MVK: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_mac_os_surface_mvk(
+    instance: Instance,
+    p_create_info: *const MacOSSurfaceCreateInfoMVK,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const MacOSSurfaceCreateInfoMVK,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_MAC_OS_SURFACE_MVK.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateMacOSSurfaceMVK",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateMacOSSurfaceMVK",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateMacOSSurfaceMVK",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_METAL_SURFACE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_metal_surface_ext(
+    instance: Instance,
+    p_create_info: *const MetalSurfaceCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const MetalSurfaceCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_METAL_SURFACE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateMetalSurfaceEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateMetalSurfaceEXT",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateMetalSurfaceEXT",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_PIPELINE_CACHE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_pipeline_cache(
+    device: Device,
+    p_create_info: *const PipelineCacheCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_cache: *mut PipelineCache,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PipelineCacheCreateInfo,
+        *const AllocationCallbacks,
+        *mut PipelineCache,
+    ) -> Result = unsafe { mem::transmute(CREATE_PIPELINE_CACHE.load(Ordering::Relaxed)) };
+    check_handles("vkCreatePipelineCache", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_pipeline_cache);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreatePipelineCache",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipeline_cache,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreatePipelineCache",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pPipelineCache", &Pointee(p_pipeline_cache, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_PIPELINE_LAYOUT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_pipeline_layout(
+    device: Device,
+    p_create_info: *const PipelineLayoutCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_pipeline_layout: *mut PipelineLayout,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PipelineLayoutCreateInfo,
+        *const AllocationCallbacks,
+        *mut PipelineLayout,
+    ) -> Result = unsafe { mem::transmute(CREATE_PIPELINE_LAYOUT.load(Ordering::Relaxed)) };
+    check_handles("vkCreatePipelineLayout", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_pipeline_layout);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreatePipelineLayout",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipeline_layout,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreatePipelineLayout",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pPipelineLayout", &Pointee(p_pipeline_layout, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_PRIVATE_DATA_SLOT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_private_data_slot_ext(
+    device: Device,
+    p_create_info: *const PrivateDataSlotCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_private_data_slot: *mut PrivateDataSlotEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PrivateDataSlotCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut PrivateDataSlotEXT,
+    ) -> Result = unsafe { mem::transmute(CREATE_PRIVATE_DATA_SLOT_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreatePrivateDataSlotEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_private_data_slot);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreatePrivateDataSlotEXT",
+                true,
+                Some(handle_arg("device", device)),
+                p_private_data_slot,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreatePrivateDataSlotEXT",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pPrivateDataSlot", &Pointee(p_private_data_slot, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_QUERY_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_query_pool(
+    device: Device,
+    p_create_info: *const QueryPoolCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_query_pool: *mut QueryPool,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const QueryPoolCreateInfo,
+        *const AllocationCallbacks,
+        *mut QueryPool,
+    ) -> Result = unsafe { mem::transmute(CREATE_QUERY_POOL.load(Ordering::Relaxed)) };
+    check_handles("vkCreateQueryPool", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_query_pool);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateQueryPool",
+                true,
+                Some(handle_arg("device", device)),
+                p_query_pool,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateQueryPool",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pQueryPool", &Pointee(p_query_pool, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_RAY_TRACING_PIPELINES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_ray_tracing_pipelines_khr(
+    device: Device,
+    deferred_operation: DeferredOperationKHR,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const RayTracingPipelineCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        PipelineCache,
+        u32,
+        *const RayTracingPipelineCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut Pipeline,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_RAY_TRACING_PIPELINES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateRayTracingPipelinesKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("deferredOperation", deferred_operation),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(
+        device,
+        deferred_operation,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateRayTracingPipelinesKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipelines,
+                create_info_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateRayTracingPipelinesKHR",
+        args: &[
+            ("device", &device),
+            ("deferredOperation", &deferred_operation),
+            ("pipelineCache", &pipeline_cache),
+            ("createInfoCount", &create_info_count),
+            (
+                "pCreateInfos",
+                &Pointees(p_create_infos, create_info_count as usize, true),
+            ),
+            (
+                "pAllocator",
+                &Pointees(p_allocator, create_info_count as usize, true),
+            ),
+            (
+                "pPipelines",
+                &Pointees(p_pipelines, create_info_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_RAY_TRACING_PIPELINES_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_ray_tracing_pipelines_nv(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const RayTracingPipelineCreateInfoNV,
+    p_allocator: *const AllocationCallbacks,
+    p_pipelines: *mut Pipeline,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        PipelineCache,
+        u32,
+        *const RayTracingPipelineCreateInfoNV,
+        *const AllocationCallbacks,
+        *mut Pipeline,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_RAY_TRACING_PIPELINES_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateRayTracingPipelinesNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateRayTracingPipelinesNV",
+                true,
+                Some(handle_arg("device", device)),
+                p_pipelines,
+                create_info_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateRayTracingPipelinesNV",
+        args: &[
+            ("device", &device),
+            ("pipelineCache", &pipeline_cache),
+            ("createInfoCount", &create_info_count),
+            (
+                "pCreateInfos",
+                &Pointees(p_create_infos, create_info_count as usize, true),
+            ),
+            (
+                "pAllocator",
+                &Pointees(p_allocator, create_info_count as usize, true),
+            ),
+            (
+                "pPipelines",
+                &Pointees(p_pipelines, create_info_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_RENDER_PASS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_render_pass(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const RenderPassCreateInfo,
+        *const AllocationCallbacks,
+        *mut RenderPass,
+    ) -> Result = unsafe { mem::transmute(CREATE_RENDER_PASS.load(Ordering::Relaxed)) };
+    check_handles("vkCreateRenderPass", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_render_pass);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateRenderPass",
+                true,
+                Some(handle_arg("device", device)),
+                p_render_pass,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateRenderPass",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pRenderPass", &p_render_pass),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_RENDER_PASS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_render_pass2(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo2,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const RenderPassCreateInfo2,
+        *const AllocationCallbacks,
+        *mut RenderPass,
+    ) -> Result = unsafe { mem::transmute(CREATE_RENDER_PASS2.load(Ordering::Relaxed)) };
+    check_handles("vkCreateRenderPass2", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_render_pass);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateRenderPass2",
+                true,
+                Some(handle_arg("device", device)),
+                p_render_pass,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateRenderPass2",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pRenderPass", &p_render_pass),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_RENDER_PASS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_render_pass2_khr(
+    device: Device,
+    p_create_info: *const RenderPassCreateInfo2,
+    p_allocator: *const AllocationCallbacks,
+    p_render_pass: *mut RenderPass,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const RenderPassCreateInfo2,
+        *const AllocationCallbacks,
+        *mut RenderPass,
+    ) -> Result = unsafe { mem::transmute(CREATE_RENDER_PASS2_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkCreateRenderPass2KHR", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_render_pass);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateRenderPass2KHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_render_pass,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateRenderPass2KHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pRenderPass", &p_render_pass),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SAMPLER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_sampler(
+    device: Device,
+    p_create_info: *const SamplerCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_sampler: *mut Sampler,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SamplerCreateInfo,
+        *const AllocationCallbacks,
+        *mut Sampler,
+    ) -> Result = unsafe { mem::transmute(CREATE_SAMPLER.load(Ordering::Relaxed)) };
+    check_handles("vkCreateSampler", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_sampler);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSampler",
+                true,
+                Some(handle_arg("device", device)),
+                p_sampler,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSampler",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSampler", &Pointee(p_sampler, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SAMPLER_YCBCR_CONVERSION: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_sampler_ycbcr_conversion(
+    device: Device,
+    p_create_info: *const SamplerYcbcrConversionCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SamplerYcbcrConversionCreateInfo,
+        *const AllocationCallbacks,
+        *mut SamplerYcbcrConversion,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_SAMPLER_YCBCR_CONVERSION.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateSamplerYcbcrConversion",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSamplerYcbcrConversion",
+                true,
+                Some(handle_arg("device", device)),
+                p_ycbcr_conversion,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSamplerYcbcrConversion",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pYcbcrConversion", &Pointee(p_ycbcr_conversion, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SAMPLER_YCBCR_CONVERSION_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_sampler_ycbcr_conversion_khr(
+    device: Device,
+    p_create_info: *const SamplerYcbcrConversionCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SamplerYcbcrConversionCreateInfo,
+        *const AllocationCallbacks,
+        *mut SamplerYcbcrConversion,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_SAMPLER_YCBCR_CONVERSION_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateSamplerYcbcrConversionKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSamplerYcbcrConversionKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_ycbcr_conversion,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSamplerYcbcrConversionKHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pYcbcrConversion", &Pointee(p_ycbcr_conversion, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SEMAPHORE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_semaphore(
+    device: Device,
+    p_create_info: *const SemaphoreCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_semaphore: *mut Semaphore,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SemaphoreCreateInfo,
+        *const AllocationCallbacks,
+        *mut Semaphore,
+    ) -> Result = unsafe { mem::transmute(CREATE_SEMAPHORE.load(Ordering::Relaxed)) };
+    check_handles("vkCreateSemaphore", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_semaphore);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSemaphore",
+                true,
+                Some(handle_arg("device", device)),
+                p_semaphore,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSemaphore",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSemaphore", &Pointee(p_semaphore, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SHADER_MODULE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_shader_module(
+    device: Device,
+    p_create_info: *const ShaderModuleCreateInfo,
+    p_allocator: *const AllocationCallbacks,
+    p_shader_module: *mut ShaderModule,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const ShaderModuleCreateInfo,
+        *const AllocationCallbacks,
+        *mut ShaderModule,
+    ) -> Result = unsafe { mem::transmute(CREATE_SHADER_MODULE.load(Ordering::Relaxed)) };
+    check_handles("vkCreateShaderModule", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_shader_module);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateShaderModule",
+                true,
+                Some(handle_arg("device", device)),
+                p_shader_module,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateShaderModule",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pShaderModule", &Pointee(p_shader_module, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SHARED_SWAPCHAINS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_shared_swapchains_khr(
+    device: Device,
+    swapchain_count: u32,
+    p_create_infos: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchains: *mut SwapchainKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const SwapchainCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SwapchainKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_SHARED_SWAPCHAINS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateSharedSwapchainsKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSharedSwapchainsKHR",
+                true,
+                Some(handle_arg("device", device)),
+                p_swapchains,
+                swapchain_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSharedSwapchainsKHR",
+        args: &[
+            ("device", &device),
+            ("swapchainCount", &swapchain_count),
+            (
+                "pCreateInfos",
+                &Pointees(p_create_infos, swapchain_count as usize, true),
+            ),
+            (
+                "pAllocator",
+                &Pointees(p_allocator, swapchain_count as usize, true),
+            ),
+            (
+                "pSwapchains",
+                &Pointees(p_swapchains, swapchain_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_STREAM_DESCRIPTOR_SURFACE_GGP: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_stream_descriptor_surface_ggp(
+    instance: Instance,
+    p_create_info: *const StreamDescriptorSurfaceCreateInfoGGP,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const StreamDescriptorSurfaceCreateInfoGGP,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result =
+        unsafe { mem::transmute(CREATE_STREAM_DESCRIPTOR_SURFACE_GGP.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateStreamDescriptorSurfaceGGP",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateStreamDescriptorSurfaceGGP",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateStreamDescriptorSurfaceGGP",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_SWAPCHAIN_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_swapchain_khr(
+    device: Device,
+    p_create_info: *const SwapchainCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_swapchain: *mut SwapchainKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SwapchainCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SwapchainKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_SWAPCHAIN_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkCreateSwapchainKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_create_info, p_allocator, p_swapchain);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateSwapchainKHR",
+                true,
+                Some(handle_arg("pCreateInfo->surface", (*p_create_info).surface)),
+                p_swapchain,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateSwapchainKHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSwapchain", &Pointee(p_swapchain, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_VALIDATION_CACHE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_validation_cache_ext(
+    device: Device,
+    p_create_info: *const ValidationCacheCreateInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_validation_cache: *mut ValidationCacheEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const ValidationCacheCreateInfoEXT,
+        *const AllocationCallbacks,
+        *mut ValidationCacheEXT,
+    ) -> Result = unsafe { mem::transmute(CREATE_VALIDATION_CACHE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateValidationCacheEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_allocator, p_validation_cache);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateValidationCacheEXT",
+                true,
+                Some(handle_arg("device", device)),
+                p_validation_cache,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateValidationCacheEXT",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pValidationCache", &Pointee(p_validation_cache, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_VI_SURFACE_NN: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_vi_surface_nn(
+    instance: Instance,
+    p_create_info: *const ViSurfaceCreateInfoNN,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const ViSurfaceCreateInfoNN,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_VI_SURFACE_NN.load(Ordering::Relaxed)) };
+    check_handles("vkCreateViSurfaceNN", &[handle_arg("instance", instance)]);
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateViSurfaceNN",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateViSurfaceNN",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_WAYLAND_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_wayland_surface_khr(
+    instance: Instance,
+    p_create_info: *const WaylandSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const WaylandSurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_WAYLAND_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateWaylandSurfaceKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateWaylandSurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateWaylandSurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_WIN32_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_win32_surface_khr(
+    instance: Instance,
+    p_create_info: *const Win32SurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const Win32SurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_WIN32_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateWin32SurfaceKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateWin32SurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateWin32SurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_XCB_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_xcb_surface_khr(
+    instance: Instance,
+    p_create_info: *const XcbSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const XcbSurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_XCB_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkCreateXcbSurfaceKHR", &[handle_arg("instance", instance)]);
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateXcbSurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateXcbSurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static CREATE_XLIB_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn create_xlib_surface_khr(
+    instance: Instance,
+    p_create_info: *const XlibSurfaceCreateInfoKHR,
+    p_allocator: *const AllocationCallbacks,
+    p_surface: *mut SurfaceKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Instance,
+        *const XlibSurfaceCreateInfoKHR,
+        *const AllocationCallbacks,
+        *mut SurfaceKHR,
+    ) -> Result = unsafe { mem::transmute(CREATE_XLIB_SURFACE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkCreateXlibSurfaceKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_create_info, p_allocator, p_surface);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkCreateXlibSurfaceKHR",
+                true,
+                Some(handle_arg("instance", instance)),
+                p_surface,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkCreateXlibSurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pSurface", &Pointee(p_surface, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static DEBUG_MARKER_SET_OBJECT_NAME_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn debug_marker_set_object_name_ext(
+    device: Device,
+    p_name_info: *const DebugMarkerObjectNameInfoEXT,
+) -> Result {
+    let real: extern "system" fn(Device, *const DebugMarkerObjectNameInfoEXT) -> Result =
+        unsafe { mem::transmute(DEBUG_MARKER_SET_OBJECT_NAME_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkDebugMarkerSetObjectNameEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_name_info);
+    record(&TraceCall {
+        command: "vkDebugMarkerSetObjectNameEXT",
+        args: &[
+            ("device", &device),
+            ("pNameInfo", &Pointee(p_name_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static DEBUG_MARKER_SET_OBJECT_TAG_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn debug_marker_set_object_tag_ext(
+    device: Device,
+    p_tag_info: *const DebugMarkerObjectTagInfoEXT,
+) -> Result {
+    let real: extern "system" fn(Device, *const DebugMarkerObjectTagInfoEXT) -> Result =
+        unsafe { mem::transmute(DEBUG_MARKER_SET_OBJECT_TAG_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkDebugMarkerSetObjectTagEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_tag_info);
+    record(&TraceCall {
+        command: "vkDebugMarkerSetObjectTagEXT",
+        args: &[
+            ("device", &device),
+            ("pTagInfo", &Pointee(p_tag_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static DEBUG_REPORT_MESSAGE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn debug_report_message_ext(
+    instance: Instance,
+    flags: DebugReportFlagsEXT,
+    object_type: DebugReportObjectTypeEXT,
+    object: u64,
+    location: size_t,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) -> c_void {
+    let real: extern "system" fn(
+        Instance,
+        DebugReportFlagsEXT,
+        DebugReportObjectTypeEXT,
+        u64,
+        size_t,
+        i32,
+        *const c_char,
+        *const c_char,
+    ) -> c_void = unsafe { mem::transmute(DEBUG_REPORT_MESSAGE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkDebugReportMessageEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(
+        instance,
+        flags,
+        object_type,
+        object,
+        location,
+        message_code,
+        p_layer_prefix,
+        p_message,
+    );
+    record(&TraceCall {
+        command: "vkDebugReportMessageEXT",
+        args: &[
+            ("instance", &instance),
+            ("flags", &flags),
+            ("objectType", &object_type),
+            ("object", &object),
+            ("location", &location),
+            ("messageCode", &message_code),
+            ("pLayerPrefix", &CStrArg(p_layer_prefix)),
+            ("pMessage", &CStrArg(p_message)),
+        ],
+        result: None,
+    });
+    result
+}
+static DEFERRED_OPERATION_JOIN_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn deferred_operation_join_khr(
+    device: Device,
+    operation: DeferredOperationKHR,
+) -> Result {
+    let real: extern "system" fn(Device, DeferredOperationKHR) -> Result =
+        unsafe { mem::transmute(DEFERRED_OPERATION_JOIN_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkDeferredOperationJoinKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("operation", operation),
+        ],
+    );
+    let result = real(device, operation);
+    record(&TraceCall {
+        command: "vkDeferredOperationJoinKHR",
+        args: &[("device", &device), ("operation", &operation)],
+        result: Some(&result),
+    });
+    result
+}
+static DESTROY_ACCELERATION_STRUCTURE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_acceleration_structure_khr(
+    device: Device,
+    acceleration_structure: AccelerationStructureKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        AccelerationStructureKHR,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_ACCELERATION_STRUCTURE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyAccelerationStructureKHR",
+        &[handle_arg("accelerationStructure", acceleration_structure)],
+    );
+    check_handles(
+        "vkDestroyAccelerationStructureKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("accelerationStructure", acceleration_structure),
+        ],
+    );
+    let result = real(device, acceleration_structure, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkDestroyAccelerationStructureKHR",
+                &acceleration_structure,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyAccelerationStructureKHR",
+        args: &[
+            ("device", &device),
+            ("accelerationStructure", &acceleration_structure),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_ACCELERATION_STRUCTURE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_acceleration_structure_nv(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        AccelerationStructureNV,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_ACCELERATION_STRUCTURE_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyAccelerationStructureNV",
+        &[handle_arg("accelerationStructure", acceleration_structure)],
+    );
+    check_handles(
+        "vkDestroyAccelerationStructureNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("accelerationStructure", acceleration_structure),
+        ],
+    );
+    let result = real(device, acceleration_structure, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkDestroyAccelerationStructureNV",
+                &acceleration_structure,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyAccelerationStructureNV",
+        args: &[
+            ("device", &device),
+            ("accelerationStructure", &acceleration_structure),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_buffer(
+    device: Device,
+    buffer: Buffer,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Buffer, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyBuffer", &[handle_arg("buffer", buffer)]);
+    check_handles(
+        "vkDestroyBuffer",
+        &[handle_arg("device", device), handle_arg("buffer", buffer)],
+    );
+    let result = real(device, buffer, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyBuffer", &buffer, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyBuffer",
+        args: &[
+            ("device", &device),
+            ("buffer", &buffer),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_BUFFER_VIEW: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_buffer_view(
+    device: Device,
+    buffer_view: BufferView,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, BufferView, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_BUFFER_VIEW.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyBufferView",
+        &[handle_arg("bufferView", buffer_view)],
+    );
+    check_handles(
+        "vkDestroyBufferView",
+        &[
+            handle_arg("device", device),
+            handle_arg("bufferView", buffer_view),
+        ],
+    );
+    let result = real(device, buffer_view, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyBufferView", &buffer_view, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyBufferView",
+        args: &[
+            ("device", &device),
+            ("bufferView", &buffer_view),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_COMMAND_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, CommandPool, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_COMMAND_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyCommandPool",
+        &[handle_arg("commandPool", command_pool)],
+    );
+    check_handles(
+        "vkDestroyCommandPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("commandPool", command_pool),
+        ],
+    );
+    let result = real(device, command_pool, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyCommandPool", &command_pool, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyCommandPool",
+        args: &[
+            ("device", &device),
+            ("commandPool", &command_pool),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DEBUG_REPORT_CALLBACK_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_debug_report_callback_ext(
+    instance: Instance,
+    callback: DebugReportCallbackEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Instance,
+        DebugReportCallbackEXT,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_DEBUG_REPORT_CALLBACK_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDebugReportCallbackEXT",
+        &[handle_arg("callback", callback)],
+    );
+    check_handles(
+        "vkDestroyDebugReportCallbackEXT",
+        &[
+            handle_arg("instance", instance),
+            handle_arg("callback", callback),
+        ],
+    );
+    let result = real(instance, callback, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDebugReportCallbackEXT", &callback, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDebugReportCallbackEXT",
+        args: &[
+            ("instance", &instance),
+            ("callback", &callback),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DEBUG_UTILS_MESSENGER_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_debug_utils_messenger_ext(
+    instance: Instance,
+    messenger: DebugUtilsMessengerEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Instance,
+        DebugUtilsMessengerEXT,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_DEBUG_UTILS_MESSENGER_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDebugUtilsMessengerEXT",
+        &[handle_arg("messenger", messenger)],
+    );
+    check_handles(
+        "vkDestroyDebugUtilsMessengerEXT",
+        &[
+            handle_arg("instance", instance),
+            handle_arg("messenger", messenger),
+        ],
+    );
+    let result = real(instance, messenger, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDebugUtilsMessengerEXT", &messenger, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDebugUtilsMessengerEXT",
+        args: &[
+            ("instance", &instance),
+            ("messenger", &messenger),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DEFERRED_OPERATION_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_deferred_operation_khr(
+    device: Device,
+    operation: DeferredOperationKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DeferredOperationKHR,
+        *const AllocationCallbacks,
+    ) -> c_void = unsafe { mem::transmute(DESTROY_DEFERRED_OPERATION_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDeferredOperationKHR",
+        &[handle_arg("operation", operation)],
+    );
+    check_handles(
+        "vkDestroyDeferredOperationKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("operation", operation),
+        ],
+    );
+    let result = real(device, operation, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDeferredOperationKHR", &operation, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDeferredOperationKHR",
+        args: &[
+            ("device", &device),
+            ("operation", &operation),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DESCRIPTOR_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_descriptor_pool(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, DescriptorPool, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_DESCRIPTOR_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDescriptorPool",
+        &[handle_arg("descriptorPool", descriptor_pool)],
+    );
+    check_handles(
+        "vkDestroyDescriptorPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorPool", descriptor_pool),
+        ],
+    );
+    let result = real(device, descriptor_pool, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDescriptorPool", &descriptor_pool, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDescriptorPool",
+        args: &[
+            ("device", &device),
+            ("descriptorPool", &descriptor_pool),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DESCRIPTOR_SET_LAYOUT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_descriptor_set_layout(
+    device: Device,
+    descriptor_set_layout: DescriptorSetLayout,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DescriptorSetLayout,
+        *const AllocationCallbacks,
+    ) -> c_void = unsafe { mem::transmute(DESTROY_DESCRIPTOR_SET_LAYOUT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDescriptorSetLayout",
+        &[handle_arg("descriptorSetLayout", descriptor_set_layout)],
+    );
+    check_handles(
+        "vkDestroyDescriptorSetLayout",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorSetLayout", descriptor_set_layout),
+        ],
+    );
+    let result = real(device, descriptor_set_layout, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDescriptorSetLayout", &descriptor_set_layout, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDescriptorSetLayout",
+        args: &[
+            ("device", &device),
+            ("descriptorSetLayout", &descriptor_set_layout),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DESCRIPTOR_UPDATE_TEMPLATE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_descriptor_update_template(
+    device: Device,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DescriptorUpdateTemplate,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_DESCRIPTOR_UPDATE_TEMPLATE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDescriptorUpdateTemplate",
+        &[handle_arg(
+            "descriptorUpdateTemplate",
+            descriptor_update_template,
+        )],
+    );
+    check_handles(
+        "vkDestroyDescriptorUpdateTemplate",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorUpdateTemplate", descriptor_update_template),
+        ],
+    );
+    let result = real(device, descriptor_update_template, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkDestroyDescriptorUpdateTemplate",
+                &descriptor_update_template,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDescriptorUpdateTemplate",
+        args: &[
+            ("device", &device),
+            ("descriptorUpdateTemplate", &descriptor_update_template),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DESCRIPTOR_UPDATE_TEMPLATE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_descriptor_update_template_khr(
+    device: Device,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DescriptorUpdateTemplate,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_DESCRIPTOR_UPDATE_TEMPLATE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyDescriptorUpdateTemplateKHR",
+        &[handle_arg(
+            "descriptorUpdateTemplate",
+            descriptor_update_template,
+        )],
+    );
+    check_handles(
+        "vkDestroyDescriptorUpdateTemplateKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorUpdateTemplate", descriptor_update_template),
+        ],
+    );
+    let result = real(device, descriptor_update_template, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkDestroyDescriptorUpdateTemplateKHR",
+                &descriptor_update_template,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDescriptorUpdateTemplateKHR",
+        args: &[
+            ("device", &device),
+            ("descriptorUpdateTemplate", &descriptor_update_template),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_DEVICE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_device(
+    device: Device,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_DEVICE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyDevice", &[handle_arg("device", device)]);
+    check_handles("vkDestroyDevice", &[handle_arg("device", device)]);
+    let result = real(device, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyDevice", &device, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyDevice",
+        args: &[
+            ("device", &device),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_event(
+    device: Device,
+    event: Event,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Event, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_EVENT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyEvent", &[handle_arg("event", event)]);
+    check_handles(
+        "vkDestroyEvent",
+        &[handle_arg("device", device), handle_arg("event", event)],
+    );
+    let result = real(device, event, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyEvent", &event, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyEvent",
+        args: &[
+            ("device", &device),
+            ("event", &event),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_FENCE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_fence(
+    device: Device,
+    fence: Fence,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Fence, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_FENCE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyFence", &[handle_arg("fence", fence)]);
+    check_handles(
+        "vkDestroyFence",
+        &[handle_arg("device", device), handle_arg("fence", fence)],
+    );
+    let result = real(device, fence, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyFence", &fence, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyFence",
+        args: &[
+            ("device", &device),
+            ("fence", &fence),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_FRAMEBUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_framebuffer(
+    device: Device,
+    framebuffer: Framebuffer,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Framebuffer, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_FRAMEBUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyFramebuffer",
+        &[handle_arg("framebuffer", framebuffer)],
+    );
+    check_handles(
+        "vkDestroyFramebuffer",
+        &[
+            handle_arg("device", device),
+            handle_arg("framebuffer", framebuffer),
+        ],
+    );
+    let result = real(device, framebuffer, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyFramebuffer", &framebuffer, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyFramebuffer",
+        args: &[
+            ("device", &device),
+            ("framebuffer", &framebuffer),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_IMAGE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_image(
+    device: Device,
+    image: Image,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Image, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_IMAGE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyImage", &[handle_arg("image", image)]);
+    check_handles(
+        "vkDestroyImage",
+        &[handle_arg("device", device), handle_arg("image", image)],
+    );
+    let result = real(device, image, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyImage", &image, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyImage",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_IMAGE_VIEW: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_image_view(
+    device: Device,
+    image_view: ImageView,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, ImageView, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_IMAGE_VIEW.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyImageView", &[handle_arg("imageView", image_view)]);
+    check_handles(
+        "vkDestroyImageView",
+        &[
+            handle_arg("device", device),
+            handle_arg("imageView", image_view),
+        ],
+    );
+    let result = real(device, image_view, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyImageView", &image_view, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyImageView",
+        args: &[
+            ("device", &device),
+            ("imageView", &image_view),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_INDIRECT_COMMANDS_LAYOUT_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_indirect_commands_layout_nv(
+    device: Device,
+    indirect_commands_layout: IndirectCommandsLayoutNV,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        IndirectCommandsLayoutNV,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_INDIRECT_COMMANDS_LAYOUT_NV.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyIndirectCommandsLayoutNV",
+        &[handle_arg(
+            "indirectCommandsLayout",
+            indirect_commands_layout,
+        )],
+    );
+    check_handles(
+        "vkDestroyIndirectCommandsLayoutNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("indirectCommandsLayout", indirect_commands_layout),
+        ],
+    );
+    let result = real(device, indirect_commands_layout, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkDestroyIndirectCommandsLayoutNV",
+                &indirect_commands_layout,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyIndirectCommandsLayoutNV",
+        args: &[
+            ("device", &device),
+            ("indirectCommandsLayout", &indirect_commands_layout),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_INSTANCE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_instance(
+    instance: Instance,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Instance, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_INSTANCE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyInstance", &[handle_arg("instance", instance)]);
+    check_handles("vkDestroyInstance", &[handle_arg("instance", instance)]);
+    let result = real(instance, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyInstance", &instance, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyInstance",
+        args: &[
+            ("instance", &instance),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_PIPELINE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_pipeline(
+    device: Device,
+    pipeline: Pipeline,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Pipeline, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_PIPELINE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyPipeline", &[handle_arg("pipeline", pipeline)]);
+    check_handles(
+        "vkDestroyPipeline",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(device, pipeline, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyPipeline", &pipeline, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyPipeline",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_PIPELINE_CACHE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_pipeline_cache(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, PipelineCache, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_PIPELINE_CACHE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyPipelineCache",
+        &[handle_arg("pipelineCache", pipeline_cache)],
+    );
+    check_handles(
+        "vkDestroyPipelineCache",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(device, pipeline_cache, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyPipelineCache", &pipeline_cache, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyPipelineCache",
+        args: &[
+            ("device", &device),
+            ("pipelineCache", &pipeline_cache),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_PIPELINE_LAYOUT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_pipeline_layout(
+    device: Device,
+    pipeline_layout: PipelineLayout,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, PipelineLayout, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_PIPELINE_LAYOUT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyPipelineLayout",
+        &[handle_arg("pipelineLayout", pipeline_layout)],
+    );
+    check_handles(
+        "vkDestroyPipelineLayout",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineLayout", pipeline_layout),
+        ],
+    );
+    let result = real(device, pipeline_layout, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyPipelineLayout", &pipeline_layout, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyPipelineLayout",
+        args: &[
+            ("device", &device),
+            ("pipelineLayout", &pipeline_layout),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_PRIVATE_DATA_SLOT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_private_data_slot_ext(
+    device: Device,
+    private_data_slot: PrivateDataSlotEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, PrivateDataSlotEXT, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_PRIVATE_DATA_SLOT_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyPrivateDataSlotEXT",
+        &[handle_arg("privateDataSlot", private_data_slot)],
+    );
+    check_handles(
+        "vkDestroyPrivateDataSlotEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("privateDataSlot", private_data_slot),
+        ],
+    );
+    let result = real(device, private_data_slot, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyPrivateDataSlotEXT", &private_data_slot, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyPrivateDataSlotEXT",
+        args: &[
+            ("device", &device),
+            ("privateDataSlot", &private_data_slot),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_QUERY_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_query_pool(
+    device: Device,
+    query_pool: QueryPool,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, QueryPool, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_QUERY_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroyQueryPool", &[handle_arg("queryPool", query_pool)]);
+    check_handles(
+        "vkDestroyQueryPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(device, query_pool, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyQueryPool", &query_pool, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyQueryPool",
+        args: &[
+            ("device", &device),
+            ("queryPool", &query_pool),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_RENDER_PASS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_render_pass(
+    device: Device,
+    render_pass: RenderPass,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, RenderPass, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_RENDER_PASS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyRenderPass",
+        &[handle_arg("renderPass", render_pass)],
+    );
+    check_handles(
+        "vkDestroyRenderPass",
+        &[
+            handle_arg("device", device),
+            handle_arg("renderPass", render_pass),
+        ],
+    );
+    let result = real(device, render_pass, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyRenderPass", &render_pass, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyRenderPass",
+        args: &[
+            ("device", &device),
+            ("renderPass", &render_pass),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SAMPLER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_sampler(
+    device: Device,
+    sampler: Sampler,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Sampler, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_SAMPLER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroySampler", &[handle_arg("sampler", sampler)]);
+    check_handles(
+        "vkDestroySampler",
+        &[handle_arg("device", device), handle_arg("sampler", sampler)],
+    );
+    let result = real(device, sampler, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySampler", &sampler, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySampler",
+        args: &[
+            ("device", &device),
+            ("sampler", &sampler),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SAMPLER_YCBCR_CONVERSION: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: Device,
+    ycbcr_conversion: SamplerYcbcrConversion,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        SamplerYcbcrConversion,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_SAMPLER_YCBCR_CONVERSION.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroySamplerYcbcrConversion",
+        &[handle_arg("ycbcrConversion", ycbcr_conversion)],
+    );
+    check_handles(
+        "vkDestroySamplerYcbcrConversion",
+        &[
+            handle_arg("device", device),
+            handle_arg("ycbcrConversion", ycbcr_conversion),
+        ],
+    );
+    let result = real(device, ycbcr_conversion, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySamplerYcbcrConversion", &ycbcr_conversion, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySamplerYcbcrConversion",
+        args: &[
+            ("device", &device),
+            ("ycbcrConversion", &ycbcr_conversion),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SAMPLER_YCBCR_CONVERSION_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_sampler_ycbcr_conversion_khr(
+    device: Device,
+    ycbcr_conversion: SamplerYcbcrConversion,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        SamplerYcbcrConversion,
+        *const AllocationCallbacks,
+    ) -> c_void =
+        unsafe { mem::transmute(DESTROY_SAMPLER_YCBCR_CONVERSION_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroySamplerYcbcrConversionKHR",
+        &[handle_arg("ycbcrConversion", ycbcr_conversion)],
+    );
+    check_handles(
+        "vkDestroySamplerYcbcrConversionKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("ycbcrConversion", ycbcr_conversion),
+        ],
+    );
+    let result = real(device, ycbcr_conversion, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySamplerYcbcrConversionKHR", &ycbcr_conversion, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySamplerYcbcrConversionKHR",
+        args: &[
+            ("device", &device),
+            ("ycbcrConversion", &ycbcr_conversion),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SEMAPHORE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_semaphore(
+    device: Device,
+    semaphore: Semaphore,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, Semaphore, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_SEMAPHORE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroySemaphore", &[handle_arg("semaphore", semaphore)]);
+    check_handles(
+        "vkDestroySemaphore",
+        &[
+            handle_arg("device", device),
+            handle_arg("semaphore", semaphore),
+        ],
+    );
+    let result = real(device, semaphore, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySemaphore", &semaphore, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySemaphore",
+        args: &[
+            ("device", &device),
+            ("semaphore", &semaphore),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SHADER_MODULE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_shader_module(
+    device: Device,
+    shader_module: ShaderModule,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, ShaderModule, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_SHADER_MODULE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyShaderModule",
+        &[handle_arg("shaderModule", shader_module)],
+    );
+    check_handles(
+        "vkDestroyShaderModule",
+        &[
+            handle_arg("device", device),
+            handle_arg("shaderModule", shader_module),
+        ],
+    );
+    let result = real(device, shader_module, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyShaderModule", &shader_module, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyShaderModule",
+        args: &[
+            ("device", &device),
+            ("shaderModule", &shader_module),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SURFACE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_surface_khr(
+    instance: Instance,
+    surface: SurfaceKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Instance, SurfaceKHR, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_SURFACE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkDestroySurfaceKHR", &[handle_arg("surface", surface)]);
+    check_handles(
+        "vkDestroySurfaceKHR",
+        &[
+            handle_arg("instance", instance),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(instance, surface, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySurfaceKHR", &surface, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySurfaceKHR",
+        args: &[
+            ("instance", &instance),
+            ("surface", &surface),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_SWAPCHAIN_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_swapchain_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, SwapchainKHR, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_SWAPCHAIN_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroySwapchainKHR",
+        &[handle_arg("swapchain", swapchain)],
+    );
+    check_handles(
+        "vkDestroySwapchainKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroySwapchainKHR", &swapchain, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroySwapchainKHR",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DESTROY_VALIDATION_CACHE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn destroy_validation_cache_ext(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, ValidationCacheEXT, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(DESTROY_VALIDATION_CACHE_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkDestroyValidationCacheEXT",
+        &[handle_arg("validationCache", validation_cache)],
+    );
+    check_handles(
+        "vkDestroyValidationCacheEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("validationCache", validation_cache),
+        ],
+    );
+    let result = real(device, validation_cache, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkDestroyValidationCacheEXT", &validation_cache, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkDestroyValidationCacheEXT",
+        args: &[
+            ("device", &device),
+            ("validationCache", &validation_cache),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static DEVICE_WAIT_IDLE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn device_wait_idle(device: Device) -> Result {
+    let real: extern "system" fn(Device) -> Result =
+        unsafe { mem::transmute(DEVICE_WAIT_IDLE.load(Ordering::Relaxed)) };
+    check_handles("vkDeviceWaitIdle", &[handle_arg("device", device)]);
+    let result = real(device);
+    record(&TraceCall {
+        command: "vkDeviceWaitIdle",
+        args: &[("device", &device)],
+        result: Some(&result),
+    });
+    result
+}
+static DISPLAY_POWER_CONTROL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn display_power_control_ext(
+    device: Device,
+    display: DisplayKHR,
+    p_display_power_info: *const DisplayPowerInfoEXT,
+) -> Result {
+    let real: extern "system" fn(Device, DisplayKHR, *const DisplayPowerInfoEXT) -> Result =
+        unsafe { mem::transmute(DISPLAY_POWER_CONTROL_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkDisplayPowerControlEXT",
+        &[handle_arg("device", device), handle_arg("display", display)],
+    );
+    let result = real(device, display, p_display_power_info);
+    record(&TraceCall {
+        command: "vkDisplayPowerControlEXT",
+        args: &[
+            ("device", &device),
+            ("display", &display),
+            ("pDisplayPowerInfo", &Pointee(p_display_power_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static END_COMMAND_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn end_command_buffer(command_buffer: CommandBuffer) -> Result {
+    let real: extern "system" fn(CommandBuffer) -> Result =
+        unsafe { mem::transmute(END_COMMAND_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkEndCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkEndCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer);
+    record(&TraceCall {
+        command: "vkEndCommandBuffer",
+        args: &[("commandBuffer", &command_buffer)],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_DEVICE_EXTENSION_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_device_extension_properties(
+    physical_device: PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut ExtensionProperties,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const c_char,
+        *mut u32,
+        *mut ExtensionProperties,
+    ) -> Result =
+        unsafe { mem::transmute(ENUMERATE_DEVICE_EXTENSION_PROPERTIES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkEnumerateDeviceExtensionProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_layer_name,
+        p_property_count,
+        p_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumerateDeviceExtensionProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pLayerName", &CStrArg(p_layer_name)),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_DEVICE_LAYER_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_device_layer_properties(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut LayerProperties,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut LayerProperties) -> Result =
+        unsafe { mem::transmute(ENUMERATE_DEVICE_LAYER_PROPERTIES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkEnumerateDeviceLayerProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumerateDeviceLayerProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_INSTANCE_EXTENSION_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_instance_extension_properties(
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut ExtensionProperties,
+) -> Result {
+    let real: extern "system" fn(*const c_char, *mut u32, *mut ExtensionProperties) -> Result =
+        unsafe { mem::transmute(ENUMERATE_INSTANCE_EXTENSION_PROPERTIES.load(Ordering::Relaxed)) };
+    let result = real(p_layer_name, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumerateInstanceExtensionProperties",
+        args: &[
+            ("pLayerName", &CStrArg(p_layer_name)),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_INSTANCE_LAYER_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut LayerProperties,
+) -> Result {
+    let real: extern "system" fn(*mut u32, *mut LayerProperties) -> Result =
+        unsafe { mem::transmute(ENUMERATE_INSTANCE_LAYER_PROPERTIES.load(Ordering::Relaxed)) };
+    let result = real(p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumerateInstanceLayerProperties",
+        args: &[
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_INSTANCE_VERSION: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_instance_version(p_api_version: *mut u32) -> Result {
+    let real: extern "system" fn(*mut u32) -> Result =
+        unsafe { mem::transmute(ENUMERATE_INSTANCE_VERSION.load(Ordering::Relaxed)) };
+    let result = real(p_api_version);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumerateInstanceVersion",
+        args: &[("pApiVersion", &Pointee(p_api_version, ok))],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_PHYSICAL_DEVICE_GROUPS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_physical_device_groups(
+    instance: Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    let real: extern "system" fn(Instance, *mut u32, *mut PhysicalDeviceGroupProperties) -> Result =
+        unsafe { mem::transmute(ENUMERATE_PHYSICAL_DEVICE_GROUPS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkEnumeratePhysicalDeviceGroups",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(
+        instance,
+        p_physical_device_group_count,
+        p_physical_device_group_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumeratePhysicalDeviceGroups",
+        args: &[
+            ("instance", &instance),
+            (
+                "pPhysicalDeviceGroupCount",
+                &Pointee(p_physical_device_group_count, ok),
+            ),
+            (
+                "pPhysicalDeviceGroupProperties",
+                &Pointee(p_physical_device_group_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_PHYSICAL_DEVICE_GROUPS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_physical_device_groups_khr(
+    instance: Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    let real: extern "system" fn(Instance, *mut u32, *mut PhysicalDeviceGroupProperties) -> Result =
+        unsafe { mem::transmute(ENUMERATE_PHYSICAL_DEVICE_GROUPS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkEnumeratePhysicalDeviceGroupsKHR",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(
+        instance,
+        p_physical_device_group_count,
+        p_physical_device_group_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumeratePhysicalDeviceGroupsKHR",
+        args: &[
+            ("instance", &instance),
+            (
+                "pPhysicalDeviceGroupCount",
+                &Pointee(p_physical_device_group_count, ok),
+            ),
+            (
+                "pPhysicalDeviceGroupProperties",
+                &Pointee(p_physical_device_group_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_COUNTERS_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_physical_device_queue_family_performance_query_counters_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    p_counter_count: *mut u32,
+    p_counters: *mut PerformanceCounterKHR,
+    p_counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        u32,
+        *mut u32,
+        *mut PerformanceCounterKHR,
+        *mut PerformanceCounterDescriptionKHR,
+    ) -> Result = unsafe {
+        mem::transmute(
+            ENUMERATE_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_COUNTERS_KHR
+                .load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        queue_family_index,
+        p_counter_count,
+        p_counters,
+        p_counter_descriptions,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("pCounterCount", &Pointee(p_counter_count, ok)),
+            ("pCounters", &Pointee(p_counters, ok)),
+            ("pCounterDescriptions", &Pointee(p_counter_descriptions, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static ENUMERATE_PHYSICAL_DEVICES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn enumerate_physical_devices(
+    instance: Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut PhysicalDevice,
+) -> Result {
+    let real: extern "system" fn(Instance, *mut u32, *mut PhysicalDevice) -> Result =
+        unsafe { mem::transmute(ENUMERATE_PHYSICAL_DEVICES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkEnumeratePhysicalDevices",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, p_physical_device_count, p_physical_devices);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkEnumeratePhysicalDevices",
+                false,
+                Some(handle_arg("instance", instance)),
+                p_physical_devices,
+                *p_physical_device_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkEnumeratePhysicalDevices",
+        args: &[
+            ("instance", &instance),
+            (
+                "pPhysicalDeviceCount",
+                &Pointee(p_physical_device_count, ok),
+            ),
+            ("pPhysicalDevices", &p_physical_devices),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static FLUSH_MAPPED_MEMORY_RANGES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn flush_mapped_memory_ranges(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const MappedMemoryRange) -> Result =
+        unsafe { mem::transmute(FLUSH_MAPPED_MEMORY_RANGES.load(Ordering::Relaxed)) };
+    check_handles("vkFlushMappedMemoryRanges", &[handle_arg("device", device)]);
+    let result = real(device, memory_range_count, p_memory_ranges);
+    record(&TraceCall {
+        command: "vkFlushMappedMemoryRanges",
+        args: &[
+            ("device", &device),
+            ("memoryRangeCount", &memory_range_count),
+            (
+                "pMemoryRanges",
+                &Pointees(p_memory_ranges, memory_range_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static FREE_COMMAND_BUFFERS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn free_command_buffers(
+    device: Device,
+    command_pool: CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const CommandBuffer,
+) -> c_void {
+    let real: extern "system" fn(Device, CommandPool, u32, *const CommandBuffer) -> c_void =
+        unsafe { mem::transmute(FREE_COMMAND_BUFFERS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkFreeCommandBuffers",
+        &[handle_arg("commandPool", command_pool)],
+    );
+    check_handles(
+        "vkFreeCommandBuffers",
+        &[
+            handle_arg("device", device),
+            handle_arg("commandPool", command_pool),
+        ],
+    );
+    let result = real(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    );
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkFreeCommandBuffers",
+                p_command_buffers,
+                command_buffer_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkFreeCommandBuffers",
+        args: &[
+            ("device", &device),
+            ("commandPool", &command_pool),
+            ("commandBufferCount", &command_buffer_count),
+            (
+                "pCommandBuffers",
+                &Pointees(p_command_buffers, command_buffer_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static FREE_DESCRIPTOR_SETS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn free_descriptor_sets(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const DescriptorSet,
+) -> Result {
+    let real: extern "system" fn(Device, DescriptorPool, u32, *const DescriptorSet) -> Result =
+        unsafe { mem::transmute(FREE_DESCRIPTOR_SETS.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkFreeDescriptorSets",
+        &[handle_arg("descriptorPool", descriptor_pool)],
+    );
+    check_handles(
+        "vkFreeDescriptorSets",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorPool", descriptor_pool),
+        ],
+    );
+    let result = real(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_destroyed(
+                "vkFreeDescriptorSets",
+                p_descriptor_sets,
+                descriptor_set_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkFreeDescriptorSets",
+        args: &[
+            ("device", &device),
+            ("descriptorPool", &descriptor_pool),
+            ("descriptorSetCount", &descriptor_set_count),
+            (
+                "pDescriptorSets",
+                &Pointees(p_descriptor_sets, descriptor_set_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static FREE_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn free_memory(
+    device: Device,
+    memory: DeviceMemory,
+    p_allocator: *const AllocationCallbacks,
+) -> c_void {
+    let real: extern "system" fn(Device, DeviceMemory, *const AllocationCallbacks) -> c_void =
+        unsafe { mem::transmute(FREE_MEMORY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkFreeMemory", &[handle_arg("memory", memory)]);
+    check_handles(
+        "vkFreeMemory",
+        &[handle_arg("device", device), handle_arg("memory", memory)],
+    );
+    let result = real(device, memory, p_allocator);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_destroyed("vkFreeMemory", &memory, 1);
+        }
+    }
+    record(&TraceCall {
+        command: "vkFreeMemory",
+        args: &[
+            ("device", &device),
+            ("memory", &memory),
+            ("pAllocator", &Pointee(p_allocator, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_ACCELERATION_STRUCTURE_BUILD_SIZES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_acceleration_structure_build_sizes_khr(
+    device: Device,
+    build_type: AccelerationStructureBuildTypeKHR,
+    p_build_info: *const AccelerationStructureBuildGeometryInfoKHR,
+    p_max_primitive_counts: *const u32,
+    p_size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        AccelerationStructureBuildTypeKHR,
+        *const AccelerationStructureBuildGeometryInfoKHR,
+        *const u32,
+        *mut AccelerationStructureBuildSizesInfoKHR,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_ACCELERATION_STRUCTURE_BUILD_SIZES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetAccelerationStructureBuildSizesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        build_type,
+        p_build_info,
+        p_max_primitive_counts,
+        p_size_info,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetAccelerationStructureBuildSizesKHR",
+        args: &[
+            ("device", &device),
+            ("buildType", &build_type),
+            ("pBuildInfo", &Pointee(p_build_info, true)),
+            (
+                "pMaxPrimitiveCounts",
+                &Pointee(p_max_primitive_counts, true),
+            ),
+            ("pSizeInfo", &Pointee(p_size_info, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_ACCELERATION_STRUCTURE_DEVICE_ADDRESS_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_acceleration_structure_device_address_khr(
+    device: Device,
+    p_info: *const AccelerationStructureDeviceAddressInfoKHR,
+) -> DeviceAddress {
+    let real: extern "system" fn(
+        Device,
+        *const AccelerationStructureDeviceAddressInfoKHR,
+    ) -> DeviceAddress = unsafe {
+        mem::transmute(GET_ACCELERATION_STRUCTURE_DEVICE_ADDRESS_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetAccelerationStructureDeviceAddressKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetAccelerationStructureDeviceAddressKHR",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_ACCELERATION_STRUCTURE_HANDLE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_acceleration_structure_handle_nv(
+    device: Device,
+    acceleration_structure: AccelerationStructureNV,
+    data_size: size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, AccelerationStructureNV, size_t, *mut c_void) -> Result =
+        unsafe { mem::transmute(GET_ACCELERATION_STRUCTURE_HANDLE_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetAccelerationStructureHandleNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("accelerationStructure", acceleration_structure),
+        ],
+    );
+    let result = real(device, acceleration_structure, data_size, p_data);
+    record(&TraceCall {
+        command: "vkGetAccelerationStructureHandleNV",
+        args: &[
+            ("device", &device),
+            ("accelerationStructure", &acceleration_structure),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: Device,
+    p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+    p_memory_requirements: *mut MemoryRequirements2KHR,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const AccelerationStructureMemoryRequirementsInfoNV,
+        *mut MemoryRequirements2KHR,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_NV.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetAccelerationStructureMemoryRequirementsNV",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    record(&TraceCall {
+        command: "vkGetAccelerationStructureMemoryRequirementsNV",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &p_memory_requirements),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_android_hardware_buffer_properties_android(
+    device: Device,
+    buffer: *const AHardwareBuffer,
+    p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const AHardwareBuffer,
+        *mut AndroidHardwareBufferPropertiesANDROID,
+    ) -> Result = unsafe {
+        mem::transmute(GET_ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, buffer, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetAndroidHardwareBufferPropertiesANDROID",
+        args: &[
+            ("device", &device),
+            ("buffer", &buffer),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_BUFFER_DEVICE_ADDRESS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_device_address(
+    device: Device,
+    p_info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let real: extern "system" fn(Device, *const BufferDeviceAddressInfo) -> DeviceAddress =
+        unsafe { mem::transmute(GET_BUFFER_DEVICE_ADDRESS.load(Ordering::Relaxed)) };
+    check_handles("vkGetBufferDeviceAddress", &[handle_arg("device", device)]);
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetBufferDeviceAddress",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_BUFFER_DEVICE_ADDRESS_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_device_address_ext(
+    device: Device,
+    p_info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let real: extern "system" fn(Device, *const BufferDeviceAddressInfo) -> DeviceAddress =
+        unsafe { mem::transmute(GET_BUFFER_DEVICE_ADDRESS_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferDeviceAddressEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetBufferDeviceAddressEXT",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_BUFFER_DEVICE_ADDRESS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_device_address_khr(
+    device: Device,
+    p_info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let real: extern "system" fn(Device, *const BufferDeviceAddressInfo) -> DeviceAddress =
+        unsafe { mem::transmute(GET_BUFFER_DEVICE_ADDRESS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferDeviceAddressKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetBufferDeviceAddressKHR",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_BUFFER_MEMORY_REQUIREMENTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_memory_requirements(
+    device: Device,
+    buffer: Buffer,
+    p_memory_requirements: *mut MemoryRequirements,
+) -> c_void {
+    let real: extern "system" fn(Device, Buffer, *mut MemoryRequirements) -> c_void =
+        unsafe { mem::transmute(GET_BUFFER_MEMORY_REQUIREMENTS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferMemoryRequirements",
+        &[handle_arg("device", device), handle_arg("buffer", buffer)],
+    );
+    let result = real(device, buffer, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetBufferMemoryRequirements",
+        args: &[
+            ("device", &device),
+            ("buffer", &buffer),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_BUFFER_MEMORY_REQUIREMENTS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_memory_requirements2(
+    device: Device,
+    p_info: *const BufferMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const BufferMemoryRequirementsInfo2,
+        *mut MemoryRequirements2,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_BUFFER_MEMORY_REQUIREMENTS2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferMemoryRequirements2",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetBufferMemoryRequirements2",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_BUFFER_MEMORY_REQUIREMENTS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_memory_requirements2_khr(
+    device: Device,
+    p_info: *const BufferMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const BufferMemoryRequirementsInfo2,
+        *mut MemoryRequirements2,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_BUFFER_MEMORY_REQUIREMENTS2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferMemoryRequirements2KHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetBufferMemoryRequirements2KHR",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_BUFFER_OPAQUE_CAPTURE_ADDRESS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_opaque_capture_address(
+    device: Device,
+    p_info: *const BufferDeviceAddressInfo,
+) -> u64 {
+    let real: extern "system" fn(Device, *const BufferDeviceAddressInfo) -> u64 =
+        unsafe { mem::transmute(GET_BUFFER_OPAQUE_CAPTURE_ADDRESS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferOpaqueCaptureAddress",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetBufferOpaqueCaptureAddress",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_BUFFER_OPAQUE_CAPTURE_ADDRESS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_buffer_opaque_capture_address_khr(
+    device: Device,
+    p_info: *const BufferDeviceAddressInfo,
+) -> u64 {
+    let real: extern "system" fn(Device, *const BufferDeviceAddressInfo) -> u64 =
+        unsafe { mem::transmute(GET_BUFFER_OPAQUE_CAPTURE_ADDRESS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetBufferOpaqueCaptureAddressKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetBufferOpaqueCaptureAddressKHR",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_CALIBRATED_TIMESTAMPS_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_calibrated_timestamps_ext(
+    device: Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const CalibratedTimestampInfoEXT,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const CalibratedTimestampInfoEXT,
+        *mut u64,
+        *mut u64,
+    ) -> Result = unsafe { mem::transmute(GET_CALIBRATED_TIMESTAMPS_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetCalibratedTimestampsEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetCalibratedTimestampsEXT",
+        args: &[
+            ("device", &device),
+            ("timestampCount", &timestamp_count),
+            (
+                "pTimestampInfos",
+                &Pointees(p_timestamp_infos, timestamp_count as usize, true),
+            ),
+            (
+                "pTimestamps",
+                &Pointees(p_timestamps, timestamp_count as usize, ok),
+            ),
+            (
+                "pMaxDeviation",
+                &Pointees(p_max_deviation, timestamp_count as usize, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEFERRED_OPERATION_MAX_CONCURRENCY_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_deferred_operation_max_concurrency_khr(
+    device: Device,
+    operation: DeferredOperationKHR,
+) -> u32 {
+    let real: extern "system" fn(Device, DeferredOperationKHR) -> u32 = unsafe {
+        mem::transmute(GET_DEFERRED_OPERATION_MAX_CONCURRENCY_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeferredOperationMaxConcurrencyKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("operation", operation),
+        ],
+    );
+    let result = real(device, operation);
+    record(&TraceCall {
+        command: "vkGetDeferredOperationMaxConcurrencyKHR",
+        args: &[("device", &device), ("operation", &operation)],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEFERRED_OPERATION_RESULT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_deferred_operation_result_khr(
+    device: Device,
+    operation: DeferredOperationKHR,
+) -> Result {
+    let real: extern "system" fn(Device, DeferredOperationKHR) -> Result =
+        unsafe { mem::transmute(GET_DEFERRED_OPERATION_RESULT_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDeferredOperationResultKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("operation", operation),
+        ],
+    );
+    let result = real(device, operation);
+    record(&TraceCall {
+        command: "vkGetDeferredOperationResultKHR",
+        args: &[("device", &device), ("operation", &operation)],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DESCRIPTOR_SET_LAYOUT_SUPPORT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_descriptor_set_layout_support(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_support: *mut DescriptorSetLayoutSupport,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorSetLayoutCreateInfo,
+        *mut DescriptorSetLayoutSupport,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_DESCRIPTOR_SET_LAYOUT_SUPPORT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDescriptorSetLayoutSupport",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_support);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDescriptorSetLayoutSupport",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pSupport", &Pointee(p_support, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_descriptor_set_layout_support_khr(
+    device: Device,
+    p_create_info: *const DescriptorSetLayoutCreateInfo,
+    p_support: *mut DescriptorSetLayoutSupport,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const DescriptorSetLayoutCreateInfo,
+        *mut DescriptorSetLayoutSupport,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDescriptorSetLayoutSupportKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_create_info, p_support);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDescriptorSetLayoutSupportKHR",
+        args: &[
+            ("device", &device),
+            ("pCreateInfo", &Pointee(p_create_info, true)),
+            ("pSupport", &Pointee(p_support, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_ACCELERATION_STRUCTURE_COMPATIBILITY_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_acceleration_structure_compatibility_khr(
+    device: Device,
+    p_version_info: *const AccelerationStructureVersionInfoKHR,
+    p_compatibility: *mut AccelerationStructureCompatibilityKHR,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const AccelerationStructureVersionInfoKHR,
+        *mut AccelerationStructureCompatibilityKHR,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_DEVICE_ACCELERATION_STRUCTURE_COMPATIBILITY_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_version_info, p_compatibility);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        args: &[
+            ("device", &device),
+            ("pVersionInfo", &Pointee(p_version_info, true)),
+            ("pCompatibility", &Pointee(p_compatibility, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_GROUP_PEER_MEMORY_FEATURES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_group_peer_memory_features(
+    device: Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+) -> c_void {
+    let real: extern "system" fn(Device, u32, u32, u32, *mut PeerMemoryFeatureFlags) -> c_void =
+        unsafe { mem::transmute(GET_DEVICE_GROUP_PEER_MEMORY_FEATURES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDeviceGroupPeerMemoryFeatures",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDeviceGroupPeerMemoryFeatures",
+        args: &[
+            ("device", &device),
+            ("heapIndex", &heap_index),
+            ("localDeviceIndex", &local_device_index),
+            ("remoteDeviceIndex", &remote_device_index),
+            ("pPeerMemoryFeatures", &Pointee(p_peer_memory_features, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_GROUP_PEER_MEMORY_FEATURES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_group_peer_memory_features_khr(
+    device: Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+) -> c_void {
+    let real: extern "system" fn(Device, u32, u32, u32, *mut PeerMemoryFeatureFlags) -> c_void = unsafe {
+        mem::transmute(GET_DEVICE_GROUP_PEER_MEMORY_FEATURES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+        args: &[
+            ("device", &device),
+            ("heapIndex", &heap_index),
+            ("localDeviceIndex", &local_device_index),
+            ("remoteDeviceIndex", &remote_device_index),
+            ("pPeerMemoryFeatures", &Pointee(p_peer_memory_features, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_GROUP_PRESENT_CAPABILITIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_group_present_capabilities_khr(
+    device: Device,
+    p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *mut DeviceGroupPresentCapabilitiesKHR) -> Result = unsafe {
+        mem::transmute(GET_DEVICE_GROUP_PRESENT_CAPABILITIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_device_group_present_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDeviceGroupPresentCapabilitiesKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pDeviceGroupPresentCapabilities",
+                &Pointee(p_device_group_present_capabilities, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEVICE_GROUP_SURFACE_PRESENT_MODES2_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_group_surface_present_modes2_ext(
+    device: Device,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PhysicalDeviceSurfaceInfo2KHR,
+        *mut DeviceGroupPresentModeFlagsKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_DEVICE_GROUP_SURFACE_PRESENT_MODES2_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceGroupSurfacePresentModes2EXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_surface_info, p_modes);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDeviceGroupSurfacePresentModes2EXT",
+        args: &[
+            ("device", &device),
+            ("pSurfaceInfo", &Pointee(p_surface_info, true)),
+            ("pModes", &Pointee(p_modes, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEVICE_GROUP_SURFACE_PRESENT_MODES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_group_surface_present_modes_khr(
+    device: Device,
+    surface: SurfaceKHR,
+    p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        SurfaceKHR,
+        *mut DeviceGroupPresentModeFlagsKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_DEVICE_GROUP_SURFACE_PRESENT_MODES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        &[handle_arg("device", device), handle_arg("surface", surface)],
+    );
+    let result = real(device, surface, p_modes);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDeviceGroupSurfacePresentModesKHR",
+        args: &[
+            ("device", &device),
+            ("surface", &surface),
+            ("pModes", &Pointee(p_modes, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEVICE_MEMORY_COMMITMENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_memory_commitment(
+    device: Device,
+    memory: DeviceMemory,
+    p_committed_memory_in_bytes: *mut DeviceSize,
+) -> c_void {
+    let real: extern "system" fn(Device, DeviceMemory, *mut DeviceSize) -> c_void =
+        unsafe { mem::transmute(GET_DEVICE_MEMORY_COMMITMENT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDeviceMemoryCommitment",
+        &[handle_arg("device", device), handle_arg("memory", memory)],
+    );
+    let result = real(device, memory, p_committed_memory_in_bytes);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetDeviceMemoryCommitment",
+        args: &[
+            ("device", &device),
+            ("memory", &memory),
+            (
+                "pCommittedMemoryInBytes",
+                &Pointee(p_committed_memory_in_bytes, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_memory_opaque_capture_address(
+    device: Device,
+    p_info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+) -> u64 {
+    let real: extern "system" fn(Device, *const DeviceMemoryOpaqueCaptureAddressInfo) -> u64 =
+        unsafe { mem::transmute(GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDeviceMemoryOpaqueCaptureAddress",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetDeviceMemoryOpaqueCaptureAddress",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_memory_opaque_capture_address_khr(
+    device: Device,
+    p_info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+) -> u64 {
+    let real: extern "system" fn(Device, *const DeviceMemoryOpaqueCaptureAddressInfo) -> u64 = unsafe {
+        mem::transmute(GET_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DEVICE_QUEUE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_queue(
+    device: Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut Queue,
+) -> c_void {
+    let real: extern "system" fn(Device, u32, u32, *mut Queue) -> c_void =
+        unsafe { mem::transmute(GET_DEVICE_QUEUE.load(Ordering::Relaxed)) };
+    check_handles("vkGetDeviceQueue", &[handle_arg("device", device)]);
+    let result = real(device, queue_family_index, queue_index, p_queue);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_created(
+                "vkGetDeviceQueue",
+                false,
+                Some(handle_arg("device", device)),
+                p_queue,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkGetDeviceQueue",
+        args: &[
+            ("device", &device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("queueIndex", &queue_index),
+            ("pQueue", &Pointee(p_queue, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DEVICE_QUEUE2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_device_queue2(
+    device: Device,
+    p_queue_info: *const DeviceQueueInfo2,
+    p_queue: *mut Queue,
+) -> c_void {
+    let real: extern "system" fn(Device, *const DeviceQueueInfo2, *mut Queue) -> c_void =
+        unsafe { mem::transmute(GET_DEVICE_QUEUE2.load(Ordering::Relaxed)) };
+    check_handles("vkGetDeviceQueue2", &[handle_arg("device", device)]);
+    let result = real(device, p_queue_info, p_queue);
+    let ok = true;
+    if ok {
+        unsafe {
+            track_created(
+                "vkGetDeviceQueue2",
+                false,
+                Some(handle_arg("device", device)),
+                p_queue,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkGetDeviceQueue2",
+        args: &[
+            ("device", &device),
+            ("pQueueInfo", &Pointee(p_queue_info, true)),
+            ("pQueue", &Pointee(p_queue, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_DISPLAY_MODE_PROPERTIES2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_display_mode_properties2_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModeProperties2KHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        DisplayKHR,
+        *mut u32,
+        *mut DisplayModeProperties2KHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_DISPLAY_MODE_PROPERTIES2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDisplayModeProperties2KHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("display", display),
+        ],
+    );
+    let result = real(physical_device, display, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDisplayModeProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("display", &display),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DISPLAY_MODE_PROPERTIES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_display_mode_properties_khr(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayModePropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        DisplayKHR,
+        *mut u32,
+        *mut DisplayModePropertiesKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_DISPLAY_MODE_PROPERTIES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDisplayModePropertiesKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("display", display),
+        ],
+    );
+    let result = real(physical_device, display, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDisplayModePropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("display", &display),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DISPLAY_PLANE_CAPABILITIES2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_display_plane_capabilities2_khr(
+    physical_device: PhysicalDevice,
+    p_display_plane_info: *const DisplayPlaneInfo2KHR,
+    p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const DisplayPlaneInfo2KHR,
+        *mut DisplayPlaneCapabilities2KHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_DISPLAY_PLANE_CAPABILITIES2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDisplayPlaneCapabilities2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_display_plane_info, p_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDisplayPlaneCapabilities2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pDisplayPlaneInfo", &Pointee(p_display_plane_info, true)),
+            ("pCapabilities", &Pointee(p_capabilities, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DISPLAY_PLANE_CAPABILITIES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_display_plane_capabilities_khr(
+    physical_device: PhysicalDevice,
+    mode: DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        DisplayModeKHR,
+        u32,
+        *mut DisplayPlaneCapabilitiesKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_DISPLAY_PLANE_CAPABILITIES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDisplayPlaneCapabilitiesKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("mode", mode),
+        ],
+    );
+    let result = real(physical_device, mode, plane_index, p_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetDisplayPlaneCapabilitiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("mode", &mode),
+            ("planeIndex", &plane_index),
+            ("pCapabilities", &Pointee(p_capabilities, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_DISPLAY_PLANE_SUPPORTED_DISPLAYS_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_display_plane_supported_displays_khr(
+    physical_device: PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut DisplayKHR,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, u32, *mut u32, *mut DisplayKHR) -> Result =
+        unsafe { mem::transmute(GET_DISPLAY_PLANE_SUPPORTED_DISPLAYS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetDisplayPlaneSupportedDisplaysKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, plane_index, p_display_count, p_displays);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkGetDisplayPlaneSupportedDisplaysKHR",
+                false,
+                Some(handle_arg("physicalDevice", physical_device)),
+                p_displays,
+                *p_display_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkGetDisplayPlaneSupportedDisplaysKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("planeIndex", &plane_index),
+            ("pDisplayCount", &Pointee(p_display_count, ok)),
+            ("pDisplays", &p_displays),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_EVENT_STATUS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_event_status(device: Device, event: Event) -> Result {
+    let real: extern "system" fn(Device, Event) -> Result =
+        unsafe { mem::transmute(GET_EVENT_STATUS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetEventStatus",
+        &[handle_arg("device", device), handle_arg("event", event)],
+    );
+    let result = real(device, event);
+    record(&TraceCall {
+        command: "vkGetEventStatus",
+        args: &[("device", &device), ("event", &event)],
+        result: Some(&result),
+    });
+    result
+}
+static GET_FENCE_FD_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_fence_fd_khr(
+    device: Device,
+    p_get_fd_info: *const FenceGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    let real: extern "system" fn(Device, *const FenceGetFdInfoKHR, *mut c_int) -> Result =
+        unsafe { mem::transmute(GET_FENCE_FD_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkGetFenceFdKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_get_fd_info, p_fd);
+    record(&TraceCall {
+        command: "vkGetFenceFdKHR",
+        args: &[
+            ("device", &device),
+            ("pGetFdInfo", &Pointee(p_get_fd_info, true)),
+            ("pFd", &p_fd),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_FENCE_STATUS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_fence_status(device: Device, fence: Fence) -> Result {
+    let real: extern "system" fn(Device, Fence) -> Result =
+        unsafe { mem::transmute(GET_FENCE_STATUS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetFenceStatus",
+        &[handle_arg("device", device), handle_arg("fence", fence)],
+    );
+    let result = real(device, fence);
+    record(&TraceCall {
+        command: "vkGetFenceStatus",
+        args: &[("device", &device), ("fence", &fence)],
+        result: Some(&result),
+    });
+    result
+}
+static GET_FENCE_WIN32_HANDLE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_fence_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    let real: extern "system" fn(Device, *const FenceGetWin32HandleInfoKHR, *mut HANDLE) -> Result =
+        unsafe { mem::transmute(GET_FENCE_WIN32_HANDLE_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkGetFenceWin32HandleKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_get_win32_handle_info, p_handle);
+    record(&TraceCall {
+        command: "vkGetFenceWin32HandleKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pGetWin32HandleInfo",
+                &Pointee(p_get_win32_handle_info, true),
+            ),
+            ("pHandle", &p_handle),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_GENERATED_COMMANDS_MEMORY_REQUIREMENTS_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_generated_commands_memory_requirements_nv(
+    device: Device,
+    p_info: *const GeneratedCommandsMemoryRequirementsInfoNV,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const GeneratedCommandsMemoryRequirementsInfoNV,
+        *mut MemoryRequirements2,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_GENERATED_COMMANDS_MEMORY_REQUIREMENTS_NV.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetGeneratedCommandsMemoryRequirementsNV",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetGeneratedCommandsMemoryRequirementsNV",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: Device,
+    image: Image,
+    p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        Image,
+        *mut ImageDrmFormatModifierPropertiesEXT,
+    ) -> Result = unsafe {
+        mem::transmute(GET_IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetImageDrmFormatModifierPropertiesEXT",
+        &[handle_arg("device", device), handle_arg("image", image)],
+    );
+    let result = real(device, image, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetImageDrmFormatModifierPropertiesEXT",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_IMAGE_MEMORY_REQUIREMENTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_memory_requirements(
+    device: Device,
+    image: Image,
+    p_memory_requirements: *mut MemoryRequirements,
+) -> c_void {
+    let real: extern "system" fn(Device, Image, *mut MemoryRequirements) -> c_void =
+        unsafe { mem::transmute(GET_IMAGE_MEMORY_REQUIREMENTS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageMemoryRequirements",
+        &[handle_arg("device", device), handle_arg("image", image)],
+    );
+    let result = real(device, image, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageMemoryRequirements",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_MEMORY_REQUIREMENTS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_memory_requirements2(
+    device: Device,
+    p_info: *const ImageMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const ImageMemoryRequirementsInfo2,
+        *mut MemoryRequirements2,
+    ) -> c_void = unsafe { mem::transmute(GET_IMAGE_MEMORY_REQUIREMENTS2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageMemoryRequirements2",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageMemoryRequirements2",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_MEMORY_REQUIREMENTS2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_memory_requirements2_khr(
+    device: Device,
+    p_info: *const ImageMemoryRequirementsInfo2,
+    p_memory_requirements: *mut MemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const ImageMemoryRequirementsInfo2,
+        *mut MemoryRequirements2,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_IMAGE_MEMORY_REQUIREMENTS2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageMemoryRequirements2KHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_memory_requirements);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageMemoryRequirements2KHR",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pMemoryRequirements", &Pointee(p_memory_requirements, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_sparse_memory_requirements(
+    device: Device,
+    image: Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        Image,
+        *mut u32,
+        *mut SparseImageMemoryRequirements,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageSparseMemoryRequirements",
+        &[handle_arg("device", device), handle_arg("image", image)],
+    );
+    let result = real(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageSparseMemoryRequirements",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            (
+                "pSparseMemoryRequirementCount",
+                &Pointee(p_sparse_memory_requirement_count, ok),
+            ),
+            (
+                "pSparseMemoryRequirements",
+                &Pointee(p_sparse_memory_requirements, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_sparse_memory_requirements2(
+    device: Device,
+    p_info: *const ImageSparseMemoryRequirementsInfo2,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const ImageSparseMemoryRequirementsInfo2,
+        *mut u32,
+        *mut SparseImageMemoryRequirements2,
+    ) -> c_void =
+        unsafe { mem::transmute(GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageSparseMemoryRequirements2",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageSparseMemoryRequirements2",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            (
+                "pSparseMemoryRequirementCount",
+                &Pointee(p_sparse_memory_requirement_count, ok),
+            ),
+            (
+                "pSparseMemoryRequirements",
+                &Pointee(p_sparse_memory_requirements, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_sparse_memory_requirements2_khr(
+    device: Device,
+    p_info: *const ImageSparseMemoryRequirementsInfo2,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        *const ImageSparseMemoryRequirementsInfo2,
+        *mut u32,
+        *mut SparseImageMemoryRequirements2,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_IMAGE_SPARSE_MEMORY_REQUIREMENTS2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetImageSparseMemoryRequirements2KHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageSparseMemoryRequirements2KHR",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            (
+                "pSparseMemoryRequirementCount",
+                &Pointee(p_sparse_memory_requirement_count, ok),
+            ),
+            (
+                "pSparseMemoryRequirements",
+                &Pointee(p_sparse_memory_requirements, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_SUBRESOURCE_LAYOUT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_subresource_layout(
+    device: Device,
+    image: Image,
+    p_subresource: *const ImageSubresource,
+    p_layout: *mut SubresourceLayout,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        Image,
+        *const ImageSubresource,
+        *mut SubresourceLayout,
+    ) -> c_void = unsafe { mem::transmute(GET_IMAGE_SUBRESOURCE_LAYOUT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageSubresourceLayout",
+        &[handle_arg("device", device), handle_arg("image", image)],
+    );
+    let result = real(device, image, p_subresource, p_layout);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetImageSubresourceLayout",
+        args: &[
+            ("device", &device),
+            ("image", &image),
+            ("pSubresource", &Pointee(p_subresource, true)),
+            ("pLayout", &Pointee(p_layout, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_IMAGE_VIEW_ADDRESS_NVX: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_view_address_nvx(
+    device: Device,
+    image_view: ImageView,
+    p_properties: *mut ImageViewAddressPropertiesNVX,
+) -> Result {
+    let real: extern "system" fn(Device, ImageView, *mut ImageViewAddressPropertiesNVX) -> Result =
+        unsafe { mem::transmute(GET_IMAGE_VIEW_ADDRESS_NVX.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetImageViewAddressNVX",
+        &[
+            handle_arg("device", device),
+            handle_arg("imageView", image_view),
+        ],
+    );
+    let result = real(device, image_view, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetImageViewAddressNVX",
+        args: &[
+            ("device", &device),
+            ("imageView", &image_view),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_IMAGE_VIEW_HANDLE_NVX: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_image_view_handle_nvx(
+    device: Device,
+    p_info: *const ImageViewHandleInfoNVX,
+) -> u32 {
+    let real: extern "system" fn(Device, *const ImageViewHandleInfoNVX) -> u32 =
+        unsafe { mem::transmute(GET_IMAGE_VIEW_HANDLE_NVX.load(Ordering::Relaxed)) };
+    check_handles("vkGetImageViewHandleNVX", &[handle_arg("device", device)]);
+    let result = real(device, p_info);
+    record(&TraceCall {
+        command: "vkGetImageViewHandleNVX",
+        args: &[("device", &device), ("pInfo", &Pointee(p_info, true))],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_ANDROID_HARDWARE_BUFFER_ANDROID: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_android_hardware_buffer_android(
+    device: Device,
+    p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+    p_buffer: *mut *mut AHardwareBuffer,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const MemoryGetAndroidHardwareBufferInfoANDROID,
+        *mut *mut AHardwareBuffer,
+    ) -> Result = unsafe {
+        mem::transmute(GET_MEMORY_ANDROID_HARDWARE_BUFFER_ANDROID.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetMemoryAndroidHardwareBufferANDROID",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_info, p_buffer);
+    record(&TraceCall {
+        command: "vkGetMemoryAndroidHardwareBufferANDROID",
+        args: &[
+            ("device", &device),
+            ("pInfo", &Pointee(p_info, true)),
+            ("pBuffer", &p_buffer),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_FD_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_fd_khr(
+    device: Device,
+    p_get_fd_info: *const MemoryGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    let real: extern "system" fn(Device, *const MemoryGetFdInfoKHR, *mut c_int) -> Result =
+        unsafe { mem::transmute(GET_MEMORY_FD_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkGetMemoryFdKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_get_fd_info, p_fd);
+    record(&TraceCall {
+        command: "vkGetMemoryFdKHR",
+        args: &[
+            ("device", &device),
+            ("pGetFdInfo", &Pointee(p_get_fd_info, true)),
+            ("pFd", &p_fd),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_FD_PROPERTIES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_fd_properties_khr(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        ExternalMemoryHandleTypeFlags,
+        c_int,
+        *mut MemoryFdPropertiesKHR,
+    ) -> Result = unsafe { mem::transmute(GET_MEMORY_FD_PROPERTIES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetMemoryFdPropertiesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, handle_type, fd, p_memory_fd_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetMemoryFdPropertiesKHR",
+        args: &[
+            ("device", &device),
+            ("handleType", &handle_type),
+            ("fd", &fd),
+            ("pMemoryFdProperties", &Pointee(p_memory_fd_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_HOST_POINTER_PROPERTIES_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_host_pointer_properties_ext(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        ExternalMemoryHandleTypeFlags,
+        *const c_void,
+        *mut MemoryHostPointerPropertiesEXT,
+    ) -> Result =
+        unsafe { mem::transmute(GET_MEMORY_HOST_POINTER_PROPERTIES_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetMemoryHostPointerPropertiesEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        handle_type,
+        p_host_pointer,
+        p_memory_host_pointer_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetMemoryHostPointerPropertiesEXT",
+        args: &[
+            ("device", &device),
+            ("handleType", &handle_type),
+            ("pHostPointer", &p_host_pointer),
+            (
+                "pMemoryHostPointerProperties",
+                &Pointee(p_memory_host_pointer_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_WIN32_HANDLE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const MemoryGetWin32HandleInfoKHR,
+        *mut HANDLE,
+    ) -> Result = unsafe { mem::transmute(GET_MEMORY_WIN32_HANDLE_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkGetMemoryWin32HandleKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_get_win32_handle_info, p_handle);
+    record(&TraceCall {
+        command: "vkGetMemoryWin32HandleKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pGetWin32HandleInfo",
+                &Pointee(p_get_win32_handle_info, true),
+            ),
+            ("pHandle", &p_handle),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_WIN32_HANDLE_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_win32_handle_nv(
+    device: Device,
+    memory: DeviceMemory,
+    handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut HANDLE,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeviceMemory,
+        ExternalMemoryHandleTypeFlagsNV,
+        *mut HANDLE,
+    ) -> Result = unsafe { mem::transmute(GET_MEMORY_WIN32_HANDLE_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetMemoryWin32HandleNV",
+        &[handle_arg("device", device), handle_arg("memory", memory)],
+    );
+    let result = real(device, memory, handle_type, p_handle);
+    record(&TraceCall {
+        command: "vkGetMemoryWin32HandleNV",
+        args: &[
+            ("device", &device),
+            ("memory", &memory),
+            ("handleType", &handle_type),
+            ("pHandle", &p_handle),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_MEMORY_WIN32_HANDLE_PROPERTIES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_memory_win32_handle_properties_khr(
+    device: Device,
+    handle_type: ExternalMemoryHandleTypeFlags,
+    handle: HANDLE,
+    p_memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        ExternalMemoryHandleTypeFlags,
+        HANDLE,
+        *mut MemoryWin32HandlePropertiesKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_MEMORY_WIN32_HANDLE_PROPERTIES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetMemoryWin32HandlePropertiesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetMemoryWin32HandlePropertiesKHR",
+        args: &[
+            ("device", &device),
+            ("handleType", &handle_type),
+            ("handle", &handle),
+            (
+                "pMemoryWin32HandleProperties",
+                &Pointee(p_memory_win32_handle_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PAST_PRESENTATION_TIMING_GOOGLE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_past_presentation_timing_google(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        SwapchainKHR,
+        *mut u32,
+        *mut PastPresentationTimingGOOGLE,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PAST_PRESENTATION_TIMING_GOOGLE.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPastPresentationTimingGOOGLE",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPastPresentationTimingGOOGLE",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            (
+                "pPresentationTimingCount",
+                &Pointee(p_presentation_timing_count, ok),
+            ),
+            ("pPresentationTimings", &Pointee(p_presentation_timings, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PERFORMANCE_PARAMETER_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_performance_parameter_intel(
+    device: Device,
+    parameter: PerformanceParameterTypeINTEL,
+    p_value: *mut PerformanceValueINTEL,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        PerformanceParameterTypeINTEL,
+        *mut PerformanceValueINTEL,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PERFORMANCE_PARAMETER_INTEL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPerformanceParameterINTEL",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, parameter, p_value);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPerformanceParameterINTEL",
+        args: &[
+            ("device", &device),
+            ("parameter", &parameter),
+            ("pValue", &Pointee(p_value, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_CALIBRATEABLE_TIME_DOMAINS_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_calibrateable_time_domains_ext(
+    physical_device: PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut TimeDomainEXT,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut TimeDomainEXT) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_CALIBRATEABLE_TIME_DOMAINS_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_time_domain_count, p_time_domains);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pTimeDomainCount", &Pointee(p_time_domain_count, ok)),
+            ("pTimeDomains", &Pointee(p_time_domains, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_cooperative_matrix_properties_nv(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut CooperativeMatrixPropertiesNV,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut CooperativeMatrixPropertiesNV,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_DIRECT_FB_PRESENTATION_SUPPORT_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_direct_fb_presentation_support_ext(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    dfb: *mut IDirectFB,
+) -> Bool32 {
+    let real: extern "system" fn(PhysicalDevice, u32, *mut IDirectFB) -> Bool32 = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_DIRECT_FB_PRESENTATION_SUPPORT_EXT.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, queue_family_index, dfb);
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("dfb", &dfb),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_display_plane_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlaneProperties2KHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut DisplayPlaneProperties2KHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_display_plane_properties_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPlanePropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut DisplayPlanePropertiesKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_DISPLAY_PLANE_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_display_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayProperties2KHR,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut DisplayProperties2KHR) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceDisplayProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceDisplayProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_display_properties_khr(
+    physical_device: PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut DisplayPropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut DisplayPropertiesKHR) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_DISPLAY_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_property_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_buffer_properties(
+    physical_device: PhysicalDevice,
+    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: *mut ExternalBufferProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalBufferInfo,
+        *mut ExternalBufferProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalBufferProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_buffer_info,
+        p_external_buffer_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalBufferProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pExternalBufferInfo",
+                &Pointee(p_external_buffer_info, true),
+            ),
+            (
+                "pExternalBufferProperties",
+                &Pointee(p_external_buffer_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_buffer_properties_khr(
+    physical_device: PhysicalDevice,
+    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    p_external_buffer_properties: *mut ExternalBufferProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalBufferInfo,
+        *mut ExternalBufferProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_EXTERNAL_BUFFER_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalBufferPropertiesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_buffer_info,
+        p_external_buffer_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalBufferPropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pExternalBufferInfo",
+                &Pointee(p_external_buffer_info, true),
+            ),
+            (
+                "pExternalBufferProperties",
+                &Pointee(p_external_buffer_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_fence_properties(
+    physical_device: PhysicalDevice,
+    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: *mut ExternalFenceProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalFenceInfo,
+        *mut ExternalFenceProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalFenceProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_fence_info,
+        p_external_fence_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalFenceProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pExternalFenceInfo", &Pointee(p_external_fence_info, true)),
+            (
+                "pExternalFenceProperties",
+                &Pointee(p_external_fence_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_fence_properties_khr(
+    physical_device: PhysicalDevice,
+    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    p_external_fence_properties: *mut ExternalFenceProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalFenceInfo,
+        *mut ExternalFenceProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_EXTERNAL_FENCE_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalFencePropertiesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_fence_info,
+        p_external_fence_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalFencePropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pExternalFenceInfo", &Pointee(p_external_fence_info, true)),
+            (
+                "pExternalFenceProperties",
+                &Pointee(p_external_fence_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_PROPERTIES_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_image_format_properties_nv(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        Format,
+        ImageType,
+        ImageTiling,
+        ImageUsageFlags,
+        ImageCreateFlags,
+        ExternalMemoryHandleTypeFlagsNV,
+        *mut ExternalImageFormatPropertiesNV,
+    ) -> Result = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_PROPERTIES_NV.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        external_handle_type,
+        p_external_image_format_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("type", &ty),
+            ("tiling", &tiling),
+            ("usage", &usage),
+            ("flags", &flags),
+            ("externalHandleType", &external_handle_type),
+            (
+                "pExternalImageFormatProperties",
+                &Pointee(p_external_image_format_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_semaphore_properties(
+    physical_device: PhysicalDevice,
+    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalSemaphoreInfo,
+        *mut ExternalSemaphoreProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_semaphore_info,
+        p_external_semaphore_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pExternalSemaphoreInfo",
+                &Pointee(p_external_semaphore_info, true),
+            ),
+            (
+                "pExternalSemaphoreProperties",
+                &Pointee(p_external_semaphore_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_external_semaphore_properties_khr(
+    physical_device: PhysicalDevice,
+    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceExternalSemaphoreInfo,
+        *mut ExternalSemaphoreProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_PROPERTIES_KHR.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_external_semaphore_info,
+        p_external_semaphore_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pExternalSemaphoreInfo",
+                &Pointee(p_external_semaphore_info, true),
+            ),
+            (
+                "pExternalSemaphoreProperties",
+                &Pointee(p_external_semaphore_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FEATURES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_features(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceFeatures) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_FEATURES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceFeatures",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_features);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFeatures",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pFeatures", &Pointee(p_features, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FEATURES2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_features2(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceFeatures2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_FEATURES2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceFeatures2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_features);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFeatures2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pFeatures", &Pointee(p_features, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FEATURES2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_features2_khr(
+    physical_device: PhysicalDevice,
+    p_features: *mut PhysicalDeviceFeatures2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceFeatures2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_FEATURES2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceFeatures2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_features);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFeatures2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pFeatures", &Pointee(p_features, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, Format, *mut FormatProperties) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceFormatProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, format, p_format_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFormatProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("pFormatProperties", &Pointee(p_format_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_format_properties2(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, Format, *mut FormatProperties2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceFormatProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, format, p_format_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFormatProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("pFormatProperties", &Pointee(p_format_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_format_properties2_khr(
+    physical_device: PhysicalDevice,
+    format: Format,
+    p_format_properties: *mut FormatProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, Format, *mut FormatProperties2) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_FORMAT_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceFormatProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, format, p_format_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFormatProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("pFormatProperties", &Pointee(p_format_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_fragment_shading_rates_khr(
+    physical_device: PhysicalDevice,
+    p_fragment_shading_rate_count: *mut u32,
+    p_fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut PhysicalDeviceFragmentShadingRateKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_fragment_shading_rate_count,
+        p_fragment_shading_rates,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pFragmentShadingRateCount",
+                &Pointee(p_fragment_shading_rate_count, ok),
+            ),
+            (
+                "pFragmentShadingRates",
+                &Pointee(p_fragment_shading_rates, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_image_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    tiling: ImageTiling,
+    usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    p_image_format_properties: *mut ImageFormatProperties,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        Format,
+        ImageType,
+        ImageTiling,
+        ImageUsageFlags,
+        ImageCreateFlags,
+        *mut ImageFormatProperties,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceImageFormatProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        p_image_format_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceImageFormatProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("type", &ty),
+            ("tiling", &tiling),
+            ("usage", &usage),
+            ("flags", &flags),
+            (
+                "pImageFormatProperties",
+                &Pointee(p_image_format_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_image_format_properties2(
+    physical_device: PhysicalDevice,
+    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceImageFormatInfo2,
+        *mut ImageFormatProperties2,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceImageFormatProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_image_format_info,
+        p_image_format_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceImageFormatProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pImageFormatInfo", &Pointee(p_image_format_info, true)),
+            (
+                "pImageFormatProperties",
+                &Pointee(p_image_format_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_image_format_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    p_image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceImageFormatInfo2,
+        *mut ImageFormatProperties2,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_IMAGE_FORMAT_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_image_format_info,
+        p_image_format_properties,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pImageFormatInfo", &Pointee(p_image_format_info, true)),
+            (
+                "pImageFormatProperties",
+                &Pointee(p_image_format_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_memory_properties(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceMemoryProperties) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceMemoryProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_memory_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceMemoryProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pMemoryProperties", &Pointee(p_memory_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_memory_properties2(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceMemoryProperties2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceMemoryProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_memory_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceMemoryProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pMemoryProperties", &Pointee(p_memory_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_memory_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceMemoryProperties2) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_MEMORY_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceMemoryProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_memory_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceMemoryProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pMemoryProperties", &Pointee(p_memory_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_MULTISAMPLE_PROPERTIES_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_multisample_properties_ext(
+    physical_device: PhysicalDevice,
+    samples: SampleCountFlags,
+    p_multisample_properties: *mut MultisamplePropertiesEXT,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        SampleCountFlags,
+        *mut MultisamplePropertiesEXT,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_MULTISAMPLE_PROPERTIES_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, samples, p_multisample_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("samples", &samples),
+            (
+                "pMultisampleProperties",
+                &Pointee(p_multisample_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_PRESENT_RECTANGLES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_present_rectangles_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut Rect2D,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, SurfaceKHR, *mut u32, *mut Rect2D) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_PRESENT_RECTANGLES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDevicePresentRectanglesKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(physical_device, surface, p_rect_count, p_rects);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDevicePresentRectanglesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("surface", &surface),
+            ("pRectCount", &Pointee(p_rect_count, ok)),
+            ("pRects", &Pointee(p_rects, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_PROPERTIES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_properties(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceProperties) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_PROPERTIES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_PROPERTIES2: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_properties2(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceProperties2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_PROPERTIES2.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_PROPERTIES2_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_properties: *mut PhysicalDeviceProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut PhysicalDeviceProperties2) -> c_void =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_PROPERTIES2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_properties);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_PASSES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_queue_family_performance_query_passes_khr(
+    physical_device: PhysicalDevice,
+    p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+    p_num_passes: *mut u32,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const QueryPoolPerformanceCreateInfoKHR,
+        *mut u32,
+    ) -> c_void = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PERFORMANCE_QUERY_PASSES_KHR.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_performance_query_create_info,
+        p_num_passes,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pPerformanceQueryCreateInfo",
+                &Pointee(p_performance_query_create_info, true),
+            ),
+            ("pNumPasses", &Pointee(p_num_passes, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_queue_family_properties(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut QueueFamilyProperties) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceQueueFamilyProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceQueueFamilyProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pQueueFamilyPropertyCount",
+                &Pointee(p_queue_family_property_count, ok),
+            ),
+            (
+                "pQueueFamilyProperties",
+                &Pointee(p_queue_family_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_queue_family_properties2(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut QueueFamilyProperties2) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceQueueFamilyProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceQueueFamilyProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pQueueFamilyPropertyCount",
+                &Pointee(p_queue_family_property_count, ok),
+            ),
+            (
+                "pQueueFamilyProperties",
+                &Pointee(p_queue_family_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_queue_family_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut QueueFamilyProperties2,
+) -> c_void {
+    let real: extern "system" fn(PhysicalDevice, *mut u32, *mut QueueFamilyProperties2) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_QUEUE_FAMILY_PROPERTIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            (
+                "pQueueFamilyPropertyCount",
+                &Pointee(p_queue_family_property_count, ok),
+            ),
+            (
+                "pQueueFamilyProperties",
+                &Pointee(p_queue_family_properties, ok),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_sparse_image_format_properties(
+    physical_device: PhysicalDevice,
+    format: Format,
+    ty: ImageType,
+    samples: SampleCountFlags,
+    usage: ImageUsageFlags,
+    tiling: ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        Format,
+        ImageType,
+        SampleCountFlags,
+        ImageUsageFlags,
+        ImageTiling,
+        *mut u32,
+        *mut SparseImageFormatProperties,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSparseImageFormatProperties",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        format,
+        ty,
+        samples,
+        usage,
+        tiling,
+        p_property_count,
+        p_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSparseImageFormatProperties",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("format", &format),
+            ("type", &ty),
+            ("samples", &samples),
+            ("usage", &usage),
+            ("tiling", &tiling),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_sparse_image_format_properties2(
+    physical_device: PhysicalDevice,
+    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties2,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceSparseImageFormatInfo2,
+        *mut u32,
+        *mut SparseImageFormatProperties2,
+    ) -> c_void = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_format_info,
+        p_property_count,
+        p_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pFormatInfo", &Pointee(p_format_info, true)),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_sparse_image_format_properties2_khr(
+    physical_device: PhysicalDevice,
+    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    p_property_count: *mut u32,
+    p_properties: *mut SparseImageFormatProperties2,
+) -> c_void {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceSparseImageFormatInfo2,
+        *mut u32,
+        *mut SparseImageFormatProperties2,
+    ) -> c_void = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_PROPERTIES2_KHR.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_format_info,
+        p_property_count,
+        p_properties,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pFormatInfo", &Pointee(p_format_info, true)),
+            ("pPropertyCount", &Pointee(p_property_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SUPPORTED_FRAMEBUFFER_MIXED_SAMPLES_COMBINATIONS_NV: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+    physical_device: PhysicalDevice,
+    p_combination_count: *mut u32,
+    p_combinations: *mut FramebufferMixedSamplesCombinationNV,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut FramebufferMixedSamplesCombinationNV,
+    ) -> Result = unsafe {
+        mem::transmute(
+            GET_PHYSICAL_DEVICE_SUPPORTED_FRAMEBUFFER_MIXED_SAMPLES_COMBINATIONS_NV
+                .load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_combination_count, p_combinations);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pCombinationCount", &Pointee(p_combination_count, ok)),
+            ("pCombinations", &Pointee(p_combinations, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_capabilities2_ext(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        SurfaceKHR,
+        *mut SurfaceCapabilities2EXT,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(physical_device, surface, p_surface_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("surface", &surface),
+            ("pSurfaceCapabilities", &Pointee(p_surface_capabilities, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_capabilities2_khr(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceSurfaceInfo2KHR,
+        *mut SurfaceCapabilities2KHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES2_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_surface_info, p_surface_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pSurfaceInfo", &Pointee(p_surface_info, true)),
+            ("pSurfaceCapabilities", &Pointee(p_surface_capabilities, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_capabilities_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        SurfaceKHR,
+        *mut SurfaceCapabilitiesKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_CAPABILITIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(physical_device, surface, p_surface_capabilities);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("surface", &surface),
+            ("pSurfaceCapabilities", &Pointee(p_surface_capabilities, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_FORMATS2_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_formats2_khr(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormat2KHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceSurfaceInfo2KHR,
+        *mut u32,
+        *mut SurfaceFormat2KHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_FORMATS2_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceFormats2KHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_surface_info,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceFormats2KHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pSurfaceInfo", &Pointee(p_surface_info, true)),
+            ("pSurfaceFormatCount", &Pointee(p_surface_format_count, ok)),
+            ("pSurfaceFormats", &Pointee(p_surface_formats, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_FORMATS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_formats_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut SurfaceFormatKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        SurfaceKHR,
+        *mut u32,
+        *mut SurfaceFormatKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_FORMATS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceFormatsKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(
+        physical_device,
+        surface,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("surface", &surface),
+            ("pSurfaceFormatCount", &Pointee(p_surface_format_count, ok)),
+            ("pSurfaceFormats", &Pointee(p_surface_formats, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES2_EXT: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_present_modes2_ext(
+    physical_device: PhysicalDevice,
+    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut PresentModeKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *const PhysicalDeviceSurfaceInfo2KHR,
+        *mut u32,
+        *mut PresentModeKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES2_EXT.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(
+        physical_device,
+        p_surface_info,
+        p_present_mode_count,
+        p_present_modes,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pSurfaceInfo", &Pointee(p_surface_info, true)),
+            ("pPresentModeCount", &Pointee(p_present_mode_count, ok)),
+            ("pPresentModes", &Pointee(p_present_modes, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_present_modes_khr(
+    physical_device: PhysicalDevice,
+    surface: SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut PresentModeKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        SurfaceKHR,
+        *mut u32,
+        *mut PresentModeKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_PRESENT_MODES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(
+        physical_device,
+        surface,
+        p_present_mode_count,
+        p_present_modes,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("surface", &surface),
+            ("pPresentModeCount", &Pointee(p_present_mode_count, ok)),
+            ("pPresentModes", &Pointee(p_present_modes, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_SURFACE_SUPPORT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_surface_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    surface: SurfaceKHR,
+    p_supported: *mut Bool32,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, u32, SurfaceKHR, *mut Bool32) -> Result =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_SURFACE_SUPPORT_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceSurfaceSupportKHR",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("surface", surface),
+        ],
+    );
+    let result = real(physical_device, queue_family_index, surface, p_supported);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceSurfaceSupportKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("surface", &surface),
+            ("pSupported", &Pointee(p_supported, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_tool_properties_ext(
+    physical_device: PhysicalDevice,
+    p_tool_count: *mut u32,
+    p_tool_properties: *mut PhysicalDeviceToolPropertiesEXT,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut u32,
+        *mut PhysicalDeviceToolPropertiesEXT,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPhysicalDeviceToolPropertiesEXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, p_tool_count, p_tool_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceToolPropertiesEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("pToolCount", &Pointee(p_tool_count, ok)),
+            ("pToolProperties", &Pointee(p_tool_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_WAYLAND_PRESENTATION_SUPPORT_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_wayland_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut wl_display,
+) -> Bool32 {
+    let real: extern "system" fn(PhysicalDevice, u32, *mut wl_display) -> Bool32 = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_WAYLAND_PRESENTATION_SUPPORT_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, queue_family_index, display);
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("display", &display),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_WIN32_PRESENTATION_SUPPORT_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_win32_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+) -> Bool32 {
+    let real: extern "system" fn(PhysicalDevice, u32) -> Bool32 = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_WIN32_PRESENTATION_SUPPORT_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, queue_family_index);
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_XCB_PRESENTATION_SUPPORT_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_xcb_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut xcb_connection_t,
+    visual_id: xcb_visualid_t,
+) -> Bool32 {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        u32,
+        *mut xcb_connection_t,
+        xcb_visualid_t,
+    ) -> Bool32 = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_XCB_PRESENTATION_SUPPORT_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, queue_family_index, connection, visual_id);
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("connection", &connection),
+            ("visualId", &visual_id),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PHYSICAL_DEVICE_XLIB_PRESENTATION_SUPPORT_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_physical_device_xlib_presentation_support_khr(
+    physical_device: PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut Display,
+    visual_id: VisualID,
+) -> Bool32 {
+    let real: extern "system" fn(PhysicalDevice, u32, *mut Display, VisualID) -> Bool32 = unsafe {
+        mem::transmute(GET_PHYSICAL_DEVICE_XLIB_PRESENTATION_SUPPORT_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, queue_family_index, dpy, visual_id);
+    record(&TraceCall {
+        command: "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("queueFamilyIndex", &queue_family_index),
+            ("dpy", &dpy),
+            ("visualId", &visual_id),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PIPELINE_CACHE_DATA: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_pipeline_cache_data(
+    device: Device,
+    pipeline_cache: PipelineCache,
+    p_data_size: *mut size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, PipelineCache, *mut size_t, *mut c_void) -> Result =
+        unsafe { mem::transmute(GET_PIPELINE_CACHE_DATA.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPipelineCacheData",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipelineCache", pipeline_cache),
+        ],
+    );
+    let result = real(device, pipeline_cache, p_data_size, p_data);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPipelineCacheData",
+        args: &[
+            ("device", &device),
+            ("pipelineCache", &pipeline_cache),
+            ("pDataSize", &Pointee(p_data_size, ok)),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATIONS_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_pipeline_executable_internal_representations_khr(
+    device: Device,
+    p_executable_info: *const PipelineExecutableInfoKHR,
+    p_internal_representation_count: *mut u32,
+    p_internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PipelineExecutableInfoKHR,
+        *mut u32,
+        *mut PipelineExecutableInternalRepresentationKHR,
+    ) -> Result = unsafe {
+        mem::transmute(GET_PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATIONS_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        p_executable_info,
+        p_internal_representation_count,
+        p_internal_representations,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPipelineExecutableInternalRepresentationsKHR",
+        args: &[
+            ("device", &device),
+            ("pExecutableInfo", &Pointee(p_executable_info, true)),
+            (
+                "pInternalRepresentationCount",
+                &Pointee(p_internal_representation_count, ok),
+            ),
+            (
+                "pInternalRepresentations",
+                &Pointee(p_internal_representations, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PIPELINE_EXECUTABLE_PROPERTIES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_pipeline_executable_properties_khr(
+    device: Device,
+    p_pipeline_info: *const PipelineInfoKHR,
+    p_executable_count: *mut u32,
+    p_properties: *mut PipelineExecutablePropertiesKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PipelineInfoKHR,
+        *mut u32,
+        *mut PipelineExecutablePropertiesKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PIPELINE_EXECUTABLE_PROPERTIES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPipelineExecutablePropertiesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_pipeline_info, p_executable_count, p_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPipelineExecutablePropertiesKHR",
+        args: &[
+            ("device", &device),
+            ("pPipelineInfo", &Pointee(p_pipeline_info, true)),
+            ("pExecutableCount", &Pointee(p_executable_count, ok)),
+            ("pProperties", &Pointee(p_properties, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PIPELINE_EXECUTABLE_STATISTICS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_pipeline_executable_statistics_khr(
+    device: Device,
+    p_executable_info: *const PipelineExecutableInfoKHR,
+    p_statistic_count: *mut u32,
+    p_statistics: *mut PipelineExecutableStatisticKHR,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const PipelineExecutableInfoKHR,
+        *mut u32,
+        *mut PipelineExecutableStatisticKHR,
+    ) -> Result =
+        unsafe { mem::transmute(GET_PIPELINE_EXECUTABLE_STATISTICS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPipelineExecutableStatisticsKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_executable_info, p_statistic_count, p_statistics);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetPipelineExecutableStatisticsKHR",
+        args: &[
+            ("device", &device),
+            ("pExecutableInfo", &Pointee(p_executable_info, true)),
+            ("pStatisticCount", &Pointee(p_statistic_count, ok)),
+            ("pStatistics", &Pointee(p_statistics, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_PRIVATE_DATA_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_private_data_ext(
+    device: Device,
+    object_type: ObjectType,
+    object_handle: u64,
+    private_data_slot: PrivateDataSlotEXT,
+    p_data: *mut u64,
+) -> c_void {
+    let real: extern "system" fn(Device, ObjectType, u64, PrivateDataSlotEXT, *mut u64) -> c_void =
+        unsafe { mem::transmute(GET_PRIVATE_DATA_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetPrivateDataEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("privateDataSlot", private_data_slot),
+        ],
+    );
+    let result = real(
+        device,
+        object_type,
+        object_handle,
+        private_data_slot,
+        p_data,
+    );
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetPrivateDataEXT",
+        args: &[
+            ("device", &device),
+            ("objectType", &object_type),
+            ("objectHandle", &object_handle),
+            ("privateDataSlot", &private_data_slot),
+            ("pData", &Pointee(p_data, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_QUERY_POOL_RESULTS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_query_pool_results(
+    device: Device,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: size_t,
+    p_data: *mut c_void,
+    stride: DeviceSize,
+    flags: QueryResultFlags,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        QueryPool,
+        u32,
+        u32,
+        size_t,
+        *mut c_void,
+        DeviceSize,
+        QueryResultFlags,
+    ) -> Result = unsafe { mem::transmute(GET_QUERY_POOL_RESULTS.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetQueryPoolResults",
+        &[
+            handle_arg("device", device),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    );
+    record(&TraceCall {
+        command: "vkGetQueryPoolResults",
+        args: &[
+            ("device", &device),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+            ("queryCount", &query_count),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+            ("stride", &stride),
+            ("flags", &flags),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_QUEUE_CHECKPOINT_DATA_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_queue_checkpoint_data_nv(
+    queue: Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut CheckpointDataNV,
+) -> c_void {
+    let real: extern "system" fn(Queue, *mut u32, *mut CheckpointDataNV) -> c_void =
+        unsafe { mem::transmute(GET_QUEUE_CHECKPOINT_DATA_NV.load(Ordering::Relaxed)) };
+    check_handles("vkGetQueueCheckpointDataNV", &[handle_arg("queue", queue)]);
+    let result = real(queue, p_checkpoint_data_count, p_checkpoint_data);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetQueueCheckpointDataNV",
+        args: &[
+            ("queue", &queue),
+            (
+                "pCheckpointDataCount",
+                &Pointee(p_checkpoint_data_count, ok),
+            ),
+            ("pCheckpointData", &Pointee(p_checkpoint_data, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_RAND_R_OUTPUT_DISPLAY_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_rand_r_output_display_ext(
+    physical_device: PhysicalDevice,
+    dpy: *mut Display,
+    rr_output: RROutput,
+    p_display: *mut DisplayKHR,
+) -> Result {
+    let real: extern "system" fn(
+        PhysicalDevice,
+        *mut Display,
+        RROutput,
+        *mut DisplayKHR,
+    ) -> Result = unsafe { mem::transmute(GET_RAND_R_OUTPUT_DISPLAY_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetRandROutputDisplayEXT",
+        &[handle_arg("physicalDevice", physical_device)],
+    );
+    let result = real(physical_device, dpy, rr_output, p_display);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkGetRandROutputDisplayEXT",
+                false,
+                Some(handle_arg("physicalDevice", physical_device)),
+                p_display,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkGetRandROutputDisplayEXT",
+        args: &[
+            ("physicalDevice", &physical_device),
+            ("dpy", &dpy),
+            ("rrOutput", &rr_output),
+            ("pDisplay", &Pointee(p_display, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_RAY_TRACING_CAPTURE_REPLAY_SHADER_GROUP_HANDLES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_ray_tracing_capture_replay_shader_group_handles_khr(
+    device: Device,
+    pipeline: Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, Pipeline, u32, u32, size_t, *mut c_void) -> Result = unsafe {
+        mem::transmute(
+            GET_RAY_TRACING_CAPTURE_REPLAY_SHADER_GROUP_HANDLES_KHR.load(Ordering::Relaxed),
+        )
+    };
+    check_handles(
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    record(&TraceCall {
+        command: "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("firstGroup", &first_group),
+            ("groupCount", &group_count),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_RAY_TRACING_SHADER_GROUP_HANDLES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_ray_tracing_shader_group_handles_khr(
+    device: Device,
+    pipeline: Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, Pipeline, u32, u32, size_t, *mut c_void) -> Result =
+        unsafe { mem::transmute(GET_RAY_TRACING_SHADER_GROUP_HANDLES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetRayTracingShaderGroupHandlesKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    record(&TraceCall {
+        command: "vkGetRayTracingShaderGroupHandlesKHR",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("firstGroup", &first_group),
+            ("groupCount", &group_count),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_RAY_TRACING_SHADER_GROUP_HANDLES_NV: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_ray_tracing_shader_group_handles_nv(
+    device: Device,
+    pipeline: Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, Pipeline, u32, u32, size_t, *mut c_void) -> Result =
+        unsafe { mem::transmute(GET_RAY_TRACING_SHADER_GROUP_HANDLES_NV.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetRayTracingShaderGroupHandlesNV",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    record(&TraceCall {
+        command: "vkGetRayTracingShaderGroupHandlesNV",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("firstGroup", &first_group),
+            ("groupCount", &group_count),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_RAY_TRACING_SHADER_GROUP_STACK_SIZE_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_ray_tracing_shader_group_stack_size_khr(
+    device: Device,
+    pipeline: Pipeline,
+    group: u32,
+    group_shader: ShaderGroupShaderKHR,
+) -> DeviceSize {
+    let real: extern "system" fn(Device, Pipeline, u32, ShaderGroupShaderKHR) -> DeviceSize = unsafe {
+        mem::transmute(GET_RAY_TRACING_SHADER_GROUP_STACK_SIZE_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkGetRayTracingShaderGroupStackSizeKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(device, pipeline, group, group_shader);
+    record(&TraceCall {
+        command: "vkGetRayTracingShaderGroupStackSizeKHR",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("group", &group),
+            ("groupShader", &group_shader),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_REFRESH_CYCLE_DURATION_GOOGLE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_refresh_cycle_duration_google(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR, *mut RefreshCycleDurationGOOGLE) -> Result =
+        unsafe { mem::transmute(GET_REFRESH_CYCLE_DURATION_GOOGLE.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetRefreshCycleDurationGOOGLE",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain, p_display_timing_properties);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetRefreshCycleDurationGOOGLE",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            (
+                "pDisplayTimingProperties",
+                &Pointee(p_display_timing_properties, ok),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_RENDER_AREA_GRANULARITY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_render_area_granularity(
+    device: Device,
+    render_pass: RenderPass,
+    p_granularity: *mut Extent2D,
+) -> c_void {
+    let real: extern "system" fn(Device, RenderPass, *mut Extent2D) -> c_void =
+        unsafe { mem::transmute(GET_RENDER_AREA_GRANULARITY.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetRenderAreaGranularity",
+        &[
+            handle_arg("device", device),
+            handle_arg("renderPass", render_pass),
+        ],
+    );
+    let result = real(device, render_pass, p_granularity);
+    let ok = true;
+    record(&TraceCall {
+        command: "vkGetRenderAreaGranularity",
+        args: &[
+            ("device", &device),
+            ("renderPass", &render_pass),
+            ("pGranularity", &Pointee(p_granularity, ok)),
+        ],
+        result: None,
+    });
+    result
+}
+static GET_SEMAPHORE_COUNTER_VALUE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_semaphore_counter_value(
+    device: Device,
+    semaphore: Semaphore,
+    p_value: *mut u64,
+) -> Result {
+    let real: extern "system" fn(Device, Semaphore, *mut u64) -> Result =
+        unsafe { mem::transmute(GET_SEMAPHORE_COUNTER_VALUE.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSemaphoreCounterValue",
+        &[
+            handle_arg("device", device),
+            handle_arg("semaphore", semaphore),
+        ],
+    );
+    let result = real(device, semaphore, p_value);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetSemaphoreCounterValue",
+        args: &[
+            ("device", &device),
+            ("semaphore", &semaphore),
+            ("pValue", &Pointee(p_value, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SEMAPHORE_COUNTER_VALUE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_semaphore_counter_value_khr(
+    device: Device,
+    semaphore: Semaphore,
+    p_value: *mut u64,
+) -> Result {
+    let real: extern "system" fn(Device, Semaphore, *mut u64) -> Result =
+        unsafe { mem::transmute(GET_SEMAPHORE_COUNTER_VALUE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSemaphoreCounterValueKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("semaphore", semaphore),
+        ],
+    );
+    let result = real(device, semaphore, p_value);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetSemaphoreCounterValueKHR",
+        args: &[
+            ("device", &device),
+            ("semaphore", &semaphore),
+            ("pValue", &Pointee(p_value, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SEMAPHORE_FD_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_semaphore_fd_khr(
+    device: Device,
+    p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+    p_fd: *mut c_int,
+) -> Result {
+    let real: extern "system" fn(Device, *const SemaphoreGetFdInfoKHR, *mut c_int) -> Result =
+        unsafe { mem::transmute(GET_SEMAPHORE_FD_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkGetSemaphoreFdKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_get_fd_info, p_fd);
+    record(&TraceCall {
+        command: "vkGetSemaphoreFdKHR",
+        args: &[
+            ("device", &device),
+            ("pGetFdInfo", &Pointee(p_get_fd_info, true)),
+            ("pFd", &p_fd),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SEMAPHORE_WIN32_HANDLE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_semaphore_win32_handle_khr(
+    device: Device,
+    p_get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+    p_handle: *mut HANDLE,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const SemaphoreGetWin32HandleInfoKHR,
+        *mut HANDLE,
+    ) -> Result = unsafe { mem::transmute(GET_SEMAPHORE_WIN32_HANDLE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSemaphoreWin32HandleKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_get_win32_handle_info, p_handle);
+    record(&TraceCall {
+        command: "vkGetSemaphoreWin32HandleKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pGetWin32HandleInfo",
+                &Pointee(p_get_win32_handle_info, true),
+            ),
+            ("pHandle", &p_handle),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SHADER_INFO_AMD: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_shader_info_amd(
+    device: Device,
+    pipeline: Pipeline,
+    shader_stage: ShaderStageFlags,
+    info_type: ShaderInfoTypeAMD,
+    p_info_size: *mut size_t,
+    p_info: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        Pipeline,
+        ShaderStageFlags,
+        ShaderInfoTypeAMD,
+        *mut size_t,
+        *mut c_void,
+    ) -> Result = unsafe { mem::transmute(GET_SHADER_INFO_AMD.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetShaderInfoAMD",
+        &[
+            handle_arg("device", device),
+            handle_arg("pipeline", pipeline),
+        ],
+    );
+    let result = real(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetShaderInfoAMD",
+        args: &[
+            ("device", &device),
+            ("pipeline", &pipeline),
+            ("shaderStage", &shader_stage),
+            ("infoType", &info_type),
+            ("pInfoSize", &Pointee(p_info_size, ok)),
+            ("pInfo", &p_info),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SWAPCHAIN_COUNTER_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_swapchain_counter_ext(
+    device: Device,
+    swapchain: SwapchainKHR,
+    counter: SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR, SurfaceCounterFlagsEXT, *mut u64) -> Result =
+        unsafe { mem::transmute(GET_SWAPCHAIN_COUNTER_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSwapchainCounterEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain, counter, p_counter_value);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetSwapchainCounterEXT",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            ("counter", &counter),
+            ("pCounterValue", &Pointee(p_counter_value, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SWAPCHAIN_GRALLOC_USAGE2_ANDROID: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_swapchain_gralloc_usage2_android(
+    device: Device,
+    format: Format,
+    image_usage: ImageUsageFlags,
+    swapchain_image_usage: SwapchainImageUsageFlagsANDROID,
+    gralloc_consumer_usage: *mut u64,
+    gralloc_producer_usage: *mut u64,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        Format,
+        ImageUsageFlags,
+        SwapchainImageUsageFlagsANDROID,
+        *mut u64,
+        *mut u64,
+    ) -> Result =
+        unsafe { mem::transmute(GET_SWAPCHAIN_GRALLOC_USAGE2_ANDROID.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSwapchainGrallocUsage2ANDROID",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        format,
+        image_usage,
+        swapchain_image_usage,
+        gralloc_consumer_usage,
+        gralloc_producer_usage,
+    );
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetSwapchainGrallocUsage2ANDROID",
+        args: &[
+            ("device", &device),
+            ("format", &format),
+            ("imageUsage", &image_usage),
+            ("swapchainImageUsage", &swapchain_image_usage),
+            ("grallocConsumerUsage", &Pointee(gralloc_consumer_usage, ok)),
+            ("grallocProducerUsage", &Pointee(gralloc_producer_usage, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SWAPCHAIN_GRALLOC_USAGE_ANDROID: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_swapchain_gralloc_usage_android(
+    device: Device,
+    format: Format,
+    image_usage: ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> Result {
+    let real: extern "system" fn(Device, Format, ImageUsageFlags, *mut c_int) -> Result =
+        unsafe { mem::transmute(GET_SWAPCHAIN_GRALLOC_USAGE_ANDROID.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSwapchainGrallocUsageANDROID",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, format, image_usage, gralloc_usage);
+    record(&TraceCall {
+        command: "vkGetSwapchainGrallocUsageANDROID",
+        args: &[
+            ("device", &device),
+            ("format", &format),
+            ("imageUsage", &image_usage),
+            ("grallocUsage", &gralloc_usage),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SWAPCHAIN_IMAGES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_swapchain_images_khr(
+    device: Device,
+    swapchain: SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut Image,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR, *mut u32, *mut Image) -> Result =
+        unsafe { mem::transmute(GET_SWAPCHAIN_IMAGES_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSwapchainImagesKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkGetSwapchainImagesKHR",
+                false,
+                Some(handle_arg("device", device)),
+                p_swapchain_images,
+                *p_swapchain_image_count as usize,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkGetSwapchainImagesKHR",
+        args: &[
+            ("device", &device),
+            ("swapchain", &swapchain),
+            (
+                "pSwapchainImageCount",
+                &Pointee(p_swapchain_image_count, ok),
+            ),
+            ("pSwapchainImages", &p_swapchain_images),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static GET_SWAPCHAIN_STATUS_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_swapchain_status_khr(device: Device, swapchain: SwapchainKHR) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR) -> Result =
+        unsafe { mem::transmute(GET_SWAPCHAIN_STATUS_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetSwapchainStatusKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain);
+    record(&TraceCall {
+        command: "vkGetSwapchainStatusKHR",
+        args: &[("device", &device), ("swapchain", &swapchain)],
+        result: Some(&result),
+    });
+    result
+}
+static GET_VALIDATION_CACHE_DATA_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn get_validation_cache_data_ext(
+    device: Device,
+    validation_cache: ValidationCacheEXT,
+    p_data_size: *mut size_t,
+    p_data: *mut c_void,
+) -> Result {
+    let real: extern "system" fn(Device, ValidationCacheEXT, *mut size_t, *mut c_void) -> Result =
+        unsafe { mem::transmute(GET_VALIDATION_CACHE_DATA_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkGetValidationCacheDataEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("validationCache", validation_cache),
+        ],
+    );
+    let result = real(device, validation_cache, p_data_size, p_data);
+    let ok = result.as_raw() >= 0;
+    record(&TraceCall {
+        command: "vkGetValidationCacheDataEXT",
+        args: &[
+            ("device", &device),
+            ("validationCache", &validation_cache),
+            ("pDataSize", &Pointee(p_data_size, ok)),
+            ("pData", &p_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static IMPORT_FENCE_FD_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn import_fence_fd_khr(
+    device: Device,
+    p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *const ImportFenceFdInfoKHR) -> Result =
+        unsafe { mem::transmute(IMPORT_FENCE_FD_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkImportFenceFdKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_import_fence_fd_info);
+    record(&TraceCall {
+        command: "vkImportFenceFdKHR",
+        args: &[
+            ("device", &device),
+            ("pImportFenceFdInfo", &Pointee(p_import_fence_fd_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static IMPORT_FENCE_WIN32_HANDLE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn import_fence_win32_handle_khr(
+    device: Device,
+    p_import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *const ImportFenceWin32HandleInfoKHR) -> Result =
+        unsafe { mem::transmute(IMPORT_FENCE_WIN32_HANDLE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkImportFenceWin32HandleKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_import_fence_win32_handle_info);
+    record(&TraceCall {
+        command: "vkImportFenceWin32HandleKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pImportFenceWin32HandleInfo",
+                &Pointee(p_import_fence_win32_handle_info, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static IMPORT_SEMAPHORE_FD_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn import_semaphore_fd_khr(
+    device: Device,
+    p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *const ImportSemaphoreFdInfoKHR) -> Result =
+        unsafe { mem::transmute(IMPORT_SEMAPHORE_FD_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkImportSemaphoreFdKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_import_semaphore_fd_info);
+    record(&TraceCall {
+        command: "vkImportSemaphoreFdKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pImportSemaphoreFdInfo",
+                &Pointee(p_import_semaphore_fd_info, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static IMPORT_SEMAPHORE_WIN32_HANDLE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn import_semaphore_win32_handle_khr(
+    device: Device,
+    p_import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Device, *const ImportSemaphoreWin32HandleInfoKHR) -> Result =
+        unsafe { mem::transmute(IMPORT_SEMAPHORE_WIN32_HANDLE_KHR.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkImportSemaphoreWin32HandleKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_import_semaphore_win32_handle_info);
+    record(&TraceCall {
+        command: "vkImportSemaphoreWin32HandleKHR",
+        args: &[
+            ("device", &device),
+            (
+                "pImportSemaphoreWin32HandleInfo",
+                &Pointee(p_import_semaphore_win32_handle_info, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static INITIALIZE_PERFORMANCE_API_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn initialize_performance_api_intel(
+    device: Device,
+    p_initialize_info: *const InitializePerformanceApiInfoINTEL,
+) -> Result {
+    let real: extern "system" fn(Device, *const InitializePerformanceApiInfoINTEL) -> Result =
+        unsafe { mem::transmute(INITIALIZE_PERFORMANCE_API_INTEL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkInitializePerformanceApiINTEL",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_initialize_info);
+    record(&TraceCall {
+        command: "vkInitializePerformanceApiINTEL",
+        args: &[
+            ("device", &device),
+            ("pInitializeInfo", &Pointee(p_initialize_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static INVALIDATE_MAPPED_MEMORY_RANGES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn invalidate_mapped_memory_ranges(
+    device: Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const MappedMemoryRange) -> Result =
+        unsafe { mem::transmute(INVALIDATE_MAPPED_MEMORY_RANGES.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkInvalidateMappedMemoryRanges",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, memory_range_count, p_memory_ranges);
+    record(&TraceCall {
+        command: "vkInvalidateMappedMemoryRanges",
+        args: &[
+            ("device", &device),
+            ("memoryRangeCount", &memory_range_count),
+            (
+                "pMemoryRanges",
+                &Pointees(p_memory_ranges, memory_range_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static MAP_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn map_memory(
+    device: Device,
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    size: DeviceSize,
+    flags: MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DeviceMemory,
+        DeviceSize,
+        DeviceSize,
+        MemoryMapFlags,
+        *mut *mut c_void,
+    ) -> Result = unsafe { mem::transmute(MAP_MEMORY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkMapMemory", &[handle_arg("memory", memory)]);
+    check_handles(
+        "vkMapMemory",
+        &[handle_arg("device", device), handle_arg("memory", memory)],
+    );
+    let result = real(device, memory, offset, size, flags, pp_data);
+    record(&TraceCall {
+        command: "vkMapMemory",
+        args: &[
+            ("device", &device),
+            ("memory", &memory),
+            ("offset", &offset),
+            ("size", &size),
+            ("flags", &flags),
+            ("ppData", &pp_data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static MERGE_PIPELINE_CACHES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn merge_pipeline_caches(
+    device: Device,
+    dst_cache: PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const PipelineCache,
+) -> Result {
+    let real: extern "system" fn(Device, PipelineCache, u32, *const PipelineCache) -> Result =
+        unsafe { mem::transmute(MERGE_PIPELINE_CACHES.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkMergePipelineCaches",
+        &[handle_arg("dstCache", dst_cache)],
+    );
+    check_handles(
+        "vkMergePipelineCaches",
+        &[
+            handle_arg("device", device),
+            handle_arg("dstCache", dst_cache),
+        ],
+    );
+    let result = real(device, dst_cache, src_cache_count, p_src_caches);
+    record(&TraceCall {
+        command: "vkMergePipelineCaches",
+        args: &[
+            ("device", &device),
+            ("dstCache", &dst_cache),
+            ("srcCacheCount", &src_cache_count),
+            (
+                "pSrcCaches",
+                &Pointees(p_src_caches, src_cache_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static MERGE_VALIDATION_CACHES_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn merge_validation_caches_ext(
+    device: Device,
+    dst_cache: ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const ValidationCacheEXT,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        ValidationCacheEXT,
+        u32,
+        *const ValidationCacheEXT,
+    ) -> Result = unsafe { mem::transmute(MERGE_VALIDATION_CACHES_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkMergeValidationCachesEXT",
+        &[handle_arg("dstCache", dst_cache)],
+    );
+    check_handles(
+        "vkMergeValidationCachesEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("dstCache", dst_cache),
+        ],
+    );
+    let result = real(device, dst_cache, src_cache_count, p_src_caches);
+    record(&TraceCall {
+        command: "vkMergeValidationCachesEXT",
+        args: &[
+            ("device", &device),
+            ("dstCache", &dst_cache),
+            ("srcCacheCount", &src_cache_count),
+            (
+                "pSrcCaches",
+                &Pointees(p_src_caches, src_cache_count as usize, true),
+            ),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_BEGIN_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    let real: extern "system" fn(Queue, *const DebugUtilsLabelEXT) -> c_void =
+        unsafe { mem::transmute(QUEUE_BEGIN_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkQueueBeginDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    check_handles(
+        "vkQueueBeginDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    let result = real(queue, p_label_info);
+    record(&TraceCall {
+        command: "vkQueueBeginDebugUtilsLabelEXT",
+        args: &[
+            ("queue", &queue),
+            ("pLabelInfo", &Pointee(p_label_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static QUEUE_BIND_SPARSE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_bind_sparse(
+    queue: Queue,
+    bind_info_count: u32,
+    p_bind_info: *const BindSparseInfo,
+    fence: Fence,
+) -> Result {
+    let real: extern "system" fn(Queue, u32, *const BindSparseInfo, Fence) -> Result =
+        unsafe { mem::transmute(QUEUE_BIND_SPARSE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkQueueBindSparse",
+        &[handle_arg("queue", queue), handle_arg("fence", fence)],
+    );
+    check_handles(
+        "vkQueueBindSparse",
+        &[handle_arg("queue", queue), handle_arg("fence", fence)],
+    );
+    let result = real(queue, bind_info_count, p_bind_info, fence);
+    record(&TraceCall {
+        command: "vkQueueBindSparse",
+        args: &[
+            ("queue", &queue),
+            ("bindInfoCount", &bind_info_count),
+            (
+                "pBindInfo",
+                &Pointees(p_bind_info, bind_info_count as usize, true),
+            ),
+            ("fence", &fence),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_END_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_end_debug_utils_label_ext(queue: Queue) -> c_void {
+    let real: extern "system" fn(Queue) -> c_void =
+        unsafe { mem::transmute(QUEUE_END_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkQueueEndDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    check_handles(
+        "vkQueueEndDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    let result = real(queue);
+    record(&TraceCall {
+        command: "vkQueueEndDebugUtilsLabelEXT",
+        args: &[("queue", &queue)],
+        result: None,
+    });
+    result
+}
+static QUEUE_INSERT_DEBUG_UTILS_LABEL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: Queue,
+    p_label_info: *const DebugUtilsLabelEXT,
+) -> c_void {
+    let real: extern "system" fn(Queue, *const DebugUtilsLabelEXT) -> c_void =
+        unsafe { mem::transmute(QUEUE_INSERT_DEBUG_UTILS_LABEL_EXT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkQueueInsertDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    check_handles(
+        "vkQueueInsertDebugUtilsLabelEXT",
+        &[handle_arg("queue", queue)],
+    );
+    let result = real(queue, p_label_info);
+    record(&TraceCall {
+        command: "vkQueueInsertDebugUtilsLabelEXT",
+        args: &[
+            ("queue", &queue),
+            ("pLabelInfo", &Pointee(p_label_info, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static QUEUE_PRESENT_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_present_khr(
+    queue: Queue,
+    p_present_info: *const PresentInfoKHR,
+) -> Result {
+    let real: extern "system" fn(Queue, *const PresentInfoKHR) -> Result =
+        unsafe { mem::transmute(QUEUE_PRESENT_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkQueuePresentKHR", &[handle_arg("queue", queue)]);
+    check_handles("vkQueuePresentKHR", &[handle_arg("queue", queue)]);
+    let result = real(queue, p_present_info);
+    record(&TraceCall {
+        command: "vkQueuePresentKHR",
+        args: &[
+            ("queue", &queue),
+            ("pPresentInfo", &Pointee(p_present_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_SET_PERFORMANCE_CONFIGURATION_INTEL: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_set_performance_configuration_intel(
+    queue: Queue,
+    configuration: PerformanceConfigurationINTEL,
+) -> Result {
+    let real: extern "system" fn(Queue, PerformanceConfigurationINTEL) -> Result = unsafe {
+        mem::transmute(QUEUE_SET_PERFORMANCE_CONFIGURATION_INTEL.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkQueueSetPerformanceConfigurationINTEL",
+        &[
+            handle_arg("queue", queue),
+            handle_arg("configuration", configuration),
+        ],
+    );
+    let result = real(queue, configuration);
+    record(&TraceCall {
+        command: "vkQueueSetPerformanceConfigurationINTEL",
+        args: &[("queue", &queue), ("configuration", &configuration)],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_SIGNAL_RELEASE_IMAGE_ANDROID: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_signal_release_image_android(
+    queue: Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const Semaphore,
+    image: Image,
+    p_native_fence_fd: *mut c_int,
+) -> Result {
+    let real: extern "system" fn(Queue, u32, *const Semaphore, Image, *mut c_int) -> Result =
+        unsafe { mem::transmute(QUEUE_SIGNAL_RELEASE_IMAGE_ANDROID.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkQueueSignalReleaseImageANDROID",
+        &[handle_arg("queue", queue), handle_arg("image", image)],
+    );
+    let result = real(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    );
+    record(&TraceCall {
+        command: "vkQueueSignalReleaseImageANDROID",
+        args: &[
+            ("queue", &queue),
+            ("waitSemaphoreCount", &wait_semaphore_count),
+            (
+                "pWaitSemaphores",
+                &Pointees(p_wait_semaphores, wait_semaphore_count as usize, true),
+            ),
+            ("image", &image),
+            ("pNativeFenceFd", &p_native_fence_fd),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_SUBMIT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_submit(
+    queue: Queue,
+    submit_count: u32,
+    p_submits: *const SubmitInfo,
+    fence: Fence,
+) -> Result {
+    let real: extern "system" fn(Queue, u32, *const SubmitInfo, Fence) -> Result =
+        unsafe { mem::transmute(QUEUE_SUBMIT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkQueueSubmit",
+        &[handle_arg("queue", queue), handle_arg("fence", fence)],
+    );
+    check_handles(
+        "vkQueueSubmit",
+        &[handle_arg("queue", queue), handle_arg("fence", fence)],
+    );
+    let result = real(queue, submit_count, p_submits, fence);
+    record(&TraceCall {
+        command: "vkQueueSubmit",
+        args: &[
+            ("queue", &queue),
+            ("submitCount", &submit_count),
+            (
+                "pSubmits",
+                &Pointees(p_submits, submit_count as usize, true),
+            ),
+            ("fence", &fence),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static QUEUE_WAIT_IDLE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn queue_wait_idle(queue: Queue) -> Result {
+    let real: extern "system" fn(Queue) -> Result =
+        unsafe { mem::transmute(QUEUE_WAIT_IDLE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkQueueWaitIdle", &[handle_arg("queue", queue)]);
+    check_handles("vkQueueWaitIdle", &[handle_arg("queue", queue)]);
+    let result = real(queue);
+    record(&TraceCall {
+        command: "vkQueueWaitIdle",
+        args: &[("queue", &queue)],
+        result: Some(&result),
+    });
+    result
+}
+static REGISTER_DEVICE_EVENT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn register_device_event_ext(
+    device: Device,
+    p_device_event_info: *const DeviceEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        *const DeviceEventInfoEXT,
+        *const AllocationCallbacks,
+        *mut Fence,
+    ) -> Result = unsafe { mem::transmute(REGISTER_DEVICE_EVENT_EXT.load(Ordering::Relaxed)) };
+    check_handles("vkRegisterDeviceEventEXT", &[handle_arg("device", device)]);
+    let result = real(device, p_device_event_info, p_allocator, p_fence);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkRegisterDeviceEventEXT",
+                false,
+                Some(handle_arg("device", device)),
+                p_fence,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkRegisterDeviceEventEXT",
+        args: &[
+            ("device", &device),
+            ("pDeviceEventInfo", &Pointee(p_device_event_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pFence", &Pointee(p_fence, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static REGISTER_DISPLAY_EVENT_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn register_display_event_ext(
+    device: Device,
+    display: DisplayKHR,
+    p_display_event_info: *const DisplayEventInfoEXT,
+    p_allocator: *const AllocationCallbacks,
+    p_fence: *mut Fence,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        DisplayKHR,
+        *const DisplayEventInfoEXT,
+        *const AllocationCallbacks,
+        *mut Fence,
+    ) -> Result = unsafe { mem::transmute(REGISTER_DISPLAY_EVENT_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkRegisterDisplayEventEXT",
+        &[handle_arg("device", device), handle_arg("display", display)],
+    );
+    let result = real(device, display, p_display_event_info, p_allocator, p_fence);
+    let ok = result.as_raw() >= 0;
+    if ok {
+        unsafe {
+            track_created(
+                "vkRegisterDisplayEventEXT",
+                false,
+                Some(handle_arg("device", device)),
+                p_fence,
+                1,
+            );
+        }
+    }
+    record(&TraceCall {
+        command: "vkRegisterDisplayEventEXT",
+        args: &[
+            ("device", &device),
+            ("display", &display),
+            ("pDisplayEventInfo", &Pointee(p_display_event_info, true)),
+            ("pAllocator", &Pointee(p_allocator, true)),
+            ("pFence", &Pointee(p_fence, ok)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static RELEASE_DISPLAY_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn release_display_ext(
+    physical_device: PhysicalDevice,
+    display: DisplayKHR,
+) -> Result {
+    let real: extern "system" fn(PhysicalDevice, DisplayKHR) -> Result =
+        unsafe { mem::transmute(RELEASE_DISPLAY_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkReleaseDisplayEXT",
+        &[
+            handle_arg("physicalDevice", physical_device),
+            handle_arg("display", display),
+        ],
+    );
+    let result = real(physical_device, display);
+    record(&TraceCall {
+        command: "vkReleaseDisplayEXT",
+        args: &[("physicalDevice", &physical_device), ("display", &display)],
+        result: Some(&result),
+    });
+    result
+}
+static RELEASE_FULL_SCREEN_EXCLUSIVE_MODE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn release_full_screen_exclusive_mode_ext(
+    device: Device,
+    swapchain: SwapchainKHR,
+) -> Result {
+    let real: extern "system" fn(Device, SwapchainKHR) -> Result =
+        unsafe { mem::transmute(RELEASE_FULL_SCREEN_EXCLUSIVE_MODE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkReleaseFullScreenExclusiveModeEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapchain", swapchain),
+        ],
+    );
+    let result = real(device, swapchain);
+    record(&TraceCall {
+        command: "vkReleaseFullScreenExclusiveModeEXT",
+        args: &[("device", &device), ("swapchain", &swapchain)],
+        result: Some(&result),
+    });
+    result
+}
+static RELEASE_PERFORMANCE_CONFIGURATION_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn release_performance_configuration_intel(
+    device: Device,
+    configuration: PerformanceConfigurationINTEL,
+) -> Result {
+    let real: extern "system" fn(Device, PerformanceConfigurationINTEL) -> Result =
+        unsafe { mem::transmute(RELEASE_PERFORMANCE_CONFIGURATION_INTEL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkReleasePerformanceConfigurationINTEL",
+        &[
+            handle_arg("device", device),
+            handle_arg("configuration", configuration),
+        ],
+    );
+    let result = real(device, configuration);
+    record(&TraceCall {
+        command: "vkReleasePerformanceConfigurationINTEL",
+        args: &[("device", &device), ("configuration", &configuration)],
+        result: Some(&result),
+    });
+    result
+}
+static RELEASE_PROFILING_LOCK_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn release_profiling_lock_khr(device: Device) -> c_void {
+    let real: extern "system" fn(Device) -> c_void =
+        unsafe { mem::transmute(RELEASE_PROFILING_LOCK_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkReleaseProfilingLockKHR", &[handle_arg("device", device)]);
+    let result = real(device);
+    record(&TraceCall {
+        command: "vkReleaseProfilingLockKHR",
+        args: &[("device", &device)],
+        result: None,
+    });
+    result
+}
+static RESET_COMMAND_BUFFER: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_command_buffer(
+    command_buffer: CommandBuffer,
+    flags: CommandBufferResetFlags,
+) -> Result {
+    let real: extern "system" fn(CommandBuffer, CommandBufferResetFlags) -> Result =
+        unsafe { mem::transmute(RESET_COMMAND_BUFFER.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkResetCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    check_handles(
+        "vkResetCommandBuffer",
+        &[handle_arg("commandBuffer", command_buffer)],
+    );
+    let result = real(command_buffer, flags);
+    record(&TraceCall {
+        command: "vkResetCommandBuffer",
+        args: &[("commandBuffer", &command_buffer), ("flags", &flags)],
+        result: Some(&result),
+    });
+    result
+}
+static RESET_COMMAND_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolResetFlags,
+) -> Result {
+    let real: extern "system" fn(Device, CommandPool, CommandPoolResetFlags) -> Result =
+        unsafe { mem::transmute(RESET_COMMAND_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkResetCommandPool",
+        &[handle_arg("commandPool", command_pool)],
+    );
+    check_handles(
+        "vkResetCommandPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("commandPool", command_pool),
+        ],
+    );
+    let result = real(device, command_pool, flags);
+    record(&TraceCall {
+        command: "vkResetCommandPool",
+        args: &[
+            ("device", &device),
+            ("commandPool", &command_pool),
+            ("flags", &flags),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static RESET_DESCRIPTOR_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_descriptor_pool(
+    device: Device,
+    descriptor_pool: DescriptorPool,
+    flags: DescriptorPoolResetFlags,
+) -> Result {
+    let real: extern "system" fn(Device, DescriptorPool, DescriptorPoolResetFlags) -> Result =
+        unsafe { mem::transmute(RESET_DESCRIPTOR_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkResetDescriptorPool",
+        &[handle_arg("descriptorPool", descriptor_pool)],
+    );
+    check_handles(
+        "vkResetDescriptorPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorPool", descriptor_pool),
+        ],
+    );
+    let result = real(device, descriptor_pool, flags);
+    record(&TraceCall {
+        command: "vkResetDescriptorPool",
+        args: &[
+            ("device", &device),
+            ("descriptorPool", &descriptor_pool),
+            ("flags", &flags),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static RESET_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_event(device: Device, event: Event) -> Result {
+    let real: extern "system" fn(Device, Event) -> Result =
+        unsafe { mem::transmute(RESET_EVENT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkResetEvent", &[handle_arg("event", event)]);
+    check_handles(
+        "vkResetEvent",
+        &[handle_arg("device", device), handle_arg("event", event)],
+    );
+    let result = real(device, event);
+    record(&TraceCall {
+        command: "vkResetEvent",
+        args: &[("device", &device), ("event", &event)],
+        result: Some(&result),
+    });
+    result
+}
+static RESET_FENCES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_fences(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const Fence) -> Result =
+        unsafe { mem::transmute(RESET_FENCES.load(Ordering::Relaxed)) };
+    check_handles("vkResetFences", &[handle_arg("device", device)]);
+    let result = real(device, fence_count, p_fences);
+    record(&TraceCall {
+        command: "vkResetFences",
+        args: &[
+            ("device", &device),
+            ("fenceCount", &fence_count),
+            ("pFences", &Pointees(p_fences, fence_count as usize, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static RESET_QUERY_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_query_pool(
+    device: Device,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+) -> c_void {
+    let real: extern "system" fn(Device, QueryPool, u32, u32) -> c_void =
+        unsafe { mem::transmute(RESET_QUERY_POOL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkResetQueryPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(device, query_pool, first_query, query_count);
+    record(&TraceCall {
+        command: "vkResetQueryPool",
+        args: &[
+            ("device", &device),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+            ("queryCount", &query_count),
+        ],
+        result: None,
+    });
+    result
+}
+static RESET_QUERY_POOL_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn reset_query_pool_ext(
+    device: Device,
+    query_pool: QueryPool,
+    first_query: u32,
+    query_count: u32,
+) -> c_void {
+    let real: extern "system" fn(Device, QueryPool, u32, u32) -> c_void =
+        unsafe { mem::transmute(RESET_QUERY_POOL_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkResetQueryPoolEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("queryPool", query_pool),
+        ],
+    );
+    let result = real(device, query_pool, first_query, query_count);
+    record(&TraceCall {
+        command: "vkResetQueryPoolEXT",
+        args: &[
+            ("device", &device),
+            ("queryPool", &query_pool),
+            ("firstQuery", &first_query),
+            ("queryCount", &query_count),
+        ],
+        result: None,
+    });
+    result
+}
+static SET_DEBUG_UTILS_OBJECT_NAME_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_debug_utils_object_name_ext(
+    device: Device,
+    p_name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    let real: extern "system" fn(Device, *const DebugUtilsObjectNameInfoEXT) -> Result =
+        unsafe { mem::transmute(SET_DEBUG_UTILS_OBJECT_NAME_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkSetDebugUtilsObjectNameEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_name_info);
+    record(&TraceCall {
+        command: "vkSetDebugUtilsObjectNameEXT",
+        args: &[
+            ("device", &device),
+            ("pNameInfo", &Pointee(p_name_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static SET_DEBUG_UTILS_OBJECT_TAG_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_debug_utils_object_tag_ext(
+    device: Device,
+    p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+) -> Result {
+    let real: extern "system" fn(Device, *const DebugUtilsObjectTagInfoEXT) -> Result =
+        unsafe { mem::transmute(SET_DEBUG_UTILS_OBJECT_TAG_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkSetDebugUtilsObjectTagEXT",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device, p_tag_info);
+    record(&TraceCall {
+        command: "vkSetDebugUtilsObjectTagEXT",
+        args: &[
+            ("device", &device),
+            ("pTagInfo", &Pointee(p_tag_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static SET_EVENT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_event(device: Device, event: Event) -> Result {
+    let real: extern "system" fn(Device, Event) -> Result =
+        unsafe { mem::transmute(SET_EVENT.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkSetEvent", &[handle_arg("event", event)]);
+    check_handles(
+        "vkSetEvent",
+        &[handle_arg("device", device), handle_arg("event", event)],
+    );
+    let result = real(device, event);
+    record(&TraceCall {
+        command: "vkSetEvent",
+        args: &[("device", &device), ("event", &event)],
+        result: Some(&result),
+    });
+    result
+}
+static SET_HDR_METADATA_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_hdr_metadata_ext(
+    device: Device,
+    swapchain_count: u32,
+    p_swapchains: *const SwapchainKHR,
+    p_metadata: *const HdrMetadataEXT,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const SwapchainKHR,
+        *const HdrMetadataEXT,
+    ) -> c_void = unsafe { mem::transmute(SET_HDR_METADATA_EXT.load(Ordering::Relaxed)) };
+    check_handles("vkSetHdrMetadataEXT", &[handle_arg("device", device)]);
+    let result = real(device, swapchain_count, p_swapchains, p_metadata);
+    record(&TraceCall {
+        command: "vkSetHdrMetadataEXT",
+        args: &[
+            ("device", &device),
+            ("swapchainCount", &swapchain_count),
+            (
+                "pSwapchains",
+                &Pointees(p_swapchains, swapchain_count as usize, true),
+            ),
+            (
+                "pMetadata",
+                &Pointees(p_metadata, swapchain_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static SET_LOCAL_DIMMING_AMD: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_local_dimming_amd(
+    device: Device,
+    swap_chain: SwapchainKHR,
+    local_dimming_enable: Bool32,
+) -> c_void {
+    let real: extern "system" fn(Device, SwapchainKHR, Bool32) -> c_void =
+        unsafe { mem::transmute(SET_LOCAL_DIMMING_AMD.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkSetLocalDimmingAMD",
+        &[
+            handle_arg("device", device),
+            handle_arg("swapChain", swap_chain),
+        ],
+    );
+    let result = real(device, swap_chain, local_dimming_enable);
+    record(&TraceCall {
+        command: "vkSetLocalDimmingAMD",
+        args: &[
+            ("device", &device),
+            ("swapChain", &swap_chain),
+            ("localDimmingEnable", &local_dimming_enable),
+        ],
+        result: None,
+    });
+    result
+}
+static SET_PRIVATE_DATA_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn set_private_data_ext(
+    device: Device,
+    object_type: ObjectType,
+    object_handle: u64,
+    private_data_slot: PrivateDataSlotEXT,
+    data: u64,
+) -> Result {
+    let real: extern "system" fn(Device, ObjectType, u64, PrivateDataSlotEXT, u64) -> Result =
+        unsafe { mem::transmute(SET_PRIVATE_DATA_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkSetPrivateDataEXT",
+        &[
+            handle_arg("device", device),
+            handle_arg("privateDataSlot", private_data_slot),
+        ],
+    );
+    let result = real(device, object_type, object_handle, private_data_slot, data);
+    record(&TraceCall {
+        command: "vkSetPrivateDataEXT",
+        args: &[
+            ("device", &device),
+            ("objectType", &object_type),
+            ("objectHandle", &object_handle),
+            ("privateDataSlot", &private_data_slot),
+            ("data", &data),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static SIGNAL_SEMAPHORE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn signal_semaphore(
+    device: Device,
+    p_signal_info: *const SemaphoreSignalInfo,
+) -> Result {
+    let real: extern "system" fn(Device, *const SemaphoreSignalInfo) -> Result =
+        unsafe { mem::transmute(SIGNAL_SEMAPHORE.load(Ordering::Relaxed)) };
+    check_handles("vkSignalSemaphore", &[handle_arg("device", device)]);
+    let result = real(device, p_signal_info);
+    record(&TraceCall {
+        command: "vkSignalSemaphore",
+        args: &[
+            ("device", &device),
+            ("pSignalInfo", &Pointee(p_signal_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static SIGNAL_SEMAPHORE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn signal_semaphore_khr(
+    device: Device,
+    p_signal_info: *const SemaphoreSignalInfo,
+) -> Result {
+    let real: extern "system" fn(Device, *const SemaphoreSignalInfo) -> Result =
+        unsafe { mem::transmute(SIGNAL_SEMAPHORE_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkSignalSemaphoreKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_signal_info);
+    record(&TraceCall {
+        command: "vkSignalSemaphoreKHR",
+        args: &[
+            ("device", &device),
+            ("pSignalInfo", &Pointee(p_signal_info, true)),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static SUBMIT_DEBUG_UTILS_MESSAGE_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn submit_debug_utils_message_ext(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) -> c_void {
+    let real: extern "system" fn(
+        Instance,
+        DebugUtilsMessageSeverityFlagsEXT,
+        DebugUtilsMessageTypeFlagsEXT,
+        *const DebugUtilsMessengerCallbackDataEXT,
+    ) -> c_void = unsafe { mem::transmute(SUBMIT_DEBUG_UTILS_MESSAGE_EXT.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkSubmitDebugUtilsMessageEXT",
+        &[handle_arg("instance", instance)],
+    );
+    let result = real(instance, message_severity, message_types, p_callback_data);
+    record(&TraceCall {
+        command: "vkSubmitDebugUtilsMessageEXT",
+        args: &[
+            ("instance", &instance),
+            ("messageSeverity", &message_severity),
+            ("messageTypes", &message_types),
+            ("pCallbackData", &Pointee(p_callback_data, true)),
+        ],
+        result: None,
+    });
+    result
+}
+static TRIM_COMMAND_POOL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn trim_command_pool(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolTrimFlags,
+) -> c_void {
+    let real: extern "system" fn(Device, CommandPool, CommandPoolTrimFlags) -> c_void =
+        unsafe { mem::transmute(TRIM_COMMAND_POOL.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkTrimCommandPool",
+        &[handle_arg("commandPool", command_pool)],
+    );
+    check_handles(
+        "vkTrimCommandPool",
+        &[
+            handle_arg("device", device),
+            handle_arg("commandPool", command_pool),
+        ],
+    );
+    let result = real(device, command_pool, flags);
+    record(&TraceCall {
+        command: "vkTrimCommandPool",
+        args: &[
+            ("device", &device),
+            ("commandPool", &command_pool),
+            ("flags", &flags),
+        ],
+        result: None,
+    });
+    result
+}
+static TRIM_COMMAND_POOL_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn trim_command_pool_khr(
+    device: Device,
+    command_pool: CommandPool,
+    flags: CommandPoolTrimFlags,
+) -> c_void {
+    let real: extern "system" fn(Device, CommandPool, CommandPoolTrimFlags) -> c_void =
+        unsafe { mem::transmute(TRIM_COMMAND_POOL_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkTrimCommandPoolKHR",
+        &[handle_arg("commandPool", command_pool)],
+    );
+    check_handles(
+        "vkTrimCommandPoolKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("commandPool", command_pool),
+        ],
+    );
+    let result = real(device, command_pool, flags);
+    record(&TraceCall {
+        command: "vkTrimCommandPoolKHR",
+        args: &[
+            ("device", &device),
+            ("commandPool", &command_pool),
+            ("flags", &flags),
+        ],
+        result: None,
+    });
+    result
+}
+static UNINITIALIZE_PERFORMANCE_API_INTEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn uninitialize_performance_api_intel(device: Device) -> c_void {
+    let real: extern "system" fn(Device) -> c_void =
+        unsafe { mem::transmute(UNINITIALIZE_PERFORMANCE_API_INTEL.load(Ordering::Relaxed)) };
+    check_handles(
+        "vkUninitializePerformanceApiINTEL",
+        &[handle_arg("device", device)],
+    );
+    let result = real(device);
+    record(&TraceCall {
+        command: "vkUninitializePerformanceApiINTEL",
+        args: &[("device", &device)],
+        result: None,
+    });
+    result
+}
+static UNMAP_MEMORY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn unmap_memory(device: Device, memory: DeviceMemory) -> c_void {
+    let real: extern "system" fn(Device, DeviceMemory) -> c_void =
+        unsafe { mem::transmute(UNMAP_MEMORY.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new("vkUnmapMemory", &[handle_arg("memory", memory)]);
+    check_handles(
+        "vkUnmapMemory",
+        &[handle_arg("device", device), handle_arg("memory", memory)],
+    );
+    let result = real(device, memory);
+    record(&TraceCall {
+        command: "vkUnmapMemory",
+        args: &[("device", &device), ("memory", &memory)],
+        result: None,
+    });
+    result
+}
+static UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn update_descriptor_set_with_template(
+    device: Device,
+    descriptor_set: DescriptorSet,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DescriptorSet,
+        DescriptorUpdateTemplate,
+        *const c_void,
+    ) -> c_void =
+        unsafe { mem::transmute(UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkUpdateDescriptorSetWithTemplate",
+        &[handle_arg("descriptorSet", descriptor_set)],
+    );
+    check_handles(
+        "vkUpdateDescriptorSetWithTemplate",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorSet", descriptor_set),
+            handle_arg("descriptorUpdateTemplate", descriptor_update_template),
+        ],
+    );
+    let result = real(device, descriptor_set, descriptor_update_template, p_data);
+    record(&TraceCall {
+        command: "vkUpdateDescriptorSetWithTemplate",
+        args: &[
+            ("device", &device),
+            ("descriptorSet", &descriptor_set),
+            ("descriptorUpdateTemplate", &descriptor_update_template),
+            ("pData", &p_data),
+        ],
+        result: None,
+    });
+    result
+}
+static UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn update_descriptor_set_with_template_khr(
+    device: Device,
+    descriptor_set: DescriptorSet,
+    descriptor_update_template: DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        DescriptorSet,
+        DescriptorUpdateTemplate,
+        *const c_void,
+    ) -> c_void =
+        unsafe { mem::transmute(UPDATE_DESCRIPTOR_SET_WITH_TEMPLATE_KHR.load(Ordering::Relaxed)) };
+    let _sync = SyncGuard::new(
+        "vkUpdateDescriptorSetWithTemplateKHR",
+        &[handle_arg("descriptorSet", descriptor_set)],
+    );
+    check_handles(
+        "vkUpdateDescriptorSetWithTemplateKHR",
+        &[
+            handle_arg("device", device),
+            handle_arg("descriptorSet", descriptor_set),
+            handle_arg("descriptorUpdateTemplate", descriptor_update_template),
+        ],
+    );
+    let result = real(device, descriptor_set, descriptor_update_template, p_data);
+    record(&TraceCall {
+        command: "vkUpdateDescriptorSetWithTemplateKHR",
+        args: &[
+            ("device", &device),
+            ("descriptorSet", &descriptor_set),
+            ("descriptorUpdateTemplate", &descriptor_update_template),
+            ("pData", &p_data),
+        ],
+        result: None,
+    });
+    result
+}
+static UPDATE_DESCRIPTOR_SETS: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn update_descriptor_sets(
+    device: Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const WriteDescriptorSet,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const CopyDescriptorSet,
+) -> c_void {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const WriteDescriptorSet,
+        u32,
+        *const CopyDescriptorSet,
+    ) -> c_void = unsafe { mem::transmute(UPDATE_DESCRIPTOR_SETS.load(Ordering::Relaxed)) };
+    check_handles("vkUpdateDescriptorSets", &[handle_arg("device", device)]);
+    let result = real(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    );
+    record(&TraceCall {
+        command: "vkUpdateDescriptorSets",
+        args: &[
+            ("device", &device),
+            ("descriptorWriteCount", &descriptor_write_count),
+            (
+                "pDescriptorWrites",
+                &Pointees(p_descriptor_writes, descriptor_write_count as usize, true),
+            ),
+            ("descriptorCopyCount", &descriptor_copy_count),
+            (
+                "pDescriptorCopies",
+                &Pointees(p_descriptor_copies, descriptor_copy_count as usize, true),
+            ),
+        ],
+        result: None,
+    });
+    result
+}
+static WAIT_FOR_FENCES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn wait_for_fences(
+    device: Device,
+    fence_count: u32,
+    p_fences: *const Fence,
+    wait_all: Bool32,
+    timeout: u64,
+) -> Result {
+    let real: extern "system" fn(Device, u32, *const Fence, Bool32, u64) -> Result =
+        unsafe { mem::transmute(WAIT_FOR_FENCES.load(Ordering::Relaxed)) };
+    check_handles("vkWaitForFences", &[handle_arg("device", device)]);
+    let result = real(device, fence_count, p_fences, wait_all, timeout);
+    record(&TraceCall {
+        command: "vkWaitForFences",
+        args: &[
+            ("device", &device),
+            ("fenceCount", &fence_count),
+            ("pFences", &Pointees(p_fences, fence_count as usize, true)),
+            ("waitAll", &wait_all),
+            ("timeout", &timeout),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static WAIT_SEMAPHORES: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn wait_semaphores(
+    device: Device,
+    p_wait_info: *const SemaphoreWaitInfo,
+    timeout: u64,
+) -> Result {
+    let real: extern "system" fn(Device, *const SemaphoreWaitInfo, u64) -> Result =
+        unsafe { mem::transmute(WAIT_SEMAPHORES.load(Ordering::Relaxed)) };
+    check_handles("vkWaitSemaphores", &[handle_arg("device", device)]);
+    let result = real(device, p_wait_info, timeout);
+    record(&TraceCall {
+        command: "vkWaitSemaphores",
+        args: &[
+            ("device", &device),
+            ("pWaitInfo", &Pointee(p_wait_info, true)),
+            ("timeout", &timeout),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static WAIT_SEMAPHORES_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+extern "system" fn wait_semaphores_khr(
+    device: Device,
+    p_wait_info: *const SemaphoreWaitInfo,
+    timeout: u64,
+) -> Result {
+    let real: extern "system" fn(Device, *const SemaphoreWaitInfo, u64) -> Result =
+        unsafe { mem::transmute(WAIT_SEMAPHORES_KHR.load(Ordering::Relaxed)) };
+    check_handles("vkWaitSemaphoresKHR", &[handle_arg("device", device)]);
+    let result = real(device, p_wait_info, timeout);
+    record(&TraceCall {
+        command: "vkWaitSemaphoresKHR",
+        args: &[
+            ("device", &device),
+            ("pWaitInfo", &Pointee(p_wait_info, true)),
+            ("timeout", &timeout),
+        ],
+        result: Some(&result),
+    });
+    result
+}
+static WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR: AtomicPtr<c_void> =
+    AtomicPtr::new(ptr::null_mut());
+extern "system" fn write_acceleration_structures_properties_khr(
+    device: Device,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const AccelerationStructureKHR,
+    query_type: QueryType,
+    data_size: size_t,
+    p_data: *mut c_void,
+    stride: size_t,
+) -> Result {
+    let real: extern "system" fn(
+        Device,
+        u32,
+        *const AccelerationStructureKHR,
+        QueryType,
+        size_t,
+        *mut c_void,
+        size_t,
+    ) -> Result = unsafe {
+        mem::transmute(WRITE_ACCELERATION_STRUCTURES_PROPERTIES_KHR.load(Ordering::Relaxed))
+    };
+    check_handles(
+        "vkWriteAccelerationStructuresPropertiesKHR",
+        &[handle_arg("device", device)],
+    );
+    let result = real(
+        device,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    record(&TraceCall {
+        command: "vkWriteAccelerationStructuresPropertiesKHR",
+        args: &[
+            ("device", &device),
+            ("accelerationStructureCount", &acceleration_structure_count),
+            (
+                "pAccelerationStructures",
+                &Pointees(
+                    p_acceleration_structures,
+                    acceleration_structure_count as usize,
+                    true,
+                ),
+            ),
+            ("queryType", &query_type),
+            ("dataSize", &data_size),
+            ("pData", &p_data),
+            ("stride", &stride),
+        ],
+        result: Some(&result),
+    });
+    result
+}
@@ -0,0 +1,325 @@
+//! Tracks the handles that the traced commands create and destroy, to report the handles that
+//! are used after they were destroyed and the ones that are still alive when the device or
+//! instance they belong to is destroyed.
+use super::{lazy_global, HandleArg, SINK};
+use crate::vk::{self, Handle};
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::Mutex;
+
+/// A handle that was used after it was destroyed, or that leaked.
+#[derive(Clone, Debug)]
+pub enum HandleIssue {
+    /// The handle is passed as `param` of `command` after `destroyed_by` destroyed it, directly
+    /// or by destroying the handle it was created from.
+    UsedAfterDestroy {
+        handle: u64,
+        object_type: vk::ObjectType,
+        param: &'static str,
+        command: &'static str,
+        destroyed_by: &'static str,
+    },
+    /// The handle that `created_by` returned is still alive when `destroyed_by` destroys the
+    /// device or instance that it belongs to.
+    Leaked {
+        handle: u64,
+        object_type: vk::ObjectType,
+        created_by: &'static str,
+        destroyed_by: &'static str,
+    },
+}
+
+impl fmt::Display for HandleIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandleIssue::UsedAfterDestroy {
+                handle,
+                object_type,
+                param,
+                command,
+                destroyed_by,
+            } => write!(
+                f,
+                "{:?} {:#x} is used as {} of {} after {} destroyed it",
+                object_type, handle, param, command, destroyed_by
+            ),
+            HandleIssue::Leaked {
+                handle,
+                object_type,
+                created_by,
+                destroyed_by,
+            } => write!(
+                f,
+                "{:?} {:#x} from {} is still alive at {}",
+                object_type, handle, created_by, destroyed_by
+            ),
+        }
+    }
+}
+
+static CHECK_HANDLE_LIFETIMES: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the tracking of handle lifetimes, which is disabled by default. Only the
+/// handles that are created while it is enabled are tracked, and the handles that are destroyed
+/// are remembered until they are created again, to recognize their use.
+pub fn check_handle_lifetimes(enabled: bool) {
+    CHECK_HANDLE_LIFETIMES.store(enabled, Ordering::Relaxed);
+}
+
+type HandleKey = (vk::ObjectType, u64);
+
+/// A handle that was created or retrieved, such as a queue, and isn't destroyed yet.
+struct Live {
+    /// The handle that it was created from, which destroys it as well.
+    parent: Option<HandleKey>,
+    command: &'static str,
+    /// Whether it is destroyed on its own, and can leak.
+    owned: bool,
+    /// How many times the handle was returned, as non-dispatchable handles don't have to be
+    /// unique.
+    count: usize,
+}
+
+#[derive(Default)]
+struct Lifetimes {
+    live: HashMap<HandleKey, Live>,
+    /// The handles that were destroyed, and the commands that destroyed them.
+    destroyed: HashMap<HandleKey, &'static str>,
+}
+
+static LIFETIMES: AtomicPtr<Mutex<Lifetimes>> = AtomicPtr::new(ptr::null_mut());
+
+fn report(issues: &[HandleIssue]) {
+    if let Some(sink) = unsafe { SINK.load(Ordering::Acquire).as_ref() } {
+        for issue in issues {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| sink.handle_issue(issue)));
+        }
+    }
+}
+
+fn with_lifetimes<R>(f: impl FnOnce(&mut Lifetimes) -> R) -> R {
+    let mut lifetimes = lazy_global(&LIFETIMES)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut lifetimes)
+}
+
+impl Lifetimes {
+    fn check(&self, command: &'static str, handles: &[HandleArg]) -> Vec<HandleIssue> {
+        handles
+            .iter()
+            .filter(|&&(_, object_type, handle)| {
+                handle != 0 && !self.live.contains_key(&(object_type, handle))
+            })
+            .filter_map(|&(param, object_type, handle)| {
+                let destroyed_by = *self.destroyed.get(&(object_type, handle))?;
+                Some(HandleIssue::UsedAfterDestroy {
+                    handle,
+                    object_type,
+                    param,
+                    command,
+                    destroyed_by,
+                })
+            })
+            .collect()
+    }
+
+    fn create(
+        &mut self,
+        command: &'static str,
+        owned: bool,
+        parent: Option<HandleArg>,
+        handles: &[u64],
+        object_type: vk::ObjectType,
+    ) {
+        let parent = parent
+            .map(|(_, object_type, handle)| (object_type, handle))
+            .filter(|&(_, handle)| handle != 0);
+        for &handle in handles.iter().filter(|&&handle| handle != 0) {
+            let key = (object_type, handle);
+            self.destroyed.remove(&key);
+            self.live
+                .entry(key)
+                .or_insert(Live {
+                    parent,
+                    command,
+                    owned,
+                    count: 0,
+                })
+                .count += 1;
+        }
+    }
+
+    /// Destroys `handles` and the handles created from them, and returns the owned ones that
+    /// leaked if a device or instance is destroyed.
+    fn destroy(
+        &mut self,
+        command: &'static str,
+        handles: &[u64],
+        object_type: vk::ObjectType,
+    ) -> Vec<HandleIssue> {
+        let mut issues = Vec::new();
+        for &handle in handles {
+            let root = (object_type, handle);
+            match self.live.get_mut(&root) {
+                Some(live) if live.count > 1 => {
+                    live.count -= 1;
+                    continue;
+                }
+                Some(_) => {}
+                None => continue,
+            }
+            let reports_leaks =
+                object_type == vk::ObjectType::DEVICE || object_type == vk::ObjectType::INSTANCE;
+            let mut destroyed = vec![root];
+            let mut i = 0;
+            while i < destroyed.len() {
+                let parent = destroyed[i];
+                let children: Vec<_> = self
+                    .live
+                    .iter()
+                    .filter(|(key, live)| live.parent == Some(parent) && !destroyed.contains(key))
+                    .map(|(&key, _)| key)
+                    .collect();
+                destroyed.extend(children);
+                i += 1;
+            }
+            for key in destroyed {
+                let live = match self.live.remove(&key) {
+                    Some(live) => live,
+                    None => continue,
+                };
+                if reports_leaks && live.owned && key != root {
+                    issues.push(HandleIssue::Leaked {
+                        handle: key.1,
+                        object_type: key.0,
+                        created_by: live.command,
+                        destroyed_by: command,
+                    });
+                }
+                self.destroyed.insert(key, command);
+            }
+        }
+        issues
+    }
+}
+
+/// Reports the `handles` that `command` uses after they were destroyed.
+pub(super) fn check_handles(command: &'static str, handles: &[HandleArg]) {
+    if !CHECK_HANDLE_LIFETIMES.load(Ordering::Relaxed) {
+        return;
+    }
+    let issues = with_lifetimes(|lifetimes| lifetimes.check(command, handles));
+    report(&issues);
+}
+
+/// Tracks the `count` handles at `handles` that `command` returned, created from `parent`.
+///
+/// # Safety
+///
+/// `handles` must be null or point to `count` handles.
+pub(super) unsafe fn track_created<H: Handle + Copy>(
+    command: &'static str,
+    owned: bool,
+    parent: Option<HandleArg>,
+    handles: *const H,
+    count: usize,
+) {
+    if !CHECK_HANDLE_LIFETIMES.load(Ordering::Relaxed) || handles.is_null() {
+        return;
+    }
+    let handles: Vec<u64> = slice::from_raw_parts(handles, count)
+        .iter()
+        .map(|&handle| handle.as_raw())
+        .collect();
+    with_lifetimes(|lifetimes| lifetimes.create(command, owned, parent, &handles, H::TYPE));
+}
+
+/// Stops tracking the `count` handles at `handles` that `command` destroyed, and reports the
+/// leaks.
+///
+/// # Safety
+///
+/// `handles` must be null or point to `count` handles.
+pub(super) unsafe fn track_destroyed<H: Handle + Copy>(
+    command: &'static str,
+    handles: *const H,
+    count: usize,
+) {
+    if !CHECK_HANDLE_LIFETIMES.load(Ordering::Relaxed) || handles.is_null() {
+        return;
+    }
+    let handles: Vec<u64> = slice::from_raw_parts(handles, count)
+        .iter()
+        .map(|&handle| handle.as_raw())
+        .collect();
+    let issues = with_lifetimes(|lifetimes| lifetimes.destroy(command, &handles, H::TYPE));
+    report(&issues);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaks_and_use_after_destroy() {
+        let mut lifetimes = Lifetimes::default();
+        let device = (vk::ObjectType::DEVICE, 0xde71ce);
+        let pool = (vk::ObjectType::COMMAND_POOL, 0x9001);
+        let command_buffers = [0xcb1, 0xcb2];
+        let queue = (vk::ObjectType::QUEUE, 0x9e9e);
+        let buffer = (vk::ObjectType::BUFFER, 0xb0ff);
+        let device_arg = Some(("device", device.0, device.1));
+        lifetimes.create("vkCreateDevice", true, None, &[device.1], device.0);
+        lifetimes.create("vkGetDeviceQueue", false, device_arg, &[queue.1], queue.0);
+        lifetimes.create("vkCreateCommandPool", true, device_arg, &[pool.1], pool.0);
+        lifetimes.create(
+            "vkAllocateCommandBuffers",
+            true,
+            Some(("pAllocateInfo->commandPool", pool.0, pool.1)),
+            &command_buffers,
+            vk::ObjectType::COMMAND_BUFFER,
+        );
+        lifetimes.create("vkCreateBuffer", true, device_arg, &[buffer.1], buffer.0);
+
+        // Destroying the pool frees its command buffers
+        assert!(lifetimes
+            .destroy("vkDestroyCommandPool", &[pool.1], pool.0)
+            .is_empty());
+        let issues = lifetimes.check(
+            "vkBeginCommandBuffer",
+            &[("commandBuffer", vk::ObjectType::COMMAND_BUFFER, 0xcb2)],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].to_string(),
+            format!(
+                "{:?} 0xcb2 is used as commandBuffer of vkBeginCommandBuffer after \
+                 vkDestroyCommandPool destroyed it",
+                vk::ObjectType::COMMAND_BUFFER
+            )
+        );
+
+        // The queue is retrieved and can't leak, the buffer can
+        let issues = lifetimes.destroy("vkDestroyDevice", &[device.1], device.0);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].to_string(),
+            format!(
+                "{:?} 0xb0ff from vkCreateBuffer is still alive at vkDestroyDevice",
+                vk::ObjectType::BUFFER
+            )
+        );
+        assert!(lifetimes.live.is_empty());
+
+        // Handles that are created again can be used
+        lifetimes.create("vkCreateBuffer", true, None, &[buffer.1], buffer.0);
+        assert!(lifetimes
+            .check("vkDestroyBuffer", &[("buffer", buffer.0, buffer.1)])
+            .is_empty());
+    }
+}
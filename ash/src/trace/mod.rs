@@ -0,0 +1,576 @@
+//! Records the calls of Vulkan commands with their arguments and results, so that the call
+//! streams of two runs can be diffed and driver issues debugged without external tools.
+//!
+//! [`interpose`] swaps the address of a command for a function that calls it and passes the call
+//! on to the sink installed with [`set_sink`]. The interposed `vkGetInstanceProcAddr` and
+//! `vkGetDeviceProcAddr` hand out traced commands as well, so interposing the loader of an
+//! [`EntryCustom`](crate::EntryCustom) traces the instances and devices created from it:
+//!
+//! ```no_run
+//! # use ash::{trace, version::EntryV1_0, vk, EntryCustom};
+//! # use std::ffi::CStr;
+//! # use std::os::raw::c_void;
+//! # fn load_vulkan(name: &CStr) -> *const c_void { std::ptr::null() }
+//! trace::set_sink(trace::WriteSink::new(std::io::stderr()));
+//! let entry = EntryCustom::new_custom((), |_, name| unsafe {
+//!     trace::interpose(name, load_vulkan(name))
+//! });
+//! // Prints `vkCreateInstance(pCreateInfo: &InstanceCreateInfo { .. }, ..) -> SUCCESS`
+//! let instance = unsafe { entry.create_instance(&vk::InstanceCreateInfo::default(), None) };
+//! ```
+//!
+//! The interposed commands are global: a traced command calls the address that was last
+//! interposed for its name, so the instances and devices that are traced at the same time have to
+//! use the same driver.
+//!
+//! With [`check_external_sync`], the traced commands also claim the handles that the application
+//! has to synchronize externally, such as the `commandPool` of `vkResetCommandPool`, for the
+//! duration of the call. A handle that is used by two threads at the same time is reported to
+//! [`TraceSink::sync_conflict`].
+//!
+//! With [`check_handle_lifetimes`], the traced commands keep track of the handles they create and
+//! destroy, with the handles they are created from by the `parent` attributes of the registry.
+//! Handles that are used after they were destroyed, and the ones that are still alive when their
+//! device or instance is destroyed, are reported to [`TraceSink::handle_issue`], as a lightweight
+//! alternative to the validation layers.
+use crate::vk;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fmt;
+use std::io::Write;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+
+mod commands;
+mod lifetimes;
+
+pub use self::lifetimes::{check_handle_lifetimes, HandleIssue};
+
+/// A call of a traced command, after it returned.
+pub struct TraceCall<'a> {
+    /// The name of the command, such as `vkCreateBuffer`.
+    pub command: &'static str,
+    /// The arguments by the names of their parameters in the registry, such as `pCreateInfo`.
+    pub args: &'a [(&'static str, &'a dyn fmt::Debug)],
+    /// The returned value, `None` for commands that return `void`.
+    pub result: Option<&'a dyn fmt::Debug>,
+}
+
+/// Prints the call on one line, like `vkDestroyBuffer(device: 0x1, buffer: 0x2, pAllocator: 0x0)`.
+/// The alternate flag `{:#}` pretty-prints the arguments.
+impl<'a> fmt::Display for TraceCall<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.command)?;
+        for (i, (name, value)) in self.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if f.alternate() {
+                write!(f, "{}: {:#?}", name, value)?;
+            } else {
+                write!(f, "{}: {:?}", name, value)?;
+            }
+        }
+        f.write_str(")")?;
+        if let Some(result) = self.result {
+            write!(f, " -> {:?}", result)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for TraceCall<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Receives the calls of traced commands, on the thread that made them.
+pub trait TraceSink: Send + Sync {
+    fn call(&self, call: &TraceCall);
+
+    /// Receives the handles that are used by two threads at the same time, on the thread that
+    /// started using the handle last, before the command is called. Only reported once
+    /// [`check_external_sync`] is enabled.
+    fn sync_conflict(&self, _conflict: &SyncConflict) {}
+
+    /// Receives the handles that are used after they were destroyed, before the command is
+    /// called, and the handles that leaked, once the device or instance they belong to is
+    /// destroyed. Only reported once [`check_handle_lifetimes`] is enabled.
+    fn handle_issue(&self, _issue: &HandleIssue) {}
+}
+
+impl<F> TraceSink for F
+where
+    F: Fn(&TraceCall) + Send + Sync,
+{
+    fn call(&self, call: &TraceCall) {
+        self(call)
+    }
+}
+
+/// Writes every call as a line to a writer, such as a file or `stderr`. Errors of the writer are
+/// ignored.
+pub struct WriteSink<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> WriteSink<W> {
+    pub fn new(writer: W) -> Self {
+        WriteSink {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<W: Write + Send> TraceSink for WriteSink<W> {
+    fn call(&self, call: &TraceCall) {
+        if let Ok(mut writer) = self.writer.lock() {
+            let _ = writeln!(writer, "{}", call);
+        }
+    }
+
+    fn sync_conflict(&self, conflict: &SyncConflict) {
+        if let Ok(mut writer) = self.writer.lock() {
+            let _ = writeln!(writer, "{}", conflict);
+        }
+    }
+
+    fn handle_issue(&self, issue: &HandleIssue) {
+        if let Ok(mut writer) = self.writer.lock() {
+            let _ = writeln!(writer, "{}", issue);
+        }
+    }
+}
+
+static SINK: AtomicPtr<Box<dyn TraceSink>> = AtomicPtr::new(ptr::null_mut());
+
+/// Sends the calls of all traced commands to `sink`. Calls made before a sink is set aren't
+/// recorded. A sink that is replaced is leaked, as other threads may still be using it.
+pub fn set_sink<S: TraceSink + 'static>(sink: S) {
+    let sink: Box<dyn TraceSink> = Box::new(sink);
+    SINK.store(Box::into_raw(Box::new(sink)), Ordering::Release);
+}
+
+fn record(call: &TraceCall) {
+    if let Some(sink) = unsafe { SINK.load(Ordering::Acquire).as_ref() } {
+        // Unwinding through the `extern "system"` functions is undefined behavior
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| sink.call(call)));
+    }
+}
+
+/// A handle that a command uses while another thread is still using it in a command that
+/// requires external synchronization of the handle.
+#[derive(Clone, Debug)]
+pub struct SyncConflict {
+    /// The raw value of the handle, as returned by [`vk::Handle::as_raw`].
+    pub handle: u64,
+    pub object_type: vk::ObjectType,
+    /// The parameter of [`command`](Self::command) that the handle is passed as.
+    pub param: &'static str,
+    pub command: &'static str,
+    pub thread: ThreadId,
+    /// The command that [`other_thread`](Self::other_thread) is calling with the handle.
+    pub other_command: &'static str,
+    pub other_thread: ThreadId,
+}
+
+impl fmt::Display for SyncConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} {:#x} is used as {} of {} on {:?} while {} uses it on {:?}",
+            self.object_type,
+            self.handle,
+            self.param,
+            self.command,
+            self.thread,
+            self.other_command,
+            self.other_thread,
+        )
+    }
+}
+
+static CHECK_EXTERNAL_SYNC: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the tracking of externally synchronized handles, which is disabled by
+/// default. The handles of [`vk::meta::extern_sync_params`] that are passed by value are tracked,
+/// not the ones in arrays and structures.
+pub fn check_external_sync(enabled: bool) {
+    CHECK_EXTERNAL_SYNC.store(enabled, Ordering::Relaxed);
+}
+
+/// The thread that is using a handle, in which command, and how many of its calls are using it.
+type SyncOwner = (ThreadId, &'static str, usize);
+
+static SYNC_OWNERS: AtomicPtr<Mutex<HashMap<(vk::ObjectType, u64), SyncOwner>>> =
+    AtomicPtr::new(ptr::null_mut());
+
+/// Returns the value of `slot`, which is set to a new default value by the first call.
+fn lazy_global<T: Default>(slot: &'static AtomicPtr<T>) -> &'static T {
+    let mut value = slot.load(Ordering::Acquire);
+    if value.is_null() {
+        let new = Box::into_raw(Box::default());
+        value = match slot.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                drop(unsafe { Box::from_raw(new) });
+                existing
+            }
+        };
+    }
+    unsafe { &*value }
+}
+
+/// A handle that is passed to a command as the parameter `.0`.
+type HandleArg = (&'static str, vk::ObjectType, u64);
+
+fn handle_arg<H: vk::Handle>(param: &'static str, handle: H) -> HandleArg {
+    (param, H::TYPE, handle.as_raw())
+}
+
+/// Claims the externally synchronized handles of a call until it is dropped.
+struct SyncGuard {
+    handles: Vec<(vk::ObjectType, u64)>,
+}
+
+impl SyncGuard {
+    fn new(command: &'static str, handles: &[HandleArg]) -> Self {
+        if !CHECK_EXTERNAL_SYNC.load(Ordering::Relaxed) {
+            return SyncGuard {
+                handles: Vec::new(),
+            };
+        }
+        let (guard, conflicts) = SyncGuard::claim(command, handles);
+        if let Some(sink) = unsafe { SINK.load(Ordering::Acquire).as_ref() } {
+            for conflict in &conflicts {
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| sink.sync_conflict(conflict)));
+            }
+        }
+        guard
+    }
+
+    /// Claims the non-null `handles` that aren't used by another thread, and returns the ones
+    /// that are.
+    fn claim(command: &'static str, handles: &[HandleArg]) -> (Self, Vec<SyncConflict>) {
+        let thread = thread::current().id();
+        let mut guard = SyncGuard {
+            handles: Vec::new(),
+        };
+        let mut conflicts = Vec::new();
+        let mut owners = lazy_global(&SYNC_OWNERS)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for &(param, object_type, handle) in handles {
+            if handle == 0 {
+                continue;
+            }
+            let owner = owners
+                .entry((object_type, handle))
+                .or_insert((thread, command, 0));
+            if owner.0 != thread {
+                conflicts.push(SyncConflict {
+                    handle,
+                    object_type,
+                    param,
+                    command,
+                    thread,
+                    other_command: owner.1,
+                    other_thread: owner.0,
+                });
+                continue;
+            }
+            owner.2 += 1;
+            guard.handles.push((object_type, handle));
+        }
+        (guard, conflicts)
+    }
+}
+
+impl Drop for SyncGuard {
+    fn drop(&mut self) {
+        if self.handles.is_empty() {
+            return;
+        }
+        let mut owners = lazy_global(&SYNC_OWNERS)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for key in &self.handles {
+            if let Some(owner) = owners.get_mut(key) {
+                owner.2 -= 1;
+                if owner.2 == 0 {
+                    owners.remove(key);
+                }
+            }
+        }
+    }
+}
+
+/// Returns a function that calls the command `name` at `real` and records the call, or `real`
+/// itself for commands that aren't traced and if it is null. Use it to wrap a loader such as the
+/// one of [`Device::load_with`](crate::Device::load_with).
+///
+/// # Safety
+///
+/// `real` must be null or the address of the command `name`. It replaces the address that the
+/// earlier traced commands of the same name call.
+pub unsafe fn interpose(name: &CStr, real: *const c_void) -> *const c_void {
+    if real.is_null() {
+        return real;
+    }
+    let (slot, tracer) = match name.to_bytes() {
+        b"vkGetInstanceProcAddr" => (
+            &GET_INSTANCE_PROC_ADDR,
+            get_instance_proc_addr as *const c_void,
+        ),
+        b"vkGetDeviceProcAddr" => (&GET_DEVICE_PROC_ADDR, get_device_proc_addr as *const c_void),
+        name => match commands::tracer(name) {
+            Some(tracer) => tracer,
+            None => return real,
+        },
+    };
+    slot.store(real as *mut c_void, Ordering::Relaxed);
+    tracer
+}
+
+// The commands that load the others aren't recorded, only the commands they return are traced
+static GET_INSTANCE_PROC_ADDR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+static GET_DEVICE_PROC_ADDR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+extern "system" fn get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        let real: vk::PFN_vkGetInstanceProcAddr =
+            mem::transmute(GET_INSTANCE_PROC_ADDR.load(Ordering::Relaxed));
+        let command = real(instance, p_name);
+        mem::transmute(interpose(CStr::from_ptr(p_name), mem::transmute(command)))
+    }
+}
+
+extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    unsafe {
+        let real: vk::PFN_vkGetDeviceProcAddr =
+            mem::transmute(GET_DEVICE_PROC_ADDR.load(Ordering::Relaxed));
+        let command = real(device, p_name);
+        mem::transmute(interpose(CStr::from_ptr(p_name), mem::transmute(command)))
+    }
+}
+
+/// An argument that points to a value, which is shown if `.1` is set and the pointer isn't null.
+/// Output parameters are only shown once the command succeeded.
+struct Pointee<P>(P, bool);
+
+impl<T: fmt::Debug> fmt::Debug for Pointee<*const T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match unsafe { self.0.as_ref() } {
+            Some(value) if self.1 => {
+                f.write_str("&")?;
+                value.fmt(f)
+            }
+            _ => self.0.fmt(f),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Pointee<*mut T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Pointee(self.0 as *const T, self.1).fmt(f)
+    }
+}
+
+/// Same as [`Pointee`] for an argument that points to an array of `.1` values.
+struct Pointees<P>(P, usize, bool);
+
+impl<T: fmt::Debug> fmt::Debug for Pointees<*const T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_null() || self.1 == 0 || !self.2 {
+            return self.0.fmt(f);
+        }
+        f.write_str("&")?;
+        unsafe { slice::from_raw_parts(self.0, self.1) }.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Pointees<*mut T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Pointees(self.0 as *const T, self.1, self.2).fmt(f)
+    }
+}
+
+/// An argument that is a null-terminated string.
+struct CStrArg(*const c_char);
+
+impl fmt::Debug for CStrArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_null() {
+            self.0.fmt(f)
+        } else {
+            unsafe { CStr::from_ptr(self.0) }.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_call() {
+        let create_info = vk::BufferCreateInfo {
+            size: 100,
+            ..Default::default()
+        };
+        let buffers = [vk::Buffer::null(); 2];
+        let name = b"buffer\0";
+        let call = TraceCall {
+            command: "vkCreateBuffer",
+            args: &[
+                ("pCreateInfo", &Pointee(&create_info as *const _, true)),
+                (
+                    "pBuffer",
+                    &Pointee(buffers.as_ptr() as *mut vk::Buffer, false),
+                ),
+                ("pBuffers", &Pointees(buffers.as_ptr(), 2, true)),
+                ("pName", &CStrArg(name.as_ptr() as *const c_char)),
+                ("pNext", &CStrArg(ptr::null())),
+            ],
+            result: Some(&vk::Result::SUCCESS),
+        };
+        let line = call.to_string();
+        assert!(line.starts_with("vkCreateBuffer(pCreateInfo: &BufferCreateInfo {"));
+        assert!(line.contains("size: 100"));
+        assert!(line.contains(&format!("pBuffer: {:?},", buffers.as_ptr())));
+        assert!(line.contains("pBuffers: &[0x0, 0x0]"));
+        assert!(line.contains("pName: \"buffer\", pNext: 0x0)"));
+        assert!(line.ends_with(&format!(") -> {:?}", vk::Result::SUCCESS)));
+    }
+
+    #[test]
+    fn sync_conflicts() {
+        use crate::vk::Handle;
+        use std::sync::mpsc;
+
+        let pool = vk::CommandPool::from_raw(0x5ca1ab1e);
+        let (outer, conflicts) =
+            SyncGuard::claim("vkResetCommandPool", &[handle_arg("commandPool", pool)]);
+        assert!(conflicts.is_empty());
+        // The same thread may use the handle again
+        let (inner, conflicts) =
+            SyncGuard::claim("vkTrimCommandPool", &[handle_arg("commandPool", pool)]);
+        assert!(conflicts.is_empty());
+        drop(inner);
+
+        let owner = thread::current().id();
+        let (claimed, other_claimed) = mpsc::channel();
+        let (released, other_released) = mpsc::channel();
+        let other = thread::spawn(move || {
+            let (_guard, conflicts) = SyncGuard::claim(
+                "vkFreeCommandBuffers",
+                &[
+                    handle_arg("commandPool", pool),
+                    handle_arg("pCommandBuffers", vk::CommandBuffer::null()),
+                ],
+            );
+            assert_eq!(conflicts.len(), 1);
+            let conflict = &conflicts[0];
+            assert_eq!(conflict.handle, 0x5ca1ab1e);
+            assert_eq!(conflict.object_type, vk::ObjectType::COMMAND_POOL);
+            assert_eq!(conflict.other_command, "vkResetCommandPool");
+            assert_eq!(conflict.other_thread, owner);
+            assert!(conflict
+                .to_string()
+                .contains("is used as commandPool of vkFreeCommandBuffers"));
+            claimed.send(()).unwrap();
+
+            other_released.recv().unwrap();
+            // Released by the first thread, so this one can use it
+            let (_guard, conflicts) =
+                SyncGuard::claim("vkDestroyCommandPool", &[handle_arg("commandPool", pool)]);
+            assert!(conflicts.is_empty());
+        });
+        other_claimed.recv().unwrap();
+        drop(outer);
+        released.send(()).unwrap();
+        other.join().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "null-driver")]
+    fn trace_null_driver() {
+        use crate::null_driver;
+        use crate::version::{DeviceV1_0, EntryV1_0, InstanceV1_0};
+        use crate::EntryCustom;
+        use std::sync::Arc;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = calls.clone();
+        set_sink(move |call: &TraceCall| sink.lock().unwrap().push(call.to_string()));
+        let entry = EntryCustom::new_custom((), |_, name| unsafe {
+            interpose(name, null_driver::proc_addr(name))
+        });
+        unsafe {
+            let instance = entry
+                .create_instance(&vk::InstanceCreateInfo::default(), None)
+                .unwrap();
+            let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+            let queue_priorities = [1.0];
+            let queue_info = vk::DeviceQueueCreateInfo::builder()
+                .queue_family_index(0)
+                .queue_priorities(&queue_priorities);
+            let queue_infos = [queue_info.build()];
+            let device_info = vk::DeviceCreateInfo::builder().queue_create_infos(&queue_infos);
+            let device = instance
+                .create_device(physical_device, &device_info, None)
+                .unwrap();
+            let buffer_info = vk::BufferCreateInfo::builder()
+                .size(100)
+                .usage(vk::BufferUsageFlags::TRANSFER_SRC);
+            let buffer = device.create_buffer(&buffer_info, None).unwrap();
+            device.destroy_buffer(buffer, None);
+            device.destroy_device(None);
+            instance.destroy_instance(None);
+        }
+        let calls = calls.lock().unwrap();
+        let commands: Vec<_> = calls
+            .iter()
+            .map(|call| &call[..call.find('(').unwrap()])
+            .collect();
+        assert_eq!(
+            commands,
+            [
+                "vkCreateInstance",
+                "vkEnumeratePhysicalDevices",
+                "vkEnumeratePhysicalDevices",
+                "vkCreateDevice",
+                "vkCreateBuffer",
+                "vkDestroyBuffer",
+                "vkDestroyDevice",
+                "vkDestroyInstance",
+            ]
+        );
+        assert!(calls[4].contains("size: 100"));
+        assert!(calls[4].contains("pBuffer: &0x"));
+    }
+}
@@ -1,9 +1,14 @@
+use crate::extensions::khr::Surface;
+use crate::prelude::*;
+use crate::version::{DeviceV1_0, DeviceV1_1, InstanceV1_1};
 use crate::vk;
+use std::ffi::{CStr, CString};
 use std::iter::Iterator;
 use std::marker::PhantomData;
 use std::mem::size_of;
 use std::os::raw::c_void;
-use std::{io, slice};
+use std::path::PathBuf;
+use std::{fs, io, ptr, slice};
 
 /// `Align` handles dynamic alignment. The is useful for dynamic uniform buffers where
 /// the alignment might be different. For example a 4x4 f32 matrix has a size of 64 bytes
@@ -27,19 +32,41 @@ pub struct AlignIter<'a, T: 'a> {
 }
 
 impl<T: Copy> Align<T> {
+    /// Copies `slice` into the mapped memory, placing each element at a multiple of the alignment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` has more than `self.len()` elements.
     pub fn copy_from_slice(&mut self, slice: &[T]) {
         use std::slice::from_raw_parts_mut;
+        assert!(
+            slice.len() <= self.len(),
+            "slice of {} elements doesn't fit into {} aligned elements",
+            slice.len(),
+            self.len()
+        );
         if self.elem_size == size_of::<T>() as u64 {
             unsafe {
                 let mapped_slice = from_raw_parts_mut(self.ptr as *mut T, slice.len());
                 mapped_slice.copy_from_slice(slice);
             }
         } else {
-            for (i, val) in self.iter_mut().enumerate().take(slice.len()) {
-                *val = slice[i];
+            for (val, src) in self.iter_mut().zip(slice) {
+                *val = *src;
             }
         }
     }
+
+    /// Copies the elements of `iter` into the mapped memory, stopping when either the iterator
+    /// or the memory is exhausted. Returns the number of elements written.
+    pub fn copy_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut count = 0;
+        for (val, src) in self.iter_mut().zip(iter) {
+            *val = src;
+            count += 1;
+        }
+        count
+    }
 }
 
 fn calc_padding(adr: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
@@ -47,6 +74,17 @@ fn calc_padding(adr: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
 }
 
 impl<T> Align<T> {
+    /// Wraps `size` bytes of mapped memory at `ptr`. `alignment` is the required offset alignment
+    /// of every element, for example `minUniformBufferOffsetAlignment` for dynamic uniform buffers.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to at least `size` bytes of host visible memory that stays mapped, and is
+    /// not accessed otherwise, for as long as the returned `Align` is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a multiple of `alignment`.
     pub unsafe fn new(ptr: *mut c_void, alignment: vk::DeviceSize, size: vk::DeviceSize) -> Self {
         let padding = calc_padding(size_of::<T>() as vk::DeviceSize, alignment);
         let elem_size = size_of::<T>() as vk::DeviceSize + padding;
@@ -59,7 +97,31 @@ impl<T> Align<T> {
         }
     }
 
-    pub fn iter_mut(&mut self) -> AlignIter<T> {
+    /// The distance in bytes between two consecutive elements, the size of `T` rounded up to the
+    /// alignment.
+    pub fn elem_size(&self) -> vk::DeviceSize {
+        self.elem_size
+    }
+
+    /// The number of elements that fit into the mapped memory. The last element doesn't need
+    /// any padding after it.
+    pub fn len(&self) -> usize {
+        let size_of_t = size_of::<T>() as vk::DeviceSize;
+        if self.size < size_of_t {
+            0
+        } else {
+            // Zero sized elements fit any number of times
+            (self.size - size_of_t)
+                .checked_div(self.elem_size)
+                .map_or(usize::MAX, |n| (n + 1) as usize)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter_mut(&mut self) -> AlignIter<'_, T> {
         AlignIter {
             current: 0,
             align: self,
@@ -70,7 +132,7 @@ impl<T> Align<T> {
 impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.align.size {
+        if self.current + size_of::<T>() as vk::DeviceSize > self.align.size {
             return None;
         }
         unsafe {
@@ -82,10 +144,11 @@ impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
     }
 }
 
-/// Decode SPIR-V from bytes.
+/// Decode SPIR-V from a reader, which is read from its current position to its end. Seek to
+/// the start of the SPIR-V first if the reader was used before.
 ///
 /// This function handles SPIR-V of arbitrary endianness gracefully, and returns correctly aligned
-/// storage.
+/// storage. The reader doesn't need to implement `Seek`, so pipes and decompressors work too.
 ///
 /// # Examples
 /// ```no_run
@@ -99,40 +162,1701 @@ impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
 ///     // ...
 /// #   0x03, 0x02, 0x23, 0x07,
 /// ];
-/// let words = ash::util::read_spv(&mut std::io::Cursor::new(&SPIRV[..])).unwrap();
+/// let words = ash::util::read_spv(&mut &SPIRV[..]).unwrap();
+/// ```
+pub fn read_spv<R: io::Read>(x: &mut R) -> io::Result<Vec<u32>> {
+    let mut bytes = Vec::new();
+    x.read_to_end(&mut bytes)?;
+    read_spv_bytes(&bytes)
+}
+
+/// Decode SPIR-V from a byte slice, for example the output of `include_bytes!`.
+///
+/// The bytes don't need to be aligned to 4, they are copied into correctly aligned storage.
+/// [`read_spv`] decodes through this after reading its input.
+///
+/// # Examples
+/// ```
+/// // Usually `include_bytes!("shader.spv")`
+/// const SPIRV: &[u8] = &[
+///     // ...
+/// #   0x03, 0x02, 0x23, 0x07,
+/// ];
+/// let words = ash::util::read_spv_bytes(SPIRV).unwrap();
 /// ```
-pub fn read_spv<R: io::Read + io::Seek>(x: &mut R) -> io::Result<Vec<u32>> {
-    let size = x.seek(io::SeekFrom::End(0))?;
-    if size % 4 != 0 {
+pub fn read_spv_bytes(bytes: &[u8]) -> io::Result<Vec<u32>> {
+    let words = bytes.chunks_exact(4);
+    if !words.remainder().is_empty() {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "input length not divisible by 4",
         ));
     }
-    if size > usize::max_value() as u64 {
-        return Err(io::Error::new(io::ErrorKind::InvalidData, "input too long"));
-    }
-    let words = (size / 4) as usize;
-    let mut result = Vec::<u32>::with_capacity(words);
-    x.seek(io::SeekFrom::Start(0))?;
-    unsafe {
-        x.read_exact(slice::from_raw_parts_mut(
-            result.as_mut_ptr() as *mut u8,
-            words * 4,
-        ))?;
-        result.set_len(words);
-    }
+    let result = words
+        .map(|word| u32::from_ne_bytes([word[0], word[1], word[2], word[3]]))
+        .collect();
+    fix_spv_endianness(result)
+}
+
+/// Swaps the words of `words` if it was written with the opposite endianness, and checks for
+/// the magic number.
+fn fix_spv_endianness(mut words: Vec<u32>) -> io::Result<Vec<u32>> {
     const MAGIC_NUMBER: u32 = 0x0723_0203;
-    if !result.is_empty() && result[0] == MAGIC_NUMBER.swap_bytes() {
-        for word in &mut result {
+    if !words.is_empty() && words[0] == MAGIC_NUMBER.swap_bytes() {
+        for word in &mut words {
             *word = word.swap_bytes();
         }
     }
-    if result.is_empty() || result[0] != MAGIC_NUMBER {
+    if words.is_empty() || words[0] != MAGIC_NUMBER {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "input missing SPIR-V magic number",
         ));
     }
-    Ok(result)
+    Ok(words)
+}
+
+/// The names of the `OpEntryPoint` instructions of a SPIR-V module, as returned by
+/// [`read_spv`].
+#[cfg(feature = "spirv-entry-points")]
+pub fn spirv_entry_points(words: &[u32]) -> io::Result<Vec<String>> {
+    const OP_ENTRY_POINT: u32 = 15;
+    let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut names = Vec::new();
+    // Instructions follow the header of 5 words
+    let mut rest = words.get(5..).unwrap_or(&[]);
+    while let Some(&first) = rest.first() {
+        let word_count = (first >> 16) as usize;
+        if word_count == 0 || word_count > rest.len() {
+            return Err(invalid("SPIR-V instruction with an invalid word count"));
+        }
+        let (instruction, next) = rest.split_at(word_count);
+        if first & 0xffff == OP_ENTRY_POINT {
+            // Execution model and id, then the nul terminated name
+            let bytes: Vec<u8> = instruction
+                .get(3..)
+                .unwrap_or(&[])
+                .iter()
+                .flat_map(|word| word.to_le_bytes().to_vec())
+                .take_while(|&byte| byte != 0)
+                .collect();
+            let name = String::from_utf8(bytes)
+                .map_err(|_| invalid("SPIR-V entry point name isn't UTF-8"))?;
+            names.push(name);
+        }
+        rest = next;
+    }
+    Ok(names)
+}
+
+/// Owns a `PhysicalDeviceFeatures2` chain together with the per-version feature structs.
+///
+/// The same chain can be filled by `vkGetPhysicalDeviceFeatures2`, edited and then passed on
+/// to `DeviceCreateInfo`. The `p_next` pointers are relinked every time the chain is borrowed
+/// through [`FeatureChain::chain`], so moving a `FeatureChain` never leaves them dangling.
+///
+/// ```no_run
+/// # use ash::{util::FeatureChain, version::InstanceV1_1, vk};
+/// # unsafe fn create(instance: &ash::Instance, physical_device: vk::PhysicalDevice) {
+/// let mut features = FeatureChain::query(instance, physical_device);
+/// features.vulkan_12.timeline_semaphore = vk::TRUE;
+/// let create_info = vk::DeviceCreateInfo::builder().push_next(features.chain());
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FeatureChain {
+    pub features2: vk::PhysicalDeviceFeatures2,
+    pub vulkan_11: vk::PhysicalDeviceVulkan11Features,
+    pub vulkan_12: vk::PhysicalDeviceVulkan12Features,
+}
+
+impl FeatureChain {
+    /// Queries the features supported by `physical_device`, which must support Vulkan 1.2.
+    pub unsafe fn query<I: InstanceV1_1>(
+        instance: &I,
+        physical_device: vk::PhysicalDevice,
+    ) -> Self {
+        let mut chain = FeatureChain::default();
+        instance.get_physical_device_features2(physical_device, chain.chain());
+        chain
+    }
+
+    /// The Vulkan 1.0 features, stored inline in `PhysicalDeviceFeatures2`.
+    pub fn vulkan_10(&mut self) -> &mut vk::PhysicalDeviceFeatures {
+        &mut self.features2.features
+    }
+
+    /// Links the chain `features2 -> vulkan_11 -> vulkan_12` and returns its head.
+    pub fn chain(&mut self) -> &mut vk::PhysicalDeviceFeatures2 {
+        self.vulkan_12.p_next = ptr::null_mut();
+        self.vulkan_11.p_next = &mut self.vulkan_12 as *mut _ as *mut c_void;
+        self.features2.p_next = &mut self.vulkan_11 as *mut _ as *mut c_void;
+        &mut self.features2
+    }
+
+    /// The names of the feature structs in which `required` enables a feature that `self`
+    /// doesn't, e.g. `["PhysicalDeviceVulkan12Features"]`.
+    pub fn missing_features(&self, required: &FeatureChain) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        if !self
+            .features2
+            .features
+            .missing_features(&required.features2.features)
+            .is_empty()
+        {
+            names.push("PhysicalDeviceFeatures");
+        }
+        if !self
+            .vulkan_11
+            .missing_features(&required.vulkan_11)
+            .is_empty()
+        {
+            names.push("PhysicalDeviceVulkan11Features");
+        }
+        if !self
+            .vulkan_12
+            .missing_features(&required.vulkan_12)
+            .is_empty()
+        {
+            names.push("PhysicalDeviceVulkan12Features");
+        }
+        names
+    }
+}
+
+/// The index of the first memory type that is allowed by `type_bits`, usually
+/// `MemoryRequirements::memory_type_bits`, and has all of `required_flags`.
+pub fn find_memory_type_index(
+    memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    type_bits: u32,
+    required_flags: vk::MemoryPropertyFlags,
+) -> Option<u32> {
+    memory_properties.memory_types[..memory_properties.memory_type_count as usize]
+        .iter()
+        .enumerate()
+        .find(|(index, memory_type)| {
+            type_bits & (1 << index) != 0 && memory_type.property_flags.contains(required_flags)
+        })
+        .map(|(index, _)| index as u32)
+}
+
+/// Like [`find_memory_type_index`], but picks a memory type that also has `preferred_flags` if
+/// there is one.
+pub fn find_memory_type_index_preferring(
+    memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    type_bits: u32,
+    required_flags: vk::MemoryPropertyFlags,
+    preferred_flags: vk::MemoryPropertyFlags,
+) -> Option<u32> {
+    find_memory_type_index(
+        memory_properties,
+        type_bits,
+        required_flags | preferred_flags,
+    )
+    .or_else(|| find_memory_type_index(memory_properties, type_bits, required_flags))
+}
+
+/// A memory type for resources that only the device accesses, `DEVICE_LOCAL` if one is allowed.
+pub fn find_device_local_memory_type_index(
+    memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    type_bits: u32,
+) -> Option<u32> {
+    find_memory_type_index_preferring(
+        memory_properties,
+        type_bits,
+        vk::MemoryPropertyFlags::empty(),
+        vk::MemoryPropertyFlags::DEVICE_LOCAL,
+    )
+}
+
+/// A `HOST_VISIBLE` memory type for resources that the host maps, `HOST_COHERENT` if one is
+/// allowed, which doesn't need [`MappedMemory::flush`].
+pub fn find_host_visible_memory_type_index(
+    memory_properties: &vk::PhysicalDeviceMemoryProperties,
+    type_bits: u32,
+) -> Option<u32> {
+    find_memory_type_index_preferring(
+        memory_properties,
+        type_bits,
+        vk::MemoryPropertyFlags::HOST_VISIBLE,
+        vk::MemoryPropertyFlags::HOST_COHERENT,
+    )
+}
+
+/// Where a [`DeviceMemoryAllocator`] places an allocation, named after how the memory is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryLocation {
+    /// Memory that only the device accesses, `DEVICE_LOCAL` if possible.
+    GpuOnly,
+    /// Mapped memory that the host writes and the device reads, such as staging buffers and
+    /// uniforms.
+    CpuToGpu,
+    /// Mapped memory that the device writes and the host reads back.
+    GpuToCpu,
+}
+
+impl MemoryLocation {
+    pub fn required_flags(self) -> vk::MemoryPropertyFlags {
+        match self {
+            MemoryLocation::GpuOnly => vk::MemoryPropertyFlags::empty(),
+            MemoryLocation::CpuToGpu => {
+                vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT
+            }
+            MemoryLocation::GpuToCpu => vk::MemoryPropertyFlags::HOST_VISIBLE,
+        }
+    }
+
+    pub fn preferred_flags(self) -> vk::MemoryPropertyFlags {
+        match self {
+            MemoryLocation::GpuOnly | MemoryLocation::CpuToGpu => {
+                vk::MemoryPropertyFlags::DEVICE_LOCAL
+            }
+            MemoryLocation::GpuToCpu => {
+                vk::MemoryPropertyFlags::HOST_CACHED | vk::MemoryPropertyFlags::HOST_COHERENT
+            }
+        }
+    }
+
+    pub fn is_mapped(self) -> bool {
+        self != MemoryLocation::GpuOnly
+    }
+
+    /// The memory type for the location, see [`find_memory_type_index_preferring`].
+    pub fn memory_type_index(
+        self,
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        type_bits: u32,
+    ) -> Option<u32> {
+        find_memory_type_index_preferring(
+            memory_properties,
+            type_bits,
+            self.required_flags(),
+            self.preferred_flags(),
+        )
+    }
+}
+
+/// A block of device memory handed out by a [`DeviceMemoryAllocator`].
+pub trait DeviceAllocation {
+    fn memory(&self) -> vk::DeviceMemory;
+
+    /// The offset of the allocation in [`DeviceAllocation::memory`], which allocators that
+    /// suballocate bind resources at.
+    fn offset(&self) -> vk::DeviceSize;
+
+    fn size(&self) -> vk::DeviceSize;
+
+    /// The host address of the allocation if its location is mapped.
+    fn mapped_ptr(&self) -> Option<ptr::NonNull<c_void>>;
+}
+
+/// Allocates the memory of buffers and images, so helpers such as [`create_buffer_with_memory`]
+/// work with whatever allocator the application uses. [`DedicatedAllocator`] is a simple
+/// implementation, suballocating allocators such as gpu-allocator or vk-mem are wrapped by
+/// implementing the trait for a newtype that forwards to them.
+pub trait DeviceMemoryAllocator {
+    type Allocation: DeviceAllocation;
+
+    /// Allocates memory meeting `requirements` at `location`. `name` is a debug name for the
+    /// allocation, which allocators may ignore.
+    unsafe fn allocate(
+        &mut self,
+        requirements: &vk::MemoryRequirements,
+        location: MemoryLocation,
+        name: &str,
+    ) -> VkResult<Self::Allocation>;
+
+    /// Frees `allocation`, which must have been returned by [`DeviceMemoryAllocator::allocate`]
+    /// of this allocator and must no longer be in use by the device.
+    unsafe fn free(&mut self, allocation: Self::Allocation);
+
+    /// Binds `buffer` to the memory of `allocation` at its offset. The allocation must meet
+    /// the memory requirements of `buffer`.
+    unsafe fn bind_buffer_memory(
+        &self,
+        buffer: vk::Buffer,
+        allocation: &Self::Allocation,
+    ) -> VkResult<()>;
+
+    /// Binds `image` to the memory of `allocation` at its offset. The allocation must meet
+    /// the memory requirements of `image`.
+    unsafe fn bind_image_memory(
+        &self,
+        image: vk::Image,
+        allocation: &Self::Allocation,
+    ) -> VkResult<()>;
+}
+
+/// An allocation of [`DedicatedAllocator`], which owns its `DeviceMemory`.
+#[derive(Debug)]
+pub struct DedicatedAllocation {
+    memory: vk::DeviceMemory,
+    size: vk::DeviceSize,
+    memory_type_index: u32,
+    mapped_ptr: Option<ptr::NonNull<c_void>>,
+}
+
+impl DedicatedAllocation {
+    pub fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+}
+
+impl DeviceAllocation for DedicatedAllocation {
+    fn memory(&self) -> vk::DeviceMemory {
+        self.memory
+    }
+
+    fn offset(&self) -> vk::DeviceSize {
+        0
+    }
+
+    fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    fn mapped_ptr(&self) -> Option<ptr::NonNull<c_void>> {
+        self.mapped_ptr
+    }
+}
+
+/// A [`DeviceMemoryAllocator`] making a `vkAllocateMemory` call for every allocation and mapping
+/// the mapped locations for the lifetime of the allocation. Devices limit the number of
+/// allocations, see `PhysicalDeviceLimits::max_memory_allocation_count`, so this suits a few
+/// large resources rather than many small ones.
+pub struct DedicatedAllocator<'a, D: DeviceV1_0> {
+    device: &'a D,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+}
+
+impl<'a, D: DeviceV1_0> DedicatedAllocator<'a, D> {
+    pub fn new(device: &'a D, memory_properties: vk::PhysicalDeviceMemoryProperties) -> Self {
+        DedicatedAllocator {
+            device,
+            memory_properties,
+        }
+    }
+}
+
+impl<'a, D: DeviceV1_0> DeviceMemoryAllocator for DedicatedAllocator<'a, D> {
+    type Allocation = DedicatedAllocation;
+
+    unsafe fn allocate(
+        &mut self,
+        requirements: &vk::MemoryRequirements,
+        location: MemoryLocation,
+        _name: &str,
+    ) -> VkResult<DedicatedAllocation> {
+        let memory_type_index = location
+            .memory_type_index(&self.memory_properties, requirements.memory_type_bits)
+            .ok_or(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY)?;
+        let allocate_info = vk::MemoryAllocateInfo::builder()
+            .allocation_size(requirements.size)
+            .memory_type_index(memory_type_index);
+        let memory = self.device.allocate_memory(&allocate_info, None)?;
+        let mapped_ptr = if location.is_mapped() {
+            match self
+                .device
+                .map_memory(memory, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())
+            {
+                Ok(ptr) => ptr::NonNull::new(ptr),
+                Err(err) => {
+                    self.device.free_memory(memory, None);
+                    return Err(err);
+                }
+            }
+        } else {
+            None
+        };
+        Ok(DedicatedAllocation {
+            memory,
+            size: requirements.size,
+            memory_type_index,
+            mapped_ptr,
+        })
+    }
+
+    unsafe fn free(&mut self, allocation: DedicatedAllocation) {
+        // Freeing mapped memory unmaps it
+        self.device.free_memory(allocation.memory, None);
+    }
+
+    unsafe fn bind_buffer_memory(
+        &self,
+        buffer: vk::Buffer,
+        allocation: &DedicatedAllocation,
+    ) -> VkResult<()> {
+        self.device.bind_buffer_memory(buffer, allocation.memory, 0)
+    }
+
+    unsafe fn bind_image_memory(
+        &self,
+        image: vk::Image,
+        allocation: &DedicatedAllocation,
+    ) -> VkResult<()> {
+        self.device.bind_image_memory(image, allocation.memory, 0)
+    }
+}
+
+/// Creates a buffer and binds it to memory at `location` from `allocator`. Nothing is leaked
+/// if a step fails.
+pub unsafe fn create_buffer_with_memory<D: DeviceV1_0, A: DeviceMemoryAllocator>(
+    device: &D,
+    allocator: &mut A,
+    create_info: &vk::BufferCreateInfo,
+    location: MemoryLocation,
+    name: &str,
+) -> VkResult<(vk::Buffer, A::Allocation)> {
+    let buffer = device.create_buffer(create_info, None)?;
+    let requirements = device.get_buffer_memory_requirements(buffer);
+    let bound = allocator
+        .allocate(&requirements, location, name)
+        .and_then(
+            |allocation| match allocator.bind_buffer_memory(buffer, &allocation) {
+                Ok(()) => Ok(allocation),
+                Err(err) => {
+                    allocator.free(allocation);
+                    Err(err)
+                }
+            },
+        );
+    match bound {
+        Ok(allocation) => Ok((buffer, allocation)),
+        Err(err) => {
+            device.destroy_buffer(buffer, None);
+            Err(err)
+        }
+    }
+}
+
+/// Creates an image and binds it to memory at `location` from `allocator`. Nothing is leaked
+/// if a step fails.
+pub unsafe fn create_image_with_memory<D: DeviceV1_0, A: DeviceMemoryAllocator>(
+    device: &D,
+    allocator: &mut A,
+    create_info: &vk::ImageCreateInfo,
+    location: MemoryLocation,
+    name: &str,
+) -> VkResult<(vk::Image, A::Allocation)> {
+    let image = device.create_image(create_info, None)?;
+    let requirements = device.get_image_memory_requirements(image);
+    let bound = allocator
+        .allocate(&requirements, location, name)
+        .and_then(
+            |allocation| match allocator.bind_image_memory(image, &allocation) {
+                Ok(()) => Ok(allocation),
+                Err(err) => {
+                    allocator.free(allocation);
+                    Err(err)
+                }
+            },
+        );
+    match bound {
+        Ok(allocation) => Ok((image, allocation)),
+        Err(err) => {
+            device.destroy_image(image, None);
+            Err(err)
+        }
+    }
+}
+
+/// The capabilities that [`plan_queue_families`] looks for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueueFamilyRequest {
+    pub graphics: bool,
+    pub compute: bool,
+    pub transfer: bool,
+    /// Presenting to a surface, which is checked by the `supports_present` callback of
+    /// [`plan_queue_families`].
+    pub present: bool,
+}
+
+/// The queue family index for each requested capability, `None` if it wasn't requested.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueueFamilyPlan {
+    pub graphics: Option<u32>,
+    pub compute: Option<u32>,
+    pub transfer: Option<u32>,
+    pub present: Option<u32>,
+}
+
+impl QueueFamilyPlan {
+    /// The distinct queue families of the plan in ascending order, to create one
+    /// `DeviceQueueCreateInfo` for each.
+    pub fn unique_families(&self) -> Vec<u32> {
+        let mut families: Vec<u32> = [self.graphics, self.compute, self.transfer, self.present]
+            .iter()
+            .filter_map(|&family| family)
+            .collect();
+        families.sort_unstable();
+        families.dedup();
+        families
+    }
+}
+
+/// The commands whose queues in [`vk::meta::command_info`] decide which families can do the
+/// graphics, compute and transfer work of a [`QueueFamilyRequest`].
+const GRAPHICS_COMMAND: &str = "vkCmdDraw";
+const COMPUTE_COMMAND: &str = "vkCmdDispatch";
+const TRANSFER_COMMAND: &str = "vkCmdCopyBuffer";
+
+/// Picks a queue family from `families`, as returned by
+/// `get_physical_device_queue_family_properties`, for each capability of `request`.
+/// `supports_present` is called with a queue family index, usually to check
+/// `get_physical_device_surface_support`. Returns `None` if a requested capability isn't
+/// supported by any family.
+///
+/// Which families support graphics, compute and transfers comes from the queues of
+/// `vkCmdDraw`, `vkCmdDispatch` and `vkCmdCopyBuffer` in the registry, so `GRAPHICS` and
+/// `COMPUTE` families support transfers even if they don't report `TRANSFER`. Compute and
+/// transfer prefer dedicated families, which run asynchronously to graphics: a compute family
+/// without graphics, and a transfer family with neither graphics nor compute. Without them they
+/// share a family with the other work. Presentation prefers the graphics family.
+pub fn plan_queue_families<F: FnMut(u32) -> bool>(
+    families: &[vk::QueueFamilyProperties],
+    request: QueueFamilyRequest,
+    mut supports_present: F,
+) -> Option<QueueFamilyPlan> {
+    let supports = |family: &vk::QueueFamilyProperties, command: &str| {
+        vk::meta::command_info(command).is_some_and(|info| info.supports_queue(family.queue_flags))
+    };
+    let find = |command: &str, excluded: &[&str]| {
+        families
+            .iter()
+            .position(|family| {
+                family.queue_count > 0
+                    && supports(family, command)
+                    && !excluded.iter().any(|&excluded| supports(family, excluded))
+            })
+            .map(|index| index as u32)
+    };
+    let graphics_family = find(GRAPHICS_COMMAND, &[]);
+    let mut plan = QueueFamilyPlan::default();
+    if request.graphics {
+        plan.graphics = Some(graphics_family?);
+    }
+    if request.compute {
+        plan.compute = Some(
+            find(COMPUTE_COMMAND, &[GRAPHICS_COMMAND]).or_else(|| find(COMPUTE_COMMAND, &[]))?,
+        );
+    }
+    if request.transfer {
+        plan.transfer = Some(
+            find(TRANSFER_COMMAND, &[GRAPHICS_COMMAND, COMPUTE_COMMAND])
+                .or_else(|| find(TRANSFER_COMMAND, &[GRAPHICS_COMMAND]))
+                .or_else(|| find(TRANSFER_COMMAND, &[]))?,
+        );
+    }
+    if request.present {
+        let graphics_presents = match graphics_family {
+            Some(family) => supports_present(family),
+            None => false,
+        };
+        plan.present = if graphics_presents {
+            graphics_family
+        } else {
+            (0..families.len() as u32)
+                .find(|&index| families[index as usize].queue_count > 0 && supports_present(index))
+        };
+        plan.present?;
+    }
+    Some(plan)
+}
+
+/// What [`select_physical_device`] requires of a physical device.
+#[derive(Clone, Copy, Default)]
+pub struct PhysicalDeviceRequirements<'a> {
+    pub extensions: &'a [&'a CStr],
+    /// Features that must be supported. Only the Vulkan 1.0 features are queried on devices
+    /// before Vulkan 1.2, which are rejected if Vulkan 1.1 or 1.2 features are required.
+    pub features: Option<&'a FeatureChain>,
+    pub queues: QueueFamilyRequest,
+    /// The surface to present to if `queues.present` is requested.
+    pub surface: Option<(&'a Surface, vk::SurfaceKHR)>,
+}
+
+/// Why [`select_physical_device`] rejected a physical device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PhysicalDeviceRejection {
+    MissingExtensions(Vec<CString>),
+    /// The feature structs with unsupported features, see [`FeatureChain::missing_features`].
+    MissingFeatures(Vec<&'static str>),
+    /// No queue family supports one of the requested capabilities.
+    MissingQueueFamilies,
+    /// Another device meets the requirements with a higher score.
+    LowerScore,
+    VkError(vk::Result),
+}
+
+/// A physical device picked by [`select_physical_device`].
+#[derive(Clone, Copy, Debug)]
+pub struct SelectedPhysicalDevice {
+    pub physical_device: vk::PhysicalDevice,
+    pub properties: vk::PhysicalDeviceProperties,
+    pub queue_families: QueueFamilyPlan,
+    pub score: u32,
+}
+
+/// The result of [`select_physical_device`], with the reasons why the other devices were
+/// rejected.
+#[derive(Clone, Debug)]
+pub struct PhysicalDeviceSelection {
+    pub selected: Option<SelectedPhysicalDevice>,
+    pub rejected: Vec<(vk::PhysicalDevice, PhysicalDeviceRejection)>,
+}
+
+/// Scores discrete GPUs above integrated, virtual and CPU devices, for
+/// [`select_physical_device`].
+pub fn device_type_score(properties: &vk::PhysicalDeviceProperties) -> u32 {
+    match properties.device_type {
+        vk::PhysicalDeviceType::DISCRETE_GPU => 4,
+        vk::PhysicalDeviceType::INTEGRATED_GPU => 3,
+        vk::PhysicalDeviceType::VIRTUAL_GPU => 2,
+        vk::PhysicalDeviceType::CPU => 1,
+        _ => 0,
+    }
+}
+
+/// Picks the physical device that meets `requirements` with the highest `score`, for
+/// instance [`device_type_score`]. The first device wins ties.
+///
+/// ```no_run
+/// # use ash::{extensions::khr::{Surface, Swapchain}, util::*, vk};
+/// # unsafe fn select(
+/// #     instance: &ash::Instance,
+/// #     surface_loader: &Surface,
+/// #     surface: vk::SurfaceKHR,
+/// # ) -> ash::prelude::VkResult<()> {
+/// let requirements = PhysicalDeviceRequirements {
+///     extensions: &[Swapchain::name()],
+///     queues: QueueFamilyRequest {
+///         graphics: true,
+///         present: true,
+///         ..Default::default()
+///     },
+///     surface: Some((surface_loader, surface)),
+///     ..Default::default()
+/// };
+/// let selection = select_physical_device(instance, &requirements, |_, properties| {
+///     device_type_score(properties)
+/// })?;
+/// for (physical_device, reason) in &selection.rejected {
+///     eprintln!("Rejected {:?}: {:?}", physical_device, reason);
+/// }
+/// # Ok(()) }
+/// ```
+pub unsafe fn select_physical_device<I, F>(
+    instance: &I,
+    requirements: &PhysicalDeviceRequirements,
+    mut score: F,
+) -> VkResult<PhysicalDeviceSelection>
+where
+    I: InstanceV1_1,
+    F: FnMut(vk::PhysicalDevice, &vk::PhysicalDeviceProperties) -> u32,
+{
+    let mut selection = PhysicalDeviceSelection {
+        selected: None,
+        rejected: Vec::new(),
+    };
+    for physical_device in instance.enumerate_physical_devices()? {
+        match check_physical_device(instance, physical_device, requirements) {
+            Ok((properties, queue_families)) => {
+                let device_score = score(physical_device, &properties);
+                if selection
+                    .selected
+                    .is_none_or(|best| device_score > best.score)
+                {
+                    if let Some(best) = selection.selected {
+                        selection
+                            .rejected
+                            .push((best.physical_device, PhysicalDeviceRejection::LowerScore));
+                    }
+                    selection.selected = Some(SelectedPhysicalDevice {
+                        physical_device,
+                        properties,
+                        queue_families,
+                        score: device_score,
+                    });
+                } else {
+                    selection
+                        .rejected
+                        .push((physical_device, PhysicalDeviceRejection::LowerScore));
+                }
+            }
+            Err(rejection) => selection.rejected.push((physical_device, rejection)),
+        }
+    }
+    Ok(selection)
+}
+
+unsafe fn check_physical_device<I: InstanceV1_1>(
+    instance: &I,
+    physical_device: vk::PhysicalDevice,
+    requirements: &PhysicalDeviceRequirements,
+) -> Result<(vk::PhysicalDeviceProperties, QueueFamilyPlan), PhysicalDeviceRejection> {
+    let properties = instance.get_physical_device_properties(physical_device);
+
+    let available = instance
+        .enumerate_device_extension_properties(physical_device)
+        .map_err(PhysicalDeviceRejection::VkError)?;
+    let missing_extensions: Vec<CString> = requirements
+        .extensions
+        .iter()
+        .filter(|&&name| {
+            !available
+                .iter()
+                .any(|extension| extension.extension_name_as_c_str() == Ok(name))
+        })
+        .map(|&name| name.to_owned())
+        .collect();
+    if !missing_extensions.is_empty() {
+        return Err(PhysicalDeviceRejection::MissingExtensions(
+            missing_extensions,
+        ));
+    }
+
+    if let Some(required) = requirements.features {
+        // `PhysicalDeviceVulkan11Features` and `PhysicalDeviceVulkan12Features` can't be
+        // queried before Vulkan 1.2, and count as unsupported
+        let supported = if properties.api_version < vk::make_version(1, 2, 0) {
+            let mut supported = FeatureChain::default();
+            *supported.vulkan_10() = instance.get_physical_device_features(physical_device);
+            supported
+        } else {
+            FeatureChain::query(instance, physical_device)
+        };
+        let missing = supported.missing_features(required);
+        if !missing.is_empty() {
+            return Err(PhysicalDeviceRejection::MissingFeatures(missing));
+        }
+    }
+
+    let families = instance.get_physical_device_queue_family_properties(physical_device);
+    let queue_families = plan_queue_families(&families, requirements.queues, |index| {
+        requirements
+            .surface
+            .is_some_and(|(surface_loader, surface)| {
+                surface_loader
+                    .get_physical_device_surface_support(physical_device, index, surface)
+                    .unwrap_or(false)
+            })
+    })
+    .ok_or(PhysicalDeviceRejection::MissingQueueFamilies)?;
+    Ok((properties, queue_families))
+}
+
+/// A mapped range of `DeviceMemory` that is unmapped when dropped.
+///
+/// The mapping is exposed as length checked slices, and [`MappedMemory::flush`] and
+/// [`MappedMemory::invalidate`] round their ranges to `nonCoherentAtomSize` as required for
+/// memory that isn't `HOST_COHERENT`.
+pub struct MappedMemory<'a, D: DeviceV1_0> {
+    device: &'a D,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    non_coherent_atom_size: vk::DeviceSize,
+    ptr: *mut u8,
+}
+
+impl<'a, D: DeviceV1_0> MappedMemory<'a, D> {
+    /// Maps `size` bytes of `memory` starting at `offset`. `non_coherent_atom_size` is
+    /// `PhysicalDeviceLimits::non_coherent_atom_size`, and panics if `offset` isn't a multiple
+    /// of it, as the ranges of [`MappedMemory::flush`] couldn't be aligned then.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be host visible, not already mapped, and `offset..offset + size` must lie
+    /// within the allocation. `size` can't be `vk::WHOLE_SIZE`, as the size of the allocation
+    /// isn't known here.
+    pub unsafe fn map(
+        device: &'a D,
+        memory: vk::DeviceMemory,
+        offset: vk::DeviceSize,
+        size: vk::DeviceSize,
+        non_coherent_atom_size: vk::DeviceSize,
+    ) -> VkResult<Self> {
+        assert!(size != vk::WHOLE_SIZE, "size must be explicit");
+        let non_coherent_atom_size = non_coherent_atom_size.max(1);
+        assert_eq!(
+            offset % non_coherent_atom_size,
+            0,
+            "offset must be a multiple of nonCoherentAtomSize"
+        );
+        assert!(
+            size <= usize::MAX as vk::DeviceSize,
+            "mapping doesn't fit into the address space"
+        );
+        let ptr = device.map_memory(memory, offset, size, vk::MemoryMapFlags::empty())?;
+        Ok(MappedMemory {
+            device,
+            memory,
+            offset,
+            size,
+            non_coherent_atom_size,
+            ptr: ptr as *mut u8,
+        })
+    }
+
+    pub fn memory(&self) -> vk::DeviceMemory {
+        self.memory
+    }
+
+    pub fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.size as usize) }
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.size as usize) }
+    }
+
+    /// Views the mapping as a slice of `T`, trailing bytes that don't fill a whole `T` are left
+    /// out. Returns `None` if the mapping is not aligned for `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every bit pattern in the mapping has to be a valid `T`.
+    pub unsafe fn as_slice_mut<T: Copy>(&mut self) -> Option<&mut [T]> {
+        if size_of::<T>() == 0 || self.ptr.align_offset(std::mem::align_of::<T>()) != 0 {
+            return None;
+        }
+        let len = self.size as usize / size_of::<T>();
+        Some(slice::from_raw_parts_mut(self.ptr as *mut T, len))
+    }
+
+    /// Copies `data` to the start of the mapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is larger than the mapping.
+    pub fn write<T: Copy>(&mut self, data: &[T]) {
+        let len = std::mem::size_of_val(data);
+        assert!(
+            len as vk::DeviceSize <= self.size,
+            "{} bytes don't fit into a mapping of {} bytes",
+            len,
+            self.size
+        );
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr() as *const u8, self.ptr, len) };
+    }
+
+    fn range(&self, offset: vk::DeviceSize, size: vk::DeviceSize) -> vk::MappedMemoryRange {
+        let (offset, size) = atom_aligned_range(
+            self.offset,
+            self.size,
+            offset,
+            size,
+            self.non_coherent_atom_size,
+        );
+        vk::MappedMemoryRange::builder()
+            .memory(self.memory)
+            .offset(offset)
+            .size(size)
+            .build()
+    }
+
+    /// Flushes `size` bytes at `offset`, relative to the start of the mapping, making host
+    /// writes visible to the device.
+    pub unsafe fn flush(&self, offset: vk::DeviceSize, size: vk::DeviceSize) -> VkResult<()> {
+        self.device
+            .flush_mapped_memory_ranges(&[self.range(offset, size)])
+    }
+
+    /// Invalidates `size` bytes at `offset`, relative to the start of the mapping, making device
+    /// writes visible to the host.
+    pub unsafe fn invalidate(&self, offset: vk::DeviceSize, size: vk::DeviceSize) -> VkResult<()> {
+        self.device
+            .invalidate_mapped_memory_ranges(&[self.range(offset, size)])
+    }
+}
+
+impl<'a, D: DeviceV1_0> Drop for MappedMemory<'a, D> {
+    fn drop(&mut self) {
+        unsafe { self.device.unmap_memory(self.memory) };
+    }
+}
+
+/// Records command buffers that are submitted once and waited for, such as the copies out of
+/// staging buffers, in a command pool and submitted to a queue of its queue family.
+///
+/// ```no_run
+/// # use ash::{util::OneTimeSubmit, version::DeviceV1_0, vk};
+/// # unsafe fn upload(
+/// #     device: &ash::Device,
+/// #     command_pool: vk::CommandPool,
+/// #     queue: vk::Queue,
+/// #     staging: vk::Buffer,
+/// #     buffer: vk::Buffer,
+/// #     size: vk::DeviceSize,
+/// # ) -> ash::prelude::VkResult<()> {
+/// OneTimeSubmit::new(device, command_pool, queue).submit(|device, command_buffer| {
+///     let region = vk::BufferCopy::builder().size(size);
+///     device.cmd_copy_buffer(command_buffer, staging, buffer, &[region.build()]);
+/// })
+/// # }
+/// ```
+pub struct OneTimeSubmit<'a, D: DeviceV1_0> {
+    device: &'a D,
+    command_pool: vk::CommandPool,
+    queue: vk::Queue,
+}
+
+impl<'a, D: DeviceV1_0> OneTimeSubmit<'a, D> {
+    /// `command_pool` should be created with `TRANSIENT`, for the queue family of `queue`.
+    pub fn new(device: &'a D, command_pool: vk::CommandPool, queue: vk::Queue) -> Self {
+        OneTimeSubmit {
+            device,
+            command_pool,
+            queue,
+        }
+    }
+
+    pub fn command_pool(&self) -> vk::CommandPool {
+        self.command_pool
+    }
+
+    pub fn queue(&self) -> vk::Queue {
+        self.queue
+    }
+
+    /// Allocates a primary command buffer, records `f` into it, submits it and waits until it
+    /// has executed. The command buffer and the fence are freed again, also if one of the
+    /// steps fails.
+    ///
+    /// # Safety
+    ///
+    /// The command pool and the queue must not be used by other threads meanwhile.
+    pub unsafe fn submit<R, F: FnOnce(&D, vk::CommandBuffer) -> R>(&self, f: F) -> VkResult<R> {
+        let allocate_info = vk::CommandBufferAllocateInfo::builder()
+            .command_pool(self.command_pool)
+            .level(vk::CommandBufferLevel::PRIMARY)
+            .command_buffer_count(1);
+        let command_buffer = self.device.allocate_command_buffers(&allocate_info)?[0];
+        let result = self.record_and_wait(command_buffer, f);
+        self.device
+            .free_command_buffers(self.command_pool, &[command_buffer]);
+        result
+    }
+
+    unsafe fn record_and_wait<R, F: FnOnce(&D, vk::CommandBuffer) -> R>(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        f: F,
+    ) -> VkResult<R> {
+        let begin_info = vk::CommandBufferBeginInfo::builder()
+            .flags(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT);
+        self.device
+            .begin_command_buffer(command_buffer, &begin_info)?;
+        let value = f(self.device, command_buffer);
+        self.device.end_command_buffer(command_buffer)?;
+
+        let fence = self
+            .device
+            .create_fence(&vk::FenceCreateInfo::default(), None)?;
+        let command_buffers = [command_buffer];
+        let submit_info = vk::SubmitInfo::builder().command_buffers(&command_buffers);
+        let result = self
+            .device
+            .queue_submit(self.queue, &[submit_info.build()], fence)
+            .and_then(|()| self.device.wait_for_fences(&[fence], true, u64::MAX));
+        self.device.destroy_fence(fence, None);
+        result.map(|()| value)
+    }
+}
+
+/// The descriptor infos that a descriptor update template reads, for the descriptor types that
+/// [`DescriptorInfo::supports`].
+pub trait DescriptorInfo: Copy {
+    fn supports(descriptor_type: vk::DescriptorType) -> bool;
+}
+
+impl DescriptorInfo for vk::DescriptorImageInfo {
+    fn supports(descriptor_type: vk::DescriptorType) -> bool {
+        matches!(
+            descriptor_type,
+            vk::DescriptorType::SAMPLER
+                | vk::DescriptorType::COMBINED_IMAGE_SAMPLER
+                | vk::DescriptorType::SAMPLED_IMAGE
+                | vk::DescriptorType::STORAGE_IMAGE
+                | vk::DescriptorType::INPUT_ATTACHMENT
+        )
+    }
+}
+
+impl DescriptorInfo for vk::DescriptorBufferInfo {
+    fn supports(descriptor_type: vk::DescriptorType) -> bool {
+        matches!(
+            descriptor_type,
+            vk::DescriptorType::UNIFORM_BUFFER
+                | vk::DescriptorType::STORAGE_BUFFER
+                | vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC
+                | vk::DescriptorType::STORAGE_BUFFER_DYNAMIC
+        )
+    }
+}
+
+impl DescriptorInfo for vk::BufferView {
+    fn supports(descriptor_type: vk::DescriptorType) -> bool {
+        matches!(
+            descriptor_type,
+            vk::DescriptorType::UNIFORM_TEXEL_BUFFER | vk::DescriptorType::STORAGE_TEXEL_BUFFER
+        )
+    }
+}
+
+/// Builds the entries of a descriptor update template from the fields of `T`, a `#[repr(C)]`
+/// struct of descriptor infos, deriving their offsets and strides.
+///
+/// ```no_run
+/// # use ash::{util::DescriptorTemplateBuilder, vk};
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default)]
+/// struct MaterialDescriptors {
+///     uniforms: vk::DescriptorBufferInfo,
+///     textures: [vk::DescriptorImageInfo; 4],
+/// }
+///
+/// # unsafe fn create(
+/// #     device: &ash::Device,
+/// #     layout: vk::DescriptorSetLayout,
+/// #     set: vk::DescriptorSet,
+/// #     data: &MaterialDescriptors,
+/// # ) -> ash::prelude::VkResult<()> {
+/// let template = DescriptorTemplateBuilder::<MaterialDescriptors>::new()
+///     .entry(0, vk::DescriptorType::UNIFORM_BUFFER, |data| &data.uniforms)
+///     .array_entry(1, 0, vk::DescriptorType::COMBINED_IMAGE_SAMPLER, |data| &data.textures[..])
+///     .create(device, layout, None)?;
+/// template.update_descriptor_set(device, set, data);
+/// # Ok(()) }
+/// ```
+pub struct DescriptorTemplateBuilder<T> {
+    entries: Vec<vk::DescriptorUpdateTemplateEntry>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Default> Default for DescriptorTemplateBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Default> DescriptorTemplateBuilder<T> {
+    pub fn new() -> Self {
+        DescriptorTemplateBuilder {
+            entries: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds the descriptor of `binding` that `field` selects from `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `E` isn't the info of `descriptor_type`, or `field` doesn't return a field of
+    /// the `T` that is passed to it.
+    pub fn entry<E: DescriptorInfo, F: FnOnce(&T) -> &E>(
+        self,
+        binding: u32,
+        descriptor_type: vk::DescriptorType,
+        field: F,
+    ) -> Self {
+        self.array_entry(binding, 0, descriptor_type, |data| {
+            std::slice::from_ref(field(data))
+        })
+    }
+
+    /// Adds the descriptors of `binding`, starting at `first_array_element`, that `field`
+    /// selects from `T`, one per element of the slice.
+    ///
+    /// # Panics
+    ///
+    /// See [`DescriptorTemplateBuilder::entry`].
+    pub fn array_entry<E: DescriptorInfo, F: FnOnce(&T) -> &[E]>(
+        mut self,
+        binding: u32,
+        first_array_element: u32,
+        descriptor_type: vk::DescriptorType,
+        field: F,
+    ) -> Self {
+        assert!(
+            E::supports(descriptor_type),
+            "{} doesn't hold {:?} descriptors",
+            std::any::type_name::<E>(),
+            descriptor_type
+        );
+        let data = T::default();
+        let start = &data as *const T as usize;
+        let infos = field(&data);
+        let offset = (infos.as_ptr() as usize).wrapping_sub(start);
+        assert!(
+            offset
+                .checked_add(std::mem::size_of_val(infos))
+                .is_some_and(|end| end <= size_of::<T>()),
+            "descriptor infos of binding {} aren't part of the template data",
+            binding
+        );
+        self.entries.push(vk::DescriptorUpdateTemplateEntry {
+            dst_binding: binding,
+            dst_array_element: first_array_element,
+            descriptor_count: infos.len() as u32,
+            descriptor_type,
+            offset,
+            stride: size_of::<E>(),
+        });
+        self
+    }
+
+    pub fn entries(&self) -> &[vk::DescriptorUpdateTemplateEntry] {
+        &self.entries
+    }
+
+    /// Creates a template that updates descriptor sets of `descriptor_set_layout`.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCreateDescriptorUpdateTemplate.html>"]
+    pub unsafe fn create<D: DeviceV1_1>(
+        &self,
+        device: &D,
+        descriptor_set_layout: vk::DescriptorSetLayout,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<DescriptorTemplate<T>> {
+        let create_info = vk::DescriptorUpdateTemplateCreateInfo::builder()
+            .descriptor_update_entries(&self.entries)
+            .template_type(vk::DescriptorUpdateTemplateType::DESCRIPTOR_SET)
+            .descriptor_set_layout(descriptor_set_layout);
+        let handle =
+            device.create_descriptor_update_template(&create_info, allocation_callbacks)?;
+        Ok(DescriptorTemplate {
+            handle,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// A descriptor update template created by [`DescriptorTemplateBuilder`], which reads the
+/// descriptor infos from a `T`.
+#[derive(Debug)]
+pub struct DescriptorTemplate<T> {
+    handle: vk::DescriptorUpdateTemplate,
+    marker: PhantomData<T>,
+}
+
+impl<T> DescriptorTemplate<T> {
+    pub fn handle(&self) -> vk::DescriptorUpdateTemplate {
+        self.handle
+    }
+
+    /// Writes the descriptors of `data` to `descriptor_set`.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkUpdateDescriptorSetWithTemplate.html>"]
+    pub unsafe fn update_descriptor_set<D: DeviceV1_1>(
+        &self,
+        device: &D,
+        descriptor_set: vk::DescriptorSet,
+        data: &T,
+    ) {
+        device.update_descriptor_set_with_template(
+            descriptor_set,
+            self.handle,
+            data as *const T as *const c_void,
+        );
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkDestroyDescriptorUpdateTemplate.html>"]
+    pub unsafe fn destroy<D: DeviceV1_1>(
+        self,
+        device: &D,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) {
+        device.destroy_descriptor_update_template(self.handle, allocation_callbacks);
+    }
+}
+
+/// Whether `data` starts with a `VkPipelineCacheHeaderVersionOne` header written by the device
+/// of `properties`, i.e. with its vendor and device ID and its `pipeline_cache_uuid`. Drivers
+/// should reject mismatching data themselves, but some only do so when creating pipelines.
+pub fn pipeline_cache_data_matches(data: &[u8], properties: &vk::PhysicalDeviceProperties) -> bool {
+    const HEADER_SIZE: usize = 16 + vk::UUID_SIZE;
+    if data.len() < HEADER_SIZE {
+        return false;
+    }
+    // Unlike other Vulkan structs the header is always little endian
+    let field = |index: usize| {
+        let bytes = &data[index * 4..index * 4 + 4];
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    };
+    field(0) as usize >= HEADER_SIZE
+        && field(1) == vk::PipelineCacheHeaderVersion::ONE.as_raw() as u32
+        && field(2) == properties.vendor_id
+        && field(3) == properties.device_id
+        && data[16..HEADER_SIZE] == properties.pipeline_cache_uuid
+}
+
+/// A pipeline cache that is loaded from a file and saved back to it when dropped.
+///
+/// Data in the file that wasn't written by the same device and driver, according to
+/// [`pipeline_cache_data_matches`], is ignored and the cache starts out empty.
+///
+/// ```no_run
+/// # use ash::{util::PipelineCacheFile, version::InstanceV1_0, vk};
+/// # unsafe fn load(
+/// #     instance: &ash::Instance,
+/// #     device: &ash::Device,
+/// #     physical_device: vk::PhysicalDevice,
+/// # ) -> ash::prelude::VkResult<()> {
+/// let properties = instance.get_physical_device_properties(physical_device);
+/// let cache = PipelineCacheFile::load(device, &properties, "pipeline_cache.bin")?;
+/// // Create pipelines with `cache.pipeline_cache()`, the cache is saved when dropped
+/// # Ok(()) }
+/// ```
+pub struct PipelineCacheFile<'a, D: DeviceV1_0> {
+    device: &'a D,
+    path: PathBuf,
+    pipeline_cache: vk::PipelineCache,
+    loaded: bool,
+}
+
+impl<'a, D: DeviceV1_0> PipelineCacheFile<'a, D> {
+    /// Creates a pipeline cache with the data of `path` if it matches `properties`, the
+    /// properties of the physical device of `device`. A missing or unreadable file leaves the
+    /// cache empty.
+    pub unsafe fn load<P: Into<PathBuf>>(
+        device: &'a D,
+        properties: &vk::PhysicalDeviceProperties,
+        path: P,
+    ) -> VkResult<Self> {
+        let path = path.into();
+        let data = fs::read(&path)
+            .ok()
+            .filter(|data| pipeline_cache_data_matches(data, properties));
+        let create_info =
+            vk::PipelineCacheCreateInfo::builder().initial_data(data.as_deref().unwrap_or(&[]));
+        let pipeline_cache = device.create_pipeline_cache(&create_info, None)?;
+        Ok(PipelineCacheFile {
+            device,
+            path,
+            pipeline_cache,
+            loaded: data.is_some(),
+        })
+    }
+
+    pub fn pipeline_cache(&self) -> vk::PipelineCache {
+        self.pipeline_cache
+    }
+
+    /// Whether the cache was created with the data of the file.
+    pub fn loaded(&self) -> bool {
+        self.loaded
+    }
+
+    /// Writes the current data of the cache to the file, through a temporary file next to it
+    /// so that an interrupted write doesn't leave a truncated cache behind.
+    pub unsafe fn save(&self) -> io::Result<()> {
+        let data = self
+            .device
+            .get_pipeline_cache_data(self.pipeline_cache)
+            .map_err(io::Error::other)?;
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, data)?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+impl<'a, D: DeviceV1_0> Drop for PipelineCacheFile<'a, D> {
+    /// Saves the cache, ignoring errors, and destroys it. Call [`PipelineCacheFile::save`]
+    /// beforehand to handle the errors.
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.save();
+            self.device
+                .destroy_pipeline_cache(self.pipeline_cache, None);
+        }
+    }
+}
+
+/// Expands `offset..offset + size`, relative to a mapping of `map_size` bytes at `map_offset`,
+/// to multiples of `atom` and returns the absolute `(offset, size)` for `MappedMemoryRange`.
+/// A range reaching the end of the mapping uses `vk::WHOLE_SIZE`, as rounding it up could
+/// exceed the allocation. Panics if `map_offset` isn't a multiple of `atom`, the range would
+/// either start before the mapping or be unaligned then.
+fn atom_aligned_range(
+    map_offset: vk::DeviceSize,
+    map_size: vk::DeviceSize,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    atom: vk::DeviceSize,
+) -> (vk::DeviceSize, vk::DeviceSize) {
+    assert_eq!(
+        map_offset % atom,
+        0,
+        "offset must be a multiple of nonCoherentAtomSize"
+    );
+    let map_end = map_offset + map_size;
+    let start = map_offset + offset.min(map_size);
+    let end = if size == vk::WHOLE_SIZE {
+        map_end
+    } else {
+        (start + size).min(map_end)
+    };
+    let aligned_start = start - start % atom;
+    let aligned_end = end + calc_padding(end, atom);
+    if aligned_end >= map_end {
+        (aligned_start, vk::WHOLE_SIZE)
+    } else {
+        (aligned_start, aligned_end - aligned_start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_chain_relinks_after_move() {
+        let chain = FeatureChain::default();
+        let mut moved = Box::new(chain);
+        let vulkan_11 = &moved.vulkan_11 as *const _ as usize;
+        let vulkan_12 = &moved.vulkan_12 as *const _ as usize;
+        let head = moved.chain();
+        let ptrs: Vec<usize> = unsafe {
+            vk::ptr_chain_iter(head)
+                .skip(1)
+                .map(|ptr| ptr as usize)
+                .collect()
+        };
+        assert_eq!(ptrs, vec![vulkan_11, vulkan_12]);
+    }
+
+    #[test]
+    fn memory_type_selection() {
+        let flags = [
+            vk::MemoryPropertyFlags::HOST_VISIBLE,
+            vk::MemoryPropertyFlags::DEVICE_LOCAL,
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
+        ];
+        let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: flags.len() as u32,
+            ..Default::default()
+        };
+        for (memory_type, &property_flags) in memory_properties.memory_types.iter_mut().zip(&flags)
+        {
+            memory_type.property_flags = property_flags;
+        }
+
+        let host_visible = vk::MemoryPropertyFlags::HOST_VISIBLE;
+        assert_eq!(
+            find_memory_type_index(&memory_properties, !0, host_visible),
+            Some(0)
+        );
+        assert_eq!(
+            find_memory_type_index(&memory_properties, 0b110, host_visible),
+            Some(2)
+        );
+        assert_eq!(
+            find_memory_type_index(&memory_properties, 0b010, host_visible),
+            None
+        );
+        assert_eq!(
+            find_host_visible_memory_type_index(&memory_properties, !0),
+            Some(2)
+        );
+        assert_eq!(
+            find_host_visible_memory_type_index(&memory_properties, 0b011),
+            Some(0)
+        );
+        assert_eq!(
+            find_device_local_memory_type_index(&memory_properties, !0),
+            Some(1)
+        );
+        assert_eq!(
+            find_device_local_memory_type_index(&memory_properties, 0b100),
+            Some(2)
+        );
+        // Memory types past `memory_type_count` are never picked
+        assert_eq!(
+            find_device_local_memory_type_index(&memory_properties, 0b1000),
+            None
+        );
+    }
+
+    #[test]
+    fn memory_location_types() {
+        let flags = [
+            vk::MemoryPropertyFlags::DEVICE_LOCAL,
+            vk::MemoryPropertyFlags::HOST_VISIBLE,
+            vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT,
+            vk::MemoryPropertyFlags::HOST_VISIBLE
+                | vk::MemoryPropertyFlags::HOST_COHERENT
+                | vk::MemoryPropertyFlags::HOST_CACHED,
+        ];
+        let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: flags.len() as u32,
+            ..Default::default()
+        };
+        for (memory_type, &property_flags) in memory_properties.memory_types.iter_mut().zip(&flags)
+        {
+            memory_type.property_flags = property_flags;
+        }
+
+        let index = |location: MemoryLocation, type_bits| {
+            location.memory_type_index(&memory_properties, type_bits)
+        };
+        assert_eq!(index(MemoryLocation::GpuOnly, !0), Some(0));
+        assert_eq!(index(MemoryLocation::GpuOnly, 0b010), Some(1));
+        // Mapped memory has to be coherent to write to it without flushing
+        assert_eq!(index(MemoryLocation::CpuToGpu, !0), Some(2));
+        assert_eq!(index(MemoryLocation::CpuToGpu, 0b011), None);
+        assert_eq!(index(MemoryLocation::GpuToCpu, !0), Some(3));
+        assert_eq!(index(MemoryLocation::GpuToCpu, 0b011), Some(1));
+        assert!(!MemoryLocation::GpuOnly.is_mapped());
+    }
+
+    #[test]
+    fn descriptor_template_entries() {
+        #[repr(C)]
+        #[derive(Clone, Copy, Default)]
+        struct Descriptors {
+            texel_buffer: vk::BufferView,
+            uniforms: vk::DescriptorBufferInfo,
+            textures: [vk::DescriptorImageInfo; 3],
+        }
+        let builder = DescriptorTemplateBuilder::<Descriptors>::new()
+            .entry(2, vk::DescriptorType::UNIFORM_BUFFER, |data| &data.uniforms)
+            .array_entry(3, 1, vk::DescriptorType::SAMPLED_IMAGE, |data| {
+                &data.textures[1..]
+            });
+        let entries = builder.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            (entries[0].dst_binding, entries[0].descriptor_count),
+            (2, 1)
+        );
+        assert_eq!(entries[0].offset, size_of::<vk::BufferView>());
+        assert_eq!(entries[1].dst_array_element, 1);
+        assert_eq!(entries[1].descriptor_count, 2);
+        assert_eq!(
+            entries[1].offset,
+            size_of::<vk::BufferView>()
+                + size_of::<vk::DescriptorBufferInfo>()
+                + size_of::<vk::DescriptorImageInfo>()
+        );
+        assert_eq!(entries[1].stride, size_of::<vk::DescriptorImageInfo>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn descriptor_template_rejects_mismatched_info() {
+        DescriptorTemplateBuilder::<vk::DescriptorImageInfo>::new().entry(
+            0,
+            vk::DescriptorType::STORAGE_BUFFER,
+            |data| data,
+        );
+    }
+
+    #[test]
+    fn pipeline_cache_header() {
+        let properties = vk::PhysicalDeviceProperties {
+            vendor_id: 0x10de,
+            device_id: 0x1c03,
+            pipeline_cache_uuid: [7; vk::UUID_SIZE],
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        for field in &[32u32, 1, 0x10de, 0x1c03] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(&[7; vk::UUID_SIZE]);
+        data.extend_from_slice(b"driver data");
+        assert!(pipeline_cache_data_matches(&data, &properties));
+        assert!(!pipeline_cache_data_matches(&data[..31], &properties));
+        let other_driver = vk::PhysicalDeviceProperties {
+            pipeline_cache_uuid: [8; vk::UUID_SIZE],
+            ..properties
+        };
+        assert!(!pipeline_cache_data_matches(&data, &other_driver));
+        data[12] = 0;
+        assert!(!pipeline_cache_data_matches(&data, &properties));
+    }
+
+    #[test]
+    fn queue_family_plans() {
+        let family = |queue_flags| vk::QueueFamilyProperties {
+            queue_flags,
+            queue_count: 1,
+            ..Default::default()
+        };
+        let families = [
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER),
+            family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER),
+            family(vk::QueueFlags::TRANSFER | vk::QueueFlags::SPARSE_BINDING),
+        ];
+        let request = QueueFamilyRequest {
+            graphics: true,
+            compute: true,
+            transfer: true,
+            present: true,
+        };
+        let plan = plan_queue_families(&families, request, |_| true).unwrap();
+        assert_eq!(
+            plan,
+            QueueFamilyPlan {
+                graphics: Some(0),
+                compute: Some(1),
+                transfer: Some(2),
+                present: Some(0),
+            }
+        );
+        assert_eq!(plan.unique_families(), [0, 1, 2]);
+
+        // Without dedicated families everything shares the graphics family
+        let plan = plan_queue_families(&families[..1], request, |_| true).unwrap();
+        assert_eq!(plan.unique_families(), [0]);
+
+        let plan = plan_queue_families(&families, request, |index| index == 1).unwrap();
+        assert_eq!(plan.present, Some(1));
+        assert_eq!(plan_queue_families(&families, request, |_| false), None);
+        assert_eq!(plan_queue_families(&families[1..], request, |_| true), None);
+
+        // Compute families support transfers without reporting `TRANSFER`
+        let families = [
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE),
+            family(vk::QueueFlags::COMPUTE),
+        ];
+        let request = QueueFamilyRequest {
+            transfer: true,
+            ..Default::default()
+        };
+        let plan = plan_queue_families(&families, request, |_| true).unwrap();
+        assert_eq!(plan.transfer, Some(1));
+    }
+
+    #[test]
+    fn missing_features() {
+        let mut supported = FeatureChain::default();
+        supported.vulkan_10().inherited_queries = vk::TRUE;
+        supported.vulkan_12.timeline_semaphore = vk::TRUE;
+        let mut required = FeatureChain::default();
+        assert!(supported.missing_features(&required).is_empty());
+        required.vulkan_10().inherited_queries = vk::TRUE;
+        required.vulkan_12.timeline_semaphore = vk::TRUE;
+        assert!(supported.missing_features(&required).is_empty());
+        required.vulkan_10().robust_buffer_access = vk::TRUE;
+        required.vulkan_11.shader_draw_parameters = vk::TRUE;
+        required.vulkan_12.subgroup_broadcast_dynamic_id = vk::TRUE;
+        assert_eq!(
+            supported.missing_features(&required),
+            [
+                "PhysicalDeviceFeatures",
+                "PhysicalDeviceVulkan11Features",
+                "PhysicalDeviceVulkan12Features"
+            ]
+        );
+    }
+
+    #[test]
+    fn align_copies_with_stride() {
+        let mut memory = [0u32; 10];
+        let mut align = unsafe { Align::<u32>::new(memory.as_mut_ptr() as *mut c_void, 8, 40) };
+        assert_eq!(align.elem_size(), 8);
+        assert_eq!(align.len(), 5);
+        align.copy_from_slice(&[1, 2, 3]);
+        assert_eq!(memory, [1, 0, 2, 0, 3, 0, 0, 0, 0, 0]);
+
+        let mut align = unsafe { Align::<u32>::new(memory.as_mut_ptr() as *mut c_void, 16, 32) };
+        assert_eq!(align.len(), 2);
+        assert_eq!(align.copy_from_iter(10..), 2);
+        assert_eq!(memory, [10, 0, 2, 0, 11, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn align_partial_trailing_element() {
+        let mut memory = [0u64; 10];
+        // Elements are 32 bytes apart, a second one at offset 32 would end past 48 bytes
+        let mut align =
+            unsafe { Align::<[u64; 3]>::new(memory.as_mut_ptr() as *mut c_void, 16, 48) };
+        assert_eq!(align.len(), 1);
+        assert_eq!(align.iter_mut().count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn align_rejects_oversized_slice() {
+        let mut memory = [0u32; 4];
+        let mut align = unsafe { Align::<u32>::new(memory.as_mut_ptr() as *mut c_void, 4, 16) };
+        align.copy_from_slice(&[0; 5]);
+    }
+
+    #[test]
+    fn read_spv_bytes_endianness() {
+        let little = [0x03, 0x02, 0x23, 0x07, 0x00, 0x00, 0x01, 0x00];
+        let big = [0x07, 0x23, 0x02, 0x03, 0x00, 0x01, 0x00, 0x00];
+        let expected = vec![0x0723_0203, 0x0001_0000];
+        assert_eq!(read_spv_bytes(&little).unwrap(), expected);
+        assert_eq!(read_spv_bytes(&big).unwrap(), expected);
+        // Unaligned input is copied into aligned storage
+        assert_eq!(
+            read_spv_bytes(&[0, 0x03, 0x02, 0x23, 0x07][1..]).unwrap()[0],
+            0x0723_0203
+        );
+        assert_eq!(read_spv(&mut io::Cursor::new(&big[..])).unwrap(), expected);
+    }
+
+    #[cfg(feature = "spirv-entry-points")]
+    #[test]
+    fn spirv_entry_point_names() {
+        let header = [0x0723_0203, 0x0001_0000, 0, 2, 0];
+        let op_capability = [(2 << 16) | 17, 1];
+        let main = u32::from_le_bytes(*b"main");
+        let op_entry_point = [(5 << 16) | 15, 4, 1, main, 0];
+        let words: Vec<u32> = header
+            .iter()
+            .chain(&op_capability)
+            .chain(&op_entry_point)
+            .copied()
+            .collect();
+        assert_eq!(spirv_entry_points(&words).unwrap(), ["main"]);
+        assert_eq!(
+            spirv_entry_points(&words[..words.len() - 1])
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn read_spv_bytes_invalid() {
+        let kind = |bytes: &[u8]| read_spv_bytes(bytes).unwrap_err().kind();
+        assert_eq!(kind(&[]), io::ErrorKind::InvalidData);
+        assert_eq!(kind(&[0x03, 0x02, 0x23]), io::ErrorKind::InvalidData);
+        assert_eq!(kind(&[0, 0, 0, 0]), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn mapped_range_rounds_to_atoms() {
+        // Mapping of 1000 bytes at 256, with an atom size of 64
+        assert_eq!(atom_aligned_range(256, 1000, 0, 10, 64), (256, 64));
+        assert_eq!(atom_aligned_range(256, 1000, 100, 100, 64), (320, 192));
+        assert_eq!(
+            atom_aligned_range(256, 1000, 990, 10, 64),
+            (1216, vk::WHOLE_SIZE)
+        );
+        assert_eq!(
+            atom_aligned_range(256, 1000, 0, vk::WHOLE_SIZE, 64),
+            (256, vk::WHOLE_SIZE)
+        );
+        assert_eq!(atom_aligned_range(0, 1000, 10, 10, 1), (10, 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "offset must be a multiple of nonCoherentAtomSize")]
+    fn mapped_range_unaligned_mapping() {
+        // Rounding down to 256 would start before the mapping at 300
+        atom_aligned_range(300, 1000, 10, 10, 64);
+    }
 }
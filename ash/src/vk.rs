@@ -24,8 +24,17 @@ mod feature_extensions;
 pub use feature_extensions::*;
 mod features;
 pub use features::*;
+mod format_classes;
+#[cfg(feature = "interop")]
+pub mod interop;
+pub mod meta;
+pub mod platform;
 mod platform_types;
 pub use platform_types::*;
+mod result_codes;
+pub use result_codes::*;
+#[cfg(test)]
+mod tests;
 #[doc = r" Iterates through the pointer chain. Includes the item that is passed into the function."]
 #[doc = r" Stops at the last `BaseOutStructure` that has a null `p_next` field."]
 pub(crate) unsafe fn ptr_chain_iter<T>(ptr: &mut T) -> impl Iterator<Item = *mut BaseOutStructure> {
@@ -40,8 +49,199 @@ pub(crate) unsafe fn ptr_chain_iter<T>(ptr: &mut T) -> impl Iterator<Item = *mut
         Some(old)
     })
 }
+#[doc = r" Panics if a struct chained to `root` can't extend the struct `root_name` according"]
+#[doc = r" to [`meta::struct_extends`], or if two structs of the chain have the same `s_type`."]
+#[doc = r" Structs that aren't in the registry, such as the ones of layers, are skipped."]
+#[cfg(debug_assertions)]
+pub(crate) unsafe fn debug_assert_ptr_chain<T>(root_name: &str, root: &mut T) {
+    let mut s_types = Vec::new();
+    for ptr in ptr_chain_iter(root).skip(1) {
+        let s_type = (*ptr).s_type;
+        if let Some(name) = meta::structure_type_struct(s_type) {
+            assert!(
+                meta::struct_extends(name).contains(&root_name),
+                "{} can't be chained to {}",
+                name,
+                root_name
+            );
+            assert!(
+                !s_types.contains(&s_type),
+                "{} is chained to {} twice",
+                name,
+                root_name
+            );
+        }
+        s_types.push(s_type);
+    }
+}
+#[doc = r" Structs with a fixed `s_type`, which identifies them in a pointer chain."]
+#[doc = r""]
+#[doc = r" # Safety"]
+#[doc = r""]
+#[doc = r" The struct must start with `s_type` and `p_next` like `BaseOutStructure`, and"]
+#[doc = r" `STRUCTURE_TYPE` must be its `s_type`."]
+pub unsafe trait TaggedStructure {
+    const STRUCTURE_TYPE: StructureType;
+    #[doc = r" Views the struct as the `s_type` and `p_next` it starts with."]
+    fn as_base_in(&self) -> &BaseInStructure
+    where
+        Self: Sized,
+    {
+        unsafe { &*(self as *const Self as *const BaseInStructure) }
+    }
+    #[doc = r" Views the struct as the `s_type` and `p_next` it starts with, e.g. to insert it into a"]
+    #[doc = r" chain by hand."]
+    fn as_base_out(&mut self) -> &mut BaseOutStructure
+    where
+        Self: Sized,
+    {
+        unsafe { &mut *(self as *mut Self as *mut BaseOutStructure) }
+    }
+}
+#[doc = r" Iterates over the structs of a pointer chain, yielding their `s_type` and a pointer to"]
+#[doc = r" each of them. Unlike creating it, using the iterator is safe; [`PtrChainIter::find_struct`]"]
+#[doc = r" and [`PtrChainIter::find_struct_mut`] downcast to the first struct of a given type."]
+#[derive(Debug)]
+pub struct PtrChainIter<'a> {
+    ptr: *mut BaseOutStructure,
+    marker: ::std::marker::PhantomData<&'a mut BaseOutStructure>,
+}
+impl<'a> PtrChainIter<'a> {
+    #[doc = r" Iterates over `head` and the structs chained to it."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `head` must start with `s_type` and `p_next`, and every `p_next` of the chain must"]
+    #[doc = r" be null or point to a valid struct whose `s_type` matches its type. The chain must"]
+    #[doc = r" not contain cycles and must not be accessed otherwise while the iterator and the"]
+    #[doc = r" references it hands out are alive."]
+    pub unsafe fn new<T>(head: &'a mut T) -> Self {
+        Self::from_raw(head as *mut T as *mut BaseOutStructure)
+    }
+    #[doc = r" Same as [`PtrChainIter::new`], for a chain received as a raw pointer, which may"]
+    #[doc = r" be null."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" See [`PtrChainIter::new`]."]
+    pub unsafe fn from_raw(ptr: *mut BaseOutStructure) -> Self {
+        PtrChainIter {
+            ptr,
+            marker: ::std::marker::PhantomData,
+        }
+    }
+    #[doc = r" Returns the first struct of type `T` in the rest of the chain."]
+    pub fn find_struct<T: TaggedStructure>(self) -> Option<&'a T> {
+        self.find_struct_mut().map(|s: &'a mut T| &*s)
+    }
+    #[doc = r" Returns the first struct of type `T` in the rest of the chain."]
+    pub fn find_struct_mut<T: TaggedStructure>(mut self) -> Option<&'a mut T> {
+        self.find(|&(s_type, _)| s_type == T::STRUCTURE_TYPE)
+            .map(|(_, ptr)| unsafe { &mut *(ptr as *mut T) })
+    }
+}
+impl<'a> Iterator for PtrChainIter<'a> {
+    type Item = (StructureType, *mut BaseOutStructure);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let current = self.ptr;
+        unsafe {
+            self.ptr = (*current).p_next;
+            Some(((*current).s_type, current))
+        }
+    }
+}
+impl BaseInStructure {
+    #[doc = r" Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `self` must be the start of a struct whose `s_type` matches its type, like the structs"]
+    #[doc = r" of a pointer chain."]
+    pub unsafe fn try_downcast<T: TaggedStructure>(&self) -> Option<&T> {
+        if self.s_type == T::STRUCTURE_TYPE {
+            Some(&*(self as *const Self as *const T))
+        } else {
+            None
+        }
+    }
+}
+impl BaseOutStructure {
+    #[doc = r" Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `self` must be the start of a struct whose `s_type` matches its type, like the structs"]
+    #[doc = r" of a pointer chain."]
+    pub unsafe fn try_downcast<T: TaggedStructure>(&self) -> Option<&T> {
+        if self.s_type == T::STRUCTURE_TYPE {
+            Some(&*(self as *const Self as *const T))
+        } else {
+            None
+        }
+    }
+    #[doc = r" Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" See [`BaseOutStructure::try_downcast`]."]
+    pub unsafe fn try_downcast_mut<T: TaggedStructure>(&mut self) -> Option<&mut T> {
+        if self.s_type == T::STRUCTURE_TYPE {
+            Some(&mut *(self as *mut Self as *mut T))
+        } else {
+            None
+        }
+    }
+}
 pub trait Handle {
     const TYPE: ObjectType;
     fn as_raw(self) -> u64;
     fn from_raw(_: u64) -> Self;
 }
+#[doc = r" Handles that are created from a [`Device`], directly or through another handle, e.g."]
+#[doc = r" [`ImageView`] or [`DescriptorSet`]."]
+pub trait DeviceChild: Handle {}
+#[doc = r" Handles that are created from an [`Instance`], directly or through another handle, but"]
+#[doc = r" not from a [`Device`], e.g. [`PhysicalDevice`] or [`SurfaceKHR`]."]
+pub trait InstanceChild: Handle {}
+impl DeviceChild for AccelerationStructureKHR {}
+impl DeviceChild for AccelerationStructureNV {}
+impl DeviceChild for Buffer {}
+impl DeviceChild for BufferView {}
+impl DeviceChild for CommandBuffer {}
+impl DeviceChild for CommandPool {}
+impl InstanceChild for DebugReportCallbackEXT {}
+impl InstanceChild for DebugUtilsMessengerEXT {}
+impl DeviceChild for DeferredOperationKHR {}
+impl DeviceChild for DescriptorPool {}
+impl DeviceChild for DescriptorSet {}
+impl DeviceChild for DescriptorSetLayout {}
+impl DeviceChild for DescriptorUpdateTemplate {}
+impl InstanceChild for Device {}
+impl DeviceChild for DeviceMemory {}
+impl InstanceChild for DisplayKHR {}
+impl InstanceChild for DisplayModeKHR {}
+impl DeviceChild for Event {}
+impl DeviceChild for Fence {}
+impl DeviceChild for Framebuffer {}
+impl DeviceChild for Image {}
+impl DeviceChild for ImageView {}
+impl DeviceChild for IndirectCommandsLayoutNV {}
+impl DeviceChild for PerformanceConfigurationINTEL {}
+impl InstanceChild for PhysicalDevice {}
+impl DeviceChild for Pipeline {}
+impl DeviceChild for PipelineCache {}
+impl DeviceChild for PipelineLayout {}
+impl DeviceChild for PrivateDataSlotEXT {}
+impl DeviceChild for QueryPool {}
+impl DeviceChild for Queue {}
+impl DeviceChild for RenderPass {}
+impl DeviceChild for Sampler {}
+impl DeviceChild for SamplerYcbcrConversion {}
+impl DeviceChild for Semaphore {}
+impl DeviceChild for ShaderModule {}
+impl InstanceChild for SurfaceKHR {}
+impl InstanceChild for SwapchainKHR {}
+impl DeviceChild for ValidationCacheEXT {}
@@ -2,6 +2,17 @@ use crate::vk::bitflags::*;
 use crate::vk::definitions::*;
 use crate::vk::enums::*;
 use std::fmt;
+#[doc = r" Writes the name of `value`, looked up in `known`, which is sorted by value."]
+pub(crate) fn debug_enum(
+    f: &mut fmt::Formatter,
+    known: &[(i32, &'static str)],
+    value: i32,
+) -> fmt::Result {
+    match known.binary_search_by_key(&value, |&(known, _)| known) {
+        Ok(index) => f.write_str(known[index].1),
+        Err(_) => fmt::Debug::fmt(&value, f),
+    }
+}
 pub(crate) fn debug_flags(
     f: &mut fmt::Formatter,
     known: &[(Flags, &'static str)],
@@ -29,12 +40,15 @@ pub(crate) fn debug_flags(
 }
 impl fmt::Debug for AccelerationStructureBuildTypeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::HOST => Some("HOST"),
             Self::DEVICE => Some("DEVICE"),
             Self::HOST_OR_DEVICE => Some("HOST_OR_DEVICE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -44,11 +58,14 @@ impl fmt::Debug for AccelerationStructureBuildTypeKHR {
 }
 impl fmt::Debug for AccelerationStructureCompatibilityKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::COMPATIBLE => Some("COMPATIBLE"),
             Self::INCOMPATIBLE => Some("INCOMPATIBLE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -58,21 +75,27 @@ impl fmt::Debug for AccelerationStructureCompatibilityKHR {
 }
 impl fmt::Debug for AccelerationStructureCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(
             AccelerationStructureCreateFlagsKHR::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
             "DEVICE_ADDRESS_CAPTURE_REPLAY",
         )];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for AccelerationStructureMemoryRequirementsTypeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::OBJECT => Some("OBJECT"),
             Self::BUILD_SCRATCH => Some("BUILD_SCRATCH"),
             Self::UPDATE_SCRATCH => Some("UPDATE_SCRATCH"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -82,12 +105,15 @@ impl fmt::Debug for AccelerationStructureMemoryRequirementsTypeNV {
 }
 impl fmt::Debug for AccelerationStructureTypeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TOP_LEVEL => Some("TOP_LEVEL"),
             Self::BOTTOM_LEVEL => Some("BOTTOM_LEVEL"),
             Self::GENERIC => Some("GENERIC"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -97,6 +123,7 @@ impl fmt::Debug for AccelerationStructureTypeKHR {
 }
 impl fmt::Debug for AccessFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 AccessFlags::INDIRECT_COMMAND_READ.0,
@@ -184,35 +211,49 @@ impl fmt::Debug for AccessFlags {
                 "COMMAND_PREPROCESS_WRITE_NV",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for AcquireProfilingLockFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for AndroidSurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for AttachmentDescriptionFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(AttachmentDescriptionFlags::MAY_ALIAS.0, "MAY_ALIAS")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for AttachmentLoadOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::LOAD => Some("LOAD"),
             Self::CLEAR => Some("CLEAR"),
             Self::DONT_CARE => Some("DONT_CARE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -222,12 +263,15 @@ impl fmt::Debug for AttachmentLoadOp {
 }
 impl fmt::Debug for AttachmentStoreOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::STORE => Some("STORE"),
             Self::DONT_CARE => Some("DONT_CARE"),
             Self::NONE_QCOM => Some("NONE_QCOM"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -237,6 +281,7 @@ impl fmt::Debug for AttachmentStoreOp {
 }
 impl fmt::Debug for BlendFactor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ZERO => Some("ZERO"),
             Self::ONE => Some("ONE"),
@@ -259,6 +304,8 @@ impl fmt::Debug for BlendFactor {
             Self::ONE_MINUS_SRC1_ALPHA => Some("ONE_MINUS_SRC1_ALPHA"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -268,6 +315,7 @@ impl fmt::Debug for BlendFactor {
 }
 impl fmt::Debug for BlendOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ADD => Some("ADD"),
             Self::SUBTRACT => Some("SUBTRACT"),
@@ -322,6 +370,8 @@ impl fmt::Debug for BlendOp {
             Self::BLUE_EXT => Some("BLUE_EXT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -331,12 +381,15 @@ impl fmt::Debug for BlendOp {
 }
 impl fmt::Debug for BlendOverlapEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UNCORRELATED => Some("UNCORRELATED"),
             Self::DISJOINT => Some("DISJOINT"),
             Self::CONJOINT => Some("CONJOINT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -346,6 +399,7 @@ impl fmt::Debug for BlendOverlapEXT {
 }
 impl fmt::Debug for BorderColor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::FLOAT_TRANSPARENT_BLACK => Some("FLOAT_TRANSPARENT_BLACK"),
             Self::INT_TRANSPARENT_BLACK => Some("INT_TRANSPARENT_BLACK"),
@@ -357,6 +411,8 @@ impl fmt::Debug for BorderColor {
             Self::INT_CUSTOM_EXT => Some("INT_CUSTOM_EXT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -366,6 +422,7 @@ impl fmt::Debug for BorderColor {
 }
 impl fmt::Debug for BufferCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (BufferCreateFlags::SPARSE_BINDING.0, "SPARSE_BINDING"),
             (BufferCreateFlags::SPARSE_RESIDENCY.0, "SPARSE_RESIDENCY"),
@@ -377,11 +434,14 @@ impl fmt::Debug for BufferCreateFlags {
                 "DEVICE_ADDRESS_CAPTURE_REPLAY",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for BufferUsageFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (BufferUsageFlags::TRANSFER_SRC.0, "TRANSFER_SRC"),
             (BufferUsageFlags::TRANSFER_DST.0, "TRANSFER_DST"),
@@ -432,17 +492,23 @@ impl fmt::Debug for BufferUsageFlags {
                 "SHADER_DEVICE_ADDRESS",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for BufferViewCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for BuildAccelerationStructureFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 BuildAccelerationStructureFlagsKHR::ALLOW_UPDATE.0,
@@ -465,16 +531,21 @@ impl fmt::Debug for BuildAccelerationStructureFlagsKHR {
                 "LOW_MEMORY",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for BuildAccelerationStructureModeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::BUILD => Some("BUILD"),
             Self::UPDATE => Some("UPDATE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -484,11 +555,14 @@ impl fmt::Debug for BuildAccelerationStructureModeKHR {
 }
 impl fmt::Debug for ChromaLocation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::COSITED_EVEN => Some("COSITED_EVEN"),
             Self::MIDPOINT => Some("MIDPOINT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -498,6 +572,7 @@ impl fmt::Debug for ChromaLocation {
 }
 impl fmt::Debug for CoarseSampleOrderTypeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEFAULT => Some("DEFAULT"),
             Self::CUSTOM => Some("CUSTOM"),
@@ -505,6 +580,8 @@ impl fmt::Debug for CoarseSampleOrderTypeNV {
             Self::SAMPLE_MAJOR => Some("SAMPLE_MAJOR"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -514,17 +591,21 @@ impl fmt::Debug for CoarseSampleOrderTypeNV {
 }
 impl fmt::Debug for ColorComponentFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ColorComponentFlags::R.0, "R"),
             (ColorComponentFlags::G.0, "G"),
             (ColorComponentFlags::B.0, "B"),
             (ColorComponentFlags::A.0, "A"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ColorSpaceKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::SRGB_NONLINEAR => Some("SRGB_NONLINEAR"),
             Self::DISPLAY_P3_NONLINEAR_EXT => Some("DISPLAY_P3_NONLINEAR_EXT"),
@@ -544,6 +625,8 @@ impl fmt::Debug for ColorSpaceKHR {
             Self::DISPLAY_NATIVE_AMD => Some("DISPLAY_NATIVE_AMD"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -553,11 +636,14 @@ impl fmt::Debug for ColorSpaceKHR {
 }
 impl fmt::Debug for CommandBufferLevel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::PRIMARY => Some("PRIMARY"),
             Self::SECONDARY => Some("SECONDARY"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -567,15 +653,19 @@ impl fmt::Debug for CommandBufferLevel {
 }
 impl fmt::Debug for CommandBufferResetFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(
             CommandBufferResetFlags::RELEASE_RESOURCES.0,
             "RELEASE_RESOURCES",
         )];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for CommandBufferUsageFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 CommandBufferUsageFlags::ONE_TIME_SUBMIT.0,
@@ -590,11 +680,14 @@ impl fmt::Debug for CommandBufferUsageFlags {
                 "SIMULTANEOUS_USE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for CommandPoolCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (CommandPoolCreateFlags::TRANSIENT.0, "TRANSIENT"),
             (
@@ -603,26 +696,35 @@ impl fmt::Debug for CommandPoolCreateFlags {
             ),
             (CommandPoolCreateFlags::PROTECTED.0, "PROTECTED"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for CommandPoolResetFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(
             CommandPoolResetFlags::RELEASE_RESOURCES.0,
             "RELEASE_RESOURCES",
         )];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for CommandPoolTrimFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for CompareOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::NEVER => Some("NEVER"),
             Self::LESS => Some("LESS"),
@@ -634,6 +736,8 @@ impl fmt::Debug for CompareOp {
             Self::ALWAYS => Some("ALWAYS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -643,6 +747,7 @@ impl fmt::Debug for CompareOp {
 }
 impl fmt::Debug for ComponentSwizzle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::IDENTITY => Some("IDENTITY"),
             Self::ZERO => Some("ZERO"),
@@ -653,6 +758,8 @@ impl fmt::Debug for ComponentSwizzle {
             Self::A => Some("A"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -662,6 +769,7 @@ impl fmt::Debug for ComponentSwizzle {
 }
 impl fmt::Debug for ComponentTypeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::FLOAT16 => Some("FLOAT16"),
             Self::FLOAT32 => Some("FLOAT32"),
@@ -676,6 +784,8 @@ impl fmt::Debug for ComponentTypeNV {
             Self::UINT64 => Some("UINT64"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -685,29 +795,38 @@ impl fmt::Debug for ComponentTypeNV {
 }
 impl fmt::Debug for CompositeAlphaFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (CompositeAlphaFlagsKHR::OPAQUE.0, "OPAQUE"),
             (CompositeAlphaFlagsKHR::PRE_MULTIPLIED.0, "PRE_MULTIPLIED"),
             (CompositeAlphaFlagsKHR::POST_MULTIPLIED.0, "POST_MULTIPLIED"),
             (CompositeAlphaFlagsKHR::INHERIT.0, "INHERIT"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ConditionalRenderingFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(ConditionalRenderingFlagsEXT::INVERTED.0, "INVERTED")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ConservativeRasterizationModeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DISABLED => Some("DISABLED"),
             Self::OVERESTIMATE => Some("OVERESTIMATE"),
             Self::UNDERESTIMATE => Some("UNDERESTIMATE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -717,6 +836,7 @@ impl fmt::Debug for ConservativeRasterizationModeEXT {
 }
 impl fmt::Debug for CopyAccelerationStructureModeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::CLONE => Some("CLONE"),
             Self::COMPACT => Some("COMPACT"),
@@ -724,6 +844,8 @@ impl fmt::Debug for CopyAccelerationStructureModeKHR {
             Self::DESERIALIZE => Some("DESERIALIZE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -733,6 +855,7 @@ impl fmt::Debug for CopyAccelerationStructureModeKHR {
 }
 impl fmt::Debug for CoverageModulationModeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::NONE => Some("NONE"),
             Self::RGB => Some("RGB"),
@@ -740,6 +863,8 @@ impl fmt::Debug for CoverageModulationModeNV {
             Self::RGBA => Some("RGBA"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -749,11 +874,14 @@ impl fmt::Debug for CoverageModulationModeNV {
 }
 impl fmt::Debug for CoverageReductionModeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::MERGE => Some("MERGE"),
             Self::TRUNCATE => Some("TRUNCATE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -763,17 +891,21 @@ impl fmt::Debug for CoverageReductionModeNV {
 }
 impl fmt::Debug for CullModeFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (CullModeFlags::NONE.0, "NONE"),
             (CullModeFlags::FRONT.0, "FRONT"),
             (CullModeFlags::BACK.0, "BACK"),
             (CullModeFlags::FRONT_AND_BACK.0, "FRONT_AND_BACK"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DebugReportFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DebugReportFlagsEXT::INFORMATION.0, "INFORMATION"),
             (DebugReportFlagsEXT::WARNING.0, "WARNING"),
@@ -784,11 +916,14 @@ impl fmt::Debug for DebugReportFlagsEXT {
             (DebugReportFlagsEXT::ERROR.0, "ERROR"),
             (DebugReportFlagsEXT::DEBUG.0, "DEBUG"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DebugReportObjectTypeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UNKNOWN => Some("UNKNOWN"),
             Self::INSTANCE => Some("INSTANCE"),
@@ -828,6 +963,8 @@ impl fmt::Debug for DebugReportObjectTypeEXT {
             Self::ACCELERATION_STRUCTURE_NV => Some("ACCELERATION_STRUCTURE_NV"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -837,49 +974,65 @@ impl fmt::Debug for DebugReportObjectTypeEXT {
 }
 impl fmt::Debug for DebugUtilsMessageSeverityFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DebugUtilsMessageSeverityFlagsEXT::VERBOSE.0, "VERBOSE"),
             (DebugUtilsMessageSeverityFlagsEXT::INFO.0, "INFO"),
             (DebugUtilsMessageSeverityFlagsEXT::WARNING.0, "WARNING"),
             (DebugUtilsMessageSeverityFlagsEXT::ERROR.0, "ERROR"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DebugUtilsMessageTypeFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DebugUtilsMessageTypeFlagsEXT::GENERAL.0, "GENERAL"),
             (DebugUtilsMessageTypeFlagsEXT::VALIDATION.0, "VALIDATION"),
             (DebugUtilsMessageTypeFlagsEXT::PERFORMANCE.0, "PERFORMANCE"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DebugUtilsMessengerCallbackDataFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DebugUtilsMessengerCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DependencyFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DependencyFlags::BY_REGION.0, "BY_REGION"),
             (DependencyFlags::DEVICE_GROUP.0, "DEVICE_GROUP"),
             (DependencyFlags::VIEW_LOCAL.0, "VIEW_LOCAL"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorBindingFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 DescriptorBindingFlags::UPDATE_AFTER_BIND.0,
@@ -896,11 +1049,14 @@ impl fmt::Debug for DescriptorBindingFlags {
             ),
             (DescriptorBindingFlags::RESERVED_4_QCOM.0, "RESERVED_4_QCOM"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorPoolCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET.0,
@@ -911,17 +1067,23 @@ impl fmt::Debug for DescriptorPoolCreateFlags {
                 "UPDATE_AFTER_BIND",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorPoolResetFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorSetLayoutCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 DescriptorSetLayoutCreateFlags::PUSH_DESCRIPTOR_KHR.0,
@@ -932,11 +1094,14 @@ impl fmt::Debug for DescriptorSetLayoutCreateFlags {
                 "UPDATE_AFTER_BIND_POOL",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::SAMPLER => Some("SAMPLER"),
             Self::COMBINED_IMAGE_SAMPLER => Some("COMBINED_IMAGE_SAMPLER"),
@@ -954,6 +1119,8 @@ impl fmt::Debug for DescriptorType {
             Self::ACCELERATION_STRUCTURE_NV => Some("ACCELERATION_STRUCTURE_NV"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -963,17 +1130,23 @@ impl fmt::Debug for DescriptorType {
 }
 impl fmt::Debug for DescriptorUpdateTemplateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DescriptorUpdateTemplateType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DESCRIPTOR_SET => Some("DESCRIPTOR_SET"),
             Self::PUSH_DESCRIPTORS_KHR => Some("PUSH_DESCRIPTORS_KHR"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -983,12 +1156,16 @@ impl fmt::Debug for DescriptorUpdateTemplateType {
 }
 impl fmt::Debug for DeviceCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DeviceDiagnosticsConfigFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 DeviceDiagnosticsConfigFlagsNV::ENABLE_SHADER_DEBUG_INFO.0,
@@ -1003,15 +1180,20 @@ impl fmt::Debug for DeviceDiagnosticsConfigFlagsNV {
                 "ENABLE_AUTOMATIC_CHECKPOINTS",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DeviceEventTypeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DISPLAY_HOTPLUG => Some("DISPLAY_HOTPLUG"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1021,6 +1203,7 @@ impl fmt::Debug for DeviceEventTypeEXT {
 }
 impl fmt::Debug for DeviceGroupPresentModeFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DeviceGroupPresentModeFlagsKHR::LOCAL.0, "LOCAL"),
             (DeviceGroupPresentModeFlagsKHR::REMOTE.0, "REMOTE"),
@@ -1030,11 +1213,14 @@ impl fmt::Debug for DeviceGroupPresentModeFlagsKHR {
                 "LOCAL_MULTI_DEVICE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DeviceMemoryReportEventTypeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ALLOCATE => Some("ALLOCATE"),
             Self::FREE => Some("FREE"),
@@ -1043,6 +1229,8 @@ impl fmt::Debug for DeviceMemoryReportEventTypeEXT {
             Self::ALLOCATION_FAILED => Some("ALLOCATION_FAILED"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1052,29 +1240,41 @@ impl fmt::Debug for DeviceMemoryReportEventTypeEXT {
 }
 impl fmt::Debug for DeviceMemoryReportFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DeviceQueueCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(DeviceQueueCreateFlags::PROTECTED.0, "PROTECTED")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DirectFBSurfaceCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DiscardRectangleModeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::INCLUSIVE => Some("INCLUSIVE"),
             Self::EXCLUSIVE => Some("EXCLUSIVE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1084,10 +1284,13 @@ impl fmt::Debug for DiscardRectangleModeEXT {
 }
 impl fmt::Debug for DisplayEventTypeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::FIRST_PIXEL_OUT => Some("FIRST_PIXEL_OUT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1097,12 +1300,16 @@ impl fmt::Debug for DisplayEventTypeEXT {
 }
 impl fmt::Debug for DisplayModeCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DisplayPlaneAlphaFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (DisplayPlaneAlphaFlagsKHR::OPAQUE.0, "OPAQUE"),
             (DisplayPlaneAlphaFlagsKHR::GLOBAL.0, "GLOBAL"),
@@ -1112,17 +1319,22 @@ impl fmt::Debug for DisplayPlaneAlphaFlagsKHR {
                 "PER_PIXEL_PREMULTIPLIED",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DisplayPowerStateEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::OFF => Some("OFF"),
             Self::SUSPEND => Some("SUSPEND"),
             Self::ON => Some("ON"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1132,12 +1344,16 @@ impl fmt::Debug for DisplayPowerStateEXT {
 }
 impl fmt::Debug for DisplaySurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for DriverId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::AMD_PROPRIETARY => Some("AMD_PROPRIETARY"),
             Self::AMD_OPEN_SOURCE => Some("AMD_OPEN_SOURCE"),
@@ -1155,6 +1371,8 @@ impl fmt::Debug for DriverId {
             Self::MOLTEN => Some("MOLTEN"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1164,6 +1382,7 @@ impl fmt::Debug for DriverId {
 }
 impl fmt::Debug for DynamicState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::VIEWPORT => Some("VIEWPORT"),
             Self::SCISSOR => Some("SCISSOR"),
@@ -1199,6 +1418,8 @@ impl fmt::Debug for DynamicState {
             Self::STENCIL_OP_EXT => Some("STENCIL_OP_EXT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1208,12 +1429,16 @@ impl fmt::Debug for DynamicState {
 }
 impl fmt::Debug for EventCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalFenceFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalFenceFeatureFlags::EXTERNAL_FENCE_FEATURE_EXPORTABLE.0,
@@ -1224,11 +1449,14 @@ impl fmt::Debug for ExternalFenceFeatureFlags {
                 "EXTERNAL_FENCE_FEATURE_IMPORTABLE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalFenceHandleTypeFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalFenceHandleTypeFlags::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD.0,
@@ -1247,11 +1475,14 @@ impl fmt::Debug for ExternalFenceHandleTypeFlags {
                 "EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalMemoryFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalMemoryFeatureFlags::EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY.0,
@@ -1266,11 +1497,14 @@ impl fmt::Debug for ExternalMemoryFeatureFlags {
                 "EXTERNAL_MEMORY_FEATURE_IMPORTABLE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalMemoryFeatureFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalMemoryFeatureFlagsNV::EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_NV.0,
@@ -1285,17 +1519,23 @@ impl fmt::Debug for ExternalMemoryFeatureFlagsNV {
                 "EXTERNAL_MEMORY_FEATURE_IMPORTABLE_NV",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalMemoryHandleTypeFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN : & [(Flags , & str)] = & [(ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32 . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_ANDROID_HARDWARE_BUFFER_ANDROID . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_ANDROID_HARDWARE_BUFFER_ANDROID") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_HOST_ALLOCATION . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_HOST_ALLOCATION") , (ExternalMemoryHandleTypeFlags :: EXTERNAL_MEMORY_HANDLE_TYPE_HOST_MAPPED_FOREIGN_MEMORY . 0 , "EXTERNAL_MEMORY_HANDLE_TYPE_HOST_MAPPED_FOREIGN_MEMORY")] ;
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalMemoryHandleTypeFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalMemoryHandleTypeFlagsNV::EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_NV.0,
@@ -1314,11 +1554,14 @@ impl fmt::Debug for ExternalMemoryHandleTypeFlagsNV {
                 "EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_IMAGE_KMT_NV",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalSemaphoreFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalSemaphoreFeatureFlags::EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE.0,
@@ -1329,11 +1572,14 @@ impl fmt::Debug for ExternalSemaphoreFeatureFlags {
                 "EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ExternalSemaphoreHandleTypeFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD.0,
@@ -1356,29 +1602,40 @@ impl fmt::Debug for ExternalSemaphoreHandleTypeFlags {
                 "EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for FenceCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(FenceCreateFlags::SIGNALED.0, "SIGNALED")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for FenceImportFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(FenceImportFlags::TEMPORARY.0, "TEMPORARY")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for Filter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::NEAREST => Some("NEAREST"),
             Self::LINEAR => Some("LINEAR"),
             Self::CUBIC_IMG => Some("CUBIC_IMG"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1388,325 +1645,581 @@ impl fmt::Debug for Filter {
 }
 impl fmt::Debug for Format {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let name = match *self {
-            Self::UNDEFINED => Some("UNDEFINED"),
-            Self::R4G4_UNORM_PACK8 => Some("R4G4_UNORM_PACK8"),
-            Self::R4G4B4A4_UNORM_PACK16 => Some("R4G4B4A4_UNORM_PACK16"),
-            Self::B4G4R4A4_UNORM_PACK16 => Some("B4G4R4A4_UNORM_PACK16"),
-            Self::R5G6B5_UNORM_PACK16 => Some("R5G6B5_UNORM_PACK16"),
-            Self::B5G6R5_UNORM_PACK16 => Some("B5G6R5_UNORM_PACK16"),
-            Self::R5G5B5A1_UNORM_PACK16 => Some("R5G5B5A1_UNORM_PACK16"),
-            Self::B5G5R5A1_UNORM_PACK16 => Some("B5G5R5A1_UNORM_PACK16"),
-            Self::A1R5G5B5_UNORM_PACK16 => Some("A1R5G5B5_UNORM_PACK16"),
-            Self::R8_UNORM => Some("R8_UNORM"),
-            Self::R8_SNORM => Some("R8_SNORM"),
-            Self::R8_USCALED => Some("R8_USCALED"),
-            Self::R8_SSCALED => Some("R8_SSCALED"),
-            Self::R8_UINT => Some("R8_UINT"),
-            Self::R8_SINT => Some("R8_SINT"),
-            Self::R8_SRGB => Some("R8_SRGB"),
-            Self::R8G8_UNORM => Some("R8G8_UNORM"),
-            Self::R8G8_SNORM => Some("R8G8_SNORM"),
-            Self::R8G8_USCALED => Some("R8G8_USCALED"),
-            Self::R8G8_SSCALED => Some("R8G8_SSCALED"),
-            Self::R8G8_UINT => Some("R8G8_UINT"),
-            Self::R8G8_SINT => Some("R8G8_SINT"),
-            Self::R8G8_SRGB => Some("R8G8_SRGB"),
-            Self::R8G8B8_UNORM => Some("R8G8B8_UNORM"),
-            Self::R8G8B8_SNORM => Some("R8G8B8_SNORM"),
-            Self::R8G8B8_USCALED => Some("R8G8B8_USCALED"),
-            Self::R8G8B8_SSCALED => Some("R8G8B8_SSCALED"),
-            Self::R8G8B8_UINT => Some("R8G8B8_UINT"),
-            Self::R8G8B8_SINT => Some("R8G8B8_SINT"),
-            Self::R8G8B8_SRGB => Some("R8G8B8_SRGB"),
-            Self::B8G8R8_UNORM => Some("B8G8R8_UNORM"),
-            Self::B8G8R8_SNORM => Some("B8G8R8_SNORM"),
-            Self::B8G8R8_USCALED => Some("B8G8R8_USCALED"),
-            Self::B8G8R8_SSCALED => Some("B8G8R8_SSCALED"),
-            Self::B8G8R8_UINT => Some("B8G8R8_UINT"),
-            Self::B8G8R8_SINT => Some("B8G8R8_SINT"),
-            Self::B8G8R8_SRGB => Some("B8G8R8_SRGB"),
-            Self::R8G8B8A8_UNORM => Some("R8G8B8A8_UNORM"),
-            Self::R8G8B8A8_SNORM => Some("R8G8B8A8_SNORM"),
-            Self::R8G8B8A8_USCALED => Some("R8G8B8A8_USCALED"),
-            Self::R8G8B8A8_SSCALED => Some("R8G8B8A8_SSCALED"),
-            Self::R8G8B8A8_UINT => Some("R8G8B8A8_UINT"),
-            Self::R8G8B8A8_SINT => Some("R8G8B8A8_SINT"),
-            Self::R8G8B8A8_SRGB => Some("R8G8B8A8_SRGB"),
-            Self::B8G8R8A8_UNORM => Some("B8G8R8A8_UNORM"),
-            Self::B8G8R8A8_SNORM => Some("B8G8R8A8_SNORM"),
-            Self::B8G8R8A8_USCALED => Some("B8G8R8A8_USCALED"),
-            Self::B8G8R8A8_SSCALED => Some("B8G8R8A8_SSCALED"),
-            Self::B8G8R8A8_UINT => Some("B8G8R8A8_UINT"),
-            Self::B8G8R8A8_SINT => Some("B8G8R8A8_SINT"),
-            Self::B8G8R8A8_SRGB => Some("B8G8R8A8_SRGB"),
-            Self::A8B8G8R8_UNORM_PACK32 => Some("A8B8G8R8_UNORM_PACK32"),
-            Self::A8B8G8R8_SNORM_PACK32 => Some("A8B8G8R8_SNORM_PACK32"),
-            Self::A8B8G8R8_USCALED_PACK32 => Some("A8B8G8R8_USCALED_PACK32"),
-            Self::A8B8G8R8_SSCALED_PACK32 => Some("A8B8G8R8_SSCALED_PACK32"),
-            Self::A8B8G8R8_UINT_PACK32 => Some("A8B8G8R8_UINT_PACK32"),
-            Self::A8B8G8R8_SINT_PACK32 => Some("A8B8G8R8_SINT_PACK32"),
-            Self::A8B8G8R8_SRGB_PACK32 => Some("A8B8G8R8_SRGB_PACK32"),
-            Self::A2R10G10B10_UNORM_PACK32 => Some("A2R10G10B10_UNORM_PACK32"),
-            Self::A2R10G10B10_SNORM_PACK32 => Some("A2R10G10B10_SNORM_PACK32"),
-            Self::A2R10G10B10_USCALED_PACK32 => Some("A2R10G10B10_USCALED_PACK32"),
-            Self::A2R10G10B10_SSCALED_PACK32 => Some("A2R10G10B10_SSCALED_PACK32"),
-            Self::A2R10G10B10_UINT_PACK32 => Some("A2R10G10B10_UINT_PACK32"),
-            Self::A2R10G10B10_SINT_PACK32 => Some("A2R10G10B10_SINT_PACK32"),
-            Self::A2B10G10R10_UNORM_PACK32 => Some("A2B10G10R10_UNORM_PACK32"),
-            Self::A2B10G10R10_SNORM_PACK32 => Some("A2B10G10R10_SNORM_PACK32"),
-            Self::A2B10G10R10_USCALED_PACK32 => Some("A2B10G10R10_USCALED_PACK32"),
-            Self::A2B10G10R10_SSCALED_PACK32 => Some("A2B10G10R10_SSCALED_PACK32"),
-            Self::A2B10G10R10_UINT_PACK32 => Some("A2B10G10R10_UINT_PACK32"),
-            Self::A2B10G10R10_SINT_PACK32 => Some("A2B10G10R10_SINT_PACK32"),
-            Self::R16_UNORM => Some("R16_UNORM"),
-            Self::R16_SNORM => Some("R16_SNORM"),
-            Self::R16_USCALED => Some("R16_USCALED"),
-            Self::R16_SSCALED => Some("R16_SSCALED"),
-            Self::R16_UINT => Some("R16_UINT"),
-            Self::R16_SINT => Some("R16_SINT"),
-            Self::R16_SFLOAT => Some("R16_SFLOAT"),
-            Self::R16G16_UNORM => Some("R16G16_UNORM"),
-            Self::R16G16_SNORM => Some("R16G16_SNORM"),
-            Self::R16G16_USCALED => Some("R16G16_USCALED"),
-            Self::R16G16_SSCALED => Some("R16G16_SSCALED"),
-            Self::R16G16_UINT => Some("R16G16_UINT"),
-            Self::R16G16_SINT => Some("R16G16_SINT"),
-            Self::R16G16_SFLOAT => Some("R16G16_SFLOAT"),
-            Self::R16G16B16_UNORM => Some("R16G16B16_UNORM"),
-            Self::R16G16B16_SNORM => Some("R16G16B16_SNORM"),
-            Self::R16G16B16_USCALED => Some("R16G16B16_USCALED"),
-            Self::R16G16B16_SSCALED => Some("R16G16B16_SSCALED"),
-            Self::R16G16B16_UINT => Some("R16G16B16_UINT"),
-            Self::R16G16B16_SINT => Some("R16G16B16_SINT"),
-            Self::R16G16B16_SFLOAT => Some("R16G16B16_SFLOAT"),
-            Self::R16G16B16A16_UNORM => Some("R16G16B16A16_UNORM"),
-            Self::R16G16B16A16_SNORM => Some("R16G16B16A16_SNORM"),
-            Self::R16G16B16A16_USCALED => Some("R16G16B16A16_USCALED"),
-            Self::R16G16B16A16_SSCALED => Some("R16G16B16A16_SSCALED"),
-            Self::R16G16B16A16_UINT => Some("R16G16B16A16_UINT"),
-            Self::R16G16B16A16_SINT => Some("R16G16B16A16_SINT"),
-            Self::R16G16B16A16_SFLOAT => Some("R16G16B16A16_SFLOAT"),
-            Self::R32_UINT => Some("R32_UINT"),
-            Self::R32_SINT => Some("R32_SINT"),
-            Self::R32_SFLOAT => Some("R32_SFLOAT"),
-            Self::R32G32_UINT => Some("R32G32_UINT"),
-            Self::R32G32_SINT => Some("R32G32_SINT"),
-            Self::R32G32_SFLOAT => Some("R32G32_SFLOAT"),
-            Self::R32G32B32_UINT => Some("R32G32B32_UINT"),
-            Self::R32G32B32_SINT => Some("R32G32B32_SINT"),
-            Self::R32G32B32_SFLOAT => Some("R32G32B32_SFLOAT"),
-            Self::R32G32B32A32_UINT => Some("R32G32B32A32_UINT"),
-            Self::R32G32B32A32_SINT => Some("R32G32B32A32_SINT"),
-            Self::R32G32B32A32_SFLOAT => Some("R32G32B32A32_SFLOAT"),
-            Self::R64_UINT => Some("R64_UINT"),
-            Self::R64_SINT => Some("R64_SINT"),
-            Self::R64_SFLOAT => Some("R64_SFLOAT"),
-            Self::R64G64_UINT => Some("R64G64_UINT"),
-            Self::R64G64_SINT => Some("R64G64_SINT"),
-            Self::R64G64_SFLOAT => Some("R64G64_SFLOAT"),
-            Self::R64G64B64_UINT => Some("R64G64B64_UINT"),
-            Self::R64G64B64_SINT => Some("R64G64B64_SINT"),
-            Self::R64G64B64_SFLOAT => Some("R64G64B64_SFLOAT"),
-            Self::R64G64B64A64_UINT => Some("R64G64B64A64_UINT"),
-            Self::R64G64B64A64_SINT => Some("R64G64B64A64_SINT"),
-            Self::R64G64B64A64_SFLOAT => Some("R64G64B64A64_SFLOAT"),
-            Self::B10G11R11_UFLOAT_PACK32 => Some("B10G11R11_UFLOAT_PACK32"),
-            Self::E5B9G9R9_UFLOAT_PACK32 => Some("E5B9G9R9_UFLOAT_PACK32"),
-            Self::D16_UNORM => Some("D16_UNORM"),
-            Self::X8_D24_UNORM_PACK32 => Some("X8_D24_UNORM_PACK32"),
-            Self::D32_SFLOAT => Some("D32_SFLOAT"),
-            Self::S8_UINT => Some("S8_UINT"),
-            Self::D16_UNORM_S8_UINT => Some("D16_UNORM_S8_UINT"),
-            Self::D24_UNORM_S8_UINT => Some("D24_UNORM_S8_UINT"),
-            Self::D32_SFLOAT_S8_UINT => Some("D32_SFLOAT_S8_UINT"),
-            Self::BC1_RGB_UNORM_BLOCK => Some("BC1_RGB_UNORM_BLOCK"),
-            Self::BC1_RGB_SRGB_BLOCK => Some("BC1_RGB_SRGB_BLOCK"),
-            Self::BC1_RGBA_UNORM_BLOCK => Some("BC1_RGBA_UNORM_BLOCK"),
-            Self::BC1_RGBA_SRGB_BLOCK => Some("BC1_RGBA_SRGB_BLOCK"),
-            Self::BC2_UNORM_BLOCK => Some("BC2_UNORM_BLOCK"),
-            Self::BC2_SRGB_BLOCK => Some("BC2_SRGB_BLOCK"),
-            Self::BC3_UNORM_BLOCK => Some("BC3_UNORM_BLOCK"),
-            Self::BC3_SRGB_BLOCK => Some("BC3_SRGB_BLOCK"),
-            Self::BC4_UNORM_BLOCK => Some("BC4_UNORM_BLOCK"),
-            Self::BC4_SNORM_BLOCK => Some("BC4_SNORM_BLOCK"),
-            Self::BC5_UNORM_BLOCK => Some("BC5_UNORM_BLOCK"),
-            Self::BC5_SNORM_BLOCK => Some("BC5_SNORM_BLOCK"),
-            Self::BC6H_UFLOAT_BLOCK => Some("BC6H_UFLOAT_BLOCK"),
-            Self::BC6H_SFLOAT_BLOCK => Some("BC6H_SFLOAT_BLOCK"),
-            Self::BC7_UNORM_BLOCK => Some("BC7_UNORM_BLOCK"),
-            Self::BC7_SRGB_BLOCK => Some("BC7_SRGB_BLOCK"),
-            Self::ETC2_R8G8B8_UNORM_BLOCK => Some("ETC2_R8G8B8_UNORM_BLOCK"),
-            Self::ETC2_R8G8B8_SRGB_BLOCK => Some("ETC2_R8G8B8_SRGB_BLOCK"),
-            Self::ETC2_R8G8B8A1_UNORM_BLOCK => Some("ETC2_R8G8B8A1_UNORM_BLOCK"),
-            Self::ETC2_R8G8B8A1_SRGB_BLOCK => Some("ETC2_R8G8B8A1_SRGB_BLOCK"),
-            Self::ETC2_R8G8B8A8_UNORM_BLOCK => Some("ETC2_R8G8B8A8_UNORM_BLOCK"),
-            Self::ETC2_R8G8B8A8_SRGB_BLOCK => Some("ETC2_R8G8B8A8_SRGB_BLOCK"),
-            Self::EAC_R11_UNORM_BLOCK => Some("EAC_R11_UNORM_BLOCK"),
-            Self::EAC_R11_SNORM_BLOCK => Some("EAC_R11_SNORM_BLOCK"),
-            Self::EAC_R11G11_UNORM_BLOCK => Some("EAC_R11G11_UNORM_BLOCK"),
-            Self::EAC_R11G11_SNORM_BLOCK => Some("EAC_R11G11_SNORM_BLOCK"),
-            Self::ASTC_4X4_UNORM_BLOCK => Some("ASTC_4X4_UNORM_BLOCK"),
-            Self::ASTC_4X4_SRGB_BLOCK => Some("ASTC_4X4_SRGB_BLOCK"),
-            Self::ASTC_5X4_UNORM_BLOCK => Some("ASTC_5X4_UNORM_BLOCK"),
-            Self::ASTC_5X4_SRGB_BLOCK => Some("ASTC_5X4_SRGB_BLOCK"),
-            Self::ASTC_5X5_UNORM_BLOCK => Some("ASTC_5X5_UNORM_BLOCK"),
-            Self::ASTC_5X5_SRGB_BLOCK => Some("ASTC_5X5_SRGB_BLOCK"),
-            Self::ASTC_6X5_UNORM_BLOCK => Some("ASTC_6X5_UNORM_BLOCK"),
-            Self::ASTC_6X5_SRGB_BLOCK => Some("ASTC_6X5_SRGB_BLOCK"),
-            Self::ASTC_6X6_UNORM_BLOCK => Some("ASTC_6X6_UNORM_BLOCK"),
-            Self::ASTC_6X6_SRGB_BLOCK => Some("ASTC_6X6_SRGB_BLOCK"),
-            Self::ASTC_8X5_UNORM_BLOCK => Some("ASTC_8X5_UNORM_BLOCK"),
-            Self::ASTC_8X5_SRGB_BLOCK => Some("ASTC_8X5_SRGB_BLOCK"),
-            Self::ASTC_8X6_UNORM_BLOCK => Some("ASTC_8X6_UNORM_BLOCK"),
-            Self::ASTC_8X6_SRGB_BLOCK => Some("ASTC_8X6_SRGB_BLOCK"),
-            Self::ASTC_8X8_UNORM_BLOCK => Some("ASTC_8X8_UNORM_BLOCK"),
-            Self::ASTC_8X8_SRGB_BLOCK => Some("ASTC_8X8_SRGB_BLOCK"),
-            Self::ASTC_10X5_UNORM_BLOCK => Some("ASTC_10X5_UNORM_BLOCK"),
-            Self::ASTC_10X5_SRGB_BLOCK => Some("ASTC_10X5_SRGB_BLOCK"),
-            Self::ASTC_10X6_UNORM_BLOCK => Some("ASTC_10X6_UNORM_BLOCK"),
-            Self::ASTC_10X6_SRGB_BLOCK => Some("ASTC_10X6_SRGB_BLOCK"),
-            Self::ASTC_10X8_UNORM_BLOCK => Some("ASTC_10X8_UNORM_BLOCK"),
-            Self::ASTC_10X8_SRGB_BLOCK => Some("ASTC_10X8_SRGB_BLOCK"),
-            Self::ASTC_10X10_UNORM_BLOCK => Some("ASTC_10X10_UNORM_BLOCK"),
-            Self::ASTC_10X10_SRGB_BLOCK => Some("ASTC_10X10_SRGB_BLOCK"),
-            Self::ASTC_12X10_UNORM_BLOCK => Some("ASTC_12X10_UNORM_BLOCK"),
-            Self::ASTC_12X10_SRGB_BLOCK => Some("ASTC_12X10_SRGB_BLOCK"),
-            Self::ASTC_12X12_UNORM_BLOCK => Some("ASTC_12X12_UNORM_BLOCK"),
-            Self::ASTC_12X12_SRGB_BLOCK => Some("ASTC_12X12_SRGB_BLOCK"),
-            Self::PVRTC1_2BPP_UNORM_BLOCK_IMG => Some("PVRTC1_2BPP_UNORM_BLOCK_IMG"),
-            Self::PVRTC1_4BPP_UNORM_BLOCK_IMG => Some("PVRTC1_4BPP_UNORM_BLOCK_IMG"),
-            Self::PVRTC2_2BPP_UNORM_BLOCK_IMG => Some("PVRTC2_2BPP_UNORM_BLOCK_IMG"),
-            Self::PVRTC2_4BPP_UNORM_BLOCK_IMG => Some("PVRTC2_4BPP_UNORM_BLOCK_IMG"),
-            Self::PVRTC1_2BPP_SRGB_BLOCK_IMG => Some("PVRTC1_2BPP_SRGB_BLOCK_IMG"),
-            Self::PVRTC1_4BPP_SRGB_BLOCK_IMG => Some("PVRTC1_4BPP_SRGB_BLOCK_IMG"),
-            Self::PVRTC2_2BPP_SRGB_BLOCK_IMG => Some("PVRTC2_2BPP_SRGB_BLOCK_IMG"),
-            Self::PVRTC2_4BPP_SRGB_BLOCK_IMG => Some("PVRTC2_4BPP_SRGB_BLOCK_IMG"),
-            Self::ASTC_4X4_SFLOAT_BLOCK_EXT => Some("ASTC_4X4_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_5X4_SFLOAT_BLOCK_EXT => Some("ASTC_5X4_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_5X5_SFLOAT_BLOCK_EXT => Some("ASTC_5X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_6X5_SFLOAT_BLOCK_EXT => Some("ASTC_6X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_6X6_SFLOAT_BLOCK_EXT => Some("ASTC_6X6_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_8X5_SFLOAT_BLOCK_EXT => Some("ASTC_8X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_8X6_SFLOAT_BLOCK_EXT => Some("ASTC_8X6_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_8X8_SFLOAT_BLOCK_EXT => Some("ASTC_8X8_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_10X5_SFLOAT_BLOCK_EXT => Some("ASTC_10X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_10X6_SFLOAT_BLOCK_EXT => Some("ASTC_10X6_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_10X8_SFLOAT_BLOCK_EXT => Some("ASTC_10X8_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_10X10_SFLOAT_BLOCK_EXT => Some("ASTC_10X10_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_12X10_SFLOAT_BLOCK_EXT => Some("ASTC_12X10_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_12X12_SFLOAT_BLOCK_EXT => Some("ASTC_12X12_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_3X3X3_UNORM_BLOCK_EXT => Some("ASTC_3X3X3_UNORM_BLOCK_EXT"),
-            Self::ASTC_3X3X3_SRGB_BLOCK_EXT => Some("ASTC_3X3X3_SRGB_BLOCK_EXT"),
-            Self::ASTC_3X3X3_SFLOAT_BLOCK_EXT => Some("ASTC_3X3X3_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_4X3X3_UNORM_BLOCK_EXT => Some("ASTC_4X3X3_UNORM_BLOCK_EXT"),
-            Self::ASTC_4X3X3_SRGB_BLOCK_EXT => Some("ASTC_4X3X3_SRGB_BLOCK_EXT"),
-            Self::ASTC_4X3X3_SFLOAT_BLOCK_EXT => Some("ASTC_4X3X3_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_4X4X3_UNORM_BLOCK_EXT => Some("ASTC_4X4X3_UNORM_BLOCK_EXT"),
-            Self::ASTC_4X4X3_SRGB_BLOCK_EXT => Some("ASTC_4X4X3_SRGB_BLOCK_EXT"),
-            Self::ASTC_4X4X3_SFLOAT_BLOCK_EXT => Some("ASTC_4X4X3_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_4X4X4_UNORM_BLOCK_EXT => Some("ASTC_4X4X4_UNORM_BLOCK_EXT"),
-            Self::ASTC_4X4X4_SRGB_BLOCK_EXT => Some("ASTC_4X4X4_SRGB_BLOCK_EXT"),
-            Self::ASTC_4X4X4_SFLOAT_BLOCK_EXT => Some("ASTC_4X4X4_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_5X4X4_UNORM_BLOCK_EXT => Some("ASTC_5X4X4_UNORM_BLOCK_EXT"),
-            Self::ASTC_5X4X4_SRGB_BLOCK_EXT => Some("ASTC_5X4X4_SRGB_BLOCK_EXT"),
-            Self::ASTC_5X4X4_SFLOAT_BLOCK_EXT => Some("ASTC_5X4X4_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_5X5X4_UNORM_BLOCK_EXT => Some("ASTC_5X5X4_UNORM_BLOCK_EXT"),
-            Self::ASTC_5X5X4_SRGB_BLOCK_EXT => Some("ASTC_5X5X4_SRGB_BLOCK_EXT"),
-            Self::ASTC_5X5X4_SFLOAT_BLOCK_EXT => Some("ASTC_5X5X4_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_5X5X5_UNORM_BLOCK_EXT => Some("ASTC_5X5X5_UNORM_BLOCK_EXT"),
-            Self::ASTC_5X5X5_SRGB_BLOCK_EXT => Some("ASTC_5X5X5_SRGB_BLOCK_EXT"),
-            Self::ASTC_5X5X5_SFLOAT_BLOCK_EXT => Some("ASTC_5X5X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_6X5X5_UNORM_BLOCK_EXT => Some("ASTC_6X5X5_UNORM_BLOCK_EXT"),
-            Self::ASTC_6X5X5_SRGB_BLOCK_EXT => Some("ASTC_6X5X5_SRGB_BLOCK_EXT"),
-            Self::ASTC_6X5X5_SFLOAT_BLOCK_EXT => Some("ASTC_6X5X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_6X6X5_UNORM_BLOCK_EXT => Some("ASTC_6X6X5_UNORM_BLOCK_EXT"),
-            Self::ASTC_6X6X5_SRGB_BLOCK_EXT => Some("ASTC_6X6X5_SRGB_BLOCK_EXT"),
-            Self::ASTC_6X6X5_SFLOAT_BLOCK_EXT => Some("ASTC_6X6X5_SFLOAT_BLOCK_EXT"),
-            Self::ASTC_6X6X6_UNORM_BLOCK_EXT => Some("ASTC_6X6X6_UNORM_BLOCK_EXT"),
-            Self::ASTC_6X6X6_SRGB_BLOCK_EXT => Some("ASTC_6X6X6_SRGB_BLOCK_EXT"),
-            Self::ASTC_6X6X6_SFLOAT_BLOCK_EXT => Some("ASTC_6X6X6_SFLOAT_BLOCK_EXT"),
-            Self::A4R4G4B4_UNORM_PACK16_EXT => Some("A4R4G4B4_UNORM_PACK16_EXT"),
-            Self::A4B4G4R4_UNORM_PACK16_EXT => Some("A4B4G4R4_UNORM_PACK16_EXT"),
-            Self::G8B8G8R8_422_UNORM => Some("G8B8G8R8_422_UNORM"),
-            Self::B8G8R8G8_422_UNORM => Some("B8G8R8G8_422_UNORM"),
-            Self::G8_B8_R8_3PLANE_420_UNORM => Some("G8_B8_R8_3PLANE_420_UNORM"),
-            Self::G8_B8R8_2PLANE_420_UNORM => Some("G8_B8R8_2PLANE_420_UNORM"),
-            Self::G8_B8_R8_3PLANE_422_UNORM => Some("G8_B8_R8_3PLANE_422_UNORM"),
-            Self::G8_B8R8_2PLANE_422_UNORM => Some("G8_B8R8_2PLANE_422_UNORM"),
-            Self::G8_B8_R8_3PLANE_444_UNORM => Some("G8_B8_R8_3PLANE_444_UNORM"),
-            Self::R10X6_UNORM_PACK16 => Some("R10X6_UNORM_PACK16"),
-            Self::R10X6G10X6_UNORM_2PACK16 => Some("R10X6G10X6_UNORM_2PACK16"),
-            Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16 => Some("R10X6G10X6B10X6A10X6_UNORM_4PACK16"),
-            Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => {
-                Some("G10X6B10X6G10X6R10X6_422_UNORM_4PACK16")
-            }
-            Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => {
-                Some("B10X6G10X6R10X6G10X6_422_UNORM_4PACK16")
-            }
-            Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 => {
-                Some("G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16")
-            }
-            Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 => {
-                Some("G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16")
-            }
-            Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 => {
-                Some("G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16")
-            }
-            Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 => {
-                Some("G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16")
-            }
-            Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 => {
-                Some("G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16")
-            }
-            Self::R12X4_UNORM_PACK16 => Some("R12X4_UNORM_PACK16"),
-            Self::R12X4G12X4_UNORM_2PACK16 => Some("R12X4G12X4_UNORM_2PACK16"),
-            Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16 => Some("R12X4G12X4B12X4A12X4_UNORM_4PACK16"),
-            Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => {
-                Some("G12X4B12X4G12X4R12X4_422_UNORM_4PACK16")
-            }
-            Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => {
-                Some("B12X4G12X4R12X4G12X4_422_UNORM_4PACK16")
-            }
-            Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 => {
-                Some("G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16")
-            }
-            Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 => {
-                Some("G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16")
-            }
-            Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 => {
-                Some("G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16")
-            }
-            Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 => {
-                Some("G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16")
-            }
-            Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 => {
-                Some("G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16")
-            }
-            Self::G16B16G16R16_422_UNORM => Some("G16B16G16R16_422_UNORM"),
-            Self::B16G16R16G16_422_UNORM => Some("B16G16R16G16_422_UNORM"),
-            Self::G16_B16_R16_3PLANE_420_UNORM => Some("G16_B16_R16_3PLANE_420_UNORM"),
-            Self::G16_B16R16_2PLANE_420_UNORM => Some("G16_B16R16_2PLANE_420_UNORM"),
-            Self::G16_B16_R16_3PLANE_422_UNORM => Some("G16_B16_R16_3PLANE_422_UNORM"),
-            Self::G16_B16R16_2PLANE_422_UNORM => Some("G16_B16R16_2PLANE_422_UNORM"),
-            Self::G16_B16_R16_3PLANE_444_UNORM => Some("G16_B16_R16_3PLANE_444_UNORM"),
-            _ => None,
-        };
-        if let Some(x) = name {
-            f.write_str(x)
-        } else {
-            self.0.fmt(f)
-        }
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(i32, &str)] = &[
+            (Format::UNDEFINED.0, "UNDEFINED"),
+            (Format::R4G4_UNORM_PACK8.0, "R4G4_UNORM_PACK8"),
+            (Format::R4G4B4A4_UNORM_PACK16.0, "R4G4B4A4_UNORM_PACK16"),
+            (Format::B4G4R4A4_UNORM_PACK16.0, "B4G4R4A4_UNORM_PACK16"),
+            (Format::R5G6B5_UNORM_PACK16.0, "R5G6B5_UNORM_PACK16"),
+            (Format::B5G6R5_UNORM_PACK16.0, "B5G6R5_UNORM_PACK16"),
+            (Format::R5G5B5A1_UNORM_PACK16.0, "R5G5B5A1_UNORM_PACK16"),
+            (Format::B5G5R5A1_UNORM_PACK16.0, "B5G5R5A1_UNORM_PACK16"),
+            (Format::A1R5G5B5_UNORM_PACK16.0, "A1R5G5B5_UNORM_PACK16"),
+            (Format::R8_UNORM.0, "R8_UNORM"),
+            (Format::R8_SNORM.0, "R8_SNORM"),
+            (Format::R8_USCALED.0, "R8_USCALED"),
+            (Format::R8_SSCALED.0, "R8_SSCALED"),
+            (Format::R8_UINT.0, "R8_UINT"),
+            (Format::R8_SINT.0, "R8_SINT"),
+            (Format::R8_SRGB.0, "R8_SRGB"),
+            (Format::R8G8_UNORM.0, "R8G8_UNORM"),
+            (Format::R8G8_SNORM.0, "R8G8_SNORM"),
+            (Format::R8G8_USCALED.0, "R8G8_USCALED"),
+            (Format::R8G8_SSCALED.0, "R8G8_SSCALED"),
+            (Format::R8G8_UINT.0, "R8G8_UINT"),
+            (Format::R8G8_SINT.0, "R8G8_SINT"),
+            (Format::R8G8_SRGB.0, "R8G8_SRGB"),
+            (Format::R8G8B8_UNORM.0, "R8G8B8_UNORM"),
+            (Format::R8G8B8_SNORM.0, "R8G8B8_SNORM"),
+            (Format::R8G8B8_USCALED.0, "R8G8B8_USCALED"),
+            (Format::R8G8B8_SSCALED.0, "R8G8B8_SSCALED"),
+            (Format::R8G8B8_UINT.0, "R8G8B8_UINT"),
+            (Format::R8G8B8_SINT.0, "R8G8B8_SINT"),
+            (Format::R8G8B8_SRGB.0, "R8G8B8_SRGB"),
+            (Format::B8G8R8_UNORM.0, "B8G8R8_UNORM"),
+            (Format::B8G8R8_SNORM.0, "B8G8R8_SNORM"),
+            (Format::B8G8R8_USCALED.0, "B8G8R8_USCALED"),
+            (Format::B8G8R8_SSCALED.0, "B8G8R8_SSCALED"),
+            (Format::B8G8R8_UINT.0, "B8G8R8_UINT"),
+            (Format::B8G8R8_SINT.0, "B8G8R8_SINT"),
+            (Format::B8G8R8_SRGB.0, "B8G8R8_SRGB"),
+            (Format::R8G8B8A8_UNORM.0, "R8G8B8A8_UNORM"),
+            (Format::R8G8B8A8_SNORM.0, "R8G8B8A8_SNORM"),
+            (Format::R8G8B8A8_USCALED.0, "R8G8B8A8_USCALED"),
+            (Format::R8G8B8A8_SSCALED.0, "R8G8B8A8_SSCALED"),
+            (Format::R8G8B8A8_UINT.0, "R8G8B8A8_UINT"),
+            (Format::R8G8B8A8_SINT.0, "R8G8B8A8_SINT"),
+            (Format::R8G8B8A8_SRGB.0, "R8G8B8A8_SRGB"),
+            (Format::B8G8R8A8_UNORM.0, "B8G8R8A8_UNORM"),
+            (Format::B8G8R8A8_SNORM.0, "B8G8R8A8_SNORM"),
+            (Format::B8G8R8A8_USCALED.0, "B8G8R8A8_USCALED"),
+            (Format::B8G8R8A8_SSCALED.0, "B8G8R8A8_SSCALED"),
+            (Format::B8G8R8A8_UINT.0, "B8G8R8A8_UINT"),
+            (Format::B8G8R8A8_SINT.0, "B8G8R8A8_SINT"),
+            (Format::B8G8R8A8_SRGB.0, "B8G8R8A8_SRGB"),
+            (Format::A8B8G8R8_UNORM_PACK32.0, "A8B8G8R8_UNORM_PACK32"),
+            (Format::A8B8G8R8_SNORM_PACK32.0, "A8B8G8R8_SNORM_PACK32"),
+            (Format::A8B8G8R8_USCALED_PACK32.0, "A8B8G8R8_USCALED_PACK32"),
+            (Format::A8B8G8R8_SSCALED_PACK32.0, "A8B8G8R8_SSCALED_PACK32"),
+            (Format::A8B8G8R8_UINT_PACK32.0, "A8B8G8R8_UINT_PACK32"),
+            (Format::A8B8G8R8_SINT_PACK32.0, "A8B8G8R8_SINT_PACK32"),
+            (Format::A8B8G8R8_SRGB_PACK32.0, "A8B8G8R8_SRGB_PACK32"),
+            (
+                Format::A2R10G10B10_UNORM_PACK32.0,
+                "A2R10G10B10_UNORM_PACK32",
+            ),
+            (
+                Format::A2R10G10B10_SNORM_PACK32.0,
+                "A2R10G10B10_SNORM_PACK32",
+            ),
+            (
+                Format::A2R10G10B10_USCALED_PACK32.0,
+                "A2R10G10B10_USCALED_PACK32",
+            ),
+            (
+                Format::A2R10G10B10_SSCALED_PACK32.0,
+                "A2R10G10B10_SSCALED_PACK32",
+            ),
+            (Format::A2R10G10B10_UINT_PACK32.0, "A2R10G10B10_UINT_PACK32"),
+            (Format::A2R10G10B10_SINT_PACK32.0, "A2R10G10B10_SINT_PACK32"),
+            (
+                Format::A2B10G10R10_UNORM_PACK32.0,
+                "A2B10G10R10_UNORM_PACK32",
+            ),
+            (
+                Format::A2B10G10R10_SNORM_PACK32.0,
+                "A2B10G10R10_SNORM_PACK32",
+            ),
+            (
+                Format::A2B10G10R10_USCALED_PACK32.0,
+                "A2B10G10R10_USCALED_PACK32",
+            ),
+            (
+                Format::A2B10G10R10_SSCALED_PACK32.0,
+                "A2B10G10R10_SSCALED_PACK32",
+            ),
+            (Format::A2B10G10R10_UINT_PACK32.0, "A2B10G10R10_UINT_PACK32"),
+            (Format::A2B10G10R10_SINT_PACK32.0, "A2B10G10R10_SINT_PACK32"),
+            (Format::R16_UNORM.0, "R16_UNORM"),
+            (Format::R16_SNORM.0, "R16_SNORM"),
+            (Format::R16_USCALED.0, "R16_USCALED"),
+            (Format::R16_SSCALED.0, "R16_SSCALED"),
+            (Format::R16_UINT.0, "R16_UINT"),
+            (Format::R16_SINT.0, "R16_SINT"),
+            (Format::R16_SFLOAT.0, "R16_SFLOAT"),
+            (Format::R16G16_UNORM.0, "R16G16_UNORM"),
+            (Format::R16G16_SNORM.0, "R16G16_SNORM"),
+            (Format::R16G16_USCALED.0, "R16G16_USCALED"),
+            (Format::R16G16_SSCALED.0, "R16G16_SSCALED"),
+            (Format::R16G16_UINT.0, "R16G16_UINT"),
+            (Format::R16G16_SINT.0, "R16G16_SINT"),
+            (Format::R16G16_SFLOAT.0, "R16G16_SFLOAT"),
+            (Format::R16G16B16_UNORM.0, "R16G16B16_UNORM"),
+            (Format::R16G16B16_SNORM.0, "R16G16B16_SNORM"),
+            (Format::R16G16B16_USCALED.0, "R16G16B16_USCALED"),
+            (Format::R16G16B16_SSCALED.0, "R16G16B16_SSCALED"),
+            (Format::R16G16B16_UINT.0, "R16G16B16_UINT"),
+            (Format::R16G16B16_SINT.0, "R16G16B16_SINT"),
+            (Format::R16G16B16_SFLOAT.0, "R16G16B16_SFLOAT"),
+            (Format::R16G16B16A16_UNORM.0, "R16G16B16A16_UNORM"),
+            (Format::R16G16B16A16_SNORM.0, "R16G16B16A16_SNORM"),
+            (Format::R16G16B16A16_USCALED.0, "R16G16B16A16_USCALED"),
+            (Format::R16G16B16A16_SSCALED.0, "R16G16B16A16_SSCALED"),
+            (Format::R16G16B16A16_UINT.0, "R16G16B16A16_UINT"),
+            (Format::R16G16B16A16_SINT.0, "R16G16B16A16_SINT"),
+            (Format::R16G16B16A16_SFLOAT.0, "R16G16B16A16_SFLOAT"),
+            (Format::R32_UINT.0, "R32_UINT"),
+            (Format::R32_SINT.0, "R32_SINT"),
+            (Format::R32_SFLOAT.0, "R32_SFLOAT"),
+            (Format::R32G32_UINT.0, "R32G32_UINT"),
+            (Format::R32G32_SINT.0, "R32G32_SINT"),
+            (Format::R32G32_SFLOAT.0, "R32G32_SFLOAT"),
+            (Format::R32G32B32_UINT.0, "R32G32B32_UINT"),
+            (Format::R32G32B32_SINT.0, "R32G32B32_SINT"),
+            (Format::R32G32B32_SFLOAT.0, "R32G32B32_SFLOAT"),
+            (Format::R32G32B32A32_UINT.0, "R32G32B32A32_UINT"),
+            (Format::R32G32B32A32_SINT.0, "R32G32B32A32_SINT"),
+            (Format::R32G32B32A32_SFLOAT.0, "R32G32B32A32_SFLOAT"),
+            (Format::R64_UINT.0, "R64_UINT"),
+            (Format::R64_SINT.0, "R64_SINT"),
+            (Format::R64_SFLOAT.0, "R64_SFLOAT"),
+            (Format::R64G64_UINT.0, "R64G64_UINT"),
+            (Format::R64G64_SINT.0, "R64G64_SINT"),
+            (Format::R64G64_SFLOAT.0, "R64G64_SFLOAT"),
+            (Format::R64G64B64_UINT.0, "R64G64B64_UINT"),
+            (Format::R64G64B64_SINT.0, "R64G64B64_SINT"),
+            (Format::R64G64B64_SFLOAT.0, "R64G64B64_SFLOAT"),
+            (Format::R64G64B64A64_UINT.0, "R64G64B64A64_UINT"),
+            (Format::R64G64B64A64_SINT.0, "R64G64B64A64_SINT"),
+            (Format::R64G64B64A64_SFLOAT.0, "R64G64B64A64_SFLOAT"),
+            (Format::B10G11R11_UFLOAT_PACK32.0, "B10G11R11_UFLOAT_PACK32"),
+            (Format::E5B9G9R9_UFLOAT_PACK32.0, "E5B9G9R9_UFLOAT_PACK32"),
+            (Format::D16_UNORM.0, "D16_UNORM"),
+            (Format::X8_D24_UNORM_PACK32.0, "X8_D24_UNORM_PACK32"),
+            (Format::D32_SFLOAT.0, "D32_SFLOAT"),
+            (Format::S8_UINT.0, "S8_UINT"),
+            (Format::D16_UNORM_S8_UINT.0, "D16_UNORM_S8_UINT"),
+            (Format::D24_UNORM_S8_UINT.0, "D24_UNORM_S8_UINT"),
+            (Format::D32_SFLOAT_S8_UINT.0, "D32_SFLOAT_S8_UINT"),
+            (Format::BC1_RGB_UNORM_BLOCK.0, "BC1_RGB_UNORM_BLOCK"),
+            (Format::BC1_RGB_SRGB_BLOCK.0, "BC1_RGB_SRGB_BLOCK"),
+            (Format::BC1_RGBA_UNORM_BLOCK.0, "BC1_RGBA_UNORM_BLOCK"),
+            (Format::BC1_RGBA_SRGB_BLOCK.0, "BC1_RGBA_SRGB_BLOCK"),
+            (Format::BC2_UNORM_BLOCK.0, "BC2_UNORM_BLOCK"),
+            (Format::BC2_SRGB_BLOCK.0, "BC2_SRGB_BLOCK"),
+            (Format::BC3_UNORM_BLOCK.0, "BC3_UNORM_BLOCK"),
+            (Format::BC3_SRGB_BLOCK.0, "BC3_SRGB_BLOCK"),
+            (Format::BC4_UNORM_BLOCK.0, "BC4_UNORM_BLOCK"),
+            (Format::BC4_SNORM_BLOCK.0, "BC4_SNORM_BLOCK"),
+            (Format::BC5_UNORM_BLOCK.0, "BC5_UNORM_BLOCK"),
+            (Format::BC5_SNORM_BLOCK.0, "BC5_SNORM_BLOCK"),
+            (Format::BC6H_UFLOAT_BLOCK.0, "BC6H_UFLOAT_BLOCK"),
+            (Format::BC6H_SFLOAT_BLOCK.0, "BC6H_SFLOAT_BLOCK"),
+            (Format::BC7_UNORM_BLOCK.0, "BC7_UNORM_BLOCK"),
+            (Format::BC7_SRGB_BLOCK.0, "BC7_SRGB_BLOCK"),
+            (Format::ETC2_R8G8B8_UNORM_BLOCK.0, "ETC2_R8G8B8_UNORM_BLOCK"),
+            (Format::ETC2_R8G8B8_SRGB_BLOCK.0, "ETC2_R8G8B8_SRGB_BLOCK"),
+            (
+                Format::ETC2_R8G8B8A1_UNORM_BLOCK.0,
+                "ETC2_R8G8B8A1_UNORM_BLOCK",
+            ),
+            (
+                Format::ETC2_R8G8B8A1_SRGB_BLOCK.0,
+                "ETC2_R8G8B8A1_SRGB_BLOCK",
+            ),
+            (
+                Format::ETC2_R8G8B8A8_UNORM_BLOCK.0,
+                "ETC2_R8G8B8A8_UNORM_BLOCK",
+            ),
+            (
+                Format::ETC2_R8G8B8A8_SRGB_BLOCK.0,
+                "ETC2_R8G8B8A8_SRGB_BLOCK",
+            ),
+            (Format::EAC_R11_UNORM_BLOCK.0, "EAC_R11_UNORM_BLOCK"),
+            (Format::EAC_R11_SNORM_BLOCK.0, "EAC_R11_SNORM_BLOCK"),
+            (Format::EAC_R11G11_UNORM_BLOCK.0, "EAC_R11G11_UNORM_BLOCK"),
+            (Format::EAC_R11G11_SNORM_BLOCK.0, "EAC_R11G11_SNORM_BLOCK"),
+            (Format::ASTC_4X4_UNORM_BLOCK.0, "ASTC_4X4_UNORM_BLOCK"),
+            (Format::ASTC_4X4_SRGB_BLOCK.0, "ASTC_4X4_SRGB_BLOCK"),
+            (Format::ASTC_5X4_UNORM_BLOCK.0, "ASTC_5X4_UNORM_BLOCK"),
+            (Format::ASTC_5X4_SRGB_BLOCK.0, "ASTC_5X4_SRGB_BLOCK"),
+            (Format::ASTC_5X5_UNORM_BLOCK.0, "ASTC_5X5_UNORM_BLOCK"),
+            (Format::ASTC_5X5_SRGB_BLOCK.0, "ASTC_5X5_SRGB_BLOCK"),
+            (Format::ASTC_6X5_UNORM_BLOCK.0, "ASTC_6X5_UNORM_BLOCK"),
+            (Format::ASTC_6X5_SRGB_BLOCK.0, "ASTC_6X5_SRGB_BLOCK"),
+            (Format::ASTC_6X6_UNORM_BLOCK.0, "ASTC_6X6_UNORM_BLOCK"),
+            (Format::ASTC_6X6_SRGB_BLOCK.0, "ASTC_6X6_SRGB_BLOCK"),
+            (Format::ASTC_8X5_UNORM_BLOCK.0, "ASTC_8X5_UNORM_BLOCK"),
+            (Format::ASTC_8X5_SRGB_BLOCK.0, "ASTC_8X5_SRGB_BLOCK"),
+            (Format::ASTC_8X6_UNORM_BLOCK.0, "ASTC_8X6_UNORM_BLOCK"),
+            (Format::ASTC_8X6_SRGB_BLOCK.0, "ASTC_8X6_SRGB_BLOCK"),
+            (Format::ASTC_8X8_UNORM_BLOCK.0, "ASTC_8X8_UNORM_BLOCK"),
+            (Format::ASTC_8X8_SRGB_BLOCK.0, "ASTC_8X8_SRGB_BLOCK"),
+            (Format::ASTC_10X5_UNORM_BLOCK.0, "ASTC_10X5_UNORM_BLOCK"),
+            (Format::ASTC_10X5_SRGB_BLOCK.0, "ASTC_10X5_SRGB_BLOCK"),
+            (Format::ASTC_10X6_UNORM_BLOCK.0, "ASTC_10X6_UNORM_BLOCK"),
+            (Format::ASTC_10X6_SRGB_BLOCK.0, "ASTC_10X6_SRGB_BLOCK"),
+            (Format::ASTC_10X8_UNORM_BLOCK.0, "ASTC_10X8_UNORM_BLOCK"),
+            (Format::ASTC_10X8_SRGB_BLOCK.0, "ASTC_10X8_SRGB_BLOCK"),
+            (Format::ASTC_10X10_UNORM_BLOCK.0, "ASTC_10X10_UNORM_BLOCK"),
+            (Format::ASTC_10X10_SRGB_BLOCK.0, "ASTC_10X10_SRGB_BLOCK"),
+            (Format::ASTC_12X10_UNORM_BLOCK.0, "ASTC_12X10_UNORM_BLOCK"),
+            (Format::ASTC_12X10_SRGB_BLOCK.0, "ASTC_12X10_SRGB_BLOCK"),
+            (Format::ASTC_12X12_UNORM_BLOCK.0, "ASTC_12X12_UNORM_BLOCK"),
+            (Format::ASTC_12X12_SRGB_BLOCK.0, "ASTC_12X12_SRGB_BLOCK"),
+            (
+                Format::PVRTC1_2BPP_UNORM_BLOCK_IMG.0,
+                "PVRTC1_2BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC1_4BPP_UNORM_BLOCK_IMG.0,
+                "PVRTC1_4BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC2_2BPP_UNORM_BLOCK_IMG.0,
+                "PVRTC2_2BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC2_4BPP_UNORM_BLOCK_IMG.0,
+                "PVRTC2_4BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC1_2BPP_SRGB_BLOCK_IMG.0,
+                "PVRTC1_2BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC1_4BPP_SRGB_BLOCK_IMG.0,
+                "PVRTC1_4BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC2_2BPP_SRGB_BLOCK_IMG.0,
+                "PVRTC2_2BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                Format::PVRTC2_4BPP_SRGB_BLOCK_IMG.0,
+                "PVRTC2_4BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                Format::ASTC_4X4_SFLOAT_BLOCK_EXT.0,
+                "ASTC_4X4_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X4_SFLOAT_BLOCK_EXT.0,
+                "ASTC_5X4_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_5X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_6X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6_SFLOAT_BLOCK_EXT.0,
+                "ASTC_6X6_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_8X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_8X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_8X6_SFLOAT_BLOCK_EXT.0,
+                "ASTC_8X6_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_8X8_SFLOAT_BLOCK_EXT.0,
+                "ASTC_8X8_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_10X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_10X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_10X6_SFLOAT_BLOCK_EXT.0,
+                "ASTC_10X6_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_10X8_SFLOAT_BLOCK_EXT.0,
+                "ASTC_10X8_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_10X10_SFLOAT_BLOCK_EXT.0,
+                "ASTC_10X10_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_12X10_SFLOAT_BLOCK_EXT.0,
+                "ASTC_12X10_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_12X12_SFLOAT_BLOCK_EXT.0,
+                "ASTC_12X12_SFLOAT_BLOCK_EXT",
+            ),
+            (Format::G8B8G8R8_422_UNORM.0, "G8B8G8R8_422_UNORM"),
+            (Format::B8G8R8G8_422_UNORM.0, "B8G8R8G8_422_UNORM"),
+            (
+                Format::G8_B8_R8_3PLANE_420_UNORM.0,
+                "G8_B8_R8_3PLANE_420_UNORM",
+            ),
+            (
+                Format::G8_B8R8_2PLANE_420_UNORM.0,
+                "G8_B8R8_2PLANE_420_UNORM",
+            ),
+            (
+                Format::G8_B8_R8_3PLANE_422_UNORM.0,
+                "G8_B8_R8_3PLANE_422_UNORM",
+            ),
+            (
+                Format::G8_B8R8_2PLANE_422_UNORM.0,
+                "G8_B8R8_2PLANE_422_UNORM",
+            ),
+            (
+                Format::G8_B8_R8_3PLANE_444_UNORM.0,
+                "G8_B8_R8_3PLANE_444_UNORM",
+            ),
+            (Format::R10X6_UNORM_PACK16.0, "R10X6_UNORM_PACK16"),
+            (
+                Format::R10X6G10X6_UNORM_2PACK16.0,
+                "R10X6G10X6_UNORM_2PACK16",
+            ),
+            (
+                Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16.0,
+                "R10X6G10X6B10X6A10X6_UNORM_4PACK16",
+            ),
+            (
+                Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16.0,
+                "G10X6B10X6G10X6R10X6_422_UNORM_4PACK16",
+            ),
+            (
+                Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16.0,
+                "B10X6G10X6R10X6G10X6_422_UNORM_4PACK16",
+            ),
+            (
+                Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16.0,
+                "G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16",
+            ),
+            (
+                Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16.0,
+                "G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16",
+            ),
+            (
+                Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16.0,
+                "G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16",
+            ),
+            (
+                Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16.0,
+                "G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16",
+            ),
+            (
+                Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16.0,
+                "G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16",
+            ),
+            (Format::R12X4_UNORM_PACK16.0, "R12X4_UNORM_PACK16"),
+            (
+                Format::R12X4G12X4_UNORM_2PACK16.0,
+                "R12X4G12X4_UNORM_2PACK16",
+            ),
+            (
+                Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16.0,
+                "R12X4G12X4B12X4A12X4_UNORM_4PACK16",
+            ),
+            (
+                Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16.0,
+                "G12X4B12X4G12X4R12X4_422_UNORM_4PACK16",
+            ),
+            (
+                Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16.0,
+                "B12X4G12X4R12X4G12X4_422_UNORM_4PACK16",
+            ),
+            (
+                Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16.0,
+                "G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16",
+            ),
+            (
+                Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16.0,
+                "G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16",
+            ),
+            (
+                Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16.0,
+                "G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16",
+            ),
+            (
+                Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16.0,
+                "G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16",
+            ),
+            (
+                Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16.0,
+                "G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16",
+            ),
+            (Format::G16B16G16R16_422_UNORM.0, "G16B16G16R16_422_UNORM"),
+            (Format::B16G16R16G16_422_UNORM.0, "B16G16R16G16_422_UNORM"),
+            (
+                Format::G16_B16_R16_3PLANE_420_UNORM.0,
+                "G16_B16_R16_3PLANE_420_UNORM",
+            ),
+            (
+                Format::G16_B16R16_2PLANE_420_UNORM.0,
+                "G16_B16R16_2PLANE_420_UNORM",
+            ),
+            (
+                Format::G16_B16_R16_3PLANE_422_UNORM.0,
+                "G16_B16_R16_3PLANE_422_UNORM",
+            ),
+            (
+                Format::G16_B16R16_2PLANE_422_UNORM.0,
+                "G16_B16R16_2PLANE_422_UNORM",
+            ),
+            (
+                Format::G16_B16_R16_3PLANE_444_UNORM.0,
+                "G16_B16_R16_3PLANE_444_UNORM",
+            ),
+            (
+                Format::ASTC_3X3X3_UNORM_BLOCK_EXT.0,
+                "ASTC_3X3X3_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_3X3X3_SRGB_BLOCK_EXT.0,
+                "ASTC_3X3X3_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_3X3X3_SFLOAT_BLOCK_EXT.0,
+                "ASTC_3X3X3_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X3X3_UNORM_BLOCK_EXT.0,
+                "ASTC_4X3X3_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X3X3_SRGB_BLOCK_EXT.0,
+                "ASTC_4X3X3_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X3X3_SFLOAT_BLOCK_EXT.0,
+                "ASTC_4X3X3_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X3_UNORM_BLOCK_EXT.0,
+                "ASTC_4X4X3_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X3_SRGB_BLOCK_EXT.0,
+                "ASTC_4X4X3_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X3_SFLOAT_BLOCK_EXT.0,
+                "ASTC_4X4X3_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X4_UNORM_BLOCK_EXT.0,
+                "ASTC_4X4X4_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X4_SRGB_BLOCK_EXT.0,
+                "ASTC_4X4X4_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_4X4X4_SFLOAT_BLOCK_EXT.0,
+                "ASTC_4X4X4_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X4X4_UNORM_BLOCK_EXT.0,
+                "ASTC_5X4X4_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X4X4_SRGB_BLOCK_EXT.0,
+                "ASTC_5X4X4_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X4X4_SFLOAT_BLOCK_EXT.0,
+                "ASTC_5X4X4_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X4_UNORM_BLOCK_EXT.0,
+                "ASTC_5X5X4_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X4_SRGB_BLOCK_EXT.0,
+                "ASTC_5X5X4_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X4_SFLOAT_BLOCK_EXT.0,
+                "ASTC_5X5X4_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X5_UNORM_BLOCK_EXT.0,
+                "ASTC_5X5X5_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X5_SRGB_BLOCK_EXT.0,
+                "ASTC_5X5X5_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_5X5X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_5X5X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X5X5_UNORM_BLOCK_EXT.0,
+                "ASTC_6X5X5_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X5X5_SRGB_BLOCK_EXT.0,
+                "ASTC_6X5X5_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X5X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_6X5X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X5_UNORM_BLOCK_EXT.0,
+                "ASTC_6X6X5_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X5_SRGB_BLOCK_EXT.0,
+                "ASTC_6X6X5_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X5_SFLOAT_BLOCK_EXT.0,
+                "ASTC_6X6X5_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X6_UNORM_BLOCK_EXT.0,
+                "ASTC_6X6X6_UNORM_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X6_SRGB_BLOCK_EXT.0,
+                "ASTC_6X6X6_SRGB_BLOCK_EXT",
+            ),
+            (
+                Format::ASTC_6X6X6_SFLOAT_BLOCK_EXT.0,
+                "ASTC_6X6X6_SFLOAT_BLOCK_EXT",
+            ),
+            (
+                Format::A4R4G4B4_UNORM_PACK16_EXT.0,
+                "A4R4G4B4_UNORM_PACK16_EXT",
+            ),
+            (
+                Format::A4B4G4R4_UNORM_PACK16_EXT.0,
+                "A4B4G4R4_UNORM_PACK16_EXT",
+            ),
+        ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(i32, &str)] = &[];
+        debug_enum(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for FormatFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN : & [(Flags , & str)] = & [(FormatFeatureFlags :: SAMPLED_IMAGE . 0 , "SAMPLED_IMAGE") , (FormatFeatureFlags :: STORAGE_IMAGE . 0 , "STORAGE_IMAGE") , (FormatFeatureFlags :: STORAGE_IMAGE_ATOMIC . 0 , "STORAGE_IMAGE_ATOMIC") , (FormatFeatureFlags :: UNIFORM_TEXEL_BUFFER . 0 , "UNIFORM_TEXEL_BUFFER") , (FormatFeatureFlags :: STORAGE_TEXEL_BUFFER . 0 , "STORAGE_TEXEL_BUFFER") , (FormatFeatureFlags :: STORAGE_TEXEL_BUFFER_ATOMIC . 0 , "STORAGE_TEXEL_BUFFER_ATOMIC") , (FormatFeatureFlags :: VERTEX_BUFFER . 0 , "VERTEX_BUFFER") , (FormatFeatureFlags :: COLOR_ATTACHMENT . 0 , "COLOR_ATTACHMENT") , (FormatFeatureFlags :: COLOR_ATTACHMENT_BLEND . 0 , "COLOR_ATTACHMENT_BLEND") , (FormatFeatureFlags :: DEPTH_STENCIL_ATTACHMENT . 0 , "DEPTH_STENCIL_ATTACHMENT") , (FormatFeatureFlags :: BLIT_SRC . 0 , "BLIT_SRC") , (FormatFeatureFlags :: BLIT_DST . 0 , "BLIT_DST") , (FormatFeatureFlags :: SAMPLED_IMAGE_FILTER_LINEAR . 0 , "SAMPLED_IMAGE_FILTER_LINEAR") , (FormatFeatureFlags :: SAMPLED_IMAGE_FILTER_CUBIC_IMG . 0 , "SAMPLED_IMAGE_FILTER_CUBIC_IMG") , (FormatFeatureFlags :: RESERVED_27_KHR . 0 , "RESERVED_27_KHR") , (FormatFeatureFlags :: RESERVED_28_KHR . 0 , "RESERVED_28_KHR") , (FormatFeatureFlags :: RESERVED_25_KHR . 0 , "RESERVED_25_KHR") , (FormatFeatureFlags :: RESERVED_26_KHR . 0 , "RESERVED_26_KHR") , (FormatFeatureFlags :: ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR . 0 , "ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR") , (FormatFeatureFlags :: FRAGMENT_DENSITY_MAP_EXT . 0 , "FRAGMENT_DENSITY_MAP_EXT") , (FormatFeatureFlags :: FRAGMENT_SHADING_RATE_ATTACHMENT_KHR . 0 , "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR") , (FormatFeatureFlags :: TRANSFER_SRC . 0 , "TRANSFER_SRC") , (FormatFeatureFlags :: TRANSFER_DST . 0 , "TRANSFER_DST") , (FormatFeatureFlags :: MIDPOINT_CHROMA_SAMPLES . 0 , "MIDPOINT_CHROMA_SAMPLES") , (FormatFeatureFlags :: SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER") , (FormatFeatureFlags :: SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER") , (FormatFeatureFlags :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT") , (FormatFeatureFlags :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE") , (FormatFeatureFlags :: DISJOINT . 0 , "DISJOINT") , (FormatFeatureFlags :: COSITED_CHROMA_SAMPLES . 0 , "COSITED_CHROMA_SAMPLES") , (FormatFeatureFlags :: SAMPLED_IMAGE_FILTER_MINMAX . 0 , "SAMPLED_IMAGE_FILTER_MINMAX")] ;
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for FragmentShadingRateCombinerOpKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::KEEP => Some("KEEP"),
             Self::REPLACE => Some("REPLACE"),
@@ -1715,6 +2228,8 @@ impl fmt::Debug for FragmentShadingRateCombinerOpKHR {
             Self::MUL => Some("MUL"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1724,6 +2239,7 @@ impl fmt::Debug for FragmentShadingRateCombinerOpKHR {
 }
 impl fmt::Debug for FragmentShadingRateNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TYPE_1_INVOCATION_PER_PIXEL => Some("TYPE_1_INVOCATION_PER_PIXEL"),
             Self::TYPE_1_INVOCATION_PER_1X2_PIXELS => Some("TYPE_1_INVOCATION_PER_1X2_PIXELS"),
@@ -1739,6 +2255,8 @@ impl fmt::Debug for FragmentShadingRateNV {
             Self::NO_INVOCATIONS => Some("NO_INVOCATIONS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1748,11 +2266,14 @@ impl fmt::Debug for FragmentShadingRateNV {
 }
 impl fmt::Debug for FragmentShadingRateTypeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::FRAGMENT_SIZE => Some("FRAGMENT_SIZE"),
             Self::ENUMS => Some("ENUMS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1762,17 +2283,23 @@ impl fmt::Debug for FragmentShadingRateTypeNV {
 }
 impl fmt::Debug for FramebufferCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(FramebufferCreateFlags::IMAGELESS.0, "IMAGELESS")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for FrontFace {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::COUNTER_CLOCKWISE => Some("COUNTER_CLOCKWISE"),
             Self::CLOCKWISE => Some("CLOCKWISE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1782,6 +2309,7 @@ impl fmt::Debug for FrontFace {
 }
 impl fmt::Debug for FullScreenExclusiveEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEFAULT => Some("DEFAULT"),
             Self::ALLOWED => Some("ALLOWED"),
@@ -1789,6 +2317,8 @@ impl fmt::Debug for FullScreenExclusiveEXT {
             Self::APPLICATION_CONTROLLED => Some("APPLICATION_CONTROLLED"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1798,6 +2328,7 @@ impl fmt::Debug for FullScreenExclusiveEXT {
 }
 impl fmt::Debug for GeometryFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (GeometryFlagsKHR::OPAQUE.0, "OPAQUE"),
             (
@@ -1805,11 +2336,14 @@ impl fmt::Debug for GeometryFlagsKHR {
                 "NO_DUPLICATE_ANY_HIT_INVOCATION",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for GeometryInstanceFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 GeometryInstanceFlagsKHR::TRIANGLE_FACING_CULL_DISABLE.0,
@@ -1825,17 +2359,22 @@ impl fmt::Debug for GeometryInstanceFlagsKHR {
                 "FORCE_NO_OPAQUE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for GeometryTypeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TRIANGLES => Some("TRIANGLES"),
             Self::AABBS => Some("AABBS"),
             Self::INSTANCES => Some("INSTANCES"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1845,18 +2384,25 @@ impl fmt::Debug for GeometryTypeKHR {
 }
 impl fmt::Debug for HeadlessSurfaceCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for IOSSurfaceCreateFlagsMVK {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageAspectFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ImageAspectFlags::COLOR.0, "COLOR"),
             (ImageAspectFlags::DEPTH.0, "DEPTH"),
@@ -1870,11 +2416,14 @@ impl fmt::Debug for ImageAspectFlags {
             (ImageAspectFlags::PLANE_1.0, "PLANE_1"),
             (ImageAspectFlags::PLANE_2.0, "PLANE_2"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ImageCreateFlags::SPARSE_BINDING.0, "SPARSE_BINDING"),
             (ImageCreateFlags::SPARSE_RESIDENCY.0, "SPARSE_RESIDENCY"),
@@ -1905,11 +2454,14 @@ impl fmt::Debug for ImageCreateFlags {
             (ImageCreateFlags::PROTECTED.0, "PROTECTED"),
             (ImageCreateFlags::DISJOINT.0, "DISJOINT"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageLayout {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UNDEFINED => Some("UNDEFINED"),
             Self::GENERAL => Some("GENERAL"),
@@ -1936,6 +2488,8 @@ impl fmt::Debug for ImageLayout {
             Self::STENCIL_READ_ONLY_OPTIMAL => Some("STENCIL_READ_ONLY_OPTIMAL"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1945,18 +2499,24 @@ impl fmt::Debug for ImageLayout {
 }
 impl fmt::Debug for ImagePipeSurfaceCreateFlagsFUCHSIA {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageTiling {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::OPTIMAL => Some("OPTIMAL"),
             Self::LINEAR => Some("LINEAR"),
             Self::DRM_FORMAT_MODIFIER_EXT => Some("DRM_FORMAT_MODIFIER_EXT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1966,12 +2526,15 @@ impl fmt::Debug for ImageTiling {
 }
 impl fmt::Debug for ImageType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TYPE_1D => Some("TYPE_1D"),
             Self::TYPE_2D => Some("TYPE_2D"),
             Self::TYPE_3D => Some("TYPE_3D"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -1981,6 +2544,7 @@ impl fmt::Debug for ImageType {
 }
 impl fmt::Debug for ImageUsageFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ImageUsageFlags::TRANSFER_SRC.0, "TRANSFER_SRC"),
             (ImageUsageFlags::TRANSFER_DST.0, "TRANSFER_DST"),
@@ -2013,11 +2577,14 @@ impl fmt::Debug for ImageUsageFlags {
                 "FRAGMENT_DENSITY_MAP_EXT",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageViewCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 ImageViewCreateFlags::FRAGMENT_DENSITY_MAP_DYNAMIC_EXT.0,
@@ -2028,11 +2595,14 @@ impl fmt::Debug for ImageViewCreateFlags {
                 "FRAGMENT_DENSITY_MAP_DEFERRED_EXT",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ImageViewType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TYPE_1D => Some("TYPE_1D"),
             Self::TYPE_2D => Some("TYPE_2D"),
@@ -2043,6 +2613,8 @@ impl fmt::Debug for ImageViewType {
             Self::CUBE_ARRAY => Some("CUBE_ARRAY"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2052,6 +2624,7 @@ impl fmt::Debug for ImageViewType {
 }
 impl fmt::Debug for IndexType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UINT16 => Some("UINT16"),
             Self::UINT32 => Some("UINT32"),
@@ -2059,6 +2632,8 @@ impl fmt::Debug for IndexType {
             Self::UINT8_EXT => Some("UINT8_EXT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2068,6 +2643,7 @@ impl fmt::Debug for IndexType {
 }
 impl fmt::Debug for IndirectCommandsLayoutUsageFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 IndirectCommandsLayoutUsageFlagsNV::EXPLICIT_PREPROCESS.0,
@@ -2082,11 +2658,14 @@ impl fmt::Debug for IndirectCommandsLayoutUsageFlagsNV {
                 "UNORDERED_SEQUENCES",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for IndirectCommandsTokenTypeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::SHADER_GROUP => Some("SHADER_GROUP"),
             Self::STATE_FLAGS => Some("STATE_FLAGS"),
@@ -2098,6 +2677,8 @@ impl fmt::Debug for IndirectCommandsTokenTypeNV {
             Self::DRAW_TASKS => Some("DRAW_TASKS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2107,23 +2688,32 @@ impl fmt::Debug for IndirectCommandsTokenTypeNV {
 }
 impl fmt::Debug for IndirectStateFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] =
             &[(IndirectStateFlagsNV::FLAG_FRONTFACE.0, "FLAG_FRONTFACE")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for InstanceCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for InternalAllocationType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::EXECUTABLE => Some("EXECUTABLE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2133,6 +2723,7 @@ impl fmt::Debug for InternalAllocationType {
 }
 impl fmt::Debug for LineRasterizationModeEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEFAULT => Some("DEFAULT"),
             Self::RECTANGULAR => Some("RECTANGULAR"),
@@ -2140,6 +2731,8 @@ impl fmt::Debug for LineRasterizationModeEXT {
             Self::RECTANGULAR_SMOOTH => Some("RECTANGULAR_SMOOTH"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2149,6 +2742,7 @@ impl fmt::Debug for LineRasterizationModeEXT {
 }
 impl fmt::Debug for LogicOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::CLEAR => Some("CLEAR"),
             Self::AND => Some("AND"),
@@ -2168,6 +2762,8 @@ impl fmt::Debug for LogicOp {
             Self::SET => Some("SET"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2177,12 +2773,16 @@ impl fmt::Debug for LogicOp {
 }
 impl fmt::Debug for MacOSSurfaceCreateFlagsMVK {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for MemoryAllocateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (MemoryAllocateFlags::DEVICE_MASK.0, "DEVICE_MASK"),
             (MemoryAllocateFlags::DEVICE_ADDRESS.0, "DEVICE_ADDRESS"),
@@ -2191,33 +2791,44 @@ impl fmt::Debug for MemoryAllocateFlags {
                 "DEVICE_ADDRESS_CAPTURE_REPLAY",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for MemoryHeapFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (MemoryHeapFlags::DEVICE_LOCAL.0, "DEVICE_LOCAL"),
             (MemoryHeapFlags::RESERVED_2_KHR.0, "RESERVED_2_KHR"),
             (MemoryHeapFlags::MULTI_INSTANCE.0, "MULTI_INSTANCE"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for MemoryMapFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for MemoryOverallocationBehaviorAMD {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEFAULT => Some("DEFAULT"),
             Self::ALLOWED => Some("ALLOWED"),
             Self::DISALLOWED => Some("DISALLOWED"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2227,6 +2838,7 @@ impl fmt::Debug for MemoryOverallocationBehaviorAMD {
 }
 impl fmt::Debug for MemoryPropertyFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (MemoryPropertyFlags::DEVICE_LOCAL.0, "DEVICE_LOCAL"),
             (MemoryPropertyFlags::HOST_VISIBLE.0, "HOST_VISIBLE"),
@@ -2243,17 +2855,23 @@ impl fmt::Debug for MemoryPropertyFlags {
             ),
             (MemoryPropertyFlags::PROTECTED.0, "PROTECTED"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for MetalSurfaceCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ObjectType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UNKNOWN => Some("UNKNOWN"),
             Self::INSTANCE => Some("INSTANCE"),
@@ -2298,6 +2916,8 @@ impl fmt::Debug for ObjectType {
             Self::DESCRIPTOR_UPDATE_TEMPLATE => Some("DESCRIPTOR_UPDATE_TEMPLATE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2307,18 +2927,24 @@ impl fmt::Debug for ObjectType {
 }
 impl fmt::Debug for PeerMemoryFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (PeerMemoryFeatureFlags::COPY_SRC.0, "COPY_SRC"),
             (PeerMemoryFeatureFlags::COPY_DST.0, "COPY_DST"),
             (PeerMemoryFeatureFlags::GENERIC_SRC.0, "GENERIC_SRC"),
             (PeerMemoryFeatureFlags::GENERIC_DST.0, "GENERIC_DST"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PerformanceConfigurationTypeINTEL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match * self { Self :: PERFORMANCE_CONFIGURATION_TYPE_COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED_INTEL => Some ("PERFORMANCE_CONFIGURATION_TYPE_COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED_INTEL") , _ => None , } ;
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2328,6 +2954,7 @@ impl fmt::Debug for PerformanceConfigurationTypeINTEL {
 }
 impl fmt::Debug for PerformanceCounterDescriptionFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 PerformanceCounterDescriptionFlagsKHR::PERFORMANCE_IMPACTING.0,
@@ -2338,17 +2965,22 @@ impl fmt::Debug for PerformanceCounterDescriptionFlagsKHR {
                 "CONCURRENTLY_IMPACTED",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PerformanceCounterScopeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::COMMAND_BUFFER => Some("COMMAND_BUFFER"),
             Self::RENDER_PASS => Some("RENDER_PASS"),
             Self::COMMAND => Some("COMMAND"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2358,6 +2990,7 @@ impl fmt::Debug for PerformanceCounterScopeKHR {
 }
 impl fmt::Debug for PerformanceCounterStorageKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::INT32 => Some("INT32"),
             Self::INT64 => Some("INT64"),
@@ -2367,6 +3000,8 @@ impl fmt::Debug for PerformanceCounterStorageKHR {
             Self::FLOAT64 => Some("FLOAT64"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2376,6 +3011,7 @@ impl fmt::Debug for PerformanceCounterStorageKHR {
 }
 impl fmt::Debug for PerformanceCounterUnitKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::GENERIC => Some("GENERIC"),
             Self::PERCENTAGE => Some("PERCENTAGE"),
@@ -2390,6 +3026,8 @@ impl fmt::Debug for PerformanceCounterUnitKHR {
             Self::CYCLES => Some("CYCLES"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2399,6 +3037,7 @@ impl fmt::Debug for PerformanceCounterUnitKHR {
 }
 impl fmt::Debug for PerformanceOverrideTypeINTEL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::PERFORMANCE_OVERRIDE_TYPE_NULL_HARDWARE_INTEL => {
                 Some("PERFORMANCE_OVERRIDE_TYPE_NULL_HARDWARE_INTEL")
@@ -2408,6 +3047,8 @@ impl fmt::Debug for PerformanceOverrideTypeINTEL {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2417,6 +3058,7 @@ impl fmt::Debug for PerformanceOverrideTypeINTEL {
 }
 impl fmt::Debug for PerformanceParameterTypeINTEL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::PERFORMANCE_PARAMETER_TYPE_HW_COUNTERS_SUPPORTED_INTEL => {
                 Some("PERFORMANCE_PARAMETER_TYPE_HW_COUNTERS_SUPPORTED_INTEL")
@@ -2426,6 +3068,8 @@ impl fmt::Debug for PerformanceParameterTypeINTEL {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2435,6 +3079,7 @@ impl fmt::Debug for PerformanceParameterTypeINTEL {
 }
 impl fmt::Debug for PerformanceValueTypeINTEL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::PERFORMANCE_VALUE_TYPE_UINT32_INTEL => {
                 Some("PERFORMANCE_VALUE_TYPE_UINT32_INTEL")
@@ -2449,6 +3094,8 @@ impl fmt::Debug for PerformanceValueTypeINTEL {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2458,6 +3105,7 @@ impl fmt::Debug for PerformanceValueTypeINTEL {
 }
 impl fmt::Debug for PhysicalDeviceType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::OTHER => Some("OTHER"),
             Self::INTEGRATED_GPU => Some("INTEGRATED_GPU"),
@@ -2466,6 +3114,8 @@ impl fmt::Debug for PhysicalDeviceType {
             Self::CPU => Some("CPU"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2475,12 +3125,15 @@ impl fmt::Debug for PhysicalDeviceType {
 }
 impl fmt::Debug for PipelineBindPoint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::GRAPHICS => Some("GRAPHICS"),
             Self::COMPUTE => Some("COMPUTE"),
             Self::RAY_TRACING_KHR => Some("RAY_TRACING_KHR"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2490,6 +3143,7 @@ impl fmt::Debug for PipelineBindPoint {
 }
 impl fmt::Debug for PipelineCacheCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (PipelineCacheCreateFlags::RESERVED_1_EXT.0, "RESERVED_1_EXT"),
             (
@@ -2498,15 +3152,20 @@ impl fmt::Debug for PipelineCacheCreateFlags {
             ),
             (PipelineCacheCreateFlags::RESERVED_2_EXT.0, "RESERVED_2_EXT"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCacheHeaderVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ONE => Some("ONE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2516,36 +3175,52 @@ impl fmt::Debug for PipelineCacheHeaderVersion {
 }
 impl fmt::Debug for PipelineColorBlendStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCompilerControlFlagsAMD {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCoverageModulationStateCreateFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCoverageReductionStateCreateFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCoverageToColorStateCreateFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 PipelineCreateFlags::DISABLE_OPTIMIZATION.0,
@@ -2612,11 +3287,14 @@ impl fmt::Debug for PipelineCreateFlags {
             ),
             (PipelineCreateFlags::DISPATCH_BASE.0, "DISPATCH_BASE"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineCreationFeedbackFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (PipelineCreationFeedbackFlagsEXT::VALID.0, "VALID"),
             (
@@ -2628,29 +3306,41 @@ impl fmt::Debug for PipelineCreationFeedbackFlagsEXT {
                 "BASE_PIPELINE_ACCELERATION",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineDepthStencilStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineDiscardRectangleStateCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineDynamicStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineExecutableStatisticFormatKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::BOOL32 => Some("BOOL32"),
             Self::INT64 => Some("INT64"),
@@ -2658,6 +3348,8 @@ impl fmt::Debug for PipelineExecutableStatisticFormatKHR {
             Self::FLOAT64 => Some("FLOAT64"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2667,48 +3359,70 @@ impl fmt::Debug for PipelineExecutableStatisticFormatKHR {
 }
 impl fmt::Debug for PipelineInputAssemblyStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineLayoutCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineMultisampleStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineRasterizationConservativeStateCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineRasterizationDepthClipStateCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineRasterizationStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineRasterizationStateStreamCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineShaderStageCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 PipelineShaderStageCreateFlags::RESERVED_2_NV.0,
@@ -2727,11 +3441,14 @@ impl fmt::Debug for PipelineShaderStageCreateFlags {
                 "RESERVED_3_KHR",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineStageFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (PipelineStageFlags::TOP_OF_PIPE.0, "TOP_OF_PIPE"),
             (PipelineStageFlags::DRAW_INDIRECT.0, "DRAW_INDIRECT"),
@@ -2798,40 +3515,57 @@ impl fmt::Debug for PipelineStageFlags {
                 "COMMAND_PREPROCESS_NV",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineTessellationStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineVertexInputStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineViewportStateCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PipelineViewportSwizzleStateCreateFlagsNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for PointClippingBehavior {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ALL_CLIP_PLANES => Some("ALL_CLIP_PLANES"),
             Self::USER_CLIP_PLANES_ONLY => Some("USER_CLIP_PLANES_ONLY"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2841,6 +3575,7 @@ impl fmt::Debug for PointClippingBehavior {
 }
 impl fmt::Debug for PolygonMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::FILL => Some("FILL"),
             Self::LINE => Some("LINE"),
@@ -2848,6 +3583,8 @@ impl fmt::Debug for PolygonMode {
             Self::FILL_RECTANGLE_NV => Some("FILL_RECTANGLE_NV"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2857,6 +3594,7 @@ impl fmt::Debug for PolygonMode {
 }
 impl fmt::Debug for PresentModeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::IMMEDIATE => Some("IMMEDIATE"),
             Self::MAILBOX => Some("MAILBOX"),
@@ -2866,6 +3604,8 @@ impl fmt::Debug for PresentModeKHR {
             Self::SHARED_CONTINUOUS_REFRESH => Some("SHARED_CONTINUOUS_REFRESH"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2875,6 +3615,7 @@ impl fmt::Debug for PresentModeKHR {
 }
 impl fmt::Debug for PrimitiveTopology {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::POINT_LIST => Some("POINT_LIST"),
             Self::LINE_LIST => Some("LINE_LIST"),
@@ -2889,6 +3630,8 @@ impl fmt::Debug for PrimitiveTopology {
             Self::PATCH_LIST => Some("PATCH_LIST"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2898,18 +3641,25 @@ impl fmt::Debug for PrimitiveTopology {
 }
 impl fmt::Debug for PrivateDataSlotCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueryControlFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(QueryControlFlags::PRECISE.0, "PRECISE")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueryPipelineStatisticFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 QueryPipelineStatisticFlags::INPUT_ASSEMBLY_VERTICES.0,
@@ -2956,23 +3706,31 @@ impl fmt::Debug for QueryPipelineStatisticFlags {
                 "COMPUTE_SHADER_INVOCATIONS",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueryPoolCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueryPoolSamplingModeINTEL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::QUERY_POOL_SAMPLING_MODE_MANUAL_INTEL => {
                 Some("QUERY_POOL_SAMPLING_MODE_MANUAL_INTEL")
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -2982,17 +3740,21 @@ impl fmt::Debug for QueryPoolSamplingModeINTEL {
 }
 impl fmt::Debug for QueryResultFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (QueryResultFlags::TYPE_64.0, "TYPE_64"),
             (QueryResultFlags::WAIT.0, "WAIT"),
             (QueryResultFlags::WITH_AVAILABILITY.0, "WITH_AVAILABILITY"),
             (QueryResultFlags::PARTIAL.0, "PARTIAL"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueryType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::OCCLUSION => Some("OCCLUSION"),
             Self::PIPELINE_STATISTICS => Some("PIPELINE_STATISTICS"),
@@ -3013,6 +3775,8 @@ impl fmt::Debug for QueryType {
             Self::PERFORMANCE_QUERY_INTEL => Some("PERFORMANCE_QUERY_INTEL"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3022,6 +3786,7 @@ impl fmt::Debug for QueryType {
 }
 impl fmt::Debug for QueueFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (QueueFlags::GRAPHICS.0, "GRAPHICS"),
             (QueueFlags::COMPUTE.0, "COMPUTE"),
@@ -3031,11 +3796,14 @@ impl fmt::Debug for QueueFlags {
             (QueueFlags::RESERVED_5_KHR.0, "RESERVED_5_KHR"),
             (QueueFlags::PROTECTED.0, "PROTECTED"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for QueueGlobalPriorityEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::LOW => Some("LOW"),
             Self::MEDIUM => Some("MEDIUM"),
@@ -3043,6 +3811,8 @@ impl fmt::Debug for QueueGlobalPriorityEXT {
             Self::REALTIME => Some("REALTIME"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3052,11 +3822,14 @@ impl fmt::Debug for QueueGlobalPriorityEXT {
 }
 impl fmt::Debug for RasterizationOrderAMD {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::STRICT => Some("STRICT"),
             Self::RELAXED => Some("RELAXED"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3066,12 +3839,15 @@ impl fmt::Debug for RasterizationOrderAMD {
 }
 impl fmt::Debug for RayTracingShaderGroupTypeKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::GENERAL => Some("GENERAL"),
             Self::TRIANGLES_HIT_GROUP => Some("TRIANGLES_HIT_GROUP"),
             Self::PROCEDURAL_HIT_GROUP => Some("PROCEDURAL_HIT_GROUP"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3081,15 +3857,19 @@ impl fmt::Debug for RayTracingShaderGroupTypeKHR {
 }
 impl fmt::Debug for RenderPassCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (RenderPassCreateFlags::RESERVED_0_KHR.0, "RESERVED_0_KHR"),
             (RenderPassCreateFlags::TRANSFORM_QCOM.0, "TRANSFORM_QCOM"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ResolveModeFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ResolveModeFlags::NONE.0, "NONE"),
             (ResolveModeFlags::SAMPLE_ZERO.0, "SAMPLE_ZERO"),
@@ -3097,11 +3877,14 @@ impl fmt::Debug for ResolveModeFlags {
             (ResolveModeFlags::MIN.0, "MIN"),
             (ResolveModeFlags::MAX.0, "MAX"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for Result {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::SUCCESS => Some("SUCCESS"),
             Self::NOT_READY => Some("NOT_READY"),
@@ -3149,6 +3932,8 @@ impl fmt::Debug for Result {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3158,6 +3943,7 @@ impl fmt::Debug for Result {
 }
 impl fmt::Debug for SampleCountFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (SampleCountFlags::TYPE_1.0, "TYPE_1"),
             (SampleCountFlags::TYPE_2.0, "TYPE_2"),
@@ -3167,11 +3953,14 @@ impl fmt::Debug for SampleCountFlags {
             (SampleCountFlags::TYPE_32.0, "TYPE_32"),
             (SampleCountFlags::TYPE_64.0, "TYPE_64"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SamplerAddressMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::REPEAT => Some("REPEAT"),
             Self::MIRRORED_REPEAT => Some("MIRRORED_REPEAT"),
@@ -3180,6 +3969,8 @@ impl fmt::Debug for SamplerAddressMode {
             Self::MIRROR_CLAMP_TO_EDGE => Some("MIRROR_CLAMP_TO_EDGE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3189,6 +3980,7 @@ impl fmt::Debug for SamplerAddressMode {
 }
 impl fmt::Debug for SamplerCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (SamplerCreateFlags::SUBSAMPLED_EXT.0, "SUBSAMPLED_EXT"),
             (
@@ -3196,16 +3988,21 @@ impl fmt::Debug for SamplerCreateFlags {
                 "SUBSAMPLED_COARSE_RECONSTRUCTION_EXT",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SamplerMipmapMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::NEAREST => Some("NEAREST"),
             Self::LINEAR => Some("LINEAR"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3215,12 +4012,15 @@ impl fmt::Debug for SamplerMipmapMode {
 }
 impl fmt::Debug for SamplerReductionMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::WEIGHTED_AVERAGE => Some("WEIGHTED_AVERAGE"),
             Self::MIN => Some("MIN"),
             Self::MAX => Some("MAX"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3230,6 +4030,7 @@ impl fmt::Debug for SamplerReductionMode {
 }
 impl fmt::Debug for SamplerYcbcrModelConversion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::RGB_IDENTITY => Some("RGB_IDENTITY"),
             Self::YCBCR_IDENTITY => Some("YCBCR_IDENTITY"),
@@ -3238,6 +4039,8 @@ impl fmt::Debug for SamplerYcbcrModelConversion {
             Self::YCBCR_2020 => Some("YCBCR_2020"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3247,11 +4050,14 @@ impl fmt::Debug for SamplerYcbcrModelConversion {
 }
 impl fmt::Debug for SamplerYcbcrRange {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ITU_FULL => Some("ITU_FULL"),
             Self::ITU_NARROW => Some("ITU_NARROW"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3261,6 +4067,7 @@ impl fmt::Debug for SamplerYcbcrRange {
 }
 impl fmt::Debug for ScopeNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEVICE => Some("DEVICE"),
             Self::WORKGROUP => Some("WORKGROUP"),
@@ -3268,6 +4075,8 @@ impl fmt::Debug for ScopeNV {
             Self::QUEUE_FAMILY => Some("QUEUE_FAMILY"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3277,23 +4086,32 @@ impl fmt::Debug for ScopeNV {
 }
 impl fmt::Debug for SemaphoreCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SemaphoreImportFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(SemaphoreImportFlags::TEMPORARY.0, "TEMPORARY")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SemaphoreType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::BINARY => Some("BINARY"),
             Self::TIMELINE => Some("TIMELINE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3303,24 +4121,33 @@ impl fmt::Debug for SemaphoreType {
 }
 impl fmt::Debug for SemaphoreWaitFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(SemaphoreWaitFlags::ANY.0, "ANY")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ShaderCorePropertiesFlagsAMD {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ShaderFloatControlsIndependence {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::TYPE_32_ONLY => Some("TYPE_32_ONLY"),
             Self::ALL => Some("ALL"),
             Self::NONE => Some("NONE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3330,6 +4157,7 @@ impl fmt::Debug for ShaderFloatControlsIndependence {
 }
 impl fmt::Debug for ShaderGroupShaderKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::GENERAL => Some("GENERAL"),
             Self::CLOSEST_HIT => Some("CLOSEST_HIT"),
@@ -3337,6 +4165,8 @@ impl fmt::Debug for ShaderGroupShaderKHR {
             Self::INTERSECTION => Some("INTERSECTION"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3346,12 +4176,15 @@ impl fmt::Debug for ShaderGroupShaderKHR {
 }
 impl fmt::Debug for ShaderInfoTypeAMD {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::STATISTICS => Some("STATISTICS"),
             Self::BINARY => Some("BINARY"),
             Self::DISASSEMBLY => Some("DISASSEMBLY"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3361,13 +4194,17 @@ impl fmt::Debug for ShaderInfoTypeAMD {
 }
 impl fmt::Debug for ShaderModuleCreateFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] =
             &[(ShaderModuleCreateFlags::RESERVED_0_NV.0, "RESERVED_0_NV")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ShaderStageFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ShaderStageFlags::VERTEX.0, "VERTEX"),
             (
@@ -3392,11 +4229,14 @@ impl fmt::Debug for ShaderStageFlags {
             (ShaderStageFlags::TASK_NV.0, "TASK_NV"),
             (ShaderStageFlags::MESH_NV.0, "MESH_NV"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ShadingRatePaletteEntryNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::NO_INVOCATIONS => Some("NO_INVOCATIONS"),
             Self::TYPE_16_INVOCATIONS_PER_PIXEL => Some("TYPE_16_INVOCATIONS_PER_PIXEL"),
@@ -3412,6 +4252,8 @@ impl fmt::Debug for ShadingRatePaletteEntryNV {
             Self::TYPE_1_INVOCATION_PER_4X4_PIXELS => Some("TYPE_1_INVOCATION_PER_4X4_PIXELS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3421,11 +4263,14 @@ impl fmt::Debug for ShadingRatePaletteEntryNV {
 }
 impl fmt::Debug for SharingMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::EXCLUSIVE => Some("EXCLUSIVE"),
             Self::CONCURRENT => Some("CONCURRENT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3435,6 +4280,7 @@ impl fmt::Debug for SharingMode {
 }
 impl fmt::Debug for SparseImageFormatFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (SparseImageFormatFlags::SINGLE_MIPTAIL.0, "SINGLE_MIPTAIL"),
             (
@@ -3446,27 +4292,36 @@ impl fmt::Debug for SparseImageFormatFlags {
                 "NONSTANDARD_BLOCK_SIZE",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SparseMemoryBindFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(SparseMemoryBindFlags::METADATA.0, "METADATA")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for StencilFaceFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (StencilFaceFlags::FRONT.0, "FRONT"),
             (StencilFaceFlags::BACK.0, "BACK"),
             (StencilFaceFlags::FRONT_AND_BACK.0, "FRONT_AND_BACK"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for StencilOp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::KEEP => Some("KEEP"),
             Self::ZERO => Some("ZERO"),
@@ -3478,6 +4333,8 @@ impl fmt::Debug for StencilOp {
             Self::DECREMENT_AND_WRAP => Some("DECREMENT_AND_WRAP"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -3487,1049 +4344,1839 @@ impl fmt::Debug for StencilOp {
 }
 impl fmt::Debug for StreamDescriptorSurfaceCreateFlagsGGP {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for StructureType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let name = match *self {
-            Self::APPLICATION_INFO => Some("APPLICATION_INFO"),
-            Self::INSTANCE_CREATE_INFO => Some("INSTANCE_CREATE_INFO"),
-            Self::DEVICE_QUEUE_CREATE_INFO => Some("DEVICE_QUEUE_CREATE_INFO"),
-            Self::DEVICE_CREATE_INFO => Some("DEVICE_CREATE_INFO"),
-            Self::SUBMIT_INFO => Some("SUBMIT_INFO"),
-            Self::MEMORY_ALLOCATE_INFO => Some("MEMORY_ALLOCATE_INFO"),
-            Self::MAPPED_MEMORY_RANGE => Some("MAPPED_MEMORY_RANGE"),
-            Self::BIND_SPARSE_INFO => Some("BIND_SPARSE_INFO"),
-            Self::FENCE_CREATE_INFO => Some("FENCE_CREATE_INFO"),
-            Self::SEMAPHORE_CREATE_INFO => Some("SEMAPHORE_CREATE_INFO"),
-            Self::EVENT_CREATE_INFO => Some("EVENT_CREATE_INFO"),
-            Self::QUERY_POOL_CREATE_INFO => Some("QUERY_POOL_CREATE_INFO"),
-            Self::BUFFER_CREATE_INFO => Some("BUFFER_CREATE_INFO"),
-            Self::BUFFER_VIEW_CREATE_INFO => Some("BUFFER_VIEW_CREATE_INFO"),
-            Self::IMAGE_CREATE_INFO => Some("IMAGE_CREATE_INFO"),
-            Self::IMAGE_VIEW_CREATE_INFO => Some("IMAGE_VIEW_CREATE_INFO"),
-            Self::SHADER_MODULE_CREATE_INFO => Some("SHADER_MODULE_CREATE_INFO"),
-            Self::PIPELINE_CACHE_CREATE_INFO => Some("PIPELINE_CACHE_CREATE_INFO"),
-            Self::PIPELINE_SHADER_STAGE_CREATE_INFO => Some("PIPELINE_SHADER_STAGE_CREATE_INFO"),
-            Self::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO => {
-                Some("PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO => {
-                Some("PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_TESSELLATION_STATE_CREATE_INFO => {
-                Some("PIPELINE_TESSELLATION_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_VIEWPORT_STATE_CREATE_INFO => {
-                Some("PIPELINE_VIEWPORT_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_RASTERIZATION_STATE_CREATE_INFO => {
-                Some("PIPELINE_RASTERIZATION_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO => {
-                Some("PIPELINE_MULTISAMPLE_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO => {
-                Some("PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO => {
-                Some("PIPELINE_COLOR_BLEND_STATE_CREATE_INFO")
-            }
-            Self::PIPELINE_DYNAMIC_STATE_CREATE_INFO => Some("PIPELINE_DYNAMIC_STATE_CREATE_INFO"),
-            Self::GRAPHICS_PIPELINE_CREATE_INFO => Some("GRAPHICS_PIPELINE_CREATE_INFO"),
-            Self::COMPUTE_PIPELINE_CREATE_INFO => Some("COMPUTE_PIPELINE_CREATE_INFO"),
-            Self::PIPELINE_LAYOUT_CREATE_INFO => Some("PIPELINE_LAYOUT_CREATE_INFO"),
-            Self::SAMPLER_CREATE_INFO => Some("SAMPLER_CREATE_INFO"),
-            Self::DESCRIPTOR_SET_LAYOUT_CREATE_INFO => Some("DESCRIPTOR_SET_LAYOUT_CREATE_INFO"),
-            Self::DESCRIPTOR_POOL_CREATE_INFO => Some("DESCRIPTOR_POOL_CREATE_INFO"),
-            Self::DESCRIPTOR_SET_ALLOCATE_INFO => Some("DESCRIPTOR_SET_ALLOCATE_INFO"),
-            Self::WRITE_DESCRIPTOR_SET => Some("WRITE_DESCRIPTOR_SET"),
-            Self::COPY_DESCRIPTOR_SET => Some("COPY_DESCRIPTOR_SET"),
-            Self::FRAMEBUFFER_CREATE_INFO => Some("FRAMEBUFFER_CREATE_INFO"),
-            Self::RENDER_PASS_CREATE_INFO => Some("RENDER_PASS_CREATE_INFO"),
-            Self::COMMAND_POOL_CREATE_INFO => Some("COMMAND_POOL_CREATE_INFO"),
-            Self::COMMAND_BUFFER_ALLOCATE_INFO => Some("COMMAND_BUFFER_ALLOCATE_INFO"),
-            Self::COMMAND_BUFFER_INHERITANCE_INFO => Some("COMMAND_BUFFER_INHERITANCE_INFO"),
-            Self::COMMAND_BUFFER_BEGIN_INFO => Some("COMMAND_BUFFER_BEGIN_INFO"),
-            Self::RENDER_PASS_BEGIN_INFO => Some("RENDER_PASS_BEGIN_INFO"),
-            Self::BUFFER_MEMORY_BARRIER => Some("BUFFER_MEMORY_BARRIER"),
-            Self::IMAGE_MEMORY_BARRIER => Some("IMAGE_MEMORY_BARRIER"),
-            Self::MEMORY_BARRIER => Some("MEMORY_BARRIER"),
-            Self::LOADER_INSTANCE_CREATE_INFO => Some("LOADER_INSTANCE_CREATE_INFO"),
-            Self::LOADER_DEVICE_CREATE_INFO => Some("LOADER_DEVICE_CREATE_INFO"),
-            Self::SWAPCHAIN_CREATE_INFO_KHR => Some("SWAPCHAIN_CREATE_INFO_KHR"),
-            Self::PRESENT_INFO_KHR => Some("PRESENT_INFO_KHR"),
-            Self::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR => {
-                Some("DEVICE_GROUP_PRESENT_CAPABILITIES_KHR")
-            }
-            Self::IMAGE_SWAPCHAIN_CREATE_INFO_KHR => Some("IMAGE_SWAPCHAIN_CREATE_INFO_KHR"),
-            Self::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR => {
-                Some("BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR")
-            }
-            Self::ACQUIRE_NEXT_IMAGE_INFO_KHR => Some("ACQUIRE_NEXT_IMAGE_INFO_KHR"),
-            Self::DEVICE_GROUP_PRESENT_INFO_KHR => Some("DEVICE_GROUP_PRESENT_INFO_KHR"),
-            Self::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR => {
-                Some("DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR")
-            }
-            Self::DISPLAY_MODE_CREATE_INFO_KHR => Some("DISPLAY_MODE_CREATE_INFO_KHR"),
-            Self::DISPLAY_SURFACE_CREATE_INFO_KHR => Some("DISPLAY_SURFACE_CREATE_INFO_KHR"),
-            Self::DISPLAY_PRESENT_INFO_KHR => Some("DISPLAY_PRESENT_INFO_KHR"),
-            Self::XLIB_SURFACE_CREATE_INFO_KHR => Some("XLIB_SURFACE_CREATE_INFO_KHR"),
-            Self::XCB_SURFACE_CREATE_INFO_KHR => Some("XCB_SURFACE_CREATE_INFO_KHR"),
-            Self::WAYLAND_SURFACE_CREATE_INFO_KHR => Some("WAYLAND_SURFACE_CREATE_INFO_KHR"),
-            Self::ANDROID_SURFACE_CREATE_INFO_KHR => Some("ANDROID_SURFACE_CREATE_INFO_KHR"),
-            Self::WIN32_SURFACE_CREATE_INFO_KHR => Some("WIN32_SURFACE_CREATE_INFO_KHR"),
-            Self::NATIVE_BUFFER_ANDROID => Some("NATIVE_BUFFER_ANDROID"),
-            Self::SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID => {
-                Some("SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID")
-            }
-            Self::PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID => {
-                Some("PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID")
-            }
-            Self::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT => {
-                Some("DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT")
-            }
-            Self::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD => {
-                Some("PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD")
-            }
-            Self::DEBUG_MARKER_OBJECT_NAME_INFO_EXT => Some("DEBUG_MARKER_OBJECT_NAME_INFO_EXT"),
-            Self::DEBUG_MARKER_OBJECT_TAG_INFO_EXT => Some("DEBUG_MARKER_OBJECT_TAG_INFO_EXT"),
-            Self::DEBUG_MARKER_MARKER_INFO_EXT => Some("DEBUG_MARKER_MARKER_INFO_EXT"),
-            Self::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV => {
-                Some("DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV")
-            }
-            Self::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV => {
-                Some("DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV")
-            }
-            Self::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV => {
-                Some("DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT => {
-                Some("PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT")
-            }
-            Self::IMAGE_VIEW_HANDLE_INFO_NVX => Some("IMAGE_VIEW_HANDLE_INFO_NVX"),
-            Self::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX => Some("IMAGE_VIEW_ADDRESS_PROPERTIES_NVX"),
-            Self::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD => {
-                Some("TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD")
-            }
-            Self::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP => {
-                Some("STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP")
-            }
-            Self::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV")
-            }
-            Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV => {
-                Some("EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV")
-            }
-            Self::EXPORT_MEMORY_ALLOCATE_INFO_NV => Some("EXPORT_MEMORY_ALLOCATE_INFO_NV"),
-            Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV => Some("IMPORT_MEMORY_WIN32_HANDLE_INFO_NV"),
-            Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV => Some("EXPORT_MEMORY_WIN32_HANDLE_INFO_NV"),
-            Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV => {
-                Some("WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV")
-            }
-            Self::VALIDATION_FLAGS_EXT => Some("VALIDATION_FLAGS_EXT"),
-            Self::VI_SURFACE_CREATE_INFO_NN => Some("VI_SURFACE_CREATE_INFO_NN"),
-            Self::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT")
-            }
-            Self::IMAGE_VIEW_ASTC_DECODE_MODE_EXT => Some("IMAGE_VIEW_ASTC_DECODE_MODE_EXT"),
-            Self::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT")
-            }
-            Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
-                Some("IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR")
-            }
-            Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR => {
-                Some("EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR")
-            }
-            Self::MEMORY_WIN32_HANDLE_PROPERTIES_KHR => Some("MEMORY_WIN32_HANDLE_PROPERTIES_KHR"),
-            Self::MEMORY_GET_WIN32_HANDLE_INFO_KHR => Some("MEMORY_GET_WIN32_HANDLE_INFO_KHR"),
-            Self::IMPORT_MEMORY_FD_INFO_KHR => Some("IMPORT_MEMORY_FD_INFO_KHR"),
-            Self::MEMORY_FD_PROPERTIES_KHR => Some("MEMORY_FD_PROPERTIES_KHR"),
-            Self::MEMORY_GET_FD_INFO_KHR => Some("MEMORY_GET_FD_INFO_KHR"),
-            Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR => {
-                Some("WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR")
-            }
-            Self::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
-                Some("IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR")
-            }
-            Self::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR => {
-                Some("EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR")
-            }
-            Self::D3D12_FENCE_SUBMIT_INFO_KHR => Some("D3D12_FENCE_SUBMIT_INFO_KHR"),
-            Self::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR => {
-                Some("SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR")
-            }
-            Self::IMPORT_SEMAPHORE_FD_INFO_KHR => Some("IMPORT_SEMAPHORE_FD_INFO_KHR"),
-            Self::SEMAPHORE_GET_FD_INFO_KHR => Some("SEMAPHORE_GET_FD_INFO_KHR"),
-            Self::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR")
-            }
-            Self::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT => {
-                Some("COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT")
-            }
-            Self::CONDITIONAL_RENDERING_BEGIN_INFO_EXT => {
-                Some("CONDITIONAL_RENDERING_BEGIN_INFO_EXT")
-            }
-            Self::PRESENT_REGIONS_KHR => Some("PRESENT_REGIONS_KHR"),
-            Self::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV")
-            }
-            Self::SURFACE_CAPABILITIES_2_EXT => Some("SURFACE_CAPABILITIES_2_EXT"),
-            Self::DISPLAY_POWER_INFO_EXT => Some("DISPLAY_POWER_INFO_EXT"),
-            Self::DEVICE_EVENT_INFO_EXT => Some("DEVICE_EVENT_INFO_EXT"),
-            Self::DISPLAY_EVENT_INFO_EXT => Some("DISPLAY_EVENT_INFO_EXT"),
-            Self::SWAPCHAIN_COUNTER_CREATE_INFO_EXT => Some("SWAPCHAIN_COUNTER_CREATE_INFO_EXT"),
-            Self::PRESENT_TIMES_INFO_GOOGLE => Some("PRESENT_TIMES_INFO_GOOGLE"),
-            Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX => {
-                Some("PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX")
-            }
-            Self::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT")
-            }
-            Self::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT")
-            }
-            Self::HDR_METADATA_EXT => Some("HDR_METADATA_EXT"),
-            Self::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR => {
-                Some("SHARED_PRESENT_SURFACE_CAPABILITIES_KHR")
-            }
-            Self::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR => Some("IMPORT_FENCE_WIN32_HANDLE_INFO_KHR"),
-            Self::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR => Some("EXPORT_FENCE_WIN32_HANDLE_INFO_KHR"),
-            Self::FENCE_GET_WIN32_HANDLE_INFO_KHR => Some("FENCE_GET_WIN32_HANDLE_INFO_KHR"),
-            Self::IMPORT_FENCE_FD_INFO_KHR => Some("IMPORT_FENCE_FD_INFO_KHR"),
-            Self::FENCE_GET_FD_INFO_KHR => Some("FENCE_GET_FD_INFO_KHR"),
-            Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR")
-            }
-            Self::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR => {
-                Some("QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR")
-            }
-            Self::PERFORMANCE_QUERY_SUBMIT_INFO_KHR => Some("PERFORMANCE_QUERY_SUBMIT_INFO_KHR"),
-            Self::ACQUIRE_PROFILING_LOCK_INFO_KHR => Some("ACQUIRE_PROFILING_LOCK_INFO_KHR"),
-            Self::PERFORMANCE_COUNTER_KHR => Some("PERFORMANCE_COUNTER_KHR"),
-            Self::PERFORMANCE_COUNTER_DESCRIPTION_KHR => {
-                Some("PERFORMANCE_COUNTER_DESCRIPTION_KHR")
-            }
-            Self::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR => Some("PHYSICAL_DEVICE_SURFACE_INFO_2_KHR"),
-            Self::SURFACE_CAPABILITIES_2_KHR => Some("SURFACE_CAPABILITIES_2_KHR"),
-            Self::SURFACE_FORMAT_2_KHR => Some("SURFACE_FORMAT_2_KHR"),
-            Self::DISPLAY_PROPERTIES_2_KHR => Some("DISPLAY_PROPERTIES_2_KHR"),
-            Self::DISPLAY_PLANE_PROPERTIES_2_KHR => Some("DISPLAY_PLANE_PROPERTIES_2_KHR"),
-            Self::DISPLAY_MODE_PROPERTIES_2_KHR => Some("DISPLAY_MODE_PROPERTIES_2_KHR"),
-            Self::DISPLAY_PLANE_INFO_2_KHR => Some("DISPLAY_PLANE_INFO_2_KHR"),
-            Self::DISPLAY_PLANE_CAPABILITIES_2_KHR => Some("DISPLAY_PLANE_CAPABILITIES_2_KHR"),
-            Self::IOS_SURFACE_CREATE_INFO_M => Some("IOS_SURFACE_CREATE_INFO_M"),
-            Self::MACOS_SURFACE_CREATE_INFO_M => Some("MACOS_SURFACE_CREATE_INFO_M"),
-            Self::DEBUG_UTILS_OBJECT_NAME_INFO_EXT => Some("DEBUG_UTILS_OBJECT_NAME_INFO_EXT"),
-            Self::DEBUG_UTILS_OBJECT_TAG_INFO_EXT => Some("DEBUG_UTILS_OBJECT_TAG_INFO_EXT"),
-            Self::DEBUG_UTILS_LABEL_EXT => Some("DEBUG_UTILS_LABEL_EXT"),
-            Self::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT => {
-                Some("DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT")
-            }
-            Self::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT => {
-                Some("DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT")
-            }
-            Self::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID => {
-                Some("ANDROID_HARDWARE_BUFFER_USAGE_ANDROID")
-            }
-            Self::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID => {
-                Some("ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID")
-            }
-            Self::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID => {
-                Some("ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID")
-            }
-            Self::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
-                Some("IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID")
-            }
-            Self::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID => {
-                Some("MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID")
-            }
-            Self::EXTERNAL_FORMAT_ANDROID => Some("EXTERNAL_FORMAT_ANDROID"),
-            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT")
-            }
-            Self::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT => {
-                Some("WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT")
-            }
-            Self::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT => {
-                Some("DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT")
-            }
-            Self::SAMPLE_LOCATIONS_INFO_EXT => Some("SAMPLE_LOCATIONS_INFO_EXT"),
-            Self::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT => {
-                Some("RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT")
-            }
-            Self::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT")
-            }
-            Self::MULTISAMPLE_PROPERTIES_EXT => Some("MULTISAMPLE_PROPERTIES_EXT"),
-            Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT")
-            }
-            Self::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV")
-            }
-            Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR => {
-                Some("WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR => {
-                Some("ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR => {
-                Some("ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR => {
-                Some("ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR => {
-                Some("ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR => {
-                Some("ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_GEOMETRY_KHR => {
-                Some("ACCELERATION_STRUCTURE_GEOMETRY_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_VERSION_INFO_KHR => {
-                Some("ACCELERATION_STRUCTURE_VERSION_INFO_KHR")
-            }
-            Self::COPY_ACCELERATION_STRUCTURE_INFO_KHR => {
-                Some("COPY_ACCELERATION_STRUCTURE_INFO_KHR")
-            }
-            Self::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR => {
-                Some("COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR")
-            }
-            Self::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR => {
-                Some("COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR")
-            }
-            Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_CREATE_INFO_KHR => {
-                Some("ACCELERATION_STRUCTURE_CREATE_INFO_KHR")
-            }
-            Self::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR => {
-                Some("ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR")
-            }
-            Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR")
-            }
-            Self::RAY_TRACING_PIPELINE_CREATE_INFO_KHR => {
-                Some("RAY_TRACING_PIPELINE_CREATE_INFO_KHR")
-            }
-            Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR => {
-                Some("RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR")
-            }
-            Self::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR => {
-                Some("RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR")
-            }
-            Self::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR")
-            }
-            Self::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV")
-            }
-            Self::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT => {
-                Some("DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT")
-            }
-            Self::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT => {
-                Some("PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT")
-            }
-            Self::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT => {
-                Some("IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT")
-            }
-            Self::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT => {
-                Some("IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT")
-            }
-            Self::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT => {
-                Some("IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT")
-            }
-            Self::VALIDATION_CACHE_CREATE_INFO_EXT => Some("VALIDATION_CACHE_CREATE_INFO_EXT"),
-            Self::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT => {
-                Some("SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR")
-            }
-            Self::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV")
-            }
-            Self::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV")
-            }
-            Self::RAY_TRACING_PIPELINE_CREATE_INFO_NV => {
-                Some("RAY_TRACING_PIPELINE_CREATE_INFO_NV")
-            }
-            Self::ACCELERATION_STRUCTURE_CREATE_INFO_NV => {
-                Some("ACCELERATION_STRUCTURE_CREATE_INFO_NV")
-            }
-            Self::GEOMETRY_NV => Some("GEOMETRY_NV"),
-            Self::GEOMETRY_TRIANGLES_NV => Some("GEOMETRY_TRIANGLES_NV"),
-            Self::GEOMETRY_AABB_NV => Some("GEOMETRY_AABB_NV"),
-            Self::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV => {
-                Some("BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV")
-            }
-            Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV => {
-                Some("WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV")
-            }
-            Self::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV => {
-                Some("ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV")
-            }
-            Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV => {
-                Some("RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV")
-            }
-            Self::ACCELERATION_STRUCTURE_INFO_NV => Some("ACCELERATION_STRUCTURE_INFO_NV"),
-            Self::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV")
-            }
-            Self::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT => {
-                Some("PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT")
-            }
-            Self::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT => {
-                Some("FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT")
-            }
-            Self::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT => {
-                Some("DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT")
-            }
-            Self::IMPORT_MEMORY_HOST_POINTER_INFO_EXT => {
-                Some("IMPORT_MEMORY_HOST_POINTER_INFO_EXT")
-            }
-            Self::MEMORY_HOST_POINTER_PROPERTIES_EXT => Some("MEMORY_HOST_POINTER_PROPERTIES_EXT"),
-            Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR")
-            }
-            Self::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD => {
-                Some("PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD")
-            }
-            Self::CALIBRATED_TIMESTAMP_INFO_EXT => Some("CALIBRATED_TIMESTAMP_INFO_EXT"),
-            Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD => {
-                Some("PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD")
-            }
-            Self::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD => {
-                Some("DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD")
-            }
-            Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT")
-            }
-            Self::PRESENT_FRAME_TOKEN_GGP => Some("PRESENT_FRAME_TOKEN_GGP"),
-            Self::PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT => {
-                Some("PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV")
-            }
-            Self::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV")
-            }
-            Self::CHECKPOINT_DATA_NV => Some("CHECKPOINT_DATA_NV"),
-            Self::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV => {
-                Some("QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL => {
-                Some("PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL")
-            }
-            Self::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL => {
-                Some("QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL")
-            }
-            Self::INITIALIZE_PERFORMANCE_API_INFO_INTEL => {
-                Some("INITIALIZE_PERFORMANCE_API_INFO_INTEL")
-            }
-            Self::PERFORMANCE_MARKER_INFO_INTEL => Some("PERFORMANCE_MARKER_INFO_INTEL"),
-            Self::PERFORMANCE_STREAM_MARKER_INFO_INTEL => {
-                Some("PERFORMANCE_STREAM_MARKER_INFO_INTEL")
-            }
-            Self::PERFORMANCE_OVERRIDE_INFO_INTEL => Some("PERFORMANCE_OVERRIDE_INFO_INTEL"),
-            Self::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL => {
-                Some("PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL")
-            }
-            Self::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT")
-            }
-            Self::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD => {
-                Some("DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD")
-            }
-            Self::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD => {
-                Some("SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD")
-            }
-            Self::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA => {
-                Some("IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR")
-            }
-            Self::METAL_SURFACE_CREATE_INFO_EXT => Some("METAL_SURFACE_CREATE_INFO_EXT"),
-            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT")
-            }
-            Self::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT => {
-                Some("RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT")
-            }
-            Self::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT => {
-                Some("PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT")
-            }
-            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR => {
-                Some("FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR")
-            }
-            Self::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR => {
-                Some("PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD => {
-                Some("PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD")
-            }
-            Self::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD => {
-                Some("PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT")
-            }
-            Self::MEMORY_PRIORITY_ALLOCATE_INFO_EXT => Some("MEMORY_PRIORITY_ALLOCATE_INFO_EXT"),
-            Self::SURFACE_PROTECTED_CAPABILITIES_KHR => Some("SURFACE_PROTECTED_CAPABILITIES_KHR"),
-            Self::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT")
-            }
-            Self::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT => {
-                Some("BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT")
-            }
-            Self::VALIDATION_FEATURES_EXT => Some("VALIDATION_FEATURES_EXT"),
-            Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV")
-            }
-            Self::COOPERATIVE_MATRIX_PROPERTIES_NV => Some("COOPERATIVE_MATRIX_PROPERTIES_NV"),
-            Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV")
-            }
-            Self::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV")
-            }
-            Self::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV")
-            }
-            Self::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV => {
-                Some("FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT")
-            }
-            Self::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT => {
-                Some("SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT")
-            }
-            Self::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT => {
-                Some("SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT")
-            }
-            Self::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT => {
-                Some("SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT")
-            }
-            Self::HEADLESS_SURFACE_CREATE_INFO_EXT => Some("HEADLESS_SURFACE_CREATE_INFO_EXT"),
-            Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT")
-            }
-            Self::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT => {
-                Some("PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR => {
-                Some("PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR")
-            }
-            Self::PIPELINE_INFO_KHR => Some("PIPELINE_INFO_KHR"),
-            Self::PIPELINE_EXECUTABLE_PROPERTIES_KHR => Some("PIPELINE_EXECUTABLE_PROPERTIES_KHR"),
-            Self::PIPELINE_EXECUTABLE_INFO_KHR => Some("PIPELINE_EXECUTABLE_INFO_KHR"),
-            Self::PIPELINE_EXECUTABLE_STATISTIC_KHR => Some("PIPELINE_EXECUTABLE_STATISTIC_KHR"),
-            Self::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR => {
-                Some("PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV")
-            }
-            Self::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV => {
-                Some("GRAPHICS_SHADER_GROUP_CREATE_INFO_NV")
-            }
-            Self::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV => {
-                Some("GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV")
-            }
-            Self::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV => Some("INDIRECT_COMMANDS_LAYOUT_TOKEN_NV"),
-            Self::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV => {
-                Some("INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV")
-            }
-            Self::GENERATED_COMMANDS_INFO_NV => Some("GENERATED_COMMANDS_INFO_NV"),
-            Self::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV => {
-                Some("GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV")
-            }
-            Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT")
-            }
-            Self::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM => {
-                Some("COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM")
-            }
-            Self::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM => {
-                Some("RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM")
-            }
-            Self::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT")
-            }
-            Self::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT => {
-                Some("DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT")
-            }
-            Self::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT => {
-                Some("DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT")
-            }
-            Self::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT")
-            }
-            Self::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT => {
-                Some("SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT")
-            }
-            Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT")
-            }
-            Self::PIPELINE_LIBRARY_CREATE_INFO_KHR => Some("PIPELINE_LIBRARY_CREATE_INFO_KHR"),
-            Self::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT")
-            }
-            Self::DEVICE_PRIVATE_DATA_CREATE_INFO_EXT => {
-                Some("DEVICE_PRIVATE_DATA_CREATE_INFO_EXT")
-            }
-            Self::PRIVATE_DATA_SLOT_CREATE_INFO_EXT => Some("PRIVATE_DATA_SLOT_CREATE_INFO_EXT"),
-            Self::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV")
-            }
-            Self::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV => {
-                Some("DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV")
-            }
-            Self::RESERVED_QCOM => Some("RESERVED_QCOM"),
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV")
-            }
-            Self::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV => {
-                Some("PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT")
-            }
-            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT => {
-                Some("PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT")
-            }
-            Self::COPY_COMMAND_TRANSFORM_INFO_QCOM => Some("COPY_COMMAND_TRANSFORM_INFO_QCOM"),
-            Self::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT")
-            }
-            Self::COPY_BUFFER_INFO_2_KHR => Some("COPY_BUFFER_INFO_2_KHR"),
-            Self::COPY_IMAGE_INFO_2_KHR => Some("COPY_IMAGE_INFO_2_KHR"),
-            Self::COPY_BUFFER_TO_IMAGE_INFO_2_KHR => Some("COPY_BUFFER_TO_IMAGE_INFO_2_KHR"),
-            Self::COPY_IMAGE_TO_BUFFER_INFO_2_KHR => Some("COPY_IMAGE_TO_BUFFER_INFO_2_KHR"),
-            Self::BLIT_IMAGE_INFO_2_KHR => Some("BLIT_IMAGE_INFO_2_KHR"),
-            Self::RESOLVE_IMAGE_INFO_2_KHR => Some("RESOLVE_IMAGE_INFO_2_KHR"),
-            Self::BUFFER_COPY_2_KHR => Some("BUFFER_COPY_2_KHR"),
-            Self::IMAGE_COPY_2_KHR => Some("IMAGE_COPY_2_KHR"),
-            Self::IMAGE_BLIT_2_KHR => Some("IMAGE_BLIT_2_KHR"),
-            Self::BUFFER_IMAGE_COPY_2_KHR => Some("BUFFER_IMAGE_COPY_2_KHR"),
-            Self::IMAGE_RESOLVE_2_KHR => Some("IMAGE_RESOLVE_2_KHR"),
-            Self::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT => {
-                Some("PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT")
-            }
-            Self::DIRECTFB_SURFACE_CREATE_INFO_EXT => Some("DIRECTFB_SURFACE_CREATE_INFO_EXT"),
-            Self::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_SUBGROUP_PROPERTIES")
-            }
-            Self::BIND_BUFFER_MEMORY_INFO => Some("BIND_BUFFER_MEMORY_INFO"),
-            Self::BIND_IMAGE_MEMORY_INFO => Some("BIND_IMAGE_MEMORY_INFO"),
-            Self::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES => {
-                Some("PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES")
-            }
-            Self::MEMORY_DEDICATED_REQUIREMENTS => Some("MEMORY_DEDICATED_REQUIREMENTS"),
-            Self::MEMORY_DEDICATED_ALLOCATE_INFO => Some("MEMORY_DEDICATED_ALLOCATE_INFO"),
-            Self::MEMORY_ALLOCATE_FLAGS_INFO => Some("MEMORY_ALLOCATE_FLAGS_INFO"),
-            Self::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO => {
-                Some("DEVICE_GROUP_RENDER_PASS_BEGIN_INFO")
-            }
-            Self::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO => {
-                Some("DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO")
-            }
-            Self::DEVICE_GROUP_SUBMIT_INFO => Some("DEVICE_GROUP_SUBMIT_INFO"),
-            Self::DEVICE_GROUP_BIND_SPARSE_INFO => Some("DEVICE_GROUP_BIND_SPARSE_INFO"),
-            Self::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO => {
-                Some("BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO")
-            }
-            Self::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO => {
-                Some("BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO")
-            }
-            Self::PHYSICAL_DEVICE_GROUP_PROPERTIES => Some("PHYSICAL_DEVICE_GROUP_PROPERTIES"),
-            Self::DEVICE_GROUP_DEVICE_CREATE_INFO => Some("DEVICE_GROUP_DEVICE_CREATE_INFO"),
-            Self::BUFFER_MEMORY_REQUIREMENTS_INFO_2 => Some("BUFFER_MEMORY_REQUIREMENTS_INFO_2"),
-            Self::IMAGE_MEMORY_REQUIREMENTS_INFO_2 => Some("IMAGE_MEMORY_REQUIREMENTS_INFO_2"),
-            Self::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 => {
-                Some("IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2")
-            }
-            Self::MEMORY_REQUIREMENTS_2 => Some("MEMORY_REQUIREMENTS_2"),
-            Self::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 => Some("SPARSE_IMAGE_MEMORY_REQUIREMENTS_2"),
-            Self::PHYSICAL_DEVICE_FEATURES_2 => Some("PHYSICAL_DEVICE_FEATURES_2"),
-            Self::PHYSICAL_DEVICE_PROPERTIES_2 => Some("PHYSICAL_DEVICE_PROPERTIES_2"),
-            Self::FORMAT_PROPERTIES_2 => Some("FORMAT_PROPERTIES_2"),
-            Self::IMAGE_FORMAT_PROPERTIES_2 => Some("IMAGE_FORMAT_PROPERTIES_2"),
-            Self::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 => {
-                Some("PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2")
-            }
-            Self::QUEUE_FAMILY_PROPERTIES_2 => Some("QUEUE_FAMILY_PROPERTIES_2"),
-            Self::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 => {
-                Some("PHYSICAL_DEVICE_MEMORY_PROPERTIES_2")
-            }
-            Self::SPARSE_IMAGE_FORMAT_PROPERTIES_2 => Some("SPARSE_IMAGE_FORMAT_PROPERTIES_2"),
-            Self::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 => {
-                Some("PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2")
-            }
-            Self::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES")
-            }
-            Self::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO => {
-                Some("RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO")
-            }
-            Self::IMAGE_VIEW_USAGE_CREATE_INFO => Some("IMAGE_VIEW_USAGE_CREATE_INFO"),
-            Self::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO => {
-                Some("PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO")
-            }
-            Self::RENDER_PASS_MULTIVIEW_CREATE_INFO => Some("RENDER_PASS_MULTIVIEW_CREATE_INFO"),
-            Self::PHYSICAL_DEVICE_MULTIVIEW_FEATURES => Some("PHYSICAL_DEVICE_MULTIVIEW_FEATURES"),
-            Self::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES")
-            }
-            Self::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES => {
-                Some("PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES")
-            }
-            Self::PROTECTED_SUBMIT_INFO => Some("PROTECTED_SUBMIT_INFO"),
-            Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES => {
-                Some("PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES")
-            }
-            Self::DEVICE_QUEUE_INFO_2 => Some("DEVICE_QUEUE_INFO_2"),
-            Self::SAMPLER_YCBCR_CONVERSION_CREATE_INFO => {
-                Some("SAMPLER_YCBCR_CONVERSION_CREATE_INFO")
-            }
-            Self::SAMPLER_YCBCR_CONVERSION_INFO => Some("SAMPLER_YCBCR_CONVERSION_INFO"),
-            Self::BIND_IMAGE_PLANE_MEMORY_INFO => Some("BIND_IMAGE_PLANE_MEMORY_INFO"),
-            Self::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO => {
-                Some("IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO")
-            }
-            Self::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES => {
-                Some("PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES")
-            }
-            Self::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES => {
-                Some("SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES")
-            }
-            Self::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO => {
-                Some("DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO")
-            }
-            Self::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO => {
-                Some("PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO")
-            }
-            Self::EXTERNAL_IMAGE_FORMAT_PROPERTIES => Some("EXTERNAL_IMAGE_FORMAT_PROPERTIES"),
-            Self::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO => {
-                Some("PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO")
-            }
-            Self::EXTERNAL_BUFFER_PROPERTIES => Some("EXTERNAL_BUFFER_PROPERTIES"),
-            Self::PHYSICAL_DEVICE_ID_PROPERTIES => Some("PHYSICAL_DEVICE_ID_PROPERTIES"),
-            Self::EXTERNAL_MEMORY_BUFFER_CREATE_INFO => Some("EXTERNAL_MEMORY_BUFFER_CREATE_INFO"),
-            Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO => Some("EXTERNAL_MEMORY_IMAGE_CREATE_INFO"),
-            Self::EXPORT_MEMORY_ALLOCATE_INFO => Some("EXPORT_MEMORY_ALLOCATE_INFO"),
-            Self::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO => {
-                Some("PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO")
-            }
-            Self::EXTERNAL_FENCE_PROPERTIES => Some("EXTERNAL_FENCE_PROPERTIES"),
-            Self::EXPORT_FENCE_CREATE_INFO => Some("EXPORT_FENCE_CREATE_INFO"),
-            Self::EXPORT_SEMAPHORE_CREATE_INFO => Some("EXPORT_SEMAPHORE_CREATE_INFO"),
-            Self::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO => {
-                Some("PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO")
-            }
-            Self::EXTERNAL_SEMAPHORE_PROPERTIES => Some("EXTERNAL_SEMAPHORE_PROPERTIES"),
-            Self::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES")
-            }
-            Self::DESCRIPTOR_SET_LAYOUT_SUPPORT => Some("DESCRIPTOR_SET_LAYOUT_SUPPORT"),
-            Self::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES => {
-                Some("PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
-                Some("PHYSICAL_DEVICE_VULKAN_1_1_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES")
-            }
-            Self::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
-                Some("PHYSICAL_DEVICE_VULKAN_1_2_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES")
-            }
-            Self::IMAGE_FORMAT_LIST_CREATE_INFO => Some("IMAGE_FORMAT_LIST_CREATE_INFO"),
-            Self::ATTACHMENT_DESCRIPTION_2 => Some("ATTACHMENT_DESCRIPTION_2"),
-            Self::ATTACHMENT_REFERENCE_2 => Some("ATTACHMENT_REFERENCE_2"),
-            Self::SUBPASS_DESCRIPTION_2 => Some("SUBPASS_DESCRIPTION_2"),
-            Self::SUBPASS_DEPENDENCY_2 => Some("SUBPASS_DEPENDENCY_2"),
-            Self::RENDER_PASS_CREATE_INFO_2 => Some("RENDER_PASS_CREATE_INFO_2"),
-            Self::SUBPASS_BEGIN_INFO => Some("SUBPASS_BEGIN_INFO"),
-            Self::SUBPASS_END_INFO => Some("SUBPASS_END_INFO"),
-            Self::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES => {
-                Some("PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_DRIVER_PROPERTIES => Some("PHYSICAL_DEVICE_DRIVER_PROPERTIES"),
-            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES => {
-                Some("PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES => {
-                Some("PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES")
-            }
-            Self::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO => {
-                Some("DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO")
-            }
-            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES => {
-                Some("PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES")
-            }
-            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO => {
-                Some("DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO")
-            }
-            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT => {
-                Some("DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT")
-            }
-            Self::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES")
-            }
-            Self::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE => {
-                Some("SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE")
-            }
-            Self::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES => {
-                Some("PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES")
-            }
-            Self::IMAGE_STENCIL_USAGE_CREATE_INFO => Some("IMAGE_STENCIL_USAGE_CREATE_INFO"),
-            Self::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES")
-            }
-            Self::SAMPLER_REDUCTION_MODE_CREATE_INFO => Some("SAMPLER_REDUCTION_MODE_CREATE_INFO"),
-            Self::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES => {
-                Some("PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES => {
-                Some("PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES")
-            }
-            Self::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO => {
-                Some("FRAMEBUFFER_ATTACHMENTS_CREATE_INFO")
-            }
-            Self::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO => Some("FRAMEBUFFER_ATTACHMENT_IMAGE_INFO"),
-            Self::RENDER_PASS_ATTACHMENT_BEGIN_INFO => Some("RENDER_PASS_ATTACHMENT_BEGIN_INFO"),
-            Self::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES => {
-                Some("PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES => {
-                Some("PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES => {
-                Some("PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES")
-            }
-            Self::ATTACHMENT_REFERENCE_STENCIL_LAYOUT => {
-                Some("ATTACHMENT_REFERENCE_STENCIL_LAYOUT")
-            }
-            Self::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT => {
-                Some("ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT")
-            }
-            Self::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES => {
-                Some("PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES => {
-                Some("PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES")
-            }
-            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES => {
-                Some("PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES")
-            }
-            Self::SEMAPHORE_TYPE_CREATE_INFO => Some("SEMAPHORE_TYPE_CREATE_INFO"),
-            Self::TIMELINE_SEMAPHORE_SUBMIT_INFO => Some("TIMELINE_SEMAPHORE_SUBMIT_INFO"),
-            Self::SEMAPHORE_WAIT_INFO => Some("SEMAPHORE_WAIT_INFO"),
-            Self::SEMAPHORE_SIGNAL_INFO => Some("SEMAPHORE_SIGNAL_INFO"),
-            Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES => {
-                Some("PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES")
-            }
-            Self::BUFFER_DEVICE_ADDRESS_INFO => Some("BUFFER_DEVICE_ADDRESS_INFO"),
-            Self::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO => {
-                Some("BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO")
-            }
-            Self::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO => {
-                Some("MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO")
-            }
-            Self::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO => {
-                Some("DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO")
-            }
-            _ => None,
-        };
-        if let Some(x) = name {
-            f.write_str(x)
-        } else {
-            self.0.fmt(f)
-        }
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(i32, &str)] = &[
+            (StructureType::APPLICATION_INFO.0, "APPLICATION_INFO"),
+            (
+                StructureType::INSTANCE_CREATE_INFO.0,
+                "INSTANCE_CREATE_INFO",
+            ),
+            (
+                StructureType::DEVICE_QUEUE_CREATE_INFO.0,
+                "DEVICE_QUEUE_CREATE_INFO",
+            ),
+            (StructureType::DEVICE_CREATE_INFO.0, "DEVICE_CREATE_INFO"),
+            (StructureType::SUBMIT_INFO.0, "SUBMIT_INFO"),
+            (
+                StructureType::MEMORY_ALLOCATE_INFO.0,
+                "MEMORY_ALLOCATE_INFO",
+            ),
+            (StructureType::MAPPED_MEMORY_RANGE.0, "MAPPED_MEMORY_RANGE"),
+            (StructureType::BIND_SPARSE_INFO.0, "BIND_SPARSE_INFO"),
+            (StructureType::FENCE_CREATE_INFO.0, "FENCE_CREATE_INFO"),
+            (
+                StructureType::SEMAPHORE_CREATE_INFO.0,
+                "SEMAPHORE_CREATE_INFO",
+            ),
+            (StructureType::EVENT_CREATE_INFO.0, "EVENT_CREATE_INFO"),
+            (
+                StructureType::QUERY_POOL_CREATE_INFO.0,
+                "QUERY_POOL_CREATE_INFO",
+            ),
+            (StructureType::BUFFER_CREATE_INFO.0, "BUFFER_CREATE_INFO"),
+            (
+                StructureType::BUFFER_VIEW_CREATE_INFO.0,
+                "BUFFER_VIEW_CREATE_INFO",
+            ),
+            (StructureType::IMAGE_CREATE_INFO.0, "IMAGE_CREATE_INFO"),
+            (
+                StructureType::IMAGE_VIEW_CREATE_INFO.0,
+                "IMAGE_VIEW_CREATE_INFO",
+            ),
+            (
+                StructureType::SHADER_MODULE_CREATE_INFO.0,
+                "SHADER_MODULE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_CACHE_CREATE_INFO.0,
+                "PIPELINE_CACHE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO.0,
+                "PIPELINE_SHADER_STAGE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO.0,
+                "PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO.0,
+                "PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO.0,
+                "PIPELINE_TESSELLATION_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO.0,
+                "PIPELINE_VIEWPORT_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO.0,
+                "PIPELINE_RASTERIZATION_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO.0,
+                "PIPELINE_MULTISAMPLE_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO.0,
+                "PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO.0,
+                "PIPELINE_COLOR_BLEND_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO.0,
+                "PIPELINE_DYNAMIC_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::GRAPHICS_PIPELINE_CREATE_INFO.0,
+                "GRAPHICS_PIPELINE_CREATE_INFO",
+            ),
+            (
+                StructureType::COMPUTE_PIPELINE_CREATE_INFO.0,
+                "COMPUTE_PIPELINE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_LAYOUT_CREATE_INFO.0,
+                "PIPELINE_LAYOUT_CREATE_INFO",
+            ),
+            (StructureType::SAMPLER_CREATE_INFO.0, "SAMPLER_CREATE_INFO"),
+            (
+                StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO.0,
+                "DESCRIPTOR_SET_LAYOUT_CREATE_INFO",
+            ),
+            (
+                StructureType::DESCRIPTOR_POOL_CREATE_INFO.0,
+                "DESCRIPTOR_POOL_CREATE_INFO",
+            ),
+            (
+                StructureType::DESCRIPTOR_SET_ALLOCATE_INFO.0,
+                "DESCRIPTOR_SET_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::WRITE_DESCRIPTOR_SET.0,
+                "WRITE_DESCRIPTOR_SET",
+            ),
+            (StructureType::COPY_DESCRIPTOR_SET.0, "COPY_DESCRIPTOR_SET"),
+            (
+                StructureType::FRAMEBUFFER_CREATE_INFO.0,
+                "FRAMEBUFFER_CREATE_INFO",
+            ),
+            (
+                StructureType::RENDER_PASS_CREATE_INFO.0,
+                "RENDER_PASS_CREATE_INFO",
+            ),
+            (
+                StructureType::COMMAND_POOL_CREATE_INFO.0,
+                "COMMAND_POOL_CREATE_INFO",
+            ),
+            (
+                StructureType::COMMAND_BUFFER_ALLOCATE_INFO.0,
+                "COMMAND_BUFFER_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::COMMAND_BUFFER_INHERITANCE_INFO.0,
+                "COMMAND_BUFFER_INHERITANCE_INFO",
+            ),
+            (
+                StructureType::COMMAND_BUFFER_BEGIN_INFO.0,
+                "COMMAND_BUFFER_BEGIN_INFO",
+            ),
+            (
+                StructureType::RENDER_PASS_BEGIN_INFO.0,
+                "RENDER_PASS_BEGIN_INFO",
+            ),
+            (
+                StructureType::BUFFER_MEMORY_BARRIER.0,
+                "BUFFER_MEMORY_BARRIER",
+            ),
+            (
+                StructureType::IMAGE_MEMORY_BARRIER.0,
+                "IMAGE_MEMORY_BARRIER",
+            ),
+            (StructureType::MEMORY_BARRIER.0, "MEMORY_BARRIER"),
+            (
+                StructureType::LOADER_INSTANCE_CREATE_INFO.0,
+                "LOADER_INSTANCE_CREATE_INFO",
+            ),
+            (
+                StructureType::LOADER_DEVICE_CREATE_INFO.0,
+                "LOADER_DEVICE_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES.0,
+                "PHYSICAL_DEVICE_VULKAN_1_1_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES.0,
+                "PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES.0,
+                "PHYSICAL_DEVICE_VULKAN_1_2_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES.0,
+                "PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES",
+            ),
+            (
+                StructureType::SWAPCHAIN_CREATE_INFO_KHR.0,
+                "SWAPCHAIN_CREATE_INFO_KHR",
+            ),
+            (StructureType::PRESENT_INFO_KHR.0, "PRESENT_INFO_KHR"),
+            (
+                StructureType::DISPLAY_MODE_CREATE_INFO_KHR.0,
+                "DISPLAY_MODE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR.0,
+                "DISPLAY_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::DISPLAY_PRESENT_INFO_KHR.0,
+                "DISPLAY_PRESENT_INFO_KHR",
+            ),
+            (
+                StructureType::XLIB_SURFACE_CREATE_INFO_KHR.0,
+                "XLIB_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::XCB_SURFACE_CREATE_INFO_KHR.0,
+                "XCB_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR.0,
+                "WAYLAND_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::ANDROID_SURFACE_CREATE_INFO_KHR.0,
+                "ANDROID_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::WIN32_SURFACE_CREATE_INFO_KHR.0,
+                "WIN32_SURFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::NATIVE_BUFFER_ANDROID.0,
+                "NATIVE_BUFFER_ANDROID",
+            ),
+            (
+                StructureType::SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID.0,
+                "SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID.0,
+                "PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID",
+            ),
+            (
+                StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT.0,
+                "DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD.0,
+                "PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD",
+            ),
+            (
+                StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT.0,
+                "DEBUG_MARKER_OBJECT_NAME_INFO_EXT",
+            ),
+            (
+                StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT.0,
+                "DEBUG_MARKER_OBJECT_TAG_INFO_EXT",
+            ),
+            (
+                StructureType::DEBUG_MARKER_MARKER_INFO_EXT.0,
+                "DEBUG_MARKER_MARKER_INFO_EXT",
+            ),
+            (
+                StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV.0,
+                "DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV.0,
+                "DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV.0,
+                "DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT.0,
+                "PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::IMAGE_VIEW_HANDLE_INFO_NVX.0,
+                "IMAGE_VIEW_HANDLE_INFO_NVX",
+            ),
+            (
+                StructureType::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX.0,
+                "IMAGE_VIEW_ADDRESS_PROPERTIES_NVX",
+            ),
+            (
+                StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD.0,
+                "TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD",
+            ),
+            (
+                StructureType::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP.0,
+                "STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV",
+            ),
+            (
+                StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO.0,
+                "RENDER_PASS_MULTIVIEW_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES.0,
+                "PHYSICAL_DEVICE_MULTIVIEW_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES.0,
+                "PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES",
+            ),
+            (
+                StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV.0,
+                "EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV.0,
+                "EXPORT_MEMORY_ALLOCATE_INFO_NV",
+            ),
+            (
+                StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV.0,
+                "IMPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+            ),
+            (
+                StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV.0,
+                "EXPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+            ),
+            (
+                StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV.0,
+                "WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FEATURES_2.0,
+                "PHYSICAL_DEVICE_FEATURES_2",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PROPERTIES_2.0,
+                "PHYSICAL_DEVICE_PROPERTIES_2",
+            ),
+            (StructureType::FORMAT_PROPERTIES_2.0, "FORMAT_PROPERTIES_2"),
+            (
+                StructureType::IMAGE_FORMAT_PROPERTIES_2.0,
+                "IMAGE_FORMAT_PROPERTIES_2",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2.0,
+                "PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2",
+            ),
+            (
+                StructureType::QUEUE_FAMILY_PROPERTIES_2.0,
+                "QUEUE_FAMILY_PROPERTIES_2",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2.0,
+                "PHYSICAL_DEVICE_MEMORY_PROPERTIES_2",
+            ),
+            (
+                StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2.0,
+                "SPARSE_IMAGE_FORMAT_PROPERTIES_2",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2.0,
+                "PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2",
+            ),
+            (
+                StructureType::MEMORY_ALLOCATE_FLAGS_INFO.0,
+                "MEMORY_ALLOCATE_FLAGS_INFO",
+            ),
+            (
+                StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO.0,
+                "DEVICE_GROUP_RENDER_PASS_BEGIN_INFO",
+            ),
+            (
+                StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO.0,
+                "DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO",
+            ),
+            (
+                StructureType::DEVICE_GROUP_SUBMIT_INFO.0,
+                "DEVICE_GROUP_SUBMIT_INFO",
+            ),
+            (
+                StructureType::DEVICE_GROUP_BIND_SPARSE_INFO.0,
+                "DEVICE_GROUP_BIND_SPARSE_INFO",
+            ),
+            (
+                StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR.0,
+                "DEVICE_GROUP_PRESENT_CAPABILITIES_KHR",
+            ),
+            (
+                StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR.0,
+                "IMAGE_SWAPCHAIN_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR.0,
+                "BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR",
+            ),
+            (
+                StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR.0,
+                "ACQUIRE_NEXT_IMAGE_INFO_KHR",
+            ),
+            (
+                StructureType::DEVICE_GROUP_PRESENT_INFO_KHR.0,
+                "DEVICE_GROUP_PRESENT_INFO_KHR",
+            ),
+            (
+                StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR.0,
+                "DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO.0,
+                "BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO",
+            ),
+            (
+                StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO.0,
+                "BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO",
+            ),
+            (
+                StructureType::VALIDATION_FLAGS_EXT.0,
+                "VALIDATION_FLAGS_EXT",
+            ),
+            (
+                StructureType::VI_SURFACE_CREATE_INFO_NN.0,
+                "VI_SURFACE_CREATE_INFO_NN",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES.0,
+                "PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT",
+            ),
+            (
+                StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT.0,
+                "IMAGE_VIEW_ASTC_DECODE_MODE_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES.0,
+                "PHYSICAL_DEVICE_GROUP_PROPERTIES",
+            ),
+            (
+                StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO.0,
+                "DEVICE_GROUP_DEVICE_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO.0,
+                "PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO",
+            ),
+            (
+                StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES.0,
+                "EXTERNAL_IMAGE_FORMAT_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO.0,
+                "PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO",
+            ),
+            (
+                StructureType::EXTERNAL_BUFFER_PROPERTIES.0,
+                "EXTERNAL_BUFFER_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ID_PROPERTIES.0,
+                "PHYSICAL_DEVICE_ID_PROPERTIES",
+            ),
+            (
+                StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO.0,
+                "EXTERNAL_MEMORY_BUFFER_CREATE_INFO",
+            ),
+            (
+                StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO.0,
+                "EXTERNAL_MEMORY_IMAGE_CREATE_INFO",
+            ),
+            (
+                StructureType::EXPORT_MEMORY_ALLOCATE_INFO.0,
+                "EXPORT_MEMORY_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR.0,
+                "IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR.0,
+                "EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR.0,
+                "MEMORY_WIN32_HANDLE_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR.0,
+                "MEMORY_GET_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::IMPORT_MEMORY_FD_INFO_KHR.0,
+                "IMPORT_MEMORY_FD_INFO_KHR",
+            ),
+            (
+                StructureType::MEMORY_FD_PROPERTIES_KHR.0,
+                "MEMORY_FD_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::MEMORY_GET_FD_INFO_KHR.0,
+                "MEMORY_GET_FD_INFO_KHR",
+            ),
+            (
+                StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR.0,
+                "WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO.0,
+                "PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO",
+            ),
+            (
+                StructureType::EXTERNAL_SEMAPHORE_PROPERTIES.0,
+                "EXTERNAL_SEMAPHORE_PROPERTIES",
+            ),
+            (
+                StructureType::EXPORT_SEMAPHORE_CREATE_INFO.0,
+                "EXPORT_SEMAPHORE_CREATE_INFO",
+            ),
+            (
+                StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR.0,
+                "IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR.0,
+                "EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::D3D12_FENCE_SUBMIT_INFO_KHR.0,
+                "D3D12_FENCE_SUBMIT_INFO_KHR",
+            ),
+            (
+                StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR.0,
+                "SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR.0,
+                "IMPORT_SEMAPHORE_FD_INFO_KHR",
+            ),
+            (
+                StructureType::SEMAPHORE_GET_FD_INFO_KHR.0,
+                "SEMAPHORE_GET_FD_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT.0,
+                "COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT",
+            ),
+            (
+                StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT.0,
+                "CONDITIONAL_RENDERING_BEGIN_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES.0,
+                "PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES.0,
+                "PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES",
+            ),
+            (StructureType::PRESENT_REGIONS_KHR.0, "PRESENT_REGIONS_KHR"),
+            (
+                StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO.0,
+                "DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::SURFACE_CAPABILITIES_2_EXT.0,
+                "SURFACE_CAPABILITIES_2_EXT",
+            ),
+            (
+                StructureType::DISPLAY_POWER_INFO_EXT.0,
+                "DISPLAY_POWER_INFO_EXT",
+            ),
+            (
+                StructureType::DEVICE_EVENT_INFO_EXT.0,
+                "DEVICE_EVENT_INFO_EXT",
+            ),
+            (
+                StructureType::DISPLAY_EVENT_INFO_EXT.0,
+                "DISPLAY_EVENT_INFO_EXT",
+            ),
+            (
+                StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT.0,
+                "SWAPCHAIN_COUNTER_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PRESENT_TIMES_INFO_GOOGLE.0,
+                "PRESENT_TIMES_INFO_GOOGLE",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES.0,
+                "PHYSICAL_DEVICE_SUBGROUP_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX.0,
+                "PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT",
+            ),
+            (StructureType::HDR_METADATA_EXT.0, "HDR_METADATA_EXT"),
+            (
+                StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES.0,
+                "PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES",
+            ),
+            (
+                StructureType::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO.0,
+                "FRAMEBUFFER_ATTACHMENTS_CREATE_INFO",
+            ),
+            (
+                StructureType::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO.0,
+                "FRAMEBUFFER_ATTACHMENT_IMAGE_INFO",
+            ),
+            (
+                StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO.0,
+                "RENDER_PASS_ATTACHMENT_BEGIN_INFO",
+            ),
+            (
+                StructureType::ATTACHMENT_DESCRIPTION_2.0,
+                "ATTACHMENT_DESCRIPTION_2",
+            ),
+            (
+                StructureType::ATTACHMENT_REFERENCE_2.0,
+                "ATTACHMENT_REFERENCE_2",
+            ),
+            (
+                StructureType::SUBPASS_DESCRIPTION_2.0,
+                "SUBPASS_DESCRIPTION_2",
+            ),
+            (
+                StructureType::SUBPASS_DEPENDENCY_2.0,
+                "SUBPASS_DEPENDENCY_2",
+            ),
+            (
+                StructureType::RENDER_PASS_CREATE_INFO_2.0,
+                "RENDER_PASS_CREATE_INFO_2",
+            ),
+            (StructureType::SUBPASS_BEGIN_INFO.0, "SUBPASS_BEGIN_INFO"),
+            (StructureType::SUBPASS_END_INFO.0, "SUBPASS_END_INFO"),
+            (
+                StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR.0,
+                "SHARED_PRESENT_SURFACE_CAPABILITIES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO.0,
+                "PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO",
+            ),
+            (
+                StructureType::EXTERNAL_FENCE_PROPERTIES.0,
+                "EXTERNAL_FENCE_PROPERTIES",
+            ),
+            (
+                StructureType::EXPORT_FENCE_CREATE_INFO.0,
+                "EXPORT_FENCE_CREATE_INFO",
+            ),
+            (
+                StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR.0,
+                "IMPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR.0,
+                "EXPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR.0,
+                "FENCE_GET_WIN32_HANDLE_INFO_KHR",
+            ),
+            (
+                StructureType::IMPORT_FENCE_FD_INFO_KHR.0,
+                "IMPORT_FENCE_FD_INFO_KHR",
+            ),
+            (
+                StructureType::FENCE_GET_FD_INFO_KHR.0,
+                "FENCE_GET_FD_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR.0,
+                "QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::PERFORMANCE_QUERY_SUBMIT_INFO_KHR.0,
+                "PERFORMANCE_QUERY_SUBMIT_INFO_KHR",
+            ),
+            (
+                StructureType::ACQUIRE_PROFILING_LOCK_INFO_KHR.0,
+                "ACQUIRE_PROFILING_LOCK_INFO_KHR",
+            ),
+            (
+                StructureType::PERFORMANCE_COUNTER_KHR.0,
+                "PERFORMANCE_COUNTER_KHR",
+            ),
+            (
+                StructureType::PERFORMANCE_COUNTER_DESCRIPTION_KHR.0,
+                "PERFORMANCE_COUNTER_DESCRIPTION_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES.0,
+                "PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES",
+            ),
+            (
+                StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO.0,
+                "RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO",
+            ),
+            (
+                StructureType::IMAGE_VIEW_USAGE_CREATE_INFO.0,
+                "IMAGE_VIEW_USAGE_CREATE_INFO",
+            ),
+            (
+                StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO.0,
+                "PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR.0,
+                "PHYSICAL_DEVICE_SURFACE_INFO_2_KHR",
+            ),
+            (
+                StructureType::SURFACE_CAPABILITIES_2_KHR.0,
+                "SURFACE_CAPABILITIES_2_KHR",
+            ),
+            (
+                StructureType::SURFACE_FORMAT_2_KHR.0,
+                "SURFACE_FORMAT_2_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES.0,
+                "PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES",
+            ),
+            (
+                StructureType::DISPLAY_PROPERTIES_2_KHR.0,
+                "DISPLAY_PROPERTIES_2_KHR",
+            ),
+            (
+                StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR.0,
+                "DISPLAY_PLANE_PROPERTIES_2_KHR",
+            ),
+            (
+                StructureType::DISPLAY_MODE_PROPERTIES_2_KHR.0,
+                "DISPLAY_MODE_PROPERTIES_2_KHR",
+            ),
+            (
+                StructureType::DISPLAY_PLANE_INFO_2_KHR.0,
+                "DISPLAY_PLANE_INFO_2_KHR",
+            ),
+            (
+                StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR.0,
+                "DISPLAY_PLANE_CAPABILITIES_2_KHR",
+            ),
+            (
+                StructureType::IOS_SURFACE_CREATE_INFO_M.0,
+                "IOS_SURFACE_CREATE_INFO_M",
+            ),
+            (
+                StructureType::MACOS_SURFACE_CREATE_INFO_M.0,
+                "MACOS_SURFACE_CREATE_INFO_M",
+            ),
+            (
+                StructureType::MEMORY_DEDICATED_REQUIREMENTS.0,
+                "MEMORY_DEDICATED_REQUIREMENTS",
+            ),
+            (
+                StructureType::MEMORY_DEDICATED_ALLOCATE_INFO.0,
+                "MEMORY_DEDICATED_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT.0,
+                "DEBUG_UTILS_OBJECT_NAME_INFO_EXT",
+            ),
+            (
+                StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT.0,
+                "DEBUG_UTILS_OBJECT_TAG_INFO_EXT",
+            ),
+            (
+                StructureType::DEBUG_UTILS_LABEL_EXT.0,
+                "DEBUG_UTILS_LABEL_EXT",
+            ),
+            (
+                StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT.0,
+                "DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT",
+            ),
+            (
+                StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT.0,
+                "DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID.0,
+                "ANDROID_HARDWARE_BUFFER_USAGE_ANDROID",
+            ),
+            (
+                StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID.0,
+                "ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID",
+            ),
+            (
+                StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID.0,
+                "ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID",
+            ),
+            (
+                StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID.0,
+                "IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+            ),
+            (
+                StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID.0,
+                "MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+            ),
+            (
+                StructureType::EXTERNAL_FORMAT_ANDROID.0,
+                "EXTERNAL_FORMAT_ANDROID",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES.0,
+                "PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES",
+            ),
+            (
+                StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO.0,
+                "SAMPLER_REDUCTION_MODE_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT.0,
+                "WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT",
+            ),
+            (
+                StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT.0,
+                "DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::SAMPLE_LOCATIONS_INFO_EXT.0,
+                "SAMPLE_LOCATIONS_INFO_EXT",
+            ),
+            (
+                StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT.0,
+                "RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT",
+            ),
+            (
+                StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::MULTISAMPLE_PROPERTIES_EXT.0,
+                "MULTISAMPLE_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PROTECTED_SUBMIT_INFO.0,
+                "PROTECTED_SUBMIT_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES.0,
+                "PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES.0,
+                "PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES",
+            ),
+            (StructureType::DEVICE_QUEUE_INFO_2.0, "DEVICE_QUEUE_INFO_2"),
+            (
+                StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2.0,
+                "BUFFER_MEMORY_REQUIREMENTS_INFO_2",
+            ),
+            (
+                StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2.0,
+                "IMAGE_MEMORY_REQUIREMENTS_INFO_2",
+            ),
+            (
+                StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2.0,
+                "IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2",
+            ),
+            (
+                StructureType::MEMORY_REQUIREMENTS_2.0,
+                "MEMORY_REQUIREMENTS_2",
+            ),
+            (
+                StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2.0,
+                "SPARSE_IMAGE_MEMORY_REQUIREMENTS_2",
+            ),
+            (
+                StructureType::IMAGE_FORMAT_LIST_CREATE_INFO.0,
+                "IMAGE_FORMAT_LIST_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR.0,
+                "ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR.0,
+                "ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR.0,
+                "ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR.0,
+                "ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR.0,
+                "ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_GEOMETRY_KHR.0,
+                "ACCELERATION_STRUCTURE_GEOMETRY_KHR",
+            ),
+            (
+                StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR.0,
+                "WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_VERSION_INFO_KHR.0,
+                "ACCELERATION_STRUCTURE_VERSION_INFO_KHR",
+            ),
+            (
+                StructureType::COPY_ACCELERATION_STRUCTURE_INFO_KHR.0,
+                "COPY_ACCELERATION_STRUCTURE_INFO_KHR",
+            ),
+            (
+                StructureType::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR.0,
+                "COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR",
+            ),
+            (
+                StructureType::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR.0,
+                "COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_KHR.0,
+                "RAY_TRACING_PIPELINE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR.0,
+                "RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_KHR.0,
+                "ACCELERATION_STRUCTURE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR.0,
+                "RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR.0,
+                "ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR",
+            ),
+            (
+                StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV",
+            ),
+            (
+                StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO.0,
+                "SAMPLER_YCBCR_CONVERSION_CREATE_INFO",
+            ),
+            (
+                StructureType::SAMPLER_YCBCR_CONVERSION_INFO.0,
+                "SAMPLER_YCBCR_CONVERSION_INFO",
+            ),
+            (
+                StructureType::BIND_IMAGE_PLANE_MEMORY_INFO.0,
+                "BIND_IMAGE_PLANE_MEMORY_INFO",
+            ),
+            (
+                StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO.0,
+                "IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES.0,
+                "PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES",
+            ),
+            (
+                StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES.0,
+                "SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES",
+            ),
+            (
+                StructureType::BIND_BUFFER_MEMORY_INFO.0,
+                "BIND_BUFFER_MEMORY_INFO",
+            ),
+            (
+                StructureType::BIND_IMAGE_MEMORY_INFO.0,
+                "BIND_IMAGE_MEMORY_INFO",
+            ),
+            (
+                StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT.0,
+                "DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT.0,
+                "PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT",
+            ),
+            (
+                StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT.0,
+                "IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT.0,
+                "IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT.0,
+                "IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::VALIDATION_CACHE_CREATE_INFO_EXT.0,
+                "VALIDATION_CACHE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT.0,
+                "SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO.0,
+                "DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES.0,
+                "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES.0,
+                "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES",
+            ),
+            (
+                StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO.0,
+                "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT.0,
+                "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV.0,
+                "RAY_TRACING_PIPELINE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV.0,
+                "ACCELERATION_STRUCTURE_CREATE_INFO_NV",
+            ),
+            (StructureType::GEOMETRY_NV.0, "GEOMETRY_NV"),
+            (
+                StructureType::GEOMETRY_TRIANGLES_NV.0,
+                "GEOMETRY_TRIANGLES_NV",
+            ),
+            (StructureType::GEOMETRY_AABB_NV.0, "GEOMETRY_AABB_NV"),
+            (
+                StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV.0,
+                "BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV",
+            ),
+            (
+                StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV.0,
+                "WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV.0,
+                "ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV",
+            ),
+            (
+                StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV.0,
+                "RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::ACCELERATION_STRUCTURE_INFO_NV.0,
+                "ACCELERATION_STRUCTURE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV",
+            ),
+            (
+                StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES.0,
+                "PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES",
+            ),
+            (
+                StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT.0,
+                "DESCRIPTOR_SET_LAYOUT_SUPPORT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT.0,
+                "PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT",
+            ),
+            (
+                StructureType::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT.0,
+                "FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT.0,
+                "DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES.0,
+                "PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES.0,
+                "PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES",
+            ),
+            (
+                StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT.0,
+                "IMPORT_MEMORY_HOST_POINTER_INFO_EXT",
+            ),
+            (
+                StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT.0,
+                "MEMORY_HOST_POINTER_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES.0,
+                "PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR",
+            ),
+            (
+                StructureType::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD.0,
+                "PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD",
+            ),
+            (
+                StructureType::CALIBRATED_TIMESTAMP_INFO_EXT.0,
+                "CALIBRATED_TIMESTAMP_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD.0,
+                "PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD",
+            ),
+            (
+                StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD.0,
+                "DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT",
+            ),
+            (
+                StructureType::PRESENT_FRAME_TOKEN_GGP.0,
+                "PRESENT_FRAME_TOKEN_GGP",
+            ),
+            (
+                StructureType::PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT.0,
+                "PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES.0,
+                "PHYSICAL_DEVICE_DRIVER_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES.0,
+                "PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES.0,
+                "PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES",
+            ),
+            (
+                StructureType::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE.0,
+                "SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV",
+            ),
+            (
+                StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV",
+            ),
+            (StructureType::CHECKPOINT_DATA_NV.0, "CHECKPOINT_DATA_NV"),
+            (
+                StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV.0,
+                "QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES.0,
+                "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES.0,
+                "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES",
+            ),
+            (
+                StructureType::SEMAPHORE_TYPE_CREATE_INFO.0,
+                "SEMAPHORE_TYPE_CREATE_INFO",
+            ),
+            (
+                StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO.0,
+                "TIMELINE_SEMAPHORE_SUBMIT_INFO",
+            ),
+            (StructureType::SEMAPHORE_WAIT_INFO.0, "SEMAPHORE_WAIT_INFO"),
+            (
+                StructureType::SEMAPHORE_SIGNAL_INFO.0,
+                "SEMAPHORE_SIGNAL_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL.0,
+                "PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL",
+            ),
+            (
+                StructureType::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL.0,
+                "QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL",
+            ),
+            (
+                StructureType::INITIALIZE_PERFORMANCE_API_INFO_INTEL.0,
+                "INITIALIZE_PERFORMANCE_API_INFO_INTEL",
+            ),
+            (
+                StructureType::PERFORMANCE_MARKER_INFO_INTEL.0,
+                "PERFORMANCE_MARKER_INFO_INTEL",
+            ),
+            (
+                StructureType::PERFORMANCE_STREAM_MARKER_INFO_INTEL.0,
+                "PERFORMANCE_STREAM_MARKER_INFO_INTEL",
+            ),
+            (
+                StructureType::PERFORMANCE_OVERRIDE_INFO_INTEL.0,
+                "PERFORMANCE_OVERRIDE_INFO_INTEL",
+            ),
+            (
+                StructureType::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL.0,
+                "PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES.0,
+                "PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD.0,
+                "DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD",
+            ),
+            (
+                StructureType::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD.0,
+                "SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD",
+            ),
+            (
+                StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA.0,
+                "IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR",
+            ),
+            (
+                StructureType::METAL_SURFACE_CREATE_INFO_EXT.0,
+                "METAL_SURFACE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT.0,
+                "RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES.0,
+                "PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT.0,
+                "PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT",
+            ),
+            (
+                StructureType::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR.0,
+                "FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR",
+            ),
+            (
+                StructureType::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR.0,
+                "PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD.0,
+                "PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD.0,
+                "PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT",
+            ),
+            (
+                StructureType::MEMORY_PRIORITY_ALLOCATE_INFO_EXT.0,
+                "MEMORY_PRIORITY_ALLOCATE_INFO_EXT",
+            ),
+            (
+                StructureType::SURFACE_PROTECTED_CAPABILITIES_KHR.0,
+                "SURFACE_PROTECTED_CAPABILITIES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES.0,
+                "PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES",
+            ),
+            (
+                StructureType::ATTACHMENT_REFERENCE_STENCIL_LAYOUT.0,
+                "ATTACHMENT_REFERENCE_STENCIL_LAYOUT",
+            ),
+            (
+                StructureType::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT.0,
+                "ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT",
+            ),
+            (
+                StructureType::BUFFER_DEVICE_ADDRESS_INFO.0,
+                "BUFFER_DEVICE_ADDRESS_INFO",
+            ),
+            (
+                StructureType::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT.0,
+                "BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::IMAGE_STENCIL_USAGE_CREATE_INFO.0,
+                "IMAGE_STENCIL_USAGE_CREATE_INFO",
+            ),
+            (
+                StructureType::VALIDATION_FEATURES_EXT.0,
+                "VALIDATION_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV",
+            ),
+            (
+                StructureType::COOPERATIVE_MATRIX_PROPERTIES_NV.0,
+                "COOPERATIVE_MATRIX_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV",
+            ),
+            (
+                StructureType::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV.0,
+                "FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES.0,
+                "PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES",
+            ),
+            (
+                StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT.0,
+                "SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT",
+            ),
+            (
+                StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT.0,
+                "SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT",
+            ),
+            (
+                StructureType::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT.0,
+                "SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT",
+            ),
+            (
+                StructureType::HEADLESS_SURFACE_CREATE_INFO_EXT.0,
+                "HEADLESS_SURFACE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES.0,
+                "PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES",
+            ),
+            (
+                StructureType::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO.0,
+                "BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO",
+            ),
+            (
+                StructureType::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO.0,
+                "MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO",
+            ),
+            (
+                StructureType::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO.0,
+                "DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT.0,
+                "PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES.0,
+                "PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR",
+            ),
+            (StructureType::PIPELINE_INFO_KHR.0, "PIPELINE_INFO_KHR"),
+            (
+                StructureType::PIPELINE_EXECUTABLE_PROPERTIES_KHR.0,
+                "PIPELINE_EXECUTABLE_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::PIPELINE_EXECUTABLE_INFO_KHR.0,
+                "PIPELINE_EXECUTABLE_INFO_KHR",
+            ),
+            (
+                StructureType::PIPELINE_EXECUTABLE_STATISTIC_KHR.0,
+                "PIPELINE_EXECUTABLE_STATISTIC_KHR",
+            ),
+            (
+                StructureType::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR.0,
+                "PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV",
+            ),
+            (
+                StructureType::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV.0,
+                "GRAPHICS_SHADER_GROUP_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV.0,
+                "GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV.0,
+                "INDIRECT_COMMANDS_LAYOUT_TOKEN_NV",
+            ),
+            (
+                StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV.0,
+                "INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::GENERATED_COMMANDS_INFO_NV.0,
+                "GENERATED_COMMANDS_INFO_NV",
+            ),
+            (
+                StructureType::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV.0,
+                "GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM.0,
+                "COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM",
+            ),
+            (
+                StructureType::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM.0,
+                "RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT",
+            ),
+            (
+                StructureType::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT.0,
+                "DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT.0,
+                "DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT.0,
+                "SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT",
+            ),
+            (
+                StructureType::PIPELINE_LIBRARY_CREATE_INFO_KHR.0,
+                "PIPELINE_LIBRARY_CREATE_INFO_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT",
+            ),
+            (
+                StructureType::DEVICE_PRIVATE_DATA_CREATE_INFO_EXT.0,
+                "DEVICE_PRIVATE_DATA_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PRIVATE_DATA_SLOT_CREATE_INFO_EXT.0,
+                "PRIVATE_DATA_SLOT_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV",
+            ),
+            (
+                StructureType::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV.0,
+                "DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV",
+            ),
+            (StructureType::RESERVED_QCOM.0, "RESERVED_QCOM"),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV.0,
+                "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV",
+            ),
+            (
+                StructureType::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV.0,
+                "PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT.0,
+                "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT",
+            ),
+            (
+                StructureType::COPY_COMMAND_TRANSFORM_INFO_QCOM.0,
+                "COPY_COMMAND_TRANSFORM_INFO_QCOM",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT",
+            ),
+            (
+                StructureType::COPY_BUFFER_INFO_2_KHR.0,
+                "COPY_BUFFER_INFO_2_KHR",
+            ),
+            (
+                StructureType::COPY_IMAGE_INFO_2_KHR.0,
+                "COPY_IMAGE_INFO_2_KHR",
+            ),
+            (
+                StructureType::COPY_BUFFER_TO_IMAGE_INFO_2_KHR.0,
+                "COPY_BUFFER_TO_IMAGE_INFO_2_KHR",
+            ),
+            (
+                StructureType::COPY_IMAGE_TO_BUFFER_INFO_2_KHR.0,
+                "COPY_IMAGE_TO_BUFFER_INFO_2_KHR",
+            ),
+            (
+                StructureType::BLIT_IMAGE_INFO_2_KHR.0,
+                "BLIT_IMAGE_INFO_2_KHR",
+            ),
+            (
+                StructureType::RESOLVE_IMAGE_INFO_2_KHR.0,
+                "RESOLVE_IMAGE_INFO_2_KHR",
+            ),
+            (StructureType::BUFFER_COPY_2_KHR.0, "BUFFER_COPY_2_KHR"),
+            (StructureType::IMAGE_COPY_2_KHR.0, "IMAGE_COPY_2_KHR"),
+            (StructureType::IMAGE_BLIT_2_KHR.0, "IMAGE_BLIT_2_KHR"),
+            (
+                StructureType::BUFFER_IMAGE_COPY_2_KHR.0,
+                "BUFFER_IMAGE_COPY_2_KHR",
+            ),
+            (StructureType::IMAGE_RESOLVE_2_KHR.0, "IMAGE_RESOLVE_2_KHR"),
+            (
+                StructureType::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT.0,
+                "PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT",
+            ),
+            (
+                StructureType::DIRECTFB_SURFACE_CREATE_INFO_EXT.0,
+                "DIRECTFB_SURFACE_CREATE_INFO_EXT",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR.0,
+                "PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR",
+            ),
+            (
+                StructureType::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR.0,
+                "PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR",
+            ),
+        ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(i32, &str)] = &[];
+        debug_enum(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SubgroupFeatureFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (SubgroupFeatureFlags::BASIC.0, "BASIC"),
             (SubgroupFeatureFlags::VOTE.0, "VOTE"),
@@ -4541,16 +6188,21 @@ impl fmt::Debug for SubgroupFeatureFlags {
             (SubgroupFeatureFlags::QUAD.0, "QUAD"),
             (SubgroupFeatureFlags::PARTITIONED_NV.0, "PARTITIONED_NV"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SubpassContents {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::INLINE => Some("INLINE"),
             Self::SECONDARY_COMMAND_BUFFERS => Some("SECONDARY_COMMAND_BUFFERS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4560,6 +6212,7 @@ impl fmt::Debug for SubpassContents {
 }
 impl fmt::Debug for SubpassDescriptionFlags {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 SubpassDescriptionFlags::PER_VIEW_ATTRIBUTES_NVX.0,
@@ -4578,17 +6231,23 @@ impl fmt::Debug for SubpassDescriptionFlags {
                 "SHADER_RESOLVE_QCOM",
             ),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SurfaceCounterFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(SurfaceCounterFlagsEXT::VBLANK.0, "VBLANK")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SurfaceTransformFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (SurfaceTransformFlagsKHR::IDENTITY.0, "IDENTITY"),
             (SurfaceTransformFlagsKHR::ROTATE_90.0, "ROTATE_90"),
@@ -4612,11 +6271,14 @@ impl fmt::Debug for SurfaceTransformFlagsKHR {
             ),
             (SurfaceTransformFlagsKHR::INHERIT.0, "INHERIT"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SwapchainCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (
                 SwapchainCreateFlagsKHR::SPLIT_INSTANCE_BIND_REGIONS.0,
@@ -4625,17 +6287,23 @@ impl fmt::Debug for SwapchainCreateFlagsKHR {
             (SwapchainCreateFlagsKHR::PROTECTED.0, "PROTECTED"),
             (SwapchainCreateFlagsKHR::MUTABLE_FORMAT.0, "MUTABLE_FORMAT"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SwapchainImageUsageFlagsANDROID {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[(SwapchainImageUsageFlagsANDROID::SHARED.0, "SHARED")];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for SystemAllocationScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::COMMAND => Some("COMMAND"),
             Self::OBJECT => Some("OBJECT"),
@@ -4644,6 +6312,8 @@ impl fmt::Debug for SystemAllocationScope {
             Self::INSTANCE => Some("INSTANCE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4653,11 +6323,14 @@ impl fmt::Debug for SystemAllocationScope {
 }
 impl fmt::Debug for TessellationDomainOrigin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::UPPER_LEFT => Some("UPPER_LEFT"),
             Self::LOWER_LEFT => Some("LOWER_LEFT"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4667,6 +6340,7 @@ impl fmt::Debug for TessellationDomainOrigin {
 }
 impl fmt::Debug for TimeDomainEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::DEVICE => Some("DEVICE"),
             Self::CLOCK_MONOTONIC => Some("CLOCK_MONOTONIC"),
@@ -4674,6 +6348,8 @@ impl fmt::Debug for TimeDomainEXT {
             Self::QUERY_PERFORMANCE_COUNTER => Some("QUERY_PERFORMANCE_COUNTER"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4683,6 +6359,7 @@ impl fmt::Debug for TimeDomainEXT {
 }
 impl fmt::Debug for ToolPurposeFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         const KNOWN: &[(Flags, &str)] = &[
             (ToolPurposeFlagsEXT::VALIDATION.0, "VALIDATION"),
             (ToolPurposeFlagsEXT::PROFILING.0, "PROFILING"),
@@ -4698,21 +6375,29 @@ impl fmt::Debug for ToolPurposeFlagsEXT {
             (ToolPurposeFlagsEXT::DEBUG_REPORTING.0, "DEBUG_REPORTING"),
             (ToolPurposeFlagsEXT::DEBUG_MARKERS.0, "DEBUG_MARKERS"),
         ];
+        #[cfg(not(feature = "debug-strings"))]
+        const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ValidationCacheCreateFlagsEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ValidationCacheHeaderVersionEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ONE => Some("ONE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4722,11 +6407,14 @@ impl fmt::Debug for ValidationCacheHeaderVersionEXT {
 }
 impl fmt::Debug for ValidationCheckEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ALL => Some("ALL"),
             Self::SHADERS => Some("SHADERS"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4736,6 +6424,7 @@ impl fmt::Debug for ValidationCheckEXT {
 }
 impl fmt::Debug for ValidationFeatureDisableEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::ALL => Some("ALL"),
             Self::SHADERS => Some("SHADERS"),
@@ -4746,6 +6435,8 @@ impl fmt::Debug for ValidationFeatureDisableEXT {
             Self::UNIQUE_HANDLES => Some("UNIQUE_HANDLES"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4755,6 +6446,7 @@ impl fmt::Debug for ValidationFeatureDisableEXT {
 }
 impl fmt::Debug for ValidationFeatureEnableEXT {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::GPU_ASSISTED => Some("GPU_ASSISTED"),
             Self::GPU_ASSISTED_RESERVE_BINDING_SLOT => Some("GPU_ASSISTED_RESERVE_BINDING_SLOT"),
@@ -4763,6 +6455,8 @@ impl fmt::Debug for ValidationFeatureEnableEXT {
             Self::SYNCHRONIZATION_VALIDATION => Some("SYNCHRONIZATION_VALIDATION"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4772,6 +6466,7 @@ impl fmt::Debug for ValidationFeatureEnableEXT {
 }
 impl fmt::Debug for VendorId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::VIV => Some("VIV"),
             Self::VSI => Some("VSI"),
@@ -4780,6 +6475,8 @@ impl fmt::Debug for VendorId {
             Self::MESA => Some("MESA"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4789,11 +6486,14 @@ impl fmt::Debug for VendorId {
 }
 impl fmt::Debug for VertexInputRate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::VERTEX => Some("VERTEX"),
             Self::INSTANCE => Some("INSTANCE"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4803,12 +6503,16 @@ impl fmt::Debug for VertexInputRate {
 }
 impl fmt::Debug for ViSurfaceCreateFlagsNN {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for ViewportCoordinateSwizzleNV {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Self::POSITIVE_X => Some("POSITIVE_X"),
             Self::NEGATIVE_X => Some("NEGATIVE_X"),
@@ -4820,6 +6524,8 @@ impl fmt::Debug for ViewportCoordinateSwizzleNV {
             Self::NEGATIVE_W => Some("NEGATIVE_W"),
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             f.write_str(x)
         } else {
@@ -4829,25 +6535,39 @@ impl fmt::Debug for ViewportCoordinateSwizzleNV {
 }
 impl fmt::Debug for WaylandSurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for Win32SurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for XcbSurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
 impl fmt::Debug for XlibSurfaceCreateFlagsKHR {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
+        const KNOWN: &[(Flags, &str)] = &[];
+        #[cfg(not(feature = "debug-strings"))]
         const KNOWN: &[(Flags, &str)] = &[];
         debug_flags(f, KNOWN, self.0)
     }
 }
+#[cfg(all(test, feature = "debug-strings"))]
+mod tests;
@@ -0,0 +1,11 @@
+use crate::vk::*;
+
+#[test]
+fn large_enum_debug() {
+    assert_eq!(format!("{:?}", Format::R8G8B8A8_UNORM), "R8G8B8A8_UNORM");
+    assert_eq!(
+        format!("{:?}", StructureType::SWAPCHAIN_CREATE_INFO_KHR),
+        "SWAPCHAIN_CREATE_INFO_KHR"
+    );
+    assert_eq!(format!("{:?}", Format::from_raw(-5)), "-5");
+}
@@ -2,10 +2,10 @@ use crate::vk::aliases::*;
 use crate::vk::bitflags::*;
 use crate::vk::constants::*;
 use crate::vk::enums::*;
+use crate::vk::platform::*;
 use crate::vk::platform_types::*;
-use crate::vk::{ptr_chain_iter, Handle};
+use crate::vk::{ptr_chain_iter, Handle, TaggedStructure};
 use std::fmt;
-use std::os::raw::*;
 pub const API_VERSION_1_0: u32 = crate::vk::make_version(1, 0, 0);
 pub const API_VERSION_1_1: u32 = crate::vk::make_version(1, 1, 0);
 pub const API_VERSION_1_2: u32 = crate::vk::make_version(1, 2, 0);
@@ -380,7 +380,7 @@ handle_nondispatchable ! (DebugUtilsMessengerEXT , DEBUG_UTILS_MESSENGER_EXT , d
 pub type PFN_vkInternalAllocationNotification = Option<
     unsafe extern "system" fn(
         p_user_data: *mut c_void,
-        size: usize,
+        size: size_t,
         allocation_type: InternalAllocationType,
         allocation_scope: SystemAllocationScope,
     ) -> c_void,
@@ -390,7 +390,7 @@ pub type PFN_vkInternalAllocationNotification = Option<
 pub type PFN_vkInternalFreeNotification = Option<
     unsafe extern "system" fn(
         p_user_data: *mut c_void,
-        size: usize,
+        size: size_t,
         allocation_type: InternalAllocationType,
         allocation_scope: SystemAllocationScope,
     ) -> c_void,
@@ -401,8 +401,8 @@ pub type PFN_vkReallocationFunction = Option<
     unsafe extern "system" fn(
         p_user_data: *mut c_void,
         p_original: *mut c_void,
-        size: usize,
-        alignment: usize,
+        size: size_t,
+        alignment: size_t,
         allocation_scope: SystemAllocationScope,
     ) -> *mut c_void,
 >;
@@ -411,8 +411,8 @@ pub type PFN_vkReallocationFunction = Option<
 pub type PFN_vkAllocationFunction = Option<
     unsafe extern "system" fn(
         p_user_data: *mut c_void,
-        size: usize,
-        alignment: usize,
+        size: size_t,
+        alignment: size_t,
         allocation_scope: SystemAllocationScope,
     ) -> *mut c_void,
 >;
@@ -430,7 +430,7 @@ pub type PFN_vkDebugReportCallbackEXT = Option<
         flags: DebugReportFlagsEXT,
         object_type: DebugReportObjectTypeEXT,
         object: u64,
-        location: usize,
+        location: size_t,
         message_code: i32,
         p_layer_prefix: *const c_char,
         p_message: *const c_char,
@@ -1000,11 +1000,19 @@ impl<'a> PhysicalDevicePropertiesBuilder<'a> {
         self.inner.device_type = device_type;
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn device_name(
         mut self,
-        device_name: [c_char; MAX_PHYSICAL_DEVICE_NAME_SIZE],
+        device_name: &::std::ffi::CStr,
     ) -> PhysicalDevicePropertiesBuilder<'a> {
-        self.inner.device_name = device_name;
+        let bytes = device_name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.device_name.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.device_name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn pipeline_cache_uuid(
@@ -1032,6 +1040,34 @@ impl<'a> PhysicalDevicePropertiesBuilder<'a> {
         self.inner
     }
 }
+impl PhysicalDeviceProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
+impl PhysicalDeviceProperties {
+    #[doc = " Returns `device_name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn device_name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.device_name.as_ptr() as *const u8,
+                self.device_name.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExtensionProperties.html>"]
@@ -1082,11 +1118,19 @@ impl<'a> ::std::ops::DerefMut for ExtensionPropertiesBuilder<'a> {
     }
 }
 impl<'a> ExtensionPropertiesBuilder<'a> {
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn extension_name(
         mut self,
-        extension_name: [c_char; MAX_EXTENSION_NAME_SIZE],
+        extension_name: &::std::ffi::CStr,
     ) -> ExtensionPropertiesBuilder<'a> {
-        self.inner.extension_name = extension_name;
+        let bytes = extension_name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.extension_name.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.extension_name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn spec_version(mut self, spec_version: u32) -> ExtensionPropertiesBuilder<'a> {
@@ -1100,6 +1144,34 @@ impl<'a> ExtensionPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl ExtensionProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
+impl ExtensionProperties {
+    #[doc = " Returns `extension_name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn extension_name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.extension_name.as_ptr() as *const u8,
+                self.extension_name.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkLayerProperties.html>"]
@@ -1158,11 +1230,16 @@ impl<'a> ::std::ops::DerefMut for LayerPropertiesBuilder<'a> {
     }
 }
 impl<'a> LayerPropertiesBuilder<'a> {
-    pub fn layer_name(
-        mut self,
-        layer_name: [c_char; MAX_EXTENSION_NAME_SIZE],
-    ) -> LayerPropertiesBuilder<'a> {
-        self.inner.layer_name = layer_name;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn layer_name(mut self, layer_name: &::std::ffi::CStr) -> LayerPropertiesBuilder<'a> {
+        let bytes = layer_name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.layer_name.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.layer_name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn spec_version(mut self, spec_version: u32) -> LayerPropertiesBuilder<'a> {
@@ -1176,11 +1253,16 @@ impl<'a> LayerPropertiesBuilder<'a> {
         self.inner.implementation_version = implementation_version;
         self
     }
-    pub fn description(
-        mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
-    ) -> LayerPropertiesBuilder<'a> {
-        self.inner.description = description;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn description(mut self, description: &::std::ffi::CStr) -> LayerPropertiesBuilder<'a> {
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     #[doc = r" Calling build will **discard** all the lifetime information. Only call this if"]
@@ -1190,6 +1272,50 @@ impl<'a> LayerPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl LayerProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
+impl LayerProperties {
+    #[doc = " Returns `layer_name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn layer_name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.layer_name.as_ptr() as *const u8,
+                self.layer_name.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkApplicationInfo.html>"]
@@ -1269,6 +1395,8 @@ impl<'a> ApplicationInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsApplicationInfo>(
         mut self,
         next: &'a mut T,
@@ -1278,6 +1406,8 @@ impl<'a> ApplicationInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkApplicationInfo", &mut self.inner);
         }
         self
     }
@@ -1288,6 +1418,9 @@ impl<'a> ApplicationInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ApplicationInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::APPLICATION_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAllocationCallbacks.html>"]
@@ -1476,6 +1609,8 @@ impl<'a> DeviceQueueCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceQueueCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -1485,6 +1620,8 @@ impl<'a> DeviceQueueCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDeviceQueueCreateInfo", &mut self.inner);
         }
         self
     }
@@ -1495,6 +1632,9 @@ impl<'a> DeviceQueueCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceQueueCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_QUEUE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceCreateInfo.html>"]
@@ -1564,6 +1704,16 @@ impl<'a> DeviceCreateInfoBuilder<'a> {
         self.inner.p_queue_create_infos = queue_create_infos.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn queue_create_infos_from_builders(
+        mut self,
+        queue_create_infos: &'a [DeviceQueueCreateInfoBuilder<'a>],
+    ) -> DeviceCreateInfoBuilder<'a> {
+        self.inner.queue_create_info_count = queue_create_infos.len() as _;
+        self.inner.p_queue_create_infos =
+            queue_create_infos.as_ptr() as *const DeviceQueueCreateInfo;
+        self
+    }
     pub fn enabled_layer_names(
         mut self,
         enabled_layer_names: &'a [*const c_char],
@@ -1592,6 +1742,8 @@ impl<'a> DeviceCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -1601,6 +1753,8 @@ impl<'a> DeviceCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDeviceCreateInfo", &mut self.inner);
         }
         self
     }
@@ -1611,6 +1765,9 @@ impl<'a> DeviceCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkInstanceCreateInfo.html>"]
@@ -1696,6 +1853,8 @@ impl<'a> InstanceCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsInstanceCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -1705,6 +1864,8 @@ impl<'a> InstanceCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkInstanceCreateInfo", &mut self.inner);
         }
         self
     }
@@ -1715,6 +1876,9 @@ impl<'a> InstanceCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for InstanceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::INSTANCE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueueFamilyProperties.html>"]
@@ -1778,6 +1942,16 @@ impl<'a> QueueFamilyPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl QueueFamilyProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMemoryProperties.html>"]
@@ -1857,6 +2031,16 @@ impl<'a> PhysicalDeviceMemoryPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl PhysicalDeviceMemoryProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryAllocateInfo.html>"]
@@ -1915,6 +2099,8 @@ impl<'a> MemoryAllocateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryAllocateInfo>(
         mut self,
         next: &'a mut T,
@@ -1924,6 +2110,8 @@ impl<'a> MemoryAllocateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryAllocateInfo", &mut self.inner);
         }
         self
     }
@@ -1934,6 +2122,9 @@ impl<'a> MemoryAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryRequirements.html>"]
@@ -1986,6 +2177,16 @@ impl<'a> MemoryRequirementsBuilder<'a> {
         self.inner
     }
 }
+impl MemoryRequirements {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSparseImageFormatProperties.html>"]
@@ -2047,6 +2248,16 @@ impl<'a> SparseImageFormatPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl SparseImageFormatProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSparseImageMemoryRequirements.html>"]
@@ -2124,6 +2335,16 @@ impl<'a> SparseImageMemoryRequirementsBuilder<'a> {
         self.inner
     }
 }
+impl SparseImageMemoryRequirements {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryType.html>"]
@@ -2282,6 +2503,8 @@ impl<'a> MappedMemoryRangeBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMappedMemoryRange>(
         mut self,
         next: &'a mut T,
@@ -2291,6 +2514,8 @@ impl<'a> MappedMemoryRangeBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMappedMemoryRange", &mut self.inner);
         }
         self
     }
@@ -2301,6 +2526,9 @@ impl<'a> MappedMemoryRangeBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MappedMemoryRange {
+    const STRUCTURE_TYPE: StructureType = StructureType::MAPPED_MEMORY_RANGE;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFormatProperties.html>"]
@@ -2362,6 +2590,16 @@ impl<'a> FormatPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl FormatProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageFormatProperties.html>"]
@@ -2430,6 +2668,16 @@ impl<'a> ImageFormatPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl ImageFormatProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorBufferInfo.html>"]
@@ -2639,6 +2887,8 @@ impl<'a> WriteDescriptorSetBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsWriteDescriptorSet>(
         mut self,
         next: &'a mut T,
@@ -2648,6 +2898,8 @@ impl<'a> WriteDescriptorSetBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkWriteDescriptorSet", &mut self.inner);
         }
         self
     }
@@ -2658,6 +2910,9 @@ impl<'a> WriteDescriptorSetBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for WriteDescriptorSet {
+    const STRUCTURE_TYPE: StructureType = StructureType::WRITE_DESCRIPTOR_SET;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyDescriptorSet.html>"]
@@ -2746,6 +3001,8 @@ impl<'a> CopyDescriptorSetBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyDescriptorSet>(
         mut self,
         next: &'a mut T,
@@ -2755,6 +3012,8 @@ impl<'a> CopyDescriptorSetBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCopyDescriptorSet", &mut self.inner);
         }
         self
     }
@@ -2765,6 +3024,9 @@ impl<'a> CopyDescriptorSetBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyDescriptorSet {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_DESCRIPTOR_SET;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferCreateInfo.html>"]
@@ -2847,6 +3109,8 @@ impl<'a> BufferCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -2856,6 +3120,8 @@ impl<'a> BufferCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferCreateInfo", &mut self.inner);
         }
         self
     }
@@ -2866,6 +3132,9 @@ impl<'a> BufferCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferViewCreateInfo.html>"]
@@ -2942,6 +3211,8 @@ impl<'a> BufferViewCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferViewCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -2951,6 +3222,8 @@ impl<'a> BufferViewCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferViewCreateInfo", &mut self.inner);
         }
         self
     }
@@ -2961,6 +3234,9 @@ impl<'a> BufferViewCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferViewCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_VIEW_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageSubresource.html>"]
@@ -3196,6 +3472,8 @@ impl<'a> MemoryBarrierBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryBarrier>(
         mut self,
         next: &'a mut T,
@@ -3205,6 +3483,8 @@ impl<'a> MemoryBarrierBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryBarrier", &mut self.inner);
         }
         self
     }
@@ -3215,6 +3495,9 @@ impl<'a> MemoryBarrierBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_BARRIER;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferMemoryBarrier.html>"]
@@ -3315,6 +3598,8 @@ impl<'a> BufferMemoryBarrierBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferMemoryBarrier>(
         mut self,
         next: &'a mut T,
@@ -3324,6 +3609,8 @@ impl<'a> BufferMemoryBarrierBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferMemoryBarrier", &mut self.inner);
         }
         self
     }
@@ -3334,6 +3621,9 @@ impl<'a> BufferMemoryBarrierBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferMemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_MEMORY_BARRIER;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageMemoryBarrier.html>"]
@@ -3443,6 +3733,8 @@ impl<'a> ImageMemoryBarrierBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageMemoryBarrier>(
         mut self,
         next: &'a mut T,
@@ -3452,6 +3744,8 @@ impl<'a> ImageMemoryBarrierBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageMemoryBarrier", &mut self.inner);
         }
         self
     }
@@ -3462,6 +3756,9 @@ impl<'a> ImageMemoryBarrierBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageMemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_MEMORY_BARRIER;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageCreateInfo.html>"]
@@ -3586,6 +3883,8 @@ impl<'a> ImageCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -3595,6 +3894,8 @@ impl<'a> ImageCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageCreateInfo", &mut self.inner);
         }
         self
     }
@@ -3605,6 +3906,9 @@ impl<'a> ImageCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubresourceLayout.html>"]
@@ -3752,6 +4056,8 @@ impl<'a> ImageViewCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageViewCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -3761,6 +4067,8 @@ impl<'a> ImageViewCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageViewCreateInfo", &mut self.inner);
         }
         self
     }
@@ -3771,6 +4079,9 @@ impl<'a> ImageViewCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageViewCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_VIEW_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferCopy.html>"]
@@ -4212,6 +4523,15 @@ impl<'a> BindSparseInfoBuilder<'a> {
         self.inner.p_buffer_binds = buffer_binds.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn buffer_binds_from_builders(
+        mut self,
+        buffer_binds: &'a [SparseBufferMemoryBindInfoBuilder<'a>],
+    ) -> BindSparseInfoBuilder<'a> {
+        self.inner.buffer_bind_count = buffer_binds.len() as _;
+        self.inner.p_buffer_binds = buffer_binds.as_ptr() as *const SparseBufferMemoryBindInfo;
+        self
+    }
     pub fn image_opaque_binds(
         mut self,
         image_opaque_binds: &'a [SparseImageOpaqueMemoryBindInfo],
@@ -4220,6 +4540,16 @@ impl<'a> BindSparseInfoBuilder<'a> {
         self.inner.p_image_opaque_binds = image_opaque_binds.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn image_opaque_binds_from_builders(
+        mut self,
+        image_opaque_binds: &'a [SparseImageOpaqueMemoryBindInfoBuilder<'a>],
+    ) -> BindSparseInfoBuilder<'a> {
+        self.inner.image_opaque_bind_count = image_opaque_binds.len() as _;
+        self.inner.p_image_opaque_binds =
+            image_opaque_binds.as_ptr() as *const SparseImageOpaqueMemoryBindInfo;
+        self
+    }
     pub fn image_binds(
         mut self,
         image_binds: &'a [SparseImageMemoryBindInfo],
@@ -4228,6 +4558,15 @@ impl<'a> BindSparseInfoBuilder<'a> {
         self.inner.p_image_binds = image_binds.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn image_binds_from_builders(
+        mut self,
+        image_binds: &'a [SparseImageMemoryBindInfoBuilder<'a>],
+    ) -> BindSparseInfoBuilder<'a> {
+        self.inner.image_bind_count = image_binds.len() as _;
+        self.inner.p_image_binds = image_binds.as_ptr() as *const SparseImageMemoryBindInfo;
+        self
+    }
     pub fn signal_semaphores(
         mut self,
         signal_semaphores: &'a [Semaphore],
@@ -4241,6 +4580,8 @@ impl<'a> BindSparseInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBindSparseInfo>(
         mut self,
         next: &'a mut T,
@@ -4250,6 +4591,8 @@ impl<'a> BindSparseInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBindSparseInfo", &mut self.inner);
         }
         self
     }
@@ -4260,6 +4603,9 @@ impl<'a> BindSparseInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindSparseInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_SPARSE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageCopy.html>"]
@@ -4546,7 +4892,7 @@ pub struct ShaderModuleCreateInfo {
     pub s_type: StructureType,
     pub p_next: *const c_void,
     pub flags: ShaderModuleCreateFlags,
-    pub code_size: usize,
+    pub code_size: size_t,
     pub p_code: *const u32,
 }
 impl ::std::default::Default for ShaderModuleCreateInfo {
@@ -4555,7 +4901,7 @@ impl ::std::default::Default for ShaderModuleCreateInfo {
             s_type: StructureType::SHADER_MODULE_CREATE_INFO,
             p_next: ::std::ptr::null(),
             flags: ShaderModuleCreateFlags::default(),
-            code_size: usize::default(),
+            code_size: size_t::default(),
             p_code: ::std::ptr::null(),
         }
     }
@@ -4600,6 +4946,8 @@ impl<'a> ShaderModuleCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsShaderModuleCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -4609,6 +4957,8 @@ impl<'a> ShaderModuleCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkShaderModuleCreateInfo", &mut self.inner);
         }
         self
     }
@@ -4619,6 +4969,9 @@ impl<'a> ShaderModuleCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ShaderModuleCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SHADER_MODULE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetLayoutBinding.html>"]
@@ -4767,11 +5120,22 @@ impl<'a> DescriptorSetLayoutCreateInfoBuilder<'a> {
         self.inner.p_bindings = bindings.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn bindings_from_builders(
+        mut self,
+        bindings: &'a [DescriptorSetLayoutBindingBuilder<'a>],
+    ) -> DescriptorSetLayoutCreateInfoBuilder<'a> {
+        self.inner.binding_count = bindings.len() as _;
+        self.inner.p_bindings = bindings.as_ptr() as *const DescriptorSetLayoutBinding;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDescriptorSetLayoutCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -4781,6 +5145,8 @@ impl<'a> DescriptorSetLayoutCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDescriptorSetLayoutCreateInfo", &mut self.inner);
         }
         self
     }
@@ -4791,6 +5157,9 @@ impl<'a> DescriptorSetLayoutCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetLayoutCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorPoolSize.html>"]
@@ -4911,6 +5280,8 @@ impl<'a> DescriptorPoolCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDescriptorPoolCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -4920,6 +5291,8 @@ impl<'a> DescriptorPoolCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDescriptorPoolCreateInfo", &mut self.inner);
         }
         self
     }
@@ -4930,6 +5303,9 @@ impl<'a> DescriptorPoolCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DESCRIPTOR_POOL_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetAllocateInfo.html>"]
@@ -4997,6 +5373,8 @@ impl<'a> DescriptorSetAllocateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDescriptorSetAllocateInfo>(
         mut self,
         next: &'a mut T,
@@ -5006,6 +5384,8 @@ impl<'a> DescriptorSetAllocateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDescriptorSetAllocateInfo", &mut self.inner);
         }
         self
     }
@@ -5016,13 +5396,16 @@ impl<'a> DescriptorSetAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DESCRIPTOR_SET_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSpecializationMapEntry.html>"]
 pub struct SpecializationMapEntry {
     pub constant_id: u32,
     pub offset: u32,
-    pub size: usize,
+    pub size: size_t,
 }
 impl SpecializationMapEntry {
     pub fn builder<'a>() -> SpecializationMapEntryBuilder<'a> {
@@ -5057,7 +5440,7 @@ impl<'a> SpecializationMapEntryBuilder<'a> {
         self.inner.offset = offset;
         self
     }
-    pub fn size(mut self, size: usize) -> SpecializationMapEntryBuilder<'a> {
+    pub fn size(mut self, size: size_t) -> SpecializationMapEntryBuilder<'a> {
         self.inner.size = size;
         self
     }
@@ -5074,7 +5457,7 @@ impl<'a> SpecializationMapEntryBuilder<'a> {
 pub struct SpecializationInfo {
     pub map_entry_count: u32,
     pub p_map_entries: *const SpecializationMapEntry,
-    pub data_size: usize,
+    pub data_size: size_t,
     pub p_data: *const c_void,
 }
 impl ::std::default::Default for SpecializationInfo {
@@ -5082,7 +5465,7 @@ impl ::std::default::Default for SpecializationInfo {
         SpecializationInfo {
             map_entry_count: u32::default(),
             p_map_entries: ::std::ptr::null(),
-            data_size: usize::default(),
+            data_size: size_t::default(),
             p_data: ::std::ptr::null(),
         }
     }
@@ -5214,6 +5597,8 @@ impl<'a> PipelineShaderStageCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineShaderStageCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5223,6 +5608,8 @@ impl<'a> PipelineShaderStageCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineShaderStageCreateInfo", &mut self.inner);
         }
         self
     }
@@ -5233,6 +5620,9 @@ impl<'a> PipelineShaderStageCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineShaderStageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkComputePipelineCreateInfo.html>"]
@@ -5318,6 +5708,8 @@ impl<'a> ComputePipelineCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsComputePipelineCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5327,6 +5719,8 @@ impl<'a> ComputePipelineCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkComputePipelineCreateInfo", &mut self.inner);
         }
         self
     }
@@ -5337,6 +5731,9 @@ impl<'a> ComputePipelineCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ComputePipelineCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::COMPUTE_PIPELINE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkVertexInputBindingDescription.html>"]
@@ -5528,6 +5925,8 @@ impl<'a> PipelineVertexInputStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineVertexInputStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5537,6 +5936,11 @@ impl<'a> PipelineVertexInputStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineVertexInputStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -5547,6 +5951,9 @@ impl<'a> PipelineVertexInputStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineVertexInputStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineInputAssemblyStateCreateInfo.html>"]
@@ -5620,6 +6027,8 @@ impl<'a> PipelineInputAssemblyStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineInputAssemblyStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5629,6 +6038,11 @@ impl<'a> PipelineInputAssemblyStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineInputAssemblyStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -5639,6 +6053,9 @@ impl<'a> PipelineInputAssemblyStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineInputAssemblyStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineTessellationStateCreateInfo.html>"]
@@ -5703,6 +6120,8 @@ impl<'a> PipelineTessellationStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineTessellationStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5712,6 +6131,11 @@ impl<'a> PipelineTessellationStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineTessellationStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -5722,6 +6146,9 @@ impl<'a> PipelineTessellationStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineTessellationStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineViewportStateCreateInfo.html>"]
@@ -5815,6 +6242,8 @@ impl<'a> PipelineViewportStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineViewportStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5824,6 +6253,8 @@ impl<'a> PipelineViewportStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineViewportStateCreateInfo", &mut self.inner);
         }
         self
     }
@@ -5834,6 +6265,9 @@ impl<'a> PipelineViewportStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationStateCreateInfo.html>"]
@@ -5979,6 +6413,8 @@ impl<'a> PipelineRasterizationStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineRasterizationStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -5988,6 +6424,11 @@ impl<'a> PipelineRasterizationStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineRasterizationStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -5998,6 +6439,9 @@ impl<'a> PipelineRasterizationStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineMultisampleStateCreateInfo.html>"]
@@ -6107,6 +6551,8 @@ impl<'a> PipelineMultisampleStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineMultisampleStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6116,6 +6562,11 @@ impl<'a> PipelineMultisampleStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineMultisampleStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -6126,6 +6577,9 @@ impl<'a> PipelineMultisampleStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineMultisampleStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineColorBlendAttachmentState.html>"]
@@ -6318,6 +6772,8 @@ impl<'a> PipelineColorBlendStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineColorBlendStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6327,6 +6783,11 @@ impl<'a> PipelineColorBlendStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineColorBlendStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -6337,6 +6798,9 @@ impl<'a> PipelineColorBlendStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineColorBlendStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineDynamicStateCreateInfo.html>"]
@@ -6404,6 +6868,8 @@ impl<'a> PipelineDynamicStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineDynamicStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6413,6 +6879,8 @@ impl<'a> PipelineDynamicStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineDynamicStateCreateInfo", &mut self.inner);
         }
         self
     }
@@ -6423,6 +6891,9 @@ impl<'a> PipelineDynamicStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineDynamicStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkStencilOpState.html>"]
@@ -6628,6 +7099,8 @@ impl<'a> PipelineDepthStencilStateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineDepthStencilStateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6637,6 +7110,11 @@ impl<'a> PipelineDepthStencilStateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineDepthStencilStateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -6647,6 +7125,9 @@ impl<'a> PipelineDepthStencilStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineDepthStencilStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGraphicsPipelineCreateInfo.html>"]
@@ -6734,6 +7215,15 @@ impl<'a> GraphicsPipelineCreateInfoBuilder<'a> {
         self.inner.p_stages = stages.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn stages_from_builders(
+        mut self,
+        stages: &'a [PipelineShaderStageCreateInfoBuilder<'a>],
+    ) -> GraphicsPipelineCreateInfoBuilder<'a> {
+        self.inner.stage_count = stages.len() as _;
+        self.inner.p_stages = stages.as_ptr() as *const PipelineShaderStageCreateInfo;
+        self
+    }
     pub fn vertex_input_state(
         mut self,
         vertex_input_state: &'a PipelineVertexInputStateCreateInfo,
@@ -6828,6 +7318,8 @@ impl<'a> GraphicsPipelineCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGraphicsPipelineCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6837,6 +7329,8 @@ impl<'a> GraphicsPipelineCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGraphicsPipelineCreateInfo", &mut self.inner);
         }
         self
     }
@@ -6847,6 +7341,9 @@ impl<'a> GraphicsPipelineCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GraphicsPipelineCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::GRAPHICS_PIPELINE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCacheCreateInfo.html>"]
@@ -6854,7 +7351,7 @@ pub struct PipelineCacheCreateInfo {
     pub s_type: StructureType,
     pub p_next: *const c_void,
     pub flags: PipelineCacheCreateFlags,
-    pub initial_data_size: usize,
+    pub initial_data_size: size_t,
     pub p_initial_data: *const c_void,
 }
 impl ::std::default::Default for PipelineCacheCreateInfo {
@@ -6863,7 +7360,7 @@ impl ::std::default::Default for PipelineCacheCreateInfo {
             s_type: StructureType::PIPELINE_CACHE_CREATE_INFO,
             p_next: ::std::ptr::null(),
             flags: PipelineCacheCreateFlags::default(),
-            initial_data_size: usize::default(),
+            initial_data_size: size_t::default(),
             p_initial_data: ::std::ptr::null(),
         }
     }
@@ -6908,6 +7405,8 @@ impl<'a> PipelineCacheCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineCacheCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -6917,6 +7416,8 @@ impl<'a> PipelineCacheCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineCacheCreateInfo", &mut self.inner);
         }
         self
     }
@@ -6927,6 +7428,9 @@ impl<'a> PipelineCacheCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCacheCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_CACHE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPushConstantRange.html>"]
@@ -7058,6 +7562,8 @@ impl<'a> PipelineLayoutCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineLayoutCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -7067,6 +7573,8 @@ impl<'a> PipelineLayoutCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineLayoutCreateInfo", &mut self.inner);
         }
         self
     }
@@ -7077,6 +7585,9 @@ impl<'a> PipelineLayoutCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineLayoutCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_LAYOUT_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerCreateInfo.html>"]
@@ -7231,6 +7742,8 @@ impl<'a> SamplerCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSamplerCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -7240,6 +7753,8 @@ impl<'a> SamplerCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSamplerCreateInfo", &mut self.inner);
         }
         self
     }
@@ -7250,6 +7765,9 @@ impl<'a> SamplerCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SAMPLER_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandPoolCreateInfo.html>"]
@@ -7311,6 +7829,8 @@ impl<'a> CommandPoolCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCommandPoolCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -7320,6 +7840,8 @@ impl<'a> CommandPoolCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCommandPoolCreateInfo", &mut self.inner);
         }
         self
     }
@@ -7330,6 +7852,9 @@ impl<'a> CommandPoolCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::COMMAND_POOL_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandBufferAllocateInfo.html>"]
@@ -7400,6 +7925,8 @@ impl<'a> CommandBufferAllocateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCommandBufferAllocateInfo>(
         mut self,
         next: &'a mut T,
@@ -7409,6 +7936,8 @@ impl<'a> CommandBufferAllocateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCommandBufferAllocateInfo", &mut self.inner);
         }
         self
     }
@@ -7419,6 +7948,9 @@ impl<'a> CommandBufferAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandBufferAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::COMMAND_BUFFER_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandBufferInheritanceInfo.html>"]
@@ -7516,6 +8048,8 @@ impl<'a> CommandBufferInheritanceInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCommandBufferInheritanceInfo>(
         mut self,
         next: &'a mut T,
@@ -7525,6 +8059,8 @@ impl<'a> CommandBufferInheritanceInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCommandBufferInheritanceInfo", &mut self.inner);
         }
         self
     }
@@ -7535,6 +8071,9 @@ impl<'a> CommandBufferInheritanceInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandBufferInheritanceInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::COMMAND_BUFFER_INHERITANCE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandBufferBeginInfo.html>"]
@@ -7596,6 +8135,8 @@ impl<'a> CommandBufferBeginInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCommandBufferBeginInfo>(
         mut self,
         next: &'a mut T,
@@ -7605,6 +8146,8 @@ impl<'a> CommandBufferBeginInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCommandBufferBeginInfo", &mut self.inner);
         }
         self
     }
@@ -7615,6 +8158,9 @@ impl<'a> CommandBufferBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandBufferBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::COMMAND_BUFFER_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassBeginInfo.html>"]
@@ -7704,6 +8250,8 @@ impl<'a> RenderPassBeginInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRenderPassBeginInfo>(
         mut self,
         next: &'a mut T,
@@ -7713,6 +8261,8 @@ impl<'a> RenderPassBeginInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkRenderPassBeginInfo", &mut self.inner);
         }
         self
     }
@@ -7723,6 +8273,9 @@ impl<'a> RenderPassBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkClearColorValue.html>"]
@@ -8263,6 +8816,15 @@ impl<'a> RenderPassCreateInfoBuilder<'a> {
         self.inner.p_subpasses = subpasses.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn subpasses_from_builders(
+        mut self,
+        subpasses: &'a [SubpassDescriptionBuilder<'a>],
+    ) -> RenderPassCreateInfoBuilder<'a> {
+        self.inner.subpass_count = subpasses.len() as _;
+        self.inner.p_subpasses = subpasses.as_ptr() as *const SubpassDescription;
+        self
+    }
     pub fn dependencies(
         mut self,
         dependencies: &'a [SubpassDependency],
@@ -8276,6 +8838,8 @@ impl<'a> RenderPassCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRenderPassCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -8285,6 +8849,8 @@ impl<'a> RenderPassCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkRenderPassCreateInfo", &mut self.inner);
         }
         self
     }
@@ -8295,6 +8861,9 @@ impl<'a> RenderPassCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkEventCreateInfo.html>"]
@@ -8347,6 +8916,8 @@ impl<'a> EventCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsEventCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -8356,6 +8927,8 @@ impl<'a> EventCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkEventCreateInfo", &mut self.inner);
         }
         self
     }
@@ -8366,6 +8939,9 @@ impl<'a> EventCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for EventCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EVENT_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFenceCreateInfo.html>"]
@@ -8418,6 +8994,8 @@ impl<'a> FenceCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFenceCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -8427,6 +9005,8 @@ impl<'a> FenceCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFenceCreateInfo", &mut self.inner);
         }
         self
     }
@@ -8437,6 +9017,9 @@ impl<'a> FenceCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FenceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FENCE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFeatures.html>"]
@@ -8877,6 +9460,208 @@ impl<'a> PhysicalDeviceFeaturesBuilder<'a> {
         self.inner
     }
 }
+impl PhysicalDeviceFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.robust_buffer_access != FALSE && self.robust_buffer_access == FALSE {
+            missing.push("robustBufferAccess");
+        }
+        if required.full_draw_index_uint32 != FALSE && self.full_draw_index_uint32 == FALSE {
+            missing.push("fullDrawIndexUint32");
+        }
+        if required.image_cube_array != FALSE && self.image_cube_array == FALSE {
+            missing.push("imageCubeArray");
+        }
+        if required.independent_blend != FALSE && self.independent_blend == FALSE {
+            missing.push("independentBlend");
+        }
+        if required.geometry_shader != FALSE && self.geometry_shader == FALSE {
+            missing.push("geometryShader");
+        }
+        if required.tessellation_shader != FALSE && self.tessellation_shader == FALSE {
+            missing.push("tessellationShader");
+        }
+        if required.sample_rate_shading != FALSE && self.sample_rate_shading == FALSE {
+            missing.push("sampleRateShading");
+        }
+        if required.dual_src_blend != FALSE && self.dual_src_blend == FALSE {
+            missing.push("dualSrcBlend");
+        }
+        if required.logic_op != FALSE && self.logic_op == FALSE {
+            missing.push("logicOp");
+        }
+        if required.multi_draw_indirect != FALSE && self.multi_draw_indirect == FALSE {
+            missing.push("multiDrawIndirect");
+        }
+        if required.draw_indirect_first_instance != FALSE
+            && self.draw_indirect_first_instance == FALSE
+        {
+            missing.push("drawIndirectFirstInstance");
+        }
+        if required.depth_clamp != FALSE && self.depth_clamp == FALSE {
+            missing.push("depthClamp");
+        }
+        if required.depth_bias_clamp != FALSE && self.depth_bias_clamp == FALSE {
+            missing.push("depthBiasClamp");
+        }
+        if required.fill_mode_non_solid != FALSE && self.fill_mode_non_solid == FALSE {
+            missing.push("fillModeNonSolid");
+        }
+        if required.depth_bounds != FALSE && self.depth_bounds == FALSE {
+            missing.push("depthBounds");
+        }
+        if required.wide_lines != FALSE && self.wide_lines == FALSE {
+            missing.push("wideLines");
+        }
+        if required.large_points != FALSE && self.large_points == FALSE {
+            missing.push("largePoints");
+        }
+        if required.alpha_to_one != FALSE && self.alpha_to_one == FALSE {
+            missing.push("alphaToOne");
+        }
+        if required.multi_viewport != FALSE && self.multi_viewport == FALSE {
+            missing.push("multiViewport");
+        }
+        if required.sampler_anisotropy != FALSE && self.sampler_anisotropy == FALSE {
+            missing.push("samplerAnisotropy");
+        }
+        if required.texture_compression_etc2 != FALSE && self.texture_compression_etc2 == FALSE {
+            missing.push("textureCompressionETC2");
+        }
+        if required.texture_compression_astc_ldr != FALSE
+            && self.texture_compression_astc_ldr == FALSE
+        {
+            missing.push("textureCompressionASTC_LDR");
+        }
+        if required.texture_compression_bc != FALSE && self.texture_compression_bc == FALSE {
+            missing.push("textureCompressionBC");
+        }
+        if required.occlusion_query_precise != FALSE && self.occlusion_query_precise == FALSE {
+            missing.push("occlusionQueryPrecise");
+        }
+        if required.pipeline_statistics_query != FALSE && self.pipeline_statistics_query == FALSE {
+            missing.push("pipelineStatisticsQuery");
+        }
+        if required.vertex_pipeline_stores_and_atomics != FALSE
+            && self.vertex_pipeline_stores_and_atomics == FALSE
+        {
+            missing.push("vertexPipelineStoresAndAtomics");
+        }
+        if required.fragment_stores_and_atomics != FALSE
+            && self.fragment_stores_and_atomics == FALSE
+        {
+            missing.push("fragmentStoresAndAtomics");
+        }
+        if required.shader_tessellation_and_geometry_point_size != FALSE
+            && self.shader_tessellation_and_geometry_point_size == FALSE
+        {
+            missing.push("shaderTessellationAndGeometryPointSize");
+        }
+        if required.shader_image_gather_extended != FALSE
+            && self.shader_image_gather_extended == FALSE
+        {
+            missing.push("shaderImageGatherExtended");
+        }
+        if required.shader_storage_image_extended_formats != FALSE
+            && self.shader_storage_image_extended_formats == FALSE
+        {
+            missing.push("shaderStorageImageExtendedFormats");
+        }
+        if required.shader_storage_image_multisample != FALSE
+            && self.shader_storage_image_multisample == FALSE
+        {
+            missing.push("shaderStorageImageMultisample");
+        }
+        if required.shader_storage_image_read_without_format != FALSE
+            && self.shader_storage_image_read_without_format == FALSE
+        {
+            missing.push("shaderStorageImageReadWithoutFormat");
+        }
+        if required.shader_storage_image_write_without_format != FALSE
+            && self.shader_storage_image_write_without_format == FALSE
+        {
+            missing.push("shaderStorageImageWriteWithoutFormat");
+        }
+        if required.shader_uniform_buffer_array_dynamic_indexing != FALSE
+            && self.shader_uniform_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderUniformBufferArrayDynamicIndexing");
+        }
+        if required.shader_sampled_image_array_dynamic_indexing != FALSE
+            && self.shader_sampled_image_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderSampledImageArrayDynamicIndexing");
+        }
+        if required.shader_storage_buffer_array_dynamic_indexing != FALSE
+            && self.shader_storage_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderStorageBufferArrayDynamicIndexing");
+        }
+        if required.shader_storage_image_array_dynamic_indexing != FALSE
+            && self.shader_storage_image_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderStorageImageArrayDynamicIndexing");
+        }
+        if required.shader_clip_distance != FALSE && self.shader_clip_distance == FALSE {
+            missing.push("shaderClipDistance");
+        }
+        if required.shader_cull_distance != FALSE && self.shader_cull_distance == FALSE {
+            missing.push("shaderCullDistance");
+        }
+        if required.shader_float64 != FALSE && self.shader_float64 == FALSE {
+            missing.push("shaderFloat64");
+        }
+        if required.shader_int64 != FALSE && self.shader_int64 == FALSE {
+            missing.push("shaderInt64");
+        }
+        if required.shader_int16 != FALSE && self.shader_int16 == FALSE {
+            missing.push("shaderInt16");
+        }
+        if required.shader_resource_residency != FALSE && self.shader_resource_residency == FALSE {
+            missing.push("shaderResourceResidency");
+        }
+        if required.shader_resource_min_lod != FALSE && self.shader_resource_min_lod == FALSE {
+            missing.push("shaderResourceMinLod");
+        }
+        if required.sparse_binding != FALSE && self.sparse_binding == FALSE {
+            missing.push("sparseBinding");
+        }
+        if required.sparse_residency_buffer != FALSE && self.sparse_residency_buffer == FALSE {
+            missing.push("sparseResidencyBuffer");
+        }
+        if required.sparse_residency_image2_d != FALSE && self.sparse_residency_image2_d == FALSE {
+            missing.push("sparseResidencyImage2D");
+        }
+        if required.sparse_residency_image3_d != FALSE && self.sparse_residency_image3_d == FALSE {
+            missing.push("sparseResidencyImage3D");
+        }
+        if required.sparse_residency2_samples != FALSE && self.sparse_residency2_samples == FALSE {
+            missing.push("sparseResidency2Samples");
+        }
+        if required.sparse_residency4_samples != FALSE && self.sparse_residency4_samples == FALSE {
+            missing.push("sparseResidency4Samples");
+        }
+        if required.sparse_residency8_samples != FALSE && self.sparse_residency8_samples == FALSE {
+            missing.push("sparseResidency8Samples");
+        }
+        if required.sparse_residency16_samples != FALSE && self.sparse_residency16_samples == FALSE
+        {
+            missing.push("sparseResidency16Samples");
+        }
+        if required.sparse_residency_aliased != FALSE && self.sparse_residency_aliased == FALSE {
+            missing.push("sparseResidencyAliased");
+        }
+        if required.variable_multisample_rate != FALSE && self.variable_multisample_rate == FALSE {
+            missing.push("variableMultisampleRate");
+        }
+        if required.inherited_queries != FALSE && self.inherited_queries == FALSE {
+            missing.push("inheritedQueries");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSparseProperties.html>"]
@@ -8955,6 +9740,16 @@ impl<'a> PhysicalDeviceSparsePropertiesBuilder<'a> {
         self.inner
     }
 }
+impl PhysicalDeviceSparseProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceLimits.html>"]
@@ -9025,7 +9820,7 @@ pub struct PhysicalDeviceLimits {
     pub max_viewport_dimensions: [u32; 2],
     pub viewport_bounds_range: [f32; 2],
     pub viewport_sub_pixel_bits: u32,
-    pub min_memory_map_alignment: usize,
+    pub min_memory_map_alignment: size_t,
     pub min_texel_buffer_offset_alignment: DeviceSize,
     pub min_uniform_buffer_offset_alignment: DeviceSize,
     pub min_storage_buffer_offset_alignment: DeviceSize,
@@ -9135,7 +9930,7 @@ impl ::std::default::Default for PhysicalDeviceLimits {
             max_viewport_dimensions: unsafe { ::std::mem::zeroed() },
             viewport_bounds_range: unsafe { ::std::mem::zeroed() },
             viewport_sub_pixel_bits: u32::default(),
-            min_memory_map_alignment: usize::default(),
+            min_memory_map_alignment: size_t::default(),
             min_texel_buffer_offset_alignment: DeviceSize::default(),
             min_uniform_buffer_offset_alignment: DeviceSize::default(),
             min_storage_buffer_offset_alignment: DeviceSize::default(),
@@ -9680,7 +10475,7 @@ impl<'a> PhysicalDeviceLimitsBuilder<'a> {
     }
     pub fn min_memory_map_alignment(
         mut self,
-        min_memory_map_alignment: usize,
+        min_memory_map_alignment: size_t,
     ) -> PhysicalDeviceLimitsBuilder<'a> {
         self.inner.min_memory_map_alignment = min_memory_map_alignment;
         self
@@ -9955,6 +10750,16 @@ impl<'a> PhysicalDeviceLimitsBuilder<'a> {
         self.inner
     }
 }
+impl PhysicalDeviceLimits {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreCreateInfo.html>"]
@@ -10007,6 +10812,8 @@ impl<'a> SemaphoreCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSemaphoreCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -10016,6 +10823,8 @@ impl<'a> SemaphoreCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSemaphoreCreateInfo", &mut self.inner);
         }
         self
     }
@@ -10026,6 +10835,9 @@ impl<'a> SemaphoreCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueryPoolCreateInfo.html>"]
@@ -10099,6 +10911,8 @@ impl<'a> QueryPoolCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsQueryPoolCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -10108,6 +10922,8 @@ impl<'a> QueryPoolCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkQueryPoolCreateInfo", &mut self.inner);
         }
         self
     }
@@ -10118,6 +10934,9 @@ impl<'a> QueryPoolCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for QueryPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::QUERY_POOL_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFramebufferCreateInfo.html>"]
@@ -10203,6 +11022,8 @@ impl<'a> FramebufferCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFramebufferCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -10212,6 +11033,8 @@ impl<'a> FramebufferCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFramebufferCreateInfo", &mut self.inner);
         }
         self
     }
@@ -10222,6 +11045,9 @@ impl<'a> FramebufferCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FramebufferCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FRAMEBUFFER_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDrawIndirectCommand.html>"]
@@ -10482,12 +11308,16 @@ impl<'a> SubmitInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSubmitInfo>(mut self, next: &'a mut T) -> SubmitInfoBuilder<'a> {
         unsafe {
             let next_ptr = next as *mut T as *mut BaseOutStructure;
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSubmitInfo", &mut self.inner);
         }
         self
     }
@@ -10498,6 +11328,9 @@ impl<'a> SubmitInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubmitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBMIT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPropertiesKHR.html>"]
@@ -10601,6 +11434,16 @@ impl<'a> DisplayPropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+impl DisplayPropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPlanePropertiesKHR.html>"]
@@ -10654,6 +11497,16 @@ impl<'a> DisplayPlanePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+impl DisplayPlanePropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayModeParametersKHR.html>"]
@@ -10757,6 +11610,16 @@ impl<'a> DisplayModePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+impl DisplayModePropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayModeCreateInfoKHR.html>"]
@@ -10821,6 +11684,8 @@ impl<'a> DisplayModeCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayModeCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -10830,6 +11695,8 @@ impl<'a> DisplayModeCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayModeCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -10840,6 +11707,9 @@ impl<'a> DisplayModeCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayModeCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_MODE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPlaneCapabilitiesKHR.html>"]
@@ -10949,6 +11819,16 @@ impl<'a> DisplayPlaneCapabilitiesKHRBuilder<'a> {
         self.inner
     }
 }
+impl DisplayPlaneCapabilitiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplaySurfaceCreateInfoKHR.html>"]
@@ -11061,6 +11941,8 @@ impl<'a> DisplaySurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplaySurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11070,6 +11952,8 @@ impl<'a> DisplaySurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplaySurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11080,6 +11964,9 @@ impl<'a> DisplaySurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplaySurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPresentInfoKHR.html>"]
@@ -11147,6 +12034,9 @@ impl<'a> DisplayPresentInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayPresentInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_PRESENT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceCapabilitiesKHR.html>"]
@@ -11255,6 +12145,16 @@ impl<'a> SurfaceCapabilitiesKHRBuilder<'a> {
         self.inner
     }
 }
+impl SurfaceCapabilitiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAndroidSurfaceCreateInfoKHR.html>"]
@@ -11316,6 +12216,8 @@ impl<'a> AndroidSurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAndroidSurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11325,6 +12227,8 @@ impl<'a> AndroidSurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAndroidSurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11335,6 +12239,9 @@ impl<'a> AndroidSurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AndroidSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ANDROID_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkViSurfaceCreateInfoNN.html>"]
@@ -11393,6 +12300,8 @@ impl<'a> ViSurfaceCreateInfoNNBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsViSurfaceCreateInfoNN>(
         mut self,
         next: &'a mut T,
@@ -11402,6 +12311,8 @@ impl<'a> ViSurfaceCreateInfoNNBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkViSurfaceCreateInfoNN", &mut self.inner);
         }
         self
     }
@@ -11412,6 +12323,9 @@ impl<'a> ViSurfaceCreateInfoNNBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ViSurfaceCreateInfoNN {
+    const STRUCTURE_TYPE: StructureType = StructureType::VI_SURFACE_CREATE_INFO_NN;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWaylandSurfaceCreateInfoKHR.html>"]
@@ -11479,6 +12393,8 @@ impl<'a> WaylandSurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsWaylandSurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11488,6 +12404,8 @@ impl<'a> WaylandSurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkWaylandSurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11498,6 +12416,9 @@ impl<'a> WaylandSurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for WaylandSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWin32SurfaceCreateInfoKHR.html>"]
@@ -11565,6 +12486,8 @@ impl<'a> Win32SurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsWin32SurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11574,6 +12497,8 @@ impl<'a> Win32SurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkWin32SurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11584,6 +12509,9 @@ impl<'a> Win32SurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for Win32SurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::WIN32_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkXlibSurfaceCreateInfoKHR.html>"]
@@ -11651,6 +12579,8 @@ impl<'a> XlibSurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsXlibSurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11660,6 +12590,8 @@ impl<'a> XlibSurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkXlibSurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11670,6 +12602,9 @@ impl<'a> XlibSurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for XlibSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::XLIB_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkXcbSurfaceCreateInfoKHR.html>"]
@@ -11737,6 +12672,8 @@ impl<'a> XcbSurfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsXcbSurfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -11746,6 +12683,8 @@ impl<'a> XcbSurfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkXcbSurfaceCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -11756,6 +12695,9 @@ impl<'a> XcbSurfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for XcbSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::XCB_SURFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDirectFBSurfaceCreateInfoEXT.html>"]
@@ -11826,6 +12768,8 @@ impl<'a> DirectFBSurfaceCreateInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDirectFBSurfaceCreateInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -11835,6 +12779,8 @@ impl<'a> DirectFBSurfaceCreateInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDirectFBSurfaceCreateInfoEXT", &mut self.inner);
         }
         self
     }
@@ -11845,6 +12791,9 @@ impl<'a> DirectFBSurfaceCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DirectFBSurfaceCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DIRECTFB_SURFACE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImagePipeSurfaceCreateInfoFUCHSIA.html>"]
@@ -11909,6 +12858,8 @@ impl<'a> ImagePipeSurfaceCreateInfoFUCHSIABuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImagePipeSurfaceCreateInfoFUCHSIA>(
         mut self,
         next: &'a mut T,
@@ -11918,6 +12869,11 @@ impl<'a> ImagePipeSurfaceCreateInfoFUCHSIABuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkImagePipeSurfaceCreateInfoFUCHSIA",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -11928,6 +12884,9 @@ impl<'a> ImagePipeSurfaceCreateInfoFUCHSIABuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImagePipeSurfaceCreateInfoFUCHSIA {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkStreamDescriptorSurfaceCreateInfoGGP.html>"]
@@ -11992,6 +12951,8 @@ impl<'a> StreamDescriptorSurfaceCreateInfoGGPBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsStreamDescriptorSurfaceCreateInfoGGP>(
         mut self,
         next: &'a mut T,
@@ -12001,6 +12962,11 @@ impl<'a> StreamDescriptorSurfaceCreateInfoGGPBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkStreamDescriptorSurfaceCreateInfoGGP",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -12011,6 +12977,9 @@ impl<'a> StreamDescriptorSurfaceCreateInfoGGPBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for StreamDescriptorSurfaceCreateInfoGGP {
+    const STRUCTURE_TYPE: StructureType = StructureType::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceFormatKHR.html>"]
@@ -12058,6 +13027,16 @@ impl<'a> SurfaceFormatKHRBuilder<'a> {
         self.inner
     }
 }
+impl SurfaceFormatKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSwapchainCreateInfoKHR.html>"]
@@ -12224,6 +13203,8 @@ impl<'a> SwapchainCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSwapchainCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -12233,6 +13214,8 @@ impl<'a> SwapchainCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSwapchainCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -12243,6 +13226,9 @@ impl<'a> SwapchainCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SwapchainCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SWAPCHAIN_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPresentInfoKHR.html>"]
@@ -12324,6 +13310,8 @@ impl<'a> PresentInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPresentInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -12333,6 +13321,8 @@ impl<'a> PresentInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPresentInfoKHR", &mut self.inner);
         }
         self
     }
@@ -12343,6 +13333,9 @@ impl<'a> PresentInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PresentInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PRESENT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugReportCallbackCreateInfoEXT.html>"]
@@ -12430,6 +13423,9 @@ impl<'a> DebugReportCallbackCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugReportCallbackCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkValidationFlagsEXT.html>"]
@@ -12491,6 +13487,9 @@ impl<'a> ValidationFlagsEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ValidationFlagsEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::VALIDATION_FLAGS_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkValidationFeaturesEXT.html>"]
@@ -12564,6 +13563,9 @@ impl<'a> ValidationFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ValidationFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::VALIDATION_FEATURES_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationStateRasterizationOrderAMD.html>"]
@@ -12628,6 +13630,10 @@ impl<'a> PipelineRasterizationStateRasterizationOrderAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationStateRasterizationOrderAMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugMarkerObjectNameInfoEXT.html>"]
@@ -12698,6 +13704,8 @@ impl<'a> DebugMarkerObjectNameInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugMarkerObjectNameInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -12707,6 +13715,8 @@ impl<'a> DebugMarkerObjectNameInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugMarkerObjectNameInfoEXT", &mut self.inner);
         }
         self
     }
@@ -12717,6 +13727,9 @@ impl<'a> DebugMarkerObjectNameInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugMarkerObjectNameInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugMarkerObjectTagInfoEXT.html>"]
@@ -12726,7 +13739,7 @@ pub struct DebugMarkerObjectTagInfoEXT {
     pub object_type: DebugReportObjectTypeEXT,
     pub object: u64,
     pub tag_name: u64,
-    pub tag_size: usize,
+    pub tag_size: size_t,
     pub p_tag: *const c_void,
 }
 impl ::std::default::Default for DebugMarkerObjectTagInfoEXT {
@@ -12737,7 +13750,7 @@ impl ::std::default::Default for DebugMarkerObjectTagInfoEXT {
             object_type: DebugReportObjectTypeEXT::default(),
             object: u64::default(),
             tag_name: u64::default(),
-            tag_size: usize::default(),
+            tag_size: size_t::default(),
             p_tag: ::std::ptr::null(),
         }
     }
@@ -12793,6 +13806,8 @@ impl<'a> DebugMarkerObjectTagInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugMarkerObjectTagInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -12802,6 +13817,8 @@ impl<'a> DebugMarkerObjectTagInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugMarkerObjectTagInfoEXT", &mut self.inner);
         }
         self
     }
@@ -12812,6 +13829,9 @@ impl<'a> DebugMarkerObjectTagInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugMarkerObjectTagInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugMarkerMarkerInfoEXT.html>"]
@@ -12873,6 +13893,8 @@ impl<'a> DebugMarkerMarkerInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugMarkerMarkerInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -12882,6 +13904,8 @@ impl<'a> DebugMarkerMarkerInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugMarkerMarkerInfoEXT", &mut self.inner);
         }
         self
     }
@@ -12892,6 +13916,9 @@ impl<'a> DebugMarkerMarkerInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugMarkerMarkerInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_MARKER_MARKER_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDedicatedAllocationImageCreateInfoNV.html>"]
@@ -12950,6 +13977,9 @@ impl<'a> DedicatedAllocationImageCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DedicatedAllocationImageCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDedicatedAllocationBufferCreateInfoNV.html>"]
@@ -13008,6 +14038,9 @@ impl<'a> DedicatedAllocationBufferCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DedicatedAllocationBufferCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDedicatedAllocationMemoryAllocateInfoNV.html>"]
@@ -13069,6 +14102,10 @@ impl<'a> DedicatedAllocationMemoryAllocateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DedicatedAllocationMemoryAllocateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalImageFormatPropertiesNV.html>"]
@@ -13196,6 +14233,9 @@ impl<'a> ExternalMemoryImageCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalMemoryImageCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportMemoryAllocateInfoNV.html>"]
@@ -13254,6 +14294,9 @@ impl<'a> ExportMemoryAllocateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportMemoryAllocateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportMemoryWin32HandleInfoNV.html>"]
@@ -13318,6 +14361,9 @@ impl<'a> ImportMemoryWin32HandleInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportMemoryWin32HandleInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportMemoryWin32HandleInfoNV.html>"]
@@ -13382,6 +14428,9 @@ impl<'a> ExportMemoryWin32HandleInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportMemoryWin32HandleInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWin32KeyedMutexAcquireReleaseInfoNV.html>"]
@@ -13485,6 +14534,9 @@ impl<'a> Win32KeyedMutexAcquireReleaseInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for Win32KeyedMutexAcquireReleaseInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV.html>"]
@@ -13543,6 +14595,21 @@ impl<'a> PhysicalDeviceDeviceGeneratedCommandsFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDeviceGeneratedCommandsFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV;
+}
+impl PhysicalDeviceDeviceGeneratedCommandsFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.device_generated_commands != FALSE && self.device_generated_commands == FALSE {
+            missing.push("deviceGeneratedCommands");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDevicePrivateDataCreateInfoEXT.html>"]
@@ -13601,6 +14668,9 @@ impl<'a> DevicePrivateDataCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DevicePrivateDataCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_PRIVATE_DATA_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPrivateDataSlotCreateInfoEXT.html>"]
@@ -13656,6 +14726,8 @@ impl<'a> PrivateDataSlotCreateInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPrivateDataSlotCreateInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -13665,6 +14737,8 @@ impl<'a> PrivateDataSlotCreateInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPrivateDataSlotCreateInfoEXT", &mut self.inner);
         }
         self
     }
@@ -13675,6 +14749,9 @@ impl<'a> PrivateDataSlotCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PrivateDataSlotCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PRIVATE_DATA_SLOT_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePrivateDataFeaturesEXT.html>"]
@@ -13733,6 +14810,20 @@ impl<'a> PhysicalDevicePrivateDataFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePrivateDataFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT;
+}
+impl PhysicalDevicePrivateDataFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.private_data != FALSE && self.private_data == FALSE {
+            missing.push("privateData");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV.html>"]
@@ -13869,6 +14960,20 @@ impl<'a> PhysicalDeviceDeviceGeneratedCommandsPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDeviceGeneratedCommandsPropertiesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV;
+}
+impl PhysicalDeviceDeviceGeneratedCommandsPropertiesNV {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGraphicsShaderGroupCreateInfoNV.html>"]
@@ -13926,6 +15031,15 @@ impl<'a> GraphicsShaderGroupCreateInfoNVBuilder<'a> {
         self.inner.p_stages = stages.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn stages_from_builders(
+        mut self,
+        stages: &'a [PipelineShaderStageCreateInfoBuilder<'a>],
+    ) -> GraphicsShaderGroupCreateInfoNVBuilder<'a> {
+        self.inner.stage_count = stages.len() as _;
+        self.inner.p_stages = stages.as_ptr() as *const PipelineShaderStageCreateInfo;
+        self
+    }
     pub fn vertex_input_state(
         mut self,
         vertex_input_state: &'a PipelineVertexInputStateCreateInfo,
@@ -13945,6 +15059,8 @@ impl<'a> GraphicsShaderGroupCreateInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGraphicsShaderGroupCreateInfoNV>(
         mut self,
         next: &'a mut T,
@@ -13954,6 +15070,8 @@ impl<'a> GraphicsShaderGroupCreateInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGraphicsShaderGroupCreateInfoNV", &mut self.inner);
         }
         self
     }
@@ -13964,6 +15082,9 @@ impl<'a> GraphicsShaderGroupCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GraphicsShaderGroupCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGraphicsPipelineShaderGroupsCreateInfoNV.html>"]
@@ -14025,6 +15146,15 @@ impl<'a> GraphicsPipelineShaderGroupsCreateInfoNVBuilder<'a> {
         self.inner.p_groups = groups.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn groups_from_builders(
+        mut self,
+        groups: &'a [GraphicsShaderGroupCreateInfoNVBuilder<'a>],
+    ) -> GraphicsPipelineShaderGroupsCreateInfoNVBuilder<'a> {
+        self.inner.group_count = groups.len() as _;
+        self.inner.p_groups = groups.as_ptr() as *const GraphicsShaderGroupCreateInfoNV;
+        self
+    }
     pub fn pipelines(
         mut self,
         pipelines: &'a [Pipeline],
@@ -14040,6 +15170,10 @@ impl<'a> GraphicsPipelineShaderGroupsCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GraphicsPipelineShaderGroupsCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindShaderGroupIndirectCommandNV.html>"]
@@ -14436,6 +15570,8 @@ impl<'a> IndirectCommandsLayoutTokenNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsIndirectCommandsLayoutTokenNV>(
         mut self,
         next: &'a mut T,
@@ -14445,6 +15581,8 @@ impl<'a> IndirectCommandsLayoutTokenNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkIndirectCommandsLayoutTokenNV", &mut self.inner);
         }
         self
     }
@@ -14455,6 +15593,9 @@ impl<'a> IndirectCommandsLayoutTokenNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for IndirectCommandsLayoutTokenNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkIndirectCommandsLayoutCreateInfoNV.html>"]
@@ -14530,6 +15671,15 @@ impl<'a> IndirectCommandsLayoutCreateInfoNVBuilder<'a> {
         self.inner.p_tokens = tokens.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn tokens_from_builders(
+        mut self,
+        tokens: &'a [IndirectCommandsLayoutTokenNVBuilder<'a>],
+    ) -> IndirectCommandsLayoutCreateInfoNVBuilder<'a> {
+        self.inner.token_count = tokens.len() as _;
+        self.inner.p_tokens = tokens.as_ptr() as *const IndirectCommandsLayoutTokenNV;
+        self
+    }
     pub fn stream_strides(
         mut self,
         stream_strides: &'a [u32],
@@ -14543,6 +15693,8 @@ impl<'a> IndirectCommandsLayoutCreateInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsIndirectCommandsLayoutCreateInfoNV>(
         mut self,
         next: &'a mut T,
@@ -14552,6 +15704,11 @@ impl<'a> IndirectCommandsLayoutCreateInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkIndirectCommandsLayoutCreateInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -14562,6 +15719,9 @@ impl<'a> IndirectCommandsLayoutCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for IndirectCommandsLayoutCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGeneratedCommandsInfoNV.html>"]
@@ -14713,6 +15873,8 @@ impl<'a> GeneratedCommandsInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGeneratedCommandsInfoNV>(
         mut self,
         next: &'a mut T,
@@ -14722,6 +15884,8 @@ impl<'a> GeneratedCommandsInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGeneratedCommandsInfoNV", &mut self.inner);
         }
         self
     }
@@ -14732,6 +15896,9 @@ impl<'a> GeneratedCommandsInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GeneratedCommandsInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::GENERATED_COMMANDS_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGeneratedCommandsMemoryRequirementsInfoNV.html>"]
@@ -14814,6 +15981,8 @@ impl<'a> GeneratedCommandsMemoryRequirementsInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGeneratedCommandsMemoryRequirementsInfoNV>(
         mut self,
         next: &'a mut T,
@@ -14823,6 +15992,11 @@ impl<'a> GeneratedCommandsMemoryRequirementsInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkGeneratedCommandsMemoryRequirementsInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -14833,6 +16007,10 @@ impl<'a> GeneratedCommandsMemoryRequirementsInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GeneratedCommandsMemoryRequirementsInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFeatures2.html>"]
@@ -14891,6 +16069,9 @@ impl<'a> PhysicalDeviceFeatures2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFeatures2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_FEATURES_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceProperties2.html>"]
@@ -14946,6 +16127,8 @@ impl<'a> PhysicalDeviceProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceProperties2>(
         mut self,
         next: &'a mut T,
@@ -14955,6 +16138,8 @@ impl<'a> PhysicalDeviceProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceProperties2", &mut self.inner);
         }
         self
     }
@@ -14965,6 +16150,26 @@ impl<'a> PhysicalDeviceProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_PROPERTIES_2;
+}
+impl PhysicalDeviceProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFormatProperties2.html>"]
@@ -15020,6 +16225,8 @@ impl<'a> FormatProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFormatProperties2>(
         mut self,
         next: &'a mut T,
@@ -15029,6 +16236,8 @@ impl<'a> FormatProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFormatProperties2", &mut self.inner);
         }
         self
     }
@@ -15039,6 +16248,26 @@ impl<'a> FormatProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FormatProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::FORMAT_PROPERTIES_2;
+}
+impl FormatProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::FORMAT_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::FORMAT_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageFormatProperties2.html>"]
@@ -15094,6 +16323,8 @@ impl<'a> ImageFormatProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageFormatProperties2>(
         mut self,
         next: &'a mut T,
@@ -15103,6 +16334,8 @@ impl<'a> ImageFormatProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageFormatProperties2", &mut self.inner);
         }
         self
     }
@@ -15113,6 +16346,26 @@ impl<'a> ImageFormatProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageFormatProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_FORMAT_PROPERTIES_2;
+}
+impl ImageFormatProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::IMAGE_FORMAT_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::IMAGE_FORMAT_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceImageFormatInfo2.html>"]
@@ -15189,6 +16442,8 @@ impl<'a> PhysicalDeviceImageFormatInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceImageFormatInfo2>(
         mut self,
         next: &'a mut T,
@@ -15198,6 +16453,8 @@ impl<'a> PhysicalDeviceImageFormatInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceImageFormatInfo2", &mut self.inner);
         }
         self
     }
@@ -15208,6 +16465,9 @@ impl<'a> PhysicalDeviceImageFormatInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceImageFormatInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueueFamilyProperties2.html>"]
@@ -15263,6 +16523,8 @@ impl<'a> QueueFamilyProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsQueueFamilyProperties2>(
         mut self,
         next: &'a mut T,
@@ -15272,6 +16534,8 @@ impl<'a> QueueFamilyProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkQueueFamilyProperties2", &mut self.inner);
         }
         self
     }
@@ -15282,6 +16546,26 @@ impl<'a> QueueFamilyProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for QueueFamilyProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::QUEUE_FAMILY_PROPERTIES_2;
+}
+impl QueueFamilyProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::QUEUE_FAMILY_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::QUEUE_FAMILY_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMemoryProperties2.html>"]
@@ -15337,6 +16621,8 @@ impl<'a> PhysicalDeviceMemoryProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceMemoryProperties2>(
         mut self,
         next: &'a mut T,
@@ -15346,6 +16632,8 @@ impl<'a> PhysicalDeviceMemoryProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceMemoryProperties2", &mut self.inner);
         }
         self
     }
@@ -15356,6 +16644,26 @@ impl<'a> PhysicalDeviceMemoryProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMemoryProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2;
+}
+impl PhysicalDeviceMemoryProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSparseImageFormatProperties2.html>"]
@@ -15411,6 +16719,8 @@ impl<'a> SparseImageFormatProperties2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSparseImageFormatProperties2>(
         mut self,
         next: &'a mut T,
@@ -15420,6 +16730,8 @@ impl<'a> SparseImageFormatProperties2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSparseImageFormatProperties2", &mut self.inner);
         }
         self
     }
@@ -15430,6 +16742,26 @@ impl<'a> SparseImageFormatProperties2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SparseImageFormatProperties2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2;
+}
+impl SparseImageFormatProperties2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSparseImageFormatInfo2.html>"]
@@ -15515,6 +16847,8 @@ impl<'a> PhysicalDeviceSparseImageFormatInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceSparseImageFormatInfo2>(
         mut self,
         next: &'a mut T,
@@ -15524,6 +16858,11 @@ impl<'a> PhysicalDeviceSparseImageFormatInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPhysicalDeviceSparseImageFormatInfo2",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -15534,6 +16873,9 @@ impl<'a> PhysicalDeviceSparseImageFormatInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSparseImageFormatInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePushDescriptorPropertiesKHR.html>"]
@@ -15595,6 +16937,20 @@ impl<'a> PhysicalDevicePushDescriptorPropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePushDescriptorPropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR;
+}
+impl PhysicalDevicePushDescriptorPropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkConformanceVersion.html>"]
@@ -15722,18 +17078,34 @@ impl<'a> PhysicalDeviceDriverPropertiesBuilder<'a> {
         self.inner.driver_id = driver_id;
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn driver_name(
         mut self,
-        driver_name: [c_char; MAX_DRIVER_NAME_SIZE],
+        driver_name: &::std::ffi::CStr,
     ) -> PhysicalDeviceDriverPropertiesBuilder<'a> {
-        self.inner.driver_name = driver_name;
+        let bytes = driver_name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.driver_name.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.driver_name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn driver_info(
         mut self,
-        driver_info: [c_char; MAX_DRIVER_INFO_SIZE],
+        driver_info: &::std::ffi::CStr,
     ) -> PhysicalDeviceDriverPropertiesBuilder<'a> {
-        self.inner.driver_info = driver_info;
+        let bytes = driver_info.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.driver_info.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.driver_info.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn conformance_version(
@@ -15750,6 +17122,60 @@ impl<'a> PhysicalDeviceDriverPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDriverProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES;
+}
+impl PhysicalDeviceDriverProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES;
+        }
+        value
+    }
+}
+impl PhysicalDeviceDriverProperties {
+    #[doc = " Returns `driver_name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn driver_name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.driver_name.as_ptr() as *const u8,
+                self.driver_name.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `driver_info` up to its nul terminator, an error if it doesn't have one."]
+    pub fn driver_info_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.driver_info.as_ptr() as *const u8,
+                self.driver_info.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPresentRegionsKHR.html>"]
@@ -15801,6 +17227,15 @@ impl<'a> PresentRegionsKHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [PresentRegionKHRBuilder<'a>],
+    ) -> PresentRegionsKHRBuilder<'a> {
+        self.inner.swapchain_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const PresentRegionKHR;
+        self
+    }
     #[doc = r" Calling build will **discard** all the lifetime information. Only call this if"]
     #[doc = r" necessary! Builders implement `Deref` targeting their corresponding Vulkan struct,"]
     #[doc = r" so references to builders can be passed directly to Vulkan functions."]
@@ -15808,6 +17243,9 @@ impl<'a> PresentRegionsKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PresentRegionsKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PRESENT_REGIONS_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPresentRegionKHR.html>"]
@@ -15979,6 +17417,25 @@ impl<'a> PhysicalDeviceVariablePointersFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVariablePointersFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES;
+}
+impl PhysicalDeviceVariablePointersFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.variable_pointers_storage_buffer != FALSE
+            && self.variable_pointers_storage_buffer == FALSE
+        {
+            missing.push("variablePointersStorageBuffer");
+        }
+        if required.variable_pointers != FALSE && self.variable_pointers == FALSE {
+            missing.push("variablePointers");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalMemoryProperties.html>"]
@@ -16040,6 +17497,16 @@ impl<'a> ExternalMemoryPropertiesBuilder<'a> {
         self.inner
     }
 }
+impl ExternalMemoryProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExternalImageFormatInfo.html>"]
@@ -16101,6 +17568,9 @@ impl<'a> PhysicalDeviceExternalImageFormatInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExternalImageFormatInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalImageFormatProperties.html>"]
@@ -16159,6 +17629,26 @@ impl<'a> ExternalImageFormatPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalImageFormatProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES;
+}
+impl ExternalImageFormatProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExternalBufferInfo.html>"]
@@ -16229,6 +17719,8 @@ impl<'a> PhysicalDeviceExternalBufferInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceExternalBufferInfo>(
         mut self,
         next: &'a mut T,
@@ -16238,6 +17730,11 @@ impl<'a> PhysicalDeviceExternalBufferInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPhysicalDeviceExternalBufferInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -16248,6 +17745,9 @@ impl<'a> PhysicalDeviceExternalBufferInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExternalBufferInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalBufferProperties.html>"]
@@ -16303,6 +17803,8 @@ impl<'a> ExternalBufferPropertiesBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsExternalBufferProperties>(
         mut self,
         next: &'a mut T,
@@ -16312,6 +17814,8 @@ impl<'a> ExternalBufferPropertiesBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkExternalBufferProperties", &mut self.inner);
         }
         self
     }
@@ -16322,6 +17826,26 @@ impl<'a> ExternalBufferPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalBufferProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_BUFFER_PROPERTIES;
+}
+impl ExternalBufferProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::EXTERNAL_BUFFER_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::EXTERNAL_BUFFER_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceIDProperties.html>"]
@@ -16416,6 +17940,26 @@ impl<'a> PhysicalDeviceIDPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceIDProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_ID_PROPERTIES;
+}
+impl PhysicalDeviceIDProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_ID_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_ID_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalMemoryImageCreateInfo.html>"]
@@ -16474,6 +18018,9 @@ impl<'a> ExternalMemoryImageCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalMemoryImageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalMemoryBufferCreateInfo.html>"]
@@ -16532,6 +18079,9 @@ impl<'a> ExternalMemoryBufferCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalMemoryBufferCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportMemoryAllocateInfo.html>"]
@@ -16590,6 +18140,9 @@ impl<'a> ExportMemoryAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportMemoryAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_MEMORY_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportMemoryWin32HandleInfoKHR.html>"]
@@ -16660,6 +18213,9 @@ impl<'a> ImportMemoryWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportMemoryWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportMemoryWin32HandleInfoKHR.html>"]
@@ -16730,6 +18286,9 @@ impl<'a> ExportMemoryWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportMemoryWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryWin32HandlePropertiesKHR.html>"]
@@ -16785,6 +18344,8 @@ impl<'a> MemoryWin32HandlePropertiesKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryWin32HandlePropertiesKHR>(
         mut self,
         next: &'a mut T,
@@ -16794,6 +18355,8 @@ impl<'a> MemoryWin32HandlePropertiesKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryWin32HandlePropertiesKHR", &mut self.inner);
         }
         self
     }
@@ -16804,6 +18367,9 @@ impl<'a> MemoryWin32HandlePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryWin32HandlePropertiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryGetWin32HandleInfoKHR.html>"]
@@ -16865,6 +18431,8 @@ impl<'a> MemoryGetWin32HandleInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryGetWin32HandleInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -16874,6 +18442,8 @@ impl<'a> MemoryGetWin32HandleInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryGetWin32HandleInfoKHR", &mut self.inner);
         }
         self
     }
@@ -16884,6 +18454,9 @@ impl<'a> MemoryGetWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryGetWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportMemoryFdInfoKHR.html>"]
@@ -16948,6 +18521,9 @@ impl<'a> ImportMemoryFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportMemoryFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_MEMORY_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryFdPropertiesKHR.html>"]
@@ -17000,6 +18576,8 @@ impl<'a> MemoryFdPropertiesKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryFdPropertiesKHR>(
         mut self,
         next: &'a mut T,
@@ -17009,6 +18587,8 @@ impl<'a> MemoryFdPropertiesKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryFdPropertiesKHR", &mut self.inner);
         }
         self
     }
@@ -17019,6 +18599,9 @@ impl<'a> MemoryFdPropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryFdPropertiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_FD_PROPERTIES_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryGetFdInfoKHR.html>"]
@@ -17080,6 +18663,8 @@ impl<'a> MemoryGetFdInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryGetFdInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -17089,6 +18674,8 @@ impl<'a> MemoryGetFdInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryGetFdInfoKHR", &mut self.inner);
         }
         self
     }
@@ -17099,6 +18686,9 @@ impl<'a> MemoryGetFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryGetFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_GET_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWin32KeyedMutexAcquireReleaseInfoKHR.html>"]
@@ -17202,6 +18792,9 @@ impl<'a> Win32KeyedMutexAcquireReleaseInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for Win32KeyedMutexAcquireReleaseInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExternalSemaphoreInfo.html>"]
@@ -17257,6 +18850,8 @@ impl<'a> PhysicalDeviceExternalSemaphoreInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceExternalSemaphoreInfo>(
         mut self,
         next: &'a mut T,
@@ -17266,6 +18861,11 @@ impl<'a> PhysicalDeviceExternalSemaphoreInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPhysicalDeviceExternalSemaphoreInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -17276,6 +18876,9 @@ impl<'a> PhysicalDeviceExternalSemaphoreInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExternalSemaphoreInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalSemaphoreProperties.html>"]
@@ -17349,6 +18952,8 @@ impl<'a> ExternalSemaphorePropertiesBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsExternalSemaphoreProperties>(
         mut self,
         next: &'a mut T,
@@ -17358,6 +18963,8 @@ impl<'a> ExternalSemaphorePropertiesBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkExternalSemaphoreProperties", &mut self.inner);
         }
         self
     }
@@ -17368,6 +18975,26 @@ impl<'a> ExternalSemaphorePropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalSemaphoreProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_SEMAPHORE_PROPERTIES;
+}
+impl ExternalSemaphoreProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::EXTERNAL_SEMAPHORE_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::EXTERNAL_SEMAPHORE_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportSemaphoreCreateInfo.html>"]
@@ -17426,6 +19053,9 @@ impl<'a> ExportSemaphoreCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportSemaphoreCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_SEMAPHORE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportSemaphoreWin32HandleInfoKHR.html>"]
@@ -17511,6 +19141,8 @@ impl<'a> ImportSemaphoreWin32HandleInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImportSemaphoreWin32HandleInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -17520,6 +19152,11 @@ impl<'a> ImportSemaphoreWin32HandleInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkImportSemaphoreWin32HandleInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -17530,6 +19167,9 @@ impl<'a> ImportSemaphoreWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportSemaphoreWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportSemaphoreWin32HandleInfoKHR.html>"]
@@ -17600,6 +19240,9 @@ impl<'a> ExportSemaphoreWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportSemaphoreWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkD3D12FenceSubmitInfoKHR.html>"]
@@ -17673,6 +19316,9 @@ impl<'a> D3D12FenceSubmitInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for D3D12FenceSubmitInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::D3D12_FENCE_SUBMIT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreGetWin32HandleInfoKHR.html>"]
@@ -17734,6 +19380,8 @@ impl<'a> SemaphoreGetWin32HandleInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSemaphoreGetWin32HandleInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -17743,6 +19391,8 @@ impl<'a> SemaphoreGetWin32HandleInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSemaphoreGetWin32HandleInfoKHR", &mut self.inner);
         }
         self
     }
@@ -17753,6 +19403,9 @@ impl<'a> SemaphoreGetWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreGetWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportSemaphoreFdInfoKHR.html>"]
@@ -17826,6 +19479,8 @@ impl<'a> ImportSemaphoreFdInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImportSemaphoreFdInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -17835,6 +19490,8 @@ impl<'a> ImportSemaphoreFdInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImportSemaphoreFdInfoKHR", &mut self.inner);
         }
         self
     }
@@ -17845,6 +19502,9 @@ impl<'a> ImportSemaphoreFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportSemaphoreFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreGetFdInfoKHR.html>"]
@@ -17906,6 +19566,8 @@ impl<'a> SemaphoreGetFdInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSemaphoreGetFdInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -17915,6 +19577,8 @@ impl<'a> SemaphoreGetFdInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSemaphoreGetFdInfoKHR", &mut self.inner);
         }
         self
     }
@@ -17925,6 +19589,9 @@ impl<'a> SemaphoreGetFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreGetFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_GET_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExternalFenceInfo.html>"]
@@ -17980,6 +19647,8 @@ impl<'a> PhysicalDeviceExternalFenceInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceExternalFenceInfo>(
         mut self,
         next: &'a mut T,
@@ -17989,6 +19658,8 @@ impl<'a> PhysicalDeviceExternalFenceInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceExternalFenceInfo", &mut self.inner);
         }
         self
     }
@@ -17999,6 +19670,9 @@ impl<'a> PhysicalDeviceExternalFenceInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExternalFenceInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalFenceProperties.html>"]
@@ -18072,6 +19746,8 @@ impl<'a> ExternalFencePropertiesBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsExternalFenceProperties>(
         mut self,
         next: &'a mut T,
@@ -18081,6 +19757,8 @@ impl<'a> ExternalFencePropertiesBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkExternalFenceProperties", &mut self.inner);
         }
         self
     }
@@ -18091,6 +19769,26 @@ impl<'a> ExternalFencePropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalFenceProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_FENCE_PROPERTIES;
+}
+impl ExternalFenceProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::EXTERNAL_FENCE_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::EXTERNAL_FENCE_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportFenceCreateInfo.html>"]
@@ -18149,6 +19847,9 @@ impl<'a> ExportFenceCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportFenceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_FENCE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportFenceWin32HandleInfoKHR.html>"]
@@ -18228,6 +19929,8 @@ impl<'a> ImportFenceWin32HandleInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImportFenceWin32HandleInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -18237,6 +19940,8 @@ impl<'a> ImportFenceWin32HandleInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImportFenceWin32HandleInfoKHR", &mut self.inner);
         }
         self
     }
@@ -18247,6 +19952,9 @@ impl<'a> ImportFenceWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportFenceWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExportFenceWin32HandleInfoKHR.html>"]
@@ -18317,6 +20025,9 @@ impl<'a> ExportFenceWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExportFenceWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFenceGetWin32HandleInfoKHR.html>"]
@@ -18378,6 +20089,8 @@ impl<'a> FenceGetWin32HandleInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFenceGetWin32HandleInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -18387,6 +20100,8 @@ impl<'a> FenceGetWin32HandleInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFenceGetWin32HandleInfoKHR", &mut self.inner);
         }
         self
     }
@@ -18397,6 +20112,9 @@ impl<'a> FenceGetWin32HandleInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FenceGetWin32HandleInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportFenceFdInfoKHR.html>"]
@@ -18470,6 +20188,8 @@ impl<'a> ImportFenceFdInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImportFenceFdInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -18479,6 +20199,8 @@ impl<'a> ImportFenceFdInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImportFenceFdInfoKHR", &mut self.inner);
         }
         self
     }
@@ -18489,6 +20211,9 @@ impl<'a> ImportFenceFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportFenceFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_FENCE_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFenceGetFdInfoKHR.html>"]
@@ -18550,6 +20275,8 @@ impl<'a> FenceGetFdInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFenceGetFdInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -18559,6 +20286,8 @@ impl<'a> FenceGetFdInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFenceGetFdInfoKHR", &mut self.inner);
         }
         self
     }
@@ -18569,6 +20298,9 @@ impl<'a> FenceGetFdInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FenceGetFdInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::FENCE_GET_FD_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMultiviewFeatures.html>"]
@@ -18642,6 +20374,28 @@ impl<'a> PhysicalDeviceMultiviewFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMultiviewFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES;
+}
+impl PhysicalDeviceMultiviewFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.multiview != FALSE && self.multiview == FALSE {
+            missing.push("multiview");
+        }
+        if required.multiview_geometry_shader != FALSE && self.multiview_geometry_shader == FALSE {
+            missing.push("multiviewGeometryShader");
+        }
+        if required.multiview_tessellation_shader != FALSE
+            && self.multiview_tessellation_shader == FALSE
+        {
+            missing.push("multiviewTessellationShader");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMultiviewProperties.html>"]
@@ -18709,6 +20463,26 @@ impl<'a> PhysicalDeviceMultiviewPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMultiviewProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES;
+}
+impl PhysicalDeviceMultiviewProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassMultiviewCreateInfo.html>"]
@@ -18791,6 +20565,9 @@ impl<'a> RenderPassMultiviewCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassMultiviewCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceCapabilities2EXT.html>"]
@@ -18930,6 +20707,8 @@ impl<'a> SurfaceCapabilities2EXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSurfaceCapabilities2EXT>(
         mut self,
         next: &'a mut T,
@@ -18939,6 +20718,8 @@ impl<'a> SurfaceCapabilities2EXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSurfaceCapabilities2EXT", &mut self.inner);
         }
         self
     }
@@ -18949,6 +20730,26 @@ impl<'a> SurfaceCapabilities2EXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceCapabilities2EXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::SURFACE_CAPABILITIES_2_EXT;
+}
+impl SurfaceCapabilities2EXT {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::SURFACE_CAPABILITIES_2_EXT;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::SURFACE_CAPABILITIES_2_EXT;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPowerInfoEXT.html>"]
@@ -19004,6 +20805,8 @@ impl<'a> DisplayPowerInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayPowerInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -19013,6 +20816,8 @@ impl<'a> DisplayPowerInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayPowerInfoEXT", &mut self.inner);
         }
         self
     }
@@ -19023,6 +20828,9 @@ impl<'a> DisplayPowerInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayPowerInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_POWER_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceEventInfoEXT.html>"]
@@ -19078,6 +20886,8 @@ impl<'a> DeviceEventInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceEventInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -19087,6 +20897,8 @@ impl<'a> DeviceEventInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDeviceEventInfoEXT", &mut self.inner);
         }
         self
     }
@@ -19097,6 +20909,9 @@ impl<'a> DeviceEventInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceEventInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_EVENT_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayEventInfoEXT.html>"]
@@ -19152,6 +20967,8 @@ impl<'a> DisplayEventInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayEventInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -19161,6 +20978,8 @@ impl<'a> DisplayEventInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayEventInfoEXT", &mut self.inner);
         }
         self
     }
@@ -19171,6 +20990,9 @@ impl<'a> DisplayEventInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayEventInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_EVENT_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSwapchainCounterCreateInfoEXT.html>"]
@@ -19229,6 +21051,9 @@ impl<'a> SwapchainCounterCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SwapchainCounterCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceGroupProperties.html>"]
@@ -19302,6 +21127,8 @@ impl<'a> PhysicalDeviceGroupPropertiesBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceGroupProperties>(
         mut self,
         next: &'a mut T,
@@ -19311,6 +21138,8 @@ impl<'a> PhysicalDeviceGroupPropertiesBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceGroupProperties", &mut self.inner);
         }
         self
     }
@@ -19321,6 +21150,26 @@ impl<'a> PhysicalDeviceGroupPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceGroupProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES;
+}
+impl PhysicalDeviceGroupProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryAllocateFlagsInfo.html>"]
@@ -19382,6 +21231,9 @@ impl<'a> MemoryAllocateFlagsInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryAllocateFlagsInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_ALLOCATE_FLAGS_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindBufferMemoryInfo.html>"]
@@ -19446,6 +21298,8 @@ impl<'a> BindBufferMemoryInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBindBufferMemoryInfo>(
         mut self,
         next: &'a mut T,
@@ -19455,6 +21309,8 @@ impl<'a> BindBufferMemoryInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBindBufferMemoryInfo", &mut self.inner);
         }
         self
     }
@@ -19465,6 +21321,9 @@ impl<'a> BindBufferMemoryInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindBufferMemoryInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_BUFFER_MEMORY_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindBufferMemoryDeviceGroupInfo.html>"]
@@ -19526,6 +21385,9 @@ impl<'a> BindBufferMemoryDeviceGroupInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindBufferMemoryDeviceGroupInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindImageMemoryInfo.html>"]
@@ -19590,6 +21452,8 @@ impl<'a> BindImageMemoryInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBindImageMemoryInfo>(
         mut self,
         next: &'a mut T,
@@ -19599,6 +21463,8 @@ impl<'a> BindImageMemoryInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBindImageMemoryInfo", &mut self.inner);
         }
         self
     }
@@ -19609,6 +21475,9 @@ impl<'a> BindImageMemoryInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindImageMemoryInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_IMAGE_MEMORY_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindImageMemoryDeviceGroupInfo.html>"]
@@ -19682,6 +21551,9 @@ impl<'a> BindImageMemoryDeviceGroupInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindImageMemoryDeviceGroupInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupRenderPassBeginInfo.html>"]
@@ -19749,6 +21621,9 @@ impl<'a> DeviceGroupRenderPassBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupRenderPassBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupCommandBufferBeginInfo.html>"]
@@ -19804,6 +21679,9 @@ impl<'a> DeviceGroupCommandBufferBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupCommandBufferBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupSubmitInfo.html>"]
@@ -19889,6 +21767,9 @@ impl<'a> DeviceGroupSubmitInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupSubmitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_SUBMIT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupBindSparseInfo.html>"]
@@ -19956,6 +21837,9 @@ impl<'a> DeviceGroupBindSparseInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupBindSparseInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_BIND_SPARSE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupPresentCapabilitiesKHR.html>"]
@@ -20020,6 +21904,8 @@ impl<'a> DeviceGroupPresentCapabilitiesKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceGroupPresentCapabilitiesKHR>(
         mut self,
         next: &'a mut T,
@@ -20029,6 +21915,11 @@ impl<'a> DeviceGroupPresentCapabilitiesKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkDeviceGroupPresentCapabilitiesKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -20039,6 +21930,26 @@ impl<'a> DeviceGroupPresentCapabilitiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupPresentCapabilitiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR;
+}
+impl DeviceGroupPresentCapabilitiesKHR {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageSwapchainCreateInfoKHR.html>"]
@@ -20094,6 +22005,9 @@ impl<'a> ImageSwapchainCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageSwapchainCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindImageMemorySwapchainInfoKHR.html>"]
@@ -20158,6 +22072,9 @@ impl<'a> BindImageMemorySwapchainInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindImageMemorySwapchainInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAcquireNextImageInfoKHR.html>"]
@@ -20234,6 +22151,8 @@ impl<'a> AcquireNextImageInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAcquireNextImageInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -20243,6 +22162,8 @@ impl<'a> AcquireNextImageInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAcquireNextImageInfoKHR", &mut self.inner);
         }
         self
     }
@@ -20253,6 +22174,9 @@ impl<'a> AcquireNextImageInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AcquireNextImageInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupPresentInfoKHR.html>"]
@@ -20320,6 +22244,9 @@ impl<'a> DeviceGroupPresentInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupPresentInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_PRESENT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupDeviceCreateInfo.html>"]
@@ -20381,6 +22308,9 @@ impl<'a> DeviceGroupDeviceCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupDeviceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceGroupSwapchainCreateInfoKHR.html>"]
@@ -20439,6 +22369,9 @@ impl<'a> DeviceGroupSwapchainCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceGroupSwapchainCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorUpdateTemplateEntry.html>"]
@@ -20447,8 +22380,8 @@ pub struct DescriptorUpdateTemplateEntry {
     pub dst_array_element: u32,
     pub descriptor_count: u32,
     pub descriptor_type: DescriptorType,
-    pub offset: usize,
-    pub stride: usize,
+    pub offset: size_t,
+    pub stride: size_t,
 }
 impl DescriptorUpdateTemplateEntry {
     pub fn builder<'a>() -> DescriptorUpdateTemplateEntryBuilder<'a> {
@@ -20500,11 +22433,11 @@ impl<'a> DescriptorUpdateTemplateEntryBuilder<'a> {
         self.inner.descriptor_type = descriptor_type;
         self
     }
-    pub fn offset(mut self, offset: usize) -> DescriptorUpdateTemplateEntryBuilder<'a> {
+    pub fn offset(mut self, offset: size_t) -> DescriptorUpdateTemplateEntryBuilder<'a> {
         self.inner.offset = offset;
         self
     }
-    pub fn stride(mut self, stride: usize) -> DescriptorUpdateTemplateEntryBuilder<'a> {
+    pub fn stride(mut self, stride: size_t) -> DescriptorUpdateTemplateEntryBuilder<'a> {
         self.inner.stride = stride;
         self
     }
@@ -20624,6 +22557,8 @@ impl<'a> DescriptorUpdateTemplateCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDescriptorUpdateTemplateCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -20633,6 +22568,11 @@ impl<'a> DescriptorUpdateTemplateCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkDescriptorUpdateTemplateCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -20643,6 +22583,9 @@ impl<'a> DescriptorUpdateTemplateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorUpdateTemplateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkXYColorEXT.html>"]
@@ -20799,6 +22742,8 @@ impl<'a> HdrMetadataEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsHdrMetadataEXT>(
         mut self,
         next: &'a mut T,
@@ -20808,6 +22753,8 @@ impl<'a> HdrMetadataEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkHdrMetadataEXT", &mut self.inner);
         }
         self
     }
@@ -20818,6 +22765,9 @@ impl<'a> HdrMetadataEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for HdrMetadataEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::HDR_METADATA_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayNativeHdrSurfaceCapabilitiesAMD.html>"]
@@ -20876,6 +22826,10 @@ impl<'a> DisplayNativeHdrSurfaceCapabilitiesAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayNativeHdrSurfaceCapabilitiesAMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSwapchainDisplayNativeHdrCreateInfoAMD.html>"]
@@ -20934,6 +22888,10 @@ impl<'a> SwapchainDisplayNativeHdrCreateInfoAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SwapchainDisplayNativeHdrCreateInfoAMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRefreshCycleDurationGOOGLE.html>"]
@@ -21111,6 +23069,9 @@ impl<'a> PresentTimesInfoGOOGLEBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PresentTimesInfoGOOGLE {
+    const STRUCTURE_TYPE: StructureType = StructureType::PRESENT_TIMES_INFO_GOOGLE;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPresentTimeGOOGLE.html>"]
@@ -21219,6 +23180,8 @@ impl<'a> IOSSurfaceCreateInfoMVKBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsIOSSurfaceCreateInfoMVK>(
         mut self,
         next: &'a mut T,
@@ -21228,6 +23191,8 @@ impl<'a> IOSSurfaceCreateInfoMVKBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkIOSSurfaceCreateInfoMVK", &mut self.inner);
         }
         self
     }
@@ -21238,6 +23203,9 @@ impl<'a> IOSSurfaceCreateInfoMVKBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for IOSSurfaceCreateInfoMVK {
+    const STRUCTURE_TYPE: StructureType = StructureType::IOS_SURFACE_CREATE_INFO_M;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMacOSSurfaceCreateInfoMVK.html>"]
@@ -21299,6 +23267,8 @@ impl<'a> MacOSSurfaceCreateInfoMVKBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMacOSSurfaceCreateInfoMVK>(
         mut self,
         next: &'a mut T,
@@ -21308,6 +23278,8 @@ impl<'a> MacOSSurfaceCreateInfoMVKBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMacOSSurfaceCreateInfoMVK", &mut self.inner);
         }
         self
     }
@@ -21318,6 +23290,9 @@ impl<'a> MacOSSurfaceCreateInfoMVKBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MacOSSurfaceCreateInfoMVK {
+    const STRUCTURE_TYPE: StructureType = StructureType::MACOS_SURFACE_CREATE_INFO_M;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMetalSurfaceCreateInfoEXT.html>"]
@@ -21379,6 +23354,8 @@ impl<'a> MetalSurfaceCreateInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMetalSurfaceCreateInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -21388,6 +23365,8 @@ impl<'a> MetalSurfaceCreateInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMetalSurfaceCreateInfoEXT", &mut self.inner);
         }
         self
     }
@@ -21398,6 +23377,9 @@ impl<'a> MetalSurfaceCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MetalSurfaceCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::METAL_SURFACE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkViewportWScalingNV.html>"]
@@ -21518,6 +23500,10 @@ impl<'a> PipelineViewportWScalingStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportWScalingStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkViewportSwizzleNV.html>"]
@@ -21648,6 +23634,10 @@ impl<'a> PipelineViewportSwizzleStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportSwizzleStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDiscardRectanglePropertiesEXT.html>"]
@@ -21709,6 +23699,20 @@ impl<'a> PhysicalDeviceDiscardRectanglePropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDiscardRectanglePropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT;
+}
+impl PhysicalDeviceDiscardRectanglePropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineDiscardRectangleStateCreateInfoEXT.html>"]
@@ -21791,6 +23795,10 @@ impl<'a> PipelineDiscardRectangleStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineDiscardRectangleStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX.html>"]
@@ -21855,6 +23863,20 @@ impl<'a> PhysicalDeviceMultiviewPerViewAttributesPropertiesNVXBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX;
+}
+impl PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkInputAttachmentAspectReference.html>"]
@@ -21974,6 +23996,10 @@ impl<'a> RenderPassInputAttachmentAspectCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassInputAttachmentAspectCreateInfo {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSurfaceInfo2KHR.html>"]
@@ -22026,6 +24052,8 @@ impl<'a> PhysicalDeviceSurfaceInfo2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceSurfaceInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -22035,6 +24063,8 @@ impl<'a> PhysicalDeviceSurfaceInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceSurfaceInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -22045,6 +24075,9 @@ impl<'a> PhysicalDeviceSurfaceInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSurfaceInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceCapabilities2KHR.html>"]
@@ -22100,6 +24133,8 @@ impl<'a> SurfaceCapabilities2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSurfaceCapabilities2KHR>(
         mut self,
         next: &'a mut T,
@@ -22109,6 +24144,8 @@ impl<'a> SurfaceCapabilities2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSurfaceCapabilities2KHR", &mut self.inner);
         }
         self
     }
@@ -22119,6 +24156,26 @@ impl<'a> SurfaceCapabilities2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceCapabilities2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SURFACE_CAPABILITIES_2_KHR;
+}
+impl SurfaceCapabilities2KHR {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::SURFACE_CAPABILITIES_2_KHR;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::SURFACE_CAPABILITIES_2_KHR;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceFormat2KHR.html>"]
@@ -22174,6 +24231,8 @@ impl<'a> SurfaceFormat2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSurfaceFormat2KHR>(
         mut self,
         next: &'a mut T,
@@ -22183,6 +24242,8 @@ impl<'a> SurfaceFormat2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSurfaceFormat2KHR", &mut self.inner);
         }
         self
     }
@@ -22193,6 +24254,26 @@ impl<'a> SurfaceFormat2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceFormat2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SURFACE_FORMAT_2_KHR;
+}
+impl SurfaceFormat2KHR {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::SURFACE_FORMAT_2_KHR;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::SURFACE_FORMAT_2_KHR;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayProperties2KHR.html>"]
@@ -22248,6 +24329,8 @@ impl<'a> DisplayProperties2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayProperties2KHR>(
         mut self,
         next: &'a mut T,
@@ -22257,6 +24340,8 @@ impl<'a> DisplayProperties2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayProperties2KHR", &mut self.inner);
         }
         self
     }
@@ -22267,6 +24352,9 @@ impl<'a> DisplayProperties2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayProperties2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_PROPERTIES_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPlaneProperties2KHR.html>"]
@@ -22322,6 +24410,8 @@ impl<'a> DisplayPlaneProperties2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayPlaneProperties2KHR>(
         mut self,
         next: &'a mut T,
@@ -22331,6 +24421,8 @@ impl<'a> DisplayPlaneProperties2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayPlaneProperties2KHR", &mut self.inner);
         }
         self
     }
@@ -22341,6 +24433,9 @@ impl<'a> DisplayPlaneProperties2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayPlaneProperties2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayModeProperties2KHR.html>"]
@@ -22396,6 +24491,8 @@ impl<'a> DisplayModeProperties2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayModeProperties2KHR>(
         mut self,
         next: &'a mut T,
@@ -22405,6 +24502,8 @@ impl<'a> DisplayModeProperties2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayModeProperties2KHR", &mut self.inner);
         }
         self
     }
@@ -22415,6 +24514,9 @@ impl<'a> DisplayModeProperties2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayModeProperties2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_MODE_PROPERTIES_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPlaneInfo2KHR.html>"]
@@ -22473,6 +24575,8 @@ impl<'a> DisplayPlaneInfo2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayPlaneInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -22482,6 +24586,8 @@ impl<'a> DisplayPlaneInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayPlaneInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -22492,6 +24598,9 @@ impl<'a> DisplayPlaneInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayPlaneInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_PLANE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDisplayPlaneCapabilities2KHR.html>"]
@@ -22547,6 +24656,8 @@ impl<'a> DisplayPlaneCapabilities2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDisplayPlaneCapabilities2KHR>(
         mut self,
         next: &'a mut T,
@@ -22556,6 +24667,8 @@ impl<'a> DisplayPlaneCapabilities2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDisplayPlaneCapabilities2KHR", &mut self.inner);
         }
         self
     }
@@ -22566,6 +24679,9 @@ impl<'a> DisplayPlaneCapabilities2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DisplayPlaneCapabilities2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSharedPresentSurfaceCapabilitiesKHR.html>"]
@@ -22624,6 +24740,9 @@ impl<'a> SharedPresentSurfaceCapabilitiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SharedPresentSurfaceCapabilitiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevice16BitStorageFeatures.html>"]
@@ -22710,6 +24829,33 @@ impl<'a> PhysicalDevice16BitStorageFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevice16BitStorageFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES;
+}
+impl PhysicalDevice16BitStorageFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.storage_buffer16_bit_access != FALSE
+            && self.storage_buffer16_bit_access == FALSE
+        {
+            missing.push("storageBuffer16BitAccess");
+        }
+        if required.uniform_and_storage_buffer16_bit_access != FALSE
+            && self.uniform_and_storage_buffer16_bit_access == FALSE
+        {
+            missing.push("uniformAndStorageBuffer16BitAccess");
+        }
+        if required.storage_push_constant16 != FALSE && self.storage_push_constant16 == FALSE {
+            missing.push("storagePushConstant16");
+        }
+        if required.storage_input_output16 != FALSE && self.storage_input_output16 == FALSE {
+            missing.push("storageInputOutput16");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupProperties.html>"]
@@ -22795,6 +24941,26 @@ impl<'a> PhysicalDeviceSubgroupPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSubgroupProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES;
+}
+impl PhysicalDeviceSubgroupProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures.html>"]
@@ -22856,6 +25022,23 @@ impl<'a> PhysicalDeviceShaderSubgroupExtendedTypesFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderSubgroupExtendedTypesFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES;
+}
+impl PhysicalDeviceShaderSubgroupExtendedTypesFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_subgroup_extended_types != FALSE
+            && self.shader_subgroup_extended_types == FALSE
+        {
+            missing.push("shaderSubgroupExtendedTypes");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferMemoryRequirementsInfo2.html>"]
@@ -22908,6 +25091,8 @@ impl<'a> BufferMemoryRequirementsInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferMemoryRequirementsInfo2>(
         mut self,
         next: &'a mut T,
@@ -22917,6 +25102,8 @@ impl<'a> BufferMemoryRequirementsInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferMemoryRequirementsInfo2", &mut self.inner);
         }
         self
     }
@@ -22927,6 +25114,9 @@ impl<'a> BufferMemoryRequirementsInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferMemoryRequirementsInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageMemoryRequirementsInfo2.html>"]
@@ -22979,6 +25169,8 @@ impl<'a> ImageMemoryRequirementsInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageMemoryRequirementsInfo2>(
         mut self,
         next: &'a mut T,
@@ -22988,6 +25180,8 @@ impl<'a> ImageMemoryRequirementsInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageMemoryRequirementsInfo2", &mut self.inner);
         }
         self
     }
@@ -22998,6 +25192,9 @@ impl<'a> ImageMemoryRequirementsInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageMemoryRequirementsInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageSparseMemoryRequirementsInfo2.html>"]
@@ -23050,6 +25247,8 @@ impl<'a> ImageSparseMemoryRequirementsInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageSparseMemoryRequirementsInfo2>(
         mut self,
         next: &'a mut T,
@@ -23059,6 +25258,11 @@ impl<'a> ImageSparseMemoryRequirementsInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkImageSparseMemoryRequirementsInfo2",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -23069,6 +25273,9 @@ impl<'a> ImageSparseMemoryRequirementsInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageSparseMemoryRequirementsInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryRequirements2.html>"]
@@ -23124,6 +25331,8 @@ impl<'a> MemoryRequirements2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryRequirements2>(
         mut self,
         next: &'a mut T,
@@ -23133,6 +25342,8 @@ impl<'a> MemoryRequirements2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryRequirements2", &mut self.inner);
         }
         self
     }
@@ -23143,6 +25354,26 @@ impl<'a> MemoryRequirements2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryRequirements2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_REQUIREMENTS_2;
+}
+impl MemoryRequirements2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::MEMORY_REQUIREMENTS_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::MEMORY_REQUIREMENTS_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSparseImageMemoryRequirements2.html>"]
@@ -23198,6 +25429,8 @@ impl<'a> SparseImageMemoryRequirements2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSparseImageMemoryRequirements2>(
         mut self,
         next: &'a mut T,
@@ -23207,6 +25440,8 @@ impl<'a> SparseImageMemoryRequirements2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSparseImageMemoryRequirements2", &mut self.inner);
         }
         self
     }
@@ -23217,6 +25452,26 @@ impl<'a> SparseImageMemoryRequirements2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SparseImageMemoryRequirements2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2;
+}
+impl SparseImageMemoryRequirements2 {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePointClippingProperties.html>"]
@@ -23275,6 +25530,26 @@ impl<'a> PhysicalDevicePointClippingPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePointClippingProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES;
+}
+impl PhysicalDevicePointClippingProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryDedicatedRequirements.html>"]
@@ -23342,6 +25617,26 @@ impl<'a> MemoryDedicatedRequirementsBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryDedicatedRequirements {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_DEDICATED_REQUIREMENTS;
+}
+impl MemoryDedicatedRequirements {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::MEMORY_DEDICATED_REQUIREMENTS;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::MEMORY_DEDICATED_REQUIREMENTS;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryDedicatedAllocateInfo.html>"]
@@ -23403,6 +25698,9 @@ impl<'a> MemoryDedicatedAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryDedicatedAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_DEDICATED_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageViewUsageCreateInfo.html>"]
@@ -23458,6 +25756,9 @@ impl<'a> ImageViewUsageCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageViewUsageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_VIEW_USAGE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineTessellationDomainOriginStateCreateInfo.html>"]
@@ -23522,6 +25823,10 @@ impl<'a> PipelineTessellationDomainOriginStateCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineTessellationDomainOriginStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerYcbcrConversionInfo.html>"]
@@ -23582,6 +25887,9 @@ impl<'a> SamplerYcbcrConversionInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerYcbcrConversionInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SAMPLER_YCBCR_CONVERSION_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerYcbcrConversionCreateInfo.html>"]
@@ -23697,6 +26005,8 @@ impl<'a> SamplerYcbcrConversionCreateInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSamplerYcbcrConversionCreateInfo>(
         mut self,
         next: &'a mut T,
@@ -23706,6 +26016,11 @@ impl<'a> SamplerYcbcrConversionCreateInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkSamplerYcbcrConversionCreateInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -23716,6 +26031,9 @@ impl<'a> SamplerYcbcrConversionCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerYcbcrConversionCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindImagePlaneMemoryInfo.html>"]
@@ -23774,6 +26092,9 @@ impl<'a> BindImagePlaneMemoryInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindImagePlaneMemoryInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_IMAGE_PLANE_MEMORY_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImagePlaneMemoryRequirementsInfo.html>"]
@@ -23832,6 +26153,9 @@ impl<'a> ImagePlaneMemoryRequirementsInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImagePlaneMemoryRequirementsInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSamplerYcbcrConversionFeatures.html>"]
@@ -23890,6 +26214,21 @@ impl<'a> PhysicalDeviceSamplerYcbcrConversionFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSamplerYcbcrConversionFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES;
+}
+impl PhysicalDeviceSamplerYcbcrConversionFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.sampler_ycbcr_conversion != FALSE && self.sampler_ycbcr_conversion == FALSE {
+            missing.push("samplerYcbcrConversion");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerYcbcrConversionImageFormatProperties.html>"]
@@ -23952,6 +26291,10 @@ impl<'a> SamplerYcbcrConversionImageFormatPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerYcbcrConversionImageFormatProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkTextureLODGatherFormatPropertiesAMD.html>"]
@@ -24011,6 +26354,9 @@ impl<'a> TextureLODGatherFormatPropertiesAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for TextureLODGatherFormatPropertiesAMD {
+    const STRUCTURE_TYPE: StructureType = StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkConditionalRenderingBeginInfoEXT.html>"]
@@ -24078,6 +26424,8 @@ impl<'a> ConditionalRenderingBeginInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsConditionalRenderingBeginInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -24087,6 +26435,11 @@ impl<'a> ConditionalRenderingBeginInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkConditionalRenderingBeginInfoEXT",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -24097,6 +26450,9 @@ impl<'a> ConditionalRenderingBeginInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ConditionalRenderingBeginInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkProtectedSubmitInfo.html>"]
@@ -24152,6 +26508,9 @@ impl<'a> ProtectedSubmitInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ProtectedSubmitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PROTECTED_SUBMIT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceProtectedMemoryFeatures.html>"]
@@ -24210,6 +26569,20 @@ impl<'a> PhysicalDeviceProtectedMemoryFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceProtectedMemoryFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES;
+}
+impl PhysicalDeviceProtectedMemoryFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.protected_memory != FALSE && self.protected_memory == FALSE {
+            missing.push("protectedMemory");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceProtectedMemoryProperties.html>"]
@@ -24271,6 +26644,20 @@ impl<'a> PhysicalDeviceProtectedMemoryPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceProtectedMemoryProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES;
+}
+impl PhysicalDeviceProtectedMemoryProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceQueueInfo2.html>"]
@@ -24335,6 +26722,8 @@ impl<'a> DeviceQueueInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceQueueInfo2>(
         mut self,
         next: &'a mut T,
@@ -24344,6 +26733,8 @@ impl<'a> DeviceQueueInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDeviceQueueInfo2", &mut self.inner);
         }
         self
     }
@@ -24354,6 +26745,9 @@ impl<'a> DeviceQueueInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceQueueInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_QUEUE_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCoverageToColorStateCreateInfoNV.html>"]
@@ -24433,6 +26827,10 @@ impl<'a> PipelineCoverageToColorStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCoverageToColorStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSamplerFilterMinmaxProperties.html>"]
@@ -24505,6 +26903,20 @@ impl<'a> PhysicalDeviceSamplerFilterMinmaxPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSamplerFilterMinmaxProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES;
+}
+impl PhysicalDeviceSamplerFilterMinmaxProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSampleLocationEXT.html>"]
@@ -24631,6 +27043,9 @@ impl<'a> SampleLocationsInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SampleLocationsInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::SAMPLE_LOCATIONS_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAttachmentSampleLocationsEXT.html>"]
@@ -24809,6 +27224,10 @@ impl<'a> RenderPassSampleLocationsBeginInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassSampleLocationsBeginInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineSampleLocationsStateCreateInfoEXT.html>"]
@@ -24882,6 +27301,10 @@ impl<'a> PipelineSampleLocationsStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineSampleLocationsStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSampleLocationsPropertiesEXT.html>"]
@@ -24979,6 +27402,20 @@ impl<'a> PhysicalDeviceSampleLocationsPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSampleLocationsPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT;
+}
+impl PhysicalDeviceSampleLocationsPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMultisamplePropertiesEXT.html>"]
@@ -25034,6 +27471,8 @@ impl<'a> MultisamplePropertiesEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMultisamplePropertiesEXT>(
         mut self,
         next: &'a mut T,
@@ -25043,6 +27482,8 @@ impl<'a> MultisamplePropertiesEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMultisamplePropertiesEXT", &mut self.inner);
         }
         self
     }
@@ -25053,6 +27494,9 @@ impl<'a> MultisamplePropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MultisamplePropertiesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::MULTISAMPLE_PROPERTIES_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerReductionModeCreateInfo.html>"]
@@ -25111,6 +27555,9 @@ impl<'a> SamplerReductionModeCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerReductionModeCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT.html>"]
@@ -25169,6 +27616,23 @@ impl<'a> PhysicalDeviceBlendOperationAdvancedFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT;
+}
+impl PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.advanced_blend_coherent_operations != FALSE
+            && self.advanced_blend_coherent_operations == FALSE
+        {
+            missing.push("advancedBlendCoherentOperations");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT.html>"]
@@ -25277,6 +27741,20 @@ impl<'a> PhysicalDeviceBlendOperationAdvancedPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT;
+}
+impl PhysicalDeviceBlendOperationAdvancedPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineColorBlendAdvancedStateCreateInfoEXT.html>"]
@@ -25359,6 +27837,10 @@ impl<'a> PipelineColorBlendAdvancedStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineColorBlendAdvancedStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceInlineUniformBlockFeaturesEXT.html>"]
@@ -25428,6 +27910,26 @@ impl<'a> PhysicalDeviceInlineUniformBlockFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceInlineUniformBlockFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT;
+}
+impl PhysicalDeviceInlineUniformBlockFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.inline_uniform_block != FALSE && self.inline_uniform_block == FALSE {
+            missing.push("inlineUniformBlock");
+        }
+        if required.descriptor_binding_inline_uniform_block_update_after_bind != FALSE
+            && self.descriptor_binding_inline_uniform_block_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingInlineUniformBlockUpdateAfterBind");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceInlineUniformBlockPropertiesEXT.html>"]
@@ -25531,6 +28033,20 @@ impl<'a> PhysicalDeviceInlineUniformBlockPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceInlineUniformBlockPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT;
+}
+impl PhysicalDeviceInlineUniformBlockPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWriteDescriptorSetInlineUniformBlockEXT.html>"]
@@ -25589,6 +28105,10 @@ impl<'a> WriteDescriptorSetInlineUniformBlockEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for WriteDescriptorSetInlineUniformBlockEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorPoolInlineUniformBlockCreateInfoEXT.html>"]
@@ -25650,6 +28170,10 @@ impl<'a> DescriptorPoolInlineUniformBlockCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorPoolInlineUniformBlockCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCoverageModulationStateCreateInfoNV.html>"]
@@ -25744,6 +28268,10 @@ impl<'a> PipelineCoverageModulationStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCoverageModulationStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageFormatListCreateInfo.html>"]
@@ -25809,6 +28337,9 @@ impl<'a> ImageFormatListCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageFormatListCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_FORMAT_LIST_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkValidationCacheCreateInfoEXT.html>"]
@@ -25816,7 +28347,7 @@ pub struct ValidationCacheCreateInfoEXT {
     pub s_type: StructureType,
     pub p_next: *const c_void,
     pub flags: ValidationCacheCreateFlagsEXT,
-    pub initial_data_size: usize,
+    pub initial_data_size: size_t,
     pub p_initial_data: *const c_void,
 }
 impl ::std::default::Default for ValidationCacheCreateInfoEXT {
@@ -25825,7 +28356,7 @@ impl ::std::default::Default for ValidationCacheCreateInfoEXT {
             s_type: StructureType::VALIDATION_CACHE_CREATE_INFO_EXT,
             p_next: ::std::ptr::null(),
             flags: ValidationCacheCreateFlagsEXT::default(),
-            initial_data_size: usize::default(),
+            initial_data_size: size_t::default(),
             p_initial_data: ::std::ptr::null(),
         }
     }
@@ -25876,6 +28407,8 @@ impl<'a> ValidationCacheCreateInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsValidationCacheCreateInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -25885,6 +28418,8 @@ impl<'a> ValidationCacheCreateInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkValidationCacheCreateInfoEXT", &mut self.inner);
         }
         self
     }
@@ -25895,6 +28430,9 @@ impl<'a> ValidationCacheCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ValidationCacheCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::VALIDATION_CACHE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkShaderModuleValidationCacheCreateInfoEXT.html>"]
@@ -25953,6 +28491,10 @@ impl<'a> ShaderModuleValidationCacheCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ShaderModuleValidationCacheCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMaintenance3Properties.html>"]
@@ -26020,6 +28562,26 @@ impl<'a> PhysicalDeviceMaintenance3PropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMaintenance3Properties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES;
+}
+impl PhysicalDeviceMaintenance3Properties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetLayoutSupport.html>"]
@@ -26072,6 +28634,8 @@ impl<'a> DescriptorSetLayoutSupportBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDescriptorSetLayoutSupport>(
         mut self,
         next: &'a mut T,
@@ -26081,6 +28645,8 @@ impl<'a> DescriptorSetLayoutSupportBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDescriptorSetLayoutSupport", &mut self.inner);
         }
         self
     }
@@ -26091,6 +28657,26 @@ impl<'a> DescriptorSetLayoutSupportBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetLayoutSupport {
+    const STRUCTURE_TYPE: StructureType = StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT;
+}
+impl DescriptorSetLayoutSupport {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderDrawParametersFeatures.html>"]
@@ -26149,6 +28735,21 @@ impl<'a> PhysicalDeviceShaderDrawParametersFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderDrawParametersFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES;
+}
+impl PhysicalDeviceShaderDrawParametersFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_draw_parameters != FALSE && self.shader_draw_parameters == FALSE {
+            missing.push("shaderDrawParameters");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderFloat16Int8Features.html>"]
@@ -26216,6 +28817,24 @@ impl<'a> PhysicalDeviceShaderFloat16Int8FeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderFloat16Int8Features {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES;
+}
+impl PhysicalDeviceShaderFloat16Int8Features {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_float16 != FALSE && self.shader_float16 == FALSE {
+            missing.push("shaderFloat16");
+        }
+        if required.shader_int8 != FALSE && self.shader_int8 == FALSE {
+            missing.push("shaderInt8");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFloatControlsProperties.html>"]
@@ -26421,6 +29040,26 @@ impl<'a> PhysicalDeviceFloatControlsPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFloatControlsProperties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES;
+}
+impl PhysicalDeviceFloatControlsProperties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceHostQueryResetFeatures.html>"]
@@ -26479,6 +29118,20 @@ impl<'a> PhysicalDeviceHostQueryResetFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceHostQueryResetFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES;
+}
+impl PhysicalDeviceHostQueryResetFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.host_query_reset != FALSE && self.host_query_reset == FALSE {
+            missing.push("hostQueryReset");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkNativeBufferUsage2ANDROID.html>"]
@@ -26602,6 +29255,8 @@ impl<'a> NativeBufferANDROIDBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsNativeBufferANDROID>(
         mut self,
         next: &'a mut T,
@@ -26611,6 +29266,8 @@ impl<'a> NativeBufferANDROIDBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkNativeBufferANDROID", &mut self.inner);
         }
         self
     }
@@ -26621,6 +29278,9 @@ impl<'a> NativeBufferANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for NativeBufferANDROID {
+    const STRUCTURE_TYPE: StructureType = StructureType::NATIVE_BUFFER_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSwapchainImageCreateInfoANDROID.html>"]
@@ -26676,6 +29336,8 @@ impl<'a> SwapchainImageCreateInfoANDROIDBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSwapchainImageCreateInfoANDROID>(
         mut self,
         next: &'a mut T,
@@ -26685,6 +29347,8 @@ impl<'a> SwapchainImageCreateInfoANDROIDBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSwapchainImageCreateInfoANDROID", &mut self.inner);
         }
         self
     }
@@ -26695,6 +29359,9 @@ impl<'a> SwapchainImageCreateInfoANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SwapchainImageCreateInfoANDROID {
+    const STRUCTURE_TYPE: StructureType = StructureType::SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePresentationPropertiesANDROID.html>"]
@@ -26750,6 +29417,8 @@ impl<'a> PhysicalDevicePresentationPropertiesANDROIDBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDevicePresentationPropertiesANDROID>(
         mut self,
         next: &'a mut T,
@@ -26759,6 +29428,11 @@ impl<'a> PhysicalDevicePresentationPropertiesANDROIDBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPhysicalDevicePresentationPropertiesANDROID",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -26769,6 +29443,20 @@ impl<'a> PhysicalDevicePresentationPropertiesANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePresentationPropertiesANDROID {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID;
+}
+impl PhysicalDevicePresentationPropertiesANDROID {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkShaderResourceUsageAMD.html>"]
@@ -26776,8 +29464,8 @@ pub struct ShaderResourceUsageAMD {
     pub num_used_vgprs: u32,
     pub num_used_sgprs: u32,
     pub lds_size_per_local_work_group: u32,
-    pub lds_usage_size_in_bytes: usize,
-    pub scratch_mem_usage_in_bytes: usize,
+    pub lds_usage_size_in_bytes: size_t,
+    pub scratch_mem_usage_in_bytes: size_t,
 }
 impl ShaderResourceUsageAMD {
     pub fn builder<'a>() -> ShaderResourceUsageAMDBuilder<'a> {
@@ -26821,14 +29509,14 @@ impl<'a> ShaderResourceUsageAMDBuilder<'a> {
     }
     pub fn lds_usage_size_in_bytes(
         mut self,
-        lds_usage_size_in_bytes: usize,
+        lds_usage_size_in_bytes: size_t,
     ) -> ShaderResourceUsageAMDBuilder<'a> {
         self.inner.lds_usage_size_in_bytes = lds_usage_size_in_bytes;
         self
     }
     pub fn scratch_mem_usage_in_bytes(
         mut self,
-        scratch_mem_usage_in_bytes: usize,
+        scratch_mem_usage_in_bytes: size_t,
     ) -> ShaderResourceUsageAMDBuilder<'a> {
         self.inner.scratch_mem_usage_in_bytes = scratch_mem_usage_in_bytes;
         self
@@ -27004,6 +29692,10 @@ impl<'a> DeviceQueueGlobalPriorityCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceQueueGlobalPriorityCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugUtilsObjectNameInfoEXT.html>"]
@@ -27074,6 +29766,8 @@ impl<'a> DebugUtilsObjectNameInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugUtilsObjectNameInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -27083,6 +29777,8 @@ impl<'a> DebugUtilsObjectNameInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugUtilsObjectNameInfoEXT", &mut self.inner);
         }
         self
     }
@@ -27093,6 +29789,9 @@ impl<'a> DebugUtilsObjectNameInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugUtilsObjectNameInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugUtilsObjectTagInfoEXT.html>"]
@@ -27102,7 +29801,7 @@ pub struct DebugUtilsObjectTagInfoEXT {
     pub object_type: ObjectType,
     pub object_handle: u64,
     pub tag_name: u64,
-    pub tag_size: usize,
+    pub tag_size: size_t,
     pub p_tag: *const c_void,
 }
 impl ::std::default::Default for DebugUtilsObjectTagInfoEXT {
@@ -27113,7 +29812,7 @@ impl ::std::default::Default for DebugUtilsObjectTagInfoEXT {
             object_type: ObjectType::default(),
             object_handle: u64::default(),
             tag_name: u64::default(),
-            tag_size: usize::default(),
+            tag_size: size_t::default(),
             p_tag: ::std::ptr::null(),
         }
     }
@@ -27166,6 +29865,8 @@ impl<'a> DebugUtilsObjectTagInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugUtilsObjectTagInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -27175,6 +29876,8 @@ impl<'a> DebugUtilsObjectTagInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugUtilsObjectTagInfoEXT", &mut self.inner);
         }
         self
     }
@@ -27185,6 +29888,9 @@ impl<'a> DebugUtilsObjectTagInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugUtilsObjectTagInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugUtilsLabelEXT.html>"]
@@ -27243,6 +29949,8 @@ impl<'a> DebugUtilsLabelEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugUtilsLabelEXT>(
         mut self,
         next: &'a mut T,
@@ -27252,6 +29960,8 @@ impl<'a> DebugUtilsLabelEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkDebugUtilsLabelEXT", &mut self.inner);
         }
         self
     }
@@ -27262,6 +29972,9 @@ impl<'a> DebugUtilsLabelEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugUtilsLabelEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_UTILS_LABEL_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugUtilsMessengerCreateInfoEXT.html>"]
@@ -27372,6 +30085,9 @@ impl<'a> DebugUtilsMessengerCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugUtilsMessengerCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDebugUtilsMessengerCallbackDataEXT.html>"]
@@ -27469,6 +30185,15 @@ impl<'a> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
         self.inner.p_queue_labels = queue_labels.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn queue_labels_from_builders(
+        mut self,
+        queue_labels: &'a [DebugUtilsLabelEXTBuilder<'a>],
+    ) -> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
+        self.inner.queue_label_count = queue_labels.len() as _;
+        self.inner.p_queue_labels = queue_labels.as_ptr() as *const DebugUtilsLabelEXT;
+        self
+    }
     pub fn cmd_buf_labels(
         mut self,
         cmd_buf_labels: &'a [DebugUtilsLabelEXT],
@@ -27477,6 +30202,15 @@ impl<'a> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
         self.inner.p_cmd_buf_labels = cmd_buf_labels.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn cmd_buf_labels_from_builders(
+        mut self,
+        cmd_buf_labels: &'a [DebugUtilsLabelEXTBuilder<'a>],
+    ) -> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
+        self.inner.cmd_buf_label_count = cmd_buf_labels.len() as _;
+        self.inner.p_cmd_buf_labels = cmd_buf_labels.as_ptr() as *const DebugUtilsLabelEXT;
+        self
+    }
     pub fn objects(
         mut self,
         objects: &'a [DebugUtilsObjectNameInfoEXT],
@@ -27485,11 +30219,22 @@ impl<'a> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
         self.inner.p_objects = objects.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn objects_from_builders(
+        mut self,
+        objects: &'a [DebugUtilsObjectNameInfoEXTBuilder<'a>],
+    ) -> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
+        self.inner.object_count = objects.len() as _;
+        self.inner.p_objects = objects.as_ptr() as *const DebugUtilsObjectNameInfoEXT;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDebugUtilsMessengerCallbackDataEXT>(
         mut self,
         next: &'a mut T,
@@ -27499,6 +30244,11 @@ impl<'a> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkDebugUtilsMessengerCallbackDataEXT",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -27509,6 +30259,9 @@ impl<'a> DebugUtilsMessengerCallbackDataEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DebugUtilsMessengerCallbackDataEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDeviceMemoryReportFeaturesEXT.html>"]
@@ -27567,6 +30320,21 @@ impl<'a> PhysicalDeviceDeviceMemoryReportFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDeviceMemoryReportFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT;
+}
+impl PhysicalDeviceDeviceMemoryReportFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.device_memory_report != FALSE && self.device_memory_report == FALSE {
+            missing.push("deviceMemoryReport");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceDeviceMemoryReportCreateInfoEXT.html>"]
@@ -27657,6 +30425,10 @@ impl<'a> DeviceDeviceMemoryReportCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceDeviceMemoryReportCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceMemoryReportCallbackDataEXT.html>"]
@@ -27760,6 +30532,8 @@ impl<'a> DeviceMemoryReportCallbackDataEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceMemoryReportCallbackDataEXT>(
         mut self,
         next: &'a mut T,
@@ -27769,6 +30543,11 @@ impl<'a> DeviceMemoryReportCallbackDataEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkDeviceMemoryReportCallbackDataEXT",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -27779,6 +30558,9 @@ impl<'a> DeviceMemoryReportCallbackDataEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceMemoryReportCallbackDataEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportMemoryHostPointerInfoEXT.html>"]
@@ -27846,6 +30628,9 @@ impl<'a> ImportMemoryHostPointerInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportMemoryHostPointerInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryHostPointerPropertiesEXT.html>"]
@@ -27901,6 +30686,8 @@ impl<'a> MemoryHostPointerPropertiesEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryHostPointerPropertiesEXT>(
         mut self,
         next: &'a mut T,
@@ -27910,6 +30697,8 @@ impl<'a> MemoryHostPointerPropertiesEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkMemoryHostPointerPropertiesEXT", &mut self.inner);
         }
         self
     }
@@ -27920,6 +30709,9 @@ impl<'a> MemoryHostPointerPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryHostPointerPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExternalMemoryHostPropertiesEXT.html>"]
@@ -27981,6 +30773,20 @@ impl<'a> PhysicalDeviceExternalMemoryHostPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExternalMemoryHostPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT;
+}
+impl PhysicalDeviceExternalMemoryHostPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceConservativeRasterizationPropertiesEXT.html>"]
@@ -28122,6 +30928,20 @@ impl<'a> PhysicalDeviceConservativeRasterizationPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceConservativeRasterizationPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT;
+}
+impl PhysicalDeviceConservativeRasterizationPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCalibratedTimestampInfoEXT.html>"]
@@ -28177,6 +30997,8 @@ impl<'a> CalibratedTimestampInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCalibratedTimestampInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -28186,6 +31008,8 @@ impl<'a> CalibratedTimestampInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCalibratedTimestampInfoEXT", &mut self.inner);
         }
         self
     }
@@ -28196,6 +31020,9 @@ impl<'a> CalibratedTimestampInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CalibratedTimestampInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::CALIBRATED_TIMESTAMP_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderCorePropertiesAMD.html>"]
@@ -28371,6 +31198,27 @@ impl<'a> PhysicalDeviceShaderCorePropertiesAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderCorePropertiesAMD {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD;
+}
+impl PhysicalDeviceShaderCorePropertiesAMD {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type =
+                StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderCoreProperties2AMD.html>"]
@@ -28438,6 +31286,20 @@ impl<'a> PhysicalDeviceShaderCoreProperties2AMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderCoreProperties2AMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD;
+}
+impl PhysicalDeviceShaderCoreProperties2AMD {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationConservativeStateCreateInfoEXT.html>"]
@@ -28520,6 +31382,10 @@ impl<'a> PipelineRasterizationConservativeStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationConservativeStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDescriptorIndexingFeatures.html>"]
@@ -28778,6 +31644,116 @@ impl<'a> PhysicalDeviceDescriptorIndexingFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDescriptorIndexingFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES;
+}
+impl PhysicalDeviceDescriptorIndexingFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_input_attachment_array_dynamic_indexing != FALSE
+            && self.shader_input_attachment_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderInputAttachmentArrayDynamicIndexing");
+        }
+        if required.shader_uniform_texel_buffer_array_dynamic_indexing != FALSE
+            && self.shader_uniform_texel_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderUniformTexelBufferArrayDynamicIndexing");
+        }
+        if required.shader_storage_texel_buffer_array_dynamic_indexing != FALSE
+            && self.shader_storage_texel_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderStorageTexelBufferArrayDynamicIndexing");
+        }
+        if required.shader_uniform_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_uniform_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderUniformBufferArrayNonUniformIndexing");
+        }
+        if required.shader_sampled_image_array_non_uniform_indexing != FALSE
+            && self.shader_sampled_image_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderSampledImageArrayNonUniformIndexing");
+        }
+        if required.shader_storage_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_storage_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageBufferArrayNonUniformIndexing");
+        }
+        if required.shader_storage_image_array_non_uniform_indexing != FALSE
+            && self.shader_storage_image_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageImageArrayNonUniformIndexing");
+        }
+        if required.shader_input_attachment_array_non_uniform_indexing != FALSE
+            && self.shader_input_attachment_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderInputAttachmentArrayNonUniformIndexing");
+        }
+        if required.shader_uniform_texel_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_uniform_texel_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderUniformTexelBufferArrayNonUniformIndexing");
+        }
+        if required.shader_storage_texel_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_storage_texel_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageTexelBufferArrayNonUniformIndexing");
+        }
+        if required.descriptor_binding_uniform_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_uniform_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingUniformBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_sampled_image_update_after_bind != FALSE
+            && self.descriptor_binding_sampled_image_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingSampledImageUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_image_update_after_bind != FALSE
+            && self.descriptor_binding_storage_image_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageImageUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_storage_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_uniform_texel_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_uniform_texel_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingUniformTexelBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_texel_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_storage_texel_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageTexelBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_update_unused_while_pending != FALSE
+            && self.descriptor_binding_update_unused_while_pending == FALSE
+        {
+            missing.push("descriptorBindingUpdateUnusedWhilePending");
+        }
+        if required.descriptor_binding_partially_bound != FALSE
+            && self.descriptor_binding_partially_bound == FALSE
+        {
+            missing.push("descriptorBindingPartiallyBound");
+        }
+        if required.descriptor_binding_variable_descriptor_count != FALSE
+            && self.descriptor_binding_variable_descriptor_count == FALSE
+        {
+            missing.push("descriptorBindingVariableDescriptorCount");
+        }
+        if required.runtime_descriptor_array != FALSE && self.runtime_descriptor_array == FALSE {
+            missing.push("runtimeDescriptorArray");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDescriptorIndexingProperties.html>"]
@@ -29077,6 +32053,20 @@ impl<'a> PhysicalDeviceDescriptorIndexingPropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDescriptorIndexingProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES;
+}
+impl PhysicalDeviceDescriptorIndexingProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetLayoutBindingFlagsCreateInfo.html>"]
@@ -29141,6 +32131,10 @@ impl<'a> DescriptorSetLayoutBindingFlagsCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetLayoutBindingFlagsCreateInfo {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetVariableDescriptorCountAllocateInfo.html>"]
@@ -29205,6 +32199,10 @@ impl<'a> DescriptorSetVariableDescriptorCountAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetVariableDescriptorCountAllocateInfo {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDescriptorSetVariableDescriptorCountLayoutSupport.html>"]
@@ -29269,6 +32267,10 @@ impl<'a> DescriptorSetVariableDescriptorCountLayoutSupportBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DescriptorSetVariableDescriptorCountLayoutSupport {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAttachmentDescription2.html>"]
@@ -29378,6 +32380,8 @@ impl<'a> AttachmentDescription2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAttachmentDescription2>(
         mut self,
         next: &'a mut T,
@@ -29387,6 +32391,8 @@ impl<'a> AttachmentDescription2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAttachmentDescription2", &mut self.inner);
         }
         self
     }
@@ -29397,6 +32403,9 @@ impl<'a> AttachmentDescription2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AttachmentDescription2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::ATTACHMENT_DESCRIPTION_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAttachmentReference2.html>"]
@@ -29461,6 +32470,8 @@ impl<'a> AttachmentReference2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAttachmentReference2>(
         mut self,
         next: &'a mut T,
@@ -29470,6 +32481,8 @@ impl<'a> AttachmentReference2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAttachmentReference2", &mut self.inner);
         }
         self
     }
@@ -29480,6 +32493,9 @@ impl<'a> AttachmentReference2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AttachmentReference2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::ATTACHMENT_REFERENCE_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubpassDescription2.html>"]
@@ -29566,6 +32582,15 @@ impl<'a> SubpassDescription2Builder<'a> {
         self.inner.p_input_attachments = input_attachments.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn input_attachments_from_builders(
+        mut self,
+        input_attachments: &'a [AttachmentReference2Builder<'a>],
+    ) -> SubpassDescription2Builder<'a> {
+        self.inner.input_attachment_count = input_attachments.len() as _;
+        self.inner.p_input_attachments = input_attachments.as_ptr() as *const AttachmentReference2;
+        self
+    }
     pub fn color_attachments(
         mut self,
         color_attachments: &'a [AttachmentReference2],
@@ -29574,6 +32599,15 @@ impl<'a> SubpassDescription2Builder<'a> {
         self.inner.p_color_attachments = color_attachments.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn color_attachments_from_builders(
+        mut self,
+        color_attachments: &'a [AttachmentReference2Builder<'a>],
+    ) -> SubpassDescription2Builder<'a> {
+        self.inner.color_attachment_count = color_attachments.len() as _;
+        self.inner.p_color_attachments = color_attachments.as_ptr() as *const AttachmentReference2;
+        self
+    }
     pub fn resolve_attachments(
         mut self,
         resolve_attachments: &'a [AttachmentReference2],
@@ -29582,6 +32616,16 @@ impl<'a> SubpassDescription2Builder<'a> {
         self.inner.p_resolve_attachments = resolve_attachments.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn resolve_attachments_from_builders(
+        mut self,
+        resolve_attachments: &'a [AttachmentReference2Builder<'a>],
+    ) -> SubpassDescription2Builder<'a> {
+        self.inner.color_attachment_count = resolve_attachments.len() as _;
+        self.inner.p_resolve_attachments =
+            resolve_attachments.as_ptr() as *const AttachmentReference2;
+        self
+    }
     pub fn depth_stencil_attachment(
         mut self,
         depth_stencil_attachment: &'a AttachmentReference2,
@@ -29602,6 +32646,8 @@ impl<'a> SubpassDescription2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSubpassDescription2>(
         mut self,
         next: &'a mut T,
@@ -29611,6 +32657,8 @@ impl<'a> SubpassDescription2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSubpassDescription2", &mut self.inner);
         }
         self
     }
@@ -29621,6 +32669,9 @@ impl<'a> SubpassDescription2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubpassDescription2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBPASS_DESCRIPTION_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubpassDependency2.html>"]
@@ -29730,6 +32781,8 @@ impl<'a> SubpassDependency2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSubpassDependency2>(
         mut self,
         next: &'a mut T,
@@ -29739,6 +32792,8 @@ impl<'a> SubpassDependency2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSubpassDependency2", &mut self.inner);
         }
         self
     }
@@ -29749,6 +32804,9 @@ impl<'a> SubpassDependency2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubpassDependency2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBPASS_DEPENDENCY_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassCreateInfo2.html>"]
@@ -29820,6 +32878,15 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
         self.inner.p_attachments = attachments.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn attachments_from_builders(
+        mut self,
+        attachments: &'a [AttachmentDescription2Builder<'a>],
+    ) -> RenderPassCreateInfo2Builder<'a> {
+        self.inner.attachment_count = attachments.len() as _;
+        self.inner.p_attachments = attachments.as_ptr() as *const AttachmentDescription2;
+        self
+    }
     pub fn subpasses(
         mut self,
         subpasses: &'a [SubpassDescription2],
@@ -29828,6 +32895,15 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
         self.inner.p_subpasses = subpasses.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn subpasses_from_builders(
+        mut self,
+        subpasses: &'a [SubpassDescription2Builder<'a>],
+    ) -> RenderPassCreateInfo2Builder<'a> {
+        self.inner.subpass_count = subpasses.len() as _;
+        self.inner.p_subpasses = subpasses.as_ptr() as *const SubpassDescription2;
+        self
+    }
     pub fn dependencies(
         mut self,
         dependencies: &'a [SubpassDependency2],
@@ -29836,6 +32912,15 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
         self.inner.p_dependencies = dependencies.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn dependencies_from_builders(
+        mut self,
+        dependencies: &'a [SubpassDependency2Builder<'a>],
+    ) -> RenderPassCreateInfo2Builder<'a> {
+        self.inner.dependency_count = dependencies.len() as _;
+        self.inner.p_dependencies = dependencies.as_ptr() as *const SubpassDependency2;
+        self
+    }
     pub fn correlated_view_masks(
         mut self,
         correlated_view_masks: &'a [u32],
@@ -29849,6 +32934,8 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRenderPassCreateInfo2>(
         mut self,
         next: &'a mut T,
@@ -29858,6 +32945,8 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkRenderPassCreateInfo2", &mut self.inner);
         }
         self
     }
@@ -29868,6 +32957,9 @@ impl<'a> RenderPassCreateInfo2Builder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassCreateInfo2 {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_CREATE_INFO_2;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubpassBeginInfo.html>"]
@@ -29920,6 +33012,8 @@ impl<'a> SubpassBeginInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSubpassBeginInfo>(
         mut self,
         next: &'a mut T,
@@ -29929,6 +33023,8 @@ impl<'a> SubpassBeginInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSubpassBeginInfo", &mut self.inner);
         }
         self
     }
@@ -29939,6 +33035,9 @@ impl<'a> SubpassBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubpassBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBPASS_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubpassEndInfo.html>"]
@@ -29985,6 +33084,8 @@ impl<'a> SubpassEndInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSubpassEndInfo>(
         mut self,
         next: &'a mut T,
@@ -29994,6 +33095,8 @@ impl<'a> SubpassEndInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSubpassEndInfo", &mut self.inner);
         }
         self
     }
@@ -30004,6 +33107,9 @@ impl<'a> SubpassEndInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubpassEndInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBPASS_END_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTimelineSemaphoreFeatures.html>"]
@@ -30062,6 +33168,21 @@ impl<'a> PhysicalDeviceTimelineSemaphoreFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTimelineSemaphoreFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES;
+}
+impl PhysicalDeviceTimelineSemaphoreFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.timeline_semaphore != FALSE && self.timeline_semaphore == FALSE {
+            missing.push("timelineSemaphore");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTimelineSemaphoreProperties.html>"]
@@ -30124,6 +33245,20 @@ impl<'a> PhysicalDeviceTimelineSemaphorePropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTimelineSemaphoreProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES;
+}
+impl PhysicalDeviceTimelineSemaphoreProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreTypeCreateInfo.html>"]
@@ -30190,6 +33325,9 @@ impl<'a> SemaphoreTypeCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreTypeCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_TYPE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkTimelineSemaphoreSubmitInfo.html>"]
@@ -30265,6 +33403,9 @@ impl<'a> TimelineSemaphoreSubmitInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for TimelineSemaphoreSubmitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreWaitInfo.html>"]
@@ -30333,6 +33474,8 @@ impl<'a> SemaphoreWaitInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSemaphoreWaitInfo>(
         mut self,
         next: &'a mut T,
@@ -30342,6 +33485,8 @@ impl<'a> SemaphoreWaitInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSemaphoreWaitInfo", &mut self.inner);
         }
         self
     }
@@ -30352,6 +33497,9 @@ impl<'a> SemaphoreWaitInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreWaitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_WAIT_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSemaphoreSignalInfo.html>"]
@@ -30410,6 +33558,8 @@ impl<'a> SemaphoreSignalInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsSemaphoreSignalInfo>(
         mut self,
         next: &'a mut T,
@@ -30419,6 +33569,8 @@ impl<'a> SemaphoreSignalInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkSemaphoreSignalInfo", &mut self.inner);
         }
         self
     }
@@ -30429,6 +33581,9 @@ impl<'a> SemaphoreSignalInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SemaphoreSignalInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SEMAPHORE_SIGNAL_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkVertexInputBindingDivisorDescriptionEXT.html>"]
@@ -30543,6 +33698,10 @@ impl<'a> PipelineVertexInputDivisorStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineVertexInputDivisorStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT.html>"]
@@ -30604,6 +33763,20 @@ impl<'a> PhysicalDeviceVertexAttributeDivisorPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT;
+}
+impl PhysicalDeviceVertexAttributeDivisorPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePCIBusInfoPropertiesEXT.html>"]
@@ -30686,6 +33859,20 @@ impl<'a> PhysicalDevicePCIBusInfoPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePCIBusInfoPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT;
+}
+impl PhysicalDevicePCIBusInfoPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImportAndroidHardwareBufferInfoANDROID.html>"]
@@ -30744,6 +33931,10 @@ impl<'a> ImportAndroidHardwareBufferInfoANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImportAndroidHardwareBufferInfoANDROID {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAndroidHardwareBufferUsageANDROID.html>"]
@@ -30802,6 +33993,9 @@ impl<'a> AndroidHardwareBufferUsageANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AndroidHardwareBufferUsageANDROID {
+    const STRUCTURE_TYPE: StructureType = StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAndroidHardwareBufferPropertiesANDROID.html>"]
@@ -30866,6 +34060,8 @@ impl<'a> AndroidHardwareBufferPropertiesANDROIDBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAndroidHardwareBufferPropertiesANDROID>(
         mut self,
         next: &'a mut T,
@@ -30875,6 +34071,11 @@ impl<'a> AndroidHardwareBufferPropertiesANDROIDBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAndroidHardwareBufferPropertiesANDROID",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -30885,6 +34086,9 @@ impl<'a> AndroidHardwareBufferPropertiesANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AndroidHardwareBufferPropertiesANDROID {
+    const STRUCTURE_TYPE: StructureType = StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryGetAndroidHardwareBufferInfoANDROID.html>"]
@@ -30940,6 +34144,8 @@ impl<'a> MemoryGetAndroidHardwareBufferInfoANDROIDBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsMemoryGetAndroidHardwareBufferInfoANDROID>(
         mut self,
         next: &'a mut T,
@@ -30949,6 +34155,11 @@ impl<'a> MemoryGetAndroidHardwareBufferInfoANDROIDBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkMemoryGetAndroidHardwareBufferInfoANDROID",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -30959,6 +34170,10 @@ impl<'a> MemoryGetAndroidHardwareBufferInfoANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryGetAndroidHardwareBufferInfoANDROID {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAndroidHardwareBufferFormatPropertiesANDROID.html>"]
@@ -31086,6 +34301,10 @@ impl<'a> AndroidHardwareBufferFormatPropertiesANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AndroidHardwareBufferFormatPropertiesANDROID {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandBufferInheritanceConditionalRenderingInfoEXT.html>"]
@@ -31150,6 +34369,10 @@ impl<'a> CommandBufferInheritanceConditionalRenderingInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandBufferInheritanceConditionalRenderingInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkExternalFormatANDROID.html>"]
@@ -31207,6 +34430,9 @@ impl<'a> ExternalFormatANDROIDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ExternalFormatANDROID {
+    const STRUCTURE_TYPE: StructureType = StructureType::EXTERNAL_FORMAT_ANDROID;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevice8BitStorageFeatures.html>"]
@@ -31284,6 +34510,29 @@ impl<'a> PhysicalDevice8BitStorageFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevice8BitStorageFeatures {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES;
+}
+impl PhysicalDevice8BitStorageFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.storage_buffer8_bit_access != FALSE && self.storage_buffer8_bit_access == FALSE
+        {
+            missing.push("storageBuffer8BitAccess");
+        }
+        if required.uniform_and_storage_buffer8_bit_access != FALSE
+            && self.uniform_and_storage_buffer8_bit_access == FALSE
+        {
+            missing.push("uniformAndStorageBuffer8BitAccess");
+        }
+        if required.storage_push_constant8 != FALSE && self.storage_push_constant8 == FALSE {
+            missing.push("storagePushConstant8");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceConditionalRenderingFeaturesEXT.html>"]
@@ -31351,6 +34600,26 @@ impl<'a> PhysicalDeviceConditionalRenderingFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceConditionalRenderingFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT;
+}
+impl PhysicalDeviceConditionalRenderingFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.conditional_rendering != FALSE && self.conditional_rendering == FALSE {
+            missing.push("conditionalRendering");
+        }
+        if required.inherited_conditional_rendering != FALSE
+            && self.inherited_conditional_rendering == FALSE
+        {
+            missing.push("inheritedConditionalRendering");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVulkanMemoryModelFeatures.html>"]
@@ -31429,6 +34698,31 @@ impl<'a> PhysicalDeviceVulkanMemoryModelFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVulkanMemoryModelFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES;
+}
+impl PhysicalDeviceVulkanMemoryModelFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.vulkan_memory_model != FALSE && self.vulkan_memory_model == FALSE {
+            missing.push("vulkanMemoryModel");
+        }
+        if required.vulkan_memory_model_device_scope != FALSE
+            && self.vulkan_memory_model_device_scope == FALSE
+        {
+            missing.push("vulkanMemoryModelDeviceScope");
+        }
+        if required.vulkan_memory_model_availability_visibility_chains != FALSE
+            && self.vulkan_memory_model_availability_visibility_chains == FALSE
+        {
+            missing.push("vulkanMemoryModelAvailabilityVisibilityChains");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderAtomicInt64Features.html>"]
@@ -31496,6 +34790,28 @@ impl<'a> PhysicalDeviceShaderAtomicInt64FeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderAtomicInt64Features {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES;
+}
+impl PhysicalDeviceShaderAtomicInt64Features {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_buffer_int64_atomics != FALSE
+            && self.shader_buffer_int64_atomics == FALSE
+        {
+            missing.push("shaderBufferInt64Atomics");
+        }
+        if required.shader_shared_int64_atomics != FALSE
+            && self.shader_shared_int64_atomics == FALSE
+        {
+            missing.push("shaderSharedInt64Atomics");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderAtomicFloatFeaturesEXT.html>"]
@@ -31653,6 +34969,78 @@ impl<'a> PhysicalDeviceShaderAtomicFloatFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderAtomicFloatFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT;
+}
+impl PhysicalDeviceShaderAtomicFloatFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_buffer_float32_atomics != FALSE
+            && self.shader_buffer_float32_atomics == FALSE
+        {
+            missing.push("shaderBufferFloat32Atomics");
+        }
+        if required.shader_buffer_float32_atomic_add != FALSE
+            && self.shader_buffer_float32_atomic_add == FALSE
+        {
+            missing.push("shaderBufferFloat32AtomicAdd");
+        }
+        if required.shader_buffer_float64_atomics != FALSE
+            && self.shader_buffer_float64_atomics == FALSE
+        {
+            missing.push("shaderBufferFloat64Atomics");
+        }
+        if required.shader_buffer_float64_atomic_add != FALSE
+            && self.shader_buffer_float64_atomic_add == FALSE
+        {
+            missing.push("shaderBufferFloat64AtomicAdd");
+        }
+        if required.shader_shared_float32_atomics != FALSE
+            && self.shader_shared_float32_atomics == FALSE
+        {
+            missing.push("shaderSharedFloat32Atomics");
+        }
+        if required.shader_shared_float32_atomic_add != FALSE
+            && self.shader_shared_float32_atomic_add == FALSE
+        {
+            missing.push("shaderSharedFloat32AtomicAdd");
+        }
+        if required.shader_shared_float64_atomics != FALSE
+            && self.shader_shared_float64_atomics == FALSE
+        {
+            missing.push("shaderSharedFloat64Atomics");
+        }
+        if required.shader_shared_float64_atomic_add != FALSE
+            && self.shader_shared_float64_atomic_add == FALSE
+        {
+            missing.push("shaderSharedFloat64AtomicAdd");
+        }
+        if required.shader_image_float32_atomics != FALSE
+            && self.shader_image_float32_atomics == FALSE
+        {
+            missing.push("shaderImageFloat32Atomics");
+        }
+        if required.shader_image_float32_atomic_add != FALSE
+            && self.shader_image_float32_atomic_add == FALSE
+        {
+            missing.push("shaderImageFloat32AtomicAdd");
+        }
+        if required.sparse_image_float32_atomics != FALSE
+            && self.sparse_image_float32_atomics == FALSE
+        {
+            missing.push("sparseImageFloat32Atomics");
+        }
+        if required.sparse_image_float32_atomic_add != FALSE
+            && self.sparse_image_float32_atomic_add == FALSE
+        {
+            missing.push("sparseImageFloat32AtomicAdd");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT.html>"]
@@ -31722,6 +35110,28 @@ impl<'a> PhysicalDeviceVertexAttributeDivisorFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT;
+}
+impl PhysicalDeviceVertexAttributeDivisorFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.vertex_attribute_instance_rate_divisor != FALSE
+            && self.vertex_attribute_instance_rate_divisor == FALSE
+        {
+            missing.push("vertexAttributeInstanceRateDivisor");
+        }
+        if required.vertex_attribute_instance_rate_zero_divisor != FALSE
+            && self.vertex_attribute_instance_rate_zero_divisor == FALSE
+        {
+            missing.push("vertexAttributeInstanceRateZeroDivisor");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueueFamilyCheckpointPropertiesNV.html>"]
@@ -31780,6 +35190,9 @@ impl<'a> QueueFamilyCheckpointPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for QueueFamilyCheckpointPropertiesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCheckpointDataNV.html>"]
@@ -31841,6 +35254,8 @@ impl<'a> CheckpointDataNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCheckpointDataNV>(
         mut self,
         next: &'a mut T,
@@ -31850,6 +35265,8 @@ impl<'a> CheckpointDataNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCheckpointDataNV", &mut self.inner);
         }
         self
     }
@@ -31860,6 +35277,9 @@ impl<'a> CheckpointDataNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CheckpointDataNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::CHECKPOINT_DATA_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDepthStencilResolveProperties.html>"]
@@ -31948,6 +35368,20 @@ impl<'a> PhysicalDeviceDepthStencilResolvePropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDepthStencilResolveProperties {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES;
+}
+impl PhysicalDeviceDepthStencilResolveProperties {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSubpassDescriptionDepthStencilResolve.html>"]
@@ -32024,6 +35458,9 @@ impl<'a> SubpassDescriptionDepthStencilResolveBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SubpassDescriptionDepthStencilResolve {
+    const STRUCTURE_TYPE: StructureType = StructureType::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageViewASTCDecodeModeEXT.html>"]
@@ -32079,6 +35516,9 @@ impl<'a> ImageViewASTCDecodeModeEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageViewASTCDecodeModeEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceASTCDecodeFeaturesEXT.html>"]
@@ -32137,6 +35577,22 @@ impl<'a> PhysicalDeviceASTCDecodeFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceASTCDecodeFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT;
+}
+impl PhysicalDeviceASTCDecodeFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.decode_mode_shared_exponent != FALSE
+            && self.decode_mode_shared_exponent == FALSE
+        {
+            missing.push("decodeModeSharedExponent");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTransformFeedbackFeaturesEXT.html>"]
@@ -32204,6 +35660,24 @@ impl<'a> PhysicalDeviceTransformFeedbackFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTransformFeedbackFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT;
+}
+impl PhysicalDeviceTransformFeedbackFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.transform_feedback != FALSE && self.transform_feedback == FALSE {
+            missing.push("transformFeedback");
+        }
+        if required.geometry_streams != FALSE && self.geometry_streams == FALSE {
+            missing.push("geometryStreams");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTransformFeedbackPropertiesEXT.html>"]
@@ -32351,6 +35825,20 @@ impl<'a> PhysicalDeviceTransformFeedbackPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTransformFeedbackPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT;
+}
+impl PhysicalDeviceTransformFeedbackPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationStateStreamCreateInfoEXT.html>"]
@@ -32424,6 +35912,10 @@ impl<'a> PipelineRasterizationStateStreamCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationStateStreamCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV.html>"]
@@ -32485,6 +35977,23 @@ impl<'a> PhysicalDeviceRepresentativeFragmentTestFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV;
+}
+impl PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.representative_fragment_test != FALSE
+            && self.representative_fragment_test == FALSE
+        {
+            missing.push("representativeFragmentTest");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRepresentativeFragmentTestStateCreateInfoNV.html>"]
@@ -32549,6 +36058,10 @@ impl<'a> PipelineRepresentativeFragmentTestStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRepresentativeFragmentTestStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExclusiveScissorFeaturesNV.html>"]
@@ -32607,6 +36120,21 @@ impl<'a> PhysicalDeviceExclusiveScissorFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExclusiveScissorFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV;
+}
+impl PhysicalDeviceExclusiveScissorFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.exclusive_scissor != FALSE && self.exclusive_scissor == FALSE {
+            missing.push("exclusiveScissor");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineViewportExclusiveScissorStateCreateInfoNV.html>"]
@@ -32674,6 +36202,10 @@ impl<'a> PipelineViewportExclusiveScissorStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportExclusiveScissorStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCornerSampledImageFeaturesNV.html>"]
@@ -32732,6 +36264,21 @@ impl<'a> PhysicalDeviceCornerSampledImageFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCornerSampledImageFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV;
+}
+impl PhysicalDeviceCornerSampledImageFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.corner_sampled_image != FALSE && self.corner_sampled_image == FALSE {
+            missing.push("cornerSampledImage");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceComputeShaderDerivativesFeaturesNV.html>"]
@@ -32802,6 +36349,28 @@ impl<'a> PhysicalDeviceComputeShaderDerivativesFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV;
+}
+impl PhysicalDeviceComputeShaderDerivativesFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.compute_derivative_group_quads != FALSE
+            && self.compute_derivative_group_quads == FALSE
+        {
+            missing.push("computeDerivativeGroupQuads");
+        }
+        if required.compute_derivative_group_linear != FALSE
+            && self.compute_derivative_group_linear == FALSE
+        {
+            missing.push("computeDerivativeGroupLinear");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShaderBarycentricFeaturesNV.html>"]
@@ -32863,6 +36432,23 @@ impl<'a> PhysicalDeviceFragmentShaderBarycentricFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV;
+}
+impl PhysicalDeviceFragmentShaderBarycentricFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.fragment_shader_barycentric != FALSE
+            && self.fragment_shader_barycentric == FALSE
+        {
+            missing.push("fragmentShaderBarycentric");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderImageFootprintFeaturesNV.html>"]
@@ -32921,6 +36507,21 @@ impl<'a> PhysicalDeviceShaderImageFootprintFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderImageFootprintFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV;
+}
+impl PhysicalDeviceShaderImageFootprintFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.image_footprint != FALSE && self.image_footprint == FALSE {
+            missing.push("imageFootprint");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV.html>"]
@@ -32984,6 +36585,23 @@ impl<'a> PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV;
+}
+impl PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.dedicated_allocation_image_aliasing != FALSE
+            && self.dedicated_allocation_image_aliasing == FALSE
+        {
+            missing.push("dedicatedAllocationImageAliasing");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkShadingRatePaletteNV.html>"]
@@ -33108,6 +36726,16 @@ impl<'a> PipelineViewportShadingRateImageStateCreateInfoNVBuilder<'a> {
         self.inner.p_shading_rate_palettes = shading_rate_palettes.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn shading_rate_palettes_from_builders(
+        mut self,
+        shading_rate_palettes: &'a [ShadingRatePaletteNVBuilder<'a>],
+    ) -> PipelineViewportShadingRateImageStateCreateInfoNVBuilder<'a> {
+        self.inner.viewport_count = shading_rate_palettes.len() as _;
+        self.inner.p_shading_rate_palettes =
+            shading_rate_palettes.as_ptr() as *const ShadingRatePaletteNV;
+        self
+    }
     #[doc = r" Calling build will **discard** all the lifetime information. Only call this if"]
     #[doc = r" necessary! Builders implement `Deref` targeting their corresponding Vulkan struct,"]
     #[doc = r" so references to builders can be passed directly to Vulkan functions."]
@@ -33115,6 +36743,10 @@ impl<'a> PipelineViewportShadingRateImageStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportShadingRateImageStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShadingRateImageFeaturesNV.html>"]
@@ -33182,6 +36814,26 @@ impl<'a> PhysicalDeviceShadingRateImageFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShadingRateImageFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV;
+}
+impl PhysicalDeviceShadingRateImageFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shading_rate_image != FALSE && self.shading_rate_image == FALSE {
+            missing.push("shadingRateImage");
+        }
+        if required.shading_rate_coarse_sample_order != FALSE
+            && self.shading_rate_coarse_sample_order == FALSE
+        {
+            missing.push("shadingRateCoarseSampleOrder");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShadingRateImagePropertiesNV.html>"]
@@ -33261,6 +36913,20 @@ impl<'a> PhysicalDeviceShadingRateImagePropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShadingRateImagePropertiesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV;
+}
+impl PhysicalDeviceShadingRateImagePropertiesNV {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCoarseSampleLocationNV.html>"]
@@ -33452,6 +37118,16 @@ impl<'a> PipelineViewportCoarseSampleOrderStateCreateInfoNVBuilder<'a> {
         self.inner.p_custom_sample_orders = custom_sample_orders.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn custom_sample_orders_from_builders(
+        mut self,
+        custom_sample_orders: &'a [CoarseSampleOrderCustomNVBuilder<'a>],
+    ) -> PipelineViewportCoarseSampleOrderStateCreateInfoNVBuilder<'a> {
+        self.inner.custom_sample_order_count = custom_sample_orders.len() as _;
+        self.inner.p_custom_sample_orders =
+            custom_sample_orders.as_ptr() as *const CoarseSampleOrderCustomNV;
+        self
+    }
     #[doc = r" Calling build will **discard** all the lifetime information. Only call this if"]
     #[doc = r" necessary! Builders implement `Deref` targeting their corresponding Vulkan struct,"]
     #[doc = r" so references to builders can be passed directly to Vulkan functions."]
@@ -33459,6 +37135,10 @@ impl<'a> PipelineViewportCoarseSampleOrderStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMeshShaderFeaturesNV.html>"]
@@ -33526,6 +37206,23 @@ impl<'a> PhysicalDeviceMeshShaderFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMeshShaderFeaturesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV;
+}
+impl PhysicalDeviceMeshShaderFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.task_shader != FALSE && self.task_shader == FALSE {
+            missing.push("taskShader");
+        }
+        if required.mesh_shader != FALSE && self.mesh_shader == FALSE {
+            missing.push("meshShader");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMeshShaderPropertiesNV.html>"]
@@ -33692,6 +37389,26 @@ impl<'a> PhysicalDeviceMeshShaderPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMeshShaderPropertiesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV;
+}
+impl PhysicalDeviceMeshShaderPropertiesNV {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDrawMeshTasksIndirectCommandNV.html>"]
@@ -33830,6 +37547,8 @@ impl<'a> RayTracingShaderGroupCreateInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRayTracingShaderGroupCreateInfoNV>(
         mut self,
         next: &'a mut T,
@@ -33839,6 +37558,11 @@ impl<'a> RayTracingShaderGroupCreateInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkRayTracingShaderGroupCreateInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -33849,6 +37573,9 @@ impl<'a> RayTracingShaderGroupCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RayTracingShaderGroupCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRayTracingShaderGroupCreateInfoKHR.html>"]
@@ -33949,6 +37676,8 @@ impl<'a> RayTracingShaderGroupCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRayTracingShaderGroupCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -33958,6 +37687,11 @@ impl<'a> RayTracingShaderGroupCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkRayTracingShaderGroupCreateInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -33968,6 +37702,9 @@ impl<'a> RayTracingShaderGroupCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RayTracingShaderGroupCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRayTracingPipelineCreateInfoNV.html>"]
@@ -34042,6 +37779,15 @@ impl<'a> RayTracingPipelineCreateInfoNVBuilder<'a> {
         self.inner.p_stages = stages.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn stages_from_builders(
+        mut self,
+        stages: &'a [PipelineShaderStageCreateInfoBuilder<'a>],
+    ) -> RayTracingPipelineCreateInfoNVBuilder<'a> {
+        self.inner.stage_count = stages.len() as _;
+        self.inner.p_stages = stages.as_ptr() as *const PipelineShaderStageCreateInfo;
+        self
+    }
     pub fn groups(
         mut self,
         groups: &'a [RayTracingShaderGroupCreateInfoNV],
@@ -34050,6 +37796,15 @@ impl<'a> RayTracingPipelineCreateInfoNVBuilder<'a> {
         self.inner.p_groups = groups.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn groups_from_builders(
+        mut self,
+        groups: &'a [RayTracingShaderGroupCreateInfoNVBuilder<'a>],
+    ) -> RayTracingPipelineCreateInfoNVBuilder<'a> {
+        self.inner.group_count = groups.len() as _;
+        self.inner.p_groups = groups.as_ptr() as *const RayTracingShaderGroupCreateInfoNV;
+        self
+    }
     pub fn max_recursion_depth(
         mut self,
         max_recursion_depth: u32,
@@ -34080,6 +37835,8 @@ impl<'a> RayTracingPipelineCreateInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRayTracingPipelineCreateInfoNV>(
         mut self,
         next: &'a mut T,
@@ -34089,6 +37846,8 @@ impl<'a> RayTracingPipelineCreateInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkRayTracingPipelineCreateInfoNV", &mut self.inner);
         }
         self
     }
@@ -34099,6 +37858,9 @@ impl<'a> RayTracingPipelineCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RayTracingPipelineCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRayTracingPipelineCreateInfoKHR.html>"]
@@ -34179,6 +37941,15 @@ impl<'a> RayTracingPipelineCreateInfoKHRBuilder<'a> {
         self.inner.p_stages = stages.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn stages_from_builders(
+        mut self,
+        stages: &'a [PipelineShaderStageCreateInfoBuilder<'a>],
+    ) -> RayTracingPipelineCreateInfoKHRBuilder<'a> {
+        self.inner.stage_count = stages.len() as _;
+        self.inner.p_stages = stages.as_ptr() as *const PipelineShaderStageCreateInfo;
+        self
+    }
     pub fn groups(
         mut self,
         groups: &'a [RayTracingShaderGroupCreateInfoKHR],
@@ -34187,6 +37958,15 @@ impl<'a> RayTracingPipelineCreateInfoKHRBuilder<'a> {
         self.inner.p_groups = groups.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn groups_from_builders(
+        mut self,
+        groups: &'a [RayTracingShaderGroupCreateInfoKHRBuilder<'a>],
+    ) -> RayTracingPipelineCreateInfoKHRBuilder<'a> {
+        self.inner.group_count = groups.len() as _;
+        self.inner.p_groups = groups.as_ptr() as *const RayTracingShaderGroupCreateInfoKHR;
+        self
+    }
     pub fn max_pipeline_ray_recursion_depth(
         mut self,
         max_pipeline_ray_recursion_depth: u32,
@@ -34238,6 +38018,8 @@ impl<'a> RayTracingPipelineCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRayTracingPipelineCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -34247,6 +38029,8 @@ impl<'a> RayTracingPipelineCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkRayTracingPipelineCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -34257,6 +38041,9 @@ impl<'a> RayTracingPipelineCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RayTracingPipelineCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGeometryTrianglesNV.html>"]
@@ -34372,6 +38159,8 @@ impl<'a> GeometryTrianglesNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGeometryTrianglesNV>(
         mut self,
         next: &'a mut T,
@@ -34381,6 +38170,8 @@ impl<'a> GeometryTrianglesNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGeometryTrianglesNV", &mut self.inner);
         }
         self
     }
@@ -34391,6 +38182,9 @@ impl<'a> GeometryTrianglesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GeometryTrianglesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::GEOMETRY_TRIANGLES_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGeometryAABBNV.html>"]
@@ -34461,6 +38255,8 @@ impl<'a> GeometryAABBNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGeometryAABBNV>(
         mut self,
         next: &'a mut T,
@@ -34470,6 +38266,8 @@ impl<'a> GeometryAABBNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGeometryAABBNV", &mut self.inner);
         }
         self
     }
@@ -34480,6 +38278,9 @@ impl<'a> GeometryAABBNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GeometryAABBNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::GEOMETRY_AABB_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkGeometryDataNV.html>"]
@@ -34591,12 +38392,16 @@ impl<'a> GeometryNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsGeometryNV>(mut self, next: &'a mut T) -> GeometryNVBuilder<'a> {
         unsafe {
             let next_ptr = next as *mut T as *mut BaseOutStructure;
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkGeometryNV", &mut self.inner);
         }
         self
     }
@@ -34607,6 +38412,9 @@ impl<'a> GeometryNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for GeometryNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::GEOMETRY_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureInfoNV.html>"]
@@ -34681,11 +38489,22 @@ impl<'a> AccelerationStructureInfoNVBuilder<'a> {
         self.inner.p_geometries = geometries.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn geometries_from_builders(
+        mut self,
+        geometries: &'a [GeometryNVBuilder<'a>],
+    ) -> AccelerationStructureInfoNVBuilder<'a> {
+        self.inner.geometry_count = geometries.len() as _;
+        self.inner.p_geometries = geometries.as_ptr() as *const GeometryNV;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureInfoNV>(
         mut self,
         next: &'a mut T,
@@ -34695,6 +38514,8 @@ impl<'a> AccelerationStructureInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAccelerationStructureInfoNV", &mut self.inner);
         }
         self
     }
@@ -34705,6 +38526,9 @@ impl<'a> AccelerationStructureInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACCELERATION_STRUCTURE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureCreateInfoNV.html>"]
@@ -34769,6 +38593,8 @@ impl<'a> AccelerationStructureCreateInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureCreateInfoNV>(
         mut self,
         next: &'a mut T,
@@ -34778,6 +38604,11 @@ impl<'a> AccelerationStructureCreateInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureCreateInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -34788,6 +38619,9 @@ impl<'a> AccelerationStructureCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBindAccelerationStructureMemoryInfoNV.html>"]
@@ -34873,6 +38707,8 @@ impl<'a> BindAccelerationStructureMemoryInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBindAccelerationStructureMemoryInfoNV>(
         mut self,
         next: &'a mut T,
@@ -34882,6 +38718,11 @@ impl<'a> BindAccelerationStructureMemoryInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkBindAccelerationStructureMemoryInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -34892,6 +38733,9 @@ impl<'a> BindAccelerationStructureMemoryInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BindAccelerationStructureMemoryInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWriteDescriptorSetAccelerationStructureKHR.html>"]
@@ -34953,6 +38797,10 @@ impl<'a> WriteDescriptorSetAccelerationStructureKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for WriteDescriptorSetAccelerationStructureKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkWriteDescriptorSetAccelerationStructureNV.html>"]
@@ -35014,6 +38862,10 @@ impl<'a> WriteDescriptorSetAccelerationStructureNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for WriteDescriptorSetAccelerationStructureNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureMemoryRequirementsInfoNV.html>"]
@@ -35078,6 +38930,8 @@ impl<'a> AccelerationStructureMemoryRequirementsInfoNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureMemoryRequirementsInfoNV>(
         mut self,
         next: &'a mut T,
@@ -35087,6 +38941,11 @@ impl<'a> AccelerationStructureMemoryRequirementsInfoNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureMemoryRequirementsInfoNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -35097,6 +38956,10 @@ impl<'a> AccelerationStructureMemoryRequirementsInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureMemoryRequirementsInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceAccelerationStructureFeaturesKHR.html>"]
@@ -35196,6 +39059,41 @@ impl<'a> PhysicalDeviceAccelerationStructureFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceAccelerationStructureFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR;
+}
+impl PhysicalDeviceAccelerationStructureFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.acceleration_structure != FALSE && self.acceleration_structure == FALSE {
+            missing.push("accelerationStructure");
+        }
+        if required.acceleration_structure_capture_replay != FALSE
+            && self.acceleration_structure_capture_replay == FALSE
+        {
+            missing.push("accelerationStructureCaptureReplay");
+        }
+        if required.acceleration_structure_indirect_build != FALSE
+            && self.acceleration_structure_indirect_build == FALSE
+        {
+            missing.push("accelerationStructureIndirectBuild");
+        }
+        if required.acceleration_structure_host_commands != FALSE
+            && self.acceleration_structure_host_commands == FALSE
+        {
+            missing.push("accelerationStructureHostCommands");
+        }
+        if required.descriptor_binding_acceleration_structure_update_after_bind != FALSE
+            && self.descriptor_binding_acceleration_structure_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingAccelerationStructureUpdateAfterBind");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRayTracingPipelineFeaturesKHR.html>"]
@@ -35295,6 +39193,41 @@ impl<'a> PhysicalDeviceRayTracingPipelineFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRayTracingPipelineFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR;
+}
+impl PhysicalDeviceRayTracingPipelineFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.ray_tracing_pipeline != FALSE && self.ray_tracing_pipeline == FALSE {
+            missing.push("rayTracingPipeline");
+        }
+        if required.ray_tracing_pipeline_shader_group_handle_capture_replay != FALSE
+            && self.ray_tracing_pipeline_shader_group_handle_capture_replay == FALSE
+        {
+            missing.push("rayTracingPipelineShaderGroupHandleCaptureReplay");
+        }
+        if required.ray_tracing_pipeline_shader_group_handle_capture_replay_mixed != FALSE
+            && self.ray_tracing_pipeline_shader_group_handle_capture_replay_mixed == FALSE
+        {
+            missing.push("rayTracingPipelineShaderGroupHandleCaptureReplayMixed");
+        }
+        if required.ray_tracing_pipeline_trace_rays_indirect != FALSE
+            && self.ray_tracing_pipeline_trace_rays_indirect == FALSE
+        {
+            missing.push("rayTracingPipelineTraceRaysIndirect");
+        }
+        if required.ray_traversal_primitive_culling != FALSE
+            && self.ray_traversal_primitive_culling == FALSE
+        {
+            missing.push("rayTraversalPrimitiveCulling");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRayQueryFeaturesKHR.html>"]
@@ -35350,6 +39283,20 @@ impl<'a> PhysicalDeviceRayQueryFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRayQueryFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR;
+}
+impl PhysicalDeviceRayQueryFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.ray_query != FALSE && self.ray_query == FALSE {
+            missing.push("rayQuery");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceAccelerationStructurePropertiesKHR.html>"]
@@ -35482,6 +39429,20 @@ impl<'a> PhysicalDeviceAccelerationStructurePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceAccelerationStructurePropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR;
+}
+impl PhysicalDeviceAccelerationStructurePropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRayTracingPipelinePropertiesKHR.html>"]
@@ -35607,6 +39568,20 @@ impl<'a> PhysicalDeviceRayTracingPipelinePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRayTracingPipelinePropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR;
+}
+impl PhysicalDeviceRayTracingPipelinePropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRayTracingPropertiesNV.html>"]
@@ -35729,6 +39704,26 @@ impl<'a> PhysicalDeviceRayTracingPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRayTracingPropertiesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV;
+}
+impl PhysicalDeviceRayTracingPropertiesNV {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkStridedDeviceAddressRegionKHR.html>"]
@@ -35897,6 +39892,9 @@ impl<'a> DrmFormatModifierPropertiesListEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DrmFormatModifierPropertiesListEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDrmFormatModifierPropertiesEXT.html>"]
@@ -36040,6 +40038,10 @@ impl<'a> PhysicalDeviceImageDrmFormatModifierInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceImageDrmFormatModifierInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageDrmFormatModifierListCreateInfoEXT.html>"]
@@ -36101,6 +40103,10 @@ impl<'a> ImageDrmFormatModifierListCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageDrmFormatModifierListCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageDrmFormatModifierExplicitCreateInfoEXT.html>"]
@@ -36171,6 +40177,10 @@ impl<'a> ImageDrmFormatModifierExplicitCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageDrmFormatModifierExplicitCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageDrmFormatModifierPropertiesEXT.html>"]
@@ -36226,6 +40236,8 @@ impl<'a> ImageDrmFormatModifierPropertiesEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageDrmFormatModifierPropertiesEXT>(
         mut self,
         next: &'a mut T,
@@ -36235,6 +40247,11 @@ impl<'a> ImageDrmFormatModifierPropertiesEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkImageDrmFormatModifierPropertiesEXT",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -36245,6 +40262,9 @@ impl<'a> ImageDrmFormatModifierPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageDrmFormatModifierPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageStencilUsageCreateInfo.html>"]
@@ -36305,6 +40325,9 @@ impl<'a> ImageStencilUsageCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageStencilUsageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_STENCIL_USAGE_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceMemoryOverallocationCreateInfoAMD.html>"]
@@ -36363,6 +40386,10 @@ impl<'a> DeviceMemoryOverallocationCreateInfoAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceMemoryOverallocationCreateInfoAMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentDensityMapFeaturesEXT.html>"]
@@ -36440,6 +40467,31 @@ impl<'a> PhysicalDeviceFragmentDensityMapFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentDensityMapFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT;
+}
+impl PhysicalDeviceFragmentDensityMapFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.fragment_density_map != FALSE && self.fragment_density_map == FALSE {
+            missing.push("fragmentDensityMap");
+        }
+        if required.fragment_density_map_dynamic != FALSE
+            && self.fragment_density_map_dynamic == FALSE
+        {
+            missing.push("fragmentDensityMapDynamic");
+        }
+        if required.fragment_density_map_non_subsampled_images != FALSE
+            && self.fragment_density_map_non_subsampled_images == FALSE
+        {
+            missing.push("fragmentDensityMapNonSubsampledImages");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentDensityMap2FeaturesEXT.html>"]
@@ -36498,6 +40550,23 @@ impl<'a> PhysicalDeviceFragmentDensityMap2FeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentDensityMap2FeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT;
+}
+impl PhysicalDeviceFragmentDensityMap2FeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.fragment_density_map_deferred != FALSE
+            && self.fragment_density_map_deferred == FALSE
+        {
+            missing.push("fragmentDensityMapDeferred");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentDensityMapPropertiesEXT.html>"]
@@ -36577,6 +40646,20 @@ impl<'a> PhysicalDeviceFragmentDensityMapPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentDensityMapPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT;
+}
+impl PhysicalDeviceFragmentDensityMapPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentDensityMap2PropertiesEXT.html>"]
@@ -36666,6 +40749,20 @@ impl<'a> PhysicalDeviceFragmentDensityMap2PropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentDensityMap2PropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT;
+}
+impl PhysicalDeviceFragmentDensityMap2PropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassFragmentDensityMapCreateInfoEXT.html>"]
@@ -36726,6 +40823,10 @@ impl<'a> RenderPassFragmentDensityMapCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassFragmentDensityMapCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceScalarBlockLayoutFeatures.html>"]
@@ -36784,6 +40885,21 @@ impl<'a> PhysicalDeviceScalarBlockLayoutFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceScalarBlockLayoutFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES;
+}
+impl PhysicalDeviceScalarBlockLayoutFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.scalar_block_layout != FALSE && self.scalar_block_layout == FALSE {
+            missing.push("scalarBlockLayout");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceProtectedCapabilitiesKHR.html>"]
@@ -36842,6 +40958,9 @@ impl<'a> SurfaceProtectedCapabilitiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceProtectedCapabilitiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SURFACE_PROTECTED_CAPABILITIES_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceUniformBufferStandardLayoutFeatures.html>"]
@@ -36903,6 +41022,23 @@ impl<'a> PhysicalDeviceUniformBufferStandardLayoutFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceUniformBufferStandardLayoutFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES;
+}
+impl PhysicalDeviceUniformBufferStandardLayoutFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.uniform_buffer_standard_layout != FALSE
+            && self.uniform_buffer_standard_layout == FALSE
+        {
+            missing.push("uniformBufferStandardLayout");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDepthClipEnableFeaturesEXT.html>"]
@@ -36961,6 +41097,21 @@ impl<'a> PhysicalDeviceDepthClipEnableFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDepthClipEnableFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT;
+}
+impl PhysicalDeviceDepthClipEnableFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.depth_clip_enable != FALSE && self.depth_clip_enable == FALSE {
+            missing.push("depthClipEnable");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationDepthClipStateCreateInfoEXT.html>"]
@@ -37034,6 +41185,10 @@ impl<'a> PipelineRasterizationDepthClipStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationDepthClipStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMemoryBudgetPropertiesEXT.html>"]
@@ -37104,6 +41259,20 @@ impl<'a> PhysicalDeviceMemoryBudgetPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMemoryBudgetPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT;
+}
+impl PhysicalDeviceMemoryBudgetPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceMemoryPriorityFeaturesEXT.html>"]
@@ -37162,6 +41331,21 @@ impl<'a> PhysicalDeviceMemoryPriorityFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceMemoryPriorityFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT;
+}
+impl PhysicalDeviceMemoryPriorityFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.memory_priority != FALSE && self.memory_priority == FALSE {
+            missing.push("memoryPriority");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryPriorityAllocateInfoEXT.html>"]
@@ -37217,6 +41401,9 @@ impl<'a> MemoryPriorityAllocateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryPriorityAllocateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::MEMORY_PRIORITY_ALLOCATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceBufferDeviceAddressFeatures.html>"]
@@ -37294,6 +41481,31 @@ impl<'a> PhysicalDeviceBufferDeviceAddressFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceBufferDeviceAddressFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES;
+}
+impl PhysicalDeviceBufferDeviceAddressFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.buffer_device_address != FALSE && self.buffer_device_address == FALSE {
+            missing.push("bufferDeviceAddress");
+        }
+        if required.buffer_device_address_capture_replay != FALSE
+            && self.buffer_device_address_capture_replay == FALSE
+        {
+            missing.push("bufferDeviceAddressCaptureReplay");
+        }
+        if required.buffer_device_address_multi_device != FALSE
+            && self.buffer_device_address_multi_device == FALSE
+        {
+            missing.push("bufferDeviceAddressMultiDevice");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceBufferDeviceAddressFeaturesEXT.html>"]
@@ -37371,6 +41583,31 @@ impl<'a> PhysicalDeviceBufferDeviceAddressFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceBufferDeviceAddressFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT;
+}
+impl PhysicalDeviceBufferDeviceAddressFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.buffer_device_address != FALSE && self.buffer_device_address == FALSE {
+            missing.push("bufferDeviceAddress");
+        }
+        if required.buffer_device_address_capture_replay != FALSE
+            && self.buffer_device_address_capture_replay == FALSE
+        {
+            missing.push("bufferDeviceAddressCaptureReplay");
+        }
+        if required.buffer_device_address_multi_device != FALSE
+            && self.buffer_device_address_multi_device == FALSE
+        {
+            missing.push("bufferDeviceAddressMultiDevice");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferDeviceAddressInfo.html>"]
@@ -37423,6 +41660,8 @@ impl<'a> BufferDeviceAddressInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferDeviceAddressInfo>(
         mut self,
         next: &'a mut T,
@@ -37432,6 +41671,8 @@ impl<'a> BufferDeviceAddressInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferDeviceAddressInfo", &mut self.inner);
         }
         self
     }
@@ -37442,6 +41683,9 @@ impl<'a> BufferDeviceAddressInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferDeviceAddressInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_DEVICE_ADDRESS_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferOpaqueCaptureAddressCreateInfo.html>"]
@@ -37500,6 +41744,9 @@ impl<'a> BufferOpaqueCaptureAddressCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferOpaqueCaptureAddressCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferDeviceAddressCreateInfoEXT.html>"]
@@ -37558,6 +41805,9 @@ impl<'a> BufferDeviceAddressCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferDeviceAddressCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceImageViewImageFormatInfoEXT.html>"]
@@ -37619,6 +41869,10 @@ impl<'a> PhysicalDeviceImageViewImageFormatInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceImageViewImageFormatInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFilterCubicImageViewImageFormatPropertiesEXT.html>"]
@@ -37689,6 +41943,10 @@ impl<'a> FilterCubicImageViewImageFormatPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FilterCubicImageViewImageFormatPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceImagelessFramebufferFeatures.html>"]
@@ -37747,6 +42005,21 @@ impl<'a> PhysicalDeviceImagelessFramebufferFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceImagelessFramebufferFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES;
+}
+impl PhysicalDeviceImagelessFramebufferFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.imageless_framebuffer != FALSE && self.imageless_framebuffer == FALSE {
+            missing.push("imagelessFramebuffer");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFramebufferAttachmentsCreateInfo.html>"]
@@ -37801,6 +42074,16 @@ impl<'a> FramebufferAttachmentsCreateInfoBuilder<'a> {
         self.inner.p_attachment_image_infos = attachment_image_infos.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn attachment_image_infos_from_builders(
+        mut self,
+        attachment_image_infos: &'a [FramebufferAttachmentImageInfoBuilder<'a>],
+    ) -> FramebufferAttachmentsCreateInfoBuilder<'a> {
+        self.inner.attachment_image_info_count = attachment_image_infos.len() as _;
+        self.inner.p_attachment_image_infos =
+            attachment_image_infos.as_ptr() as *const FramebufferAttachmentImageInfo;
+        self
+    }
     #[doc = r" Calling build will **discard** all the lifetime information. Only call this if"]
     #[doc = r" necessary! Builders implement `Deref` targeting their corresponding Vulkan struct,"]
     #[doc = r" so references to builders can be passed directly to Vulkan functions."]
@@ -37808,6 +42091,9 @@ impl<'a> FramebufferAttachmentsCreateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FramebufferAttachmentsCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFramebufferAttachmentImageInfo.html>"]
@@ -37896,6 +42182,8 @@ impl<'a> FramebufferAttachmentImageInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFramebufferAttachmentImageInfo>(
         mut self,
         next: &'a mut T,
@@ -37905,6 +42193,8 @@ impl<'a> FramebufferAttachmentImageInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkFramebufferAttachmentImageInfo", &mut self.inner);
         }
         self
     }
@@ -37915,6 +42205,9 @@ impl<'a> FramebufferAttachmentImageInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FramebufferAttachmentImageInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassAttachmentBeginInfo.html>"]
@@ -37976,6 +42269,9 @@ impl<'a> RenderPassAttachmentBeginInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassAttachmentBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTextureCompressionASTCHDRFeaturesEXT.html>"]
@@ -38037,6 +42333,23 @@ impl<'a> PhysicalDeviceTextureCompressionASTCHDRFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTextureCompressionASTCHDRFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT;
+}
+impl PhysicalDeviceTextureCompressionASTCHDRFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.texture_compression_astc_hdr != FALSE
+            && self.texture_compression_astc_hdr == FALSE
+        {
+            missing.push("textureCompressionASTC_HDR");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCooperativeMatrixFeaturesNV.html>"]
@@ -38105,6 +42418,26 @@ impl<'a> PhysicalDeviceCooperativeMatrixFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCooperativeMatrixFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV;
+}
+impl PhysicalDeviceCooperativeMatrixFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.cooperative_matrix != FALSE && self.cooperative_matrix == FALSE {
+            missing.push("cooperativeMatrix");
+        }
+        if required.cooperative_matrix_robust_buffer_access != FALSE
+            && self.cooperative_matrix_robust_buffer_access == FALSE
+        {
+            missing.push("cooperativeMatrixRobustBufferAccess");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCooperativeMatrixPropertiesNV.html>"]
@@ -38166,6 +42499,20 @@ impl<'a> PhysicalDeviceCooperativeMatrixPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCooperativeMatrixPropertiesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV;
+}
+impl PhysicalDeviceCooperativeMatrixPropertiesNV {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCooperativeMatrixPropertiesNV.html>"]
@@ -38260,6 +42607,8 @@ impl<'a> CooperativeMatrixPropertiesNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCooperativeMatrixPropertiesNV>(
         mut self,
         next: &'a mut T,
@@ -38269,6 +42618,8 @@ impl<'a> CooperativeMatrixPropertiesNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCooperativeMatrixPropertiesNV", &mut self.inner);
         }
         self
     }
@@ -38279,6 +42630,9 @@ impl<'a> CooperativeMatrixPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CooperativeMatrixPropertiesNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::COOPERATIVE_MATRIX_PROPERTIES_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceYcbcrImageArraysFeaturesEXT.html>"]
@@ -38337,6 +42691,21 @@ impl<'a> PhysicalDeviceYcbcrImageArraysFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceYcbcrImageArraysFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT;
+}
+impl PhysicalDeviceYcbcrImageArraysFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.ycbcr_image_arrays != FALSE && self.ycbcr_image_arrays == FALSE {
+            missing.push("ycbcrImageArrays");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageViewHandleInfoNVX.html>"]
@@ -38404,6 +42773,8 @@ impl<'a> ImageViewHandleInfoNVXBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageViewHandleInfoNVX>(
         mut self,
         next: &'a mut T,
@@ -38413,6 +42784,8 @@ impl<'a> ImageViewHandleInfoNVXBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageViewHandleInfoNVX", &mut self.inner);
         }
         self
     }
@@ -38423,6 +42796,9 @@ impl<'a> ImageViewHandleInfoNVXBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageViewHandleInfoNVX {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_VIEW_HANDLE_INFO_NVX;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageViewAddressPropertiesNVX.html>"]
@@ -38484,6 +42860,8 @@ impl<'a> ImageViewAddressPropertiesNVXBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageViewAddressPropertiesNVX>(
         mut self,
         next: &'a mut T,
@@ -38493,6 +42871,8 @@ impl<'a> ImageViewAddressPropertiesNVXBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageViewAddressPropertiesNVX", &mut self.inner);
         }
         self
     }
@@ -38503,6 +42883,9 @@ impl<'a> ImageViewAddressPropertiesNVXBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageViewAddressPropertiesNVX {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPresentFrameTokenGGP.html>"]
@@ -38558,6 +42941,9 @@ impl<'a> PresentFrameTokenGGPBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PresentFrameTokenGGP {
+    const STRUCTURE_TYPE: StructureType = StructureType::PRESENT_FRAME_TOKEN_GGP;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCreationFeedbackEXT.html>"]
@@ -38692,6 +43078,9 @@ impl<'a> PipelineCreationFeedbackCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCreationFeedbackCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceFullScreenExclusiveInfoEXT.html>"]
@@ -38752,6 +43141,9 @@ impl<'a> SurfaceFullScreenExclusiveInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceFullScreenExclusiveInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceFullScreenExclusiveWin32InfoEXT.html>"]
@@ -38815,6 +43207,10 @@ impl<'a> SurfaceFullScreenExclusiveWin32InfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceFullScreenExclusiveWin32InfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSurfaceCapabilitiesFullScreenExclusiveEXT.html>"]
@@ -38876,6 +43272,10 @@ impl<'a> SurfaceCapabilitiesFullScreenExclusiveEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SurfaceCapabilitiesFullScreenExclusiveEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePerformanceQueryFeaturesKHR.html>"]
@@ -38944,6 +43344,28 @@ impl<'a> PhysicalDevicePerformanceQueryFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePerformanceQueryFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR;
+}
+impl PhysicalDevicePerformanceQueryFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.performance_counter_query_pools != FALSE
+            && self.performance_counter_query_pools == FALSE
+        {
+            missing.push("performanceCounterQueryPools");
+        }
+        if required.performance_counter_multiple_query_pools != FALSE
+            && self.performance_counter_multiple_query_pools == FALSE
+        {
+            missing.push("performanceCounterMultipleQueryPools");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePerformanceQueryPropertiesKHR.html>"]
@@ -39005,6 +43427,20 @@ impl<'a> PhysicalDevicePerformanceQueryPropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePerformanceQueryPropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR;
+}
+impl PhysicalDevicePerformanceQueryPropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceCounterKHR.html>"]
@@ -39078,6 +43514,8 @@ impl<'a> PerformanceCounterKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceCounterKHR>(
         mut self,
         next: &'a mut T,
@@ -39087,6 +43525,8 @@ impl<'a> PerformanceCounterKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPerformanceCounterKHR", &mut self.inner);
         }
         self
     }
@@ -39097,6 +43537,9 @@ impl<'a> PerformanceCounterKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceCounterKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_COUNTER_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceCounterDescriptionKHR.html>"]
@@ -39171,25 +43614,43 @@ impl<'a> PerformanceCounterDescriptionKHRBuilder<'a> {
         self.inner.flags = flags;
         self
     }
-    pub fn name(
-        mut self,
-        name: [c_char; MAX_DESCRIPTION_SIZE],
-    ) -> PerformanceCounterDescriptionKHRBuilder<'a> {
-        self.inner.name = name;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn name(mut self, name: &::std::ffi::CStr) -> PerformanceCounterDescriptionKHRBuilder<'a> {
+        let bytes = name.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.name.len(), "String is too long");
+        for (dst, &src) in self.inner.name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn category(
         mut self,
-        category: [c_char; MAX_DESCRIPTION_SIZE],
+        category: &::std::ffi::CStr,
     ) -> PerformanceCounterDescriptionKHRBuilder<'a> {
-        self.inner.category = category;
+        let bytes = category.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.category.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.category.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn description(
         mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
+        description: &::std::ffi::CStr,
     ) -> PerformanceCounterDescriptionKHRBuilder<'a> {
-        self.inner.description = description;
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
@@ -39197,6 +43658,8 @@ impl<'a> PerformanceCounterDescriptionKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceCounterDescriptionKHR>(
         mut self,
         next: &'a mut T,
@@ -39206,6 +43669,11 @@ impl<'a> PerformanceCounterDescriptionKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPerformanceCounterDescriptionKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -39216,6 +43684,53 @@ impl<'a> PerformanceCounterDescriptionKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceCounterDescriptionKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_COUNTER_DESCRIPTION_KHR;
+}
+impl PerformanceCounterDescriptionKHR {
+    #[doc = " Returns `name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.name.as_ptr() as *const u8, self.name.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `category` up to its nul terminator, an error if it doesn't have one."]
+    pub fn category_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.category.as_ptr() as *const u8, self.category.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueryPoolPerformanceCreateInfoKHR.html>"]
@@ -39286,6 +43801,9 @@ impl<'a> QueryPoolPerformanceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for QueryPoolPerformanceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceCounterResultKHR.html>"]
@@ -39363,6 +43881,8 @@ impl<'a> AcquireProfilingLockInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAcquireProfilingLockInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -39372,6 +43892,8 @@ impl<'a> AcquireProfilingLockInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkAcquireProfilingLockInfoKHR", &mut self.inner);
         }
         self
     }
@@ -39382,6 +43904,9 @@ impl<'a> AcquireProfilingLockInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AcquireProfilingLockInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACQUIRE_PROFILING_LOCK_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceQuerySubmitInfoKHR.html>"]
@@ -39440,6 +43965,9 @@ impl<'a> PerformanceQuerySubmitInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceQuerySubmitInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_QUERY_SUBMIT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkHeadlessSurfaceCreateInfoEXT.html>"]
@@ -39495,6 +44023,8 @@ impl<'a> HeadlessSurfaceCreateInfoEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsHeadlessSurfaceCreateInfoEXT>(
         mut self,
         next: &'a mut T,
@@ -39504,6 +44034,8 @@ impl<'a> HeadlessSurfaceCreateInfoEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkHeadlessSurfaceCreateInfoEXT", &mut self.inner);
         }
         self
     }
@@ -39514,6 +44046,9 @@ impl<'a> HeadlessSurfaceCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for HeadlessSurfaceCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::HEADLESS_SURFACE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCoverageReductionModeFeaturesNV.html>"]
@@ -39572,6 +44107,21 @@ impl<'a> PhysicalDeviceCoverageReductionModeFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCoverageReductionModeFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV;
+}
+impl PhysicalDeviceCoverageReductionModeFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.coverage_reduction_mode != FALSE && self.coverage_reduction_mode == FALSE {
+            missing.push("coverageReductionMode");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCoverageReductionStateCreateInfoNV.html>"]
@@ -39645,6 +44195,10 @@ impl<'a> PipelineCoverageReductionStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCoverageReductionStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFramebufferMixedSamplesCombinationNV.html>"]
@@ -39727,6 +44281,8 @@ impl<'a> FramebufferMixedSamplesCombinationNVBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsFramebufferMixedSamplesCombinationNV>(
         mut self,
         next: &'a mut T,
@@ -39736,6 +44292,11 @@ impl<'a> FramebufferMixedSamplesCombinationNVBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkFramebufferMixedSamplesCombinationNV",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -39746,6 +44307,9 @@ impl<'a> FramebufferMixedSamplesCombinationNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FramebufferMixedSamplesCombinationNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL.html>"]
@@ -39807,6 +44371,21 @@ impl<'a> PhysicalDeviceShaderIntegerFunctions2FeaturesINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL;
+}
+impl PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_integer_functions2 != FALSE && self.shader_integer_functions2 == FALSE {
+            missing.push("shaderIntegerFunctions2");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceValueDataINTEL.html>"]
@@ -39932,6 +44511,8 @@ impl<'a> InitializePerformanceApiInfoINTELBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsInitializePerformanceApiInfoINTEL>(
         mut self,
         next: &'a mut T,
@@ -39941,6 +44522,11 @@ impl<'a> InitializePerformanceApiInfoINTELBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkInitializePerformanceApiInfoINTEL",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -39951,6 +44537,9 @@ impl<'a> InitializePerformanceApiInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for InitializePerformanceApiInfoINTEL {
+    const STRUCTURE_TYPE: StructureType = StructureType::INITIALIZE_PERFORMANCE_API_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkQueryPoolPerformanceQueryCreateInfoINTEL.html>"]
@@ -40009,6 +44598,10 @@ impl<'a> QueryPoolPerformanceQueryCreateInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for QueryPoolPerformanceQueryCreateInfoINTEL {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceMarkerInfoINTEL.html>"]
@@ -40061,6 +44654,8 @@ impl<'a> PerformanceMarkerInfoINTELBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceMarkerInfoINTEL>(
         mut self,
         next: &'a mut T,
@@ -40070,6 +44665,8 @@ impl<'a> PerformanceMarkerInfoINTELBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPerformanceMarkerInfoINTEL", &mut self.inner);
         }
         self
     }
@@ -40080,6 +44677,9 @@ impl<'a> PerformanceMarkerInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceMarkerInfoINTEL {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_MARKER_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceStreamMarkerInfoINTEL.html>"]
@@ -40132,6 +44732,8 @@ impl<'a> PerformanceStreamMarkerInfoINTELBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceStreamMarkerInfoINTEL>(
         mut self,
         next: &'a mut T,
@@ -40141,6 +44743,11 @@ impl<'a> PerformanceStreamMarkerInfoINTELBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPerformanceStreamMarkerInfoINTEL",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -40151,6 +44758,9 @@ impl<'a> PerformanceStreamMarkerInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceStreamMarkerInfoINTEL {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_STREAM_MARKER_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceOverrideInfoINTEL.html>"]
@@ -40218,6 +44828,8 @@ impl<'a> PerformanceOverrideInfoINTELBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceOverrideInfoINTEL>(
         mut self,
         next: &'a mut T,
@@ -40227,6 +44839,8 @@ impl<'a> PerformanceOverrideInfoINTELBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPerformanceOverrideInfoINTEL", &mut self.inner);
         }
         self
     }
@@ -40237,6 +44851,9 @@ impl<'a> PerformanceOverrideInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceOverrideInfoINTEL {
+    const STRUCTURE_TYPE: StructureType = StructureType::PERFORMANCE_OVERRIDE_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPerformanceConfigurationAcquireInfoINTEL.html>"]
@@ -40292,6 +44909,8 @@ impl<'a> PerformanceConfigurationAcquireInfoINTELBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPerformanceConfigurationAcquireInfoINTEL>(
         mut self,
         next: &'a mut T,
@@ -40301,6 +44920,11 @@ impl<'a> PerformanceConfigurationAcquireInfoINTELBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPerformanceConfigurationAcquireInfoINTEL",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -40311,6 +44935,10 @@ impl<'a> PerformanceConfigurationAcquireInfoINTELBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PerformanceConfigurationAcquireInfoINTEL {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderClockFeaturesKHR.html>"]
@@ -40378,6 +45006,23 @@ impl<'a> PhysicalDeviceShaderClockFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderClockFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR;
+}
+impl PhysicalDeviceShaderClockFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_subgroup_clock != FALSE && self.shader_subgroup_clock == FALSE {
+            missing.push("shaderSubgroupClock");
+        }
+        if required.shader_device_clock != FALSE && self.shader_device_clock == FALSE {
+            missing.push("shaderDeviceClock");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceIndexTypeUint8FeaturesEXT.html>"]
@@ -40436,6 +45081,21 @@ impl<'a> PhysicalDeviceIndexTypeUint8FeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceIndexTypeUint8FeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT;
+}
+impl PhysicalDeviceIndexTypeUint8FeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.index_type_uint8 != FALSE && self.index_type_uint8 == FALSE {
+            missing.push("indexTypeUint8");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderSMBuiltinsPropertiesNV.html>"]
@@ -40506,6 +45166,20 @@ impl<'a> PhysicalDeviceShaderSMBuiltinsPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderSMBuiltinsPropertiesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV;
+}
+impl PhysicalDeviceShaderSMBuiltinsPropertiesNV {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderSMBuiltinsFeaturesNV.html>"]
@@ -40564,6 +45238,21 @@ impl<'a> PhysicalDeviceShaderSMBuiltinsFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderSMBuiltinsFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV;
+}
+impl PhysicalDeviceShaderSMBuiltinsFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_sm_builtins != FALSE && self.shader_sm_builtins == FALSE {
+            missing.push("shaderSMBuiltins");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT.html>"]
@@ -40644,6 +45333,33 @@ impl<'a> PhysicalDeviceFragmentShaderInterlockFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShaderInterlockFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT;
+}
+impl PhysicalDeviceFragmentShaderInterlockFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.fragment_shader_sample_interlock != FALSE
+            && self.fragment_shader_sample_interlock == FALSE
+        {
+            missing.push("fragmentShaderSampleInterlock");
+        }
+        if required.fragment_shader_pixel_interlock != FALSE
+            && self.fragment_shader_pixel_interlock == FALSE
+        {
+            missing.push("fragmentShaderPixelInterlock");
+        }
+        if required.fragment_shader_shading_rate_interlock != FALSE
+            && self.fragment_shader_shading_rate_interlock == FALSE
+        {
+            missing.push("fragmentShaderShadingRateInterlock");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures.html>"]
@@ -40705,6 +45421,23 @@ impl<'a> PhysicalDeviceSeparateDepthStencilLayoutsFeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSeparateDepthStencilLayoutsFeatures {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES;
+}
+impl PhysicalDeviceSeparateDepthStencilLayoutsFeatures {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.separate_depth_stencil_layouts != FALSE
+            && self.separate_depth_stencil_layouts == FALSE
+        {
+            missing.push("separateDepthStencilLayouts");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAttachmentReferenceStencilLayout.html>"]
@@ -40763,6 +45496,9 @@ impl<'a> AttachmentReferenceStencilLayoutBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AttachmentReferenceStencilLayout {
+    const STRUCTURE_TYPE: StructureType = StructureType::ATTACHMENT_REFERENCE_STENCIL_LAYOUT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAttachmentDescriptionStencilLayout.html>"]
@@ -40830,6 +45566,9 @@ impl<'a> AttachmentDescriptionStencilLayoutBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AttachmentDescriptionStencilLayout {
+    const STRUCTURE_TYPE: StructureType = StructureType::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR.html>"]
@@ -40891,6 +45630,21 @@ impl<'a> PhysicalDevicePipelineExecutablePropertiesFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePipelineExecutablePropertiesFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR;
+}
+impl PhysicalDevicePipelineExecutablePropertiesFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.pipeline_executable_info != FALSE && self.pipeline_executable_info == FALSE {
+            missing.push("pipelineExecutableInfo");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineInfoKHR.html>"]
@@ -40943,6 +45697,8 @@ impl<'a> PipelineInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -40952,6 +45708,8 @@ impl<'a> PipelineInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineInfoKHR", &mut self.inner);
         }
         self
     }
@@ -40962,6 +45720,9 @@ impl<'a> PipelineInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineExecutablePropertiesKHR.html>"]
@@ -41034,18 +45795,28 @@ impl<'a> PipelineExecutablePropertiesKHRBuilder<'a> {
         self.inner.stages = stages;
         self
     }
-    pub fn name(
-        mut self,
-        name: [c_char; MAX_DESCRIPTION_SIZE],
-    ) -> PipelineExecutablePropertiesKHRBuilder<'a> {
-        self.inner.name = name;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn name(mut self, name: &::std::ffi::CStr) -> PipelineExecutablePropertiesKHRBuilder<'a> {
+        let bytes = name.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.name.len(), "String is too long");
+        for (dst, &src) in self.inner.name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn description(
         mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
+        description: &::std::ffi::CStr,
     ) -> PipelineExecutablePropertiesKHRBuilder<'a> {
-        self.inner.description = description;
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn subgroup_size(
@@ -41060,6 +45831,8 @@ impl<'a> PipelineExecutablePropertiesKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineExecutablePropertiesKHR>(
         mut self,
         next: &'a mut T,
@@ -41069,6 +45842,8 @@ impl<'a> PipelineExecutablePropertiesKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineExecutablePropertiesKHR", &mut self.inner);
         }
         self
     }
@@ -41079,6 +45854,40 @@ impl<'a> PipelineExecutablePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineExecutablePropertiesKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_EXECUTABLE_PROPERTIES_KHR;
+}
+impl PipelineExecutablePropertiesKHR {
+    #[doc = " Returns `name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.name.as_ptr() as *const u8, self.name.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineExecutableInfoKHR.html>"]
@@ -41140,6 +45949,8 @@ impl<'a> PipelineExecutableInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineExecutableInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -41149,6 +45960,8 @@ impl<'a> PipelineExecutableInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineExecutableInfoKHR", &mut self.inner);
         }
         self
     }
@@ -41159,6 +45972,9 @@ impl<'a> PipelineExecutableInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineExecutableInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_EXECUTABLE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineExecutableStatisticValueKHR.html>"]
@@ -41238,18 +46054,28 @@ impl<'a> ::std::ops::DerefMut for PipelineExecutableStatisticKHRBuilder<'a> {
     }
 }
 impl<'a> PipelineExecutableStatisticKHRBuilder<'a> {
-    pub fn name(
-        mut self,
-        name: [c_char; MAX_DESCRIPTION_SIZE],
-    ) -> PipelineExecutableStatisticKHRBuilder<'a> {
-        self.inner.name = name;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn name(mut self, name: &::std::ffi::CStr) -> PipelineExecutableStatisticKHRBuilder<'a> {
+        let bytes = name.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.name.len(), "String is too long");
+        for (dst, &src) in self.inner.name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn description(
         mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
+        description: &::std::ffi::CStr,
     ) -> PipelineExecutableStatisticKHRBuilder<'a> {
-        self.inner.description = description;
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn format(
@@ -41271,6 +46097,8 @@ impl<'a> PipelineExecutableStatisticKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineExecutableStatisticKHR>(
         mut self,
         next: &'a mut T,
@@ -41280,6 +46108,8 @@ impl<'a> PipelineExecutableStatisticKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineExecutableStatisticKHR", &mut self.inner);
         }
         self
     }
@@ -41290,6 +46120,40 @@ impl<'a> PipelineExecutableStatisticKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineExecutableStatisticKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_EXECUTABLE_STATISTIC_KHR;
+}
+impl PipelineExecutableStatisticKHR {
+    #[doc = " Returns `name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.name.as_ptr() as *const u8, self.name.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineExecutableInternalRepresentationKHR.html>"]
@@ -41299,7 +46163,7 @@ pub struct PipelineExecutableInternalRepresentationKHR {
     pub name: [c_char; MAX_DESCRIPTION_SIZE],
     pub description: [c_char; MAX_DESCRIPTION_SIZE],
     pub is_text: Bool32,
-    pub data_size: usize,
+    pub data_size: size_t,
     pub p_data: *mut c_void,
 }
 impl fmt::Debug for PipelineExecutableInternalRepresentationKHR {
@@ -41327,7 +46191,7 @@ impl ::std::default::Default for PipelineExecutableInternalRepresentationKHR {
             name: unsafe { ::std::mem::zeroed() },
             description: unsafe { ::std::mem::zeroed() },
             is_text: Bool32::default(),
-            data_size: usize::default(),
+            data_size: size_t::default(),
             p_data: ::std::ptr::null_mut(),
         }
     }
@@ -41358,18 +46222,31 @@ impl<'a> ::std::ops::DerefMut for PipelineExecutableInternalRepresentationKHRBui
     }
 }
 impl<'a> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn name(
         mut self,
-        name: [c_char; MAX_DESCRIPTION_SIZE],
+        name: &::std::ffi::CStr,
     ) -> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
-        self.inner.name = name;
+        let bytes = name.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.name.len(), "String is too long");
+        for (dst, &src) in self.inner.name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn description(
         mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
+        description: &::std::ffi::CStr,
     ) -> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
-        self.inner.description = description;
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn is_text(
@@ -41392,6 +46269,8 @@ impl<'a> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineExecutableInternalRepresentationKHR>(
         mut self,
         next: &'a mut T,
@@ -41401,6 +46280,11 @@ impl<'a> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPipelineExecutableInternalRepresentationKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -41411,6 +46295,41 @@ impl<'a> PipelineExecutableInternalRepresentationKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineExecutableInternalRepresentationKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR;
+}
+impl PipelineExecutableInternalRepresentationKHR {
+    #[doc = " Returns `name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.name.as_ptr() as *const u8, self.name.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT.html>"]
@@ -41474,6 +46393,23 @@ impl<'a> PhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT;
+}
+impl PhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_demote_to_helper_invocation != FALSE
+            && self.shader_demote_to_helper_invocation == FALSE
+        {
+            missing.push("shaderDemoteToHelperInvocation");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT.html>"]
@@ -41532,6 +46468,21 @@ impl<'a> PhysicalDeviceTexelBufferAlignmentFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTexelBufferAlignmentFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT;
+}
+impl PhysicalDeviceTexelBufferAlignmentFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.texel_buffer_alignment != FALSE && self.texel_buffer_alignment == FALSE {
+            missing.push("texelBufferAlignment");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceTexelBufferAlignmentPropertiesEXT.html>"]
@@ -41626,6 +46577,20 @@ impl<'a> PhysicalDeviceTexelBufferAlignmentPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceTexelBufferAlignmentPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT;
+}
+impl PhysicalDeviceTexelBufferAlignmentPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlFeaturesEXT.html>"]
@@ -41693,6 +46658,24 @@ impl<'a> PhysicalDeviceSubgroupSizeControlFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSubgroupSizeControlFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT;
+}
+impl PhysicalDeviceSubgroupSizeControlFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.subgroup_size_control != FALSE && self.subgroup_size_control == FALSE {
+            missing.push("subgroupSizeControl");
+        }
+        if required.compute_full_subgroups != FALSE && self.compute_full_subgroups == FALSE {
+            missing.push("computeFullSubgroups");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlPropertiesEXT.html>"]
@@ -41781,6 +46764,20 @@ impl<'a> PhysicalDeviceSubgroupSizeControlPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceSubgroupSizeControlPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT;
+}
+impl PhysicalDeviceSubgroupSizeControlPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineShaderStageRequiredSubgroupSizeCreateInfoEXT.html>"]
@@ -41845,6 +46842,10 @@ impl<'a> PipelineShaderStageRequiredSubgroupSizeCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineShaderStageRequiredSubgroupSizeCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkMemoryOpaqueCaptureAddressAllocateInfo.html>"]
@@ -41903,6 +46904,10 @@ impl<'a> MemoryOpaqueCaptureAddressAllocateInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for MemoryOpaqueCaptureAddressAllocateInfo {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceMemoryOpaqueCaptureAddressInfo.html>"]
@@ -41958,6 +46963,8 @@ impl<'a> DeviceMemoryOpaqueCaptureAddressInfoBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsDeviceMemoryOpaqueCaptureAddressInfo>(
         mut self,
         next: &'a mut T,
@@ -41967,6 +46974,11 @@ impl<'a> DeviceMemoryOpaqueCaptureAddressInfoBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkDeviceMemoryOpaqueCaptureAddressInfo",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -41977,6 +46989,9 @@ impl<'a> DeviceMemoryOpaqueCaptureAddressInfoBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceMemoryOpaqueCaptureAddressInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceLineRasterizationFeaturesEXT.html>"]
@@ -42080,6 +47095,37 @@ impl<'a> PhysicalDeviceLineRasterizationFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceLineRasterizationFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT;
+}
+impl PhysicalDeviceLineRasterizationFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.rectangular_lines != FALSE && self.rectangular_lines == FALSE {
+            missing.push("rectangularLines");
+        }
+        if required.bresenham_lines != FALSE && self.bresenham_lines == FALSE {
+            missing.push("bresenhamLines");
+        }
+        if required.smooth_lines != FALSE && self.smooth_lines == FALSE {
+            missing.push("smoothLines");
+        }
+        if required.stippled_rectangular_lines != FALSE && self.stippled_rectangular_lines == FALSE
+        {
+            missing.push("stippledRectangularLines");
+        }
+        if required.stippled_bresenham_lines != FALSE && self.stippled_bresenham_lines == FALSE {
+            missing.push("stippledBresenhamLines");
+        }
+        if required.stippled_smooth_lines != FALSE && self.stippled_smooth_lines == FALSE {
+            missing.push("stippledSmoothLines");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceLineRasterizationPropertiesEXT.html>"]
@@ -42141,6 +47187,20 @@ impl<'a> PhysicalDeviceLineRasterizationPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceLineRasterizationPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT;
+}
+impl PhysicalDeviceLineRasterizationPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineRasterizationLineStateCreateInfoEXT.html>"]
@@ -42232,6 +47292,10 @@ impl<'a> PipelineRasterizationLineStateCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineRasterizationLineStateCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePipelineCreationCacheControlFeaturesEXT.html>"]
@@ -42293,6 +47357,23 @@ impl<'a> PhysicalDevicePipelineCreationCacheControlFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePipelineCreationCacheControlFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT;
+}
+impl PhysicalDevicePipelineCreationCacheControlFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.pipeline_creation_cache_control != FALSE
+            && self.pipeline_creation_cache_control == FALSE
+        {
+            missing.push("pipelineCreationCacheControl");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVulkan11Features.html>"]
@@ -42448,6 +47529,61 @@ impl<'a> PhysicalDeviceVulkan11FeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVulkan11Features {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES;
+}
+impl PhysicalDeviceVulkan11Features {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.storage_buffer16_bit_access != FALSE
+            && self.storage_buffer16_bit_access == FALSE
+        {
+            missing.push("storageBuffer16BitAccess");
+        }
+        if required.uniform_and_storage_buffer16_bit_access != FALSE
+            && self.uniform_and_storage_buffer16_bit_access == FALSE
+        {
+            missing.push("uniformAndStorageBuffer16BitAccess");
+        }
+        if required.storage_push_constant16 != FALSE && self.storage_push_constant16 == FALSE {
+            missing.push("storagePushConstant16");
+        }
+        if required.storage_input_output16 != FALSE && self.storage_input_output16 == FALSE {
+            missing.push("storageInputOutput16");
+        }
+        if required.multiview != FALSE && self.multiview == FALSE {
+            missing.push("multiview");
+        }
+        if required.multiview_geometry_shader != FALSE && self.multiview_geometry_shader == FALSE {
+            missing.push("multiviewGeometryShader");
+        }
+        if required.multiview_tessellation_shader != FALSE
+            && self.multiview_tessellation_shader == FALSE
+        {
+            missing.push("multiviewTessellationShader");
+        }
+        if required.variable_pointers_storage_buffer != FALSE
+            && self.variable_pointers_storage_buffer == FALSE
+        {
+            missing.push("variablePointersStorageBuffer");
+        }
+        if required.variable_pointers != FALSE && self.variable_pointers == FALSE {
+            missing.push("variablePointers");
+        }
+        if required.protected_memory != FALSE && self.protected_memory == FALSE {
+            missing.push("protectedMemory");
+        }
+        if required.sampler_ycbcr_conversion != FALSE && self.sampler_ycbcr_conversion == FALSE {
+            missing.push("samplerYcbcrConversion");
+        }
+        if required.shader_draw_parameters != FALSE && self.shader_draw_parameters == FALSE {
+            missing.push("shaderDrawParameters");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVulkan11Properties.html>"]
@@ -42633,6 +47769,26 @@ impl<'a> PhysicalDeviceVulkan11PropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVulkan11Properties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES;
+}
+impl PhysicalDeviceVulkan11Properties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES;
+        }
+        value
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVulkan12Features.html>"]
@@ -43135,6 +48291,223 @@ impl<'a> PhysicalDeviceVulkan12FeaturesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVulkan12Features {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES;
+}
+impl PhysicalDeviceVulkan12Features {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.sampler_mirror_clamp_to_edge != FALSE
+            && self.sampler_mirror_clamp_to_edge == FALSE
+        {
+            missing.push("samplerMirrorClampToEdge");
+        }
+        if required.draw_indirect_count != FALSE && self.draw_indirect_count == FALSE {
+            missing.push("drawIndirectCount");
+        }
+        if required.storage_buffer8_bit_access != FALSE && self.storage_buffer8_bit_access == FALSE
+        {
+            missing.push("storageBuffer8BitAccess");
+        }
+        if required.uniform_and_storage_buffer8_bit_access != FALSE
+            && self.uniform_and_storage_buffer8_bit_access == FALSE
+        {
+            missing.push("uniformAndStorageBuffer8BitAccess");
+        }
+        if required.storage_push_constant8 != FALSE && self.storage_push_constant8 == FALSE {
+            missing.push("storagePushConstant8");
+        }
+        if required.shader_buffer_int64_atomics != FALSE
+            && self.shader_buffer_int64_atomics == FALSE
+        {
+            missing.push("shaderBufferInt64Atomics");
+        }
+        if required.shader_shared_int64_atomics != FALSE
+            && self.shader_shared_int64_atomics == FALSE
+        {
+            missing.push("shaderSharedInt64Atomics");
+        }
+        if required.shader_float16 != FALSE && self.shader_float16 == FALSE {
+            missing.push("shaderFloat16");
+        }
+        if required.shader_int8 != FALSE && self.shader_int8 == FALSE {
+            missing.push("shaderInt8");
+        }
+        if required.descriptor_indexing != FALSE && self.descriptor_indexing == FALSE {
+            missing.push("descriptorIndexing");
+        }
+        if required.shader_input_attachment_array_dynamic_indexing != FALSE
+            && self.shader_input_attachment_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderInputAttachmentArrayDynamicIndexing");
+        }
+        if required.shader_uniform_texel_buffer_array_dynamic_indexing != FALSE
+            && self.shader_uniform_texel_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderUniformTexelBufferArrayDynamicIndexing");
+        }
+        if required.shader_storage_texel_buffer_array_dynamic_indexing != FALSE
+            && self.shader_storage_texel_buffer_array_dynamic_indexing == FALSE
+        {
+            missing.push("shaderStorageTexelBufferArrayDynamicIndexing");
+        }
+        if required.shader_uniform_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_uniform_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderUniformBufferArrayNonUniformIndexing");
+        }
+        if required.shader_sampled_image_array_non_uniform_indexing != FALSE
+            && self.shader_sampled_image_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderSampledImageArrayNonUniformIndexing");
+        }
+        if required.shader_storage_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_storage_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageBufferArrayNonUniformIndexing");
+        }
+        if required.shader_storage_image_array_non_uniform_indexing != FALSE
+            && self.shader_storage_image_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageImageArrayNonUniformIndexing");
+        }
+        if required.shader_input_attachment_array_non_uniform_indexing != FALSE
+            && self.shader_input_attachment_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderInputAttachmentArrayNonUniformIndexing");
+        }
+        if required.shader_uniform_texel_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_uniform_texel_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderUniformTexelBufferArrayNonUniformIndexing");
+        }
+        if required.shader_storage_texel_buffer_array_non_uniform_indexing != FALSE
+            && self.shader_storage_texel_buffer_array_non_uniform_indexing == FALSE
+        {
+            missing.push("shaderStorageTexelBufferArrayNonUniformIndexing");
+        }
+        if required.descriptor_binding_uniform_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_uniform_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingUniformBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_sampled_image_update_after_bind != FALSE
+            && self.descriptor_binding_sampled_image_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingSampledImageUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_image_update_after_bind != FALSE
+            && self.descriptor_binding_storage_image_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageImageUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_storage_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_uniform_texel_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_uniform_texel_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingUniformTexelBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_storage_texel_buffer_update_after_bind != FALSE
+            && self.descriptor_binding_storage_texel_buffer_update_after_bind == FALSE
+        {
+            missing.push("descriptorBindingStorageTexelBufferUpdateAfterBind");
+        }
+        if required.descriptor_binding_update_unused_while_pending != FALSE
+            && self.descriptor_binding_update_unused_while_pending == FALSE
+        {
+            missing.push("descriptorBindingUpdateUnusedWhilePending");
+        }
+        if required.descriptor_binding_partially_bound != FALSE
+            && self.descriptor_binding_partially_bound == FALSE
+        {
+            missing.push("descriptorBindingPartiallyBound");
+        }
+        if required.descriptor_binding_variable_descriptor_count != FALSE
+            && self.descriptor_binding_variable_descriptor_count == FALSE
+        {
+            missing.push("descriptorBindingVariableDescriptorCount");
+        }
+        if required.runtime_descriptor_array != FALSE && self.runtime_descriptor_array == FALSE {
+            missing.push("runtimeDescriptorArray");
+        }
+        if required.sampler_filter_minmax != FALSE && self.sampler_filter_minmax == FALSE {
+            missing.push("samplerFilterMinmax");
+        }
+        if required.scalar_block_layout != FALSE && self.scalar_block_layout == FALSE {
+            missing.push("scalarBlockLayout");
+        }
+        if required.imageless_framebuffer != FALSE && self.imageless_framebuffer == FALSE {
+            missing.push("imagelessFramebuffer");
+        }
+        if required.uniform_buffer_standard_layout != FALSE
+            && self.uniform_buffer_standard_layout == FALSE
+        {
+            missing.push("uniformBufferStandardLayout");
+        }
+        if required.shader_subgroup_extended_types != FALSE
+            && self.shader_subgroup_extended_types == FALSE
+        {
+            missing.push("shaderSubgroupExtendedTypes");
+        }
+        if required.separate_depth_stencil_layouts != FALSE
+            && self.separate_depth_stencil_layouts == FALSE
+        {
+            missing.push("separateDepthStencilLayouts");
+        }
+        if required.host_query_reset != FALSE && self.host_query_reset == FALSE {
+            missing.push("hostQueryReset");
+        }
+        if required.timeline_semaphore != FALSE && self.timeline_semaphore == FALSE {
+            missing.push("timelineSemaphore");
+        }
+        if required.buffer_device_address != FALSE && self.buffer_device_address == FALSE {
+            missing.push("bufferDeviceAddress");
+        }
+        if required.buffer_device_address_capture_replay != FALSE
+            && self.buffer_device_address_capture_replay == FALSE
+        {
+            missing.push("bufferDeviceAddressCaptureReplay");
+        }
+        if required.buffer_device_address_multi_device != FALSE
+            && self.buffer_device_address_multi_device == FALSE
+        {
+            missing.push("bufferDeviceAddressMultiDevice");
+        }
+        if required.vulkan_memory_model != FALSE && self.vulkan_memory_model == FALSE {
+            missing.push("vulkanMemoryModel");
+        }
+        if required.vulkan_memory_model_device_scope != FALSE
+            && self.vulkan_memory_model_device_scope == FALSE
+        {
+            missing.push("vulkanMemoryModelDeviceScope");
+        }
+        if required.vulkan_memory_model_availability_visibility_chains != FALSE
+            && self.vulkan_memory_model_availability_visibility_chains == FALSE
+        {
+            missing.push("vulkanMemoryModelAvailabilityVisibilityChains");
+        }
+        if required.shader_output_viewport_index != FALSE
+            && self.shader_output_viewport_index == FALSE
+        {
+            missing.push("shaderOutputViewportIndex");
+        }
+        if required.shader_output_layer != FALSE && self.shader_output_layer == FALSE {
+            missing.push("shaderOutputLayer");
+        }
+        if required.subgroup_broadcast_dynamic_id != FALSE
+            && self.subgroup_broadcast_dynamic_id == FALSE
+        {
+            missing.push("subgroupBroadcastDynamicId");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceVulkan12Properties.html>"]
@@ -43487,18 +48860,34 @@ impl<'a> PhysicalDeviceVulkan12PropertiesBuilder<'a> {
         self.inner.driver_id = driver_id;
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn driver_name(
         mut self,
-        driver_name: [c_char; MAX_DRIVER_NAME_SIZE],
+        driver_name: &::std::ffi::CStr,
     ) -> PhysicalDeviceVulkan12PropertiesBuilder<'a> {
-        self.inner.driver_name = driver_name;
+        let bytes = driver_name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.driver_name.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.driver_name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn driver_info(
         mut self,
-        driver_info: [c_char; MAX_DRIVER_INFO_SIZE],
+        driver_info: &::std::ffi::CStr,
     ) -> PhysicalDeviceVulkan12PropertiesBuilder<'a> {
-        self.inner.driver_info = driver_info;
+        let bytes = driver_info.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.driver_info.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.driver_info.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn conformance_version(
@@ -43898,6 +49287,60 @@ impl<'a> PhysicalDeviceVulkan12PropertiesBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceVulkan12Properties {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES;
+}
+impl PhysicalDeviceVulkan12Properties {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES;
+        }
+        value
+    }
+}
+impl PhysicalDeviceVulkan12Properties {
+    #[doc = " Returns `driver_name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn driver_name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.driver_name.as_ptr() as *const u8,
+                self.driver_name.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `driver_info` up to its nul terminator, an error if it doesn't have one."]
+    pub fn driver_info_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.driver_info.as_ptr() as *const u8,
+                self.driver_info.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineCompilerControlCreateInfoAMD.html>"]
@@ -43958,6 +49401,9 @@ impl<'a> PipelineCompilerControlCreateInfoAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineCompilerControlCreateInfoAMD {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCoherentMemoryFeaturesAMD.html>"]
@@ -44016,6 +49462,21 @@ impl<'a> PhysicalDeviceCoherentMemoryFeaturesAMDBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCoherentMemoryFeaturesAMD {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD;
+}
+impl PhysicalDeviceCoherentMemoryFeaturesAMD {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.device_coherent_memory != FALSE && self.device_coherent_memory == FALSE {
+            missing.push("deviceCoherentMemory");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceToolPropertiesEXT.html>"]
@@ -44088,18 +49549,28 @@ impl<'a> ::std::ops::DerefMut for PhysicalDeviceToolPropertiesEXTBuilder<'a> {
     }
 }
 impl<'a> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
-    pub fn name(
-        mut self,
-        name: [c_char; MAX_EXTENSION_NAME_SIZE],
-    ) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
-        self.inner.name = name;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn name(mut self, name: &::std::ffi::CStr) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
+        let bytes = name.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.name.len(), "String is too long");
+        for (dst, &src) in self.inner.name.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn version(
         mut self,
-        version: [c_char; MAX_EXTENSION_NAME_SIZE],
+        version: &::std::ffi::CStr,
     ) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
-        self.inner.version = version;
+        let bytes = version.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.version.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.version.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     pub fn purposes(
@@ -44109,18 +49580,28 @@ impl<'a> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
         self.inner.purposes = purposes;
         self
     }
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
     pub fn description(
         mut self,
-        description: [c_char; MAX_DESCRIPTION_SIZE],
+        description: &::std::ffi::CStr,
     ) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
-        self.inner.description = description;
+        let bytes = description.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= self.inner.description.len(),
+            "String is too long"
+        );
+        for (dst, &src) in self.inner.description.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
-    pub fn layer(
-        mut self,
-        layer: [c_char; MAX_EXTENSION_NAME_SIZE],
-    ) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
-        self.inner.layer = layer;
+    #[doc = r" Copies the string including its nul terminator, panics if it doesn't fit."]
+    pub fn layer(mut self, layer: &::std::ffi::CStr) -> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
+        let bytes = layer.to_bytes_with_nul();
+        assert!(bytes.len() <= self.inner.layer.len(), "String is too long");
+        for (dst, &src) in self.inner.layer.iter_mut().zip(bytes) {
+            *dst = src as c_char;
+        }
         self
     }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
@@ -44128,6 +49609,8 @@ impl<'a> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceToolPropertiesEXT>(
         mut self,
         next: &'a mut T,
@@ -44137,6 +49620,8 @@ impl<'a> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPhysicalDeviceToolPropertiesEXT", &mut self.inner);
         }
         self
     }
@@ -44147,6 +49632,83 @@ impl<'a> PhysicalDeviceToolPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceToolPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT;
+}
+impl PhysicalDeviceToolPropertiesEXT {
+    #[doc = r" Returns this struct with `s_type` set and the other members zeroed."]
+    pub const fn zeroed() -> Self {
+        let mut value: Self = unsafe { ::std::mem::zeroed() };
+        value.s_type = StructureType::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT;
+        value
+    }
+    #[doc = r" Returns storage for the driver to write this struct to, with `s_type` set and"]
+    #[doc = r" the other members zeroed."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            (*value.as_mut_ptr()).s_type = StructureType::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT;
+        }
+        value
+    }
+}
+impl PhysicalDeviceToolPropertiesEXT {
+    #[doc = " Returns `name` up to its nul terminator, an error if it doesn't have one."]
+    pub fn name_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.name.as_ptr() as *const u8, self.name.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `version` up to its nul terminator, an error if it doesn't have one."]
+    pub fn version_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.version.as_ptr() as *const u8, self.version.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `description` up to its nul terminator, an error if it doesn't have one."]
+    pub fn description_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(
+                self.description.as_ptr() as *const u8,
+                self.description.len(),
+            )
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+    #[doc = " Returns `layer` up to its nul terminator, an error if it doesn't have one."]
+    pub fn layer_as_c_str(
+        &self,
+    ) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+        let bytes = unsafe {
+            ::std::slice::from_raw_parts(self.layer.as_ptr() as *const u8, self.layer.len())
+        };
+        let len = bytes
+            .iter()
+            .position(|&b| b == 0)
+            .map_or(bytes.len(), |nul| nul + 1);
+        ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkSamplerCustomBorderColorCreateInfoEXT.html>"]
@@ -44221,6 +49783,10 @@ impl<'a> SamplerCustomBorderColorCreateInfoEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for SamplerCustomBorderColorCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCustomBorderColorPropertiesEXT.html>"]
@@ -44282,6 +49848,20 @@ impl<'a> PhysicalDeviceCustomBorderColorPropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCustomBorderColorPropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT;
+}
+impl PhysicalDeviceCustomBorderColorPropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceCustomBorderColorFeaturesEXT.html>"]
@@ -44349,6 +49929,26 @@ impl<'a> PhysicalDeviceCustomBorderColorFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceCustomBorderColorFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT;
+}
+impl PhysicalDeviceCustomBorderColorFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.custom_border_colors != FALSE && self.custom_border_colors == FALSE {
+            missing.push("customBorderColors");
+        }
+        if required.custom_border_color_without_format != FALSE
+            && self.custom_border_color_without_format == FALSE
+        {
+            missing.push("customBorderColorWithoutFormat");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceOrHostAddressKHR.html>"]
@@ -44497,6 +50097,8 @@ impl<'a> AccelerationStructureGeometryTrianglesDataKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureGeometryTrianglesDataKHR>(
         mut self,
         next: &'a mut T,
@@ -44506,6 +50108,11 @@ impl<'a> AccelerationStructureGeometryTrianglesDataKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureGeometryTrianglesDataKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -44516,6 +50123,10 @@ impl<'a> AccelerationStructureGeometryTrianglesDataKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureGeometryTrianglesDataKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureGeometryAabbsDataKHR.html>"]
@@ -44590,6 +50201,8 @@ impl<'a> AccelerationStructureGeometryAabbsDataKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureGeometryAabbsDataKHR>(
         mut self,
         next: &'a mut T,
@@ -44599,6 +50212,11 @@ impl<'a> AccelerationStructureGeometryAabbsDataKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureGeometryAabbsDataKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -44609,6 +50227,10 @@ impl<'a> AccelerationStructureGeometryAabbsDataKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureGeometryAabbsDataKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureGeometryInstancesDataKHR.html>"]
@@ -44683,6 +50305,8 @@ impl<'a> AccelerationStructureGeometryInstancesDataKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureGeometryInstancesDataKHR>(
         mut self,
         next: &'a mut T,
@@ -44692,6 +50316,11 @@ impl<'a> AccelerationStructureGeometryInstancesDataKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureGeometryInstancesDataKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -44702,6 +50331,10 @@ impl<'a> AccelerationStructureGeometryInstancesDataKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureGeometryInstancesDataKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureGeometryDataKHR.html>"]
@@ -44796,6 +50429,8 @@ impl<'a> AccelerationStructureGeometryKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureGeometryKHR>(
         mut self,
         next: &'a mut T,
@@ -44805,6 +50440,11 @@ impl<'a> AccelerationStructureGeometryKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureGeometryKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -44815,6 +50455,9 @@ impl<'a> AccelerationStructureGeometryKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureGeometryKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACCELERATION_STRUCTURE_GEOMETRY_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureBuildGeometryInfoKHR.html>"]
@@ -44940,6 +50583,15 @@ impl<'a> AccelerationStructureBuildGeometryInfoKHRBuilder<'a> {
         self.inner.p_geometries = geometries.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn geometries_from_builders(
+        mut self,
+        geometries: &'a [AccelerationStructureGeometryKHRBuilder<'a>],
+    ) -> AccelerationStructureBuildGeometryInfoKHRBuilder<'a> {
+        self.inner.geometry_count = geometries.len() as _;
+        self.inner.p_geometries = geometries.as_ptr() as *const AccelerationStructureGeometryKHR;
+        self
+    }
     pub fn geometries_ptrs(
         mut self,
         geometries: &'a [*const AccelerationStructureGeometryKHR],
@@ -44960,6 +50612,8 @@ impl<'a> AccelerationStructureBuildGeometryInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureBuildGeometryInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -44969,6 +50623,11 @@ impl<'a> AccelerationStructureBuildGeometryInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureBuildGeometryInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -44979,6 +50638,10 @@ impl<'a> AccelerationStructureBuildGeometryInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureBuildGeometryInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureBuildRangeInfoKHR.html>"]
@@ -45139,6 +50802,8 @@ impl<'a> AccelerationStructureCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45148,6 +50813,11 @@ impl<'a> AccelerationStructureCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureCreateInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45158,6 +50828,9 @@ impl<'a> AccelerationStructureCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAabbPositionsKHR.html>"]
@@ -45299,6 +50972,8 @@ impl<'a> AccelerationStructureDeviceAddressInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureDeviceAddressInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45308,6 +50983,11 @@ impl<'a> AccelerationStructureDeviceAddressInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureDeviceAddressInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45318,6 +50998,10 @@ impl<'a> AccelerationStructureDeviceAddressInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureDeviceAddressInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureVersionInfoKHR.html>"]
@@ -45373,6 +51057,8 @@ impl<'a> AccelerationStructureVersionInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureVersionInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45382,6 +51068,11 @@ impl<'a> AccelerationStructureVersionInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureVersionInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45392,6 +51083,9 @@ impl<'a> AccelerationStructureVersionInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureVersionInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::ACCELERATION_STRUCTURE_VERSION_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyAccelerationStructureInfoKHR.html>"]
@@ -45465,6 +51159,8 @@ impl<'a> CopyAccelerationStructureInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyAccelerationStructureInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45474,6 +51170,11 @@ impl<'a> CopyAccelerationStructureInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkCopyAccelerationStructureInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45484,6 +51185,9 @@ impl<'a> CopyAccelerationStructureInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyAccelerationStructureInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_ACCELERATION_STRUCTURE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyAccelerationStructureToMemoryInfoKHR.html>"]
@@ -45568,6 +51272,8 @@ impl<'a> CopyAccelerationStructureToMemoryInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyAccelerationStructureToMemoryInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45577,6 +51283,11 @@ impl<'a> CopyAccelerationStructureToMemoryInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkCopyAccelerationStructureToMemoryInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45587,6 +51298,10 @@ impl<'a> CopyAccelerationStructureToMemoryInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyAccelerationStructureToMemoryInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyMemoryToAccelerationStructureInfoKHR.html>"]
@@ -45671,6 +51386,8 @@ impl<'a> CopyMemoryToAccelerationStructureInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyMemoryToAccelerationStructureInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45680,6 +51397,11 @@ impl<'a> CopyMemoryToAccelerationStructureInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkCopyMemoryToAccelerationStructureInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45690,6 +51412,10 @@ impl<'a> CopyMemoryToAccelerationStructureInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyMemoryToAccelerationStructureInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRayTracingPipelineInterfaceCreateInfoKHR.html>"]
@@ -45754,6 +51480,8 @@ impl<'a> RayTracingPipelineInterfaceCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsRayTracingPipelineInterfaceCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45763,6 +51491,11 @@ impl<'a> RayTracingPipelineInterfaceCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkRayTracingPipelineInterfaceCreateInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -45773,6 +51506,10 @@ impl<'a> RayTracingPipelineInterfaceCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RayTracingPipelineInterfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineLibraryCreateInfoKHR.html>"]
@@ -45831,6 +51568,8 @@ impl<'a> PipelineLibraryCreateInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPipelineLibraryCreateInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -45840,6 +51579,8 @@ impl<'a> PipelineLibraryCreateInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkPipelineLibraryCreateInfoKHR", &mut self.inner);
         }
         self
     }
@@ -45850,6 +51591,9 @@ impl<'a> PipelineLibraryCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineLibraryCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PIPELINE_LIBRARY_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceExtendedDynamicStateFeaturesEXT.html>"]
@@ -45908,6 +51652,21 @@ impl<'a> PhysicalDeviceExtendedDynamicStateFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceExtendedDynamicStateFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT;
+}
+impl PhysicalDeviceExtendedDynamicStateFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.extended_dynamic_state != FALSE && self.extended_dynamic_state == FALSE {
+            missing.push("extendedDynamicState");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkRenderPassTransformBeginInfoQCOM.html>"]
@@ -45966,6 +51725,9 @@ impl<'a> RenderPassTransformBeginInfoQCOMBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for RenderPassTransformBeginInfoQCOM {
+    const STRUCTURE_TYPE: StructureType = StructureType::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyCommandTransformInfoQCOM.html>"]
@@ -46026,6 +51788,9 @@ impl<'a> CopyCommandTransformInfoQCOMBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyCommandTransformInfoQCOM {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_COMMAND_TRANSFORM_INFO_QCOM;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCommandBufferInheritanceRenderPassTransformInfoQCOM.html>"]
@@ -46099,6 +51864,10 @@ impl<'a> CommandBufferInheritanceRenderPassTransformInfoQCOMBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CommandBufferInheritanceRenderPassTransformInfoQCOM {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceDiagnosticsConfigFeaturesNV.html>"]
@@ -46157,6 +51926,21 @@ impl<'a> PhysicalDeviceDiagnosticsConfigFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceDiagnosticsConfigFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV;
+}
+impl PhysicalDeviceDiagnosticsConfigFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.diagnostics_config != FALSE && self.diagnostics_config == FALSE {
+            missing.push("diagnosticsConfig");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkDeviceDiagnosticsConfigCreateInfoNV.html>"]
@@ -46215,6 +51999,9 @@ impl<'a> DeviceDiagnosticsConfigCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for DeviceDiagnosticsConfigCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType = StructureType::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRobustness2FeaturesEXT.html>"]
@@ -46291,6 +52078,26 @@ impl<'a> PhysicalDeviceRobustness2FeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRobustness2FeaturesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT;
+}
+impl PhysicalDeviceRobustness2FeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.robust_buffer_access2 != FALSE && self.robust_buffer_access2 == FALSE {
+            missing.push("robustBufferAccess2");
+        }
+        if required.robust_image_access2 != FALSE && self.robust_image_access2 == FALSE {
+            missing.push("robustImageAccess2");
+        }
+        if required.null_descriptor != FALSE && self.null_descriptor == FALSE {
+            missing.push("nullDescriptor");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceRobustness2PropertiesEXT.html>"]
@@ -46360,6 +52167,20 @@ impl<'a> PhysicalDeviceRobustness2PropertiesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceRobustness2PropertiesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT;
+}
+impl PhysicalDeviceRobustness2PropertiesEXT {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceImageRobustnessFeaturesEXT.html>"]
@@ -46418,6 +52239,21 @@ impl<'a> PhysicalDeviceImageRobustnessFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceImageRobustnessFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT;
+}
+impl PhysicalDeviceImageRobustnessFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.robust_image_access != FALSE && self.robust_image_access == FALSE {
+            missing.push("robustImageAccess");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePortabilitySubsetFeaturesKHR.html>"]
@@ -46601,6 +52437,73 @@ impl<'a> PhysicalDevicePortabilitySubsetFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePortabilitySubsetFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR;
+}
+impl PhysicalDevicePortabilitySubsetFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.constant_alpha_color_blend_factors != FALSE
+            && self.constant_alpha_color_blend_factors == FALSE
+        {
+            missing.push("constantAlphaColorBlendFactors");
+        }
+        if required.events != FALSE && self.events == FALSE {
+            missing.push("events");
+        }
+        if required.image_view_format_reinterpretation != FALSE
+            && self.image_view_format_reinterpretation == FALSE
+        {
+            missing.push("imageViewFormatReinterpretation");
+        }
+        if required.image_view_format_swizzle != FALSE && self.image_view_format_swizzle == FALSE {
+            missing.push("imageViewFormatSwizzle");
+        }
+        if required.image_view2_d_on3_d_image != FALSE && self.image_view2_d_on3_d_image == FALSE {
+            missing.push("imageView2DOn3DImage");
+        }
+        if required.multisample_array_image != FALSE && self.multisample_array_image == FALSE {
+            missing.push("multisampleArrayImage");
+        }
+        if required.mutable_comparison_samplers != FALSE
+            && self.mutable_comparison_samplers == FALSE
+        {
+            missing.push("mutableComparisonSamplers");
+        }
+        if required.point_polygons != FALSE && self.point_polygons == FALSE {
+            missing.push("pointPolygons");
+        }
+        if required.sampler_mip_lod_bias != FALSE && self.sampler_mip_lod_bias == FALSE {
+            missing.push("samplerMipLodBias");
+        }
+        if required.separate_stencil_mask_ref != FALSE && self.separate_stencil_mask_ref == FALSE {
+            missing.push("separateStencilMaskRef");
+        }
+        if required.shader_sample_rate_interpolation_functions != FALSE
+            && self.shader_sample_rate_interpolation_functions == FALSE
+        {
+            missing.push("shaderSampleRateInterpolationFunctions");
+        }
+        if required.tessellation_isolines != FALSE && self.tessellation_isolines == FALSE {
+            missing.push("tessellationIsolines");
+        }
+        if required.tessellation_point_mode != FALSE && self.tessellation_point_mode == FALSE {
+            missing.push("tessellationPointMode");
+        }
+        if required.triangle_fans != FALSE && self.triangle_fans == FALSE {
+            missing.push("triangleFans");
+        }
+        if required.vertex_attribute_access_beyond_stride != FALSE
+            && self.vertex_attribute_access_beyond_stride == FALSE
+        {
+            missing.push("vertexAttributeAccessBeyondStride");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevicePortabilitySubsetPropertiesKHR.html>"]
@@ -46663,6 +52566,20 @@ impl<'a> PhysicalDevicePortabilitySubsetPropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevicePortabilitySubsetPropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR;
+}
+impl PhysicalDevicePortabilitySubsetPropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDevice4444FormatsFeaturesEXT.html>"]
@@ -46730,6 +52647,23 @@ impl<'a> PhysicalDevice4444FormatsFeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDevice4444FormatsFeaturesEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT;
+}
+impl PhysicalDevice4444FormatsFeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.format_a4r4g4b4 != FALSE && self.format_a4r4g4b4 == FALSE {
+            missing.push("formatA4r4g4b4");
+        }
+        if required.format_a4b4g4r4 != FALSE && self.format_a4b4g4r4 == FALSE {
+            missing.push("formatA4b4g4r4");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferCopy2KHR.html>"]
@@ -46794,6 +52728,8 @@ impl<'a> BufferCopy2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferCopy2KHR>(
         mut self,
         next: &'a mut T,
@@ -46803,6 +52739,8 @@ impl<'a> BufferCopy2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferCopy2KHR", &mut self.inner);
         }
         self
     }
@@ -46813,6 +52751,9 @@ impl<'a> BufferCopy2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferCopy2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_COPY_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageCopy2KHR.html>"]
@@ -46895,6 +52836,8 @@ impl<'a> ImageCopy2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageCopy2KHR>(
         mut self,
         next: &'a mut T,
@@ -46904,6 +52847,8 @@ impl<'a> ImageCopy2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageCopy2KHR", &mut self.inner);
         }
         self
     }
@@ -46914,6 +52859,9 @@ impl<'a> ImageCopy2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageCopy2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_COPY_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageBlit2KHR.html>"]
@@ -46990,6 +52938,8 @@ impl<'a> ImageBlit2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageBlit2KHR>(
         mut self,
         next: &'a mut T,
@@ -46999,6 +52949,8 @@ impl<'a> ImageBlit2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageBlit2KHR", &mut self.inner);
         }
         self
     }
@@ -47009,6 +52961,9 @@ impl<'a> ImageBlit2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageBlit2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_BLIT_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBufferImageCopy2KHR.html>"]
@@ -47097,6 +53052,8 @@ impl<'a> BufferImageCopy2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBufferImageCopy2KHR>(
         mut self,
         next: &'a mut T,
@@ -47106,6 +53063,8 @@ impl<'a> BufferImageCopy2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBufferImageCopy2KHR", &mut self.inner);
         }
         self
     }
@@ -47116,6 +53075,9 @@ impl<'a> BufferImageCopy2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BufferImageCopy2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::BUFFER_IMAGE_COPY_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkImageResolve2KHR.html>"]
@@ -47198,6 +53160,8 @@ impl<'a> ImageResolve2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsImageResolve2KHR>(
         mut self,
         next: &'a mut T,
@@ -47207,6 +53171,8 @@ impl<'a> ImageResolve2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkImageResolve2KHR", &mut self.inner);
         }
         self
     }
@@ -47217,6 +53183,9 @@ impl<'a> ImageResolve2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ImageResolve2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::IMAGE_RESOLVE_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyBufferInfo2KHR.html>"]
@@ -47279,11 +53248,22 @@ impl<'a> CopyBufferInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [BufferCopy2KHRBuilder<'a>],
+    ) -> CopyBufferInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const BufferCopy2KHR;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyBufferInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47293,6 +53273,8 @@ impl<'a> CopyBufferInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCopyBufferInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47303,6 +53285,9 @@ impl<'a> CopyBufferInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyBufferInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_BUFFER_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyImageInfo2KHR.html>"]
@@ -47383,11 +53368,22 @@ impl<'a> CopyImageInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [ImageCopy2KHRBuilder<'a>],
+    ) -> CopyImageInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const ImageCopy2KHR;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyImageInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47397,6 +53393,8 @@ impl<'a> CopyImageInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCopyImageInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47407,6 +53405,9 @@ impl<'a> CopyImageInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyImageInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_IMAGE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkBlitImageInfo2KHR.html>"]
@@ -47489,6 +53490,15 @@ impl<'a> BlitImageInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [ImageBlit2KHRBuilder<'a>],
+    ) -> BlitImageInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const ImageBlit2KHR;
+        self
+    }
     pub fn filter(mut self, filter: Filter) -> BlitImageInfo2KHRBuilder<'a> {
         self.inner.filter = filter;
         self
@@ -47498,6 +53508,8 @@ impl<'a> BlitImageInfo2KHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsBlitImageInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47507,6 +53519,8 @@ impl<'a> BlitImageInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkBlitImageInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47517,6 +53531,9 @@ impl<'a> BlitImageInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for BlitImageInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::BLIT_IMAGE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyBufferToImageInfo2KHR.html>"]
@@ -47591,11 +53608,22 @@ impl<'a> CopyBufferToImageInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [BufferImageCopy2KHRBuilder<'a>],
+    ) -> CopyBufferToImageInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const BufferImageCopy2KHR;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyBufferToImageInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47605,6 +53633,8 @@ impl<'a> CopyBufferToImageInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCopyBufferToImageInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47615,6 +53645,9 @@ impl<'a> CopyBufferToImageInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyBufferToImageInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_BUFFER_TO_IMAGE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkCopyImageToBufferInfo2KHR.html>"]
@@ -47689,11 +53722,22 @@ impl<'a> CopyImageToBufferInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [BufferImageCopy2KHRBuilder<'a>],
+    ) -> CopyImageToBufferInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const BufferImageCopy2KHR;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsCopyImageToBufferInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47703,6 +53747,8 @@ impl<'a> CopyImageToBufferInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkCopyImageToBufferInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47713,6 +53759,9 @@ impl<'a> CopyImageToBufferInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for CopyImageToBufferInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::COPY_IMAGE_TO_BUFFER_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkResolveImageInfo2KHR.html>"]
@@ -47793,11 +53842,22 @@ impl<'a> ResolveImageInfo2KHRBuilder<'a> {
         self.inner.p_regions = regions.as_ptr();
         self
     }
+    #[doc = r" Takes the builders of the elements, keeping the lifetime of the data they borrow."]
+    pub fn regions_from_builders(
+        mut self,
+        regions: &'a [ImageResolve2KHRBuilder<'a>],
+    ) -> ResolveImageInfo2KHRBuilder<'a> {
+        self.inner.region_count = regions.len() as _;
+        self.inner.p_regions = regions.as_ptr() as *const ImageResolve2KHR;
+        self
+    }
     #[doc = r" Prepends the given extension struct between the root and the first pointer. This"]
     #[doc = r" method only exists on structs that can be passed to a function directly. Only"]
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsResolveImageInfo2KHR>(
         mut self,
         next: &'a mut T,
@@ -47807,6 +53867,8 @@ impl<'a> ResolveImageInfo2KHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain("VkResolveImageInfo2KHR", &mut self.inner);
         }
         self
     }
@@ -47817,6 +53879,9 @@ impl<'a> ResolveImageInfo2KHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for ResolveImageInfo2KHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::RESOLVE_IMAGE_INFO_2_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT.html>"]
@@ -47884,6 +53949,26 @@ impl<'a> PhysicalDeviceShaderImageAtomicInt64FeaturesEXTBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderImageAtomicInt64FeaturesEXT {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT;
+}
+impl PhysicalDeviceShaderImageAtomicInt64FeaturesEXT {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_image_int64_atomics != FALSE && self.shader_image_int64_atomics == FALSE
+        {
+            missing.push("shaderImageInt64Atomics");
+        }
+        if required.sparse_image_int64_atomics != FALSE && self.sparse_image_int64_atomics == FALSE
+        {
+            missing.push("sparseImageInt64Atomics");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkFragmentShadingRateAttachmentInfoKHR.html>"]
@@ -47951,6 +54036,9 @@ impl<'a> FragmentShadingRateAttachmentInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for FragmentShadingRateAttachmentInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineFragmentShadingRateStateCreateInfoKHR.html>"]
@@ -48021,6 +54109,10 @@ impl<'a> PipelineFragmentShadingRateStateCreateInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineFragmentShadingRateStateCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShadingRateFeaturesKHR.html>"]
@@ -48097,6 +54189,33 @@ impl<'a> PhysicalDeviceFragmentShadingRateFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShadingRateFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR;
+}
+impl PhysicalDeviceFragmentShadingRateFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.pipeline_fragment_shading_rate != FALSE
+            && self.pipeline_fragment_shading_rate == FALSE
+        {
+            missing.push("pipelineFragmentShadingRate");
+        }
+        if required.primitive_fragment_shading_rate != FALSE
+            && self.primitive_fragment_shading_rate == FALSE
+        {
+            missing.push("primitiveFragmentShadingRate");
+        }
+        if required.attachment_fragment_shading_rate != FALSE
+            && self.attachment_fragment_shading_rate == FALSE
+        {
+            missing.push("attachmentFragmentShadingRate");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShadingRatePropertiesKHR.html>"]
@@ -48322,6 +54441,20 @@ impl<'a> PhysicalDeviceFragmentShadingRatePropertiesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShadingRatePropertiesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR;
+}
+impl PhysicalDeviceFragmentShadingRatePropertiesKHR {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShadingRateKHR.html>"]
@@ -48386,6 +54519,8 @@ impl<'a> PhysicalDeviceFragmentShadingRateKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsPhysicalDeviceFragmentShadingRateKHR>(
         mut self,
         next: &'a mut T,
@@ -48395,6 +54530,11 @@ impl<'a> PhysicalDeviceFragmentShadingRateKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkPhysicalDeviceFragmentShadingRateKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -48405,6 +54545,9 @@ impl<'a> PhysicalDeviceFragmentShadingRateKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShadingRateKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceShaderTerminateInvocationFeaturesKHR.html>"]
@@ -48466,6 +54609,23 @@ impl<'a> PhysicalDeviceShaderTerminateInvocationFeaturesKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceShaderTerminateInvocationFeaturesKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR;
+}
+impl PhysicalDeviceShaderTerminateInvocationFeaturesKHR {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.shader_terminate_invocation != FALSE
+            && self.shader_terminate_invocation == FALSE
+        {
+            missing.push("shaderTerminateInvocation");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV.html>"]
@@ -48546,6 +54706,33 @@ impl<'a> PhysicalDeviceFragmentShadingRateEnumsFeaturesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShadingRateEnumsFeaturesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV;
+}
+impl PhysicalDeviceFragmentShadingRateEnumsFeaturesNV {
+    #[doc = r" The names of the features that `required` enables and `self` doesn't, such as"]
+    #[doc = r" `geometryShader`."]
+    pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if required.fragment_shading_rate_enums != FALSE
+            && self.fragment_shading_rate_enums == FALSE
+        {
+            missing.push("fragmentShadingRateEnums");
+        }
+        if required.supersample_fragment_shading_rates != FALSE
+            && self.supersample_fragment_shading_rates == FALSE
+        {
+            missing.push("supersampleFragmentShadingRates");
+        }
+        if required.no_invocation_fragment_shading_rates != FALSE
+            && self.no_invocation_fragment_shading_rates == FALSE
+        {
+            missing.push("noInvocationFragmentShadingRates");
+        }
+        missing
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV.html>"]
@@ -48611,6 +54798,20 @@ impl<'a> PhysicalDeviceFragmentShadingRateEnumsPropertiesNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PhysicalDeviceFragmentShadingRateEnumsPropertiesNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV;
+}
+impl PhysicalDeviceFragmentShadingRateEnumsPropertiesNV {
+    #[doc = r" Returns this struct with all members zeroed."]
+    pub const fn zeroed() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+    #[doc = r" Returns storage for the driver to write this struct to."]
+    pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+        ::std::mem::MaybeUninit::uninit()
+    }
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPipelineFragmentShadingRateEnumStateCreateInfoNV.html>"]
@@ -48690,6 +54891,10 @@ impl<'a> PipelineFragmentShadingRateEnumStateCreateInfoNVBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for PipelineFragmentShadingRateEnumStateCreateInfoNV {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV;
+}
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkAccelerationStructureBuildSizesInfoKHR.html>"]
@@ -48763,6 +54968,8 @@ impl<'a> AccelerationStructureBuildSizesInfoKHRBuilder<'a> {
     #[doc = r" valid extension structs can be pushed into the chain."]
     #[doc = r" If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the"]
     #[doc = r" chain will look like `A -> D -> B -> C`."]
+    #[doc = r" In debug builds, panics if a struct that `next` brings along doesn't extend this"]
+    #[doc = r" struct, or if the chain contains two structs of the same type."]
     pub fn push_next<T: ExtendsAccelerationStructureBuildSizesInfoKHR>(
         mut self,
         next: &'a mut T,
@@ -48772,6 +54979,11 @@ impl<'a> AccelerationStructureBuildSizesInfoKHRBuilder<'a> {
             let last_next = ptr_chain_iter(next).last().unwrap();
             (*last_next).p_next = self.inner.p_next as _;
             self.inner.p_next = next_ptr as _;
+            #[cfg(debug_assertions)]
+            crate::vk::debug_assert_ptr_chain(
+                "VkAccelerationStructureBuildSizesInfoKHR",
+                &mut self.inner,
+            );
         }
         self
     }
@@ -48782,3 +54994,9 @@ impl<'a> AccelerationStructureBuildSizesInfoKHRBuilder<'a> {
         self.inner
     }
 }
+unsafe impl TaggedStructure for AccelerationStructureBuildSizesInfoKHR {
+    const STRUCTURE_TYPE: StructureType =
+        StructureType::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR;
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn uninit_sets_s_type() {
+    let props = unsafe { PhysicalDeviceProperties2::uninit().assume_init() };
+    assert_eq!(props.s_type, StructureType::PHYSICAL_DEVICE_PROPERTIES_2);
+    assert!(props.p_next.is_null());
+}
+
+#[test]
+fn zeroed_constructors() {
+    const PROPS: PhysicalDeviceProperties2 = PhysicalDeviceProperties2::zeroed();
+    assert_eq!(PROPS.s_type, StructureType::PHYSICAL_DEVICE_PROPERTIES_2);
+    assert!(PROPS.p_next.is_null());
+    assert_eq!(MemoryRequirements::zeroed().size, 0);
+}
+
+#[test]
+fn c_str_members() {
+    let name = std::ffi::CStr::from_bytes_with_nul(b"VK_KHR_swapchain\0").unwrap();
+    let properties = ExtensionProperties::builder().extension_name(name).build();
+    assert_eq!(properties.extension_name_as_c_str(), Ok(name));
+    let unterminated = ExtensionProperties {
+        extension_name: [1; MAX_EXTENSION_NAME_SIZE],
+        spec_version: 0,
+    };
+    assert!(unterminated.extension_name_as_c_str().is_err());
+}
+
+#[test]
+fn nested_builders() {
+    let color_attachments = [AttachmentReference2::builder()
+        .attachment(1)
+        .layout(ImageLayout::COLOR_ATTACHMENT_OPTIMAL)];
+    let input_attachments = [AttachmentReference2::builder().attachment(0)];
+    let subpasses = [
+        SubpassDescription2::builder()
+            .input_attachments_from_builders(&input_attachments)
+            .color_attachments_from_builders(&color_attachments),
+        SubpassDescription2::builder().view_mask(1),
+    ];
+    let create_info = RenderPassCreateInfo2::builder().subpasses_from_builders(&subpasses);
+
+    assert_eq!(create_info.subpass_count, 2);
+    let subpasses = unsafe {
+        std::slice::from_raw_parts(create_info.p_subpasses, create_info.subpass_count as _)
+    };
+    assert_eq!(subpasses[1].view_mask, 1);
+    assert_eq!(subpasses[0].color_attachment_count, 1);
+    let color_attachments = unsafe { &*subpasses[0].p_color_attachments };
+    assert_eq!(color_attachments.attachment, 1);
+    assert_eq!(
+        color_attachments.layout,
+        ImageLayout::COLOR_ATTACHMENT_OPTIMAL
+    );
+    assert_eq!(
+        subpasses[0].p_input_attachments,
+        input_attachments.as_ptr() as *const AttachmentReference2
+    );
+}
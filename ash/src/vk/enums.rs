@@ -921,6 +921,7 @@ impl Result {
 }
 impl ::std::error::Error for Result {
     fn description(&self) -> &str {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Result::SUCCESS => Some("Command completed successfully"),
             Result::NOT_READY => Some("A fence or query has not yet completed"),
@@ -955,11 +956,14 @@ impl ::std::error::Error for Result {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         name.unwrap_or("unknown error")
     }
 }
 impl fmt::Display for Result {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "debug-strings")]
         let name = match *self {
             Result::SUCCESS => Some("Command completed successfully"),
             Result::NOT_READY => Some("A fence or query has not yet completed"),
@@ -994,6 +998,8 @@ impl fmt::Display for Result {
             }
             _ => None,
         };
+        #[cfg(not(feature = "debug-strings"))]
+        let name: Option<&str> = None;
         if let Some(x) = name {
             fmt.write_str(x)
         } else {
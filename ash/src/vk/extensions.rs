@@ -2,8 +2,8 @@ use crate::vk::aliases::*;
 use crate::vk::bitflags::*;
 use crate::vk::definitions::*;
 use crate::vk::enums::*;
+use crate::vk::platform::*;
 use crate::vk::platform_types::*;
-use std::os::raw::*;
 impl KhrSurfaceFn {
     pub fn name() -> &'static ::std::ffi::CStr {
         ::std::ffi::CStr::from_bytes_with_nul(b"VK_KHR_surface\0").expect("Wrong extension string")
@@ -89,6 +89,9 @@ impl ::std::clone::Clone for KhrSurfaceFn {
     }
 }
 impl KhrSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -263,17 +266,54 @@ impl KhrSurfaceFn {
             p_present_modes,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_surface_formats_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_surface_formats_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_surface_format_count: *mut u32,
+        p_surface_formats: *mut SurfaceFormatKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_surface_formats_khr(
+            physical_device,
+            surface,
+            p_surface_format_count,
+            p_surface_formats,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_physical_device_surface_present_modes_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_surface_present_modes_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_present_mode_count: *mut u32,
+        p_present_modes: *mut PresentModeKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_surface_present_modes_khr(
+            physical_device,
+            surface,
+            p_present_mode_count,
+            p_present_modes,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_surface'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_surface`."]
     pub const ERROR_SURFACE_LOST_KHR: Self = Self(-1000000000);
 }
 #[doc = "Generated from 'VK_KHR_surface'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_surface`."]
     pub const ERROR_NATIVE_WINDOW_IN_USE_KHR: Self = Self(-1000000001);
 }
 #[doc = "Generated from 'VK_KHR_surface'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_surface`."]
     pub const SURFACE_KHR: Self = Self(1_000_000_000);
 }
 impl KhrSwapchainFn {
@@ -282,6 +322,26 @@ impl KhrSwapchainFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 70u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateSwapchainKHR"
+            | b"vkDestroySwapchainKHR"
+            | b"vkGetSwapchainImagesKHR"
+            | b"vkAcquireNextImageKHR"
+            | b"vkQueuePresentKHR"
+            | b"vkGetDeviceGroupPresentCapabilitiesKHR"
+            | b"vkGetDeviceGroupSurfacePresentModesKHR"
+            | b"vkAcquireNextImage2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSwapchainKHR = extern "system" fn(
@@ -409,6 +469,9 @@ impl ::std::clone::Clone for KhrSwapchainFn {
     }
 }
 impl KhrSwapchainFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -692,61 +755,138 @@ impl KhrSwapchainFn {
     ) -> Result {
         (self.acquire_next_image2_khr)(device, p_acquire_info, p_image_index)
     }
+    #[doc = r" Like [`Self::acquire_next_image_khr`], but returns `image_index` together with the success code, which is one of `SUCCESS`, `TIMEOUT`, `NOT_READY`, `SUBOPTIMAL_KHR`."]
+    pub unsafe fn acquire_next_image_khr_with_success_code(
+        &self,
+        device: Device,
+        swapchain: SwapchainKHR,
+        timeout: u64,
+        semaphore: Semaphore,
+        fence: Fence,
+    ) -> ::std::result::Result<(u32, crate::vk::SuccessCode), Result> {
+        let mut image_index = ::std::mem::MaybeUninit::<u32>::zeroed();
+        let result = self.acquire_next_image_khr(
+            device,
+            swapchain,
+            timeout,
+            semaphore,
+            fence,
+            image_index.as_mut_ptr(),
+        );
+        result
+            .into_codes()
+            .map(|code| (image_index.assume_init(), code))
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::queue_present_khr`], but returns the success code, which is one of `SUCCESS`, `SUBOPTIMAL_KHR`."]
+    pub unsafe fn queue_present_khr_with_success_code(
+        &self,
+        queue: Queue,
+        p_present_info: *const PresentInfoKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.queue_present_khr(queue, p_present_info)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_physical_device_present_rectangles_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_present_rectangles_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_rect_count: *mut u32,
+        p_rects: *mut Rect2D,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_present_rectangles_khr(
+            physical_device,
+            surface,
+            p_rect_count,
+            p_rects,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::acquire_next_image2_khr`], but returns `image_index` together with the success code, which is one of `SUCCESS`, `TIMEOUT`, `NOT_READY`, `SUBOPTIMAL_KHR`."]
+    pub unsafe fn acquire_next_image2_khr_with_success_code(
+        &self,
+        device: Device,
+        p_acquire_info: *const AcquireNextImageInfoKHR,
+    ) -> ::std::result::Result<(u32, crate::vk::SuccessCode), Result> {
+        let mut image_index = ::std::mem::MaybeUninit::<u32>::zeroed();
+        let result = self.acquire_next_image2_khr(device, p_acquire_info, image_index.as_mut_ptr());
+        result
+            .into_codes()
+            .map(|code| (image_index.assume_init(), code))
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const SWAPCHAIN_CREATE_INFO_KHR: Self = Self(1_000_001_000);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const PRESENT_INFO_KHR: Self = Self(1_000_001_001);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const PRESENT_SRC_KHR: Self = Self(1_000_001_002);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const SUBOPTIMAL_KHR: Self = Self(1_000_001_003);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const ERROR_OUT_OF_DATE_KHR: Self = Self(-1000001004);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const SWAPCHAIN_KHR: Self = Self(1_000_001_000);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const DEVICE_GROUP_PRESENT_CAPABILITIES_KHR: Self = Self(1_000_060_007);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const IMAGE_SWAPCHAIN_CREATE_INFO_KHR: Self = Self(1_000_060_008);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR: Self = Self(1_000_060_009);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const ACQUIRE_NEXT_IMAGE_INFO_KHR: Self = Self(1_000_060_010);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const DEVICE_GROUP_PRESENT_INFO_KHR: Self = Self(1_000_060_011);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR: Self = Self(1_000_060_012);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl SwapchainCreateFlagsKHR {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const SPLIT_INSTANCE_BIND_REGIONS: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_KHR_swapchain'"]
 impl SwapchainCreateFlagsKHR {
+    #[doc = "Provided by `VK_KHR_swapchain`."]
     pub const PROTECTED: Self = Self(0b10);
 }
 impl KhrDisplayFn {
@@ -864,6 +1004,9 @@ impl ::std::clone::Clone for KhrDisplayFn {
     }
 }
 impl KhrDisplayFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1109,21 +1252,89 @@ impl KhrDisplayFn {
     ) -> Result {
         (self.create_display_plane_surface_khr)(instance, p_create_info, p_allocator, p_surface)
     }
+    #[doc = r" Like [`Self::get_physical_device_display_properties_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_display_properties_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPropertiesKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_display_properties_khr(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_physical_device_display_plane_properties_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_display_plane_properties_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPlanePropertiesKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_display_plane_properties_khr(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_display_plane_supported_displays_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_display_plane_supported_displays_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        plane_index: u32,
+        p_display_count: *mut u32,
+        p_displays: *mut DisplayKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_display_plane_supported_displays_khr(
+            physical_device,
+            plane_index,
+            p_display_count,
+            p_displays,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_display_mode_properties_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_display_mode_properties_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayModePropertiesKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_display_mode_properties_khr(
+            physical_device,
+            display,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_display'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_display`."]
     pub const DISPLAY_MODE_CREATE_INFO_KHR: Self = Self(1_000_002_000);
 }
 #[doc = "Generated from 'VK_KHR_display'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_display`."]
     pub const DISPLAY_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_002_001);
 }
 #[doc = "Generated from 'VK_KHR_display'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_display`."]
     pub const DISPLAY_KHR: Self = Self(1_000_002_000);
 }
 #[doc = "Generated from 'VK_KHR_display'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_display`."]
     pub const DISPLAY_MODE_KHR: Self = Self(1_000_002_001);
 }
 impl KhrDisplaySwapchainFn {
@@ -1132,6 +1343,19 @@ impl KhrDisplaySwapchainFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 10u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateSharedSwapchainsKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSharedSwapchainsKHR = extern "system" fn(
@@ -1160,6 +1384,9 @@ impl ::std::clone::Clone for KhrDisplaySwapchainFn {
     }
 }
 impl KhrDisplaySwapchainFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1209,10 +1436,12 @@ impl KhrDisplaySwapchainFn {
 }
 #[doc = "Generated from 'VK_KHR_display_swapchain'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_display_swapchain`."]
     pub const DISPLAY_PRESENT_INFO_KHR: Self = Self(1_000_003_000);
 }
 #[doc = "Generated from 'VK_KHR_display_swapchain'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_display_swapchain`."]
     pub const ERROR_INCOMPATIBLE_DISPLAY_KHR: Self = Self(-1000003001);
 }
 impl KhrXlibSurfaceFn {
@@ -1262,6 +1491,9 @@ impl ::std::clone::Clone for KhrXlibSurfaceFn {
     }
 }
 impl KhrXlibSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1339,6 +1571,7 @@ impl KhrXlibSurfaceFn {
 }
 #[doc = "Generated from 'VK_KHR_xlib_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_xlib_surface`."]
     pub const XLIB_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_004_000);
 }
 impl KhrXcbSurfaceFn {
@@ -1388,6 +1621,9 @@ impl ::std::clone::Clone for KhrXcbSurfaceFn {
     }
 }
 impl KhrXcbSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1465,6 +1701,7 @@ impl KhrXcbSurfaceFn {
 }
 #[doc = "Generated from 'VK_KHR_xcb_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_xcb_surface`."]
     pub const XCB_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_005_000);
 }
 impl KhrWaylandSurfaceFn {
@@ -1512,6 +1749,9 @@ impl ::std::clone::Clone for KhrWaylandSurfaceFn {
     }
 }
 impl KhrWaylandSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1586,6 +1826,7 @@ impl KhrWaylandSurfaceFn {
 }
 #[doc = "Generated from 'VK_KHR_wayland_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_wayland_surface`."]
     pub const WAYLAND_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_006_000);
 }
 impl KhrMirSurfaceFn {
@@ -1604,6 +1845,9 @@ impl ::std::clone::Clone for KhrMirSurfaceFn {
     }
 }
 impl KhrMirSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1643,6 +1887,9 @@ impl ::std::clone::Clone for KhrAndroidSurfaceFn {
     }
 }
 impl KhrAndroidSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1684,6 +1931,7 @@ impl KhrAndroidSurfaceFn {
 }
 #[doc = "Generated from 'VK_KHR_android_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_android_surface`."]
     pub const ANDROID_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_008_000);
 }
 impl KhrWin32SurfaceFn {
@@ -1725,6 +1973,9 @@ impl ::std::clone::Clone for KhrWin32SurfaceFn {
     }
 }
 impl KhrWin32SurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -1796,6 +2047,7 @@ impl KhrWin32SurfaceFn {
 }
 #[doc = "Generated from 'VK_KHR_win32_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_win32_surface`."]
     pub const WIN32_SURFACE_CREATE_INFO_KHR: Self = Self(1_000_009_000);
 }
 impl AndroidNativeBufferFn {
@@ -1804,6 +2056,22 @@ impl AndroidNativeBufferFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 8u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetSwapchainGrallocUsageANDROID"
+            | b"vkAcquireImageANDROID"
+            | b"vkQueueSignalReleaseImageANDROID"
+            | b"vkGetSwapchainGrallocUsage2ANDROID" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSwapchainGrallocUsageANDROID = extern "system" fn(
@@ -1880,6 +2148,9 @@ impl ::std::clone::Clone for AndroidNativeBufferFn {
     }
 }
 impl AndroidNativeBufferFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2035,14 +2306,17 @@ impl AndroidNativeBufferFn {
 }
 #[doc = "Generated from 'VK_ANDROID_native_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_native_buffer`."]
     pub const NATIVE_BUFFER_ANDROID: Self = Self(1_000_010_000);
 }
 #[doc = "Generated from 'VK_ANDROID_native_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_native_buffer`."]
     pub const SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID: Self = Self(1_000_010_001);
 }
 #[doc = "Generated from 'VK_ANDROID_native_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_native_buffer`."]
     pub const PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID: Self = Self(1_000_010_002);
 }
 impl ExtDebugReportFn {
@@ -2071,7 +2345,7 @@ pub type PFN_vkDebugReportMessageEXT = extern "system" fn(
     flags: DebugReportFlagsEXT,
     object_type: DebugReportObjectTypeEXT,
     object: u64,
-    location: usize,
+    location: size_t,
     message_code: i32,
     p_layer_prefix: *const c_char,
     p_message: *const c_char,
@@ -2093,7 +2367,7 @@ pub struct ExtDebugReportFn {
         flags: DebugReportFlagsEXT,
         object_type: DebugReportObjectTypeEXT,
         object: u64,
-        location: usize,
+        location: size_t,
         message_code: i32,
         p_layer_prefix: *const c_char,
         p_message: *const c_char,
@@ -2111,6 +2385,9 @@ impl ::std::clone::Clone for ExtDebugReportFn {
     }
 }
 impl ExtDebugReportFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2163,7 +2440,7 @@ impl ExtDebugReportFn {
                     _flags: DebugReportFlagsEXT,
                     _object_type: DebugReportObjectTypeEXT,
                     _object: u64,
-                    _location: usize,
+                    _location: size_t,
                     _message_code: i32,
                     _p_layer_prefix: *const c_char,
                     _p_message: *const c_char,
@@ -2210,7 +2487,7 @@ impl ExtDebugReportFn {
         flags: DebugReportFlagsEXT,
         object_type: DebugReportObjectTypeEXT,
         object: u64,
-        location: usize,
+        location: size_t,
         message_code: i32,
         p_layer_prefix: *const c_char,
         p_message: *const c_char,
@@ -2229,27 +2506,33 @@ impl ExtDebugReportFn {
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT: Self = Self(1_000_011_000);
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const DEBUG_REPORT_CREATE_INFO_EXT: Self =
         StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT;
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const ERROR_VALIDATION_FAILED_EXT: Self = Self(-1000011001);
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const DEBUG_REPORT_CALLBACK_EXT: Self = Self(1_000_011_000);
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const SAMPLER_YCBCR_CONVERSION: Self = Self(1_000_156_000);
 }
 #[doc = "Generated from 'VK_EXT_debug_report'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_EXT_debug_report`."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE: Self = Self(1_000_085_000);
 }
 impl NvGlslShaderFn {
@@ -2268,6 +2551,9 @@ impl ::std::clone::Clone for NvGlslShaderFn {
     }
 }
 impl NvGlslShaderFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2277,6 +2563,7 @@ impl NvGlslShaderFn {
 }
 #[doc = "Generated from 'VK_NV_glsl_shader'"]
 impl Result {
+    #[doc = "Provided by `VK_NV_glsl_shader`."]
     pub const ERROR_INVALID_SHADER_NV: Self = Self(-1000012000);
 }
 impl ExtDepthRangeUnrestrictedFn {
@@ -2295,6 +2582,9 @@ impl ::std::clone::Clone for ExtDepthRangeUnrestrictedFn {
     }
 }
 impl ExtDepthRangeUnrestrictedFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2318,6 +2608,9 @@ impl ::std::clone::Clone for KhrSamplerMirrorClampToEdgeFn {
     }
 }
 impl KhrSamplerMirrorClampToEdgeFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2327,10 +2620,12 @@ impl KhrSamplerMirrorClampToEdgeFn {
 }
 #[doc = "Generated from 'VK_KHR_sampler_mirror_clamp_to_edge'"]
 impl SamplerAddressMode {
+    #[doc = "Provided by `VK_KHR_sampler_mirror_clamp_to_edge`."]
     pub const MIRROR_CLAMP_TO_EDGE: Self = Self(4);
 }
 #[doc = "Generated from 'VK_KHR_sampler_mirror_clamp_to_edge'"]
 impl SamplerAddressMode {
+    #[doc = "Provided by `VK_KHR_sampler_mirror_clamp_to_edge`."]
     pub const MIRROR_CLAMP_TO_EDGE_KHR: Self = SamplerAddressMode::MIRROR_CLAMP_TO_EDGE;
 }
 impl ImgFilterCubicFn {
@@ -2349,6 +2644,9 @@ impl ::std::clone::Clone for ImgFilterCubicFn {
     }
 }
 impl ImgFilterCubicFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2358,10 +2656,12 @@ impl ImgFilterCubicFn {
 }
 #[doc = "Generated from 'VK_IMG_filter_cubic'"]
 impl Filter {
+    #[doc = "Provided by `VK_IMG_filter_cubic`."]
     pub const CUBIC_IMG: Self = Self(1_000_015_000);
 }
 #[doc = "Generated from 'VK_IMG_filter_cubic'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_IMG_filter_cubic`."]
     pub const SAMPLED_IMAGE_FILTER_CUBIC_IMG: Self = Self(0b10_0000_0000_0000);
 }
 impl AmdExtension17Fn {
@@ -2380,6 +2680,9 @@ impl ::std::clone::Clone for AmdExtension17Fn {
     }
 }
 impl AmdExtension17Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2403,6 +2706,9 @@ impl ::std::clone::Clone for AmdExtension18Fn {
     }
 }
 impl AmdExtension18Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2426,6 +2732,9 @@ impl ::std::clone::Clone for AmdRasterizationOrderFn {
     }
 }
 impl AmdRasterizationOrderFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2435,6 +2744,7 @@ impl AmdRasterizationOrderFn {
 }
 #[doc = "Generated from 'VK_AMD_rasterization_order'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_rasterization_order`."]
     pub const PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD: Self = Self(1_000_018_000);
 }
 impl AmdExtension20Fn {
@@ -2453,6 +2763,9 @@ impl ::std::clone::Clone for AmdExtension20Fn {
     }
 }
 impl AmdExtension20Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2476,6 +2789,9 @@ impl ::std::clone::Clone for AmdShaderTrinaryMinmaxFn {
     }
 }
 impl AmdShaderTrinaryMinmaxFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2499,6 +2815,9 @@ impl ::std::clone::Clone for AmdShaderExplicitVertexParameterFn {
     }
 }
 impl AmdShaderExplicitVertexParameterFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2512,6 +2831,23 @@ impl ExtDebugMarkerFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 4u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkDebugMarkerSetObjectTagEXT"
+            | b"vkDebugMarkerSetObjectNameEXT"
+            | b"vkCmdDebugMarkerBeginEXT"
+            | b"vkCmdDebugMarkerEndEXT"
+            | b"vkCmdDebugMarkerInsertEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDebugMarkerSetObjectTagEXT =
@@ -2564,6 +2900,9 @@ impl ::std::clone::Clone for ExtDebugMarkerFn {
     }
 }
 impl ExtDebugMarkerFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2682,6 +3021,8 @@ impl ExtDebugMarkerFn {
         (self.debug_marker_set_object_name_ext)(device, p_name_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDebugMarkerBeginEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_debug_marker_begin_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -2690,10 +3031,14 @@ impl ExtDebugMarkerFn {
         (self.cmd_debug_marker_begin_ext)(command_buffer, p_marker_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDebugMarkerEndEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_debug_marker_end_ext(&self, command_buffer: CommandBuffer) -> c_void {
         (self.cmd_debug_marker_end_ext)(command_buffer)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDebugMarkerInsertEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_debug_marker_insert_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -2704,14 +3049,17 @@ impl ExtDebugMarkerFn {
 }
 #[doc = "Generated from 'VK_EXT_debug_marker'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_marker`."]
     pub const DEBUG_MARKER_OBJECT_NAME_INFO_EXT: Self = Self(1_000_022_000);
 }
 #[doc = "Generated from 'VK_EXT_debug_marker'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_marker`."]
     pub const DEBUG_MARKER_OBJECT_TAG_INFO_EXT: Self = Self(1_000_022_001);
 }
 #[doc = "Generated from 'VK_EXT_debug_marker'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_marker`."]
     pub const DEBUG_MARKER_MARKER_INFO_EXT: Self = Self(1_000_022_002);
 }
 impl AmdExtension24Fn {
@@ -2730,6 +3078,9 @@ impl ::std::clone::Clone for AmdExtension24Fn {
     }
 }
 impl AmdExtension24Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2739,46 +3090,57 @@ impl AmdExtension24Fn {
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl QueueFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_6_KHR: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_27_KHR: Self = Self(0b1000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_30_KHR: Self = Self(0b100_0000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_15_KHR: Self = Self(0b1000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_16_KHR: Self = Self(0b1_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_13_KHR: Self = Self(0b10_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_14_KHR: Self = Self(0b100_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_15_KHR: Self = Self(0b1000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_27_KHR: Self = Self(0b1000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_28_KHR: Self = Self(0b1_0000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_24'"]
 impl QueryType {
+    #[doc = "Provided by `VK_AMD_extension_24`."]
     pub const RESERVED_8: Self = Self(1_000_023_008);
 }
 impl AmdExtension25Fn {
@@ -2797,6 +3159,9 @@ impl ::std::clone::Clone for AmdExtension25Fn {
     }
 }
 impl AmdExtension25Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2806,50 +3171,62 @@ impl AmdExtension25Fn {
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl QueueFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_5_KHR: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_26_KHR: Self = Self(0b100_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_28_KHR: Self = Self(0b1_0000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_29_KHR: Self = Self(0b10_0000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_13_KHR: Self = Self(0b10_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_14_KHR: Self = Self(0b100_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_10_KHR: Self = Self(0b100_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_11_KHR: Self = Self(0b1000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_12_KHR: Self = Self(0b1_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_25_KHR: Self = Self(0b10_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_26_KHR: Self = Self(0b100_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_AMD_extension_25'"]
 impl QueryType {
+    #[doc = "Provided by `VK_AMD_extension_25`."]
     pub const RESERVED_4: Self = Self(1_000_024_004);
 }
 impl AmdGcnShaderFn {
@@ -2868,6 +3245,9 @@ impl ::std::clone::Clone for AmdGcnShaderFn {
     }
 }
 impl AmdGcnShaderFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2891,6 +3271,9 @@ impl ::std::clone::Clone for NvDedicatedAllocationFn {
     }
 }
 impl NvDedicatedAllocationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2900,14 +3283,17 @@ impl NvDedicatedAllocationFn {
 }
 #[doc = "Generated from 'VK_NV_dedicated_allocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_dedicated_allocation`."]
     pub const DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV: Self = Self(1_000_026_000);
 }
 #[doc = "Generated from 'VK_NV_dedicated_allocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_dedicated_allocation`."]
     pub const DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV: Self = Self(1_000_026_001);
 }
 #[doc = "Generated from 'VK_NV_dedicated_allocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_dedicated_allocation`."]
     pub const DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV: Self = Self(1_000_026_002);
 }
 impl ExtExtension28Fn {
@@ -2926,6 +3312,9 @@ impl ::std::clone::Clone for ExtExtension28Fn {
     }
 }
 impl ExtExtension28Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2939,6 +3328,24 @@ impl ExtTransformFeedbackFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdBindTransformFeedbackBuffersEXT"
+            | b"vkCmdBeginTransformFeedbackEXT"
+            | b"vkCmdEndTransformFeedbackEXT"
+            | b"vkCmdBeginQueryIndexedEXT"
+            | b"vkCmdEndQueryIndexedEXT"
+            | b"vkCmdDrawIndirectByteCountEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBindTransformFeedbackBuffersEXT = extern "system" fn(
@@ -3051,6 +3458,9 @@ impl ::std::clone::Clone for ExtTransformFeedbackFn {
     }
 }
 impl ExtTransformFeedbackFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3193,6 +3603,8 @@ impl ExtTransformFeedbackFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_transform_feedback_buffers_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3212,6 +3624,8 @@ impl ExtTransformFeedbackFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_begin_transform_feedback_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3229,6 +3643,8 @@ impl ExtTransformFeedbackFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndTransformFeedbackEXT.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_end_transform_feedback_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3246,6 +3662,8 @@ impl ExtTransformFeedbackFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginQueryIndexedEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_begin_query_indexed_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3257,6 +3675,8 @@ impl ExtTransformFeedbackFn {
         (self.cmd_begin_query_indexed_ext)(command_buffer, query_pool, query, flags, index)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndQueryIndexedEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_end_query_indexed_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3267,6 +3687,8 @@ impl ExtTransformFeedbackFn {
         (self.cmd_end_query_indexed_ext)(command_buffer, query_pool, query, index)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndirectByteCountEXT.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indirect_byte_count_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -3290,43 +3712,53 @@ impl ExtTransformFeedbackFn {
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT: Self = Self(1_000_028_000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT: Self = Self(1_000_028_001);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT: Self = Self(1_000_028_002);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl QueryType {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_STREAM_EXT: Self = Self(1_000_028_004);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_BUFFER_EXT: Self = Self(0b1000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_COUNTER_BUFFER_EXT: Self = Self(0b1_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_WRITE_EXT: Self = Self(0b10_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_COUNTER_READ_EXT: Self = Self(0b100_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT: Self =
         Self(0b1000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_transform_feedback'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_EXT_transform_feedback`."]
     pub const TRANSFORM_FEEDBACK_EXT: Self = Self(0b1_0000_0000_0000_0000_0000_0000);
 }
 impl NvxExtension30Fn {
@@ -3345,6 +3777,9 @@ impl ::std::clone::Clone for NvxExtension30Fn {
     }
 }
 impl NvxExtension30Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3358,6 +3793,19 @@ impl NvxImageViewHandleFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetImageViewHandleNVX" | b"vkGetImageViewAddressNVX" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageViewHandleNVX =
@@ -3388,6 +3836,9 @@ impl ::std::clone::Clone for NvxImageViewHandleFn {
     }
 }
 impl NvxImageViewHandleFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3454,10 +3905,12 @@ impl NvxImageViewHandleFn {
 }
 #[doc = "Generated from 'VK_NVX_image_view_handle'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NVX_image_view_handle`."]
     pub const IMAGE_VIEW_HANDLE_INFO_NVX: Self = Self(1_000_030_000);
 }
 #[doc = "Generated from 'VK_NVX_image_view_handle'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NVX_image_view_handle`."]
     pub const IMAGE_VIEW_ADDRESS_PROPERTIES_NVX: Self = Self(1_000_030_001);
 }
 impl AmdExtension32Fn {
@@ -3476,6 +3929,9 @@ impl ::std::clone::Clone for AmdExtension32Fn {
     }
 }
 impl AmdExtension32Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3499,6 +3955,9 @@ impl ::std::clone::Clone for AmdExtension33Fn {
     }
 }
 impl AmdExtension33Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3512,6 +3971,19 @@ impl AmdDrawIndirectCountFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdDrawIndirectCountAMD" | b"vkCmdDrawIndexedIndirectCountAMD" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawIndirectCount = extern "system" fn(
@@ -3564,6 +4036,9 @@ impl ::std::clone::Clone for AmdDrawIndirectCountFn {
     }
 }
 impl AmdDrawIndirectCountFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3620,6 +4095,8 @@ impl AmdDrawIndirectCountFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndirectCountAMD.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indirect_count_amd(
         &self,
         command_buffer: CommandBuffer,
@@ -3641,6 +4118,8 @@ impl AmdDrawIndirectCountFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexedIndirectCountAMD.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indexed_indirect_count_amd(
         &self,
         command_buffer: CommandBuffer,
@@ -3678,6 +4157,9 @@ impl ::std::clone::Clone for AmdExtension35Fn {
     }
 }
 impl AmdExtension35Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3701,6 +4183,9 @@ impl ::std::clone::Clone for AmdNegativeViewportHeightFn {
     }
 }
 impl AmdNegativeViewportHeightFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3724,6 +4209,9 @@ impl ::std::clone::Clone for AmdGpuShaderHalfFloatFn {
     }
 }
 impl AmdGpuShaderHalfFloatFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3747,6 +4235,9 @@ impl ::std::clone::Clone for AmdShaderBallotFn {
     }
 }
 impl AmdShaderBallotFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3770,6 +4261,9 @@ impl ::std::clone::Clone for AmdExtension39Fn {
     }
 }
 impl AmdExtension39Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3793,6 +4287,9 @@ impl ::std::clone::Clone for AmdExtension40Fn {
     }
 }
 impl AmdExtension40Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3816,6 +4313,9 @@ impl ::std::clone::Clone for AmdExtension41Fn {
     }
 }
 impl AmdExtension41Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3839,6 +4339,9 @@ impl ::std::clone::Clone for AmdTextureGatherBiasLodFn {
     }
 }
 impl AmdTextureGatherBiasLodFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3848,6 +4351,7 @@ impl AmdTextureGatherBiasLodFn {
 }
 #[doc = "Generated from 'VK_AMD_texture_gather_bias_lod'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_texture_gather_bias_lod`."]
     pub const TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD: Self = Self(1_000_041_000);
 }
 impl AmdShaderInfoFn {
@@ -3856,6 +4360,19 @@ impl AmdShaderInfoFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetShaderInfoAMD" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetShaderInfoAMD = extern "system" fn(
@@ -3863,7 +4380,7 @@ pub type PFN_vkGetShaderInfoAMD = extern "system" fn(
     pipeline: Pipeline,
     shader_stage: ShaderStageFlags,
     info_type: ShaderInfoTypeAMD,
-    p_info_size: *mut usize,
+    p_info_size: *mut size_t,
     p_info: *mut c_void,
 ) -> Result;
 pub struct AmdShaderInfoFn {
@@ -3872,7 +4389,7 @@ pub struct AmdShaderInfoFn {
         pipeline: Pipeline,
         shader_stage: ShaderStageFlags,
         info_type: ShaderInfoTypeAMD,
-        p_info_size: *mut usize,
+        p_info_size: *mut size_t,
         p_info: *mut c_void,
     ) -> Result,
 }
@@ -3886,6 +4403,9 @@ impl ::std::clone::Clone for AmdShaderInfoFn {
     }
 }
 impl AmdShaderInfoFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3897,7 +4417,7 @@ impl AmdShaderInfoFn {
                     _pipeline: Pipeline,
                     _shader_stage: ShaderStageFlags,
                     _info_type: ShaderInfoTypeAMD,
-                    _p_info_size: *mut usize,
+                    _p_info_size: *mut size_t,
                     _p_info: *mut c_void,
                 ) -> Result {
                     panic!(concat!("Unable to load ", stringify!(get_shader_info_amd)))
@@ -3920,7 +4440,7 @@ impl AmdShaderInfoFn {
         pipeline: Pipeline,
         shader_stage: ShaderStageFlags,
         info_type: ShaderInfoTypeAMD,
-        p_info_size: *mut usize,
+        p_info_size: *mut size_t,
         p_info: *mut c_void,
     ) -> Result {
         (self.get_shader_info_amd)(
@@ -3932,6 +4452,27 @@ impl AmdShaderInfoFn {
             p_info,
         )
     }
+    #[doc = r" Like [`Self::get_shader_info_amd`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_shader_info_amd_with_success_code(
+        &self,
+        device: Device,
+        pipeline: Pipeline,
+        shader_stage: ShaderStageFlags,
+        info_type: ShaderInfoTypeAMD,
+        p_info_size: *mut size_t,
+        p_info: *mut c_void,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_shader_info_amd(
+            device,
+            pipeline,
+            shader_stage,
+            info_type,
+            p_info_size,
+            p_info,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 impl AmdExtension44Fn {
     pub fn name() -> &'static ::std::ffi::CStr {
@@ -3949,6 +4490,9 @@ impl ::std::clone::Clone for AmdExtension44Fn {
     }
 }
 impl AmdExtension44Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3972,6 +4516,9 @@ impl ::std::clone::Clone for AmdExtension45Fn {
     }
 }
 impl AmdExtension45Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -3995,6 +4542,9 @@ impl ::std::clone::Clone for AmdExtension46Fn {
     }
 }
 impl AmdExtension46Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4018,6 +4568,9 @@ impl ::std::clone::Clone for AmdShaderImageLoadStoreLodFn {
     }
 }
 impl AmdShaderImageLoadStoreLodFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4041,6 +4594,9 @@ impl ::std::clone::Clone for NvxExtension48Fn {
     }
 }
 impl NvxExtension48Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4064,6 +4620,9 @@ impl ::std::clone::Clone for GoogleExtension49Fn {
     }
 }
 impl GoogleExtension49Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4103,6 +4662,9 @@ impl ::std::clone::Clone for GgpStreamDescriptorSurfaceFn {
     }
 }
 impl GgpStreamDescriptorSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4144,6 +4706,7 @@ impl GgpStreamDescriptorSurfaceFn {
 }
 #[doc = "Generated from 'VK_GGP_stream_descriptor_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_GGP_stream_descriptor_surface`."]
     pub const STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP: Self = Self(1_000_049_000);
 }
 impl NvCornerSampledImageFn {
@@ -4162,6 +4725,9 @@ impl ::std::clone::Clone for NvCornerSampledImageFn {
     }
 }
 impl NvCornerSampledImageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4171,10 +4737,12 @@ impl NvCornerSampledImageFn {
 }
 #[doc = "Generated from 'VK_NV_corner_sampled_image'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_NV_corner_sampled_image`."]
     pub const CORNER_SAMPLED_NV: Self = Self(0b10_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_corner_sampled_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_corner_sampled_image`."]
     pub const PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV: Self = Self(1_000_050_000);
 }
 impl NvExtension52Fn {
@@ -4193,6 +4761,9 @@ impl ::std::clone::Clone for NvExtension52Fn {
     }
 }
 impl NvExtension52Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4202,10 +4773,12 @@ impl NvExtension52Fn {
 }
 #[doc = "Generated from 'VK_NV_extension_52'"]
 impl ShaderModuleCreateFlags {
+    #[doc = "Provided by `VK_NV_extension_52`."]
     pub const RESERVED_0_NV: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_NV_extension_52'"]
 impl PipelineShaderStageCreateFlags {
+    #[doc = "Provided by `VK_NV_extension_52`."]
     pub const RESERVED_2_NV: Self = Self(0b100);
 }
 impl NvExtension53Fn {
@@ -4224,6 +4797,9 @@ impl ::std::clone::Clone for NvExtension53Fn {
     }
 }
 impl NvExtension53Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4247,6 +4823,9 @@ impl ::std::clone::Clone for KhrMultiviewFn {
     }
 }
 impl KhrMultiviewFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4256,21 +4835,25 @@ impl KhrMultiviewFn {
 }
 #[doc = "Generated from 'VK_KHR_multiview'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_multiview`."]
     pub const RENDER_PASS_MULTIVIEW_CREATE_INFO_KHR: Self =
         StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_multiview'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_multiview`."]
     pub const PHYSICAL_DEVICE_MULTIVIEW_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_multiview'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_multiview`."]
     pub const PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_multiview'"]
 impl DependencyFlags {
+    #[doc = "Provided by `VK_KHR_multiview`."]
     pub const VIEW_LOCAL_KHR: Self = DependencyFlags::VIEW_LOCAL;
 }
 impl ImgFormatPvrtcFn {
@@ -4289,6 +4872,9 @@ impl ::std::clone::Clone for ImgFormatPvrtcFn {
     }
 }
 impl ImgFormatPvrtcFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4298,34 +4884,42 @@ impl ImgFormatPvrtcFn {
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC1_2BPP_UNORM_BLOCK_IMG: Self = Self(1_000_054_000);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC1_4BPP_UNORM_BLOCK_IMG: Self = Self(1_000_054_001);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC2_2BPP_UNORM_BLOCK_IMG: Self = Self(1_000_054_002);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC2_4BPP_UNORM_BLOCK_IMG: Self = Self(1_000_054_003);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC1_2BPP_SRGB_BLOCK_IMG: Self = Self(1_000_054_004);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC1_4BPP_SRGB_BLOCK_IMG: Self = Self(1_000_054_005);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC2_2BPP_SRGB_BLOCK_IMG: Self = Self(1_000_054_006);
 }
 #[doc = "Generated from 'VK_IMG_format_pvrtc'"]
 impl Format {
+    #[doc = "Provided by `VK_IMG_format_pvrtc`."]
     pub const PVRTC2_4BPP_SRGB_BLOCK_IMG: Self = Self(1_000_054_007);
 }
 impl NvExternalMemoryCapabilitiesFn {
@@ -4369,6 +4963,9 @@ impl ::std::clone::Clone for NvExternalMemoryCapabilitiesFn {
     }
 }
 impl NvExternalMemoryCapabilitiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4441,6 +5038,9 @@ impl ::std::clone::Clone for NvExternalMemoryFn {
     }
 }
 impl NvExternalMemoryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4450,10 +5050,12 @@ impl NvExternalMemoryFn {
 }
 #[doc = "Generated from 'VK_NV_external_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_external_memory`."]
     pub const EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV: Self = Self(1_000_056_000);
 }
 #[doc = "Generated from 'VK_NV_external_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_external_memory`."]
     pub const EXPORT_MEMORY_ALLOCATE_INFO_NV: Self = Self(1_000_056_001);
 }
 impl NvExternalMemoryWin32Fn {
@@ -4462,6 +5064,19 @@ impl NvExternalMemoryWin32Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetMemoryWin32HandleNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryWin32HandleNV = extern "system" fn(
@@ -4488,6 +5103,9 @@ impl ::std::clone::Clone for NvExternalMemoryWin32Fn {
     }
 }
 impl NvExternalMemoryWin32Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4529,10 +5147,12 @@ impl NvExternalMemoryWin32Fn {
 }
 #[doc = "Generated from 'VK_NV_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_external_memory_win32`."]
     pub const IMPORT_MEMORY_WIN32_HANDLE_INFO_NV: Self = Self(1_000_057_000);
 }
 #[doc = "Generated from 'VK_NV_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_external_memory_win32`."]
     pub const EXPORT_MEMORY_WIN32_HANDLE_INFO_NV: Self = Self(1_000_057_001);
 }
 impl NvWin32KeyedMutexFn {
@@ -4551,6 +5171,9 @@ impl ::std::clone::Clone for NvWin32KeyedMutexFn {
     }
 }
 impl NvWin32KeyedMutexFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4560,6 +5183,7 @@ impl NvWin32KeyedMutexFn {
 }
 #[doc = "Generated from 'VK_NV_win32_keyed_mutex'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_win32_keyed_mutex`."]
     pub const WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV: Self = Self(1_000_058_000);
 }
 impl KhrGetPhysicalDeviceProperties2Fn {
@@ -4665,6 +5289,9 @@ impl ::std::clone::Clone for KhrGetPhysicalDeviceProperties2Fn {
     }
 }
 impl KhrGetPhysicalDeviceProperties2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -4891,41 +5518,50 @@ impl KhrGetPhysicalDeviceProperties2Fn {
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const PHYSICAL_DEVICE_FEATURES_2_KHR: Self = StructureType::PHYSICAL_DEVICE_FEATURES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const PHYSICAL_DEVICE_PROPERTIES_2_KHR: Self = StructureType::PHYSICAL_DEVICE_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const FORMAT_PROPERTIES_2_KHR: Self = StructureType::FORMAT_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const IMAGE_FORMAT_PROPERTIES_2_KHR: Self = StructureType::IMAGE_FORMAT_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2_KHR: Self =
         StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const QUEUE_FAMILY_PROPERTIES_2_KHR: Self = StructureType::QUEUE_FAMILY_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const PHYSICAL_DEVICE_MEMORY_PROPERTIES_2_KHR: Self =
         StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const SPARSE_IMAGE_FORMAT_PROPERTIES_2_KHR: Self =
         StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2;
 }
 #[doc = "Generated from 'VK_KHR_get_physical_device_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_physical_device_properties2`."]
     pub const PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2;
 }
@@ -4935,6 +5571,24 @@ impl KhrDeviceGroupFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 4u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetDeviceGroupPeerMemoryFeaturesKHR"
+            | b"vkCmdSetDeviceMaskKHR"
+            | b"vkCmdDispatchBaseKHR"
+            | b"vkGetDeviceGroupPresentCapabilitiesKHR"
+            | b"vkGetDeviceGroupSurfacePresentModesKHR"
+            | b"vkAcquireNextImage2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceGroupPeerMemoryFeatures = extern "system" fn(
@@ -5017,6 +5671,9 @@ impl ::std::clone::Clone for KhrDeviceGroupFn {
     }
 }
 impl KhrDeviceGroupFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5187,6 +5844,8 @@ impl KhrDeviceGroupFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDeviceMaskKHR.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_device_mask_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -5195,6 +5854,8 @@ impl KhrDeviceGroupFn {
         (self.cmd_set_device_mask_khr)(command_buffer, device_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatchBaseKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_dispatch_base_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -5259,71 +5920,116 @@ impl KhrDeviceGroupFn {
     ) -> Result {
         (self.acquire_next_image2_khr)(device, p_acquire_info, p_image_index)
     }
+    #[doc = r" Like [`Self::get_physical_device_present_rectangles_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_present_rectangles_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        p_rect_count: *mut u32,
+        p_rects: *mut Rect2D,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_present_rectangles_khr(
+            physical_device,
+            surface,
+            p_rect_count,
+            p_rects,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::acquire_next_image2_khr`], but returns `image_index` together with the success code, which is one of `SUCCESS`, `TIMEOUT`, `NOT_READY`, `SUBOPTIMAL_KHR`."]
+    pub unsafe fn acquire_next_image2_khr_with_success_code(
+        &self,
+        device: Device,
+        p_acquire_info: *const AcquireNextImageInfoKHR,
+    ) -> ::std::result::Result<(u32, crate::vk::SuccessCode), Result> {
+        let mut image_index = ::std::mem::MaybeUninit::<u32>::zeroed();
+        let result = self.acquire_next_image2_khr(device, p_acquire_info, image_index.as_mut_ptr());
+        result
+            .into_codes()
+            .map(|code| (image_index.assume_init(), code))
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const MEMORY_ALLOCATE_FLAGS_INFO_KHR: Self = StructureType::MEMORY_ALLOCATE_FLAGS_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_GROUP_RENDER_PASS_BEGIN_INFO_KHR: Self =
         StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO_KHR: Self =
         StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_GROUP_SUBMIT_INFO_KHR: Self = StructureType::DEVICE_GROUP_SUBMIT_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_GROUP_BIND_SPARSE_INFO_KHR: Self =
         StructureType::DEVICE_GROUP_BIND_SPARSE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl PeerMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const COPY_SRC_KHR: Self = PeerMemoryFeatureFlags::COPY_SRC;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl PeerMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const COPY_DST_KHR: Self = PeerMemoryFeatureFlags::COPY_DST;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl PeerMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const GENERIC_SRC_KHR: Self = PeerMemoryFeatureFlags::GENERIC_SRC;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl PeerMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const GENERIC_DST_KHR: Self = PeerMemoryFeatureFlags::GENERIC_DST;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl MemoryAllocateFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_MASK_KHR: Self = MemoryAllocateFlags::DEVICE_MASK;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const VIEW_INDEX_FROM_DEVICE_INDEX_KHR: Self =
         PipelineCreateFlags::VIEW_INDEX_FROM_DEVICE_INDEX;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl DependencyFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const DEVICE_GROUP_KHR: Self = DependencyFlags::DEVICE_GROUP;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR: Self =
         StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR: Self =
         StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_device_group`."]
     pub const SPLIT_INSTANCE_BIND_REGIONS_KHR: Self = ImageCreateFlags::SPLIT_INSTANCE_BIND_REGIONS;
 }
 impl ExtValidationFlagsFn {
@@ -5342,6 +6048,9 @@ impl ::std::clone::Clone for ExtValidationFlagsFn {
     }
 }
 impl ExtValidationFlagsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5351,6 +6060,7 @@ impl ExtValidationFlagsFn {
 }
 #[doc = "Generated from 'VK_EXT_validation_flags'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_validation_flags`."]
     pub const VALIDATION_FLAGS_EXT: Self = Self(1_000_061_000);
 }
 impl NnViSurfaceFn {
@@ -5385,6 +6095,9 @@ impl ::std::clone::Clone for NnViSurfaceFn {
     }
 }
 impl NnViSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5423,6 +6136,7 @@ impl NnViSurfaceFn {
 }
 #[doc = "Generated from 'VK_NN_vi_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NN_vi_surface`."]
     pub const VI_SURFACE_CREATE_INFO_NN: Self = Self(1_000_062_000);
 }
 impl KhrShaderDrawParametersFn {
@@ -5441,6 +6155,9 @@ impl ::std::clone::Clone for KhrShaderDrawParametersFn {
     }
 }
 impl KhrShaderDrawParametersFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5464,6 +6181,9 @@ impl ::std::clone::Clone for ExtShaderSubgroupBallotFn {
     }
 }
 impl ExtShaderSubgroupBallotFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5487,6 +6207,9 @@ impl ::std::clone::Clone for ExtShaderSubgroupVoteFn {
     }
 }
 impl ExtShaderSubgroupVoteFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5510,6 +6233,9 @@ impl ::std::clone::Clone for ExtTextureCompressionAstcHdrFn {
     }
 }
 impl ExtTextureCompressionAstcHdrFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5519,62 +6245,77 @@ impl ExtTextureCompressionAstcHdrFn {
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT: Self = Self(1_000_066_000);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_4X4_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_000);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_5X4_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_001);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_5X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_002);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_6X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_003);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_6X6_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_004);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_8X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_005);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_8X6_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_006);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_8X8_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_007);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_10X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_008);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_10X6_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_009);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_10X8_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_010);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_10X10_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_011);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_12X10_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_012);
 }
 #[doc = "Generated from 'VK_EXT_texture_compression_astc_hdr'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_texture_compression_astc_hdr`."]
     pub const ASTC_12X12_SFLOAT_BLOCK_EXT: Self = Self(1_000_066_013);
 }
 impl ExtAstcDecodeModeFn {
@@ -5593,6 +6334,9 @@ impl ::std::clone::Clone for ExtAstcDecodeModeFn {
     }
 }
 impl ExtAstcDecodeModeFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5602,10 +6346,12 @@ impl ExtAstcDecodeModeFn {
 }
 #[doc = "Generated from 'VK_EXT_astc_decode_mode'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_astc_decode_mode`."]
     pub const IMAGE_VIEW_ASTC_DECODE_MODE_EXT: Self = Self(1_000_067_000);
 }
 #[doc = "Generated from 'VK_EXT_astc_decode_mode'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_astc_decode_mode`."]
     pub const PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT: Self = Self(1_000_067_001);
 }
 impl ImgExtension69Fn {
@@ -5624,6 +6370,9 @@ impl ::std::clone::Clone for ImgExtension69Fn {
     }
 }
 impl ImgExtension69Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5637,6 +6386,19 @@ impl KhrMaintenance1Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkTrimCommandPoolKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkTrimCommandPool = extern "system" fn(
@@ -5661,6 +6423,9 @@ impl ::std::clone::Clone for KhrMaintenance1Fn {
     }
 }
 impl KhrMaintenance1Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5700,18 +6465,22 @@ impl KhrMaintenance1Fn {
 }
 #[doc = "Generated from 'VK_KHR_maintenance1'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_maintenance1`."]
     pub const ERROR_OUT_OF_POOL_MEMORY_KHR: Self = Result::ERROR_OUT_OF_POOL_MEMORY;
 }
 #[doc = "Generated from 'VK_KHR_maintenance1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_maintenance1`."]
     pub const TRANSFER_SRC_KHR: Self = FormatFeatureFlags::TRANSFER_SRC;
 }
 #[doc = "Generated from 'VK_KHR_maintenance1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_maintenance1`."]
     pub const TRANSFER_DST_KHR: Self = FormatFeatureFlags::TRANSFER_DST;
 }
 #[doc = "Generated from 'VK_KHR_maintenance1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_maintenance1`."]
     pub const TYPE_2D_ARRAY_COMPATIBLE_KHR: Self = ImageCreateFlags::TYPE_2D_ARRAY_COMPATIBLE;
 }
 impl KhrDeviceGroupCreationFn {
@@ -5744,6 +6513,9 @@ impl ::std::clone::Clone for KhrDeviceGroupCreationFn {
     }
 }
 impl KhrDeviceGroupCreationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5784,19 +6556,37 @@ impl KhrDeviceGroupCreationFn {
             p_physical_device_group_properties,
         )
     }
+    #[doc = r" Like [`Self::enumerate_physical_device_groups_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_physical_device_groups_khr_with_success_code(
+        &self,
+        instance: Instance,
+        p_physical_device_group_count: *mut u32,
+        p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_physical_device_groups_khr(
+            instance,
+            p_physical_device_group_count,
+            p_physical_device_group_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_device_group_creation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group_creation`."]
     pub const PHYSICAL_DEVICE_GROUP_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_device_group_creation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_device_group_creation`."]
     pub const DEVICE_GROUP_DEVICE_CREATE_INFO_KHR: Self =
         StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_device_group_creation'"]
 impl MemoryHeapFlags {
+    #[doc = "Provided by `VK_KHR_device_group_creation`."]
     pub const MULTI_INSTANCE_KHR: Self = MemoryHeapFlags::MULTI_INSTANCE;
 }
 impl KhrExternalMemoryCapabilitiesFn {
@@ -5830,6 +6620,9 @@ impl ::std::clone::Clone for KhrExternalMemoryCapabilitiesFn {
     }
 }
 impl KhrExternalMemoryCapabilitiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5873,75 +6666,90 @@ impl KhrExternalMemoryCapabilitiesFn {
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO_KHR: Self =
         StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_IMAGE_FORMAT_PROPERTIES_KHR: Self =
         StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO_KHR: Self =
         StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_BUFFER_PROPERTIES_KHR: Self = StructureType::EXTERNAL_BUFFER_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const PHYSICAL_DEVICE_ID_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_ID_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE_KHR: Self =
         ExternalMemoryHandleTypeFlags::EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_KHR: Self =
         ExternalMemoryFeatureFlags::EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_FEATURE_EXPORTABLE_KHR: Self =
         ExternalMemoryFeatureFlags::EXTERNAL_MEMORY_FEATURE_EXPORTABLE;
 }
 #[doc = "Generated from 'VK_KHR_external_memory_capabilities'"]
 impl ExternalMemoryFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_memory_capabilities`."]
     pub const EXTERNAL_MEMORY_FEATURE_IMPORTABLE_KHR: Self =
         ExternalMemoryFeatureFlags::EXTERNAL_MEMORY_FEATURE_IMPORTABLE;
 }
@@ -5961,6 +6769,9 @@ impl ::std::clone::Clone for KhrExternalMemoryFn {
     }
 }
 impl KhrExternalMemoryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -5970,20 +6781,24 @@ impl KhrExternalMemoryFn {
 }
 #[doc = "Generated from 'VK_KHR_external_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory`."]
     pub const EXTERNAL_MEMORY_BUFFER_CREATE_INFO_KHR: Self =
         StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory`."]
     pub const EXTERNAL_MEMORY_IMAGE_CREATE_INFO_KHR: Self =
         StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory`."]
     pub const EXPORT_MEMORY_ALLOCATE_INFO_KHR: Self = StructureType::EXPORT_MEMORY_ALLOCATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_memory'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_external_memory`."]
     pub const ERROR_INVALID_EXTERNAL_HANDLE_KHR: Self = Result::ERROR_INVALID_EXTERNAL_HANDLE;
 }
 impl KhrExternalMemoryWin32Fn {
@@ -5992,6 +6807,21 @@ impl KhrExternalMemoryWin32Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetMemoryWin32HandleKHR" | b"vkGetMemoryWin32HandlePropertiesKHR" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryWin32HandleKHR = extern "system" fn(
@@ -6030,6 +6860,9 @@ impl ::std::clone::Clone for KhrExternalMemoryWin32Fn {
     }
 }
 impl KhrExternalMemoryWin32Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6105,18 +6938,22 @@ impl KhrExternalMemoryWin32Fn {
 }
 #[doc = "Generated from 'VK_KHR_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_win32`."]
     pub const IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_073_000);
 }
 #[doc = "Generated from 'VK_KHR_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_win32`."]
     pub const EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_073_001);
 }
 #[doc = "Generated from 'VK_KHR_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_win32`."]
     pub const MEMORY_WIN32_HANDLE_PROPERTIES_KHR: Self = Self(1_000_073_002);
 }
 #[doc = "Generated from 'VK_KHR_external_memory_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_win32`."]
     pub const MEMORY_GET_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_073_003);
 }
 impl KhrExternalMemoryFdFn {
@@ -6125,6 +6962,19 @@ impl KhrExternalMemoryFdFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetMemoryFdKHR" | b"vkGetMemoryFdPropertiesKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryFdKHR = extern "system" fn(
@@ -6163,6 +7013,9 @@ impl ::std::clone::Clone for KhrExternalMemoryFdFn {
     }
 }
 impl KhrExternalMemoryFdFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6230,14 +7083,17 @@ impl KhrExternalMemoryFdFn {
 }
 #[doc = "Generated from 'VK_KHR_external_memory_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_fd`."]
     pub const IMPORT_MEMORY_FD_INFO_KHR: Self = Self(1_000_074_000);
 }
 #[doc = "Generated from 'VK_KHR_external_memory_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_fd`."]
     pub const MEMORY_FD_PROPERTIES_KHR: Self = Self(1_000_074_001);
 }
 #[doc = "Generated from 'VK_KHR_external_memory_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_memory_fd`."]
     pub const MEMORY_GET_FD_INFO_KHR: Self = Self(1_000_074_002);
 }
 impl KhrWin32KeyedMutexFn {
@@ -6256,6 +7112,9 @@ impl ::std::clone::Clone for KhrWin32KeyedMutexFn {
     }
 }
 impl KhrWin32KeyedMutexFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6265,6 +7124,7 @@ impl KhrWin32KeyedMutexFn {
 }
 #[doc = "Generated from 'VK_KHR_win32_keyed_mutex'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_win32_keyed_mutex`."]
     pub const WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR: Self = Self(1_000_075_000);
 }
 impl KhrExternalSemaphoreCapabilitiesFn {
@@ -6298,6 +7158,9 @@ impl ::std::clone::Clone for KhrExternalSemaphoreCapabilitiesFn {
     }
 }
 impl KhrExternalSemaphoreCapabilitiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6341,46 +7204,55 @@ impl KhrExternalSemaphoreCapabilitiesFn {
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO_KHR: Self =
         StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_PROPERTIES_KHR: Self =
         StructureType::EXTERNAL_SEMAPHORE_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_KHR: Self =
         ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KHR: Self =
         ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT_KHR: Self =
         ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE_KHR: Self =
         ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_KHR: Self =
         ExternalSemaphoreHandleTypeFlags::EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE_KHR: Self =
         ExternalSemaphoreFeatureFlags::EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_capabilities'"]
 impl ExternalSemaphoreFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore_capabilities`."]
     pub const EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE_KHR: Self =
         ExternalSemaphoreFeatureFlags::EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE;
 }
@@ -6400,6 +7272,9 @@ impl ::std::clone::Clone for KhrExternalSemaphoreFn {
     }
 }
 impl KhrExternalSemaphoreFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6409,10 +7284,12 @@ impl KhrExternalSemaphoreFn {
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore`."]
     pub const EXPORT_SEMAPHORE_CREATE_INFO_KHR: Self = StructureType::EXPORT_SEMAPHORE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore'"]
 impl SemaphoreImportFlags {
+    #[doc = "Provided by `VK_KHR_external_semaphore`."]
     pub const TEMPORARY_KHR: Self = SemaphoreImportFlags::TEMPORARY;
 }
 impl KhrExternalSemaphoreWin32Fn {
@@ -6421,6 +7298,21 @@ impl KhrExternalSemaphoreWin32Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkImportSemaphoreWin32HandleKHR" | b"vkGetSemaphoreWin32HandleKHR" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportSemaphoreWin32HandleKHR = extern "system" fn(
@@ -6455,6 +7347,9 @@ impl ::std::clone::Clone for KhrExternalSemaphoreWin32Fn {
     }
 }
 impl KhrExternalSemaphoreWin32Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6521,18 +7416,22 @@ impl KhrExternalSemaphoreWin32Fn {
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_win32`."]
     pub const IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_078_000);
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_win32`."]
     pub const EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_078_001);
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_win32`."]
     pub const D3D12_FENCE_SUBMIT_INFO_KHR: Self = Self(1_000_078_002);
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_win32`."]
     pub const SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_078_003);
 }
 impl KhrExternalSemaphoreFdFn {
@@ -6541,6 +7440,19 @@ impl KhrExternalSemaphoreFdFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkImportSemaphoreFdKHR" | b"vkGetSemaphoreFdKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportSemaphoreFdKHR = extern "system" fn(
@@ -6575,6 +7487,9 @@ impl ::std::clone::Clone for KhrExternalSemaphoreFdFn {
     }
 }
 impl KhrExternalSemaphoreFdFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6638,10 +7553,12 @@ impl KhrExternalSemaphoreFdFn {
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_fd`."]
     pub const IMPORT_SEMAPHORE_FD_INFO_KHR: Self = Self(1_000_079_000);
 }
 #[doc = "Generated from 'VK_KHR_external_semaphore_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_semaphore_fd`."]
     pub const SEMAPHORE_GET_FD_INFO_KHR: Self = Self(1_000_079_001);
 }
 impl KhrPushDescriptorFn {
@@ -6650,6 +7567,21 @@ impl KhrPushDescriptorFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdPushDescriptorSetKHR" | b"vkCmdPushDescriptorSetWithTemplateKHR" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdPushDescriptorSetKHR = extern "system" fn(
@@ -6697,6 +7629,9 @@ impl ::std::clone::Clone for KhrPushDescriptorFn {
     }
 }
 impl KhrPushDescriptorFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6750,25 +7685,28 @@ impl KhrPushDescriptorFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPushDescriptorSetKHR.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_push_descriptor_set_khr(
         &self,
         command_buffer: CommandBuffer,
         pipeline_bind_point: PipelineBindPoint,
         layout: PipelineLayout,
         set: u32,
-        descriptor_write_count: u32,
-        p_descriptor_writes: *const WriteDescriptorSet,
+        descriptor_writes: &[WriteDescriptorSet],
     ) -> c_void {
         (self.cmd_push_descriptor_set_khr)(
             command_buffer,
             pipeline_bind_point,
             layout,
             set,
-            descriptor_write_count,
-            p_descriptor_writes,
+            descriptor_writes.len() as _,
+            descriptor_writes.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPushDescriptorSetWithTemplateKHR.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_push_descriptor_set_with_template_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -6788,14 +7726,17 @@ impl KhrPushDescriptorFn {
 }
 #[doc = "Generated from 'VK_KHR_push_descriptor'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_push_descriptor`."]
     pub const PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR: Self = Self(1_000_080_000);
 }
 #[doc = "Generated from 'VK_KHR_push_descriptor'"]
 impl DescriptorSetLayoutCreateFlags {
+    #[doc = "Provided by `VK_KHR_push_descriptor`."]
     pub const PUSH_DESCRIPTOR_KHR: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_KHR_push_descriptor'"]
 impl DescriptorUpdateTemplateType {
+    #[doc = "Provided by `VK_KHR_push_descriptor`."]
     pub const PUSH_DESCRIPTORS_KHR: Self = Self(1);
 }
 impl ExtConditionalRenderingFn {
@@ -6804,6 +7745,21 @@ impl ExtConditionalRenderingFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdBeginConditionalRenderingEXT" | b"vkCmdEndConditionalRenderingEXT" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBeginConditionalRenderingEXT = extern "system" fn(
@@ -6832,6 +7788,9 @@ impl ::std::clone::Clone for ExtConditionalRenderingFn {
     }
 }
 impl ExtConditionalRenderingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6877,6 +7836,8 @@ impl ExtConditionalRenderingFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginConditionalRenderingEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_begin_conditional_rendering_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -6885,6 +7846,8 @@ impl ExtConditionalRenderingFn {
         (self.cmd_begin_conditional_rendering_ext)(command_buffer, p_conditional_rendering_begin)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndConditionalRenderingEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_end_conditional_rendering_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -6894,26 +7857,32 @@ impl ExtConditionalRenderingFn {
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT: Self = Self(1_000_081_000);
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT: Self = Self(1_000_081_001);
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const CONDITIONAL_RENDERING_BEGIN_INFO_EXT: Self = Self(1_000_081_002);
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const CONDITIONAL_RENDERING_READ_EXT: Self = Self(0b1_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const CONDITIONAL_RENDERING_EXT: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_conditional_rendering'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_EXT_conditional_rendering`."]
     pub const CONDITIONAL_RENDERING_EXT: Self = Self(0b100_0000_0000_0000_0000);
 }
 impl KhrShaderFloat16Int8Fn {
@@ -6932,6 +7901,9 @@ impl ::std::clone::Clone for KhrShaderFloat16Int8Fn {
     }
 }
 impl KhrShaderFloat16Int8Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6941,11 +7913,13 @@ impl KhrShaderFloat16Int8Fn {
 }
 #[doc = "Generated from 'VK_KHR_shader_float16_int8'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_float16_int8`."]
     pub const PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_shader_float16_int8'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_float16_int8`."]
     pub const PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES;
 }
@@ -6965,6 +7939,9 @@ impl ::std::clone::Clone for Khr16bitStorageFn {
     }
 }
 impl Khr16bitStorageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6974,6 +7951,7 @@ impl Khr16bitStorageFn {
 }
 #[doc = "Generated from 'VK_KHR_16bit_storage'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_16bit_storage`."]
     pub const PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES;
 }
@@ -6993,6 +7971,9 @@ impl ::std::clone::Clone for KhrIncrementalPresentFn {
     }
 }
 impl KhrIncrementalPresentFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7002,6 +7983,7 @@ impl KhrIncrementalPresentFn {
 }
 #[doc = "Generated from 'VK_KHR_incremental_present'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_incremental_present`."]
     pub const PRESENT_REGIONS_KHR: Self = Self(1_000_084_000);
 }
 impl KhrDescriptorUpdateTemplateFn {
@@ -7010,6 +7992,22 @@ impl KhrDescriptorUpdateTemplateFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateDescriptorUpdateTemplateKHR"
+            | b"vkDestroyDescriptorUpdateTemplateKHR"
+            | b"vkUpdateDescriptorSetWithTemplateKHR"
+            | b"vkCmdPushDescriptorSetWithTemplateKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDescriptorUpdateTemplate = extern "system" fn(
@@ -7071,6 +8069,9 @@ impl ::std::clone::Clone for KhrDescriptorUpdateTemplateFn {
     }
 }
 impl KhrDescriptorUpdateTemplateFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7206,6 +8207,8 @@ impl KhrDescriptorUpdateTemplateFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPushDescriptorSetWithTemplateKHR.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_push_descriptor_set_with_template_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -7225,19 +8228,23 @@ impl KhrDescriptorUpdateTemplateFn {
 }
 #[doc = "Generated from 'VK_KHR_descriptor_update_template'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_descriptor_update_template`."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR: Self =
         StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_descriptor_update_template'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_descriptor_update_template`."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE_KHR: Self = ObjectType::DESCRIPTOR_UPDATE_TEMPLATE;
 }
 #[doc = "Generated from 'VK_KHR_descriptor_update_template'"]
 impl DescriptorUpdateTemplateType {
+    #[doc = "Provided by `VK_KHR_descriptor_update_template`."]
     pub const DESCRIPTOR_SET_KHR: Self = DescriptorUpdateTemplateType::DESCRIPTOR_SET;
 }
 #[doc = "Generated from 'VK_KHR_descriptor_update_template'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_KHR_descriptor_update_template`."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE_KHR: Self =
         DebugReportObjectTypeEXT::DESCRIPTOR_UPDATE_TEMPLATE;
 }
@@ -7257,6 +8264,9 @@ impl ::std::clone::Clone for NvxDeviceGeneratedCommandsFn {
     }
 }
 impl NvxDeviceGeneratedCommandsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7270,6 +8280,19 @@ impl NvClipSpaceWScalingFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetViewportWScalingNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetViewportWScalingNV = extern "system" fn(
@@ -7296,6 +8319,9 @@ impl ::std::clone::Clone for NvClipSpaceWScalingFn {
     }
 }
 impl NvClipSpaceWScalingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7325,27 +8351,30 @@ impl NvClipSpaceWScalingFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetViewportWScalingNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_viewport_w_scaling_nv(
         &self,
         command_buffer: CommandBuffer,
         first_viewport: u32,
-        viewport_count: u32,
-        p_viewport_w_scalings: *const ViewportWScalingNV,
+        viewport_w_scalings: &[ViewportWScalingNV],
     ) -> c_void {
         (self.cmd_set_viewport_w_scaling_nv)(
             command_buffer,
             first_viewport,
-            viewport_count,
-            p_viewport_w_scalings,
+            viewport_w_scalings.len() as _,
+            viewport_w_scalings.as_ptr(),
         )
     }
 }
 #[doc = "Generated from 'VK_NV_clip_space_w_scaling'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_clip_space_w_scaling`."]
     pub const PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV: Self = Self(1_000_087_000);
 }
 #[doc = "Generated from 'VK_NV_clip_space_w_scaling'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_NV_clip_space_w_scaling`."]
     pub const VIEWPORT_W_SCALING_NV: Self = Self(1_000_087_000);
 }
 impl ExtDirectModeDisplayFn {
@@ -7372,6 +8401,9 @@ impl ::std::clone::Clone for ExtDirectModeDisplayFn {
     }
 }
 impl ExtDirectModeDisplayFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7448,6 +8480,9 @@ impl ::std::clone::Clone for ExtAcquireXlibDisplayFn {
     }
 }
 impl ExtAcquireXlibDisplayFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7547,6 +8582,9 @@ impl ::std::clone::Clone for ExtDisplaySurfaceCounterFn {
     }
 }
 impl ExtDisplaySurfaceCounterFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7590,10 +8628,12 @@ impl ExtDisplaySurfaceCounterFn {
 }
 #[doc = "Generated from 'VK_EXT_display_surface_counter'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_surface_counter`."]
     pub const SURFACE_CAPABILITIES_2_EXT: Self = Self(1_000_090_000);
 }
 #[doc = "Generated from 'VK_EXT_display_surface_counter'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_surface_counter`."]
     pub const SURFACE_CAPABILITIES2_EXT: Self = StructureType::SURFACE_CAPABILITIES_2_EXT;
 }
 impl ExtDisplayControlFn {
@@ -7602,6 +8642,22 @@ impl ExtDisplayControlFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkDisplayPowerControlEXT"
+            | b"vkRegisterDeviceEventEXT"
+            | b"vkRegisterDisplayEventEXT"
+            | b"vkGetSwapchainCounterEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDisplayPowerControlEXT = extern "system" fn(
@@ -7670,6 +8726,9 @@ impl ::std::clone::Clone for ExtDisplayControlFn {
     }
 }
 impl ExtDisplayControlFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7810,18 +8869,22 @@ impl ExtDisplayControlFn {
 }
 #[doc = "Generated from 'VK_EXT_display_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_control`."]
     pub const DISPLAY_POWER_INFO_EXT: Self = Self(1_000_091_000);
 }
 #[doc = "Generated from 'VK_EXT_display_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_control`."]
     pub const DEVICE_EVENT_INFO_EXT: Self = Self(1_000_091_001);
 }
 #[doc = "Generated from 'VK_EXT_display_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_control`."]
     pub const DISPLAY_EVENT_INFO_EXT: Self = Self(1_000_091_002);
 }
 #[doc = "Generated from 'VK_EXT_display_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_display_control`."]
     pub const SWAPCHAIN_COUNTER_CREATE_INFO_EXT: Self = Self(1_000_091_003);
 }
 impl GoogleDisplayTimingFn {
@@ -7830,6 +8893,21 @@ impl GoogleDisplayTimingFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetRefreshCycleDurationGOOGLE" | b"vkGetPastPresentationTimingGOOGLE" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetRefreshCycleDurationGOOGLE = extern "system" fn(
@@ -7868,6 +8946,9 @@ impl ::std::clone::Clone for GoogleDisplayTimingFn {
     }
 }
 impl GoogleDisplayTimingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7940,9 +9021,27 @@ impl GoogleDisplayTimingFn {
             p_presentation_timings,
         )
     }
+    #[doc = r" Like [`Self::get_past_presentation_timing_google`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_past_presentation_timing_google_with_success_code(
+        &self,
+        device: Device,
+        swapchain: SwapchainKHR,
+        p_presentation_timing_count: *mut u32,
+        p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_past_presentation_timing_google(
+            device,
+            swapchain,
+            p_presentation_timing_count,
+            p_presentation_timings,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_GOOGLE_display_timing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_GOOGLE_display_timing`."]
     pub const PRESENT_TIMES_INFO_GOOGLE: Self = Self(1_000_092_000);
 }
 impl NvSampleMaskOverrideCoverageFn {
@@ -7961,6 +9060,9 @@ impl ::std::clone::Clone for NvSampleMaskOverrideCoverageFn {
     }
 }
 impl NvSampleMaskOverrideCoverageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7984,6 +9086,9 @@ impl ::std::clone::Clone for NvGeometryShaderPassthroughFn {
     }
 }
 impl NvGeometryShaderPassthroughFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8007,6 +9112,9 @@ impl ::std::clone::Clone for NvViewportArray2Fn {
     }
 }
 impl NvViewportArray2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8030,6 +9138,9 @@ impl ::std::clone::Clone for NvxMultiviewPerViewAttributesFn {
     }
 }
 impl NvxMultiviewPerViewAttributesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8039,15 +9150,18 @@ impl NvxMultiviewPerViewAttributesFn {
 }
 #[doc = "Generated from 'VK_NVX_multiview_per_view_attributes'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NVX_multiview_per_view_attributes`."]
     pub const PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX: Self =
         Self(1_000_097_000);
 }
 #[doc = "Generated from 'VK_NVX_multiview_per_view_attributes'"]
 impl SubpassDescriptionFlags {
+    #[doc = "Provided by `VK_NVX_multiview_per_view_attributes`."]
     pub const PER_VIEW_ATTRIBUTES_NVX: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_NVX_multiview_per_view_attributes'"]
 impl SubpassDescriptionFlags {
+    #[doc = "Provided by `VK_NVX_multiview_per_view_attributes`."]
     pub const PER_VIEW_POSITION_X_ONLY_NVX: Self = Self(0b10);
 }
 impl NvViewportSwizzleFn {
@@ -8066,6 +9180,9 @@ impl ::std::clone::Clone for NvViewportSwizzleFn {
     }
 }
 impl NvViewportSwizzleFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8075,6 +9192,7 @@ impl NvViewportSwizzleFn {
 }
 #[doc = "Generated from 'VK_NV_viewport_swizzle'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_viewport_swizzle`."]
     pub const PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV: Self = Self(1_000_098_000);
 }
 impl ExtDiscardRectanglesFn {
@@ -8083,6 +9201,19 @@ impl ExtDiscardRectanglesFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetDiscardRectangleEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetDiscardRectangleEXT = extern "system" fn(
@@ -8109,6 +9240,9 @@ impl ::std::clone::Clone for ExtDiscardRectanglesFn {
     }
 }
 impl ExtDiscardRectanglesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8138,31 +9272,35 @@ impl ExtDiscardRectanglesFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDiscardRectangleEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_discard_rectangle_ext(
         &self,
         command_buffer: CommandBuffer,
         first_discard_rectangle: u32,
-        discard_rectangle_count: u32,
-        p_discard_rectangles: *const Rect2D,
+        discard_rectangles: &[Rect2D],
     ) -> c_void {
         (self.cmd_set_discard_rectangle_ext)(
             command_buffer,
             first_discard_rectangle,
-            discard_rectangle_count,
-            p_discard_rectangles,
+            discard_rectangles.len() as _,
+            discard_rectangles.as_ptr(),
         )
     }
 }
 #[doc = "Generated from 'VK_EXT_discard_rectangles'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_discard_rectangles`."]
     pub const PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT: Self = Self(1_000_099_000);
 }
 #[doc = "Generated from 'VK_EXT_discard_rectangles'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_discard_rectangles`."]
     pub const PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT: Self = Self(1_000_099_001);
 }
 #[doc = "Generated from 'VK_EXT_discard_rectangles'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_discard_rectangles`."]
     pub const DISCARD_RECTANGLE_EXT: Self = Self(1_000_099_000);
 }
 impl NvExtension101Fn {
@@ -8181,6 +9319,9 @@ impl ::std::clone::Clone for NvExtension101Fn {
     }
 }
 impl NvExtension101Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8204,6 +9345,9 @@ impl ::std::clone::Clone for ExtConservativeRasterizationFn {
     }
 }
 impl ExtConservativeRasterizationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8213,10 +9357,12 @@ impl ExtConservativeRasterizationFn {
 }
 #[doc = "Generated from 'VK_EXT_conservative_rasterization'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_conservative_rasterization`."]
     pub const PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT: Self = Self(1_000_101_000);
 }
 #[doc = "Generated from 'VK_EXT_conservative_rasterization'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_conservative_rasterization`."]
     pub const PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT: Self = Self(1_000_101_001);
 }
 impl ExtDepthClipEnableFn {
@@ -8235,6 +9381,9 @@ impl ::std::clone::Clone for ExtDepthClipEnableFn {
     }
 }
 impl ExtDepthClipEnableFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8244,10 +9393,12 @@ impl ExtDepthClipEnableFn {
 }
 #[doc = "Generated from 'VK_EXT_depth_clip_enable'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_depth_clip_enable`."]
     pub const PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT: Self = Self(1_000_102_000);
 }
 #[doc = "Generated from 'VK_EXT_depth_clip_enable'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_depth_clip_enable`."]
     pub const PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT: Self = Self(1_000_102_001);
 }
 impl NvExtension104Fn {
@@ -8266,6 +9417,9 @@ impl ::std::clone::Clone for NvExtension104Fn {
     }
 }
 impl NvExtension104Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8289,6 +9443,9 @@ impl ::std::clone::Clone for ExtSwapchainColorspaceFn {
     }
 }
 impl ExtSwapchainColorspaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8298,62 +9455,77 @@ impl ExtSwapchainColorspaceFn {
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const DISPLAY_P3_NONLINEAR_EXT: Self = Self(1_000_104_001);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const EXTENDED_SRGB_LINEAR_EXT: Self = Self(1_000_104_002);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const DISPLAY_P3_LINEAR_EXT: Self = Self(1_000_104_003);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const DCI_P3_NONLINEAR_EXT: Self = Self(1_000_104_004);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const BT709_LINEAR_EXT: Self = Self(1_000_104_005);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const BT709_NONLINEAR_EXT: Self = Self(1_000_104_006);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const BT2020_LINEAR_EXT: Self = Self(1_000_104_007);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const HDR10_ST2084_EXT: Self = Self(1_000_104_008);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const DOLBYVISION_EXT: Self = Self(1_000_104_009);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const HDR10_HLG_EXT: Self = Self(1_000_104_010);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const ADOBERGB_LINEAR_EXT: Self = Self(1_000_104_011);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const ADOBERGB_NONLINEAR_EXT: Self = Self(1_000_104_012);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const PASS_THROUGH_EXT: Self = Self(1_000_104_013);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const EXTENDED_SRGB_NONLINEAR_EXT: Self = Self(1_000_104_014);
 }
 #[doc = "Generated from 'VK_EXT_swapchain_colorspace'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_EXT_swapchain_colorspace`."]
     pub const DCI_P3_LINEAR_EXT: Self = ColorSpaceKHR::DISPLAY_P3_LINEAR_EXT;
 }
 impl ExtHdrMetadataFn {
@@ -8362,6 +9534,19 @@ impl ExtHdrMetadataFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkSetHdrMetadataEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetHdrMetadataEXT = extern "system" fn(
@@ -8388,6 +9573,9 @@ impl ::std::clone::Clone for ExtHdrMetadataFn {
     }
 }
 impl ExtHdrMetadataFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8426,6 +9614,7 @@ impl ExtHdrMetadataFn {
 }
 #[doc = "Generated from 'VK_EXT_hdr_metadata'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_hdr_metadata`."]
     pub const HDR_METADATA_EXT: Self = Self(1_000_105_000);
 }
 impl ImgExtension107Fn {
@@ -8444,6 +9633,9 @@ impl ::std::clone::Clone for ImgExtension107Fn {
     }
 }
 impl ImgExtension107Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8467,6 +9659,9 @@ impl ::std::clone::Clone for ImgExtension108Fn {
     }
 }
 impl ImgExtension108Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8490,6 +9685,9 @@ impl ::std::clone::Clone for KhrImagelessFramebufferFn {
     }
 }
 impl KhrImagelessFramebufferFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8499,26 +9697,31 @@ impl KhrImagelessFramebufferFn {
 }
 #[doc = "Generated from 'VK_KHR_imageless_framebuffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_imageless_framebuffer`."]
     pub const PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_imageless_framebuffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_imageless_framebuffer`."]
     pub const FRAMEBUFFER_ATTACHMENTS_CREATE_INFO_KHR: Self =
         StructureType::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_imageless_framebuffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_imageless_framebuffer`."]
     pub const FRAMEBUFFER_ATTACHMENT_IMAGE_INFO_KHR: Self =
         StructureType::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_imageless_framebuffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_imageless_framebuffer`."]
     pub const RENDER_PASS_ATTACHMENT_BEGIN_INFO_KHR: Self =
         StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO;
 }
 #[doc = "Generated from 'VK_KHR_imageless_framebuffer'"]
 impl FramebufferCreateFlags {
+    #[doc = "Provided by `VK_KHR_imageless_framebuffer`."]
     pub const IMAGELESS_KHR: Self = FramebufferCreateFlags::IMAGELESS;
 }
 impl KhrCreateRenderpass2Fn {
@@ -8527,6 +9730,22 @@ impl KhrCreateRenderpass2Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateRenderPass2KHR"
+            | b"vkCmdBeginRenderPass2KHR"
+            | b"vkCmdNextSubpass2KHR"
+            | b"vkCmdEndRenderPass2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateRenderPass2 = extern "system" fn(
@@ -8587,6 +9806,9 @@ impl ::std::clone::Clone for KhrCreateRenderpass2Fn {
     }
 }
 impl KhrCreateRenderpass2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8685,6 +9907,8 @@ impl KhrCreateRenderpass2Fn {
         (self.create_render_pass2_khr)(device, p_create_info, p_allocator, p_render_pass)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginRenderPass2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_begin_render_pass2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -8694,6 +9918,8 @@ impl KhrCreateRenderpass2Fn {
         (self.cmd_begin_render_pass2_khr)(command_buffer, p_render_pass_begin, p_subpass_begin_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdNextSubpass2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_next_subpass2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -8703,6 +9929,8 @@ impl KhrCreateRenderpass2Fn {
         (self.cmd_next_subpass2_khr)(command_buffer, p_subpass_begin_info, p_subpass_end_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_end_render_pass2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -8713,30 +9941,37 @@ impl KhrCreateRenderpass2Fn {
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const ATTACHMENT_DESCRIPTION_2_KHR: Self = StructureType::ATTACHMENT_DESCRIPTION_2;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const ATTACHMENT_REFERENCE_2_KHR: Self = StructureType::ATTACHMENT_REFERENCE_2;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const SUBPASS_DESCRIPTION_2_KHR: Self = StructureType::SUBPASS_DESCRIPTION_2;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const SUBPASS_DEPENDENCY_2_KHR: Self = StructureType::SUBPASS_DEPENDENCY_2;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const RENDER_PASS_CREATE_INFO_2_KHR: Self = StructureType::RENDER_PASS_CREATE_INFO_2;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const SUBPASS_BEGIN_INFO_KHR: Self = StructureType::SUBPASS_BEGIN_INFO;
 }
 #[doc = "Generated from 'VK_KHR_create_renderpass2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_create_renderpass2`."]
     pub const SUBPASS_END_INFO_KHR: Self = StructureType::SUBPASS_END_INFO;
 }
 impl ImgExtension111Fn {
@@ -8755,6 +9990,9 @@ impl ::std::clone::Clone for ImgExtension111Fn {
     }
 }
 impl ImgExtension111Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8768,6 +10006,19 @@ impl KhrSharedPresentableImageFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetSwapchainStatusKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSwapchainStatusKHR =
@@ -8786,6 +10037,9 @@ impl ::std::clone::Clone for KhrSharedPresentableImageFn {
     }
 }
 impl KhrSharedPresentableImageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8820,21 +10074,35 @@ impl KhrSharedPresentableImageFn {
     ) -> Result {
         (self.get_swapchain_status_khr)(device, swapchain)
     }
+    #[doc = r" Like [`Self::get_swapchain_status_khr`], but returns the success code, which is one of `SUCCESS`, `SUBOPTIMAL_KHR`."]
+    pub unsafe fn get_swapchain_status_khr_with_success_code(
+        &self,
+        device: Device,
+        swapchain: SwapchainKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_swapchain_status_khr(device, swapchain)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_shared_presentable_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shared_presentable_image`."]
     pub const SHARED_PRESENT_SURFACE_CAPABILITIES_KHR: Self = Self(1_000_111_000);
 }
 #[doc = "Generated from 'VK_KHR_shared_presentable_image'"]
 impl PresentModeKHR {
+    #[doc = "Provided by `VK_KHR_shared_presentable_image`."]
     pub const SHARED_DEMAND_REFRESH: Self = Self(1_000_111_000);
 }
 #[doc = "Generated from 'VK_KHR_shared_presentable_image'"]
 impl PresentModeKHR {
+    #[doc = "Provided by `VK_KHR_shared_presentable_image`."]
     pub const SHARED_CONTINUOUS_REFRESH: Self = Self(1_000_111_001);
 }
 #[doc = "Generated from 'VK_KHR_shared_presentable_image'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_shared_presentable_image`."]
     pub const SHARED_PRESENT_KHR: Self = Self(1_000_111_000);
 }
 impl KhrExternalFenceCapabilitiesFn {
@@ -8868,6 +10136,9 @@ impl ::std::clone::Clone for KhrExternalFenceCapabilitiesFn {
     }
 }
 impl KhrExternalFenceCapabilitiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8911,40 +10182,48 @@ impl KhrExternalFenceCapabilitiesFn {
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO_KHR: Self =
         StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_PROPERTIES_KHR: Self = StructureType::EXTERNAL_FENCE_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_KHR: Self =
         ExternalFenceHandleTypeFlags::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KHR: Self =
         ExternalFenceHandleTypeFlags::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT_KHR: Self =
         ExternalFenceHandleTypeFlags::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceHandleTypeFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_KHR: Self =
         ExternalFenceHandleTypeFlags::EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_FEATURE_EXPORTABLE_KHR: Self =
         ExternalFenceFeatureFlags::EXTERNAL_FENCE_FEATURE_EXPORTABLE;
 }
 #[doc = "Generated from 'VK_KHR_external_fence_capabilities'"]
 impl ExternalFenceFeatureFlags {
+    #[doc = "Provided by `VK_KHR_external_fence_capabilities`."]
     pub const EXTERNAL_FENCE_FEATURE_IMPORTABLE_KHR: Self =
         ExternalFenceFeatureFlags::EXTERNAL_FENCE_FEATURE_IMPORTABLE;
 }
@@ -8964,6 +10243,9 @@ impl ::std::clone::Clone for KhrExternalFenceFn {
     }
 }
 impl KhrExternalFenceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -8973,10 +10255,12 @@ impl KhrExternalFenceFn {
 }
 #[doc = "Generated from 'VK_KHR_external_fence'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence`."]
     pub const EXPORT_FENCE_CREATE_INFO_KHR: Self = StructureType::EXPORT_FENCE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_external_fence'"]
 impl FenceImportFlags {
+    #[doc = "Provided by `VK_KHR_external_fence`."]
     pub const TEMPORARY_KHR: Self = FenceImportFlags::TEMPORARY;
 }
 impl KhrExternalFenceWin32Fn {
@@ -8985,6 +10269,19 @@ impl KhrExternalFenceWin32Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkImportFenceWin32HandleKHR" | b"vkGetFenceWin32HandleKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportFenceWin32HandleKHR = extern "system" fn(
@@ -9019,6 +10316,9 @@ impl ::std::clone::Clone for KhrExternalFenceWin32Fn {
     }
 }
 impl KhrExternalFenceWin32Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9085,14 +10385,17 @@ impl KhrExternalFenceWin32Fn {
 }
 #[doc = "Generated from 'VK_KHR_external_fence_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_win32`."]
     pub const IMPORT_FENCE_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_114_000);
 }
 #[doc = "Generated from 'VK_KHR_external_fence_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_win32`."]
     pub const EXPORT_FENCE_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_114_001);
 }
 #[doc = "Generated from 'VK_KHR_external_fence_win32'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_win32`."]
     pub const FENCE_GET_WIN32_HANDLE_INFO_KHR: Self = Self(1_000_114_002);
 }
 impl KhrExternalFenceFdFn {
@@ -9101,6 +10404,19 @@ impl KhrExternalFenceFdFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkImportFenceFdKHR" | b"vkGetFenceFdKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportFenceFdKHR = extern "system" fn(
@@ -9135,6 +10451,9 @@ impl ::std::clone::Clone for KhrExternalFenceFdFn {
     }
 }
 impl KhrExternalFenceFdFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9195,10 +10514,12 @@ impl KhrExternalFenceFdFn {
 }
 #[doc = "Generated from 'VK_KHR_external_fence_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_fd`."]
     pub const IMPORT_FENCE_FD_INFO_KHR: Self = Self(1_000_115_000);
 }
 #[doc = "Generated from 'VK_KHR_external_fence_fd'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_external_fence_fd`."]
     pub const FENCE_GET_FD_INFO_KHR: Self = Self(1_000_115_001);
 }
 impl KhrPerformanceQueryFn {
@@ -9207,6 +10528,19 @@ impl KhrPerformanceQueryFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkAcquireProfilingLockKHR" | b"vkReleaseProfilingLockKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR =
@@ -9263,6 +10597,9 @@ impl ::std::clone::Clone for KhrPerformanceQueryFn {
     }
 }
 impl KhrPerformanceQueryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9392,37 +10729,64 @@ impl KhrPerformanceQueryFn {
     pub unsafe fn release_profiling_lock_khr(&self, device: Device) -> c_void {
         (self.release_profiling_lock_khr)(device)
     }
+    #[doc = r" Like [`Self::enumerate_physical_device_queue_family_performance_query_counters_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_physical_device_queue_family_performance_query_counters_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        p_counter_count: *mut u32,
+        p_counters: *mut PerformanceCounterKHR,
+        p_counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_physical_device_queue_family_performance_query_counters_khr(
+            physical_device,
+            queue_family_index,
+            p_counter_count,
+            p_counters,
+            p_counter_descriptions,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl QueryType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PERFORMANCE_QUERY_KHR: Self = Self(1_000_116_000);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR: Self = Self(1_000_116_000);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR: Self = Self(1_000_116_001);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR: Self = Self(1_000_116_002);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PERFORMANCE_QUERY_SUBMIT_INFO_KHR: Self = Self(1_000_116_003);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const ACQUIRE_PROFILING_LOCK_INFO_KHR: Self = Self(1_000_116_004);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PERFORMANCE_COUNTER_KHR: Self = Self(1_000_116_005);
 }
 #[doc = "Generated from 'VK_KHR_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_performance_query`."]
     pub const PERFORMANCE_COUNTER_DESCRIPTION_KHR: Self = Self(1_000_116_006);
 }
 impl KhrMaintenance2Fn {
@@ -9441,6 +10805,9 @@ impl ::std::clone::Clone for KhrMaintenance2Fn {
     }
 }
 impl KhrMaintenance2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9450,55 +10817,67 @@ impl KhrMaintenance2Fn {
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const BLOCK_TEXEL_VIEW_COMPATIBLE_KHR: Self = ImageCreateFlags::BLOCK_TEXEL_VIEW_COMPATIBLE;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const EXTENDED_USAGE_KHR: Self = ImageCreateFlags::EXTENDED_USAGE;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO_KHR: Self =
         StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const IMAGE_VIEW_USAGE_CREATE_INFO_KHR: Self = StructureType::IMAGE_VIEW_USAGE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO_KHR: Self =
         StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL_KHR: Self =
         ImageLayout::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL_KHR: Self =
         ImageLayout::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl PointClippingBehavior {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const ALL_CLIP_PLANES_KHR: Self = PointClippingBehavior::ALL_CLIP_PLANES;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl PointClippingBehavior {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const USER_CLIP_PLANES_ONLY_KHR: Self = PointClippingBehavior::USER_CLIP_PLANES_ONLY;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl TessellationDomainOrigin {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const UPPER_LEFT_KHR: Self = TessellationDomainOrigin::UPPER_LEFT;
 }
 #[doc = "Generated from 'VK_KHR_maintenance2'"]
 impl TessellationDomainOrigin {
+    #[doc = "Provided by `VK_KHR_maintenance2`."]
     pub const LOWER_LEFT_KHR: Self = TessellationDomainOrigin::LOWER_LEFT;
 }
 impl KhrExtension119Fn {
@@ -9517,6 +10896,9 @@ impl ::std::clone::Clone for KhrExtension119Fn {
     }
 }
 impl KhrExtension119Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9569,6 +10951,9 @@ impl ::std::clone::Clone for KhrGetSurfaceCapabilities2Fn {
     }
 }
 impl KhrGetSurfaceCapabilities2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9645,17 +11030,37 @@ impl KhrGetSurfaceCapabilities2Fn {
             p_surface_formats,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_surface_formats2_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_surface_formats2_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+        p_surface_format_count: *mut u32,
+        p_surface_formats: *mut SurfaceFormat2KHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_surface_formats2_khr(
+            physical_device,
+            p_surface_info,
+            p_surface_format_count,
+            p_surface_formats,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_get_surface_capabilities2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_surface_capabilities2`."]
     pub const PHYSICAL_DEVICE_SURFACE_INFO_2_KHR: Self = Self(1_000_119_000);
 }
 #[doc = "Generated from 'VK_KHR_get_surface_capabilities2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_surface_capabilities2`."]
     pub const SURFACE_CAPABILITIES_2_KHR: Self = Self(1_000_119_001);
 }
 #[doc = "Generated from 'VK_KHR_get_surface_capabilities2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_surface_capabilities2`."]
     pub const SURFACE_FORMAT_2_KHR: Self = Self(1_000_119_002);
 }
 impl KhrVariablePointersFn {
@@ -9674,6 +11079,9 @@ impl ::std::clone::Clone for KhrVariablePointersFn {
     }
 }
 impl KhrVariablePointersFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9683,11 +11091,13 @@ impl KhrVariablePointersFn {
 }
 #[doc = "Generated from 'VK_KHR_variable_pointers'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_variable_pointers`."]
     pub const PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_variable_pointers'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_variable_pointers`."]
     pub const PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR;
 }
@@ -9761,6 +11171,9 @@ impl ::std::clone::Clone for KhrGetDisplayProperties2Fn {
     }
 }
 impl KhrGetDisplayProperties2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9890,38 +11303,90 @@ impl KhrGetDisplayProperties2Fn {
             p_properties,
         )
     }
-    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html>"]
-    pub unsafe fn get_display_plane_capabilities2_khr(
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html>"]
+    pub unsafe fn get_display_plane_capabilities2_khr(
+        &self,
+        physical_device: PhysicalDevice,
+        p_display_plane_info: *const DisplayPlaneInfo2KHR,
+        p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+    ) -> Result {
+        (self.get_display_plane_capabilities2_khr)(
+            physical_device,
+            p_display_plane_info,
+            p_capabilities,
+        )
+    }
+    #[doc = r" Like [`Self::get_physical_device_display_properties2_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_display_properties2_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayProperties2KHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_display_properties2_khr(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_physical_device_display_plane_properties2_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_display_plane_properties2_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayPlaneProperties2KHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_display_plane_properties2_khr(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_display_mode_properties2_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_display_mode_properties2_khr_with_success_code(
         &self,
         physical_device: PhysicalDevice,
-        p_display_plane_info: *const DisplayPlaneInfo2KHR,
-        p_capabilities: *mut DisplayPlaneCapabilities2KHR,
-    ) -> Result {
-        (self.get_display_plane_capabilities2_khr)(
+        display: DisplayKHR,
+        p_property_count: *mut u32,
+        p_properties: *mut DisplayModeProperties2KHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_display_mode_properties2_khr(
             physical_device,
-            p_display_plane_info,
-            p_capabilities,
+            display,
+            p_property_count,
+            p_properties,
         )
+        .into_codes()
+        .map_err(Result::from)
     }
 }
 #[doc = "Generated from 'VK_KHR_get_display_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_display_properties2`."]
     pub const DISPLAY_PROPERTIES_2_KHR: Self = Self(1_000_121_000);
 }
 #[doc = "Generated from 'VK_KHR_get_display_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_display_properties2`."]
     pub const DISPLAY_PLANE_PROPERTIES_2_KHR: Self = Self(1_000_121_001);
 }
 #[doc = "Generated from 'VK_KHR_get_display_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_display_properties2`."]
     pub const DISPLAY_MODE_PROPERTIES_2_KHR: Self = Self(1_000_121_002);
 }
 #[doc = "Generated from 'VK_KHR_get_display_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_display_properties2`."]
     pub const DISPLAY_PLANE_INFO_2_KHR: Self = Self(1_000_121_003);
 }
 #[doc = "Generated from 'VK_KHR_get_display_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_display_properties2`."]
     pub const DISPLAY_PLANE_CAPABILITIES_2_KHR: Self = Self(1_000_121_004);
 }
 impl MvkIosSurfaceFn {
@@ -9956,6 +11421,9 @@ impl ::std::clone::Clone for MvkIosSurfaceFn {
     }
 }
 impl MvkIosSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -9997,6 +11465,7 @@ impl MvkIosSurfaceFn {
 }
 #[doc = "Generated from 'VK_MVK_ios_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_MVK_ios_surface`."]
     pub const IOS_SURFACE_CREATE_INFO_M: Self = Self(1_000_122_000);
 }
 impl MvkMacosSurfaceFn {
@@ -10031,6 +11500,9 @@ impl ::std::clone::Clone for MvkMacosSurfaceFn {
     }
 }
 impl MvkMacosSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10072,6 +11544,7 @@ impl MvkMacosSurfaceFn {
 }
 #[doc = "Generated from 'VK_MVK_macos_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_MVK_macos_surface`."]
     pub const MACOS_SURFACE_CREATE_INFO_M: Self = Self(1_000_123_000);
 }
 impl MvkMoltenvkFn {
@@ -10089,6 +11562,9 @@ impl ::std::clone::Clone for MvkMoltenvkFn {
     }
 }
 impl MvkMoltenvkFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10112,6 +11588,9 @@ impl ::std::clone::Clone for ExtExternalMemoryDmaBufFn {
     }
 }
 impl ExtExternalMemoryDmaBufFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10121,6 +11600,7 @@ impl ExtExternalMemoryDmaBufFn {
 }
 #[doc = "Generated from 'VK_EXT_external_memory_dma_buf'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_EXT_external_memory_dma_buf`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF: Self = Self(0b10_0000_0000);
 }
 impl ExtQueueFamilyForeignFn {
@@ -10139,6 +11619,9 @@ impl ::std::clone::Clone for ExtQueueFamilyForeignFn {
     }
 }
 impl ExtQueueFamilyForeignFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10162,6 +11645,9 @@ impl ::std::clone::Clone for KhrDedicatedAllocationFn {
     }
 }
 impl KhrDedicatedAllocationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10171,11 +11657,13 @@ impl KhrDedicatedAllocationFn {
 }
 #[doc = "Generated from 'VK_KHR_dedicated_allocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_dedicated_allocation`."]
     pub const MEMORY_DEDICATED_REQUIREMENTS_KHR: Self =
         StructureType::MEMORY_DEDICATED_REQUIREMENTS;
 }
 #[doc = "Generated from 'VK_KHR_dedicated_allocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_dedicated_allocation`."]
     pub const MEMORY_DEDICATED_ALLOCATE_INFO_KHR: Self =
         StructureType::MEMORY_DEDICATED_ALLOCATE_INFO;
 }
@@ -10185,6 +11673,26 @@ impl ExtDebugUtilsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkSetDebugUtilsObjectNameEXT"
+            | b"vkSetDebugUtilsObjectTagEXT"
+            | b"vkQueueBeginDebugUtilsLabelEXT"
+            | b"vkQueueEndDebugUtilsLabelEXT"
+            | b"vkQueueInsertDebugUtilsLabelEXT"
+            | b"vkCmdBeginDebugUtilsLabelEXT"
+            | b"vkCmdEndDebugUtilsLabelEXT"
+            | b"vkCmdInsertDebugUtilsLabelEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetDebugUtilsObjectNameEXT =
@@ -10292,6 +11800,9 @@ impl ::std::clone::Clone for ExtDebugUtilsFn {
     }
 }
 impl ExtDebugUtilsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10546,6 +12057,8 @@ impl ExtDebugUtilsFn {
         (self.queue_insert_debug_utils_label_ext)(queue, p_label_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginDebugUtilsLabelEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_begin_debug_utils_label_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -10554,10 +12067,14 @@ impl ExtDebugUtilsFn {
         (self.cmd_begin_debug_utils_label_ext)(command_buffer, p_label_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_end_debug_utils_label_ext(&self, command_buffer: CommandBuffer) -> c_void {
         (self.cmd_end_debug_utils_label_ext)(command_buffer)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdInsertDebugUtilsLabelEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_insert_debug_utils_label_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -10602,26 +12119,32 @@ impl ExtDebugUtilsFn {
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_OBJECT_NAME_INFO_EXT: Self = Self(1_000_128_000);
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_OBJECT_TAG_INFO_EXT: Self = Self(1_000_128_001);
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_LABEL_EXT: Self = Self(1_000_128_002);
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT: Self = Self(1_000_128_003);
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT: Self = Self(1_000_128_004);
 }
 #[doc = "Generated from 'VK_EXT_debug_utils'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_EXT_debug_utils`."]
     pub const DEBUG_UTILS_MESSENGER_EXT: Self = Self(1_000_128_000);
 }
 impl AndroidExternalMemoryAndroidHardwareBufferFn {
@@ -10632,6 +12155,20 @@ impl AndroidExternalMemoryAndroidHardwareBufferFn {
         .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 3u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetAndroidHardwareBufferPropertiesANDROID"
+            | b"vkGetMemoryAndroidHardwareBufferANDROID" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetAndroidHardwareBufferPropertiesANDROID = extern "system" fn(
@@ -10670,6 +12207,9 @@ impl ::std::clone::Clone for AndroidExternalMemoryAndroidHardwareBufferFn {
     }
 }
 impl AndroidExternalMemoryAndroidHardwareBufferFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10738,31 +12278,38 @@ impl AndroidExternalMemoryAndroidHardwareBufferFn {
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_ANDROID_HARDWARE_BUFFER_ANDROID: Self =
         Self(0b100_0000_0000);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const ANDROID_HARDWARE_BUFFER_USAGE_ANDROID: Self = Self(1_000_129_000);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID: Self = Self(1_000_129_001);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID: Self = Self(1_000_129_002);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID: Self = Self(1_000_129_003);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID: Self = Self(1_000_129_004);
 }
 #[doc = "Generated from 'VK_ANDROID_external_memory_android_hardware_buffer'"]
 impl StructureType {
+    #[doc = "Provided by `VK_ANDROID_external_memory_android_hardware_buffer`."]
     pub const EXTERNAL_FORMAT_ANDROID: Self = Self(1_000_129_005);
 }
 impl ExtSamplerFilterMinmaxFn {
@@ -10781,6 +12328,9 @@ impl ::std::clone::Clone for ExtSamplerFilterMinmaxFn {
     }
 }
 impl ExtSamplerFilterMinmaxFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10790,29 +12340,35 @@ impl ExtSamplerFilterMinmaxFn {
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES;
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT: Self =
         StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const SAMPLED_IMAGE_FILTER_MINMAX_EXT: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_FILTER_MINMAX;
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl SamplerReductionMode {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const WEIGHTED_AVERAGE_EXT: Self = SamplerReductionMode::WEIGHTED_AVERAGE;
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl SamplerReductionMode {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const MIN_EXT: Self = SamplerReductionMode::MIN;
 }
 #[doc = "Generated from 'VK_EXT_sampler_filter_minmax'"]
 impl SamplerReductionMode {
+    #[doc = "Provided by `VK_EXT_sampler_filter_minmax`."]
     pub const MAX_EXT: Self = SamplerReductionMode::MAX;
 }
 impl KhrStorageBufferStorageClassFn {
@@ -10831,6 +12387,9 @@ impl ::std::clone::Clone for KhrStorageBufferStorageClassFn {
     }
 }
 impl KhrStorageBufferStorageClassFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10854,6 +12413,9 @@ impl ::std::clone::Clone for AmdGpuShaderInt16Fn {
     }
 }
 impl AmdGpuShaderInt16Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10877,6 +12439,9 @@ impl ::std::clone::Clone for AmdExtension134Fn {
     }
 }
 impl AmdExtension134Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10900,6 +12465,9 @@ impl ::std::clone::Clone for AmdExtension135Fn {
     }
 }
 impl AmdExtension135Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10923,6 +12491,9 @@ impl ::std::clone::Clone for AmdExtension136Fn {
     }
 }
 impl AmdExtension136Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10946,6 +12517,9 @@ impl ::std::clone::Clone for AmdMixedAttachmentSamplesFn {
     }
 }
 impl AmdMixedAttachmentSamplesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10969,6 +12543,9 @@ impl ::std::clone::Clone for AmdShaderFragmentMaskFn {
     }
 }
 impl AmdShaderFragmentMaskFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -10992,6 +12569,9 @@ impl ::std::clone::Clone for ExtInlineUniformBlockFn {
     }
 }
 impl ExtInlineUniformBlockFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11001,22 +12581,27 @@ impl ExtInlineUniformBlockFn {
 }
 #[doc = "Generated from 'VK_EXT_inline_uniform_block'"]
 impl DescriptorType {
+    #[doc = "Provided by `VK_EXT_inline_uniform_block`."]
     pub const INLINE_UNIFORM_BLOCK_EXT: Self = Self(1_000_138_000);
 }
 #[doc = "Generated from 'VK_EXT_inline_uniform_block'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_inline_uniform_block`."]
     pub const PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT: Self = Self(1_000_138_000);
 }
 #[doc = "Generated from 'VK_EXT_inline_uniform_block'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_inline_uniform_block`."]
     pub const PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT: Self = Self(1_000_138_001);
 }
 #[doc = "Generated from 'VK_EXT_inline_uniform_block'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_inline_uniform_block`."]
     pub const WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT: Self = Self(1_000_138_002);
 }
 #[doc = "Generated from 'VK_EXT_inline_uniform_block'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_inline_uniform_block`."]
     pub const DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT: Self = Self(1_000_138_003);
 }
 impl AmdExtension140Fn {
@@ -11035,6 +12620,9 @@ impl ::std::clone::Clone for AmdExtension140Fn {
     }
 }
 impl AmdExtension140Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11058,6 +12646,9 @@ impl ::std::clone::Clone for ExtShaderStencilExportFn {
     }
 }
 impl ExtShaderStencilExportFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11081,6 +12672,9 @@ impl ::std::clone::Clone for AmdExtension142Fn {
     }
 }
 impl AmdExtension142Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11104,6 +12698,9 @@ impl ::std::clone::Clone for AmdExtension143Fn {
     }
 }
 impl AmdExtension143Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11117,6 +12714,19 @@ impl ExtSampleLocationsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetSampleLocationsEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetSampleLocationsEXT = extern "system" fn(
@@ -11152,6 +12762,9 @@ impl ::std::clone::Clone for ExtSampleLocationsFn {
     }
 }
 impl ExtSampleLocationsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11199,6 +12812,8 @@ impl ExtSampleLocationsFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetSampleLocationsEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_sample_locations_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -11222,30 +12837,37 @@ impl ExtSampleLocationsFn {
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT: Self = Self(0b1_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const SAMPLE_LOCATIONS_INFO_EXT: Self = Self(1_000_143_000);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT: Self = Self(1_000_143_001);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT: Self = Self(1_000_143_002);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT: Self = Self(1_000_143_003);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const MULTISAMPLE_PROPERTIES_EXT: Self = Self(1_000_143_004);
 }
 #[doc = "Generated from 'VK_EXT_sample_locations'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_sample_locations`."]
     pub const SAMPLE_LOCATIONS_EXT: Self = Self(1_000_143_000);
 }
 impl KhrRelaxedBlockLayoutFn {
@@ -11264,6 +12886,9 @@ impl ::std::clone::Clone for KhrRelaxedBlockLayoutFn {
     }
 }
 impl KhrRelaxedBlockLayoutFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11277,6 +12902,21 @@ impl KhrGetMemoryRequirements2Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetImageMemoryRequirements2KHR"
+            | b"vkGetBufferMemoryRequirements2KHR"
+            | b"vkGetImageSparseMemoryRequirements2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageMemoryRequirements2 = extern "system" fn(
@@ -11328,6 +12968,9 @@ impl ::std::clone::Clone for KhrGetMemoryRequirements2Fn {
     }
 }
 impl KhrGetMemoryRequirements2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11432,25 +13075,30 @@ impl KhrGetMemoryRequirements2Fn {
 }
 #[doc = "Generated from 'VK_KHR_get_memory_requirements2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_memory_requirements2`."]
     pub const BUFFER_MEMORY_REQUIREMENTS_INFO_2_KHR: Self =
         StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2;
 }
 #[doc = "Generated from 'VK_KHR_get_memory_requirements2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_memory_requirements2`."]
     pub const IMAGE_MEMORY_REQUIREMENTS_INFO_2_KHR: Self =
         StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2;
 }
 #[doc = "Generated from 'VK_KHR_get_memory_requirements2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_memory_requirements2`."]
     pub const IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2_KHR: Self =
         StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2;
 }
 #[doc = "Generated from 'VK_KHR_get_memory_requirements2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_memory_requirements2`."]
     pub const MEMORY_REQUIREMENTS_2_KHR: Self = StructureType::MEMORY_REQUIREMENTS_2;
 }
 #[doc = "Generated from 'VK_KHR_get_memory_requirements2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_get_memory_requirements2`."]
     pub const SPARSE_IMAGE_MEMORY_REQUIREMENTS_2_KHR: Self =
         StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2;
 }
@@ -11470,6 +13118,9 @@ impl ::std::clone::Clone for KhrImageFormatListFn {
     }
 }
 impl KhrImageFormatListFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11479,6 +13130,7 @@ impl KhrImageFormatListFn {
 }
 #[doc = "Generated from 'VK_KHR_image_format_list'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_image_format_list`."]
     pub const IMAGE_FORMAT_LIST_CREATE_INFO_KHR: Self =
         StructureType::IMAGE_FORMAT_LIST_CREATE_INFO;
 }
@@ -11498,6 +13150,9 @@ impl ::std::clone::Clone for ExtBlendOperationAdvancedFn {
     }
 }
 impl ExtBlendOperationAdvancedFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11507,202 +13162,252 @@ impl ExtBlendOperationAdvancedFn {
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT: Self = Self(1_000_148_000);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT: Self = Self(1_000_148_001);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT: Self = Self(1_000_148_002);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const ZERO_EXT: Self = Self(1_000_148_000);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SRC_EXT: Self = Self(1_000_148_001);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DST_EXT: Self = Self(1_000_148_002);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SRC_OVER_EXT: Self = Self(1_000_148_003);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DST_OVER_EXT: Self = Self(1_000_148_004);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SRC_IN_EXT: Self = Self(1_000_148_005);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DST_IN_EXT: Self = Self(1_000_148_006);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SRC_OUT_EXT: Self = Self(1_000_148_007);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DST_OUT_EXT: Self = Self(1_000_148_008);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SRC_ATOP_EXT: Self = Self(1_000_148_009);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DST_ATOP_EXT: Self = Self(1_000_148_010);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const XOR_EXT: Self = Self(1_000_148_011);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const MULTIPLY_EXT: Self = Self(1_000_148_012);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SCREEN_EXT: Self = Self(1_000_148_013);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const OVERLAY_EXT: Self = Self(1_000_148_014);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DARKEN_EXT: Self = Self(1_000_148_015);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const LIGHTEN_EXT: Self = Self(1_000_148_016);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const COLORDODGE_EXT: Self = Self(1_000_148_017);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const COLORBURN_EXT: Self = Self(1_000_148_018);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HARDLIGHT_EXT: Self = Self(1_000_148_019);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const SOFTLIGHT_EXT: Self = Self(1_000_148_020);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const DIFFERENCE_EXT: Self = Self(1_000_148_021);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const EXCLUSION_EXT: Self = Self(1_000_148_022);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const INVERT_EXT: Self = Self(1_000_148_023);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const INVERT_RGB_EXT: Self = Self(1_000_148_024);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const LINEARDODGE_EXT: Self = Self(1_000_148_025);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const LINEARBURN_EXT: Self = Self(1_000_148_026);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const VIVIDLIGHT_EXT: Self = Self(1_000_148_027);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const LINEARLIGHT_EXT: Self = Self(1_000_148_028);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PINLIGHT_EXT: Self = Self(1_000_148_029);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HARDMIX_EXT: Self = Self(1_000_148_030);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HSL_HUE_EXT: Self = Self(1_000_148_031);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HSL_SATURATION_EXT: Self = Self(1_000_148_032);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HSL_COLOR_EXT: Self = Self(1_000_148_033);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const HSL_LUMINOSITY_EXT: Self = Self(1_000_148_034);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PLUS_EXT: Self = Self(1_000_148_035);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PLUS_CLAMPED_EXT: Self = Self(1_000_148_036);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PLUS_CLAMPED_ALPHA_EXT: Self = Self(1_000_148_037);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const PLUS_DARKER_EXT: Self = Self(1_000_148_038);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const MINUS_EXT: Self = Self(1_000_148_039);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const MINUS_CLAMPED_EXT: Self = Self(1_000_148_040);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const CONTRAST_EXT: Self = Self(1_000_148_041);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const INVERT_OVG_EXT: Self = Self(1_000_148_042);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const RED_EXT: Self = Self(1_000_148_043);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const GREEN_EXT: Self = Self(1_000_148_044);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl BlendOp {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const BLUE_EXT: Self = Self(1_000_148_045);
 }
 #[doc = "Generated from 'VK_EXT_blend_operation_advanced'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_blend_operation_advanced`."]
     pub const COLOR_ATTACHMENT_READ_NONCOHERENT_EXT: Self = Self(0b1000_0000_0000_0000_0000);
 }
 impl NvFragmentCoverageToColorFn {
@@ -11721,6 +13426,9 @@ impl ::std::clone::Clone for NvFragmentCoverageToColorFn {
     }
 }
 impl NvFragmentCoverageToColorFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -11730,6 +13438,7 @@ impl NvFragmentCoverageToColorFn {
 }
 #[doc = "Generated from 'VK_NV_fragment_coverage_to_color'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_fragment_coverage_to_color`."]
     pub const PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV: Self = Self(1_000_149_000);
 }
 impl KhrAccelerationStructureFn {
@@ -11738,6 +13447,34 @@ impl KhrAccelerationStructureFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 11u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateAccelerationStructureKHR"
+            | b"vkDestroyAccelerationStructureKHR"
+            | b"vkCmdBuildAccelerationStructuresKHR"
+            | b"vkCmdBuildAccelerationStructuresIndirectKHR"
+            | b"vkBuildAccelerationStructuresKHR"
+            | b"vkCopyAccelerationStructureKHR"
+            | b"vkCopyAccelerationStructureToMemoryKHR"
+            | b"vkCopyMemoryToAccelerationStructureKHR"
+            | b"vkWriteAccelerationStructuresPropertiesKHR"
+            | b"vkCmdCopyAccelerationStructureKHR"
+            | b"vkCmdCopyAccelerationStructureToMemoryKHR"
+            | b"vkCmdCopyMemoryToAccelerationStructureKHR"
+            | b"vkGetAccelerationStructureDeviceAddressKHR"
+            | b"vkCmdWriteAccelerationStructuresPropertiesKHR"
+            | b"vkGetDeviceAccelerationStructureCompatibilityKHR"
+            | b"vkGetAccelerationStructureBuildSizesKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateAccelerationStructureKHR = extern "system" fn(
@@ -11800,9 +13537,9 @@ pub type PFN_vkWriteAccelerationStructuresPropertiesKHR = extern "system" fn(
     acceleration_structure_count: u32,
     p_acceleration_structures: *const AccelerationStructureKHR,
     query_type: QueryType,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
-    stride: usize,
+    stride: size_t,
 ) -> Result;
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdCopyAccelerationStructureKHR = extern "system" fn(
@@ -11900,9 +13637,9 @@ pub struct KhrAccelerationStructureFn {
         acceleration_structure_count: u32,
         p_acceleration_structures: *const AccelerationStructureKHR,
         query_type: QueryType,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
-        stride: usize,
+        stride: size_t,
     ) -> Result,
     pub cmd_copy_acceleration_structure_khr: extern "system" fn(
         command_buffer: CommandBuffer,
@@ -11976,6 +13713,9 @@ impl ::std::clone::Clone for KhrAccelerationStructureFn {
     }
 }
 impl KhrAccelerationStructureFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -12154,9 +13894,9 @@ impl KhrAccelerationStructureFn {
                     _acceleration_structure_count: u32,
                     _p_acceleration_structures: *const AccelerationStructureKHR,
                     _query_type: QueryType,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
-                    _stride: usize,
+                    _stride: size_t,
                 ) -> Result {
                     panic!(concat!(
                         "Unable to load ",
@@ -12340,6 +14080,8 @@ impl KhrAccelerationStructureFn {
         (self.destroy_acceleration_structure_khr)(device, acceleration_structure, p_allocator)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBuildAccelerationStructuresKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_build_acceleration_structures_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12355,6 +14097,8 @@ impl KhrAccelerationStructureFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBuildAccelerationStructuresIndirectKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_build_acceleration_structures_indirect_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12424,9 +14168,9 @@ impl KhrAccelerationStructureFn {
         acceleration_structure_count: u32,
         p_acceleration_structures: *const AccelerationStructureKHR,
         query_type: QueryType,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
-        stride: usize,
+        stride: size_t,
     ) -> Result {
         (self.write_acceleration_structures_properties_khr)(
             device,
@@ -12439,6 +14183,8 @@ impl KhrAccelerationStructureFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyAccelerationStructureKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_acceleration_structure_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12447,6 +14193,8 @@ impl KhrAccelerationStructureFn {
         (self.cmd_copy_acceleration_structure_khr)(command_buffer, p_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyAccelerationStructureToMemoryKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_acceleration_structure_to_memory_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12455,6 +14203,8 @@ impl KhrAccelerationStructureFn {
         (self.cmd_copy_acceleration_structure_to_memory_khr)(command_buffer, p_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyMemoryToAccelerationStructureKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_memory_to_acceleration_structure_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12471,19 +14221,20 @@ impl KhrAccelerationStructureFn {
         (self.get_acceleration_structure_device_address_khr)(device, p_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_write_acceleration_structures_properties_khr(
         &self,
         command_buffer: CommandBuffer,
-        acceleration_structure_count: u32,
-        p_acceleration_structures: *const AccelerationStructureKHR,
+        acceleration_structures: &[AccelerationStructureKHR],
         query_type: QueryType,
         query_pool: QueryPool,
         first_query: u32,
     ) -> c_void {
         (self.cmd_write_acceleration_structures_properties_khr)(
             command_buffer,
-            acceleration_structure_count,
-            p_acceleration_structures,
+            acceleration_structures.len() as _,
+            acceleration_structures.as_ptr(),
             query_type,
             query_pool,
             first_query,
@@ -12519,115 +14270,175 @@ impl KhrAccelerationStructureFn {
             p_size_info,
         )
     }
+    #[doc = r" Like [`Self::copy_acceleration_structure_khr`], but returns the success code, which is one of `SUCCESS`, `OPERATION_DEFERRED_KHR`, `OPERATION_NOT_DEFERRED_KHR`."]
+    pub unsafe fn copy_acceleration_structure_khr_with_success_code(
+        &self,
+        device: Device,
+        deferred_operation: DeferredOperationKHR,
+        p_info: *const CopyAccelerationStructureInfoKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.copy_acceleration_structure_khr(device, deferred_operation, p_info)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::copy_acceleration_structure_to_memory_khr`], but returns the success code, which is one of `SUCCESS`, `OPERATION_DEFERRED_KHR`, `OPERATION_NOT_DEFERRED_KHR`."]
+    pub unsafe fn copy_acceleration_structure_to_memory_khr_with_success_code(
+        &self,
+        device: Device,
+        deferred_operation: DeferredOperationKHR,
+        p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.copy_acceleration_structure_to_memory_khr(device, deferred_operation, p_info)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::copy_memory_to_acceleration_structure_khr`], but returns the success code, which is one of `SUCCESS`, `OPERATION_DEFERRED_KHR`, `OPERATION_NOT_DEFERRED_KHR`."]
+    pub unsafe fn copy_memory_to_acceleration_structure_khr_with_success_code(
+        &self,
+        device: Device,
+        deferred_operation: DeferredOperationKHR,
+        p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.copy_memory_to_acceleration_structure_khr(device, deferred_operation, p_info)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR: Self = Self(1_000_150_007);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR: Self = Self(1_000_150_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR: Self = Self(1_000_150_002);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR: Self = Self(1_000_150_003);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR: Self = Self(1_000_150_004);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR: Self = Self(1_000_150_005);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_GEOMETRY_KHR: Self = Self(1_000_150_006);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_VERSION_INFO_KHR: Self = Self(1_000_150_009);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const COPY_ACCELERATION_STRUCTURE_INFO_KHR: Self = Self(1_000_150_010);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR: Self = Self(1_000_150_011);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR: Self = Self(1_000_150_012);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR: Self = Self(1_000_150_013);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR: Self = Self(1_000_150_014);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_CREATE_INFO_KHR: Self = Self(1_000_150_017);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR: Self = Self(1_000_150_020);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_BUILD_KHR: Self = Self(0b10_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl DescriptorType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_KHR: Self = Self(1_000_150_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_READ_KHR: Self = Self(0b10_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_WRITE_KHR: Self = Self(0b100_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl QueryType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR: Self = Self(1_000_150_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl QueryType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR: Self = Self(1_000_150_001);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_KHR: Self = Self(1_000_150_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_KHR: Self = Self(1_000_150_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl IndexType {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const NONE_KHR: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR: Self =
         Self(0b10_0000_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_KHR: Self =
         Self(0b1000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_acceleration_structure'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_KHR_acceleration_structure`."]
     pub const ACCELERATION_STRUCTURE_STORAGE_KHR: Self = Self(0b1_0000_0000_0000_0000_0000);
 }
 impl KhrRayTracingPipelineFn {
@@ -12636,6 +14447,25 @@ impl KhrRayTracingPipelineFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdTraceRaysKHR"
+            | b"vkCreateRayTracingPipelinesKHR"
+            | b"vkGetRayTracingShaderGroupHandlesKHR"
+            | b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR"
+            | b"vkCmdTraceRaysIndirectKHR"
+            | b"vkGetRayTracingShaderGroupStackSizeKHR"
+            | b"vkCmdSetRayTracingPipelineStackSizeKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdTraceRaysKHR = extern "system" fn(
@@ -12664,7 +14494,7 @@ pub type PFN_vkGetRayTracingShaderGroupHandlesKHR = extern "system" fn(
     pipeline: Pipeline,
     first_group: u32,
     group_count: u32,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
 ) -> Result;
 #[allow(non_camel_case_types)]
@@ -12673,7 +14503,7 @@ pub type PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR = extern "system"
     pipeline: Pipeline,
     first_group: u32,
     group_count: u32,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
 ) -> Result;
 #[allow(non_camel_case_types)]
@@ -12720,7 +14550,7 @@ pub struct KhrRayTracingPipelineFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result,
     pub get_ray_tracing_capture_replay_shader_group_handles_khr: extern "system" fn(
@@ -12728,7 +14558,7 @@ pub struct KhrRayTracingPipelineFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result,
     pub cmd_trace_rays_indirect_khr: extern "system" fn(
@@ -12767,6 +14597,9 @@ impl ::std::clone::Clone for KhrRayTracingPipelineFn {
     }
 }
 impl KhrRayTracingPipelineFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -12824,7 +14657,7 @@ impl KhrRayTracingPipelineFn {
                     _pipeline: Pipeline,
                     _first_group: u32,
                     _group_count: u32,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -12847,7 +14680,7 @@ impl KhrRayTracingPipelineFn {
                     _pipeline: Pipeline,
                     _first_group: u32,
                     _group_count: u32,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -12930,6 +14763,8 @@ impl KhrRayTracingPipelineFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdTraceRaysKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_trace_rays_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -12980,7 +14815,7 @@ impl KhrRayTracingPipelineFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_ray_tracing_shader_group_handles_khr)(
@@ -12999,7 +14834,7 @@ impl KhrRayTracingPipelineFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_ray_tracing_capture_replay_shader_group_handles_khr)(
@@ -13012,6 +14847,8 @@ impl KhrRayTracingPipelineFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdTraceRaysIndirectKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_trace_rays_indirect_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -13041,6 +14878,8 @@ impl KhrRayTracingPipelineFn {
         (self.get_ray_tracing_shader_group_stack_size_khr)(device, pipeline, group, group_shader)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetRayTracingPipelineStackSizeKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_set_ray_tracing_pipeline_stack_size_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -13051,91 +14890,113 @@ impl KhrRayTracingPipelineFn {
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR: Self = Self(1_000_347_000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR: Self = Self(1_000_347_001);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_PIPELINE_CREATE_INFO_KHR: Self = Self(1_000_150_015);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR: Self = Self(1_000_150_016);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR: Self = Self(1_000_150_018);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAYGEN_KHR: Self = Self(0b1_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const ANY_HIT_KHR: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const CLOSEST_HIT_KHR: Self = Self(0b100_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const MISS_KHR: Self = Self(0b1000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const INTERSECTION_KHR: Self = Self(0b1_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const CALLABLE_KHR: Self = Self(0b10_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_SHADER_KHR: Self = Self(0b10_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const SHADER_BINDING_TABLE_KHR: Self = Self(0b100_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineBindPoint {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_KHR: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_KHR: Self = Self(0b100_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_KHR: Self = Self(0b1000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_NO_NULL_MISS_SHADERS_KHR: Self = Self(0b1_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_KHR: Self = Self(0b10_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_SKIP_TRIANGLES_KHR: Self = Self(0b1_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_SKIP_AABBS_KHR: Self = Self(0b10_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_KHR: Self =
         Self(0b1000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_ray_tracing_pipeline'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_KHR_ray_tracing_pipeline`."]
     pub const RAY_TRACING_PIPELINE_STACK_SIZE_KHR: Self = Self(1_000_347_000);
 }
 impl KhrRayQueryFn {
@@ -13154,6 +15015,9 @@ impl ::std::clone::Clone for KhrRayQueryFn {
     }
 }
 impl KhrRayQueryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13163,6 +15027,7 @@ impl KhrRayQueryFn {
 }
 #[doc = "Generated from 'VK_KHR_ray_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_ray_query`."]
     pub const PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR: Self = Self(1_000_348_013);
 }
 impl NvExtension152Fn {
@@ -13181,6 +15046,9 @@ impl ::std::clone::Clone for NvExtension152Fn {
     }
 }
 impl NvExtension152Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13204,6 +15072,9 @@ impl ::std::clone::Clone for NvFramebufferMixedSamplesFn {
     }
 }
 impl NvFramebufferMixedSamplesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13213,6 +15084,7 @@ impl NvFramebufferMixedSamplesFn {
 }
 #[doc = "Generated from 'VK_NV_framebuffer_mixed_samples'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_framebuffer_mixed_samples`."]
     pub const PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV: Self = Self(1_000_152_000);
 }
 impl NvFillRectangleFn {
@@ -13231,6 +15103,9 @@ impl ::std::clone::Clone for NvFillRectangleFn {
     }
 }
 impl NvFillRectangleFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13240,6 +15115,7 @@ impl NvFillRectangleFn {
 }
 #[doc = "Generated from 'VK_NV_fill_rectangle'"]
 impl PolygonMode {
+    #[doc = "Provided by `VK_NV_fill_rectangle`."]
     pub const FILL_RECTANGLE_NV: Self = Self(1_000_153_000);
 }
 impl NvShaderSmBuiltinsFn {
@@ -13258,6 +15134,9 @@ impl ::std::clone::Clone for NvShaderSmBuiltinsFn {
     }
 }
 impl NvShaderSmBuiltinsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13267,10 +15146,12 @@ impl NvShaderSmBuiltinsFn {
 }
 #[doc = "Generated from 'VK_NV_shader_sm_builtins'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shader_sm_builtins`."]
     pub const PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV: Self = Self(1_000_154_000);
 }
 #[doc = "Generated from 'VK_NV_shader_sm_builtins'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shader_sm_builtins`."]
     pub const PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV: Self = Self(1_000_154_001);
 }
 impl ExtPostDepthCoverageFn {
@@ -13289,6 +15170,9 @@ impl ::std::clone::Clone for ExtPostDepthCoverageFn {
     }
 }
 impl ExtPostDepthCoverageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13302,6 +15186,21 @@ impl KhrSamplerYcbcrConversionFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 14u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateSamplerYcbcrConversionKHR" | b"vkDestroySamplerYcbcrConversionKHR" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSamplerYcbcrConversion = extern "system" fn(
@@ -13340,6 +15239,9 @@ impl ::std::clone::Clone for KhrSamplerYcbcrConversionFn {
     }
 }
 impl KhrSamplerYcbcrConversionFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13415,276 +15317,338 @@ impl KhrSamplerYcbcrConversionFn {
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLER_YCBCR_CONVERSION_CREATE_INFO_KHR: Self =
         StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLER_YCBCR_CONVERSION_INFO_KHR: Self =
         StructureType::SAMPLER_YCBCR_CONVERSION_INFO;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const BIND_IMAGE_PLANE_MEMORY_INFO_KHR: Self = StructureType::BIND_IMAGE_PLANE_MEMORY_INFO;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO_KHR: Self =
         StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES_KHR: Self =
         StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLER_YCBCR_CONVERSION_KHR: Self =
         DebugReportObjectTypeEXT::SAMPLER_YCBCR_CONVERSION;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLER_YCBCR_CONVERSION_KHR: Self = ObjectType::SAMPLER_YCBCR_CONVERSION;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8B8G8R8_422_UNORM_KHR: Self = Format::G8B8G8R8_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const B8G8R8G8_422_UNORM_KHR: Self = Format::B8G8R8G8_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8_B8_R8_3PLANE_420_UNORM_KHR: Self = Format::G8_B8_R8_3PLANE_420_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8_B8R8_2PLANE_420_UNORM_KHR: Self = Format::G8_B8R8_2PLANE_420_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8_B8_R8_3PLANE_422_UNORM_KHR: Self = Format::G8_B8_R8_3PLANE_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8_B8R8_2PLANE_422_UNORM_KHR: Self = Format::G8_B8R8_2PLANE_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G8_B8_R8_3PLANE_444_UNORM_KHR: Self = Format::G8_B8_R8_3PLANE_444_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R10X6_UNORM_PACK16_KHR: Self = Format::R10X6_UNORM_PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R10X6G10X6_UNORM_2PACK16_KHR: Self = Format::R10X6G10X6_UNORM_2PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR: Self =
         Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR: Self =
         Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR: Self =
         Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR: Self =
         Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR: Self =
         Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR: Self =
         Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR: Self =
         Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR: Self =
         Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R12X4_UNORM_PACK16_KHR: Self = Format::R12X4_UNORM_PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R12X4G12X4_UNORM_2PACK16_KHR: Self = Format::R12X4G12X4_UNORM_2PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR: Self =
         Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR: Self =
         Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR: Self =
         Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR: Self =
         Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR: Self =
         Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR: Self =
         Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR: Self =
         Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR: Self =
         Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16B16G16R16_422_UNORM_KHR: Self = Format::G16B16G16R16_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const B16G16R16G16_422_UNORM_KHR: Self = Format::B16G16R16G16_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16_B16_R16_3PLANE_420_UNORM_KHR: Self = Format::G16_B16_R16_3PLANE_420_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16_B16R16_2PLANE_420_UNORM_KHR: Self = Format::G16_B16R16_2PLANE_420_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16_B16_R16_3PLANE_422_UNORM_KHR: Self = Format::G16_B16_R16_3PLANE_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16_B16R16_2PLANE_422_UNORM_KHR: Self = Format::G16_B16R16_2PLANE_422_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl Format {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const G16_B16_R16_3PLANE_444_UNORM_KHR: Self = Format::G16_B16_R16_3PLANE_444_UNORM;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const PLANE_0_KHR: Self = ImageAspectFlags::PLANE_0;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const PLANE_1_KHR: Self = ImageAspectFlags::PLANE_1;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const PLANE_2_KHR: Self = ImageAspectFlags::PLANE_2;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const DISJOINT_KHR: Self = ImageCreateFlags::DISJOINT;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const MIDPOINT_CHROMA_SAMPLES_KHR: Self = FormatFeatureFlags::MIDPOINT_CHROMA_SAMPLES;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const DISJOINT_KHR: Self = FormatFeatureFlags::DISJOINT;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const COSITED_CHROMA_SAMPLES_KHR: Self = FormatFeatureFlags::COSITED_CHROMA_SAMPLES;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrModelConversion {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const RGB_IDENTITY_KHR: Self = SamplerYcbcrModelConversion::RGB_IDENTITY;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrModelConversion {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const YCBCR_IDENTITY_KHR: Self = SamplerYcbcrModelConversion::YCBCR_IDENTITY;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrModelConversion {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const YCBCR_709_KHR: Self = SamplerYcbcrModelConversion::YCBCR_709;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrModelConversion {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const YCBCR_601_KHR: Self = SamplerYcbcrModelConversion::YCBCR_601;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrModelConversion {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const YCBCR_2020_KHR: Self = SamplerYcbcrModelConversion::YCBCR_2020;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrRange {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const ITU_FULL_KHR: Self = SamplerYcbcrRange::ITU_FULL;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl SamplerYcbcrRange {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const ITU_NARROW_KHR: Self = SamplerYcbcrRange::ITU_NARROW;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ChromaLocation {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const COSITED_EVEN_KHR: Self = ChromaLocation::COSITED_EVEN;
 }
 #[doc = "Generated from 'VK_KHR_sampler_ycbcr_conversion'"]
 impl ChromaLocation {
+    #[doc = "Provided by `VK_KHR_sampler_ycbcr_conversion`."]
     pub const MIDPOINT_KHR: Self = ChromaLocation::MIDPOINT;
 }
 impl KhrBindMemory2Fn {
@@ -13693,6 +15657,19 @@ impl KhrBindMemory2Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkBindBufferMemory2KHR" | b"vkBindImageMemory2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkBindBufferMemory2 = extern "system" fn(
@@ -13729,6 +15706,9 @@ impl ::std::clone::Clone for KhrBindMemory2Fn {
     }
 }
 impl KhrBindMemory2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13797,14 +15777,17 @@ impl KhrBindMemory2Fn {
 }
 #[doc = "Generated from 'VK_KHR_bind_memory2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_bind_memory2`."]
     pub const BIND_BUFFER_MEMORY_INFO_KHR: Self = StructureType::BIND_BUFFER_MEMORY_INFO;
 }
 #[doc = "Generated from 'VK_KHR_bind_memory2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_bind_memory2`."]
     pub const BIND_IMAGE_MEMORY_INFO_KHR: Self = StructureType::BIND_IMAGE_MEMORY_INFO;
 }
 #[doc = "Generated from 'VK_KHR_bind_memory2'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_KHR_bind_memory2`."]
     pub const ALIAS_KHR: Self = ImageCreateFlags::ALIAS;
 }
 impl ExtImageDrmFormatModifierFn {
@@ -13813,6 +15796,19 @@ impl ExtImageDrmFormatModifierFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetImageDrmFormatModifierPropertiesEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageDrmFormatModifierPropertiesEXT = extern "system" fn(
@@ -13838,6 +15834,9 @@ impl ::std::clone::Clone for ExtImageDrmFormatModifierFn {
     }
 }
 impl ExtImageDrmFormatModifierFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13877,46 +15876,57 @@ impl ExtImageDrmFormatModifierFn {
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT: Self = Self(-1000158000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT: Self = Self(1_000_158_000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT: Self = Self(1_000_158_002);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT: Self = Self(1_000_158_003);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT: Self = Self(1_000_158_004);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT: Self = Self(1_000_158_005);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl ImageTiling {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const DRM_FORMAT_MODIFIER_EXT: Self = Self(1_000_158_000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const MEMORY_PLANE_0_EXT: Self = Self(0b1000_0000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const MEMORY_PLANE_1_EXT: Self = Self(0b1_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const MEMORY_PLANE_2_EXT: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_image_drm_format_modifier'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by `VK_EXT_image_drm_format_modifier`."]
     pub const MEMORY_PLANE_3_EXT: Self = Self(0b100_0000_0000);
 }
 impl ExtExtension160Fn {
@@ -13935,6 +15945,9 @@ impl ::std::clone::Clone for ExtExtension160Fn {
     }
 }
 impl ExtExtension160Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -13948,6 +15961,22 @@ impl ExtValidationCacheFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateValidationCacheEXT"
+            | b"vkDestroyValidationCacheEXT"
+            | b"vkMergeValidationCachesEXT"
+            | b"vkGetValidationCacheDataEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateValidationCacheEXT = extern "system" fn(
@@ -13973,7 +16002,7 @@ pub type PFN_vkMergeValidationCachesEXT = extern "system" fn(
 pub type PFN_vkGetValidationCacheDataEXT = extern "system" fn(
     device: Device,
     validation_cache: ValidationCacheEXT,
-    p_data_size: *mut usize,
+    p_data_size: *mut size_t,
     p_data: *mut c_void,
 ) -> Result;
 pub struct ExtValidationCacheFn {
@@ -13997,7 +16026,7 @@ pub struct ExtValidationCacheFn {
     pub get_validation_cache_data_ext: extern "system" fn(
         device: Device,
         validation_cache: ValidationCacheEXT,
-        p_data_size: *mut usize,
+        p_data_size: *mut size_t,
         p_data: *mut c_void,
     ) -> Result,
 }
@@ -14014,6 +16043,9 @@ impl ::std::clone::Clone for ExtValidationCacheFn {
     }
 }
 impl ExtValidationCacheFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14085,7 +16117,7 @@ impl ExtValidationCacheFn {
                 extern "system" fn get_validation_cache_data_ext(
                     _device: Device,
                     _validation_cache: ValidationCacheEXT,
-                    _p_data_size: *mut usize,
+                    _p_data_size: *mut size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -14138,22 +16170,37 @@ impl ExtValidationCacheFn {
         &self,
         device: Device,
         validation_cache: ValidationCacheEXT,
-        p_data_size: *mut usize,
+        p_data_size: *mut size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_validation_cache_data_ext)(device, validation_cache, p_data_size, p_data)
     }
+    #[doc = r" Like [`Self::get_validation_cache_data_ext`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_validation_cache_data_ext_with_success_code(
+        &self,
+        device: Device,
+        validation_cache: ValidationCacheEXT,
+        p_data_size: *mut size_t,
+        p_data: *mut c_void,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_validation_cache_data_ext(device, validation_cache, p_data_size, p_data)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_EXT_validation_cache'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_validation_cache`."]
     pub const VALIDATION_CACHE_CREATE_INFO_EXT: Self = Self(1_000_160_000);
 }
 #[doc = "Generated from 'VK_EXT_validation_cache'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_validation_cache`."]
     pub const SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT: Self = Self(1_000_160_001);
 }
 #[doc = "Generated from 'VK_EXT_validation_cache'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_EXT_validation_cache`."]
     pub const VALIDATION_CACHE_EXT: Self = Self(1_000_160_000);
 }
 impl ExtDescriptorIndexingFn {
@@ -14172,6 +16219,9 @@ impl ::std::clone::Clone for ExtDescriptorIndexingFn {
     }
 }
 impl ExtDescriptorIndexingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14181,58 +16231,70 @@ impl ExtDescriptorIndexingFn {
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT: Self =
         StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT: Self =
         StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT: Self =
         StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorBindingFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const UPDATE_AFTER_BIND_EXT: Self = DescriptorBindingFlags::UPDATE_AFTER_BIND;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorBindingFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const UPDATE_UNUSED_WHILE_PENDING_EXT: Self =
         DescriptorBindingFlags::UPDATE_UNUSED_WHILE_PENDING;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorBindingFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const PARTIALLY_BOUND_EXT: Self = DescriptorBindingFlags::PARTIALLY_BOUND;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorBindingFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const VARIABLE_DESCRIPTOR_COUNT_EXT: Self =
         DescriptorBindingFlags::VARIABLE_DESCRIPTOR_COUNT;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorPoolCreateFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const UPDATE_AFTER_BIND_EXT: Self = DescriptorPoolCreateFlags::UPDATE_AFTER_BIND;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl DescriptorSetLayoutCreateFlags {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const UPDATE_AFTER_BIND_POOL_EXT: Self =
         DescriptorSetLayoutCreateFlags::UPDATE_AFTER_BIND_POOL;
 }
 #[doc = "Generated from 'VK_EXT_descriptor_indexing'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_descriptor_indexing`."]
     pub const ERROR_FRAGMENTATION_EXT: Self = Result::ERROR_FRAGMENTATION;
 }
 impl ExtShaderViewportIndexLayerFn {
@@ -14251,6 +16313,9 @@ impl ::std::clone::Clone for ExtShaderViewportIndexLayerFn {
     }
 }
 impl ExtShaderViewportIndexLayerFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14258,6 +16323,7 @@ impl ExtShaderViewportIndexLayerFn {
         ExtShaderViewportIndexLayerFn {}
     }
 }
+#[cfg(feature = "provisional")]
 impl KhrPortabilitySubsetFn {
     pub fn name() -> &'static ::std::ffi::CStr {
         ::std::ffi::CStr::from_bytes_with_nul(b"VK_KHR_portability_subset\0")
@@ -14265,15 +16331,23 @@ impl KhrPortabilitySubsetFn {
     }
     pub const SPEC_VERSION: u32 = 1u32;
 }
+#[cfg(feature = "provisional")]
 pub struct KhrPortabilitySubsetFn {}
+#[cfg(feature = "provisional")]
 unsafe impl Send for KhrPortabilitySubsetFn {}
+#[cfg(feature = "provisional")]
 unsafe impl Sync for KhrPortabilitySubsetFn {}
+#[cfg(feature = "provisional")]
 impl ::std::clone::Clone for KhrPortabilitySubsetFn {
     fn clone(&self) -> Self {
         KhrPortabilitySubsetFn {}
     }
 }
+#[cfg(feature = "provisional")]
 impl KhrPortabilitySubsetFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14283,10 +16357,12 @@ impl KhrPortabilitySubsetFn {
 }
 #[doc = "Generated from 'VK_KHR_portability_subset'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_portability_subset`."]
     pub const PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR: Self = Self(1_000_163_000);
 }
 #[doc = "Generated from 'VK_KHR_portability_subset'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_portability_subset`."]
     pub const PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR: Self = Self(1_000_163_001);
 }
 impl NvShadingRateImageFn {
@@ -14295,6 +16371,21 @@ impl NvShadingRateImageFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 3u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdBindShadingRateImageNV"
+            | b"vkCmdSetViewportShadingRatePaletteNV"
+            | b"vkCmdSetCoarseSampleOrderNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBindShadingRateImageNV = extern "system" fn(
@@ -14347,6 +16438,9 @@ impl ::std::clone::Clone for NvShadingRateImageFn {
     }
 }
 impl NvShadingRateImageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14417,6 +16511,8 @@ impl NvShadingRateImageFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindShadingRateImageNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_shading_rate_image_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -14426,75 +16522,87 @@ impl NvShadingRateImageFn {
         (self.cmd_bind_shading_rate_image_nv)(command_buffer, image_view, image_layout)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_viewport_shading_rate_palette_nv(
         &self,
         command_buffer: CommandBuffer,
         first_viewport: u32,
-        viewport_count: u32,
-        p_shading_rate_palettes: *const ShadingRatePaletteNV,
+        shading_rate_palettes: &[ShadingRatePaletteNV],
     ) -> c_void {
         (self.cmd_set_viewport_shading_rate_palette_nv)(
             command_buffer,
             first_viewport,
-            viewport_count,
-            p_shading_rate_palettes,
+            shading_rate_palettes.len() as _,
+            shading_rate_palettes.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_coarse_sample_order_nv(
         &self,
         command_buffer: CommandBuffer,
         sample_order_type: CoarseSampleOrderTypeNV,
-        custom_sample_order_count: u32,
-        p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
+        custom_sample_orders: &[CoarseSampleOrderCustomNV],
     ) -> c_void {
         (self.cmd_set_coarse_sample_order_nv)(
             command_buffer,
             sample_order_type,
-            custom_sample_order_count,
-            p_custom_sample_orders,
+            custom_sample_orders.len() as _,
+            custom_sample_orders.as_ptr(),
         )
     }
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV: Self = Self(1_000_164_000);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV: Self = Self(1_000_164_001);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV: Self = Self(1_000_164_002);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const SHADING_RATE_OPTIMAL_NV: Self = Self(1_000_164_003);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const VIEWPORT_SHADING_RATE_PALETTE_NV: Self = Self(1_000_164_004);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const SHADING_RATE_IMAGE_READ_NV: Self = Self(0b1000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const SHADING_RATE_IMAGE_NV: Self = Self(0b1_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const SHADING_RATE_IMAGE_NV: Self = Self(0b100_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV: Self =
         Self(1_000_164_005);
 }
 #[doc = "Generated from 'VK_NV_shading_rate_image'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_NV_shading_rate_image`."]
     pub const VIEWPORT_COARSE_SAMPLE_ORDER_NV: Self = Self(1_000_164_006);
 }
 impl NvRayTracingFn {
@@ -14503,6 +16611,30 @@ impl NvRayTracingFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 3u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateAccelerationStructureNV"
+            | b"vkDestroyAccelerationStructureNV"
+            | b"vkGetAccelerationStructureMemoryRequirementsNV"
+            | b"vkBindAccelerationStructureMemoryNV"
+            | b"vkCmdBuildAccelerationStructureNV"
+            | b"vkCmdCopyAccelerationStructureNV"
+            | b"vkCmdTraceRaysNV"
+            | b"vkCreateRayTracingPipelinesNV"
+            | b"vkGetRayTracingShaderGroupHandlesNV"
+            | b"vkGetAccelerationStructureHandleNV"
+            | b"vkCmdWriteAccelerationStructuresPropertiesNV"
+            | b"vkCompileDeferredNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateAccelerationStructureNV = extern "system" fn(
@@ -14581,14 +16713,14 @@ pub type PFN_vkGetAccelerationStructureHandleNV = extern "system" fn(
     pipeline: Pipeline,
     first_group: u32,
     group_count: u32,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
 ) -> Result;
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdWriteAccelerationStructuresPropertiesNV = extern "system" fn(
     device: Device,
     acceleration_structure: AccelerationStructureNV,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
 ) -> Result;
 #[allow(non_camel_case_types)]
@@ -14669,13 +16801,13 @@ pub struct NvRayTracingFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result,
     pub get_acceleration_structure_handle_nv: extern "system" fn(
         device: Device,
         acceleration_structure: AccelerationStructureNV,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result,
     pub cmd_write_acceleration_structures_properties_nv: extern "system" fn(
@@ -14712,6 +16844,9 @@ impl ::std::clone::Clone for NvRayTracingFn {
     }
 }
 impl NvRayTracingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -14903,7 +17038,7 @@ impl NvRayTracingFn {
                     _pipeline: Pipeline,
                     _first_group: u32,
                     _group_count: u32,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -14924,7 +17059,7 @@ impl NvRayTracingFn {
                 extern "system" fn get_acceleration_structure_handle_nv(
                     _device: Device,
                     _acceleration_structure: AccelerationStructureNV,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -15030,6 +17165,8 @@ impl NvRayTracingFn {
         (self.bind_acceleration_structure_memory_nv)(device, bind_info_count, p_bind_infos)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBuildAccelerationStructureNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_build_acceleration_structure_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -15055,6 +17192,8 @@ impl NvRayTracingFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyAccelerationStructureNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_acceleration_structure_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -15065,6 +17204,8 @@ impl NvRayTracingFn {
         (self.cmd_copy_acceleration_structure_nv)(command_buffer, dst, src, mode)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdTraceRaysNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_trace_rays_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -15127,7 +17268,7 @@ impl NvRayTracingFn {
         pipeline: Pipeline,
         first_group: u32,
         group_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_ray_tracing_shader_group_handles_nv)(
@@ -15144,7 +17285,7 @@ impl NvRayTracingFn {
         &self,
         device: Device,
         acceleration_structure: AccelerationStructureNV,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_acceleration_structure_handle_nv)(
@@ -15155,19 +17296,20 @@ impl NvRayTracingFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_write_acceleration_structures_properties_nv(
         &self,
         command_buffer: CommandBuffer,
-        acceleration_structure_count: u32,
-        p_acceleration_structures: *const AccelerationStructureNV,
+        acceleration_structures: &[AccelerationStructureNV],
         query_type: QueryType,
         query_pool: QueryPool,
         first_query: u32,
     ) -> c_void {
         (self.cmd_write_acceleration_structures_properties_nv)(
             command_buffer,
-            acceleration_structure_count,
-            p_acceleration_structures,
+            acceleration_structures.len() as _,
+            acceleration_structures.as_ptr(),
             query_type,
             query_pool,
             first_query,
@@ -15185,202 +17327,251 @@ impl NvRayTracingFn {
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAY_TRACING_PIPELINE_CREATE_INFO_NV: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_CREATE_INFO_NV: Self = Self(1_000_165_001);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const GEOMETRY_NV: Self = Self(1_000_165_003);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const GEOMETRY_TRIANGLES_NV: Self = Self(1_000_165_004);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const GEOMETRY_AABB_NV: Self = Self(1_000_165_005);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV: Self = Self(1_000_165_006);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV: Self = Self(1_000_165_007);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV: Self = Self(1_000_165_008);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV: Self = Self(1_000_165_009);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV: Self = Self(1_000_165_011);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_INFO_NV: Self = Self(1_000_165_012);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAYGEN_NV: Self = ShaderStageFlags::RAYGEN_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ANY_HIT_NV: Self = ShaderStageFlags::ANY_HIT_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const CLOSEST_HIT_NV: Self = ShaderStageFlags::CLOSEST_HIT_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const MISS_NV: Self = ShaderStageFlags::MISS_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const INTERSECTION_NV: Self = ShaderStageFlags::INTERSECTION_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const CALLABLE_NV: Self = ShaderStageFlags::CALLABLE_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAY_TRACING_SHADER_NV: Self = PipelineStageFlags::RAY_TRACING_SHADER_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_BUILD_NV: Self =
         PipelineStageFlags::ACCELERATION_STRUCTURE_BUILD_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAY_TRACING_NV: Self = BufferUsageFlags::SHADER_BINDING_TABLE_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl PipelineBindPoint {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const RAY_TRACING_NV: Self = PipelineBindPoint::RAY_TRACING_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl DescriptorType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_NV: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_READ_NV: Self = AccessFlags::ACCELERATION_STRUCTURE_READ_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_WRITE_NV: Self = AccessFlags::ACCELERATION_STRUCTURE_WRITE_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl QueryType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const DEFER_COMPILE_NV: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_NV: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl DebugReportObjectTypeEXT {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ACCELERATION_STRUCTURE_NV: Self = Self(1_000_165_000);
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl IndexType {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const NONE_NV: Self = IndexType::NONE_KHR;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl RayTracingShaderGroupTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const GENERAL_NV: Self = RayTracingShaderGroupTypeKHR::GENERAL;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl RayTracingShaderGroupTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const TRIANGLES_HIT_GROUP_NV: Self = RayTracingShaderGroupTypeKHR::TRIANGLES_HIT_GROUP;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl RayTracingShaderGroupTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const PROCEDURAL_HIT_GROUP_NV: Self = RayTracingShaderGroupTypeKHR::PROCEDURAL_HIT_GROUP;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const TRIANGLES_NV: Self = GeometryTypeKHR::TRIANGLES;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const AABBS_NV: Self = GeometryTypeKHR::AABBS;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl AccelerationStructureTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const TOP_LEVEL_NV: Self = AccelerationStructureTypeKHR::TOP_LEVEL;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl AccelerationStructureTypeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const BOTTOM_LEVEL_NV: Self = AccelerationStructureTypeKHR::BOTTOM_LEVEL;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const OPAQUE_NV: Self = GeometryFlagsKHR::OPAQUE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const NO_DUPLICATE_ANY_HIT_INVOCATION_NV: Self =
         GeometryFlagsKHR::NO_DUPLICATE_ANY_HIT_INVOCATION;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryInstanceFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const TRIANGLE_CULL_DISABLE_NV: Self =
         GeometryInstanceFlagsKHR::TRIANGLE_FACING_CULL_DISABLE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryInstanceFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const TRIANGLE_FRONT_COUNTERCLOCKWISE_NV: Self =
         GeometryInstanceFlagsKHR::TRIANGLE_FRONT_COUNTERCLOCKWISE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryInstanceFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const FORCE_OPAQUE_NV: Self = GeometryInstanceFlagsKHR::FORCE_OPAQUE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl GeometryInstanceFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const FORCE_NO_OPAQUE_NV: Self = GeometryInstanceFlagsKHR::FORCE_NO_OPAQUE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BuildAccelerationStructureFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ALLOW_UPDATE_NV: Self = BuildAccelerationStructureFlagsKHR::ALLOW_UPDATE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BuildAccelerationStructureFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const ALLOW_COMPACTION_NV: Self = BuildAccelerationStructureFlagsKHR::ALLOW_COMPACTION;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BuildAccelerationStructureFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const PREFER_FAST_TRACE_NV: Self = BuildAccelerationStructureFlagsKHR::PREFER_FAST_TRACE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BuildAccelerationStructureFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const PREFER_FAST_BUILD_NV: Self = BuildAccelerationStructureFlagsKHR::PREFER_FAST_BUILD;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl BuildAccelerationStructureFlagsKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const LOW_MEMORY_NV: Self = BuildAccelerationStructureFlagsKHR::LOW_MEMORY;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl CopyAccelerationStructureModeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const CLONE_NV: Self = CopyAccelerationStructureModeKHR::CLONE;
 }
 #[doc = "Generated from 'VK_NV_ray_tracing'"]
 impl CopyAccelerationStructureModeKHR {
+    #[doc = "Provided by `VK_NV_ray_tracing`."]
     pub const COMPACT_NV: Self = CopyAccelerationStructureModeKHR::COMPACT;
 }
 impl NvRepresentativeFragmentTestFn {
@@ -15399,6 +17590,9 @@ impl ::std::clone::Clone for NvRepresentativeFragmentTestFn {
     }
 }
 impl NvRepresentativeFragmentTestFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15408,10 +17602,12 @@ impl NvRepresentativeFragmentTestFn {
 }
 #[doc = "Generated from 'VK_NV_representative_fragment_test'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_representative_fragment_test`."]
     pub const PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV: Self = Self(1_000_166_000);
 }
 #[doc = "Generated from 'VK_NV_representative_fragment_test'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_representative_fragment_test`."]
     pub const PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV: Self =
         Self(1_000_166_001);
 }
@@ -15431,6 +17627,9 @@ impl ::std::clone::Clone for NvExtension168Fn {
     }
 }
 impl NvExtension168Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15444,6 +17643,19 @@ impl KhrMaintenance3Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetDescriptorSetLayoutSupportKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDescriptorSetLayoutSupport = extern "system" fn(
@@ -15468,6 +17680,9 @@ impl ::std::clone::Clone for KhrMaintenance3Fn {
     }
 }
 impl KhrMaintenance3Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15507,11 +17722,13 @@ impl KhrMaintenance3Fn {
 }
 #[doc = "Generated from 'VK_KHR_maintenance3'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance3`."]
     pub const PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_maintenance3'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_maintenance3`."]
     pub const DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR: Self =
         StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT;
 }
@@ -15521,6 +17738,19 @@ impl KhrDrawIndirectCountFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdDrawIndirectCountKHR" | b"vkCmdDrawIndexedIndirectCountKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 pub struct KhrDrawIndirectCountFn {
     pub cmd_draw_indirect_count_khr: extern "system" fn(
@@ -15553,6 +17783,9 @@ impl ::std::clone::Clone for KhrDrawIndirectCountFn {
     }
 }
 impl KhrDrawIndirectCountFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15609,6 +17842,8 @@ impl KhrDrawIndirectCountFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndirectCountKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indirect_count_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -15630,6 +17865,8 @@ impl KhrDrawIndirectCountFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexedIndirectCountKHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indexed_indirect_count_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -15667,6 +17904,9 @@ impl ::std::clone::Clone for ExtFilterCubicFn {
     }
 }
 impl ExtFilterCubicFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15676,19 +17916,23 @@ impl ExtFilterCubicFn {
 }
 #[doc = "Generated from 'VK_EXT_filter_cubic'"]
 impl Filter {
+    #[doc = "Provided by `VK_EXT_filter_cubic`."]
     pub const CUBIC_EXT: Self = Filter::CUBIC_IMG;
 }
 #[doc = "Generated from 'VK_EXT_filter_cubic'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_EXT_filter_cubic`."]
     pub const SAMPLED_IMAGE_FILTER_CUBIC_EXT: Self =
         FormatFeatureFlags::SAMPLED_IMAGE_FILTER_CUBIC_IMG;
 }
 #[doc = "Generated from 'VK_EXT_filter_cubic'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_filter_cubic`."]
     pub const PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT: Self = Self(1_000_170_000);
 }
 #[doc = "Generated from 'VK_EXT_filter_cubic'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_filter_cubic`."]
     pub const FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT: Self = Self(1_000_170_001);
 }
 impl QcomRenderPassShaderResolveFn {
@@ -15707,6 +17951,9 @@ impl ::std::clone::Clone for QcomRenderPassShaderResolveFn {
     }
 }
 impl QcomRenderPassShaderResolveFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15716,10 +17963,12 @@ impl QcomRenderPassShaderResolveFn {
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_shader_resolve'"]
 impl SubpassDescriptionFlags {
+    #[doc = "Provided by `VK_QCOM_render_pass_shader_resolve`."]
     pub const FRAGMENT_REGION_QCOM: Self = Self(0b100);
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_shader_resolve'"]
 impl SubpassDescriptionFlags {
+    #[doc = "Provided by `VK_QCOM_render_pass_shader_resolve`."]
     pub const SHADER_RESOLVE_QCOM: Self = Self(0b1000);
 }
 impl QcomExtension173Fn {
@@ -15738,6 +17987,9 @@ impl ::std::clone::Clone for QcomExtension173Fn {
     }
 }
 impl QcomExtension173Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15747,14 +17999,17 @@ impl QcomExtension173Fn {
 }
 #[doc = "Generated from 'VK_QCOM_extension_173'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_QCOM_extension_173`."]
     pub const RESERVED_18_QCOM: Self = Self(0b100_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_QCOM_extension_173'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_QCOM_extension_173`."]
     pub const RESERVED_16_QCOM: Self = Self(0b1_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_QCOM_extension_173'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_QCOM_extension_173`."]
     pub const RESERVED_17_QCOM: Self = Self(0b10_0000_0000_0000_0000);
 }
 impl QcomExtension174Fn {
@@ -15773,6 +18028,9 @@ impl ::std::clone::Clone for QcomExtension174Fn {
     }
 }
 impl QcomExtension174Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15796,6 +18054,9 @@ impl ::std::clone::Clone for ExtGlobalPriorityFn {
     }
 }
 impl ExtGlobalPriorityFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15805,10 +18066,12 @@ impl ExtGlobalPriorityFn {
 }
 #[doc = "Generated from 'VK_EXT_global_priority'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_global_priority`."]
     pub const DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT: Self = Self(1_000_174_000);
 }
 #[doc = "Generated from 'VK_EXT_global_priority'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_global_priority`."]
     pub const ERROR_NOT_PERMITTED_EXT: Self = Self(-1000174001);
 }
 impl KhrShaderSubgroupExtendedTypesFn {
@@ -15827,6 +18090,9 @@ impl ::std::clone::Clone for KhrShaderSubgroupExtendedTypesFn {
     }
 }
 impl KhrShaderSubgroupExtendedTypesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15836,6 +18102,7 @@ impl KhrShaderSubgroupExtendedTypesFn {
 }
 #[doc = "Generated from 'VK_KHR_shader_subgroup_extended_types'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_subgroup_extended_types`."]
     pub const PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES;
 }
@@ -15855,6 +18122,9 @@ impl ::std::clone::Clone for ExtExtension177Fn {
     }
 }
 impl ExtExtension177Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15878,6 +18148,9 @@ impl ::std::clone::Clone for Khr8bitStorageFn {
     }
 }
 impl Khr8bitStorageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15887,6 +18160,7 @@ impl Khr8bitStorageFn {
 }
 #[doc = "Generated from 'VK_KHR_8bit_storage'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_8bit_storage`."]
     pub const PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES;
 }
@@ -15896,6 +18170,19 @@ impl ExtExternalMemoryHostFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetMemoryHostPointerPropertiesEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryHostPointerPropertiesEXT = extern "system" fn(
@@ -15922,6 +18209,9 @@ impl ::std::clone::Clone for ExtExternalMemoryHostFn {
     }
 }
 impl ExtExternalMemoryHostFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -15968,22 +18258,27 @@ impl ExtExternalMemoryHostFn {
 }
 #[doc = "Generated from 'VK_EXT_external_memory_host'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_external_memory_host`."]
     pub const IMPORT_MEMORY_HOST_POINTER_INFO_EXT: Self = Self(1_000_178_000);
 }
 #[doc = "Generated from 'VK_EXT_external_memory_host'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_external_memory_host`."]
     pub const MEMORY_HOST_POINTER_PROPERTIES_EXT: Self = Self(1_000_178_001);
 }
 #[doc = "Generated from 'VK_EXT_external_memory_host'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_external_memory_host`."]
     pub const PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT: Self = Self(1_000_178_002);
 }
 #[doc = "Generated from 'VK_EXT_external_memory_host'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_EXT_external_memory_host`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_HOST_ALLOCATION: Self = Self(0b1000_0000);
 }
 #[doc = "Generated from 'VK_EXT_external_memory_host'"]
 impl ExternalMemoryHandleTypeFlags {
+    #[doc = "Provided by `VK_EXT_external_memory_host`."]
     pub const EXTERNAL_MEMORY_HANDLE_TYPE_HOST_MAPPED_FOREIGN_MEMORY: Self = Self(0b1_0000_0000);
 }
 impl AmdBufferMarkerFn {
@@ -15992,6 +18287,19 @@ impl AmdBufferMarkerFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdWriteBufferMarkerAMD" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdWriteBufferMarkerAMD = extern "system" fn(
@@ -16020,6 +18328,9 @@ impl ::std::clone::Clone for AmdBufferMarkerFn {
     }
 }
 impl AmdBufferMarkerFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16050,6 +18361,8 @@ impl AmdBufferMarkerFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_write_buffer_marker_amd(
         &self,
         command_buffer: CommandBuffer,
@@ -16083,6 +18396,9 @@ impl ::std::clone::Clone for KhrShaderAtomicInt64Fn {
     }
 }
 impl KhrShaderAtomicInt64Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16092,6 +18408,7 @@ impl KhrShaderAtomicInt64Fn {
 }
 #[doc = "Generated from 'VK_KHR_shader_atomic_int64'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_atomic_int64`."]
     pub const PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES;
 }
@@ -16111,6 +18428,9 @@ impl ::std::clone::Clone for KhrShaderClockFn {
     }
 }
 impl KhrShaderClockFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16120,6 +18440,7 @@ impl KhrShaderClockFn {
 }
 #[doc = "Generated from 'VK_KHR_shader_clock'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_clock`."]
     pub const PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR: Self = Self(1_000_181_000);
 }
 impl AmdExtension183Fn {
@@ -16138,6 +18459,9 @@ impl ::std::clone::Clone for AmdExtension183Fn {
     }
 }
 impl AmdExtension183Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16161,6 +18485,9 @@ impl ::std::clone::Clone for AmdPipelineCompilerControlFn {
     }
 }
 impl AmdPipelineCompilerControlFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16170,6 +18497,7 @@ impl AmdPipelineCompilerControlFn {
 }
 #[doc = "Generated from 'VK_AMD_pipeline_compiler_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_pipeline_compiler_control`."]
     pub const PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD: Self = Self(1_000_183_000);
 }
 impl ExtCalibratedTimestampsFn {
@@ -16178,6 +18506,19 @@ impl ExtCalibratedTimestampsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetCalibratedTimestampsEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT = extern "system" fn(
@@ -16219,6 +18560,9 @@ impl ::std::clone::Clone for ExtCalibratedTimestampsFn {
     }
 }
 impl ExtCalibratedTimestampsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16298,9 +18642,25 @@ impl ExtCalibratedTimestampsFn {
             p_max_deviation,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_calibrateable_time_domains_ext`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_calibrateable_time_domains_ext_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_time_domain_count: *mut u32,
+        p_time_domains: *mut TimeDomainEXT,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_calibrateable_time_domains_ext(
+            physical_device,
+            p_time_domain_count,
+            p_time_domains,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_EXT_calibrated_timestamps'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_calibrated_timestamps`."]
     pub const CALIBRATED_TIMESTAMP_INFO_EXT: Self = Self(1_000_184_000);
 }
 impl AmdShaderCorePropertiesFn {
@@ -16319,6 +18679,9 @@ impl ::std::clone::Clone for AmdShaderCorePropertiesFn {
     }
 }
 impl AmdShaderCorePropertiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16328,6 +18691,7 @@ impl AmdShaderCorePropertiesFn {
 }
 #[doc = "Generated from 'VK_AMD_shader_core_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_shader_core_properties`."]
     pub const PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD: Self = Self(1_000_185_000);
 }
 impl AmdExtension187Fn {
@@ -16346,6 +18710,9 @@ impl ::std::clone::Clone for AmdExtension187Fn {
     }
 }
 impl AmdExtension187Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16369,6 +18736,9 @@ impl ::std::clone::Clone for AmdExtension188Fn {
     }
 }
 impl AmdExtension188Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16392,6 +18762,9 @@ impl ::std::clone::Clone for AmdExtension189Fn {
     }
 }
 impl AmdExtension189Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16415,6 +18788,9 @@ impl ::std::clone::Clone for AmdMemoryOverallocationBehaviorFn {
     }
 }
 impl AmdMemoryOverallocationBehaviorFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16424,6 +18800,7 @@ impl AmdMemoryOverallocationBehaviorFn {
 }
 #[doc = "Generated from 'VK_AMD_memory_overallocation_behavior'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_memory_overallocation_behavior`."]
     pub const DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD: Self = Self(1_000_189_000);
 }
 impl ExtVertexAttributeDivisorFn {
@@ -16442,6 +18819,9 @@ impl ::std::clone::Clone for ExtVertexAttributeDivisorFn {
     }
 }
 impl ExtVertexAttributeDivisorFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16451,14 +18831,17 @@ impl ExtVertexAttributeDivisorFn {
 }
 #[doc = "Generated from 'VK_EXT_vertex_attribute_divisor'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_vertex_attribute_divisor`."]
     pub const PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT: Self = Self(1_000_190_000);
 }
 #[doc = "Generated from 'VK_EXT_vertex_attribute_divisor'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_vertex_attribute_divisor`."]
     pub const PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT: Self = Self(1_000_190_001);
 }
 #[doc = "Generated from 'VK_EXT_vertex_attribute_divisor'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_vertex_attribute_divisor`."]
     pub const PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT: Self = Self(1_000_190_002);
 }
 impl GgpFrameTokenFn {
@@ -16477,6 +18860,9 @@ impl ::std::clone::Clone for GgpFrameTokenFn {
     }
 }
 impl GgpFrameTokenFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16486,6 +18872,7 @@ impl GgpFrameTokenFn {
 }
 #[doc = "Generated from 'VK_GGP_frame_token'"]
 impl StructureType {
+    #[doc = "Provided by `VK_GGP_frame_token`."]
     pub const PRESENT_FRAME_TOKEN_GGP: Self = Self(1_000_191_000);
 }
 impl ExtPipelineCreationFeedbackFn {
@@ -16504,6 +18891,9 @@ impl ::std::clone::Clone for ExtPipelineCreationFeedbackFn {
     }
 }
 impl ExtPipelineCreationFeedbackFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16513,6 +18903,7 @@ impl ExtPipelineCreationFeedbackFn {
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_feedback'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_feedback`."]
     pub const PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT: Self = Self(1_000_192_000);
 }
 impl GoogleExtension194Fn {
@@ -16531,6 +18922,9 @@ impl ::std::clone::Clone for GoogleExtension194Fn {
     }
 }
 impl GoogleExtension194Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16554,6 +18948,9 @@ impl ::std::clone::Clone for GoogleExtension195Fn {
     }
 }
 impl GoogleExtension195Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16577,6 +18974,9 @@ impl ::std::clone::Clone for GoogleExtension196Fn {
     }
 }
 impl GoogleExtension196Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16586,6 +18986,7 @@ impl GoogleExtension196Fn {
 }
 #[doc = "Generated from 'VK_GOOGLE_extension_196'"]
 impl PipelineCacheCreateFlags {
+    #[doc = "Provided by `VK_GOOGLE_extension_196`."]
     pub const RESERVED_1_EXT: Self = Self(0b10);
 }
 impl KhrDriverPropertiesFn {
@@ -16604,6 +19005,9 @@ impl ::std::clone::Clone for KhrDriverPropertiesFn {
     }
 }
 impl KhrDriverPropertiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16613,55 +19017,68 @@ impl KhrDriverPropertiesFn {
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const AMD_PROPRIETARY_KHR: Self = DriverId::AMD_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const AMD_OPEN_SOURCE_KHR: Self = DriverId::AMD_OPEN_SOURCE;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const MESA_RADV_KHR: Self = DriverId::MESA_RADV;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const NVIDIA_PROPRIETARY_KHR: Self = DriverId::NVIDIA_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const INTEL_PROPRIETARY_WINDOWS_KHR: Self = DriverId::INTEL_PROPRIETARY_WINDOWS;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const INTEL_OPEN_SOURCE_MESA_KHR: Self = DriverId::INTEL_OPEN_SOURCE_MESA;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const IMAGINATION_PROPRIETARY_KHR: Self = DriverId::IMAGINATION_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const QUALCOMM_PROPRIETARY_KHR: Self = DriverId::QUALCOMM_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const ARM_PROPRIETARY_KHR: Self = DriverId::ARM_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const GOOGLE_SWIFTSHADER_KHR: Self = DriverId::GOOGLE_SWIFTSHADER;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const GGP_PROPRIETARY_KHR: Self = DriverId::GGP_PROPRIETARY;
 }
 #[doc = "Generated from 'VK_KHR_driver_properties'"]
 impl DriverId {
+    #[doc = "Provided by `VK_KHR_driver_properties`."]
     pub const BROADCOM_PROPRIETARY_KHR: Self = DriverId::BROADCOM_PROPRIETARY;
 }
 impl KhrShaderFloatControlsFn {
@@ -16680,6 +19097,9 @@ impl ::std::clone::Clone for KhrShaderFloatControlsFn {
     }
 }
 impl KhrShaderFloatControlsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16689,19 +19109,23 @@ impl KhrShaderFloatControlsFn {
 }
 #[doc = "Generated from 'VK_KHR_shader_float_controls'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_float_controls`."]
     pub const PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_shader_float_controls'"]
 impl ShaderFloatControlsIndependence {
+    #[doc = "Provided by `VK_KHR_shader_float_controls`."]
     pub const TYPE_32_ONLY_KHR: Self = ShaderFloatControlsIndependence::TYPE_32_ONLY;
 }
 #[doc = "Generated from 'VK_KHR_shader_float_controls'"]
 impl ShaderFloatControlsIndependence {
+    #[doc = "Provided by `VK_KHR_shader_float_controls`."]
     pub const ALL_KHR: Self = ShaderFloatControlsIndependence::ALL;
 }
 #[doc = "Generated from 'VK_KHR_shader_float_controls'"]
 impl ShaderFloatControlsIndependence {
+    #[doc = "Provided by `VK_KHR_shader_float_controls`."]
     pub const NONE_KHR: Self = ShaderFloatControlsIndependence::NONE;
 }
 impl NvShaderSubgroupPartitionedFn {
@@ -16720,6 +19144,9 @@ impl ::std::clone::Clone for NvShaderSubgroupPartitionedFn {
     }
 }
 impl NvShaderSubgroupPartitionedFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16729,6 +19156,7 @@ impl NvShaderSubgroupPartitionedFn {
 }
 #[doc = "Generated from 'VK_NV_shader_subgroup_partitioned'"]
 impl SubgroupFeatureFlags {
+    #[doc = "Provided by `VK_NV_shader_subgroup_partitioned`."]
     pub const PARTITIONED_NV: Self = Self(0b1_0000_0000);
 }
 impl KhrDepthStencilResolveFn {
@@ -16747,6 +19175,9 @@ impl ::std::clone::Clone for KhrDepthStencilResolveFn {
     }
 }
 impl KhrDepthStencilResolveFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16756,32 +19187,39 @@ impl KhrDepthStencilResolveFn {
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE_KHR: Self =
         StructureType::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl ResolveModeFlags {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const NONE_KHR: Self = ResolveModeFlags::NONE;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl ResolveModeFlags {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const SAMPLE_ZERO_KHR: Self = ResolveModeFlags::SAMPLE_ZERO;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl ResolveModeFlags {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const AVERAGE_KHR: Self = ResolveModeFlags::AVERAGE;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl ResolveModeFlags {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const MIN_KHR: Self = ResolveModeFlags::MIN;
 }
 #[doc = "Generated from 'VK_KHR_depth_stencil_resolve'"]
 impl ResolveModeFlags {
+    #[doc = "Provided by `VK_KHR_depth_stencil_resolve`."]
     pub const MAX_KHR: Self = ResolveModeFlags::MAX;
 }
 impl KhrSwapchainMutableFormatFn {
@@ -16800,6 +19238,9 @@ impl ::std::clone::Clone for KhrSwapchainMutableFormatFn {
     }
 }
 impl KhrSwapchainMutableFormatFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16809,6 +19250,7 @@ impl KhrSwapchainMutableFormatFn {
 }
 #[doc = "Generated from 'VK_KHR_swapchain_mutable_format'"]
 impl SwapchainCreateFlagsKHR {
+    #[doc = "Provided by `VK_KHR_swapchain_mutable_format`."]
     pub const MUTABLE_FORMAT: Self = Self(0b100);
 }
 impl NvComputeShaderDerivativesFn {
@@ -16827,6 +19269,9 @@ impl ::std::clone::Clone for NvComputeShaderDerivativesFn {
     }
 }
 impl NvComputeShaderDerivativesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16836,6 +19281,7 @@ impl NvComputeShaderDerivativesFn {
 }
 #[doc = "Generated from 'VK_NV_compute_shader_derivatives'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_compute_shader_derivatives`."]
     pub const PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV: Self = Self(1_000_201_000);
 }
 impl NvMeshShaderFn {
@@ -16844,6 +19290,21 @@ impl NvMeshShaderFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdDrawMeshTasksNV"
+            | b"vkCmdDrawMeshTasksIndirectNV"
+            | b"vkCmdDrawMeshTasksIndirectCountNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawMeshTasksNV =
@@ -16901,6 +19362,9 @@ impl ::std::clone::Clone for NvMeshShaderFn {
     }
 }
 impl NvMeshShaderFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -16975,6 +19439,8 @@ impl NvMeshShaderFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawMeshTasksNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_mesh_tasks_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -16984,6 +19450,8 @@ impl NvMeshShaderFn {
         (self.cmd_draw_mesh_tasks_nv)(command_buffer, task_count, first_task)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_mesh_tasks_indirect_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -16995,6 +19463,8 @@ impl NvMeshShaderFn {
         (self.cmd_draw_mesh_tasks_indirect_nv)(command_buffer, buffer, offset, draw_count, stride)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawMeshTasksIndirectCountNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_mesh_tasks_indirect_count_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -17018,26 +19488,32 @@ impl NvMeshShaderFn {
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV: Self = Self(1_000_202_000);
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV: Self = Self(1_000_202_001);
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const TASK_NV: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl ShaderStageFlags {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const MESH_NV: Self = Self(0b1000_0000);
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const TASK_SHADER_NV: Self = Self(0b1000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_mesh_shader'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_mesh_shader`."]
     pub const MESH_SHADER_NV: Self = Self(0b1_0000_0000_0000_0000_0000);
 }
 impl NvFragmentShaderBarycentricFn {
@@ -17056,6 +19532,9 @@ impl ::std::clone::Clone for NvFragmentShaderBarycentricFn {
     }
 }
 impl NvFragmentShaderBarycentricFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17065,6 +19544,7 @@ impl NvFragmentShaderBarycentricFn {
 }
 #[doc = "Generated from 'VK_NV_fragment_shader_barycentric'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_fragment_shader_barycentric`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV: Self = Self(1_000_203_000);
 }
 impl NvShaderImageFootprintFn {
@@ -17083,6 +19563,9 @@ impl ::std::clone::Clone for NvShaderImageFootprintFn {
     }
 }
 impl NvShaderImageFootprintFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17092,6 +19575,7 @@ impl NvShaderImageFootprintFn {
 }
 #[doc = "Generated from 'VK_NV_shader_image_footprint'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_shader_image_footprint`."]
     pub const PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV: Self = Self(1_000_204_000);
 }
 impl NvScissorExclusiveFn {
@@ -17100,6 +19584,19 @@ impl NvScissorExclusiveFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetExclusiveScissorNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetExclusiveScissorNV = extern "system" fn(
@@ -17126,6 +19623,9 @@ impl ::std::clone::Clone for NvScissorExclusiveFn {
     }
 }
 impl NvScissorExclusiveFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17155,31 +19655,35 @@ impl NvScissorExclusiveFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetExclusiveScissorNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_exclusive_scissor_nv(
         &self,
         command_buffer: CommandBuffer,
         first_exclusive_scissor: u32,
-        exclusive_scissor_count: u32,
-        p_exclusive_scissors: *const Rect2D,
+        exclusive_scissors: &[Rect2D],
     ) -> c_void {
         (self.cmd_set_exclusive_scissor_nv)(
             command_buffer,
             first_exclusive_scissor,
-            exclusive_scissor_count,
-            p_exclusive_scissors,
+            exclusive_scissors.len() as _,
+            exclusive_scissors.as_ptr(),
         )
     }
 }
 #[doc = "Generated from 'VK_NV_scissor_exclusive'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_scissor_exclusive`."]
     pub const PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV: Self = Self(1_000_205_000);
 }
 #[doc = "Generated from 'VK_NV_scissor_exclusive'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_NV_scissor_exclusive`."]
     pub const EXCLUSIVE_SCISSOR_NV: Self = Self(1_000_205_001);
 }
 #[doc = "Generated from 'VK_NV_scissor_exclusive'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_scissor_exclusive`."]
     pub const PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV: Self = Self(1_000_205_002);
 }
 impl NvDeviceDiagnosticCheckpointsFn {
@@ -17188,6 +19692,19 @@ impl NvDeviceDiagnosticCheckpointsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetCheckpointNV" | b"vkGetQueueCheckpointDataNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetCheckpointNV =
@@ -17220,6 +19737,9 @@ impl ::std::clone::Clone for NvDeviceDiagnosticCheckpointsFn {
     }
 }
 impl NvDeviceDiagnosticCheckpointsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17267,6 +19787,8 @@ impl NvDeviceDiagnosticCheckpointsFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetCheckpointNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_checkpoint_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -17286,10 +19808,12 @@ impl NvDeviceDiagnosticCheckpointsFn {
 }
 #[doc = "Generated from 'VK_NV_device_diagnostic_checkpoints'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_diagnostic_checkpoints`."]
     pub const CHECKPOINT_DATA_NV: Self = Self(1_000_206_000);
 }
 #[doc = "Generated from 'VK_NV_device_diagnostic_checkpoints'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_diagnostic_checkpoints`."]
     pub const QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV: Self = Self(1_000_206_001);
 }
 impl KhrTimelineSemaphoreFn {
@@ -17298,6 +19822,21 @@ impl KhrTimelineSemaphoreFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetSemaphoreCounterValueKHR" | b"vkWaitSemaphoresKHR" | b"vkSignalSemaphoreKHR" => {
+                load_device(name)
+            }
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSemaphoreCounterValue =
@@ -17334,6 +19873,9 @@ impl ::std::clone::Clone for KhrTimelineSemaphoreFn {
     }
 }
 impl KhrTimelineSemaphoreFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17420,44 +19962,64 @@ impl KhrTimelineSemaphoreFn {
     ) -> Result {
         (self.signal_semaphore_khr)(device, p_signal_info)
     }
+    #[doc = r" Like [`Self::wait_semaphores_khr`], but returns the success code, which is one of `SUCCESS`, `TIMEOUT`."]
+    pub unsafe fn wait_semaphores_khr_with_success_code(
+        &self,
+        device: Device,
+        p_wait_info: *const SemaphoreWaitInfo,
+        timeout: u64,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.wait_semaphores_khr(device, p_wait_info, timeout)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const SEMAPHORE_TYPE_CREATE_INFO_KHR: Self = StructureType::SEMAPHORE_TYPE_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const TIMELINE_SEMAPHORE_SUBMIT_INFO_KHR: Self =
         StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const SEMAPHORE_WAIT_INFO_KHR: Self = StructureType::SEMAPHORE_WAIT_INFO;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const SEMAPHORE_SIGNAL_INFO_KHR: Self = StructureType::SEMAPHORE_SIGNAL_INFO;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl SemaphoreType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const BINARY_KHR: Self = SemaphoreType::BINARY;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl SemaphoreType {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const TIMELINE_KHR: Self = SemaphoreType::TIMELINE;
 }
 #[doc = "Generated from 'VK_KHR_timeline_semaphore'"]
 impl SemaphoreWaitFlags {
+    #[doc = "Provided by `VK_KHR_timeline_semaphore`."]
     pub const ANY_KHR: Self = SemaphoreWaitFlags::ANY;
 }
 impl KhrExtension209Fn {
@@ -17476,6 +20038,9 @@ impl ::std::clone::Clone for KhrExtension209Fn {
     }
 }
 impl KhrExtension209Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17499,6 +20064,9 @@ impl ::std::clone::Clone for IntelShaderIntegerFunctions2Fn {
     }
 }
 impl IntelShaderIntegerFunctions2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17508,6 +20076,7 @@ impl IntelShaderIntegerFunctions2Fn {
 }
 #[doc = "Generated from 'VK_INTEL_shader_integer_functions2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_shader_integer_functions2`."]
     pub const PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL: Self = Self(1_000_209_000);
 }
 impl IntelPerformanceQueryFn {
@@ -17516,6 +20085,27 @@ impl IntelPerformanceQueryFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkInitializePerformanceApiINTEL"
+            | b"vkUninitializePerformanceApiINTEL"
+            | b"vkCmdSetPerformanceMarkerINTEL"
+            | b"vkCmdSetPerformanceStreamMarkerINTEL"
+            | b"vkCmdSetPerformanceOverrideINTEL"
+            | b"vkAcquirePerformanceConfigurationINTEL"
+            | b"vkReleasePerformanceConfigurationINTEL"
+            | b"vkQueueSetPerformanceConfigurationINTEL"
+            | b"vkGetPerformanceParameterINTEL" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkInitializePerformanceApiINTEL = extern "system" fn(
@@ -17609,6 +20199,9 @@ impl ::std::clone::Clone for IntelPerformanceQueryFn {
     }
 }
 impl IntelPerformanceQueryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17799,6 +20392,8 @@ impl IntelPerformanceQueryFn {
         (self.uninitialize_performance_api_intel)(device)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_performance_marker_intel(
         &self,
         command_buffer: CommandBuffer,
@@ -17807,6 +20402,8 @@ impl IntelPerformanceQueryFn {
         (self.cmd_set_performance_marker_intel)(command_buffer, p_marker_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_performance_stream_marker_intel(
         &self,
         command_buffer: CommandBuffer,
@@ -17815,6 +20412,8 @@ impl IntelPerformanceQueryFn {
         (self.cmd_set_performance_stream_marker_intel)(command_buffer, p_marker_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_performance_override_intel(
         &self,
         command_buffer: CommandBuffer,
@@ -17859,39 +20458,48 @@ impl IntelPerformanceQueryFn {
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL: Self = Self(1_000_210_000);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const QUERY_POOL_CREATE_INFO_INTEL: Self =
         StructureType::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL;
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const INITIALIZE_PERFORMANCE_API_INFO_INTEL: Self = Self(1_000_210_001);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_MARKER_INFO_INTEL: Self = Self(1_000_210_002);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_STREAM_MARKER_INFO_INTEL: Self = Self(1_000_210_003);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_OVERRIDE_INFO_INTEL: Self = Self(1_000_210_004);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl StructureType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL: Self = Self(1_000_210_005);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl QueryType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_QUERY_INTEL: Self = Self(1_000_210_000);
 }
 #[doc = "Generated from 'VK_INTEL_performance_query'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_INTEL_performance_query`."]
     pub const PERFORMANCE_CONFIGURATION_INTEL: Self = Self(1_000_210_000);
 }
 impl KhrVulkanMemoryModelFn {
@@ -17910,6 +20518,9 @@ impl ::std::clone::Clone for KhrVulkanMemoryModelFn {
     }
 }
 impl KhrVulkanMemoryModelFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17919,6 +20530,7 @@ impl KhrVulkanMemoryModelFn {
 }
 #[doc = "Generated from 'VK_KHR_vulkan_memory_model'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_vulkan_memory_model`."]
     pub const PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES;
 }
@@ -17938,6 +20550,9 @@ impl ::std::clone::Clone for ExtPciBusInfoFn {
     }
 }
 impl ExtPciBusInfoFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -17947,6 +20562,7 @@ impl ExtPciBusInfoFn {
 }
 #[doc = "Generated from 'VK_EXT_pci_bus_info'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_pci_bus_info`."]
     pub const PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT: Self = Self(1_000_212_000);
 }
 impl AmdDisplayNativeHdrFn {
@@ -17955,6 +20571,19 @@ impl AmdDisplayNativeHdrFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkSetLocalDimmingAMD" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetLocalDimmingAMD = extern "system" fn(
@@ -17979,6 +20608,9 @@ impl ::std::clone::Clone for AmdDisplayNativeHdrFn {
     }
 }
 impl AmdDisplayNativeHdrFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18018,14 +20650,17 @@ impl AmdDisplayNativeHdrFn {
 }
 #[doc = "Generated from 'VK_AMD_display_native_hdr'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_display_native_hdr`."]
     pub const DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD: Self = Self(1_000_213_000);
 }
 #[doc = "Generated from 'VK_AMD_display_native_hdr'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_display_native_hdr`."]
     pub const SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD: Self = Self(1_000_213_001);
 }
 #[doc = "Generated from 'VK_AMD_display_native_hdr'"]
 impl ColorSpaceKHR {
+    #[doc = "Provided by `VK_AMD_display_native_hdr`."]
     pub const DISPLAY_NATIVE_AMD: Self = Self(1_000_213_000);
 }
 impl FuchsiaImagepipeSurfaceFn {
@@ -18060,6 +20695,9 @@ impl ::std::clone::Clone for FuchsiaImagepipeSurfaceFn {
     }
 }
 impl FuchsiaImagepipeSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18101,6 +20739,7 @@ impl FuchsiaImagepipeSurfaceFn {
 }
 #[doc = "Generated from 'VK_FUCHSIA_imagepipe_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_FUCHSIA_imagepipe_surface`."]
     pub const IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA: Self = Self(1_000_214_000);
 }
 impl KhrShaderTerminateInvocationFn {
@@ -18119,6 +20758,9 @@ impl ::std::clone::Clone for KhrShaderTerminateInvocationFn {
     }
 }
 impl KhrShaderTerminateInvocationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18128,6 +20770,7 @@ impl KhrShaderTerminateInvocationFn {
 }
 #[doc = "Generated from 'VK_KHR_shader_terminate_invocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_shader_terminate_invocation`."]
     pub const PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR: Self = Self(1_000_215_000);
 }
 impl GoogleExtension217Fn {
@@ -18146,6 +20789,9 @@ impl ::std::clone::Clone for GoogleExtension217Fn {
     }
 }
 impl GoogleExtension217Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18185,6 +20831,9 @@ impl ::std::clone::Clone for ExtMetalSurfaceFn {
     }
 }
 impl ExtMetalSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18226,6 +20875,7 @@ impl ExtMetalSurfaceFn {
 }
 #[doc = "Generated from 'VK_EXT_metal_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_metal_surface`."]
     pub const METAL_SURFACE_CREATE_INFO_EXT: Self = Self(1_000_217_000);
 }
 impl ExtFragmentDensityMapFn {
@@ -18244,6 +20894,9 @@ impl ::std::clone::Clone for ExtFragmentDensityMapFn {
     }
 }
 impl ExtFragmentDensityMapFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18253,50 +20906,62 @@ impl ExtFragmentDensityMapFn {
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT: Self = Self(1_000_218_000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT: Self = Self(1_000_218_001);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT: Self = Self(1_000_218_002);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const SUBSAMPLED_EXT: Self = Self(0b100_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_MAP_OPTIMAL_EXT: Self = Self(1_000_218_000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_MAP_READ_EXT: Self = Self(0b1_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_MAP_EXT: Self = Self(0b1_0000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_MAP_EXT: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl ImageViewCreateFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_MAP_DYNAMIC_EXT: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const FRAGMENT_DENSITY_PROCESS_EXT: Self = Self(0b1000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl SamplerCreateFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const SUBSAMPLED_EXT: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map'"]
 impl SamplerCreateFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map`."]
     pub const SUBSAMPLED_COARSE_RECONSTRUCTION_EXT: Self = Self(0b10);
 }
 impl ExtExtension220Fn {
@@ -18315,6 +20980,9 @@ impl ::std::clone::Clone for ExtExtension220Fn {
     }
 }
 impl ExtExtension220Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18338,6 +21006,9 @@ impl ::std::clone::Clone for KhrExtension221Fn {
     }
 }
 impl KhrExtension221Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18347,6 +21018,7 @@ impl KhrExtension221Fn {
 }
 #[doc = "Generated from 'VK_KHR_extension_221'"]
 impl RenderPassCreateFlags {
+    #[doc = "Provided by `VK_KHR_extension_221`."]
     pub const RESERVED_0_KHR: Self = Self(0b1);
 }
 impl ExtScalarBlockLayoutFn {
@@ -18365,6 +21037,9 @@ impl ::std::clone::Clone for ExtScalarBlockLayoutFn {
     }
 }
 impl ExtScalarBlockLayoutFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18374,6 +21049,7 @@ impl ExtScalarBlockLayoutFn {
 }
 #[doc = "Generated from 'VK_EXT_scalar_block_layout'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_scalar_block_layout`."]
     pub const PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES;
 }
@@ -18393,6 +21069,9 @@ impl ::std::clone::Clone for ExtExtension223Fn {
     }
 }
 impl ExtExtension223Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18416,6 +21095,9 @@ impl ::std::clone::Clone for GoogleHlslFunctionality1Fn {
     }
 }
 impl GoogleHlslFunctionality1Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18439,6 +21121,9 @@ impl ::std::clone::Clone for GoogleDecorateStringFn {
     }
 }
 impl GoogleDecorateStringFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18462,6 +21147,9 @@ impl ::std::clone::Clone for ExtSubgroupSizeControlFn {
     }
 }
 impl ExtSubgroupSizeControlFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18471,23 +21159,28 @@ impl ExtSubgroupSizeControlFn {
 }
 #[doc = "Generated from 'VK_EXT_subgroup_size_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_subgroup_size_control`."]
     pub const PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT: Self = Self(1_000_225_000);
 }
 #[doc = "Generated from 'VK_EXT_subgroup_size_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_subgroup_size_control`."]
     pub const PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT: Self =
         Self(1_000_225_001);
 }
 #[doc = "Generated from 'VK_EXT_subgroup_size_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_subgroup_size_control`."]
     pub const PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT: Self = Self(1_000_225_002);
 }
 #[doc = "Generated from 'VK_EXT_subgroup_size_control'"]
 impl PipelineShaderStageCreateFlags {
+    #[doc = "Provided by `VK_EXT_subgroup_size_control`."]
     pub const ALLOW_VARYING_SUBGROUP_SIZE_EXT: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_EXT_subgroup_size_control'"]
 impl PipelineShaderStageCreateFlags {
+    #[doc = "Provided by `VK_EXT_subgroup_size_control`."]
     pub const REQUIRE_FULL_SUBGROUPS_EXT: Self = Self(0b10);
 }
 impl KhrFragmentShadingRateFn {
@@ -18496,6 +21189,19 @@ impl KhrFragmentShadingRateFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetFragmentShadingRateKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR = extern "system" fn(
@@ -18533,6 +21239,9 @@ impl ::std::clone::Clone for KhrFragmentShadingRateFn {
     }
 }
 impl KhrFragmentShadingRateFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18594,6 +21303,8 @@ impl KhrFragmentShadingRateFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetFragmentShadingRateKHR.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_fragment_shading_rate_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -18602,52 +21313,78 @@ impl KhrFragmentShadingRateFn {
     ) -> c_void {
         (self.cmd_set_fragment_shading_rate_khr)(command_buffer, p_fragment_size, combiner_ops)
     }
+    #[doc = r" Like [`Self::get_physical_device_fragment_shading_rates_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_fragment_shading_rates_khr_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_fragment_shading_rate_count: *mut u32,
+        p_fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_fragment_shading_rates_khr(
+            physical_device,
+            p_fragment_shading_rate_count,
+            p_fragment_shading_rates,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR: Self =
         ImageLayout::SHADING_RATE_OPTIMAL_NV;
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_KHR: Self = Self(1_000_226_000);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR: Self = Self(1_000_226_000);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR: Self = Self(1_000_226_001);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR: Self = Self(1_000_226_002);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR: Self = Self(1_000_226_003);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR: Self = Self(1_000_226_004);
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR: Self =
         AccessFlags::SHADING_RATE_IMAGE_READ_NV;
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl ImageUsageFlags {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_KHR: Self = ImageUsageFlags::SHADING_RATE_IMAGE_NV;
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_KHR: Self =
         PipelineStageFlags::SHADING_RATE_IMAGE_NV;
 }
 #[doc = "Generated from 'VK_KHR_fragment_shading_rate'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by `VK_KHR_fragment_shading_rate`."]
     pub const FRAGMENT_SHADING_RATE_ATTACHMENT_KHR: Self =
         Self(0b100_0000_0000_0000_0000_0000_0000_0000);
 }
@@ -18667,6 +21404,9 @@ impl ::std::clone::Clone for AmdShaderCoreProperties2Fn {
     }
 }
 impl AmdShaderCoreProperties2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18676,6 +21416,7 @@ impl AmdShaderCoreProperties2Fn {
 }
 #[doc = "Generated from 'VK_AMD_shader_core_properties2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_shader_core_properties2`."]
     pub const PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD: Self = Self(1_000_227_000);
 }
 impl AmdExtension229Fn {
@@ -18694,6 +21435,9 @@ impl ::std::clone::Clone for AmdExtension229Fn {
     }
 }
 impl AmdExtension229Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18717,6 +21461,9 @@ impl ::std::clone::Clone for AmdDeviceCoherentMemoryFn {
     }
 }
 impl AmdDeviceCoherentMemoryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18726,14 +21473,17 @@ impl AmdDeviceCoherentMemoryFn {
 }
 #[doc = "Generated from 'VK_AMD_device_coherent_memory'"]
 impl MemoryPropertyFlags {
+    #[doc = "Provided by `VK_AMD_device_coherent_memory`."]
     pub const DEVICE_COHERENT_AMD: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_AMD_device_coherent_memory'"]
 impl MemoryPropertyFlags {
+    #[doc = "Provided by `VK_AMD_device_coherent_memory`."]
     pub const DEVICE_UNCACHED_AMD: Self = Self(0b1000_0000);
 }
 #[doc = "Generated from 'VK_AMD_device_coherent_memory'"]
 impl StructureType {
+    #[doc = "Provided by `VK_AMD_device_coherent_memory`."]
     pub const PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD: Self = Self(1_000_229_000);
 }
 impl AmdExtension231Fn {
@@ -18752,6 +21502,9 @@ impl ::std::clone::Clone for AmdExtension231Fn {
     }
 }
 impl AmdExtension231Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18775,6 +21528,9 @@ impl ::std::clone::Clone for AmdExtension232Fn {
     }
 }
 impl AmdExtension232Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18798,6 +21554,9 @@ impl ::std::clone::Clone for AmdExtension233Fn {
     }
 }
 impl AmdExtension233Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18821,6 +21580,9 @@ impl ::std::clone::Clone for AmdExtension234Fn {
     }
 }
 impl AmdExtension234Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18844,6 +21606,9 @@ impl ::std::clone::Clone for ExtShaderImageAtomicInt64Fn {
     }
 }
 impl ExtShaderImageAtomicInt64Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18853,6 +21618,7 @@ impl ExtShaderImageAtomicInt64Fn {
 }
 #[doc = "Generated from 'VK_EXT_shader_image_atomic_int64'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_shader_image_atomic_int64`."]
     pub const PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT: Self = Self(1_000_234_000);
 }
 impl AmdExtension236Fn {
@@ -18871,6 +21637,9 @@ impl ::std::clone::Clone for AmdExtension236Fn {
     }
 }
 impl AmdExtension236Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18894,6 +21663,9 @@ impl ::std::clone::Clone for KhrSpirv14Fn {
     }
 }
 impl KhrSpirv14Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18917,6 +21689,9 @@ impl ::std::clone::Clone for ExtMemoryBudgetFn {
     }
 }
 impl ExtMemoryBudgetFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18926,6 +21701,7 @@ impl ExtMemoryBudgetFn {
 }
 #[doc = "Generated from 'VK_EXT_memory_budget'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_memory_budget`."]
     pub const PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT: Self = Self(1_000_237_000);
 }
 impl ExtMemoryPriorityFn {
@@ -18944,6 +21720,9 @@ impl ::std::clone::Clone for ExtMemoryPriorityFn {
     }
 }
 impl ExtMemoryPriorityFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18953,10 +21732,12 @@ impl ExtMemoryPriorityFn {
 }
 #[doc = "Generated from 'VK_EXT_memory_priority'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_memory_priority`."]
     pub const PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT: Self = Self(1_000_238_000);
 }
 #[doc = "Generated from 'VK_EXT_memory_priority'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_memory_priority`."]
     pub const MEMORY_PRIORITY_ALLOCATE_INFO_EXT: Self = Self(1_000_238_001);
 }
 impl KhrSurfaceProtectedCapabilitiesFn {
@@ -18975,6 +21756,9 @@ impl ::std::clone::Clone for KhrSurfaceProtectedCapabilitiesFn {
     }
 }
 impl KhrSurfaceProtectedCapabilitiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -18984,6 +21768,7 @@ impl KhrSurfaceProtectedCapabilitiesFn {
 }
 #[doc = "Generated from 'VK_KHR_surface_protected_capabilities'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_surface_protected_capabilities`."]
     pub const SURFACE_PROTECTED_CAPABILITIES_KHR: Self = Self(1_000_239_000);
 }
 impl NvDedicatedAllocationImageAliasingFn {
@@ -19002,6 +21787,9 @@ impl ::std::clone::Clone for NvDedicatedAllocationImageAliasingFn {
     }
 }
 impl NvDedicatedAllocationImageAliasingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19011,6 +21799,7 @@ impl NvDedicatedAllocationImageAliasingFn {
 }
 #[doc = "Generated from 'VK_NV_dedicated_allocation_image_aliasing'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_dedicated_allocation_image_aliasing`."]
     pub const PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV: Self =
         Self(1_000_240_000);
 }
@@ -19030,6 +21819,9 @@ impl ::std::clone::Clone for KhrSeparateDepthStencilLayoutsFn {
     }
 }
 impl KhrSeparateDepthStencilLayoutsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19039,33 +21831,40 @@ impl KhrSeparateDepthStencilLayoutsFn {
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const ATTACHMENT_REFERENCE_STENCIL_LAYOUT_KHR: Self =
         StructureType::ATTACHMENT_REFERENCE_STENCIL_LAYOUT;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT_KHR: Self =
         StructureType::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const DEPTH_ATTACHMENT_OPTIMAL_KHR: Self = ImageLayout::DEPTH_ATTACHMENT_OPTIMAL;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const DEPTH_READ_ONLY_OPTIMAL_KHR: Self = ImageLayout::DEPTH_READ_ONLY_OPTIMAL;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const STENCIL_ATTACHMENT_OPTIMAL_KHR: Self = ImageLayout::STENCIL_ATTACHMENT_OPTIMAL;
 }
 #[doc = "Generated from 'VK_KHR_separate_depth_stencil_layouts'"]
 impl ImageLayout {
+    #[doc = "Provided by `VK_KHR_separate_depth_stencil_layouts`."]
     pub const STENCIL_READ_ONLY_OPTIMAL_KHR: Self = ImageLayout::STENCIL_READ_ONLY_OPTIMAL;
 }
 impl IntelExtension243Fn {
@@ -19084,6 +21883,9 @@ impl ::std::clone::Clone for IntelExtension243Fn {
     }
 }
 impl IntelExtension243Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19107,6 +21909,9 @@ impl ::std::clone::Clone for MesaExtension244Fn {
     }
 }
 impl MesaExtension244Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19120,6 +21925,19 @@ impl ExtBufferDeviceAddressFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 2u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetBufferDeviceAddressEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetBufferDeviceAddress =
@@ -19138,6 +21956,9 @@ impl ::std::clone::Clone for ExtBufferDeviceAddressFn {
     }
 }
 impl ExtBufferDeviceAddressFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19175,32 +21996,39 @@ impl ExtBufferDeviceAddressFn {
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT: Self = Self(1_000_244_000);
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const PHYSICAL_DEVICE_BUFFER_ADDRESS_FEATURES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT;
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const BUFFER_DEVICE_ADDRESS_INFO_EXT: Self = StructureType::BUFFER_DEVICE_ADDRESS_INFO;
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT: Self = Self(1_000_244_002);
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const SHADER_DEVICE_ADDRESS_EXT: Self = BufferUsageFlags::SHADER_DEVICE_ADDRESS;
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl BufferCreateFlags {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const DEVICE_ADDRESS_CAPTURE_REPLAY_EXT: Self =
         BufferCreateFlags::DEVICE_ADDRESS_CAPTURE_REPLAY;
 }
 #[doc = "Generated from 'VK_EXT_buffer_device_address'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_buffer_device_address`."]
     pub const ERROR_INVALID_DEVICE_ADDRESS_EXT: Self = Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS;
 }
 impl ExtToolingInfoFn {
@@ -19233,6 +22061,9 @@ impl ::std::clone::Clone for ExtToolingInfoFn {
     }
 }
 impl ExtToolingInfoFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19273,17 +22104,35 @@ impl ExtToolingInfoFn {
             p_tool_properties,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_tool_properties_ext`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_tool_properties_ext_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_tool_count: *mut u32,
+        p_tool_properties: *mut PhysicalDeviceToolPropertiesEXT,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_tool_properties_ext(
+            physical_device,
+            p_tool_count,
+            p_tool_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_EXT_tooling_info'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_tooling_info`."]
     pub const PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT: Self = Self(1_000_245_000);
 }
 #[doc = "Generated from 'VK_EXT_tooling_info'"]
 impl ToolPurposeFlagsEXT {
+    #[doc = "Provided by `VK_EXT_tooling_info`."]
     pub const DEBUG_REPORTING: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_EXT_tooling_info'"]
 impl ToolPurposeFlagsEXT {
+    #[doc = "Provided by `VK_EXT_tooling_info`."]
     pub const DEBUG_MARKERS: Self = Self(0b100_0000);
 }
 impl ExtSeparateStencilUsageFn {
@@ -19302,6 +22151,9 @@ impl ::std::clone::Clone for ExtSeparateStencilUsageFn {
     }
 }
 impl ExtSeparateStencilUsageFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19311,6 +22163,7 @@ impl ExtSeparateStencilUsageFn {
 }
 #[doc = "Generated from 'VK_EXT_separate_stencil_usage'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_separate_stencil_usage`."]
     pub const IMAGE_STENCIL_USAGE_CREATE_INFO_EXT: Self =
         StructureType::IMAGE_STENCIL_USAGE_CREATE_INFO;
 }
@@ -19330,6 +22183,9 @@ impl ::std::clone::Clone for ExtValidationFeaturesFn {
     }
 }
 impl ExtValidationFeaturesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19339,6 +22195,7 @@ impl ExtValidationFeaturesFn {
 }
 #[doc = "Generated from 'VK_EXT_validation_features'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_validation_features`."]
     pub const VALIDATION_FEATURES_EXT: Self = Self(1_000_247_000);
 }
 impl KhrExtension249Fn {
@@ -19357,6 +22214,9 @@ impl ::std::clone::Clone for KhrExtension249Fn {
     }
 }
 impl KhrExtension249Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19395,6 +22255,9 @@ impl ::std::clone::Clone for NvCooperativeMatrixFn {
     }
 }
 impl NvCooperativeMatrixFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19435,17 +22298,35 @@ impl NvCooperativeMatrixFn {
             p_properties,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_cooperative_matrix_properties_nv`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_cooperative_matrix_properties_nv_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut CooperativeMatrixPropertiesNV,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_cooperative_matrix_properties_nv(
+            physical_device,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_NV_cooperative_matrix'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_cooperative_matrix`."]
     pub const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV: Self = Self(1_000_249_000);
 }
 #[doc = "Generated from 'VK_NV_cooperative_matrix'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_cooperative_matrix`."]
     pub const COOPERATIVE_MATRIX_PROPERTIES_NV: Self = Self(1_000_249_001);
 }
 #[doc = "Generated from 'VK_NV_cooperative_matrix'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_cooperative_matrix`."]
     pub const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV: Self = Self(1_000_249_002);
 }
 impl NvCoverageReductionModeFn {
@@ -19481,6 +22362,9 @@ impl ::std::clone::Clone for NvCoverageReductionModeFn {
     }
 }
 impl NvCoverageReductionModeFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19524,17 +22408,35 @@ impl NvCoverageReductionModeFn {
             p_combinations,
         )
     }
+    #[doc = r" Like [`Self::get_physical_device_supported_framebuffer_mixed_samples_combinations_nv`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_combination_count: *mut u32,
+        p_combinations: *mut FramebufferMixedSamplesCombinationNV,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+            physical_device,
+            p_combination_count,
+            p_combinations,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_NV_coverage_reduction_mode'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_coverage_reduction_mode`."]
     pub const PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV: Self = Self(1_000_250_000);
 }
 #[doc = "Generated from 'VK_NV_coverage_reduction_mode'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_coverage_reduction_mode`."]
     pub const PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV: Self = Self(1_000_250_001);
 }
 #[doc = "Generated from 'VK_NV_coverage_reduction_mode'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_coverage_reduction_mode`."]
     pub const FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV: Self = Self(1_000_250_002);
 }
 impl ExtFragmentShaderInterlockFn {
@@ -19553,6 +22455,9 @@ impl ::std::clone::Clone for ExtFragmentShaderInterlockFn {
     }
 }
 impl ExtFragmentShaderInterlockFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19562,6 +22467,7 @@ impl ExtFragmentShaderInterlockFn {
 }
 #[doc = "Generated from 'VK_EXT_fragment_shader_interlock'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_shader_interlock`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT: Self = Self(1_000_251_000);
 }
 impl ExtYcbcrImageArraysFn {
@@ -19580,6 +22486,9 @@ impl ::std::clone::Clone for ExtYcbcrImageArraysFn {
     }
 }
 impl ExtYcbcrImageArraysFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19589,6 +22498,7 @@ impl ExtYcbcrImageArraysFn {
 }
 #[doc = "Generated from 'VK_EXT_ycbcr_image_arrays'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_ycbcr_image_arrays`."]
     pub const PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT: Self = Self(1_000_252_000);
 }
 impl KhrUniformBufferStandardLayoutFn {
@@ -19607,6 +22517,9 @@ impl ::std::clone::Clone for KhrUniformBufferStandardLayoutFn {
     }
 }
 impl KhrUniformBufferStandardLayoutFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19616,6 +22529,7 @@ impl KhrUniformBufferStandardLayoutFn {
 }
 #[doc = "Generated from 'VK_KHR_uniform_buffer_standard_layout'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_uniform_buffer_standard_layout`."]
     pub const PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES;
 }
@@ -19635,6 +22549,9 @@ impl ::std::clone::Clone for ExtExtension255Fn {
     }
 }
 impl ExtExtension255Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19648,6 +22565,21 @@ impl ExtFullScreenExclusiveFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 4u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkAcquireFullScreenExclusiveModeEXT"
+            | b"vkReleaseFullScreenExclusiveModeEXT"
+            | b"vkGetDeviceGroupSurfacePresentModes2EXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT = extern "system" fn(
@@ -19700,6 +22632,9 @@ impl ::std::clone::Clone for ExtFullScreenExclusiveFn {
     }
 }
 impl ExtFullScreenExclusiveFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19826,21 +22761,42 @@ impl ExtFullScreenExclusiveFn {
     ) -> Result {
         (self.get_device_group_surface_present_modes2_ext)(device, p_surface_info, p_modes)
     }
+    #[doc = r" Like [`Self::get_physical_device_surface_present_modes2_ext`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_physical_device_surface_present_modes2_ext_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+        p_present_mode_count: *mut u32,
+        p_present_modes: *mut PresentModeKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_physical_device_surface_present_modes2_ext(
+            physical_device,
+            p_surface_info,
+            p_present_mode_count,
+            p_present_modes,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_EXT_full_screen_exclusive'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_full_screen_exclusive`."]
     pub const SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT: Self = Self(1_000_255_000);
 }
 #[doc = "Generated from 'VK_EXT_full_screen_exclusive'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_full_screen_exclusive`."]
     pub const SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT: Self = Self(1_000_255_002);
 }
 #[doc = "Generated from 'VK_EXT_full_screen_exclusive'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_full_screen_exclusive`."]
     pub const ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT: Self = Self(-1000255000);
 }
 #[doc = "Generated from 'VK_EXT_full_screen_exclusive'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_full_screen_exclusive`."]
     pub const SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT: Self = Self(1_000_255_001);
 }
 impl ExtHeadlessSurfaceFn {
@@ -19875,6 +22831,9 @@ impl ::std::clone::Clone for ExtHeadlessSurfaceFn {
     }
 }
 impl ExtHeadlessSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -19916,6 +22875,7 @@ impl ExtHeadlessSurfaceFn {
 }
 #[doc = "Generated from 'VK_EXT_headless_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_headless_surface`."]
     pub const HEADLESS_SURFACE_CREATE_INFO_EXT: Self = Self(1_000_256_000);
 }
 impl KhrBufferDeviceAddressFn {
@@ -19924,6 +22884,21 @@ impl KhrBufferDeviceAddressFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetBufferDeviceAddressKHR"
+            | b"vkGetBufferOpaqueCaptureAddressKHR"
+            | b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetBufferOpaqueCaptureAddress =
@@ -19954,6 +22929,9 @@ impl ::std::clone::Clone for KhrBufferDeviceAddressFn {
     }
 }
 impl KhrBufferDeviceAddressFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20045,48 +23023,58 @@ impl KhrBufferDeviceAddressFn {
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_KHR: Self =
         StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const BUFFER_DEVICE_ADDRESS_INFO_KHR: Self = StructureType::BUFFER_DEVICE_ADDRESS_INFO;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO_KHR: Self =
         StructureType::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO_KHR: Self =
         StructureType::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO_KHR: Self =
         StructureType::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const SHADER_DEVICE_ADDRESS_KHR: Self = BufferUsageFlags::SHADER_DEVICE_ADDRESS;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl BufferCreateFlags {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const DEVICE_ADDRESS_CAPTURE_REPLAY_KHR: Self =
         BufferCreateFlags::DEVICE_ADDRESS_CAPTURE_REPLAY;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl MemoryAllocateFlags {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const DEVICE_ADDRESS_KHR: Self = MemoryAllocateFlags::DEVICE_ADDRESS;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl MemoryAllocateFlags {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const DEVICE_ADDRESS_CAPTURE_REPLAY_KHR: Self =
         MemoryAllocateFlags::DEVICE_ADDRESS_CAPTURE_REPLAY;
 }
 #[doc = "Generated from 'VK_KHR_buffer_device_address'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_buffer_device_address`."]
     pub const ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR: Self =
         Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS;
 }
@@ -20106,6 +23094,9 @@ impl ::std::clone::Clone for ExtExtension259Fn {
     }
 }
 impl ExtExtension259Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20119,6 +23110,19 @@ impl ExtLineRasterizationFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetLineStippleEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetLineStippleEXT = extern "system" fn(
@@ -20143,6 +23147,9 @@ impl ::std::clone::Clone for ExtLineRasterizationFn {
     }
 }
 impl ExtLineRasterizationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20171,6 +23178,8 @@ impl ExtLineRasterizationFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetLineStippleEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_line_stipple_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20182,18 +23191,22 @@ impl ExtLineRasterizationFn {
 }
 #[doc = "Generated from 'VK_EXT_line_rasterization'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_line_rasterization`."]
     pub const PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT: Self = Self(1_000_259_000);
 }
 #[doc = "Generated from 'VK_EXT_line_rasterization'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_line_rasterization`."]
     pub const PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT: Self = Self(1_000_259_001);
 }
 #[doc = "Generated from 'VK_EXT_line_rasterization'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_line_rasterization`."]
     pub const PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT: Self = Self(1_000_259_002);
 }
 #[doc = "Generated from 'VK_EXT_line_rasterization'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_line_rasterization`."]
     pub const LINE_STIPPLE_EXT: Self = Self(1_000_259_000);
 }
 impl ExtShaderAtomicFloatFn {
@@ -20212,6 +23225,9 @@ impl ::std::clone::Clone for ExtShaderAtomicFloatFn {
     }
 }
 impl ExtShaderAtomicFloatFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20221,6 +23237,7 @@ impl ExtShaderAtomicFloatFn {
 }
 #[doc = "Generated from 'VK_EXT_shader_atomic_float'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_shader_atomic_float`."]
     pub const PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT: Self = Self(1_000_260_000);
 }
 impl ExtHostQueryResetFn {
@@ -20229,6 +23246,19 @@ impl ExtHostQueryResetFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkResetQueryPoolEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkResetQueryPool = extern "system" fn(
@@ -20255,6 +23285,9 @@ impl ::std::clone::Clone for ExtHostQueryResetFn {
     }
 }
 impl ExtHostQueryResetFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20293,6 +23326,7 @@ impl ExtHostQueryResetFn {
 }
 #[doc = "Generated from 'VK_EXT_host_query_reset'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_host_query_reset`."]
     pub const PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES_EXT: Self =
         StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES;
 }
@@ -20312,6 +23346,9 @@ impl ::std::clone::Clone for GgpExtension263Fn {
     }
 }
 impl GgpExtension263Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20335,6 +23372,9 @@ impl ::std::clone::Clone for BrcmExtension264Fn {
     }
 }
 impl BrcmExtension264Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20358,6 +23398,9 @@ impl ::std::clone::Clone for BrcmExtension265Fn {
     }
 }
 impl BrcmExtension265Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20381,6 +23424,9 @@ impl ::std::clone::Clone for ExtIndexTypeUint8Fn {
     }
 }
 impl ExtIndexTypeUint8Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20390,10 +23436,12 @@ impl ExtIndexTypeUint8Fn {
 }
 #[doc = "Generated from 'VK_EXT_index_type_uint8'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_index_type_uint8`."]
     pub const PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT: Self = Self(1_000_265_000);
 }
 #[doc = "Generated from 'VK_EXT_index_type_uint8'"]
 impl IndexType {
+    #[doc = "Provided by `VK_EXT_index_type_uint8`."]
     pub const UINT8_EXT: Self = Self(1_000_265_000);
 }
 impl ExtExtension267Fn {
@@ -20412,6 +23460,9 @@ impl ::std::clone::Clone for ExtExtension267Fn {
     }
 }
 impl ExtExtension267Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20425,6 +23476,30 @@ impl ExtExtendedDynamicStateFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetCullModeEXT"
+            | b"vkCmdSetFrontFaceEXT"
+            | b"vkCmdSetPrimitiveTopologyEXT"
+            | b"vkCmdSetViewportWithCountEXT"
+            | b"vkCmdSetScissorWithCountEXT"
+            | b"vkCmdBindVertexBuffers2EXT"
+            | b"vkCmdSetDepthTestEnableEXT"
+            | b"vkCmdSetDepthWriteEnableEXT"
+            | b"vkCmdSetDepthCompareOpEXT"
+            | b"vkCmdSetDepthBoundsTestEnableEXT"
+            | b"vkCmdSetStencilTestEnableEXT"
+            | b"vkCmdSetStencilOpEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetCullModeEXT =
@@ -20553,6 +23628,9 @@ impl ::std::clone::Clone for ExtExtendedDynamicStateFn {
     }
 }
 impl ExtExtendedDynamicStateFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -20800,6 +23878,8 @@ impl ExtExtendedDynamicStateFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetCullModeEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_cull_mode_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20808,6 +23888,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_cull_mode_ext)(command_buffer, cull_mode)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetFrontFaceEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_front_face_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20816,6 +23898,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_front_face_ext)(command_buffer, front_face)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetPrimitiveTopologyEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_primitive_topology_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20824,24 +23908,36 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_primitive_topology_ext)(command_buffer, primitive_topology)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetViewportWithCountEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_viewport_with_count_ext(
         &self,
         command_buffer: CommandBuffer,
-        viewport_count: u32,
-        p_viewports: *const Viewport,
+        viewports: &[Viewport],
     ) -> c_void {
-        (self.cmd_set_viewport_with_count_ext)(command_buffer, viewport_count, p_viewports)
+        (self.cmd_set_viewport_with_count_ext)(
+            command_buffer,
+            viewports.len() as _,
+            viewports.as_ptr(),
+        )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetScissorWithCountEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_scissor_with_count_ext(
         &self,
         command_buffer: CommandBuffer,
-        scissor_count: u32,
-        p_scissors: *const Rect2D,
+        scissors: &[Rect2D],
     ) -> c_void {
-        (self.cmd_set_scissor_with_count_ext)(command_buffer, scissor_count, p_scissors)
+        (self.cmd_set_scissor_with_count_ext)(
+            command_buffer,
+            scissors.len() as _,
+            scissors.as_ptr(),
+        )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindVertexBuffers2EXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_vertex_buffers2_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20863,6 +23959,8 @@ impl ExtExtendedDynamicStateFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthTestEnableEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_test_enable_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20871,6 +23969,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_depth_test_enable_ext)(command_buffer, depth_test_enable)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthWriteEnableEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_write_enable_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20879,6 +23979,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_depth_write_enable_ext)(command_buffer, depth_write_enable)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthCompareOpEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_compare_op_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20887,6 +23989,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_depth_compare_op_ext)(command_buffer, depth_compare_op)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthBoundsTestEnableEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_bounds_test_enable_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20895,6 +23999,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_depth_bounds_test_enable_ext)(command_buffer, depth_bounds_test_enable)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilTestEnableEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_stencil_test_enable_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20903,6 +24009,8 @@ impl ExtExtendedDynamicStateFn {
         (self.cmd_set_stencil_test_enable_ext)(command_buffer, stencil_test_enable)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilOpEXT.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_stencil_op_ext(
         &self,
         command_buffer: CommandBuffer,
@@ -20924,54 +24032,67 @@ impl ExtExtendedDynamicStateFn {
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT: Self = Self(1_000_267_000);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const CULL_MODE_EXT: Self = Self(1_000_267_000);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const FRONT_FACE_EXT: Self = Self(1_000_267_001);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const PRIMITIVE_TOPOLOGY_EXT: Self = Self(1_000_267_002);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const VIEWPORT_WITH_COUNT_EXT: Self = Self(1_000_267_003);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const SCISSOR_WITH_COUNT_EXT: Self = Self(1_000_267_004);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const VERTEX_INPUT_BINDING_STRIDE_EXT: Self = Self(1_000_267_005);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const DEPTH_TEST_ENABLE_EXT: Self = Self(1_000_267_006);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const DEPTH_WRITE_ENABLE_EXT: Self = Self(1_000_267_007);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const DEPTH_COMPARE_OP_EXT: Self = Self(1_000_267_008);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const DEPTH_BOUNDS_TEST_ENABLE_EXT: Self = Self(1_000_267_009);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const STENCIL_TEST_ENABLE_EXT: Self = Self(1_000_267_010);
 }
 #[doc = "Generated from 'VK_EXT_extended_dynamic_state'"]
 impl DynamicState {
+    #[doc = "Provided by `VK_EXT_extended_dynamic_state`."]
     pub const STENCIL_OP_EXT: Self = Self(1_000_267_011);
 }
 impl KhrDeferredHostOperationsFn {
@@ -20980,6 +24101,23 @@ impl KhrDeferredHostOperationsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 4u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreateDeferredOperationKHR"
+            | b"vkDestroyDeferredOperationKHR"
+            | b"vkGetDeferredOperationMaxConcurrencyKHR"
+            | b"vkGetDeferredOperationResultKHR"
+            | b"vkDeferredOperationJoinKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDeferredOperationKHR = extern "system" fn(
@@ -21035,6 +24173,9 @@ impl ::std::clone::Clone for KhrDeferredHostOperationsFn {
     }
 }
 impl KhrDeferredHostOperationsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21181,25 +24322,40 @@ impl KhrDeferredHostOperationsFn {
     ) -> Result {
         (self.deferred_operation_join_khr)(device, operation)
     }
+    #[doc = r" Like [`Self::deferred_operation_join_khr`], but returns the success code, which is one of `SUCCESS`, `THREAD_DONE_KHR`, `THREAD_IDLE_KHR`."]
+    pub unsafe fn deferred_operation_join_khr_with_success_code(
+        &self,
+        device: Device,
+        operation: DeferredOperationKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.deferred_operation_join_khr(device, operation)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_deferred_host_operations'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_KHR_deferred_host_operations`."]
     pub const DEFERRED_OPERATION_KHR: Self = Self(1_000_268_000);
 }
 #[doc = "Generated from 'VK_KHR_deferred_host_operations'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_deferred_host_operations`."]
     pub const THREAD_IDLE_KHR: Self = Self(1_000_268_000);
 }
 #[doc = "Generated from 'VK_KHR_deferred_host_operations'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_deferred_host_operations`."]
     pub const THREAD_DONE_KHR: Self = Self(1_000_268_001);
 }
 #[doc = "Generated from 'VK_KHR_deferred_host_operations'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_deferred_host_operations`."]
     pub const OPERATION_DEFERRED_KHR: Self = Self(1_000_268_002);
 }
 #[doc = "Generated from 'VK_KHR_deferred_host_operations'"]
 impl Result {
+    #[doc = "Provided by `VK_KHR_deferred_host_operations`."]
     pub const OPERATION_NOT_DEFERRED_KHR: Self = Self(1_000_268_003);
 }
 impl KhrPipelineExecutablePropertiesFn {
@@ -21208,6 +24364,21 @@ impl KhrPipelineExecutablePropertiesFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetPipelineExecutablePropertiesKHR"
+            | b"vkGetPipelineExecutableStatisticsKHR"
+            | b"vkGetPipelineExecutableInternalRepresentationsKHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPipelineExecutablePropertiesKHR = extern "system" fn(
@@ -21263,6 +24434,9 @@ impl ::std::clone::Clone for KhrPipelineExecutablePropertiesFn {
     }
 }
 impl KhrPipelineExecutablePropertiesFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21378,38 +24552,97 @@ impl KhrPipelineExecutablePropertiesFn {
             p_internal_representations,
         )
     }
+    #[doc = r" Like [`Self::get_pipeline_executable_properties_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_pipeline_executable_properties_khr_with_success_code(
+        &self,
+        device: Device,
+        p_pipeline_info: *const PipelineInfoKHR,
+        p_executable_count: *mut u32,
+        p_properties: *mut PipelineExecutablePropertiesKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_pipeline_executable_properties_khr(
+            device,
+            p_pipeline_info,
+            p_executable_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_pipeline_executable_statistics_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_pipeline_executable_statistics_khr_with_success_code(
+        &self,
+        device: Device,
+        p_executable_info: *const PipelineExecutableInfoKHR,
+        p_statistic_count: *mut u32,
+        p_statistics: *mut PipelineExecutableStatisticKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_pipeline_executable_statistics_khr(
+            device,
+            p_executable_info,
+            p_statistic_count,
+            p_statistics,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::get_pipeline_executable_internal_representations_khr`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn get_pipeline_executable_internal_representations_khr_with_success_code(
+        &self,
+        device: Device,
+        p_executable_info: *const PipelineExecutableInfoKHR,
+        p_internal_representation_count: *mut u32,
+        p_internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_pipeline_executable_internal_representations_khr(
+            device,
+            p_executable_info,
+            p_internal_representation_count,
+            p_internal_representations,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR: Self =
         Self(1_000_269_000);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PIPELINE_INFO_KHR: Self = Self(1_000_269_001);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PIPELINE_EXECUTABLE_PROPERTIES_KHR: Self = Self(1_000_269_002);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PIPELINE_EXECUTABLE_INFO_KHR: Self = Self(1_000_269_003);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PIPELINE_EXECUTABLE_STATISTIC_KHR: Self = Self(1_000_269_004);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR: Self = Self(1_000_269_005);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const CAPTURE_STATISTICS_KHR: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_executable_properties'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_pipeline_executable_properties`."]
     pub const CAPTURE_INTERNAL_REPRESENTATIONS_KHR: Self = Self(0b1000_0000);
 }
 impl IntelExtension271Fn {
@@ -21428,6 +24661,9 @@ impl ::std::clone::Clone for IntelExtension271Fn {
     }
 }
 impl IntelExtension271Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21451,6 +24687,9 @@ impl ::std::clone::Clone for IntelExtension272Fn {
     }
 }
 impl IntelExtension272Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21474,6 +24713,9 @@ impl ::std::clone::Clone for IntelExtension273Fn {
     }
 }
 impl IntelExtension273Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21497,6 +24739,9 @@ impl ::std::clone::Clone for IntelExtension274Fn {
     }
 }
 impl IntelExtension274Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21520,6 +24765,9 @@ impl ::std::clone::Clone for KhrExtension275Fn {
     }
 }
 impl KhrExtension275Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21543,6 +24791,9 @@ impl ::std::clone::Clone for KhrExtension276Fn {
     }
 }
 impl KhrExtension276Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21566,6 +24817,9 @@ impl ::std::clone::Clone for ExtShaderDemoteToHelperInvocationFn {
     }
 }
 impl ExtShaderDemoteToHelperInvocationFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21575,6 +24829,7 @@ impl ExtShaderDemoteToHelperInvocationFn {
 }
 #[doc = "Generated from 'VK_EXT_shader_demote_to_helper_invocation'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_shader_demote_to_helper_invocation`."]
     pub const PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT: Self =
         Self(1_000_276_000);
 }
@@ -21584,6 +24839,24 @@ impl NvDeviceGeneratedCommandsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 3u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkGetGeneratedCommandsMemoryRequirementsNV"
+            | b"vkCmdPreprocessGeneratedCommandsNV"
+            | b"vkCmdExecuteGeneratedCommandsNV"
+            | b"vkCmdBindPipelineShaderGroupNV"
+            | b"vkCreateIndirectCommandsLayoutNV"
+            | b"vkDestroyIndirectCommandsLayoutNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetGeneratedCommandsMemoryRequirementsNV = extern "system" fn(
@@ -21671,6 +24944,9 @@ impl ::std::clone::Clone for NvDeviceGeneratedCommandsFn {
     }
 }
 impl NvDeviceGeneratedCommandsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21809,6 +25085,8 @@ impl NvDeviceGeneratedCommandsFn {
         (self.get_generated_commands_memory_requirements_nv)(device, p_info, p_memory_requirements)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_preprocess_generated_commands_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -21817,6 +25095,8 @@ impl NvDeviceGeneratedCommandsFn {
         (self.cmd_preprocess_generated_commands_nv)(command_buffer, p_generated_commands_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_execute_generated_commands_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -21830,6 +25110,8 @@ impl NvDeviceGeneratedCommandsFn {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_pipeline_shader_group_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -21871,54 +25153,67 @@ impl NvDeviceGeneratedCommandsFn {
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV: Self = Self(1_000_277_000);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const GRAPHICS_SHADER_GROUP_CREATE_INFO_NV: Self = Self(1_000_277_001);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV: Self = Self(1_000_277_002);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const INDIRECT_COMMANDS_LAYOUT_TOKEN_NV: Self = Self(1_000_277_003);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV: Self = Self(1_000_277_004);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const GENERATED_COMMANDS_INFO_NV: Self = Self(1_000_277_005);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV: Self = Self(1_000_277_006);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV: Self = Self(1_000_277_007);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const INDIRECT_BINDABLE_NV: Self = Self(0b100_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl PipelineStageFlags {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const COMMAND_PREPROCESS_NV: Self = Self(0b10_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const COMMAND_PREPROCESS_READ_NV: Self = Self(0b10_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl AccessFlags {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const COMMAND_PREPROCESS_WRITE_NV: Self = Self(0b100_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_NV_device_generated_commands'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_NV_device_generated_commands`."]
     pub const INDIRECT_COMMANDS_LAYOUT_NV: Self = Self(1_000_277_000);
 }
 impl NvExtension279Fn {
@@ -21937,6 +25232,9 @@ impl ::std::clone::Clone for NvExtension279Fn {
     }
 }
 impl NvExtension279Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21960,6 +25258,9 @@ impl ::std::clone::Clone for KhrExtension280Fn {
     }
 }
 impl KhrExtension280Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -21983,6 +25284,9 @@ impl ::std::clone::Clone for ArmExtension281Fn {
     }
 }
 impl ArmExtension281Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22006,6 +25310,9 @@ impl ::std::clone::Clone for ExtTexelBufferAlignmentFn {
     }
 }
 impl ExtTexelBufferAlignmentFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22015,10 +25322,12 @@ impl ExtTexelBufferAlignmentFn {
 }
 #[doc = "Generated from 'VK_EXT_texel_buffer_alignment'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_texel_buffer_alignment`."]
     pub const PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT: Self = Self(1_000_281_000);
 }
 #[doc = "Generated from 'VK_EXT_texel_buffer_alignment'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_texel_buffer_alignment`."]
     pub const PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT: Self = Self(1_000_281_001);
 }
 impl QcomRenderPassTransformFn {
@@ -22037,6 +25346,9 @@ impl ::std::clone::Clone for QcomRenderPassTransformFn {
     }
 }
 impl QcomRenderPassTransformFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22046,15 +25358,18 @@ impl QcomRenderPassTransformFn {
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_transform'"]
 impl StructureType {
+    #[doc = "Provided by `VK_QCOM_render_pass_transform`."]
     pub const COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM: Self =
         Self(1_000_282_000);
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_transform'"]
 impl StructureType {
+    #[doc = "Provided by `VK_QCOM_render_pass_transform`."]
     pub const RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM: Self = Self(1_000_282_001);
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_transform'"]
 impl RenderPassCreateFlags {
+    #[doc = "Provided by `VK_QCOM_render_pass_transform`."]
     pub const TRANSFORM_QCOM: Self = Self(0b10);
 }
 impl ExtExtension284Fn {
@@ -22073,6 +25388,9 @@ impl ::std::clone::Clone for ExtExtension284Fn {
     }
 }
 impl ExtExtension284Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22096,6 +25414,9 @@ impl ::std::clone::Clone for ExtDeviceMemoryReportFn {
     }
 }
 impl ExtDeviceMemoryReportFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22105,14 +25426,17 @@ impl ExtDeviceMemoryReportFn {
 }
 #[doc = "Generated from 'VK_EXT_device_memory_report'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_device_memory_report`."]
     pub const PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT: Self = Self(1_000_284_000);
 }
 #[doc = "Generated from 'VK_EXT_device_memory_report'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_device_memory_report`."]
     pub const DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT: Self = Self(1_000_284_001);
 }
 #[doc = "Generated from 'VK_EXT_device_memory_report'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_device_memory_report`."]
     pub const DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT: Self = Self(1_000_284_002);
 }
 impl ExtExtension286Fn {
@@ -22131,6 +25455,9 @@ impl ::std::clone::Clone for ExtExtension286Fn {
     }
 }
 impl ExtExtension286Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22154,6 +25481,9 @@ impl ::std::clone::Clone for ExtRobustness2Fn {
     }
 }
 impl ExtRobustness2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22163,10 +25493,12 @@ impl ExtRobustness2Fn {
 }
 #[doc = "Generated from 'VK_EXT_robustness2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_robustness2`."]
     pub const PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT: Self = Self(1_000_286_000);
 }
 #[doc = "Generated from 'VK_EXT_robustness2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_robustness2`."]
     pub const PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT: Self = Self(1_000_286_001);
 }
 impl ExtCustomBorderColorFn {
@@ -22185,6 +25517,9 @@ impl ::std::clone::Clone for ExtCustomBorderColorFn {
     }
 }
 impl ExtCustomBorderColorFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22194,22 +25529,27 @@ impl ExtCustomBorderColorFn {
 }
 #[doc = "Generated from 'VK_EXT_custom_border_color'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_custom_border_color`."]
     pub const SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT: Self = Self(1_000_287_000);
 }
 #[doc = "Generated from 'VK_EXT_custom_border_color'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_custom_border_color`."]
     pub const PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT: Self = Self(1_000_287_001);
 }
 #[doc = "Generated from 'VK_EXT_custom_border_color'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_custom_border_color`."]
     pub const PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT: Self = Self(1_000_287_002);
 }
 #[doc = "Generated from 'VK_EXT_custom_border_color'"]
 impl BorderColor {
+    #[doc = "Provided by `VK_EXT_custom_border_color`."]
     pub const FLOAT_CUSTOM_EXT: Self = Self(1_000_287_003);
 }
 #[doc = "Generated from 'VK_EXT_custom_border_color'"]
 impl BorderColor {
+    #[doc = "Provided by `VK_EXT_custom_border_color`."]
     pub const INT_CUSTOM_EXT: Self = Self(1_000_287_004);
 }
 impl ExtExtension289Fn {
@@ -22228,6 +25568,9 @@ impl ::std::clone::Clone for ExtExtension289Fn {
     }
 }
 impl ExtExtension289Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22237,122 +25580,152 @@ impl ExtExtension289Fn {
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_3X3X3_UNORM_BLOCK_EXT: Self = Self(1_000_288_000);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_3X3X3_SRGB_BLOCK_EXT: Self = Self(1_000_288_001);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_3X3X3_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_002);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X3X3_UNORM_BLOCK_EXT: Self = Self(1_000_288_003);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X3X3_SRGB_BLOCK_EXT: Self = Self(1_000_288_004);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X3X3_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_005);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X3_UNORM_BLOCK_EXT: Self = Self(1_000_288_006);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X3_SRGB_BLOCK_EXT: Self = Self(1_000_288_007);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X3_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_008);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X4_UNORM_BLOCK_EXT: Self = Self(1_000_288_009);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X4_SRGB_BLOCK_EXT: Self = Self(1_000_288_010);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_4X4X4_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_011);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X4X4_UNORM_BLOCK_EXT: Self = Self(1_000_288_012);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X4X4_SRGB_BLOCK_EXT: Self = Self(1_000_288_013);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X4X4_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_014);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X4_UNORM_BLOCK_EXT: Self = Self(1_000_288_015);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X4_SRGB_BLOCK_EXT: Self = Self(1_000_288_016);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X4_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_017);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X5_UNORM_BLOCK_EXT: Self = Self(1_000_288_018);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X5_SRGB_BLOCK_EXT: Self = Self(1_000_288_019);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_5X5X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_020);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X5X5_UNORM_BLOCK_EXT: Self = Self(1_000_288_021);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X5X5_SRGB_BLOCK_EXT: Self = Self(1_000_288_022);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X5X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_023);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X5_UNORM_BLOCK_EXT: Self = Self(1_000_288_024);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X5_SRGB_BLOCK_EXT: Self = Self(1_000_288_025);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X5_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_026);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X6_UNORM_BLOCK_EXT: Self = Self(1_000_288_027);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X6_SRGB_BLOCK_EXT: Self = Self(1_000_288_028);
 }
 #[doc = "Generated from 'VK_EXT_extension_289'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_extension_289`."]
     pub const ASTC_6X6X6_SFLOAT_BLOCK_EXT: Self = Self(1_000_288_029);
 }
 impl GoogleUserTypeFn {
@@ -22371,6 +25744,9 @@ impl ::std::clone::Clone for GoogleUserTypeFn {
     }
 }
 impl GoogleUserTypeFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22394,6 +25770,9 @@ impl ::std::clone::Clone for KhrPipelineLibraryFn {
     }
 }
 impl KhrPipelineLibraryFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22403,10 +25782,12 @@ impl KhrPipelineLibraryFn {
 }
 #[doc = "Generated from 'VK_KHR_pipeline_library'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_KHR_pipeline_library`."]
     pub const LIBRARY_KHR: Self = Self(0b1000_0000_0000);
 }
 #[doc = "Generated from 'VK_KHR_pipeline_library'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_pipeline_library`."]
     pub const PIPELINE_LIBRARY_CREATE_INFO_KHR: Self = Self(1_000_290_000);
 }
 impl NvExtension292Fn {
@@ -22425,6 +25806,9 @@ impl ::std::clone::Clone for NvExtension292Fn {
     }
 }
 impl NvExtension292Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22448,6 +25832,9 @@ impl ::std::clone::Clone for NvExtension293Fn {
     }
 }
 impl NvExtension293Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22471,6 +25858,9 @@ impl ::std::clone::Clone for KhrShaderNonSemanticInfoFn {
     }
 }
 impl KhrShaderNonSemanticInfoFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22494,6 +25884,9 @@ impl ::std::clone::Clone for KhrExtension295Fn {
     }
 }
 impl KhrExtension295Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22507,6 +25900,22 @@ impl ExtPrivateDataFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCreatePrivateDataSlotEXT"
+            | b"vkDestroyPrivateDataSlotEXT"
+            | b"vkSetPrivateDataEXT"
+            | b"vkGetPrivateDataEXT" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreatePrivateDataSlotEXT = extern "system" fn(
@@ -22577,6 +25986,9 @@ impl ::std::clone::Clone for ExtPrivateDataFn {
     }
 }
 impl ExtPrivateDataFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22713,18 +26125,22 @@ impl ExtPrivateDataFn {
 }
 #[doc = "Generated from 'VK_EXT_private_data'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_private_data`."]
     pub const PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT: Self = Self(1_000_295_000);
 }
 #[doc = "Generated from 'VK_EXT_private_data'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_private_data`."]
     pub const DEVICE_PRIVATE_DATA_CREATE_INFO_EXT: Self = Self(1_000_295_001);
 }
 #[doc = "Generated from 'VK_EXT_private_data'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_private_data`."]
     pub const PRIVATE_DATA_SLOT_CREATE_INFO_EXT: Self = Self(1_000_295_002);
 }
 #[doc = "Generated from 'VK_EXT_private_data'"]
 impl ObjectType {
+    #[doc = "Provided by `VK_EXT_private_data`."]
     pub const PRIVATE_DATA_SLOT_EXT: Self = Self(1_000_295_000);
 }
 impl KhrExtension297Fn {
@@ -22743,6 +26159,9 @@ impl ::std::clone::Clone for KhrExtension297Fn {
     }
 }
 impl KhrExtension297Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22752,6 +26171,7 @@ impl KhrExtension297Fn {
 }
 #[doc = "Generated from 'VK_KHR_extension_297'"]
 impl PipelineShaderStageCreateFlags {
+    #[doc = "Provided by `VK_KHR_extension_297`."]
     pub const RESERVED_3_KHR: Self = Self(0b1000);
 }
 impl ExtPipelineCreationCacheControlFn {
@@ -22770,6 +26190,9 @@ impl ::std::clone::Clone for ExtPipelineCreationCacheControlFn {
     }
 }
 impl ExtPipelineCreationCacheControlFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22779,27 +26202,33 @@ impl ExtPipelineCreationCacheControlFn {
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT: Self =
         Self(1_000_297_000);
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const FAIL_ON_PIPELINE_COMPILE_REQUIRED_EXT: Self = Self(0b1_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const EARLY_RETURN_ON_FAILURE_EXT: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const PIPELINE_COMPILE_REQUIRED_EXT: Self = Self(1_000_297_000);
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl Result {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const ERROR_PIPELINE_COMPILE_REQUIRED_EXT: Self = Result::PIPELINE_COMPILE_REQUIRED_EXT;
 }
 #[doc = "Generated from 'VK_EXT_pipeline_creation_cache_control'"]
 impl PipelineCacheCreateFlags {
+    #[doc = "Provided by `VK_EXT_pipeline_creation_cache_control`."]
     pub const EXTERNALLY_SYNCHRONIZED_EXT: Self = Self(0b1);
 }
 impl KhrExtension299Fn {
@@ -22818,6 +26247,9 @@ impl ::std::clone::Clone for KhrExtension299Fn {
     }
 }
 impl KhrExtension299Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22841,6 +26273,9 @@ impl ::std::clone::Clone for KhrExtension300Fn {
     }
 }
 impl KhrExtension300Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22864,6 +26299,9 @@ impl ::std::clone::Clone for NvDeviceDiagnosticsConfigFn {
     }
 }
 impl NvDeviceDiagnosticsConfigFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22873,10 +26311,12 @@ impl NvDeviceDiagnosticsConfigFn {
 }
 #[doc = "Generated from 'VK_NV_device_diagnostics_config'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_diagnostics_config`."]
     pub const PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV: Self = Self(1_000_300_000);
 }
 #[doc = "Generated from 'VK_NV_device_diagnostics_config'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_device_diagnostics_config`."]
     pub const DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV: Self = Self(1_000_300_001);
 }
 impl QcomRenderPassStoreOpsFn {
@@ -22895,6 +26335,9 @@ impl ::std::clone::Clone for QcomRenderPassStoreOpsFn {
     }
 }
 impl QcomRenderPassStoreOpsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22904,6 +26347,7 @@ impl QcomRenderPassStoreOpsFn {
 }
 #[doc = "Generated from 'VK_QCOM_render_pass_store_ops'"]
 impl AttachmentStoreOp {
+    #[doc = "Provided by `VK_QCOM_render_pass_store_ops`."]
     pub const NONE_QCOM: Self = Self(1_000_301_000);
 }
 impl QcomExtension303Fn {
@@ -22922,6 +26366,9 @@ impl ::std::clone::Clone for QcomExtension303Fn {
     }
 }
 impl QcomExtension303Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22945,6 +26392,9 @@ impl ::std::clone::Clone for QcomExtension304Fn {
     }
 }
 impl QcomExtension304Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22968,6 +26418,9 @@ impl ::std::clone::Clone for QcomExtension305Fn {
     }
 }
 impl QcomExtension305Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -22991,6 +26444,9 @@ impl ::std::clone::Clone for QcomExtension306Fn {
     }
 }
 impl QcomExtension306Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23014,6 +26470,9 @@ impl ::std::clone::Clone for QcomExtension307Fn {
     }
 }
 impl QcomExtension307Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23037,6 +26496,9 @@ impl ::std::clone::Clone for NvExtension308Fn {
     }
 }
 impl NvExtension308Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23060,6 +26522,9 @@ impl ::std::clone::Clone for KhrExtension309Fn {
     }
 }
 impl KhrExtension309Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23069,6 +26534,7 @@ impl KhrExtension309Fn {
 }
 #[doc = "Generated from 'VK_KHR_extension_309'"]
 impl MemoryHeapFlags {
+    #[doc = "Provided by `VK_KHR_extension_309`."]
     pub const RESERVED_2_KHR: Self = Self(0b100);
 }
 impl QcomExtension310Fn {
@@ -23087,6 +26553,9 @@ impl ::std::clone::Clone for QcomExtension310Fn {
     }
 }
 impl QcomExtension310Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23096,6 +26565,7 @@ impl QcomExtension310Fn {
 }
 #[doc = "Generated from 'VK_QCOM_extension_310'"]
 impl StructureType {
+    #[doc = "Provided by `VK_QCOM_extension_310`."]
     pub const RESERVED_QCOM: Self = Self(1_000_309_000);
 }
 impl NvExtension311Fn {
@@ -23114,6 +26584,9 @@ impl ::std::clone::Clone for NvExtension311Fn {
     }
 }
 impl NvExtension311Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23137,6 +26610,9 @@ impl ::std::clone::Clone for ExtExtension312Fn {
     }
 }
 impl ExtExtension312Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23160,6 +26636,9 @@ impl ::std::clone::Clone for ExtExtension313Fn {
     }
 }
 impl ExtExtension313Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23183,6 +26662,9 @@ impl ::std::clone::Clone for AmdExtension314Fn {
     }
 }
 impl AmdExtension314Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23206,6 +26688,9 @@ impl ::std::clone::Clone for AmdExtension315Fn {
     }
 }
 impl AmdExtension315Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23229,6 +26714,9 @@ impl ::std::clone::Clone for AmdExtension316Fn {
     }
 }
 impl AmdExtension316Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23252,6 +26740,9 @@ impl ::std::clone::Clone for AmdExtension317Fn {
     }
 }
 impl AmdExtension317Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23275,6 +26766,9 @@ impl ::std::clone::Clone for AmdExtension318Fn {
     }
 }
 impl AmdExtension318Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23298,6 +26792,9 @@ impl ::std::clone::Clone for AmdExtension319Fn {
     }
 }
 impl AmdExtension319Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23321,6 +26818,9 @@ impl ::std::clone::Clone for AmdExtension320Fn {
     }
 }
 impl AmdExtension320Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23344,6 +26844,9 @@ impl ::std::clone::Clone for AmdExtension321Fn {
     }
 }
 impl AmdExtension321Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23367,6 +26870,9 @@ impl ::std::clone::Clone for AmdExtension322Fn {
     }
 }
 impl AmdExtension322Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23390,6 +26896,9 @@ impl ::std::clone::Clone for AmdExtension323Fn {
     }
 }
 impl AmdExtension323Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23413,6 +26922,9 @@ impl ::std::clone::Clone for KhrExtension324Fn {
     }
 }
 impl KhrExtension324Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23436,6 +26948,9 @@ impl ::std::clone::Clone for KhrExtension325Fn {
     }
 }
 impl KhrExtension325Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23459,6 +26974,9 @@ impl ::std::clone::Clone for KhrExtension326Fn {
     }
 }
 impl KhrExtension326Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23472,6 +26990,19 @@ impl NvFragmentShadingRateEnumsFn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdSetFragmentShadingRateEnumNV" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetFragmentShadingRateEnumNV = extern "system" fn(
@@ -23496,6 +27027,9 @@ impl ::std::clone::Clone for NvFragmentShadingRateEnumsFn {
     }
 }
 impl NvFragmentShadingRateEnumsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23524,6 +27058,8 @@ impl NvFragmentShadingRateEnumsFn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_fragment_shading_rate_enum_nv(
         &self,
         command_buffer: CommandBuffer,
@@ -23535,14 +27071,17 @@ impl NvFragmentShadingRateEnumsFn {
 }
 #[doc = "Generated from 'VK_NV_fragment_shading_rate_enums'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_fragment_shading_rate_enums`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV: Self = Self(1_000_326_000);
 }
 #[doc = "Generated from 'VK_NV_fragment_shading_rate_enums'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_fragment_shading_rate_enums`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV: Self = Self(1_000_326_001);
 }
 #[doc = "Generated from 'VK_NV_fragment_shading_rate_enums'"]
 impl StructureType {
+    #[doc = "Provided by `VK_NV_fragment_shading_rate_enums`."]
     pub const PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV: Self = Self(1_000_326_002);
 }
 impl NvExtension328Fn {
@@ -23561,6 +27100,9 @@ impl ::std::clone::Clone for NvExtension328Fn {
     }
 }
 impl NvExtension328Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23584,6 +27126,9 @@ impl ::std::clone::Clone for NvExtension329Fn {
     }
 }
 impl NvExtension329Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23607,6 +27152,9 @@ impl ::std::clone::Clone for NvExtension330Fn {
     }
 }
 impl NvExtension330Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23630,6 +27178,9 @@ impl ::std::clone::Clone for NvExtension331Fn {
     }
 }
 impl NvExtension331Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23653,6 +27204,9 @@ impl ::std::clone::Clone for NvExtension332Fn {
     }
 }
 impl NvExtension332Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23676,6 +27230,9 @@ impl ::std::clone::Clone for ExtFragmentDensityMap2Fn {
     }
 }
 impl ExtFragmentDensityMap2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23685,14 +27242,17 @@ impl ExtFragmentDensityMap2Fn {
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_density_map2`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT: Self = Self(1_000_332_000);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_fragment_density_map2`."]
     pub const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT: Self = Self(1_000_332_001);
 }
 #[doc = "Generated from 'VK_EXT_fragment_density_map2'"]
 impl ImageViewCreateFlags {
+    #[doc = "Provided by `VK_EXT_fragment_density_map2`."]
     pub const FRAGMENT_DENSITY_MAP_DEFERRED_EXT: Self = Self(0b10);
 }
 impl QcomRotatedCopyCommandsFn {
@@ -23711,6 +27271,9 @@ impl ::std::clone::Clone for QcomRotatedCopyCommandsFn {
     }
 }
 impl QcomRotatedCopyCommandsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23720,6 +27283,7 @@ impl QcomRotatedCopyCommandsFn {
 }
 #[doc = "Generated from 'VK_QCOM_rotated_copy_commands'"]
 impl StructureType {
+    #[doc = "Provided by `VK_QCOM_rotated_copy_commands`."]
     pub const COPY_COMMAND_TRANSFORM_INFO_QCOM: Self = Self(1_000_333_000);
 }
 impl KhrExtension335Fn {
@@ -23738,6 +27302,9 @@ impl ::std::clone::Clone for KhrExtension335Fn {
     }
 }
 impl KhrExtension335Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23761,6 +27328,9 @@ impl ::std::clone::Clone for ExtImageRobustnessFn {
     }
 }
 impl ExtImageRobustnessFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23770,6 +27340,7 @@ impl ExtImageRobustnessFn {
 }
 #[doc = "Generated from 'VK_EXT_image_robustness'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_image_robustness`."]
     pub const PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT: Self = Self(1_000_335_000);
 }
 impl KhrExtension337Fn {
@@ -23788,6 +27359,9 @@ impl ::std::clone::Clone for KhrExtension337Fn {
     }
 }
 impl KhrExtension337Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23801,6 +27375,24 @@ impl KhrCopyCommands2Fn {
             .expect("Wrong extension string")
     }
     pub const SPEC_VERSION: u32 = 1u32;
+    #[doc = r" Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or"]
+    #[doc = r" `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and"]
+    #[doc = r" the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`."]
+    pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+    where
+        I: FnMut(&::std::ffi::CStr) -> *const c_void,
+        D: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load(|name| match name.to_bytes() {
+            b"vkCmdCopyBuffer2KHR"
+            | b"vkCmdCopyImage2KHR"
+            | b"vkCmdCopyBufferToImage2KHR"
+            | b"vkCmdCopyImageToBuffer2KHR"
+            | b"vkCmdBlitImage2KHR"
+            | b"vkCmdResolveImage2KHR" => load_device(name),
+            _ => load_instance(name),
+        })
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdCopyBuffer2KHR = extern "system" fn(
@@ -23873,6 +27465,9 @@ impl ::std::clone::Clone for KhrCopyCommands2Fn {
     }
 }
 impl KhrCopyCommands2Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -23986,6 +27581,8 @@ impl KhrCopyCommands2Fn {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyBuffer2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_buffer2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -23994,6 +27591,8 @@ impl KhrCopyCommands2Fn {
         (self.cmd_copy_buffer2_khr)(command_buffer, p_copy_buffer_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyImage2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_image2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -24002,6 +27601,8 @@ impl KhrCopyCommands2Fn {
         (self.cmd_copy_image2_khr)(command_buffer, p_copy_image_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyBufferToImage2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_buffer_to_image2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -24010,6 +27611,8 @@ impl KhrCopyCommands2Fn {
         (self.cmd_copy_buffer_to_image2_khr)(command_buffer, p_copy_buffer_to_image_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyImageToBuffer2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_image_to_buffer2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -24018,6 +27621,8 @@ impl KhrCopyCommands2Fn {
         (self.cmd_copy_image_to_buffer2_khr)(command_buffer, p_copy_image_to_buffer_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBlitImage2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_blit_image2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -24026,6 +27631,8 @@ impl KhrCopyCommands2Fn {
         (self.cmd_blit_image2_khr)(command_buffer, p_blit_image_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResolveImage2KHR.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_resolve_image2_khr(
         &self,
         command_buffer: CommandBuffer,
@@ -24036,46 +27643,57 @@ impl KhrCopyCommands2Fn {
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const COPY_BUFFER_INFO_2_KHR: Self = Self(1_000_337_000);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const COPY_IMAGE_INFO_2_KHR: Self = Self(1_000_337_001);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const COPY_BUFFER_TO_IMAGE_INFO_2_KHR: Self = Self(1_000_337_002);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const COPY_IMAGE_TO_BUFFER_INFO_2_KHR: Self = Self(1_000_337_003);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const BLIT_IMAGE_INFO_2_KHR: Self = Self(1_000_337_004);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const RESOLVE_IMAGE_INFO_2_KHR: Self = Self(1_000_337_005);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const BUFFER_COPY_2_KHR: Self = Self(1_000_337_006);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const IMAGE_COPY_2_KHR: Self = Self(1_000_337_007);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const IMAGE_BLIT_2_KHR: Self = Self(1_000_337_008);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const BUFFER_IMAGE_COPY_2_KHR: Self = Self(1_000_337_009);
 }
 #[doc = "Generated from 'VK_KHR_copy_commands2'"]
 impl StructureType {
+    #[doc = "Provided by `VK_KHR_copy_commands2`."]
     pub const IMAGE_RESOLVE_2_KHR: Self = Self(1_000_337_010);
 }
 impl ArmExtension339Fn {
@@ -24094,6 +27712,9 @@ impl ::std::clone::Clone for ArmExtension339Fn {
     }
 }
 impl ArmExtension339Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24117,6 +27738,9 @@ impl ::std::clone::Clone for ExtExtension340Fn {
     }
 }
 impl ExtExtension340Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24140,6 +27764,9 @@ impl ::std::clone::Clone for Ext4444FormatsFn {
     }
 }
 impl Ext4444FormatsFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24149,14 +27776,17 @@ impl Ext4444FormatsFn {
 }
 #[doc = "Generated from 'VK_EXT_4444_formats'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_4444_formats`."]
     pub const PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT: Self = Self(1_000_340_000);
 }
 #[doc = "Generated from 'VK_EXT_4444_formats'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_4444_formats`."]
     pub const A4R4G4B4_UNORM_PACK16_EXT: Self = Self(1_000_340_000);
 }
 #[doc = "Generated from 'VK_EXT_4444_formats'"]
 impl Format {
+    #[doc = "Provided by `VK_EXT_4444_formats`."]
     pub const A4B4G4R4_UNORM_PACK16_EXT: Self = Self(1_000_340_001);
 }
 impl ExtExtension342Fn {
@@ -24175,6 +27805,9 @@ impl ::std::clone::Clone for ExtExtension342Fn {
     }
 }
 impl ExtExtension342Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24198,6 +27831,9 @@ impl ::std::clone::Clone for ArmExtension343Fn {
     }
 }
 impl ArmExtension343Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24221,6 +27857,9 @@ impl ::std::clone::Clone for ArmExtension344Fn {
     }
 }
 impl ArmExtension344Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24244,6 +27883,9 @@ impl ::std::clone::Clone for ArmExtension345Fn {
     }
 }
 impl ArmExtension345Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24267,6 +27909,9 @@ impl ::std::clone::Clone for NvExtension346Fn {
     }
 }
 impl NvExtension346Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24319,6 +27964,9 @@ impl ::std::clone::Clone for ExtDirectfbSurfaceFn {
     }
 }
 impl ExtDirectfbSurfaceFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24393,6 +28041,7 @@ impl ExtDirectfbSurfaceFn {
 }
 #[doc = "Generated from 'VK_EXT_directfb_surface'"]
 impl StructureType {
+    #[doc = "Provided by `VK_EXT_directfb_surface`."]
     pub const DIRECTFB_SURFACE_CREATE_INFO_EXT: Self = Self(1_000_346_000);
 }
 impl KhrExtension350Fn {
@@ -24411,6 +28060,9 @@ impl ::std::clone::Clone for KhrExtension350Fn {
     }
 }
 impl KhrExtension350Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24420,6 +28072,7 @@ impl KhrExtension350Fn {
 }
 #[doc = "Generated from 'VK_KHR_extension_350'"]
 impl PipelineCacheCreateFlags {
+    #[doc = "Provided by `VK_KHR_extension_350`."]
     pub const RESERVED_2_EXT: Self = Self(0b100);
 }
 impl NvExtension351Fn {
@@ -24438,6 +28091,9 @@ impl ::std::clone::Clone for NvExtension351Fn {
     }
 }
 impl NvExtension351Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24461,6 +28117,9 @@ impl ::std::clone::Clone for ExtExtension352Fn {
     }
 }
 impl ExtExtension352Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24484,6 +28143,9 @@ impl ::std::clone::Clone for ExtExtension353Fn {
     }
 }
 impl ExtExtension353Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24507,6 +28169,9 @@ impl ::std::clone::Clone for ExtExtension354Fn {
     }
 }
 impl ExtExtension354Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24530,6 +28195,9 @@ impl ::std::clone::Clone for ExtExtension355Fn {
     }
 }
 impl ExtExtension355Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24553,6 +28221,9 @@ impl ::std::clone::Clone for ExtVertexAttributeAliasingFn {
     }
 }
 impl ExtVertexAttributeAliasingFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24575,6 +28246,9 @@ impl ::std::clone::Clone for ExtExtension357Fn {
     }
 }
 impl ExtExtension357Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24598,6 +28272,9 @@ impl ::std::clone::Clone for KhrExtension358Fn {
     }
 }
 impl KhrExtension358Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24620,6 +28297,9 @@ impl ::std::clone::Clone for ExtExtension359Fn {
     }
 }
 impl ExtExtension359Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24642,6 +28322,9 @@ impl ::std::clone::Clone for ExtExtension360Fn {
     }
 }
 impl ExtExtension360Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24664,6 +28347,9 @@ impl ::std::clone::Clone for KhrExtension361Fn {
     }
 }
 impl KhrExtension361Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24687,6 +28373,9 @@ impl ::std::clone::Clone for ExtExtension362Fn {
     }
 }
 impl ExtExtension362Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24710,6 +28399,9 @@ impl ::std::clone::Clone for ExtExtension363Fn {
     }
 }
 impl ExtExtension363Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24733,6 +28425,9 @@ impl ::std::clone::Clone for FuchsiaExtension364Fn {
     }
 }
 impl FuchsiaExtension364Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24756,6 +28451,9 @@ impl ::std::clone::Clone for FuchsiaExtension365Fn {
     }
 }
 impl FuchsiaExtension365Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24779,6 +28477,9 @@ impl ::std::clone::Clone for FuchsiaExtension366Fn {
     }
 }
 impl FuchsiaExtension366Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24802,6 +28503,9 @@ impl ::std::clone::Clone for FuchsiaExtension367Fn {
     }
 }
 impl FuchsiaExtension367Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24825,6 +28529,9 @@ impl ::std::clone::Clone for FuchsiaExtension368Fn {
     }
 }
 impl FuchsiaExtension368Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24848,6 +28555,9 @@ impl ::std::clone::Clone for QcomExtension369Fn {
     }
 }
 impl QcomExtension369Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24857,6 +28567,7 @@ impl QcomExtension369Fn {
 }
 #[doc = "Generated from 'VK_QCOM_extension_369'"]
 impl DescriptorBindingFlags {
+    #[doc = "Provided by `VK_QCOM_extension_369`."]
     pub const RESERVED_4_QCOM: Self = Self(0b1_0000);
 }
 impl HuaweiExtension370Fn {
@@ -24875,6 +28586,9 @@ impl ::std::clone::Clone for HuaweiExtension370Fn {
     }
 }
 impl HuaweiExtension370Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24898,6 +28612,9 @@ impl ::std::clone::Clone for HuaweiExtension371Fn {
     }
 }
 impl HuaweiExtension371Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24921,6 +28638,9 @@ impl ::std::clone::Clone for NvExtension372Fn {
     }
 }
 impl NvExtension372Fn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -24930,9 +28650,11 @@ impl NvExtension372Fn {
 }
 #[doc = "Generated from 'VK_NV_extension_372'"]
 impl BufferCreateFlags {
+    #[doc = "Provided by `VK_NV_extension_372`."]
     pub const RESERVED_5_NV: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_NV_extension_372'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by `VK_NV_extension_372`."]
     pub const RESERVED_15_NV: Self = Self(0b1000_0000_0000_0000);
 }
@@ -2,806 +2,1006 @@ use crate::vk::bitflags::*;
 use crate::vk::enums::*;
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_SUBGROUP_PROPERTIES: Self = Self(1_000_094_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BIND_BUFFER_MEMORY_INFO: Self = Self(1_000_157_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BIND_IMAGE_MEMORY_INFO: Self = Self(1_000_157_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const ALIAS: Self = Self(0b100_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES: Self = Self(1_000_083_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MEMORY_DEDICATED_REQUIREMENTS: Self = Self(1_000_127_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MEMORY_DEDICATED_ALLOCATE_INFO: Self = Self(1_000_127_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MEMORY_ALLOCATE_FLAGS_INFO: Self = Self(1_000_060_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP_RENDER_PASS_BEGIN_INFO: Self = Self(1_000_060_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO: Self = Self(1_000_060_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP_SUBMIT_INFO: Self = Self(1_000_060_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP_BIND_SPARSE_INFO: Self = Self(1_000_060_006);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const VIEW_INDEX_FROM_DEVICE_INDEX: Self = Self(0b1000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl PipelineCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DISPATCH_BASE: Self = Self(0b1_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl DependencyFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP: Self = Self(0b100);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO: Self = Self(1_000_060_013);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO: Self = Self(1_000_060_014);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SPLIT_INSTANCE_BIND_REGIONS: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_GROUP_PROPERTIES: Self = Self(1_000_070_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_GROUP_DEVICE_CREATE_INFO: Self = Self(1_000_070_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl MemoryHeapFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MULTI_INSTANCE: Self = Self(0b10);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BUFFER_MEMORY_REQUIREMENTS_INFO_2: Self = Self(1_000_146_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const IMAGE_MEMORY_REQUIREMENTS_INFO_2: Self = Self(1_000_146_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2: Self = Self(1_000_146_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MEMORY_REQUIREMENTS_2: Self = Self(1_000_146_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SPARSE_IMAGE_MEMORY_REQUIREMENTS_2: Self = Self(1_000_146_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_FEATURES_2: Self = Self(1_000_059_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_PROPERTIES_2: Self = Self(1_000_059_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const FORMAT_PROPERTIES_2: Self = Self(1_000_059_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const IMAGE_FORMAT_PROPERTIES_2: Self = Self(1_000_059_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2: Self = Self(1_000_059_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const QUEUE_FAMILY_PROPERTIES_2: Self = Self(1_000_059_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_MEMORY_PROPERTIES_2: Self = Self(1_000_059_006);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SPARSE_IMAGE_FORMAT_PROPERTIES_2: Self = Self(1_000_059_007);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2: Self = Self(1_000_059_008);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Result {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const ERROR_OUT_OF_POOL_MEMORY: Self = Self(-1000069000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const TRANSFER_SRC: Self = Self(0b100_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const TRANSFER_DST: Self = Self(0b1000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const TYPE_2D_ARRAY_COMPATIBLE: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BLOCK_TEXEL_VIEW_COMPATIBLE: Self = Self(0b1000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTENDED_USAGE: Self = Self(0b1_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES: Self = Self(1_000_117_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO: Self = Self(1_000_117_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const IMAGE_VIEW_USAGE_CREATE_INFO: Self = Self(1_000_117_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO: Self = Self(1_000_117_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL: Self = Self(1_000_117_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL: Self = Self(1_000_117_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const RENDER_PASS_MULTIVIEW_CREATE_INFO: Self = Self(1_000_053_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_MULTIVIEW_FEATURES: Self = Self(1_000_053_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES: Self = Self(1_000_053_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl DependencyFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const VIEW_LOCAL: Self = Self(0b10);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES: Self = Self(1_000_120_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES: Self =
         StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES;
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED_SUBMIT_INFO: Self = Self(1_000_145_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES: Self = Self(1_000_145_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES: Self = Self(1_000_145_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DEVICE_QUEUE_INFO_2: Self = Self(1_000_145_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl QueueFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b1_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl DeviceQueueCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl MemoryPropertyFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl BufferCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b1000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b1000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl CommandPoolCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PROTECTED: Self = Self(0b100);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLER_YCBCR_CONVERSION_CREATE_INFO: Self = Self(1_000_156_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLER_YCBCR_CONVERSION_INFO: Self = Self(1_000_156_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const BIND_IMAGE_PLANE_MEMORY_INFO: Self = Self(1_000_156_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO: Self = Self(1_000_156_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES: Self = Self(1_000_156_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES: Self = Self(1_000_156_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ObjectType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLER_YCBCR_CONVERSION: Self = Self(1_000_156_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8B8G8R8_422_UNORM: Self = Self(1_000_156_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const B8G8R8G8_422_UNORM: Self = Self(1_000_156_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8_B8_R8_3PLANE_420_UNORM: Self = Self(1_000_156_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8_B8R8_2PLANE_420_UNORM: Self = Self(1_000_156_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8_B8_R8_3PLANE_422_UNORM: Self = Self(1_000_156_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8_B8R8_2PLANE_422_UNORM: Self = Self(1_000_156_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G8_B8_R8_3PLANE_444_UNORM: Self = Self(1_000_156_006);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R10X6_UNORM_PACK16: Self = Self(1_000_156_007);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R10X6G10X6_UNORM_2PACK16: Self = Self(1_000_156_008);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R10X6G10X6B10X6A10X6_UNORM_4PACK16: Self = Self(1_000_156_009);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6B10X6G10X6R10X6_422_UNORM_4PACK16: Self = Self(1_000_156_010);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const B10X6G10X6R10X6G10X6_422_UNORM_4PACK16: Self = Self(1_000_156_011);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16: Self = Self(1_000_156_012);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16: Self = Self(1_000_156_013);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16: Self = Self(1_000_156_014);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16: Self = Self(1_000_156_015);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16: Self = Self(1_000_156_016);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R12X4_UNORM_PACK16: Self = Self(1_000_156_017);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R12X4G12X4_UNORM_2PACK16: Self = Self(1_000_156_018);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const R12X4G12X4B12X4A12X4_UNORM_4PACK16: Self = Self(1_000_156_019);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4B12X4G12X4R12X4_422_UNORM_4PACK16: Self = Self(1_000_156_020);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const B12X4G12X4R12X4G12X4_422_UNORM_4PACK16: Self = Self(1_000_156_021);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16: Self = Self(1_000_156_022);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16: Self = Self(1_000_156_023);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16: Self = Self(1_000_156_024);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16: Self = Self(1_000_156_025);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16: Self = Self(1_000_156_026);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16B16G16R16_422_UNORM: Self = Self(1_000_156_027);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const B16G16R16G16_422_UNORM: Self = Self(1_000_156_028);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16_B16_R16_3PLANE_420_UNORM: Self = Self(1_000_156_029);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16_B16R16_2PLANE_420_UNORM: Self = Self(1_000_156_030);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16_B16_R16_3PLANE_422_UNORM: Self = Self(1_000_156_031);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16_B16R16_2PLANE_422_UNORM: Self = Self(1_000_156_032);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Format {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const G16_B16_R16_3PLANE_444_UNORM: Self = Self(1_000_156_033);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PLANE_0: Self = Self(0b1_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PLANE_1: Self = Self(0b10_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageAspectFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PLANE_2: Self = Self(0b100_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ImageCreateFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DISJOINT: Self = Self(0b10_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const MIDPOINT_CHROMA_SAMPLES: Self = Self(0b10_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER: Self = Self(0b100_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER: Self =
         Self(0b1000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT: Self =
         Self(0b1_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE: Self =
         Self(0b10_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DISJOINT: Self = Self(0b100_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const COSITED_CHROMA_SAMPLES: Self = Self(0b1000_0000_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO: Self = Self(1_000_085_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl ObjectType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DESCRIPTOR_UPDATE_TEMPLATE: Self = Self(1_000_085_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO: Self = Self(1_000_071_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_IMAGE_FORMAT_PROPERTIES: Self = Self(1_000_071_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO: Self = Self(1_000_071_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_BUFFER_PROPERTIES: Self = Self(1_000_071_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_ID_PROPERTIES: Self = Self(1_000_071_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_MEMORY_BUFFER_CREATE_INFO: Self = Self(1_000_072_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_MEMORY_IMAGE_CREATE_INFO: Self = Self(1_000_072_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXPORT_MEMORY_ALLOCATE_INFO: Self = Self(1_000_072_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl Result {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const ERROR_INVALID_EXTERNAL_HANDLE: Self = Self(-1000072003);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO: Self = Self(1_000_112_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_FENCE_PROPERTIES: Self = Self(1_000_112_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXPORT_FENCE_CREATE_INFO: Self = Self(1_000_113_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXPORT_SEMAPHORE_CREATE_INFO: Self = Self(1_000_077_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO: Self = Self(1_000_076_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const EXTERNAL_SEMAPHORE_PROPERTIES: Self = Self(1_000_076_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES: Self = Self(1_000_168_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const DESCRIPTOR_SET_LAYOUT_SUPPORT: Self = Self(1_000_168_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES: Self = Self(1_000_063_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_1'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.1."]
     pub const PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES: Self =
         StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES;
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_VULKAN_1_1_FEATURES: Self = Self(49);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES: Self = Self(50);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_VULKAN_1_2_FEATURES: Self = Self(51);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES: Self = Self(52);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const IMAGE_FORMAT_LIST_CREATE_INFO: Self = Self(1_000_147_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ATTACHMENT_DESCRIPTION_2: Self = Self(1_000_109_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ATTACHMENT_REFERENCE_2: Self = Self(1_000_109_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SUBPASS_DESCRIPTION_2: Self = Self(1_000_109_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SUBPASS_DEPENDENCY_2: Self = Self(1_000_109_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const RENDER_PASS_CREATE_INFO_2: Self = Self(1_000_109_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SUBPASS_BEGIN_INFO: Self = Self(1_000_109_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SUBPASS_END_INFO: Self = Self(1_000_109_006);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES: Self = Self(1_000_177_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_DRIVER_PROPERTIES: Self = Self(1_000_196_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES: Self = Self(1_000_180_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES: Self = Self(1_000_082_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES: Self = Self(1_000_197_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO: Self = Self(1_000_161_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES: Self = Self(1_000_161_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES: Self = Self(1_000_161_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO: Self = Self(1_000_161_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT: Self = Self(1_000_161_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl DescriptorPoolCreateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const UPDATE_AFTER_BIND: Self = Self(0b10);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl DescriptorSetLayoutCreateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const UPDATE_AFTER_BIND_POOL: Self = Self(0b10);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl Result {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ERROR_FRAGMENTATION: Self = Self(-1000161000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES: Self = Self(1_000_199_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE: Self = Self(1_000_199_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES: Self = Self(1_000_221_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const IMAGE_STENCIL_USAGE_CREATE_INFO: Self = Self(1_000_246_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES: Self = Self(1_000_130_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SAMPLER_REDUCTION_MODE_CREATE_INFO: Self = Self(1_000_130_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl FormatFeatureFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SAMPLED_IMAGE_FILTER_MINMAX: Self = Self(0b1_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES: Self = Self(1_000_211_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES: Self = Self(1_000_108_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const FRAMEBUFFER_ATTACHMENTS_CREATE_INFO: Self = Self(1_000_108_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const FRAMEBUFFER_ATTACHMENT_IMAGE_INFO: Self = Self(1_000_108_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const RENDER_PASS_ATTACHMENT_BEGIN_INFO: Self = Self(1_000_108_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl FramebufferCreateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const IMAGELESS: Self = Self(0b1);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES: Self = Self(1_000_253_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES: Self = Self(1_000_175_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES: Self = Self(1_000_241_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ATTACHMENT_REFERENCE_STENCIL_LAYOUT: Self = Self(1_000_241_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT: Self = Self(1_000_241_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEPTH_ATTACHMENT_OPTIMAL: Self = Self(1_000_241_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEPTH_READ_ONLY_OPTIMAL: Self = Self(1_000_241_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const STENCIL_ATTACHMENT_OPTIMAL: Self = Self(1_000_241_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl ImageLayout {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const STENCIL_READ_ONLY_OPTIMAL: Self = Self(1_000_241_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES: Self = Self(1_000_261_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES: Self = Self(1_000_207_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES: Self = Self(1_000_207_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SEMAPHORE_TYPE_CREATE_INFO: Self = Self(1_000_207_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const TIMELINE_SEMAPHORE_SUBMIT_INFO: Self = Self(1_000_207_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SEMAPHORE_WAIT_INFO: Self = Self(1_000_207_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SEMAPHORE_SIGNAL_INFO: Self = Self(1_000_207_005);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES: Self = Self(1_000_257_000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const BUFFER_DEVICE_ADDRESS_INFO: Self = Self(1_000_244_001);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO: Self = Self(1_000_257_002);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO: Self = Self(1_000_257_003);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl StructureType {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO: Self = Self(1_000_257_004);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl BufferUsageFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const SHADER_DEVICE_ADDRESS: Self = Self(0b10_0000_0000_0000_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl BufferCreateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEVICE_ADDRESS_CAPTURE_REPLAY: Self = Self(0b1_0000);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl MemoryAllocateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEVICE_ADDRESS: Self = Self(0b10);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl MemoryAllocateFlags {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const DEVICE_ADDRESS_CAPTURE_REPLAY: Self = Self(0b100);
 }
 #[doc = "Generated from 'VK_VERSION_1_2'"]
 impl Result {
+    #[doc = "Provided by Vulkan 1.2."]
     pub const ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS: Self = Self(-1000257000);
 }
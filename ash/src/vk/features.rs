@@ -1,7 +1,7 @@
 use crate::vk::bitflags::*;
 use crate::vk::definitions::*;
 use crate::vk::enums::*;
-use std::os::raw::*;
+use crate::vk::platform::*;
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetInstanceProcAddr =
     extern "system" fn(instance: Instance, p_name: *const c_char) -> PFN_vkVoidFunction;
@@ -19,6 +19,9 @@ impl ::std::clone::Clone for StaticFn {
     }
 }
 impl StaticFn {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -97,6 +100,9 @@ impl ::std::clone::Clone for EntryFnV1_0 {
     }
 }
 impl EntryFnV1_0 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -186,6 +192,27 @@ impl EntryFnV1_0 {
     ) -> Result {
         (self.enumerate_instance_layer_properties)(p_property_count, p_properties)
     }
+    #[doc = r" Like [`Self::enumerate_instance_extension_properties`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_instance_extension_properties_with_success_code(
+        &self,
+        p_layer_name: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut ExtensionProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_instance_extension_properties(p_layer_name, p_property_count, p_properties)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::enumerate_instance_layer_properties`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_instance_layer_properties_with_success_code(
+        &self,
+        p_property_count: *mut u32,
+        p_properties: *mut LayerProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_instance_layer_properties(p_property_count, p_properties)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDestroyInstance =
@@ -361,6 +388,9 @@ impl ::std::clone::Clone for InstanceFnV1_0 {
     }
 }
 impl InstanceFnV1_0 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -774,6 +804,34 @@ impl InstanceFnV1_0 {
             p_properties,
         )
     }
+    #[doc = r" Like [`Self::enumerate_device_extension_properties`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_device_extension_properties_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_layer_name: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut ExtensionProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_device_extension_properties(
+            physical_device,
+            p_layer_name,
+            p_property_count,
+            p_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::enumerate_device_layer_properties`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_device_layer_properties_with_success_code(
+        &self,
+        physical_device: PhysicalDevice,
+        p_property_count: *mut u32,
+        p_properties: *mut LayerProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_device_layer_properties(physical_device, p_property_count, p_properties)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDestroyDevice =
@@ -955,7 +1013,7 @@ pub type PFN_vkGetQueryPoolResults = extern "system" fn(
     query_pool: QueryPool,
     first_query: u32,
     query_count: u32,
-    data_size: usize,
+    data_size: size_t,
     p_data: *mut c_void,
     stride: DeviceSize,
     flags: QueryResultFlags,
@@ -1049,7 +1107,7 @@ pub type PFN_vkDestroyPipelineCache = extern "system" fn(
 pub type PFN_vkGetPipelineCacheData = extern "system" fn(
     device: Device,
     pipeline_cache: PipelineCache,
-    p_data_size: *mut usize,
+    p_data_size: *mut size_t,
     p_data: *mut c_void,
 ) -> Result;
 #[allow(non_camel_case_types)]
@@ -1709,7 +1767,7 @@ pub struct DeviceFnV1_0 {
         query_pool: QueryPool,
         first_query: u32,
         query_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
         stride: DeviceSize,
         flags: QueryResultFlags,
@@ -1789,7 +1847,7 @@ pub struct DeviceFnV1_0 {
     pub get_pipeline_cache_data: extern "system" fn(
         device: Device,
         pipeline_cache: PipelineCache,
-        p_data_size: *mut usize,
+        p_data_size: *mut size_t,
         p_data: *mut c_void,
     ) -> Result,
     pub merge_pipeline_caches: extern "system" fn(
@@ -2366,6 +2424,9 @@ impl ::std::clone::Clone for DeviceFnV1_0 {
     }
 }
 impl DeviceFnV1_0 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -2926,7 +2987,7 @@ impl DeviceFnV1_0 {
                     _query_pool: QueryPool,
                     _first_query: u32,
                     _query_count: u32,
-                    _data_size: usize,
+                    _data_size: size_t,
                     _p_data: *mut c_void,
                     _stride: DeviceSize,
                     _flags: QueryResultFlags,
@@ -3189,7 +3250,7 @@ impl DeviceFnV1_0 {
                 extern "system" fn get_pipeline_cache_data(
                     _device: Device,
                     _pipeline_cache: PipelineCache,
-                    _p_data_size: *mut usize,
+                    _p_data_size: *mut size_t,
                     _p_data: *mut c_void,
                 ) -> Result {
                     panic!(concat!(
@@ -4904,7 +4965,7 @@ impl DeviceFnV1_0 {
         query_pool: QueryPool,
         first_query: u32,
         query_count: u32,
-        data_size: usize,
+        data_size: size_t,
         p_data: *mut c_void,
         stride: DeviceSize,
         flags: QueryResultFlags,
@@ -5049,7 +5110,7 @@ impl DeviceFnV1_0 {
         &self,
         device: Device,
         pipeline_cache: PipelineCache,
-        p_data_size: *mut usize,
+        p_data_size: *mut size_t,
         p_data: *mut c_void,
     ) -> Result {
         (self.get_pipeline_cache_data)(device, pipeline_cache, p_data_size, p_data)
@@ -5357,6 +5418,8 @@ impl DeviceFnV1_0 {
         (self.reset_command_buffer)(command_buffer, flags)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindPipeline.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_pipeline(
         &self,
         command_buffer: CommandBuffer,
@@ -5366,26 +5429,40 @@ impl DeviceFnV1_0 {
         (self.cmd_bind_pipeline)(command_buffer, pipeline_bind_point, pipeline)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetViewport.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_viewport(
         &self,
         command_buffer: CommandBuffer,
         first_viewport: u32,
-        viewport_count: u32,
-        p_viewports: *const Viewport,
+        viewports: &[Viewport],
     ) -> c_void {
-        (self.cmd_set_viewport)(command_buffer, first_viewport, viewport_count, p_viewports)
+        (self.cmd_set_viewport)(
+            command_buffer,
+            first_viewport,
+            viewports.len() as _,
+            viewports.as_ptr(),
+        )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetScissor.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_scissor(
         &self,
         command_buffer: CommandBuffer,
         first_scissor: u32,
-        scissor_count: u32,
-        p_scissors: *const Rect2D,
+        scissors: &[Rect2D],
     ) -> c_void {
-        (self.cmd_set_scissor)(command_buffer, first_scissor, scissor_count, p_scissors)
+        (self.cmd_set_scissor)(
+            command_buffer,
+            first_scissor,
+            scissors.len() as _,
+            scissors.as_ptr(),
+        )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetLineWidth.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_line_width(
         &self,
         command_buffer: CommandBuffer,
@@ -5394,6 +5471,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_line_width)(command_buffer, line_width)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthBias.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_bias(
         &self,
         command_buffer: CommandBuffer,
@@ -5409,6 +5488,8 @@ impl DeviceFnV1_0 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetBlendConstants.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_blend_constants(
         &self,
         command_buffer: CommandBuffer,
@@ -5417,6 +5498,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_blend_constants)(command_buffer, blend_constants)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDepthBounds.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_depth_bounds(
         &self,
         command_buffer: CommandBuffer,
@@ -5426,6 +5509,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_depth_bounds)(command_buffer, min_depth_bounds, max_depth_bounds)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilCompareMask.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_stencil_compare_mask(
         &self,
         command_buffer: CommandBuffer,
@@ -5435,6 +5520,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_stencil_compare_mask)(command_buffer, face_mask, compare_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilWriteMask.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_stencil_write_mask(
         &self,
         command_buffer: CommandBuffer,
@@ -5444,6 +5531,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_stencil_write_mask)(command_buffer, face_mask, write_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetStencilReference.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_stencil_reference(
         &self,
         command_buffer: CommandBuffer,
@@ -5453,29 +5542,31 @@ impl DeviceFnV1_0 {
         (self.cmd_set_stencil_reference)(command_buffer, face_mask, reference)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindDescriptorSets.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_descriptor_sets(
         &self,
         command_buffer: CommandBuffer,
         pipeline_bind_point: PipelineBindPoint,
         layout: PipelineLayout,
         first_set: u32,
-        descriptor_set_count: u32,
-        p_descriptor_sets: *const DescriptorSet,
-        dynamic_offset_count: u32,
-        p_dynamic_offsets: *const u32,
+        descriptor_sets: &[DescriptorSet],
+        dynamic_offsets: &[u32],
     ) -> c_void {
         (self.cmd_bind_descriptor_sets)(
             command_buffer,
             pipeline_bind_point,
             layout,
             first_set,
-            descriptor_set_count,
-            p_descriptor_sets,
-            dynamic_offset_count,
-            p_dynamic_offsets,
+            descriptor_sets.len() as _,
+            descriptor_sets.as_ptr(),
+            dynamic_offsets.len() as _,
+            dynamic_offsets.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindIndexBuffer.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_index_buffer(
         &self,
         command_buffer: CommandBuffer,
@@ -5486,23 +5577,27 @@ impl DeviceFnV1_0 {
         (self.cmd_bind_index_buffer)(command_buffer, buffer, offset, index_type)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBindVertexBuffers.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_bind_vertex_buffers(
         &self,
         command_buffer: CommandBuffer,
         first_binding: u32,
-        binding_count: u32,
-        p_buffers: *const Buffer,
-        p_offsets: *const DeviceSize,
+        buffers: &[Buffer],
+        offsets: &[DeviceSize],
     ) -> c_void {
+        assert_eq!(buffers.len(), offsets.len());
         (self.cmd_bind_vertex_buffers)(
             command_buffer,
             first_binding,
-            binding_count,
-            p_buffers,
-            p_offsets,
+            buffers.len() as _,
+            buffers.as_ptr(),
+            offsets.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDraw.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw(
         &self,
         command_buffer: CommandBuffer,
@@ -5520,6 +5615,8 @@ impl DeviceFnV1_0 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexed.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indexed(
         &self,
         command_buffer: CommandBuffer,
@@ -5539,6 +5636,8 @@ impl DeviceFnV1_0 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndirect.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indirect(
         &self,
         command_buffer: CommandBuffer,
@@ -5550,6 +5649,8 @@ impl DeviceFnV1_0 {
         (self.cmd_draw_indirect)(command_buffer, buffer, offset, draw_count, stride)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexedIndirect.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indexed_indirect(
         &self,
         command_buffer: CommandBuffer,
@@ -5561,6 +5662,8 @@ impl DeviceFnV1_0 {
         (self.cmd_draw_indexed_indirect)(command_buffer, buffer, offset, draw_count, stride)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatch.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_dispatch(
         &self,
         command_buffer: CommandBuffer,
@@ -5571,6 +5674,8 @@ impl DeviceFnV1_0 {
         (self.cmd_dispatch)(command_buffer, group_count_x, group_count_y, group_count_z)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatchIndirect.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_dispatch_indirect(
         &self,
         command_buffer: CommandBuffer,
@@ -5580,23 +5685,26 @@ impl DeviceFnV1_0 {
         (self.cmd_dispatch_indirect)(command_buffer, buffer, offset)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyBuffer.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_buffer(
         &self,
         command_buffer: CommandBuffer,
         src_buffer: Buffer,
         dst_buffer: Buffer,
-        region_count: u32,
-        p_regions: *const BufferCopy,
+        regions: &[BufferCopy],
     ) -> c_void {
         (self.cmd_copy_buffer)(
             command_buffer,
             src_buffer,
             dst_buffer,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_image(
         &self,
         command_buffer: CommandBuffer,
@@ -5604,8 +5712,7 @@ impl DeviceFnV1_0 {
         src_image_layout: ImageLayout,
         dst_image: Image,
         dst_image_layout: ImageLayout,
-        region_count: u32,
-        p_regions: *const ImageCopy,
+        regions: &[ImageCopy],
     ) -> c_void {
         (self.cmd_copy_image)(
             command_buffer,
@@ -5613,11 +5720,13 @@ impl DeviceFnV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBlitImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_blit_image(
         &self,
         command_buffer: CommandBuffer,
@@ -5625,8 +5734,7 @@ impl DeviceFnV1_0 {
         src_image_layout: ImageLayout,
         dst_image: Image,
         dst_image_layout: ImageLayout,
-        region_count: u32,
-        p_regions: *const ImageBlit,
+        regions: &[ImageBlit],
         filter: Filter,
     ) -> c_void {
         (self.cmd_blit_image)(
@@ -5635,50 +5743,54 @@ impl DeviceFnV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
             filter,
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyBufferToImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_buffer_to_image(
         &self,
         command_buffer: CommandBuffer,
         src_buffer: Buffer,
         dst_image: Image,
         dst_image_layout: ImageLayout,
-        region_count: u32,
-        p_regions: *const BufferImageCopy,
+        regions: &[BufferImageCopy],
     ) -> c_void {
         (self.cmd_copy_buffer_to_image)(
             command_buffer,
             src_buffer,
             dst_image,
             dst_image_layout,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyImageToBuffer.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_image_to_buffer(
         &self,
         command_buffer: CommandBuffer,
         src_image: Image,
         src_image_layout: ImageLayout,
         dst_buffer: Buffer,
-        region_count: u32,
-        p_regions: *const BufferImageCopy,
+        regions: &[BufferImageCopy],
     ) -> c_void {
         (self.cmd_copy_image_to_buffer)(
             command_buffer,
             src_image,
             src_image_layout,
             dst_buffer,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdUpdateBuffer.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_update_buffer(
         &self,
         command_buffer: CommandBuffer,
@@ -5690,6 +5802,8 @@ impl DeviceFnV1_0 {
         (self.cmd_update_buffer)(command_buffer, dst_buffer, dst_offset, data_size, p_data)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdFillBuffer.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_fill_buffer(
         &self,
         command_buffer: CommandBuffer,
@@ -5701,61 +5815,65 @@ impl DeviceFnV1_0 {
         (self.cmd_fill_buffer)(command_buffer, dst_buffer, dst_offset, size, data)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdClearColorImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_clear_color_image(
         &self,
         command_buffer: CommandBuffer,
         image: Image,
         image_layout: ImageLayout,
         p_color: *const ClearColorValue,
-        range_count: u32,
-        p_ranges: *const ImageSubresourceRange,
+        ranges: &[ImageSubresourceRange],
     ) -> c_void {
         (self.cmd_clear_color_image)(
             command_buffer,
             image,
             image_layout,
             p_color,
-            range_count,
-            p_ranges,
+            ranges.len() as _,
+            ranges.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdClearDepthStencilImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_clear_depth_stencil_image(
         &self,
         command_buffer: CommandBuffer,
         image: Image,
         image_layout: ImageLayout,
         p_depth_stencil: *const ClearDepthStencilValue,
-        range_count: u32,
-        p_ranges: *const ImageSubresourceRange,
+        ranges: &[ImageSubresourceRange],
     ) -> c_void {
         (self.cmd_clear_depth_stencil_image)(
             command_buffer,
             image,
             image_layout,
             p_depth_stencil,
-            range_count,
-            p_ranges,
+            ranges.len() as _,
+            ranges.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdClearAttachments.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_clear_attachments(
         &self,
         command_buffer: CommandBuffer,
-        attachment_count: u32,
-        p_attachments: *const ClearAttachment,
-        rect_count: u32,
-        p_rects: *const ClearRect,
+        attachments: &[ClearAttachment],
+        rects: &[ClearRect],
     ) -> c_void {
         (self.cmd_clear_attachments)(
             command_buffer,
-            attachment_count,
-            p_attachments,
-            rect_count,
-            p_rects,
+            attachments.len() as _,
+            attachments.as_ptr(),
+            rects.len() as _,
+            rects.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResolveImage.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_resolve_image(
         &self,
         command_buffer: CommandBuffer,
@@ -5763,8 +5881,7 @@ impl DeviceFnV1_0 {
         src_image_layout: ImageLayout,
         dst_image: Image,
         dst_image_layout: ImageLayout,
-        region_count: u32,
-        p_regions: *const ImageResolve,
+        regions: &[ImageResolve],
     ) -> c_void {
         (self.cmd_resolve_image)(
             command_buffer,
@@ -5772,11 +5889,13 @@ impl DeviceFnV1_0 {
             src_image_layout,
             dst_image,
             dst_image_layout,
-            region_count,
-            p_regions,
+            regions.len() as _,
+            regions.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetEvent.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_set_event(
         &self,
         command_buffer: CommandBuffer,
@@ -5786,6 +5905,8 @@ impl DeviceFnV1_0 {
         (self.cmd_set_event)(command_buffer, event, stage_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResetEvent.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_reset_event(
         &self,
         command_buffer: CommandBuffer,
@@ -5795,62 +5916,61 @@ impl DeviceFnV1_0 {
         (self.cmd_reset_event)(command_buffer, event, stage_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWaitEvents.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_wait_events(
         &self,
         command_buffer: CommandBuffer,
-        event_count: u32,
-        p_events: *const Event,
+        events: &[Event],
         src_stage_mask: PipelineStageFlags,
         dst_stage_mask: PipelineStageFlags,
-        memory_barrier_count: u32,
-        p_memory_barriers: *const MemoryBarrier,
-        buffer_memory_barrier_count: u32,
-        p_buffer_memory_barriers: *const BufferMemoryBarrier,
-        image_memory_barrier_count: u32,
-        p_image_memory_barriers: *const ImageMemoryBarrier,
+        memory_barriers: &[MemoryBarrier],
+        buffer_memory_barriers: &[BufferMemoryBarrier],
+        image_memory_barriers: &[ImageMemoryBarrier],
     ) -> c_void {
         (self.cmd_wait_events)(
             command_buffer,
-            event_count,
-            p_events,
+            events.len() as _,
+            events.as_ptr(),
             src_stage_mask,
             dst_stage_mask,
-            memory_barrier_count,
-            p_memory_barriers,
-            buffer_memory_barrier_count,
-            p_buffer_memory_barriers,
-            image_memory_barrier_count,
-            p_image_memory_barriers,
+            memory_barriers.len() as _,
+            memory_barriers.as_ptr(),
+            buffer_memory_barriers.len() as _,
+            buffer_memory_barriers.as_ptr(),
+            image_memory_barriers.len() as _,
+            image_memory_barriers.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPipelineBarrier.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_pipeline_barrier(
         &self,
         command_buffer: CommandBuffer,
         src_stage_mask: PipelineStageFlags,
         dst_stage_mask: PipelineStageFlags,
         dependency_flags: DependencyFlags,
-        memory_barrier_count: u32,
-        p_memory_barriers: *const MemoryBarrier,
-        buffer_memory_barrier_count: u32,
-        p_buffer_memory_barriers: *const BufferMemoryBarrier,
-        image_memory_barrier_count: u32,
-        p_image_memory_barriers: *const ImageMemoryBarrier,
+        memory_barriers: &[MemoryBarrier],
+        buffer_memory_barriers: &[BufferMemoryBarrier],
+        image_memory_barriers: &[ImageMemoryBarrier],
     ) -> c_void {
         (self.cmd_pipeline_barrier)(
             command_buffer,
             src_stage_mask,
             dst_stage_mask,
             dependency_flags,
-            memory_barrier_count,
-            p_memory_barriers,
-            buffer_memory_barrier_count,
-            p_buffer_memory_barriers,
-            image_memory_barrier_count,
-            p_image_memory_barriers,
+            memory_barriers.len() as _,
+            memory_barriers.as_ptr(),
+            buffer_memory_barriers.len() as _,
+            buffer_memory_barriers.as_ptr(),
+            image_memory_barriers.len() as _,
+            image_memory_barriers.as_ptr(),
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginQuery.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_begin_query(
         &self,
         command_buffer: CommandBuffer,
@@ -5861,6 +5981,8 @@ impl DeviceFnV1_0 {
         (self.cmd_begin_query)(command_buffer, query_pool, query, flags)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndQuery.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_end_query(
         &self,
         command_buffer: CommandBuffer,
@@ -5870,6 +5992,8 @@ impl DeviceFnV1_0 {
         (self.cmd_end_query)(command_buffer, query_pool, query)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdResetQueryPool.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_reset_query_pool(
         &self,
         command_buffer: CommandBuffer,
@@ -5880,6 +6004,8 @@ impl DeviceFnV1_0 {
         (self.cmd_reset_query_pool)(command_buffer, query_pool, first_query, query_count)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdWriteTimestamp.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_write_timestamp(
         &self,
         command_buffer: CommandBuffer,
@@ -5890,6 +6016,8 @@ impl DeviceFnV1_0 {
         (self.cmd_write_timestamp)(command_buffer, pipeline_stage, query_pool, query)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdCopyQueryPoolResults.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_copy_query_pool_results(
         &self,
         command_buffer: CommandBuffer,
@@ -5913,6 +6041,8 @@ impl DeviceFnV1_0 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdPushConstants.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_push_constants(
         &self,
         command_buffer: CommandBuffer,
@@ -5925,6 +6055,8 @@ impl DeviceFnV1_0 {
         (self.cmd_push_constants)(command_buffer, layout, stage_flags, offset, size, p_values)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginRenderPass.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_begin_render_pass(
         &self,
         command_buffer: CommandBuffer,
@@ -5934,6 +6066,8 @@ impl DeviceFnV1_0 {
         (self.cmd_begin_render_pass)(command_buffer, p_render_pass_begin, contents)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdNextSubpass.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_next_subpass(
         &self,
         command_buffer: CommandBuffer,
@@ -5942,17 +6076,89 @@ impl DeviceFnV1_0 {
         (self.cmd_next_subpass)(command_buffer, contents)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_end_render_pass(&self, command_buffer: CommandBuffer) -> c_void {
         (self.cmd_end_render_pass)(command_buffer)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdExecuteCommands.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_execute_commands(
         &self,
         command_buffer: CommandBuffer,
-        command_buffer_count: u32,
-        p_command_buffers: *const CommandBuffer,
+        command_buffers: &[CommandBuffer],
     ) -> c_void {
-        (self.cmd_execute_commands)(command_buffer, command_buffer_count, p_command_buffers)
+        (self.cmd_execute_commands)(
+            command_buffer,
+            command_buffers.len() as _,
+            command_buffers.as_ptr(),
+        )
+    }
+    #[doc = r" Like [`Self::get_fence_status`], but returns the success code, which is one of `SUCCESS`, `NOT_READY`."]
+    pub unsafe fn get_fence_status_with_success_code(
+        &self,
+        device: Device,
+        fence: Fence,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.get_fence_status(device, fence)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::wait_for_fences`], but returns the success code, which is one of `SUCCESS`, `TIMEOUT`."]
+    pub unsafe fn wait_for_fences_with_success_code(
+        &self,
+        device: Device,
+        fence_count: u32,
+        p_fences: *const Fence,
+        wait_all: Bool32,
+        timeout: u64,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.wait_for_fences(device, fence_count, p_fences, wait_all, timeout)
+            .into_codes()
+            .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::create_graphics_pipelines`], but returns the success code, which is one of `SUCCESS`, `PIPELINE_COMPILE_REQUIRED_EXT`."]
+    pub unsafe fn create_graphics_pipelines_with_success_code(
+        &self,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        create_info_count: u32,
+        p_create_infos: *const GraphicsPipelineCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipelines: *mut Pipeline,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.create_graphics_pipelines(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
+    #[doc = r" Like [`Self::create_compute_pipelines`], but returns the success code, which is one of `SUCCESS`, `PIPELINE_COMPILE_REQUIRED_EXT`."]
+    pub unsafe fn create_compute_pipelines_with_success_code(
+        &self,
+        device: Device,
+        pipeline_cache: PipelineCache,
+        create_info_count: u32,
+        p_create_infos: *const ComputePipelineCreateInfo,
+        p_allocator: *const AllocationCallbacks,
+        p_pipelines: *mut Pipeline,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.create_compute_pipelines(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        )
+        .into_codes()
+        .map_err(Result::from)
     }
 }
 #[allow(non_camel_case_types)]
@@ -5970,6 +6176,9 @@ impl ::std::clone::Clone for EntryFnV1_1 {
     }
 }
 impl EntryFnV1_1 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6079,6 +6288,9 @@ impl ::std::clone::Clone for InstanceFnV1_1 {
     }
 }
 impl InstanceFnV1_1 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6430,6 +6642,21 @@ impl InstanceFnV1_1 {
             p_external_semaphore_properties,
         )
     }
+    #[doc = r" Like [`Self::enumerate_physical_device_groups`], but returns the success code, which is one of `SUCCESS`, `INCOMPLETE`."]
+    pub unsafe fn enumerate_physical_device_groups_with_success_code(
+        &self,
+        instance: Instance,
+        p_physical_device_group_count: *mut u32,
+        p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.enumerate_physical_device_groups(
+            instance,
+            p_physical_device_group_count,
+            p_physical_device_group_properties,
+        )
+        .into_codes()
+        .map_err(Result::from)
+    }
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceQueue2 = extern "system" fn(
@@ -6551,6 +6778,9 @@ impl ::std::clone::Clone for DeviceFnV1_1 {
     }
 }
 impl DeviceFnV1_1 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -6905,6 +7135,8 @@ impl DeviceFnV1_1 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdSetDeviceMask.html>"]
+    #[doc = ""]
+    #[doc = " Can be recorded inside or outside of a render pass instance."]
     pub unsafe fn cmd_set_device_mask(
         &self,
         command_buffer: CommandBuffer,
@@ -6913,6 +7145,8 @@ impl DeviceFnV1_1 {
         (self.cmd_set_device_mask)(command_buffer, device_mask)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDispatchBase.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_dispatch_base(
         &self,
         command_buffer: CommandBuffer,
@@ -7066,6 +7300,9 @@ impl ::std::clone::Clone for EntryFnV1_2 {
     }
 }
 impl EntryFnV1_2 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7082,6 +7319,9 @@ impl ::std::clone::Clone for InstanceFnV1_2 {
     }
 }
 impl InstanceFnV1_2 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7174,6 +7414,9 @@ impl ::std::clone::Clone for DeviceFnV1_2 {
     }
 }
 impl DeviceFnV1_2 {
+    #[doc = r" Loads the commands with `_f`, which returns the address of the command with the"]
+    #[doc = r" given name, or null if it isn't available. Any resolver works, not only"]
+    #[doc = r" `vkGet*ProcAddr`, so that commands can be interposed."]
     pub fn load<F>(mut _f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
@@ -7429,6 +7672,8 @@ impl DeviceFnV1_2 {
         }
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndirectCount.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indirect_count(
         &self,
         command_buffer: CommandBuffer,
@@ -7450,6 +7695,8 @@ impl DeviceFnV1_2 {
         )
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdDrawIndexedIndirectCount.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_draw_indexed_indirect_count(
         &self,
         command_buffer: CommandBuffer,
@@ -7481,6 +7728,8 @@ impl DeviceFnV1_2 {
         (self.create_render_pass2)(device, p_create_info, p_allocator, p_render_pass)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdBeginRenderPass2.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded outside of a render pass instance."]
     pub unsafe fn cmd_begin_render_pass2(
         &self,
         command_buffer: CommandBuffer,
@@ -7490,6 +7739,8 @@ impl DeviceFnV1_2 {
         (self.cmd_begin_render_pass2)(command_buffer, p_render_pass_begin, p_subpass_begin_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdNextSubpass2.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_next_subpass2(
         &self,
         command_buffer: CommandBuffer,
@@ -7499,6 +7750,8 @@ impl DeviceFnV1_2 {
         (self.cmd_next_subpass2)(command_buffer, p_subpass_begin_info, p_subpass_end_info)
     }
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/vkCmdEndRenderPass2.html>"]
+    #[doc = ""]
+    #[doc = " Must be recorded inside of a render pass instance."]
     pub unsafe fn cmd_end_render_pass2(
         &self,
         command_buffer: CommandBuffer,
@@ -7566,4 +7819,15 @@ impl DeviceFnV1_2 {
     ) -> u64 {
         (self.get_device_memory_opaque_capture_address)(device, p_info)
     }
+    #[doc = r" Like [`Self::wait_semaphores`], but returns the success code, which is one of `SUCCESS`, `TIMEOUT`."]
+    pub unsafe fn wait_semaphores_with_success_code(
+        &self,
+        device: Device,
+        p_wait_info: *const SemaphoreWaitInfo,
+        timeout: u64,
+    ) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+        self.wait_semaphores(device, p_wait_info, timeout)
+            .into_codes()
+            .map_err(Result::from)
+    }
 }
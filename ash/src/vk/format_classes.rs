@@ -0,0 +1,290 @@
+use crate::vk::enums::Format;
+impl Format {
+    #[doc = r" Returns the compatibility class of the format, such as `32-bit` or `BC1_RGBA`,"]
+    #[doc = r" `None` for [`Format::UNDEFINED`] and formats that are newer than the table."]
+    #[doc = r""]
+    #[doc = r" Image views of images with `ImageCreateFlags::MUTABLE_FORMAT` and copies between"]
+    #[doc = r" images need formats of the same class. The planes of multi-planar formats have the"]
+    #[doc = r" class of their own single-plane format."]
+    pub fn compatibility_class(self) -> Option<&'static str> {
+        match self {
+            Format::R4G4_UNORM_PACK8
+            | Format::R8_UNORM
+            | Format::R8_SNORM
+            | Format::R8_USCALED
+            | Format::R8_SSCALED
+            | Format::R8_UINT
+            | Format::R8_SINT
+            | Format::R8_SRGB => Some("8-bit"),
+            Format::R4G4B4A4_UNORM_PACK16
+            | Format::B4G4R4A4_UNORM_PACK16
+            | Format::R5G6B5_UNORM_PACK16
+            | Format::B5G6R5_UNORM_PACK16
+            | Format::R5G5B5A1_UNORM_PACK16
+            | Format::B5G5R5A1_UNORM_PACK16
+            | Format::A1R5G5B5_UNORM_PACK16
+            | Format::R8G8_UNORM
+            | Format::R8G8_SNORM
+            | Format::R8G8_USCALED
+            | Format::R8G8_SSCALED
+            | Format::R8G8_UINT
+            | Format::R8G8_SINT
+            | Format::R8G8_SRGB
+            | Format::R16_UNORM
+            | Format::R16_SNORM
+            | Format::R16_USCALED
+            | Format::R16_SSCALED
+            | Format::R16_UINT
+            | Format::R16_SINT
+            | Format::R16_SFLOAT
+            | Format::A4R4G4B4_UNORM_PACK16_EXT
+            | Format::A4B4G4R4_UNORM_PACK16_EXT
+            | Format::R10X6_UNORM_PACK16
+            | Format::R12X4_UNORM_PACK16 => Some("16-bit"),
+            Format::R8G8B8_UNORM
+            | Format::R8G8B8_SNORM
+            | Format::R8G8B8_USCALED
+            | Format::R8G8B8_SSCALED
+            | Format::R8G8B8_UINT
+            | Format::R8G8B8_SINT
+            | Format::R8G8B8_SRGB
+            | Format::B8G8R8_UNORM
+            | Format::B8G8R8_SNORM
+            | Format::B8G8R8_USCALED
+            | Format::B8G8R8_SSCALED
+            | Format::B8G8R8_UINT
+            | Format::B8G8R8_SINT
+            | Format::B8G8R8_SRGB => Some("24-bit"),
+            Format::R8G8B8A8_UNORM
+            | Format::R8G8B8A8_SNORM
+            | Format::R8G8B8A8_USCALED
+            | Format::R8G8B8A8_SSCALED
+            | Format::R8G8B8A8_UINT
+            | Format::R8G8B8A8_SINT
+            | Format::R8G8B8A8_SRGB
+            | Format::B8G8R8A8_UNORM
+            | Format::B8G8R8A8_SNORM
+            | Format::B8G8R8A8_USCALED
+            | Format::B8G8R8A8_SSCALED
+            | Format::B8G8R8A8_UINT
+            | Format::B8G8R8A8_SINT
+            | Format::B8G8R8A8_SRGB
+            | Format::A8B8G8R8_UNORM_PACK32
+            | Format::A8B8G8R8_SNORM_PACK32
+            | Format::A8B8G8R8_USCALED_PACK32
+            | Format::A8B8G8R8_SSCALED_PACK32
+            | Format::A8B8G8R8_UINT_PACK32
+            | Format::A8B8G8R8_SINT_PACK32
+            | Format::A8B8G8R8_SRGB_PACK32
+            | Format::A2R10G10B10_UNORM_PACK32
+            | Format::A2R10G10B10_SNORM_PACK32
+            | Format::A2R10G10B10_USCALED_PACK32
+            | Format::A2R10G10B10_SSCALED_PACK32
+            | Format::A2R10G10B10_UINT_PACK32
+            | Format::A2R10G10B10_SINT_PACK32
+            | Format::A2B10G10R10_UNORM_PACK32
+            | Format::A2B10G10R10_SNORM_PACK32
+            | Format::A2B10G10R10_USCALED_PACK32
+            | Format::A2B10G10R10_SSCALED_PACK32
+            | Format::A2B10G10R10_UINT_PACK32
+            | Format::A2B10G10R10_SINT_PACK32
+            | Format::R16G16_UNORM
+            | Format::R16G16_SNORM
+            | Format::R16G16_USCALED
+            | Format::R16G16_SSCALED
+            | Format::R16G16_UINT
+            | Format::R16G16_SINT
+            | Format::R16G16_SFLOAT
+            | Format::R32_UINT
+            | Format::R32_SINT
+            | Format::R32_SFLOAT
+            | Format::B10G11R11_UFLOAT_PACK32
+            | Format::E5B9G9R9_UFLOAT_PACK32
+            | Format::R10X6G10X6_UNORM_2PACK16
+            | Format::R12X4G12X4_UNORM_2PACK16 => Some("32-bit"),
+            Format::R16G16B16_UNORM
+            | Format::R16G16B16_SNORM
+            | Format::R16G16B16_USCALED
+            | Format::R16G16B16_SSCALED
+            | Format::R16G16B16_UINT
+            | Format::R16G16B16_SINT
+            | Format::R16G16B16_SFLOAT => Some("48-bit"),
+            Format::R16G16B16A16_UNORM
+            | Format::R16G16B16A16_SNORM
+            | Format::R16G16B16A16_USCALED
+            | Format::R16G16B16A16_SSCALED
+            | Format::R16G16B16A16_UINT
+            | Format::R16G16B16A16_SINT
+            | Format::R16G16B16A16_SFLOAT
+            | Format::R32G32_UINT
+            | Format::R32G32_SINT
+            | Format::R32G32_SFLOAT
+            | Format::R64_UINT
+            | Format::R64_SINT
+            | Format::R64_SFLOAT => Some("64-bit"),
+            Format::R32G32B32_UINT | Format::R32G32B32_SINT | Format::R32G32B32_SFLOAT => {
+                Some("96-bit")
+            }
+            Format::R32G32B32A32_UINT
+            | Format::R32G32B32A32_SINT
+            | Format::R32G32B32A32_SFLOAT
+            | Format::R64G64_UINT
+            | Format::R64G64_SINT
+            | Format::R64G64_SFLOAT => Some("128-bit"),
+            Format::R64G64B64_UINT | Format::R64G64B64_SINT | Format::R64G64B64_SFLOAT => {
+                Some("192-bit")
+            }
+            Format::R64G64B64A64_UINT | Format::R64G64B64A64_SINT | Format::R64G64B64A64_SFLOAT => {
+                Some("256-bit")
+            }
+            Format::D16_UNORM => Some("D16"),
+            Format::X8_D24_UNORM_PACK32 => Some("D24"),
+            Format::D32_SFLOAT => Some("D32"),
+            Format::S8_UINT => Some("S8"),
+            Format::D16_UNORM_S8_UINT => Some("D16S8"),
+            Format::D24_UNORM_S8_UINT => Some("D24S8"),
+            Format::D32_SFLOAT_S8_UINT => Some("D32S8"),
+            Format::BC1_RGB_UNORM_BLOCK | Format::BC1_RGB_SRGB_BLOCK => Some("BC1_RGB"),
+            Format::BC1_RGBA_UNORM_BLOCK | Format::BC1_RGBA_SRGB_BLOCK => Some("BC1_RGBA"),
+            Format::BC2_UNORM_BLOCK | Format::BC2_SRGB_BLOCK => Some("BC2"),
+            Format::BC3_UNORM_BLOCK | Format::BC3_SRGB_BLOCK => Some("BC3"),
+            Format::BC4_UNORM_BLOCK | Format::BC4_SNORM_BLOCK => Some("BC4"),
+            Format::BC5_UNORM_BLOCK | Format::BC5_SNORM_BLOCK => Some("BC5"),
+            Format::BC6H_UFLOAT_BLOCK | Format::BC6H_SFLOAT_BLOCK => Some("BC6H"),
+            Format::BC7_UNORM_BLOCK | Format::BC7_SRGB_BLOCK => Some("BC7"),
+            Format::ETC2_R8G8B8_UNORM_BLOCK | Format::ETC2_R8G8B8_SRGB_BLOCK => Some("ETC2_RGB"),
+            Format::ETC2_R8G8B8A1_UNORM_BLOCK | Format::ETC2_R8G8B8A1_SRGB_BLOCK => {
+                Some("ETC2_RGBA")
+            }
+            Format::ETC2_R8G8B8A8_UNORM_BLOCK | Format::ETC2_R8G8B8A8_SRGB_BLOCK => {
+                Some("ETC2_EAC_RGBA")
+            }
+            Format::EAC_R11_UNORM_BLOCK | Format::EAC_R11_SNORM_BLOCK => Some("EAC_R"),
+            Format::EAC_R11G11_UNORM_BLOCK | Format::EAC_R11G11_SNORM_BLOCK => Some("EAC_RG"),
+            Format::ASTC_4X4_UNORM_BLOCK
+            | Format::ASTC_4X4_SRGB_BLOCK
+            | Format::ASTC_4X4_SFLOAT_BLOCK_EXT => Some("ASTC_4x4"),
+            Format::ASTC_5X4_UNORM_BLOCK
+            | Format::ASTC_5X4_SRGB_BLOCK
+            | Format::ASTC_5X4_SFLOAT_BLOCK_EXT => Some("ASTC_5x4"),
+            Format::ASTC_5X5_UNORM_BLOCK
+            | Format::ASTC_5X5_SRGB_BLOCK
+            | Format::ASTC_5X5_SFLOAT_BLOCK_EXT => Some("ASTC_5x5"),
+            Format::ASTC_6X5_UNORM_BLOCK
+            | Format::ASTC_6X5_SRGB_BLOCK
+            | Format::ASTC_6X5_SFLOAT_BLOCK_EXT => Some("ASTC_6x5"),
+            Format::ASTC_6X6_UNORM_BLOCK
+            | Format::ASTC_6X6_SRGB_BLOCK
+            | Format::ASTC_6X6_SFLOAT_BLOCK_EXT => Some("ASTC_6x6"),
+            Format::ASTC_8X5_UNORM_BLOCK
+            | Format::ASTC_8X5_SRGB_BLOCK
+            | Format::ASTC_8X5_SFLOAT_BLOCK_EXT => Some("ASTC_8x5"),
+            Format::ASTC_8X6_UNORM_BLOCK
+            | Format::ASTC_8X6_SRGB_BLOCK
+            | Format::ASTC_8X6_SFLOAT_BLOCK_EXT => Some("ASTC_8x6"),
+            Format::ASTC_8X8_UNORM_BLOCK
+            | Format::ASTC_8X8_SRGB_BLOCK
+            | Format::ASTC_8X8_SFLOAT_BLOCK_EXT => Some("ASTC_8x8"),
+            Format::ASTC_10X5_UNORM_BLOCK
+            | Format::ASTC_10X5_SRGB_BLOCK
+            | Format::ASTC_10X5_SFLOAT_BLOCK_EXT => Some("ASTC_10x5"),
+            Format::ASTC_10X6_UNORM_BLOCK
+            | Format::ASTC_10X6_SRGB_BLOCK
+            | Format::ASTC_10X6_SFLOAT_BLOCK_EXT => Some("ASTC_10x6"),
+            Format::ASTC_10X8_UNORM_BLOCK
+            | Format::ASTC_10X8_SRGB_BLOCK
+            | Format::ASTC_10X8_SFLOAT_BLOCK_EXT => Some("ASTC_10x8"),
+            Format::ASTC_10X10_UNORM_BLOCK
+            | Format::ASTC_10X10_SRGB_BLOCK
+            | Format::ASTC_10X10_SFLOAT_BLOCK_EXT => Some("ASTC_10x10"),
+            Format::ASTC_12X10_UNORM_BLOCK
+            | Format::ASTC_12X10_SRGB_BLOCK
+            | Format::ASTC_12X10_SFLOAT_BLOCK_EXT => Some("ASTC_12x10"),
+            Format::ASTC_12X12_UNORM_BLOCK
+            | Format::ASTC_12X12_SRGB_BLOCK
+            | Format::ASTC_12X12_SFLOAT_BLOCK_EXT => Some("ASTC_12x12"),
+            Format::PVRTC1_2BPP_UNORM_BLOCK_IMG | Format::PVRTC1_2BPP_SRGB_BLOCK_IMG => {
+                Some("PVRTC1_2BPP")
+            }
+            Format::PVRTC1_4BPP_UNORM_BLOCK_IMG | Format::PVRTC1_4BPP_SRGB_BLOCK_IMG => {
+                Some("PVRTC1_4BPP")
+            }
+            Format::PVRTC2_2BPP_UNORM_BLOCK_IMG | Format::PVRTC2_2BPP_SRGB_BLOCK_IMG => {
+                Some("PVRTC2_2BPP")
+            }
+            Format::PVRTC2_4BPP_UNORM_BLOCK_IMG | Format::PVRTC2_4BPP_SRGB_BLOCK_IMG => {
+                Some("PVRTC2_4BPP")
+            }
+            Format::ASTC_3X3X3_UNORM_BLOCK_EXT
+            | Format::ASTC_3X3X3_SRGB_BLOCK_EXT
+            | Format::ASTC_3X3X3_SFLOAT_BLOCK_EXT => Some("ASTC_3x3x3"),
+            Format::ASTC_4X3X3_UNORM_BLOCK_EXT
+            | Format::ASTC_4X3X3_SRGB_BLOCK_EXT
+            | Format::ASTC_4X3X3_SFLOAT_BLOCK_EXT => Some("ASTC_4x3x3"),
+            Format::ASTC_4X4X3_UNORM_BLOCK_EXT
+            | Format::ASTC_4X4X3_SRGB_BLOCK_EXT
+            | Format::ASTC_4X4X3_SFLOAT_BLOCK_EXT => Some("ASTC_4x4x3"),
+            Format::ASTC_4X4X4_UNORM_BLOCK_EXT
+            | Format::ASTC_4X4X4_SRGB_BLOCK_EXT
+            | Format::ASTC_4X4X4_SFLOAT_BLOCK_EXT => Some("ASTC_4x4x4"),
+            Format::ASTC_5X4X4_UNORM_BLOCK_EXT
+            | Format::ASTC_5X4X4_SRGB_BLOCK_EXT
+            | Format::ASTC_5X4X4_SFLOAT_BLOCK_EXT => Some("ASTC_5x4x4"),
+            Format::ASTC_5X5X4_UNORM_BLOCK_EXT
+            | Format::ASTC_5X5X4_SRGB_BLOCK_EXT
+            | Format::ASTC_5X5X4_SFLOAT_BLOCK_EXT => Some("ASTC_5x5x4"),
+            Format::ASTC_5X5X5_UNORM_BLOCK_EXT
+            | Format::ASTC_5X5X5_SRGB_BLOCK_EXT
+            | Format::ASTC_5X5X5_SFLOAT_BLOCK_EXT => Some("ASTC_5x5x5"),
+            Format::ASTC_6X5X5_UNORM_BLOCK_EXT
+            | Format::ASTC_6X5X5_SRGB_BLOCK_EXT
+            | Format::ASTC_6X5X5_SFLOAT_BLOCK_EXT => Some("ASTC_6x5x5"),
+            Format::ASTC_6X6X5_UNORM_BLOCK_EXT
+            | Format::ASTC_6X6X5_SRGB_BLOCK_EXT
+            | Format::ASTC_6X6X5_SFLOAT_BLOCK_EXT => Some("ASTC_6x6x5"),
+            Format::ASTC_6X6X6_UNORM_BLOCK_EXT
+            | Format::ASTC_6X6X6_SRGB_BLOCK_EXT
+            | Format::ASTC_6X6X6_SFLOAT_BLOCK_EXT => Some("ASTC_6x6x6"),
+            Format::G8B8G8R8_422_UNORM => Some("32-bit G8B8G8R8"),
+            Format::B8G8R8G8_422_UNORM => Some("32-bit B8G8R8G8"),
+            Format::G8_B8_R8_3PLANE_420_UNORM => Some("8-bit 3-plane 420"),
+            Format::G8_B8R8_2PLANE_420_UNORM => Some("8-bit 2-plane 420"),
+            Format::G8_B8_R8_3PLANE_422_UNORM => Some("8-bit 3-plane 422"),
+            Format::G8_B8R8_2PLANE_422_UNORM => Some("8-bit 2-plane 422"),
+            Format::G8_B8_R8_3PLANE_444_UNORM => Some("8-bit 3-plane 444"),
+            Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16 => Some("64-bit R10G10B10A10"),
+            Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => Some("64-bit G10B10G10R10"),
+            Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => Some("64-bit B10G10R10G10"),
+            Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 => Some("10-bit 3-plane 420"),
+            Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 => Some("10-bit 2-plane 420"),
+            Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 => Some("10-bit 3-plane 422"),
+            Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 => Some("10-bit 2-plane 422"),
+            Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 => Some("10-bit 3-plane 444"),
+            Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16 => Some("64-bit R12G12B12A12"),
+            Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => Some("64-bit G12B12G12R12"),
+            Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => Some("64-bit B12G12R12G12"),
+            Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 => Some("12-bit 3-plane 420"),
+            Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 => Some("12-bit 2-plane 420"),
+            Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 => Some("12-bit 3-plane 422"),
+            Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 => Some("12-bit 2-plane 422"),
+            Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 => Some("12-bit 3-plane 444"),
+            Format::G16B16G16R16_422_UNORM => Some("64-bit G16B16G16R16"),
+            Format::B16G16R16G16_422_UNORM => Some("64-bit B16G16R16G16"),
+            Format::G16_B16_R16_3PLANE_420_UNORM => Some("16-bit 3-plane 420"),
+            Format::G16_B16R16_2PLANE_420_UNORM => Some("16-bit 2-plane 420"),
+            Format::G16_B16_R16_3PLANE_422_UNORM => Some("16-bit 3-plane 422"),
+            Format::G16_B16R16_2PLANE_422_UNORM => Some("16-bit 2-plane 422"),
+            Format::G16_B16_R16_3PLANE_444_UNORM => Some("16-bit 3-plane 444"),
+            _ => None,
+        }
+    }
+    #[doc = r" Returns whether the formats are the same, or in the same compatibility class."]
+    pub fn is_compatible_with(self, other: Format) -> bool {
+        self == other
+            || self
+                .compatibility_class()
+                .is_some_and(|class| other.compatibility_class() == Some(class))
+    }
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn format_compatibility() {
+    let format = Format::R8G8B8A8_UNORM;
+    assert_eq!(format.compatibility_class(), Some("32-bit"));
+    assert!(format.is_compatible_with(Format::R32_UINT));
+    assert!(format.is_compatible_with(Format::B8G8R8A8_SRGB));
+    assert!(!format.is_compatible_with(Format::R8G8B8_UNORM));
+    assert!(!Format::D32_SFLOAT.is_compatible_with(Format::R32_SFLOAT));
+    assert!(Format::UNDEFINED.is_compatible_with(Format::UNDEFINED));
+}
@@ -0,0 +1,326 @@
+#![doc = r" Formats with the same memory layout in other graphics APIs."]
+#![doc = r""]
+#![doc = r" # Sharing memory with OpenGL"]
+#![doc = r""]
+#![doc = r" OpenGL can't export its memory, so images that both APIs use are created by Vulkan and"]
+#![doc = r" imported into a memory object with `GL_EXT_memory_object`:"]
+#![doc = r""]
+#![doc = r" 1. Create the image with a [`vk::ExternalMemoryImageCreateInfo`] and allocate its memory"]
+#![doc = r"    with a [`vk::ExportMemoryAllocateInfo`], both with the `OPAQUE_FD` handle type, or"]
+#![doc = r"    `OPAQUE_WIN32` on Windows."]
+#![doc = r" 2. Get the handle with [`khr::ExternalMemoryFd::get_memory_fd`], or"]
+#![doc = r"    `vkGetMemoryWin32HandleKHR`."]
+#![doc = r" 3. Create the memory object with `glCreateMemoryObjectsEXT` and import the handle with"]
+#![doc = r"    `glImportMemoryFdEXT` or `glImportMemoryWin32HandleEXT`, passing the allocation size."]
+#![doc = r" 4. Set `GL_TEXTURE_TILING_EXT` to [`ImageTiling::to_gl_tiling`] of the image's tiling and"]
+#![doc = r"    create the texture with `glTexStorageMem2DEXT`, passing [`Format::to_gl`] of the"]
+#![doc = r"    image's format as the internal format."]
+#![doc = r""]
+#![doc = r" Semaphores are shared the same way with [`vk::ExportSemaphoreCreateInfo`],"]
+#![doc = r" `glGenSemaphoresEXT` and `glImportSemaphoreFdEXT`, and signaled and waited on with"]
+#![doc = r" `glSignalSemaphoreEXT` and `glWaitSemaphoreEXT`, which take the layouts that the images"]
+#![doc = r" are in."]
+#![doc = r""]
+#![doc = r" [`vk::ExternalMemoryImageCreateInfo`]: crate::vk::ExternalMemoryImageCreateInfo"]
+#![doc = r" [`vk::ExportMemoryAllocateInfo`]: crate::vk::ExportMemoryAllocateInfo"]
+#![doc = r" [`vk::ExportSemaphoreCreateInfo`]: crate::vk::ExportSemaphoreCreateInfo"]
+#![doc = r" [`khr::ExternalMemoryFd::get_memory_fd`]: crate::extensions::khr::ExternalMemoryFd::get_memory_fd"]
+use crate::vk::enums::{Format, ImageTiling};
+#[doc = r" A `DXGI_FORMAT` value."]
+pub type DxgiFormat = u32;
+#[doc = r" A `MTLPixelFormat` value."]
+pub type MtlPixelFormat = u64;
+#[doc = r" A `GLenum` value."]
+pub type GlEnum = u32;
+#[doc = r" Vulkan formats and the `DXGI_FORMAT` with the same memory layout. Formats without an"]
+#[doc = r" equivalent are missing."]
+pub const DXGI_FORMATS: &[(Format, DxgiFormat)] = &[
+    (Format::R32G32B32A32_SFLOAT, 2),
+    (Format::R32G32B32A32_UINT, 3),
+    (Format::R32G32B32A32_SINT, 4),
+    (Format::R32G32B32_SFLOAT, 6),
+    (Format::R32G32B32_UINT, 7),
+    (Format::R32G32B32_SINT, 8),
+    (Format::R16G16B16A16_SFLOAT, 10),
+    (Format::R16G16B16A16_UNORM, 11),
+    (Format::R16G16B16A16_UINT, 12),
+    (Format::R16G16B16A16_SNORM, 13),
+    (Format::R16G16B16A16_SINT, 14),
+    (Format::R32G32_SFLOAT, 16),
+    (Format::R32G32_UINT, 17),
+    (Format::R32G32_SINT, 18),
+    (Format::D32_SFLOAT_S8_UINT, 20),
+    (Format::A2B10G10R10_UNORM_PACK32, 24),
+    (Format::A2B10G10R10_UINT_PACK32, 25),
+    (Format::B10G11R11_UFLOAT_PACK32, 26),
+    (Format::R8G8B8A8_UNORM, 28),
+    (Format::R8G8B8A8_SRGB, 29),
+    (Format::R8G8B8A8_UINT, 30),
+    (Format::R8G8B8A8_SNORM, 31),
+    (Format::R8G8B8A8_SINT, 32),
+    (Format::R16G16_SFLOAT, 34),
+    (Format::R16G16_UNORM, 35),
+    (Format::R16G16_UINT, 36),
+    (Format::R16G16_SNORM, 37),
+    (Format::R16G16_SINT, 38),
+    (Format::D32_SFLOAT, 40),
+    (Format::R32_SFLOAT, 41),
+    (Format::R32_UINT, 42),
+    (Format::R32_SINT, 43),
+    (Format::D24_UNORM_S8_UINT, 45),
+    (Format::R8G8_UNORM, 49),
+    (Format::R8G8_UINT, 50),
+    (Format::R8G8_SNORM, 51),
+    (Format::R8G8_SINT, 52),
+    (Format::R16_SFLOAT, 54),
+    (Format::D16_UNORM, 55),
+    (Format::R16_UNORM, 56),
+    (Format::R16_UINT, 57),
+    (Format::R16_SNORM, 58),
+    (Format::R16_SINT, 59),
+    (Format::R8_UNORM, 61),
+    (Format::R8_UINT, 62),
+    (Format::R8_SNORM, 63),
+    (Format::R8_SINT, 64),
+    (Format::E5B9G9R9_UFLOAT_PACK32, 67),
+    (Format::BC1_RGBA_UNORM_BLOCK, 71),
+    (Format::BC1_RGBA_SRGB_BLOCK, 72),
+    (Format::BC2_UNORM_BLOCK, 74),
+    (Format::BC2_SRGB_BLOCK, 75),
+    (Format::BC3_UNORM_BLOCK, 77),
+    (Format::BC3_SRGB_BLOCK, 78),
+    (Format::BC4_UNORM_BLOCK, 80),
+    (Format::BC4_SNORM_BLOCK, 81),
+    (Format::BC5_UNORM_BLOCK, 83),
+    (Format::BC5_SNORM_BLOCK, 84),
+    (Format::R5G6B5_UNORM_PACK16, 85),
+    (Format::A1R5G5B5_UNORM_PACK16, 86),
+    (Format::B8G8R8A8_UNORM, 87),
+    (Format::B8G8R8A8_SRGB, 91),
+    (Format::BC6H_UFLOAT_BLOCK, 95),
+    (Format::BC6H_SFLOAT_BLOCK, 96),
+    (Format::BC7_UNORM_BLOCK, 98),
+    (Format::BC7_SRGB_BLOCK, 99),
+];
+#[doc = r" Vulkan formats and the `MTLPixelFormat` with the same memory layout. Formats without an"]
+#[doc = r" equivalent are missing."]
+pub const METAL_FORMATS: &[(Format, MtlPixelFormat)] = &[
+    (Format::R8_UNORM, 10),
+    (Format::R8_SRGB, 11),
+    (Format::R8_SNORM, 12),
+    (Format::R8_UINT, 13),
+    (Format::R8_SINT, 14),
+    (Format::R16_UNORM, 20),
+    (Format::R16_SNORM, 22),
+    (Format::R16_UINT, 23),
+    (Format::R16_SINT, 24),
+    (Format::R16_SFLOAT, 25),
+    (Format::R8G8_UNORM, 30),
+    (Format::R8G8_SRGB, 31),
+    (Format::R8G8_SNORM, 32),
+    (Format::R8G8_UINT, 33),
+    (Format::R8G8_SINT, 34),
+    (Format::R5G6B5_UNORM_PACK16, 40),
+    (Format::A1R5G5B5_UNORM_PACK16, 41),
+    (Format::R4G4B4A4_UNORM_PACK16, 42),
+    (Format::R5G5B5A1_UNORM_PACK16, 43),
+    (Format::R32_UINT, 53),
+    (Format::R32_SINT, 54),
+    (Format::R32_SFLOAT, 55),
+    (Format::R16G16_UNORM, 60),
+    (Format::R16G16_SNORM, 62),
+    (Format::R16G16_UINT, 63),
+    (Format::R16G16_SINT, 64),
+    (Format::R16G16_SFLOAT, 65),
+    (Format::R8G8B8A8_UNORM, 70),
+    (Format::R8G8B8A8_SRGB, 71),
+    (Format::R8G8B8A8_SNORM, 72),
+    (Format::R8G8B8A8_UINT, 73),
+    (Format::R8G8B8A8_SINT, 74),
+    (Format::B8G8R8A8_UNORM, 80),
+    (Format::B8G8R8A8_SRGB, 81),
+    (Format::A2B10G10R10_UNORM_PACK32, 90),
+    (Format::A2B10G10R10_UINT_PACK32, 91),
+    (Format::B10G11R11_UFLOAT_PACK32, 92),
+    (Format::E5B9G9R9_UFLOAT_PACK32, 93),
+    (Format::A2R10G10B10_UNORM_PACK32, 94),
+    (Format::R32G32_UINT, 103),
+    (Format::R32G32_SINT, 104),
+    (Format::R32G32_SFLOAT, 105),
+    (Format::R16G16B16A16_UNORM, 110),
+    (Format::R16G16B16A16_SNORM, 112),
+    (Format::R16G16B16A16_UINT, 113),
+    (Format::R16G16B16A16_SINT, 114),
+    (Format::R16G16B16A16_SFLOAT, 115),
+    (Format::R32G32B32A32_UINT, 123),
+    (Format::R32G32B32A32_SINT, 124),
+    (Format::R32G32B32A32_SFLOAT, 125),
+    (Format::BC1_RGBA_UNORM_BLOCK, 130),
+    (Format::BC1_RGBA_SRGB_BLOCK, 131),
+    (Format::BC2_UNORM_BLOCK, 132),
+    (Format::BC2_SRGB_BLOCK, 133),
+    (Format::BC3_UNORM_BLOCK, 134),
+    (Format::BC3_SRGB_BLOCK, 135),
+    (Format::BC4_UNORM_BLOCK, 140),
+    (Format::BC4_SNORM_BLOCK, 141),
+    (Format::BC5_UNORM_BLOCK, 142),
+    (Format::BC5_SNORM_BLOCK, 143),
+    (Format::BC6H_SFLOAT_BLOCK, 150),
+    (Format::BC6H_UFLOAT_BLOCK, 151),
+    (Format::BC7_UNORM_BLOCK, 152),
+    (Format::BC7_SRGB_BLOCK, 153),
+    (Format::D16_UNORM, 250),
+    (Format::D32_SFLOAT, 252),
+    (Format::S8_UINT, 253),
+    (Format::D24_UNORM_S8_UINT, 255),
+    (Format::D32_SFLOAT_S8_UINT, 260),
+];
+#[doc = r" Vulkan formats and the sized OpenGL internal format with the same memory layout."]
+#[doc = r" Formats without an equivalent are missing."]
+pub const GL_FORMATS: &[(Format, GlEnum)] = &[
+    (Format::R8_UNORM, 33321),
+    (Format::R8_SNORM, 36756),
+    (Format::R8_UINT, 33330),
+    (Format::R8_SINT, 33329),
+    (Format::R16_UNORM, 33322),
+    (Format::R16_SNORM, 36760),
+    (Format::R16_UINT, 33332),
+    (Format::R16_SINT, 33331),
+    (Format::R16_SFLOAT, 33325),
+    (Format::R32_UINT, 33334),
+    (Format::R32_SINT, 33333),
+    (Format::R32_SFLOAT, 33326),
+    (Format::R8G8_UNORM, 33323),
+    (Format::R8G8_SNORM, 36757),
+    (Format::R8G8_UINT, 33336),
+    (Format::R8G8_SINT, 33335),
+    (Format::R16G16_UNORM, 33324),
+    (Format::R16G16_SNORM, 36761),
+    (Format::R16G16_UINT, 33338),
+    (Format::R16G16_SINT, 33337),
+    (Format::R16G16_SFLOAT, 33327),
+    (Format::R32G32_UINT, 33340),
+    (Format::R32G32_SINT, 33339),
+    (Format::R32G32_SFLOAT, 33328),
+    (Format::R8G8B8_UNORM, 32849),
+    (Format::R8G8B8_SNORM, 36758),
+    (Format::R8G8B8_UINT, 36221),
+    (Format::R8G8B8_SINT, 36239),
+    (Format::R8G8B8_SRGB, 35905),
+    (Format::R16G16B16_SFLOAT, 34843),
+    (Format::R32G32B32_UINT, 36209),
+    (Format::R32G32B32_SINT, 36227),
+    (Format::R32G32B32_SFLOAT, 34837),
+    (Format::R8G8B8A8_UNORM, 32856),
+    (Format::R8G8B8A8_SNORM, 36759),
+    (Format::R8G8B8A8_UINT, 36220),
+    (Format::R8G8B8A8_SINT, 36238),
+    (Format::R8G8B8A8_SRGB, 35907),
+    (Format::R16G16B16A16_UNORM, 32859),
+    (Format::R16G16B16A16_SNORM, 36763),
+    (Format::R16G16B16A16_UINT, 36214),
+    (Format::R16G16B16A16_SINT, 36232),
+    (Format::R16G16B16A16_SFLOAT, 34842),
+    (Format::R32G32B32A32_UINT, 36208),
+    (Format::R32G32B32A32_SINT, 36226),
+    (Format::R32G32B32A32_SFLOAT, 34836),
+    (Format::R5G6B5_UNORM_PACK16, 36194),
+    (Format::A2B10G10R10_UNORM_PACK32, 32857),
+    (Format::A2B10G10R10_UINT_PACK32, 36975),
+    (Format::B10G11R11_UFLOAT_PACK32, 35898),
+    (Format::E5B9G9R9_UFLOAT_PACK32, 35901),
+    (Format::D16_UNORM, 33189),
+    (Format::X8_D24_UNORM_PACK32, 33190),
+    (Format::D32_SFLOAT, 36012),
+    (Format::S8_UINT, 36168),
+    (Format::D24_UNORM_S8_UINT, 35056),
+    (Format::D32_SFLOAT_S8_UINT, 36013),
+    (Format::BC1_RGB_UNORM_BLOCK, 33776),
+    (Format::BC1_RGB_SRGB_BLOCK, 35916),
+    (Format::BC1_RGBA_UNORM_BLOCK, 33777),
+    (Format::BC1_RGBA_SRGB_BLOCK, 35917),
+    (Format::BC2_UNORM_BLOCK, 33778),
+    (Format::BC2_SRGB_BLOCK, 35918),
+    (Format::BC3_UNORM_BLOCK, 33779),
+    (Format::BC3_SRGB_BLOCK, 35919),
+    (Format::BC4_UNORM_BLOCK, 36283),
+    (Format::BC4_SNORM_BLOCK, 36284),
+    (Format::BC5_UNORM_BLOCK, 36285),
+    (Format::BC5_SNORM_BLOCK, 36286),
+    (Format::BC6H_UFLOAT_BLOCK, 36495),
+    (Format::BC6H_SFLOAT_BLOCK, 36494),
+    (Format::BC7_UNORM_BLOCK, 36492),
+    (Format::BC7_SRGB_BLOCK, 36493),
+    (Format::ETC2_R8G8B8_UNORM_BLOCK, 37492),
+    (Format::ETC2_R8G8B8_SRGB_BLOCK, 37493),
+    (Format::ETC2_R8G8B8A1_UNORM_BLOCK, 37494),
+    (Format::ETC2_R8G8B8A1_SRGB_BLOCK, 37495),
+    (Format::ETC2_R8G8B8A8_UNORM_BLOCK, 37496),
+    (Format::ETC2_R8G8B8A8_SRGB_BLOCK, 37497),
+    (Format::EAC_R11_UNORM_BLOCK, 37488),
+    (Format::EAC_R11_SNORM_BLOCK, 37489),
+    (Format::EAC_R11G11_UNORM_BLOCK, 37490),
+    (Format::EAC_R11G11_SNORM_BLOCK, 37491),
+];
+impl Format {
+    #[doc = r" Returns the `DXGI_FORMAT` with the same memory layout, `None` if there is none."]
+    pub fn to_dxgi(self) -> Option<DxgiFormat> {
+        DXGI_FORMATS
+            .iter()
+            .find(|&&(format, _)| format == self)
+            .map(|&(_, dxgi)| dxgi)
+    }
+    #[doc = r" Returns the format with the same memory layout as the `DXGI_FORMAT` `dxgi`, `None`"]
+    #[doc = r" if there is none."]
+    pub fn from_dxgi(dxgi: DxgiFormat) -> Option<Self> {
+        DXGI_FORMATS
+            .iter()
+            .find(|&&(_, value)| value == dxgi)
+            .map(|&(format, _)| format)
+    }
+    #[doc = r" Returns the `MTLPixelFormat` with the same memory layout, `None` if there is none."]
+    pub fn to_metal(self) -> Option<MtlPixelFormat> {
+        METAL_FORMATS
+            .iter()
+            .find(|&&(format, _)| format == self)
+            .map(|&(_, metal)| metal)
+    }
+    #[doc = r" Returns the format with the same memory layout as the `MTLPixelFormat` `metal`,"]
+    #[doc = r" `None` if there is none."]
+    pub fn from_metal(metal: MtlPixelFormat) -> Option<Self> {
+        METAL_FORMATS
+            .iter()
+            .find(|&&(_, value)| value == metal)
+            .map(|&(format, _)| format)
+    }
+    #[doc = r" Returns the sized OpenGL internal format with the same memory layout, `None` if"]
+    #[doc = r" there is none."]
+    pub fn to_gl(self) -> Option<GlEnum> {
+        GL_FORMATS
+            .iter()
+            .find(|&&(format, _)| format == self)
+            .map(|&(_, gl)| gl)
+    }
+    #[doc = r" Returns the format with the same memory layout as the OpenGL internal format `gl`,"]
+    #[doc = r" `None` if there is none."]
+    pub fn from_gl(gl: GlEnum) -> Option<Self> {
+        GL_FORMATS
+            .iter()
+            .find(|&&(_, value)| value == gl)
+            .map(|&(format, _)| format)
+    }
+}
+impl ImageTiling {
+    #[doc = r" Returns the `GL_TEXTURE_TILING_EXT` value of textures that are stored in the memory"]
+    #[doc = r" of an image with this tiling, `None` for the DRM format modifier tiling that OpenGL"]
+    #[doc = r" doesn't support."]
+    pub fn to_gl_tiling(self) -> Option<GlEnum> {
+        match self {
+            ImageTiling::OPTIMAL => Some(0x9584),
+            ImageTiling::LINEAR => Some(0x9585),
+            _ => None,
+        }
+    }
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,19 @@
+use super::*;
+
+#[test]
+fn formats() {
+    let format = Format::B8G8R8A8_SRGB;
+    assert_eq!(format.to_dxgi(), Some(91));
+    assert_eq!(Format::from_dxgi(91), Some(format));
+    assert_eq!(format.to_metal(), Some(81));
+    assert_eq!(Format::from_metal(81), Some(format));
+    assert_eq!(Format::UNDEFINED.to_dxgi(), None);
+}
+
+#[test]
+fn gl_formats() {
+    assert_eq!(Format::B8G8R8A8_SRGB.to_gl(), None);
+    assert_eq!(Format::R8G8B8A8_SRGB.to_gl(), Some(0x8c43));
+    assert_eq!(Format::from_gl(0x8c43), Some(Format::R8G8B8A8_SRGB));
+    assert_eq!(ImageTiling::LINEAR.to_gl_tiling(), Some(0x9585));
+}
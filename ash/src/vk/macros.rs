@@ -56,6 +56,14 @@ macro_rules! vk_bitflags_wrapped {
             pub fn contains(self, other: $name) -> bool {
                 self & other == other
             }
+            #[doc = r" Returns whether `self` has bits that aren't in `all()`, e.g. bits of a newer"]
+            #[doc = r" version or extension reported by the driver. Such bits are kept by `|`, `&`,"]
+            #[doc = r" `^` and `-`, while `!` only complements the bits in `all()` and leaves them"]
+            #[doc = r" unchanged."]
+            #[inline]
+            pub fn contains_unknown_bits(self) -> bool {
+                self.0 & !$name::all().0 != 0
+            }
         }
         impl ::std::ops::BitOr for $name {
             type Output = $name;
@@ -100,7 +108,7 @@ macro_rules! vk_bitflags_wrapped {
             type Output = $name;
             #[inline]
             fn sub(self, rhs: $name) -> $name {
-                self & !rhs
+                $name(self.0 & !rhs.0)
             }
         }
         impl ::std::ops::SubAssign for $name {
@@ -141,6 +149,16 @@ macro_rules! handle_nondispatchable {
             pub const fn null() -> $name {
                 $name(0)
             }
+            #[doc = r" Casts `handles` to their raw values without copying."]
+            pub fn as_raw_slice(handles: &[$name]) -> &[u64] {
+                unsafe {
+                    ::std::slice::from_raw_parts(handles.as_ptr() as *const u64, handles.len())
+                }
+            }
+            #[doc = r" Casts the raw values in `raw` to handles without copying."]
+            pub fn from_raw_slice(raw: &[u64]) -> &[$name] {
+                unsafe { ::std::slice::from_raw_parts(raw.as_ptr() as *const $name, raw.len()) }
+            }
         }
         impl fmt::Pointer for $name {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -197,3 +215,22 @@ macro_rules! define_handle {
         }
     };
 }
+#[doc = r" Builds the array of extension name pointers that `enabled_extension_names` takes, from"]
+#[doc = r" types with a `name() -> &'static CStr` function, such as the extension loaders in"]
+#[doc = r" `ash::extensions` and the `vk::*Fn` tables."]
+#[doc = r""]
+#[doc = r" ```"]
+#[doc = r" use ash::extensions::{ext, khr};"]
+#[doc = r" use ash::{vk, vk_extensions};"]
+#[doc = r" let extension_names = vk_extensions![khr::Surface, ext::DebugUtils, vk::KhrDisplayFn];"]
+#[doc = r" let create_info ="]
+#[doc = r"     vk::InstanceCreateInfo::builder().enabled_extension_names(&extension_names);"]
+#[doc = r" ```"]
+#[macro_export]
+macro_rules! vk_extensions {
+    ($ ($ extension : path) , * $ (,) ?) => {
+        [$(<$extension>::name().as_ptr()),*]
+    };
+}
+#[cfg(test)]
+mod tests;
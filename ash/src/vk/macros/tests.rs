@@ -0,0 +1,21 @@
+use crate::vk::*;
+
+#[test]
+fn raw_handle_slices() {
+    let raw = [1u64, 2, 3];
+    let buffers = Buffer::from_raw_slice(&raw);
+    assert_eq!(Handle::as_raw(buffers[1]), 2);
+    assert_eq!(Buffer::as_raw_slice(buffers), raw);
+    assert_eq!(Buffer::as_raw_slice(buffers).as_ptr(), raw.as_ptr());
+}
+
+#[test]
+fn unknown_flag_bits() {
+    let unknown = 1 << 31;
+    let flags = QueueFlags::from_raw(QueueFlags::GRAPHICS.as_raw() | unknown);
+    assert!(flags.contains_unknown_bits());
+    assert!(!QueueFlags::all().contains_unknown_bits());
+    assert_eq!(!!flags, flags);
+    assert_eq!((flags - QueueFlags::GRAPHICS).as_raw(), unknown);
+    assert!((flags - flags).is_empty());
+}
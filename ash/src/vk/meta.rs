@@ -0,0 +1,5336 @@
+use crate::vk::bitflags::QueueFlags;
+use crate::vk::enums::{CommandBufferLevel, StructureType};
+use crate::vk::macros::{make_version, version_major, version_minor};
+#[doc = r" The core versions and extensions that provide a command, e.g."]
+#[doc = r#" `("vkCreateSwapchainKHR", &["VK_KHR_swapchain"])`. Sorted by command name."#]
+pub const COMMAND_PROVIDERS: &[(&str, &[&str])] = &[
+    (
+        "vkAcquireFullScreenExclusiveModeEXT",
+        &["VK_EXT_full_screen_exclusive"],
+    ),
+    (
+        "vkAcquireNextImage2KHR",
+        &["VK_KHR_swapchain", "VK_KHR_device_group"],
+    ),
+    ("vkAcquireNextImageKHR", &["VK_KHR_swapchain"]),
+    (
+        "vkAcquirePerformanceConfigurationINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    ("vkAcquireProfilingLockKHR", &["VK_KHR_performance_query"]),
+    ("vkAcquireXlibDisplayEXT", &["VK_EXT_acquire_xlib_display"]),
+    ("vkAllocateCommandBuffers", &["VK_VERSION_1_0"]),
+    ("vkAllocateDescriptorSets", &["VK_VERSION_1_0"]),
+    ("vkAllocateMemory", &["VK_VERSION_1_0"]),
+    ("vkBeginCommandBuffer", &["VK_VERSION_1_0"]),
+    (
+        "vkBindAccelerationStructureMemoryNV",
+        &["VK_NV_ray_tracing"],
+    ),
+    ("vkBindBufferMemory", &["VK_VERSION_1_0"]),
+    ("vkBindBufferMemory2", &["VK_VERSION_1_1"]),
+    ("vkBindBufferMemory2KHR", &["VK_KHR_bind_memory2"]),
+    ("vkBindImageMemory", &["VK_VERSION_1_0"]),
+    ("vkBindImageMemory2", &["VK_VERSION_1_1"]),
+    ("vkBindImageMemory2KHR", &["VK_KHR_bind_memory2"]),
+    (
+        "vkBuildAccelerationStructuresKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCmdBeginConditionalRenderingEXT",
+        &["VK_EXT_conditional_rendering"],
+    ),
+    ("vkCmdBeginDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkCmdBeginQuery", &["VK_VERSION_1_0"]),
+    ("vkCmdBeginQueryIndexedEXT", &["VK_EXT_transform_feedback"]),
+    ("vkCmdBeginRenderPass", &["VK_VERSION_1_0"]),
+    ("vkCmdBeginRenderPass2", &["VK_VERSION_1_2"]),
+    ("vkCmdBeginRenderPass2KHR", &["VK_KHR_create_renderpass2"]),
+    (
+        "vkCmdBeginTransformFeedbackEXT",
+        &["VK_EXT_transform_feedback"],
+    ),
+    ("vkCmdBindDescriptorSets", &["VK_VERSION_1_0"]),
+    ("vkCmdBindIndexBuffer", &["VK_VERSION_1_0"]),
+    ("vkCmdBindPipeline", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdBindPipelineShaderGroupNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    ("vkCmdBindShadingRateImageNV", &["VK_NV_shading_rate_image"]),
+    (
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        &["VK_EXT_transform_feedback"],
+    ),
+    ("vkCmdBindVertexBuffers", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdBindVertexBuffers2EXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    ("vkCmdBlitImage", &["VK_VERSION_1_0"]),
+    ("vkCmdBlitImage2KHR", &["VK_KHR_copy_commands2"]),
+    ("vkCmdBuildAccelerationStructureNV", &["VK_NV_ray_tracing"]),
+    (
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCmdBuildAccelerationStructuresKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkCmdClearAttachments", &["VK_VERSION_1_0"]),
+    ("vkCmdClearColorImage", &["VK_VERSION_1_0"]),
+    ("vkCmdClearDepthStencilImage", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdCopyAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkCmdCopyAccelerationStructureNV", &["VK_NV_ray_tracing"]),
+    (
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkCmdCopyBuffer", &["VK_VERSION_1_0"]),
+    ("vkCmdCopyBuffer2KHR", &["VK_KHR_copy_commands2"]),
+    ("vkCmdCopyBufferToImage", &["VK_VERSION_1_0"]),
+    ("vkCmdCopyBufferToImage2KHR", &["VK_KHR_copy_commands2"]),
+    ("vkCmdCopyImage", &["VK_VERSION_1_0"]),
+    ("vkCmdCopyImage2KHR", &["VK_KHR_copy_commands2"]),
+    ("vkCmdCopyImageToBuffer", &["VK_VERSION_1_0"]),
+    ("vkCmdCopyImageToBuffer2KHR", &["VK_KHR_copy_commands2"]),
+    (
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkCmdCopyQueryPoolResults", &["VK_VERSION_1_0"]),
+    ("vkCmdDebugMarkerBeginEXT", &["VK_EXT_debug_marker"]),
+    ("vkCmdDebugMarkerEndEXT", &["VK_EXT_debug_marker"]),
+    ("vkCmdDebugMarkerInsertEXT", &["VK_EXT_debug_marker"]),
+    ("vkCmdDispatch", &["VK_VERSION_1_0"]),
+    ("vkCmdDispatchBase", &["VK_VERSION_1_1"]),
+    ("vkCmdDispatchBaseKHR", &["VK_KHR_device_group"]),
+    ("vkCmdDispatchIndirect", &["VK_VERSION_1_0"]),
+    ("vkCmdDraw", &["VK_VERSION_1_0"]),
+    ("vkCmdDrawIndexed", &["VK_VERSION_1_0"]),
+    ("vkCmdDrawIndexedIndirect", &["VK_VERSION_1_0"]),
+    ("vkCmdDrawIndexedIndirectCount", &["VK_VERSION_1_2"]),
+    (
+        "vkCmdDrawIndexedIndirectCountAMD",
+        &["VK_AMD_draw_indirect_count"],
+    ),
+    (
+        "vkCmdDrawIndexedIndirectCountKHR",
+        &["VK_KHR_draw_indirect_count"],
+    ),
+    ("vkCmdDrawIndirect", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdDrawIndirectByteCountEXT",
+        &["VK_EXT_transform_feedback"],
+    ),
+    ("vkCmdDrawIndirectCount", &["VK_VERSION_1_2"]),
+    ("vkCmdDrawIndirectCountAMD", &["VK_AMD_draw_indirect_count"]),
+    ("vkCmdDrawIndirectCountKHR", &["VK_KHR_draw_indirect_count"]),
+    ("vkCmdDrawMeshTasksIndirectCountNV", &["VK_NV_mesh_shader"]),
+    ("vkCmdDrawMeshTasksIndirectNV", &["VK_NV_mesh_shader"]),
+    ("vkCmdDrawMeshTasksNV", &["VK_NV_mesh_shader"]),
+    (
+        "vkCmdEndConditionalRenderingEXT",
+        &["VK_EXT_conditional_rendering"],
+    ),
+    ("vkCmdEndDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkCmdEndQuery", &["VK_VERSION_1_0"]),
+    ("vkCmdEndQueryIndexedEXT", &["VK_EXT_transform_feedback"]),
+    ("vkCmdEndRenderPass", &["VK_VERSION_1_0"]),
+    ("vkCmdEndRenderPass2", &["VK_VERSION_1_2"]),
+    ("vkCmdEndRenderPass2KHR", &["VK_KHR_create_renderpass2"]),
+    (
+        "vkCmdEndTransformFeedbackEXT",
+        &["VK_EXT_transform_feedback"],
+    ),
+    ("vkCmdExecuteCommands", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdExecuteGeneratedCommandsNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    ("vkCmdFillBuffer", &["VK_VERSION_1_0"]),
+    ("vkCmdInsertDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkCmdNextSubpass", &["VK_VERSION_1_0"]),
+    ("vkCmdNextSubpass2", &["VK_VERSION_1_2"]),
+    ("vkCmdNextSubpass2KHR", &["VK_KHR_create_renderpass2"]),
+    ("vkCmdPipelineBarrier", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdPreprocessGeneratedCommandsNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    ("vkCmdPushConstants", &["VK_VERSION_1_0"]),
+    ("vkCmdPushDescriptorSetKHR", &["VK_KHR_push_descriptor"]),
+    (
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        &[
+            "VK_KHR_push_descriptor",
+            "VK_KHR_descriptor_update_template",
+        ],
+    ),
+    ("vkCmdResetEvent", &["VK_VERSION_1_0"]),
+    ("vkCmdResetQueryPool", &["VK_VERSION_1_0"]),
+    ("vkCmdResolveImage", &["VK_VERSION_1_0"]),
+    ("vkCmdResolveImage2KHR", &["VK_KHR_copy_commands2"]),
+    ("vkCmdSetBlendConstants", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetCheckpointNV",
+        &["VK_NV_device_diagnostic_checkpoints"],
+    ),
+    ("vkCmdSetCoarseSampleOrderNV", &["VK_NV_shading_rate_image"]),
+    ("vkCmdSetCullModeEXT", &["VK_EXT_extended_dynamic_state"]),
+    ("vkCmdSetDepthBias", &["VK_VERSION_1_0"]),
+    ("vkCmdSetDepthBounds", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    (
+        "vkCmdSetDepthCompareOpEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    (
+        "vkCmdSetDepthTestEnableEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    (
+        "vkCmdSetDepthWriteEnableEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    ("vkCmdSetDeviceMask", &["VK_VERSION_1_1"]),
+    ("vkCmdSetDeviceMaskKHR", &["VK_KHR_device_group"]),
+    (
+        "vkCmdSetDiscardRectangleEXT",
+        &["VK_EXT_discard_rectangles"],
+    ),
+    ("vkCmdSetEvent", &["VK_VERSION_1_0"]),
+    ("vkCmdSetExclusiveScissorNV", &["VK_NV_scissor_exclusive"]),
+    (
+        "vkCmdSetFragmentShadingRateEnumNV",
+        &["VK_NV_fragment_shading_rate_enums"],
+    ),
+    (
+        "vkCmdSetFragmentShadingRateKHR",
+        &["VK_KHR_fragment_shading_rate"],
+    ),
+    ("vkCmdSetFrontFaceEXT", &["VK_EXT_extended_dynamic_state"]),
+    ("vkCmdSetLineStippleEXT", &["VK_EXT_line_rasterization"]),
+    ("vkCmdSetLineWidth", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetPerformanceMarkerINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    (
+        "vkCmdSetPerformanceOverrideINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    (
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    (
+        "vkCmdSetPrimitiveTopologyEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    (
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    ("vkCmdSetSampleLocationsEXT", &["VK_EXT_sample_locations"]),
+    ("vkCmdSetScissor", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetScissorWithCountEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    ("vkCmdSetStencilCompareMask", &["VK_VERSION_1_0"]),
+    ("vkCmdSetStencilOpEXT", &["VK_EXT_extended_dynamic_state"]),
+    ("vkCmdSetStencilReference", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetStencilTestEnableEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    ("vkCmdSetStencilWriteMask", &["VK_VERSION_1_0"]),
+    ("vkCmdSetViewport", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdSetViewportShadingRatePaletteNV",
+        &["VK_NV_shading_rate_image"],
+    ),
+    (
+        "vkCmdSetViewportWScalingNV",
+        &["VK_NV_clip_space_w_scaling"],
+    ),
+    (
+        "vkCmdSetViewportWithCountEXT",
+        &["VK_EXT_extended_dynamic_state"],
+    ),
+    (
+        "vkCmdTraceRaysIndirectKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    ("vkCmdTraceRaysKHR", &["VK_KHR_ray_tracing_pipeline"]),
+    ("vkCmdTraceRaysNV", &["VK_NV_ray_tracing"]),
+    ("vkCmdUpdateBuffer", &["VK_VERSION_1_0"]),
+    ("vkCmdWaitEvents", &["VK_VERSION_1_0"]),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        &["VK_NV_ray_tracing"],
+    ),
+    ("vkCmdWriteBufferMarkerAMD", &["VK_AMD_buffer_marker"]),
+    ("vkCmdWriteTimestamp", &["VK_VERSION_1_0"]),
+    ("vkCompileDeferredNV", &["VK_NV_ray_tracing"]),
+    (
+        "vkCopyAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCopyAccelerationStructureToMemoryKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCopyMemoryToAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkCreateAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkCreateAccelerationStructureNV", &["VK_NV_ray_tracing"]),
+    ("vkCreateAndroidSurfaceKHR", &["VK_KHR_android_surface"]),
+    ("vkCreateBuffer", &["VK_VERSION_1_0"]),
+    ("vkCreateBufferView", &["VK_VERSION_1_0"]),
+    ("vkCreateCommandPool", &["VK_VERSION_1_0"]),
+    ("vkCreateComputePipelines", &["VK_VERSION_1_0"]),
+    ("vkCreateDebugReportCallbackEXT", &["VK_EXT_debug_report"]),
+    ("vkCreateDebugUtilsMessengerEXT", &["VK_EXT_debug_utils"]),
+    (
+        "vkCreateDeferredOperationKHR",
+        &["VK_KHR_deferred_host_operations"],
+    ),
+    ("vkCreateDescriptorPool", &["VK_VERSION_1_0"]),
+    ("vkCreateDescriptorSetLayout", &["VK_VERSION_1_0"]),
+    ("vkCreateDescriptorUpdateTemplate", &["VK_VERSION_1_1"]),
+    (
+        "vkCreateDescriptorUpdateTemplateKHR",
+        &["VK_KHR_descriptor_update_template"],
+    ),
+    ("vkCreateDevice", &["VK_VERSION_1_0"]),
+    ("vkCreateDirectFBSurfaceEXT", &["VK_EXT_directfb_surface"]),
+    ("vkCreateDisplayModeKHR", &["VK_KHR_display"]),
+    ("vkCreateDisplayPlaneSurfaceKHR", &["VK_KHR_display"]),
+    ("vkCreateEvent", &["VK_VERSION_1_0"]),
+    ("vkCreateFence", &["VK_VERSION_1_0"]),
+    ("vkCreateFramebuffer", &["VK_VERSION_1_0"]),
+    ("vkCreateGraphicsPipelines", &["VK_VERSION_1_0"]),
+    ("vkCreateHeadlessSurfaceEXT", &["VK_EXT_headless_surface"]),
+    ("vkCreateIOSSurfaceMVK", &["VK_MVK_ios_surface"]),
+    ("vkCreateImage", &["VK_VERSION_1_0"]),
+    (
+        "vkCreateImagePipeSurfaceFUCHSIA",
+        &["VK_FUCHSIA_imagepipe_surface"],
+    ),
+    ("vkCreateImageView", &["VK_VERSION_1_0"]),
+    (
+        "vkCreateIndirectCommandsLayoutNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    ("vkCreateInstance", &["VK_VERSION_1_0"]),
+    ("vkCreateMacOSSurfaceMVK", &["VK_MVK_macos_surface"]),
+    ("vkCreateMetalSurfaceEXT", &["VK_EXT_metal_surface"]),
+    ("vkCreatePipelineCache", &["VK_VERSION_1_0"]),
+    ("vkCreatePipelineLayout", &["VK_VERSION_1_0"]),
+    ("vkCreatePrivateDataSlotEXT", &["VK_EXT_private_data"]),
+    ("vkCreateQueryPool", &["VK_VERSION_1_0"]),
+    (
+        "vkCreateRayTracingPipelinesKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    ("vkCreateRayTracingPipelinesNV", &["VK_NV_ray_tracing"]),
+    ("vkCreateRenderPass", &["VK_VERSION_1_0"]),
+    ("vkCreateRenderPass2", &["VK_VERSION_1_2"]),
+    ("vkCreateRenderPass2KHR", &["VK_KHR_create_renderpass2"]),
+    ("vkCreateSampler", &["VK_VERSION_1_0"]),
+    ("vkCreateSamplerYcbcrConversion", &["VK_VERSION_1_1"]),
+    (
+        "vkCreateSamplerYcbcrConversionKHR",
+        &["VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    ("vkCreateSemaphore", &["VK_VERSION_1_0"]),
+    ("vkCreateShaderModule", &["VK_VERSION_1_0"]),
+    ("vkCreateSharedSwapchainsKHR", &["VK_KHR_display_swapchain"]),
+    (
+        "vkCreateStreamDescriptorSurfaceGGP",
+        &["VK_GGP_stream_descriptor_surface"],
+    ),
+    ("vkCreateSwapchainKHR", &["VK_KHR_swapchain"]),
+    ("vkCreateValidationCacheEXT", &["VK_EXT_validation_cache"]),
+    ("vkCreateViSurfaceNN", &["VK_NN_vi_surface"]),
+    ("vkCreateWaylandSurfaceKHR", &["VK_KHR_wayland_surface"]),
+    ("vkCreateWin32SurfaceKHR", &["VK_KHR_win32_surface"]),
+    ("vkCreateXcbSurfaceKHR", &["VK_KHR_xcb_surface"]),
+    ("vkCreateXlibSurfaceKHR", &["VK_KHR_xlib_surface"]),
+    ("vkDebugMarkerSetObjectNameEXT", &["VK_EXT_debug_marker"]),
+    ("vkDebugMarkerSetObjectTagEXT", &["VK_EXT_debug_marker"]),
+    ("vkDebugReportMessageEXT", &["VK_EXT_debug_report"]),
+    (
+        "vkDeferredOperationJoinKHR",
+        &["VK_KHR_deferred_host_operations"],
+    ),
+    (
+        "vkDestroyAccelerationStructureKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkDestroyAccelerationStructureNV", &["VK_NV_ray_tracing"]),
+    ("vkDestroyBuffer", &["VK_VERSION_1_0"]),
+    ("vkDestroyBufferView", &["VK_VERSION_1_0"]),
+    ("vkDestroyCommandPool", &["VK_VERSION_1_0"]),
+    ("vkDestroyDebugReportCallbackEXT", &["VK_EXT_debug_report"]),
+    ("vkDestroyDebugUtilsMessengerEXT", &["VK_EXT_debug_utils"]),
+    (
+        "vkDestroyDeferredOperationKHR",
+        &["VK_KHR_deferred_host_operations"],
+    ),
+    ("vkDestroyDescriptorPool", &["VK_VERSION_1_0"]),
+    ("vkDestroyDescriptorSetLayout", &["VK_VERSION_1_0"]),
+    ("vkDestroyDescriptorUpdateTemplate", &["VK_VERSION_1_1"]),
+    (
+        "vkDestroyDescriptorUpdateTemplateKHR",
+        &["VK_KHR_descriptor_update_template"],
+    ),
+    ("vkDestroyDevice", &["VK_VERSION_1_0"]),
+    ("vkDestroyEvent", &["VK_VERSION_1_0"]),
+    ("vkDestroyFence", &["VK_VERSION_1_0"]),
+    ("vkDestroyFramebuffer", &["VK_VERSION_1_0"]),
+    ("vkDestroyImage", &["VK_VERSION_1_0"]),
+    ("vkDestroyImageView", &["VK_VERSION_1_0"]),
+    (
+        "vkDestroyIndirectCommandsLayoutNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    ("vkDestroyInstance", &["VK_VERSION_1_0"]),
+    ("vkDestroyPipeline", &["VK_VERSION_1_0"]),
+    ("vkDestroyPipelineCache", &["VK_VERSION_1_0"]),
+    ("vkDestroyPipelineLayout", &["VK_VERSION_1_0"]),
+    ("vkDestroyPrivateDataSlotEXT", &["VK_EXT_private_data"]),
+    ("vkDestroyQueryPool", &["VK_VERSION_1_0"]),
+    ("vkDestroyRenderPass", &["VK_VERSION_1_0"]),
+    ("vkDestroySampler", &["VK_VERSION_1_0"]),
+    ("vkDestroySamplerYcbcrConversion", &["VK_VERSION_1_1"]),
+    (
+        "vkDestroySamplerYcbcrConversionKHR",
+        &["VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    ("vkDestroySemaphore", &["VK_VERSION_1_0"]),
+    ("vkDestroyShaderModule", &["VK_VERSION_1_0"]),
+    ("vkDestroySurfaceKHR", &["VK_KHR_surface"]),
+    ("vkDestroySwapchainKHR", &["VK_KHR_swapchain"]),
+    ("vkDestroyValidationCacheEXT", &["VK_EXT_validation_cache"]),
+    ("vkDeviceWaitIdle", &["VK_VERSION_1_0"]),
+    ("vkDisplayPowerControlEXT", &["VK_EXT_display_control"]),
+    ("vkEndCommandBuffer", &["VK_VERSION_1_0"]),
+    ("vkEnumerateDeviceExtensionProperties", &["VK_VERSION_1_0"]),
+    ("vkEnumerateDeviceLayerProperties", &["VK_VERSION_1_0"]),
+    (
+        "vkEnumerateInstanceExtensionProperties",
+        &["VK_VERSION_1_0"],
+    ),
+    ("vkEnumerateInstanceLayerProperties", &["VK_VERSION_1_0"]),
+    ("vkEnumerateInstanceVersion", &["VK_VERSION_1_1"]),
+    ("vkEnumeratePhysicalDeviceGroups", &["VK_VERSION_1_1"]),
+    (
+        "vkEnumeratePhysicalDeviceGroupsKHR",
+        &["VK_KHR_device_group_creation"],
+    ),
+    (
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        &["VK_KHR_performance_query"],
+    ),
+    ("vkEnumeratePhysicalDevices", &["VK_VERSION_1_0"]),
+    ("vkFlushMappedMemoryRanges", &["VK_VERSION_1_0"]),
+    ("vkFreeCommandBuffers", &["VK_VERSION_1_0"]),
+    ("vkFreeDescriptorSets", &["VK_VERSION_1_0"]),
+    ("vkFreeMemory", &["VK_VERSION_1_0"]),
+    (
+        "vkGetAccelerationStructureBuildSizesKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    (
+        "vkGetAccelerationStructureDeviceAddressKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkGetAccelerationStructureHandleNV", &["VK_NV_ray_tracing"]),
+    (
+        "vkGetAccelerationStructureMemoryRequirementsNV",
+        &["VK_NV_ray_tracing"],
+    ),
+    (
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        &["VK_ANDROID_external_memory_android_hardware_buffer"],
+    ),
+    ("vkGetBufferDeviceAddress", &["VK_VERSION_1_2"]),
+    (
+        "vkGetBufferDeviceAddressEXT",
+        &["VK_EXT_buffer_device_address"],
+    ),
+    (
+        "vkGetBufferDeviceAddressKHR",
+        &["VK_KHR_buffer_device_address"],
+    ),
+    ("vkGetBufferMemoryRequirements", &["VK_VERSION_1_0"]),
+    ("vkGetBufferMemoryRequirements2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetBufferMemoryRequirements2KHR",
+        &["VK_KHR_get_memory_requirements2"],
+    ),
+    ("vkGetBufferOpaqueCaptureAddress", &["VK_VERSION_1_2"]),
+    (
+        "vkGetBufferOpaqueCaptureAddressKHR",
+        &["VK_KHR_buffer_device_address"],
+    ),
+    (
+        "vkGetCalibratedTimestampsEXT",
+        &["VK_EXT_calibrated_timestamps"],
+    ),
+    (
+        "vkGetDeferredOperationMaxConcurrencyKHR",
+        &["VK_KHR_deferred_host_operations"],
+    ),
+    (
+        "vkGetDeferredOperationResultKHR",
+        &["VK_KHR_deferred_host_operations"],
+    ),
+    ("vkGetDescriptorSetLayoutSupport", &["VK_VERSION_1_1"]),
+    (
+        "vkGetDescriptorSetLayoutSupportKHR",
+        &["VK_KHR_maintenance3"],
+    ),
+    (
+        "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+    ("vkGetDeviceGroupPeerMemoryFeatures", &["VK_VERSION_1_1"]),
+    (
+        "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+        &["VK_KHR_device_group"],
+    ),
+    (
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        &["VK_KHR_swapchain", "VK_KHR_device_group"],
+    ),
+    (
+        "vkGetDeviceGroupSurfacePresentModes2EXT",
+        &["VK_EXT_full_screen_exclusive"],
+    ),
+    (
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        &["VK_KHR_swapchain", "VK_KHR_device_group"],
+    ),
+    ("vkGetDeviceMemoryCommitment", &["VK_VERSION_1_0"]),
+    ("vkGetDeviceMemoryOpaqueCaptureAddress", &["VK_VERSION_1_2"]),
+    (
+        "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+        &["VK_KHR_buffer_device_address"],
+    ),
+    ("vkGetDeviceProcAddr", &["VK_VERSION_1_0"]),
+    ("vkGetDeviceQueue", &["VK_VERSION_1_0"]),
+    ("vkGetDeviceQueue2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetDisplayModeProperties2KHR",
+        &["VK_KHR_get_display_properties2"],
+    ),
+    ("vkGetDisplayModePropertiesKHR", &["VK_KHR_display"]),
+    (
+        "vkGetDisplayPlaneCapabilities2KHR",
+        &["VK_KHR_get_display_properties2"],
+    ),
+    ("vkGetDisplayPlaneCapabilitiesKHR", &["VK_KHR_display"]),
+    ("vkGetDisplayPlaneSupportedDisplaysKHR", &["VK_KHR_display"]),
+    ("vkGetEventStatus", &["VK_VERSION_1_0"]),
+    ("vkGetFenceFdKHR", &["VK_KHR_external_fence_fd"]),
+    ("vkGetFenceStatus", &["VK_VERSION_1_0"]),
+    ("vkGetFenceWin32HandleKHR", &["VK_KHR_external_fence_win32"]),
+    (
+        "vkGetGeneratedCommandsMemoryRequirementsNV",
+        &["VK_NV_device_generated_commands"],
+    ),
+    (
+        "vkGetImageDrmFormatModifierPropertiesEXT",
+        &["VK_EXT_image_drm_format_modifier"],
+    ),
+    ("vkGetImageMemoryRequirements", &["VK_VERSION_1_0"]),
+    ("vkGetImageMemoryRequirements2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetImageMemoryRequirements2KHR",
+        &["VK_KHR_get_memory_requirements2"],
+    ),
+    ("vkGetImageSparseMemoryRequirements", &["VK_VERSION_1_0"]),
+    ("vkGetImageSparseMemoryRequirements2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetImageSparseMemoryRequirements2KHR",
+        &["VK_KHR_get_memory_requirements2"],
+    ),
+    ("vkGetImageSubresourceLayout", &["VK_VERSION_1_0"]),
+    ("vkGetImageViewAddressNVX", &["VK_NVX_image_view_handle"]),
+    ("vkGetImageViewHandleNVX", &["VK_NVX_image_view_handle"]),
+    ("vkGetInstanceProcAddr", &["VK_VERSION_1_0"]),
+    (
+        "vkGetMemoryAndroidHardwareBufferANDROID",
+        &["VK_ANDROID_external_memory_android_hardware_buffer"],
+    ),
+    ("vkGetMemoryFdKHR", &["VK_KHR_external_memory_fd"]),
+    ("vkGetMemoryFdPropertiesKHR", &["VK_KHR_external_memory_fd"]),
+    (
+        "vkGetMemoryHostPointerPropertiesEXT",
+        &["VK_EXT_external_memory_host"],
+    ),
+    (
+        "vkGetMemoryWin32HandleKHR",
+        &["VK_KHR_external_memory_win32"],
+    ),
+    ("vkGetMemoryWin32HandleNV", &["VK_NV_external_memory_win32"]),
+    (
+        "vkGetMemoryWin32HandlePropertiesKHR",
+        &["VK_KHR_external_memory_win32"],
+    ),
+    (
+        "vkGetPastPresentationTimingGOOGLE",
+        &["VK_GOOGLE_display_timing"],
+    ),
+    (
+        "vkGetPerformanceParameterINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    (
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        &["VK_EXT_calibrated_timestamps"],
+    ),
+    (
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        &["VK_NV_cooperative_matrix"],
+    ),
+    (
+        "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        &["VK_EXT_directfb_surface"],
+    ),
+    (
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        &["VK_KHR_get_display_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        &["VK_KHR_display"],
+    ),
+    (
+        "vkGetPhysicalDeviceDisplayProperties2KHR",
+        &["VK_KHR_get_display_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        &["VK_KHR_display"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalBufferProperties",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalBufferPropertiesKHR",
+        &["VK_KHR_external_memory_capabilities"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalFenceProperties",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalFencePropertiesKHR",
+        &["VK_KHR_external_fence_capabilities"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        &["VK_NV_external_memory_capabilities"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR",
+        &["VK_KHR_external_semaphore_capabilities"],
+    ),
+    ("vkGetPhysicalDeviceFeatures", &["VK_VERSION_1_0"]),
+    ("vkGetPhysicalDeviceFeatures2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetPhysicalDeviceFeatures2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    ("vkGetPhysicalDeviceFormatProperties", &["VK_VERSION_1_0"]),
+    ("vkGetPhysicalDeviceFormatProperties2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetPhysicalDeviceFormatProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        &["VK_KHR_fragment_shading_rate"],
+    ),
+    (
+        "vkGetPhysicalDeviceImageFormatProperties",
+        &["VK_VERSION_1_0"],
+    ),
+    (
+        "vkGetPhysicalDeviceImageFormatProperties2",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    ("vkGetPhysicalDeviceMemoryProperties", &["VK_VERSION_1_0"]),
+    ("vkGetPhysicalDeviceMemoryProperties2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetPhysicalDeviceMemoryProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        &["VK_EXT_sample_locations"],
+    ),
+    (
+        "vkGetPhysicalDevicePresentRectanglesKHR",
+        &["VK_KHR_swapchain", "VK_KHR_device_group"],
+    ),
+    ("vkGetPhysicalDeviceProperties", &["VK_VERSION_1_0"]),
+    ("vkGetPhysicalDeviceProperties2", &["VK_VERSION_1_1"]),
+    (
+        "vkGetPhysicalDeviceProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        &["VK_KHR_performance_query"],
+    ),
+    (
+        "vkGetPhysicalDeviceQueueFamilyProperties",
+        &["VK_VERSION_1_0"],
+    ),
+    (
+        "vkGetPhysicalDeviceQueueFamilyProperties2",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceSparseImageFormatProperties",
+        &["VK_VERSION_1_0"],
+    ),
+    (
+        "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        &["VK_VERSION_1_1"],
+    ),
+    (
+        "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+        &["VK_KHR_get_physical_device_properties2"],
+    ),
+    (
+        "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        &["VK_NV_coverage_reduction_mode"],
+    ),
+    (
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        &["VK_EXT_display_surface_counter"],
+    ),
+    (
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        &["VK_KHR_get_surface_capabilities2"],
+    ),
+    (
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        &["VK_KHR_surface"],
+    ),
+    (
+        "vkGetPhysicalDeviceSurfaceFormats2KHR",
+        &["VK_KHR_get_surface_capabilities2"],
+    ),
+    ("vkGetPhysicalDeviceSurfaceFormatsKHR", &["VK_KHR_surface"]),
+    (
+        "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        &["VK_EXT_full_screen_exclusive"],
+    ),
+    (
+        "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        &["VK_KHR_surface"],
+    ),
+    ("vkGetPhysicalDeviceSurfaceSupportKHR", &["VK_KHR_surface"]),
+    (
+        "vkGetPhysicalDeviceToolPropertiesEXT",
+        &["VK_EXT_tooling_info"],
+    ),
+    (
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        &["VK_KHR_wayland_surface"],
+    ),
+    (
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        &["VK_KHR_win32_surface"],
+    ),
+    (
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        &["VK_KHR_xcb_surface"],
+    ),
+    (
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        &["VK_KHR_xlib_surface"],
+    ),
+    ("vkGetPipelineCacheData", &["VK_VERSION_1_0"]),
+    (
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        &["VK_KHR_pipeline_executable_properties"],
+    ),
+    (
+        "vkGetPipelineExecutablePropertiesKHR",
+        &["VK_KHR_pipeline_executable_properties"],
+    ),
+    (
+        "vkGetPipelineExecutableStatisticsKHR",
+        &["VK_KHR_pipeline_executable_properties"],
+    ),
+    ("vkGetPrivateDataEXT", &["VK_EXT_private_data"]),
+    ("vkGetQueryPoolResults", &["VK_VERSION_1_0"]),
+    (
+        "vkGetQueueCheckpointDataNV",
+        &["VK_NV_device_diagnostic_checkpoints"],
+    ),
+    (
+        "vkGetRandROutputDisplayEXT",
+        &["VK_EXT_acquire_xlib_display"],
+    ),
+    (
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    (
+        "vkGetRayTracingShaderGroupHandlesKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    (
+        "vkGetRayTracingShaderGroupHandlesNV",
+        &["VK_NV_ray_tracing"],
+    ),
+    (
+        "vkGetRayTracingShaderGroupStackSizeKHR",
+        &["VK_KHR_ray_tracing_pipeline"],
+    ),
+    (
+        "vkGetRefreshCycleDurationGOOGLE",
+        &["VK_GOOGLE_display_timing"],
+    ),
+    ("vkGetRenderAreaGranularity", &["VK_VERSION_1_0"]),
+    ("vkGetSemaphoreCounterValue", &["VK_VERSION_1_2"]),
+    (
+        "vkGetSemaphoreCounterValueKHR",
+        &["VK_KHR_timeline_semaphore"],
+    ),
+    ("vkGetSemaphoreFdKHR", &["VK_KHR_external_semaphore_fd"]),
+    (
+        "vkGetSemaphoreWin32HandleKHR",
+        &["VK_KHR_external_semaphore_win32"],
+    ),
+    ("vkGetShaderInfoAMD", &["VK_AMD_shader_info"]),
+    ("vkGetSwapchainCounterEXT", &["VK_EXT_display_control"]),
+    ("vkGetSwapchainImagesKHR", &["VK_KHR_swapchain"]),
+    (
+        "vkGetSwapchainStatusKHR",
+        &["VK_KHR_shared_presentable_image"],
+    ),
+    ("vkGetValidationCacheDataEXT", &["VK_EXT_validation_cache"]),
+    ("vkImportFenceFdKHR", &["VK_KHR_external_fence_fd"]),
+    (
+        "vkImportFenceWin32HandleKHR",
+        &["VK_KHR_external_fence_win32"],
+    ),
+    ("vkImportSemaphoreFdKHR", &["VK_KHR_external_semaphore_fd"]),
+    (
+        "vkImportSemaphoreWin32HandleKHR",
+        &["VK_KHR_external_semaphore_win32"],
+    ),
+    (
+        "vkInitializePerformanceApiINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    ("vkInvalidateMappedMemoryRanges", &["VK_VERSION_1_0"]),
+    ("vkMapMemory", &["VK_VERSION_1_0"]),
+    ("vkMergePipelineCaches", &["VK_VERSION_1_0"]),
+    ("vkMergeValidationCachesEXT", &["VK_EXT_validation_cache"]),
+    ("vkQueueBeginDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkQueueBindSparse", &["VK_VERSION_1_0"]),
+    ("vkQueueEndDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkQueueInsertDebugUtilsLabelEXT", &["VK_EXT_debug_utils"]),
+    ("vkQueuePresentKHR", &["VK_KHR_swapchain"]),
+    (
+        "vkQueueSetPerformanceConfigurationINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    ("vkQueueSubmit", &["VK_VERSION_1_0"]),
+    ("vkQueueWaitIdle", &["VK_VERSION_1_0"]),
+    ("vkRegisterDeviceEventEXT", &["VK_EXT_display_control"]),
+    ("vkRegisterDisplayEventEXT", &["VK_EXT_display_control"]),
+    ("vkReleaseDisplayEXT", &["VK_EXT_direct_mode_display"]),
+    (
+        "vkReleaseFullScreenExclusiveModeEXT",
+        &["VK_EXT_full_screen_exclusive"],
+    ),
+    (
+        "vkReleasePerformanceConfigurationINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    ("vkReleaseProfilingLockKHR", &["VK_KHR_performance_query"]),
+    ("vkResetCommandBuffer", &["VK_VERSION_1_0"]),
+    ("vkResetCommandPool", &["VK_VERSION_1_0"]),
+    ("vkResetDescriptorPool", &["VK_VERSION_1_0"]),
+    ("vkResetEvent", &["VK_VERSION_1_0"]),
+    ("vkResetFences", &["VK_VERSION_1_0"]),
+    ("vkResetQueryPool", &["VK_VERSION_1_2"]),
+    ("vkResetQueryPoolEXT", &["VK_EXT_host_query_reset"]),
+    ("vkSetDebugUtilsObjectNameEXT", &["VK_EXT_debug_utils"]),
+    ("vkSetDebugUtilsObjectTagEXT", &["VK_EXT_debug_utils"]),
+    ("vkSetEvent", &["VK_VERSION_1_0"]),
+    ("vkSetHdrMetadataEXT", &["VK_EXT_hdr_metadata"]),
+    ("vkSetLocalDimmingAMD", &["VK_AMD_display_native_hdr"]),
+    ("vkSetPrivateDataEXT", &["VK_EXT_private_data"]),
+    ("vkSignalSemaphore", &["VK_VERSION_1_2"]),
+    ("vkSignalSemaphoreKHR", &["VK_KHR_timeline_semaphore"]),
+    ("vkSubmitDebugUtilsMessageEXT", &["VK_EXT_debug_utils"]),
+    ("vkTrimCommandPool", &["VK_VERSION_1_1"]),
+    ("vkTrimCommandPoolKHR", &["VK_KHR_maintenance1"]),
+    (
+        "vkUninitializePerformanceApiINTEL",
+        &["VK_INTEL_performance_query"],
+    ),
+    ("vkUnmapMemory", &["VK_VERSION_1_0"]),
+    ("vkUpdateDescriptorSetWithTemplate", &["VK_VERSION_1_1"]),
+    (
+        "vkUpdateDescriptorSetWithTemplateKHR",
+        &["VK_KHR_descriptor_update_template"],
+    ),
+    ("vkUpdateDescriptorSets", &["VK_VERSION_1_0"]),
+    ("vkWaitForFences", &["VK_VERSION_1_0"]),
+    ("vkWaitSemaphores", &["VK_VERSION_1_2"]),
+    ("vkWaitSemaphoresKHR", &["VK_KHR_timeline_semaphore"]),
+    (
+        "vkWriteAccelerationStructuresPropertiesKHR",
+        &["VK_KHR_acceleration_structure"],
+    ),
+];
+#[doc = r" The structs that a struct can be chained to with `p_next`, e.g."]
+#[doc = r#" `("VkPhysicalDeviceVulkan12Features", &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"])`."#]
+#[doc = r" Sorted by struct name."]
+pub const STRUCT_EXTENDS: &[(&str, &[&str])] = &[
+    (
+        "VkAndroidHardwareBufferFormatPropertiesANDROID",
+        &["VkAndroidHardwareBufferPropertiesANDROID"],
+    ),
+    (
+        "VkAndroidHardwareBufferUsageANDROID",
+        &["VkImageFormatProperties2"],
+    ),
+    (
+        "VkAttachmentDescriptionStencilLayout",
+        &["VkAttachmentDescription2"],
+    ),
+    (
+        "VkAttachmentReferenceStencilLayout",
+        &["VkAttachmentReference2"],
+    ),
+    (
+        "VkBindBufferMemoryDeviceGroupInfo",
+        &["VkBindBufferMemoryInfo"],
+    ),
+    (
+        "VkBindImageMemoryDeviceGroupInfo",
+        &["VkBindImageMemoryInfo"],
+    ),
+    (
+        "VkBindImageMemorySwapchainInfoKHR",
+        &["VkBindImageMemoryInfo"],
+    ),
+    ("VkBindImagePlaneMemoryInfo", &["VkBindImageMemoryInfo"]),
+    (
+        "VkBufferDeviceAddressCreateInfoEXT",
+        &["VkBufferCreateInfo"],
+    ),
+    (
+        "VkBufferOpaqueCaptureAddressCreateInfo",
+        &["VkBufferCreateInfo"],
+    ),
+    (
+        "VkCommandBufferInheritanceConditionalRenderingInfoEXT",
+        &["VkCommandBufferInheritanceInfo"],
+    ),
+    (
+        "VkCommandBufferInheritanceRenderPassTransformInfoQCOM",
+        &["VkCommandBufferInheritanceInfo"],
+    ),
+    (
+        "VkCopyCommandTransformInfoQCOM",
+        &["VkBufferImageCopy2KHR", "VkImageBlit2KHR"],
+    ),
+    ("VkD3D12FenceSubmitInfoKHR", &["VkSubmitInfo"]),
+    (
+        "VkDebugReportCallbackCreateInfoEXT",
+        &["VkInstanceCreateInfo"],
+    ),
+    (
+        "VkDebugUtilsMessengerCreateInfoEXT",
+        &["VkInstanceCreateInfo"],
+    ),
+    (
+        "VkDedicatedAllocationBufferCreateInfoNV",
+        &["VkBufferCreateInfo"],
+    ),
+    (
+        "VkDedicatedAllocationImageCreateInfoNV",
+        &["VkImageCreateInfo"],
+    ),
+    (
+        "VkDedicatedAllocationMemoryAllocateInfoNV",
+        &["VkMemoryAllocateInfo"],
+    ),
+    (
+        "VkDescriptorPoolInlineUniformBlockCreateInfoEXT",
+        &["VkDescriptorPoolCreateInfo"],
+    ),
+    (
+        "VkDescriptorSetLayoutBindingFlagsCreateInfo",
+        &["VkDescriptorSetLayoutCreateInfo"],
+    ),
+    (
+        "VkDescriptorSetVariableDescriptorCountAllocateInfo",
+        &["VkDescriptorSetAllocateInfo"],
+    ),
+    (
+        "VkDescriptorSetVariableDescriptorCountLayoutSupport",
+        &["VkDescriptorSetLayoutSupport"],
+    ),
+    (
+        "VkDeviceDeviceMemoryReportCreateInfoEXT",
+        &["VkDeviceCreateInfo"],
+    ),
+    (
+        "VkDeviceDiagnosticsConfigCreateInfoNV",
+        &["VkDeviceCreateInfo"],
+    ),
+    ("VkDeviceGroupBindSparseInfo", &["VkBindSparseInfo"]),
+    (
+        "VkDeviceGroupCommandBufferBeginInfo",
+        &["VkCommandBufferBeginInfo"],
+    ),
+    ("VkDeviceGroupDeviceCreateInfo", &["VkDeviceCreateInfo"]),
+    ("VkDeviceGroupPresentInfoKHR", &["VkPresentInfoKHR"]),
+    (
+        "VkDeviceGroupRenderPassBeginInfo",
+        &["VkRenderPassBeginInfo"],
+    ),
+    ("VkDeviceGroupSubmitInfo", &["VkSubmitInfo"]),
+    (
+        "VkDeviceGroupSwapchainCreateInfoKHR",
+        &["VkSwapchainCreateInfoKHR"],
+    ),
+    (
+        "VkDeviceMemoryOverallocationCreateInfoAMD",
+        &["VkDeviceCreateInfo"],
+    ),
+    ("VkDevicePrivateDataCreateInfoEXT", &["VkDeviceCreateInfo"]),
+    (
+        "VkDeviceQueueGlobalPriorityCreateInfoEXT",
+        &["VkDeviceQueueCreateInfo"],
+    ),
+    (
+        "VkDisplayNativeHdrSurfaceCapabilitiesAMD",
+        &["VkSurfaceCapabilities2KHR"],
+    ),
+    ("VkDisplayPresentInfoKHR", &["VkPresentInfoKHR"]),
+    (
+        "VkDrmFormatModifierPropertiesListEXT",
+        &["VkFormatProperties2"],
+    ),
+    ("VkExportFenceCreateInfo", &["VkFenceCreateInfo"]),
+    ("VkExportFenceWin32HandleInfoKHR", &["VkFenceCreateInfo"]),
+    ("VkExportMemoryAllocateInfo", &["VkMemoryAllocateInfo"]),
+    ("VkExportMemoryAllocateInfoNV", &["VkMemoryAllocateInfo"]),
+    (
+        "VkExportMemoryWin32HandleInfoKHR",
+        &["VkMemoryAllocateInfo"],
+    ),
+    ("VkExportMemoryWin32HandleInfoNV", &["VkMemoryAllocateInfo"]),
+    ("VkExportSemaphoreCreateInfo", &["VkSemaphoreCreateInfo"]),
+    (
+        "VkExportSemaphoreWin32HandleInfoKHR",
+        &["VkSemaphoreCreateInfo"],
+    ),
+    (
+        "VkExternalFormatANDROID",
+        &["VkImageCreateInfo", "VkSamplerYcbcrConversionCreateInfo"],
+    ),
+    (
+        "VkExternalImageFormatProperties",
+        &["VkImageFormatProperties2"],
+    ),
+    ("VkExternalMemoryBufferCreateInfo", &["VkBufferCreateInfo"]),
+    ("VkExternalMemoryImageCreateInfo", &["VkImageCreateInfo"]),
+    ("VkExternalMemoryImageCreateInfoNV", &["VkImageCreateInfo"]),
+    (
+        "VkFilterCubicImageViewImageFormatPropertiesEXT",
+        &["VkImageFormatProperties2"],
+    ),
+    (
+        "VkFragmentShadingRateAttachmentInfoKHR",
+        &["VkSubpassDescription2"],
+    ),
+    (
+        "VkFramebufferAttachmentsCreateInfo",
+        &["VkFramebufferCreateInfo"],
+    ),
+    (
+        "VkGraphicsPipelineShaderGroupsCreateInfoNV",
+        &["VkGraphicsPipelineCreateInfo"],
+    ),
+    (
+        "VkImageDrmFormatModifierExplicitCreateInfoEXT",
+        &["VkImageCreateInfo"],
+    ),
+    (
+        "VkImageDrmFormatModifierListCreateInfoEXT",
+        &["VkImageCreateInfo"],
+    ),
+    (
+        "VkImageFormatListCreateInfo",
+        &[
+            "VkImageCreateInfo",
+            "VkSwapchainCreateInfoKHR",
+            "VkPhysicalDeviceImageFormatInfo2",
+        ],
+    ),
+    (
+        "VkImagePlaneMemoryRequirementsInfo",
+        &["VkImageMemoryRequirementsInfo2"],
+    ),
+    (
+        "VkImageStencilUsageCreateInfo",
+        &["VkImageCreateInfo", "VkPhysicalDeviceImageFormatInfo2"],
+    ),
+    ("VkImageSwapchainCreateInfoKHR", &["VkImageCreateInfo"]),
+    ("VkImageViewASTCDecodeModeEXT", &["VkImageViewCreateInfo"]),
+    ("VkImageViewUsageCreateInfo", &["VkImageViewCreateInfo"]),
+    (
+        "VkImportAndroidHardwareBufferInfoANDROID",
+        &["VkMemoryAllocateInfo"],
+    ),
+    ("VkImportMemoryFdInfoKHR", &["VkMemoryAllocateInfo"]),
+    (
+        "VkImportMemoryHostPointerInfoEXT",
+        &["VkMemoryAllocateInfo"],
+    ),
+    (
+        "VkImportMemoryWin32HandleInfoKHR",
+        &["VkMemoryAllocateInfo"],
+    ),
+    ("VkImportMemoryWin32HandleInfoNV", &["VkMemoryAllocateInfo"]),
+    ("VkMemoryAllocateFlagsInfo", &["VkMemoryAllocateInfo"]),
+    ("VkMemoryDedicatedAllocateInfo", &["VkMemoryAllocateInfo"]),
+    ("VkMemoryDedicatedRequirements", &["VkMemoryRequirements2"]),
+    (
+        "VkMemoryOpaqueCaptureAddressAllocateInfo",
+        &["VkMemoryAllocateInfo"],
+    ),
+    ("VkMemoryPriorityAllocateInfoEXT", &["VkMemoryAllocateInfo"]),
+    ("VkPerformanceQuerySubmitInfoKHR", &["VkSubmitInfo"]),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevice4444FormatsFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevice8BitStorageFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceASTCDecodeFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceAccelerationStructureFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceAccelerationStructurePropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceBufferDeviceAddressFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceCoherentMemoryFeaturesAMD",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceConditionalRenderingFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceConservativeRasterizationPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceCornerSampledImageFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceCoverageReductionModeFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceCustomBorderColorFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDepthClipEnableFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDepthStencilResolveProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDiagnosticsConfigFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceDriverProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceExclusiveScissorFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceExternalImageFormatInfo",
+        &["VkPhysicalDeviceImageFormatInfo2"],
+    ),
+    (
+        "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    ("VkPhysicalDeviceFeatures2", &["VkDeviceCreateInfo"]),
+    (
+        "VkPhysicalDeviceFloatControlsProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShaderBarycentricFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRatePropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceHostQueryResetFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceIDProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceImageDrmFormatModifierInfoEXT",
+        &["VkPhysicalDeviceImageFormatInfo2"],
+    ),
+    (
+        "VkPhysicalDeviceImageRobustnessFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceImageViewImageFormatInfoEXT",
+        &["VkPhysicalDeviceImageFormatInfo2"],
+    ),
+    (
+        "VkPhysicalDeviceImagelessFramebufferFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceIndexTypeUint8FeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceMaintenance3Properties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceMemoryBudgetPropertiesEXT",
+        &["VkPhysicalDeviceMemoryProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceMemoryPriorityFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceMultiviewFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceMultiviewProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePerformanceQueryFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePipelineCreationCacheControlFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevicePointClippingProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePortabilitySubsetFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePrivateDataFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceProtectedMemoryFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceProtectedMemoryProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDevicePushDescriptorPropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceRayQueryFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceRobustness2FeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceRobustness2PropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceScalarBlockLayoutFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderAtomicInt64Features",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderClockFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderCoreProperties2AMD",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceShaderCorePropertiesAMD",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderDrawParametersFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderFloat16Int8Features",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShaderTerminateInvocationFeaturesKHR",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShadingRateImageFeaturesNV",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceShadingRateImagePropertiesNV",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceSubgroupProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceTextureCompressionASTCHDRFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreProperties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceUniformBufferStandardLayoutFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceVariablePointersFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceVulkan11Features",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceVulkan11Properties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceVulkan12Features",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceVulkan12Properties",
+        &["VkPhysicalDeviceProperties2"],
+    ),
+    (
+        "VkPhysicalDeviceVulkanMemoryModelFeatures",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT",
+        &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"],
+    ),
+    (
+        "VkPipelineColorBlendAdvancedStateCreateInfoEXT",
+        &["VkPipelineColorBlendStateCreateInfo"],
+    ),
+    (
+        "VkPipelineCompilerControlCreateInfoAMD",
+        &[
+            "VkGraphicsPipelineCreateInfo",
+            "VkComputePipelineCreateInfo",
+        ],
+    ),
+    (
+        "VkPipelineCoverageModulationStateCreateInfoNV",
+        &["VkPipelineMultisampleStateCreateInfo"],
+    ),
+    (
+        "VkPipelineCoverageReductionStateCreateInfoNV",
+        &["VkPipelineMultisampleStateCreateInfo"],
+    ),
+    (
+        "VkPipelineCoverageToColorStateCreateInfoNV",
+        &["VkPipelineMultisampleStateCreateInfo"],
+    ),
+    (
+        "VkPipelineCreationFeedbackCreateInfoEXT",
+        &[
+            "VkGraphicsPipelineCreateInfo",
+            "VkComputePipelineCreateInfo",
+            "VkRayTracingPipelineCreateInfoNV",
+            "VkRayTracingPipelineCreateInfoKHR",
+        ],
+    ),
+    (
+        "VkPipelineDiscardRectangleStateCreateInfoEXT",
+        &["VkGraphicsPipelineCreateInfo"],
+    ),
+    (
+        "VkPipelineFragmentShadingRateEnumStateCreateInfoNV",
+        &["VkGraphicsPipelineCreateInfo"],
+    ),
+    (
+        "VkPipelineFragmentShadingRateStateCreateInfoKHR",
+        &["VkGraphicsPipelineCreateInfo"],
+    ),
+    (
+        "VkPipelineRasterizationConservativeStateCreateInfoEXT",
+        &["VkPipelineRasterizationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineRasterizationDepthClipStateCreateInfoEXT",
+        &["VkPipelineRasterizationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineRasterizationLineStateCreateInfoEXT",
+        &["VkPipelineRasterizationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineRasterizationStateRasterizationOrderAMD",
+        &["VkPipelineRasterizationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineRasterizationStateStreamCreateInfoEXT",
+        &["VkPipelineRasterizationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineRepresentativeFragmentTestStateCreateInfoNV",
+        &["VkGraphicsPipelineCreateInfo"],
+    ),
+    (
+        "VkPipelineSampleLocationsStateCreateInfoEXT",
+        &["VkPipelineMultisampleStateCreateInfo"],
+    ),
+    (
+        "VkPipelineShaderStageRequiredSubgroupSizeCreateInfoEXT",
+        &["VkPipelineShaderStageCreateInfo"],
+    ),
+    (
+        "VkPipelineTessellationDomainOriginStateCreateInfo",
+        &["VkPipelineTessellationStateCreateInfo"],
+    ),
+    (
+        "VkPipelineVertexInputDivisorStateCreateInfoEXT",
+        &["VkPipelineVertexInputStateCreateInfo"],
+    ),
+    (
+        "VkPipelineViewportCoarseSampleOrderStateCreateInfoNV",
+        &["VkPipelineViewportStateCreateInfo"],
+    ),
+    (
+        "VkPipelineViewportExclusiveScissorStateCreateInfoNV",
+        &["VkPipelineViewportStateCreateInfo"],
+    ),
+    (
+        "VkPipelineViewportShadingRateImageStateCreateInfoNV",
+        &["VkPipelineViewportStateCreateInfo"],
+    ),
+    (
+        "VkPipelineViewportSwizzleStateCreateInfoNV",
+        &["VkPipelineViewportStateCreateInfo"],
+    ),
+    (
+        "VkPipelineViewportWScalingStateCreateInfoNV",
+        &["VkPipelineViewportStateCreateInfo"],
+    ),
+    ("VkPresentFrameTokenGGP", &["VkPresentInfoKHR"]),
+    ("VkPresentRegionsKHR", &["VkPresentInfoKHR"]),
+    ("VkPresentTimesInfoGOOGLE", &["VkPresentInfoKHR"]),
+    ("VkProtectedSubmitInfo", &["VkSubmitInfo"]),
+    (
+        "VkQueryPoolPerformanceCreateInfoKHR",
+        &["VkQueryPoolCreateInfo"],
+    ),
+    (
+        "VkQueryPoolPerformanceQueryCreateInfoINTEL",
+        &["VkQueryPoolCreateInfo"],
+    ),
+    (
+        "VkQueueFamilyCheckpointPropertiesNV",
+        &["VkQueueFamilyProperties2"],
+    ),
+    (
+        "VkRenderPassAttachmentBeginInfo",
+        &["VkRenderPassBeginInfo"],
+    ),
+    (
+        "VkRenderPassFragmentDensityMapCreateInfoEXT",
+        &["VkRenderPassCreateInfo", "VkRenderPassCreateInfo2"],
+    ),
+    (
+        "VkRenderPassInputAttachmentAspectCreateInfo",
+        &["VkRenderPassCreateInfo"],
+    ),
+    (
+        "VkRenderPassMultiviewCreateInfo",
+        &["VkRenderPassCreateInfo"],
+    ),
+    (
+        "VkRenderPassSampleLocationsBeginInfoEXT",
+        &["VkRenderPassBeginInfo"],
+    ),
+    (
+        "VkRenderPassTransformBeginInfoQCOM",
+        &["VkRenderPassBeginInfo"],
+    ),
+    ("VkSampleLocationsInfoEXT", &["VkImageMemoryBarrier"]),
+    (
+        "VkSamplerCustomBorderColorCreateInfoEXT",
+        &["VkSamplerCreateInfo"],
+    ),
+    ("VkSamplerReductionModeCreateInfo", &["VkSamplerCreateInfo"]),
+    (
+        "VkSamplerYcbcrConversionImageFormatProperties",
+        &["VkImageFormatProperties2"],
+    ),
+    (
+        "VkSamplerYcbcrConversionInfo",
+        &["VkSamplerCreateInfo", "VkImageViewCreateInfo"],
+    ),
+    (
+        "VkSemaphoreTypeCreateInfo",
+        &[
+            "VkSemaphoreCreateInfo",
+            "VkPhysicalDeviceExternalSemaphoreInfo",
+        ],
+    ),
+    (
+        "VkShaderModuleValidationCacheCreateInfoEXT",
+        &["VkShaderModuleCreateInfo"],
+    ),
+    (
+        "VkSharedPresentSurfaceCapabilitiesKHR",
+        &["VkSurfaceCapabilities2KHR"],
+    ),
+    (
+        "VkSubpassDescriptionDepthStencilResolve",
+        &["VkSubpassDescription2"],
+    ),
+    (
+        "VkSurfaceCapabilitiesFullScreenExclusiveEXT",
+        &["VkSurfaceCapabilities2KHR"],
+    ),
+    (
+        "VkSurfaceFullScreenExclusiveInfoEXT",
+        &[
+            "VkPhysicalDeviceSurfaceInfo2KHR",
+            "VkSwapchainCreateInfoKHR",
+        ],
+    ),
+    (
+        "VkSurfaceFullScreenExclusiveWin32InfoEXT",
+        &[
+            "VkPhysicalDeviceSurfaceInfo2KHR",
+            "VkSwapchainCreateInfoKHR",
+        ],
+    ),
+    (
+        "VkSurfaceProtectedCapabilitiesKHR",
+        &["VkSurfaceCapabilities2KHR"],
+    ),
+    (
+        "VkSwapchainCounterCreateInfoEXT",
+        &["VkSwapchainCreateInfoKHR"],
+    ),
+    (
+        "VkSwapchainDisplayNativeHdrCreateInfoAMD",
+        &["VkSwapchainCreateInfoKHR"],
+    ),
+    (
+        "VkTextureLODGatherFormatPropertiesAMD",
+        &["VkImageFormatProperties2"],
+    ),
+    (
+        "VkTimelineSemaphoreSubmitInfo",
+        &["VkSubmitInfo", "VkBindSparseInfo"],
+    ),
+    ("VkValidationFeaturesEXT", &["VkInstanceCreateInfo"]),
+    ("VkValidationFlagsEXT", &["VkInstanceCreateInfo"]),
+    ("VkWin32KeyedMutexAcquireReleaseInfoKHR", &["VkSubmitInfo"]),
+    ("VkWin32KeyedMutexAcquireReleaseInfoNV", &["VkSubmitInfo"]),
+    (
+        "VkWriteDescriptorSetAccelerationStructureKHR",
+        &["VkWriteDescriptorSet"],
+    ),
+    (
+        "VkWriteDescriptorSetAccelerationStructureNV",
+        &["VkWriteDescriptorSet"],
+    ),
+    (
+        "VkWriteDescriptorSetInlineUniformBlockEXT",
+        &["VkWriteDescriptorSet"],
+    ),
+];
+#[doc = r" The `s_type` of the structs that have a fixed one, e.g."]
+#[doc = r#" `("VkDeviceCreateInfo", StructureType::DEVICE_CREATE_INFO)`. Sorted by struct name."#]
+pub const STRUCTURE_TYPES: &[(&str, StructureType)] = &[
+    (
+        "VkAccelerationStructureBuildGeometryInfoKHR",
+        StructureType::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR,
+    ),
+    (
+        "VkAccelerationStructureBuildSizesInfoKHR",
+        StructureType::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR,
+    ),
+    (
+        "VkAccelerationStructureCreateInfoKHR",
+        StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkAccelerationStructureCreateInfoNV",
+        StructureType::ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+    ),
+    (
+        "VkAccelerationStructureDeviceAddressInfoKHR",
+        StructureType::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR,
+    ),
+    (
+        "VkAccelerationStructureGeometryAabbsDataKHR",
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR,
+    ),
+    (
+        "VkAccelerationStructureGeometryInstancesDataKHR",
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR,
+    ),
+    (
+        "VkAccelerationStructureGeometryKHR",
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_KHR,
+    ),
+    (
+        "VkAccelerationStructureGeometryTrianglesDataKHR",
+        StructureType::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR,
+    ),
+    (
+        "VkAccelerationStructureInfoNV",
+        StructureType::ACCELERATION_STRUCTURE_INFO_NV,
+    ),
+    (
+        "VkAccelerationStructureMemoryRequirementsInfoNV",
+        StructureType::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV,
+    ),
+    (
+        "VkAccelerationStructureVersionInfoKHR",
+        StructureType::ACCELERATION_STRUCTURE_VERSION_INFO_KHR,
+    ),
+    (
+        "VkAcquireNextImageInfoKHR",
+        StructureType::ACQUIRE_NEXT_IMAGE_INFO_KHR,
+    ),
+    (
+        "VkAcquireProfilingLockInfoKHR",
+        StructureType::ACQUIRE_PROFILING_LOCK_INFO_KHR,
+    ),
+    (
+        "VkAndroidHardwareBufferFormatPropertiesANDROID",
+        StructureType::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID,
+    ),
+    (
+        "VkAndroidHardwareBufferPropertiesANDROID",
+        StructureType::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+    ),
+    (
+        "VkAndroidHardwareBufferUsageANDROID",
+        StructureType::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID,
+    ),
+    (
+        "VkAndroidSurfaceCreateInfoKHR",
+        StructureType::ANDROID_SURFACE_CREATE_INFO_KHR,
+    ),
+    ("VkApplicationInfo", StructureType::APPLICATION_INFO),
+    (
+        "VkAttachmentDescription2",
+        StructureType::ATTACHMENT_DESCRIPTION_2,
+    ),
+    (
+        "VkAttachmentDescriptionStencilLayout",
+        StructureType::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT,
+    ),
+    (
+        "VkAttachmentReference2",
+        StructureType::ATTACHMENT_REFERENCE_2,
+    ),
+    (
+        "VkAttachmentReferenceStencilLayout",
+        StructureType::ATTACHMENT_REFERENCE_STENCIL_LAYOUT,
+    ),
+    (
+        "VkBindAccelerationStructureMemoryInfoNV",
+        StructureType::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV,
+    ),
+    (
+        "VkBindBufferMemoryDeviceGroupInfo",
+        StructureType::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO,
+    ),
+    (
+        "VkBindBufferMemoryInfo",
+        StructureType::BIND_BUFFER_MEMORY_INFO,
+    ),
+    (
+        "VkBindImageMemoryDeviceGroupInfo",
+        StructureType::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO,
+    ),
+    (
+        "VkBindImageMemoryInfo",
+        StructureType::BIND_IMAGE_MEMORY_INFO,
+    ),
+    (
+        "VkBindImageMemorySwapchainInfoKHR",
+        StructureType::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR,
+    ),
+    (
+        "VkBindImagePlaneMemoryInfo",
+        StructureType::BIND_IMAGE_PLANE_MEMORY_INFO,
+    ),
+    ("VkBindSparseInfo", StructureType::BIND_SPARSE_INFO),
+    ("VkBlitImageInfo2KHR", StructureType::BLIT_IMAGE_INFO_2_KHR),
+    ("VkBufferCopy2KHR", StructureType::BUFFER_COPY_2_KHR),
+    ("VkBufferCreateInfo", StructureType::BUFFER_CREATE_INFO),
+    (
+        "VkBufferDeviceAddressCreateInfoEXT",
+        StructureType::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT,
+    ),
+    (
+        "VkBufferDeviceAddressInfo",
+        StructureType::BUFFER_DEVICE_ADDRESS_INFO,
+    ),
+    (
+        "VkBufferImageCopy2KHR",
+        StructureType::BUFFER_IMAGE_COPY_2_KHR,
+    ),
+    (
+        "VkBufferMemoryBarrier",
+        StructureType::BUFFER_MEMORY_BARRIER,
+    ),
+    (
+        "VkBufferMemoryRequirementsInfo2",
+        StructureType::BUFFER_MEMORY_REQUIREMENTS_INFO_2,
+    ),
+    (
+        "VkBufferOpaqueCaptureAddressCreateInfo",
+        StructureType::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO,
+    ),
+    (
+        "VkBufferViewCreateInfo",
+        StructureType::BUFFER_VIEW_CREATE_INFO,
+    ),
+    (
+        "VkCalibratedTimestampInfoEXT",
+        StructureType::CALIBRATED_TIMESTAMP_INFO_EXT,
+    ),
+    ("VkCheckpointDataNV", StructureType::CHECKPOINT_DATA_NV),
+    (
+        "VkCommandBufferAllocateInfo",
+        StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
+    ),
+    (
+        "VkCommandBufferBeginInfo",
+        StructureType::COMMAND_BUFFER_BEGIN_INFO,
+    ),
+    (
+        "VkCommandBufferInheritanceConditionalRenderingInfoEXT",
+        StructureType::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT,
+    ),
+    (
+        "VkCommandBufferInheritanceInfo",
+        StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+    ),
+    (
+        "VkCommandBufferInheritanceRenderPassTransformInfoQCOM",
+        StructureType::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM,
+    ),
+    (
+        "VkCommandPoolCreateInfo",
+        StructureType::COMMAND_POOL_CREATE_INFO,
+    ),
+    (
+        "VkComputePipelineCreateInfo",
+        StructureType::COMPUTE_PIPELINE_CREATE_INFO,
+    ),
+    (
+        "VkConditionalRenderingBeginInfoEXT",
+        StructureType::CONDITIONAL_RENDERING_BEGIN_INFO_EXT,
+    ),
+    (
+        "VkCooperativeMatrixPropertiesNV",
+        StructureType::COOPERATIVE_MATRIX_PROPERTIES_NV,
+    ),
+    (
+        "VkCopyAccelerationStructureInfoKHR",
+        StructureType::COPY_ACCELERATION_STRUCTURE_INFO_KHR,
+    ),
+    (
+        "VkCopyAccelerationStructureToMemoryInfoKHR",
+        StructureType::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR,
+    ),
+    (
+        "VkCopyBufferInfo2KHR",
+        StructureType::COPY_BUFFER_INFO_2_KHR,
+    ),
+    (
+        "VkCopyBufferToImageInfo2KHR",
+        StructureType::COPY_BUFFER_TO_IMAGE_INFO_2_KHR,
+    ),
+    (
+        "VkCopyCommandTransformInfoQCOM",
+        StructureType::COPY_COMMAND_TRANSFORM_INFO_QCOM,
+    ),
+    ("VkCopyDescriptorSet", StructureType::COPY_DESCRIPTOR_SET),
+    ("VkCopyImageInfo2KHR", StructureType::COPY_IMAGE_INFO_2_KHR),
+    (
+        "VkCopyImageToBufferInfo2KHR",
+        StructureType::COPY_IMAGE_TO_BUFFER_INFO_2_KHR,
+    ),
+    (
+        "VkCopyMemoryToAccelerationStructureInfoKHR",
+        StructureType::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR,
+    ),
+    (
+        "VkD3D12FenceSubmitInfoKHR",
+        StructureType::D3D12_FENCE_SUBMIT_INFO_KHR,
+    ),
+    (
+        "VkDebugMarkerMarkerInfoEXT",
+        StructureType::DEBUG_MARKER_MARKER_INFO_EXT,
+    ),
+    (
+        "VkDebugMarkerObjectNameInfoEXT",
+        StructureType::DEBUG_MARKER_OBJECT_NAME_INFO_EXT,
+    ),
+    (
+        "VkDebugMarkerObjectTagInfoEXT",
+        StructureType::DEBUG_MARKER_OBJECT_TAG_INFO_EXT,
+    ),
+    (
+        "VkDebugReportCallbackCreateInfoEXT",
+        StructureType::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT,
+    ),
+    ("VkDebugUtilsLabelEXT", StructureType::DEBUG_UTILS_LABEL_EXT),
+    (
+        "VkDebugUtilsMessengerCallbackDataEXT",
+        StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+    ),
+    (
+        "VkDebugUtilsMessengerCreateInfoEXT",
+        StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+    ),
+    (
+        "VkDebugUtilsObjectNameInfoEXT",
+        StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+    ),
+    (
+        "VkDebugUtilsObjectTagInfoEXT",
+        StructureType::DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+    ),
+    (
+        "VkDedicatedAllocationBufferCreateInfoNV",
+        StructureType::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV,
+    ),
+    (
+        "VkDedicatedAllocationImageCreateInfoNV",
+        StructureType::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV,
+    ),
+    (
+        "VkDedicatedAllocationMemoryAllocateInfoNV",
+        StructureType::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV,
+    ),
+    (
+        "VkDescriptorPoolCreateInfo",
+        StructureType::DESCRIPTOR_POOL_CREATE_INFO,
+    ),
+    (
+        "VkDescriptorPoolInlineUniformBlockCreateInfoEXT",
+        StructureType::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT,
+    ),
+    (
+        "VkDescriptorSetAllocateInfo",
+        StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+    ),
+    (
+        "VkDescriptorSetLayoutBindingFlagsCreateInfo",
+        StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO,
+    ),
+    (
+        "VkDescriptorSetLayoutCreateInfo",
+        StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+    ),
+    (
+        "VkDescriptorSetLayoutSupport",
+        StructureType::DESCRIPTOR_SET_LAYOUT_SUPPORT,
+    ),
+    (
+        "VkDescriptorSetVariableDescriptorCountAllocateInfo",
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO,
+    ),
+    (
+        "VkDescriptorSetVariableDescriptorCountLayoutSupport",
+        StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT,
+    ),
+    (
+        "VkDescriptorUpdateTemplateCreateInfo",
+        StructureType::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO,
+    ),
+    ("VkDeviceCreateInfo", StructureType::DEVICE_CREATE_INFO),
+    (
+        "VkDeviceDeviceMemoryReportCreateInfoEXT",
+        StructureType::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT,
+    ),
+    (
+        "VkDeviceDiagnosticsConfigCreateInfoNV",
+        StructureType::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV,
+    ),
+    ("VkDeviceEventInfoEXT", StructureType::DEVICE_EVENT_INFO_EXT),
+    (
+        "VkDeviceGroupBindSparseInfo",
+        StructureType::DEVICE_GROUP_BIND_SPARSE_INFO,
+    ),
+    (
+        "VkDeviceGroupCommandBufferBeginInfo",
+        StructureType::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO,
+    ),
+    (
+        "VkDeviceGroupDeviceCreateInfo",
+        StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO,
+    ),
+    (
+        "VkDeviceGroupPresentCapabilitiesKHR",
+        StructureType::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+    ),
+    (
+        "VkDeviceGroupPresentInfoKHR",
+        StructureType::DEVICE_GROUP_PRESENT_INFO_KHR,
+    ),
+    (
+        "VkDeviceGroupRenderPassBeginInfo",
+        StructureType::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO,
+    ),
+    (
+        "VkDeviceGroupSubmitInfo",
+        StructureType::DEVICE_GROUP_SUBMIT_INFO,
+    ),
+    (
+        "VkDeviceGroupSwapchainCreateInfoKHR",
+        StructureType::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR,
+    ),
+    (
+        "VkDeviceMemoryOpaqueCaptureAddressInfo",
+        StructureType::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO,
+    ),
+    (
+        "VkDeviceMemoryOverallocationCreateInfoAMD",
+        StructureType::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD,
+    ),
+    (
+        "VkDeviceMemoryReportCallbackDataEXT",
+        StructureType::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT,
+    ),
+    (
+        "VkDevicePrivateDataCreateInfoEXT",
+        StructureType::DEVICE_PRIVATE_DATA_CREATE_INFO_EXT,
+    ),
+    (
+        "VkDeviceQueueCreateInfo",
+        StructureType::DEVICE_QUEUE_CREATE_INFO,
+    ),
+    (
+        "VkDeviceQueueGlobalPriorityCreateInfoEXT",
+        StructureType::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT,
+    ),
+    ("VkDeviceQueueInfo2", StructureType::DEVICE_QUEUE_INFO_2),
+    (
+        "VkDirectFBSurfaceCreateInfoEXT",
+        StructureType::DIRECTFB_SURFACE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkDisplayEventInfoEXT",
+        StructureType::DISPLAY_EVENT_INFO_EXT,
+    ),
+    (
+        "VkDisplayModeCreateInfoKHR",
+        StructureType::DISPLAY_MODE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkDisplayModeProperties2KHR",
+        StructureType::DISPLAY_MODE_PROPERTIES_2_KHR,
+    ),
+    (
+        "VkDisplayNativeHdrSurfaceCapabilitiesAMD",
+        StructureType::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD,
+    ),
+    (
+        "VkDisplayPlaneCapabilities2KHR",
+        StructureType::DISPLAY_PLANE_CAPABILITIES_2_KHR,
+    ),
+    (
+        "VkDisplayPlaneInfo2KHR",
+        StructureType::DISPLAY_PLANE_INFO_2_KHR,
+    ),
+    (
+        "VkDisplayPlaneProperties2KHR",
+        StructureType::DISPLAY_PLANE_PROPERTIES_2_KHR,
+    ),
+    (
+        "VkDisplayPowerInfoEXT",
+        StructureType::DISPLAY_POWER_INFO_EXT,
+    ),
+    (
+        "VkDisplayPresentInfoKHR",
+        StructureType::DISPLAY_PRESENT_INFO_KHR,
+    ),
+    (
+        "VkDisplayProperties2KHR",
+        StructureType::DISPLAY_PROPERTIES_2_KHR,
+    ),
+    (
+        "VkDisplaySurfaceCreateInfoKHR",
+        StructureType::DISPLAY_SURFACE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkDrmFormatModifierPropertiesListEXT",
+        StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+    ),
+    ("VkEventCreateInfo", StructureType::EVENT_CREATE_INFO),
+    (
+        "VkExportFenceCreateInfo",
+        StructureType::EXPORT_FENCE_CREATE_INFO,
+    ),
+    (
+        "VkExportFenceWin32HandleInfoKHR",
+        StructureType::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkExportMemoryAllocateInfo",
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO,
+    ),
+    (
+        "VkExportMemoryAllocateInfoNV",
+        StructureType::EXPORT_MEMORY_ALLOCATE_INFO_NV,
+    ),
+    (
+        "VkExportMemoryWin32HandleInfoKHR",
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkExportMemoryWin32HandleInfoNV",
+        StructureType::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+    ),
+    (
+        "VkExportSemaphoreCreateInfo",
+        StructureType::EXPORT_SEMAPHORE_CREATE_INFO,
+    ),
+    (
+        "VkExportSemaphoreWin32HandleInfoKHR",
+        StructureType::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkExternalBufferProperties",
+        StructureType::EXTERNAL_BUFFER_PROPERTIES,
+    ),
+    (
+        "VkExternalFenceProperties",
+        StructureType::EXTERNAL_FENCE_PROPERTIES,
+    ),
+    (
+        "VkExternalFormatANDROID",
+        StructureType::EXTERNAL_FORMAT_ANDROID,
+    ),
+    (
+        "VkExternalImageFormatProperties",
+        StructureType::EXTERNAL_IMAGE_FORMAT_PROPERTIES,
+    ),
+    (
+        "VkExternalMemoryBufferCreateInfo",
+        StructureType::EXTERNAL_MEMORY_BUFFER_CREATE_INFO,
+    ),
+    (
+        "VkExternalMemoryImageCreateInfo",
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+    ),
+    (
+        "VkExternalMemoryImageCreateInfoNV",
+        StructureType::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV,
+    ),
+    (
+        "VkExternalSemaphoreProperties",
+        StructureType::EXTERNAL_SEMAPHORE_PROPERTIES,
+    ),
+    ("VkFenceCreateInfo", StructureType::FENCE_CREATE_INFO),
+    ("VkFenceGetFdInfoKHR", StructureType::FENCE_GET_FD_INFO_KHR),
+    (
+        "VkFenceGetWin32HandleInfoKHR",
+        StructureType::FENCE_GET_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkFilterCubicImageViewImageFormatPropertiesEXT",
+        StructureType::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT,
+    ),
+    ("VkFormatProperties2", StructureType::FORMAT_PROPERTIES_2),
+    (
+        "VkFragmentShadingRateAttachmentInfoKHR",
+        StructureType::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR,
+    ),
+    (
+        "VkFramebufferAttachmentImageInfo",
+        StructureType::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO,
+    ),
+    (
+        "VkFramebufferAttachmentsCreateInfo",
+        StructureType::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO,
+    ),
+    (
+        "VkFramebufferCreateInfo",
+        StructureType::FRAMEBUFFER_CREATE_INFO,
+    ),
+    (
+        "VkFramebufferMixedSamplesCombinationNV",
+        StructureType::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV,
+    ),
+    (
+        "VkGeneratedCommandsInfoNV",
+        StructureType::GENERATED_COMMANDS_INFO_NV,
+    ),
+    (
+        "VkGeneratedCommandsMemoryRequirementsInfoNV",
+        StructureType::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV,
+    ),
+    ("VkGeometryAABBNV", StructureType::GEOMETRY_AABB_NV),
+    ("VkGeometryNV", StructureType::GEOMETRY_NV),
+    (
+        "VkGeometryTrianglesNV",
+        StructureType::GEOMETRY_TRIANGLES_NV,
+    ),
+    (
+        "VkGraphicsPipelineCreateInfo",
+        StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
+    ),
+    (
+        "VkGraphicsPipelineShaderGroupsCreateInfoNV",
+        StructureType::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV,
+    ),
+    (
+        "VkGraphicsShaderGroupCreateInfoNV",
+        StructureType::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV,
+    ),
+    ("VkHdrMetadataEXT", StructureType::HDR_METADATA_EXT),
+    (
+        "VkHeadlessSurfaceCreateInfoEXT",
+        StructureType::HEADLESS_SURFACE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkIOSSurfaceCreateInfoMVK",
+        StructureType::IOS_SURFACE_CREATE_INFO_M,
+    ),
+    ("VkImageBlit2KHR", StructureType::IMAGE_BLIT_2_KHR),
+    ("VkImageCopy2KHR", StructureType::IMAGE_COPY_2_KHR),
+    ("VkImageCreateInfo", StructureType::IMAGE_CREATE_INFO),
+    (
+        "VkImageDrmFormatModifierExplicitCreateInfoEXT",
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT,
+    ),
+    (
+        "VkImageDrmFormatModifierListCreateInfoEXT",
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT,
+    ),
+    (
+        "VkImageDrmFormatModifierPropertiesEXT",
+        StructureType::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+    ),
+    (
+        "VkImageFormatListCreateInfo",
+        StructureType::IMAGE_FORMAT_LIST_CREATE_INFO,
+    ),
+    (
+        "VkImageFormatProperties2",
+        StructureType::IMAGE_FORMAT_PROPERTIES_2,
+    ),
+    ("VkImageMemoryBarrier", StructureType::IMAGE_MEMORY_BARRIER),
+    (
+        "VkImageMemoryRequirementsInfo2",
+        StructureType::IMAGE_MEMORY_REQUIREMENTS_INFO_2,
+    ),
+    (
+        "VkImagePipeSurfaceCreateInfoFUCHSIA",
+        StructureType::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA,
+    ),
+    (
+        "VkImagePlaneMemoryRequirementsInfo",
+        StructureType::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO,
+    ),
+    ("VkImageResolve2KHR", StructureType::IMAGE_RESOLVE_2_KHR),
+    (
+        "VkImageSparseMemoryRequirementsInfo2",
+        StructureType::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2,
+    ),
+    (
+        "VkImageStencilUsageCreateInfo",
+        StructureType::IMAGE_STENCIL_USAGE_CREATE_INFO,
+    ),
+    (
+        "VkImageSwapchainCreateInfoKHR",
+        StructureType::IMAGE_SWAPCHAIN_CREATE_INFO_KHR,
+    ),
+    (
+        "VkImageViewASTCDecodeModeEXT",
+        StructureType::IMAGE_VIEW_ASTC_DECODE_MODE_EXT,
+    ),
+    (
+        "VkImageViewAddressPropertiesNVX",
+        StructureType::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX,
+    ),
+    (
+        "VkImageViewCreateInfo",
+        StructureType::IMAGE_VIEW_CREATE_INFO,
+    ),
+    (
+        "VkImageViewHandleInfoNVX",
+        StructureType::IMAGE_VIEW_HANDLE_INFO_NVX,
+    ),
+    (
+        "VkImageViewUsageCreateInfo",
+        StructureType::IMAGE_VIEW_USAGE_CREATE_INFO,
+    ),
+    (
+        "VkImportAndroidHardwareBufferInfoANDROID",
+        StructureType::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+    ),
+    (
+        "VkImportFenceFdInfoKHR",
+        StructureType::IMPORT_FENCE_FD_INFO_KHR,
+    ),
+    (
+        "VkImportFenceWin32HandleInfoKHR",
+        StructureType::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkImportMemoryFdInfoKHR",
+        StructureType::IMPORT_MEMORY_FD_INFO_KHR,
+    ),
+    (
+        "VkImportMemoryHostPointerInfoEXT",
+        StructureType::IMPORT_MEMORY_HOST_POINTER_INFO_EXT,
+    ),
+    (
+        "VkImportMemoryWin32HandleInfoKHR",
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkImportMemoryWin32HandleInfoNV",
+        StructureType::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+    ),
+    (
+        "VkImportSemaphoreFdInfoKHR",
+        StructureType::IMPORT_SEMAPHORE_FD_INFO_KHR,
+    ),
+    (
+        "VkImportSemaphoreWin32HandleInfoKHR",
+        StructureType::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkIndirectCommandsLayoutCreateInfoNV",
+        StructureType::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV,
+    ),
+    (
+        "VkIndirectCommandsLayoutTokenNV",
+        StructureType::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV,
+    ),
+    (
+        "VkInitializePerformanceApiInfoINTEL",
+        StructureType::INITIALIZE_PERFORMANCE_API_INFO_INTEL,
+    ),
+    ("VkInstanceCreateInfo", StructureType::INSTANCE_CREATE_INFO),
+    (
+        "VkMacOSSurfaceCreateInfoMVK",
+        StructureType::MACOS_SURFACE_CREATE_INFO_M,
+    ),
+    ("VkMappedMemoryRange", StructureType::MAPPED_MEMORY_RANGE),
+    (
+        "VkMemoryAllocateFlagsInfo",
+        StructureType::MEMORY_ALLOCATE_FLAGS_INFO,
+    ),
+    ("VkMemoryAllocateInfo", StructureType::MEMORY_ALLOCATE_INFO),
+    ("VkMemoryBarrier", StructureType::MEMORY_BARRIER),
+    (
+        "VkMemoryDedicatedAllocateInfo",
+        StructureType::MEMORY_DEDICATED_ALLOCATE_INFO,
+    ),
+    (
+        "VkMemoryDedicatedRequirements",
+        StructureType::MEMORY_DEDICATED_REQUIREMENTS,
+    ),
+    (
+        "VkMemoryFdPropertiesKHR",
+        StructureType::MEMORY_FD_PROPERTIES_KHR,
+    ),
+    (
+        "VkMemoryGetAndroidHardwareBufferInfoANDROID",
+        StructureType::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+    ),
+    (
+        "VkMemoryGetFdInfoKHR",
+        StructureType::MEMORY_GET_FD_INFO_KHR,
+    ),
+    (
+        "VkMemoryGetWin32HandleInfoKHR",
+        StructureType::MEMORY_GET_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkMemoryHostPointerPropertiesEXT",
+        StructureType::MEMORY_HOST_POINTER_PROPERTIES_EXT,
+    ),
+    (
+        "VkMemoryOpaqueCaptureAddressAllocateInfo",
+        StructureType::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO,
+    ),
+    (
+        "VkMemoryPriorityAllocateInfoEXT",
+        StructureType::MEMORY_PRIORITY_ALLOCATE_INFO_EXT,
+    ),
+    (
+        "VkMemoryRequirements2",
+        StructureType::MEMORY_REQUIREMENTS_2,
+    ),
+    (
+        "VkMemoryWin32HandlePropertiesKHR",
+        StructureType::MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+    ),
+    (
+        "VkMetalSurfaceCreateInfoEXT",
+        StructureType::METAL_SURFACE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkMultisamplePropertiesEXT",
+        StructureType::MULTISAMPLE_PROPERTIES_EXT,
+    ),
+    (
+        "VkNativeBufferANDROID",
+        StructureType::NATIVE_BUFFER_ANDROID,
+    ),
+    (
+        "VkPerformanceConfigurationAcquireInfoINTEL",
+        StructureType::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL,
+    ),
+    (
+        "VkPerformanceCounterDescriptionKHR",
+        StructureType::PERFORMANCE_COUNTER_DESCRIPTION_KHR,
+    ),
+    (
+        "VkPerformanceCounterKHR",
+        StructureType::PERFORMANCE_COUNTER_KHR,
+    ),
+    (
+        "VkPerformanceMarkerInfoINTEL",
+        StructureType::PERFORMANCE_MARKER_INFO_INTEL,
+    ),
+    (
+        "VkPerformanceOverrideInfoINTEL",
+        StructureType::PERFORMANCE_OVERRIDE_INFO_INTEL,
+    ),
+    (
+        "VkPerformanceQuerySubmitInfoKHR",
+        StructureType::PERFORMANCE_QUERY_SUBMIT_INFO_KHR,
+    ),
+    (
+        "VkPerformanceStreamMarkerInfoINTEL",
+        StructureType::PERFORMANCE_STREAM_MARKER_INFO_INTEL,
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+    ),
+    (
+        "VkPhysicalDevice4444FormatsFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDevice8BitStorageFeatures",
+        StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceASTCDecodeFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceAccelerationStructureFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceAccelerationStructurePropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceBufferDeviceAddressFeatures",
+        StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceCoherentMemoryFeaturesAMD",
+        StructureType::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD,
+    ),
+    (
+        "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceConditionalRenderingFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceConservativeRasterizationPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceCornerSampledImageFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceCoverageReductionModeFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceCustomBorderColorFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceDepthClipEnableFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceDepthStencilResolveProperties",
+        StructureType::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingFeatures",
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingProperties",
+        StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceDiagnosticsConfigFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceDriverProperties",
+        StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceExclusiveScissorFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceExternalBufferInfo",
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO,
+    ),
+    (
+        "VkPhysicalDeviceExternalFenceInfo",
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO,
+    ),
+    (
+        "VkPhysicalDeviceExternalImageFormatInfo",
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO,
+    ),
+    (
+        "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceExternalSemaphoreInfo",
+        StructureType::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO,
+    ),
+    (
+        "VkPhysicalDeviceFeatures2",
+        StructureType::PHYSICAL_DEVICE_FEATURES_2,
+    ),
+    (
+        "VkPhysicalDeviceFloatControlsProperties",
+        StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShaderBarycentricFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRateKHR",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR,
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRatePropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceGroupProperties",
+        StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceHostQueryResetFeatures",
+        StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceIDProperties",
+        StructureType::PHYSICAL_DEVICE_ID_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceImageDrmFormatModifierInfoEXT",
+        StructureType::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT,
+    ),
+    (
+        "VkPhysicalDeviceImageFormatInfo2",
+        StructureType::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2,
+    ),
+    (
+        "VkPhysicalDeviceImageRobustnessFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceImageViewImageFormatInfoEXT",
+        StructureType::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT,
+    ),
+    (
+        "VkPhysicalDeviceImagelessFramebufferFeatures",
+        StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceIndexTypeUint8FeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceMaintenance3Properties",
+        StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceMemoryBudgetPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceMemoryPriorityFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceMemoryProperties2",
+        StructureType::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2,
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceMultiviewFeatures",
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX",
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+    ),
+    (
+        "VkPhysicalDeviceMultiviewProperties",
+        StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDevicePerformanceQueryFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDevicePipelineCreationCacheControlFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDevicePointClippingProperties",
+        StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDevicePortabilitySubsetFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDevicePresentationPropertiesANDROID",
+        StructureType::PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID,
+    ),
+    (
+        "VkPhysicalDevicePrivateDataFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceProperties2",
+        StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+    ),
+    (
+        "VkPhysicalDeviceProtectedMemoryFeatures",
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceProtectedMemoryProperties",
+        StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDevicePushDescriptorPropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceRayQueryFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceRobustness2FeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceRobustness2PropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+        StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+        StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceScalarBlockLayoutFeatures",
+        StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures",
+        StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceShaderAtomicInt64Features",
+        StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceShaderClockFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceShaderCoreProperties2AMD",
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD,
+    ),
+    (
+        "VkPhysicalDeviceShaderCorePropertiesAMD",
+        StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+    ),
+    (
+        "VkPhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceShaderDrawParametersFeatures",
+        StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceShaderFloat16Int8Features",
+        StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+        StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL,
+    ),
+    (
+        "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+        StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures",
+        StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceShaderTerminateInvocationFeaturesKHR",
+        StructureType::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR,
+    ),
+    (
+        "VkPhysicalDeviceShadingRateImageFeaturesNV",
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+    ),
+    (
+        "VkPhysicalDeviceShadingRateImagePropertiesNV",
+        StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+    ),
+    (
+        "VkPhysicalDeviceSparseImageFormatInfo2",
+        StructureType::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2,
+    ),
+    (
+        "VkPhysicalDeviceSubgroupProperties",
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceSurfaceInfo2KHR",
+        StructureType::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR,
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceTextureCompressionASTCHDRFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreFeatures",
+        StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreProperties",
+        StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceToolPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceUniformBufferStandardLayoutFeatures",
+        StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceVariablePointersFeatures",
+        StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT",
+        StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+    ),
+    (
+        "VkPhysicalDeviceVulkan11Features",
+        StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceVulkan11Properties",
+        StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceVulkan12Features",
+        StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceVulkan12Properties",
+        StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES,
+    ),
+    (
+        "VkPhysicalDeviceVulkanMemoryModelFeatures",
+        StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES,
+    ),
+    (
+        "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT",
+        StructureType::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT,
+    ),
+    (
+        "VkPipelineCacheCreateInfo",
+        StructureType::PIPELINE_CACHE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineColorBlendAdvancedStateCreateInfoEXT",
+        StructureType::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineColorBlendStateCreateInfo",
+        StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineCompilerControlCreateInfoAMD",
+        StructureType::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD,
+    ),
+    (
+        "VkPipelineCoverageModulationStateCreateInfoNV",
+        StructureType::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineCoverageReductionStateCreateInfoNV",
+        StructureType::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineCoverageToColorStateCreateInfoNV",
+        StructureType::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineCreationFeedbackCreateInfoEXT",
+        StructureType::PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineDepthStencilStateCreateInfo",
+        StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineDiscardRectangleStateCreateInfoEXT",
+        StructureType::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineDynamicStateCreateInfo",
+        StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineExecutableInfoKHR",
+        StructureType::PIPELINE_EXECUTABLE_INFO_KHR,
+    ),
+    (
+        "VkPipelineExecutableInternalRepresentationKHR",
+        StructureType::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR,
+    ),
+    (
+        "VkPipelineExecutablePropertiesKHR",
+        StructureType::PIPELINE_EXECUTABLE_PROPERTIES_KHR,
+    ),
+    (
+        "VkPipelineExecutableStatisticKHR",
+        StructureType::PIPELINE_EXECUTABLE_STATISTIC_KHR,
+    ),
+    (
+        "VkPipelineFragmentShadingRateEnumStateCreateInfoNV",
+        StructureType::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineFragmentShadingRateStateCreateInfoKHR",
+        StructureType::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR,
+    ),
+    ("VkPipelineInfoKHR", StructureType::PIPELINE_INFO_KHR),
+    (
+        "VkPipelineInputAssemblyStateCreateInfo",
+        StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineLayoutCreateInfo",
+        StructureType::PIPELINE_LAYOUT_CREATE_INFO,
+    ),
+    (
+        "VkPipelineLibraryCreateInfoKHR",
+        StructureType::PIPELINE_LIBRARY_CREATE_INFO_KHR,
+    ),
+    (
+        "VkPipelineMultisampleStateCreateInfo",
+        StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineRasterizationConservativeStateCreateInfoEXT",
+        StructureType::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineRasterizationDepthClipStateCreateInfoEXT",
+        StructureType::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineRasterizationLineStateCreateInfoEXT",
+        StructureType::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineRasterizationStateCreateInfo",
+        StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineRasterizationStateRasterizationOrderAMD",
+        StructureType::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD,
+    ),
+    (
+        "VkPipelineRasterizationStateStreamCreateInfoEXT",
+        StructureType::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineRepresentativeFragmentTestStateCreateInfoNV",
+        StructureType::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineSampleLocationsStateCreateInfoEXT",
+        StructureType::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineShaderStageCreateInfo",
+        StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineShaderStageRequiredSubgroupSizeCreateInfoEXT",
+        StructureType::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineTessellationDomainOriginStateCreateInfo",
+        StructureType::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineTessellationStateCreateInfo",
+        StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineVertexInputDivisorStateCreateInfoEXT",
+        StructureType::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkPipelineVertexInputStateCreateInfo",
+        StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineViewportCoarseSampleOrderStateCreateInfoNV",
+        StructureType::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineViewportExclusiveScissorStateCreateInfoNV",
+        StructureType::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineViewportShadingRateImageStateCreateInfoNV",
+        StructureType::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineViewportStateCreateInfo",
+        StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+    ),
+    (
+        "VkPipelineViewportSwizzleStateCreateInfoNV",
+        StructureType::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPipelineViewportWScalingStateCreateInfoNV",
+        StructureType::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV,
+    ),
+    (
+        "VkPresentFrameTokenGGP",
+        StructureType::PRESENT_FRAME_TOKEN_GGP,
+    ),
+    ("VkPresentInfoKHR", StructureType::PRESENT_INFO_KHR),
+    ("VkPresentRegionsKHR", StructureType::PRESENT_REGIONS_KHR),
+    (
+        "VkPresentTimesInfoGOOGLE",
+        StructureType::PRESENT_TIMES_INFO_GOOGLE,
+    ),
+    (
+        "VkPrivateDataSlotCreateInfoEXT",
+        StructureType::PRIVATE_DATA_SLOT_CREATE_INFO_EXT,
+    ),
+    (
+        "VkProtectedSubmitInfo",
+        StructureType::PROTECTED_SUBMIT_INFO,
+    ),
+    (
+        "VkQueryPoolCreateInfo",
+        StructureType::QUERY_POOL_CREATE_INFO,
+    ),
+    (
+        "VkQueryPoolPerformanceCreateInfoKHR",
+        StructureType::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkQueryPoolPerformanceQueryCreateInfoINTEL",
+        StructureType::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL,
+    ),
+    (
+        "VkQueueFamilyCheckpointPropertiesNV",
+        StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+    ),
+    (
+        "VkQueueFamilyProperties2",
+        StructureType::QUEUE_FAMILY_PROPERTIES_2,
+    ),
+    (
+        "VkRayTracingPipelineCreateInfoKHR",
+        StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkRayTracingPipelineCreateInfoNV",
+        StructureType::RAY_TRACING_PIPELINE_CREATE_INFO_NV,
+    ),
+    (
+        "VkRayTracingPipelineInterfaceCreateInfoKHR",
+        StructureType::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkRayTracingShaderGroupCreateInfoKHR",
+        StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR,
+    ),
+    (
+        "VkRayTracingShaderGroupCreateInfoNV",
+        StructureType::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV,
+    ),
+    (
+        "VkRenderPassAttachmentBeginInfo",
+        StructureType::RENDER_PASS_ATTACHMENT_BEGIN_INFO,
+    ),
+    (
+        "VkRenderPassBeginInfo",
+        StructureType::RENDER_PASS_BEGIN_INFO,
+    ),
+    (
+        "VkRenderPassCreateInfo",
+        StructureType::RENDER_PASS_CREATE_INFO,
+    ),
+    (
+        "VkRenderPassCreateInfo2",
+        StructureType::RENDER_PASS_CREATE_INFO_2,
+    ),
+    (
+        "VkRenderPassFragmentDensityMapCreateInfoEXT",
+        StructureType::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT,
+    ),
+    (
+        "VkRenderPassInputAttachmentAspectCreateInfo",
+        StructureType::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO,
+    ),
+    (
+        "VkRenderPassMultiviewCreateInfo",
+        StructureType::RENDER_PASS_MULTIVIEW_CREATE_INFO,
+    ),
+    (
+        "VkRenderPassSampleLocationsBeginInfoEXT",
+        StructureType::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT,
+    ),
+    (
+        "VkRenderPassTransformBeginInfoQCOM",
+        StructureType::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM,
+    ),
+    (
+        "VkResolveImageInfo2KHR",
+        StructureType::RESOLVE_IMAGE_INFO_2_KHR,
+    ),
+    (
+        "VkSampleLocationsInfoEXT",
+        StructureType::SAMPLE_LOCATIONS_INFO_EXT,
+    ),
+    ("VkSamplerCreateInfo", StructureType::SAMPLER_CREATE_INFO),
+    (
+        "VkSamplerCustomBorderColorCreateInfoEXT",
+        StructureType::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT,
+    ),
+    (
+        "VkSamplerReductionModeCreateInfo",
+        StructureType::SAMPLER_REDUCTION_MODE_CREATE_INFO,
+    ),
+    (
+        "VkSamplerYcbcrConversionCreateInfo",
+        StructureType::SAMPLER_YCBCR_CONVERSION_CREATE_INFO,
+    ),
+    (
+        "VkSamplerYcbcrConversionImageFormatProperties",
+        StructureType::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES,
+    ),
+    (
+        "VkSamplerYcbcrConversionInfo",
+        StructureType::SAMPLER_YCBCR_CONVERSION_INFO,
+    ),
+    (
+        "VkSemaphoreCreateInfo",
+        StructureType::SEMAPHORE_CREATE_INFO,
+    ),
+    (
+        "VkSemaphoreGetFdInfoKHR",
+        StructureType::SEMAPHORE_GET_FD_INFO_KHR,
+    ),
+    (
+        "VkSemaphoreGetWin32HandleInfoKHR",
+        StructureType::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR,
+    ),
+    (
+        "VkSemaphoreSignalInfo",
+        StructureType::SEMAPHORE_SIGNAL_INFO,
+    ),
+    (
+        "VkSemaphoreTypeCreateInfo",
+        StructureType::SEMAPHORE_TYPE_CREATE_INFO,
+    ),
+    ("VkSemaphoreWaitInfo", StructureType::SEMAPHORE_WAIT_INFO),
+    (
+        "VkShaderModuleCreateInfo",
+        StructureType::SHADER_MODULE_CREATE_INFO,
+    ),
+    (
+        "VkShaderModuleValidationCacheCreateInfoEXT",
+        StructureType::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkSharedPresentSurfaceCapabilitiesKHR",
+        StructureType::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR,
+    ),
+    (
+        "VkSparseImageFormatProperties2",
+        StructureType::SPARSE_IMAGE_FORMAT_PROPERTIES_2,
+    ),
+    (
+        "VkSparseImageMemoryRequirements2",
+        StructureType::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2,
+    ),
+    (
+        "VkStreamDescriptorSurfaceCreateInfoGGP",
+        StructureType::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP,
+    ),
+    ("VkSubmitInfo", StructureType::SUBMIT_INFO),
+    ("VkSubpassBeginInfo", StructureType::SUBPASS_BEGIN_INFO),
+    ("VkSubpassDependency2", StructureType::SUBPASS_DEPENDENCY_2),
+    (
+        "VkSubpassDescription2",
+        StructureType::SUBPASS_DESCRIPTION_2,
+    ),
+    (
+        "VkSubpassDescriptionDepthStencilResolve",
+        StructureType::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE,
+    ),
+    ("VkSubpassEndInfo", StructureType::SUBPASS_END_INFO),
+    (
+        "VkSurfaceCapabilities2EXT",
+        StructureType::SURFACE_CAPABILITIES_2_EXT,
+    ),
+    (
+        "VkSurfaceCapabilities2KHR",
+        StructureType::SURFACE_CAPABILITIES_2_KHR,
+    ),
+    (
+        "VkSurfaceCapabilitiesFullScreenExclusiveEXT",
+        StructureType::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT,
+    ),
+    ("VkSurfaceFormat2KHR", StructureType::SURFACE_FORMAT_2_KHR),
+    (
+        "VkSurfaceFullScreenExclusiveInfoEXT",
+        StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT,
+    ),
+    (
+        "VkSurfaceFullScreenExclusiveWin32InfoEXT",
+        StructureType::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT,
+    ),
+    (
+        "VkSurfaceProtectedCapabilitiesKHR",
+        StructureType::SURFACE_PROTECTED_CAPABILITIES_KHR,
+    ),
+    (
+        "VkSwapchainCounterCreateInfoEXT",
+        StructureType::SWAPCHAIN_COUNTER_CREATE_INFO_EXT,
+    ),
+    (
+        "VkSwapchainCreateInfoKHR",
+        StructureType::SWAPCHAIN_CREATE_INFO_KHR,
+    ),
+    (
+        "VkSwapchainDisplayNativeHdrCreateInfoAMD",
+        StructureType::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD,
+    ),
+    (
+        "VkSwapchainImageCreateInfoANDROID",
+        StructureType::SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID,
+    ),
+    (
+        "VkTextureLODGatherFormatPropertiesAMD",
+        StructureType::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD,
+    ),
+    (
+        "VkTimelineSemaphoreSubmitInfo",
+        StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO,
+    ),
+    (
+        "VkValidationCacheCreateInfoEXT",
+        StructureType::VALIDATION_CACHE_CREATE_INFO_EXT,
+    ),
+    (
+        "VkValidationFeaturesEXT",
+        StructureType::VALIDATION_FEATURES_EXT,
+    ),
+    ("VkValidationFlagsEXT", StructureType::VALIDATION_FLAGS_EXT),
+    (
+        "VkViSurfaceCreateInfoNN",
+        StructureType::VI_SURFACE_CREATE_INFO_NN,
+    ),
+    (
+        "VkWaylandSurfaceCreateInfoKHR",
+        StructureType::WAYLAND_SURFACE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkWin32KeyedMutexAcquireReleaseInfoKHR",
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR,
+    ),
+    (
+        "VkWin32KeyedMutexAcquireReleaseInfoNV",
+        StructureType::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV,
+    ),
+    (
+        "VkWin32SurfaceCreateInfoKHR",
+        StructureType::WIN32_SURFACE_CREATE_INFO_KHR,
+    ),
+    ("VkWriteDescriptorSet", StructureType::WRITE_DESCRIPTOR_SET),
+    (
+        "VkWriteDescriptorSetAccelerationStructureKHR",
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR,
+    ),
+    (
+        "VkWriteDescriptorSetAccelerationStructureNV",
+        StructureType::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV,
+    ),
+    (
+        "VkWriteDescriptorSetInlineUniformBlockEXT",
+        StructureType::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT,
+    ),
+    (
+        "VkXcbSurfaceCreateInfoKHR",
+        StructureType::XCB_SURFACE_CREATE_INFO_KHR,
+    ),
+    (
+        "VkXlibSurfaceCreateInfoKHR",
+        StructureType::XLIB_SURFACE_CREATE_INFO_KHR,
+    ),
+];
+#[doc = r" The core versions and extensions that add a value to an existing enum, e.g."]
+#[doc = r#" `("VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR", &["VK_KHR_swapchain"])`. Sorted by the"#]
+#[doc = r" name of the value."]
+pub const ENUM_VALUE_PROVIDERS : & [(& str , & [& str])] = & [("VK_ACCELERATION_STRUCTURE_CREATE_RESERVED_BIT_2_NV" , & ["VK_NV_extension_328"]) , ("VK_ACCELERATION_STRUCTURE_TYPE_BOTTOM_LEVEL_NV" , & ["VK_NV_ray_tracing"]) , ("VK_ACCELERATION_STRUCTURE_TYPE_TOP_LEVEL_NV" , & ["VK_NV_ray_tracing"]) , ("VK_ACCESS_ACCELERATION_STRUCTURE_READ_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_ACCESS_ACCELERATION_STRUCTURE_READ_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_ACCESS_ACCELERATION_STRUCTURE_WRITE_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_ACCESS_ACCELERATION_STRUCTURE_WRITE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_ACCESS_COLOR_ATTACHMENT_READ_NONCOHERENT_BIT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_ACCESS_COMMAND_PREPROCESS_READ_BIT_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_ACCESS_COMMAND_PREPROCESS_WRITE_BIT_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_ACCESS_CONDITIONAL_RENDERING_READ_BIT_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_ACCESS_FRAGMENT_DENSITY_MAP_READ_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_ACCESS_FRAGMENT_SHADING_RATE_ATTACHMENT_READ_BIT_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_ACCESS_RESERVED_28_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_ACCESS_RESERVED_29_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_ACCESS_RESERVED_30_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_ACCESS_SHADING_RATE_IMAGE_READ_BIT_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_ACCESS_TRANSFORM_FEEDBACK_COUNTER_READ_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_ACCESS_TRANSFORM_FEEDBACK_COUNTER_WRITE_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_ACCESS_TRANSFORM_FEEDBACK_WRITE_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_ATTACHMENT_STORE_OP_NONE_QCOM" , & ["VK_QCOM_render_pass_store_ops"]) , ("VK_BLEND_OP_BLUE_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_COLORBURN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_COLORDODGE_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_CONTRAST_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DARKEN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DIFFERENCE_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DST_ATOP_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DST_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DST_IN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DST_OUT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_DST_OVER_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_EXCLUSION_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_GREEN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HARDLIGHT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HARDMIX_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HSL_COLOR_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HSL_HUE_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HSL_LUMINOSITY_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_HSL_SATURATION_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_INVERT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_INVERT_OVG_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_INVERT_RGB_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_LIGHTEN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_LINEARBURN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_LINEARDODGE_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_LINEARLIGHT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_MINUS_CLAMPED_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_MINUS_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_MULTIPLY_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_OVERLAY_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_PINLIGHT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_PLUS_CLAMPED_ALPHA_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_PLUS_CLAMPED_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_PLUS_DARKER_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_PLUS_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_RED_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SCREEN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SOFTLIGHT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SRC_ATOP_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SRC_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SRC_IN_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SRC_OUT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_SRC_OVER_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_VIVIDLIGHT_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_XOR_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BLEND_OP_ZERO_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_BORDER_COLOR_FLOAT_CUSTOM_EXT" , & ["VK_EXT_custom_border_color"]) , ("VK_BORDER_COLOR_INT_CUSTOM_EXT" , & ["VK_EXT_custom_border_color"]) , ("VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT" , & ["VK_VERSION_1_2"]) , ("VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_BUFFER_CREATE_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_BUFFER_CREATE_RESERVED_5_BIT_NV" , & ["VK_NV_extension_372"]) , ("VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_STORAGE_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_BUFFER_USAGE_CONDITIONAL_RENDERING_BIT_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_BUFFER_USAGE_RAY_TRACING_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUFFER_USAGE_RESERVED_13_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_BUFFER_USAGE_RESERVED_14_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_BUFFER_USAGE_RESERVED_15_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_BUFFER_USAGE_RESERVED_16_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_BUFFER_USAGE_RESERVED_18_BIT_QCOM" , & ["VK_QCOM_extension_173"]) , ("VK_BUFFER_USAGE_SHADER_BINDING_TABLE_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT" , & ["VK_VERSION_1_2"]) , ("VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_BUFFER_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_COUNTER_BUFFER_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_COMPACTION_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_UPDATE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_LOW_MEMORY_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_BUILD_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_TRACE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_BUILD_ACCELERATION_STRUCTURE_RESERVED_BIT_5_NV" , & ["VK_NV_extension_328"]) , ("VK_CHROMA_LOCATION_COSITED_EVEN_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_CHROMA_LOCATION_MIDPOINT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_COLOR_SPACE_ADOBERGB_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_ADOBERGB_NONLINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_BT2020_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_BT709_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_BT709_NONLINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_DCI_P3_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_DCI_P3_NONLINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_DISPLAY_NATIVE_AMD" , & ["VK_AMD_display_native_hdr"]) , ("VK_COLOR_SPACE_DISPLAY_P3_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_DISPLAY_P3_NONLINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_DOLBYVISION_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_EXTENDED_SRGB_LINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_EXTENDED_SRGB_NONLINEAR_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_HDR10_HLG_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_HDR10_ST2084_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COLOR_SPACE_PASS_THROUGH_EXT" , & ["VK_EXT_swapchain_colorspace"]) , ("VK_COMMAND_POOL_CREATE_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_COPY_ACCELERATION_STRUCTURE_MODE_CLONE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_COPY_ACCELERATION_STRUCTURE_MODE_COMPACT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_ACCELERATION_STRUCTURE_KHR_EXT" , & ["VK_KHR_acceleration_structure"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_ACCELERATION_STRUCTURE_NV_EXT" , & ["VK_NV_ray_tracing"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_EXT" , & ["VK_EXT_debug_report"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_KHR_EXT" , & ["VK_KHR_descriptor_update_template"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_EXT" , & ["VK_EXT_debug_report" , "VK_KHR_sampler_ycbcr_conversion"]) , ("VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_KHR_EXT" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_DEPENDENCY_DEVICE_GROUP_BIT" , & ["VK_VERSION_1_1"]) , ("VK_DEPENDENCY_DEVICE_GROUP_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_DEPENDENCY_VIEW_LOCAL_BIT" , & ["VK_VERSION_1_1"]) , ("VK_DEPENDENCY_VIEW_LOCAL_BIT_KHR" , & ["VK_KHR_multiview"]) , ("VK_DESCRIPTOR_BINDING_PARTIALLY_BOUND_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_BINDING_RESERVED_4_BIT_QCOM" , & ["VK_QCOM_extension_369"]) , ("VK_DESCRIPTOR_BINDING_UPDATE_AFTER_BIND_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_BINDING_UPDATE_UNUSED_WHILE_PENDING_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_BINDING_VARIABLE_DESCRIPTOR_COUNT_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_POOL_CREATE_UPDATE_AFTER_BIND_BIT" , & ["VK_VERSION_1_2"]) , ("VK_DESCRIPTOR_POOL_CREATE_UPDATE_AFTER_BIND_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR" , & ["VK_KHR_push_descriptor"]) , ("VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT" , & ["VK_VERSION_1_2"]) , ("VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_DESCRIPTOR_TYPE_ACCELERATION_STRUCTURE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_DESCRIPTOR_TYPE_ACCELERATION_STRUCTURE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK_EXT" , & ["VK_EXT_inline_uniform_block"]) , ("VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_DESCRIPTOR_SET_KHR" , & ["VK_KHR_descriptor_update_template"]) , ("VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_PUSH_DESCRIPTORS_KHR" , & ["VK_KHR_push_descriptor" , "VK_KHR_descriptor_update_template"]) , ("VK_DEVICE_QUEUE_CREATE_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_DRIVER_ID_AMD_OPEN_SOURCE_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_AMD_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_ARM_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_BROADCOM_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_GGP_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_GOOGLE_SWIFTSHADER_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_IMAGINATION_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_INTEL_OPEN_SOURCE_MESA_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_INTEL_PROPRIETARY_WINDOWS_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_MESA_RADV_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_NVIDIA_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DRIVER_ID_QUALCOMM_PROPRIETARY_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_DYNAMIC_STATE_CULL_MODE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_DEPTH_BOUNDS_TEST_ENABLE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_DEPTH_COMPARE_OP_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_DEPTH_TEST_ENABLE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_DEPTH_WRITE_ENABLE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_DISCARD_RECTANGLE_EXT" , & ["VK_EXT_discard_rectangles"]) , ("VK_DYNAMIC_STATE_EXCLUSIVE_SCISSOR_NV" , & ["VK_NV_scissor_exclusive"]) , ("VK_DYNAMIC_STATE_FRAGMENT_SHADING_RATE_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_DYNAMIC_STATE_FRONT_FACE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_LINE_STIPPLE_EXT" , & ["VK_EXT_line_rasterization"]) , ("VK_DYNAMIC_STATE_PRIMITIVE_TOPOLOGY_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_RAY_TRACING_PIPELINE_STACK_SIZE_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_DYNAMIC_STATE_SAMPLE_LOCATIONS_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_DYNAMIC_STATE_SCISSOR_WITH_COUNT_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_STENCIL_OP_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_STENCIL_TEST_ENABLE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_VERTEX_INPUT_BINDING_STRIDE_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_VIEWPORT_COARSE_SAMPLE_ORDER_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_DYNAMIC_STATE_VIEWPORT_SHADING_RATE_PALETTE_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_DYNAMIC_STATE_VIEWPORT_WITH_COUNT_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_DYNAMIC_STATE_VIEWPORT_W_SCALING_NV" , & ["VK_NV_clip_space_w_scaling"]) , ("VK_ERROR_FRAGMENTATION" , & ["VK_VERSION_1_2"]) , ("VK_ERROR_FRAGMENTATION_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT" , & ["VK_EXT_full_screen_exclusive"]) , ("VK_ERROR_INCOMPATIBLE_DISPLAY_KHR" , & ["VK_KHR_display_swapchain"]) , ("VK_ERROR_INVALID_DEVICE_ADDRESS_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_ERROR_INVALID_EXTERNAL_HANDLE" , & ["VK_VERSION_1_1"]) , ("VK_ERROR_INVALID_EXTERNAL_HANDLE_KHR" , & ["VK_KHR_external_memory"]) , ("VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS" , & ["VK_VERSION_1_2"]) , ("VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_ERROR_INVALID_SHADER_NV" , & ["VK_NV_glsl_shader"]) , ("VK_ERROR_NATIVE_WINDOW_IN_USE_KHR" , & ["VK_KHR_surface"]) , ("VK_ERROR_NOT_PERMITTED_EXT" , & ["VK_EXT_global_priority"]) , ("VK_ERROR_OUT_OF_DATE_KHR" , & ["VK_KHR_swapchain"]) , ("VK_ERROR_OUT_OF_POOL_MEMORY" , & ["VK_VERSION_1_1"]) , ("VK_ERROR_OUT_OF_POOL_MEMORY_KHR" , & ["VK_KHR_maintenance1"]) , ("VK_ERROR_PIPELINE_COMPILE_REQUIRED_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_ERROR_SURFACE_LOST_KHR" , & ["VK_KHR_surface"]) , ("VK_ERROR_VALIDATION_FAILED_EXT" , & ["VK_EXT_debug_report"]) , ("VK_EXTERNAL_FENCE_FEATURE_EXPORTABLE_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_FENCE_FEATURE_IMPORTABLE_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_FEATURE_EXPORTABLE_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_FEATURE_IMPORTABLE_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_ANDROID_HARDWARE_BUFFER_BIT_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF_BIT_EXT" , & ["VK_EXT_external_memory_dma_buf"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_HOST_ALLOCATION_BIT_EXT" , & ["VK_EXT_external_memory_host"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_HOST_MAPPED_FOREIGN_MEMORY_BIT_EXT" , & ["VK_EXT_external_memory_host"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_FENCE_IMPORT_TEMPORARY_BIT_KHR" , & ["VK_KHR_external_fence"]) , ("VK_FILTER_CUBIC_EXT" , & ["VK_EXT_filter_cubic"]) , ("VK_FILTER_CUBIC_IMG" , & ["VK_IMG_filter_cubic"]) , ("VK_FORMAT_A4B4G4R4_UNORM_PACK16_EXT" , & ["VK_EXT_4444_formats"]) , ("VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT" , & ["VK_EXT_4444_formats"]) , ("VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_3x3x3_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_3x3x3_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_3x3x3_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x3x3_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x3x3_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x3x3_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_4x4x3_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4x3_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4x3_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4x4_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4x4_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_4x4x4_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_5x4x4_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x4x4_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x4x4_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_5x5x4_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5x4_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5x4_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5x5_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_5x5x5_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_6x5x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x5x5_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x5x5_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_6x6x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6x5_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6x5_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6x6_SFLOAT_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6x6_SRGB_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_6x6x6_UNORM_BLOCK_EXT" , & ["VK_EXT_extension_289"]) , ("VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_B16G16R16G16_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_B16G16R16G16_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_B8G8R8G8_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_B8G8R8G8_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_ACCELERATION_STRUCTURE_VERTEX_BUFFER_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_DISJOINT_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_DISJOINT_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_FRAGMENT_DENSITY_MAP_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_FORMAT_FEATURE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_RESERVED_25_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_FORMAT_FEATURE_RESERVED_26_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_FORMAT_FEATURE_RESERVED_27_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_FORMAT_FEATURE_RESERVED_28_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT" , & ["VK_EXT_filter_cubic"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_IMG" , & ["VK_IMG_filter_cubic"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT" , & ["VK_VERSION_1_2"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_FEATURE_TRANSFER_DST_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_TRANSFER_DST_BIT_KHR" , & ["VK_KHR_maintenance1"]) , ("VK_FORMAT_FEATURE_TRANSFER_SRC_BIT" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_FEATURE_TRANSFER_SRC_BIT_KHR" , & ["VK_KHR_maintenance1"]) , ("VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16B16G16R16_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16B16G16R16_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16_B16R16_2PLANE_420_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16_B16R16_2PLANE_420_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16_B16R16_2PLANE_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16_B16R16_2PLANE_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8B8G8R8_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8B8G8R8_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8_B8R8_2PLANE_420_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8_B8R8_2PLANE_420_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8_B8R8_2PLANE_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8_B8R8_2PLANE_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG" , & ["VK_IMG_format_pvrtc"]) , ("VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_R10X6G10X6_UNORM_2PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R10X6G10X6_UNORM_2PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_R10X6_UNORM_PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R10X6_UNORM_PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_R12X4G12X4_UNORM_2PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R12X4G12X4_UNORM_2PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FORMAT_R12X4_UNORM_PACK16" , & ["VK_VERSION_1_1"]) , ("VK_FORMAT_R12X4_UNORM_PACK16_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT" , & ["VK_VERSION_1_2"]) , ("VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT_KHR" , & ["VK_KHR_imageless_framebuffer"]) , ("VK_GEOMETRY_INSTANCE_FORCE_NO_OPAQUE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_INSTANCE_FORCE_OPAQUE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_INSTANCE_TRIANGLE_CULL_DISABLE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_INSTANCE_TRIANGLE_FRONT_COUNTERCLOCKWISE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_NO_DUPLICATE_ANY_HIT_INVOCATION_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_OPAQUE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_TYPE_AABBS_NV" , & ["VK_NV_ray_tracing"]) , ("VK_GEOMETRY_TYPE_TRIANGLES_NV" , & ["VK_NV_ray_tracing"]) , ("VK_IMAGE_ASPECT_MEMORY_PLANE_0_BIT_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_IMAGE_ASPECT_MEMORY_PLANE_1_BIT_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_IMAGE_ASPECT_MEMORY_PLANE_2_BIT_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_IMAGE_ASPECT_MEMORY_PLANE_3_BIT_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_IMAGE_ASPECT_PLANE_0_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_ASPECT_PLANE_0_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_IMAGE_ASPECT_PLANE_1_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_ASPECT_PLANE_1_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_IMAGE_ASPECT_PLANE_2_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_ASPECT_PLANE_2_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT_KHR" , & ["VK_KHR_maintenance1"]) , ("VK_IMAGE_CREATE_ALIAS_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_ALIAS_BIT_KHR" , & ["VK_KHR_bind_memory2"]) , ("VK_IMAGE_CREATE_BLOCK_TEXEL_VIEW_COMPATIBLE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_BLOCK_TEXEL_VIEW_COMPATIBLE_BIT_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_IMAGE_CREATE_CORNER_SAMPLED_BIT_NV" , & ["VK_NV_corner_sampled_image"]) , ("VK_IMAGE_CREATE_DISJOINT_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_DISJOINT_BIT_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_IMAGE_CREATE_EXTENDED_USAGE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_EXTENDED_USAGE_BIT_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_IMAGE_CREATE_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_RESERVED_15_BIT_NV" , & ["VK_NV_extension_372"]) , ("VK_IMAGE_CREATE_SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_BIT_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_IMAGE_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_IMAGE_CREATE_SUBSAMPLED_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_OPTIMAL" , & ["VK_VERSION_1_2"]) , ("VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_OPTIMAL_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL" , & ["VK_VERSION_1_2"]) , ("VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL" , & ["VK_VERSION_1_1"]) , ("VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_IMAGE_LAYOUT_FRAGMENT_DENSITY_MAP_OPTIMAL_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_IMAGE_LAYOUT_FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_IMAGE_LAYOUT_PRESENT_SRC_KHR" , & ["VK_KHR_swapchain"]) , ("VK_IMAGE_LAYOUT_SHADING_RATE_OPTIMAL_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_IMAGE_LAYOUT_SHARED_PRESENT_KHR" , & ["VK_KHR_shared_presentable_image"]) , ("VK_IMAGE_LAYOUT_STENCIL_ATTACHMENT_OPTIMAL" , & ["VK_VERSION_1_2"]) , ("VK_IMAGE_LAYOUT_STENCIL_ATTACHMENT_OPTIMAL_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_IMAGE_LAYOUT_STENCIL_READ_ONLY_OPTIMAL" , & ["VK_VERSION_1_2"]) , ("VK_IMAGE_LAYOUT_STENCIL_READ_ONLY_OPTIMAL_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_IMAGE_USAGE_FRAGMENT_DENSITY_MAP_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_IMAGE_USAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_IMAGE_USAGE_RESERVED_10_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_IMAGE_USAGE_RESERVED_11_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_IMAGE_USAGE_RESERVED_12_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_IMAGE_USAGE_RESERVED_13_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_IMAGE_USAGE_RESERVED_14_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_IMAGE_USAGE_RESERVED_15_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_IMAGE_USAGE_RESERVED_16_BIT_QCOM" , & ["VK_QCOM_extension_173"]) , ("VK_IMAGE_USAGE_RESERVED_17_BIT_QCOM" , & ["VK_QCOM_extension_173"]) , ("VK_IMAGE_USAGE_SHADING_RATE_IMAGE_BIT_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_IMAGE_VIEW_CREATE_FRAGMENT_DENSITY_MAP_DEFERRED_BIT_EXT" , & ["VK_EXT_fragment_density_map2"]) , ("VK_IMAGE_VIEW_CREATE_FRAGMENT_DENSITY_MAP_DYNAMIC_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_INDEX_TYPE_NONE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_INDEX_TYPE_NONE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_INDEX_TYPE_UINT8_EXT" , & ["VK_EXT_index_type_uint8"]) , ("VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_BIT" , & ["VK_VERSION_1_2"]) , ("VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_BIT_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT" , & ["VK_VERSION_1_2"]) , ("VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_MEMORY_ALLOCATE_DEVICE_MASK_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_MEMORY_HEAP_MULTI_INSTANCE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_MEMORY_HEAP_MULTI_INSTANCE_BIT_KHR" , & ["VK_KHR_device_group_creation"]) , ("VK_MEMORY_HEAP_RESERVED_2_BIT_KHR" , & ["VK_KHR_extension_309"]) , ("VK_MEMORY_PROPERTY_DEVICE_COHERENT_BIT_AMD" , & ["VK_AMD_device_coherent_memory"]) , ("VK_MEMORY_PROPERTY_DEVICE_UNCACHED_BIT_AMD" , & ["VK_AMD_device_coherent_memory"]) , ("VK_MEMORY_PROPERTY_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_OBJECT_TYPE_ACCELERATION_STRUCTURE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_OBJECT_TYPE_ACCELERATION_STRUCTURE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT" , & ["VK_EXT_debug_report"]) , ("VK_OBJECT_TYPE_DEBUG_UTILS_MESSENGER_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_OBJECT_TYPE_DEFERRED_OPERATION_KHR" , & ["VK_KHR_deferred_host_operations"]) , ("VK_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE" , & ["VK_VERSION_1_1"]) , ("VK_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_KHR" , & ["VK_KHR_descriptor_update_template"]) , ("VK_OBJECT_TYPE_DISPLAY_KHR" , & ["VK_KHR_display"]) , ("VK_OBJECT_TYPE_DISPLAY_MODE_KHR" , & ["VK_KHR_display"]) , ("VK_OBJECT_TYPE_INDIRECT_COMMANDS_LAYOUT_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_OBJECT_TYPE_PERFORMANCE_CONFIGURATION_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_OBJECT_TYPE_PRIVATE_DATA_SLOT_EXT" , & ["VK_EXT_private_data"]) , ("VK_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION" , & ["VK_VERSION_1_1"]) , ("VK_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_OBJECT_TYPE_SURFACE_KHR" , & ["VK_KHR_surface"]) , ("VK_OBJECT_TYPE_SWAPCHAIN_KHR" , & ["VK_KHR_swapchain"]) , ("VK_OBJECT_TYPE_VALIDATION_CACHE_EXT" , & ["VK_EXT_validation_cache"]) , ("VK_OPERATION_DEFERRED_KHR" , & ["VK_KHR_deferred_host_operations"]) , ("VK_OPERATION_NOT_DEFERRED_KHR" , & ["VK_KHR_deferred_host_operations"]) , ("VK_PEER_MEMORY_FEATURE_COPY_DST_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_PEER_MEMORY_FEATURE_COPY_SRC_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_PEER_MEMORY_FEATURE_GENERIC_DST_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_PEER_MEMORY_FEATURE_GENERIC_SRC_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_PIPELINE_BIND_POINT_RAY_TRACING_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_BIND_POINT_RAY_TRACING_NV" , & ["VK_NV_ray_tracing"]) , ("VK_PIPELINE_CACHE_CREATE_EXTERNALLY_SYNCHRONIZED_BIT_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_PIPELINE_CACHE_CREATE_RESERVED_1_BIT_EXT" , & ["VK_GOOGLE_extension_196"]) , ("VK_PIPELINE_CACHE_CREATE_RESERVED_2_BIT_EXT" , & ["VK_KHR_extension_350"]) , ("VK_PIPELINE_COMPILE_REQUIRED_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_PIPELINE_CREATE_CAPTURE_INTERNAL_REPRESENTATIONS_BIT_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_PIPELINE_CREATE_CAPTURE_STATISTICS_BIT_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_PIPELINE_CREATE_DEFER_COMPILE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_PIPELINE_CREATE_DISPATCH_BASE" , & ["VK_VERSION_1_1"]) , ("VK_PIPELINE_CREATE_DISPATCH_BASE_BIT" , & ["VK_VERSION_1_1"]) , ("VK_PIPELINE_CREATE_DISPATCH_BASE_KHR" , & ["VK_KHR_device_group"]) , ("VK_PIPELINE_CREATE_EARLY_RETURN_ON_FAILURE_BIT_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_PIPELINE_CREATE_FAIL_ON_PIPELINE_COMPILE_REQUIRED_BIT_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_PIPELINE_CREATE_INDIRECT_BINDABLE_BIT_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_PIPELINE_CREATE_LIBRARY_BIT_KHR" , & ["VK_KHR_pipeline_library"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_MISS_SHADERS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_SKIP_AABBS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_RAY_TRACING_SKIP_TRIANGLES_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_CREATE_VIEW_INDEX_FROM_DEVICE_INDEX_BIT" , & ["VK_VERSION_1_1"]) , ("VK_PIPELINE_CREATE_VIEW_INDEX_FROM_DEVICE_INDEX_BIT_KHR" , & ["VK_KHR_device_group"]) , ("VK_PIPELINE_SHADER_STAGE_CREATE_ALLOW_VARYING_SUBGROUP_SIZE_BIT_EXT" , & ["VK_EXT_subgroup_size_control"]) , ("VK_PIPELINE_SHADER_STAGE_CREATE_REQUIRE_FULL_SUBGROUPS_BIT_EXT" , & ["VK_EXT_subgroup_size_control"]) , ("VK_PIPELINE_SHADER_STAGE_CREATE_RESERVED_2_BIT_NV" , & ["VK_NV_extension_52"]) , ("VK_PIPELINE_SHADER_STAGE_CREATE_RESERVED_3_BIT_KHR" , & ["VK_KHR_extension_297"]) , ("VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_PIPELINE_STAGE_COMMAND_PREPROCESS_BIT_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_PIPELINE_STAGE_MESH_SHADER_BIT_NV" , & ["VK_NV_mesh_shader"]) , ("VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_PIPELINE_STAGE_RESERVED_26_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_PIPELINE_STAGE_RESERVED_27_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_PIPELINE_STAGE_SHADING_RATE_IMAGE_BIT_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_PIPELINE_STAGE_TASK_SHADER_BIT_NV" , & ["VK_NV_mesh_shader"]) , ("VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_POLYGON_MODE_FILL_RECTANGLE_NV" , & ["VK_NV_fill_rectangle"]) , ("VK_PRESENT_MODE_SHARED_CONTINUOUS_REFRESH_KHR" , & ["VK_KHR_shared_presentable_image"]) , ("VK_PRESENT_MODE_SHARED_DEMAND_REFRESH_KHR" , & ["VK_KHR_shared_presentable_image"]) , ("VK_QUERY_TYPE_ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_QUERY_TYPE_ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_QUERY_TYPE_ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_QUERY_TYPE_PERFORMANCE_QUERY_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_QUERY_TYPE_PERFORMANCE_QUERY_KHR" , & ["VK_KHR_performance_query"]) , ("VK_QUERY_TYPE_RESERVED_4" , & ["VK_AMD_extension_25"]) , ("VK_QUERY_TYPE_RESERVED_8" , & ["VK_AMD_extension_24"]) , ("VK_QUERY_TYPE_TRANSFORM_FEEDBACK_STREAM_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_QUEUE_PROTECTED_BIT" , & ["VK_VERSION_1_1"]) , ("VK_QUEUE_RESERVED_5_BIT_KHR" , & ["VK_AMD_extension_25"]) , ("VK_QUEUE_RESERVED_6_BIT_KHR" , & ["VK_AMD_extension_24"]) , ("VK_RAY_TRACING_SHADER_GROUP_TYPE_GENERAL_NV" , & ["VK_NV_ray_tracing"]) , ("VK_RAY_TRACING_SHADER_GROUP_TYPE_PROCEDURAL_HIT_GROUP_NV" , & ["VK_NV_ray_tracing"]) , ("VK_RAY_TRACING_SHADER_GROUP_TYPE_TRIANGLES_HIT_GROUP_NV" , & ["VK_NV_ray_tracing"]) , ("VK_RENDER_PASS_CREATE_RESERVED_0_BIT_KHR" , & ["VK_KHR_extension_221"]) , ("VK_RENDER_PASS_CREATE_TRANSFORM_BIT_QCOM" , & ["VK_QCOM_render_pass_transform"]) , ("VK_RESOLVE_MODE_AVERAGE_BIT_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_RESOLVE_MODE_MAX_BIT_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_RESOLVE_MODE_MIN_BIT_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_RESOLVE_MODE_NONE_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_RESOLVE_MODE_SAMPLE_ZERO_BIT_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_SAMPLER_ADDRESS_MODE_MIRROR_CLAMP_TO_EDGE" , & ["VK_VERSION_1_2" , "VK_KHR_sampler_mirror_clamp_to_edge"]) , ("VK_SAMPLER_ADDRESS_MODE_MIRROR_CLAMP_TO_EDGE_KHR" , & ["VK_KHR_sampler_mirror_clamp_to_edge"]) , ("VK_SAMPLER_CREATE_SUBSAMPLED_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_SAMPLER_CREATE_SUBSAMPLED_COARSE_RECONSTRUCTION_BIT_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_SAMPLER_REDUCTION_MODE_MAX_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_SAMPLER_REDUCTION_MODE_MIN_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_SAMPLER_YCBCR_MODEL_CONVERSION_RGB_IDENTITY_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_2020_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_601_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_709_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_IDENTITY_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_RANGE_ITU_FULL_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SAMPLER_YCBCR_RANGE_ITU_NARROW_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_SEMAPHORE_IMPORT_TEMPORARY_BIT_KHR" , & ["VK_KHR_external_semaphore"]) , ("VK_SEMAPHORE_TYPE_BINARY_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_SEMAPHORE_TYPE_TIMELINE_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_SEMAPHORE_WAIT_ANY_BIT_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_BIT_ONLY_KHR" , & ["VK_KHR_shader_float_controls"]) , ("VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL_KHR" , & ["VK_KHR_shader_float_controls"]) , ("VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_NONE_KHR" , & ["VK_KHR_shader_float_controls"]) , ("VK_SHADER_MODULE_CREATE_RESERVED_0_BIT_NV" , & ["VK_NV_extension_52"]) , ("VK_SHADER_STAGE_ANY_HIT_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_ANY_HIT_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_CALLABLE_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_CALLABLE_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_CLOSEST_HIT_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_CLOSEST_HIT_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_INTERSECTION_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_INTERSECTION_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_MESH_BIT_NV" , & ["VK_NV_mesh_shader"]) , ("VK_SHADER_STAGE_MISS_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_MISS_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_RAYGEN_BIT_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_SHADER_STAGE_RAYGEN_BIT_NV" , & ["VK_NV_ray_tracing"]) , ("VK_SHADER_STAGE_TASK_BIT_NV" , & ["VK_NV_mesh_shader"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CREATE_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CREATE_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_VERSION_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_ACQUIRE_NEXT_IMAGE_INFO_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_ACQUIRE_PROFILING_LOCK_INFO_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_USAGE_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_ANDROID_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_android_surface"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_2" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_2_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_2" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_2_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_STENCIL_LAYOUT" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_STENCIL_LAYOUT_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_STRUCTURE_TYPE_BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_INFO_KHR" , & ["VK_KHR_bind_memory2"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_INFO_KHR" , & ["VK_KHR_bind_memory2"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_PLANE_MEMORY_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BIND_IMAGE_PLANE_MEMORY_INFO_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_BLIT_IMAGE_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_BUFFER_COPY_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_BUFFER_IMAGE_COPY_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_BUFFER_MEMORY_REQUIREMENTS_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_BUFFER_MEMORY_REQUIREMENTS_INFO_2_KHR" , & ["VK_KHR_get_memory_requirements2"]) , ("VK_STRUCTURE_TYPE_BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_CALIBRATED_TIMESTAMP_INFO_EXT" , & ["VK_EXT_calibrated_timestamps"]) , ("VK_STRUCTURE_TYPE_CHECKPOINT_DATA_NV" , & ["VK_NV_device_diagnostic_checkpoints"]) , ("VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM" , & ["VK_QCOM_render_pass_transform"]) , ("VK_STRUCTURE_TYPE_CONDITIONAL_RENDERING_BEGIN_INFO_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_STRUCTURE_TYPE_COOPERATIVE_MATRIX_PROPERTIES_NV" , & ["VK_NV_cooperative_matrix"]) , ("VK_STRUCTURE_TYPE_COPY_ACCELERATION_STRUCTURE_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_COPY_BUFFER_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_COPY_BUFFER_TO_IMAGE_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_COPY_COMMAND_TRANSFORM_INFO_QCOM" , & ["VK_QCOM_rotated_copy_commands"]) , ("VK_STRUCTURE_TYPE_COPY_IMAGE_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_COPY_IMAGE_TO_BUFFER_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_D3D12_FENCE_SUBMIT_INFO_KHR" , & ["VK_KHR_external_semaphore_win32"]) , ("VK_STRUCTURE_TYPE_DEBUG_MARKER_MARKER_INFO_EXT" , & ["VK_EXT_debug_marker"]) , ("VK_STRUCTURE_TYPE_DEBUG_MARKER_OBJECT_NAME_INFO_EXT" , & ["VK_EXT_debug_marker"]) , ("VK_STRUCTURE_TYPE_DEBUG_MARKER_OBJECT_TAG_INFO_EXT" , & ["VK_EXT_debug_marker"]) , ("VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT" , & ["VK_EXT_debug_report"]) , ("VK_STRUCTURE_TYPE_DEBUG_REPORT_CREATE_INFO_EXT" , & ["VK_EXT_debug_report"]) , ("VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_TAG_INFO_EXT" , & ["VK_EXT_debug_utils"]) , ("VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV" , & ["VK_NV_dedicated_allocation"]) , ("VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV" , & ["VK_NV_dedicated_allocation"]) , ("VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV" , & ["VK_NV_dedicated_allocation"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT" , & ["VK_EXT_inline_uniform_block"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_SUPPORT" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR" , & ["VK_KHR_maintenance3"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR" , & ["VK_KHR_descriptor_update_template"]) , ("VK_STRUCTURE_TYPE_DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT" , & ["VK_EXT_device_memory_report"]) , ("VK_STRUCTURE_TYPE_DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV" , & ["VK_NV_device_diagnostics_config"]) , ("VK_STRUCTURE_TYPE_DEVICE_EVENT_INFO_EXT" , & ["VK_EXT_display_control"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_BIND_SPARSE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_BIND_SPARSE_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_DEVICE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_DEVICE_CREATE_INFO_KHR" , & ["VK_KHR_device_group_creation"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_PRESENT_CAPABILITIES_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_PRESENT_INFO_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_RENDER_PASS_BEGIN_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_RENDER_PASS_BEGIN_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_SUBMIT_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_SUBMIT_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD" , & ["VK_AMD_memory_overallocation_behavior"]) , ("VK_STRUCTURE_TYPE_DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT" , & ["VK_EXT_device_memory_report"]) , ("VK_STRUCTURE_TYPE_DEVICE_PRIVATE_DATA_CREATE_INFO_EXT" , & ["VK_EXT_private_data"]) , ("VK_STRUCTURE_TYPE_DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT" , & ["VK_EXT_global_priority"]) , ("VK_STRUCTURE_TYPE_DEVICE_QUEUE_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_DIRECTFB_SURFACE_CREATE_INFO_EXT" , & ["VK_EXT_directfb_surface"]) , ("VK_STRUCTURE_TYPE_DISPLAY_EVENT_INFO_EXT" , & ["VK_EXT_display_control"]) , ("VK_STRUCTURE_TYPE_DISPLAY_MODE_CREATE_INFO_KHR" , & ["VK_KHR_display"]) , ("VK_STRUCTURE_TYPE_DISPLAY_MODE_PROPERTIES_2_KHR" , & ["VK_KHR_get_display_properties2"]) , ("VK_STRUCTURE_TYPE_DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD" , & ["VK_AMD_display_native_hdr"]) , ("VK_STRUCTURE_TYPE_DISPLAY_PLANE_CAPABILITIES_2_KHR" , & ["VK_KHR_get_display_properties2"]) , ("VK_STRUCTURE_TYPE_DISPLAY_PLANE_INFO_2_KHR" , & ["VK_KHR_get_display_properties2"]) , ("VK_STRUCTURE_TYPE_DISPLAY_PLANE_PROPERTIES_2_KHR" , & ["VK_KHR_get_display_properties2"]) , ("VK_STRUCTURE_TYPE_DISPLAY_POWER_INFO_EXT" , & ["VK_EXT_display_control"]) , ("VK_STRUCTURE_TYPE_DISPLAY_PRESENT_INFO_KHR" , & ["VK_KHR_display_swapchain"]) , ("VK_STRUCTURE_TYPE_DISPLAY_PROPERTIES_2_KHR" , & ["VK_KHR_get_display_properties2"]) , ("VK_STRUCTURE_TYPE_DISPLAY_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_display"]) , ("VK_STRUCTURE_TYPE_DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_STRUCTURE_TYPE_EXPORT_FENCE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXPORT_FENCE_CREATE_INFO_KHR" , & ["VK_KHR_external_fence"]) , ("VK_STRUCTURE_TYPE_EXPORT_FENCE_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_fence_win32"]) , ("VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO_KHR" , & ["VK_KHR_external_memory"]) , ("VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO_NV" , & ["VK_NV_external_memory"]) , ("VK_STRUCTURE_TYPE_EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_EXPORT_MEMORY_WIN32_HANDLE_INFO_NV" , & ["VK_NV_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO_KHR" , & ["VK_KHR_external_semaphore"]) , ("VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_semaphore_win32"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_BUFFER_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_BUFFER_PROPERTIES_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_FENCE_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_FENCE_PROPERTIES_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_FORMAT_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_IMAGE_FORMAT_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_IMAGE_FORMAT_PROPERTIES_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_BUFFER_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_BUFFER_CREATE_INFO_KHR" , & ["VK_KHR_external_memory"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO_KHR" , & ["VK_KHR_external_memory"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV" , & ["VK_NV_external_memory"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_SEMAPHORE_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_EXTERNAL_SEMAPHORE_PROPERTIES_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_STRUCTURE_TYPE_FENCE_GET_FD_INFO_KHR" , & ["VK_KHR_external_fence_fd"]) , ("VK_STRUCTURE_TYPE_FENCE_GET_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_fence_win32"]) , ("VK_STRUCTURE_TYPE_FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT" , & ["VK_EXT_filter_cubic"]) , ("VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENTS_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENTS_CREATE_INFO_KHR" , & ["VK_KHR_imageless_framebuffer"]) , ("VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENT_IMAGE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENT_IMAGE_INFO_KHR" , & ["VK_KHR_imageless_framebuffer"]) , ("VK_STRUCTURE_TYPE_FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV" , & ["VK_NV_coverage_reduction_mode"]) , ("VK_STRUCTURE_TYPE_GENERATED_COMMANDS_INFO_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_GEOMETRY_AABB_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_GEOMETRY_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_GEOMETRY_TRIANGLES_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_GRAPHICS_SHADER_GROUP_CREATE_INFO_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_HDR_METADATA_EXT" , & ["VK_EXT_hdr_metadata"]) , ("VK_STRUCTURE_TYPE_HEADLESS_SURFACE_CREATE_INFO_EXT" , & ["VK_EXT_headless_surface"]) , ("VK_STRUCTURE_TYPE_IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA" , & ["VK_FUCHSIA_imagepipe_surface"]) , ("VK_STRUCTURE_TYPE_IMAGE_BLIT_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_IMAGE_COPY_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_STRUCTURE_TYPE_IMAGE_FORMAT_LIST_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_IMAGE_FORMAT_LIST_CREATE_INFO_KHR" , & ["VK_KHR_image_format_list"]) , ("VK_STRUCTURE_TYPE_IMAGE_FORMAT_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_IMAGE_FORMAT_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_IMAGE_MEMORY_REQUIREMENTS_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_IMAGE_MEMORY_REQUIREMENTS_INFO_2_KHR" , & ["VK_KHR_get_memory_requirements2"]) , ("VK_STRUCTURE_TYPE_IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_IMAGE_RESOLVE_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2_KHR" , & ["VK_KHR_get_memory_requirements2"]) , ("VK_STRUCTURE_TYPE_IMAGE_STENCIL_USAGE_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_IMAGE_STENCIL_USAGE_CREATE_INFO_EXT" , & ["VK_EXT_separate_stencil_usage"]) , ("VK_STRUCTURE_TYPE_IMAGE_SWAPCHAIN_CREATE_INFO_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_IMAGE_VIEW_ADDRESS_PROPERTIES_NVX" , & ["VK_NVX_image_view_handle"]) , ("VK_STRUCTURE_TYPE_IMAGE_VIEW_ASTC_DECODE_MODE_EXT" , & ["VK_EXT_astc_decode_mode"]) , ("VK_STRUCTURE_TYPE_IMAGE_VIEW_HANDLE_INFO_NVX" , & ["VK_NVX_image_view_handle"]) , ("VK_STRUCTURE_TYPE_IMAGE_VIEW_USAGE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_IMAGE_VIEW_USAGE_CREATE_INFO_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_STRUCTURE_TYPE_IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_IMPORT_FENCE_FD_INFO_KHR" , & ["VK_KHR_external_fence_fd"]) , ("VK_STRUCTURE_TYPE_IMPORT_FENCE_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_fence_win32"]) , ("VK_STRUCTURE_TYPE_IMPORT_MEMORY_FD_INFO_KHR" , & ["VK_KHR_external_memory_fd"]) , ("VK_STRUCTURE_TYPE_IMPORT_MEMORY_HOST_POINTER_INFO_EXT" , & ["VK_EXT_external_memory_host"]) , ("VK_STRUCTURE_TYPE_IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_IMPORT_MEMORY_WIN32_HANDLE_INFO_NV" , & ["VK_NV_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_IMPORT_SEMAPHORE_FD_INFO_KHR" , & ["VK_KHR_external_semaphore_fd"]) , ("VK_STRUCTURE_TYPE_IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_semaphore_win32"]) , ("VK_STRUCTURE_TYPE_INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_INDIRECT_COMMANDS_LAYOUT_TOKEN_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_INITIALIZE_PERFORMANCE_API_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_IOS_SURFACE_CREATE_INFO_MVK" , & ["VK_MVK_ios_surface"]) , ("VK_STRUCTURE_TYPE_MACOS_SURFACE_CREATE_INFO_MVK" , & ["VK_MVK_macos_surface"]) , ("VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_FLAGS_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_FLAGS_INFO_KHR" , & ["VK_KHR_device_group"]) , ("VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO_KHR" , & ["VK_KHR_dedicated_allocation"]) , ("VK_STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS_KHR" , & ["VK_KHR_dedicated_allocation"]) , ("VK_STRUCTURE_TYPE_MEMORY_FD_PROPERTIES_KHR" , & ["VK_KHR_external_memory_fd"]) , ("VK_STRUCTURE_TYPE_MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID" , & ["VK_ANDROID_external_memory_android_hardware_buffer"]) , ("VK_STRUCTURE_TYPE_MEMORY_GET_FD_INFO_KHR" , & ["VK_KHR_external_memory_fd"]) , ("VK_STRUCTURE_TYPE_MEMORY_GET_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_MEMORY_HOST_POINTER_PROPERTIES_EXT" , & ["VK_EXT_external_memory_host"]) , ("VK_STRUCTURE_TYPE_MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_MEMORY_PRIORITY_ALLOCATE_INFO_EXT" , & ["VK_EXT_memory_priority"]) , ("VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2_KHR" , & ["VK_KHR_get_memory_requirements2"]) , ("VK_STRUCTURE_TYPE_MEMORY_WIN32_HANDLE_PROPERTIES_KHR" , & ["VK_KHR_external_memory_win32"]) , ("VK_STRUCTURE_TYPE_METAL_SURFACE_CREATE_INFO_EXT" , & ["VK_EXT_metal_surface"]) , ("VK_STRUCTURE_TYPE_MULTISAMPLE_PROPERTIES_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_STRUCTURE_TYPE_NATIVE_BUFFER_ANDROID" , & ["VK_ANDROID_native_buffer"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_COUNTER_DESCRIPTION_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_COUNTER_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_MARKER_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_OVERRIDE_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_QUERY_SUBMIT_INFO_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_PERFORMANCE_STREAM_MARKER_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES_KHR" , & ["VK_KHR_16bit_storage"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT" , & ["VK_EXT_4444_formats"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR" , & ["VK_KHR_8bit_storage"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT" , & ["VK_EXT_astc_decode_mode"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_ADDRESS_FEATURES_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT" , & ["VK_EXT_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_KHR" , & ["VK_KHR_buffer_device_address"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD" , & ["VK_AMD_device_coherent_memory"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV" , & ["VK_NV_compute_shader_derivatives"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT" , & ["VK_EXT_conditional_rendering"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT" , & ["VK_EXT_conservative_rasterization"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV" , & ["VK_NV_cooperative_matrix"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV" , & ["VK_NV_cooperative_matrix"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV" , & ["VK_NV_corner_sampled_image"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV" , & ["VK_NV_coverage_reduction_mode"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT" , & ["VK_EXT_custom_border_color"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT" , & ["VK_EXT_custom_border_color"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV" , & ["VK_NV_dedicated_allocation_image_aliasing"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT" , & ["VK_EXT_depth_clip_enable"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT" , & ["VK_EXT_descriptor_indexing"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV" , & ["VK_NV_device_generated_commands"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT" , & ["VK_EXT_device_memory_report"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV" , & ["VK_NV_device_diagnostics_config"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT" , & ["VK_EXT_discard_rectangles"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR" , & ["VK_KHR_driver_properties"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV" , & ["VK_NV_scissor_exclusive"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT" , & ["VK_EXT_extended_dynamic_state"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO_KHR" , & ["VK_KHR_external_fence_capabilities"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO_KHR" , & ["VK_KHR_external_memory_capabilities"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT" , & ["VK_EXT_external_memory_host"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO_KHR" , & ["VK_KHR_external_semaphore_capabilities"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR" , & ["VK_KHR_shader_float16_int8"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES_KHR" , & ["VK_KHR_shader_float_controls"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT" , & ["VK_EXT_fragment_density_map2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT" , & ["VK_EXT_fragment_density_map2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV" , & ["VK_NV_fragment_shader_barycentric"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT" , & ["VK_EXT_fragment_shader_interlock"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV" , & ["VK_NV_fragment_shading_rate_enums"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV" , & ["VK_NV_fragment_shading_rate_enums"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES_KHR" , & ["VK_KHR_device_group_creation"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES_EXT" , & ["VK_EXT_host_query_reset"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES_KHR" , & ["VK_KHR_external_memory_capabilities" , "VK_KHR_external_semaphore_capabilities" , "VK_KHR_external_fence_capabilities"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR" , & ["VK_KHR_imageless_framebuffer"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT" , & ["VK_EXT_image_drm_format_modifier"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT" , & ["VK_EXT_image_robustness"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT" , & ["VK_EXT_filter_cubic"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT" , & ["VK_EXT_index_type_uint8"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT" , & ["VK_EXT_inline_uniform_block"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT" , & ["VK_EXT_inline_uniform_block"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT" , & ["VK_EXT_line_rasterization"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT" , & ["VK_EXT_line_rasterization"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES_KHR" , & ["VK_KHR_maintenance3"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT" , & ["VK_EXT_memory_budget"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT" , & ["VK_EXT_memory_priority"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV" , & ["VK_NV_mesh_shader"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV" , & ["VK_NV_mesh_shader"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_FEATURES_KHR" , & ["VK_KHR_multiview"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX" , & ["VK_NVX_multiview_per_view_attributes"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES_KHR" , & ["VK_KHR_multiview"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT" , & ["VK_EXT_pci_bus_info"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT" , & ["VK_EXT_pipeline_creation_cache_control"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR" , & ["VK_KHR_portability_subset"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR" , & ["VK_KHR_portability_subset"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID" , & ["VK_ANDROID_native_buffer"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT" , & ["VK_EXT_private_data"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR" , & ["VK_KHR_push_descriptor"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR" , & ["VK_KHR_ray_query"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV" , & ["VK_NV_representative_fragment_test"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT" , & ["VK_EXT_robustness2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT" , & ["VK_EXT_robustness2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES_EXT" , & ["VK_EXT_scalar_block_layout"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES_KHR" , & ["VK_KHR_separate_depth_stencil_layouts"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT" , & ["VK_EXT_shader_atomic_float"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR" , & ["VK_KHR_shader_atomic_int64"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR" , & ["VK_KHR_shader_clock"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD" , & ["VK_AMD_shader_core_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD" , & ["VK_AMD_shader_core_properties"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT" , & ["VK_EXT_shader_demote_to_helper_invocation"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES_KHR" , & ["VK_KHR_shader_float16_int8"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT" , & ["VK_EXT_shader_image_atomic_int64"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV" , & ["VK_NV_shader_image_footprint"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL" , & ["VK_INTEL_shader_integer_functions2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV" , & ["VK_NV_shader_sm_builtins"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV" , & ["VK_NV_shader_sm_builtins"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES_KHR" , & ["VK_KHR_shader_subgroup_extended_types"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR" , & ["VK_KHR_shader_terminate_invocation"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT" , & ["VK_EXT_subgroup_size_control"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT" , & ["VK_EXT_subgroup_size_control"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SURFACE_INFO_2_KHR" , & ["VK_KHR_get_surface_capabilities2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT" , & ["VK_EXT_texel_buffer_alignment"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT" , & ["VK_EXT_texel_buffer_alignment"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT" , & ["VK_EXT_texture_compression_astc_hdr"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT" , & ["VK_EXT_tooling_info"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES_KHR" , & ["VK_KHR_uniform_buffer_standard_layout"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR" , & ["VK_KHR_variable_pointers"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES_KHR" , & ["VK_KHR_variable_pointers"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT" , & ["VK_EXT_vertex_attribute_divisor"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT" , & ["VK_EXT_vertex_attribute_divisor"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR" , & ["VK_KHR_vulkan_memory_model"]) , ("VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT" , & ["VK_EXT_ycbcr_image_arrays"]) , ("VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT" , & ["VK_EXT_blend_operation_advanced"]) , ("VK_STRUCTURE_TYPE_PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD" , & ["VK_AMD_pipeline_compiler_control"]) , ("VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV" , & ["VK_NV_framebuffer_mixed_samples"]) , ("VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV" , & ["VK_NV_coverage_reduction_mode"]) , ("VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV" , & ["VK_NV_fragment_coverage_to_color"]) , ("VK_STRUCTURE_TYPE_PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT" , & ["VK_EXT_pipeline_creation_feedback"]) , ("VK_STRUCTURE_TYPE_PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT" , & ["VK_EXT_discard_rectangles"]) , ("VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_INFO_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_PROPERTIES_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_STATISTIC_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV" , & ["VK_NV_fragment_shading_rate_enums"]) , ("VK_STRUCTURE_TYPE_PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR" , & ["VK_KHR_fragment_shading_rate"]) , ("VK_STRUCTURE_TYPE_PIPELINE_INFO_KHR" , & ["VK_KHR_pipeline_executable_properties"]) , ("VK_STRUCTURE_TYPE_PIPELINE_LIBRARY_CREATE_INFO_KHR" , & ["VK_KHR_pipeline_library"]) , ("VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT" , & ["VK_EXT_conservative_rasterization"]) , ("VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT" , & ["VK_EXT_depth_clip_enable"]) , ("VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT" , & ["VK_EXT_line_rasterization"]) , ("VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD" , & ["VK_AMD_rasterization_order"]) , ("VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT" , & ["VK_EXT_transform_feedback"]) , ("VK_STRUCTURE_TYPE_PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV" , & ["VK_NV_representative_fragment_test"]) , ("VK_STRUCTURE_TYPE_PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT" , & ["VK_EXT_subgroup_size_control"]) , ("VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT" , & ["VK_EXT_vertex_attribute_divisor"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV" , & ["VK_NV_scissor_exclusive"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV" , & ["VK_NV_shading_rate_image"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV" , & ["VK_NV_viewport_swizzle"]) , ("VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV" , & ["VK_NV_clip_space_w_scaling"]) , ("VK_STRUCTURE_TYPE_PRESENT_FRAME_TOKEN_GGP" , & ["VK_GGP_frame_token"]) , ("VK_STRUCTURE_TYPE_PRESENT_INFO_KHR" , & ["VK_KHR_swapchain"]) , ("VK_STRUCTURE_TYPE_PRESENT_REGIONS_KHR" , & ["VK_KHR_incremental_present"]) , ("VK_STRUCTURE_TYPE_PRESENT_TIMES_INFO_GOOGLE" , & ["VK_GOOGLE_display_timing"]) , ("VK_STRUCTURE_TYPE_PRIVATE_DATA_SLOT_CREATE_INFO_EXT" , & ["VK_EXT_private_data"]) , ("VK_STRUCTURE_TYPE_PROTECTED_SUBMIT_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_QUERY_POOL_CREATE_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR" , & ["VK_KHR_performance_query"]) , ("VK_STRUCTURE_TYPE_QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL" , & ["VK_INTEL_performance_query"]) , ("VK_STRUCTURE_TYPE_QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV" , & ["VK_NV_device_diagnostic_checkpoints"]) , ("VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_CREATE_INFO_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_CREATE_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_STRUCTURE_TYPE_RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR" , & ["VK_KHR_ray_tracing_pipeline"]) , ("VK_STRUCTURE_TYPE_RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_ATTACHMENT_BEGIN_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_ATTACHMENT_BEGIN_INFO_KHR" , & ["VK_KHR_imageless_framebuffer"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO_2" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO_2_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT" , & ["VK_EXT_fragment_density_map"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_MULTIVIEW_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_MULTIVIEW_CREATE_INFO_KHR" , & ["VK_KHR_multiview"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_STRUCTURE_TYPE_RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM" , & ["VK_QCOM_render_pass_transform"]) , ("VK_STRUCTURE_TYPE_RESERVED_QCOM" , & ["VK_QCOM_extension_310"]) , ("VK_STRUCTURE_TYPE_RESOLVE_IMAGE_INFO_2_KHR" , & ["VK_KHR_copy_commands2"]) , ("VK_STRUCTURE_TYPE_SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT" , & ["VK_EXT_custom_border_color"]) , ("VK_STRUCTURE_TYPE_SAMPLER_REDUCTION_MODE_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT" , & ["VK_EXT_sampler_filter_minmax"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_CREATE_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_CREATE_INFO_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_INFO" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_INFO_KHR" , & ["VK_KHR_sampler_ycbcr_conversion"]) , ("VK_STRUCTURE_TYPE_SAMPLE_LOCATIONS_INFO_EXT" , & ["VK_EXT_sample_locations"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_GET_FD_INFO_KHR" , & ["VK_KHR_external_semaphore_fd"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR" , & ["VK_KHR_external_semaphore_win32"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT" , & ["VK_EXT_validation_cache"]) , ("VK_STRUCTURE_TYPE_SHARED_PRESENT_SURFACE_CAPABILITIES_KHR" , & ["VK_KHR_shared_presentable_image"]) , ("VK_STRUCTURE_TYPE_SPARSE_IMAGE_FORMAT_PROPERTIES_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_SPARSE_IMAGE_FORMAT_PROPERTIES_2_KHR" , & ["VK_KHR_get_physical_device_properties2"]) , ("VK_STRUCTURE_TYPE_SPARSE_IMAGE_MEMORY_REQUIREMENTS_2" , & ["VK_VERSION_1_1"]) , ("VK_STRUCTURE_TYPE_SPARSE_IMAGE_MEMORY_REQUIREMENTS_2_KHR" , & ["VK_KHR_get_memory_requirements2"]) , ("VK_STRUCTURE_TYPE_STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP" , & ["VK_GGP_stream_descriptor_surface"]) , ("VK_STRUCTURE_TYPE_SUBPASS_BEGIN_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_BEGIN_INFO_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DEPENDENCY_2" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DEPENDENCY_2_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_2" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_2_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE_KHR" , & ["VK_KHR_depth_stencil_resolve"]) , ("VK_STRUCTURE_TYPE_SUBPASS_END_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_SUBPASS_END_INFO_KHR" , & ["VK_KHR_create_renderpass2"]) , ("VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES2_EXT" , & ["VK_EXT_display_surface_counter"]) , ("VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_2_EXT" , & ["VK_EXT_display_surface_counter"]) , ("VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_2_KHR" , & ["VK_KHR_get_surface_capabilities2"]) , ("VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT" , & ["VK_EXT_full_screen_exclusive"]) , ("VK_STRUCTURE_TYPE_SURFACE_FORMAT_2_KHR" , & ["VK_KHR_get_surface_capabilities2"]) , ("VK_STRUCTURE_TYPE_SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT" , & ["VK_EXT_full_screen_exclusive"]) , ("VK_STRUCTURE_TYPE_SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT" , & ["VK_EXT_full_screen_exclusive"]) , ("VK_STRUCTURE_TYPE_SURFACE_PROTECTED_CAPABILITIES_KHR" , & ["VK_KHR_surface_protected_capabilities"]) , ("VK_STRUCTURE_TYPE_SWAPCHAIN_COUNTER_CREATE_INFO_EXT" , & ["VK_EXT_display_control"]) , ("VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR" , & ["VK_KHR_swapchain"]) , ("VK_STRUCTURE_TYPE_SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD" , & ["VK_AMD_display_native_hdr"]) , ("VK_STRUCTURE_TYPE_SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID" , & ["VK_ANDROID_native_buffer"]) , ("VK_STRUCTURE_TYPE_TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD" , & ["VK_AMD_texture_gather_bias_lod"]) , ("VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO" , & ["VK_VERSION_1_2"]) , ("VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO_KHR" , & ["VK_KHR_timeline_semaphore"]) , ("VK_STRUCTURE_TYPE_VALIDATION_CACHE_CREATE_INFO_EXT" , & ["VK_EXT_validation_cache"]) , ("VK_STRUCTURE_TYPE_VALIDATION_FEATURES_EXT" , & ["VK_EXT_validation_features"]) , ("VK_STRUCTURE_TYPE_VALIDATION_FLAGS_EXT" , & ["VK_EXT_validation_flags"]) , ("VK_STRUCTURE_TYPE_VI_SURFACE_CREATE_INFO_NN" , & ["VK_NN_vi_surface"]) , ("VK_STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_wayland_surface"]) , ("VK_STRUCTURE_TYPE_WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR" , & ["VK_KHR_win32_keyed_mutex"]) , ("VK_STRUCTURE_TYPE_WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV" , & ["VK_NV_win32_keyed_mutex"]) , ("VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_win32_surface"]) , ("VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR" , & ["VK_KHR_acceleration_structure"]) , ("VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV" , & ["VK_NV_ray_tracing"]) , ("VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT" , & ["VK_EXT_inline_uniform_block"]) , ("VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_xcb_surface"]) , ("VK_STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR" , & ["VK_KHR_xlib_surface"]) , ("VK_SUBGROUP_FEATURE_PARTITIONED_BIT_NV" , & ["VK_NV_shader_subgroup_partitioned"]) , ("VK_SUBOPTIMAL_KHR" , & ["VK_KHR_swapchain"]) , ("VK_SUBPASS_DESCRIPTION_FRAGMENT_REGION_BIT_QCOM" , & ["VK_QCOM_render_pass_shader_resolve"]) , ("VK_SUBPASS_DESCRIPTION_PER_VIEW_ATTRIBUTES_BIT_NVX" , & ["VK_NVX_multiview_per_view_attributes"]) , ("VK_SUBPASS_DESCRIPTION_PER_VIEW_POSITION_X_ONLY_BIT_NVX" , & ["VK_NVX_multiview_per_view_attributes"]) , ("VK_SUBPASS_DESCRIPTION_SHADER_RESOLVE_BIT_QCOM" , & ["VK_QCOM_render_pass_shader_resolve"]) , ("VK_SWAPCHAIN_CREATE_MUTABLE_FORMAT_BIT_KHR" , & ["VK_KHR_swapchain_mutable_format"]) , ("VK_SWAPCHAIN_CREATE_PROTECTED_BIT_KHR" , & ["VK_KHR_swapchain"]) , ("VK_SWAPCHAIN_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT_KHR" , & ["VK_KHR_swapchain" , "VK_KHR_device_group"]) , ("VK_TESSELLATION_DOMAIN_ORIGIN_LOWER_LEFT_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_TESSELLATION_DOMAIN_ORIGIN_UPPER_LEFT_KHR" , & ["VK_KHR_maintenance2"]) , ("VK_THREAD_DONE_KHR" , & ["VK_KHR_deferred_host_operations"]) , ("VK_THREAD_IDLE_KHR" , & ["VK_KHR_deferred_host_operations"]) , ("VK_TOOL_PURPOSE_DEBUG_MARKERS_BIT_EXT" , & ["VK_EXT_tooling_info"]) , ("VK_TOOL_PURPOSE_DEBUG_REPORTING_BIT_EXT" , & ["VK_EXT_tooling_info"])] ;
+#[doc = r" The parameters of a command that the application has to synchronize externally, or"]
+#[doc = r" members of them like `pBindInfo[].pBufferBinds[].buffer`, e.g."]
+#[doc = r#" `("vkQueueSubmit", &["queue", "fence"])`. Sorted by command name."#]
+pub const EXTERN_SYNC_PARAMS: &[(&str, &[&str])] = &[
+    (
+        "vkAcquireNextImageKHR",
+        &["swapchain", "semaphore", "fence"],
+    ),
+    ("vkAllocateCommandBuffers", &["pAllocateInfo->commandPool"]),
+    (
+        "vkAllocateDescriptorSets",
+        &["pAllocateInfo->descriptorPool"],
+    ),
+    ("vkBeginCommandBuffer", &["commandBuffer"]),
+    ("vkBindBufferMemory", &["buffer"]),
+    ("vkBindImageMemory", &["image"]),
+    ("vkCmdBeginConditionalRenderingEXT", &["commandBuffer"]),
+    ("vkCmdBeginDebugUtilsLabelEXT", &["commandBuffer"]),
+    ("vkCmdBeginQuery", &["commandBuffer"]),
+    ("vkCmdBeginQueryIndexedEXT", &["commandBuffer"]),
+    ("vkCmdBeginRenderPass", &["commandBuffer"]),
+    ("vkCmdBeginRenderPass2", &["commandBuffer"]),
+    ("vkCmdBeginRenderPass2KHR", &["commandBuffer"]),
+    ("vkCmdBeginTransformFeedbackEXT", &["commandBuffer"]),
+    ("vkCmdBindDescriptorSets", &["commandBuffer"]),
+    ("vkCmdBindIndexBuffer", &["commandBuffer"]),
+    ("vkCmdBindPipeline", &["commandBuffer"]),
+    ("vkCmdBindPipelineShaderGroupNV", &["commandBuffer"]),
+    ("vkCmdBindShadingRateImageNV", &["commandBuffer"]),
+    ("vkCmdBindTransformFeedbackBuffersEXT", &["commandBuffer"]),
+    ("vkCmdBindVertexBuffers", &["commandBuffer"]),
+    ("vkCmdBindVertexBuffers2EXT", &["commandBuffer"]),
+    ("vkCmdBlitImage", &["commandBuffer"]),
+    ("vkCmdBlitImage2KHR", &["commandBuffer"]),
+    ("vkCmdBuildAccelerationStructureNV", &["commandBuffer"]),
+    (
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        &["commandBuffer"],
+    ),
+    ("vkCmdBuildAccelerationStructuresKHR", &["commandBuffer"]),
+    ("vkCmdClearAttachments", &["commandBuffer"]),
+    ("vkCmdClearColorImage", &["commandBuffer"]),
+    ("vkCmdClearDepthStencilImage", &["commandBuffer"]),
+    ("vkCmdCopyAccelerationStructureKHR", &["commandBuffer"]),
+    ("vkCmdCopyAccelerationStructureNV", &["commandBuffer"]),
+    (
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        &["commandBuffer"],
+    ),
+    ("vkCmdCopyBuffer", &["commandBuffer"]),
+    ("vkCmdCopyBuffer2KHR", &["commandBuffer"]),
+    ("vkCmdCopyBufferToImage", &["commandBuffer"]),
+    ("vkCmdCopyBufferToImage2KHR", &["commandBuffer"]),
+    ("vkCmdCopyImage", &["commandBuffer"]),
+    ("vkCmdCopyImage2KHR", &["commandBuffer"]),
+    ("vkCmdCopyImageToBuffer", &["commandBuffer"]),
+    ("vkCmdCopyImageToBuffer2KHR", &["commandBuffer"]),
+    (
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        &["commandBuffer"],
+    ),
+    ("vkCmdCopyQueryPoolResults", &["commandBuffer"]),
+    ("vkCmdDebugMarkerBeginEXT", &["commandBuffer"]),
+    ("vkCmdDebugMarkerEndEXT", &["commandBuffer"]),
+    ("vkCmdDebugMarkerInsertEXT", &["commandBuffer"]),
+    ("vkCmdDispatch", &["commandBuffer"]),
+    ("vkCmdDispatchBase", &["commandBuffer"]),
+    ("vkCmdDispatchBaseKHR", &["commandBuffer"]),
+    ("vkCmdDispatchIndirect", &["commandBuffer"]),
+    ("vkCmdDraw", &["commandBuffer"]),
+    ("vkCmdDrawIndexed", &["commandBuffer"]),
+    ("vkCmdDrawIndexedIndirect", &["commandBuffer"]),
+    ("vkCmdDrawIndexedIndirectCount", &["commandBuffer"]),
+    ("vkCmdDrawIndexedIndirectCountAMD", &["commandBuffer"]),
+    ("vkCmdDrawIndexedIndirectCountKHR", &["commandBuffer"]),
+    ("vkCmdDrawIndirect", &["commandBuffer"]),
+    ("vkCmdDrawIndirectByteCountEXT", &["commandBuffer"]),
+    ("vkCmdDrawIndirectCount", &["commandBuffer"]),
+    ("vkCmdDrawIndirectCountAMD", &["commandBuffer"]),
+    ("vkCmdDrawIndirectCountKHR", &["commandBuffer"]),
+    ("vkCmdDrawMeshTasksIndirectCountNV", &["commandBuffer"]),
+    ("vkCmdDrawMeshTasksIndirectNV", &["commandBuffer"]),
+    ("vkCmdDrawMeshTasksNV", &["commandBuffer"]),
+    ("vkCmdEndConditionalRenderingEXT", &["commandBuffer"]),
+    ("vkCmdEndDebugUtilsLabelEXT", &["commandBuffer"]),
+    ("vkCmdEndQuery", &["commandBuffer"]),
+    ("vkCmdEndQueryIndexedEXT", &["commandBuffer"]),
+    ("vkCmdEndRenderPass", &["commandBuffer"]),
+    ("vkCmdEndRenderPass2", &["commandBuffer"]),
+    ("vkCmdEndRenderPass2KHR", &["commandBuffer"]),
+    ("vkCmdEndTransformFeedbackEXT", &["commandBuffer"]),
+    ("vkCmdExecuteCommands", &["commandBuffer"]),
+    ("vkCmdExecuteGeneratedCommandsNV", &["commandBuffer"]),
+    ("vkCmdFillBuffer", &["commandBuffer"]),
+    ("vkCmdInsertDebugUtilsLabelEXT", &["commandBuffer"]),
+    ("vkCmdNextSubpass", &["commandBuffer"]),
+    ("vkCmdNextSubpass2", &["commandBuffer"]),
+    ("vkCmdNextSubpass2KHR", &["commandBuffer"]),
+    ("vkCmdPipelineBarrier", &["commandBuffer"]),
+    ("vkCmdPreprocessGeneratedCommandsNV", &["commandBuffer"]),
+    ("vkCmdPushConstants", &["commandBuffer"]),
+    ("vkCmdPushDescriptorSetKHR", &["commandBuffer"]),
+    ("vkCmdPushDescriptorSetWithTemplateKHR", &["commandBuffer"]),
+    ("vkCmdResetEvent", &["commandBuffer"]),
+    ("vkCmdResetQueryPool", &["commandBuffer"]),
+    ("vkCmdResolveImage", &["commandBuffer"]),
+    ("vkCmdResolveImage2KHR", &["commandBuffer"]),
+    ("vkCmdSetBlendConstants", &["commandBuffer"]),
+    ("vkCmdSetCheckpointNV", &["commandBuffer"]),
+    ("vkCmdSetCoarseSampleOrderNV", &["commandBuffer"]),
+    ("vkCmdSetCullModeEXT", &["commandBuffer"]),
+    ("vkCmdSetDepthBias", &["commandBuffer"]),
+    ("vkCmdSetDepthBounds", &["commandBuffer"]),
+    ("vkCmdSetDepthBoundsTestEnableEXT", &["commandBuffer"]),
+    ("vkCmdSetDepthCompareOpEXT", &["commandBuffer"]),
+    ("vkCmdSetDepthTestEnableEXT", &["commandBuffer"]),
+    ("vkCmdSetDepthWriteEnableEXT", &["commandBuffer"]),
+    ("vkCmdSetDeviceMask", &["commandBuffer"]),
+    ("vkCmdSetDeviceMaskKHR", &["commandBuffer"]),
+    ("vkCmdSetDiscardRectangleEXT", &["commandBuffer"]),
+    ("vkCmdSetEvent", &["commandBuffer"]),
+    ("vkCmdSetExclusiveScissorNV", &["commandBuffer"]),
+    ("vkCmdSetFragmentShadingRateEnumNV", &["commandBuffer"]),
+    ("vkCmdSetFragmentShadingRateKHR", &["commandBuffer"]),
+    ("vkCmdSetFrontFaceEXT", &["commandBuffer"]),
+    ("vkCmdSetLineStippleEXT", &["commandBuffer"]),
+    ("vkCmdSetLineWidth", &["commandBuffer"]),
+    ("vkCmdSetPerformanceMarkerINTEL", &["commandBuffer"]),
+    ("vkCmdSetPerformanceOverrideINTEL", &["commandBuffer"]),
+    ("vkCmdSetPerformanceStreamMarkerINTEL", &["commandBuffer"]),
+    ("vkCmdSetPrimitiveTopologyEXT", &["commandBuffer"]),
+    ("vkCmdSetRayTracingPipelineStackSizeKHR", &["commandBuffer"]),
+    ("vkCmdSetSampleLocationsEXT", &["commandBuffer"]),
+    ("vkCmdSetScissor", &["commandBuffer"]),
+    ("vkCmdSetScissorWithCountEXT", &["commandBuffer"]),
+    ("vkCmdSetStencilCompareMask", &["commandBuffer"]),
+    ("vkCmdSetStencilOpEXT", &["commandBuffer"]),
+    ("vkCmdSetStencilReference", &["commandBuffer"]),
+    ("vkCmdSetStencilTestEnableEXT", &["commandBuffer"]),
+    ("vkCmdSetStencilWriteMask", &["commandBuffer"]),
+    ("vkCmdSetViewport", &["commandBuffer"]),
+    ("vkCmdSetViewportShadingRatePaletteNV", &["commandBuffer"]),
+    ("vkCmdSetViewportWScalingNV", &["commandBuffer"]),
+    ("vkCmdSetViewportWithCountEXT", &["commandBuffer"]),
+    ("vkCmdTraceRaysIndirectKHR", &["commandBuffer"]),
+    ("vkCmdTraceRaysKHR", &["commandBuffer"]),
+    ("vkCmdTraceRaysNV", &["commandBuffer"]),
+    ("vkCmdUpdateBuffer", &["commandBuffer"]),
+    ("vkCmdWaitEvents", &["commandBuffer"]),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        &["commandBuffer"],
+    ),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        &["commandBuffer"],
+    ),
+    ("vkCmdWriteBufferMarkerAMD", &["commandBuffer"]),
+    ("vkCmdWriteTimestamp", &["commandBuffer"]),
+    (
+        "vkCreateSharedSwapchainsKHR",
+        &["pCreateInfos[].surface", "pCreateInfos[].oldSwapchain"],
+    ),
+    (
+        "vkCreateSwapchainKHR",
+        &["pCreateInfo->surface", "pCreateInfo->oldSwapchain"],
+    ),
+    ("vkDebugMarkerSetObjectNameEXT", &["pNameInfo->object"]),
+    ("vkDebugMarkerSetObjectTagEXT", &["pTagInfo->object"]),
+    (
+        "vkDestroyAccelerationStructureKHR",
+        &["accelerationStructure"],
+    ),
+    (
+        "vkDestroyAccelerationStructureNV",
+        &["accelerationStructure"],
+    ),
+    ("vkDestroyBuffer", &["buffer"]),
+    ("vkDestroyBufferView", &["bufferView"]),
+    ("vkDestroyCommandPool", &["commandPool"]),
+    ("vkDestroyDebugReportCallbackEXT", &["callback"]),
+    ("vkDestroyDebugUtilsMessengerEXT", &["messenger"]),
+    ("vkDestroyDeferredOperationKHR", &["operation"]),
+    ("vkDestroyDescriptorPool", &["descriptorPool"]),
+    ("vkDestroyDescriptorSetLayout", &["descriptorSetLayout"]),
+    (
+        "vkDestroyDescriptorUpdateTemplate",
+        &["descriptorUpdateTemplate"],
+    ),
+    (
+        "vkDestroyDescriptorUpdateTemplateKHR",
+        &["descriptorUpdateTemplate"],
+    ),
+    ("vkDestroyDevice", &["device"]),
+    ("vkDestroyEvent", &["event"]),
+    ("vkDestroyFence", &["fence"]),
+    ("vkDestroyFramebuffer", &["framebuffer"]),
+    ("vkDestroyImage", &["image"]),
+    ("vkDestroyImageView", &["imageView"]),
+    (
+        "vkDestroyIndirectCommandsLayoutNV",
+        &["indirectCommandsLayout"],
+    ),
+    ("vkDestroyInstance", &["instance"]),
+    ("vkDestroyPipeline", &["pipeline"]),
+    ("vkDestroyPipelineCache", &["pipelineCache"]),
+    ("vkDestroyPipelineLayout", &["pipelineLayout"]),
+    ("vkDestroyPrivateDataSlotEXT", &["privateDataSlot"]),
+    ("vkDestroyQueryPool", &["queryPool"]),
+    ("vkDestroyRenderPass", &["renderPass"]),
+    ("vkDestroySampler", &["sampler"]),
+    ("vkDestroySamplerYcbcrConversion", &["ycbcrConversion"]),
+    ("vkDestroySamplerYcbcrConversionKHR", &["ycbcrConversion"]),
+    ("vkDestroySemaphore", &["semaphore"]),
+    ("vkDestroyShaderModule", &["shaderModule"]),
+    ("vkDestroySurfaceKHR", &["surface"]),
+    ("vkDestroySwapchainKHR", &["swapchain"]),
+    ("vkDestroyValidationCacheEXT", &["validationCache"]),
+    ("vkEndCommandBuffer", &["commandBuffer"]),
+    ("vkFreeCommandBuffers", &["commandPool", "pCommandBuffers"]),
+    (
+        "vkFreeDescriptorSets",
+        &["descriptorPool", "pDescriptorSets"],
+    ),
+    ("vkFreeMemory", &["memory"]),
+    ("vkImportFenceFdKHR", &["pImportFenceFdInfo->fence"]),
+    (
+        "vkImportFenceWin32HandleKHR",
+        &["pImportFenceWin32HandleInfo->fence"],
+    ),
+    (
+        "vkImportSemaphoreFdKHR",
+        &["pImportSemaphoreFdInfo->semaphore"],
+    ),
+    (
+        "vkImportSemaphoreWin32HandleKHR",
+        &["pImportSemaphoreWin32HandleInfo->semaphore"],
+    ),
+    ("vkMapMemory", &["memory"]),
+    ("vkMergePipelineCaches", &["dstCache"]),
+    ("vkMergeValidationCachesEXT", &["dstCache"]),
+    ("vkQueueBeginDebugUtilsLabelEXT", &["queue"]),
+    (
+        "vkQueueBindSparse",
+        &[
+            "queue",
+            "pBindInfo[].pBufferBinds[].buffer",
+            "pBindInfo[].pImageOpaqueBinds[].image",
+            "pBindInfo[].pImageBinds[].image",
+            "fence",
+        ],
+    ),
+    ("vkQueueEndDebugUtilsLabelEXT", &["queue"]),
+    ("vkQueueInsertDebugUtilsLabelEXT", &["queue"]),
+    (
+        "vkQueuePresentKHR",
+        &[
+            "queue",
+            "pPresentInfo->pWaitSemaphores[]",
+            "pPresentInfo->pSwapchains[]",
+        ],
+    ),
+    ("vkQueueSubmit", &["queue", "fence"]),
+    ("vkQueueWaitIdle", &["queue"]),
+    ("vkResetCommandBuffer", &["commandBuffer"]),
+    ("vkResetCommandPool", &["commandPool"]),
+    ("vkResetDescriptorPool", &["descriptorPool"]),
+    ("vkResetEvent", &["event"]),
+    ("vkResetFences", &["pFences"]),
+    ("vkSetDebugUtilsObjectNameEXT", &["pNameInfo->objectHandle"]),
+    ("vkSetDebugUtilsObjectTagEXT", &["pTagInfo->objectHandle"]),
+    ("vkSetEvent", &["event"]),
+    ("vkTrimCommandPool", &["commandPool"]),
+    ("vkTrimCommandPoolKHR", &["commandPool"]),
+    ("vkUnmapMemory", &["memory"]),
+    ("vkUpdateDescriptorSetWithTemplate", &["descriptorSet"]),
+    ("vkUpdateDescriptorSetWithTemplateKHR", &["descriptorSet"]),
+    (
+        "vkUpdateDescriptorSets",
+        &["pDescriptorWrites[].dstSet", "pDescriptorCopies[].dstSet"],
+    ),
+];
+#[doc = r" Whether a command is recorded inside or outside of a render pass instance."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RenderPassScope {
+    Inside,
+    Outside,
+    Both,
+}
+#[doc = r" Where a command can be used, from the `queues`, `cmdbufferlevel` and `renderpass`"]
+#[doc = r" attributes of the registry."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommandInfo {
+    #[doc = r" The queues that support the command, empty if the registry doesn't restrict them."]
+    pub queues: QueueFlags,
+    #[doc = r" The levels of command buffer that the command can be recorded in, empty for"]
+    #[doc = r" commands that aren't recorded."]
+    pub command_buffer_levels: &'static [CommandBufferLevel],
+    #[doc = r" Whether the command is recorded inside or outside of a render pass instance, `None`"]
+    #[doc = r" for commands that aren't recorded."]
+    pub render_pass: Option<RenderPassScope>,
+}
+impl CommandInfo {
+    #[doc = r" Returns whether a queue of a family with `queue_flags` supports the command."]
+    pub fn supports_queue(&self, queue_flags: QueueFlags) -> bool {
+        self.queues.is_empty() || queue_flags.intersects(self.queues)
+    }
+    #[doc = r" Returns whether the command can be recorded in a command buffer of `level`."]
+    pub fn supports_level(&self, level: CommandBufferLevel) -> bool {
+        self.command_buffer_levels.is_empty() || self.command_buffer_levels.contains(&level)
+    }
+    #[doc = r" Returns whether the command can be recorded while a render pass instance is active,"]
+    #[doc = r" if `inside_render_pass`, or while none is."]
+    pub fn supports_render_pass(&self, inside_render_pass: bool) -> bool {
+        match self.render_pass {
+            Some(RenderPassScope::Inside) => inside_render_pass,
+            Some(RenderPassScope::Outside) => !inside_render_pass,
+            Some(RenderPassScope::Both) | None => true,
+        }
+    }
+}
+#[doc = r" The [`CommandInfo`] of the commands that have one, including aliases, e.g."]
+#[doc = r#" `("vkCmdDispatch", CommandInfo { queues: QueueFlags::COMPUTE, .. })`. Sorted by command"#]
+#[doc = r" name."]
+pub const COMMAND_INFOS: &[(&str, CommandInfo)] = &[
+    (
+        "vkCmdBeginConditionalRenderingEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBeginDebugUtilsLabelEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBeginQuery",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBeginQueryIndexedEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBeginRenderPass",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBeginRenderPass2",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBeginRenderPass2KHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBeginTransformFeedbackEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdBindDescriptorSets",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindIndexBuffer",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindPipeline",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindPipelineShaderGroupNV",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindShadingRateImageNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindVertexBuffers",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBindVertexBuffers2EXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdBlitImage",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBlitImage2KHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBuildAccelerationStructureNV",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdBuildAccelerationStructuresKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdClearAttachments",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdClearColorImage",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdClearDepthStencilImage",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyAccelerationStructureKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyAccelerationStructureNV",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyBuffer",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyBuffer2KHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyBufferToImage",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyBufferToImage2KHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyImage",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyImage2KHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyImageToBuffer",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyImageToBuffer2KHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdCopyQueryPoolResults",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdDebugMarkerBeginEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdDebugMarkerEndEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdDebugMarkerInsertEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdDispatch",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdDispatchBase",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdDispatchBaseKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdDispatchIndirect",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdDraw",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndexed",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndexedIndirect",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndexedIndirectCount",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndexedIndirectCountAMD",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndexedIndirectCountKHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndirect",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndirectByteCountEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndirectCount",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndirectCountAMD",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawIndirectCountKHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawMeshTasksIndirectNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdDrawMeshTasksNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdEndConditionalRenderingEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdEndDebugUtilsLabelEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdEndQuery",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdEndQueryIndexedEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdEndRenderPass",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdEndRenderPass2",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdEndRenderPass2KHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdEndTransformFeedbackEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdExecuteCommands",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdExecuteGeneratedCommandsNV",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdFillBuffer",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdInsertDebugUtilsLabelEXT",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdNextSubpass",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdNextSubpass2",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdNextSubpass2KHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY],
+            render_pass: Some(RenderPassScope::Inside),
+        },
+    ),
+    (
+        "vkCmdPipelineBarrier",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdPreprocessGeneratedCommandsNV",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdPushConstants",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdPushDescriptorSetKHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdResetEvent",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdResetQueryPool",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdResolveImage",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdResolveImage2KHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdSetBlendConstants",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetCheckpointNV",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetCoarseSampleOrderNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetCullModeEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthBias",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthBounds",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthCompareOpEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthTestEnableEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDepthWriteEnableEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDeviceMask",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDeviceMaskKHR",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetDiscardRectangleEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetEvent",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdSetExclusiveScissorNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetFragmentShadingRateEnumNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetFragmentShadingRateKHR",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetFrontFaceEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetLineStippleEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetLineWidth",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetPerformanceMarkerINTEL",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetPerformanceOverrideINTEL",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetPrimitiveTopologyEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdSetSampleLocationsEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetScissor",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetScissorWithCountEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetStencilCompareMask",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetStencilOpEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetStencilReference",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetStencilTestEnableEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetStencilWriteMask",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetViewport",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetViewportShadingRatePaletteNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetViewportWScalingNV",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdSetViewportWithCountEXT",
+        CommandInfo {
+            queues: QueueFlags::GRAPHICS,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdTraceRaysIndirectKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdTraceRaysKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdTraceRaysNV",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdUpdateBuffer",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdWaitEvents",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw() | QueueFlags::COMPUTE.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        CommandInfo {
+            queues: QueueFlags::COMPUTE,
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Outside),
+        },
+    ),
+    (
+        "vkCmdWriteBufferMarkerAMD",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkCmdWriteTimestamp",
+        CommandInfo {
+            queues: QueueFlags::from_raw(
+                QueueFlags::GRAPHICS.as_raw()
+                    | QueueFlags::COMPUTE.as_raw()
+                    | QueueFlags::TRANSFER.as_raw(),
+            ),
+            command_buffer_levels: &[CommandBufferLevel::PRIMARY, CommandBufferLevel::SECONDARY],
+            render_pass: Some(RenderPassScope::Both),
+        },
+    ),
+    (
+        "vkQueueBindSparse",
+        CommandInfo {
+            queues: QueueFlags::SPARSE_BINDING,
+            command_buffer_levels: &[],
+            render_pass: None,
+        },
+    ),
+];
+#[doc = r" The extensions that were promoted to a core version, and the version, e.g."]
+#[doc = r#" `("VK_KHR_maintenance1", make_version(1, 1, 0))`. Sorted by extension name."#]
+pub const PROMOTED_EXTENSIONS: &[(&str, u32)] = &[
+    ("VK_AMD_draw_indirect_count", make_version(1, 2, 0)),
+    ("VK_EXT_descriptor_indexing", make_version(1, 2, 0)),
+    ("VK_EXT_host_query_reset", make_version(1, 2, 0)),
+    ("VK_EXT_sampler_filter_minmax", make_version(1, 2, 0)),
+    ("VK_EXT_scalar_block_layout", make_version(1, 2, 0)),
+    ("VK_EXT_separate_stencil_usage", make_version(1, 2, 0)),
+    ("VK_EXT_shader_viewport_index_layer", make_version(1, 2, 0)),
+    ("VK_KHR_16bit_storage", make_version(1, 1, 0)),
+    ("VK_KHR_8bit_storage", make_version(1, 2, 0)),
+    ("VK_KHR_bind_memory2", make_version(1, 1, 0)),
+    ("VK_KHR_buffer_device_address", make_version(1, 2, 0)),
+    ("VK_KHR_create_renderpass2", make_version(1, 2, 0)),
+    ("VK_KHR_dedicated_allocation", make_version(1, 1, 0)),
+    ("VK_KHR_depth_stencil_resolve", make_version(1, 2, 0)),
+    ("VK_KHR_descriptor_update_template", make_version(1, 1, 0)),
+    ("VK_KHR_device_group", make_version(1, 1, 0)),
+    ("VK_KHR_device_group_creation", make_version(1, 1, 0)),
+    ("VK_KHR_draw_indirect_count", make_version(1, 2, 0)),
+    ("VK_KHR_driver_properties", make_version(1, 2, 0)),
+    ("VK_KHR_external_fence", make_version(1, 1, 0)),
+    ("VK_KHR_external_fence_capabilities", make_version(1, 1, 0)),
+    ("VK_KHR_external_memory", make_version(1, 1, 0)),
+    ("VK_KHR_external_memory_capabilities", make_version(1, 1, 0)),
+    ("VK_KHR_external_semaphore", make_version(1, 1, 0)),
+    (
+        "VK_KHR_external_semaphore_capabilities",
+        make_version(1, 1, 0),
+    ),
+    ("VK_KHR_get_memory_requirements2", make_version(1, 1, 0)),
+    (
+        "VK_KHR_get_physical_device_properties2",
+        make_version(1, 1, 0),
+    ),
+    ("VK_KHR_image_format_list", make_version(1, 2, 0)),
+    ("VK_KHR_imageless_framebuffer", make_version(1, 2, 0)),
+    ("VK_KHR_maintenance1", make_version(1, 1, 0)),
+    ("VK_KHR_maintenance2", make_version(1, 1, 0)),
+    ("VK_KHR_maintenance3", make_version(1, 1, 0)),
+    ("VK_KHR_multiview", make_version(1, 1, 0)),
+    ("VK_KHR_relaxed_block_layout", make_version(1, 1, 0)),
+    ("VK_KHR_sampler_mirror_clamp_to_edge", make_version(1, 2, 0)),
+    ("VK_KHR_sampler_ycbcr_conversion", make_version(1, 1, 0)),
+    (
+        "VK_KHR_separate_depth_stencil_layouts",
+        make_version(1, 2, 0),
+    ),
+    ("VK_KHR_shader_atomic_int64", make_version(1, 2, 0)),
+    ("VK_KHR_shader_draw_parameters", make_version(1, 1, 0)),
+    ("VK_KHR_shader_float16_int8", make_version(1, 2, 0)),
+    ("VK_KHR_shader_float_controls", make_version(1, 2, 0)),
+    (
+        "VK_KHR_shader_subgroup_extended_types",
+        make_version(1, 2, 0),
+    ),
+    ("VK_KHR_spirv_1_4", make_version(1, 2, 0)),
+    ("VK_KHR_storage_buffer_storage_class", make_version(1, 1, 0)),
+    ("VK_KHR_timeline_semaphore", make_version(1, 2, 0)),
+    (
+        "VK_KHR_uniform_buffer_standard_layout",
+        make_version(1, 2, 0),
+    ),
+    ("VK_KHR_variable_pointers", make_version(1, 1, 0)),
+    ("VK_KHR_vulkan_memory_model", make_version(1, 2, 0)),
+];
+fn lookup(
+    table: &'static [(&str, &'static [&'static str])],
+    name: &str,
+) -> &'static [&'static str] {
+    match table.binary_search_by_key(&name, |&(key, _)| key) {
+        Ok(i) => table[i].1,
+        Err(_) => &[],
+    }
+}
+#[doc = r" Returns the core versions and extensions that provide the command `name`, e.g."]
+#[doc = r" `vkCmdDrawIndirectCount`."]
+pub fn command_providers(name: &str) -> &'static [&'static str] {
+    lookup(COMMAND_PROVIDERS, name)
+}
+#[doc = r" Returns the structs that the struct `name`, e.g. `VkPhysicalDeviceVulkan12Features`, can"]
+#[doc = r" be chained to."]
+pub fn struct_extends(name: &str) -> &'static [&'static str] {
+    lookup(STRUCT_EXTENDS, name)
+}
+#[doc = r" Returns the struct whose `s_type` is fixed to `s_type`, e.g. `VkDeviceCreateInfo` for"]
+#[doc = r" `StructureType::DEVICE_CREATE_INFO`."]
+pub fn structure_type_struct(s_type: StructureType) -> Option<&'static str> {
+    STRUCTURE_TYPES
+        .iter()
+        .find(|&&(_, ty)| ty == s_type)
+        .map(|&(name, _)| name)
+}
+#[doc = r" Returns the core versions and extensions that add the enum value `name`, e.g."]
+#[doc = r" `VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR`. Values of the original enum are not"]
+#[doc = r" listed."]
+pub fn enum_value_providers(name: &str) -> &'static [&'static str] {
+    lookup(ENUM_VALUE_PROVIDERS, name)
+}
+#[doc = r" Returns the parameters of the command `name` that have to be synchronized externally,"]
+#[doc = r" e.g. `commandPool` of `vkResetCommandPool`."]
+pub fn extern_sync_params(name: &str) -> &'static [&'static str] {
+    lookup(EXTERN_SYNC_PARAMS, name)
+}
+#[doc = r" Returns the [`CommandInfo`] of the command `name`, e.g. `vkCmdDraw`, `None` for commands"]
+#[doc = r" that the registry doesn't restrict."]
+pub fn command_info(name: &str) -> Option<&'static CommandInfo> {
+    COMMAND_INFOS
+        .binary_search_by_key(&name, |&(key, _)| key)
+        .ok()
+        .map(|i| &COMMAND_INFOS[i].1)
+}
+#[doc = r" Returns the core version that the extension `name` was promoted to, e.g."]
+#[doc = r" `make_version(1, 1, 0)` for `VK_KHR_maintenance1`."]
+pub fn promoted_to(name: &str) -> Option<u32> {
+    PROMOTED_EXTENSIONS
+        .binary_search_by_key(&name, |&(key, _)| key)
+        .ok()
+        .map(|i| PROMOTED_EXTENSIONS[i].1)
+}
+#[doc = r" Splits `extensions` into the ones that still have to be enabled with `api_version`, and"]
+#[doc = r" the ones that are part of the core API of `api_version`. Promoted functionality can"]
+#[doc = r" still be optional in core, e.g. `drawIndirectCount` of `VK_KHR_draw_indirect_count`,"]
+#[doc = r" and has to be enabled through the features of the core version."]
+pub fn split_promoted_extensions<'a>(
+    api_version: u32,
+    extensions: &[&'a str],
+) -> (Vec<&'a str>, Vec<&'a str>) {
+    let version = |version: u32| (version_major(version), version_minor(version));
+    extensions.iter().copied().partition(|&name| {
+        promoted_to(name).is_none_or(|promoted| version(promoted) > version(api_version))
+    })
+}
+#[doc = r" Panics in debug builds if the command `name` can't be recorded in a command buffer of"]
+#[doc = r" `level`, allocated from a pool of a queue family with `queue_flags`. `ash` doesn't know"]
+#[doc = r" which queue family a command buffer belongs to, so wrappers that track it can call this"]
+#[doc = r" before recording, to catch e.g. draws recorded for a compute-only queue."]
+pub fn debug_assert_command(name: &str, queue_flags: QueueFlags, level: CommandBufferLevel) {
+    if cfg!(debug_assertions) {
+        if let Some(info) = command_info(name) {
+            assert!(
+                info.supports_queue(queue_flags),
+                "{} requires a queue with {:?}, but the command buffer is for {:?}",
+                name,
+                info.queues,
+                queue_flags
+            );
+            assert!(
+                info.supports_level(level),
+                "{} can't be recorded in a {:?} command buffer",
+                name,
+                level
+            );
+        }
+    }
+}
+#[doc = r" Panics in debug builds if the command `name` can't be recorded while a render pass"]
+#[doc = r" instance is active, if `inside_render_pass`, or while none is. Like"]
+#[doc = r" [`debug_assert_command`] this is meant for wrappers that track the render pass scope of"]
+#[doc = r" their command buffers."]
+pub fn debug_assert_render_pass(name: &str, inside_render_pass: bool) {
+    if cfg!(debug_assertions) {
+        if let Some(info) = command_info(name) {
+            assert!(
+                info.supports_render_pass(inside_render_pass),
+                "{} must be recorded {} a render pass instance",
+                name,
+                if inside_render_pass {
+                    "outside"
+                } else {
+                    "inside"
+                }
+            );
+        }
+    }
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn provider_lookup() {
+    assert_eq!(
+        command_providers("vkCreateSwapchainKHR"),
+        &["VK_KHR_swapchain"]
+    );
+    assert_eq!(command_providers("vkCreateInstance"), &["VK_VERSION_1_0"]);
+    assert!(command_providers("vkNotACommand").is_empty());
+    assert!(struct_extends("VkPhysicalDeviceVulkan12Features").contains(&"VkDeviceCreateInfo"));
+    assert_eq!(
+        enum_value_providers("VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR"),
+        &["VK_KHR_swapchain"]
+    );
+}
+
+#[test]
+fn command_queues_and_levels() {
+    let info = command_info("vkCmdDispatch").unwrap();
+    assert!(info.supports_queue(QueueFlags::COMPUTE | QueueFlags::TRANSFER));
+    assert!(!info.supports_queue(QueueFlags::GRAPHICS));
+    assert!(command_info("vkCmdExecuteCommands")
+        .unwrap()
+        .supports_level(CommandBufferLevel::PRIMARY));
+    assert!(!command_info("vkCmdBeginRenderPass2KHR")
+        .unwrap()
+        .supports_level(CommandBufferLevel::SECONDARY));
+    assert!(command_info("vkCreateInstance").is_none());
+}
+
+#[test]
+fn command_render_pass_scope() {
+    let draw = command_info("vkCmdDraw").unwrap();
+    assert_eq!(draw.render_pass, Some(RenderPassScope::Inside));
+    assert!(draw.supports_render_pass(true));
+    assert!(!draw.supports_render_pass(false));
+    let barrier = command_info("vkCmdPipelineBarrier").unwrap();
+    assert!(barrier.supports_render_pass(true) && barrier.supports_render_pass(false));
+}
+
+#[test]
+fn promoted_extension_split() {
+    let extensions = [
+        "VK_KHR_swapchain",
+        "VK_KHR_maintenance1",
+        "VK_KHR_timeline_semaphore",
+    ];
+    let (enable, core) = split_promoted_extensions(make_version(1, 1, 0), &extensions);
+    assert_eq!(enable, ["VK_KHR_swapchain", "VK_KHR_timeline_semaphore"]);
+    assert_eq!(core, ["VK_KHR_maintenance1"]);
+    let (enable, _) = split_promoted_extensions(make_version(1, 2, 162), &extensions);
+    assert_eq!(enable, ["VK_KHR_swapchain"]);
+}
@@ -0,0 +1,13 @@
+pub use std::os::raw::*;
+#[doc = "`void` of `vk_platform.h`."]
+#[allow(non_camel_case_types)]
+pub type c_void = ::std::os::raw::c_void;
+#[doc = "`char` of `vk_platform.h`."]
+#[allow(non_camel_case_types)]
+pub type c_char = ::std::os::raw::c_char;
+#[doc = "`int` of `vk_platform.h`."]
+#[allow(non_camel_case_types)]
+pub type c_int = ::std::os::raw::c_int;
+#[doc = "`size_t` of `vk_platform.h`."]
+#[allow(non_camel_case_types)]
+pub type size_t = usize;
@@ -1,4 +1,4 @@
-use std::os::raw::*;
+use crate::vk::platform::*;
 pub type RROutput = c_ulong;
 pub type VisualID = c_uint;
 pub type Display = *const c_void;
@@ -32,3 +32,7 @@ pub type GgpStreamDescriptor = u32;
 pub type GgpFrameToken = u64;
 pub type IDirectFB = c_void;
 pub type IDirectFBSurface = c_void;
+#[allow(non_camel_case_types)]
+pub type _screen_context = c_void;
+#[allow(non_camel_case_types)]
+pub type _screen_window = c_void;
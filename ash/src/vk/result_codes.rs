@@ -0,0 +1,142 @@
+use crate::vk::enums::Result;
+use std::convert::TryFrom;
+use std::fmt;
+#[doc = r" A [`Result`] that is a success code, which is never negative. `INCOMPLETE`,"]
+#[doc = r" `SUBOPTIMAL_KHR` or `TIMEOUT` are success codes too, and carry information about how"]
+#[doc = r" the command succeeded."]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SuccessCode(Result);
+impl SuccessCode {
+    pub const EVENT_RESET: Self = SuccessCode(Result::EVENT_RESET);
+    pub const EVENT_SET: Self = SuccessCode(Result::EVENT_SET);
+    pub const INCOMPLETE: Self = SuccessCode(Result::INCOMPLETE);
+    pub const NOT_READY: Self = SuccessCode(Result::NOT_READY);
+    pub const OPERATION_DEFERRED_KHR: Self = SuccessCode(Result::OPERATION_DEFERRED_KHR);
+    pub const OPERATION_NOT_DEFERRED_KHR: Self = SuccessCode(Result::OPERATION_NOT_DEFERRED_KHR);
+    pub const PIPELINE_COMPILE_REQUIRED_EXT: Self =
+        SuccessCode(Result::PIPELINE_COMPILE_REQUIRED_EXT);
+    pub const SUBOPTIMAL_KHR: Self = SuccessCode(Result::SUBOPTIMAL_KHR);
+    pub const SUCCESS: Self = SuccessCode(Result::SUCCESS);
+    pub const THREAD_DONE_KHR: Self = SuccessCode(Result::THREAD_DONE_KHR);
+    pub const THREAD_IDLE_KHR: Self = SuccessCode(Result::THREAD_IDLE_KHR);
+    pub const TIMEOUT: Self = SuccessCode(Result::TIMEOUT);
+    pub const fn as_result(self) -> Result {
+        self.0
+    }
+}
+#[doc = r" A [`Result`] that is an error code, which is always negative."]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode(Result);
+impl ErrorCode {
+    pub const ERROR_DEVICE_LOST: Self = ErrorCode(Result::ERROR_DEVICE_LOST);
+    pub const ERROR_EXTENSION_NOT_PRESENT: Self = ErrorCode(Result::ERROR_EXTENSION_NOT_PRESENT);
+    pub const ERROR_FEATURE_NOT_PRESENT: Self = ErrorCode(Result::ERROR_FEATURE_NOT_PRESENT);
+    pub const ERROR_FORMAT_NOT_SUPPORTED: Self = ErrorCode(Result::ERROR_FORMAT_NOT_SUPPORTED);
+    pub const ERROR_FRAGMENTATION: Self = ErrorCode(Result::ERROR_FRAGMENTATION);
+    pub const ERROR_FRAGMENTED_POOL: Self = ErrorCode(Result::ERROR_FRAGMENTED_POOL);
+    pub const ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT: Self =
+        ErrorCode(Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT);
+    pub const ERROR_INCOMPATIBLE_DISPLAY_KHR: Self =
+        ErrorCode(Result::ERROR_INCOMPATIBLE_DISPLAY_KHR);
+    pub const ERROR_INCOMPATIBLE_DRIVER: Self = ErrorCode(Result::ERROR_INCOMPATIBLE_DRIVER);
+    pub const ERROR_INITIALIZATION_FAILED: Self = ErrorCode(Result::ERROR_INITIALIZATION_FAILED);
+    pub const ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT: Self =
+        ErrorCode(Result::ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT);
+    pub const ERROR_INVALID_EXTERNAL_HANDLE: Self =
+        ErrorCode(Result::ERROR_INVALID_EXTERNAL_HANDLE);
+    pub const ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS: Self =
+        ErrorCode(Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS);
+    pub const ERROR_INVALID_SHADER_NV: Self = ErrorCode(Result::ERROR_INVALID_SHADER_NV);
+    pub const ERROR_LAYER_NOT_PRESENT: Self = ErrorCode(Result::ERROR_LAYER_NOT_PRESENT);
+    pub const ERROR_MEMORY_MAP_FAILED: Self = ErrorCode(Result::ERROR_MEMORY_MAP_FAILED);
+    pub const ERROR_NATIVE_WINDOW_IN_USE_KHR: Self =
+        ErrorCode(Result::ERROR_NATIVE_WINDOW_IN_USE_KHR);
+    pub const ERROR_OUT_OF_DATE_KHR: Self = ErrorCode(Result::ERROR_OUT_OF_DATE_KHR);
+    pub const ERROR_OUT_OF_DEVICE_MEMORY: Self = ErrorCode(Result::ERROR_OUT_OF_DEVICE_MEMORY);
+    pub const ERROR_OUT_OF_HOST_MEMORY: Self = ErrorCode(Result::ERROR_OUT_OF_HOST_MEMORY);
+    pub const ERROR_OUT_OF_POOL_MEMORY: Self = ErrorCode(Result::ERROR_OUT_OF_POOL_MEMORY);
+    pub const ERROR_SURFACE_LOST_KHR: Self = ErrorCode(Result::ERROR_SURFACE_LOST_KHR);
+    pub const ERROR_TOO_MANY_OBJECTS: Self = ErrorCode(Result::ERROR_TOO_MANY_OBJECTS);
+    pub const fn as_result(self) -> Result {
+        self.0
+    }
+}
+#[doc = r" The registry name and the description of every code, from its comment in the registry"]
+#[doc = r" or else from the specification. Empty without the `debug-strings` feature."]
+#[cfg(feature = "debug-strings")]
+const DESCRIPTIONS: &[(Result, &str, &str)] = &[(Result::ERROR_DEVICE_LOST, "VK_ERROR_DEVICE_LOST", "The logical device has been lost"), (Result::ERROR_EXTENSION_NOT_PRESENT, "VK_ERROR_EXTENSION_NOT_PRESENT", "Extension specified does not exist"), (Result::ERROR_FEATURE_NOT_PRESENT, "VK_ERROR_FEATURE_NOT_PRESENT", "Requested feature is not available on this device"), (Result::ERROR_FORMAT_NOT_SUPPORTED, "VK_ERROR_FORMAT_NOT_SUPPORTED", "Requested format is not supported on this device"), (Result::ERROR_FRAGMENTATION, "VK_ERROR_FRAGMENTATION", "A descriptor pool creation has failed due to fragmentation"), (Result::ERROR_FRAGMENTED_POOL, "VK_ERROR_FRAGMENTED_POOL", "A requested pool allocation has failed due to fragmentation of the pool's memory"), (Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT, "VK_ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT", "An operation on a swapchain created with application controlled full-screen access failed as it did not have exclusive full-screen access"), (Result::ERROR_INCOMPATIBLE_DISPLAY_KHR, "VK_ERROR_INCOMPATIBLE_DISPLAY_KHR", "The display used by a swapchain does not use the same presentable image layout, or is incompatible in a way that prevents sharing an image"), (Result::ERROR_INCOMPATIBLE_DRIVER, "VK_ERROR_INCOMPATIBLE_DRIVER", "Unable to find a Vulkan driver"), (Result::ERROR_INITIALIZATION_FAILED, "VK_ERROR_INITIALIZATION_FAILED", "Initialization of a object has failed"), (Result::ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT, "VK_ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT", "The plane layouts of an image with an explicit DRM format modifier are invalid"), (Result::ERROR_INVALID_EXTERNAL_HANDLE, "VK_ERROR_INVALID_EXTERNAL_HANDLE", "An external handle is not a valid handle of the specified type"), (Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS, "VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS", "A buffer creation or memory allocation failed because the requested address is not available"), (Result::ERROR_INVALID_SHADER_NV, "VK_ERROR_INVALID_SHADER_NV", "One or more shaders failed to compile or link"), (Result::ERROR_LAYER_NOT_PRESENT, "VK_ERROR_LAYER_NOT_PRESENT", "Layer specified does not exist"), (Result::ERROR_MEMORY_MAP_FAILED, "VK_ERROR_MEMORY_MAP_FAILED", "Mapping of a memory object has failed"), (Result::ERROR_NATIVE_WINDOW_IN_USE_KHR, "VK_ERROR_NATIVE_WINDOW_IN_USE_KHR", "The requested window is already in use by Vulkan or another API in a manner which prevents it from being used again"), (Result::ERROR_NOT_PERMITTED_EXT, "VK_ERROR_NOT_PERMITTED_EXT", "The application does not have sufficient privileges for the requested priority"), (Result::ERROR_OUT_OF_DATE_KHR, "VK_ERROR_OUT_OF_DATE_KHR", "A surface has changed in such a way that it is no longer compatible with the swapchain, and further presentation requests using the swapchain will fail"), (Result::ERROR_OUT_OF_DEVICE_MEMORY, "VK_ERROR_OUT_OF_DEVICE_MEMORY", "A device memory allocation has failed"), (Result::ERROR_OUT_OF_HOST_MEMORY, "VK_ERROR_OUT_OF_HOST_MEMORY", "A host memory allocation has failed"), (Result::ERROR_OUT_OF_POOL_MEMORY, "VK_ERROR_OUT_OF_POOL_MEMORY", "A pool memory allocation has failed"), (Result::ERROR_SURFACE_LOST_KHR, "VK_ERROR_SURFACE_LOST_KHR", "A surface is no longer available"), (Result::ERROR_TOO_MANY_OBJECTS, "VK_ERROR_TOO_MANY_OBJECTS", "Too many objects of the type have already been created"), (Result::ERROR_UNKNOWN, "VK_ERROR_UNKNOWN", "An unknown error has occurred, due to an implementation or application bug"), (Result::ERROR_VALIDATION_FAILED_EXT, "VK_ERROR_VALIDATION_FAILED_EXT", "A command failed because invalid usage was detected by the implementation or a validation layer"), (Result::EVENT_RESET, "VK_EVENT_RESET", "An event is unsignaled"), (Result::EVENT_SET, "VK_EVENT_SET", "An event is signaled"), (Result::INCOMPLETE, "VK_INCOMPLETE", "A return array was too small for the result"), (Result::NOT_READY, "VK_NOT_READY", "A fence or query has not yet completed"), (Result::OPERATION_DEFERRED_KHR, "VK_OPERATION_DEFERRED_KHR", "A deferred operation was requested and at least some of the work was deferred"), (Result::OPERATION_NOT_DEFERRED_KHR, "VK_OPERATION_NOT_DEFERRED_KHR", "A deferred operation was requested and no operations were deferred"), (Result::PIPELINE_COMPILE_REQUIRED_EXT, "VK_PIPELINE_COMPILE_REQUIRED_EXT", "A requested pipeline creation would have required compilation, but the application requested compilation to not be performed"), (Result::SUBOPTIMAL_KHR, "VK_SUBOPTIMAL_KHR", "A swapchain no longer matches the surface properties exactly, but can still be used to present to the surface successfully"), (Result::SUCCESS, "VK_SUCCESS", "Command completed successfully"), (Result::THREAD_DONE_KHR, "VK_THREAD_DONE_KHR", "A deferred operation is not complete but there is no work remaining to assign to additional threads"), (Result::THREAD_IDLE_KHR, "VK_THREAD_IDLE_KHR", "A deferred operation is not complete but there is currently no work for this thread to do at the time of this call"), (Result::TIMEOUT, "VK_TIMEOUT", "A wait operation has not completed in the specified time")];
+#[cfg(not(feature = "debug-strings"))]
+const DESCRIPTIONS: &[(Result, &str, &str)] = &[];
+impl Result {
+    #[doc = r" Splits the result into a success code and an error code, which unlike"]
+    #[doc = r" `result()` keeps the success codes other than `SUCCESS` out of the error."]
+    pub fn into_codes(self) -> ::std::result::Result<SuccessCode, ErrorCode> {
+        if self.as_raw() >= 0 {
+            Ok(SuccessCode(self))
+        } else {
+            Err(ErrorCode(self))
+        }
+    }
+    #[doc = r" The name of the code in the registry, e.g. `VK_ERROR_SURFACE_LOST_KHR`. `None` for"]
+    #[doc = r" unknown codes, and without the `debug-strings` feature."]
+    pub fn name(self) -> Option<&'static str> {
+        DESCRIPTIONS
+            .iter()
+            .find(|&&(code, _, _)| code == self)
+            .map(|&(_, name, _)| name)
+    }
+    #[doc = r#" Describes the code, e.g. "A surface is no longer available" for"#]
+    #[doc = r" `ERROR_SURFACE_LOST_KHR`. `None` for unknown codes, and without the"]
+    #[doc = r" `debug-strings` feature."]
+    pub fn describe(self) -> Option<&'static str> {
+        DESCRIPTIONS
+            .iter()
+            .find(|&&(code, _, _)| code == self)
+            .map(|&(_, _, description)| description)
+    }
+}
+impl TryFrom<Result> for SuccessCode {
+    type Error = ErrorCode;
+    fn try_from(result: Result) -> ::std::result::Result<Self, Self::Error> {
+        result.into_codes()
+    }
+}
+impl TryFrom<Result> for ErrorCode {
+    type Error = SuccessCode;
+    fn try_from(result: Result) -> ::std::result::Result<Self, Self::Error> {
+        match result.into_codes() {
+            Ok(success) => Err(success),
+            Err(error) => Ok(error),
+        }
+    }
+}
+impl From<SuccessCode> for Result {
+    fn from(code: SuccessCode) -> Self {
+        code.0
+    }
+}
+impl From<ErrorCode> for Result {
+    fn from(code: ErrorCode) -> Self {
+        code.0
+    }
+}
+impl fmt::Debug for SuccessCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl fmt::Debug for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+impl ::std::error::Error for ErrorCode {}
+#[cfg(test)]
+mod tests;
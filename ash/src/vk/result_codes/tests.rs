@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn result_codes() {
+    assert_eq!(Result::INCOMPLETE.into_codes(), Ok(SuccessCode::INCOMPLETE));
+    assert_eq!(
+        ErrorCode::try_from(Result::ERROR_DEVICE_LOST),
+        Ok(ErrorCode::ERROR_DEVICE_LOST)
+    );
+    assert_eq!(
+        ErrorCode::try_from(Result::TIMEOUT),
+        Err(SuccessCode::TIMEOUT)
+    );
+    let unknown = Result::from_raw(-1_000_999_000).into_codes().unwrap_err();
+    assert_eq!(Result::from(unknown).as_raw(), -1_000_999_000);
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn ptr_chain_find() {
+    let mut variable_pointers = PhysicalDeviceVariablePointerFeatures::builder();
+    let mut corner = PhysicalDeviceCornerSampledImageFeaturesNV::builder();
+    let mut device_create_info = DeviceCreateInfo::builder()
+        .push_next(&mut corner)
+        .push_next(&mut variable_pointers);
+    let chain = unsafe { PtrChainIter::new(&mut *device_create_info) };
+    let types: Vec<StructureType> = chain.map(|(s_type, _)| s_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            StructureType::DEVICE_CREATE_INFO,
+            StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+            StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+        ]
+    );
+    let chain = unsafe { PtrChainIter::new(&mut *device_create_info) };
+    let found = chain
+        .find_struct_mut::<PhysicalDeviceCornerSampledImageFeaturesNV>()
+        .unwrap();
+    found.corner_sampled_image = TRUE;
+    let chain = unsafe { PtrChainIter::new(&mut *device_create_info) };
+    assert!(chain
+        .find_struct::<PhysicalDeviceMultiviewFeatures>()
+        .is_none());
+    assert_eq!(corner.corner_sampled_image, TRUE);
+}
+
+#[test]
+fn handle_owners() {
+    fn device_child<T: DeviceChild>() {}
+    fn instance_child<T: InstanceChild>() {}
+    device_child::<ImageView>();
+    device_child::<CommandBuffer>();
+    instance_child::<PhysicalDevice>();
+    instance_child::<SurfaceKHR>();
+}
+
+#[test]
+fn base_structure_casts() {
+    let mut corner = PhysicalDeviceCornerSampledImageFeaturesNV::default();
+    let base = corner.as_base_out();
+    assert_eq!(
+        base.s_type,
+        StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV
+    );
+    assert!(unsafe { base.try_downcast::<PhysicalDeviceMultiviewFeatures>() }.is_none());
+    let found =
+        unsafe { base.try_downcast_mut::<PhysicalDeviceCornerSampledImageFeaturesNV>() }.unwrap();
+    found.corner_sampled_image = TRUE;
+    assert_eq!(corner.corner_sampled_image, TRUE);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(
+    expected = "VkPhysicalDeviceMultiviewFeatures is chained to VkDeviceCreateInfo twice"
+)]
+fn ptr_chain_duplicate() {
+    let mut second = PhysicalDeviceMultiviewFeatures::default();
+    let mut first = PhysicalDeviceMultiviewFeatures {
+        p_next: &mut second as *mut _ as *mut std::os::raw::c_void,
+        ..Default::default()
+    };
+    let _ = DeviceCreateInfo::builder().push_next(&mut first);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "VkImageFormatListCreateInfo can't be chained to VkDeviceCreateInfo")]
+fn ptr_chain_extends() {
+    let mut format_list = ImageFormatListCreateInfo::default();
+    let mut multiview = PhysicalDeviceMultiviewFeatures {
+        p_next: &mut format_list as *mut _ as *mut std::os::raw::c_void,
+        ..Default::default()
+    };
+    let _ = DeviceCreateInfo::builder().push_next(&mut multiview);
+}
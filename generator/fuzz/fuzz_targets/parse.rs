@@ -0,0 +1,12 @@
+//! Feeds arbitrary input to the registry parser and the generator passes that work on the
+//! parsed model. Run with `cargo fuzz run parse` from `generator/`, seeding the corpus with
+//! `vk.xml` helps the fuzzer reach the interesting constructs.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(ctx) = generator::Context::from_reader(data) {
+        let _ = generator::registry_ir(&ctx);
+        let _ = generator::generate_meta(&ctx);
+    }
+});
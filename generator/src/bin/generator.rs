@@ -1,14 +1,72 @@
-use generator::write_source_code;
+use generator::{
+    registry_ir, write_profiles, write_source_code, write_source_code_strict, Context,
+};
 use std::path::Path;
 
+const USAGE: &str = "usage: generator [--strict | --emit-ir json [<out_file>] | \
+    --profiles <profiles.json> <out_file> | --fetch <tag> [<vk.xml sha256>]]";
+
 fn main() {
     let cwd = std::env::current_dir().unwrap();
-    if cwd.ends_with("generator") {
-        write_source_code(Path::new("Vulkan-Headers/registry/vk.xml"), "../ash/src");
+    let (vk_xml, src_dir) = if cwd.ends_with("generator") {
+        (Path::new("Vulkan-Headers/registry/vk.xml"), "../ash/src")
     } else {
-        write_source_code(
+        (
             Path::new("generator/Vulkan-Headers/registry/vk.xml"),
             "ash/src",
-        );
+        )
+    };
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => write_source_code(vk_xml, src_dir),
+        [flag] if flag == "--strict" => write_source_code_strict(vk_xml, src_dir),
+        [flag, format, out_file @ ..] if flag == "--emit-ir" && out_file.len() <= 1 => {
+            if format != "json" {
+                eprintln!(
+                    "Unsupported IR format `{}`, only `json` is supported",
+                    format
+                );
+                std::process::exit(1);
+            }
+            let ctx = Context::from_file(vk_xml).expect("Invalid xml file");
+            let json = serde_json::to_string_pretty(&registry_ir(&ctx)).unwrap();
+            match out_file.first() {
+                Some(out_file) => std::fs::write(out_file, json).expect("Unable to write IR"),
+                None => println!("{}", json),
+            }
+        }
+        [flag, profiles_json, out_file] if flag == "--profiles" => {
+            let ctx = Context::from_file(vk_xml).expect("Invalid xml file");
+            if let Err(err) = write_profiles(&ctx, Path::new(profiles_json), Path::new(out_file)) {
+                eprintln!("Unable to generate the profiles: {}", err);
+                std::process::exit(1);
+            }
+        }
+        #[cfg(feature = "fetch")]
+        [flag, tag, sha256 @ ..] if flag == "--fetch" && sha256.len() <= 1 => {
+            let cache_dir = if cwd.ends_with("generator") {
+                "../target/vk-registry"
+            } else {
+                "target/vk-registry"
+            };
+            let mut fetcher = generator::RegistryFetcher::new(tag, cache_dir);
+            if let Some(sha256) = sha256.first() {
+                fetcher = fetcher.sha256("vk.xml", sha256);
+            }
+            let files = fetcher.fetch().unwrap_or_else(|err| {
+                eprintln!("Unable to fetch the registry for {}: {}", tag, err);
+                std::process::exit(1);
+            });
+            write_source_code(&files.vk_xml, src_dir)
+        }
+        #[cfg(not(feature = "fetch"))]
+        [flag, ..] if flag == "--fetch" => {
+            eprintln!("--fetch requires the `fetch` feature");
+            std::process::exit(1);
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(1);
+        }
     }
 }
@@ -0,0 +1,626 @@
+//! The parsed registry as a semantic model, independent of code generation.
+use crate::CommandMap;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+/// Owns both representations of `vk.xml` that the generator works with, the lossless
+/// `vk_parse::Registry` and the `vkxml::Registry` converted from it, and collects the items that
+/// the code emitters consume. Tools that need the registry model, but not the Rust bindings, can
+/// use it instead of re-parsing `vk.xml`.
+pub struct Context {
+    registry: vk_parse::Registry,
+    vkxml: vkxml::Registry,
+    parse_errors: Vec<vk_parse::Error>,
+}
+
+/// An enum value added to an existing enum by an extension or core version, e.g.
+/// `VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR` extending `VkStructureType`.
+#[derive(Clone, Debug)]
+pub struct ExtensionEnum<'a> {
+    /// The extension or core version that adds the value, e.g. `VK_KHR_swapchain` or
+    /// `VK_VERSION_1_1`.
+    pub provided_by: &'a str,
+    /// The extension number of `provided_by`, `0` for core versions.
+    pub extension_number: i64,
+    /// The Vulkan name of the value.
+    pub name: &'a str,
+    /// The Vulkan name of the extended enum.
+    pub extends: &'a str,
+    pub comment: Option<&'a str>,
+    pub spec: &'a vk_parse::EnumSpec,
+}
+
+/// A value in the `<enums>` block of an enum that is an alias of another value, e.g.
+/// `VK_STENCIL_FRONT_AND_BACK` in `VkStencilFaceFlagBits`.
+#[derive(Clone, Copy, Debug)]
+pub struct EnumAlias<'a> {
+    /// The Vulkan name of the alias.
+    pub name: &'a str,
+    /// The Vulkan name of the aliased value.
+    pub alias: &'a str,
+    /// The Vulkan name of the enum of the aliased value. This is a different enum than the one of
+    /// the alias if the alias belongs to an extension enum that was promoted to core.
+    pub alias_enum: &'a str,
+}
+
+/// The value of an enum, as written in the registry or resolved from an extension offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumValue<'a> {
+    Number(i64),
+    BitPos(u32),
+    /// Another value of the same enum.
+    Alias(&'a str),
+}
+
+impl<'a> ExtensionEnum<'a> {
+    /// Returns `None` for values that aren't numbers, or that don't fit in an `i64`.
+    pub fn value(&self) -> Option<EnumValue<'a>> {
+        use vk_parse::EnumSpec;
+        match self.spec {
+            EnumSpec::Offset {
+                offset,
+                extnumber,
+                dir: positive,
+                ..
+            } => offset_enum_value(
+                extnumber.unwrap_or(self.extension_number),
+                *offset,
+                *positive,
+            )
+            .map(EnumValue::Number),
+            EnumSpec::Bitpos { bitpos, .. } => Some(EnumValue::BitPos(*bitpos as u32)),
+            EnumSpec::Value { value, .. } => value.parse().ok().map(EnumValue::Number),
+            EnumSpec::Alias { alias, .. } => Some(EnumValue::Alias(alias)),
+            _ => None,
+        }
+    }
+}
+
+impl Context {
+    pub fn from_reader<R: Read>(vk_xml: R) -> Result<Self, vk_parse::FatalError> {
+        let (registry, parse_errors) = vk_parse::parse_stream(vk_xml)?;
+        let vkxml = registry.clone().into();
+        Ok(Context {
+            registry,
+            vkxml,
+            parse_errors,
+        })
+    }
+
+    pub fn from_file(vk_xml: &Path) -> Result<Self, vk_parse::FatalError> {
+        Self::from_reader(std::io::BufReader::new(std::fs::File::open(vk_xml)?))
+    }
+
+    pub fn parse(vk_xml: &str) -> Result<Self, vk_parse::FatalError> {
+        Self::from_reader(vk_xml.as_bytes())
+    }
+
+    pub fn registry(&self) -> &vk_parse::Registry {
+        &self.registry
+    }
+
+    pub fn vkxml(&self) -> &vkxml::Registry {
+        &self.vkxml
+    }
+
+    /// The elements and attributes that `vk_parse` doesn't know or couldn't parse, which are
+    /// missing from [`Context::registry`].
+    pub fn parse_errors(&self) -> &[vk_parse::Error] {
+        &self.parse_errors
+    }
+
+    pub fn extensions(&self) -> &[vk_parse::Extension] {
+        self.registry
+            .0
+            .iter()
+            .find_map(|item| match item {
+                vk_parse::RegistryChild::Extensions(ext) => Some(ext.children.as_slice()),
+                _ => None,
+            })
+            .unwrap_or(&[])
+    }
+
+    /// The core versions, `VK_VERSION_1_0` and so on.
+    pub fn core_features(&self) -> impl Iterator<Item = &vk_parse::Feature> {
+        self.registry.0.iter().filter_map(|item| match item {
+            vk_parse::RegistryChild::Feature(feature) => Some(feature),
+            _ => None,
+        })
+    }
+
+    /// The core versions as `vkxml` features, with their commands resolved.
+    pub fn features(&self) -> Vec<&vkxml::Feature> {
+        self.vkxml
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::RegistryElement::Features(features) => Some(features),
+                _ => None,
+            })
+            .flat_map(|features| features.elements.iter())
+            .collect()
+    }
+
+    /// All commands by name, without aliases.
+    pub fn commands(&self) -> CommandMap<'_> {
+        self.vkxml
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::RegistryElement::Commands(cmds) => Some(cmds),
+                _ => None,
+            })
+            .flat_map(|cmds| cmds.elements.iter().map(|cmd| (cmd.name.clone(), cmd)))
+            .collect()
+    }
+
+    /// The aliases in the `<enums>` block of every enum, keyed by the name of the enum. `vkxml`
+    /// drops these, so they are missing from [`Context::enums`].
+    pub fn enum_aliases(&self) -> HashMap<&str, Vec<EnumAlias<'_>>> {
+        let blocks = || {
+            self.registry.0.iter().filter_map(|item| match item {
+                vk_parse::RegistryChild::Enums(enums) => {
+                    let values = enums.children.iter().filter_map(|child| match child {
+                        vk_parse::EnumsChild::Enum(e) => Some(e),
+                        _ => None,
+                    });
+                    Some((enums.name.as_deref()?, values))
+                }
+                _ => None,
+            })
+        };
+        let owners: HashMap<&str, &str> = blocks()
+            .flat_map(|(enum_name, values)| values.map(move |e| (e.name.as_str(), enum_name)))
+            .collect();
+        blocks()
+            .map(|(enum_name, values)| {
+                let aliases = values
+                    .filter_map(|e| match &e.spec {
+                        vk_parse::EnumSpec::Alias { alias, .. } => Some(EnumAlias {
+                            name: &e.name,
+                            alias,
+                            alias_enum: owners.get(alias.as_str()).copied().unwrap_or(enum_name),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                (enum_name, aliases)
+            })
+            .filter(|(_, aliases)| !aliases.is_empty())
+            .collect()
+    }
+
+    /// The `vk_parse` definitions of all commands, without aliases. Unlike [`Context::commands`]
+    /// these keep attributes such as `queues` and `cmdbufferlevel`.
+    pub fn command_definitions(&self) -> impl Iterator<Item = &vk_parse::CommandDefinition> {
+        self.registry
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Commands(cmds) => Some(cmds.children.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|cmd| match cmd {
+                vk_parse::Command::Definition(def) => Some(def),
+                _ => None,
+            })
+    }
+
+    /// The success codes of the commands that have others than `VK_SUCCESS`, e.g.
+    /// `vkWaitForFences` with `VK_SUCCESS` and `VK_TIMEOUT`, without aliases.
+    pub fn success_codes(&self) -> HashMap<&str, Vec<&str>> {
+        self.command_definitions()
+            .filter_map(|def| {
+                let codes: Vec<&str> = def.successcodes.as_deref()?.split(',').collect();
+                if codes == ["VK_SUCCESS"] {
+                    None
+                } else {
+                    Some((def.proto.name.as_str(), codes))
+                }
+            })
+            .collect()
+    }
+
+    /// The parameters of the commands that the application has to synchronize externally, e.g.
+    /// `vkQueueSubmit` with `queue` and `fence`, and `vkQueueBindSparse` with members like
+    /// `pBindInfo[].pBufferBinds[].buffer`. Aliases share the parameters of the aliased command.
+    pub fn extern_sync_params(&self) -> HashMap<&str, Vec<&str>> {
+        let mut params: HashMap<&str, Vec<&str>> = self
+            .command_definitions()
+            .filter_map(|def| {
+                let params: Vec<&str> = def
+                    .params
+                    .iter()
+                    .flat_map(|param| match param.externsync.as_deref() {
+                        Some("true") => vec![param.definition.name.as_str()],
+                        Some(members) => members.split(',').collect(),
+                        None => Vec::new(),
+                    })
+                    .collect();
+                if params.is_empty() {
+                    None
+                } else {
+                    Some((def.proto.name.as_str(), params))
+                }
+            })
+            .collect();
+        let aliases: Vec<_> = self
+            .registry
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Commands(cmds) => Some(cmds.children.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|cmd| match cmd {
+                vk_parse::Command::Alias { name, alias } => {
+                    Some((name.as_str(), params.get(alias.as_str())?.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        params.extend(aliases);
+        params
+    }
+
+    /// Maps the name of every command alias to the name of the aliased command.
+    pub fn command_aliases(&self) -> HashMap<String, String> {
+        self.registry
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Commands(cmds) => Some(cmds.children.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|cmd| match cmd {
+                vk_parse::Command::Alias { name, alias } => Some((name.clone(), alias.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The `parent` attribute of every handle that has one, e.g. `VkImageView` to `["VkDevice"]`.
+    /// Handle aliases are not included.
+    pub fn handle_parents(&self) -> HashMap<&str, Vec<&str>> {
+        self.registry
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Types(types) => Some(types.children.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|child| match child {
+                vk_parse::TypesChild::Type(ty) if ty.category.as_deref() == Some("handle") => {
+                    let parent = ty.parent.as_deref()?;
+                    let name = match &ty.spec {
+                        vk_parse::TypeSpec::Code(code) => {
+                            code.markup.iter().find_map(|markup| match markup {
+                                vk_parse::TypeCodeMarkup::Name(name) => Some(name.as_str()),
+                                _ => None,
+                            })?
+                        }
+                        _ => return None,
+                    };
+                    Some((name, parent.split(',').collect()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Structs, unions, handles, type aliases and the other type definitions.
+    pub fn definitions(&self) -> Vec<&vkxml::DefinitionsElement> {
+        self.vkxml
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::RegistryElement::Definitions(definitions) => Some(definitions),
+                _ => None,
+            })
+            .flat_map(|definitions| definitions.elements.iter())
+            .collect()
+    }
+
+    /// Enums and bitmasks with the values defined in their `<enums>` block.
+    pub fn enums(&self) -> Vec<&vkxml::Enumeration> {
+        self.vkxml
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::RegistryElement::Enums(enums) => Some(enums),
+                _ => None,
+            })
+            .flat_map(|enums| {
+                enums.elements.iter().filter_map(|elem| match elem {
+                    vkxml::EnumsElement::Enumeration(e) => Some(e),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    /// API constants like `VK_MAX_EXTENSION_NAME_SIZE`.
+    pub fn constants(&self) -> Vec<&vkxml::Constant> {
+        self.vkxml
+            .elements
+            .iter()
+            .filter_map(|elem| match elem {
+                vkxml::RegistryElement::Constants(constants) => Some(constants),
+                _ => None,
+            })
+            .flat_map(|constants| constants.elements.iter())
+            .collect()
+    }
+
+    /// The enum values that core versions and extensions add to existing enums, core versions
+    /// first. A value that is required by several extensions is listed for each of them.
+    pub fn extension_enums(&self) -> Vec<ExtensionEnum<'_>> {
+        let features = self
+            .core_features()
+            .map(|feature| (feature.name.as_str(), 0, feature.children.as_slice()));
+        let extensions = self.extensions().iter().map(|ext| {
+            (
+                ext.name.as_str(),
+                ext.number.unwrap_or(0),
+                ext.children.as_slice(),
+            )
+        });
+        features
+            .chain(extensions)
+            .flat_map(|(provided_by, extension_number, children)| {
+                children
+                    .iter()
+                    .filter_map(|child| match child {
+                        vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
+                        _ => None,
+                    })
+                    .flatten()
+                    .filter_map(move |item| match item {
+                        vk_parse::InterfaceItem::Enum(e) => Some(ExtensionEnum {
+                            provided_by,
+                            extension_number,
+                            name: &e.name,
+                            extends: enum_spec_extends(&e.spec)?,
+                            comment: e.comment.as_deref(),
+                            spec: &e.spec,
+                        }),
+                        _ => None,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Resolves an enum value given as an `offset` into the block of values reserved for an
+/// extension, `1000000000 + (extension_number - 1) * 1000 + offset`, negated if `positive` is
+/// `false`. The extension number is the `extnumber` of the value if it has one, which is the case
+/// for values of promoted extensions that are required by a core version. Returns `None` if the
+/// value doesn't fit in an `i64`, which only happens in a malformed registry.
+pub(crate) fn offset_enum_value(extension_number: i64, offset: i64, positive: bool) -> Option<i64> {
+    const EXT_BASE: i64 = 1_000_000_000;
+    const EXT_BLOCK_SIZE: i64 = 1000;
+    let value = extension_number
+        .checked_sub(1)?
+        .checked_mul(EXT_BLOCK_SIZE)?
+        .checked_add(EXT_BASE)?
+        .checked_add(offset)?;
+    if positive {
+        Some(value)
+    } else {
+        value.checked_neg()
+    }
+}
+
+fn enum_spec_extends(spec: &vk_parse::EnumSpec) -> Option<&str> {
+    use vk_parse::EnumSpec;
+    match spec {
+        EnumSpec::Alias { extends, .. }
+        | EnumSpec::Bitpos { extends, .. }
+        | EnumSpec::Value { extends, .. } => extends.as_deref(),
+        EnumSpec::Offset { extends, .. } => Some(extends),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const VK_XML: &str = r#"<registry>
+        <types>
+            <type name="VkStructureType" category="enum"/>
+        </types>
+        <enums name="VkStructureType" type="enum">
+            <enum value="0" name="VK_STRUCTURE_TYPE_APPLICATION_INFO"/>
+        </enums>
+        <commands>
+            <command>
+                <proto><type>void</type> <name>vkTrimCommandPool</name></proto>
+                <param><type>VkDevice</type> <name>device</name></param>
+            </command>
+            <command name="vkTrimCommandPoolKHR" alias="vkTrimCommandPool"/>
+        </commands>
+        <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+            <require>
+                <command name="vkTrimCommandPool"/>
+            </require>
+        </feature>
+        <extensions>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan">
+                <require>
+                    <enum value="70" name="VK_KHR_SWAPCHAIN_SPEC_VERSION"/>
+                    <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR"/>
+                </require>
+            </extension>
+        </extensions>
+    </registry>"#;
+
+    /// Parses a hand-written registry made of `children`, for tests that only need a few items
+    /// instead of the whole `vk.xml`.
+    pub(crate) fn registry(children: &str) -> Context {
+        Context::parse(&format!("<registry>{}</registry>", children))
+            .expect("Invalid test registry")
+    }
+
+    /// A registry with only the `<enums>` block of `name` and its type, `kind` being `enum` or
+    /// `bitmask`.
+    pub(crate) fn enums_registry(name: &str, kind: &str, values: &str) -> Context {
+        registry(&format!(
+            r#"<types><type name="{name}" category="enum"/></types>
+            <enums name="{name}" type="{kind}">{values}</enums>"#,
+            name = name,
+            kind = kind,
+            values = values
+        ))
+    }
+
+    /// A registry with only the extension `name`, which requires `items`.
+    pub(crate) fn extension_registry(name: &str, number: i64, items: &str) -> Context {
+        registry(&format!(
+            r#"<extensions>
+                <extension name="{}" number="{}" supported="vulkan">
+                    <require>{}</require>
+                </extension>
+            </extensions>"#,
+            name, number, items
+        ))
+    }
+
+    #[test]
+    fn collect_registry_items() {
+        let ctx = Context::parse(VK_XML).unwrap();
+        assert_eq!(ctx.extensions().len(), 1);
+        assert_eq!(ctx.core_features().count(), 1);
+        assert!(ctx.commands().contains_key("vkTrimCommandPool"));
+        assert_eq!(
+            ctx.command_aliases()["vkTrimCommandPoolKHR"],
+            "vkTrimCommandPool"
+        );
+        let extension_enums = ctx.extension_enums();
+        assert_eq!(extension_enums.len(), 1);
+        assert_eq!(
+            extension_enums[0].name,
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR"
+        );
+        assert_eq!(extension_enums[0].extends, "VkStructureType");
+        assert_eq!(extension_enums[0].provided_by, "VK_KHR_swapchain");
+        assert_eq!(extension_enums[0].extension_number, 2);
+    }
+
+    #[test]
+    fn extension_enum_value_overflow() {
+        let ctx = extension_registry(
+            "VK_EXT_huge",
+            i64::MAX,
+            r#"<enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_HUGE"/>"#,
+        );
+        let extension_enums = ctx.extension_enums();
+        assert_eq!(extension_enums.len(), 1);
+        assert_eq!(extension_enums[0].value(), None);
+    }
+
+    #[test]
+    fn extension_enum_value_from_offset() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+                <require>
+                    <enum extends="VkStructureType" extnumber="158" offset="1" name="VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_INFO"/>
+                </require>
+            </feature>
+            <extensions>
+                <extension name="VK_KHR_swapchain" number="2" supported="vulkan">
+                    <require>
+                        <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR"/>
+                        <enum offset="4" extends="VkResult" dir="-" name="VK_ERROR_OUT_OF_DATE_KHR"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let values: Vec<_> = ctx.extension_enums().iter().map(|e| e.value()).collect();
+        assert_eq!(
+            values,
+            [
+                Some(EnumValue::Number(1_000_157_001)),
+                Some(EnumValue::Number(1_000_001_000)),
+                Some(EnumValue::Number(-1_000_001_004)),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_extension_enums() {
+        let ctx = extension_registry(
+            "VK_EXT_foo",
+            10,
+            r#"<enum value="1" name="VK_EXT_FOO_SPEC_VERSION"/>
+            <enum value="&quot;VK_EXT_foo&quot;" name="VK_EXT_FOO_EXTENSION_NAME"/>
+            <enum bitpos="3" extends="VkCullModeFlagBits" name="VK_CULL_MODE_SIDEWAYS_BIT_EXT"/>
+            <enum extends="VkResult" value="-7" name="VK_ERROR_FOO_EXT"/>
+            <enum extends="VkResult" alias="VK_ERROR_FOO_EXT" name="VK_ERROR_BAR_EXT"/>
+            <type name="VkFooEXT"/>"#,
+        );
+        // Constants of the extension don't extend an enum and aren't collected
+        let extension_enums = ctx.extension_enums();
+        let collected: Vec<_> = extension_enums
+            .iter()
+            .map(|e| {
+                (
+                    e.provided_by,
+                    e.extension_number,
+                    e.extends,
+                    e.name,
+                    e.value(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            collected,
+            [
+                (
+                    "VK_EXT_foo",
+                    10,
+                    "VkCullModeFlagBits",
+                    "VK_CULL_MODE_SIDEWAYS_BIT_EXT",
+                    Some(EnumValue::BitPos(3))
+                ),
+                (
+                    "VK_EXT_foo",
+                    10,
+                    "VkResult",
+                    "VK_ERROR_FOO_EXT",
+                    Some(EnumValue::Number(-7))
+                ),
+                (
+                    "VK_EXT_foo",
+                    10,
+                    "VkResult",
+                    "VK_ERROR_BAR_EXT",
+                    Some(EnumValue::Alias("VK_ERROR_FOO_EXT"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_aliases_of_fixture() {
+        let ctx = enums_registry(
+            "VkStencilFaceFlagBits",
+            "bitmask",
+            r#"<enum bitpos="0" name="VK_STENCIL_FACE_FRONT_BIT"/>
+            <enum value="0x00000003" name="VK_STENCIL_FACE_FRONT_AND_BACK"/>
+            <enum name="VK_STENCIL_FRONT_AND_BACK" alias="VK_STENCIL_FACE_FRONT_AND_BACK"/>"#,
+        );
+        let aliases = &ctx.enum_aliases()["VkStencilFaceFlagBits"];
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].name, "VK_STENCIL_FRONT_AND_BACK");
+        assert_eq!(aliases[0].alias_enum, "VkStencilFaceFlagBits");
+    }
+}
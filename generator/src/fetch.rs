@@ -0,0 +1,197 @@
+//! Downloads the registry files of a tagged Vulkan-Headers release, so that the bindings can be
+//! regenerated for a spec update without checking out the submodule at the new tag.
+//!
+//! Files are cached in `<cache_dir>/<tag>/` with the same layout as `Vulkan-Headers/registry`,
+//! so the returned `vk.xml` path can be passed straight to
+//! [`write_source_code`](crate::write_source_code).
+//! A `<file>.sha256` is written next to every downloaded file and checked whenever the cached file
+//! is reused. Files that can't be downloaded, including the ones a tag doesn't have, aren't
+//! cached and are requested again on the next fetch.
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+const BASE_URL: &str = "https://raw.githubusercontent.com/KhronosGroup/Vulkan-Headers";
+
+/// Paths to the cached registry files of one tag. `video.xml` and `validusage.json` are `None`
+/// for tags that predate them.
+#[derive(Clone, Debug)]
+pub struct RegistryFiles {
+    pub vk_xml: PathBuf,
+    pub video_xml: Option<PathBuf>,
+    pub validusage_json: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Io(io::Error),
+    Http(Box<ureq::Error>),
+    /// The downloaded or cached file doesn't have the expected SHA-256 digest.
+    Checksum {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Io(err) => fmt::Display::fmt(err, f),
+            FetchError::Http(err) => fmt::Display::fmt(err, f),
+            FetchError::Checksum {
+                file,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "SHA-256 of {} is {}, expected {}",
+                file, actual, expected
+            ),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io(err) => Some(err),
+            FetchError::Http(err) => Some(err),
+            FetchError::Checksum { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Fetches the registry files of a Vulkan-Headers tag, e.g. `v1.2.170`.
+///
+/// ```no_run
+/// # fn main() -> Result<(), generator::FetchError> {
+/// let files = generator::RegistryFetcher::new("v1.2.170", "target/vk-registry")
+///     .sha256("vk.xml", "<expected digest>")
+///     .fetch()?;
+/// generator::write_source_code(&files.vk_xml, "ash/src");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegistryFetcher {
+    tag: String,
+    cache_dir: PathBuf,
+    checksums: HashMap<String, String>,
+}
+
+impl RegistryFetcher {
+    pub fn new(tag: &str, cache_dir: impl AsRef<Path>) -> Self {
+        RegistryFetcher {
+            tag: tag.to_owned(),
+            cache_dir: cache_dir.as_ref().to_owned(),
+            checksums: HashMap::new(),
+        }
+    }
+
+    /// Requires `file`, e.g. `vk.xml`, to have the hex encoded SHA-256 digest `sha256`. Files
+    /// without an expected digest are only checked against the digest recorded when they were
+    /// cached.
+    pub fn sha256(mut self, file: &str, sha256: &str) -> Self {
+        self.checksums
+            .insert(file.to_owned(), sha256.to_ascii_lowercase());
+        self
+    }
+
+    pub fn fetch(&self) -> Result<RegistryFiles, FetchError> {
+        let dir = self.cache_dir.join(&self.tag);
+        fs::create_dir_all(&dir)?;
+        let vk_xml = self
+            .fetch_file(&dir, "vk.xml")?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "vk.xml"))?;
+        Ok(RegistryFiles {
+            vk_xml,
+            video_xml: self.fetch_file(&dir, "video.xml")?,
+            validusage_json: self.fetch_file(&dir, "validusage.json")?,
+        })
+    }
+
+    /// Returns `None` if the tag doesn't have `file`.
+    fn fetch_file(&self, dir: &Path, file: &str) -> Result<Option<PathBuf>, FetchError> {
+        let path = dir.join(file);
+        let digest_path = dir.join(format!("{}.sha256", file));
+        if let (Ok(bytes), Ok(recorded)) = (fs::read(&path), fs::read_to_string(&digest_path)) {
+            let actual = sha256_hex(&bytes);
+            // A corrupt or outdated cache entry is downloaded again
+            if actual == recorded.trim() && self.check(file, &actual).is_ok() {
+                return Ok(Some(path));
+            }
+        }
+
+        let url = format!("{}/{}/registry/{}", BASE_URL, self.tag, file);
+        let mut bytes = Vec::new();
+        match ureq::get(&url).call() {
+            Ok(response) => {
+                response.into_reader().read_to_end(&mut bytes)?;
+            }
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(err) => return Err(FetchError::Http(Box::new(err))),
+        }
+        let actual = sha256_hex(&bytes);
+        self.check(file, &actual)?;
+
+        // Write to a temporary file first so an interrupted download isn't mistaken for a cached one
+        let tmp_path = dir.join(format!("{}.tmp", file));
+        fs::write(&tmp_path, &bytes)?;
+        fs::rename(&tmp_path, &path)?;
+        fs::write(&digest_path, &actual)?;
+        Ok(Some(path))
+    }
+
+    fn check(&self, file: &str, actual: &str) -> Result<(), FetchError> {
+        match self.checksums.get(file) {
+            Some(expected) if expected != actual => Err(FetchError::Checksum {
+                file: file.to_owned(),
+                expected: expected.clone(),
+                actual: actual.to_owned(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_of_empty_input() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let fetcher = RegistryFetcher::new("v1.2.170", "cache").sha256("vk.xml", "ABCD");
+        assert!(fetcher.check("vk.xml", "abcd").is_ok());
+        assert!(matches!(
+            fetcher.check("vk.xml", "1234"),
+            Err(FetchError::Checksum { .. })
+        ));
+        assert!(fetcher.check("video.xml", "1234").is_ok());
+    }
+}
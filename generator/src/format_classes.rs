@@ -0,0 +1,98 @@
+//! Generates `Format::compatibility_class` and `Format::is_compatible_with` from the format
+//! compatibility classes of the specification. The classes come from `format_classes.json`, which
+//! is bundled with the generator as the `formats` section that has them is missing from this
+//! version of the registry.
+use crate::variant_ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+const CLASSES_JSON: &str = include_str!("../format_classes.json");
+
+/// The formats of each compatibility class, in the order the classes first appear.
+fn format_classes(classes: &Value) -> Vec<(&str, Vec<&str>)> {
+    let mut order = Vec::new();
+    let mut formats = BTreeMap::<&str, Vec<&str>>::new();
+    for entry in classes["classes"]
+        .as_array()
+        .expect("Missing `classes` in format_classes.json")
+    {
+        let vk_name = entry["vk"].as_str().expect("Format without `vk` name");
+        let class = entry["class"].as_str().expect("Format without `class`");
+        if !formats.contains_key(class) {
+            order.push(class);
+        }
+        formats.entry(class).or_default().push(vk_name);
+    }
+    order
+        .into_iter()
+        .map(|class| (class, formats.remove(class).unwrap()))
+        .collect()
+}
+
+pub fn generate_format_classes() -> TokenStream {
+    let classes: Value = serde_json::from_str(CLASSES_JSON).expect("Invalid format_classes.json");
+    let arms = format_classes(&classes)
+        .into_iter()
+        .map(|(class, formats)| {
+            let variants = formats
+                .iter()
+                .map(|vk_name| variant_ident("VkFormat", vk_name));
+            quote! { #(Format::#variants)|* => Some(#class) }
+        });
+    quote! {
+        use crate::vk::enums::Format;
+
+        impl Format {
+            /// Returns the compatibility class of the format, such as `32-bit` or `BC1_RGBA`,
+            /// `None` for [`Format::UNDEFINED`] and formats that are newer than the table.
+            ///
+            /// Image views of images with `ImageCreateFlags::MUTABLE_FORMAT` and copies between
+            /// images need formats of the same class. The planes of multi-planar formats have the
+            /// class of their own single-plane format.
+            pub fn compatibility_class(self) -> Option<&'static str> {
+                match self {
+                    #(#arms,)*
+                    _ => None,
+                }
+            }
+
+            /// Returns whether the formats are the same, or in the same compatibility class.
+            pub fn is_compatible_with(self, other: Format) -> bool {
+                self == other
+                    || self
+                        .compatibility_class()
+                        .is_some_and(|class| other.compatibility_class() == Some(class))
+            }
+        }
+
+        #[cfg(test)]
+        mod tests;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_classes() {
+        let classes: Value = serde_json::from_str(CLASSES_JSON).unwrap();
+        let classes = format_classes(&classes);
+        assert_eq!(classes[0].0, "8-bit");
+        let bit32 = &classes
+            .iter()
+            .find(|(class, _)| *class == "32-bit")
+            .unwrap()
+            .1;
+        assert!(bit32.contains(&"VK_FORMAT_R8G8B8A8_UNORM"));
+        assert!(bit32.contains(&"VK_FORMAT_B10G11R11_UFLOAT_PACK32"));
+        assert!(!bit32.contains(&"VK_FORMAT_D32_SFLOAT"));
+
+        let code = generate_format_classes().to_string();
+        assert!(
+            code.contains("Format :: BC7_UNORM_BLOCK | Format :: BC7_SRGB_BLOCK => Some (\"BC7\")")
+        );
+    }
+}
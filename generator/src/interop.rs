@@ -0,0 +1,167 @@
+//! Generates `vk::interop`, tables that map `vk::Format` to the `DXGI_FORMAT`, `MTLPixelFormat`
+//! and OpenGL internal format with the same memory layout, for sharing images with Direct3D, Metal
+//! and OpenGL. The pairs come from `interop_formats.json`, which is bundled with the generator as the registry
+//! doesn't have this information.
+use crate::variant_ident;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use serde_json::Value;
+
+const FORMATS_JSON: &str = include_str!("../interop_formats.json");
+
+fn table(formats: &Value, api: &str) -> TokenStream {
+    let entries = formats[api]
+        .as_array()
+        .unwrap_or_else(|| panic!("Missing `{}` in interop_formats.json", api))
+        .iter()
+        .map(|entry| {
+            let vk_name = entry["vk"].as_str().expect("Format without `vk` name");
+            let value = entry["value"].as_u64().expect("Format without `value`");
+            let variant = variant_ident("VkFormat", vk_name);
+            let value = Literal::u64_unsuffixed(value);
+            quote! { (Format::#variant, #value) }
+        });
+    quote! { &[#(#entries),*] }
+}
+
+pub fn generate_interop() -> TokenStream {
+    let formats: Value = serde_json::from_str(FORMATS_JSON).expect("Invalid interop_formats.json");
+    let dxgi_formats = table(&formats, "dxgi");
+    let metal_formats = table(&formats, "metal");
+    let gl_formats = table(&formats, "gl");
+    quote! {
+        //! Formats with the same memory layout in other graphics APIs.
+        //!
+        //! # Sharing memory with OpenGL
+        //!
+        //! OpenGL can't export its memory, so images that both APIs use are created by Vulkan and
+        //! imported into a memory object with `GL_EXT_memory_object`:
+        //!
+        //! 1. Create the image with a [`vk::ExternalMemoryImageCreateInfo`] and allocate its memory
+        //!    with a [`vk::ExportMemoryAllocateInfo`], both with the `OPAQUE_FD` handle type, or
+        //!    `OPAQUE_WIN32` on Windows.
+        //! 2. Get the handle with [`khr::ExternalMemoryFd::get_memory_fd`], or
+        //!    `vkGetMemoryWin32HandleKHR`.
+        //! 3. Create the memory object with `glCreateMemoryObjectsEXT` and import the handle with
+        //!    `glImportMemoryFdEXT` or `glImportMemoryWin32HandleEXT`, passing the allocation size.
+        //! 4. Set `GL_TEXTURE_TILING_EXT` to [`ImageTiling::to_gl_tiling`] of the image's tiling and
+        //!    create the texture with `glTexStorageMem2DEXT`, passing [`Format::to_gl`] of the
+        //!    image's format as the internal format.
+        //!
+        //! Semaphores are shared the same way with [`vk::ExportSemaphoreCreateInfo`],
+        //! `glGenSemaphoresEXT` and `glImportSemaphoreFdEXT`, and signaled and waited on with
+        //! `glSignalSemaphoreEXT` and `glWaitSemaphoreEXT`, which take the layouts that the images
+        //! are in.
+        //!
+        //! [`vk::ExternalMemoryImageCreateInfo`]: crate::vk::ExternalMemoryImageCreateInfo
+        //! [`vk::ExportMemoryAllocateInfo`]: crate::vk::ExportMemoryAllocateInfo
+        //! [`vk::ExportSemaphoreCreateInfo`]: crate::vk::ExportSemaphoreCreateInfo
+        //! [`khr::ExternalMemoryFd::get_memory_fd`]: crate::extensions::khr::ExternalMemoryFd::get_memory_fd
+        use crate::vk::enums::{Format, ImageTiling};
+
+        /// A `DXGI_FORMAT` value.
+        pub type DxgiFormat = u32;
+        /// A `MTLPixelFormat` value.
+        pub type MtlPixelFormat = u64;
+        /// A `GLenum` value.
+        pub type GlEnum = u32;
+
+        /// Vulkan formats and the `DXGI_FORMAT` with the same memory layout. Formats without an
+        /// equivalent are missing.
+        pub const DXGI_FORMATS: &[(Format, DxgiFormat)] = #dxgi_formats;
+
+        /// Vulkan formats and the `MTLPixelFormat` with the same memory layout. Formats without an
+        /// equivalent are missing.
+        pub const METAL_FORMATS: &[(Format, MtlPixelFormat)] = #metal_formats;
+
+        /// Vulkan formats and the sized OpenGL internal format with the same memory layout.
+        /// Formats without an equivalent are missing.
+        pub const GL_FORMATS: &[(Format, GlEnum)] = #gl_formats;
+
+        impl Format {
+            /// Returns the `DXGI_FORMAT` with the same memory layout, `None` if there is none.
+            pub fn to_dxgi(self) -> Option<DxgiFormat> {
+                DXGI_FORMATS
+                    .iter()
+                    .find(|&&(format, _)| format == self)
+                    .map(|&(_, dxgi)| dxgi)
+            }
+
+            /// Returns the format with the same memory layout as the `DXGI_FORMAT` `dxgi`, `None`
+            /// if there is none.
+            pub fn from_dxgi(dxgi: DxgiFormat) -> Option<Self> {
+                DXGI_FORMATS
+                    .iter()
+                    .find(|&&(_, value)| value == dxgi)
+                    .map(|&(format, _)| format)
+            }
+
+            /// Returns the `MTLPixelFormat` with the same memory layout, `None` if there is none.
+            pub fn to_metal(self) -> Option<MtlPixelFormat> {
+                METAL_FORMATS
+                    .iter()
+                    .find(|&&(format, _)| format == self)
+                    .map(|&(_, metal)| metal)
+            }
+
+            /// Returns the format with the same memory layout as the `MTLPixelFormat` `metal`,
+            /// `None` if there is none.
+            pub fn from_metal(metal: MtlPixelFormat) -> Option<Self> {
+                METAL_FORMATS
+                    .iter()
+                    .find(|&&(_, value)| value == metal)
+                    .map(|&(format, _)| format)
+            }
+
+            /// Returns the sized OpenGL internal format with the same memory layout, `None` if
+            /// there is none.
+            pub fn to_gl(self) -> Option<GlEnum> {
+                GL_FORMATS
+                    .iter()
+                    .find(|&&(format, _)| format == self)
+                    .map(|&(_, gl)| gl)
+            }
+
+            /// Returns the format with the same memory layout as the OpenGL internal format `gl`,
+            /// `None` if there is none.
+            pub fn from_gl(gl: GlEnum) -> Option<Self> {
+                GL_FORMATS
+                    .iter()
+                    .find(|&&(_, value)| value == gl)
+                    .map(|&(format, _)| format)
+            }
+        }
+
+        impl ImageTiling {
+            /// Returns the `GL_TEXTURE_TILING_EXT` value of textures that are stored in the memory
+            /// of an image with this tiling, `None` for the DRM format modifier tiling that OpenGL
+            /// doesn't support.
+            pub fn to_gl_tiling(self) -> Option<GlEnum> {
+                match self {
+                    // GL_OPTIMAL_TILING_EXT
+                    ImageTiling::OPTIMAL => Some(0x9584),
+                    // GL_LINEAR_TILING_EXT
+                    ImageTiling::LINEAR => Some(0x9585),
+                    _ => None,
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_formats() {
+        let code = generate_interop().to_string();
+        assert!(code.contains("(Format :: R8G8B8A8_UNORM , 28)"));
+        assert!(code.contains("(Format :: B8G8R8A8_SRGB , 81)"));
+        assert!(code.contains("(Format :: R8G8B8A8_SRGB , 35907)"));
+        assert!(code.contains("(Format :: D24_UNORM_S8_UINT , 35056)"));
+    }
+}
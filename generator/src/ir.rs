@@ -0,0 +1,150 @@
+//! A JSON dump of the resolved registry, so that tools outside of Rust can reuse the work of
+//! [`Context`] instead of resolving `vk.xml` themselves.
+use crate::{Context, EnumValue};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+fn enum_value_json(name: &str, value: EnumValue) -> Value {
+    match value {
+        EnumValue::Number(value) => json!({ "name": name, "value": value }),
+        EnumValue::BitPos(bitpos) => json!({ "name": name, "bitpos": bitpos }),
+        EnumValue::Alias(alias) => json!({ "name": name, "alias": alias }),
+    }
+}
+
+fn constant_value(constant: &vkxml::Constant) -> Option<EnumValue<'_>> {
+    if let Some(number) = constant.number {
+        Some(EnumValue::Number(number.into()))
+    } else if let Some(bitpos) = constant.bitpos {
+        Some(EnumValue::BitPos(bitpos))
+    } else {
+        let hex = constant.hex.as_ref()?;
+        let value = i64::from_str_radix(hex.trim_start_matches("0x"), 16).ok()?;
+        Some(EnumValue::Number(value))
+    }
+}
+
+/// Returns the enums with the values added by core versions and extensions, the structs,
+/// handles and other definitions, the commands and the extensions of `ctx`.
+///
+/// Enum values are resolved to `value`, `bitpos` or `alias`, values added by an extension
+/// name it in `provided_by`. Definitions and commands use the serialization of `vkxml`.
+pub fn registry_ir(ctx: &Context) -> Value {
+    let extension_enums = ctx.extension_enums();
+    let enums: Vec<Value> = ctx
+        .enums()
+        .into_iter()
+        .map(|e| {
+            let mut seen = HashSet::new();
+            let core_values = e.elements.iter().filter_map(|elem| match elem {
+                vkxml::EnumerationElement::Enum(constant) => {
+                    seen.insert(constant.name.as_str());
+                    Some(enum_value_json(&constant.name, constant_value(constant)?))
+                }
+                _ => None,
+            });
+            let mut values: Vec<Value> = core_values.collect();
+            for ext_enum in extension_enums.iter().filter(|ext| ext.extends == e.name) {
+                // Values required by several extensions are only listed for the first one
+                if !seen.insert(ext_enum.name) {
+                    continue;
+                }
+                if let Some(value) = ext_enum.value() {
+                    let mut value = enum_value_json(ext_enum.name, value);
+                    value["provided_by"] = json!(ext_enum.provided_by);
+                    values.push(value);
+                }
+            }
+            let bitmask = matches!(e.purpose, Some(vkxml::EnumerationPurpose::Bitmask));
+            json!({ "name": e.name, "bitmask": bitmask, "values": values })
+        })
+        .collect();
+
+    let mut commands: Vec<Value> = ctx
+        .commands()
+        .values()
+        .map(|cmd| serde_json::to_value(cmd).expect("Unable to serialize command"))
+        .collect();
+    commands.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+    let command_aliases: Map<String, Value> = ctx
+        .command_aliases()
+        .into_iter()
+        .map(|(name, alias)| (name, json!(alias)))
+        .collect();
+
+    let extensions: Vec<Value> = ctx
+        .extensions()
+        .iter()
+        .map(|ext| {
+            let commands: Vec<&str> = ext
+                .children
+                .iter()
+                .filter_map(|child| match child {
+                    vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|item| match item {
+                    vk_parse::InterfaceItem::Command { name, .. } => Some(name.as_str()),
+                    _ => None,
+                })
+                .collect();
+            let requires: Vec<&str> = ext
+                .requires
+                .iter()
+                .flat_map(|requires| requires.split(','))
+                .collect();
+            json!({
+                "name": ext.name,
+                "number": ext.number,
+                "type": ext.ext_type,
+                "supported": ext.supported,
+                "platform": ext.platform,
+                "requires": requires,
+                "requires_core": ext.requires_core,
+                "promoted_to": ext.promotedto,
+                "deprecated_by": ext.deprecatedby,
+                "obsoleted_by": ext.obsoletedby,
+                "provisional": ext.provisional,
+                "commands": commands,
+            })
+        })
+        .collect();
+
+    let definitions: Vec<Value> = ctx
+        .definitions()
+        .into_iter()
+        .map(|def| serde_json::to_value(def).expect("Unable to serialize definition"))
+        .collect();
+
+    json!({
+        "enums": enums,
+        "definitions": definitions,
+        "commands": commands,
+        "command_aliases": command_aliases,
+        "extensions": extensions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_extension_enum_values() {
+        let ctx = Context::parse(crate::context::tests::VK_XML).unwrap();
+        let ir = registry_ir(&ctx);
+        let values = &ir["enums"][0]["values"];
+        assert_eq!(
+            values[0],
+            json!({ "name": "VK_STRUCTURE_TYPE_APPLICATION_INFO", "value": 0 })
+        );
+        assert_eq!(values[1]["value"], 1_000_001_000);
+        assert_eq!(values[1]["provided_by"], "VK_KHR_swapchain");
+        assert_eq!(
+            ir["command_aliases"]["vkTrimCommandPoolKHR"],
+            "vkTrimCommandPool"
+        );
+        assert_eq!(ir["extensions"][0]["number"], 2);
+    }
+}
@@ -6,12 +6,47 @@ use quote::*;
 use heck::{CamelCase, ShoutySnakeCase, SnakeCase};
 use itertools::Itertools;
 use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::convert::TryFrom;
 use std::fmt::Display;
 use std::hash::BuildHasher;
 use std::path::Path;
+use std::rc::Rc;
 use syn::Ident;
 
+mod context;
+#[cfg(feature = "fetch")]
+mod fetch;
+mod format_classes;
+mod interop;
+mod ir;
+mod meta;
+mod native;
+mod owned;
+mod profiles;
+mod result_codes;
+mod trace;
+mod validate;
+mod validusage;
+pub use context::{Context, EnumAlias, EnumValue, ExtensionEnum};
+#[cfg(feature = "fetch")]
+pub use fetch::{FetchError, RegistryFetcher, RegistryFiles};
+pub use format_classes::generate_format_classes;
+pub use interop::generate_interop;
+pub use ir::registry_ir;
+pub use meta::generate_meta;
+pub use native::generate_native_enums;
+pub use owned::generate_owned;
+pub use profiles::{generate_profiles, write_profiles, ProfileError};
+pub use result_codes::generate_result_codes;
+pub use trace::generate_trace;
+pub use validate::{Conflict, RegistryConflicts};
+pub use validusage::{ValidUsage, ValidUsageMap};
+
+use context::offset_enum_value;
+
 pub trait ExtensionExt {}
 #[derive(Copy, Clone, Debug)]
 pub enum CType {
@@ -87,6 +122,28 @@ fn khronos_link<S: Display>(name: &S) -> Literal {
     ))
 }
 
+pub fn vk_extensions_macro() -> TokenStream {
+    quote! {
+        /// Builds the array of extension name pointers that `enabled_extension_names` takes, from
+        /// types with a `name() -> &'static CStr` function, such as the extension loaders in
+        /// `ash::extensions` and the `vk::*Fn` tables.
+        ///
+        /// ```
+        /// use ash::extensions::{ext, khr};
+        /// use ash::{vk, vk_extensions};
+        /// let extension_names = vk_extensions![khr::Surface, ext::DebugUtils, vk::KhrDisplayFn];
+        /// let create_info =
+        ///     vk::InstanceCreateInfo::builder().enabled_extension_names(&extension_names);
+        /// ```
+        #[macro_export]
+        macro_rules! vk_extensions {
+            ($($extension: path),* $(,)?) => {
+                [$(<$extension>::name().as_ptr()),*]
+            };
+        }
+    }
+}
+
 pub fn define_handle_macro() -> TokenStream {
     quote! {
         #[macro_export]
@@ -159,6 +216,16 @@ pub fn handle_nondispatchable_macro() -> TokenStream {
                     pub const fn null() -> $name{
                         $name(0)
                     }
+
+                    /// Casts `handles` to their raw values without copying.
+                    pub fn as_raw_slice(handles: &[$name]) -> &[u64] {
+                        unsafe { ::std::slice::from_raw_parts(handles.as_ptr() as *const u64, handles.len()) }
+                    }
+
+                    /// Casts the raw values in `raw` to handles without copying.
+                    pub fn from_raw_slice(raw: &[u64]) -> &[$name] {
+                        unsafe { ::std::slice::from_raw_parts(raw.as_ptr() as *const $name, raw.len()) }
+                    }
                 }
 
                 impl fmt::Pointer for $name {
@@ -248,6 +315,15 @@ pub fn vk_bitflags_wrapped_macro() -> TokenStream {
                     pub fn contains(self, other: $name) -> bool {
                         self & other == other
                     }
+
+                    /// Returns whether `self` has bits that aren't in `all()`, e.g. bits of a newer
+                    /// version or extension reported by the driver. Such bits are kept by `|`, `&`,
+                    /// `^` and `-`, while `!` only complements the bits in `all()` and leaves them
+                    /// unchanged.
+                    #[inline]
+                    pub fn contains_unknown_bits(self) -> bool {
+                        self.0 & !$name::all().0 != 0
+                    }
                 }
 
                 impl ::std::ops::BitOr for $name {
@@ -303,7 +379,7 @@ pub fn vk_bitflags_wrapped_macro() -> TokenStream {
 
                     #[inline]
                     fn sub(self, rhs: $name) -> $name {
-                        self & !rhs
+                        $name (self.0 & !rhs.0)
                     }
                 }
 
@@ -327,6 +403,44 @@ pub fn vk_bitflags_wrapped_macro() -> TokenStream {
     }
 }
 
+/// The types of `vk_platform.h` whose size differs between targets, with the name that the
+/// generated code uses for them and their Rust equivalent on the usual targets. The fixed-width
+/// integer types of `vk_platform.h` are the Rust integer types of the same width everywhere.
+const VK_PLATFORM_TYPES: &[(&str, &str, &str)] = &[
+    ("void", "c_void", "::std::os::raw::c_void"),
+    ("char", "c_char", "::std::os::raw::c_char"),
+    ("int", "c_int", "::std::os::raw::c_int"),
+    ("size_t", "size_t", "usize"),
+];
+
+/// Generates `vk::platform`, which the other generated modules import the C types from instead
+/// of `std::os::raw`, so that the types of `vk_platform.h` can be changed in one place with
+/// [`RenderOptions::platform_types`].
+pub fn generate_platform(overrides: &HashMap<String, String>) -> TokenStream {
+    if let Some(unknown) = overrides
+        .keys()
+        .find(|c_name| !VK_PLATFORM_TYPES.iter().any(|(name, ..)| name == c_name))
+    {
+        panic!("`{}` isn't a platform type that can be overridden", unknown);
+    }
+    let types = VK_PLATFORM_TYPES.iter().map(|&(c_name, name, rust_type)| {
+        let rust_type = overrides.get(c_name).map_or(rust_type, String::as_str);
+        let rust_type: syn::Type = syn::parse_str(rust_type)
+            .unwrap_or_else(|_| panic!("Invalid Rust type `{}` for `{}`", rust_type, c_name));
+        let ident = format_ident!("{}", name);
+        let doc = format!("`{}` of `vk_platform.h`.", c_name);
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            pub type #ident = #rust_type;
+        }
+    });
+    quote! {
+        pub use std::os::raw::*;
+        #(#types)*
+    }
+}
+
 fn is_opaque_type(ty: &str) -> bool {
     matches!(
         ty,
@@ -340,6 +454,8 @@ fn is_opaque_type(ty: &str) -> bool {
             | "CAMetalLayer"
             | "IDirectFB"
             | "IDirectFBSurface"
+            | "_screen_context"
+            | "_screen_window"
     )
 }
 
@@ -384,6 +500,10 @@ pub fn platform_specific_types() -> TokenStream {
         pub type GgpFrameToken = u64;
         pub type IDirectFB = c_void;
         pub type IDirectFBSurface = c_void;
+        #[allow(non_camel_case_types)]
+        pub type _screen_context = c_void;
+        #[allow(non_camel_case_types)]
+        pub type _screen_window = c_void;
     }
 }
 #[derive(Debug, Copy, Clone)]
@@ -404,6 +524,10 @@ pub trait ConstantExt {
     fn constant(&self) -> Constant;
     fn variant_ident(&self, enum_name: &str) -> Ident;
     fn notation(&self) -> Option<&str>;
+    /// Which core version or extension added the value, `None` for values of Vulkan 1.0.
+    fn provided_by(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl ConstantExt for vkxml::ExtensionEnum {
@@ -430,6 +554,50 @@ impl ConstantExt for vkxml::Constant {
     }
 }
 
+/// The integer type that the values of an enum or bitmask are generated as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntType {
+    /// Enums, like `VkResult`.
+    I32,
+    /// `VkFlags` bitmasks.
+    U32,
+    /// `VkFlags64` bitmasks.
+    U64,
+}
+
+impl IntType {
+    /// The type of the values of the enum or bitmask named `enum_name` in `vk.xml`. Bitmasks are
+    /// assumed to be `VkFlags`, as the bitmasks of the registry are all 32 bits wide so far.
+    pub fn of_enum(enum_name: &str) -> Self {
+        if enum_name.contains("FlagBits") {
+            IntType::U32
+        } else {
+            IntType::I32
+        }
+    }
+
+    /// Reinterprets the bits of `value` as this type, `None` if `value` doesn't fit in either the
+    /// signed or the unsigned integer of the same width. This turns `0xFFFFFFFF` into `-1` for
+    /// `I32`, the value the C headers end up with.
+    fn wrap(self, value: i128) -> Option<i128> {
+        let (bits, signed) = match self {
+            IntType::I32 => (32, true),
+            IntType::U32 => (32, false),
+            IntType::U64 => (64, false),
+        };
+        let mask = (1i128 << bits) - 1;
+        if value < -(1i128 << (bits - 1)) || value > mask {
+            return None;
+        }
+        let value = value & mask;
+        if signed && value > mask >> 1 {
+            Some(value - (1i128 << bits))
+        } else {
+            Some(value)
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Constant {
     Number(i32),
@@ -443,25 +611,14 @@ pub enum Constant {
 impl quote::ToTokens for Constant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match *self {
-            Constant::Number(n) => {
-                let number = interleave_number('_', 3, &n.to_string());
-                syn::LitInt::new(&number, Span::call_site()).to_tokens(tokens);
-            }
-            Constant::Hex(ref s) => {
-                let number = interleave_number('_', 4, s);
-                syn::LitInt::new(&format!("0x{}", number), Span::call_site()).to_tokens(tokens);
+            Constant::Number(_) | Constant::Hex(_) | Constant::BitPos(_) => {
+                self.int_literal(None).to_tokens(tokens)
             }
             Constant::Text(ref text) => text.to_tokens(tokens),
             Constant::CExpr(ref expr) => {
                 let (_, (_, rexpr)) = cexpr(expr).expect("Unable to parse cexpr");
                 tokens.extend(rexpr.parse::<TokenStream>());
             }
-            Constant::BitPos(pos) => {
-                let value = 1 << pos;
-                let bit_string = format!("{:b}", value);
-                let bit_string = interleave_number('_', 4, &bit_string);
-                syn::LitInt::new(&format!("0b{}", bit_string), Span::call_site()).to_tokens(tokens);
-            }
             Constant::Alias(ref base, ref value) => tokens.extend(quote!(#base::#value)),
         }
     }
@@ -492,16 +649,57 @@ fn interleave_number(symbol: char, count: usize, n: &str) -> String {
         });
     number.chars().rev().collect()
 }
+
+fn decimal_literal(value: i128) -> String {
+    let digits = interleave_number('_', 3, &value.unsigned_abs().to_string());
+    if value < 0 {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
 impl Constant {
-    pub fn value(&self) -> Option<ConstVal> {
+    /// The value of a number, hex or bitpos constant, `None` for the other kinds.
+    pub fn int_value(&self) -> Option<i128> {
         match *self {
-            Constant::Number(n) => Some(ConstVal::U64(n as u64)),
-            Constant::Hex(ref hex) => u64::from_str_radix(&hex, 16).ok().map(ConstVal::U64),
-            Constant::BitPos(pos) => Some(ConstVal::U64((1 << pos) as u64)),
+            Constant::Number(n) => Some(i128::from(n)),
+            Constant::Hex(ref hex) => i128::from_str_radix(hex, 16).ok(),
+            Constant::BitPos(pos) => 1i128.checked_shl(pos),
             _ => None,
         }
     }
 
+    /// Formats a number, hex or bitpos constant as a literal in the same notation, decimal, hex or
+    /// binary. With `ty`, the value is converted to that type first, and written in decimal if
+    /// the conversion changes it, as `0xFFFFFFFF` isn't a valid `i32` literal.
+    ///
+    /// Panics if the constant isn't a number, hex or bitpos, or doesn't fit in `ty`.
+    pub fn int_literal(&self, ty: Option<IntType>) -> syn::LitInt {
+        let value = self
+            .int_value()
+            .unwrap_or_else(|| panic!("{:?} is not an integer", self));
+        let typed = match ty {
+            Some(ty) => ty
+                .wrap(value)
+                .unwrap_or_else(|| panic!("{:?} doesn't fit in {:?}", self, ty)),
+            None => value,
+        };
+        let repr = match *self {
+            _ if typed != value => decimal_literal(typed),
+            Constant::Hex(_) => format!("0x{}", interleave_number('_', 4, &format!("{:X}", value))),
+            Constant::BitPos(_) => {
+                format!("0b{}", interleave_number('_', 4, &format!("{:b}", value)))
+            }
+            _ => decimal_literal(value),
+        };
+        syn::LitInt::new(&repr, Span::call_site())
+    }
+
+    pub fn value(&self) -> Option<ConstVal> {
+        self.int_value().map(|value| ConstVal::U64(value as u64))
+    }
+
     pub fn ty(&self) -> CType {
         match self {
             Constant::Number(_) | Constant::Hex(_) => CType::USize,
@@ -667,7 +865,7 @@ fn name_to_tokens(type_name: &str) -> Ident {
         "int16_t" => "i16",
         "int32_t" => "i32",
         "int64_t" => "i64",
-        "size_t" => "usize",
+        "size_t" => "size_t",
         "int" => "c_int",
         "void" => "c_void",
         "char" => "c_char",
@@ -676,8 +874,33 @@ fn name_to_tokens(type_name: &str) -> Ident {
         "long" => "c_ulong",
         _ => type_name.strip_prefix("Vk").unwrap_or(type_name),
     };
-    let new_name = new_name.replace("FlagBits", "Flags");
-    format_ident!("{}", new_name.as_str())
+    Ident::new(&bitflags_type_name(new_name), Span::call_site())
+}
+
+/// Splits the name of a `FlagBits` enum into the parts before and after `FlagBits`, the version
+/// of 64-bit bitmasks and the vendor suffix: `VkAccessFlagBits2KHR` into `VkAccess`, `2` and
+/// `KHR`. Returns `None` if `name` doesn't end in such a suffix.
+fn split_flag_bits(name: &str) -> Option<(&str, &str, &str)> {
+    let idx = name.rfind("FlagBits")?;
+    let (base, rest) = (&name[..idx], &name[idx + "FlagBits".len()..]);
+    let version_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (version, vendor) = rest.split_at(version_len);
+    if vendor.chars().all(|c| c.is_ascii_uppercase()) {
+        Some((base, version, vendor))
+    } else {
+        None
+    }
+}
+
+/// The name of the `Flags` type of a `FlagBits` enum, e.g. `AccessFlags2KHR` for
+/// `AccessFlagBits2KHR`. Other names are returned unchanged.
+pub fn bitflags_type_name(name: &str) -> Cow<'_, str> {
+    match split_flag_bits(name) {
+        Some((base, version, vendor)) => format!("{}Flags{}{}", base, version, vendor).into(),
+        None => name.into(),
+    }
 }
 
 fn map_identifier_to_rust(ident: Ident) -> TokenTree {
@@ -788,11 +1011,112 @@ impl FieldExt for vkxml::Field {
 
 pub type CommandMap<'a> = HashMap<vkxml::Identifier, &'a vkxml::Command>;
 
+/// The array parameters of a `vkCmd*` command that its wrapper takes as slices, grouped by the
+/// name of the count parameter that is their length. Counts that are also the length of an array
+/// that may be null, or of an array of pointers or of `void`, are left out, as the wrapper keeps
+/// the raw parameters for all of their arrays.
+fn slice_params(cmd: &vkxml::Command) -> BTreeMap<&str, Vec<&vkxml::Field>> {
+    let mut groups: BTreeMap<&str, Vec<&vkxml::Field>> = BTreeMap::new();
+    if !cmd.name.starts_with("vkCmd") {
+        return groups;
+    }
+    let mut raw_counts = HashSet::new();
+    for field in &cmd.param {
+        let len = match (&field.array, &field.size) {
+            (Some(vkxml::ArrayType::Dynamic), Some(len)) => len.as_str(),
+            _ => continue,
+        };
+        let is_count = cmd.param.iter().any(|param| {
+            param.name.as_deref() == Some(len) && param.reference.is_none() && param.array.is_none()
+        });
+        let is_optional = field
+            .optional
+            .as_deref()
+            .is_some_and(|optional| optional.starts_with("true"));
+        if is_count
+            && field.is_const
+            && matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+            && field.basetype != "void"
+            && !is_optional
+        {
+            groups.entry(len).or_default().push(field);
+        } else {
+            raw_counts.insert(len);
+        }
+    }
+    groups.retain(|len, _| !raw_counts.contains(len));
+    groups
+}
+
+/// The parameters, their names and the body of the wrapper of `cmd` on its function pointer table. Array
+/// parameters of `vkCmd*` commands are taken as slices, see [`slice_params`], and their count is
+/// the length of the slice; arrays with the same count must have the same length.
+fn command_wrapper(
+    cmd: &vkxml::Command,
+    fn_name: &Ident,
+    unloaded_commands: UnloadedCommands,
+) -> (Vec<TokenStream>, Vec<Ident>, TokenStream) {
+    let slices = slice_params(cmd);
+    let slice_ident = |field: &vkxml::Field| {
+        let name = field.param_ident().to_string();
+        format_ident!("{}", name.strip_prefix("p_").unwrap_or(&name))
+    };
+    let mut params = Vec::new();
+    let mut param_names = Vec::new();
+    let mut asserts = Vec::new();
+    let mut args = Vec::new();
+    for field in &cmd.param {
+        let name = field.param_ident();
+        let field_name = field.name.as_deref().unwrap_or_default();
+        let group = field.size.as_deref().and_then(|len| {
+            slices
+                .get(len)
+                .filter(|group| group.iter().any(|f| std::ptr::eq(*f, field)))
+        });
+        if let Some(group) = slices.get(field_name) {
+            let first = slice_ident(group[0]);
+            args.push(quote!(#first.len() as _));
+        } else if let Some(group) = group {
+            let ident = slice_ident(field);
+            let ty = name_to_tokens(&field.basetype);
+            params.push(quote!(#ident: &[#ty]));
+            args.push(quote!(#ident.as_ptr()));
+            param_names.push(ident.clone());
+            let first = slice_ident(group[0]);
+            if first != ident {
+                asserts.push(quote!(assert_eq!(#first.len(), #ident.len());));
+            }
+        } else {
+            let ty = field.type_tokens(true);
+            params.push(quote!(#name: #ty));
+            args.push(quote!(#name));
+            param_names.push(name);
+        }
+    }
+    let fn_ptr = match unloaded_commands {
+        UnloadedCommands::PanicStub => quote!(self.#fn_name),
+        UnloadedCommands::Option => {
+            quote!(self.#fn_name.expect(concat!("Unable to load ", stringify!(#fn_name))))
+        }
+    };
+    (
+        params,
+        param_names,
+        quote! {
+            #(#asserts)*
+            (#fn_ptr)(#(#args,)*)
+        },
+    )
+}
+
 fn generate_function_pointers<'a>(
     ident: Ident,
     commands: &[&'a vkxml::Command],
     aliases: &HashMap<String, String, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    success_codes: &HashMap<&str, Vec<&str>, impl BuildHasher>,
+    valid_usage: &ValidUsageMap,
+    unloaded_commands: UnloadedCommands,
 ) -> TokenStream {
     // Commands can have duplicates inside them because they are declared per features. But we only
     // really want to generate one function pointer.
@@ -839,17 +1163,38 @@ fn generate_function_pointers<'a>(
         .map(|cmd| function_name(&cmd.name))
         .collect();
     let names_ref = &names;
-    let names_ref1 = &names;
-    let names_ref2 = &names;
-    let names_ref3 = &names;
     let raw_names: Vec<_> = commands
         .iter()
         .map(|cmd| format_ident!("{}", function_name_raw(cmd.name.as_str()).as_str()))
         .collect();
-    let raw_names_ref = &raw_names;
     let names_left = &names;
     let names_right = &names;
     let khronos_links: Vec<_> = raw_names.iter().map(|name| khronos_link(name)).collect();
+    let valid_usage_docs: Vec<_> = commands
+        .iter()
+        .map(|cmd| valid_usage.doc_tokens(&cmd.name))
+        .collect();
+    let render_pass_docs: Vec<_> = commands
+        .iter()
+        .map(|cmd| {
+            let line = match cmd.renderpass {
+                Some(vkxml::Renderpass::Inside) => {
+                    " Must be recorded inside of a render pass instance."
+                }
+                Some(vkxml::Renderpass::Outside) => {
+                    " Must be recorded outside of a render pass instance."
+                }
+                Some(vkxml::Renderpass::Both) => {
+                    " Can be recorded inside or outside of a render pass instance."
+                }
+                None => return quote!(),
+            };
+            quote! {
+                #[doc = ""]
+                #[doc = #line]
+            }
+        })
+        .collect();
 
     let params: Vec<Vec<(Ident, TokenStream)>> = commands
         .iter()
@@ -867,15 +1212,75 @@ fn generate_function_pointers<'a>(
         })
         .collect();
 
-    let params_names: Vec<Vec<_>> = params
+    let mut wrapper_params = Vec::new();
+    let mut wrapper_param_lists = Vec::new();
+    let mut wrapper_param_names = Vec::new();
+    let mut wrapper_bodies = Vec::new();
+    for (cmd, name) in commands.iter().zip(&names) {
+        let (params, param_names, body) = command_wrapper(cmd, name, unloaded_commands);
+        wrapper_params.push(quote!(#(#params,)*));
+        wrapper_param_lists.push(params);
+        wrapper_param_names.push(param_names);
+        wrapper_bodies.push(body);
+    }
+    // Commands with several success codes also get a wrapper that tells them apart from the
+    // error codes, which callers otherwise compare against by hand. A single trailing output
+    // parameter is returned next to the success code instead of being taken as a pointer
+    let success_code_wrappers = commands
         .iter()
-        .map(|inner_params| {
-            inner_params
+        .zip(&names)
+        .zip(wrapper_param_lists.iter().zip(&wrapper_param_names))
+        .filter_map(|((cmd, name), (params, param_names))| {
+            let codes = success_codes.get(cmd.name.as_str())?;
+            let wrapper_name = format_ident!("{}_with_success_code", name);
+            let variants = codes
                 .iter()
-                .map(|(param_name, _)| param_name.clone())
-                .collect()
-        })
-        .collect();
+                .map(|code| format!("`{}`", variant_ident("VkResult", code)))
+                .join(", ");
+            let output = cmd.param.last().filter(|field| {
+                matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+                    && !field.is_const
+                    && field.size.is_none()
+                    && field.basetype != "void"
+            });
+            Some(match output {
+                Some(output) => {
+                    let output_name = output.param_ident().to_string();
+                    let output_name =
+                        format_ident!("{}", output_name.strip_prefix("p_").unwrap_or(&output_name));
+                    let output_ty = name_to_tokens(&output.basetype);
+                    let params = &params[..params.len() - 1];
+                    let param_names = &param_names[..param_names.len() - 1];
+                    let doc = format!(
+                        " Like [`Self::{}`], but returns `{}` together with the success code, which is one of {}.",
+                        name, output_name, variants
+                    );
+                    quote! {
+                        #[doc = #doc]
+                        pub unsafe fn #wrapper_name(&self, #(#params,)*) -> ::std::result::Result<(#output_ty, crate::vk::SuccessCode), Result> {
+                            let mut #output_name = ::std::mem::MaybeUninit::<#output_ty>::zeroed();
+                            let result = self.#name(#(#param_names,)* #output_name.as_mut_ptr());
+                            result
+                                .into_codes()
+                                .map(|code| (#output_name.assume_init(), code))
+                                .map_err(Result::from)
+                        }
+                    }
+                }
+                None => {
+                    let doc = format!(
+                        " Like [`Self::{}`], but returns the success code, which is one of {}.",
+                        name, variants
+                    );
+                    quote! {
+                        #[doc = #doc]
+                        pub unsafe fn #wrapper_name(&self, #(#params,)*) -> ::std::result::Result<crate::vk::SuccessCode, Result> {
+                            self.#name(#(#param_names),*).into_codes().map_err(Result::from)
+                        }
+                    }
+                }
+            })
+        });
     let expanded_params: Vec<_> = params
         .iter()
         .map(|inner_params| {
@@ -899,7 +1304,6 @@ fn generate_function_pointers<'a>(
             }
         })
         .collect();
-    let expanded_params_ref = &expanded_params;
 
     let return_types: Vec<_> = commands
         .iter()
@@ -907,6 +1311,54 @@ fn generate_function_pointers<'a>(
         .collect();
     let return_types_ref = &return_types;
 
+    let fields = names.iter().zip(&expanded_params).zip(&return_types).map(
+        |((name, params), return_type)| {
+            let fn_ty = quote!(extern "system" fn(#params) -> #return_type);
+            match unloaded_commands {
+                UnloadedCommands::PanicStub => quote!(pub #name: #fn_ty,),
+                UnloadedCommands::Option => quote!(pub #name: Option<#fn_ty>,),
+            }
+        },
+    );
+    let loads = names
+        .iter()
+        .zip(&raw_names)
+        .zip(expanded_params_unused.iter().zip(&return_types))
+        .map(|((name, raw_name), (params_unused, return_type))| {
+            let load = quote! {
+                let raw_name = stringify!(#raw_name);
+                let cname = ::std::ffi::CString::new(raw_name).unwrap();
+                let val = _f(&cname);
+            };
+            match unloaded_commands {
+                UnloadedCommands::PanicStub => quote! {
+                    #name: unsafe {
+
+                        extern "system" fn #name (#params_unused) -> #return_type {
+                            panic!(concat!("Unable to load ", stringify!(#name)))
+                        }
+                        #load
+                        if val.is_null(){
+                            #name
+                        }
+                        else{
+                            ::std::mem::transmute(val)
+                        }
+                    },
+                },
+                UnloadedCommands::Option => quote! {
+                    #name: unsafe {
+                        #load
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(::std::mem::transmute(val))
+                        }
+                    },
+                },
+            }
+        });
+
     let pfn_names: Vec<_> = commands_pfn
         .iter()
         .map(|cmd| format_ident!("{}", format!("PFN_{}", cmd.name.as_str())))
@@ -943,9 +1395,7 @@ fn generate_function_pointers<'a>(
         )*
 
         pub struct #ident {
-            #(
-                pub #names_ref: extern "system" fn(#expanded_params_ref) -> #return_types_ref,
-            )*
+            #(#fields)*
         }
 
         unsafe impl Send for #ident {}
@@ -959,41 +1409,33 @@ fn generate_function_pointers<'a>(
             }
         }
         impl #ident {
+            /// Loads the commands with `_f`, which returns the address of the command with the
+            /// given name, or null if it isn't available. Any resolver works, not only
+            /// `vkGet*ProcAddr`, so that commands can be interposed.
             pub fn load<F>(mut _f: F) -> Self
                 where F: FnMut(&::std::ffi::CStr) -> *const c_void
             {
                 #ident {
-                    #(
-                        #names_ref: unsafe {
-
-                            extern "system" fn #names_ref1 (#expanded_params_unused) -> #return_types_ref {
-                                panic!(concat!("Unable to load ", stringify!(#names_ref2)))
-                            }
-                            let raw_name = stringify!(#raw_names_ref);
-                            let cname = ::std::ffi::CString::new(raw_name).unwrap();
-                            let val = _f(&cname);
-                            if val.is_null(){
-                                #names_ref3
-                            }
-                            else{
-                                ::std::mem::transmute(val)
-                            }
-                        },
-                    )*
+                    #(#loads)*
                 }
             }
             #(
                 #[doc = #khronos_links]
-                pub unsafe fn #names_ref(&self, #expanded_params_ref) -> #return_types_ref {
-                    (self.#names_left)(#(#params_names,)*)
+                #render_pass_docs
+                #valid_usage_docs
+                pub unsafe fn #names_ref(&self, #wrapper_params) -> #return_types_ref {
+                    #wrapper_bodies
                 }
             )*
+            #(#success_code_wrappers)*
         }
     }
 }
 pub struct ExtensionConstant<'a> {
     pub name: &'a str,
     pub constant: Constant,
+    pub notation: Option<String>,
+    pub provided_by: Option<String>,
 }
 impl<'a> ConstantExt for ExtensionConstant<'a> {
     fn constant(&self) -> Constant {
@@ -1003,7 +1445,18 @@ impl<'a> ConstantExt for ExtensionConstant<'a> {
         variant_ident(enum_name, self.name)
     }
     fn notation(&self) -> Option<&str> {
-        None
+        self.notation.as_deref()
+    }
+    fn provided_by(&self) -> Option<&str> {
+        self.provided_by.as_deref()
+    }
+}
+
+/// Documents which core version, like `VK_VERSION_1_1`, or extension added a value.
+fn provided_by(feature_or_extension: &str) -> String {
+    match feature_or_extension.strip_prefix("VK_VERSION_") {
+        Some(version) => format!("Provided by Vulkan {}.", version.replace('_', ".")),
+        None => format!("Provided by `{}`.", feature_or_extension),
     }
 }
 
@@ -1013,6 +1466,7 @@ pub fn generate_extension_constants<'a>(
     extension_items: &'a [vk_parse::ExtensionChild],
     const_cache: &mut HashSet<&'a str, impl BuildHasher>,
     const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    skipped: &mut SkippedItems,
 ) -> TokenStream {
     use vk_parse::EnumSpec;
     let items = extension_items
@@ -1038,20 +1492,30 @@ pub fn generate_extension_constants<'a>(
                     extnumber,
                     dir: positive,
                 } => {
-                    let ext_base = 1_000_000_000;
-                    let ext_block_size = 1000;
-                    let extnumber = extnumber.unwrap_or_else(|| extension_number);
-                    let value = ext_base + (extnumber - 1) * ext_block_size + offset;
-                    let value = if *positive { value } else { -value };
-                    Some((Constant::Number(value as i32), Some(extends.clone()), false))
-                }
-                EnumSpec::Value { value, extends } => {
-                    if let (Some(extends), Ok(value)) = (extends, value.parse::<i32>()) {
+                    let extnumber = extnumber.unwrap_or(extension_number);
+                    let value = offset_enum_value(extnumber, *offset, *positive)
+                        .and_then(|value| i32::try_from(value).ok());
+                    match value {
+                        Some(value) => {
+                            Some((Constant::Number(value), Some(extends.clone()), false))
+                        }
+                        None => {
+                            skipped.insert("enum values that don't fit in an i32", &_enum.name);
+                            None
+                        }
+                    }
+                }
+                // Values without `extends` are constants like the spec version of the extension
+                EnumSpec::Value { value, extends } => match (extends, value.parse::<i32>()) {
+                    (Some(extends), Ok(value)) => {
                         Some((Constant::Number(value), Some(extends.clone()), false))
-                    } else {
+                    }
+                    (Some(_), Err(_)) => {
+                        skipped.insert("enum values that aren't integers", &_enum.name);
                         None
                     }
-                }
+                    (None, _) => None,
+                },
                 EnumSpec::Alias { alias, extends } => {
                     if let Some(extends) = extends {
                         let ident = name_to_tokens(&extends);
@@ -1068,18 +1532,46 @@ pub fn generate_extension_constants<'a>(
                 _ => None,
             }?;
             let extends = extends?;
+            let ident = name_to_tokens(&extends);
+            let variant = variant_ident(&extends, &_enum.name);
+            let known = const_values.get_mut(&ident).unwrap();
+            // Another extension already added a variant with this name
+            if known.iter().any(|info| info.ident == variant) {
+                const_cache.insert(_enum.name.as_str());
+                return None;
+            }
+            let value = constant.int_value();
+            let existing = value.and_then(|value| {
+                known
+                    .iter()
+                    .find(|info| !info.is_alias && info.value == Some(value))
+            });
+            // A value that is already in the enum under another name becomes an alias of it, so
+            // that it doesn't show up twice in `Debug`
+            let (constant, is_alias, notation) = match existing {
+                Some(existing) => {
+                    let existing = existing.ident.clone();
+                    let notation =
+                        format!("Alias of [`Self::{}`], which has the same value", existing);
+                    (
+                        Constant::Alias(ident.clone(), existing),
+                        true,
+                        Some(notation),
+                    )
+                }
+                None => (constant, is_alias, None),
+            };
+            known.push(ConstantMatchInfo {
+                ident: variant,
+                is_alias,
+                value: if is_alias { None } else { value },
+            });
             let ext_constant = ExtensionConstant {
                 name: &_enum.name,
                 constant,
+                notation,
+                provided_by: Some(provided_by(extension_name)),
             };
-            let ident = name_to_tokens(&extends);
-            const_values
-                .get_mut(&ident)
-                .unwrap()
-                .push(ConstantMatchInfo {
-                    ident: ext_constant.variant_ident(&extends),
-                    is_alias,
-                });
             let impl_block = bitflags_impl_block(ident, &extends, &[&ext_constant]);
             let doc_string = format!("Generated from '{}'", extension_name);
             let q = quote! {
@@ -1102,6 +1594,9 @@ pub fn generate_extension_commands<'a>(
     cmd_map: &CommandMap<'a>,
     cmd_aliases: &HashMap<String, String, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    success_codes: &HashMap<&str, Vec<&str>, impl BuildHasher>,
+    valid_usage: &ValidUsageMap,
+    options: &RenderOptions,
 ) -> TokenStream {
     let mut commands = Vec::new();
     let mut aliases = HashMap::new();
@@ -1129,9 +1624,25 @@ pub fn generate_extension_commands<'a>(
             }
         });
 
-    let name = format!("{}Fn", extension_name.to_camel_case());
+    let name = format!("{}Fn", options.pascal_case(extension_name));
     let ident = format_ident!("{}", &name[2..]);
-    let fp = generate_function_pointers(ident.clone(), &commands, &aliases, fn_cache);
+    let mut loaded = HashSet::new();
+    let device_commands: Vec<_> = commands
+        .iter()
+        .filter(|cmd| matches!(cmd.function_type(), FunctionType::Device))
+        .map(|cmd| aliases.get(&cmd.name).unwrap_or(&cmd.name))
+        .filter(|name| loaded.insert(name.as_str()))
+        .map(|name| Literal::byte_string(name.as_bytes()))
+        .collect();
+    let fp = generate_function_pointers(
+        ident.clone(),
+        &commands,
+        &aliases,
+        fn_cache,
+        success_codes,
+        valid_usage,
+        options.unloaded_commands,
+    );
     let byte_name = format!("{}\0", extension_name);
 
     let spec_version = items
@@ -1156,6 +1667,28 @@ pub fn generate_extension_commands<'a>(
             }
         });
 
+    // Device level commands are dispatched by the driver directly when they are loaded with
+    // `vkGetDeviceProcAddr`, instead of going through the trampoline of the loader
+    let load_split = if device_commands.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            /// Loads the device level commands, whose first parameter is a `VkDevice`, `VkQueue` or
+            /// `VkCommandBuffer`, with `load_device`, usually wrapping `vkGetDeviceProcAddr`, and
+            /// the other commands with `load_instance`, usually wrapping `vkGetInstanceProcAddr`.
+            pub fn load_split<I, D>(mut load_instance: I, mut load_device: D) -> Self
+            where
+                I: FnMut(&::std::ffi::CStr) -> *const c_void,
+                D: FnMut(&::std::ffi::CStr) -> *const c_void,
+            {
+                Self::load(|name| match name.to_bytes() {
+                    #(#device_commands)|* => load_device(name),
+                    _ => load_instance(name),
+                })
+            }
+        }
+    };
+
     let byte_name_ident = syn::LitByteStr::new(byte_name.as_bytes(), Span::call_site());
     let extension_cstr = quote! {
         impl #ident {
@@ -1163,6 +1696,7 @@ pub fn generate_extension_commands<'a>(
                 ::std::ffi::CStr::from_bytes_with_nul(#byte_name_ident).expect("Wrong extension string")
             }
             #spec_version
+            #load_split
         }
     };
     quote! {
@@ -1170,6 +1704,7 @@ pub fn generate_extension_commands<'a>(
         #fp
     }
 }
+#[allow(clippy::too_many_arguments)]
 pub fn generate_extension<'a>(
     extension: &'a vk_parse::Extension,
     cmd_map: &CommandMap<'a>,
@@ -1177,6 +1712,10 @@ pub fn generate_extension<'a>(
     const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
     cmd_aliases: &HashMap<String, String, impl BuildHasher>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    success_codes: &HashMap<&str, Vec<&str>, impl BuildHasher>,
+    valid_usage: &ValidUsageMap,
+    options: &RenderOptions,
+    skipped: &mut SkippedItems,
 ) -> Option<TokenStream> {
     // Okay this is a little bit odd. We need to generate all extensions, even disabled ones,
     // because otherwise some StructureTypes won't get generated. But we don't generate extensions
@@ -1190,6 +1729,7 @@ pub fn generate_extension<'a>(
         &extension.children,
         const_cache,
         const_values,
+        skipped,
     );
     let fp = generate_extension_commands(
         &extension.name,
@@ -1197,13 +1737,34 @@ pub fn generate_extension<'a>(
         cmd_map,
         cmd_aliases,
         fn_cache,
+        success_codes,
+        valid_usage,
+        options,
     );
+    // The enum values stay available either way, as the structs of the extension use them
+    let fp = match (extension.provisional, options.provisional) {
+        (false, _) | (true, ProvisionalExtensions::Include) => fp,
+        (true, ProvisionalExtensions::Feature) => cfg_items(fp, quote!(feature = "provisional")),
+        (true, ProvisionalExtensions::Exclude) => quote!(),
+    };
     let q = quote! {
         #fp
         #extension_tokens
     };
     Some(q)
 }
+/// Puts `#[cfg(#predicate)]` on every item in `items`.
+fn cfg_items(items: TokenStream, predicate: TokenStream) -> TokenStream {
+    let file: syn::File = syn::parse2(items).expect("Unable to parse generated items");
+    let items = file.items.iter();
+    quote! {
+        #(
+            #[cfg(#predicate)]
+            #items
+        )*
+    }
+}
+
 pub fn generate_define(define: &vkxml::Define) -> TokenStream {
     let name = constant_name(&define.name);
     let ident = format_ident!("{}", name);
@@ -1251,13 +1812,12 @@ pub fn generate_bitmask(
     if bitmask.name.is_empty() {
         return None;
     }
-    // If this enum has constants, then it will generated later in generate_enums.
-    if bitmask.enumref.is_some() {
-        return None;
-    }
 
     let name = &bitmask.name[2..];
     let ident = format_ident!("{}", name);
+    // If the registry has the `FlagBits` enum of this bitmask, then the type was already
+    // generated in generate_enum. Otherwise it is generated here without any bits, like the
+    // `Flags` types that are reserved for future use.
     if bitflags_cache.contains(&ident) {
         return None;
     };
@@ -1278,34 +1838,77 @@ pub enum EnumType {
     Enum(TokenStream),
 }
 
+/// `s` with all occurrences of `from` removed, without allocating if there are none.
+fn strip_all<'a>(s: &'a str, from: &str) -> Cow<'a, str> {
+    if from.is_empty() || !s.contains(from) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.replace(from, ""))
+    }
+}
+
+/// The part of an enum name that is stripped from its variant names, and its vendor suffix.
+struct VariantPrefix {
+    name: String,
+    vendor: &'static str,
+}
+
+/// Returns the [`VariantPrefix`] of `enum_name`. The prefixes are interned, so they are computed
+/// once per enum instead of once per variant.
+fn variant_prefix(enum_name: &str) -> Rc<VariantPrefix> {
+    thread_local! {
+        static PREFIXES: RefCell<HashMap<String, Rc<VariantPrefix>>> = RefCell::default();
+    }
+    PREFIXES.with(|prefixes| {
+        if let Some(prefix) = prefixes.borrow().get(enum_name) {
+            return prefix.clone();
+        }
+        // TODO: Should be read from vk.xml id:2
+        // TODO: Also needs to be more robust, vendor names can be substrings from itself, id:4
+        // like NVX and NV
+        let vendors = ["_NVX", "_KHR", "_EXT", "_NV", "_AMD", "_ANDROID", "_GOOGLE"];
+        let struct_name = match split_flag_bits(enum_name) {
+            // `VkAccessFlagBits2KHR` has variants like `VK_ACCESS_2_SHADER_READ_BIT_KHR`
+            Some((base, version, vendor)) => {
+                [base.to_shouty_snake_case().as_str(), version, vendor]
+                    .iter()
+                    .filter(|part| !part.is_empty())
+                    .join("_")
+            }
+            None => enum_name.to_shouty_snake_case(),
+        };
+        let vendor = vendors
+            .iter()
+            .find(|&vendor| struct_name.contains(vendor))
+            .cloned()
+            .unwrap_or("");
+        let prefix = Rc::new(VariantPrefix {
+            name: strip_all(&struct_name, vendor).into_owned(),
+            vendor,
+        });
+        prefixes
+            .borrow_mut()
+            .insert(enum_name.to_owned(), prefix.clone());
+        prefix
+    })
+}
+
 pub fn variant_ident(enum_name: &str, variant_name: &str) -> Ident {
-    let _name = enum_name.replace("FlagBits", "");
-    // TODO: Should be read from vk.xml id:2
-    // TODO: Also needs to be more robust, vendor names can be substrings from itself, id:4
-    // like NVX and NV
-    let vendors = ["_NVX", "_KHR", "_EXT", "_NV", "_AMD", "_ANDROID", "_GOOGLE"];
-    let mut struct_name = _name.to_shouty_snake_case();
-    let vendor = vendors
-        .iter()
-        .find(|&vendor| struct_name.contains(vendor))
-        .cloned()
-        .unwrap_or("");
-    struct_name = struct_name.replace(vendor, "");
-    let new_variant_name = variant_name.replace(&struct_name, "").replace("VK", "");
-    let new_variant_name = new_variant_name
-        .trim_matches('_')
-        .to_shouty_snake_case()
-        .replace("_BIT", "")
-        .replace(vendor, "");
+    let prefix = variant_prefix(enum_name);
+    let new_variant_name = strip_all(variant_name, &prefix.name);
+    let new_variant_name = strip_all(&new_variant_name, "VK");
+    let new_variant_name = new_variant_name.trim_matches('_').to_shouty_snake_case();
+    let new_variant_name = strip_all(&new_variant_name, "_BIT");
+    let new_variant_name = strip_all(&new_variant_name, prefix.vendor);
     let is_digit = new_variant_name
         .chars()
         .next()
         .map(|c| c.is_digit(10))
         .unwrap_or(false);
     if is_digit {
-        format_ident!("{}", format!("TYPE_{}", new_variant_name).as_str())
+        format_ident!("TYPE_{}", new_variant_name.as_ref())
     } else {
-        format_ident!("{}", new_variant_name)
+        Ident::new(&new_variant_name, Span::call_site())
     }
 }
 
@@ -1314,26 +1917,40 @@ pub fn bitflags_impl_block(
     enum_name: &str,
     constants: &[&impl ConstantExt],
 ) -> TokenStream {
+    let int_type = IntType::of_enum(enum_name);
     let variants = constants
         .iter()
         .map(|constant| {
             let variant_ident = constant.variant_ident(enum_name);
             let constant = constant.constant();
-            let tokens = if let Constant::Alias(_, _) = &constant {
-                quote!(#constant)
-            } else {
-                quote!(Self(#constant))
+            let tokens = match constant {
+                Constant::Alias(_, _) => quote!(#constant),
+                Constant::Number(_) | Constant::Hex(_) | Constant::BitPos(_) => {
+                    let literal = constant.int_literal(Some(int_type));
+                    quote!(Self(#literal))
+                }
+                _ => quote!(Self(#constant)),
             };
             (variant_ident, tokens)
         })
         .collect_vec();
 
     let notations = constants.iter().map(|constant| {
-        constant.notation().map(|n| {
+        let provided_by = constant.provided_by().map(|p| quote!(#[doc = #p]));
+        let separator = match (constant.provided_by(), constant.notation()) {
+            (Some(_), Some(_)) => Some(quote!(#[doc = ""])),
+            _ => None,
+        };
+        let notation = constant.notation().map(|n| {
             quote! {
                 #[doc = #n]
             }
-        })
+        });
+        quote! {
+            #provided_by
+            #separator
+            #notation
+        }
     });
 
     let variants =
@@ -1360,8 +1977,8 @@ pub fn generate_enum<'a>(
     bitflags_cache: &mut HashSet<Ident, impl BuildHasher>,
 ) -> EnumType {
     let name = &_enum.name[2..];
-    let _name = name.replace("FlagBits", "Flags");
-    let ident = format_ident!("{}", _name.as_str());
+    let _name = bitflags_type_name(name);
+    let ident = format_ident!("{}", _name.as_ref());
     let constants: Vec<_> = _enum
         .elements
         .iter()
@@ -1376,6 +1993,7 @@ pub fn generate_enum<'a>(
         values.push(ConstantMatchInfo {
             ident: constant.variant_ident(&_enum.name),
             is_alias: false,
+            value: Constant::from_constant(constant).int_value(),
         });
     }
     const_values.insert(ident.clone(), values);
@@ -1386,8 +2004,8 @@ pub fn generate_enum<'a>(
         let ident = format_ident!("{}", _name.as_str());
         let all_bits = constants
             .iter()
-            .filter_map(|constant| Constant::from_constant(constant).value())
-            .fold(0, |acc, next| acc | next.bits());
+            .filter_map(|constant| Constant::from_constant(constant).int_value())
+            .fold(0, |acc, next| acc | next);
         let bit_string = format!("{:b}", all_bits);
         let bit_string = interleave_number('_', 4, &bit_string);
         let all_bits_term = syn::LitInt::new(&format!("0b{}", bit_string), Span::call_site());
@@ -1415,16 +2033,9 @@ pub fn generate_enum<'a>(
         };
 
         let impl_block = bitflags_impl_block(ident.clone(), &_enum.name, &constants);
+        let open_enum = open_enum(&ident, &khronos_link, struct_attribute);
         let enum_quote = quote! {
-            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-            #[repr(transparent)]
-            #[doc = #khronos_link]
-            #struct_attribute
-            pub struct #ident(pub(crate) i32);
-            impl #ident {
-                pub const fn from_raw(x: i32) -> Self { #ident(x) }
-                pub const fn as_raw(self) -> i32 { self.0 }
-            }
+            #open_enum
             #impl_block
         };
         let q = quote! {
@@ -1436,6 +2047,73 @@ pub fn generate_enum<'a>(
     }
 }
 
+/// Generates the aliases in the `<enums>` block of `enum_name`, which [`generate_enum`] doesn't
+/// see as `vkxml` drops them. Enums that only consist of aliases of a promoted enum still get
+/// their values this way, converted from the values of the promoted enum.
+pub fn generate_enum_aliases<'a>(
+    enum_name: &str,
+    aliases: &[EnumAlias<'a>],
+    const_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+) -> TokenStream {
+    let ident = name_to_tokens(enum_name);
+    let known = const_values.entry(ident.clone()).or_default();
+    let constants = aliases
+        .iter()
+        .filter_map(|alias| {
+            let variant = variant_ident(enum_name, alias.name);
+            let alias_ident = name_to_tokens(alias.alias_enum);
+            let alias_variant = variant_ident(alias.alias_enum, alias.alias);
+            let same_enum = alias_ident == ident;
+            // Skip names that are taken already, and aliases that would refer to themselves
+            // after the vendor suffix is stripped
+            if known.iter().any(|info| info.ident == variant)
+                || (same_enum && variant == alias_variant)
+            {
+                return None;
+            }
+            const_cache.insert(alias.name);
+            known.push(ConstantMatchInfo {
+                ident: variant.clone(),
+                is_alias: true,
+                value: None,
+            });
+            let value = if same_enum {
+                quote!(Self::#alias_variant)
+            } else {
+                quote!(Self(#alias_ident::#alias_variant.0))
+            };
+            Some(quote! {
+                pub const #variant: Self = #value;
+            })
+        })
+        .collect_vec();
+    if constants.is_empty() {
+        return quote!();
+    }
+    quote! {
+        impl #ident {
+            #(#constants)*
+        }
+    }
+}
+
+/// The [`EnumRepresentation::Open`] type of an enum, to which the values are added as associated
+/// constants, by [`generate_enum`] and by the extensions.
+fn open_enum(ident: &Ident, khronos_link: &Literal, attribute: TokenStream) -> TokenStream {
+    quote! {
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[repr(transparent)]
+        #[doc = #khronos_link]
+        #attribute
+        pub struct #ident(pub(crate) i32);
+        impl #ident {
+            pub const fn from_raw(x: i32) -> Self { #ident(x) }
+            pub const fn as_raw(self) -> i32 { self.0 }
+        }
+    }
+}
+
 pub fn generate_result(ident: Ident, _enum: &vkxml::Enumeration) -> TokenStream {
     let notation = _enum.elements.iter().filter_map(|elem| {
         let (variant_name, notation) = match *elem {
@@ -1458,19 +2136,25 @@ pub fn generate_result(ident: Ident, _enum: &vkxml::Enumeration) -> TokenStream
     quote! {
         impl ::std::error::Error for #ident {
             fn description(&self) -> &str {
+                #[cfg(feature = "debug-strings")]
                 let name = match *self {
                     #(#notation),*,
                     _ => None,
                 };
+                #[cfg(not(feature = "debug-strings"))]
+                let name: Option<&str> = None;
                 name.unwrap_or("unknown error")
             }
         }
         impl fmt::Display for #ident {
             fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                #[cfg(feature = "debug-strings")]
                 let name = match *self {
                     #(#notation2),*,
                     _ => None,
                 };
+                #[cfg(not(feature = "debug-strings"))]
+                let name: Option<&str> = None;
                 if let Some(x) = name {
                     fmt.write_str(x)
                 } else {
@@ -1577,18 +2261,178 @@ pub fn derive_default(_struct: &vkxml::Struct) -> Option<TokenStream> {
     };
     Some(q)
 }
-pub fn derive_debug(
-    _struct: &vkxml::Struct,
-    union_types: &HashSet<&str, impl BuildHasher>,
-) -> Option<TokenStream> {
+/// Generates `uninit` for structs that the driver returns, so that they can be passed to commands
+/// without going through `mem::zeroed` or `Default`.
+pub fn derive_uninit(_struct: &vkxml::Struct) -> Option<TokenStream> {
+    if !_struct.is_return {
+        return None;
+    }
     let name = name_to_tokens(&_struct.name);
-    let members = _struct.elements.iter().filter_map(|elem| match *elem {
-        vkxml::StructElement::Member(ref field) => Some(field),
-        _ => None,
-    });
-    let contains_pfn = members.clone().any(|field| {
-        field
-            .name
+    let uninit = match structure_type_value(_struct) {
+        Some(variant) => {
+            let variant_ident = variant_ident("VkStructureType", variant);
+            quote! {
+                /// Returns this struct with `s_type` set and the other members zeroed.
+                pub const fn zeroed() -> Self {
+                    let mut value: Self = unsafe { ::std::mem::zeroed() };
+                    value.s_type = StructureType::#variant_ident;
+                    value
+                }
+                /// Returns storage for the driver to write this struct to, with `s_type` set and
+                /// the other members zeroed.
+                pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+                    let mut value = ::std::mem::MaybeUninit::<Self>::zeroed();
+                    unsafe {
+                        (*value.as_mut_ptr()).s_type = StructureType::#variant_ident;
+                    }
+                    value
+                }
+            }
+        }
+        None => quote! {
+            /// Returns this struct with all members zeroed.
+            pub const fn zeroed() -> Self {
+                unsafe { ::std::mem::zeroed() }
+            }
+            /// Returns storage for the driver to write this struct to.
+            pub fn uninit() -> ::std::mem::MaybeUninit<Self> {
+                ::std::mem::MaybeUninit::uninit()
+            }
+        },
+    };
+    Some(quote! {
+        impl #name {
+            #uninit
+        }
+    })
+}
+/// Generates `<member>_as_c_str` getters for fixed size `char` arrays, which hold nul terminated
+/// strings.
+pub fn derive_c_str_getters(_struct: &vkxml::Struct) -> Option<TokenStream> {
+    let getters: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match *elem {
+            vkxml::StructElement::Member(ref field) => Some(field),
+            _ => None,
+        })
+        .filter(|field| is_static_array(field) && field.basetype == "char")
+        .map(|field| {
+            let param_ident = field.param_ident();
+            let getter_ident = format_ident!("{}_as_c_str", param_ident);
+            let doc = format!(
+                " Returns `{}` up to its nul terminator, an error if it doesn't have one.",
+                param_ident
+            );
+            quote! {
+                #[doc = #doc]
+                pub fn #getter_ident(&self) -> ::std::result::Result<&::std::ffi::CStr, ::std::ffi::FromBytesWithNulError> {
+                    let bytes = unsafe {
+                        ::std::slice::from_raw_parts(self.#param_ident.as_ptr() as *const u8, self.#param_ident.len())
+                    };
+                    let len = bytes.iter().position(|&b| b == 0).map_or(bytes.len(), |nul| nul + 1);
+                    ::std::ffi::CStr::from_bytes_with_nul(&bytes[..len])
+                }
+            }
+        })
+        .collect();
+    if getters.is_empty() {
+        return None;
+    }
+    let name = name_to_tokens(&_struct.name);
+    Some(quote! {
+        impl #name {
+            #(#getters)*
+        }
+    })
+}
+/// Generates `missing_features` for `VkPhysicalDeviceFeatures` and the structs that extend
+/// `VkPhysicalDeviceFeatures2`, comparing their `VkBool32` members one by one.
+pub fn derive_missing_features(_struct: &vkxml::Struct) -> Option<TokenStream> {
+    let is_features = _struct.name == "VkPhysicalDeviceFeatures"
+        || _struct.extends.as_ref().is_some_and(|extends| {
+            extends
+                .split(',')
+                .any(|extend| extend == "VkPhysicalDeviceFeatures2")
+        });
+    if !is_features {
+        return None;
+    }
+    let checks: Vec<_> = _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match *elem {
+            vkxml::StructElement::Member(ref field) => Some(field),
+            _ => None,
+        })
+        .filter(|field| {
+            field.basetype == "VkBool32" && field.array.is_none() && field.reference.is_none()
+        })
+        .map(|field| {
+            let param_ident = field.param_ident();
+            let name = field.name.as_deref().unwrap_or_default();
+            quote! {
+                if required.#param_ident != FALSE && self.#param_ident == FALSE {
+                    missing.push(#name);
+                }
+            }
+        })
+        .collect();
+    if checks.is_empty() {
+        return None;
+    }
+    let name = name_to_tokens(&_struct.name);
+    Some(quote! {
+        impl #name {
+            /// The names of the features that `required` enables and `self` doesn't, such as
+            /// `geometryShader`.
+            pub fn missing_features(&self, required: &Self) -> Vec<&'static str> {
+                let mut missing = Vec::new();
+                #(#checks)*
+                missing
+            }
+        }
+    })
+}
+/// The `VkStructureType` value that the `sType` of a struct is fixed to, e.g.
+/// `VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO`.
+pub fn structure_type_value(_struct: &vkxml::Struct) -> Option<&str> {
+    _struct
+        .elements
+        .iter()
+        .filter_map(|elem| match *elem {
+            vkxml::StructElement::Member(ref field) => Some(field),
+            _ => None,
+        })
+        .find(|field| field.basetype == "VkStructureType")?
+        .type_enums
+        .as_deref()?
+        .split(',')
+        .next()
+}
+/// Implements `TaggedStructure` for structs with a fixed `s_type`.
+pub fn derive_tagged_structure(_struct: &vkxml::Struct) -> Option<TokenStream> {
+    let name = name_to_tokens(&_struct.name);
+    let variant_ident = variant_ident("VkStructureType", structure_type_value(_struct)?);
+    Some(quote! {
+        unsafe impl TaggedStructure for #name {
+            const STRUCTURE_TYPE: StructureType = StructureType::#variant_ident;
+        }
+    })
+}
+
+pub fn derive_debug(
+    _struct: &vkxml::Struct,
+    union_types: &HashSet<&str, impl BuildHasher>,
+) -> Option<TokenStream> {
+    let name = name_to_tokens(&_struct.name);
+    let members = _struct.elements.iter().filter_map(|elem| match *elem {
+        vkxml::StructElement::Member(ref field) => Some(field),
+        _ => None,
+    });
+    let contains_pfn = members.clone().any(|field| {
+        field
+            .name
             .as_ref()
             .map(|n| n.contains("pfn"))
             .unwrap_or(false)
@@ -1639,15 +2483,20 @@ pub fn derive_debug(
     Some(q)
 }
 
+/// Structs that don't get a builder.
+const BUILDERLESS_STRUCTS: &[&str] = &[
+    "VkBaseInStructure",
+    "VkBaseOutStructure",
+    "VkTransformMatrixKHR",
+    "VkAccelerationStructureInstanceKHR",
+];
+
 pub fn derive_setters(
     _struct: &vkxml::Struct,
     root_struct_names: &HashSet<String, impl BuildHasher>,
+    borrowing_struct_names: &HashSet<String, impl BuildHasher>,
 ) -> Option<TokenStream> {
-    if &_struct.name == "VkBaseInStructure"
-        || &_struct.name == "VkBaseOutStructure"
-        || &_struct.name == "VkTransformMatrixKHR"
-        || &_struct.name == "VkAccelerationStructureInstanceKHR"
-    {
+    if BUILDERLESS_STRUCTS.contains(&_struct.name.as_str()) {
         return None;
     }
 
@@ -1745,6 +2594,20 @@ pub fn derive_setters(
             }
         }
 
+        if is_static_array(field) && field.basetype == "char" {
+            return Some(quote!{
+                /// Copies the string including its nul terminator, panics if it doesn't fit.
+                pub fn #param_ident_short(mut self, #param_ident_short: &::std::ffi::CStr) -> #name_builder<'a> {
+                    let bytes = #param_ident_short.to_bytes_with_nul();
+                    assert!(bytes.len() <= self.inner.#param_ident.len(), "String is too long");
+                    for (dst, &src) in self.inner.#param_ident.iter_mut().zip(bytes) {
+                        *dst = src as c_char;
+                    }
+                    self
+                }
+            });
+        }
+
         // TODO: Improve in future when https://github.com/rust-lang/rust/issues/53667 is merged id:6
         if field.reference.is_some() {
             if field.basetype == "char" && matches!(field.reference, Some(vkxml::ReferenceType::Pointer)) {
@@ -1795,12 +2658,34 @@ pub fn derive_setters(
                             quote!(self.inner.#array_size_ident = #param_ident_short.len() as _;)
                         };
 
+                        // Builders of elements that borrow data themselves can be passed as is,
+                        // as `build()` on each of them would discard their lifetime
+                        let builders_setter = if field.is_const
+                            && !set_size_stmt.is_empty()
+                            && borrowing_struct_names.contains(&field.basetype)
+                        {
+                            let setter_ident = format_ident!("{}_from_builders", param_ident_short);
+                            let element_ty = name_to_tokens(&field.basetype);
+                            let element_builder = name_to_tokens(&(field.basetype.clone() + "Builder"));
+                            quote! {
+                                /// Takes the builders of the elements, keeping the lifetime of the data they borrow.
+                                pub fn #setter_ident(mut self, #param_ident_short: &'a [#element_builder<'a>]) -> #name_builder<'a> {
+                                    #set_size_stmt
+                                    self.inner.#param_ident = #param_ident_short.as_ptr() as *const #element_ty;
+                                    self
+                                }
+                            }
+                        } else {
+                            quote!()
+                        };
+
                         return Some(quote! {
                             pub fn #param_ident_short(mut self, #param_ident_short: &'a #mutable #slice_param_ty_tokens) -> #name_builder<'a> {
                                 #set_size_stmt
                                 self.inner.#param_ident = #param_ident_short#ptr;
                                 self
                             }
+                            #builders_setter
                         });
                     }
                 }
@@ -1846,6 +2731,7 @@ pub fn derive_setters(
         .unwrap_or_else(Vec::new);
 
     // We only implement a next methods for root structs with a `pnext` field.
+    let c_name = _struct.name.as_str();
     let next_function = if has_next && root_structs.is_empty() {
         quote! {
             /// Prepends the given extension struct between the root and the first pointer. This
@@ -1853,6 +2739,8 @@ pub fn derive_setters(
             /// valid extension structs can be pushed into the chain.
             /// If the chain looks like `A -> B -> C`, and you call `builder.push_next(&mut D)`, then the
             /// chain will look like `A -> D -> B -> C`.
+            /// In debug builds, panics if a struct that `next` brings along doesn't extend this
+            /// struct, or if the chain contains two structs of the same type.
             pub fn push_next<T: #extends_name>(mut self, next: &'a mut T) -> #name_builder<'a> {
                 unsafe{
                     let next_ptr = next as *mut T as *mut BaseOutStructure;
@@ -1868,6 +2756,8 @@ pub fn derive_setters(
                     let last_next = ptr_chain_iter(next).last().unwrap();
                     (*last_next).p_next = self.inner.p_next as _;
                     self.inner.p_next = next_ptr as _;
+                    #[cfg(debug_assertions)]
+                    crate::vk::debug_assert_ptr_chain(#c_name, &mut self.inner);
                 }
                 self
             }
@@ -1962,7 +2852,9 @@ pub fn manual_derives(_struct: &vkxml::Struct) -> TokenStream {
 pub fn generate_struct(
     _struct: &vkxml::Struct,
     root_struct_names: &HashSet<String, impl BuildHasher>,
+    borrowing_struct_names: &HashSet<String, impl BuildHasher>,
     union_types: &HashSet<&str, impl BuildHasher>,
+    valid_usage: &ValidUsageMap,
 ) -> TokenStream {
     let name = name_to_tokens(&_struct.name);
     if &_struct.name == "VkTransformMatrixKHR" {
@@ -2007,7 +2899,11 @@ pub fn generate_struct(
 
     let debug_tokens = derive_debug(_struct, union_types);
     let default_tokens = derive_default(_struct);
-    let setter_tokens = derive_setters(_struct, root_struct_names);
+    let setter_tokens = derive_setters(_struct, root_struct_names, borrowing_struct_names);
+    let tagged_tokens = derive_tagged_structure(_struct);
+    let uninit_tokens = derive_uninit(_struct);
+    let c_str_tokens = derive_c_str_getters(_struct);
+    let missing_features_tokens = derive_missing_features(_struct);
     let manual_derive_tokens = manual_derives(_struct);
     let dbg_str = if debug_tokens.is_none() {
         quote!(Debug,)
@@ -2020,16 +2916,22 @@ pub fn generate_struct(
         quote!()
     };
     let khronos_link = khronos_link(&_struct.name);
+    let valid_usage_docs = valid_usage.doc_tokens(&_struct.name);
     quote! {
         #[repr(C)]
         #[derive(Copy, Clone, #default_str #dbg_str #manual_derive_tokens)]
         #[doc = #khronos_link]
+        #valid_usage_docs
         pub struct #name {
             #(#params,)*
         }
         #debug_tokens
         #default_tokens
         #setter_tokens
+        #tagged_tokens
+        #uninit_tokens
+        #c_str_tokens
+        #missing_features_tokens
     }
 }
 
@@ -2058,6 +2960,39 @@ pub fn generate_handle(handle: &vkxml::Handle) -> Option<TokenStream> {
     };
     Some(tokens)
 }
+/// Whether `handle` is created from `ancestor`, directly or through one of its other parents.
+fn has_ancestor(handle: &str, ancestor: &str, handle_parents: &HashMap<&str, Vec<&str>>) -> bool {
+    handle_parents.get(handle).is_some_and(|parents| {
+        parents
+            .iter()
+            .any(|&parent| parent == ancestor || has_ancestor(parent, ancestor, handle_parents))
+    })
+}
+/// Generates `DeviceChild` and `InstanceChild` from the `parent` attributes of the handles, so that
+/// generic code can require a handle that a device or an instance owns.
+pub fn generate_handle_owners(handle_parents: &HashMap<&str, Vec<&str>>) -> TokenStream {
+    let mut handles: Vec<&str> = handle_parents.keys().copied().collect();
+    handles.sort_unstable();
+    let impls = handles.into_iter().map(|handle| {
+        let ident = name_to_tokens(handle);
+        if has_ancestor(handle, "VkDevice", handle_parents) {
+            quote!(impl DeviceChild for #ident {})
+        } else if has_ancestor(handle, "VkInstance", handle_parents) {
+            quote!(impl InstanceChild for #ident {})
+        } else {
+            quote!()
+        }
+    });
+    quote! {
+        /// Handles that are created from a [`Device`], directly or through another handle, e.g.
+        /// [`ImageView`] or [`DescriptorSet`].
+        pub trait DeviceChild: Handle {}
+        /// Handles that are created from an [`Instance`], directly or through another handle, but
+        /// not from a [`Device`], e.g. [`PhysicalDevice`] or [`SurfaceKHR`].
+        pub trait InstanceChild: Handle {}
+        #(#impls)*
+    }
+}
 fn generate_funcptr(fnptr: &vkxml::FunctionPointer) -> TokenStream {
     let name = format_ident!("{}", fnptr.name.as_str());
     let ret_ty_tokens = fnptr.return_type.type_tokens(true);
@@ -2116,25 +3051,63 @@ pub fn root_struct_names(definitions: &[&vkxml::DefinitionsElement]) -> HashSet<
         })
         .collect()
 }
+/// Structs with a builder and pointer members, whose builders carry the lifetime of the data they
+/// point to.
+pub fn borrowing_struct_names(definitions: &[&vkxml::DefinitionsElement]) -> HashSet<String> {
+    definitions
+        .iter()
+        .filter_map(|definition| match *definition {
+            vkxml::DefinitionsElement::Struct(ref _struct)
+                if !BUILDERLESS_STRUCTS.contains(&_struct.name.as_str()) =>
+            {
+                let has_pointers = _struct.elements.iter().any(|elem| {
+                    matches!(elem, vkxml::StructElement::Member(field) if field.reference.is_some())
+                });
+                if has_pointers {
+                    Some(_struct.name.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
 pub fn generate_definition(
     definition: &vkxml::DefinitionsElement,
     union_types: &HashSet<&str, impl BuildHasher>,
     root_structs: &HashSet<String, impl BuildHasher>,
+    borrowing_structs: &HashSet<String, impl BuildHasher>,
     bitflags_cache: &mut HashSet<Ident, impl BuildHasher>,
     const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    valid_usage: &ValidUsageMap,
+    skipped: &mut SkippedItems,
 ) -> Option<TokenStream> {
     match *definition {
         vkxml::DefinitionsElement::Define(ref define) => Some(generate_define(define)),
         vkxml::DefinitionsElement::Typedef(ref typedef) => Some(generate_typedef(typedef)),
-        vkxml::DefinitionsElement::Struct(ref _struct) => {
-            Some(generate_struct(_struct, root_structs, union_types))
-        }
+        vkxml::DefinitionsElement::Struct(ref _struct) => Some(generate_struct(
+            _struct,
+            root_structs,
+            borrowing_structs,
+            union_types,
+            valid_usage,
+        )),
         vkxml::DefinitionsElement::Bitmask(ref mask) => {
             generate_bitmask(mask, bitflags_cache, const_values)
         }
         vkxml::DefinitionsElement::Handle(ref handle) => generate_handle(handle),
         vkxml::DefinitionsElement::FuncPtr(ref fp) => Some(generate_funcptr(fp)),
         vkxml::DefinitionsElement::Union(ref union) => Some(generate_union(union)),
+        vkxml::DefinitionsElement::Include(ref include) => {
+            skipped.insert("includes", &include.name);
+            None
+        }
+        vkxml::DefinitionsElement::Reference(ref reference) => {
+            skipped.insert("external types", &reference.name);
+            None
+        }
+        // Enums are generated from their `<enums>` block, and notations are only comments
         _ => None,
     }
 }
@@ -2142,6 +3115,9 @@ pub fn generate_feature<'a>(
     feature: &vkxml::Feature,
     commands: &CommandMap<'a>,
     fn_cache: &mut HashSet<&'a str, impl BuildHasher>,
+    success_codes: &HashMap<&str, Vec<&str>, impl BuildHasher>,
+    valid_usage: &ValidUsageMap,
+    options: &RenderOptions,
 ) -> TokenStream {
     let (static_commands, entry_commands, device_commands, instance_commands) = feature
         .elements
@@ -2191,6 +3167,9 @@ pub fn generate_feature<'a>(
             &static_commands,
             &HashMap::new(),
             fn_cache,
+            success_codes,
+            valid_usage,
+            options.unloaded_commands,
         )
     } else {
         quote! {}
@@ -2200,18 +3179,27 @@ pub fn generate_feature<'a>(
         &entry_commands,
         &HashMap::new(),
         fn_cache,
+        success_codes,
+        valid_usage,
+        options.unloaded_commands,
     );
     let instance = generate_function_pointers(
         format_ident!("{}", format!("InstanceFnV{}", version).as_str()),
         &instance_commands,
         &HashMap::new(),
         fn_cache,
+        success_codes,
+        valid_usage,
+        options.unloaded_commands,
     );
     let device = generate_function_pointers(
         format_ident!("{}", format!("DeviceFnV{}", version).as_str()),
         &device_commands,
         &HashMap::new(),
         fn_cache,
+        success_codes,
+        valid_usage,
+        options.unloaded_commands,
     );
     quote! {
         #static_fn
@@ -2247,6 +3235,7 @@ pub fn generate_feature_extension<'a>(
     registry: &'a vk_parse::Registry,
     const_cache: &mut HashSet<&'a str, impl BuildHasher>,
     const_values: &mut BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    skipped: &mut SkippedItems,
 ) -> TokenStream {
     let constants = registry.0.iter().filter_map(|item| match item {
         vk_parse::RegistryChild::Feature(feature) => Some(generate_extension_constants(
@@ -2255,6 +3244,7 @@ pub fn generate_feature_extension<'a>(
             &feature.children,
             const_cache,
             const_values,
+            skipped,
         )),
         _ => None,
     });
@@ -2266,6 +3256,128 @@ pub fn generate_feature_extension<'a>(
 pub struct ConstantMatchInfo {
     pub ident: Ident,
     pub is_alias: bool,
+    /// The integer value of the variant, `None` for aliases and expressions.
+    pub value: Option<i128>,
+}
+
+/// Generates the conversions that [`RenderOptions::unknown_enum_values`] asks for, which need all
+/// values of the enums, including the ones added by extensions.
+pub fn generate_enum_checks(
+    const_values: &BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    options: &RenderOptions,
+) -> TokenStream {
+    let impls: Vec<_> = const_values
+        .iter()
+        .filter_map(|(ty, values)| {
+            let strategy = options
+                .unknown_enum_values
+                .get(&format!("Vk{}", ty))
+                .copied()
+                .unwrap_or_default();
+            if strategy == UnknownEnumValues::Keep || ty.to_string().contains("Flags") {
+                return None;
+            }
+            let known: Vec<_> = values
+                .iter()
+                .filter(|value| !value.is_alias)
+                .map(|value| &value.ident)
+                .collect();
+            let is_known_body = if known.is_empty() {
+                quote!(false)
+            } else {
+                quote! {
+                    match self {
+                        #(Self::#known)|* => true,
+                        _ => false,
+                    }
+                }
+            };
+            let is_known = quote! {
+                impl #ty {
+                    /// Returns whether the value is in the registry these bindings were generated from.
+                    pub fn is_known(self) -> bool {
+                        #is_known_body
+                    }
+                }
+            };
+            let conversion = match strategy {
+                UnknownEnumValues::Keep => unreachable!(),
+                UnknownEnumValues::TryFrom => quote! {
+                    impl ::std::convert::TryFrom<i32> for #ty {
+                        type Error = UnknownEnumValue;
+                        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+                            let value = Self(value);
+                            if value.is_known() {
+                                Ok(value)
+                            } else {
+                                Err(UnknownEnumValue(value.0))
+                            }
+                        }
+                    }
+                },
+                UnknownEnumValues::Unknown => quote! {
+                    impl From<#ty> for CheckedEnum<#ty> {
+                        fn from(value: #ty) -> Self {
+                            if value.is_known() {
+                                CheckedEnum::Known(value)
+                            } else {
+                                CheckedEnum::Unknown(value.0)
+                            }
+                        }
+                    }
+                },
+            };
+            Some(quote! {
+                #is_known
+                #conversion
+            })
+        })
+        .collect();
+    if impls.is_empty() {
+        return quote!();
+    }
+    quote! {
+        /// An enum value that isn't in the registry these bindings were generated from.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct UnknownEnumValue(pub i32);
+
+        impl fmt::Display for UnknownEnumValue {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "Unknown enum value {}", self.0)
+            }
+        }
+
+        impl ::std::error::Error for UnknownEnumValue {}
+
+        /// An enum value, split into the values in the registry these bindings were generated from
+        /// and the raw value of the others.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum CheckedEnum<T> {
+            Known(T),
+            Unknown(i32),
+        }
+
+        #(#impls)*
+    }
+}
+
+/// Enums with more values than this look up their names in a table sorted by value instead of a
+/// `match`, which is a lot of code for enums like `Format` or `StructureType`.
+const NAME_TABLE_MIN_VALUES: usize = 64;
+
+/// The values and names of an enum that is large enough for [`NAME_TABLE_MIN_VALUES`], `None` if
+/// it isn't, or if some values aren't known.
+fn name_table(values: &[ConstantMatchInfo]) -> Option<Vec<(i128, &Ident)>> {
+    let table = values
+        .iter()
+        .filter(|value| !value.is_alias)
+        .map(|value| Some((value.value?, &value.ident)))
+        .collect::<Option<Vec<_>>>()?;
+    if table.len() > NAME_TABLE_MIN_VALUES {
+        Some(table)
+    } else {
+        None
+    }
 }
 
 pub fn generate_const_debugs(
@@ -2285,11 +3397,31 @@ pub fn generate_const_debugs(
             quote! {
                 impl fmt::Debug for #ty {
                     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        #[cfg(feature = "debug-strings")]
                         const KNOWN: &[(Flags, &str)] = &[#(#cases),*];
+                        #[cfg(not(feature = "debug-strings"))]
+                        const KNOWN: &[(Flags, &str)] = &[];
                         debug_flags(f, KNOWN, self.0)
                     }
                 }
             }
+        } else if let Some(mut table) = name_table(values) {
+            table.sort_by_key(|&(value, _)| value);
+            let entries = table.iter().map(|(_, ident)| {
+                let name = ident.to_string();
+                quote! { (#ty::#ident.0, #name) }
+            });
+            quote! {
+                impl fmt::Debug for #ty {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        #[cfg(feature = "debug-strings")]
+                        const KNOWN: &[(i32, &str)] = &[#(#entries),*];
+                        #[cfg(not(feature = "debug-strings"))]
+                        const KNOWN: &[(i32, &str)] = &[];
+                        debug_enum(f, KNOWN, self.0)
+                    }
+                }
+            }
         } else {
             let cases = values.iter().filter_map(|value| {
                 if value.is_alias {
@@ -2303,10 +3435,13 @@ pub fn generate_const_debugs(
             quote! {
                 impl fmt::Debug for #ty {
                     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        #[cfg(feature = "debug-strings")]
                         let name = match *self {
                             #(#cases)*
                             _ => None,
                         };
+                        #[cfg(not(feature = "debug-strings"))]
+                        let name: Option<&str> = None;
                         if let Some(x) = name {
                             f.write_str(x)
                         } else {
@@ -2318,6 +3453,14 @@ pub fn generate_const_debugs(
         }
     });
     quote! {
+        /// Writes the name of `value`, looked up in `known`, which is sorted by value.
+        pub(crate) fn debug_enum(f: &mut fmt::Formatter, known: &[(i32, &'static str)], value: i32) -> fmt::Result {
+            match known.binary_search_by_key(&value, |&(known, _)| known) {
+                Ok(index) => f.write_str(known[index].1),
+                Err(_) => fmt::Debug::fmt(&value, f),
+            }
+        }
+
         pub(crate) fn debug_flags(f: &mut fmt::Formatter, known: &[(Flags, &'static str)], value: Flags) -> fmt::Result {
             let mut first = true;
             let mut accum = value;
@@ -2366,361 +3509,1557 @@ pub fn generate_aliases_of_types(
         #(#aliases)*
     }
 }
+/// Generates the bindings from the registry at `vk_xml` into `src_dir`. The valid usage docs are
+/// read from `validusage.json` next to `vk_xml`, if it exists.
 pub fn write_source_code<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
-    use std::fs::File;
-    use std::io::Write;
-    let (spec2, _errors) = vk_parse::parse_file(vk_xml).expect("Invalid xml file");
-    let extensions: &Vec<vk_parse::Extension> = spec2
-        .0
-        .iter()
-        .filter_map(|item| match item {
-            vk_parse::RegistryChild::Extensions(ref ext) => Some(&ext.children),
-            _ => None,
-        })
-        .next()
-        .expect("extension");
-    let mut ty_cache = HashSet::new();
-    let aliases: Vec<_> = spec2
-        .0
-        .iter()
-        .filter_map(|item| match item {
-            vk_parse::RegistryChild::Types(ref ty) => {
-                Some(generate_aliases_of_types(ty, &mut ty_cache))
-            }
-            _ => None,
-        })
-        .collect();
+    let ctx = Context::from_file(vk_xml).expect("Invalid xml file");
+    // The registry ships `validusage.json` next to `vk.xml`
+    let valid_usage = ValidUsageMap::from_file(&vk_xml.with_file_name("validusage.json"));
+    write_context(&ctx, &valid_usage, src_dir.as_ref(), false);
+}
 
-    let spec = vk_parse::parse_file_as_vkxml(vk_xml).expect("Invalid xml file.");
-    let cmd_aliases: HashMap<String, String> = spec2
-        .0
-        .iter()
-        .filter_map(|item| match item {
-            vk_parse::RegistryChild::Commands(cmds) => {
-                let cmd_tuple_iter = cmds.children.iter().filter_map(|cmd| match cmd {
-                    vk_parse::Command::Alias { name, alias } => {
-                        Some((name.to_string(), alias.to_string()))
-                    }
-                    _ => None,
-                });
-                Some(cmd_tuple_iter)
-            }
-            _ => None,
-        })
-        .flatten()
-        .collect();
+/// Same as [`write_source_code`], but panics without writing anything if the registry has parts
+/// that the generator doesn't know, see [`Context::check_strict`].
+pub fn write_source_code_strict<P: AsRef<Path>>(vk_xml: &Path, src_dir: P) {
+    let ctx = Context::from_file(vk_xml).expect("Invalid xml file");
+    let valid_usage = ValidUsageMap::from_file(&vk_xml.with_file_name("validusage.json"));
+    write_context(&ctx, &valid_usage, src_dir.as_ref(), true);
+}
 
-    let commands: HashMap<vkxml::Identifier, &vkxml::Command> = spec
-        .elements
-        .iter()
-        .filter_map(|elem| match elem {
-            vkxml::RegistryElement::Commands(ref cmds) => Some(cmds),
-            _ => None,
-        })
-        .flat_map(|cmds| cmds.elements.iter().map(|cmd| (cmd.name.clone(), cmd)))
-        .collect();
+/// Same as [`write_source_code`], for a registry that is already in memory, e.g. embedded in a
+/// build script or pre-processed before generation.
+pub fn write_source_code_from_str<P: AsRef<Path>>(
+    vk_xml: &str,
+    valid_usage: &ValidUsageMap,
+    src_dir: P,
+) {
+    write_source_code_from_reader(vk_xml.as_bytes(), valid_usage, src_dir);
+}
 
-    let features: Vec<&vkxml::Feature> = spec
-        .elements
-        .iter()
-        .filter_map(|elem| match elem {
-            vkxml::RegistryElement::Features(ref features) => Some(features),
-            _ => None,
-        })
-        .flat_map(|features| features.elements.iter())
-        .collect();
+/// Same as [`write_source_code`], reading the registry from `vk_xml`. Pass
+/// `&ValidUsageMap::default()` to generate without valid usage docs.
+pub fn write_source_code_from_reader<R: std::io::Read, P: AsRef<Path>>(
+    vk_xml: R,
+    valid_usage: &ValidUsageMap,
+    src_dir: P,
+) {
+    let ctx = Context::from_reader(vk_xml).expect("Invalid xml file");
+    write_context(&ctx, valid_usage, src_dir.as_ref(), false);
+}
 
-    let definitions: Vec<&vkxml::DefinitionsElement> = spec
-        .elements
-        .iter()
-        .filter_map(|elem| match elem {
-            vkxml::RegistryElement::Definitions(ref definitions) => Some(definitions),
-            _ => None,
-        })
-        .flat_map(|definitions| definitions.elements.iter())
-        .collect();
+fn write_context(ctx: &Context, valid_usage: &ValidUsageMap, src_dir: &Path, strict: bool) {
+    ctx.validate()
+        .unwrap_or_else(|conflicts| panic!("{}", conflicts));
+    if strict {
+        ctx.check_strict()
+            .unwrap_or_else(|conflicts| panic!("{}", conflicts));
+    }
+    let code = ctx.render(valid_usage);
+    code.write_to(src_dir)
+        .expect("Unable to write the generated code");
+    if !code.skipped.is_empty() {
+        eprintln!("{}", code.skipped);
+    }
+}
 
-    let enums: Vec<&vkxml::Enumeration> = spec
-        .elements
-        .iter()
-        .filter_map(|elem| match elem {
-            vkxml::RegistryElement::Enums(ref enums) => Some(enums),
-            _ => None,
-        })
-        .flat_map(|enums| {
-            enums.elements.iter().filter_map(|_enum| match *_enum {
-                vkxml::EnumsElement::Enumeration(ref e) => Some(e),
-                _ => None,
-            })
-        })
-        .collect();
+/// One generated source file.
+#[derive(Clone, Debug)]
+pub struct GeneratedFile {
+    /// The path of the file, relative to the `src` directory of ash.
+    pub path: &'static str,
+    /// Text written before `code`, for items that don't survive a round trip through a
+    /// `TokenStream` unharmed.
+    pub header: &'static str,
+    pub code: TokenStream,
+}
 
-    let constants: Vec<&vkxml::Constant> = spec
-        .elements
-        .iter()
-        .filter_map(|elem| match elem {
-            vkxml::RegistryElement::Constants(ref constants) => Some(constants),
-            _ => None,
-        })
-        .flat_map(|constants| constants.elements.iter())
-        .collect();
+impl Display for GeneratedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.header, self.code)
+    }
+}
 
-    let mut fn_cache = HashSet::new();
-    let mut bitflags_cache = HashSet::new();
-    let mut const_cache = HashSet::new();
+/// The registry items that no code was generated for, by the kind of item, e.g. `includes`.
+/// Values that are left out on purpose, like duplicates, aren't recorded.
+#[derive(Clone, Debug, Default)]
+pub struct SkippedItems {
+    items: BTreeMap<&'static str, BTreeSet<String>>,
+}
 
-    let mut const_values: BTreeMap<Ident, Vec<ConstantMatchInfo>> = BTreeMap::new();
+impl SkippedItems {
+    pub(crate) fn insert(&mut self, kind: &'static str, name: &str) {
+        self.items.entry(kind).or_default().insert(name.to_owned());
+    }
 
-    let (enum_code, bitflags_code) = enums
-        .into_iter()
-        .map(|e| generate_enum(e, &mut const_cache, &mut const_values, &mut bitflags_cache))
-        .fold((Vec::new(), Vec::new()), |mut acc, elem| {
-            match elem {
-                EnumType::Enum(token) => acc.0.push(token),
-                EnumType::Bitflags(token) => acc.1.push(token),
-            };
-            acc
-        });
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
 
-    let mut constants_code: Vec<_> = constants
-        .iter()
-        .map(|constant| generate_constant(constant, &mut const_cache))
-        .collect();
+    /// Returns the names of the skipped items of `kind`, sorted.
+    pub fn get(&self, kind: &str) -> impl Iterator<Item = &str> {
+        self.items
+            .get(kind)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+}
 
-    constants_code.push(quote! { pub const SHADER_UNUSED_NV : u32 = SHADER_UNUSED_KHR;});
-
-    let extension_code = extensions
-        .iter()
-        .filter_map(|ext| {
-            generate_extension(
-                ext,
-                &commands,
-                &mut const_cache,
-                &mut const_values,
-                &cmd_aliases,
-                &mut fn_cache,
-            )
-        })
-        .collect_vec();
+impl Display for SkippedItems {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, (kind, names)) in self.items.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "Skipped {} {}: {}",
+                names.len(),
+                kind,
+                names.iter().join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
 
-    let union_types = definitions
-        .iter()
-        .filter_map(|def| match def {
-            vkxml::DefinitionsElement::Union(ref union) => Some(union.name.as_str()),
-            _ => None,
-        })
-        .collect::<HashSet<&str>>();
+/// The bindings generated by [`Context::render`], not yet written to disk.
+#[derive(Clone, Debug)]
+pub struct GeneratedCode {
+    pub files: Vec<GeneratedFile>,
+    /// The registry items that were skipped, reported by [`write_source_code`] so that gaps in
+    /// the bindings don't go unnoticed.
+    pub skipped: SkippedItems,
+}
 
-    let root_names = root_struct_names(&definitions);
-    let definition_code: Vec<_> = definitions
-        .into_iter()
-        .filter_map(|def| {
-            generate_definition(
-                def,
-                &union_types,
-                &root_names,
-                &mut bitflags_cache,
-                &mut const_values,
-            )
-        })
-        .collect();
+impl GeneratedCode {
+    /// Returns the file at `path`, e.g. `vk/enums.rs`.
+    pub fn get(&self, path: &str) -> Option<&GeneratedFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
 
-    let feature_code: Vec<_> = features
-        .iter()
-        .map(|feature| generate_feature(feature, &commands, &mut fn_cache))
-        .collect();
-    let feature_extensions_code =
-        generate_feature_extension(&spec2, &mut const_cache, &mut const_values);
-
-    let const_debugs = generate_const_debugs(&const_values);
-
-    let bitflags_macro = vk_bitflags_wrapped_macro();
-    let handle_nondispatchable_macro = handle_nondispatchable_macro();
-    let define_handle_macro = define_handle_macro();
-    let version_macros = vk_version_macros();
-    let platform_specific_types = platform_specific_types();
-
-    let ptr_chain_code = quote! {
-        /// Iterates through the pointer chain. Includes the item that is passed into the function.
-        /// Stops at the last `BaseOutStructure` that has a null `p_next` field.
-        pub(crate) unsafe fn ptr_chain_iter<T>(
-            ptr: &mut T,
-        ) -> impl Iterator<Item = *mut BaseOutStructure> {
-            let ptr: *mut BaseOutStructure = ptr as *mut T as _;
-            (0..).scan(ptr, |p_ptr, _| {
-                if p_ptr.is_null() {
-                    return None;
-                }
-                let n_ptr = (**p_ptr).p_next as *mut BaseOutStructure;
-                let old = *p_ptr;
-                *p_ptr = n_ptr;
-                Some(old)
+    /// Writes the files into `src_dir`, creating the directories they are in.
+    ///
+    /// All files are rendered before the first one is written, and every file is written to a
+    /// temporary file next to it that then replaces it, so that a failure doesn't leave behind
+    /// files that are cut off or out of sync with the others.
+    pub fn write_to<P: AsRef<Path>>(&self, src_dir: P) -> std::io::Result<()> {
+        use std::fmt::Write;
+        let src_dir = src_dir.as_ref();
+        // Each buffer starts out as large as the largest file so far, so that the big files
+        // after the first few don't grow their `String` over and over
+        let mut capacity = 0;
+        let rendered: Vec<_> = self
+            .files
+            .iter()
+            .map(|file| {
+                let mut buf = String::with_capacity(capacity);
+                write!(&mut buf, "{}", file).unwrap();
+                capacity = capacity.max(buf.len());
+                (src_dir.join(file.path), buf)
             })
+            .collect();
+        for (path, code) in rendered {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let tmp_path = path.with_extension("rs.tmp");
+            std::fs::write(&tmp_path, code)?;
+            if let Err(err) = std::fs::rename(&tmp_path, &path) {
+                let _ = std::fs::remove_file(&tmp_path);
+                return Err(err);
+            }
         }
-    };
+        Ok(())
+    }
+}
 
-    let macros_code = quote! {
-        #version_macros
-        #bitflags_macro
-        #handle_nondispatchable_macro
-        #define_handle_macro
-    };
+/// What to generate for the extensions marked as `provisional` in the registry, whose interface
+/// may still change in incompatible ways.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvisionalExtensions {
+    /// Generate them behind the `provisional` feature of ash.
+    Feature,
+    /// Generate them like any other extension.
+    Include,
+    /// Don't generate their commands and function pointer tables. Their enum values are still
+    /// generated, as the structs of the extensions need them.
+    Exclude,
+}
 
-    let src_dir = src_dir.as_ref();
-
-    let vk_dir = src_dir.join("vk");
-    std::fs::create_dir_all(&vk_dir).expect("failed to create vk dir");
-
-    let mut vk_rs_file = File::create(src_dir.join("vk.rs")).expect("vk.rs");
-
-    let mut vk_macros_file = File::create(vk_dir.join("macros.rs")).expect("vk/macros.rs");
-    let mut vk_features_file = File::create(vk_dir.join("features.rs")).expect("vk/features.rs");
-    let mut vk_definitions_file =
-        File::create(vk_dir.join("definitions.rs")).expect("vk/definitions.rs");
-    let mut vk_platform_types_file =
-        File::create(vk_dir.join("platform_types.rs")).expect("vk/platform_types.rs");
-    let mut vk_enums_file = File::create(vk_dir.join("enums.rs")).expect("vk/enums.rs");
-    let mut vk_bitflags_file = File::create(vk_dir.join("bitflags.rs")).expect("vk/bitflags.rs");
-    let mut vk_constants_file = File::create(vk_dir.join("constants.rs")).expect("vk/constants.rs");
-    let mut vk_extensions_file =
-        File::create(vk_dir.join("extensions.rs")).expect("vk/extensions.rs");
-    let mut vk_feature_extensions_file =
-        File::create(vk_dir.join("feature_extensions.rs")).expect("vk/feature_extensions.rs");
-    let mut vk_const_debugs_file =
-        File::create(vk_dir.join("const_debugs.rs")).expect("vk/const_debugs.rs");
-    let mut vk_aliases_file = File::create(vk_dir.join("aliases.rs")).expect("vk/aliases.rs");
-
-    let feature_code = quote! {
-        use std::os::raw::*;
-        use crate::vk::bitflags::*;
-        use crate::vk::definitions::*;
-        use crate::vk::enums::*;
-        #(#feature_code)*
-    };
+impl Default for ProvisionalExtensions {
+    fn default() -> Self {
+        ProvisionalExtensions::Feature
+    }
+}
 
-    let definition_code = quote! {
-        use std::fmt;
-        use std::os::raw::*;
-        use crate::vk::{Handle, ptr_chain_iter};
-        use crate::vk::platform_types::*;
-        use crate::vk::aliases::*;
-        use crate::vk::bitflags::*;
-        use crate::vk::constants::*;
-        use crate::vk::enums::*;
-        #(#definition_code)*
-    };
+/// What to generate for the values of an enum that aren't in the registry, which drivers of a newer
+/// Vulkan version or with newer extensions can return.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnknownEnumValues {
+    /// Nothing, the enum holds any `i32` given to `from_raw`.
+    Keep,
+    /// `TryFrom<i32>`, which fails with `UnknownEnumValue` for values that aren't in the registry.
+    TryFrom,
+    /// `From<Enum> for CheckedEnum<Enum>`, which puts the values that aren't in the registry in
+    /// `CheckedEnum::Unknown(i32)`.
+    Unknown,
+}
 
-    let enum_code = quote! {
-        use std::fmt;
-        #(#enum_code)*
-    };
+impl Default for UnknownEnumValues {
+    fn default() -> Self {
+        UnknownEnumValues::Keep
+    }
+}
 
-    let bitflags_code = quote! {
-        use crate::vk::definitions::*;
-        #(#bitflags_code)*
-    };
+/// How the native enums of [`EnumRepresentation::OpenWithNative`] are kept open for values that
+/// later versions of the registry add, so that matching on them needs a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonExhaustive {
+    /// `#[non_exhaustive]`, which needs Rust 1.40.
+    Attribute,
+    /// A `#[doc(hidden)]` variant with the value `0x7FFF_FFFF`, which Vulkan reserves as the
+    /// `*_MAX_ENUM` of every enum.
+    HiddenVariant,
+}
 
-    let constants_code = quote! {
-        use crate::vk::definitions::*;
-        #(#constants_code)*
-    };
+/// How the enums of the registry are represented. The types in `vk` are always open enums, as
+/// they are passed to and returned by the driver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// `#[repr(transparent)]` newtypes over `i32`, with an associated constant for every value.
+    /// Unlike a Rust `enum`, they can hold values that aren't in the registry, which drivers of a
+    /// newer Vulkan version can return, without undefined behavior.
+    Open,
+    /// [`Open`](Self::Open), and `vk::native` with a Rust `enum` for every enum, which converts
+    /// from and to the open enum.
+    OpenWithNative(NonExhaustive),
+}
 
-    let extension_code = quote! {
-        use std::os::raw::*;
-        use crate::vk::platform_types::*;
-        use crate::vk::aliases::*;
-        use crate::vk::bitflags::*;
-        use crate::vk::definitions::*;
-        use crate::vk::enums::*;
-        #(#extension_code)*
-    };
+impl Default for EnumRepresentation {
+    fn default() -> Self {
+        EnumRepresentation::Open
+    }
+}
 
-    let feature_extensions_code = quote! {
-        use crate::vk::bitflags::*;
-        use crate::vk::enums::*;
-       #feature_extensions_code
-    };
+/// What the function pointer tables hold for commands that the resolver passed to `load` returns
+/// null for, usually because their extension isn't enabled or the driver is too old. Build scripts
+/// can pick one per build profile, e.g. depending on the `PROFILE` environment variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnloadedCommands {
+    /// A stub that panics with the name of the command when it is called.
+    PanicStub,
+    /// `None`, the fields being `Option`s of function pointers. Whether a command was loaded can be
+    /// checked, and the wrappers panic with the name of the command like the stub does.
+    Option,
+}
 
-    let const_debugs = quote! {
-        use std::fmt;
-        use crate::vk::bitflags::*;
-        use crate::vk::definitions::*;
-        use crate::vk::enums::*;
-        #const_debugs
-    };
+impl Default for UnloadedCommands {
+    fn default() -> Self {
+        UnloadedCommands::PanicStub
+    }
+}
 
-    let aliases = quote! {
-        use crate::vk::bitflags::*;
-        use crate::vk::definitions::*;
-        use crate::vk::enums::*;
-        #(#aliases)*
-    };
+/// Settings for [`Context::render_with`] that change the generated code.
+#[derive(Clone, Debug, Default)]
+pub struct RenderOptions {
+    /// Words that stay upper case when a name is converted to PascalCase, e.g. `SRGB`, `ASTC` or
+    /// `UUID`. Matched case-insensitively against the words of the name, and written like they are
+    /// written here. Empty by default, which keeps the names ash has always generated.
+    pub acronyms: Vec<String>,
+    pub provisional: ProvisionalExtensions,
+    /// How unknown values are handled per enum, keyed by the name in the registry, e.g.
+    /// `VkFormat`. Enums that aren't in the map use [`UnknownEnumValues::Keep`].
+    pub unknown_enum_values: HashMap<String, UnknownEnumValues>,
+    pub enum_representation: EnumRepresentation,
+    /// Rust types that replace the default equivalent of the `vk_platform.h` types `void`,
+    /// `char`, `int` and `size_t`, keyed by the C name, e.g. `"size_t"` to `"u32"` for a target
+    /// where `size_t` isn't pointer-sized.
+    pub platform_types: HashMap<String, String>,
+    pub unloaded_commands: UnloadedCommands,
+}
 
-    let platform_types_code = quote! {
-        use std::os::raw::*;
-        #platform_specific_types
-    };
+impl RenderOptions {
+    /// Converts a `snake_case` or `SHOUTY_SNAKE_CASE` name to PascalCase, like
+    /// `VK_EXT_astc_decode_mode` to `VkExtAstcDecodeMode`, or to `VkExtASTCDecodeMode` if `ASTC` is
+    /// one of the [`acronyms`](Self::acronyms).
+    pub fn pascal_case(&self, name: &str) -> String {
+        name.split('_')
+            .map(|word| {
+                match self
+                    .acronyms
+                    .iter()
+                    .find(|acronym| acronym.eq_ignore_ascii_case(word))
+                {
+                    Some(acronym) => acronym.clone(),
+                    None => word.to_camel_case(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Context {
+    /// Generates the bindings for the registry, without touching the file system.
+    pub fn render(&self, valid_usage: &ValidUsageMap) -> GeneratedCode {
+        self.render_with(valid_usage, &RenderOptions::default())
+    }
+
+    /// Same as [`Context::render`], with non-default [`RenderOptions`].
+    pub fn render_with(
+        &self,
+        valid_usage: &ValidUsageMap,
+        options: &RenderOptions,
+    ) -> GeneratedCode {
+        let ctx = self;
+        let extensions = ctx.extensions();
+        let mut ty_cache = HashSet::new();
+        let aliases: Vec<_> = ctx
+            .registry()
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Types(ref ty) => {
+                    Some(generate_aliases_of_types(ty, &mut ty_cache))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let cmd_aliases = ctx.command_aliases();
+        let commands = ctx.commands();
+        let success_codes = ctx.success_codes();
+        let features = ctx.features();
+        let definitions = ctx.definitions();
+        let enums = ctx.enums();
+        let enum_aliases = ctx.enum_aliases();
+        let constants = ctx.constants();
+
+        let mut fn_cache = HashSet::new();
+        let mut bitflags_cache = HashSet::new();
+        let mut const_cache = HashSet::new();
+
+        let mut const_values: BTreeMap<Ident, Vec<ConstantMatchInfo>> = BTreeMap::new();
+        let mut skipped = SkippedItems::default();
+
+        let (enum_code, bitflags_code) = enums
+            .into_iter()
+            .map(|e| {
+                let mut code =
+                    generate_enum(e, &mut const_cache, &mut const_values, &mut bitflags_cache);
+                if let Some(aliases) = enum_aliases.get(e.name.as_str()) {
+                    let alias_code = generate_enum_aliases(
+                        &e.name,
+                        aliases,
+                        &mut const_cache,
+                        &mut const_values,
+                    );
+                    match &mut code {
+                        EnumType::Enum(token) | EnumType::Bitflags(token) => {
+                            token.extend(alias_code)
+                        }
+                    }
+                }
+                code
+            })
+            .fold((Vec::new(), Vec::new()), |mut acc, elem| {
+                match elem {
+                    EnumType::Enum(token) => acc.0.push(token),
+                    EnumType::Bitflags(token) => acc.1.push(token),
+                };
+                acc
+            });
+
+        let mut constants_code: Vec<_> = constants
+            .iter()
+            .map(|constant| generate_constant(constant, &mut const_cache))
+            .collect();
+
+        constants_code.push(quote! { pub const SHADER_UNUSED_NV : u32 = SHADER_UNUSED_KHR;});
+
+        let extension_code = extensions
+            .iter()
+            .filter_map(|ext| {
+                generate_extension(
+                    ext,
+                    &commands,
+                    &mut const_cache,
+                    &mut const_values,
+                    &cmd_aliases,
+                    &mut fn_cache,
+                    &success_codes,
+                    valid_usage,
+                    options,
+                    &mut skipped,
+                )
+            })
+            .collect_vec();
+
+        let union_types = definitions
+            .iter()
+            .filter_map(|def| match def {
+                vkxml::DefinitionsElement::Union(ref union) => Some(union.name.as_str()),
+                _ => None,
+            })
+            .collect::<HashSet<&str>>();
+
+        let root_names = root_struct_names(&definitions);
+        let borrowing_names = borrowing_struct_names(&definitions);
+        let definition_code: Vec<_> = definitions
+            .into_iter()
+            .filter_map(|def| {
+                generate_definition(
+                    def,
+                    &union_types,
+                    &root_names,
+                    &borrowing_names,
+                    &mut bitflags_cache,
+                    &mut const_values,
+                    valid_usage,
+                    &mut skipped,
+                )
+            })
+            .collect();
+
+        let feature_code: Vec<_> = features
+            .iter()
+            .map(|feature| {
+                generate_feature(
+                    feature,
+                    &commands,
+                    &mut fn_cache,
+                    &success_codes,
+                    valid_usage,
+                    options,
+                )
+            })
+            .collect();
+        let feature_extensions_code = generate_feature_extension(
+            ctx.registry(),
+            &mut const_cache,
+            &mut const_values,
+            &mut skipped,
+        );
+
+        let const_debugs = generate_const_debugs(&const_values);
+        let enum_checks = generate_enum_checks(&const_values, options);
+
+        let bitflags_macro = vk_bitflags_wrapped_macro();
+        let handle_nondispatchable_macro = handle_nondispatchable_macro();
+        let define_handle_macro = define_handle_macro();
+        let extensions_macro = vk_extensions_macro();
+        let version_macros = vk_version_macros();
+        let platform_specific_types = platform_specific_types();
+
+        let handle_owners_code = generate_handle_owners(&ctx.handle_parents());
+
+        let ptr_chain_code = quote! {
+            /// Iterates through the pointer chain. Includes the item that is passed into the function.
+            /// Stops at the last `BaseOutStructure` that has a null `p_next` field.
+            pub(crate) unsafe fn ptr_chain_iter<T>(
+                ptr: &mut T,
+            ) -> impl Iterator<Item = *mut BaseOutStructure> {
+                let ptr: *mut BaseOutStructure = ptr as *mut T as _;
+                (0..).scan(ptr, |p_ptr, _| {
+                    if p_ptr.is_null() {
+                        return None;
+                    }
+                    let n_ptr = (**p_ptr).p_next as *mut BaseOutStructure;
+                    let old = *p_ptr;
+                    *p_ptr = n_ptr;
+                    Some(old)
+                })
+            }
+
+            /// Panics if a struct chained to `root` can't extend the struct `root_name` according
+            /// to [`meta::struct_extends`], or if two structs of the chain have the same `s_type`.
+            /// Structs that aren't in the registry, such as the ones of layers, are skipped.
+            #[cfg(debug_assertions)]
+            pub(crate) unsafe fn debug_assert_ptr_chain<T>(root_name: &str, root: &mut T) {
+                let mut s_types = Vec::new();
+                for ptr in ptr_chain_iter(root).skip(1) {
+                    let s_type = (*ptr).s_type;
+                    if let Some(name) = meta::structure_type_struct(s_type) {
+                        assert!(
+                            meta::struct_extends(name).contains(&root_name),
+                            "{} can't be chained to {}",
+                            name,
+                            root_name
+                        );
+                        assert!(
+                            !s_types.contains(&s_type),
+                            "{} is chained to {} twice",
+                            name,
+                            root_name
+                        );
+                    }
+                    s_types.push(s_type);
+                }
+            }
+
+            /// Structs with a fixed `s_type`, which identifies them in a pointer chain.
+            ///
+            /// # Safety
+            ///
+            /// The struct must start with `s_type` and `p_next` like `BaseOutStructure`, and
+            /// `STRUCTURE_TYPE` must be its `s_type`.
+            pub unsafe trait TaggedStructure {
+                const STRUCTURE_TYPE: StructureType;
+
+                /// Views the struct as the `s_type` and `p_next` it starts with.
+                fn as_base_in(&self) -> &BaseInStructure
+                where
+                    Self: Sized,
+                {
+                    unsafe { &*(self as *const Self as *const BaseInStructure) }
+                }
+
+                /// Views the struct as the `s_type` and `p_next` it starts with, e.g. to insert it into a
+                /// chain by hand.
+                fn as_base_out(&mut self) -> &mut BaseOutStructure
+                where
+                    Self: Sized,
+                {
+                    unsafe { &mut *(self as *mut Self as *mut BaseOutStructure) }
+                }
+            }
+
+            /// Iterates over the structs of a pointer chain, yielding their `s_type` and a pointer to
+            /// each of them. Unlike creating it, using the iterator is safe; [`PtrChainIter::find_struct`]
+            /// and [`PtrChainIter::find_struct_mut`] downcast to the first struct of a given type.
+            #[derive(Debug)]
+            pub struct PtrChainIter<'a> {
+                ptr: *mut BaseOutStructure,
+                marker: ::std::marker::PhantomData<&'a mut BaseOutStructure>,
+            }
+
+            impl<'a> PtrChainIter<'a> {
+                /// Iterates over `head` and the structs chained to it.
+                ///
+                /// # Safety
+                ///
+                /// `head` must start with `s_type` and `p_next`, and every `p_next` of the chain must
+                /// be null or point to a valid struct whose `s_type` matches its type. The chain must
+                /// not contain cycles and must not be accessed otherwise while the iterator and the
+                /// references it hands out are alive.
+                pub unsafe fn new<T>(head: &'a mut T) -> Self {
+                    Self::from_raw(head as *mut T as *mut BaseOutStructure)
+                }
+
+                /// Same as [`PtrChainIter::new`], for a chain received as a raw pointer, which may
+                /// be null.
+                ///
+                /// # Safety
+                ///
+                /// See [`PtrChainIter::new`].
+                pub unsafe fn from_raw(ptr: *mut BaseOutStructure) -> Self {
+                    PtrChainIter {
+                        ptr,
+                        marker: ::std::marker::PhantomData,
+                    }
+                }
+
+                /// Returns the first struct of type `T` in the rest of the chain.
+                pub fn find_struct<T: TaggedStructure>(self) -> Option<&'a T> {
+                    self.find_struct_mut().map(|s: &'a mut T| &*s)
+                }
+
+                /// Returns the first struct of type `T` in the rest of the chain.
+                pub fn find_struct_mut<T: TaggedStructure>(mut self) -> Option<&'a mut T> {
+                    self.find(|&(s_type, _)| s_type == T::STRUCTURE_TYPE)
+                        .map(|(_, ptr)| unsafe { &mut *(ptr as *mut T) })
+                }
+            }
+
+            impl<'a> Iterator for PtrChainIter<'a> {
+                type Item = (StructureType, *mut BaseOutStructure);
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.ptr.is_null() {
+                        return None;
+                    }
+                    let current = self.ptr;
+                    unsafe {
+                        self.ptr = (*current).p_next;
+                        Some(((*current).s_type, current))
+                    }
+                }
+            }
+
+            impl BaseInStructure {
+                /// Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`.
+                ///
+                /// # Safety
+                ///
+                /// `self` must be the start of a struct whose `s_type` matches its type, like the structs
+                /// of a pointer chain.
+                pub unsafe fn try_downcast<T: TaggedStructure>(&self) -> Option<&T> {
+                    if self.s_type == T::STRUCTURE_TYPE {
+                        Some(&*(self as *const Self as *const T))
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl BaseOutStructure {
+                /// Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`.
+                ///
+                /// # Safety
+                ///
+                /// `self` must be the start of a struct whose `s_type` matches its type, like the structs
+                /// of a pointer chain.
+                pub unsafe fn try_downcast<T: TaggedStructure>(&self) -> Option<&T> {
+                    if self.s_type == T::STRUCTURE_TYPE {
+                        Some(&*(self as *const Self as *const T))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Casts to `T` if `s_type` is `T::STRUCTURE_TYPE`.
+                ///
+                /// # Safety
+                ///
+                /// See [`BaseOutStructure::try_downcast`].
+                pub unsafe fn try_downcast_mut<T: TaggedStructure>(&mut self) -> Option<&mut T> {
+                    if self.s_type == T::STRUCTURE_TYPE {
+                        Some(&mut *(self as *mut Self as *mut T))
+                    } else {
+                        None
+                    }
+                }
+            }
+        };
+
+        let macros_code = quote! {
+            #version_macros
+            #bitflags_macro
+            #handle_nondispatchable_macro
+            #define_handle_macro
+            #extensions_macro
+            #[cfg(test)]
+            mod tests;
+        };
 
-    // These are defined outside of `quote!` because rustfmt doesn't seem
-    // to format them correctly when they contain extra spaces.
-    let vk_rs_clippy_lints = r#"
+        let feature_code = quote! {
+            use crate::vk::platform::*;
+            use crate::vk::bitflags::*;
+            use crate::vk::definitions::*;
+            use crate::vk::enums::*;
+            #(#feature_code)*
+        };
+
+        let definition_code = quote! {
+            use std::fmt;
+            use crate::vk::platform::*;
+            use crate::vk::{Handle, TaggedStructure, ptr_chain_iter};
+            use crate::vk::platform_types::*;
+            use crate::vk::aliases::*;
+            use crate::vk::bitflags::*;
+            use crate::vk::constants::*;
+            use crate::vk::enums::*;
+            #(#definition_code)*
+            #[cfg(test)]
+            mod tests;
+        };
+
+        let enum_code = quote! {
+            use std::fmt;
+            #(#enum_code)*
+            #enum_checks
+        };
+
+        let bitflags_code = quote! {
+            use crate::vk::definitions::*;
+            #(#bitflags_code)*
+        };
+
+        let constants_code = quote! {
+            use crate::vk::definitions::*;
+            #(#constants_code)*
+        };
+
+        let extension_code = quote! {
+            use crate::vk::platform::*;
+            use crate::vk::platform_types::*;
+            use crate::vk::aliases::*;
+            use crate::vk::bitflags::*;
+            use crate::vk::definitions::*;
+            use crate::vk::enums::*;
+            #(#extension_code)*
+        };
+
+        let feature_extensions_code = quote! {
+            use crate::vk::bitflags::*;
+            use crate::vk::enums::*;
+           #feature_extensions_code
+        };
+
+        let const_debugs = quote! {
+            use std::fmt;
+            use crate::vk::bitflags::*;
+            use crate::vk::definitions::*;
+            use crate::vk::enums::*;
+            #const_debugs
+            #[cfg(all(test, feature = "debug-strings"))]
+            mod tests;
+        };
+
+        let aliases = quote! {
+            use crate::vk::bitflags::*;
+            use crate::vk::definitions::*;
+            use crate::vk::enums::*;
+            #(#aliases)*
+        };
+
+        let platform_types_code = quote! {
+            use crate::vk::platform::*;
+            #platform_specific_types
+        };
+
+        // These are defined outside of `quote!` because rustfmt doesn't seem
+        // to format them correctly when they contain extra spaces.
+        let vk_rs_clippy_lints = r#"
 #![allow(clippy::too_many_arguments, clippy::cognitive_complexity, clippy::wrong_self_convention)]
 "#;
 
-    let vk_rs_code = quote! {
-        #[macro_use]
-        mod macros;
-        pub use macros::*;
-        mod aliases;
-        pub use aliases::*;
-        mod bitflags;
-        pub use bitflags::*;
-        mod const_debugs;
-        pub(crate) use const_debugs::*;
-        mod constants;
-        pub use constants::*;
-        mod definitions;
-        pub use definitions::*;
-        mod enums;
-        pub use enums::*;
-        mod extensions;
-        pub use extensions::*;
-        mod feature_extensions;
-        pub use feature_extensions::*;
-        mod features;
-        pub use features::*;
-        mod platform_types;
-        pub use platform_types::*;
-
-        #ptr_chain_code
-
-        pub trait Handle {
-            const TYPE: ObjectType;
-            fn as_raw(self) -> u64;
-            fn from_raw(_: u64) -> Self;
+        let native_mod = match options.enum_representation {
+            EnumRepresentation::Open => None,
+            EnumRepresentation::OpenWithNative(_) => Some(quote!(
+                pub mod native;
+            )),
+        };
+
+        let vk_rs_code = quote! {
+            #[macro_use]
+            mod macros;
+            pub use macros::*;
+            mod aliases;
+            pub use aliases::*;
+            mod bitflags;
+            pub use bitflags::*;
+            mod const_debugs;
+            pub(crate) use const_debugs::*;
+            mod constants;
+            pub use constants::*;
+            mod definitions;
+            pub use definitions::*;
+            mod enums;
+            pub use enums::*;
+            mod extensions;
+            pub use extensions::*;
+            mod feature_extensions;
+            pub use feature_extensions::*;
+            mod features;
+            pub use features::*;
+            mod format_classes;
+            #[cfg(feature = "interop")]
+            pub mod interop;
+            pub mod meta;
+            #native_mod
+            pub mod platform;
+            mod platform_types;
+            pub use platform_types::*;
+            mod result_codes;
+            pub use result_codes::*;
+            #[cfg(test)]
+            mod tests;
+
+            #ptr_chain_code
+
+            pub trait Handle {
+                const TYPE: ObjectType;
+                fn as_raw(self) -> u64;
+                fn from_raw(_: u64) -> Self;
+            }
+
+            #handle_owners_code
+        };
+
+        let file = |path, code| GeneratedFile {
+            path,
+            header: "",
+            code,
+        };
+        let mut files = vec![
+            file("vk/macros.rs", macros_code),
+            file("vk/platform.rs", generate_platform(&options.platform_types)),
+            file("vk/platform_types.rs", platform_types_code),
+            file("vk/features.rs", feature_code),
+            file("vk/definitions.rs", definition_code),
+            file("vk/enums.rs", enum_code),
+            file("vk/bitflags.rs", bitflags_code),
+            file("vk/constants.rs", constants_code),
+            file("vk/extensions.rs", extension_code),
+            file("vk/feature_extensions.rs", feature_extensions_code),
+            file("vk/const_debugs.rs", const_debugs),
+            file("vk/aliases.rs", aliases),
+            file("vk/meta.rs", generate_meta(ctx)),
+            file("vk/format_classes.rs", generate_format_classes()),
+            file("vk/interop.rs", generate_interop()),
+            file("vk/result_codes.rs", generate_result_codes(ctx)),
+            file("owned.rs", generate_owned(ctx)),
+            GeneratedFile {
+                path: "trace/commands.rs",
+                header: "#![allow(clippy::too_many_arguments)]\n",
+                code: generate_trace(ctx),
+            },
+            GeneratedFile {
+                path: "vk.rs",
+                header: vk_rs_clippy_lints,
+                code: vk_rs_code,
+            },
+        ];
+        if let EnumRepresentation::OpenWithNative(non_exhaustive) = options.enum_representation {
+            files.push(file(
+                "vk/native.rs",
+                generate_native_enums(&const_values, non_exhaustive),
+            ));
         }
-    };
+        GeneratedCode { files, skipped }
+    }
+}
 
-    write!(&mut vk_macros_file, "{}", macros_code).expect("Unable to write vk/macros.rs");
-    write!(&mut vk_platform_types_file, "{}", platform_types_code)
-        .expect("Unable to write to vk/platform_types.rs");
-    write!(&mut vk_features_file, "{}", feature_code).expect("Unable to write vk/features.rs");
-    write!(&mut vk_definitions_file, "{}", definition_code)
-        .expect("Unable to write vk/definitions.rs");
-    write!(&mut vk_enums_file, "{}", enum_code).expect("Unable to write vk/enums.rs");
-    write!(&mut vk_bitflags_file, "{}", bitflags_code).expect("Unable to write vk/bitflags.rs");
-    write!(&mut vk_constants_file, "{}", constants_code).expect("Unable to write vk/constants.rs");
-    write!(&mut vk_extensions_file, "{}", extension_code)
-        .expect("Unable to write vk/extensions.rs");
-    write!(
-        &mut vk_feature_extensions_file,
-        "{}",
-        feature_extensions_code
-    )
-    .expect("Unable to write vk/feature_extensions.rs");
-    write!(&mut vk_const_debugs_file, "{}", const_debugs)
-        .expect("Unable to write vk/const_debugs.rs");
-    write!(&mut vk_aliases_file, "{}", aliases).expect("Unable to write vk/aliases.rs");
-    write!(&mut vk_rs_file, "{} {}", vk_rs_clippy_lints, vk_rs_code)
-        .expect("Unable to write vk.rs");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::tests::{enums_registry, registry};
+
+    #[test]
+    fn write_replaces_files() {
+        let src_dir = std::env::temp_dir().join(format!("ash-generator-{}", std::process::id()));
+        let code = GeneratedCode {
+            files: vec![GeneratedFile {
+                path: "vk/enums.rs",
+                header: "// header\n",
+                code: quote!(
+                    pub struct Foo;
+                ),
+            }],
+            skipped: SkippedItems::default(),
+        };
+        code.write_to(&src_dir).unwrap();
+        let path = src_dir.join("vk/enums.rs");
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "// header\npub struct Foo ;"
+        );
+        assert!(!path.with_extension("rs.tmp").exists());
+        std::fs::remove_dir_all(src_dir).unwrap();
+    }
+
+    #[test]
+    fn render_without_writing() {
+        let ctx = Context::parse("<registry></registry>").unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        assert_eq!(code.files.len(), 17);
+        let vk_rs = code.get("vk.rs").unwrap().to_string();
+        assert!(vk_rs.starts_with("\n#![allow("));
+        assert!(vk_rs.contains("pub trait Handle"));
+        assert!(code.get("vk/meta.rs").is_some());
+    }
+
+    #[test]
+    fn handle_owners() {
+        let parents: HashMap<_, _> = vec![
+            ("VkPhysicalDevice", vec!["VkInstance"]),
+            ("VkDevice", vec!["VkPhysicalDevice"]),
+            ("VkDescriptorPool", vec!["VkDevice"]),
+            ("VkDescriptorSet", vec!["VkDescriptorPool"]),
+        ]
+        .into_iter()
+        .collect();
+        let code = generate_handle_owners(&parents).to_string();
+        assert!(code.contains("impl InstanceChild for PhysicalDevice { }"));
+        assert!(code.contains("impl InstanceChild for Device { }"));
+        assert!(code.contains("impl DeviceChild for DescriptorPool { }"));
+        assert!(code.contains("impl DeviceChild for DescriptorSet { }"));
+    }
+
+    #[test]
+    fn empty_bitmask() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type name="VkFlags" category="basetype">typedef <type>uint32_t</type> <name>VkFlags</name>;</type>
+                <type requires="VkDeviceCreateFlagBits" category="bitmask">typedef <type>VkFlags</type> <name>VkDeviceCreateFlags</name>;</type>
+            </types>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert!(definitions.contains("pub struct DeviceCreateFlags"));
+        assert!(definitions.contains("vk_bitflags_wrapped ! (DeviceCreateFlags , 0b0 , Flags)"));
+    }
+
+    #[test]
+    fn returned_struct_uninit() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type category="struct" name="VkExtent2D">
+                    <member><type>uint32_t</type> <name>width</name></member>
+                </type>
+                <type category="struct" name="VkFormatProperties" returnedonly="true">
+                    <member><type>uint32_t</type> <name>bufferFeatures</name></member>
+                </type>
+            </types>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert_eq!(definitions.matches("pub fn uninit").count(), 1);
+        assert_eq!(definitions.matches("pub const fn zeroed").count(), 1);
+        assert!(definitions.contains(
+            "impl FormatProperties { # [doc = r\" Returns this struct with all members zeroed.\"]"
+        ));
+    }
+
+    #[test]
+    fn c_str_members() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type category="struct" name="VkExtensionProperties" returnedonly="true">
+                    <member><type>char</type> <name>extensionName</name>[<enum>VK_MAX_EXTENSION_NAME_SIZE</enum>]</member>
+                    <member><type>uint32_t</type> <name>specVersion</name></member>
+                </type>
+            </types>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert!(definitions.contains(
+            "pub fn extension_name_as_c_str (& self) -> :: std :: result :: Result < & :: std :: ffi :: CStr"
+        ));
+        assert!(definitions.contains(
+            "pub fn extension_name (mut self , extension_name : & :: std :: ffi :: CStr)"
+        ));
+        assert!(!definitions.contains("spec_version_as_c_str"));
+    }
+
+    #[test]
+    fn missing_features_members() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type category="struct" name="VkPhysicalDeviceMultiviewFeatures" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+                    <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_FEATURES"><type>VkStructureType</type> <name>sType</name></member>
+                    <member><type>void</type>* <name>pNext</name></member>
+                    <member><type>VkBool32</type> <name>multiview</name></member>
+                    <member><type>VkBool32</type> <name>multiviewGeometryShader</name></member>
+                </type>
+                <type category="struct" name="VkPhysicalDeviceMultiviewProperties" returnedonly="true" structextends="VkPhysicalDeviceProperties2">
+                    <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES"><type>VkStructureType</type> <name>sType</name></member>
+                    <member><type>void</type>* <name>pNext</name></member>
+                    <member><type>VkBool32</type> <name>multiviewSupported</name></member>
+                </type>
+            </types>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert!(definitions.contains(
+            "impl PhysicalDeviceMultiviewFeatures { # [doc = r\" The names of the features"
+        ));
+        assert!(definitions.contains(
+            "if required . multiview_geometry_shader != FALSE && self . multiview_geometry_shader == FALSE { missing . push (\"multiviewGeometryShader\") ; }"
+        ));
+        assert!(!definitions.contains("self . multiview_supported"));
+    }
+
+    #[test]
+    fn push_next_checks_chain() {
+        let ctx = registry(
+            r#"<types>
+                <type category="struct" name="VkDeviceCreateInfo">
+                    <member values="VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                    <member>const <type>void</type>* <name>pNext</name></member>
+                </type>
+                <type category="struct" name="VkPhysicalDeviceFeatures2" structextends="VkDeviceCreateInfo">
+                    <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2"><type>VkStructureType</type> <name>sType</name></member>
+                    <member><type>void</type>* <name>pNext</name></member>
+                </type>
+            </types>"#,
+        );
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert!(definitions.contains(
+            "# [cfg (debug_assertions)] crate :: vk :: debug_assert_ptr_chain (\"VkDeviceCreateInfo\" , & mut self . inner) ;"
+        ));
+        let vk_rs = code.get("vk.rs").unwrap().to_string();
+        assert!(vk_rs.contains("pub (crate) unsafe fn debug_assert_ptr_chain"));
+    }
+
+    #[test]
+    fn nested_struct_builders() {
+        let ctx = registry(
+            r#"<types>
+                <type category="struct" name="VkOffset2D">
+                    <member><type>int32_t</type> <name>x</name></member>
+                </type>
+                <type category="struct" name="VkAttachmentReference">
+                    <member>const <type>void</type>* <name>pNext</name></member>
+                    <member><type>uint32_t</type> <name>attachment</name></member>
+                </type>
+                <type category="struct" name="VkSubpassDescription">
+                    <member><type>uint32_t</type> <name>colorAttachmentCount</name></member>
+                    <member len="colorAttachmentCount">const <type>VkAttachmentReference</type>* <name>pColorAttachments</name></member>
+                    <member><type>uint32_t</type> <name>offsetCount</name></member>
+                    <member len="offsetCount">const <type>VkOffset2D</type>* <name>pOffsets</name></member>
+                </type>
+                <type category="struct" name="VkRenderPassCreateInfo">
+                    <member><type>uint32_t</type> <name>subpassCount</name></member>
+                    <member len="subpassCount">const <type>VkSubpassDescription</type>* <name>pSubpasses</name></member>
+                </type>
+            </types>"#,
+        );
+        let borrowing = borrowing_struct_names(&ctx.definitions());
+        assert!(borrowing.contains("VkAttachmentReference"));
+        assert!(borrowing.contains("VkSubpassDescription"));
+        assert!(!borrowing.contains("VkOffset2D"));
+
+        let code = ctx.render(&ValidUsageMap::default());
+        let definitions = code.get("vk/definitions.rs").unwrap().to_string();
+        assert!(definitions.contains(
+            "pub fn subpasses_from_builders (mut self , subpasses : & 'a [SubpassDescriptionBuilder < 'a >]) -> RenderPassCreateInfoBuilder < 'a >"
+        ));
+        assert!(definitions.contains(
+            "self . inner . p_color_attachments = color_attachments . as_ptr () as * const AttachmentReference ;"
+        ));
+        assert!(!definitions.contains("offsets_from_builders"));
+    }
+
+    #[test]
+    fn alias_only_enum() {
+        let ctx = registry(
+            r#"<enums name="VkPointClippingBehavior" type="enum">
+                <enum value="0" name="VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES"/>
+            </enums>
+            <enums name="VkPointClippingBehaviorKHR" type="enum">
+                <enum name="VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES_KHR" alias="VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES"/>
+            </enums>"#,
+        );
+        let aliases = ctx.enum_aliases();
+        assert_eq!(aliases.len(), 1);
+        let mut const_values = BTreeMap::new();
+        let code = generate_enum_aliases(
+            "VkPointClippingBehaviorKHR",
+            &aliases["VkPointClippingBehaviorKHR"],
+            &mut HashSet::new(),
+            &mut const_values,
+        )
+        .to_string();
+        assert!(code.contains(
+            "pub const ALL_CLIP_PLANES : Self = Self (PointClippingBehavior :: ALL_CLIP_PLANES . 0)"
+        ));
+        assert!(const_values[&format_ident!("PointClippingBehaviorKHR")][0].is_alias);
+    }
+
+    #[test]
+    fn pascal_case_acronyms() {
+        let mut options = RenderOptions::default();
+        assert_eq!(
+            options.pascal_case("VK_EXT_astc_decode_mode"),
+            "VkExtAstcDecodeMode"
+        );
+        assert_eq!(
+            options.pascal_case("VK_KHR_16bit_storage"),
+            "VkKhr16bitStorage"
+        );
+        options.acronyms = vec!["ASTC".to_owned(), "UUID".to_owned()];
+        assert_eq!(
+            options.pascal_case("VK_EXT_astc_decode_mode"),
+            "VkExtASTCDecodeMode"
+        );
+        assert_eq!(options.pascal_case("device_uuid"), "DeviceUUID");
+    }
+
+    #[test]
+    fn flag_bits_names() {
+        assert_eq!(bitflags_type_name("AccessFlagBits"), "AccessFlags");
+        assert_eq!(bitflags_type_name("AccessFlagBits2"), "AccessFlags2");
+        assert_eq!(bitflags_type_name("AccessFlagBits2KHR"), "AccessFlags2KHR");
+        assert_eq!(
+            bitflags_type_name("SurfaceTransformFlagBitsKHR"),
+            "SurfaceTransformFlagsKHR"
+        );
+        assert_eq!(bitflags_type_name("FlagBitsAccessor"), "FlagBitsAccessor");
+        assert_eq!(bitflags_type_name("Format"), "Format");
+        assert_eq!(
+            variant_ident("VkAccessFlagBits2KHR", "VK_ACCESS_2_SHADER_READ_BIT_KHR"),
+            "SHADER_READ"
+        );
+        assert_eq!(
+            variant_ident(
+                "VkSurfaceTransformFlagBitsKHR",
+                "VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR"
+            ),
+            "IDENTITY"
+        );
+    }
+
+    #[test]
+    fn enum_variants_from_fixture() {
+        let variants = |ctx: Context, ty: &str| {
+            let mut const_values = BTreeMap::new();
+            generate_enum(
+                ctx.enums()[0],
+                &mut HashSet::new(),
+                &mut const_values,
+                &mut HashSet::new(),
+            );
+            const_values[&format_ident!("{}", ty)]
+                .iter()
+                .map(|info| (info.ident.to_string(), info.value))
+                .collect_vec()
+        };
+
+        let ctx = enums_registry(
+            "VkImageType",
+            "enum",
+            r#"<enum value="0" name="VK_IMAGE_TYPE_1D"/>
+            <enum value="1" name="VK_IMAGE_TYPE_2D"/>"#,
+        );
+        assert_eq!(
+            variants(ctx, "ImageType"),
+            [
+                ("TYPE_1D".to_owned(), Some(0)),
+                ("TYPE_2D".to_owned(), Some(1))
+            ]
+        );
+
+        let ctx = enums_registry(
+            "VkCullModeFlagBits",
+            "bitmask",
+            r#"<enum value="0" name="VK_CULL_MODE_NONE"/>
+            <enum bitpos="1" name="VK_CULL_MODE_BACK_BIT"/>
+            <enum value="0x00000003" name="VK_CULL_MODE_FRONT_AND_BACK"/>"#,
+        );
+        assert_eq!(
+            variants(ctx, "CullModeFlags"),
+            [
+                ("NONE".to_owned(), Some(0)),
+                ("BACK".to_owned(), Some(2)),
+                ("FRONT_AND_BACK".to_owned(), Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn slice_wrappers() {
+        let ctx = registry(
+            r#"<commands>
+                <command>
+                    <proto><type>void</type> <name>vkCmdSetViewport</name></proto>
+                    <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                    <param><type>uint32_t</type> <name>firstViewport</name></param>
+                    <param><type>uint32_t</type> <name>viewportCount</name></param>
+                    <param len="viewportCount">const <type>VkViewport</type>* <name>pViewports</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkCmdBindVertexBuffers2EXT</name></proto>
+                    <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                    <param><type>uint32_t</type> <name>bindingCount</name></param>
+                    <param len="bindingCount">const <type>VkBuffer</type>* <name>pBuffers</name></param>
+                    <param optional="true" len="bindingCount">const <type>VkDeviceSize</type>* <name>pSizes</name></param>
+                </command>
+            </commands>"#,
+        );
+        let wrapper = |name: &str, fn_name: &str| {
+            let (params, _, body) = command_wrapper(
+                ctx.commands()[name],
+                &format_ident!("{}", fn_name),
+                UnloadedCommands::PanicStub,
+            );
+            (params.to_string(), body.to_string())
+        };
+
+        let (params, body) = wrapper("vkCmdSetViewport", "cmd_set_viewport");
+        assert_eq!(
+            params,
+            "command_buffer : CommandBuffer , first_viewport : u32 , viewports : & [Viewport] ,"
+        );
+        assert!(body.contains("viewports . len () as _ , viewports . as_ptr () ,"));
+
+        // `pSizes` may be null, so both arrays keep their raw parameters
+        let (params, _) = wrapper("vkCmdBindVertexBuffers2EXT", "cmd_bind_vertex_buffers2_ext");
+        assert!(params.contains("binding_count : u32 , p_buffers : * const Buffer ,"));
+    }
+
+    #[test]
+    fn provisional_extensions() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <extensions>
+                <extension name="VK_KHR_portability_subset" number="164" type="device" supported="vulkan" provisional="true">
+                    <require>
+                        <enum value="1" name="VK_KHR_PORTABILITY_SUBSET_SPEC_VERSION"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let extensions = |provisional| {
+            let options = RenderOptions {
+                provisional,
+                ..RenderOptions::default()
+            };
+            ctx.render_with(&ValidUsageMap::default(), &options)
+                .get("vk/extensions.rs")
+                .unwrap()
+                .to_string()
+        };
+        let gated = extensions(ProvisionalExtensions::Feature);
+        assert!(
+            gated.contains("# [cfg (feature = \"provisional\")] pub struct KhrPortabilitySubsetFn")
+        );
+        let included = extensions(ProvisionalExtensions::Include);
+        assert!(included.contains("KhrPortabilitySubsetFn"));
+        assert!(!included.contains("cfg"));
+        let excluded = extensions(ProvisionalExtensions::Exclude);
+        assert!(!excluded.contains("KhrPortabilitySubsetFn"));
+    }
+
+    #[test]
+    fn success_code_wrappers() {
+        let ctx = registry(
+            r#"<commands>
+                <command successcodes="VK_SUCCESS,VK_TIMEOUT,VK_SUBOPTIMAL_KHR" errorcodes="VK_ERROR_DEVICE_LOST">
+                    <proto><type>VkResult</type> <name>vkAcquireNextImageKHR</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param><type>uint64_t</type> <name>timeout</name></param>
+                    <param><type>uint32_t</type>* <name>pImageIndex</name></param>
+                </command>
+                <command successcodes="VK_SUCCESS,VK_TIMEOUT" errorcodes="VK_ERROR_DEVICE_LOST">
+                    <proto><type>VkResult</type> <name>vkWaitForFences</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param><type>uint64_t</type> <name>timeout</name></param>
+                </command>
+                <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_DEVICE_LOST">
+                    <proto><type>VkResult</type> <name>vkQueueWaitIdle</name></proto>
+                    <param><type>VkQueue</type> <name>queue</name></param>
+                </command>
+            </commands>
+            <extensions>
+                <extension name="VK_KHR_swapchain" number="2" supported="vulkan">
+                    <require>
+                        <command name="vkAcquireNextImageKHR"/>
+                        <command name="vkWaitForFences"/>
+                        <command name="vkQueueWaitIdle"/>
+                    </require>
+                </extension>
+            </extensions>"#,
+        );
+        let success_codes = ctx.success_codes();
+        assert_eq!(
+            success_codes["vkAcquireNextImageKHR"],
+            ["VK_SUCCESS", "VK_TIMEOUT", "VK_SUBOPTIMAL_KHR"]
+        );
+        assert!(!success_codes.contains_key("vkQueueWaitIdle"));
+
+        let code = ctx.render(&ValidUsageMap::default());
+        let extensions = code.get("vk/extensions.rs").unwrap().to_string();
+        assert!(extensions.contains("pub unsafe fn acquire_next_image_khr_with_success_code (& self , device : Device , timeout : u64 ,) -> :: std :: result :: Result < (u32 , crate :: vk :: SuccessCode) , Result > { let mut image_index = :: std :: mem :: MaybeUninit :: < u32 > :: zeroed () ; let result = self . acquire_next_image_khr (device , timeout , image_index . as_mut_ptr ()) ; result . into_codes () . map (| code | (image_index . assume_init () , code)) . map_err (Result :: from) }"));
+        assert!(extensions.contains("pub unsafe fn wait_for_fences_with_success_code (& self , device : Device , timeout : u64 ,) -> :: std :: result :: Result < crate :: vk :: SuccessCode , Result > { self . wait_for_fences (device , timeout) . into_codes () . map_err (Result :: from) }"));
+        assert!(!extensions.contains("queue_wait_idle_with_success_code"));
+    }
+
+    #[test]
+    fn unloaded_commands() {
+        let ctx = registry(
+            r#"<commands>
+                <command>
+                    <proto><type>void</type> <name>vkCmdSetLineStippleEXT</name></proto>
+                    <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                    <param><type>uint32_t</type> <name>lineStippleFactor</name></param>
+                </command>
+            </commands>
+            <extensions>
+                <extension name="VK_EXT_line_rasterization" number="260" supported="vulkan">
+                    <require><command name="vkCmdSetLineStippleEXT"/></require>
+                </extension>
+            </extensions>"#,
+        );
+        let extensions = |unloaded_commands| {
+            let options = RenderOptions {
+                unloaded_commands,
+                ..RenderOptions::default()
+            };
+            ctx.render_with(&ValidUsageMap::default(), &options)
+                .get("vk/extensions.rs")
+                .unwrap()
+                .to_string()
+        };
+        let stub = extensions(UnloadedCommands::PanicStub);
+        assert!(stub.contains("pub cmd_set_line_stipple_ext : extern \"system\" fn"));
+        assert!(stub.contains(
+            "panic ! (concat ! (\"Unable to load \" , stringify ! (cmd_set_line_stipple_ext)))"
+        ));
+        let option = extensions(UnloadedCommands::Option);
+        assert!(option.contains("pub cmd_set_line_stipple_ext : Option < extern \"system\" fn"));
+        assert!(option.contains("Some (:: std :: mem :: transmute (val))"));
+        assert!(option.contains("(self . cmd_set_line_stipple_ext . expect (concat ! (\"Unable to load \" , stringify ! (cmd_set_line_stipple_ext)))) (command_buffer , line_stipple_factor ,)"));
+        assert!(!option.contains("panic !"));
+    }
+
+    #[test]
+    fn unknown_enum_values() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <enums name="VkFilter" type="enum">
+                <enum value="0" name="VK_FILTER_NEAREST"/>
+                <enum value="1" name="VK_FILTER_LINEAR"/>
+            </enums>
+            <enums name="VkIndexType" type="enum">
+                <enum value="0" name="VK_INDEX_TYPE_UINT16"/>
+            </enums>
+        </registry>"#,
+        )
+        .unwrap();
+        let enums = |strategy| {
+            let mut options = RenderOptions::default();
+            options
+                .unknown_enum_values
+                .insert("VkFilter".to_owned(), strategy);
+            ctx.render_with(&ValidUsageMap::default(), &options)
+                .get("vk/enums.rs")
+                .unwrap()
+                .to_string()
+        };
+        let kept = enums(UnknownEnumValues::Keep);
+        assert!(!kept.contains("is_known"));
+        assert!(!kept.contains("UnknownEnumValue"));
+        let try_from = enums(UnknownEnumValues::TryFrom);
+        assert!(try_from.contains("Self :: NEAREST | Self :: LINEAR => true"));
+        assert!(try_from.contains("impl :: std :: convert :: TryFrom < i32 > for Filter"));
+        // `vk::Result` is in scope in `vk/enums.rs`, so the conversion has to name std's `Result`
+        assert!(try_from.contains(
+            "fn try_from (value : i32) -> :: std :: result :: Result < Self , Self :: Error >"
+        ));
+        assert!(!try_from.contains("for IndexType"));
+        let unknown = enums(UnknownEnumValues::Unknown);
+        assert!(unknown.contains("impl From < Filter > for CheckedEnum < Filter >"));
+    }
+
+    #[test]
+    fn report_skipped_items() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <extensions>
+                <extension name="VK_EXT_foo" number="1" supported="vulkan">
+                    <require>
+                        <enum value="1" name="VK_EXT_FOO_SPEC_VERSION"/>
+                        <enum value="(~0U)" extends="VkStructureType" name="VK_STRUCTURE_TYPE_FOO_EXT"/>
+                        <enum offset="0" extnumber="3000000" extends="VkStructureType" name="VK_STRUCTURE_TYPE_BAR_EXT"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let mut const_values = BTreeMap::new();
+        const_values.insert(format_ident!("StructureType"), Vec::new());
+        let mut skipped = SkippedItems::default();
+        let code = generate_extension_constants(
+            "VK_EXT_foo",
+            1,
+            &ctx.extensions()[0].children,
+            &mut HashSet::new(),
+            &mut const_values,
+            &mut skipped,
+        );
+        assert!(code.is_empty());
+        assert_eq!(
+            skipped
+                .get("enum values that aren't integers")
+                .collect_vec(),
+            ["VK_STRUCTURE_TYPE_FOO_EXT"]
+        );
+        skipped.insert("includes", "vk_platform");
+        assert_eq!(
+            skipped.to_string(),
+            "Skipped 1 enum values that aren't integers: VK_STRUCTURE_TYPE_FOO_EXT\n\
+             Skipped 1 enum values that don't fit in an i32: VK_STRUCTURE_TYPE_BAR_EXT\n\
+             Skipped 1 includes: vk_platform"
+        );
+    }
+
+    #[test]
+    fn platform_types() {
+        let code = generate_platform(&HashMap::new()).to_string();
+        assert!(code.contains("pub type size_t = usize ;"));
+        assert!(code.contains("pub type c_char = :: std :: os :: raw :: c_char ;"));
+        let mut overrides = HashMap::new();
+        overrides.insert("size_t".to_owned(), "u32".to_owned());
+        let code = generate_platform(&overrides).to_string();
+        assert!(code.contains("pub type size_t = u32 ;"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`uint32_t` isn't a platform type that can be overridden")]
+    fn fixed_width_platform_types() {
+        let mut overrides = HashMap::new();
+        overrides.insert("uint32_t".to_owned(), "u64".to_owned());
+        generate_platform(&overrides);
+    }
+
+    #[test]
+    fn native_enums_module() {
+        let ctx = Context::parse("<registry></registry>").unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        assert!(code.get("vk/native.rs").is_none());
+        let options = RenderOptions {
+            enum_representation: EnumRepresentation::OpenWithNative(NonExhaustive::Attribute),
+            ..RenderOptions::default()
+        };
+        let code = ctx.render_with(&ValidUsageMap::default(), &options);
+        assert!(code.get("vk/native.rs").is_some());
+        let vk_rs = code.get("vk.rs").unwrap().to_string();
+        assert!(vk_rs.contains("pub mod native ;"));
+    }
+
+    #[test]
+    fn split_loading() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <commands>
+                <command>
+                    <proto><type>void</type> <name>vkGetPhysicalDeviceFooEXT</name></proto>
+                    <param><type>VkPhysicalDevice</type> <name>physicalDevice</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkCmdFooEXT</name></proto>
+                    <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                </command>
+            </commands>
+            <extensions>
+                <extension name="VK_EXT_foo" number="1000" type="device" supported="vulkan">
+                    <require>
+                        <enum value="1" name="VK_EXT_FOO_SPEC_VERSION"/>
+                        <command name="vkGetPhysicalDeviceFooEXT"/>
+                        <command name="vkCmdFooEXT"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = ctx.render(&ValidUsageMap::default());
+        let extensions = code.get("vk/extensions.rs").unwrap().to_string();
+        assert!(extensions.contains("b\"vkCmdFooEXT\" => load_device (name)"));
+        assert!(!extensions.contains("b\"vkGetPhysicalDeviceFooEXT\""));
+    }
+
+    #[test]
+    fn int_literals() {
+        let literal = |constant: Constant, ty| constant.int_literal(ty).to_string();
+        assert_eq!(literal(Constant::Number(1000001004), None), "1_000_001_004");
+        assert_eq!(literal(Constant::Number(-100000), None), "-100_000");
+        assert_eq!(
+            literal(Constant::Hex("7FFFFFFF".to_owned()), Some(IntType::I32)),
+            "0x7FFF_FFFF"
+        );
+        assert_eq!(
+            literal(Constant::Hex("FFFFFFFF".to_owned()), Some(IntType::I32)),
+            "-1"
+        );
+        assert_eq!(
+            literal(Constant::BitPos(31), Some(IntType::U32)),
+            "0b1000_0000_0000_0000_0000_0000_0000_0000"
+        );
+        assert_eq!(
+            literal(Constant::BitPos(31), Some(IntType::I32)),
+            "-2_147_483_648"
+        );
+        assert_eq!(
+            literal(Constant::BitPos(40), Some(IntType::U64)),
+            "0b1_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000"
+        );
+    }
+
+    #[test]
+    fn large_enum_name_table() {
+        let mut const_values = BTreeMap::new();
+        let values = (0..=NAME_TABLE_MIN_VALUES as i128)
+            .rev()
+            .map(|value| ConstantMatchInfo {
+                ident: format_ident!("VALUE_{}", value),
+                is_alias: false,
+                value: Some(value),
+            })
+            .collect();
+        const_values.insert(format_ident!("StructureType"), values);
+        const_values.insert(
+            format_ident!("Filter"),
+            vec![ConstantMatchInfo {
+                ident: format_ident!("NEAREST"),
+                is_alias: false,
+                value: Some(0),
+            }],
+        );
+        let code = generate_const_debugs(&const_values).to_string();
+        assert!(code.contains(
+            "& [(StructureType :: VALUE_0 . 0 , \"VALUE_0\") , (StructureType :: VALUE_1 . 0 , \"VALUE_1\")"
+        ));
+        assert!(code.contains("debug_enum (f , KNOWN , self . 0)"));
+        assert!(code.contains("Self :: NEAREST => Some (\"NEAREST\")"));
+    }
+
+    #[test]
+    fn provided_by_notes() {
+        assert_eq!(provided_by("VK_VERSION_1_2"), "Provided by Vulkan 1.2.");
+        assert_eq!(
+            provided_by("VK_KHR_swapchain"),
+            "Provided by `VK_KHR_swapchain`."
+        );
+    }
+
+    #[test]
+    fn duplicate_extension_values_become_aliases() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <extensions>
+                <extension name="VK_KHR_a" number="1" supported="vulkan">
+                    <require>
+                        <enum value="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_A_KHR"/>
+                        <enum value="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_APPLICATION_INFO"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let mut const_values = BTreeMap::new();
+        const_values.insert(
+            format_ident!("StructureType"),
+            vec![ConstantMatchInfo {
+                ident: format_ident!("APPLICATION_INFO"),
+                is_alias: false,
+                value: Some(0),
+            }],
+        );
+        let code = generate_extension_constants(
+            "VK_KHR_a",
+            1,
+            &ctx.extensions()[0].children,
+            &mut HashSet::new(),
+            &mut const_values,
+            &mut SkippedItems::default(),
+        )
+        .to_string();
+        assert!(code.contains("pub const A_KHR : Self = StructureType :: APPLICATION_INFO"));
+        assert!(code
+            .contains("# [doc = \"Provided by `VK_KHR_a`.\"] # [doc = \"\"] # [doc = \"Alias of"));
+        assert_eq!(code.matches("APPLICATION_INFO :").count(), 0);
+        let values = &const_values[&format_ident!("StructureType")];
+        assert_eq!(values.len(), 2);
+        assert!(values[1].is_alias);
+    }
 }
@@ -0,0 +1,486 @@
+//! Generates `vk::meta`, const tables describing which core version or extension provides what,
+//! so that tools can introspect the API at runtime without parsing `vk.xml`.
+use crate::{structure_type_value, variant_ident, Context};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+/// The `queues` attribute values and the matching `VkQueueFlagBits`, in the order of their bits.
+const QUEUES: &[(&str, &str)] = &[
+    ("graphics", "GRAPHICS"),
+    ("compute", "COMPUTE"),
+    ("transfer", "TRANSFER"),
+    ("sparse_binding", "SPARSE_BINDING"),
+];
+
+/// A table sorted by name, which `lookup` in the generated code depends on.
+fn table(entries: BTreeMap<&str, Vec<&str>>) -> TokenStream {
+    let entries = entries.iter().map(|(name, values)| {
+        quote! { (#name, &[#(#values),*]) }
+    });
+    quote! { &[#(#entries),*] }
+}
+
+/// The `CommandInfo` of a command from its `queues`, `cmdbufferlevel` and `renderpass`
+/// attributes, `None` if it has none of them.
+fn command_info(def: &vk_parse::CommandDefinition) -> Option<TokenStream> {
+    if def.queues.is_none() && def.cmdbufferlevel.is_none() && def.renderpass.is_none() {
+        return None;
+    }
+    let queues: Vec<&str> = def
+        .queues
+        .as_deref()
+        .map(|queues| queues.split(',').collect())
+        .unwrap_or_default();
+    if let Some(unknown) = queues
+        .iter()
+        .find(|queue| !QUEUES.iter().any(|(name, _)| name == *queue))
+    {
+        panic!("Unknown queue `{}` of {}", unknown, def.proto.name);
+    }
+    let flags: Vec<_> = QUEUES
+        .iter()
+        .filter(|(name, _)| queues.contains(name))
+        .map(|(_, flag)| format_ident!("{}", flag))
+        .collect();
+    let queues = match flags.as_slice() {
+        [] => quote!(QueueFlags::empty()),
+        [flag] => quote!(QueueFlags::#flag),
+        flags => quote!(QueueFlags::from_raw(#(QueueFlags::#flags.as_raw())|*)),
+    };
+    let levels = def
+        .cmdbufferlevel
+        .iter()
+        .flat_map(|levels| levels.split(','))
+        .map(|level| format_ident!("{}", level.to_uppercase()));
+    let render_pass = match def.renderpass.as_deref() {
+        None => quote!(None),
+        Some("inside") => quote!(Some(RenderPassScope::Inside)),
+        Some("outside") => quote!(Some(RenderPassScope::Outside)),
+        Some("both") => quote!(Some(RenderPassScope::Both)),
+        Some(scope) => panic!(
+            "Unknown render pass scope `{}` of {}",
+            scope, def.proto.name
+        ),
+    };
+    Some(quote! {
+        CommandInfo {
+            queues: #queues,
+            command_buffer_levels: &[#(CommandBufferLevel::#levels),*],
+            render_pass: #render_pass,
+        }
+    })
+}
+
+/// The core version, e.g. `(1, 2)`, that an extension was promoted to, following promotions to
+/// other extensions such as `VK_AMD_draw_indirect_count` to `VK_KHR_draw_indirect_count`.
+fn promoted_version(promotions: &HashMap<&str, &str>, extension: &str) -> Option<(u32, u32)> {
+    let mut name = extension;
+    // Bounded, in case of a promotion cycle in a broken registry
+    for _ in 0..=promotions.len() {
+        name = promotions.get(name)?;
+        if let Some(version) = name.strip_prefix("VK_VERSION_") {
+            let mut parts = version.splitn(2, '_');
+            return Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?));
+        }
+    }
+    None
+}
+
+pub fn generate_meta(ctx: &Context) -> TokenStream {
+    let mut command_providers: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let features = ctx
+        .core_features()
+        .map(|feature| (feature.name.as_str(), feature.children.as_slice()));
+    let extensions = ctx
+        .extensions()
+        .iter()
+        .filter(|ext| ext.supported.as_deref() != Some("disabled"))
+        .map(|ext| (ext.name.as_str(), ext.children.as_slice()));
+    for (provided_by, children) in features.chain(extensions) {
+        let commands = children
+            .iter()
+            .filter_map(|child| match child {
+                vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                vk_parse::InterfaceItem::Command { name, .. } => Some(name.as_str()),
+                _ => None,
+            });
+        for command in commands {
+            let providers = command_providers.entry(command).or_default();
+            if !providers.contains(&provided_by) {
+                providers.push(provided_by);
+            }
+        }
+    }
+
+    let struct_extends: BTreeMap<&str, Vec<&str>> = ctx
+        .definitions()
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Struct(s) => {
+                let extends = s.extends.as_ref()?;
+                Some((s.name.as_str(), extends.split(',').collect()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let structure_types: BTreeMap<&str, &str> = ctx
+        .definitions()
+        .into_iter()
+        .filter_map(|def| match def {
+            vkxml::DefinitionsElement::Struct(s) => {
+                Some((s.name.as_str(), structure_type_value(s)?))
+            }
+            _ => None,
+        })
+        .collect();
+    let structure_types = structure_types.iter().map(|(name, value)| {
+        let variant = variant_ident("VkStructureType", value);
+        quote! { (#name, StructureType::#variant) }
+    });
+
+    let mut enum_providers: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for ext_enum in ctx.extension_enums() {
+        let providers = enum_providers.entry(ext_enum.name).or_default();
+        if !providers.contains(&ext_enum.provided_by) {
+            providers.push(ext_enum.provided_by);
+        }
+    }
+
+    let command_aliases = ctx.command_aliases();
+    let mut command_infos: BTreeMap<&str, TokenStream> = ctx
+        .command_definitions()
+        .filter_map(|def| Some((def.proto.name.as_str(), command_info(def)?)))
+        .collect();
+    for (alias, name) in &command_aliases {
+        if let Some(info) = command_infos.get(name.as_str()).cloned() {
+            command_infos.insert(alias.as_str(), info);
+        }
+    }
+    let command_infos = command_infos
+        .iter()
+        .map(|(name, info)| quote! { (#name, #info) });
+
+    let promotions: HashMap<&str, &str> = ctx
+        .extensions()
+        .iter()
+        .filter_map(|ext| Some((ext.name.as_str(), ext.promotedto.as_deref()?)))
+        .collect();
+    let promoted: BTreeMap<&str, (u32, u32)> = promotions
+        .keys()
+        .filter_map(|&name| Some((name, promoted_version(&promotions, name)?)))
+        .collect();
+    let promoted = promoted.iter().map(|(name, &(major, minor))| {
+        let major = Literal::u32_unsuffixed(major);
+        let minor = Literal::u32_unsuffixed(minor);
+        quote! { (#name, make_version(#major, #minor, 0)) }
+    });
+
+    let command_providers = table(command_providers);
+    let struct_extends = table(struct_extends);
+    let enum_providers = table(enum_providers);
+    let extern_sync_params = table(ctx.extern_sync_params().into_iter().collect());
+    quote! {
+        use crate::vk::bitflags::QueueFlags;
+        use crate::vk::enums::{CommandBufferLevel, StructureType};
+        use crate::vk::macros::{make_version, version_major, version_minor};
+
+        /// The core versions and extensions that provide a command, e.g.
+        /// `("vkCreateSwapchainKHR", &["VK_KHR_swapchain"])`. Sorted by command name.
+        pub const COMMAND_PROVIDERS: &[(&str, &[&str])] = #command_providers;
+
+        /// The structs that a struct can be chained to with `p_next`, e.g.
+        /// `("VkPhysicalDeviceVulkan12Features", &["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"])`.
+        /// Sorted by struct name.
+        pub const STRUCT_EXTENDS: &[(&str, &[&str])] = #struct_extends;
+
+        /// The `s_type` of the structs that have a fixed one, e.g.
+        /// `("VkDeviceCreateInfo", StructureType::DEVICE_CREATE_INFO)`. Sorted by struct name.
+        pub const STRUCTURE_TYPES: &[(&str, StructureType)] = &[#(#structure_types),*];
+
+        /// The core versions and extensions that add a value to an existing enum, e.g.
+        /// `("VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR", &["VK_KHR_swapchain"])`. Sorted by the
+        /// name of the value.
+        pub const ENUM_VALUE_PROVIDERS: &[(&str, &[&str])] = #enum_providers;
+
+        /// The parameters of a command that the application has to synchronize externally, or
+        /// members of them like `pBindInfo[].pBufferBinds[].buffer`, e.g.
+        /// `("vkQueueSubmit", &["queue", "fence"])`. Sorted by command name.
+        pub const EXTERN_SYNC_PARAMS: &[(&str, &[&str])] = #extern_sync_params;
+
+        /// Whether a command is recorded inside or outside of a render pass instance.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum RenderPassScope {
+            Inside,
+            Outside,
+            Both,
+        }
+
+        /// Where a command can be used, from the `queues`, `cmdbufferlevel` and `renderpass`
+        /// attributes of the registry.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct CommandInfo {
+            /// The queues that support the command, empty if the registry doesn't restrict them.
+            pub queues: QueueFlags,
+            /// The levels of command buffer that the command can be recorded in, empty for
+            /// commands that aren't recorded.
+            pub command_buffer_levels: &'static [CommandBufferLevel],
+            /// Whether the command is recorded inside or outside of a render pass instance, `None`
+            /// for commands that aren't recorded.
+            pub render_pass: Option<RenderPassScope>,
+        }
+
+        impl CommandInfo {
+            /// Returns whether a queue of a family with `queue_flags` supports the command.
+            pub fn supports_queue(&self, queue_flags: QueueFlags) -> bool {
+                self.queues.is_empty() || queue_flags.intersects(self.queues)
+            }
+
+            /// Returns whether the command can be recorded in a command buffer of `level`.
+            pub fn supports_level(&self, level: CommandBufferLevel) -> bool {
+                self.command_buffer_levels.is_empty() || self.command_buffer_levels.contains(&level)
+            }
+
+            /// Returns whether the command can be recorded while a render pass instance is active,
+            /// if `inside_render_pass`, or while none is.
+            pub fn supports_render_pass(&self, inside_render_pass: bool) -> bool {
+                match self.render_pass {
+                    Some(RenderPassScope::Inside) => inside_render_pass,
+                    Some(RenderPassScope::Outside) => !inside_render_pass,
+                    Some(RenderPassScope::Both) | None => true,
+                }
+            }
+        }
+
+        /// The [`CommandInfo`] of the commands that have one, including aliases, e.g.
+        /// `("vkCmdDispatch", CommandInfo { queues: QueueFlags::COMPUTE, .. })`. Sorted by command
+        /// name.
+        pub const COMMAND_INFOS: &[(&str, CommandInfo)] = &[#(#command_infos),*];
+
+        /// The extensions that were promoted to a core version, and the version, e.g.
+        /// `("VK_KHR_maintenance1", make_version(1, 1, 0))`. Sorted by extension name.
+        pub const PROMOTED_EXTENSIONS: &[(&str, u32)] = &[#(#promoted),*];
+
+        fn lookup(table: &'static [(&str, &'static [&'static str])], name: &str) -> &'static [&'static str] {
+            match table.binary_search_by_key(&name, |&(key, _)| key) {
+                Ok(i) => table[i].1,
+                Err(_) => &[],
+            }
+        }
+
+        /// Returns the core versions and extensions that provide the command `name`, e.g.
+        /// `vkCmdDrawIndirectCount`.
+        pub fn command_providers(name: &str) -> &'static [&'static str] {
+            lookup(COMMAND_PROVIDERS, name)
+        }
+
+        /// Returns the structs that the struct `name`, e.g. `VkPhysicalDeviceVulkan12Features`, can
+        /// be chained to.
+        pub fn struct_extends(name: &str) -> &'static [&'static str] {
+            lookup(STRUCT_EXTENDS, name)
+        }
+
+        /// Returns the struct whose `s_type` is fixed to `s_type`, e.g. `VkDeviceCreateInfo` for
+        /// `StructureType::DEVICE_CREATE_INFO`.
+        pub fn structure_type_struct(s_type: StructureType) -> Option<&'static str> {
+            STRUCTURE_TYPES
+                .iter()
+                .find(|&&(_, ty)| ty == s_type)
+                .map(|&(name, _)| name)
+        }
+
+        /// Returns the core versions and extensions that add the enum value `name`, e.g.
+        /// `VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR`. Values of the original enum are not
+        /// listed.
+        pub fn enum_value_providers(name: &str) -> &'static [&'static str] {
+            lookup(ENUM_VALUE_PROVIDERS, name)
+        }
+
+        /// Returns the parameters of the command `name` that have to be synchronized externally,
+        /// e.g. `commandPool` of `vkResetCommandPool`.
+        pub fn extern_sync_params(name: &str) -> &'static [&'static str] {
+            lookup(EXTERN_SYNC_PARAMS, name)
+        }
+
+        /// Returns the [`CommandInfo`] of the command `name`, e.g. `vkCmdDraw`, `None` for commands
+        /// that the registry doesn't restrict.
+        pub fn command_info(name: &str) -> Option<&'static CommandInfo> {
+            COMMAND_INFOS
+                .binary_search_by_key(&name, |&(key, _)| key)
+                .ok()
+                .map(|i| &COMMAND_INFOS[i].1)
+        }
+
+        /// Returns the core version that the extension `name` was promoted to, e.g.
+        /// `make_version(1, 1, 0)` for `VK_KHR_maintenance1`.
+        pub fn promoted_to(name: &str) -> Option<u32> {
+            PROMOTED_EXTENSIONS
+                .binary_search_by_key(&name, |&(key, _)| key)
+                .ok()
+                .map(|i| PROMOTED_EXTENSIONS[i].1)
+        }
+
+        /// Splits `extensions` into the ones that still have to be enabled with `api_version`, and
+        /// the ones that are part of the core API of `api_version`. Promoted functionality can
+        /// still be optional in core, e.g. `drawIndirectCount` of `VK_KHR_draw_indirect_count`,
+        /// and has to be enabled through the features of the core version.
+        pub fn split_promoted_extensions<'a>(
+            api_version: u32,
+            extensions: &[&'a str],
+        ) -> (Vec<&'a str>, Vec<&'a str>) {
+            let version = |version: u32| (version_major(version), version_minor(version));
+            extensions.iter().copied().partition(|&name| {
+                promoted_to(name).is_none_or(|promoted| version(promoted) > version(api_version))
+            })
+        }
+
+        /// Panics in debug builds if the command `name` can't be recorded in a command buffer of
+        /// `level`, allocated from a pool of a queue family with `queue_flags`. `ash` doesn't know
+        /// which queue family a command buffer belongs to, so wrappers that track it can call this
+        /// before recording, to catch e.g. draws recorded for a compute-only queue.
+        pub fn debug_assert_command(name: &str, queue_flags: QueueFlags, level: CommandBufferLevel) {
+            if cfg!(debug_assertions) {
+                if let Some(info) = command_info(name) {
+                    assert!(
+                        info.supports_queue(queue_flags),
+                        "{} requires a queue with {:?}, but the command buffer is for {:?}",
+                        name,
+                        info.queues,
+                        queue_flags
+                    );
+                    assert!(
+                        info.supports_level(level),
+                        "{} can't be recorded in a {:?} command buffer",
+                        name,
+                        level
+                    );
+                }
+            }
+        }
+
+        /// Panics in debug builds if the command `name` can't be recorded while a render pass
+        /// instance is active, if `inside_render_pass`, or while none is. Like
+        /// [`debug_assert_command`] this is meant for wrappers that track the render pass scope of
+        /// their command buffers.
+        pub fn debug_assert_render_pass(name: &str, inside_render_pass: bool) {
+            if cfg!(debug_assertions) {
+                if let Some(info) = command_info(name) {
+                    assert!(
+                        info.supports_render_pass(inside_render_pass),
+                        "{} must be recorded {} a render pass instance",
+                        name,
+                        if inside_render_pass { "outside" } else { "inside" }
+                    );
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_infos() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <commands>
+                <command queues="graphics,compute" renderpass="outside" cmdbufferlevel="primary,secondary">
+                    <proto><type>void</type> <name>vkCmdDispatchBase</name></proto>
+                    <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+                </command>
+                <command name="vkCmdDispatchBaseKHR" alias="vkCmdDispatchBase"/>
+                <command>
+                    <proto><type>void</type> <name>vkTrimCommandPool</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                </command>
+            </commands>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = generate_meta(&ctx).to_string();
+        let info = "CommandInfo { queues : QueueFlags :: from_raw (QueueFlags :: GRAPHICS . as_raw () | QueueFlags :: COMPUTE . as_raw ()) , command_buffer_levels : & [CommandBufferLevel :: PRIMARY , CommandBufferLevel :: SECONDARY] , render_pass : Some (RenderPassScope :: Outside) , }";
+        assert!(code.contains(&format!("(\"vkCmdDispatchBase\" , {})", info)));
+        assert!(code.contains(&format!("(\"vkCmdDispatchBaseKHR\" , {})", info)));
+        assert!(!code.contains("(\"vkTrimCommandPool\" , CommandInfo"));
+    }
+
+    #[test]
+    fn extern_sync_params() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <commands>
+                <command>
+                    <proto><type>VkResult</type> <name>vkQueueBindSparse</name></proto>
+                    <param externsync="true"><type>VkQueue</type> <name>queue</name></param>
+                    <param><type>uint32_t</type> <name>bindInfoCount</name></param>
+                    <param len="bindInfoCount" externsync="pBindInfo[].pBufferBinds[].buffer,pBindInfo[].pImageBinds[].image">const <type>VkBindSparseInfo</type>* <name>pBindInfo</name></param>
+                    <param optional="true" externsync="true"><type>VkFence</type> <name>fence</name></param>
+                </command>
+                <command name="vkQueueBindSparseKHR" alias="vkQueueBindSparse"/>
+                <command>
+                    <proto><type>void</type> <name>vkGetDeviceQueue</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                </command>
+            </commands>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = generate_meta(&ctx).to_string();
+        let params = "& [\"queue\" , \"pBindInfo[].pBufferBinds[].buffer\" , \"pBindInfo[].pImageBinds[].image\" , \"fence\"]";
+        assert!(code.contains(&format!("(\"vkQueueBindSparse\" , {})", params)));
+        assert!(code.contains(&format!("(\"vkQueueBindSparseKHR\" , {})", params)));
+        assert!(!code.contains("(\"vkGetDeviceQueue\" , & ["));
+    }
+
+    #[test]
+    fn structure_types() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type category="struct" name="VkDeviceCreateInfo">
+                    <member values="VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+                    <member>const <type>void</type>* <name>pNext</name></member>
+                </type>
+                <type category="struct" name="VkBaseOutStructure">
+                    <member><type>VkStructureType</type> <name>sType</name></member>
+                    <member>struct <type>VkBaseOutStructure</type>* <name>pNext</name></member>
+                </type>
+            </types>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = generate_meta(&ctx).to_string();
+        assert!(code.contains(
+            "STRUCTURE_TYPES : & [(& str , StructureType)] = & [(\"VkDeviceCreateInfo\" , StructureType :: DEVICE_CREATE_INFO)] ;"
+        ));
+    }
+
+    #[test]
+    fn promoted_extensions() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <extensions>
+                <extension name="VK_KHR_draw_indirect_count" number="170" supported="vulkan" promotedto="VK_VERSION_1_2"/>
+                <extension name="VK_AMD_draw_indirect_count" number="34" supported="vulkan" promotedto="VK_KHR_draw_indirect_count"/>
+                <extension name="VK_EXT_debug_marker" number="23" supported="vulkan" promotedto="VK_EXT_debug_utils"/>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let code = generate_meta(&ctx).to_string();
+        assert!(code.contains(
+            "& [(\"VK_AMD_draw_indirect_count\" , make_version (1 , 2 , 0)) , (\"VK_KHR_draw_indirect_count\" , make_version (1 , 2 , 0))]"
+        ));
+        assert!(!code.contains("(\"VK_EXT_debug_marker\" , make_version"));
+    }
+}
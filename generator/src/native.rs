@@ -0,0 +1,126 @@
+//! Generates `vk::native`, a Rust `enum` for every enum of the registry. The FFI types in `vk` stay
+//! newtypes over `i32`, as drivers can return values that aren't in the registry, and the native
+//! enums are converted from and to them. The native enums are kept open for values of later
+//! versions of the registry, so that matching on them needs a wildcard arm and updating the
+//! registry isn't a breaking change.
+use crate::{ConstantMatchInfo, NonExhaustive};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, HashSet};
+use syn::Ident;
+
+fn native_enum(
+    ty: &Ident,
+    values: &[ConstantMatchInfo],
+    non_exhaustive: NonExhaustive,
+) -> TokenStream {
+    let mut seen = HashSet::new();
+    // Aliases and values that are expressions are left out, as are values that are already in
+    // the enum under another name, which a native enum can't have twice
+    let variants: Vec<_> = values
+        .iter()
+        .filter(|info| !info.is_alias)
+        .filter_map(|info| Some((&info.ident, info.value?)))
+        .filter(|&(_, value)| seen.insert(value))
+        .collect();
+    let (attribute, hidden_variant) = match non_exhaustive {
+        NonExhaustive::Attribute => (quote!(#[non_exhaustive]), quote!()),
+        NonExhaustive::HiddenVariant => (
+            quote!(),
+            quote! {
+                #[doc(hidden)]
+                __MaxEnum = 0x7FFF_FFFF,
+            },
+        ),
+    };
+    let discriminants = variants.iter().map(|&(ident, value)| {
+        let value = Literal::i32_unsuffixed(value as i32);
+        quote!(#ident = #value,)
+    });
+    let conversions = variants
+        .iter()
+        .map(|&(ident, _)| quote!(crate::vk::#ty::#ident => Ok(#ty::#ident),));
+    quote! {
+        #[repr(i32)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        #[allow(non_camel_case_types)]
+        #attribute
+        pub enum #ty {
+            #(#discriminants)*
+            #hidden_variant
+        }
+
+        impl From<#ty> for crate::vk::#ty {
+            fn from(value: #ty) -> Self {
+                crate::vk::#ty::from_raw(value as i32)
+            }
+        }
+
+        impl TryFrom<crate::vk::#ty> for #ty {
+            /// The value, which isn't in the registry these bindings were generated from.
+            type Error = crate::vk::#ty;
+            fn try_from(value: crate::vk::#ty) -> ::std::result::Result<Self, Self::Error> {
+                match value {
+                    #(#conversions)*
+                    _ => Err(value),
+                }
+            }
+        }
+    }
+}
+
+pub fn generate_native_enums(
+    const_values: &BTreeMap<Ident, Vec<ConstantMatchInfo>>,
+    non_exhaustive: NonExhaustive,
+) -> TokenStream {
+    let enums = const_values
+        .iter()
+        .filter(|(ty, values)| {
+            !ty.to_string().contains("Flags") && values.iter().any(|info| info.value.is_some())
+        })
+        .map(|(ty, values)| native_enum(ty, values, non_exhaustive));
+    quote! {
+        use std::convert::TryFrom;
+
+        #(#enums)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::format_ident;
+
+    #[test]
+    fn native_enums() {
+        let info = |ident, is_alias, value| ConstantMatchInfo {
+            ident: format_ident!("{}", ident),
+            is_alias,
+            value,
+        };
+        let mut const_values = BTreeMap::new();
+        const_values.insert(
+            format_ident!("Filter"),
+            vec![
+                info("NEAREST", false, Some(0)),
+                info("LINEAR", false, Some(1)),
+                info("CUBIC_IMG", false, Some(1_000_015_000)),
+                info("CUBIC_EXT", true, None),
+            ],
+        );
+        const_values.insert(
+            format_ident!("QueueFlags"),
+            vec![info("GRAPHICS", false, Some(1))],
+        );
+
+        let code = generate_native_enums(&const_values, NonExhaustive::Attribute).to_string();
+        assert!(code.contains("# [non_exhaustive] pub enum Filter { NEAREST = 0 , LINEAR = 1 , CUBIC_IMG = 1000015000 , }"));
+        assert!(code.contains("crate :: vk :: Filter :: LINEAR => Ok (Filter :: LINEAR) ,"));
+        assert!(!code.contains("CUBIC_EXT"));
+        assert!(!code.contains("QueueFlags"));
+
+        let code = generate_native_enums(&const_values, NonExhaustive::HiddenVariant).to_string();
+        assert!(!code.contains("non_exhaustive"));
+        assert!(code.contains("# [doc (hidden)] __MaxEnum = 0x7FFF_FFFF ,"));
+    }
+}
@@ -0,0 +1,208 @@
+//! Generates the `owned` module of ash, with a wrapper for every handle that a core version
+//! destroys with `vkDestroy*(device, handle, pAllocator)` or `vkFreeMemory`.
+use crate::{name_to_tokens, Context};
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeSet;
+
+/// A handle that is destroyed on its own with the device and allocation callbacks.
+#[derive(Debug, PartialEq, Eq)]
+struct OwnedHandle<'a> {
+    handle: &'a str,
+    command: &'a str,
+    /// The `DeviceV1_x` trait that the command is a method of.
+    version_trait: String,
+}
+
+fn owned_handles(ctx: &Context) -> Vec<OwnedHandle<'_>> {
+    let commands: Vec<_> = ctx.command_definitions().collect();
+    let mut handles: Vec<_> = ctx
+        .core_features()
+        .flat_map(|feature| {
+            let version_trait = feature.name.replace("VK_VERSION_", "DeviceV");
+            feature
+                .children
+                .iter()
+                .filter_map(|child| match child {
+                    vk_parse::ExtensionChild::Require { items, .. } => Some(items.iter()),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|item| match item {
+                    vk_parse::InterfaceItem::Command { name, .. } => Some(name.as_str()),
+                    _ => None,
+                })
+                .map(move |command| (command, version_trait.clone()))
+        })
+        .filter_map(|(command, version_trait)| {
+            if !command.starts_with("vkDestroy") && command != "vkFreeMemory" {
+                return None;
+            }
+            let def = *commands.iter().find(|def| def.proto.name == command)?;
+            let param_types: Vec<_> = def
+                .params
+                .iter()
+                .map(|param| param.definition.type_name.as_deref())
+                .collect();
+            match param_types.as_slice() {
+                [Some("VkDevice"), Some(handle), Some("VkAllocationCallbacks")]
+                    if *handle != "VkDevice" =>
+                {
+                    Some(OwnedHandle {
+                        handle: *handle,
+                        command,
+                        version_trait,
+                    })
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    handles.sort_by_key(|owned| owned.handle);
+    handles
+}
+
+pub fn generate_owned(ctx: &Context) -> TokenStream {
+    let handles = owned_handles(ctx);
+    let version_traits: BTreeSet<_> = handles
+        .iter()
+        .map(|owned| format_ident!("{}", owned.version_trait))
+        .collect();
+    let wrappers = handles.iter().map(|owned| {
+        let handle = name_to_tokens(owned.handle);
+        let name = format_ident!("Owned{}", handle);
+        let destroy = format_ident!("{}", owned.command[2..].to_snake_case());
+        let doc = format!(
+            "A [`vk::{}`] that is destroyed with `{}` when dropped.",
+            handle, destroy
+        );
+        quote! {
+            #[doc = #doc]
+            pub struct #name<'a> {
+                device: &'a Device,
+                handle: vk::#handle,
+            }
+
+            impl<'a> #name<'a> {
+                /// Takes ownership of `handle`.
+                ///
+                /// # Safety
+                ///
+                /// `handle` must be created from `device` without allocation callbacks, and must
+                /// not be destroyed elsewhere.
+                pub unsafe fn new(device: &'a Device, handle: vk::#handle) -> Self {
+                    #name { device, handle }
+                }
+
+                pub fn device(&self) -> &'a Device {
+                    self.device
+                }
+
+                pub fn handle(&self) -> vk::#handle {
+                    self.handle
+                }
+
+                /// Gives up ownership of the handle without destroying it.
+                pub fn into_raw(self) -> vk::#handle {
+                    let handle = self.handle;
+                    mem::forget(self);
+                    handle
+                }
+            }
+
+            impl<'a> Drop for #name<'a> {
+                fn drop(&mut self) {
+                    unsafe { self.device.#destroy(self.handle, None) };
+                }
+            }
+
+            impl<'a> fmt::Debug for #name<'a> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.debug_tuple(stringify!(#name)).field(&self.handle).finish()
+                }
+            }
+        }
+    });
+    quote! {
+        //! Handles that carry a reference to their [`Device`] and destroy themselves when dropped,
+        //! for scope-based cleanup. The wrappers only cover the handles that a core version of
+        //! Vulkan destroys on their own, and don't support allocation callbacks.
+        use crate::version::{#(#version_traits),*};
+        use crate::vk;
+        use crate::Device;
+        use std::fmt;
+        use std::mem;
+        #(#wrappers)*
+        #[cfg(test)]
+        mod tests;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::tests::registry;
+
+    #[test]
+    fn owned_handles_of_core_commands() {
+        let ctx = registry(
+            r#"<commands>
+                <command>
+                    <proto><type>void</type> <name>vkDestroyBuffer</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param optional="true"><type>VkBuffer</type> <name>buffer</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkDestroyDevice</name></proto>
+                    <param optional="true"><type>VkDevice</type> <name>device</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkDestroySamplerYcbcrConversion</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param optional="true"><type>VkSamplerYcbcrConversion</type> <name>ycbcrConversion</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkDestroySwapchainKHR</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param optional="true"><type>VkSwapchainKHR</type> <name>swapchain</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                </command>
+            </commands>
+            <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+                <require>
+                    <command name="vkDestroyDevice"/>
+                    <command name="vkDestroyBuffer"/>
+                </require>
+            </feature>
+            <feature api="vulkan" name="VK_VERSION_1_1" number="1.1">
+                <require>
+                    <command name="vkDestroySamplerYcbcrConversion"/>
+                </require>
+            </feature>"#,
+        );
+        assert_eq!(
+            owned_handles(&ctx),
+            [
+                OwnedHandle {
+                    handle: "VkBuffer",
+                    command: "vkDestroyBuffer",
+                    version_trait: "DeviceV1_0".to_owned(),
+                },
+                OwnedHandle {
+                    handle: "VkSamplerYcbcrConversion",
+                    command: "vkDestroySamplerYcbcrConversion",
+                    version_trait: "DeviceV1_1".to_owned(),
+                },
+            ]
+        );
+
+        let code = generate_owned(&ctx).to_string();
+        assert!(code.contains("use crate :: version :: { DeviceV1_0 , DeviceV1_1 } ;"));
+        assert!(code.contains("pub struct OwnedBuffer < 'a >"));
+        assert!(code.contains("self . device . destroy_buffer (self . handle , None)"));
+    }
+}
@@ -0,0 +1,605 @@
+//! Generates requirement tables and a runtime checker from a Vulkan Profiles JSON file, see
+//! <https://github.com/KhronosGroup/Vulkan-Profiles>.
+//!
+//! The core features are checked against `util::FeatureChain` and the core properties against
+//! `PhysicalDeviceProperties`. Every other struct must extend `VkPhysicalDeviceFeatures2` or
+//! `VkPhysicalDeviceProperties2` according to the registry, and is queried by chaining it to
+//! `vkGetPhysicalDeviceFeatures2` or `vkGetPhysicalDeviceProperties2`. Profiles with other structs,
+//! or with members that the registry doesn't know, are rejected with a [`ProfileError`].
+use crate::{name_to_tokens, variant_ident, Context, FieldExt};
+use heck::SnakeCase;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum ProfileError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// A struct that isn't in the registry, or that doesn't extend `VkPhysicalDeviceFeatures2` or
+    /// `VkPhysicalDeviceProperties2`.
+    UnsupportedStruct(String),
+    /// A member that isn't in the registry definition of its struct.
+    UnknownMember {
+        struct_name: String,
+        member: String,
+    },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProfileError::Io(err) => fmt::Display::fmt(err, f),
+            ProfileError::Json(err) => fmt::Display::fmt(err, f),
+            ProfileError::UnsupportedStruct(name) => {
+                write!(f, "{} can't be queried from a physical device", name)
+            }
+            ProfileError::UnknownMember {
+                struct_name,
+                member,
+            } => write!(f, "{} has no member {}", struct_name, member),
+        }
+    }
+}
+
+impl Error for ProfileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProfileError::Io(err) => Some(err),
+            ProfileError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ProfileError {
+    fn from(err: io::Error) -> Self {
+        ProfileError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ProfileError {
+    fn from(err: serde_json::Error) -> Self {
+        ProfileError::Json(err)
+    }
+}
+
+struct Structs<'a>(HashMap<&'a str, &'a vkxml::Struct>);
+
+impl<'a> Structs<'a> {
+    fn new(ctx: &'a Context) -> Self {
+        let structs = ctx
+            .definitions()
+            .into_iter()
+            .filter_map(|definition| match definition {
+                vkxml::DefinitionsElement::Struct(s) => Some((s.name.as_str(), s)),
+                _ => None,
+            })
+            .collect();
+        Structs(structs)
+    }
+
+    fn member(&self, struct_name: &str, member: &str) -> Result<&'a vkxml::Field, ProfileError> {
+        self.0
+            .get(struct_name)
+            .and_then(|s| {
+                s.elements.iter().find_map(|elem| match elem {
+                    vkxml::StructElement::Member(field)
+                        if field.name.as_deref() == Some(member) =>
+                    {
+                        Some(field)
+                    }
+                    _ => None,
+                })
+            })
+            .ok_or_else(|| ProfileError::UnknownMember {
+                struct_name: struct_name.to_owned(),
+                member: member.to_owned(),
+            })
+    }
+
+    fn extends(&self, struct_name: &str, root: &str) -> bool {
+        self.0
+            .get(struct_name)
+            .and_then(|s| s.extends.as_ref())
+            .is_some_and(|extends| extends.split(',').any(|e| e == root))
+    }
+}
+
+/// The structs of the profiles that are queried by chaining them to `VkPhysicalDeviceFeatures2`
+/// or `VkPhysicalDeviceProperties2`, by their `Capabilities` field.
+#[derive(Default)]
+struct ChainedStructs<'a> {
+    features: BTreeMap<&'a str, Ident>,
+    properties: BTreeMap<&'a str, Ident>,
+}
+
+/// The name of the `Capabilities` field holding `struct_name`, e.g. `ray_query_features_khr` for
+/// `VkPhysicalDeviceRayQueryFeaturesKHR`.
+fn capability_ident(struct_name: &str) -> Ident {
+    let name = struct_name
+        .trim_start_matches("VkPhysicalDevice")
+        .to_snake_case();
+    format_ident!("{}", name)
+}
+
+/// Builds a `bool` expression checking that the device value `expr` of `field` meets the
+/// required `value`. Whether larger or smaller values are better is derived from the member name,
+/// following the naming conventions of `VkPhysicalDeviceLimits`.
+fn compare(expr: TokenStream, field: &vkxml::Field, value: &Value) -> Option<TokenStream> {
+    let name = field.name.as_deref().unwrap_or("");
+    let literal = |value: &Value| -> Option<Literal> {
+        if field.basetype == "float" {
+            Some(Literal::f32_suffixed(value.as_f64()? as f32))
+        } else if let Some(n) = value.as_u64() {
+            Some(Literal::u64_unsuffixed(n))
+        } else {
+            Some(Literal::i64_unsuffixed(value.as_i64()?))
+        }
+    };
+    match value {
+        Value::Bool(true) => Some(quote!(#expr == vk::TRUE)),
+        Value::Bool(false) => None,
+        Value::Number(_) => {
+            let value = literal(value)?;
+            if name.starts_with("max") || name.ends_with("Bits") {
+                Some(quote!(#expr >= #value))
+            } else if name.starts_with("min")
+                || name.ends_with("Alignment")
+                || name.ends_with("Granularity")
+                || name == "nonCoherentAtomSize"
+            {
+                Some(quote!(#expr <= #value))
+            } else {
+                Some(quote!(#expr == #value))
+            }
+        }
+        Value::Array(values) if values.iter().all(Value::is_string) => {
+            let enum_name = field.basetype.replace("Flags", "FlagBits");
+            let flags = values.iter().map(|value| {
+                let variant = variant_ident(&enum_name, value.as_str().unwrap());
+                let ty = name_to_tokens(&field.basetype);
+                quote!(vk::#ty::#variant)
+            });
+            Some(quote!(#expr.contains(#(#flags)|*)))
+        }
+        Value::Array(values) if name.ends_with("Range") && values.len() == 2 => {
+            let (low, high) = (literal(&values[0])?, literal(&values[1])?);
+            Some(quote!(#expr[0] <= #low && #expr[1] >= #high))
+        }
+        Value::Array(values) => {
+            let checks = values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| compare(quote!(#expr[#i]), field, value))
+                .collect::<Option<Vec<_>>>()?;
+            Some(quote!(#(#checks)&&*))
+        }
+        _ => None,
+    }
+}
+
+/// Collects `(name, check)` pairs for the members in `values`, recursing into nested structs.
+fn requirements(
+    structs: &Structs,
+    struct_name: &str,
+    expr: TokenStream,
+    values: &Value,
+    out: &mut Vec<TokenStream>,
+) -> Result<(), ProfileError> {
+    for (member, value) in values.as_object().into_iter().flatten() {
+        let field = structs.member(struct_name, member)?;
+        let ident = field.param_ident();
+        let member_expr = quote!(#expr.#ident);
+        if value.is_object() {
+            requirements(structs, &field.basetype, member_expr, value, out)?;
+        } else if let Some(check) = compare(member_expr, field, value) {
+            let name = format!("{}.{}", struct_name, member);
+            out.push(quote! {
+                Requirement { name: #name, check: |s| #check }
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Builds the statements querying the chained `fields` of `capabilities` with `query`, e.g.
+/// `get_physical_device_features2`, chained to `root`.
+fn query_chain(
+    root: TokenStream,
+    query: TokenStream,
+    fields: &BTreeMap<&str, Ident>,
+) -> TokenStream {
+    if fields.is_empty() {
+        return quote!();
+    }
+    let fields = fields.values().collect::<Vec<_>>();
+    quote! {
+        let mut root = #root #(.push_next(&mut capabilities.#fields))*;
+        instance.#query(physical_device, &mut root);
+        // The chain points into `capabilities`, which is moved out
+        #(capabilities.#fields.p_next = ::std::ptr::null_mut();)*
+    }
+}
+
+fn api_version(version: &str) -> TokenStream {
+    let mut parts = version
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let (major, minor, patch) = (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    );
+    quote!(vk::make_version(#major, #minor, #patch))
+}
+
+/// Generates a module with one `Profile` constant per profile in `json`, plus `PROFILES` listing
+/// all of them, and the `Capabilities` of a physical device that they are checked against.
+pub fn generate_profiles(json: &str, ctx: &Context) -> Result<TokenStream, ProfileError> {
+    let root: Value = serde_json::from_str(json)?;
+    let structs = Structs::new(ctx);
+    let is_instance_extension = |name: &str| {
+        ctx.extensions()
+            .iter()
+            .any(|ext| ext.name == name && ext.ext_type.as_deref() == Some("instance"))
+    };
+    let capabilities = &root["capabilities"];
+    let mut chained = ChainedStructs::default();
+
+    let mut profiles = Vec::new();
+    for (profile_name, profile) in root["profiles"].as_object().into_iter().flatten() {
+        let ident = format_ident!("{}", profile_name.to_uppercase());
+        let spec_version = profile["version"].as_u64().unwrap_or(1) as u32;
+        let api_version = api_version(profile["api-version"].as_str().unwrap_or("1.0.0"));
+        let description = profile["description"].as_str().unwrap_or("");
+
+        let mut instance_extensions = Vec::new();
+        let mut device_extensions = Vec::new();
+        let mut features = Vec::new();
+        let mut properties = Vec::new();
+        // Alternatives (nested arrays) are not supported yet, the first one is used
+        let capability_names = profile["capabilities"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|name| name.as_str().or_else(|| name.get(0)?.as_str()));
+        for capability in capability_names.map(|name| &capabilities[name]) {
+            for name in capability["extensions"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(n, _)| n)
+            {
+                if is_instance_extension(name) {
+                    instance_extensions.push(name.clone());
+                } else {
+                    device_extensions.push(name.clone());
+                }
+            }
+            for (struct_name, values) in capability["features"].as_object().into_iter().flatten() {
+                let expr = match struct_name.as_str() {
+                    "VkPhysicalDeviceFeatures" => quote!(s.features.features2.features),
+                    "VkPhysicalDeviceFeatures2" => quote!(s.features.features2),
+                    "VkPhysicalDeviceVulkan11Features" => quote!(s.features.vulkan_11),
+                    "VkPhysicalDeviceVulkan12Features" => quote!(s.features.vulkan_12),
+                    name if structs.extends(name, "VkPhysicalDeviceFeatures2") => {
+                        let field = chained
+                            .features
+                            .entry(name)
+                            .or_insert_with(|| capability_ident(name));
+                        quote!(s.#field)
+                    }
+                    name => return Err(ProfileError::UnsupportedStruct(name.to_owned())),
+                };
+                requirements(&structs, struct_name, expr, values, &mut features)?;
+            }
+            for (struct_name, values) in capability["properties"].as_object().into_iter().flatten()
+            {
+                let expr = match struct_name.as_str() {
+                    "VkPhysicalDeviceProperties" => quote!(s.properties),
+                    name if structs.extends(name, "VkPhysicalDeviceProperties2") => {
+                        let field = chained
+                            .properties
+                            .entry(name)
+                            .or_insert_with(|| capability_ident(name));
+                        quote!(s.#field)
+                    }
+                    name => return Err(ProfileError::UnsupportedStruct(name.to_owned())),
+                };
+                requirements(&structs, struct_name, expr, values, &mut properties)?;
+            }
+        }
+        profiles.push(quote! {
+            #[doc = #description]
+            pub const #ident: Profile = Profile {
+                name: #profile_name,
+                spec_version: #spec_version,
+                api_version: #api_version,
+                instance_extensions: &[#(#instance_extensions),*],
+                device_extensions: &[#(#device_extensions),*],
+                features: &[#(#features),*],
+                properties: &[#(#properties),*],
+            };
+        });
+    }
+    let names = root["profiles"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, _)| format_ident!("{}", name.to_uppercase()));
+
+    let chained_fields = chained
+        .features
+        .iter()
+        .chain(&chained.properties)
+        .map(|(name, field)| {
+            let ty = name_to_tokens(name);
+            quote!(pub #field: vk::#ty)
+        });
+    let query_features = query_chain(
+        quote!(vk::PhysicalDeviceFeatures2::builder()),
+        quote!(get_physical_device_features2),
+        &chained.features,
+    );
+    let query_properties = query_chain(
+        quote!(vk::PhysicalDeviceProperties2::builder()),
+        quote!(get_physical_device_properties2),
+        &chained.properties,
+    );
+
+    let query_extensions = if chained.features.is_empty() && chained.properties.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            if api_version >= vk::make_version(1, 1, 0) {
+                #query_features
+                #query_properties
+            }
+        }
+    };
+
+    Ok(quote! {
+        use ash::util::FeatureChain;
+        use ash::version::InstanceV1_1;
+        use ash::vk;
+
+        /// A single requirement of a profile, `name` is the Vulkan name of the struct member.
+        pub struct Requirement {
+            pub name: &'static str,
+            pub check: fn(&Capabilities) -> bool,
+        }
+
+        /// The features and properties of a physical device that the profiles of this module
+        /// check.
+        #[derive(Default)]
+        pub struct Capabilities {
+            pub features: FeatureChain,
+            pub properties: vk::PhysicalDeviceProperties,
+            #(#chained_fields,)*
+        }
+
+        impl Capabilities {
+            /// Queries the capabilities of `physical_device`. The structs of extensions are only
+            /// queried from Vulkan 1.1 devices, and are left zeroed on Vulkan 1.0 devices.
+            pub unsafe fn query<I: InstanceV1_1>(
+                instance: &I,
+                physical_device: vk::PhysicalDevice,
+            ) -> Self {
+                let mut capabilities = Capabilities {
+                    properties: instance.get_physical_device_properties(physical_device),
+                    ..Default::default()
+                };
+                let api_version = capabilities.properties.api_version;
+                if api_version >= vk::make_version(1, 2, 0) {
+                    capabilities.features = FeatureChain::query(instance, physical_device);
+                } else {
+                    capabilities.features.features2.features =
+                        instance.get_physical_device_features(physical_device);
+                }
+                #query_extensions
+                capabilities
+            }
+        }
+
+        pub struct Profile {
+            pub name: &'static str,
+            pub spec_version: u32,
+            pub api_version: u32,
+            pub instance_extensions: &'static [&'static str],
+            pub device_extensions: &'static [&'static str],
+            pub features: &'static [Requirement],
+            pub properties: &'static [Requirement],
+        }
+
+        impl Profile {
+            /// Returns the requirements of this profile that `physical_device` doesn't meet.
+            /// The device supports the profile if the result is empty. Instance extensions are
+            /// not checked, they need to be enabled when creating the instance.
+            pub unsafe fn unmet_requirements<I: InstanceV1_1>(
+                &self,
+                instance: &I,
+                physical_device: vk::PhysicalDevice,
+            ) -> Vec<&'static str> {
+                let mut unmet = Vec::new();
+                let capabilities = Capabilities::query(instance, physical_device);
+                if capabilities.properties.api_version < self.api_version {
+                    unmet.push("apiVersion");
+                }
+                let extensions = instance
+                    .enumerate_device_extension_properties(physical_device)
+                    .unwrap_or_default();
+                for &name in self.device_extensions {
+                    let supported = extensions.iter().any(|ext| {
+                        ext.extension_name_as_c_str()
+                            .is_ok_and(|ext_name| ext_name.to_bytes() == name.as_bytes())
+                    });
+                    if !supported {
+                        unmet.push(name);
+                    }
+                }
+                unmet.extend(
+                    self.features
+                        .iter()
+                        .chain(self.properties)
+                        .filter(|r| !(r.check)(&capabilities))
+                        .map(|r| r.name),
+                );
+                unmet
+            }
+        }
+
+        #(#profiles)*
+
+        pub const PROFILES: &[Profile] = &[#(#names),*];
+    })
+}
+
+/// Writes the module generated by [`generate_profiles`] to `out_file`, with the registry that
+/// was already parsed into `ctx` for the bindings.
+pub fn write_profiles(
+    ctx: &Context,
+    profiles_json: &Path,
+    out_file: &Path,
+) -> Result<(), ProfileError> {
+    let json = std::fs::read_to_string(profiles_json)?;
+    let code = generate_profiles(&json, ctx)?;
+    std::fs::write(out_file, code.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"<registry>
+        <types>
+            <type category="struct" name="VkPhysicalDeviceFeatures">
+                <member><type>VkBool32</type> <name>geometryShader</name></member>
+                <member><type>VkBool32</type> <name>wideLines</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceVulkan12Features" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+                <member><type>VkBool32</type> <name>timelineSemaphore</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceRayQueryFeaturesKHR" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+                <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR"><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+                <member><type>VkBool32</type> <name>rayQuery</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceLimits" returnedonly="true">
+                <member><type>uint32_t</type> <name>maxImageDimension2D</name></member>
+                <member><type>VkDeviceSize</type> <name>minUniformBufferOffsetAlignment</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceProperties" returnedonly="true">
+                <member><type>uint32_t</type> <name>apiVersion</name></member>
+                <member><type>VkPhysicalDeviceLimits</type> <name>limits</name></member>
+            </type>
+            <type category="struct" name="VkPhysicalDeviceMultiviewProperties" returnedonly="true" structextends="VkPhysicalDeviceProperties2">
+                <member values="VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES"><type>VkStructureType</type> <name>sType</name></member>
+                <member><type>void</type>* <name>pNext</name></member>
+                <member><type>uint32_t</type> <name>maxMultiviewViewCount</name></member>
+            </type>
+        </types>
+        <extensions>
+            <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan"/>
+            <extension name="VK_KHR_swapchain" number="2" type="device" supported="vulkan"/>
+        </extensions>
+    </registry>"#;
+
+    fn profile(capability: &str) -> String {
+        format!(
+            r#"{{
+                "capabilities": {{ "baseline": {} }},
+                "profiles": {{
+                    "VP_TEST_baseline": {{
+                        "version": 2,
+                        "api-version": "1.2.0",
+                        "description": "Test profile",
+                        "capabilities": ["baseline"]
+                    }}
+                }}
+            }}"#,
+            capability
+        )
+    }
+
+    #[test]
+    fn profile_requirements() {
+        let ctx = Context::parse(REGISTRY).unwrap();
+        let json = profile(
+            r#"{
+                "extensions": { "VK_KHR_surface": 1, "VK_KHR_swapchain": 70 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "geometryShader": true, "wideLines": false },
+                    "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true },
+                    "VkPhysicalDeviceRayQueryFeaturesKHR": { "rayQuery": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "limits": {
+                            "maxImageDimension2D": 4096,
+                            "minUniformBufferOffsetAlignment": 256
+                        }
+                    },
+                    "VkPhysicalDeviceMultiviewProperties": { "maxMultiviewViewCount": 6 }
+                }
+            }"#,
+        );
+        let code = generate_profiles(&json, &ctx).unwrap();
+        // The module is written out as is, so it has to be valid Rust
+        syn::parse2::<syn::File>(code.clone()).expect("Unable to parse the generated profiles");
+
+        let code = code.to_string();
+        assert!(code.contains("pub const VP_TEST_BASELINE : Profile"));
+        assert!(code.contains("api_version : vk :: make_version (1u32 , 2u32 , 0u32)"));
+        assert!(code.contains("instance_extensions : & [\"VK_KHR_surface\"]"));
+        assert!(code.contains("device_extensions : & [\"VK_KHR_swapchain\"]"));
+        assert!(code.contains(
+            "check : | s | s . features . features2 . features . geometry_shader == vk :: TRUE"
+        ));
+        assert!(!code.contains("wide_lines"));
+        assert!(code
+            .contains("check : | s | s . features . vulkan_12 . timeline_semaphore == vk :: TRUE"));
+        assert!(
+            code.contains("pub ray_query_features_khr : vk :: PhysicalDeviceRayQueryFeaturesKHR")
+        );
+        assert!(code.contains("check : | s | s . ray_query_features_khr . ray_query == vk :: TRUE"));
+        assert!(code.contains(
+            "let mut root = vk :: PhysicalDeviceFeatures2 :: builder () . push_next (& mut capabilities . ray_query_features_khr)"
+        ));
+        assert!(
+            code.contains("check : | s | s . properties . limits . max_image_dimension2_d >= 4096")
+        );
+        assert!(code.contains(
+            "check : | s | s . properties . limits . min_uniform_buffer_offset_alignment <= 256"
+        ));
+        assert!(
+            code.contains("check : | s | s . multiview_properties . max_multiview_view_count >= 6")
+        );
+        assert!(code.contains("pub const PROFILES : & [Profile] = & [VP_TEST_BASELINE]"));
+    }
+
+    #[test]
+    fn unsupported_profile_structs() {
+        let ctx = Context::parse(REGISTRY).unwrap();
+        let json = profile(
+            r#"{ "features": { "VkPhysicalDeviceLimits": { "maxImageDimension2D": 1 } } }"#,
+        );
+        assert!(matches!(
+            generate_profiles(&json, &ctx),
+            Err(ProfileError::UnsupportedStruct(name)) if name == "VkPhysicalDeviceLimits"
+        ));
+        let json = profile(
+            r#"{ "features": { "VkPhysicalDeviceRayQueryFeaturesKHR": { "rayTracing": true } } }"#,
+        );
+        assert!(matches!(
+            generate_profiles(&json, &ctx),
+            Err(ProfileError::UnknownMember { member, .. }) if member == "rayTracing"
+        ));
+    }
+}
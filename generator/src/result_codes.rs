@@ -0,0 +1,370 @@
+//! Generates `SuccessCode` and `ErrorCode`, the two halves of `VkResult`, with a constant for every
+//! code that the `successcodes` and `errorcodes` of the commands list. Vulkan keeps success codes
+//! non-negative and error codes negative, which is how the conversions from `Result` sort values
+//! that aren't in the registry.
+use crate::{variant_ident, Context, EnumValue};
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The descriptions of the `VkResult` section of the specification, for the codes that the
+/// registry doesn't have a comment for.
+const SPEC_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("VK_ERROR_SURFACE_LOST_KHR", "A surface is no longer available"),
+    (
+        "VK_ERROR_NATIVE_WINDOW_IN_USE_KHR",
+        "The requested window is already in use by Vulkan or another API in a manner which prevents it from being used again",
+    ),
+    (
+        "VK_SUBOPTIMAL_KHR",
+        "A swapchain no longer matches the surface properties exactly, but can still be used to present to the surface successfully",
+    ),
+    (
+        "VK_ERROR_OUT_OF_DATE_KHR",
+        "A surface has changed in such a way that it is no longer compatible with the swapchain, and further presentation requests using the swapchain will fail",
+    ),
+    (
+        "VK_ERROR_INCOMPATIBLE_DISPLAY_KHR",
+        "The display used by a swapchain does not use the same presentable image layout, or is incompatible in a way that prevents sharing an image",
+    ),
+    (
+        "VK_ERROR_VALIDATION_FAILED_EXT",
+        "A command failed because invalid usage was detected by the implementation or a validation layer",
+    ),
+    ("VK_ERROR_INVALID_SHADER_NV", "One or more shaders failed to compile or link"),
+    (
+        "VK_ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT",
+        "The plane layouts of an image with an explicit DRM format modifier are invalid",
+    ),
+    (
+        "VK_ERROR_NOT_PERMITTED_EXT",
+        "The application does not have sufficient privileges for the requested priority",
+    ),
+    (
+        "VK_ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT",
+        "An operation on a swapchain created with application controlled full-screen access failed as it did not have exclusive full-screen access",
+    ),
+    (
+        "VK_THREAD_IDLE_KHR",
+        "A deferred operation is not complete but there is currently no work for this thread to do at the time of this call",
+    ),
+    (
+        "VK_THREAD_DONE_KHR",
+        "A deferred operation is not complete but there is no work remaining to assign to additional threads",
+    ),
+    (
+        "VK_OPERATION_DEFERRED_KHR",
+        "A deferred operation was requested and at least some of the work was deferred",
+    ),
+    (
+        "VK_OPERATION_NOT_DEFERRED_KHR",
+        "A deferred operation was requested and no operations were deferred",
+    ),
+    (
+        "VK_PIPELINE_COMPILE_REQUIRED_EXT",
+        "A requested pipeline creation would have required compilation, but the application requested compilation to not be performed",
+    ),
+    ("VK_ERROR_OUT_OF_POOL_MEMORY", "A pool memory allocation has failed"),
+    (
+        "VK_ERROR_INVALID_EXTERNAL_HANDLE",
+        "An external handle is not a valid handle of the specified type",
+    ),
+    (
+        "VK_ERROR_FRAGMENTATION",
+        "A descriptor pool creation has failed due to fragmentation",
+    ),
+    (
+        "VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS",
+        "A buffer creation or memory allocation failed because the requested address is not available",
+    ),
+];
+
+/// The codes that commands return on success and the ones they only return on failure. Codes
+/// that some commands list as errors and others as success, such as `VK_TIMEOUT`, are success
+/// codes.
+fn result_codes(ctx: &Context) -> (BTreeSet<&str>, BTreeSet<&str>) {
+    let mut success_codes = BTreeSet::new();
+    let mut error_codes = BTreeSet::new();
+    for def in ctx.command_definitions() {
+        success_codes.extend(def.successcodes.iter().flat_map(|codes| codes.split(',')));
+        error_codes.extend(def.errorcodes.iter().flat_map(|codes| codes.split(',')));
+    }
+    let error_codes = error_codes.difference(&success_codes).copied().collect();
+    (success_codes, error_codes)
+}
+
+/// Replaces the asciidoc cross-references `<<anchor,text>>` of a registry comment with their text,
+/// and drops the sentences with references to an anchor alone, like `See <<anchor>>`.
+fn strip_cross_references(description: &str) -> String {
+    description
+        .split(". ")
+        .filter_map(|sentence| {
+            let mut text = String::new();
+            let mut rest = sentence;
+            while let Some(start) = rest.find("<<") {
+                let end = start + rest[start..].find(">>")?;
+                let (_, reference_text) = rest[start + 2..end].split_once(',')?;
+                text.push_str(&rest[..start]);
+                text.push_str(reference_text);
+                rest = &rest[end + 2..];
+            }
+            text.push_str(rest);
+            Some(text)
+        })
+        .join(". ")
+}
+
+/// The description of every `VkResult` value that isn't an alias, from its comment in the registry
+/// or else from [`SPEC_DESCRIPTIONS`]. Values without either are left out.
+fn result_descriptions(ctx: &Context) -> BTreeMap<&str, String> {
+    let core_values = ctx
+        .enums()
+        .into_iter()
+        .filter(|e| e.name == "VkResult")
+        .flat_map(|e| e.elements.iter())
+        .filter_map(|elem| match elem {
+            vkxml::EnumerationElement::Enum(constant) => {
+                Some((constant.name.as_str(), constant.notation.as_deref()))
+            }
+            _ => None,
+        });
+    let extension_enums = ctx.extension_enums();
+    let extension_values = extension_enums
+        .iter()
+        .filter(|e| e.extends == "VkResult" && !matches!(e.value(), Some(EnumValue::Alias(_))))
+        .map(|e| (e.name, e.comment));
+    core_values
+        .chain(extension_values)
+        .filter_map(|(name, comment)| {
+            let description = comment.or_else(|| {
+                SPEC_DESCRIPTIONS
+                    .iter()
+                    .find(|&&(code, _)| code == name)
+                    .map(|&(_, description)| description)
+            })?;
+            Some((name, strip_cross_references(description)))
+        })
+        .collect()
+}
+
+pub fn generate_result_codes(ctx: &Context) -> TokenStream {
+    let (success_codes, error_codes) = result_codes(ctx);
+    let descriptions = result_descriptions(ctx)
+        .into_iter()
+        .map(|(name, description)| {
+            let variant = variant_ident("VkResult", name);
+            quote!((Result::#variant, #name, #description))
+        });
+    let success_codes = success_codes.iter().map(|code| {
+        let variant = variant_ident("VkResult", code);
+        quote!(pub const #variant: Self = SuccessCode(Result::#variant);)
+    });
+    let error_codes = error_codes.iter().map(|code| {
+        let variant = variant_ident("VkResult", code);
+        quote!(pub const #variant: Self = ErrorCode(Result::#variant);)
+    });
+    quote! {
+        use crate::vk::enums::Result;
+        use std::convert::TryFrom;
+        use std::fmt;
+
+        /// A [`Result`] that is a success code, which is never negative. `INCOMPLETE`,
+        /// `SUBOPTIMAL_KHR` or `TIMEOUT` are success codes too, and carry information about how
+        /// the command succeeded.
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct SuccessCode(Result);
+
+        impl SuccessCode {
+            #(#success_codes)*
+
+            pub const fn as_result(self) -> Result {
+                self.0
+            }
+        }
+
+        /// A [`Result`] that is an error code, which is always negative.
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct ErrorCode(Result);
+
+        impl ErrorCode {
+            #(#error_codes)*
+
+            pub const fn as_result(self) -> Result {
+                self.0
+            }
+        }
+
+        /// The registry name and the description of every code, from its comment in the registry
+        /// or else from the specification. Empty without the `debug-strings` feature.
+        #[cfg(feature = "debug-strings")]
+        const DESCRIPTIONS: &[(Result, &str, &str)] = &[#(#descriptions),*];
+        #[cfg(not(feature = "debug-strings"))]
+        const DESCRIPTIONS: &[(Result, &str, &str)] = &[];
+
+        impl Result {
+            /// Splits the result into a success code and an error code, which unlike
+            /// `result()` keeps the success codes other than `SUCCESS` out of the error.
+            pub fn into_codes(self) -> ::std::result::Result<SuccessCode, ErrorCode> {
+                if self.as_raw() >= 0 {
+                    Ok(SuccessCode(self))
+                } else {
+                    Err(ErrorCode(self))
+                }
+            }
+
+            /// The name of the code in the registry, e.g. `VK_ERROR_SURFACE_LOST_KHR`. `None` for
+            /// unknown codes, and without the `debug-strings` feature.
+            pub fn name(self) -> Option<&'static str> {
+                DESCRIPTIONS
+                    .iter()
+                    .find(|&&(code, _, _)| code == self)
+                    .map(|&(_, name, _)| name)
+            }
+
+            /// Describes the code, e.g. "A surface is no longer available" for
+            /// `ERROR_SURFACE_LOST_KHR`. `None` for unknown codes, and without the
+            /// `debug-strings` feature.
+            pub fn describe(self) -> Option<&'static str> {
+                DESCRIPTIONS
+                    .iter()
+                    .find(|&&(code, _, _)| code == self)
+                    .map(|&(_, _, description)| description)
+            }
+        }
+
+        impl TryFrom<Result> for SuccessCode {
+            type Error = ErrorCode;
+            fn try_from(result: Result) -> ::std::result::Result<Self, Self::Error> {
+                result.into_codes()
+            }
+        }
+
+        impl TryFrom<Result> for ErrorCode {
+            type Error = SuccessCode;
+            fn try_from(result: Result) -> ::std::result::Result<Self, Self::Error> {
+                match result.into_codes() {
+                    Ok(success) => Err(success),
+                    Err(error) => Ok(error),
+                }
+            }
+        }
+
+        impl From<SuccessCode> for Result {
+            fn from(code: SuccessCode) -> Self {
+                code.0
+            }
+        }
+
+        impl From<ErrorCode> for Result {
+            fn from(code: ErrorCode) -> Self {
+                code.0
+            }
+        }
+
+        impl fmt::Debug for SuccessCode {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Debug for ErrorCode {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Display for ErrorCode {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::error::Error for ErrorCode {}
+
+        #[cfg(test)]
+        mod tests;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::tests::registry;
+
+    #[test]
+    fn split_result_codes() {
+        let ctx = registry(
+            r#"<commands>
+                <command successcodes="VK_SUCCESS,VK_TIMEOUT" errorcodes="VK_ERROR_DEVICE_LOST">
+                    <proto><type>VkResult</type> <name>vkWaitForFences</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                </command>
+                <command successcodes="VK_SUCCESS" errorcodes="VK_TIMEOUT,VK_ERROR_OUT_OF_HOST_MEMORY">
+                    <proto><type>VkResult</type> <name>vkAcquireProfilingLockKHR</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                </command>
+            </commands>"#,
+        );
+        let (success_codes, error_codes) = result_codes(&ctx);
+        assert_eq!(
+            success_codes.into_iter().collect::<Vec<_>>(),
+            ["VK_SUCCESS", "VK_TIMEOUT"]
+        );
+        assert_eq!(
+            error_codes.into_iter().collect::<Vec<_>>(),
+            ["VK_ERROR_DEVICE_LOST", "VK_ERROR_OUT_OF_HOST_MEMORY"]
+        );
+
+        let code = generate_result_codes(&ctx).to_string();
+        assert!(code.contains("pub const TIMEOUT : Self = SuccessCode (Result :: TIMEOUT) ;"));
+        assert!(code.contains(
+            "pub const ERROR_DEVICE_LOST : Self = ErrorCode (Result :: ERROR_DEVICE_LOST) ;"
+        ));
+    }
+
+    #[test]
+    fn result_descriptions_from_comments() {
+        let ctx = registry(
+            r#"<enums name="VkResult" type="enum">
+                <enum value="0" name="VK_SUCCESS" comment="Command completed successfully"/>
+                <enum value="-4" name="VK_ERROR_DEVICE_LOST" comment="The logical device has been lost. See &lt;&lt;devsandqueues-lost-device&gt;&gt;"/>
+                <enum value="-13" name="VK_ERROR_UNKNOWN"/>
+            </enums>
+            <extensions>
+                <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan">
+                    <require>
+                        <enum offset="0" extends="VkResult" dir="-" name="VK_ERROR_SURFACE_LOST_KHR"/>
+                        <enum offset="1" extends="VkResult" dir="-" name="VK_ERROR_NATIVE_WINDOW_IN_USE_KHR" comment="Window in use"/>
+                        <enum extends="VkResult" name="VK_ERROR_SURFACE_LOST" alias="VK_ERROR_SURFACE_LOST_KHR"/>
+                    </require>
+                </extension>
+            </extensions>"#,
+        );
+        assert_eq!(
+            result_descriptions(&ctx)
+                .iter()
+                .map(|(&name, description)| (name, description.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("VK_ERROR_DEVICE_LOST", "The logical device has been lost"),
+                ("VK_ERROR_NATIVE_WINDOW_IN_USE_KHR", "Window in use"),
+                (
+                    "VK_ERROR_SURFACE_LOST_KHR",
+                    "A surface is no longer available"
+                ),
+                ("VK_SUCCESS", "Command completed successfully"),
+            ]
+        );
+        let code = generate_result_codes(&ctx).to_string();
+        assert!(code.contains(
+            "(Result :: ERROR_SURFACE_LOST_KHR , \"VK_ERROR_SURFACE_LOST_KHR\" , \"A surface is no longer available\")"
+        ));
+        assert_eq!(
+            strip_cross_references("Lost, see <<devsandqueues-lost-device,Lost Device>>"),
+            "Lost, see Lost Device"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+//! Generates `trace/commands.rs` of ash, with a function for every command that calls the real
+//! command and records the call. The `trace` module of ash hands these out instead of the real
+//! commands.
+use crate::{Context, FieldExt};
+use heck::{ShoutySnakeCase, SnakeCase};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Loaded by hand in the `trace` module, to interpose the commands they return.
+const PROC_ADDR_COMMANDS: [&str; 2] = ["vkGetInstanceProcAddr", "vkGetDeviceProcAddr"];
+
+const PRINTABLE_PRIMITIVES: [&str; 9] = [
+    "uint8_t", "uint16_t", "uint32_t", "uint64_t", "int32_t", "int64_t", "float", "double",
+    "size_t",
+];
+
+/// Whether the `Debug` impl of `basetype` can be used for the values a parameter points to.
+fn is_printable(basetype: &str, union_types: &HashSet<&str>) -> bool {
+    (basetype.starts_with("Vk") && !union_types.contains(basetype))
+        || PRINTABLE_PRIMITIVES.contains(&basetype)
+}
+
+/// The expression that is recorded for `field`, and whether it depends on the command having
+/// succeeded. Pointers to a single value or to an array with a count parameter show the values,
+/// output parameters only once the command succeeded. C strings are shown as strings, all other
+/// pointers as their address.
+fn arg_tokens(
+    field: &vkxml::Field,
+    cmd: &vkxml::Command,
+    union_types: &HashSet<&str>,
+) -> (TokenStream, bool) {
+    let ident = field.param_ident();
+    let shown = if field.is_const {
+        quote!(true)
+    } else {
+        quote!(ok)
+    };
+    let values = match (&field.reference, &field.array) {
+        (Some(vkxml::ReferenceType::Pointer), _) if field.basetype == "char" && field.is_const => {
+            return (quote!(&CStrArg(#ident)), false);
+        }
+        (Some(vkxml::ReferenceType::Pointer), None)
+            if is_printable(&field.basetype, union_types) =>
+        {
+            quote!(&Pointee(#ident, #shown))
+        }
+        (Some(vkxml::ReferenceType::Pointer), Some(vkxml::ArrayType::Dynamic))
+            if is_printable(&field.basetype, union_types) =>
+        {
+            let count = field.size.as_deref().and_then(|len| {
+                cmd.param.iter().find(|param| {
+                    param.name.as_deref() == Some(len)
+                        && param.reference.is_none()
+                        && param.array.is_none()
+                })
+            });
+            match count {
+                Some(count) => {
+                    let count = count.param_ident();
+                    quote!(&Pointees(#ident, #count as usize, #shown))
+                }
+                None => return (quote!(&#ident), false),
+            }
+        }
+        _ => return (quote!(&#ident), false),
+    };
+    (values, !field.is_const)
+}
+
+/// The types and attributes of the registry that the tracers depend on.
+struct TraceTypes<'a> {
+    union_types: HashSet<&'a str>,
+    handle_types: HashSet<&'a str>,
+    handle_parents: HashMap<&'a str, Vec<&'a str>>,
+    /// The handles that a `vkDestroy*` or `vkFree*` command destroys, which can leak.
+    destroyable: HashSet<&'a str>,
+    structs: HashMap<&'a str, &'a vkxml::Struct>,
+    extern_sync: HashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> TraceTypes<'a> {
+    fn is_handle(&self, field: &vkxml::Field) -> bool {
+        self.handle_types.contains(field.basetype.as_str())
+    }
+
+    fn is_handle_value(&self, field: &vkxml::Field) -> bool {
+        field.reference.is_none() && field.array.is_none() && self.is_handle(field)
+    }
+}
+
+fn is_destroy_command(name: &str) -> bool {
+    name.starts_with("vkDestroy") || name.starts_with("vkFree")
+}
+
+/// The `handle_arg` of `field`, a handle that is passed by value.
+fn handle_arg(field: &vkxml::Field) -> TokenStream {
+    let ident = field.param_ident();
+    let cname = field.name.as_deref().unwrap_or("field");
+    quote!(handle_arg(#cname, #ident))
+}
+
+/// The number of values that the pointer `field` points to, from its `len` attribute, which names
+/// a parameter, a parameter that points to the count, or a member of a parameter like
+/// `pAllocateInfo->commandBufferCount`.
+fn pointee_count(field: &vkxml::Field, cmd: &vkxml::Command) -> Option<TokenStream> {
+    let len = match field.array {
+        None => return Some(quote!(1)),
+        Some(vkxml::ArrayType::Dynamic) => field.size.as_deref()?,
+        Some(vkxml::ArrayType::Static) => return None,
+    };
+    let mut path = len.split("->");
+    let param = path.next()?;
+    let count = cmd
+        .param
+        .iter()
+        .find(|param_field| param_field.name.as_deref() == Some(param))?;
+    let ident = count.param_ident();
+    match (path.next(), &count.reference) {
+        (None, None) => Some(quote!(#ident as usize)),
+        (None, Some(vkxml::ReferenceType::Pointer)) => Some(quote!(*#ident as usize)),
+        (Some(member), Some(vkxml::ReferenceType::Pointer)) => {
+            let member = format_ident!("{}", member.to_snake_case());
+            Some(quote!((*#ident).#member as usize))
+        }
+        _ => None,
+    }
+}
+
+/// Claims the handles among the externally synchronized parameters of `cmd` for the duration of
+/// the call, so that concurrent use from another thread is reported. Members of parameters, like
+/// `pBindInfo[].pBufferBinds[].buffer`, aren't checked.
+fn sync_guard_tokens(name: &str, cmd: &vkxml::Command, types: &TraceTypes) -> TokenStream {
+    let extern_sync = types.extern_sync.get(name).map_or(&[][..], Vec::as_slice);
+    let handles: Vec<_> = cmd
+        .param
+        .iter()
+        .filter(|field| {
+            types.is_handle_value(field)
+                && field
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| extern_sync.contains(&name))
+        })
+        .map(handle_arg)
+        .collect();
+    if handles.is_empty() {
+        return quote!();
+    }
+    quote!(let _sync = SyncGuard::new(#name, &[#(#handles),*]);)
+}
+
+/// The handle that the handle `created` by `cmd` is created from, by the `parent` attribute of
+/// its type: a parameter, a member of a parameter like `pAllocateInfo->commandPool`, or else the
+/// first parameter of `cmd`.
+fn parent_tokens(created: &vkxml::Field, cmd: &vkxml::Command, types: &TraceTypes) -> TokenStream {
+    let parents = types
+        .handle_parents
+        .get(created.basetype.as_str())
+        .map_or(&[][..], Vec::as_slice);
+    let is_parent = |field: &vkxml::Field| {
+        types.is_handle_value(field) && parents.contains(&field.basetype.as_str())
+    };
+    if let Some(param) = cmd.param.iter().find(|field| is_parent(field)) {
+        let arg = handle_arg(param);
+        return quote!(Some(#arg));
+    }
+    let member = cmd
+        .param
+        .iter()
+        .filter(|field| {
+            field.is_const
+                && field.array.is_none()
+                && matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+        })
+        .find_map(|param| {
+            let members = &types.structs.get(param.basetype.as_str())?.elements;
+            let member = members.iter().find_map(|elem| match elem {
+                vkxml::StructElement::Member(member) if is_parent(member) => Some(member),
+                _ => None,
+            })?;
+            Some((param, member))
+        });
+    if let Some((param, member)) = member {
+        let name = format!(
+            "{}->{}",
+            param.name.as_deref().unwrap_or("field"),
+            member.name.as_deref().unwrap_or("field")
+        );
+        let param = param.param_ident();
+        let member = member.param_ident();
+        return quote!(Some(handle_arg(#name, (*#param).#member)));
+    }
+    match cmd.param.first() {
+        Some(first) if types.is_handle_value(first) => {
+            let arg = handle_arg(first);
+            quote!(Some(#arg))
+        }
+        _ => quote!(None),
+    }
+}
+
+/// The bookkeeping of the handles that `cmd` uses, creates and destroys, before and after the
+/// real command is called. Handles that are retrieved, such as the queues of `vkGetDeviceQueue`,
+/// are tracked without being owned, so they never leak.
+fn lifetime_tokens(
+    name: &str,
+    cmd: &vkxml::Command,
+    types: &TraceTypes,
+) -> (TokenStream, TokenStream) {
+    let used: Vec<_> = cmd
+        .param
+        .iter()
+        .filter(|field| types.is_handle_value(field))
+        .map(handle_arg)
+        .collect();
+    let check = if used.is_empty() {
+        quote!()
+    } else {
+        quote!(check_handles(#name, &[#(#used),*]);)
+    };
+
+    let created = cmd
+        .param
+        .iter()
+        .filter(|field| {
+            !field.is_const
+                && matches!(field.reference, Some(vkxml::ReferenceType::Pointer))
+                && types.is_handle(field)
+        })
+        .filter_map(|field| {
+            let count = pointee_count(field, cmd)?;
+            let ident = field.param_ident();
+            let owned = (name.starts_with("vkCreate") || name.starts_with("vkAllocate"))
+                && types.destroyable.contains(field.basetype.as_str());
+            let parent = parent_tokens(field, cmd, types);
+            Some(quote!(track_created(#name, #owned, #parent, #ident, #count);))
+        });
+    let destroyed = cmd
+        .param
+        .iter()
+        .rev()
+        .find(|field| types.is_handle(field))
+        .filter(|_| is_destroy_command(name))
+        .and_then(|field| {
+            let ident = field.param_ident();
+            if types.is_handle_value(field) {
+                Some(quote!(track_destroyed(#name, &#ident, 1);))
+            } else if field.is_const {
+                let count = pointee_count(field, cmd)?;
+                Some(quote!(track_destroyed(#name, #ident, #count);))
+            } else {
+                None
+            }
+        });
+    let tracked: Vec<_> = created.chain(destroyed).collect();
+    let track = if tracked.is_empty() {
+        quote!()
+    } else {
+        quote!(if ok {
+            unsafe {
+                #(#tracked)*
+            }
+        })
+    };
+    (check, track)
+}
+
+fn generate_tracer(
+    name: &str,
+    cmd: &vkxml::Command,
+    types: &TraceTypes,
+) -> (TokenStream, TokenStream) {
+    let fn_name = format_ident!("{}", name[2..].to_snake_case());
+    let slot = format_ident!("{}", name[2..].to_shouty_snake_case());
+    let params: Vec<_> = cmd
+        .param
+        .iter()
+        .map(|field| {
+            let ident = field.param_ident();
+            let ty = field.type_tokens(true);
+            quote!(#ident: #ty)
+        })
+        .collect();
+    let param_types: Vec<_> = cmd
+        .param
+        .iter()
+        .map(|field| field.type_tokens(true))
+        .collect();
+    let param_idents: Vec<_> = cmd.param.iter().map(|field| field.param_ident()).collect();
+    let arg_names = cmd
+        .param
+        .iter()
+        .map(|field| field.name.as_deref().unwrap_or("field"));
+    let (args, uses_ok): (Vec<_>, Vec<_>) = cmd
+        .param
+        .iter()
+        .map(|field| arg_tokens(field, cmd, &types.union_types))
+        .unzip();
+    let sync_guard = sync_guard_tokens(name, cmd, types);
+    let (check_handles, track_handles) = lifetime_tokens(name, cmd, types);
+    let return_type = cmd.return_type.type_tokens(true);
+    let result = match cmd.return_type.basetype.as_str() {
+        "void" => quote!(None),
+        _ => quote!(Some(&result)),
+    };
+    let uses_ok = uses_ok.contains(&true) || !track_handles.is_empty();
+    let ok = match (uses_ok, cmd.return_type.basetype.as_str()) {
+        (false, _) => quote!(),
+        (true, "VkResult") => quote!(let ok = result.as_raw() >= 0;),
+        (true, _) => quote!(let ok = true;),
+    };
+    let tracer = quote! {
+        static #slot: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+        extern "system" fn #fn_name(#(#params),*) -> #return_type {
+            let real: extern "system" fn(#(#param_types),*) -> #return_type =
+                unsafe { mem::transmute(#slot.load(Ordering::Relaxed)) };
+            #sync_guard
+            #check_handles
+            let result = real(#(#param_idents),*);
+            #ok
+            #track_handles
+            record(&TraceCall {
+                command: #name,
+                args: &[#((#arg_names, #args)),*],
+                result: #result,
+            });
+            result
+        }
+    };
+    let arm = quote!((&#slot, #fn_name as *const c_void));
+    (tracer, arm)
+}
+
+pub fn generate_trace(ctx: &Context) -> TokenStream {
+    let commands: BTreeMap<_, _> = ctx
+        .commands()
+        .into_iter()
+        .filter(|(name, _)| !PROC_ADDR_COMMANDS.contains(&name.as_str()))
+        .collect();
+    let definitions = ctx.definitions();
+    let mut types = TraceTypes {
+        union_types: HashSet::new(),
+        handle_types: HashSet::new(),
+        handle_parents: ctx.handle_parents(),
+        destroyable: HashSet::new(),
+        structs: HashMap::new(),
+        extern_sync: ctx.extern_sync_params(),
+    };
+    for def in &definitions {
+        match def {
+            vkxml::DefinitionsElement::Union(union) => {
+                types.union_types.insert(union.name.as_str());
+            }
+            vkxml::DefinitionsElement::Handle(handle) => {
+                types.handle_types.insert(handle.name.as_str());
+            }
+            vkxml::DefinitionsElement::Struct(_struct) => {
+                types.structs.insert(_struct.name.as_str(), _struct);
+            }
+            _ => {}
+        }
+    }
+    types.destroyable = commands
+        .iter()
+        .filter(|(name, _)| is_destroy_command(name))
+        .filter_map(|(_, cmd)| {
+            let field = cmd
+                .param
+                .iter()
+                .rev()
+                .find(|field| types.is_handle(field))?;
+            Some(field.basetype.as_str())
+        })
+        .collect();
+
+    // Command aliases are commands of their own in `vkxml`, so they get their own tracer
+    let (tracers, arms): (Vec<_>, Vec<_>) = commands
+        .iter()
+        .map(|(name, cmd)| generate_tracer(name, cmd, &types))
+        .unzip();
+    let arm_names = commands
+        .keys()
+        .map(|name| Literal::byte_string(name.as_bytes()));
+
+    quote! {
+        use super::lifetimes::{check_handles, track_created, track_destroyed};
+        use super::{handle_arg, record, CStrArg, Pointee, Pointees, SyncGuard, TraceCall};
+        use crate::vk::platform::*;
+        use crate::vk::*;
+        use std::mem;
+        use std::ptr;
+        use std::sync::atomic::{AtomicPtr, Ordering};
+        /// The slot that the real command of `name` is stored in, and the function that calls it.
+        pub(super) fn tracer(name: &[u8]) -> Option<(&'static AtomicPtr<c_void>, *const c_void)> {
+            Some(match name {
+                #(#arm_names => #arms,)*
+                _ => return None,
+            })
+        }
+        #(#tracers)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::tests::registry;
+
+    #[test]
+    fn tracers_record_arguments() {
+        let ctx = registry(
+            r#"<types>
+                <type category="handle" objtypeenum="VK_OBJECT_TYPE_DEVICE"><type>VK_DEFINE_HANDLE</type>(<name>VkDevice</name>)</type>
+                <type category="handle" parent="VkDevice" objtypeenum="VK_OBJECT_TYPE_BUFFER"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkBuffer</name>)</type>
+            </types>
+            <commands>
+                <command>
+                    <proto><type>void</type> <name>vkDestroyBuffer</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param optional="true" externsync="true"><type>VkBuffer</type> <name>buffer</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                </command>
+                <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+                    <proto><type>VkResult</type> <name>vkCreateBuffer</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param>const <type>VkBufferCreateInfo</type>* <name>pCreateInfo</name></param>
+                    <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+                    <param><type>VkBuffer</type>* <name>pBuffer</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkDestroyBuffers</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param><type>uint32_t</type> <name>bufferCount</name></param>
+                    <param len="bufferCount">const <type>VkBuffer</type>* <name>pBuffers</name></param>
+                    <param len="null-terminated">const <type>char</type>* <name>pReason</name></param>
+                </command>
+                <command>
+                    <proto><type>PFN_vkVoidFunction</type> <name>vkGetDeviceProcAddr</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param len="null-terminated">const <type>char</type>* <name>pName</name></param>
+                </command>
+            </commands>"#,
+        );
+        let code = generate_trace(&ctx).to_string();
+        assert!(code.contains(
+            "b\"vkCreateBuffer\" => (& CREATE_BUFFER , create_buffer as * const c_void) ,"
+        ));
+        assert!(!code.contains("vkGetDeviceProcAddr"));
+        assert!(code.contains("let ok = result . as_raw () >= 0 ;"));
+        assert!(code.contains("(\"pCreateInfo\" , & Pointee (p_create_info , true))"));
+        assert!(code.contains("(\"pBuffer\" , & Pointee (p_buffer , ok))"));
+        assert!(
+            code.contains("(\"pBuffers\" , & Pointees (p_buffers , buffer_count as usize , true))")
+        );
+        assert!(code.contains("(\"pReason\" , & CStrArg (p_reason))"));
+        assert!(code.contains("result : None ,"));
+        assert!(code.contains(
+            "let _sync = SyncGuard :: new (\"vkDestroyBuffer\" , & [handle_arg (\"buffer\" , buffer)]) ;"
+        ));
+        assert_eq!(code.matches("SyncGuard :: new").count(), 1);
+    }
+
+    #[test]
+    fn tracers_track_handle_lifetimes() {
+        let ctx = registry(
+            r#"<types>
+                <type category="handle" objtypeenum="VK_OBJECT_TYPE_DEVICE"><type>VK_DEFINE_HANDLE</type>(<name>VkDevice</name>)</type>
+                <type category="handle" parent="VkDevice" objtypeenum="VK_OBJECT_TYPE_COMMAND_POOL"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkCommandPool</name>)</type>
+                <type category="handle" parent="VkCommandPool" objtypeenum="VK_OBJECT_TYPE_COMMAND_BUFFER"><type>VK_DEFINE_HANDLE</type>(<name>VkCommandBuffer</name>)</type>
+                <type category="struct" name="VkCommandBufferAllocateInfo">
+                    <member><type>VkCommandPool</type> <name>commandPool</name></member>
+                    <member><type>uint32_t</type> <name>commandBufferCount</name></member>
+                </type>
+            </types>
+            <commands>
+                <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+                    <proto><type>VkResult</type> <name>vkAllocateCommandBuffers</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param>const <type>VkCommandBufferAllocateInfo</type>* <name>pAllocateInfo</name></param>
+                    <param len="pAllocateInfo-&gt;commandBufferCount"><type>VkCommandBuffer</type>* <name>pCommandBuffers</name></param>
+                </command>
+                <command>
+                    <proto><type>void</type> <name>vkFreeCommandBuffers</name></proto>
+                    <param><type>VkDevice</type> <name>device</name></param>
+                    <param><type>VkCommandPool</type> <name>commandPool</name></param>
+                    <param><type>uint32_t</type> <name>commandBufferCount</name></param>
+                    <param len="commandBufferCount">const <type>VkCommandBuffer</type>* <name>pCommandBuffers</name></param>
+                </command>
+            </commands>"#,
+        );
+        let code = generate_trace(&ctx).to_string();
+        assert!(code.contains("track_created (\"vkAllocateCommandBuffers\" , true , Some (handle_arg (\"pAllocateInfo->commandPool\" , (* p_allocate_info) . command_pool)) , p_command_buffers , (* p_allocate_info) . command_buffer_count as usize) ;"));
+        assert!(code.contains(
+            "check_handles (\"vkFreeCommandBuffers\" , & [handle_arg (\"device\" , device) , handle_arg (\"commandPool\" , command_pool)]) ;"
+        ));
+        assert!(code.contains(
+            "track_destroyed (\"vkFreeCommandBuffers\" , p_command_buffers , command_buffer_count as usize) ;"
+        ));
+        assert!(code.contains("let ok = true ;"));
+    }
+}
@@ -0,0 +1,538 @@
+//! Cross-checks the registry before code is generated from it. The generator trusts the registry,
+//! so a registry that contradicts itself, e.g. after a bad merge of a local patch, would otherwise
+//! end up as bindings that don't compile or that have wrong values.
+use crate::{Context, EnumValue, ExtensionEnum};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// An inconsistency in the registry found by [`Context::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Conflict {
+    /// Two extensions have the same number, so the values they add to enums overlap.
+    DuplicateExtensionNumber {
+        number: i64,
+        first: String,
+        second: String,
+    },
+    /// An enum value is defined twice with different values.
+    DuplicateEnumValue {
+        name: String,
+        first: String,
+        second: String,
+    },
+    /// An alias of an enum value, type or command refers to one that doesn't exist.
+    MissingAliasTarget {
+        kind: &'static str,
+        name: String,
+        alias: String,
+    },
+    /// A struct lists a type in `structextends` that doesn't exist.
+    UnknownStructExtends { name: String, extends: String },
+    /// A part of the registry that the generator doesn't know how to generate, found by
+    /// [`Context::check_strict`]. `fragment` is the XML of it, rebuilt from the parsed registry.
+    UnknownConstruct {
+        kind: &'static str,
+        fragment: String,
+    },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Conflict::DuplicateExtensionNumber {
+                number,
+                first,
+                second,
+            } => write!(
+                f,
+                "`{}` and `{}` both have extension number {}",
+                first, second, number
+            ),
+            Conflict::DuplicateEnumValue {
+                name,
+                first,
+                second,
+            } => write!(f, "`{}` is defined as {} and as {}", name, first, second),
+            Conflict::MissingAliasTarget { kind, name, alias } => write!(
+                f,
+                "{} `{}` is an alias of `{}`, which doesn't exist",
+                kind, name, alias
+            ),
+            Conflict::UnknownStructExtends { name, extends } => {
+                write!(f, "`{}` extends `{}`, which doesn't exist", name, extends)
+            }
+            Conflict::UnknownConstruct { kind, fragment } => {
+                write!(f, "Unknown {}: {}", kind, fragment)
+            }
+        }
+    }
+}
+
+/// The conflicts found by [`Context::validate`], displayed as one line per conflict.
+#[derive(Clone, Debug)]
+pub struct RegistryConflicts {
+    pub conflicts: Vec<Conflict>,
+}
+
+impl fmt::Display for RegistryConflicts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The registry has {} conflict(s):", self.conflicts.len())?;
+        for conflict in &self.conflicts {
+            write!(f, "\n  {}", conflict)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for RegistryConflicts {}
+
+/// Describes where an enum value is defined and what it is defined as, for the report.
+fn describe(value: EnumValue<'_>, defined_in: &str) -> String {
+    match value {
+        EnumValue::Number(value) => format!("{} in `{}`", value, defined_in),
+        EnumValue::BitPos(bitpos) => format!("bit {} in `{}`", bitpos, defined_in),
+        EnumValue::Alias(alias) => format!("an alias of `{}` in `{}`", alias, defined_in),
+    }
+}
+
+/// The type categories that the generator knows. Types without a category are platform types
+/// like `Display` and C types like `uint32_t`.
+const TYPE_CATEGORIES: &[&str] = &[
+    "include",
+    "define",
+    "basetype",
+    "bitmask",
+    "handle",
+    "enum",
+    "funcpointer",
+    "struct",
+    "union",
+];
+
+/// The kinds of `<enums>` blocks that the generator knows. The block of API constants doesn't
+/// have a kind in older registries.
+const ENUMS_KINDS: &[&str] = &["enum", "bitmask", "constants"];
+
+fn enum_fragment(name: &str, spec: &vk_parse::EnumSpec) -> String {
+    use vk_parse::EnumSpec;
+    let extends = |extends: Option<&str>| {
+        extends
+            .map(|extends| format!(" extends=\"{}\"", extends))
+            .unwrap_or_default()
+    };
+    match spec {
+        EnumSpec::Alias { alias, extends: e } => format!(
+            "<enum alias=\"{}\"{} name=\"{}\"/>",
+            alias,
+            extends(e.as_deref()),
+            name
+        ),
+        EnumSpec::Offset {
+            offset,
+            extends: e,
+            extnumber,
+            dir,
+        } => format!(
+            "<enum offset=\"{}\"{}{}{} name=\"{}\"/>",
+            offset,
+            if *dir { "" } else { " dir=\"-\"" },
+            extnumber
+                .map(|extnumber| format!(" extnumber=\"{}\"", extnumber))
+                .unwrap_or_default(),
+            extends(Some(e)),
+            name
+        ),
+        EnumSpec::Bitpos { bitpos, extends: e } => format!(
+            "<enum bitpos=\"{}\"{} name=\"{}\"/>",
+            bitpos,
+            extends(e.as_deref()),
+            name
+        ),
+        EnumSpec::Value { value, extends: e } => format!(
+            "<enum value=\"{}\"{} name=\"{}\"/>",
+            value,
+            extends(e.as_deref()),
+            name
+        ),
+        _ => format!("<enum name=\"{}\"/>", name),
+    }
+}
+
+fn type_name(ty: &vk_parse::Type) -> Option<&str> {
+    ty.name.as_deref().or_else(|| match &ty.spec {
+        vk_parse::TypeSpec::Code(code) => code.markup.iter().find_map(|markup| match markup {
+            vk_parse::TypeCodeMarkup::Name(name) => Some(name.as_str()),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+impl Context {
+    fn types(&self) -> impl Iterator<Item = &vk_parse::Type> {
+        self.registry()
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Types(types) => Some(types.children.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|child| match child {
+                vk_parse::TypesChild::Type(ty) => Some(ty),
+                _ => None,
+            })
+    }
+
+    /// The values in the `<enums>` blocks, as [`ExtensionEnum`]s that are provided by the block.
+    fn block_enums(&self) -> Vec<ExtensionEnum<'_>> {
+        self.registry()
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                vk_parse::RegistryChild::Enums(enums) => Some((enums.name.as_deref()?, enums)),
+                _ => None,
+            })
+            .flat_map(|(enum_name, enums)| {
+                enums.children.iter().filter_map(move |child| match child {
+                    vk_parse::EnumsChild::Enum(e) => Some(ExtensionEnum {
+                        provided_by: enum_name,
+                        extension_number: 0,
+                        name: &e.name,
+                        extends: enum_name,
+                        comment: e.comment.as_deref(),
+                        spec: &e.spec,
+                    }),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    /// Cross-checks extension numbers, enum values, aliases and `structextends`, and returns
+    /// every conflict that would lead to broken bindings. A value that is required by several
+    /// extensions, or by an extension and the core version it was promoted to, isn't a conflict
+    /// as long as it resolves to the same value everywhere.
+    pub fn validate(&self) -> Result<(), RegistryConflicts> {
+        let mut conflicts = Vec::new();
+
+        let mut numbers = HashMap::new();
+        for ext in self.extensions() {
+            if let Some(number) = ext.number {
+                if let Some(first) = numbers.insert(number, ext.name.as_str()) {
+                    conflicts.push(Conflict::DuplicateExtensionNumber {
+                        number,
+                        first: first.to_owned(),
+                        second: ext.name.clone(),
+                    });
+                }
+            }
+        }
+
+        // API constants and extension names are enums without `extends`, they are only
+        // checked as alias targets
+        let mut block_enums = self.block_enums();
+        block_enums.extend(self.extension_enums());
+        let enum_names: HashSet<&str> = self
+            .core_features()
+            .map(|feature| &feature.children)
+            .chain(self.extensions().iter().map(|ext| &ext.children))
+            .flatten()
+            .filter_map(|child| match child {
+                vk_parse::ExtensionChild::Require { items, .. } => Some(items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                vk_parse::InterfaceItem::Enum(e) => Some(e.name.as_str()),
+                _ => None,
+            })
+            .chain(block_enums.iter().map(|e| e.name))
+            .collect();
+        let mut values = HashMap::new();
+        for e in &block_enums {
+            let value = match e.value() {
+                Some(value) => value,
+                None => continue,
+            };
+            if let EnumValue::Alias(alias) = value {
+                if !enum_names.contains(alias) {
+                    conflicts.push(Conflict::MissingAliasTarget {
+                        kind: "Enum value",
+                        name: e.name.to_owned(),
+                        alias: alias.to_owned(),
+                    });
+                }
+            }
+            match values.get(e.name) {
+                Some(&(first, first_provided_by)) if first != value => {
+                    conflicts.push(Conflict::DuplicateEnumValue {
+                        name: e.name.to_owned(),
+                        first: describe(first, first_provided_by),
+                        second: describe(value, e.provided_by),
+                    })
+                }
+                Some(_) => {}
+                None => {
+                    values.insert(e.name, (value, e.provided_by));
+                }
+            }
+        }
+
+        let type_names: HashSet<&str> = self.types().filter_map(type_name).collect();
+        for ty in self.types() {
+            let name = match type_name(ty) {
+                Some(name) => name,
+                None => continue,
+            };
+            if let Some(alias) = &ty.alias {
+                if !type_names.contains(alias.as_str()) {
+                    conflicts.push(Conflict::MissingAliasTarget {
+                        kind: "Type",
+                        name: name.to_owned(),
+                        alias: alias.clone(),
+                    });
+                }
+            }
+            for extends in ty.structextends.iter().flat_map(|s| s.split(',')) {
+                if !type_names.contains(extends) {
+                    conflicts.push(Conflict::UnknownStructExtends {
+                        name: name.to_owned(),
+                        extends: extends.to_owned(),
+                    });
+                }
+            }
+        }
+
+        let command_names: HashSet<&str> = self
+            .command_definitions()
+            .map(|def| def.proto.name.as_str())
+            .collect();
+        let mut command_aliases: Vec<_> = self.command_aliases().into_iter().collect();
+        command_aliases.sort();
+        for (name, alias) in command_aliases {
+            if !command_names.contains(alias.as_str()) {
+                conflicts.push(Conflict::MissingAliasTarget {
+                    kind: "Command",
+                    name,
+                    alias,
+                });
+            }
+        }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(RegistryConflicts { conflicts })
+        }
+    }
+
+    /// Returns every part of the registry that the generator doesn't know, and would leave out
+    /// of the bindings or generate incompletely: elements and attributes that `vk_parse` doesn't
+    /// know, unknown type categories and kinds of `<enums>`, and enum values that don't have a
+    /// value the generator can use. Used by the `--strict` option of the generator, so that a
+    /// new revision of the registry can't silently lead to incomplete bindings.
+    pub fn check_strict(&self) -> Result<(), RegistryConflicts> {
+        let mut conflicts: Vec<_> = self
+            .parse_errors()
+            .iter()
+            .map(|err| Conflict::UnknownConstruct {
+                kind: "XML",
+                fragment: format!("{:?}", err),
+            })
+            .collect();
+
+        for ty in self.types() {
+            if let Some(category) = &ty.category {
+                if !TYPE_CATEGORIES.contains(&category.as_str()) {
+                    conflicts.push(Conflict::UnknownConstruct {
+                        kind: "type category",
+                        fragment: format!(
+                            "<type category=\"{}\" name=\"{}\">",
+                            category,
+                            type_name(ty).unwrap_or_default()
+                        ),
+                    });
+                }
+            }
+        }
+
+        for item in &self.registry().0 {
+            if let vk_parse::RegistryChild::Enums(enums) = item {
+                if let Some(kind) = &enums.kind {
+                    if !ENUMS_KINDS.contains(&kind.as_str()) {
+                        conflicts.push(Conflict::UnknownConstruct {
+                            kind: "kind of enums",
+                            fragment: format!(
+                                "<enums name=\"{}\" type=\"{}\">",
+                                enums.name.as_deref().unwrap_or_default(),
+                                kind
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Values that extend an enum must resolve to a number that fits the enum. The values in
+        // the block of an enum can also be hexadecimal or expressions, like API constants, but
+        // they can't be references without a value
+        let block_enums = self
+            .block_enums()
+            .into_iter()
+            .filter(|e| matches!(e.spec, vk_parse::EnumSpec::None));
+        let extension_enums = self
+            .extension_enums()
+            .into_iter()
+            .filter(|e| match e.value() {
+                Some(EnumValue::Number(value)) => i32::try_from(value).is_err(),
+                Some(_) => false,
+                None => true,
+            });
+        for e in block_enums.chain(extension_enums) {
+            conflicts.push(Conflict::UnknownConstruct {
+                kind: "enum value",
+                fragment: enum_fragment(e.name, e.spec),
+            });
+        }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(RegistryConflicts { conflicts })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::tests::VK_XML;
+
+    #[test]
+    fn consistent_registry() {
+        let ctx = Context::parse(VK_XML).unwrap();
+        assert!(ctx.validate().is_ok());
+    }
+
+    #[test]
+    fn report_conflicts() {
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type name="VkStructureType" category="enum"/>
+                <type category="struct" name="VkA" structextends="VkStructureType,VkMissing"/>
+                <type category="struct" name="VkBKHR" alias="VkB"/>
+            </types>
+            <enums name="VkStructureType" type="enum">
+                <enum value="0" name="VK_STRUCTURE_TYPE_APPLICATION_INFO"/>
+                <enum name="VK_STRUCTURE_TYPE_OLD" alias="VK_STRUCTURE_TYPE_GONE"/>
+            </enums>
+            <commands>
+                <command name="vkTrimCommandPoolKHR" alias="vkTrimCommandPool"/>
+            </commands>
+            <extensions>
+                <extension name="VK_KHR_a" number="2" supported="vulkan">
+                    <require>
+                        <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_A_KHR"/>
+                    </require>
+                </extension>
+                <extension name="VK_KHR_b" number="2" supported="vulkan">
+                    <require>
+                        <enum offset="1" extends="VkStructureType" name="VK_STRUCTURE_TYPE_A_KHR"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let report = ctx.validate().unwrap_err();
+        assert_eq!(
+            report.conflicts,
+            vec![
+                Conflict::DuplicateExtensionNumber {
+                    number: 2,
+                    first: "VK_KHR_a".to_owned(),
+                    second: "VK_KHR_b".to_owned(),
+                },
+                Conflict::MissingAliasTarget {
+                    kind: "Enum value",
+                    name: "VK_STRUCTURE_TYPE_OLD".to_owned(),
+                    alias: "VK_STRUCTURE_TYPE_GONE".to_owned(),
+                },
+                Conflict::DuplicateEnumValue {
+                    name: "VK_STRUCTURE_TYPE_A_KHR".to_owned(),
+                    first: "1000001000 in `VK_KHR_a`".to_owned(),
+                    second: "1000001001 in `VK_KHR_b`".to_owned(),
+                },
+                Conflict::UnknownStructExtends {
+                    name: "VkA".to_owned(),
+                    extends: "VkMissing".to_owned(),
+                },
+                Conflict::MissingAliasTarget {
+                    kind: "Type",
+                    name: "VkBKHR".to_owned(),
+                    alias: "VkB".to_owned(),
+                },
+                Conflict::MissingAliasTarget {
+                    kind: "Command",
+                    name: "vkTrimCommandPoolKHR".to_owned(),
+                    alias: "vkTrimCommandPool".to_owned(),
+                },
+            ]
+        );
+        assert!(report
+            .to_string()
+            .starts_with("The registry has 6 conflict(s):\n  `VK_KHR_a` and `VK_KHR_b`"));
+    }
+
+    #[test]
+    fn strict_unknown_constructs() {
+        let ctx = Context::parse(VK_XML).unwrap();
+        assert!(ctx.check_strict().is_ok());
+
+        let ctx = Context::parse(
+            r#"<registry>
+            <types>
+                <type name="VkStructureType" category="enum"/>
+                <type category="hologram" name="VkHologram"/>
+            </types>
+            <enums name="VkStructureType" type="enum">
+                <enum value="0" name="VK_STRUCTURE_TYPE_APPLICATION_INFO"/>
+            </enums>
+            <enums name="VkQuantumBits" type="qubits">
+            </enums>
+            <extensions>
+                <extension name="VK_KHR_a" number="2" supported="vulkan">
+                    <require>
+                        <enum value="0x10" extends="VkStructureType" name="VK_STRUCTURE_TYPE_A_KHR"/>
+                    </require>
+                </extension>
+            </extensions>
+        </registry>"#,
+        )
+        .unwrap();
+        let report = ctx.check_strict().unwrap_err();
+        assert_eq!(
+            report.conflicts,
+            vec![
+                Conflict::UnknownConstruct {
+                    kind: "type category",
+                    fragment: r#"<type category="hologram" name="VkHologram">"#.to_owned(),
+                },
+                Conflict::UnknownConstruct {
+                    kind: "kind of enums",
+                    fragment: r#"<enums name="VkQuantumBits" type="qubits">"#.to_owned(),
+                },
+                Conflict::UnknownConstruct {
+                    kind: "enum value",
+                    fragment:
+                        r#"<enum value="0x10" extends="VkStructureType" name="VK_STRUCTURE_TYPE_A_KHR"/>"#
+                            .to_owned(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Support for the spec's `validusage.json`, which lists the valid usage statements (VUIDs)
+//! for every command and struct. These are rendered into doc comments on the generated code.
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidUsage {
+    pub vuid: String,
+    /// Plain text of the statement, with the spec's html markup converted to markdown.
+    pub text: String,
+    /// The extension(s) or version(s) the statement applies to, `None` for core statements.
+    pub condition: Option<String>,
+}
+
+/// Valid usage statements keyed by the Vulkan name of the command or struct, e.g.
+/// `vkCreateInstance` or `VkInstanceCreateInfo`.
+#[derive(Clone, Debug, Default)]
+pub struct ValidUsageMap(HashMap<String, Vec<ValidUsage>>);
+
+impl ValidUsageMap {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        let root: serde_json::Value = serde_json::from_str(json)?;
+        let mut map = HashMap::new();
+        if let Some(validation) = root.get("validation").and_then(|v| v.as_object()) {
+            for (name, conditions) in validation {
+                let mut usages =
+                    conditions
+                        .as_object()
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|(condition, statements)| {
+                            let condition = match condition.as_str() {
+                                "core" => None,
+                                c => Some(c.trim_matches(|c| c == '(' || c == ')').to_string()),
+                            };
+                            statements.as_array().into_iter().flatten().filter_map(
+                                move |statement| {
+                                    Some(ValidUsage {
+                                        vuid: statement.get("vuid")?.as_str()?.to_string(),
+                                        text: html_to_markdown(statement.get("text")?.as_str()?),
+                                        condition: condition.clone(),
+                                    })
+                                },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                // Core statements first, the order within a condition is kept
+                usages.sort_by_key(|usage| usage.condition.is_some());
+                map.insert(name.clone(), usages);
+            }
+        }
+        Ok(ValidUsageMap(map))
+    }
+
+    /// Reads `validusage.json` from `path`. Returns an empty map if the file doesn't exist, so
+    /// that generating without it simply omits the valid usage docs.
+    pub fn from_file(path: &Path) -> Self {
+        if !path.exists() {
+            return ValidUsageMap::default();
+        }
+        let json = std::fs::read_to_string(path).expect("Unable to read validusage.json");
+        Self::parse(&json).expect("Invalid validusage.json")
+    }
+
+    pub fn get(&self, name: &str) -> &[ValidUsage] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the `#[doc]` attributes listing the valid usage statements of `name`, or nothing
+    /// if there are none.
+    pub fn doc_tokens(&self, name: &str) -> TokenStream {
+        let usages = self.get(name);
+        if usages.is_empty() {
+            return quote!();
+        }
+        let lines = usages.iter().map(|usage| {
+            let line = match &usage.condition {
+                Some(condition) => {
+                    format!(" - `{}`: {} (with `{}`)", usage.vuid, usage.text, condition)
+                }
+                None => format!(" - `{}`: {}", usage.vuid, usage.text),
+            };
+            quote!(#[doc = #line])
+        });
+        quote! {
+            #[doc = ""]
+            #[doc = " # Valid Usage"]
+            #[doc = ""]
+            #(#lines)*
+        }
+    }
+}
+
+/// The valid usage texts are html fragments. Inline code becomes markdown code, all other tags
+/// are dropped and entities are unescaped.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+        match &rest[start + 1..end] {
+            "code" | "/code" => out.push('`'),
+            _ => {}
+        }
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    let out = out
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#8217;", "'")
+        .replace("&amp;", "&");
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALIDUSAGE: &str = r##"{
+        "version info": { "schema version": 2, "api version": "1.2.162" },
+        "validation": {
+            "vkCmdDraw": {
+                "core": [
+                    { "vuid": "VUID-vkCmdDraw-None-02690", "text": " If a <code>VkImageView</code> is sampled with <a href=\"#textures-depth-compare-operation\">depth comparison</a>, the image view&#8217;s format <strong class=\"purple\">must</strong> be valid" }
+                ],
+                "(VK_EXT_transform_feedback)": [
+                    { "vuid": "VUID-vkCmdDraw-None-02720", "text": "<code>transformFeedbackQueries</code> &amp; more" }
+                ]
+            }
+        }
+    }"##;
+
+    #[test]
+    fn parse_validusage() {
+        let map = ValidUsageMap::parse(VALIDUSAGE).unwrap();
+        let usages = map.get("vkCmdDraw");
+        assert_eq!(usages.len(), 2);
+        assert_eq!(usages[0].vuid, "VUID-vkCmdDraw-None-02690");
+        assert_eq!(
+            usages[0].text,
+            "If a `VkImageView` is sampled with depth comparison, the image view's format must be valid"
+        );
+        assert_eq!(usages[0].condition, None);
+        assert_eq!(usages[1].text, "`transformFeedbackQueries` & more");
+        assert_eq!(
+            usages[1].condition.as_deref(),
+            Some("VK_EXT_transform_feedback")
+        );
+        assert!(map.get("vkCmdDispatch").is_empty());
+        assert!(map.doc_tokens("vkCmdDispatch").is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Compares the enum values and flag bits generated from `Vulkan-Headers` against the generated
+//! code of upstream ash, to catch renamed or changed values. Needs the `parity` feature and the
+//! upstream code, `ASH_UPSTREAM_VK` being the path of its `vk.rs` or of its `vk` directory:
+//!
+//! ```sh
+//! ASH_UPSTREAM_VK=../upstream-ash/ash/src/vk cargo test -p generator --features parity
+//! ```
+//!
+//! Values that only exist in the generated code, from a newer registry, aren't reported.
+#![cfg(feature = "parity")]
+use generator::{Context, ValidUsageMap};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The value of every `pub const NAME: Self = Self(<literal>);` by type and name. Aliases of
+/// other values are left out, their targets are compared instead.
+type Values = BTreeMap<(String, String), i128>;
+
+fn literal_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_value(expr).map(|value| -value),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => literal_value(expr),
+        _ => None,
+    }
+}
+
+/// The value of `Self(<literal>)`, `None` for aliases and expressions.
+fn self_literal(expr: &syn::Expr) -> Option<i128> {
+    let call = match expr {
+        syn::Expr::Call(call) if call.args.len() == 1 => call,
+        _ => return None,
+    };
+    match &*call.func {
+        syn::Expr::Path(path) if path.path.is_ident("Self") => literal_value(&call.args[0]),
+        _ => None,
+    }
+}
+
+fn collect_values(items: &[syn::Item], values: &mut Values) {
+    for item in items {
+        match item {
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => collect_values(items, values),
+            syn::Item::Impl(imp) if imp.trait_.is_none() => {
+                let ty = match &*imp.self_ty {
+                    syn::Type::Path(path) => match path.path.segments.last() {
+                        Some(segment) => segment.ident.to_string(),
+                        None => continue,
+                    },
+                    _ => continue,
+                };
+                for item in &imp.items {
+                    let constant = match item {
+                        syn::ImplItem::Const(constant) => constant,
+                        _ => continue,
+                    };
+                    if let Some(value) = self_literal(&constant.expr) {
+                        values.insert((ty.clone(), constant.ident.to_string()), value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn upstream_values(path: &Path, values: &mut Values) {
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path).unwrap() {
+            upstream_values(&entry.unwrap().path(), values);
+        }
+    } else if path.extension().map_or(false, |ext| ext == "rs") {
+        let code = std::fs::read_to_string(path).unwrap();
+        let file = syn::parse_file(&code)
+            .unwrap_or_else(|err| panic!("Unable to parse {}: {}", path.display(), err));
+        collect_values(&file.items, values);
+    }
+}
+
+#[test]
+fn enum_values_match_upstream() {
+    let upstream = std::env::var_os("ASH_UPSTREAM_VK")
+        .expect("`ASH_UPSTREAM_VK` must be the path of the `vk.rs` or `vk` of upstream ash");
+    let mut expected = Values::new();
+    upstream_values(Path::new(&upstream), &mut expected);
+    assert!(
+        !expected.is_empty(),
+        "No enum values in {:?}",
+        Path::new(&upstream)
+    );
+
+    let vk_xml = Path::new(env!("CARGO_MANIFEST_DIR")).join("Vulkan-Headers/registry/vk.xml");
+    let ctx = Context::from_file(&vk_xml).expect("Invalid xml file");
+    let mut generated = Values::new();
+    for file in ctx.render(&ValidUsageMap::default()).files {
+        let path = file.path;
+        let file: syn::File = syn::parse2(file.code)
+            .unwrap_or_else(|err| panic!("Unable to parse generated {}: {}", path, err));
+        collect_values(&file.items, &mut generated);
+    }
+
+    let regressions: Vec<_> = expected
+        .iter()
+        .filter_map(
+            |((ty, name), value)| match generated.get(&(ty.clone(), name.clone())) {
+                Some(generated) if generated == value => None,
+                Some(generated) => Some(format!(
+                    "{}::{} is {}, upstream {}",
+                    ty, name, generated, value
+                )),
+                None => Some(format!("{}::{} is missing, upstream {}", ty, name, value)),
+            },
+        )
+        .collect();
+    assert!(
+        regressions.is_empty(),
+        "{} of {} upstream values differ:\n{}",
+        regressions.len(),
+        expected.len(),
+        regressions.join("\n")
+    );
+}